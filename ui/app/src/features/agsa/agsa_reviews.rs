@@ -18,6 +18,7 @@ use super::types::{
     ComplianceStatus,
 };
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// AGSA Reviews page
 #[component]
@@ -68,49 +69,49 @@ pub fn agsa_reviews() -> View {
     let finding_columns = vec![
         DataTableColumn {
             key: "reference".to_string(),
-            label: "Reference".to_string(),
+            label: t("Reference").to_string(),
             width: Some("160px".to_string()),
             align: None,
             cell_class: Some("id-cell".to_string()),
         },
         DataTableColumn {
             key: "title".to_string(),
-            label: "Finding".to_string(),
+            label: t("Finding").to_string(),
             width: None,
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "category".to_string(),
-            label: "Category".to_string(),
+            label: t("Category").to_string(),
             width: Some("140px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "severity".to_string(),
-            label: "Severity".to_string(),
+            label: t("Severity").to_string(),
             width: Some("100px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "department".to_string(),
-            label: "Department".to_string(),
+            label: t("Department").to_string(),
             width: Some("150px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "target".to_string(),
-            label: "Target Date".to_string(),
+            label: t("Target Date").to_string(),
             width: Some("100px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "status".to_string(),
-            label: "Status".to_string(),
+            label: t("Status").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
@@ -130,10 +131,10 @@ pub fn agsa_reviews() -> View {
             };
 
             let severity_tag = match finding.severity {
-                FindingSeverity::Material => tag("Material".to_string(), TagType::Danger),
-                FindingSeverity::Significant => tag("Significant".to_string(), TagType::Warning),
-                FindingSeverity::Minor => tag("Minor".to_string(), TagType::Info),
-                FindingSeverity::Observation => tag("Observation".to_string(), TagType::Default),
+                FindingSeverity::Material => tag(t("Material").to_string(), TagType::Danger),
+                FindingSeverity::Significant => tag(t("Significant").to_string(), TagType::Warning),
+                FindingSeverity::Minor => tag(t("Minor").to_string(), TagType::Info),
+                FindingSeverity::Observation => tag(t("Observation").to_string(), TagType::Default),
             };
 
             let category_display = finding.category.label();
@@ -142,7 +143,7 @@ pub fn agsa_reviews() -> View {
                 view! {
                     <span class="title-cell">
                         {finding.title.clone()}
-                        <span class="repeat-badge" title="Repeat Finding">" (R)"</span>
+                        <span class="repeat-badge" title={t("Repeat Finding")}>{t(" (R)")}</span>
                     </span>
                 }
             } else {
@@ -168,49 +169,49 @@ pub fn agsa_reviews() -> View {
     let action_columns = vec![
         DataTableColumn {
             key: "reference".to_string(),
-            label: "Reference".to_string(),
+            label: t("Reference").to_string(),
             width: Some("140px".to_string()),
             align: None,
             cell_class: Some("id-cell".to_string()),
         },
         DataTableColumn {
             key: "description".to_string(),
-            label: "Action".to_string(),
+            label: t("Action").to_string(),
             width: None,
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "assigned".to_string(),
-            label: "Assigned To".to_string(),
+            label: t("Assigned To").to_string(),
             width: Some("150px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "priority".to_string(),
-            label: "Priority".to_string(),
+            label: t("Priority").to_string(),
             width: Some("90px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "due".to_string(),
-            label: "Due Date".to_string(),
+            label: t("Due Date").to_string(),
             width: Some("100px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "progress".to_string(),
-            label: "Progress".to_string(),
+            label: t("Progress").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "status".to_string(),
-            label: "Status".to_string(),
+            label: t("Status").to_string(),
             width: Some("110px".to_string()),
             align: None,
             cell_class: None,
@@ -230,10 +231,10 @@ pub fn agsa_reviews() -> View {
             };
 
             let priority_tag = match action.priority {
-                ActionPriority::Critical => tag("Critical".to_string(), TagType::Danger),
-                ActionPriority::High => tag("High".to_string(), TagType::Warning),
-                ActionPriority::Medium => tag("Medium".to_string(), TagType::Info),
-                ActionPriority::Low => tag("Low".to_string(), TagType::Default),
+                ActionPriority::Critical => tag(t("Critical").to_string(), TagType::Danger),
+                ActionPriority::High => tag(t("High").to_string(), TagType::Warning),
+                ActionPriority::Medium => tag(t("Medium").to_string(), TagType::Info),
+                ActionPriority::Low => tag(t("Low").to_string(), TagType::Default),
             };
 
             let progress_color = if action.progress_percent >= 75 {
@@ -270,42 +271,42 @@ pub fn agsa_reviews() -> View {
     let report_columns = vec![
         DataTableColumn {
             key: "reference".to_string(),
-            label: "Report No.".to_string(),
+            label: t("Report No.").to_string(),
             width: Some("160px".to_string()),
             align: None,
             cell_class: Some("id-cell".to_string()),
         },
         DataTableColumn {
             key: "year".to_string(),
-            label: "Financial Year".to_string(),
+            label: t("Financial Year").to_string(),
             width: Some("110px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "type".to_string(),
-            label: "Type".to_string(),
+            label: t("Type").to_string(),
             width: Some("130px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "findings".to_string(),
-            label: "Findings".to_string(),
+            label: t("Findings").to_string(),
             width: Some("90px".to_string()),
             align: Some("center".to_string()),
             cell_class: None,
         },
         DataTableColumn {
             key: "resolved".to_string(),
-            label: "Resolved".to_string(),
+            label: t("Resolved").to_string(),
             width: Some("90px".to_string()),
             align: Some("center".to_string()),
             cell_class: None,
         },
         DataTableColumn {
             key: "status".to_string(),
-            label: "Opinion".to_string(),
+            label: t("Opinion").to_string(),
             width: Some("180px".to_string()),
             align: None,
             cell_class: None,
@@ -316,12 +317,12 @@ pub fn agsa_reviews() -> View {
     let report_rows: Vec<DataTableRow> = {
         audit_reports.get().iter().map(|report| {
             let status_tag = match report.compliance_status {
-                ComplianceStatus::Clean => tag("Clean Audit".to_string(), TagType::Success),
-                ComplianceStatus::UnqualifiedWithFindings => tag("Unqualified w/Findings".to_string(), TagType::Info),
-                ComplianceStatus::Qualified => tag("Qualified".to_string(), TagType::Warning),
-                ComplianceStatus::Adverse => tag("Adverse".to_string(), TagType::Danger),
-                ComplianceStatus::Disclaimer => tag("Disclaimer".to_string(), TagType::Danger),
-                ComplianceStatus::PendingAudit => tag("Pending".to_string(), TagType::Default),
+                ComplianceStatus::Clean => tag(t("Clean Audit").to_string(), TagType::Success),
+                ComplianceStatus::UnqualifiedWithFindings => tag(t("Unqualified w/Findings").to_string(), TagType::Info),
+                ComplianceStatus::Qualified => tag(t("Qualified").to_string(), TagType::Warning),
+                ComplianceStatus::Adverse => tag(t("Adverse").to_string(), TagType::Danger),
+                ComplianceStatus::Disclaimer => tag(t("Disclaimer").to_string(), TagType::Danger),
+                ComplianceStatus::PendingAudit => tag(t("Pending").to_string(), TagType::Default),
             };
 
             let type_display = report.audit_type.label();
@@ -453,7 +454,7 @@ pub fn agsa_reviews() -> View {
     let minor_count = findings.get().iter().filter(|f| matches!(f.severity, FindingSeverity::Minor)).count();
     let observation_count = findings.get().iter().filter(|f| matches!(f.severity, FindingSeverity::Observation)).count();
     let compliance_style = format!("background: {}20; color: {}", compliance_display.1, compliance_display.1);
-    let compliance_label = compliance_display.0;
+    let compliance_label = t(compliance_display.0);
 
     view! {
         style {
@@ -641,46 +642,46 @@ pub fn agsa_reviews() -> View {
 
         <div class="agsa-reviews" data-testid="agsa-reviews">
             {page_header(
-                "AGSA - Audit Findings".to_string(),
-                Some("Auditor-General South Africa findings, action items, and compliance tracking".to_string()),
+                t("AGSA - Audit Findings").to_string(),
+                Some(t("Auditor-General South Africa findings, action items, and compliance tracking").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export Report"</button> },
-                    view! { <button class="btn btn-primary">"Submit Action Plan"</button> },
+                    view! { <button class="btn btn-secondary">{t("Export Report")}</button> },
+                    view! { <button class="btn btn-primary">{t("Submit Action Plan")}</button> },
                 ]
             )}
 
             // KPI Row
             <div class="kpi-grid">
                 {kpi_card(
-                    "Total Findings".to_string(),
+                    t("Total Findings").to_string(),
                     kpis.get().total_findings.to_string(),
                     KpiColor::Orange,
                     icon_alert.to_string(),
-                    Some(KpiDelta { value: format!("{} material", kpis.get().open_findings), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: tn("{n} material", "{n} material", kpis.get().open_findings as i64), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Action Items".to_string(),
+                    t("Action Items").to_string(),
                     kpis.get().total_action_items.to_string(),
                     KpiColor::Blue,
                     icon_target.to_string(),
-                    Some(KpiDelta { value: format!("{} overdue", kpis.get().overdue_actions), is_positive: Some(false), suffix: "".to_string() }),
+                    Some(KpiDelta { value: tn("{n} overdue", "{n} overdue", kpis.get().overdue_actions as i64), is_positive: Some(false), suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Resolution Rate".to_string(),
+                    t("Resolution Rate").to_string(),
                     format!("{:.1}%", kpis.get().resolution_rate),
                     KpiColor::Green,
                     icon_trending.to_string(),
-                    Some(KpiDelta { value: "Target: 100%".to_string(), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: tf("Target: {value}", &[("value", "100%".to_string())]), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Financial Impact".to_string(),
+                    t("Financial Impact").to_string(),
                     format_currency(kpis.get().financial_impact_total),
                     KpiColor::Red,
                     icon_dollar.to_string(),
-                    Some(KpiDelta { value: "Identified exposure".to_string(), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: t("Identified exposure").to_string(), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
             </div>
@@ -694,7 +695,7 @@ pub fn agsa_reviews() -> View {
                     <div class="summary-icon open" inner_html={icon_file}></div>
                     <div class="summary-content">
                         <h3>{total_findings_count.to_string()}</h3>
-                        <p>"Total Findings"</p>
+                        <p>{t("Total Findings")}</p>
                     </div>
                 </div>
                 <div
@@ -704,7 +705,7 @@ pub fn agsa_reviews() -> View {
                     <div class="summary-icon open" inner_html={icon_alert}></div>
                     <div class="summary-content">
                         <h3>{open_count.to_string()}</h3>
-                        <p>"Open"</p>
+                        <p>{t("Open")}</p>
                     </div>
                 </div>
                 <div
@@ -714,7 +715,7 @@ pub fn agsa_reviews() -> View {
                     <div class="summary-icon progress" inner_html={icon_clock}></div>
                     <div class="summary-content">
                         <h3>{in_progress_count.to_string()}</h3>
-                        <p>"In Progress"</p>
+                        <p>{t("In Progress")}</p>
                     </div>
                 </div>
                 <div
@@ -724,7 +725,7 @@ pub fn agsa_reviews() -> View {
                     <div class="summary-icon resolved" inner_html={icon_check}></div>
                     <div class="summary-content">
                         <h3>{resolved_count.to_string()}</h3>
-                        <p>"Resolved"</p>
+                        <p>{t("Resolved")}</p>
                     </div>
                 </div>
             </div>
@@ -735,9 +736,9 @@ pub fn agsa_reviews() -> View {
                 <div class="main-content">
                     {tabs(
                         vec![
-                            Tab { id: "findings".to_string(), label: "Audit Findings".to_string(), icon: None, active: false },
-                            Tab { id: "actions".to_string(), label: "Action Items".to_string(), icon: None, active: false },
-                            Tab { id: "reports".to_string(), label: "Audit Reports".to_string(), icon: None, active: false },
+                            Tab { id: "findings".to_string(), label: t("Audit Findings").to_string(), icon: None, active: false },
+                            Tab { id: "actions".to_string(), label: t("Action Items").to_string(), icon: None, active: false },
+                            Tab { id: "reports".to_string(), label: t("Audit Reports").to_string(), icon: None, active: false },
                         ],
                         active_tab.clone(),
                         vec![]
@@ -745,10 +746,10 @@ pub fn agsa_reviews() -> View {
 
                     // Tab content - show findings panel by default
                     {panel(
-                        "AGSA Audit Findings".to_string(),
+                        t("AGSA Audit Findings").to_string(),
                         vec![
-                            view! { <button class="btn btn-sm btn-secondary">"Filter"</button> },
-                            view! { <button class="btn btn-sm btn-secondary">"Export"</button> },
+                            view! { <button class="btn btn-sm btn-secondary">{t("Filter")}</button> },
+                            view! { <button class="btn btn-sm btn-secondary">{t("Export")}</button> },
                         ],
                         vec![data_table(finding_columns.clone(), finding_rows.clone(), Some(handle_finding_click.clone()))]
                     )}
@@ -758,7 +759,7 @@ pub fn agsa_reviews() -> View {
                 <div class="sidebar">
                     // Current compliance status
                     <div class="compliance-status">
-                        <h4>"Current Audit Opinion"</h4>
+                        <h4>{t("Current Audit Opinion")}</h4>
                         <div
                             class="compliance-badge"
                             style={compliance_style}
@@ -769,58 +770,58 @@ pub fn agsa_reviews() -> View {
 
                     // Upcoming deadlines
                     {panel(
-                        "Upcoming Deadlines".to_string(),
+                        t("Upcoming Deadlines").to_string(),
                         vec![],
                         vec![timeline(deadline_timeline.clone(), None)]
                     )}
 
                     // Quick stats
                     <div class="quick-stats">
-                        <h4>"Finding Statistics"</h4>
+                        <h4>{t("Finding Statistics")}</h4>
                         <div class="stat-row">
-                            <span class="stat-label">"Repeat Findings"</span>
+                            <span class="stat-label">{t("Repeat Findings")}</span>
                             <span class="stat-value warning">{repeat_count.to_string()}</span>
                         </div>
                         <div class="stat-row">
-                            <span class="stat-label">"Avg Resolution Days"</span>
+                            <span class="stat-label">{t("Avg Resolution Days")}</span>
                             <span class="stat-value">{avg_resolution_days}</span>
                         </div>
                         <div class="stat-row">
-                            <span class="stat-label">"Actions Completed"</span>
+                            <span class="stat-label">{t("Actions Completed")}</span>
                             <span class="stat-value highlight">{completed_actions.to_string()}</span>
                         </div>
                         <div class="stat-row">
-                            <span class="stat-label">"Actions Overdue"</span>
+                            <span class="stat-label">{t("Actions Overdue")}</span>
                             <span class="stat-value danger">{overdue_actions_count.to_string()}</span>
                         </div>
                     </div>
 
                     // Severity breakdown
                     {panel(
-                        "Findings by Severity".to_string(),
+                        t("Findings by Severity").to_string(),
                         vec![],
                         vec![view! {
                             <div class="severity-breakdown">
                                 <div class="stat-row">
-                                    <span class="stat-label">"Material"</span>
+                                    <span class="stat-label">{t("Material")}</span>
                                     <span class="stat-value danger">
                                         {material_count.to_string()}
                                     </span>
                                 </div>
                                 <div class="stat-row">
-                                    <span class="stat-label">"Significant"</span>
+                                    <span class="stat-label">{t("Significant")}</span>
                                     <span class="stat-value warning">
                                         {significant_count.to_string()}
                                     </span>
                                 </div>
                                 <div class="stat-row">
-                                    <span class="stat-label">"Minor"</span>
+                                    <span class="stat-label">{t("Minor")}</span>
                                     <span class="stat-value">
                                         {minor_count.to_string()}
                                     </span>
                                 </div>
                                 <div class="stat-row">
-                                    <span class="stat-label">"Observation"</span>
+                                    <span class="stat-label">{t("Observation")}</span>
                                     <span class="stat-value">
                                         {observation_count.to_string()}
                                     </span>
@@ -0,0 +1,242 @@
+//! AGSA audit finding detail page
+
+use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table, DataTableColumn, DataTableRow,
+    status_badge, StatusType,
+    tag, TagType,
+    progress_bar, ProgressColor,
+    notice_bar, NoticeType,
+};
+use crate::util::format::{format_currency, format_date};
+use super::types::{AuditFinding, FindingSeverity, FindingStatus};
+use super::store::AgsaStore;
+use super::service;
+use crate::util::i18n::{t, tn};
+
+/// AGSA audit finding detail page
+#[component]
+pub fn finding_detail(finding_id: String) -> View {
+    let store = use_context::<AgsaStore>();
+
+    // Load finding on mount
+    effect({
+        let store = store.clone();
+        let finding_id = finding_id.clone();
+        move || {
+            let store = store.clone();
+            let finding_id = finding_id.clone();
+            spawn(async move {
+                service::load_finding(&store, &finding_id).await;
+            });
+        }
+    });
+
+    let loading = store.loading.get();
+    let error = store.error.get();
+    let finding = store.selected_finding.get().filter(|f| f.id == finding_id);
+
+    view! {
+        style {
+            r#"
+            .finding-detail { display: flex; flex-direction: column; gap: var(--space-6); }
+            .finding-detail-grid {
+                display: grid;
+                grid-template-columns: 2fr 1fr;
+                gap: 24px;
+            }
+            .finding-detail .detail-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 8px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .finding-detail .detail-row:last-child { border-bottom: none; }
+            .finding-detail .detail-row .label { color: var(--text-muted); }
+            .finding-detail .detail-row .value { font-weight: 500; text-align: right; }
+            .finding-detail .finding-section { margin-bottom: 16px; }
+            .finding-detail .finding-section h5 {
+                font-size: 12px;
+                font-weight: 600;
+                color: var(--text-muted);
+                margin-bottom: 6px;
+            }
+            .finding-detail .finding-section p { font-size: 13px; line-height: 1.5; }
+            .finding-detail .agsa-comment {
+                padding: 12px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .finding-detail .agsa-comment:last-child { border-bottom: none; }
+            .finding-detail .agsa-comment .meta { font-size: 11px; color: var(--text-muted); margin-bottom: 4px; }
+            @media (max-width: 1024px) {
+                .finding-detail-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="finding-detail" data-testid="agsa-finding-detail">
+            if loading {
+                <div class="loading-state">{t("Loading finding...")}</div>
+            } else if let Some(finding) = finding {
+                {render_finding(finding)}
+            } else {
+                {entity_not_found(t("Finding"), &finding_id, error, Route::AgsaReviews, t("Back to AGSA Reviews"))}
+            }
+        </div>
+    }
+}
+
+fn render_finding(finding: AuditFinding) -> View {
+    let status = match finding.status {
+        FindingStatus::Open => status_badge(StatusType::Pending),
+        FindingStatus::InProgress => status_badge(StatusType::InProgress),
+        FindingStatus::Resolved => status_badge(StatusType::Complete),
+        FindingStatus::Closed => status_badge(StatusType::Approved),
+        FindingStatus::Recurring => status_badge(StatusType::OnHold),
+        FindingStatus::Overdue => status_badge(StatusType::Rejected),
+    };
+
+    let severity = match finding.severity {
+        FindingSeverity::Material => tag(t("Material").to_string(), TagType::Danger),
+        FindingSeverity::Significant => tag(t("Significant").to_string(), TagType::Warning),
+        FindingSeverity::Minor => tag(t("Minor").to_string(), TagType::Info),
+        FindingSeverity::Observation => tag(t("Observation").to_string(), TagType::Default),
+    };
+
+    let columns = vec![
+        DataTableColumn { key: "reference".to_string(), label: t("Reference").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "description".to_string(), label: t("Action").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "assigned".to_string(), label: t("Assigned To").to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "due".to_string(), label: t("Due").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "progress".to_string(), label: t("Progress").to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+    ];
+
+    let rows: Vec<DataTableRow> = finding.action_items.iter().map(|action| {
+        DataTableRow {
+            id: action.id.clone(),
+            cells: vec![
+                view! { <span class="id-cell">{action.reference_number.clone()}</span> },
+                view! { <span>{action.description.clone()}</span> },
+                view! { <span>{action.assigned_to.clone()}</span> },
+                view! { <span>{format_date(&action.due_date)}</span> },
+                progress_bar(action.progress_percent as f64, ProgressColor::Blue, true, None),
+                view! { <span style={format!("color: {}", action.status.color())}>{action.status.label()}</span> },
+            ],
+        }
+    }).collect();
+
+    view! {
+        {page_header(
+            finding.title.clone(),
+            Some(format!("{} - {}", finding.reference_number, finding.financial_year)),
+            vec![
+                view! { <a href="/agsa" class="btn btn-secondary">{t("Back to AGSA Reviews")}</a> },
+            ]
+        )}
+
+        if finding.is_repeat_finding {
+            {notice_bar(
+                tn("Repeat finding, outstanding for {n} year", "Repeat finding, outstanding for {n} years", finding.years_outstanding as i64),
+                NoticeType::Warning,
+                None
+            )}
+        }
+
+        <div class="finding-detail-grid">
+            <div>
+                {panel(
+                    t("Finding").to_string(),
+                    vec![],
+                    vec![
+                        view! {
+                            <div>
+                                <div class="finding-section">
+                                    <h5>{t("Description")}</h5>
+                                    <p>{finding.description.clone()}</p>
+                                </div>
+                                <div class="finding-section">
+                                    <h5>{t("Root Cause")}</h5>
+                                    <p>{finding.root_cause.clone()}</p>
+                                </div>
+                                <div class="finding-section">
+                                    <h5>{t("Risk Implication")}</h5>
+                                    <p>{finding.risk_implication.clone()}</p>
+                                </div>
+                                <div class="finding-section">
+                                    <h5>{t("Management Response")}</h5>
+                                    <p>{finding.management_response.clone()}</p>
+                                </div>
+                            </div>
+                        }
+                    ]
+                )}
+
+                {panel(
+                    t("Action Plan").to_string(),
+                    vec![],
+                    vec![data_table(columns, rows, None)]
+                )}
+
+                if !finding.agsa_comments.is_empty() {
+                    {panel(
+                        t("AGSA Comments").to_string(),
+                        vec![],
+                        vec![
+                            view! {
+                                <div>
+                                    for comment in finding.agsa_comments.iter() {
+                                        <div class="agsa-comment">
+                                            <div class="meta">{format!("{} - {}", comment.author, format_date(&comment.created_at))}</div>
+                                            <div>{comment.content.clone()}</div>
+                                        </div>
+                                    }
+                                </div>
+                            }
+                        ]
+                    )}
+                }
+            </div>
+
+            {panel(
+                t("Details").to_string(),
+                vec![],
+                vec![
+                    view! {
+                        <div>
+                            <div class="detail-row">
+                                <span class="label">{t("Status")}</span>
+                                <span class="value">{status}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Severity")}</span>
+                                <span class="value">{severity}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Category")}</span>
+                                <span class="value">{finding.category.label()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Responsible")}</span>
+                                <span class="value">{format!("{} ({})", finding.responsible_person, finding.responsible_department)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Target Date")}</span>
+                                <span class="value">{format_date(&finding.target_date)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Financial Impact")}</span>
+                                <span class="value">{finding.financial_impact.map(format_currency).unwrap_or_else(|| "-".to_string())}</span>
+                            </div>
+                        </div>
+                    }
+                ]
+            )}
+        </div>
+    }
+}
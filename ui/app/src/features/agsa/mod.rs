@@ -4,3 +4,4 @@ pub mod types;
 pub mod store;
 pub mod service;
 pub mod agsa_reviews;
+pub mod finding_detail;
@@ -1,5 +1,8 @@
 //! AGSA service - API calls
 
+use crate::util::api::ApiError;
+use crate::util::backend;
+use crate::util::money::Money;
 use super::store::{AgsaStore, load_mock_data};
 use super::types::{
     AuditFinding, AuditReport, ActionItem, FindingStatus, ActionStatus,
@@ -11,9 +14,7 @@ pub async fn load_agsa_data(store: &AgsaStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -25,7 +26,7 @@ pub async fn load_findings(store: &AgsaStore) {
 
     // Ensure data is loaded
     if store.findings.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     store.loading.set(false);
@@ -38,7 +39,7 @@ pub async fn load_finding(store: &AgsaStore, finding_id: &str) {
 
     // Ensure findings are loaded
     if store.findings.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Select the finding
@@ -54,7 +55,7 @@ pub async fn load_audit_reports(store: &AgsaStore) {
 
     // Ensure data is loaded
     if store.audit_reports.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     store.loading.set(false);
@@ -67,7 +68,7 @@ pub async fn load_audit_report(store: &AgsaStore, report_id: &str) {
 
     // Ensure reports are loaded
     if store.audit_reports.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Select the report
@@ -310,7 +311,7 @@ pub fn get_finding_stats(store: &AgsaStore) -> FindingStats {
     let material = findings.iter().filter(|f| matches!(f.severity, super::types::FindingSeverity::Material)).count();
     let significant = findings.iter().filter(|f| matches!(f.severity, super::types::FindingSeverity::Significant)).count();
 
-    let financial_impact: f64 = findings.iter()
+    let financial_impact: Money = findings.iter()
         .filter_map(|f| f.financial_impact)
         .sum();
 
@@ -372,7 +373,7 @@ pub struct FindingStats {
     pub recurring: usize,
     pub material: usize,
     pub significant: usize,
-    pub financial_impact: f64,
+    pub financial_impact: Money,
 }
 
 /// Action item statistics
@@ -387,3 +388,24 @@ pub struct ActionStats {
     pub critical_priority: usize,
     pub high_priority: usize,
 }
+
+/// Refresh AGSA findings, reports and action items from the configured backend
+async fn load_from_backend(store: &AgsaStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/agsa/findings", &store.findings).await?;
+        backend::fetch_into("/agsa/reports", &store.audit_reports).await?;
+        backend::fetch_into("/agsa/action-items", &store.action_items).await?;
+        backend::fetch_into("/agsa/kpis", &store.kpis).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    }
+}
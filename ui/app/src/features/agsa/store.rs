@@ -1,6 +1,7 @@
 //! AGSA store
 
 use components::prelude::*;
+use crate::util::money::Money;
 use super::types::{
     AuditFinding, AuditReport, ActionItem, ComplianceStatus, FindingStatus,
     FindingCategory, FindingSeverity, ActionPriority, ActionStatus, AuditType,
@@ -247,7 +248,7 @@ pub fn load_mock_data(store: &AgsaStore) {
             is_repeat_finding: true,
             previous_finding_ref: Some("AGSA/FND/2023-24/003".to_string()),
             years_outstanding: 2,
-            financial_impact: Some(4_200_000.0),
+            financial_impact: Some(Money::rands(4_200_000)),
             action_items: Vec::new(),
             evidence_documents: vec!["/documents/agsa/fnd_001/evidence_1.pdf".to_string()],
             agsa_comments: vec![
@@ -282,7 +283,7 @@ pub fn load_mock_data(store: &AgsaStore) {
             is_repeat_finding: true,
             previous_finding_ref: Some("AGSA/FND/2023-24/001".to_string()),
             years_outstanding: 3,
-            financial_impact: Some(20_800_000.0),
+            financial_impact: Some(Money::rands(20_800_000)),
             action_items: Vec::new(),
             evidence_documents: Vec::new(),
             agsa_comments: Vec::new(),
@@ -336,7 +337,7 @@ pub fn load_mock_data(store: &AgsaStore) {
             is_repeat_finding: true,
             previous_finding_ref: Some("AGSA/FND/2023-24/005".to_string()),
             years_outstanding: 2,
-            financial_impact: Some(45_000_000.0),
+            financial_impact: Some(Money::rands(45_000_000)),
             action_items: Vec::new(),
             evidence_documents: Vec::new(),
             agsa_comments: Vec::new(),
@@ -561,7 +562,7 @@ pub fn load_mock_data(store: &AgsaStore) {
         resolution_rate: 8.3,
         average_resolution_days: 45.0,
         current_compliance_status: ComplianceStatus::UnqualifiedWithFindings,
-        financial_impact_total: 70_000_000.0,
+        financial_impact_total: Money::rands(70_000_000),
     };
 
     store.audit_reports.set(mock_reports);
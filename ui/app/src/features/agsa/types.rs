@@ -1,6 +1,8 @@
 //! AGSA domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::Money;
 
 /// Compliance status for AGSA audits
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -26,14 +28,14 @@ impl ComplianceStatus {
     }
 
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ComplianceStatus::Clean => "Clean Audit",
             ComplianceStatus::UnqualifiedWithFindings => "Unqualified with Findings",
             ComplianceStatus::Qualified => "Qualified Opinion",
             ComplianceStatus::Adverse => "Adverse Opinion",
             ComplianceStatus::Disclaimer => "Disclaimer of Opinion",
             ComplianceStatus::PendingAudit => "Pending Audit",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -65,12 +67,12 @@ pub enum FindingSeverity {
 
 impl FindingSeverity {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             FindingSeverity::Material => "Material",
             FindingSeverity::Significant => "Significant",
             FindingSeverity::Minor => "Minor",
             FindingSeverity::Observation => "Observation",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -102,14 +104,14 @@ pub enum FindingStatus {
 
 impl FindingStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             FindingStatus::Open => "Open",
             FindingStatus::InProgress => "In Progress",
             FindingStatus::Resolved => "Resolved",
             FindingStatus::Closed => "Closed",
             FindingStatus::Recurring => "Recurring",
             FindingStatus::Overdue => "Overdue",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -147,7 +149,7 @@ pub enum FindingCategory {
 
 impl FindingCategory {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             FindingCategory::FinancialStatements => "Financial Statements",
             FindingCategory::Compliance => "Compliance",
             FindingCategory::PerformanceInformation => "Performance Information",
@@ -158,7 +160,7 @@ impl FindingCategory {
             FindingCategory::InformationTechnology => "Information Technology",
             FindingCategory::Governance => "Governance",
             FindingCategory::FraudAndIrregularities => "Fraud & Irregularities",
-        }
+        })
     }
 }
 
@@ -179,12 +181,12 @@ pub enum ActionPriority {
 
 impl ActionPriority {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ActionPriority::Critical => "Critical",
             ActionPriority::High => "High",
             ActionPriority::Medium => "Medium",
             ActionPriority::Low => "Low",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -216,14 +218,14 @@ pub enum ActionStatus {
 
 impl ActionStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ActionStatus::NotStarted => "Not Started",
             ActionStatus::InProgress => "In Progress",
             ActionStatus::Completed => "Completed",
             ActionStatus::Verified => "Verified",
             ActionStatus::Overdue => "Overdue",
             ActionStatus::Cancelled => "Cancelled",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -266,7 +268,7 @@ pub struct AuditFinding {
     pub is_repeat_finding: bool,
     pub previous_finding_ref: Option<String>,
     pub years_outstanding: u32,
-    pub financial_impact: Option<f64>,
+    pub financial_impact: Option<Money>,
     pub action_items: Vec<ActionItem>,
     pub evidence_documents: Vec<String>,
     pub agsa_comments: Vec<AgsaComment>,
@@ -386,13 +388,13 @@ pub enum CommentType {
 
 impl CommentType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             CommentType::InitialFinding => "Initial Finding",
             CommentType::FollowUp => "Follow-up",
             CommentType::Verification => "Verification",
             CommentType::Closure => "Closure",
             CommentType::Concern => "Concern",
-        }
+        })
     }
 }
 
@@ -408,13 +410,13 @@ pub enum AuditType {
 
 impl AuditType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             AuditType::Regularity => "Regularity Audit",
             AuditType::Performance => "Performance Audit",
             AuditType::Special => "Special Audit",
             AuditType::FollowUp => "Follow-up Audit",
             AuditType::Interim => "Interim Audit",
-        }
+        })
     }
 }
 
@@ -491,7 +493,7 @@ impl Default for AuditReport {
 }
 
 /// AGSA KPI summary
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct AgsaKpis {
     pub total_findings: u32,
     pub open_findings: u32,
@@ -505,7 +507,7 @@ pub struct AgsaKpis {
     pub resolution_rate: f64,
     pub average_resolution_days: f64,
     pub current_compliance_status: ComplianceStatus,
-    pub financial_impact_total: f64,
+    pub financial_impact_total: Money,
 }
 
 /// Filter criteria for AGSA findings
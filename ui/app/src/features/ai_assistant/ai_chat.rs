@@ -16,6 +16,7 @@ use super::types::{
     MessageRole, Suggestion, SuggestionCategory, ConversationContext,
     ResponseType,
 };
+use crate::util::i18n::{t, tf};
 
 /// AI Chat panel component - floating chat interface
 #[component]
@@ -452,7 +453,7 @@ pub fn ai_chat_panel() -> View {
                 <button
                     class="ai-chat-fab"
                     on:click={move || toggle_chat(&store)}
-                    title="AI Assistant"
+                    title={t("AI Assistant")}
                     data-testid="ai-chat-fab"
                 >
                     <span inner_html={icon_ai}></span>
@@ -474,7 +475,7 @@ pub fn ai_chat_panel() -> View {
                     <div class="ai-chat-header">
                         <div class="ai-chat-header-title">
                             <span inner_html={icon_ai}></span>
-                            <span>"AI Procurement Assistant"</span>
+                            <span>{t("AI Procurement Assistant")}</span>
                         </div>
                         <div class="ai-chat-header-actions">
                             <button
@@ -483,7 +484,7 @@ pub fn ai_chat_panel() -> View {
                                     let handle = handle_new_conversation.clone();
                                     move || handle()
                                 }
-                                title="New Conversation"
+                                title={t("New Conversation")}
                             >
                                 <span inner_html={icon_new}></span>
                             </button>
@@ -493,7 +494,7 @@ pub fn ai_chat_panel() -> View {
                                     let handle = handle_close.clone();
                                     move || handle()
                                 }
-                                title="Close"
+                                title={t("Close")}
                             >
                                 <span inner_html={icon_close}></span>
                             </button>
@@ -507,13 +508,13 @@ pub fn ai_chat_panel() -> View {
                             view! {
                                 <div class="ai-chat-welcome">
                                     <div class="ai-chat-welcome-icon" inner_html={icon_ai}></div>
-                                    <h3>"How can I help you today?"</h3>
-                                    <p>"I can assist with tender analysis, compliance checks, bid evaluation, and procurement guidance."</p>
+                                    <h3>{t("How can I help you today?")}</h3>
+                                    <p>{t("I can assist with tender analysis, compliance checks, bid evaluation, and procurement guidance.")}</p>
                                 </div>
 
                                 // Quick action suggestions
                                 <div class="ai-suggestions">
-                                    <div class="ai-suggestions-title">"Quick Actions"</div>
+                                    <div class="ai-suggestions-title">{t("Quick Actions")}</div>
                                     <div class="ai-suggestions-grid">
                                         {suggestions.iter().take(6).map(|suggestion| {
                                             let prompt = suggestion.prompt.clone();
@@ -550,7 +551,7 @@ pub fn ai_chat_panel() -> View {
                                                     {if !sources.is_empty() {
                                                         view! {
                                                             <div class="ai-message-sources">
-                                                                <div class="ai-message-sources-title">"Sources:"</div>
+                                                                <div class="ai-message-sources-title">{t("Sources:")}</div>
                                                                 <div class="ai-message-sources-list">
                                                                     {sources.iter().map(|s| {
                                                                         view! {
@@ -578,7 +579,7 @@ pub fn ai_chat_panel() -> View {
                                                     <div class="ai-chat-loading-dot"></div>
                                                     <div class="ai-chat-loading-dot"></div>
                                                 </div>
-                                                <span>"Thinking..."</span>
+                                                <span>{t("Thinking...")}</span>
                                             </div>
                                         }
                                     } else {
@@ -603,7 +604,7 @@ pub fn ai_chat_panel() -> View {
                         <div class="ai-chat-input-container">
                             <textarea
                                 class="ai-chat-input"
-                                placeholder="Ask me anything about procurement..."
+                                placeholder={t("Ask me anything about procurement...")}
                                 prop:value={input_message.get()}
                                 on:input={handle_text_input.clone()}
                                 data-testid="ai-chat-input"
@@ -721,11 +722,14 @@ pub fn ai_quick_analysis(
         _ => ConversationContext::General,
     };
 
-    let prompt = format!("Analyze {} {} for completeness and compliance.", entity_type, entity_id);
+    let prompt = tf(
+        "Analyze {entity} {id} for completeness and compliance.",
+        &[("entity", entity_type.clone()), ("id", entity_id.clone())],
+    );
 
     view! {
         {ai_chat_trigger(
-            format!("AI Analysis"),
+            t("AI Analysis").to_string(),
             context,
             Some(prompt),
         )}
@@ -810,7 +814,7 @@ pub fn ai_suggestions_panel(category: SuggestionCategory) -> View {
                     <circle cx="12" cy="12" r="3"/>
                     <path d="M12 2v4m0 12v4M2 12h4m12 0h4"/>
                 </svg>
-                <span class="ai-suggestions-panel-title">{format!("AI {} Suggestions", category.label())}</span>
+                <span class="ai-suggestions-panel-title">{tf("AI {category} Suggestions", &[("category", category.label().to_string())])}</span>
             </div>
             <div class="ai-suggestions-panel-list">
                 {suggestions.iter().map(|s| {
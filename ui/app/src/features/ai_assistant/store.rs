@@ -1,6 +1,8 @@
 //! AI Assistant store
 
 use components::prelude::*;
+use crate::util::datetime::{now, now_iso};
+use crate::util::i18n::t;
 use super::types::{
     Conversation, Message, MessageRole, MessageMetadata, ResponseType,
     Suggestion, SuggestionCategory, AnalysisResult, ConversationContext,
@@ -249,7 +251,7 @@ pub fn load_conversation_history(store: &AiAssistantStore) {
 
 /// Start a new conversation
 pub fn start_new_conversation(store: &AiAssistantStore, context: ConversationContext) {
-    let conv_id = format!("conv-{}", chrono_now());
+    let conv_id = format!("conv-{}", now().millis());
     let title = match &context {
         ConversationContext::General => "New Conversation".to_string(),
         ConversationContext::Tender { tender_title, .. } => format!("Tender: {}", tender_title),
@@ -262,8 +264,8 @@ pub fn start_new_conversation(store: &AiAssistantStore, context: ConversationCon
         id: conv_id,
         title,
         messages: Vec::new(),
-        created_at: chrono_now(),
-        updated_at: chrono_now(),
+        created_at: now_iso(),
+        updated_at: now_iso(),
         context: context.clone(),
     };
 
@@ -276,14 +278,14 @@ pub fn start_new_conversation(store: &AiAssistantStore, context: ConversationCon
 pub fn add_message(store: &AiAssistantStore, message: Message) {
     let mut conversation = store.current_conversation.get();
     conversation.messages.push(message);
-    conversation.updated_at = chrono_now();
+    conversation.updated_at = now_iso();
     store.current_conversation.set(conversation);
 }
 
 /// Add user message and prepare for response
 pub fn send_user_message(store: &AiAssistantStore, content: String) {
     let message = Message {
-        id: format!("msg-{}", chrono_now()),
+        id: format!("msg-{}", now().millis()),
         role: MessageRole::User,
         content,
         timestamp: format_timestamp(),
@@ -296,7 +298,7 @@ pub fn send_user_message(store: &AiAssistantStore, content: String) {
 /// Add assistant response
 pub fn add_assistant_response(store: &AiAssistantStore, content: String, metadata: Option<MessageMetadata>) {
     let message = Message {
-        id: format!("msg-{}", chrono_now()),
+        id: format!("msg-{}", now().millis()),
         role: MessageRole::Assistant,
         content,
         timestamp: format_timestamp(),
@@ -380,13 +382,8 @@ pub fn get_suggestions_by_category(store: &AiAssistantStore, category: Suggestio
         .collect()
 }
 
-/// Helper: Generate timestamp string
-fn chrono_now() -> String {
-    // In production, use proper timestamp
-    "2025-02-15T10:30:00Z".to_string()
-}
-
 /// Helper: Format timestamp for display
 fn format_timestamp() -> String {
-    "Today, 10:30".to_string()
+    let (hour, minute, _) = now().sast_time();
+    format!("{}, {:02}:{:02}", t("Today"), hour, minute)
 }
@@ -1,6 +1,7 @@
 //! AI Assistant domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
 
 /// Message role in conversation
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -15,11 +16,11 @@ pub enum MessageRole {
 
 impl MessageRole {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             MessageRole::User => "You",
             MessageRole::Assistant => "AI Assistant",
             MessageRole::System => "System",
-        }
+        })
     }
 }
 
@@ -68,7 +69,7 @@ pub enum ResponseType {
 
 impl ResponseType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ResponseType::General => "General",
             ResponseType::TenderAnalysis => "Tender Analysis",
             ResponseType::ComplianceCheck => "Compliance Check",
@@ -76,7 +77,7 @@ impl ResponseType {
             ResponseType::RegulatoryGuidance => "Regulatory Guidance",
             ResponseType::RiskAssessment => "Risk Assessment",
             ResponseType::ProcessRecommendation => "Process Recommendation",
-        }
+        })
     }
 }
 
@@ -157,14 +158,14 @@ pub enum SuggestionCategory {
 
 impl SuggestionCategory {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             SuggestionCategory::TenderAnalysis => "Tender Analysis",
             SuggestionCategory::Compliance => "Compliance",
             SuggestionCategory::BidEvaluation => "Bid Evaluation",
             SuggestionCategory::RiskAssessment => "Risk Assessment",
             SuggestionCategory::Regulatory => "Regulatory",
             SuggestionCategory::Process => "Process",
-        }
+        })
     }
 }
 
@@ -202,14 +203,14 @@ pub enum AnalysisType {
 
 impl AnalysisType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             AnalysisType::TenderDocument => "Tender Document Analysis",
             AnalysisType::Compliance => "Compliance Check",
             AnalysisType::BidComparison => "Bid Comparison",
             AnalysisType::SupplierRisk => "Supplier Risk Assessment",
             AnalysisType::ContractReview => "Contract Review",
             AnalysisType::SpendAnalysis => "Spend Analysis",
-        }
+        })
     }
 }
 
@@ -236,13 +237,13 @@ pub enum Severity {
 
 impl Severity {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             Severity::Info => "Info",
             Severity::Low => "Low",
             Severity::Medium => "Medium",
             Severity::High => "High",
             Severity::Critical => "Critical",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -278,12 +279,12 @@ pub enum Priority {
 
 impl Priority {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             Priority::Low => "Low",
             Priority::Medium => "Medium",
             Priority::High => "High",
             Priority::Urgent => "Urgent",
-        }
+        })
     }
 }
 
@@ -301,7 +302,7 @@ pub enum ActionType {
 
 impl ActionType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ActionType::Review => "Review Required",
             ActionType::Update => "Update Needed",
             ActionType::Escalate => "Escalate",
@@ -309,7 +310,7 @@ impl ActionType {
             ActionType::Reject => "Reject",
             ActionType::Investigate => "Investigate",
             ActionType::Document => "Document",
-        }
+        })
     }
 }
 
@@ -324,12 +325,12 @@ pub enum RiskLevel {
 
 impl RiskLevel {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             RiskLevel::Low => "Low Risk",
             RiskLevel::Medium => "Medium Risk",
             RiskLevel::High => "High Risk",
             RiskLevel::Critical => "Critical Risk",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -407,11 +408,11 @@ pub enum DetailLevel {
 
 impl DetailLevel {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             DetailLevel::Summary => "Summary",
             DetailLevel::Standard => "Standard",
             DetailLevel::Detailed => "Detailed",
             DetailLevel::Comprehensive => "Comprehensive",
-        }
+        })
     }
 }
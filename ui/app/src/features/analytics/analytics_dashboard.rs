@@ -1,6 +1,7 @@
 //! Analytics Dashboard page
 
 use components::prelude::*;
+use crate::Route;
 use crate::shared::layout::page_header;
 use crate::shared::components::{
     kpi_card, KpiColor, KpiDelta,
@@ -16,6 +17,7 @@ use crate::shared::charts::{
 use crate::util::format::{format_currency, format_number, format_percentage};
 use super::store::{AnalyticsStore, load_mock_data};
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Analytics Dashboard page
 #[component]
@@ -53,7 +55,7 @@ pub fn analytics_dashboard() -> View {
     let spend_trend: Vec<TrendChartData> = data.trends.monthly_spend.iter().map(|p| {
         TrendChartData {
             label: p.period.clone(),
-            value: p.value,
+            value: p.value.to_rands(),
         }
     }).collect();
 
@@ -61,7 +63,7 @@ pub fn analytics_dashboard() -> View {
     let category_bars: Vec<BarChartData> = data.category_breakdown.iter().map(|c| {
         BarChartData {
             label: c.code.clone(),
-            value: c.spend,
+            value: c.spend.to_rands(),
             color: Some(c.color.clone()),
         }
     }).collect();
@@ -70,7 +72,7 @@ pub fn analytics_dashboard() -> View {
     let province_bars: Vec<BarChartData> = data.province_distribution.iter().take(5).map(|p| {
         BarChartData {
             label: p.province.chars().take(3).collect::<String>().to_uppercase(),
-            value: p.spend,
+            value: p.spend.to_rands(),
             color: Some("var(--blue)".to_string()),
         }
     }).collect();
@@ -78,30 +80,30 @@ pub fn analytics_dashboard() -> View {
     // B-BBEE pie chart data
     let bbbee = &data.bbbee_metrics;
     let bbbee_pie: Vec<PieChartData> = vec![
-        PieChartData { label: "Level 1".to_string(), value: bbbee.level_1_percent, color: "var(--green)".to_string() },
-        PieChartData { label: "Level 2".to_string(), value: bbbee.level_2_percent, color: "var(--blue)".to_string() },
-        PieChartData { label: "Level 3-4".to_string(), value: bbbee.level_3_4_percent, color: "var(--orange)".to_string() },
-        PieChartData { label: "Level 5-8".to_string(), value: bbbee.level_5_8_percent, color: "var(--purple)".to_string() },
-        PieChartData { label: "Non-Compliant".to_string(), value: bbbee.non_compliant_percent, color: "var(--text-muted)".to_string() },
+        PieChartData { label: t("Level 1").to_string(), value: bbbee.level_1_percent, color: "var(--green)".to_string() },
+        PieChartData { label: t("Level 2").to_string(), value: bbbee.level_2_percent, color: "var(--blue)".to_string() },
+        PieChartData { label: t("Level 3-4").to_string(), value: bbbee.level_3_4_percent, color: "var(--orange)".to_string() },
+        PieChartData { label: t("Level 5-8").to_string(), value: bbbee.level_5_8_percent, color: "var(--purple)".to_string() },
+        PieChartData { label: t("Non-Compliant").to_string(), value: bbbee.non_compliant_percent, color: "var(--text-muted)".to_string() },
     ];
 
     // Supplier table columns
     let supplier_columns = vec![
         DataTableColumn { key: "rank".to_string(), label: "#".to_string(), width: Some("40px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "supplier".to_string(), label: "Supplier".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "spend".to_string(), label: "Total Spend".to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "bbbee".to_string(), label: "B-BBEE".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "rating".to_string(), label: "Rating".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "spend".to_string(), label: t("Total Spend").to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "bbbee".to_string(), label: t("B-BBEE").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "rating".to_string(), label: t("Rating").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
     ];
 
     // Transform suppliers to table rows
     let supplier_rows: Vec<DataTableRow> = data.top_suppliers.iter().enumerate().map(|(idx, sup)| {
         let bbbee_tag = if sup.bbbee_level <= 2 {
-            tag(format!("Level {}", sup.bbbee_level), TagType::Success)
+            tag(tf("Level {level}", &[("level", sup.bbbee_level.to_string())]), TagType::Success)
         } else if sup.bbbee_level <= 4 {
-            tag(format!("Level {}", sup.bbbee_level), TagType::Warning)
+            tag(tf("Level {level}", &[("level", sup.bbbee_level.to_string())]), TagType::Warning)
         } else {
-            tag(format!("Level {}", sup.bbbee_level), TagType::Default)
+            tag(tf("Level {level}", &[("level", sup.bbbee_level.to_string())]), TagType::Default)
         };
 
         let rating_color = if sup.overall_rating >= 90.0 {
@@ -128,17 +130,43 @@ pub fn analytics_dashboard() -> View {
 
     // Category table columns
     let category_columns = vec![
-        DataTableColumn { key: "category".to_string(), label: "Category".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "spend".to_string(), label: "Spend".to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "budget".to_string(), label: "Budget".to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "variance".to_string(), label: "Variance".to_string(), width: None, align: Some("right".to_string()), cell_class: None },
-        DataTableColumn { key: "percentage".to_string(), label: "% of Total".to_string(), width: Some("100px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "category".to_string(), label: t("Category").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "spend".to_string(), label: t("Spend").to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "budget".to_string(), label: t("Budget").to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "variance".to_string(), label: t("Variance").to_string(), width: None, align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "percentage".to_string(), label: t("% of Total").to_string(), width: Some("100px".to_string()), align: Some("center".to_string()), cell_class: None },
     ];
 
     // Category table rows
+    // Approval turnaround by approver role
+    let turnaround_columns = vec![
+        DataTableColumn { key: "role".to_string(), label: t("Approver").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "completed".to_string(), label: t("Decisions").to_string(), width: Some("100px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "average".to_string(), label: t("Avg. Business Days").to_string(), width: Some("150px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "within_sla".to_string(), label: t("Within SLA").to_string(), width: Some("110px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "overdue".to_string(), label: t("Overdue").to_string(), width: Some("90px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "escalated".to_string(), label: t("Escalated").to_string(), width: Some("90px".to_string()), align: Some("right".to_string()), cell_class: None },
+    ];
+    let turnaround_rows: Vec<DataTableRow> = store.approval_turnaround.get().iter()
+        .filter(|row| row.completed > 0 || row.overdue > 0 || row.escalated > 0)
+        .map(|row| {
+            DataTableRow {
+                id: row.role.clone(),
+                cells: vec![
+                    view! { <span>{row.role.clone()}</span> },
+                    view! { <span>{format_number(row.completed)}</span> },
+                    view! { <span>{format!("{:.1}", row.average_days)}</span> },
+                    view! { <span>{format_percentage(row.within_sla_percent(), 0)}</span> },
+                    view! { <span class={if row.overdue > 0 { "variance negative" } else { "" }}>{format_number(row.overdue)}</span> },
+                    view! { <span>{format_number(row.escalated)}</span> },
+                ],
+            }
+        })
+        .collect();
+
     let category_rows: Vec<DataTableRow> = data.category_breakdown.iter().map(|cat| {
-        let variance_class = if cat.variance >= 0.0 { "positive" } else { "negative" };
-        let variance_display = if cat.variance >= 0.0 {
+        let variance_class = if !cat.variance.is_negative() { "positive" } else { "negative" };
+        let variance_display = if !cat.variance.is_negative() {
             format!("+{}", format_currency(cat.variance))
         } else {
             format_currency(cat.variance)
@@ -244,72 +272,72 @@ pub fn analytics_dashboard() -> View {
 
         <div class="analytics-dashboard" data-testid="analytics-dashboard">
             {page_header(
-                "Spend Analytics".to_string(),
-                Some("Comprehensive procurement spend analysis for FY 2025/26".to_string()),
+                t("Spend Analytics").to_string(),
+                Some(t("Comprehensive procurement spend analysis for FY 2025/26").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export PDF"</button> },
-                    view! { <button class="btn btn-secondary">"Export Excel"</button> },
+                    view! { <button class="btn btn-secondary">{t("Export PDF")}</button> },
+                    view! { <button class="btn btn-secondary">{t("Export Excel")}</button> },
                 ]
             )}
 
             // Filter bar
             <div class="filter-bar">
                 <select data-testid="period-filter">
-                    <option value="year" selected>"FY 2025/26"</option>
-                    <option value="quarter">"This Quarter"</option>
-                    <option value="month">"This Month"</option>
-                    <option value="custom">"Custom Range"</option>
+                    <option value="year" selected>{t("FY 2025/26")}</option>
+                    <option value="quarter">{t("This Quarter")}</option>
+                    <option value="month">{t("This Month")}</option>
+                    <option value="custom">{t("Custom Range")}</option>
                 </select>
                 <select data-testid="category-filter">
-                    <option value="" selected>"All Categories"</option>
-                    <option value="IT">"Information Technology"</option>
-                    <option value="PRO">"Professional Services"</option>
-                    <option value="FAC">"Facilities Management"</option>
-                    <option value="SEC">"Security Services"</option>
-                    <option value="FLT">"Fleet Management"</option>
+                    <option value="" selected>{t("All Categories")}</option>
+                    <option value="IT">{t("Information Technology")}</option>
+                    <option value="PRO">{t("Professional Services")}</option>
+                    <option value="FAC">{t("Facilities Management")}</option>
+                    <option value="SEC">{t("Security Services")}</option>
+                    <option value="FLT">{t("Fleet Management")}</option>
                 </select>
                 <select data-testid="province-filter">
-                    <option value="" selected>"All Provinces"</option>
-                    <option value="GP">"Gauteng"</option>
-                    <option value="WC">"Western Cape"</option>
-                    <option value="KZN">"KwaZulu-Natal"</option>
-                    <option value="EC">"Eastern Cape"</option>
-                    <option value="MP">"Mpumalanga"</option>
+                    <option value="" selected>{t("All Provinces")}</option>
+                    <option value="GP">{t("Gauteng")}</option>
+                    <option value="WC">{t("Western Cape")}</option>
+                    <option value="KZN">{t("KwaZulu-Natal")}</option>
+                    <option value="EC">{t("Eastern Cape")}</option>
+                    <option value="MP">{t("Mpumalanga")}</option>
                 </select>
             </div>
 
             // KPI Row 1 - Financial Overview
             <div class="kpi-grid">
                 {kpi_card(
-                    "YTD Spend".to_string(),
+                    t("YTD Spend").to_string(),
                     format_currency(kpis.get().ytd_spend),
                     KpiColor::Blue,
                     icon_dollar.to_string(),
-                    Some(KpiDelta { value: "3.2%".to_string(), is_positive: Some(false), suffix: "under budget".to_string() }),
+                    Some(KpiDelta { value: "3.2%".to_string(), is_positive: Some(false), suffix: t("under budget").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Budget Utilization".to_string(),
+                    t("Budget Utilization").to_string(),
                     format_percentage(kpis.get().budget_utilization, 1),
                     KpiColor::Green,
                     icon_chart.to_string(),
-                    Some(KpiDelta { value: "R 153M".to_string(), is_positive: None, suffix: "remaining".to_string() }),
+                    Some(KpiDelta { value: "R 153M".to_string(), is_positive: None, suffix: t("remaining").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Cost Savings".to_string(),
+                    t("Cost Savings").to_string(),
                     format_currency(kpis.get().cost_savings),
                     KpiColor::Accent,
                     icon_save.to_string(),
-                    Some(KpiDelta { value: "3.8%".to_string(), is_positive: Some(true), suffix: "of total spend".to_string() }),
+                    Some(KpiDelta { value: "3.8%".to_string(), is_positive: Some(true), suffix: t("of total spend").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Active Suppliers".to_string(),
+                    t("Active Suppliers").to_string(),
                     format_number(data.active_suppliers),
                     KpiColor::Purple,
                     icon_users.to_string(),
-                    Some(KpiDelta { value: "45".to_string(), is_positive: Some(true), suffix: "new this month".to_string() }),
+                    Some(KpiDelta { value: "45".to_string(), is_positive: Some(true), suffix: t("new this month").to_string() }),
                     None
                 )}
             </div>
@@ -317,35 +345,35 @@ pub fn analytics_dashboard() -> View {
             // KPI Row 2 - Performance Metrics
             <div class="kpi-grid">
                 {kpi_card(
-                    "Active Contracts".to_string(),
+                    t("Active Contracts").to_string(),
                     data.active_contracts.to_string(),
                     KpiColor::Cyan,
                     icon_file.to_string(),
-                    Some(KpiDelta { value: "12 expiring in 30 days".to_string(), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: tn("{n} expiring in 30 days", "{n} expiring in 30 days", 12), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Avg. Procurement Cycle".to_string(),
-                    format!("{} days", kpis.get().avg_procurement_cycle),
+                    t("Avg. Procurement Cycle").to_string(),
+                    tn("{n} day", "{n} days", kpis.get().avg_procurement_cycle as i64),
                     KpiColor::Orange,
                     icon_clock.to_string(),
-                    Some(KpiDelta { value: "5 days".to_string(), is_positive: Some(true), suffix: "faster than target".to_string() }),
+                    Some(KpiDelta { value: tn("{n} day", "{n} days", 5), is_positive: Some(true), suffix: t("faster than target").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Supplier Performance".to_string(),
+                    t("Supplier Performance").to_string(),
                     format_percentage(kpis.get().supplier_performance, 1),
                     KpiColor::Green,
                     icon_star.to_string(),
-                    Some(KpiDelta { value: "2.3%".to_string(), is_positive: Some(true), suffix: "from last quarter".to_string() }),
+                    Some(KpiDelta { value: "2.3%".to_string(), is_positive: Some(true), suffix: t("from last quarter").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Compliance Score".to_string(),
+                    t("Compliance Score").to_string(),
                     format_percentage(kpis.get().compliance_score, 1),
                     KpiColor::Blue,
                     icon_shield.to_string(),
-                    Some(KpiDelta { value: "7 issues".to_string(), is_positive: None, suffix: "require attention".to_string() }),
+                    Some(KpiDelta { value: tn("{n} issue", "{n} issues", 7), is_positive: None, suffix: t("require attention").to_string() }),
                     None
                 )}
             </div>
@@ -353,7 +381,7 @@ pub fn analytics_dashboard() -> View {
             // Charts Row 1 - Spend Trends and Category Breakdown
             <div class="charts-grid">
                 {panel(
-                    "Monthly Spend Trend".to_string(),
+                    t("Monthly Spend Trend").to_string(),
                     vec![],
                     vec![
                         view! {
@@ -362,7 +390,7 @@ pub fn analytics_dashboard() -> View {
                                     <div class="trend-legend">
                                         <div class="trend-legend-item">
                                             <div class="legend-color" style="background: var(--blue);"></div>
-                                            <span>"Actual Spend"</span>
+                                            <span>{t("Actual Spend")}</span>
                                         </div>
                                     </div>
                                 </div>
@@ -373,7 +401,7 @@ pub fn analytics_dashboard() -> View {
                 )}
 
                 {panel(
-                    "Spend by Category".to_string(),
+                    t("Spend by Category").to_string(),
                     vec![],
                     vec![bar_chart(category_bars, Some(200))]
                 )}
@@ -382,15 +410,15 @@ pub fn analytics_dashboard() -> View {
             // Charts Row 2 - B-BBEE and Province Distribution
             <div class="charts-grid">
                 {panel(
-                    "B-BBEE Spend Distribution".to_string(),
-                    vec![view! { <a href="#" class="btn btn-sm btn-secondary">"View Details"</a> }],
+                    t("B-BBEE Spend Distribution").to_string(),
+                    vec![view! { <a href="#" class="btn btn-sm btn-secondary">{t("View Details")}</a> }],
                     vec![
                         pie_chart(bbbee_pie, None),
                         view! {
                             <div class="bbbee-progress-section">
                                 <div class="bbbee-target-info">
-                                    <span class="target">"Target: "{format_percentage(bbbee.bbbee_target, 0)}</span>
-                                    <span class="current">"Current: "{format_percentage(bbbee.bbbee_actual, 1)}</span>
+                                    <span class="target">{tf("Target: {value}", &[("value", format_percentage(bbbee.bbbee_target, 0))])}</span>
+                                    <span class="current">{tf("Current: {value}", &[("value", format_percentage(bbbee.bbbee_actual, 1))])}</span>
                                 </div>
                                 {progress_bar(bbbee.bbbee_actual, ProgressColor::Green, true, None)}
                             </div>
@@ -399,7 +427,7 @@ pub fn analytics_dashboard() -> View {
                 )}
 
                 {panel(
-                    "Provincial Distribution".to_string(),
+                    t("Provincial Distribution").to_string(),
                     vec![],
                     vec![
                         bar_chart(province_bars, Some(180)),
@@ -410,7 +438,7 @@ pub fn analytics_dashboard() -> View {
                                         <span class="name">{prov.province.clone()}</span>
                                         <div class="stats">
                                             <span class="amount">{format_currency(prov.spend)}</span>
-                                            <span>{format!("{} suppliers", prov.supplier_count)}</span>
+                                            <span>{tn("{n} supplier", "{n} suppliers", prov.supplier_count as i64)}</span>
                                         </div>
                                     </div>
                                 }
@@ -423,17 +451,26 @@ pub fn analytics_dashboard() -> View {
             // Tables Row - Category Details and Top Suppliers
             <div class="charts-grid">
                 {panel(
-                    "Category Breakdown".to_string(),
-                    vec![view! { <a href="#" class="btn btn-sm btn-secondary">"View All"</a> }],
+                    t("Category Breakdown").to_string(),
+                    vec![view! { <a href="#" class="btn btn-sm btn-secondary">{t("View All")}</a> }],
                     vec![data_table(category_columns, category_rows, None)]
                 )}
 
                 {panel(
-                    "Top Suppliers by Spend".to_string(),
-                    vec![view! { <a href="#" class="btn btn-sm btn-secondary">"View All"</a> }],
+                    t("Top Suppliers by Spend").to_string(),
+                    vec![view! { <a href="#" class="btn btn-sm btn-secondary">{t("View All")}</a> }],
                     vec![data_table(supplier_columns, supplier_rows, None)]
                 )}
             </div>
+
+            // Cycle time - approval turnaround against the approval SLAs
+            <div data-testid="approval-turnaround">
+                {panel(
+                    t("Approval Turnaround").to_string(),
+                    vec![view! { <a href={Route::OverdueApprovals.to_path()} class="btn btn-sm btn-secondary">{t("Overdue Approvals")}</a> }],
+                    vec![data_table(turnaround_columns, turnaround_rows, None)]
+                )}
+            </div>
         </div>
     }
 }
@@ -1,5 +1,7 @@
 //! Analytics service - API calls
 
+use crate::util::api::ApiError;
+use crate::util::backend;
 use super::store::{AnalyticsStore, load_mock_data};
 use super::types::{TimePeriod, AnalyticsFilter};
 
@@ -8,9 +10,7 @@ pub async fn load_analytics(store: &AnalyticsStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API with filter parameters
-    // GET /api/v1/analytics/spend?period={}&category={}&province={}
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -25,8 +25,7 @@ pub async fn load_analytics_by_period(store: &AnalyticsStore, period: TimePeriod
     filter.time_period = period;
     store.filter.set(filter);
 
-    // In production: GET /api/v1/analytics/spend?period={period}
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -46,8 +45,7 @@ pub async fn load_analytics_custom_range(
     filter.end_date = Some(end_date.to_string());
     store.filter.set(filter);
 
-    // In production: GET /api/v1/analytics/spend?start={}&end={}
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -61,8 +59,7 @@ pub async fn load_category_analytics(store: &AnalyticsStore, category_code: &str
     filter.category = Some(category_code.to_string());
     store.filter.set(filter);
 
-    // In production: GET /api/v1/analytics/spend/category/{code}
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -76,8 +73,7 @@ pub async fn load_province_analytics(store: &AnalyticsStore, province: &str) {
     filter.province = Some(province.to_string());
     store.filter.set(filter);
 
-    // In production: GET /api/v1/analytics/spend/province/{province}
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -87,8 +83,7 @@ pub async fn load_bbbee_analytics(store: &AnalyticsStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production: GET /api/v1/analytics/bbbee
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -98,8 +93,7 @@ pub async fn load_supplier_analytics(store: &AnalyticsStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production: GET /api/v1/analytics/suppliers
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -135,9 +129,27 @@ pub async fn load_analytics_with_filter(store: &AnalyticsStore, filter: &Analyti
 
     store.filter.set(filter.clone());
 
-    // In production, construct query params from filter
-    // GET /api/v1/analytics/spend?period={}&category={}&province={}&bbbee_level={}
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
+
+/// Refresh spend analytics from the configured backend
+async fn load_from_backend(store: &AnalyticsStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/analytics/spend", &store.analytics).await?;
+        backend::fetch_into("/analytics/kpis", &store.kpis).await?;
+        backend::fetch_into("/analytics/approval-turnaround", &store.approval_turnaround).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    }
+}
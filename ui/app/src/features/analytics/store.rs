@@ -1,6 +1,11 @@
 //! Analytics store
 
 use components::prelude::*;
+use crate::features::requisitions::store::{self as requisitions, RequisitionsStore};
+use crate::features::workflow::service as workflow;
+use crate::features::workflow::types::ApprovalTurnaround;
+use crate::util::datetime::today;
+use crate::util::money::Money;
 use super::types::{
     SpendAnalytics, AnalyticsFilter, AnalyticsKpis, TimePeriod,
     TrendData, TrendDataPoint, CategoryBreakdown, SupplierMetrics,
@@ -13,6 +18,8 @@ pub struct AnalyticsStore {
     pub analytics: Signal<SpendAnalytics>,
     pub filter: Signal<AnalyticsFilter>,
     pub kpis: Signal<AnalyticsKpis>,
+    /// Approval turnaround by approver role, for the cycle-time report
+    pub approval_turnaround: Signal<Vec<ApprovalTurnaround>>,
     pub loading: Signal<bool>,
     pub error: Signal<Option<String>>,
     pub selected_view: Signal<String>,
@@ -24,6 +31,7 @@ impl AnalyticsStore {
             analytics: signal(SpendAnalytics::default()),
             filter: signal(AnalyticsFilter::default()),
             kpis: signal(AnalyticsKpis::default()),
+            approval_turnaround: signal(Vec::new()),
             loading: signal(false),
             error: signal(None),
             selected_view: signal("overview".to_string()),
@@ -35,20 +43,20 @@ impl AnalyticsStore {
 pub fn load_mock_data(store: &AnalyticsStore) {
     // Monthly spend data for FY 2025/26 (April to current)
     let monthly_spend = vec![
-        TrendDataPoint { period: "Apr".to_string(), value: 68_500_000.0, budget: Some(75_000_000.0) },
-        TrendDataPoint { period: "May".to_string(), value: 72_300_000.0, budget: Some(75_000_000.0) },
-        TrendDataPoint { period: "Jun".to_string(), value: 85_200_000.0, budget: Some(80_000_000.0) },
-        TrendDataPoint { period: "Jul".to_string(), value: 78_900_000.0, budget: Some(80_000_000.0) },
-        TrendDataPoint { period: "Aug".to_string(), value: 81_400_000.0, budget: Some(85_000_000.0) },
-        TrendDataPoint { period: "Sep".to_string(), value: 92_100_000.0, budget: Some(90_000_000.0) },
-        TrendDataPoint { period: "Oct".to_string(), value: 88_700_000.0, budget: Some(85_000_000.0) },
-        TrendDataPoint { period: "Nov".to_string(), value: 95_300_000.0, budget: Some(90_000_000.0) },
-        TrendDataPoint { period: "Dec".to_string(), value: 76_200_000.0, budget: Some(80_000_000.0) },
-        TrendDataPoint { period: "Jan".to_string(), value: 108_400_000.0, budget: Some(100_000_000.0) },
+        TrendDataPoint { period: "Apr".to_string(), value: Money::rands(68_500_000), budget: Some(Money::rands(75_000_000)) },
+        TrendDataPoint { period: "May".to_string(), value: Money::rands(72_300_000), budget: Some(Money::rands(75_000_000)) },
+        TrendDataPoint { period: "Jun".to_string(), value: Money::rands(85_200_000), budget: Some(Money::rands(80_000_000)) },
+        TrendDataPoint { period: "Jul".to_string(), value: Money::rands(78_900_000), budget: Some(Money::rands(80_000_000)) },
+        TrendDataPoint { period: "Aug".to_string(), value: Money::rands(81_400_000), budget: Some(Money::rands(85_000_000)) },
+        TrendDataPoint { period: "Sep".to_string(), value: Money::rands(92_100_000), budget: Some(Money::rands(90_000_000)) },
+        TrendDataPoint { period: "Oct".to_string(), value: Money::rands(88_700_000), budget: Some(Money::rands(85_000_000)) },
+        TrendDataPoint { period: "Nov".to_string(), value: Money::rands(95_300_000), budget: Some(Money::rands(90_000_000)) },
+        TrendDataPoint { period: "Dec".to_string(), value: Money::rands(76_200_000), budget: Some(Money::rands(80_000_000)) },
+        TrendDataPoint { period: "Jan".to_string(), value: Money::rands(108_400_000), budget: Some(Money::rands(100_000_000)) },
     ];
 
     // Cumulative spend
-    let mut cumulative = 0.0;
+    let mut cumulative = Money::ZERO;
     let cumulative_spend: Vec<TrendDataPoint> = monthly_spend.iter().map(|m| {
         cumulative += m.value;
         TrendDataPoint {
@@ -60,9 +68,9 @@ pub fn load_mock_data(store: &AnalyticsStore) {
 
     // Year-over-year comparison
     let year_over_year = vec![
-        TrendDataPoint { period: "FY 23/24".to_string(), value: 890_000_000.0, budget: None },
-        TrendDataPoint { period: "FY 24/25".to_string(), value: 945_000_000.0, budget: None },
-        TrendDataPoint { period: "FY 25/26 (YTD)".to_string(), value: 847_000_000.0, budget: None },
+        TrendDataPoint { period: "FY 23/24".to_string(), value: Money::rands(890_000_000), budget: None },
+        TrendDataPoint { period: "FY 24/25".to_string(), value: Money::rands(945_000_000), budget: None },
+        TrendDataPoint { period: "FY 25/26 (YTD)".to_string(), value: Money::rands(847_000_000), budget: None },
     ];
 
     let trends = TrendData {
@@ -76,9 +84,9 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         CategoryBreakdown {
             code: "IT".to_string(),
             name: "Information Technology".to_string(),
-            spend: 250_000_000.0,
-            budget: 280_000_000.0,
-            variance: 30_000_000.0,
+            spend: Money::rands(250_000_000),
+            budget: Money::rands(280_000_000),
+            variance: Money::rands(30_000_000),
             percentage: 29.5,
             contract_count: 45,
             supplier_count: 28,
@@ -87,9 +95,9 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         CategoryBreakdown {
             code: "PRO".to_string(),
             name: "Professional Services".to_string(),
-            spend: 200_000_000.0,
-            budget: 220_000_000.0,
-            variance: 20_000_000.0,
+            spend: Money::rands(200_000_000),
+            budget: Money::rands(220_000_000),
+            variance: Money::rands(20_000_000),
             percentage: 23.6,
             contract_count: 38,
             supplier_count: 42,
@@ -98,9 +106,9 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         CategoryBreakdown {
             code: "FAC".to_string(),
             name: "Facilities Management".to_string(),
-            spend: 180_000_000.0,
-            budget: 175_000_000.0,
-            variance: -5_000_000.0,
+            spend: Money::rands(180_000_000),
+            budget: Money::rands(175_000_000),
+            variance: Money::rands(-5_000_000),
             percentage: 21.3,
             contract_count: 22,
             supplier_count: 18,
@@ -109,9 +117,9 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         CategoryBreakdown {
             code: "SEC".to_string(),
             name: "Security Services".to_string(),
-            spend: 120_000_000.0,
-            budget: 130_000_000.0,
-            variance: 10_000_000.0,
+            spend: Money::rands(120_000_000),
+            budget: Money::rands(130_000_000),
+            variance: Money::rands(10_000_000),
             percentage: 14.2,
             contract_count: 15,
             supplier_count: 12,
@@ -120,9 +128,9 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         CategoryBreakdown {
             code: "FLT".to_string(),
             name: "Fleet Management".to_string(),
-            spend: 97_000_000.0,
-            budget: 95_000_000.0,
-            variance: -2_000_000.0,
+            spend: Money::rands(97_000_000),
+            budget: Money::rands(95_000_000),
+            variance: Money::rands(-2_000_000),
             percentage: 11.4,
             contract_count: 8,
             supplier_count: 6,
@@ -132,15 +140,15 @@ pub fn load_mock_data(store: &AnalyticsStore) {
 
     // Province distribution
     let province_distribution = vec![
-        ProvinceDistribution { province: "Gauteng".to_string(), spend: 380_000_000.0, percentage: 44.9, supplier_count: 542 },
-        ProvinceDistribution { province: "Western Cape".to_string(), spend: 165_000_000.0, percentage: 19.5, supplier_count: 234 },
-        ProvinceDistribution { province: "KwaZulu-Natal".to_string(), spend: 120_000_000.0, percentage: 14.2, supplier_count: 187 },
-        ProvinceDistribution { province: "Eastern Cape".to_string(), spend: 68_000_000.0, percentage: 8.0, supplier_count: 98 },
-        ProvinceDistribution { province: "Mpumalanga".to_string(), spend: 42_000_000.0, percentage: 5.0, supplier_count: 67 },
-        ProvinceDistribution { province: "Free State".to_string(), spend: 28_000_000.0, percentage: 3.3, supplier_count: 54 },
-        ProvinceDistribution { province: "Limpopo".to_string(), spend: 22_000_000.0, percentage: 2.6, supplier_count: 48 },
-        ProvinceDistribution { province: "North West".to_string(), spend: 15_000_000.0, percentage: 1.8, supplier_count: 35 },
-        ProvinceDistribution { province: "Northern Cape".to_string(), spend: 7_000_000.0, percentage: 0.8, supplier_count: 19 },
+        ProvinceDistribution { province: "Gauteng".to_string(), spend: Money::rands(380_000_000), percentage: 44.9, supplier_count: 542 },
+        ProvinceDistribution { province: "Western Cape".to_string(), spend: Money::rands(165_000_000), percentage: 19.5, supplier_count: 234 },
+        ProvinceDistribution { province: "KwaZulu-Natal".to_string(), spend: Money::rands(120_000_000), percentage: 14.2, supplier_count: 187 },
+        ProvinceDistribution { province: "Eastern Cape".to_string(), spend: Money::rands(68_000_000), percentage: 8.0, supplier_count: 98 },
+        ProvinceDistribution { province: "Mpumalanga".to_string(), spend: Money::rands(42_000_000), percentage: 5.0, supplier_count: 67 },
+        ProvinceDistribution { province: "Free State".to_string(), spend: Money::rands(28_000_000), percentage: 3.3, supplier_count: 54 },
+        ProvinceDistribution { province: "Limpopo".to_string(), spend: Money::rands(22_000_000), percentage: 2.6, supplier_count: 48 },
+        ProvinceDistribution { province: "North West".to_string(), spend: Money::rands(15_000_000), percentage: 1.8, supplier_count: 35 },
+        ProvinceDistribution { province: "Northern Cape".to_string(), spend: Money::rands(7_000_000), percentage: 0.8, supplier_count: 19 },
     ];
 
     // Top suppliers by spend
@@ -148,7 +156,7 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         SupplierMetrics {
             supplier_id: "SUP-001".to_string(),
             supplier_name: "TechSolutions SA (Pty) Ltd".to_string(),
-            total_spend: 45_000_000.0,
+            total_spend: Money::rands(45_000_000),
             contract_count: 3,
             average_delivery_time: 4.2,
             quality_score: 95.0,
@@ -160,7 +168,7 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         SupplierMetrics {
             supplier_id: "SUP-004".to_string(),
             supplier_name: "SecureGuard Holdings (Pty) Ltd".to_string(),
-            total_spend: 35_000_000.0,
+            total_spend: Money::rands(35_000_000),
             contract_count: 5,
             average_delivery_time: 2.8,
             quality_score: 90.0,
@@ -172,7 +180,7 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         SupplierMetrics {
             supplier_id: "SUP-002".to_string(),
             supplier_name: "Ubuntu Construction Group".to_string(),
-            total_spend: 28_500_000.0,
+            total_spend: Money::rands(28_500_000),
             contract_count: 2,
             average_delivery_time: 12.5,
             quality_score: 88.0,
@@ -184,7 +192,7 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         SupplierMetrics {
             supplier_id: "SUP-012".to_string(),
             supplier_name: "North West Mining Equipment".to_string(),
-            total_spend: 22_000_000.0,
+            total_spend: Money::rands(22_000_000),
             contract_count: 2,
             average_delivery_time: 8.3,
             quality_score: 83.0,
@@ -196,7 +204,7 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         SupplierMetrics {
             supplier_id: "SUP-007".to_string(),
             supplier_name: "Mpumalanga Transport Logistics".to_string(),
-            total_spend: 18_000_000.0,
+            total_spend: Money::rands(18_000_000),
             contract_count: 3,
             average_delivery_time: 3.5,
             quality_score: 85.0,
@@ -208,7 +216,7 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         SupplierMetrics {
             supplier_id: "SUP-005".to_string(),
             supplier_name: "GreenTech Environmental Solutions".to_string(),
-            total_spend: 15_500_000.0,
+            total_spend: Money::rands(15_500_000),
             contract_count: 2,
             average_delivery_time: 5.2,
             quality_score: 93.0,
@@ -220,7 +228,7 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         SupplierMetrics {
             supplier_id: "SUP-003".to_string(),
             supplier_name: "Cape Catering Services CC".to_string(),
-            total_spend: 8_200_000.0,
+            total_spend: Money::rands(8_200_000),
             contract_count: 4,
             average_delivery_time: 1.5,
             quality_score: 96.0,
@@ -232,7 +240,7 @@ pub fn load_mock_data(store: &AnalyticsStore) {
         SupplierMetrics {
             supplier_id: "SUP-008".to_string(),
             supplier_name: "Eastern Cape Medical Supplies".to_string(),
-            total_spend: 6_800_000.0,
+            total_spend: Money::rands(6_800_000),
             contract_count: 1,
             average_delivery_time: 9.8,
             quality_score: 78.0,
@@ -244,40 +252,40 @@ pub fn load_mock_data(store: &AnalyticsStore) {
     ];
 
     // B-BBEE metrics
-    let total_spend = 847_000_000.0;
+    let total_spend = Money::rands(847_000_000);
     let bbbee_metrics = BbbeeMetrics {
-        level_1_spend: 356_000_000.0,
+        level_1_spend: Money::rands(356_000_000),
         level_1_percent: 42.0,
-        level_2_spend: 237_000_000.0,
+        level_2_spend: Money::rands(237_000_000),
         level_2_percent: 28.0,
-        level_3_4_spend: 152_500_000.0,
+        level_3_4_spend: Money::rands(152_500_000),
         level_3_4_percent: 18.0,
-        level_5_8_spend: 50_800_000.0,
+        level_5_8_spend: Money::rands(50_800_000),
         level_5_8_percent: 6.0,
-        non_compliant_spend: 50_700_000.0,
+        non_compliant_spend: Money::rands(50_700_000),
         non_compliant_percent: 6.0,
-        total_bbbee_spend: 745_500_000.0,
+        total_bbbee_spend: Money::rands(745_500_000),
         bbbee_target: 80.0,
         bbbee_actual: 78.4,
         suppliers_by_level: vec![
-            BbbeeLevelCount { level: "Level 1".to_string(), count: 485, spend: 356_000_000.0 },
-            BbbeeLevelCount { level: "Level 2".to_string(), count: 312, spend: 237_000_000.0 },
-            BbbeeLevelCount { level: "Level 3".to_string(), count: 187, spend: 95_000_000.0 },
-            BbbeeLevelCount { level: "Level 4".to_string(), count: 124, spend: 57_500_000.0 },
-            BbbeeLevelCount { level: "Level 5-8".to_string(), count: 98, spend: 50_800_000.0 },
-            BbbeeLevelCount { level: "Non-Compliant".to_string(), count: 78, spend: 50_700_000.0 },
+            BbbeeLevelCount { level: "Level 1".to_string(), count: 485, spend: Money::rands(356_000_000) },
+            BbbeeLevelCount { level: "Level 2".to_string(), count: 312, spend: Money::rands(237_000_000) },
+            BbbeeLevelCount { level: "Level 3".to_string(), count: 187, spend: Money::rands(95_000_000) },
+            BbbeeLevelCount { level: "Level 4".to_string(), count: 124, spend: Money::rands(57_500_000) },
+            BbbeeLevelCount { level: "Level 5-8".to_string(), count: 98, spend: Money::rands(50_800_000) },
+            BbbeeLevelCount { level: "Non-Compliant".to_string(), count: 78, spend: Money::rands(50_700_000) },
         ],
     };
 
     // Complete analytics data
     let analytics = SpendAnalytics {
         total_spend,
-        budget_allocated: 1_000_000_000.0,
-        budget_variance: 153_000_000.0,
+        budget_allocated: Money::rands(1_000_000_000),
+        budget_variance: Money::rands(153_000_000),
         active_contracts: 456,
         active_suppliers: 1284,
-        pending_payments: 45_600_000.0,
-        savings_achieved: 32_500_000.0,
+        pending_payments: Money::rands(45_600_000),
+        savings_achieved: Money::rands(32_500_000),
         savings_percentage: 3.8,
         trends,
         category_breakdown,
@@ -288,9 +296,9 @@ pub fn load_mock_data(store: &AnalyticsStore) {
 
     // KPIs
     let kpis = AnalyticsKpis {
-        ytd_spend: 847_000_000.0,
+        ytd_spend: Money::rands(847_000_000),
         budget_utilization: 84.7,
-        cost_savings: 32_500_000.0,
+        cost_savings: Money::rands(32_500_000),
         bbbee_compliance: 78.4,
         avg_procurement_cycle: 42,
         supplier_performance: 86.5,
@@ -300,6 +308,15 @@ pub fn load_mock_data(store: &AnalyticsStore) {
 
     store.analytics.set(analytics);
     store.kpis.set(kpis);
+    store.approval_turnaround.set(mock_approval_turnaround());
+}
+
+/// Approval turnaround over the requisition fixtures' approval chains
+fn mock_approval_turnaround() -> Vec<ApprovalTurnaround> {
+    let fixtures = RequisitionsStore::new();
+    requisitions::load_mock_data(&fixtures);
+    let requisitions = fixtures.requisitions.get();
+    workflow::turnaround(requisitions.iter().flat_map(|r| r.approval_workflow.iter()), today())
 }
 
 /// Update time period filter
@@ -1,6 +1,8 @@
 //! Analytics domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::Money;
 
 /// Time period for analytics filtering
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -19,13 +21,13 @@ pub enum TimePeriod {
 
 impl TimePeriod {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             TimePeriod::ThisMonth => "This Month",
             TimePeriod::ThisQuarter => "This Quarter",
             TimePeriod::ThisYear => "FY 2025/26",
             TimePeriod::Last12Months => "Last 12 Months",
             TimePeriod::Custom => "Custom Range",
-        }
+        })
     }
 }
 
@@ -39,8 +41,8 @@ impl Default for TimePeriod {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TrendDataPoint {
     pub period: String,
-    pub value: f64,
-    pub budget: Option<f64>,
+    pub value: Money,
+    pub budget: Option<Money>,
 }
 
 /// Overall spend trends
@@ -66,9 +68,9 @@ impl Default for TrendData {
 pub struct CategoryBreakdown {
     pub code: String,
     pub name: String,
-    pub spend: f64,
-    pub budget: f64,
-    pub variance: f64,
+    pub spend: Money,
+    pub budget: Money,
+    pub variance: Money,
     pub percentage: f64,
     pub contract_count: u32,
     pub supplier_count: u32,
@@ -80,7 +82,7 @@ pub struct CategoryBreakdown {
 pub struct SupplierMetrics {
     pub supplier_id: String,
     pub supplier_name: String,
-    pub total_spend: f64,
+    pub total_spend: Money,
     pub contract_count: u32,
     pub average_delivery_time: f64,
     pub quality_score: f64,
@@ -93,17 +95,17 @@ pub struct SupplierMetrics {
 /// B-BBEE metrics for spend analysis
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BbbeeMetrics {
-    pub level_1_spend: f64,
+    pub level_1_spend: Money,
     pub level_1_percent: f64,
-    pub level_2_spend: f64,
+    pub level_2_spend: Money,
     pub level_2_percent: f64,
-    pub level_3_4_spend: f64,
+    pub level_3_4_spend: Money,
     pub level_3_4_percent: f64,
-    pub level_5_8_spend: f64,
+    pub level_5_8_spend: Money,
     pub level_5_8_percent: f64,
-    pub non_compliant_spend: f64,
+    pub non_compliant_spend: Money,
     pub non_compliant_percent: f64,
-    pub total_bbbee_spend: f64,
+    pub total_bbbee_spend: Money,
     pub bbbee_target: f64,
     pub bbbee_actual: f64,
     pub suppliers_by_level: Vec<BbbeeLevelCount>,
@@ -114,23 +116,23 @@ pub struct BbbeeMetrics {
 pub struct BbbeeLevelCount {
     pub level: String,
     pub count: u32,
-    pub spend: f64,
+    pub spend: Money,
 }
 
 impl Default for BbbeeMetrics {
     fn default() -> Self {
         Self {
-            level_1_spend: 0.0,
+            level_1_spend: Money::ZERO,
             level_1_percent: 0.0,
-            level_2_spend: 0.0,
+            level_2_spend: Money::ZERO,
             level_2_percent: 0.0,
-            level_3_4_spend: 0.0,
+            level_3_4_spend: Money::ZERO,
             level_3_4_percent: 0.0,
-            level_5_8_spend: 0.0,
+            level_5_8_spend: Money::ZERO,
             level_5_8_percent: 0.0,
-            non_compliant_spend: 0.0,
+            non_compliant_spend: Money::ZERO,
             non_compliant_percent: 0.0,
-            total_bbbee_spend: 0.0,
+            total_bbbee_spend: Money::ZERO,
             bbbee_target: 80.0,
             bbbee_actual: 0.0,
             suppliers_by_level: Vec::new(),
@@ -142,7 +144,7 @@ impl Default for BbbeeMetrics {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ProvinceDistribution {
     pub province: String,
-    pub spend: f64,
+    pub spend: Money,
     pub percentage: f64,
     pub supplier_count: u32,
 }
@@ -151,13 +153,13 @@ pub struct ProvinceDistribution {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SpendAnalytics {
     // Summary KPIs
-    pub total_spend: f64,
-    pub budget_allocated: f64,
-    pub budget_variance: f64,
+    pub total_spend: Money,
+    pub budget_allocated: Money,
+    pub budget_variance: Money,
     pub active_contracts: u32,
     pub active_suppliers: u32,
-    pub pending_payments: f64,
-    pub savings_achieved: f64,
+    pub pending_payments: Money,
+    pub savings_achieved: Money,
     pub savings_percentage: f64,
 
     // Trends
@@ -177,13 +179,13 @@ pub struct SpendAnalytics {
 impl Default for SpendAnalytics {
     fn default() -> Self {
         Self {
-            total_spend: 0.0,
-            budget_allocated: 0.0,
-            budget_variance: 0.0,
+            total_spend: Money::ZERO,
+            budget_allocated: Money::ZERO,
+            budget_variance: Money::ZERO,
             active_contracts: 0,
             active_suppliers: 0,
-            pending_payments: 0.0,
-            savings_achieved: 0.0,
+            pending_payments: Money::ZERO,
+            savings_achieved: Money::ZERO,
             savings_percentage: 0.0,
             trends: TrendData::default(),
             category_breakdown: Vec::new(),
@@ -207,11 +209,11 @@ pub struct AnalyticsFilter {
 }
 
 /// Analytics summary KPIs
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct AnalyticsKpis {
-    pub ytd_spend: f64,
+    pub ytd_spend: Money,
     pub budget_utilization: f64,
-    pub cost_savings: f64,
+    pub cost_savings: Money,
     pub bbbee_compliance: f64,
     pub avg_procurement_cycle: u32,
     pub supplier_performance: f64,
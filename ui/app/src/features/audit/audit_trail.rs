@@ -11,9 +11,11 @@ use crate::shared::components::{
 };
 use crate::shared::forms::filter_bar;
 use crate::util::format::format_date;
+use crate::util::url;
 use super::store::AuditStore;
 use super::types::{AuditEntityType, AuditActionType, AuditEntry};
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Audit trail list page
 #[component]
@@ -31,13 +33,15 @@ pub fn audit_trail() -> View {
         }
     });
 
-    // Filter signals
-    let entity_type_filter = signal(String::new());
-    let action_type_filter = signal(String::new());
-    let user_filter = signal(String::new());
-    let date_from_filter = signal(String::new());
-    let date_to_filter = signal(String::new());
-    let search_filter = signal(String::new());
+    // Filter signals, seeded from the URL
+    url::sync_filter(&store.filter);
+    let initial = store.filter.get();
+    let entity_type_filter = signal(initial.entity_type.as_ref().map(|t| t.as_param()).unwrap_or_default());
+    let action_type_filter = signal(initial.action_type.as_ref().map(|t| t.as_param()).unwrap_or_default());
+    let user_filter = signal(initial.user_search.clone().unwrap_or_default());
+    let date_from_filter = signal(initial.date_from.clone().unwrap_or_default());
+    let date_to_filter = signal(initial.date_to.clone().unwrap_or_default());
+    let search_filter = signal(initial.search.clone().unwrap_or_default());
 
     // Handle entity type filter change
     let handle_entity_type_change = Callback::new({
@@ -196,42 +200,42 @@ pub fn audit_trail() -> View {
     let columns = vec![
         DataTableColumn {
             key: "timestamp".to_string(),
-            label: "Timestamp".to_string(),
+            label: t("Timestamp").to_string(),
             width: Some("160px".to_string()),
             align: None,
             cell_class: Some("timestamp-cell".to_string()),
         },
         DataTableColumn {
             key: "user".to_string(),
-            label: "User".to_string(),
+            label: t("User").to_string(),
             width: Some("180px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "action".to_string(),
-            label: "Action".to_string(),
+            label: t("Action").to_string(),
             width: Some("100px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "entity".to_string(),
-            label: "Entity".to_string(),
+            label: t("Entity").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "details".to_string(),
-            label: "Details".to_string(),
+            label: t("Details").to_string(),
             width: None,
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "ip".to_string(),
-            label: "IP Address".to_string(),
+            label: t("IP Address").to_string(),
             width: Some("130px".to_string()),
             align: None,
             cell_class: Some("ip-cell".to_string()),
@@ -258,8 +262,8 @@ pub fn audit_trail() -> View {
 
             let changes_indicator = if entry.has_changes() {
                 view! {
-                    <span class="changes-indicator" title={format!("{} field(s) changed", entry.changes.len())}>
-                        {format!("{} changes", entry.changes.len())}
+                    <span class="changes-indicator" title={tn("{n} field changed", "{n} fields changed", entry.changes.len() as i64)}>
+                        {tn("{n} change", "{n} changes", entry.changes.len() as i64)}
                     </span>
                 }
             } else {
@@ -293,7 +297,7 @@ pub fn audit_trail() -> View {
                     view! { <span class="ip-cell">{entry.ip_address.clone()}</span> },
                     view! {
                         <div class="row-actions">
-                            <button class="btn btn-sm btn-secondary" title="View Details">"View"</button>
+                            <button class="btn btn-sm btn-secondary" title={t("View Details")}>{t("View")}</button>
                         </div>
                     },
                 ],
@@ -499,6 +503,9 @@ pub fn audit_trail() -> View {
             .action-badge.logout { background: var(--bg); color: var(--text-muted); }
             .action-badge.export { background: var(--purple-light); color: var(--purple); }
             .action-badge.import { background: var(--purple-light); color: var(--purple); }
+            .action-badge.delegate { background: var(--orange-light); color: var(--orange); }
+            .action-badge.reassign { background: var(--orange-light); color: var(--orange); }
+            .action-badge.escalate { background: var(--red-light); color: var(--red); }
 
             .entity-tag {
                 display: inline-flex;
@@ -521,10 +528,10 @@ pub fn audit_trail() -> View {
 
         <div class="audit-trail-page" data-testid="audit-trail">
             {page_header(
-                "Audit Trail".to_string(),
-                Some("Track all system activities and changes".to_string()),
+                t("Audit Trail").to_string(),
+                Some(t("Track all system activities and changes").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary" on:click={handle_export}>"Export"</button> },
+                    view! { <button class="btn btn-secondary" on:click={handle_export}>{t("Export")}</button> },
                 ]
             )}
 
@@ -534,28 +541,28 @@ pub fn audit_trail() -> View {
                     <div class="stat-icon blue" inner_html={icon_activity}></div>
                     <div class="stat-content">
                         <h3>{total_entries.to_string()}</h3>
-                        <p>"Total Entries"</p>
+                        <p>{t("Total Entries")}</p>
                     </div>
                 </div>
                 <div class="stat-card">
                     <div class="stat-icon green" inner_html={icon_clock}></div>
                     <div class="stat-content">
                         <h3>{entries_today.to_string()}</h3>
-                        <p>"Today's Activity"</p>
+                        <p>{t("Today's Activity")}</p>
                     </div>
                 </div>
                 <div class="stat-card">
                     <div class="stat-icon orange" inner_html={icon_users}></div>
                     <div class="stat-content">
                         <h3>{unique_users.to_string()}</h3>
-                        <p>"Active Users"</p>
+                        <p>{t("Active Users")}</p>
                     </div>
                 </div>
                 <div class="stat-card">
                     <div class="stat-icon purple" inner_html={icon_shield}></div>
                     <div class="stat-content">
                         <h3>{logins.to_string()}</h3>
-                        <p>"Login Events"</p>
+                        <p>{t("Login Events")}</p>
                     </div>
                 </div>
             </div>
@@ -564,48 +571,48 @@ pub fn audit_trail() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Entity Type"</label>
-                        <select on:change={handle_entity_type_change}>
-                            <option value="">"All Entities"</option>
-                            <option value="requisition">"Requisition"</option>
-                            <option value="tender">"Tender"</option>
-                            <option value="bid">"Bid"</option>
-                            <option value="evaluation">"Evaluation"</option>
-                            <option value="contract">"Contract"</option>
-                            <option value="purchase_order">"Purchase Order"</option>
-                            <option value="goods_receipt">"Goods Receipt"</option>
-                            <option value="supplier">"Supplier"</option>
-                            <option value="user">"User"</option>
-                            <option value="system">"System"</option>
+                        <label>{t("Entity Type")}</label>
+                        <select on:change={handle_entity_type_change} value={entity_type_filter.get()}>
+                            <option value="">{t("All Entities")}</option>
+                            <option value="requisition">{t("Requisition")}</option>
+                            <option value="tender">{t("Tender")}</option>
+                            <option value="bid">{t("Bid")}</option>
+                            <option value="evaluation">{t("Evaluation")}</option>
+                            <option value="contract">{t("Contract")}</option>
+                            <option value="purchase_order">{t("Purchase Order")}</option>
+                            <option value="goods_receipt">{t("Goods Receipt")}</option>
+                            <option value="supplier">{t("Supplier")}</option>
+                            <option value="user">{t("User")}</option>
+                            <option value="system">{t("System")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Action Type"</label>
-                        <select on:change={handle_action_type_change}>
-                            <option value="">"All Actions"</option>
-                            <option value="create">"Create"</option>
-                            <option value="update">"Update"</option>
-                            <option value="delete">"Delete"</option>
-                            <option value="view">"View"</option>
-                            <option value="approve">"Approve"</option>
-                            <option value="reject">"Reject"</option>
-                            <option value="submit">"Submit"</option>
-                            <option value="cancel">"Cancel"</option>
-                            <option value="login">"Login"</option>
-                            <option value="logout">"Logout"</option>
-                            <option value="export">"Export"</option>
-                            <option value="import">"Import"</option>
+                        <label>{t("Action Type")}</label>
+                        <select on:change={handle_action_type_change} value={action_type_filter.get()}>
+                            <option value="">{t("All Actions")}</option>
+                            <option value="create">{t("Create")}</option>
+                            <option value="update">{t("Update")}</option>
+                            <option value="delete">{t("Delete")}</option>
+                            <option value="view">{t("View")}</option>
+                            <option value="approve">{t("Approve")}</option>
+                            <option value="reject">{t("Reject")}</option>
+                            <option value="submit">{t("Submit")}</option>
+                            <option value="cancel">{t("Cancel")}</option>
+                            <option value="login">{t("Login")}</option>
+                            <option value="logout">{t("Logout")}</option>
+                            <option value="export">{t("Export")}</option>
+                            <option value="import">{t("Import")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"User"</label>
+                        <label>{t("User")}</label>
                         <input
                             type="text"
-                            placeholder="Search user..."
+                            placeholder={t("Search user...")}
                             value={user_filter.get()}
                             on:input={handle_user_change}
                         />
@@ -613,7 +620,7 @@ pub fn audit_trail() -> View {
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Date From"</label>
+                        <label>{t("Date From")}</label>
                         <input
                             type="date"
                             value={date_from_filter.get()}
@@ -623,7 +630,7 @@ pub fn audit_trail() -> View {
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Date To"</label>
+                        <label>{t("Date To")}</label>
                         <input
                             type="date"
                             value={date_to_filter.get()}
@@ -634,10 +641,10 @@ pub fn audit_trail() -> View {
                 view! { <div class="filter-spacer"></div> },
                 view! {
                     <div class="filter-group">
-                        <label>"Search"</label>
+                        <label>{t("Search")}</label>
                         <input
                             type="text"
-                            placeholder="Search audit logs..."
+                            placeholder={t("Search audit logs...")}
                             value={search_filter.get()}
                             on:input={handle_search_change}
                         />
@@ -645,24 +652,24 @@ pub fn audit_trail() -> View {
                 },
                 view! {
                     <div class="filter-actions">
-                        <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>"Clear Filters"</button>
+                        <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>{t("Clear Filters")}</button>
                     </div>
                 },
             ])}
 
             // Audit entries table
             if loading.get() {
-                <div class="loading-state">"Loading audit entries..."</div>
+                <div class="loading-state">{t("Loading audit entries...")}</div>
             } else if rows.is_empty() {
                 {empty_state(
-                    "No audit entries found".to_string(),
-                    Some("Try adjusting your filters to see more results".to_string()),
+                    t("No audit entries found").to_string(),
+                    Some(t("Try adjusting your filters to see more results").to_string()),
                     None,
                     None
                 )}
             } else {
                 {panel(
-                    format!("Audit Entries ({})", rows.len()),
+                    tf("Audit Entries ({count})", &[("count", rows.len().to_string())]),
                     vec![],
                     vec![data_table(columns.clone(), rows.clone(), Some(handle_row_click))]
                 )}
@@ -686,6 +693,9 @@ fn get_action_badge(action: &AuditActionType) -> View {
         AuditActionType::Logout => "action-badge logout",
         AuditActionType::Export => "action-badge export",
         AuditActionType::Import => "action-badge import",
+        AuditActionType::Delegate => "action-badge delegate",
+        AuditActionType::Reassign => "action-badge reassign",
+        AuditActionType::Escalate => "action-badge escalate",
     };
 
     view! {
@@ -781,46 +791,46 @@ pub fn audit_entry_detail(entry: AuditEntry) -> View {
                 <h3>{entry.summary()}</h3>
                 <div class="audit-detail-meta">
                     <span>{service::format_audit_timestamp(&entry.timestamp)}</span>
-                    <span>{format!("IP: {}", entry.ip_address)}</span>
+                    <span>{tf("IP: {address}", &[("address", entry.ip_address.clone())])}</span>
                 </div>
             </div>
 
             <div class="audit-detail-section">
-                <h4>"User Information"</h4>
+                <h4>{t("User Information")}</h4>
                 <dl class="audit-detail-grid">
-                    <dt>"Name"</dt>
+                    <dt>{t("Name")}</dt>
                     <dd>{entry.user_name.clone()}</dd>
-                    <dt>"Email"</dt>
+                    <dt>{t("Email")}</dt>
                     <dd>{entry.user_email.clone()}</dd>
-                    <dt>"User ID"</dt>
+                    <dt>{t("User ID")}</dt>
                     <dd>{entry.user_id.clone()}</dd>
                 </dl>
             </div>
 
             <div class="audit-detail-section">
-                <h4>"Action Details"</h4>
+                <h4>{t("Action Details")}</h4>
                 <dl class="audit-detail-grid">
-                    <dt>"Action"</dt>
+                    <dt>{t("Action")}</dt>
                     <dd>{entry.action.as_str()}</dd>
-                    <dt>"Entity Type"</dt>
+                    <dt>{t("Entity Type")}</dt>
                     <dd>{entry.entity_type.as_str()}</dd>
-                    <dt>"Entity ID"</dt>
+                    <dt>{t("Entity ID")}</dt>
                     <dd>{entry.entity_id.clone()}</dd>
-                    <dt>"Entity Name"</dt>
+                    <dt>{t("Entity Name")}</dt>
                     <dd>{entry.entity_name.clone().unwrap_or_else(|| "-".to_string())}</dd>
                 </dl>
             </div>
 
             if !entry.changes.is_empty() {
                 <div class="audit-detail-section">
-                    <h4>"Changes"</h4>
+                    <h4>{t("Changes")}</h4>
                     <div class="changes-list">
                         {entry.changes.iter().map(|change| {
                             view! {
                                 <div class="change-item">
                                     <span class="field-name">{change.field_name.clone()}</span>
-                                    <span class="old-value">{change.old_value.clone().unwrap_or_else(|| "(empty)".to_string())}</span>
-                                    <span class="new-value">{change.new_value.clone().unwrap_or_else(|| "(empty)".to_string())}</span>
+                                    <span class="old-value">{change.old_value.clone().unwrap_or_else(|| t("(empty)").to_string())}</span>
+                                    <span class="new-value">{change.new_value.clone().unwrap_or_else(|| t("(empty)").to_string())}</span>
                                 </div>
                             }
                         }).collect::<Vec<_>>()}
@@ -830,7 +840,7 @@ pub fn audit_entry_detail(entry: AuditEntry) -> View {
 
             if let Some(description) = entry.description.as_ref() {
                 <div class="audit-detail-section">
-                    <h4>"Description"</h4>
+                    <h4>{t("Description")}</h4>
                     <p>{description.clone()}</p>
                 </div>
             }
@@ -1,6 +1,12 @@
 //! Audit service - API calls
 
-use super::store::{AuditStore, load_mock_data};
+use components::prelude::*;
+use crate::util::api::{ApiError, HttpMethod};
+use crate::util::auth;
+use crate::util::backend;
+use crate::util::datetime::now_iso;
+use crate::util::reference;
+use super::store::{self, AuditStore, load_mock_data};
 use super::types::{AuditEntry, AuditFilter, AuditExportRequest, ExportFormat};
 
 /// Load all audit entries
@@ -8,9 +14,7 @@ pub async fn load_audit_entries(store: &AuditStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -25,7 +29,7 @@ pub async fn load_filtered_audit_entries(store: &AuditStore, filter: AuditFilter
 
     // Ensure entries are loaded
     if store.entries.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     store.loading.set(false);
@@ -38,7 +42,7 @@ pub async fn load_audit_entry(store: &AuditStore, entry_id: &str) {
 
     // Ensure entries are loaded
     if store.entries.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Select the entry
@@ -58,7 +62,7 @@ pub async fn load_entity_audit_trail(
 
     // Ensure entries are loaded
     if store.entries.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Set filter for specific entity
@@ -77,7 +81,7 @@ pub async fn load_user_audit_trail(store: &AuditStore, user_id: &str) {
 
     // Ensure entries are loaded
     if store.entries.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Set filter for specific user
@@ -95,7 +99,7 @@ pub async fn search_audit_entries(store: &AuditStore, query: &str) {
 
     // Ensure entries are loaded
     if store.entries.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Set search filter
@@ -147,7 +151,7 @@ pub async fn load_audit_stats(store: &AuditStore) {
 
     // Ensure entries are loaded
     if store.entries.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Calculate stats from loaded entries
@@ -172,6 +176,35 @@ pub async fn create_audit_entry(
     Ok(entry_id)
 }
 
+/// Record an action the signed-in user just took elsewhere in the app.
+///
+/// Fills in the entry's id, timestamp and user, adds it to the installed
+/// store and posts it to the backend, queued while offline.
+pub fn record(mut entry: AuditEntry) {
+    let user = auth::current().user.unwrap_or_default();
+    entry.id = reference::unique_id("AUD");
+    entry.timestamp = now_iso();
+    entry.user_id = user.id;
+    entry.user_name = user.name;
+    entry.user_email = user.email;
+
+    let store = store::installed();
+    let mut recorded = store.recorded.get();
+    recorded.insert(0, entry.clone());
+    store.recorded.set(recorded);
+    let mut entries = store.entries.get();
+    entries.insert(0, entry.clone());
+    store.entries.set(entries);
+    store.calculate_stats();
+
+    if !backend::is_mock() {
+        spawn(async move {
+            let label = format!("Audit entry: {}", entry.summary());
+            let _ = backend::save_or_queue(HttpMethod::Post, "/audit/entries", &entry, &label, None).await;
+        });
+    }
+}
+
 /// Refresh audit data
 pub async fn refresh_audit_data(store: &AuditStore) {
     store.loading.set(true);
@@ -234,6 +267,9 @@ pub fn get_action_icon_class(action: &super::types::AuditActionType) -> &'static
         AuditActionType::Logout => "icon-logout",
         AuditActionType::Export => "icon-export",
         AuditActionType::Import => "icon-import",
+        AuditActionType::Delegate => "icon-delegate",
+        AuditActionType::Reassign => "icon-reassign",
+        AuditActionType::Escalate => "icon-escalate",
     }
 }
 
@@ -251,3 +287,23 @@ pub fn get_action_color_class(action: &super::types::AuditActionType) -> &'stati
         _ => "action-default",
     }
 }
+
+/// Refresh audit entries from the configured backend
+async fn load_from_backend(store: &AuditStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/audit/entries", &store.entries).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    } else {
+        store.total_count.set(store.entries.get().len());
+    }
+}
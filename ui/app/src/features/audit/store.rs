@@ -1,5 +1,6 @@
 //! Audit store
 
+use std::cell::RefCell;
 use components::prelude::*;
 use super::types::{
     AuditEntry, AuditFilter, AuditStats, AuditEntityType, AuditActionType, FieldChange,
@@ -17,6 +18,9 @@ pub struct AuditStore {
     pub page: Signal<usize>,
     pub page_size: Signal<usize>,
     pub total_count: Signal<usize>,
+    /// Entries recorded by this session, which the mock data keeps ahead of
+    /// its fixtures
+    pub recorded: Signal<Vec<AuditEntry>>,
 }
 
 impl AuditStore {
@@ -31,6 +35,7 @@ impl AuditStore {
             page: signal(1),
             page_size: signal(50),
             total_count: signal(0),
+            recorded: signal(Vec::new()),
         }
     }
 
@@ -224,6 +229,24 @@ impl AuditStore {
     }
 }
 
+thread_local! {
+    static STORE: RefCell<Option<AuditStore>> = RefCell::new(None);
+}
+
+/// Register the app's audit store so other features can record to it
+pub fn install(store: AuditStore) {
+    STORE.with(|s| *s.borrow_mut() = Some(store));
+}
+
+/// The installed audit store, or a fresh one outside the app component
+pub fn installed() -> AuditStore {
+    STORE.with(|s| s.borrow().clone()).unwrap_or_else(|| {
+        let store = AuditStore::new();
+        install(store.clone());
+        store
+    })
+}
+
 /// Load mock audit data for demo
 pub fn load_mock_data(store: &AuditStore) {
     let mock_entries = vec![
@@ -501,7 +524,9 @@ pub fn load_mock_data(store: &AuditStore) {
         },
     ];
 
-    store.entries.set(mock_entries);
+    let mut entries = store.recorded.get();
+    entries.extend(mock_entries);
+    store.entries.set(entries);
     store.calculate_stats();
     store.total_count.set(store.entries.get().len());
 }
@@ -1,6 +1,7 @@
 //! Audit domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::url::{QueryFilter, QueryParams};
 
 /// Entity types that can be audited
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -12,7 +13,10 @@ pub enum AuditEntityType {
     Contract,
     PurchaseOrder,
     GoodsReceipt,
+    Invoice,
+    PaymentRun,
     Supplier,
+    Budget,
     User,
     System,
 }
@@ -27,7 +31,10 @@ impl AuditEntityType {
             AuditEntityType::Contract => "Contract",
             AuditEntityType::PurchaseOrder => "Purchase Order",
             AuditEntityType::GoodsReceipt => "Goods Receipt",
+            AuditEntityType::Invoice => "Invoice",
+            AuditEntityType::PaymentRun => "Payment Run",
             AuditEntityType::Supplier => "Supplier",
+            AuditEntityType::Budget => "Budget",
             AuditEntityType::User => "User",
             AuditEntityType::System => "System",
         }
@@ -42,13 +49,21 @@ impl AuditEntityType {
             "contract" => AuditEntityType::Contract,
             "purchase_order" | "purchaseorder" | "purchase order" => AuditEntityType::PurchaseOrder,
             "goods_receipt" | "goodsreceipt" | "goods receipt" => AuditEntityType::GoodsReceipt,
+            "invoice" => AuditEntityType::Invoice,
+            "payment_run" | "paymentrun" | "payment run" => AuditEntityType::PaymentRun,
             "supplier" => AuditEntityType::Supplier,
+            "budget" => AuditEntityType::Budget,
             "user" => AuditEntityType::User,
             "system" => AuditEntityType::System,
             _ => AuditEntityType::System,
         }
     }
 
+    /// Filter / URL value, e.g. `purchase_order`
+    pub fn as_param(&self) -> String {
+        self.as_str().to_lowercase().replace(' ', "_")
+    }
+
     /// Get all entity types for filter dropdown
     pub fn all() -> Vec<AuditEntityType> {
         vec![
@@ -59,7 +74,10 @@ impl AuditEntityType {
             AuditEntityType::Contract,
             AuditEntityType::PurchaseOrder,
             AuditEntityType::GoodsReceipt,
+            AuditEntityType::Invoice,
+            AuditEntityType::PaymentRun,
             AuditEntityType::Supplier,
+            AuditEntityType::Budget,
             AuditEntityType::User,
             AuditEntityType::System,
         ]
@@ -87,6 +105,12 @@ pub enum AuditActionType {
     Logout,
     Export,
     Import,
+    /// Approval authority handed to another user
+    Delegate,
+    /// Pending approval step moved to another approver
+    Reassign,
+    /// Overdue approval step moved on to the next authority
+    Escalate,
 }
 
 impl AuditActionType {
@@ -104,6 +128,9 @@ impl AuditActionType {
             AuditActionType::Logout => "Logout",
             AuditActionType::Export => "Export",
             AuditActionType::Import => "Import",
+            AuditActionType::Delegate => "Delegate",
+            AuditActionType::Reassign => "Reassign",
+            AuditActionType::Escalate => "Escalate",
         }
     }
 
@@ -121,10 +148,18 @@ impl AuditActionType {
             "logout" => AuditActionType::Logout,
             "export" => AuditActionType::Export,
             "import" => AuditActionType::Import,
+            "delegate" => AuditActionType::Delegate,
+            "reassign" => AuditActionType::Reassign,
+            "escalate" => AuditActionType::Escalate,
             _ => AuditActionType::View,
         }
     }
 
+    /// Filter / URL value, e.g. `purchase_order`
+    pub fn as_param(&self) -> String {
+        self.as_str().to_lowercase().replace(' ', "_")
+    }
+
     /// Get all action types for filter dropdown
     pub fn all() -> Vec<AuditActionType> {
         vec![
@@ -140,6 +175,9 @@ impl AuditActionType {
             AuditActionType::Logout,
             AuditActionType::Export,
             AuditActionType::Import,
+            AuditActionType::Delegate,
+            AuditActionType::Reassign,
+            AuditActionType::Escalate,
         ]
     }
 }
@@ -244,6 +282,34 @@ pub struct AuditFilter {
     pub search: Option<String>,
 }
 
+impl QueryFilter for AuditFilter {
+    fn from_query(params: &QueryParams) -> Self {
+        Self {
+            entity_type: params.get("entity").map(|t| AuditEntityType::from_str(&t)),
+            action_type: params.get("action").map(|t| AuditActionType::from_str(&t)),
+            user_id: params.get("user_id"),
+            user_search: params.get("user"),
+            entity_id: params.get("entity_id"),
+            date_from: params.get("from"),
+            date_to: params.get("to"),
+            ip_address: params.get("ip"),
+            search: params.get("q"),
+        }
+    }
+
+    fn write_query(&self, params: &mut QueryParams) {
+        params.set("entity", self.entity_type.as_ref().map(|t| t.as_param()));
+        params.set("action", self.action_type.as_ref().map(|t| t.as_param()));
+        params.set("user_id", self.user_id.as_ref());
+        params.set("user", self.user_search.as_ref());
+        params.set("entity_id", self.entity_id.as_ref());
+        params.set("from", self.date_from.as_ref());
+        params.set("to", self.date_to.as_ref());
+        params.set("ip", self.ip_address.as_ref());
+        params.set("q", self.search.as_ref());
+    }
+}
+
 impl AuditFilter {
     pub fn is_empty(&self) -> bool {
         self.entity_type.is_none()
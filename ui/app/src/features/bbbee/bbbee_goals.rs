@@ -18,12 +18,14 @@ use crate::shared::charts::{
     trend_chart, TrendChartData,
 };
 use crate::util::format::{format_currency, format_number, format_percentage};
+use crate::util::money::Money;
 use super::store::BbbeeStore;
 use super::types::{
     BbbeeLevel, OwnershipClassification, TargetStatus, Trend,
     ComplianceStatus, VerificationStatus, EnterpriseSize,
 };
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// B-BBEE Goals Dashboard page
 #[component]
@@ -108,7 +110,7 @@ pub fn bbbee_goals() -> View {
         .map(|l| {
             BarChartData {
                 label: format!("L{}", l.level.to_u8().unwrap_or(0)),
-                value: l.spend_amount,
+                value: l.spend_amount.to_rands(),
                 color: Some(l.level.color().to_string()),
             }
         }).collect();
@@ -120,9 +122,9 @@ pub fn bbbee_goals() -> View {
             OwnershipClassification::BlackWomenOwned => "BWO".to_string(),
             OwnershipClassification::EME => "EME".to_string(),
             OwnershipClassification::QSE => "QSE".to_string(),
-            OwnershipClassification::YouthOwned => "Youth".to_string(),
-            OwnershipClassification::RuralTownship => "Rural".to_string(),
-            _ => "Other".to_string(),
+            OwnershipClassification::YouthOwned => t("Youth").to_string(),
+            OwnershipClassification::RuralTownship => t("Rural").to_string(),
+            _ => t("Other").to_string(),
         };
         BarChartData {
             label,
@@ -133,22 +135,22 @@ pub fn bbbee_goals() -> View {
 
     // Spend targets table columns
     let target_columns = vec![
-        DataTableColumn { key: "name".to_string(), label: "Target".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "target".to_string(), label: "Target".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "actual".to_string(), label: "Actual".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "variance".to_string(), label: "Variance".to_string(), width: Some("90px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "progress".to_string(), label: "Progress".to_string(), width: Some("150px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "name".to_string(), label: t("Target").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "target".to_string(), label: t("Target").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "actual".to_string(), label: t("Actual").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "variance".to_string(), label: t("Variance").to_string(), width: Some("90px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "progress".to_string(), label: t("Progress").to_string(), width: Some("150px".to_string()), align: None, cell_class: None },
     ];
 
     // Transform targets to table rows
     let target_rows: Vec<DataTableRow> = targets.iter().map(|target| {
         let status_tag = match target.status {
-            TargetStatus::Exceeding => tag("Exceeding".to_string(), TagType::Green),
-            TargetStatus::OnTrack => tag("On Track".to_string(), TagType::Blue),
-            TargetStatus::AtRisk => tag("At Risk".to_string(), TagType::Orange),
-            TargetStatus::BelowTarget => tag("Below".to_string(), TagType::Red),
-            TargetStatus::NotApplicable => tag("N/A".to_string(), TagType::Default),
+            TargetStatus::Exceeding => tag(t("Exceeding").to_string(), TagType::Green),
+            TargetStatus::OnTrack => tag(t("On Track").to_string(), TagType::Blue),
+            TargetStatus::AtRisk => tag(t("At Risk").to_string(), TagType::Orange),
+            TargetStatus::BelowTarget => tag(t("Below").to_string(), TagType::Red),
+            TargetStatus::NotApplicable => tag(t("N/A").to_string(), TagType::Default),
         };
 
         let variance_class = if target.variance >= 0.0 { "positive" } else { "negative" };
@@ -188,12 +190,12 @@ pub fn bbbee_goals() -> View {
 
     // Level breakdown table columns
     let level_columns = vec![
-        DataTableColumn { key: "level".to_string(), label: "B-BBEE Level".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "suppliers".to_string(), label: "Suppliers".to_string(), width: Some("90px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "spend".to_string(), label: "Spend".to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "percent".to_string(), label: "% of Total".to_string(), width: Some("90px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "recognition".to_string(), label: "Recognition".to_string(), width: Some("100px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "recognized".to_string(), label: "Recognized Spend".to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "level".to_string(), label: t("B-BBEE Level").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "suppliers".to_string(), label: t("Suppliers").to_string(), width: Some("90px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "spend".to_string(), label: t("Spend").to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "percent".to_string(), label: t("% of Total").to_string(), width: Some("90px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "recognition".to_string(), label: t("Recognition").to_string(), width: Some("100px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "recognized".to_string(), label: t("Recognized Spend").to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
     ];
 
     // Transform level breakdown to table rows
@@ -221,12 +223,12 @@ pub fn bbbee_goals() -> View {
 
     // Supplier table columns
     let supplier_columns = vec![
-        DataTableColumn { key: "name".to_string(), label: "Supplier".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "level".to_string(), label: "B-BBEE".to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "ownership".to_string(), label: "Ownership".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "size".to_string(), label: "Size".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "spend".to_string(), label: "Spend".to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "status".to_string(), label: "Verified".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "name".to_string(), label: t("Supplier").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "level".to_string(), label: t("B-BBEE").to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "ownership".to_string(), label: t("Ownership").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "size".to_string(), label: t("Size").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "spend".to_string(), label: t("Spend").to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "status".to_string(), label: t("Verified").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
     ];
 
     // Transform suppliers to table rows
@@ -249,7 +251,7 @@ pub fn bbbee_goals() -> View {
         let size_tag = match sup.enterprise_size {
             EnterpriseSize::EME => tag("EME".to_string(), TagType::Purple),
             EnterpriseSize::QSE => tag("QSE".to_string(), TagType::Cyan),
-            EnterpriseSize::Generic => tag("Generic".to_string(), TagType::Default),
+            EnterpriseSize::Generic => tag(t("Generic").to_string(), TagType::Default),
         };
 
         let status = match sup.verification_status {
@@ -280,22 +282,22 @@ pub fn bbbee_goals() -> View {
 
     // Compliance metrics table columns
     let metric_columns = vec![
-        DataTableColumn { key: "name".to_string(), label: "Metric".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "element".to_string(), label: "Element".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "target".to_string(), label: "Target".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "actual".to_string(), label: "Actual".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "points".to_string(), label: "Points".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "name".to_string(), label: t("Metric").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "element".to_string(), label: t("Element").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "target".to_string(), label: t("Target").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "actual".to_string(), label: t("Actual").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "points".to_string(), label: t("Points").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
     ];
 
     // Transform metrics to table rows
     let metric_rows: Vec<DataTableRow> = metrics.iter().map(|metric| {
         let status_tag = match metric.status {
-            ComplianceStatus::Compliant => tag("Compliant".to_string(), TagType::Green),
-            ComplianceStatus::PartiallyCompliant => tag("Partial".to_string(), TagType::Orange),
-            ComplianceStatus::NonCompliant => tag("Non-Compliant".to_string(), TagType::Red),
-            ComplianceStatus::PendingReview => tag("Pending".to_string(), TagType::Blue),
-            ComplianceStatus::NotApplicable => tag("N/A".to_string(), TagType::Default),
+            ComplianceStatus::Compliant => tag(t("Compliant").to_string(), TagType::Green),
+            ComplianceStatus::PartiallyCompliant => tag(t("Partial").to_string(), TagType::Orange),
+            ComplianceStatus::NonCompliant => tag(t("Non-Compliant").to_string(), TagType::Red),
+            ComplianceStatus::PendingReview => tag(t("Pending").to_string(), TagType::Blue),
+            ComplianceStatus::NotApplicable => tag(t("N/A").to_string(), TagType::Default),
         };
 
         let points_color = if metric.points_achieved >= metric.weighting_points * 0.9 {
@@ -339,7 +341,7 @@ pub fn bbbee_goals() -> View {
     let icon_trending = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><polyline points="23 6 13.5 15.5 8.5 10.5 1 18"/><polyline points="17 6 23 6 23 12"/></svg>"#;
 
     // Pre-compute values for type inference in view! macro
-    let level_1_2_spend: f64 = kpi_data.total_bbbee_spend * kpi_data.level_1_2_percent / 100.0;
+    let level_1_2_spend: Money = kpi_data.total_bbbee_spend.percent(kpi_data.level_1_2_percent);
     let scorecard_percentage: f64 = if kpi_data.max_scorecard_points > 0.0 {
         kpi_data.scorecard_points / kpi_data.max_scorecard_points * 100.0
     } else {
@@ -393,7 +395,7 @@ pub fn bbbee_goals() -> View {
     let expiring_certs_display = kpi_data.expiring_certificates.to_string();
     let projected_level_display = kpi_data.projected_level.label();
     let scorecard_points_display = format!("{:.1}", kpi_data.scorecard_points);
-    let scorecard_max_display = format!("of {:.0} points", kpi_data.max_scorecard_points);
+    let scorecard_max_display = tf("of {points} points", &[("points", format!("{:.0}", kpi_data.max_scorecard_points))]);
     let compliant_supplier_display = format_number(kpi_data.compliant_supplier_count);
     let non_compliant_display = kpi_data.non_compliant_supplier_count.to_string();
     let has_non_compliant_suppliers = kpi_data.non_compliant_supplier_count > 0;
@@ -414,7 +416,7 @@ pub fn bbbee_goals() -> View {
         (
             prov.province.clone(),
             format_percentage(prov.bbbee_percentage, 0),
-            format!("{} suppliers", prov.supplier_count),
+            tn("{n} supplier", "{n} suppliers", prov.supplier_count as i64),
             format_currency(prov.total_spend),
         )
     }).collect();
@@ -658,11 +660,11 @@ pub fn bbbee_goals() -> View {
 
         <div class="bbbee-goals" data-testid="bbbee-goals">
             {page_header(
-                "B-BBEE Goals".to_string(),
-                Some("Broad-Based Black Economic Empowerment compliance tracking for FY 2025/26".to_string()),
+                t("B-BBEE Goals").to_string(),
+                Some(t("Broad-Based Black Economic Empowerment compliance tracking for FY 2025/26").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export DTI Report"</button> },
-                    view! { <button class="btn btn-secondary">"Export Excel"</button> },
+                    view! { <button class="btn btn-secondary">{t("Export DTI Report")}</button> },
+                    view! { <button class="btn btn-secondary">{t("Export Excel")}</button> },
                 ]
             )}
 
@@ -672,9 +674,9 @@ pub fn bbbee_goals() -> View {
                     <span class="icon" inner_html={icon_alert}></span>
                     <span class="message">
                         <strong>{expiring_certs_display.clone()}</strong>
-                        " supplier B-BBEE certificates expiring within 90 days"
+                        {t(" supplier B-BBEE certificates expiring within 90 days")}
                     </span>
-                    <button class="action">"View List"</button>
+                    <button class="action">{t("View List")}</button>
                 </div>
             }
 
@@ -682,11 +684,11 @@ pub fn bbbee_goals() -> View {
             <div class="scorecard-summary">
                 <div class="scorecard-level">
                     <span class="level-badge">{projected_level_display.clone()}</span>
-                    <span class="level-label">"Projected Level"</span>
+                    <span class="level-label">{t("Projected Level")}</span>
                 </div>
                 <div class="scorecard-info">
-                    <span class="scorecard-title">"B-BBEE Preferential Procurement Scorecard"</span>
-                    <span class="scorecard-subtitle">"Enterprise & Supplier Development Element - Code 400"</span>
+                    <span class="scorecard-title">{t("B-BBEE Preferential Procurement Scorecard")}</span>
+                    <span class="scorecard-subtitle">{t("Enterprise & Supplier Development Element - Code 400")}</span>
                 </div>
                 <div class="scorecard-points">
                     <span class="points-value">{scorecard_points_display.clone()}</span>
@@ -699,19 +701,19 @@ pub fn bbbee_goals() -> View {
                 <button
                     class={if active_tab.get() == "overview" { "tab-button active" } else { "tab-button" }}
                     on:click={set_tab_overview}
-                >"Overview"</button>
+                >{t("Overview")}</button>
                 <button
                     class={if active_tab.get() == "targets" { "tab-button active" } else { "tab-button" }}
                     on:click={set_tab_targets}
-                >"Spend Targets"</button>
+                >{t("Spend Targets")}</button>
                 <button
                     class={if active_tab.get() == "suppliers" { "tab-button active" } else { "tab-button" }}
                     on:click={set_tab_suppliers}
-                >"Suppliers"</button>
+                >{t("Suppliers")}</button>
                 <button
                     class={if active_tab.get() == "metrics" { "tab-button active" } else { "tab-button" }}
                     on:click={set_tab_metrics}
-                >"Compliance Metrics"</button>
+                >{t("Compliance Metrics")}</button>
             </div>
 
             // Overview tab content
@@ -720,31 +722,31 @@ pub fn bbbee_goals() -> View {
                     // KPI Row
                     <div class="kpi-grid">
                         {kpi_card(
-                            "B-BBEE Spend".to_string(),
+                            t("B-BBEE Spend").to_string(),
                             kpi_overall_bbbee_pct.clone(),
                             kpi_overall_color,
                             icon_target.to_string(),
                             Some(KpiDelta {
                                 value: kpi_target_bbbee_pct.clone(),
                                 is_positive: None,
-                                suffix: "target".to_string(),
+                                suffix: t("target").to_string(),
                             }),
                             None
                         )}
                         {kpi_card(
-                            "Compliant Suppliers".to_string(),
+                            t("Compliant Suppliers").to_string(),
                             compliant_supplier_display.clone(),
                             KpiColor::Blue,
                             icon_users.to_string(),
                             Some(KpiDelta {
                                 value: non_compliant_display.clone(),
                                 is_positive: if has_non_compliant_suppliers { Some(false) } else { None },
-                                suffix: "non-compliant".to_string(),
+                                suffix: t("non-compliant").to_string(),
                             }),
                             None
                         )}
                         {kpi_card(
-                            "Level 1-2 Spend".to_string(),
+                            t("Level 1-2 Spend").to_string(),
                             kpi_level_1_2_pct.clone(),
                             KpiColor::Green,
                             icon_star.to_string(),
@@ -756,14 +758,14 @@ pub fn bbbee_goals() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Black Owned Spend".to_string(),
+                            t("Black Owned Spend").to_string(),
                             kpi_black_owned_pct.clone(),
                             kpi_black_owned_color,
                             icon_chart.to_string(),
                             Some(KpiDelta {
                                 value: "40%".to_string(),
                                 is_positive: None,
-                                suffix: "target".to_string(),
+                                suffix: t("target").to_string(),
                             }),
                             None
                         )}
@@ -772,50 +774,50 @@ pub fn bbbee_goals() -> View {
                     // Second KPI Row
                     <div class="kpi-grid">
                         {kpi_card(
-                            "Black Women Owned".to_string(),
+                            t("Black Women Owned").to_string(),
                             kpi_black_women_pct.clone(),
                             kpi_black_women_color,
                             icon_users.to_string(),
                             Some(KpiDelta {
                                 value: "12%".to_string(),
                                 is_positive: Some(kpi_black_women_positive),
-                                suffix: "target".to_string(),
+                                suffix: t("target").to_string(),
                             }),
                             None
                         )}
                         {kpi_card(
-                            "EME/QSE Spend".to_string(),
+                            t("EME/QSE Spend").to_string(),
                             kpi_eme_qse_pct.clone(),
                             kpi_eme_qse_color,
                             icon_trending.to_string(),
                             Some(KpiDelta {
                                 value: "30%".to_string(),
                                 is_positive: None,
-                                suffix: "target".to_string(),
+                                suffix: t("target").to_string(),
                             }),
                             None
                         )}
                         {kpi_card(
-                            "Designated Groups".to_string(),
+                            t("Designated Groups").to_string(),
                             kpi_designated_group_pct.clone(),
                             KpiColor::Accent,
                             icon_shield.to_string(),
                             Some(KpiDelta {
                                 value: "2%".to_string(),
                                 is_positive: Some(kpi_designated_positive),
-                                suffix: "target".to_string(),
+                                suffix: t("target").to_string(),
                             }),
                             None
                         )}
                         {kpi_card(
-                            "Recognized Spend".to_string(),
+                            t("Recognized Spend").to_string(),
                             kpi_recognized_spend.clone(),
                             KpiColor::Purple,
                             icon_check.to_string(),
                             Some(KpiDelta {
                                 value: kpi_spend_bonus.clone(),
                                 is_positive: Some(true),
-                                suffix: "bonus".to_string(),
+                                suffix: t("bonus").to_string(),
                             }),
                             None
                         )}
@@ -824,13 +826,13 @@ pub fn bbbee_goals() -> View {
                     // Charts Row
                     <div class="charts-grid">
                         {panel(
-                            "B-BBEE Spend Trend".to_string(),
+                            t("B-BBEE Spend Trend").to_string(),
                             vec![],
                             vec![
                                 view! {
                                     <div class="target-values" style="margin-bottom: 12px;">
-                                        <span class="target">"Target: "{kpi_target_80_pct.clone()}</span>
-                                        <span class="actual">"Current: "{kpi_overall_bbbee_pct.clone()}</span>
+                                        <span class="target">{tf("Target: {value}", &[("value", kpi_target_80_pct.clone())])}</span>
+                                        <span class="actual">{tf("Current: {value}", &[("value", kpi_overall_bbbee_pct.clone())])}</span>
                                     </div>
                                 },
                                 trend_chart(spend_trend, chart_height, Some("var(--green)".to_string())),
@@ -838,7 +840,7 @@ pub fn bbbee_goals() -> View {
                         )}
 
                         {panel(
-                            "Spend by B-BBEE Level".to_string(),
+                            t("Spend by B-BBEE Level").to_string(),
                             vec![],
                             vec![pie_chart(level_pie.clone(), None)]
                         )}
@@ -847,13 +849,13 @@ pub fn bbbee_goals() -> View {
                     // Second Charts Row
                     <div class="charts-grid">
                         {panel(
-                            "Level Distribution".to_string(),
-                            vec![view! { <a href="#" class="btn btn-sm btn-secondary">"View Details"</a> }],
+                            t("Level Distribution").to_string(),
+                            vec![view! { <a href="#" class="btn btn-sm btn-secondary">{t("View Details")}</a> }],
                             vec![bar_chart(level_bars, chart_height)]
                         )}
 
                         {panel(
-                            "Designated Group Spend (%)".to_string(),
+                            t("Designated Group Spend (%)").to_string(),
                             vec![],
                             vec![bar_chart(designated_bars, chart_height)]
                         )}
@@ -861,7 +863,7 @@ pub fn bbbee_goals() -> View {
 
                     // Provincial Distribution
                     {panel(
-                        "Provincial B-BBEE Distribution".to_string(),
+                        t("Provincial B-BBEE Distribution").to_string(),
                         vec![],
                         vec![view! {
                             <div class="provincial-list">
@@ -891,16 +893,16 @@ pub fn bbbee_goals() -> View {
                                 <div class="target-header">
                                     <span class="target-title">{name.clone()}</span>
                                     {match status {
-                                        TargetStatus::Exceeding => tag("Exceeding".to_string(), TagType::Green),
-                                        TargetStatus::OnTrack => tag("On Track".to_string(), TagType::Blue),
-                                        TargetStatus::AtRisk => tag("At Risk".to_string(), TagType::Orange),
-                                        TargetStatus::BelowTarget => tag("Below".to_string(), TagType::Red),
-                                        TargetStatus::NotApplicable => tag("N/A".to_string(), TagType::Default),
+                                        TargetStatus::Exceeding => tag(t("Exceeding").to_string(), TagType::Green),
+                                        TargetStatus::OnTrack => tag(t("On Track").to_string(), TagType::Blue),
+                                        TargetStatus::AtRisk => tag(t("At Risk").to_string(), TagType::Orange),
+                                        TargetStatus::BelowTarget => tag(t("Below").to_string(), TagType::Red),
+                                        TargetStatus::NotApplicable => tag(t("N/A").to_string(), TagType::Default),
                                     }}
                                 </div>
                                 <div class="target-values">
-                                    <span class="target">"Target: "{target_pct_display.clone()}</span>
-                                    <span class="actual">"Actual: "{actual_pct_display.clone()}</span>
+                                    <span class="target">{tf("Target: {value}", &[("value", target_pct_display.clone())])}</span>
+                                    <span class="actual">{tf("Actual: {value}", &[("value", actual_pct_display.clone())])}</span>
                                 </div>
                                 {progress_bar(
                                     *progress,
@@ -919,11 +921,11 @@ pub fn bbbee_goals() -> View {
 
                     // Full targets table
                     {panel(
-                        "All Spend Targets".to_string(),
-                        vec![view! { <button class="btn btn-sm btn-primary">"Add Target"</button> }],
+                        t("All Spend Targets").to_string(),
+                        vec![view! { <button class="btn btn-sm btn-primary">{t("Add Target")}</button> }],
                         vec![
                             if loading.get() {
-                                view! { <div class="loading-overlay">"Loading targets..."</div> }
+                                view! { <div class="loading-overlay">{t("Loading targets...")}</div> }
                             } else {
                                 view! { <div>{data_table(target_columns.clone(), target_rows.clone(), None)}</div> }
                             }
@@ -932,11 +934,11 @@ pub fn bbbee_goals() -> View {
 
                     // Level breakdown table
                     {panel(
-                        "B-BBEE Level Breakdown".to_string(),
+                        t("B-BBEE Level Breakdown").to_string(),
                         vec![],
                         vec![
                             if loading.get() {
-                                view! { <div class="loading-overlay">"Loading level data..."</div> }
+                                view! { <div class="loading-overlay">{t("Loading level data...")}</div> }
                             } else {
                                 view! { <div>{data_table(level_columns.clone(), level_rows.clone(), None)}</div> }
                             }
@@ -951,7 +953,7 @@ pub fn bbbee_goals() -> View {
                     // Supplier KPIs
                     <div class="kpi-grid">
                         {kpi_card(
-                            "Total Suppliers".to_string(),
+                            t("Total Suppliers").to_string(),
                             format_number(total_supplier_count),
                             KpiColor::Blue,
                             icon_users.to_string(),
@@ -959,7 +961,7 @@ pub fn bbbee_goals() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Level 1-2".to_string(),
+                            t("Level 1-2").to_string(),
                             level_1_2_supplier_count.to_string(),
                             KpiColor::Green,
                             icon_star.to_string(),
@@ -967,7 +969,7 @@ pub fn bbbee_goals() -> View {
                             None
                         )}
                         {kpi_card(
-                            "EME Suppliers".to_string(),
+                            t("EME Suppliers").to_string(),
                             eme_supplier_count.to_string(),
                             KpiColor::Purple,
                             icon_trending.to_string(),
@@ -975,7 +977,7 @@ pub fn bbbee_goals() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Expiring Certs".to_string(),
+                            t("Expiring Certs").to_string(),
                             expiring_certs_count.to_string(),
                             if expiring_certs_count > 0 { KpiColor::Orange } else { KpiColor::Green },
                             icon_alert.to_string(),
@@ -986,14 +988,14 @@ pub fn bbbee_goals() -> View {
 
                     // Suppliers table
                     {panel(
-                        format!("B-BBEE Supplier Classifications ({} total)", suppliers.len()),
+                        tf("B-BBEE Supplier Classifications ({count} total)", &[("count", suppliers.len().to_string())]),
                         vec![
-                            view! { <button class="btn btn-sm btn-secondary">"Filter"</button> },
-                            view! { <button class="btn btn-sm btn-primary">"Export"</button> },
+                            view! { <button class="btn btn-sm btn-secondary">{t("Filter")}</button> },
+                            view! { <button class="btn btn-sm btn-primary">{t("Export")}</button> },
                         ],
                         vec![
                             if loading.get() {
-                                view! { <div class="loading-overlay">"Loading suppliers..."</div> }
+                                view! { <div class="loading-overlay">{t("Loading suppliers...")}</div> }
                             } else {
                                 view! { <div>{data_table(supplier_columns.clone(), supplier_rows.clone(), None)}</div> }
                             }
@@ -1008,19 +1010,19 @@ pub fn bbbee_goals() -> View {
                     // Metrics summary
                     <div class="kpi-grid">
                         {kpi_card(
-                            "Scorecard Points".to_string(),
+                            t("Scorecard Points").to_string(),
                             metrics_scorecard_display.clone(),
                             metrics_scorecard_color,
                             icon_star.to_string(),
                             Some(KpiDelta {
                                 value: metrics_scorecard_pct.clone(),
                                 is_positive: None,
-                                suffix: "achieved".to_string(),
+                                suffix: t("achieved").to_string(),
                             }),
                             None
                         )}
                         {kpi_card(
-                            "Compliant Metrics".to_string(),
+                            t("Compliant Metrics").to_string(),
                             compliant_metrics_count.to_string(),
                             KpiColor::Green,
                             icon_check.to_string(),
@@ -1028,7 +1030,7 @@ pub fn bbbee_goals() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Partial Compliance".to_string(),
+                            t("Partial Compliance").to_string(),
                             partial_metrics_count.to_string(),
                             KpiColor::Orange,
                             icon_alert.to_string(),
@@ -1036,7 +1038,7 @@ pub fn bbbee_goals() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Non-Compliant".to_string(),
+                            t("Non-Compliant").to_string(),
                             non_compliant_metrics_count.to_string(),
                             if has_non_compliant { KpiColor::Red } else { KpiColor::Green },
                             icon_shield.to_string(),
@@ -1047,11 +1049,11 @@ pub fn bbbee_goals() -> View {
 
                     // Compliance metrics table
                     {panel(
-                        "B-BBEE Compliance Metrics".to_string(),
-                        vec![view! { <button class="btn btn-sm btn-primary">"Run Assessment"</button> }],
+                        t("B-BBEE Compliance Metrics").to_string(),
+                        vec![view! { <button class="btn btn-sm btn-primary">{t("Run Assessment")}</button> }],
                         vec![
                             if loading.get() {
-                                view! { <div class="loading-overlay">"Loading metrics..."</div> }
+                                view! { <div class="loading-overlay">{t("Loading metrics...")}</div> }
                             } else {
                                 view! { <div>{data_table(metric_columns.clone(), metric_rows.clone(), None)}</div> }
                             }
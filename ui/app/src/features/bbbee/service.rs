@@ -1,5 +1,8 @@
 //! B-BBEE service - API calls for B-BBEE compliance management
 
+use crate::util::api::ApiError;
+use crate::util::backend;
+use crate::util::money::Money;
 use super::store::{BbbeeStore, load_mock_data};
 use super::types::{
     BbbeeLevel, OwnershipClassification, EnterpriseSize, BbbeeFilter,
@@ -11,9 +14,7 @@ pub async fn load_bbbee_data(store: &BbbeeStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API:
-    // GET /api/v1/bbbee/dashboard
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -23,8 +24,7 @@ pub async fn load_spend_targets(store: &BbbeeStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production: GET /api/v1/bbbee/spend-targets?year={}&category={}
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -34,8 +34,7 @@ pub async fn load_level_breakdown(store: &BbbeeStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production: GET /api/v1/bbbee/level-breakdown
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -45,8 +44,7 @@ pub async fn load_compliance_metrics(store: &BbbeeStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production: GET /api/v1/bbbee/compliance-metrics
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -58,9 +56,8 @@ pub async fn load_supplier_classifications(store: &BbbeeStore) {
 
     let filter = store.filter.get();
 
-    // In production: GET /api/v1/bbbee/suppliers?level={}&ownership={}&size={}
     // Build query params from filter
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -70,8 +67,7 @@ pub async fn load_trend_data(store: &BbbeeStore, financial_year: &str) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production: GET /api/v1/bbbee/trends?year={}
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -81,8 +77,7 @@ pub async fn load_designated_group_spend(store: &BbbeeStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production: GET /api/v1/bbbee/designated-groups
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -92,8 +87,7 @@ pub async fn load_provincial_distribution(store: &BbbeeStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production: GET /api/v1/bbbee/provincial-distribution
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -105,9 +99,7 @@ pub async fn load_with_filter(store: &BbbeeStore, filter: &BbbeeFilter) {
 
     store.filter.set(filter.clone());
 
-    // In production, construct query params from filter:
-    // GET /api/v1/bbbee/dashboard?year={}&level={}&ownership={}&province={}&size={}
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -320,8 +312,8 @@ pub async fn get_expiring_certificates(
 }
 
 /// Calculate recognized spend for a supplier
-pub fn calculate_recognized_spend(spend: f64, level: BbbeeLevel) -> f64 {
-    spend * (level.recognition_level() / 100.0)
+pub fn calculate_recognized_spend(spend: Money, level: BbbeeLevel) -> Money {
+    spend.percent(level.recognition_level())
 }
 
 /// Check if supplier meets preferential procurement criteria
@@ -346,3 +338,28 @@ pub fn meets_preferential_criteria(
 
     level_ok && black_owned_ok && black_women_ok
 }
+
+/// Refresh B-BBEE data from the configured backend
+async fn load_from_backend(store: &BbbeeStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/bbbee/spend-targets", &store.spend_targets).await?;
+        backend::fetch_into("/bbbee/level-breakdown", &store.level_breakdown).await?;
+        backend::fetch_into("/bbbee/compliance-metrics", &store.compliance_metrics).await?;
+        backend::fetch_into("/bbbee/suppliers", &store.supplier_classifications).await?;
+        backend::fetch_into("/bbbee/trends", &store.trend_data).await?;
+        backend::fetch_into("/bbbee/designated-groups", &store.designated_group_spend).await?;
+        backend::fetch_into("/bbbee/provincial-distribution", &store.provincial_distribution).await?;
+        backend::fetch_into("/bbbee/kpis", &store.kpis).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    }
+}
@@ -1,6 +1,7 @@
 //! B-BBEE store - state management for B-BBEE compliance tracking
 
 use components::prelude::*;
+use crate::util::money::Money;
 use super::types::{
     SpendTarget, SubTarget, LevelBreakdown, ComplianceMetric, SupplierClassification,
     BbbeeKpis, BbbeeFilter, BbbeeTrendPoint, DesignatedGroupSpend, ProvincialDistribution,
@@ -59,7 +60,7 @@ impl BbbeeStore {
 /// Load mock B-BBEE data for demo
 pub fn load_mock_data(store: &BbbeeStore) {
     // Total measurable procurement spend
-    let total_mpsp = 847_000_000.0;
+    let total_mpsp = Money::rands(847_000_000);
 
     // Spend targets
     let spend_targets = vec![
@@ -69,8 +70,8 @@ pub fn load_mock_data(store: &BbbeeStore) {
             description: "Total procurement spend with B-BBEE compliant suppliers".to_string(),
             target_percentage: 80.0,
             actual_percentage: 78.4,
-            target_amount: 677_600_000.0,
-            actual_amount: 664_048_000.0,
+            target_amount: Money::rands(677_600_000),
+            actual_amount: Money::rands(664_048_000),
             total_spend: total_mpsp,
             category: "Overall".to_string(),
             financial_year: "FY 2025/26".to_string(),
@@ -79,14 +80,14 @@ pub fn load_mock_data(store: &BbbeeStore) {
                     name: "Level 1-2 Suppliers".to_string(),
                     target_percentage: 40.0,
                     actual_percentage: 42.0,
-                    actual_amount: 355_740_000.0,
+                    actual_amount: Money::rands(355_740_000),
                     classification: None,
                 },
                 SubTarget {
                     name: "Level 3-4 Suppliers".to_string(),
                     target_percentage: 25.0,
                     actual_percentage: 23.5,
-                    actual_amount: 199_045_000.0,
+                    actual_amount: Money::rands(199_045_000),
                     classification: None,
                 },
             ],
@@ -101,8 +102,8 @@ pub fn load_mock_data(store: &BbbeeStore) {
             description: "Spend with 51%+ Black owned enterprises".to_string(),
             target_percentage: 40.0,
             actual_percentage: 38.5,
-            target_amount: 338_800_000.0,
-            actual_amount: 326_095_000.0,
+            target_amount: Money::rands(338_800_000),
+            actual_amount: Money::rands(326_095_000),
             total_spend: total_mpsp,
             category: "Ownership".to_string(),
             financial_year: "FY 2025/26".to_string(),
@@ -118,8 +119,8 @@ pub fn load_mock_data(store: &BbbeeStore) {
             description: "Spend with 30%+ Black Women owned enterprises".to_string(),
             target_percentage: 12.0,
             actual_percentage: 14.2,
-            target_amount: 101_640_000.0,
-            actual_amount: 120_274_000.0,
+            target_amount: Money::rands(101_640_000),
+            actual_amount: Money::rands(120_274_000),
             total_spend: total_mpsp,
             category: "Ownership".to_string(),
             financial_year: "FY 2025/26".to_string(),
@@ -135,8 +136,8 @@ pub fn load_mock_data(store: &BbbeeStore) {
             description: "Spend with Exempted Micro Enterprises and Qualifying Small Enterprises".to_string(),
             target_percentage: 30.0,
             actual_percentage: 28.8,
-            target_amount: 254_100_000.0,
-            actual_amount: 243_936_000.0,
+            target_amount: Money::rands(254_100_000),
+            actual_amount: Money::rands(243_936_000),
             total_spend: total_mpsp,
             category: "Enterprise Size".to_string(),
             financial_year: "FY 2025/26".to_string(),
@@ -145,14 +146,14 @@ pub fn load_mock_data(store: &BbbeeStore) {
                     name: "EME Spend".to_string(),
                     target_percentage: 15.0,
                     actual_percentage: 12.5,
-                    actual_amount: 105_875_000.0,
+                    actual_amount: Money::rands(105_875_000),
                     classification: Some(OwnershipClassification::EME),
                 },
                 SubTarget {
                     name: "QSE Spend".to_string(),
                     target_percentage: 15.0,
                     actual_percentage: 16.3,
-                    actual_amount: 138_061_000.0,
+                    actual_amount: Money::rands(138_061_000),
                     classification: Some(OwnershipClassification::QSE),
                 },
             ],
@@ -167,8 +168,8 @@ pub fn load_mock_data(store: &BbbeeStore) {
             description: "Spend with youth, disabled, military veteran, and rural/township suppliers".to_string(),
             target_percentage: 2.0,
             actual_percentage: 2.8,
-            target_amount: 16_940_000.0,
-            actual_amount: 23_716_000.0,
+            target_amount: Money::rands(16_940_000),
+            actual_amount: Money::rands(23_716_000),
             total_spend: total_mpsp,
             category: "Designated Groups".to_string(),
             financial_year: "FY 2025/26".to_string(),
@@ -177,28 +178,28 @@ pub fn load_mock_data(store: &BbbeeStore) {
                     name: "Youth Owned".to_string(),
                     target_percentage: 0.5,
                     actual_percentage: 0.8,
-                    actual_amount: 6_776_000.0,
+                    actual_amount: Money::rands(6_776_000),
                     classification: Some(OwnershipClassification::YouthOwned),
                 },
                 SubTarget {
                     name: "Township/Rural".to_string(),
                     target_percentage: 1.0,
                     actual_percentage: 1.5,
-                    actual_amount: 12_705_000.0,
+                    actual_amount: Money::rands(12_705_000),
                     classification: Some(OwnershipClassification::RuralTownship),
                 },
                 SubTarget {
                     name: "Disability Owned".to_string(),
                     target_percentage: 0.3,
                     actual_percentage: 0.3,
-                    actual_amount: 2_541_000.0,
+                    actual_amount: Money::rands(2_541_000),
                     classification: Some(OwnershipClassification::DisabilityOwned),
                 },
                 SubTarget {
                     name: "Military Veteran".to_string(),
                     target_percentage: 0.2,
                     actual_percentage: 0.2,
-                    actual_amount: 1_694_000.0,
+                    actual_amount: Money::rands(1_694_000),
                     classification: Some(OwnershipClassification::MilitaryVeteranOwned),
                 },
             ],
@@ -214,81 +215,81 @@ pub fn load_mock_data(store: &BbbeeStore) {
         LevelBreakdown {
             level: BbbeeLevel::Level1,
             supplier_count: 485,
-            spend_amount: 356_000_000.0,
+            spend_amount: Money::rands(356_000_000),
             spend_percentage: 42.0,
-            recognized_spend: 480_600_000.0, // 135% recognition
+            recognized_spend: Money::rands(480_600_000), // 135% recognition
             contract_count: 156,
             avg_performance: 91.2,
         },
         LevelBreakdown {
             level: BbbeeLevel::Level2,
             supplier_count: 312,
-            spend_amount: 237_000_000.0,
+            spend_amount: Money::rands(237_000_000),
             spend_percentage: 28.0,
-            recognized_spend: 296_250_000.0, // 125% recognition
+            recognized_spend: Money::rands(296_250_000), // 125% recognition
             contract_count: 98,
             avg_performance: 88.5,
         },
         LevelBreakdown {
             level: BbbeeLevel::Level3,
             supplier_count: 124,
-            spend_amount: 95_000_000.0,
+            spend_amount: Money::rands(95_000_000),
             spend_percentage: 11.2,
-            recognized_spend: 104_500_000.0, // 110% recognition
+            recognized_spend: Money::rands(104_500_000), // 110% recognition
             contract_count: 45,
             avg_performance: 85.3,
         },
         LevelBreakdown {
             level: BbbeeLevel::Level4,
             supplier_count: 87,
-            spend_amount: 57_500_000.0,
+            spend_amount: Money::rands(57_500_000),
             spend_percentage: 6.8,
-            recognized_spend: 57_500_000.0, // 100% recognition
+            recognized_spend: Money::rands(57_500_000), // 100% recognition
             contract_count: 32,
             avg_performance: 82.1,
         },
         LevelBreakdown {
             level: BbbeeLevel::Level5,
             supplier_count: 45,
-            spend_amount: 28_000_000.0,
+            spend_amount: Money::rands(28_000_000),
             spend_percentage: 3.3,
-            recognized_spend: 22_400_000.0, // 80% recognition
+            recognized_spend: Money::rands(22_400_000), // 80% recognition
             contract_count: 18,
             avg_performance: 78.5,
         },
         LevelBreakdown {
             level: BbbeeLevel::Level6,
             supplier_count: 32,
-            spend_amount: 15_000_000.0,
+            spend_amount: Money::rands(15_000_000),
             spend_percentage: 1.8,
-            recognized_spend: 9_000_000.0, // 60% recognition
+            recognized_spend: Money::rands(9_000_000), // 60% recognition
             contract_count: 12,
             avg_performance: 75.2,
         },
         LevelBreakdown {
             level: BbbeeLevel::Level7,
             supplier_count: 18,
-            spend_amount: 5_800_000.0,
+            spend_amount: Money::rands(5_800_000),
             spend_percentage: 0.7,
-            recognized_spend: 2_900_000.0, // 50% recognition
+            recognized_spend: Money::rands(2_900_000), // 50% recognition
             contract_count: 8,
             avg_performance: 72.8,
         },
         LevelBreakdown {
             level: BbbeeLevel::Level8,
             supplier_count: 12,
-            spend_amount: 2_000_000.0,
+            spend_amount: Money::rands(2_000_000),
             spend_percentage: 0.2,
-            recognized_spend: 200_000.0, // 10% recognition
+            recognized_spend: Money::rands(200_000), // 10% recognition
             contract_count: 4,
             avg_performance: 70.5,
         },
         LevelBreakdown {
             level: BbbeeLevel::NonCompliant,
             supplier_count: 78,
-            spend_amount: 50_700_000.0,
+            spend_amount: Money::rands(50_700_000),
             spend_percentage: 6.0,
-            recognized_spend: 0.0, // 0% recognition
+            recognized_spend: Money::ZERO, // 0% recognition
             contract_count: 28,
             avg_performance: 68.2,
         },
@@ -432,10 +433,10 @@ pub fn load_mock_data(store: &BbbeeStore) {
             is_eme: false,
             is_qse: false,
             enterprise_size: EnterpriseSize::Generic,
-            annual_turnover: Some(120_000_000.0),
-            total_spend: 45_000_000.0,
+            annual_turnover: Some(Money::rands(120_000_000)),
+            total_spend: Money::rands(45_000_000),
             recognition_level: 135.0,
-            recognized_spend: 60_750_000.0,
+            recognized_spend: Money::rands(60_750_000),
             province: "Gauteng".to_string(),
             is_township_rural: false,
             verification_status: VerificationStatus::Verified,
@@ -454,10 +455,10 @@ pub fn load_mock_data(store: &BbbeeStore) {
             is_eme: false,
             is_qse: false,
             enterprise_size: EnterpriseSize::Generic,
-            annual_turnover: Some(85_000_000.0),
-            total_spend: 28_500_000.0,
+            annual_turnover: Some(Money::rands(85_000_000)),
+            total_spend: Money::rands(28_500_000),
             recognition_level: 125.0,
-            recognized_spend: 35_625_000.0,
+            recognized_spend: Money::rands(35_625_000),
             province: "Gauteng".to_string(),
             is_township_rural: false,
             verification_status: VerificationStatus::Verified,
@@ -476,10 +477,10 @@ pub fn load_mock_data(store: &BbbeeStore) {
             is_eme: false,
             is_qse: true,
             enterprise_size: EnterpriseSize::QSE,
-            annual_turnover: Some(25_000_000.0),
-            total_spend: 8_200_000.0,
+            annual_turnover: Some(Money::rands(25_000_000)),
+            total_spend: Money::rands(8_200_000),
             recognition_level: 135.0,
-            recognized_spend: 11_070_000.0,
+            recognized_spend: Money::rands(11_070_000),
             province: "Western Cape".to_string(),
             is_township_rural: false,
             verification_status: VerificationStatus::Verified,
@@ -498,10 +499,10 @@ pub fn load_mock_data(store: &BbbeeStore) {
             is_eme: false,
             is_qse: false,
             enterprise_size: EnterpriseSize::Generic,
-            annual_turnover: Some(95_000_000.0),
-            total_spend: 35_000_000.0,
+            annual_turnover: Some(Money::rands(95_000_000)),
+            total_spend: Money::rands(35_000_000),
             recognition_level: 135.0,
-            recognized_spend: 47_250_000.0,
+            recognized_spend: Money::rands(47_250_000),
             province: "KwaZulu-Natal".to_string(),
             is_township_rural: false,
             verification_status: VerificationStatus::Verified,
@@ -520,10 +521,10 @@ pub fn load_mock_data(store: &BbbeeStore) {
             is_eme: false,
             is_qse: true,
             enterprise_size: EnterpriseSize::QSE,
-            annual_turnover: Some(35_000_000.0),
-            total_spend: 15_500_000.0,
+            annual_turnover: Some(Money::rands(35_000_000)),
+            total_spend: Money::rands(15_500_000),
             recognition_level: 135.0,
-            recognized_spend: 20_925_000.0,
+            recognized_spend: Money::rands(20_925_000),
             province: "Gauteng".to_string(),
             is_township_rural: false,
             verification_status: VerificationStatus::Verified,
@@ -542,10 +543,10 @@ pub fn load_mock_data(store: &BbbeeStore) {
             is_eme: true,
             is_qse: false,
             enterprise_size: EnterpriseSize::EME,
-            annual_turnover: Some(6_500_000.0),
-            total_spend: 2_800_000.0,
+            annual_turnover: Some(Money::rands(6_500_000)),
+            total_spend: Money::rands(2_800_000),
             recognition_level: 135.0,
-            recognized_spend: 3_780_000.0,
+            recognized_spend: Money::rands(3_780_000),
             province: "Gauteng".to_string(),
             is_township_rural: true,
             verification_status: VerificationStatus::SwornAffidavit,
@@ -564,10 +565,10 @@ pub fn load_mock_data(store: &BbbeeStore) {
             is_eme: false,
             is_qse: false,
             enterprise_size: EnterpriseSize::Generic,
-            annual_turnover: Some(68_000_000.0),
-            total_spend: 18_000_000.0,
+            annual_turnover: Some(Money::rands(68_000_000)),
+            total_spend: Money::rands(18_000_000),
             recognition_level: 125.0,
-            recognized_spend: 22_500_000.0,
+            recognized_spend: Money::rands(22_500_000),
             province: "Mpumalanga".to_string(),
             is_township_rural: false,
             verification_status: VerificationStatus::Verified,
@@ -586,10 +587,10 @@ pub fn load_mock_data(store: &BbbeeStore) {
             is_eme: false,
             is_qse: true,
             enterprise_size: EnterpriseSize::QSE,
-            annual_turnover: Some(42_000_000.0),
-            total_spend: 6_800_000.0,
+            annual_turnover: Some(Money::rands(42_000_000)),
+            total_spend: Money::rands(6_800_000),
             recognition_level: 100.0,
-            recognized_spend: 6_800_000.0,
+            recognized_spend: Money::rands(6_800_000),
             province: "Eastern Cape".to_string(),
             is_township_rural: false,
             verification_status: VerificationStatus::Verified,
@@ -599,16 +600,16 @@ pub fn load_mock_data(store: &BbbeeStore) {
 
     // Monthly trend data for FY 2025/26
     let trend_data = vec![
-        BbbeeTrendPoint { period: "Apr".to_string(), total_spend: 68_500_000.0, bbbee_spend: 52_985_000.0, bbbee_percent: 77.3, target_percent: 80.0 },
-        BbbeeTrendPoint { period: "May".to_string(), total_spend: 72_300_000.0, bbbee_spend: 56_394_000.0, bbbee_percent: 78.0, target_percent: 80.0 },
-        BbbeeTrendPoint { period: "Jun".to_string(), total_spend: 85_200_000.0, bbbee_spend: 66_456_000.0, bbbee_percent: 78.0, target_percent: 80.0 },
-        BbbeeTrendPoint { period: "Jul".to_string(), total_spend: 78_900_000.0, bbbee_spend: 61_542_000.0, bbbee_percent: 78.0, target_percent: 80.0 },
-        BbbeeTrendPoint { period: "Aug".to_string(), total_spend: 81_400_000.0, bbbee_spend: 63_492_000.0, bbbee_percent: 78.0, target_percent: 80.0 },
-        BbbeeTrendPoint { period: "Sep".to_string(), total_spend: 92_100_000.0, bbbee_spend: 72_039_000.0, bbbee_percent: 78.2, target_percent: 80.0 },
-        BbbeeTrendPoint { period: "Oct".to_string(), total_spend: 88_700_000.0, bbbee_spend: 69_586_000.0, bbbee_percent: 78.4, target_percent: 80.0 },
-        BbbeeTrendPoint { period: "Nov".to_string(), total_spend: 95_300_000.0, bbbee_spend: 75_137_000.0, bbbee_percent: 78.8, target_percent: 80.0 },
-        BbbeeTrendPoint { period: "Dec".to_string(), total_spend: 76_200_000.0, bbbee_spend: 60_198_000.0, bbbee_percent: 79.0, target_percent: 80.0 },
-        BbbeeTrendPoint { period: "Jan".to_string(), total_spend: 108_400_000.0, bbbee_spend: 85_659_200.0, bbbee_percent: 79.0, target_percent: 80.0 },
+        BbbeeTrendPoint { period: "Apr".to_string(), total_spend: Money::rands(68_500_000), bbbee_spend: Money::rands(52_985_000), bbbee_percent: 77.3, target_percent: 80.0 },
+        BbbeeTrendPoint { period: "May".to_string(), total_spend: Money::rands(72_300_000), bbbee_spend: Money::rands(56_394_000), bbbee_percent: 78.0, target_percent: 80.0 },
+        BbbeeTrendPoint { period: "Jun".to_string(), total_spend: Money::rands(85_200_000), bbbee_spend: Money::rands(66_456_000), bbbee_percent: 78.0, target_percent: 80.0 },
+        BbbeeTrendPoint { period: "Jul".to_string(), total_spend: Money::rands(78_900_000), bbbee_spend: Money::rands(61_542_000), bbbee_percent: 78.0, target_percent: 80.0 },
+        BbbeeTrendPoint { period: "Aug".to_string(), total_spend: Money::rands(81_400_000), bbbee_spend: Money::rands(63_492_000), bbbee_percent: 78.0, target_percent: 80.0 },
+        BbbeeTrendPoint { period: "Sep".to_string(), total_spend: Money::rands(92_100_000), bbbee_spend: Money::rands(72_039_000), bbbee_percent: 78.2, target_percent: 80.0 },
+        BbbeeTrendPoint { period: "Oct".to_string(), total_spend: Money::rands(88_700_000), bbbee_spend: Money::rands(69_586_000), bbbee_percent: 78.4, target_percent: 80.0 },
+        BbbeeTrendPoint { period: "Nov".to_string(), total_spend: Money::rands(95_300_000), bbbee_spend: Money::rands(75_137_000), bbbee_percent: 78.8, target_percent: 80.0 },
+        BbbeeTrendPoint { period: "Dec".to_string(), total_spend: Money::rands(76_200_000), bbbee_spend: Money::rands(60_198_000), bbbee_percent: 79.0, target_percent: 80.0 },
+        BbbeeTrendPoint { period: "Jan".to_string(), total_spend: Money::rands(108_400_000), bbbee_spend: Money::rands(85_659_200), bbbee_percent: 79.0, target_percent: 80.0 },
     ];
 
     // Designated group spend breakdown
@@ -616,7 +617,7 @@ pub fn load_mock_data(store: &BbbeeStore) {
         DesignatedGroupSpend {
             classification: OwnershipClassification::BlackOwned,
             supplier_count: 542,
-            spend_amount: 326_095_000.0,
+            spend_amount: Money::rands(326_095_000),
             spend_percentage: 38.5,
             target_percentage: 40.0,
             variance: -1.5,
@@ -624,7 +625,7 @@ pub fn load_mock_data(store: &BbbeeStore) {
         DesignatedGroupSpend {
             classification: OwnershipClassification::BlackWomenOwned,
             supplier_count: 245,
-            spend_amount: 120_274_000.0,
+            spend_amount: Money::rands(120_274_000),
             spend_percentage: 14.2,
             target_percentage: 12.0,
             variance: 2.2,
@@ -632,7 +633,7 @@ pub fn load_mock_data(store: &BbbeeStore) {
         DesignatedGroupSpend {
             classification: OwnershipClassification::EME,
             supplier_count: 312,
-            spend_amount: 105_875_000.0,
+            spend_amount: Money::rands(105_875_000),
             spend_percentage: 12.5,
             target_percentage: 15.0,
             variance: -2.5,
@@ -640,7 +641,7 @@ pub fn load_mock_data(store: &BbbeeStore) {
         DesignatedGroupSpend {
             classification: OwnershipClassification::QSE,
             supplier_count: 187,
-            spend_amount: 138_061_000.0,
+            spend_amount: Money::rands(138_061_000),
             spend_percentage: 16.3,
             target_percentage: 15.0,
             variance: 1.3,
@@ -648,7 +649,7 @@ pub fn load_mock_data(store: &BbbeeStore) {
         DesignatedGroupSpend {
             classification: OwnershipClassification::YouthOwned,
             supplier_count: 68,
-            spend_amount: 6_776_000.0,
+            spend_amount: Money::rands(6_776_000),
             spend_percentage: 0.8,
             target_percentage: 0.5,
             variance: 0.3,
@@ -656,7 +657,7 @@ pub fn load_mock_data(store: &BbbeeStore) {
         DesignatedGroupSpend {
             classification: OwnershipClassification::RuralTownship,
             supplier_count: 95,
-            spend_amount: 12_705_000.0,
+            spend_amount: Money::rands(12_705_000),
             spend_percentage: 1.5,
             target_percentage: 1.0,
             variance: 0.5,
@@ -664,7 +665,7 @@ pub fn load_mock_data(store: &BbbeeStore) {
         DesignatedGroupSpend {
             classification: OwnershipClassification::DisabilityOwned,
             supplier_count: 18,
-            spend_amount: 2_541_000.0,
+            spend_amount: Money::rands(2_541_000),
             spend_percentage: 0.3,
             target_percentage: 0.3,
             variance: 0.0,
@@ -672,7 +673,7 @@ pub fn load_mock_data(store: &BbbeeStore) {
         DesignatedGroupSpend {
             classification: OwnershipClassification::MilitaryVeteranOwned,
             supplier_count: 12,
-            spend_amount: 1_694_000.0,
+            spend_amount: Money::rands(1_694_000),
             spend_percentage: 0.2,
             target_percentage: 0.2,
             variance: 0.0,
@@ -681,15 +682,15 @@ pub fn load_mock_data(store: &BbbeeStore) {
 
     // Provincial distribution
     let provincial_distribution = vec![
-        ProvincialDistribution { province: "Gauteng".to_string(), supplier_count: 542, total_spend: 380_000_000.0, bbbee_spend: 304_000_000.0, bbbee_percentage: 80.0, township_rural_spend: 8_500_000.0 },
-        ProvincialDistribution { province: "Western Cape".to_string(), supplier_count: 234, total_spend: 165_000_000.0, bbbee_spend: 128_700_000.0, bbbee_percentage: 78.0, township_rural_spend: 2_200_000.0 },
-        ProvincialDistribution { province: "KwaZulu-Natal".to_string(), supplier_count: 187, total_spend: 120_000_000.0, bbbee_spend: 94_800_000.0, bbbee_percentage: 79.0, township_rural_spend: 1_800_000.0 },
-        ProvincialDistribution { province: "Eastern Cape".to_string(), supplier_count: 98, total_spend: 68_000_000.0, bbbee_spend: 52_360_000.0, bbbee_percentage: 77.0, township_rural_spend: 1_200_000.0 },
-        ProvincialDistribution { province: "Mpumalanga".to_string(), supplier_count: 67, total_spend: 42_000_000.0, bbbee_spend: 33_600_000.0, bbbee_percentage: 80.0, township_rural_spend: 950_000.0 },
-        ProvincialDistribution { province: "Free State".to_string(), supplier_count: 54, total_spend: 28_000_000.0, bbbee_spend: 21_840_000.0, bbbee_percentage: 78.0, township_rural_spend: 650_000.0 },
-        ProvincialDistribution { province: "Limpopo".to_string(), supplier_count: 48, total_spend: 22_000_000.0, bbbee_spend: 17_160_000.0, bbbee_percentage: 78.0, township_rural_spend: 850_000.0 },
-        ProvincialDistribution { province: "North West".to_string(), supplier_count: 35, total_spend: 15_000_000.0, bbbee_spend: 11_550_000.0, bbbee_percentage: 77.0, township_rural_spend: 450_000.0 },
-        ProvincialDistribution { province: "Northern Cape".to_string(), supplier_count: 19, total_spend: 7_000_000.0, bbbee_spend: 5_320_000.0, bbbee_percentage: 76.0, township_rural_spend: 200_000.0 },
+        ProvincialDistribution { province: "Gauteng".to_string(), supplier_count: 542, total_spend: Money::rands(380_000_000), bbbee_spend: Money::rands(304_000_000), bbbee_percentage: 80.0, township_rural_spend: Money::rands(8_500_000) },
+        ProvincialDistribution { province: "Western Cape".to_string(), supplier_count: 234, total_spend: Money::rands(165_000_000), bbbee_spend: Money::rands(128_700_000), bbbee_percentage: 78.0, township_rural_spend: Money::rands(2_200_000) },
+        ProvincialDistribution { province: "KwaZulu-Natal".to_string(), supplier_count: 187, total_spend: Money::rands(120_000_000), bbbee_spend: Money::rands(94_800_000), bbbee_percentage: 79.0, township_rural_spend: Money::rands(1_800_000) },
+        ProvincialDistribution { province: "Eastern Cape".to_string(), supplier_count: 98, total_spend: Money::rands(68_000_000), bbbee_spend: Money::rands(52_360_000), bbbee_percentage: 77.0, township_rural_spend: Money::rands(1_200_000) },
+        ProvincialDistribution { province: "Mpumalanga".to_string(), supplier_count: 67, total_spend: Money::rands(42_000_000), bbbee_spend: Money::rands(33_600_000), bbbee_percentage: 80.0, township_rural_spend: Money::rands(950_000) },
+        ProvincialDistribution { province: "Free State".to_string(), supplier_count: 54, total_spend: Money::rands(28_000_000), bbbee_spend: Money::rands(21_840_000), bbbee_percentage: 78.0, township_rural_spend: Money::rands(650_000) },
+        ProvincialDistribution { province: "Limpopo".to_string(), supplier_count: 48, total_spend: Money::rands(22_000_000), bbbee_spend: Money::rands(17_160_000), bbbee_percentage: 78.0, township_rural_spend: Money::rands(850_000) },
+        ProvincialDistribution { province: "North West".to_string(), supplier_count: 35, total_spend: Money::rands(15_000_000), bbbee_spend: Money::rands(11_550_000), bbbee_percentage: 77.0, township_rural_spend: Money::rands(450_000) },
+        ProvincialDistribution { province: "Northern Cape".to_string(), supplier_count: 19, total_spend: Money::rands(7_000_000), bbbee_spend: Money::rands(5_320_000), bbbee_percentage: 76.0, township_rural_spend: Money::rands(200_000) },
     ];
 
     // Summary KPIs
@@ -697,8 +698,8 @@ pub fn load_mock_data(store: &BbbeeStore) {
         overall_bbbee_percent: 78.4,
         target_bbbee_percent: 80.0,
         total_mpsp: total_mpsp,
-        total_bbbee_spend: 664_048_000.0,
-        total_recognized_spend: 973_350_000.0,
+        total_bbbee_spend: Money::rands(664_048_000),
+        total_recognized_spend: Money::rands(973_350_000),
         level_1_2_percent: 70.0,
         level_1_4_percent: 88.0,
         black_owned_percent: 38.5,
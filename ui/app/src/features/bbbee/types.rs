@@ -4,6 +4,8 @@
 //! supplier classifications, and scorecard elements per the B-BBEE Codes of Good Practice.
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::Money;
 
 /// B-BBEE certification levels (1-8 + Non-Compliant)
 /// Recognition levels per the Amended Codes of Good Practice
@@ -22,7 +24,7 @@ pub enum BbbeeLevel {
 
 impl BbbeeLevel {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             BbbeeLevel::Level1 => "Level 1",
             BbbeeLevel::Level2 => "Level 2",
             BbbeeLevel::Level3 => "Level 3",
@@ -32,7 +34,7 @@ impl BbbeeLevel {
             BbbeeLevel::Level7 => "Level 7",
             BbbeeLevel::Level8 => "Level 8",
             BbbeeLevel::NonCompliant => "Non-Compliant",
-        }
+        })
     }
 
     /// B-BBEE procurement recognition level percentage
@@ -128,7 +130,7 @@ pub enum OwnershipClassification {
 
 impl OwnershipClassification {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             OwnershipClassification::BlackOwned => "Black Owned (51%+)",
             OwnershipClassification::BlackWomenOwned => "Black Women Owned (30%+)",
             OwnershipClassification::EME => "Exempted Micro Enterprise",
@@ -139,7 +141,7 @@ impl OwnershipClassification {
             OwnershipClassification::MilitaryVeteranOwned => "Military Veteran Owned",
             OwnershipClassification::RuralTownship => "Rural/Township Based",
             OwnershipClassification::DesignatedGroup => "Designated Group",
-        }
+        })
     }
 
     /// Bonus points multiplier for preferential procurement
@@ -172,11 +174,11 @@ pub struct SpendTarget {
     /// Actual percentage achieved
     pub actual_percentage: f64,
     /// Target spend amount in Rand
-    pub target_amount: f64,
+    pub target_amount: Money,
     /// Actual spend amount in Rand
-    pub actual_amount: f64,
+    pub actual_amount: Money,
     /// Total measurable procurement spend
-    pub total_spend: f64,
+    pub total_spend: Money,
     /// Category (e.g., "Overall", "Goods", "Services", "Works")
     pub category: String,
     /// Financial year (e.g., "FY 2025/26")
@@ -201,9 +203,9 @@ impl Default for SpendTarget {
             description: String::new(),
             target_percentage: 0.0,
             actual_percentage: 0.0,
-            target_amount: 0.0,
-            actual_amount: 0.0,
-            total_spend: 0.0,
+            target_amount: Money::ZERO,
+            actual_amount: Money::ZERO,
+            total_spend: Money::ZERO,
             category: "Overall".to_string(),
             financial_year: "FY 2025/26".to_string(),
             sub_targets: Vec::new(),
@@ -221,7 +223,7 @@ pub struct SubTarget {
     pub name: String,
     pub target_percentage: f64,
     pub actual_percentage: f64,
-    pub actual_amount: f64,
+    pub actual_amount: Money,
     pub classification: Option<OwnershipClassification>,
 }
 
@@ -242,13 +244,13 @@ pub enum TargetStatus {
 
 impl TargetStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             TargetStatus::Exceeding => "Exceeding",
             TargetStatus::OnTrack => "On Track",
             TargetStatus::AtRisk => "At Risk",
             TargetStatus::BelowTarget => "Below Target",
             TargetStatus::NotApplicable => "N/A",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -272,11 +274,11 @@ pub enum Trend {
 
 impl Trend {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             Trend::Increasing => "Increasing",
             Trend::Stable => "Stable",
             Trend::Decreasing => "Decreasing",
-        }
+        })
     }
 
     pub fn icon(&self) -> &'static str {
@@ -296,11 +298,11 @@ pub struct LevelBreakdown {
     /// Number of suppliers at this level
     pub supplier_count: u32,
     /// Total spend with suppliers at this level
-    pub spend_amount: f64,
+    pub spend_amount: Money,
     /// Percentage of total spend
     pub spend_percentage: f64,
     /// Recognized spend (after applying recognition level)
-    pub recognized_spend: f64,
+    pub recognized_spend: Money,
     /// Number of active contracts
     pub contract_count: u32,
     /// Average supplier performance score
@@ -312,9 +314,9 @@ impl Default for LevelBreakdown {
         Self {
             level: BbbeeLevel::NonCompliant,
             supplier_count: 0,
-            spend_amount: 0.0,
+            spend_amount: Money::ZERO,
             spend_percentage: 0.0,
-            recognized_spend: 0.0,
+            recognized_spend: Money::ZERO,
             contract_count: 0,
             avg_performance: 0.0,
         }
@@ -400,7 +402,7 @@ pub enum ScorecardElement {
 
 impl ScorecardElement {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ScorecardElement::Ownership => "Ownership",
             ScorecardElement::ManagementControl => "Management Control",
             ScorecardElement::SkillsDevelopment => "Skills Development",
@@ -409,7 +411,7 @@ impl ScorecardElement {
             ScorecardElement::EnterpriseDevelopment => "Enterprise Development",
             ScorecardElement::SupplierDevelopment => "Supplier Development",
             ScorecardElement::SocioEconomicDevelopment => "Socio-Economic Development",
-        }
+        })
     }
 
     /// Maximum weighting points per Generic Codes
@@ -439,13 +441,13 @@ pub enum ComplianceStatus {
 
 impl ComplianceStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ComplianceStatus::Compliant => "Compliant",
             ComplianceStatus::PartiallyCompliant => "Partially Compliant",
             ComplianceStatus::NonCompliant => "Non-Compliant",
             ComplianceStatus::PendingReview => "Pending Review",
             ComplianceStatus::NotApplicable => "N/A",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -485,13 +487,13 @@ pub struct SupplierClassification {
     /// Enterprise size category
     pub enterprise_size: EnterpriseSize,
     /// Annual turnover
-    pub annual_turnover: Option<f64>,
+    pub annual_turnover: Option<Money>,
     /// Total spend with this supplier
-    pub total_spend: f64,
+    pub total_spend: Money,
     /// Recognition level percentage
     pub recognition_level: f64,
     /// Recognized spend value
-    pub recognized_spend: f64,
+    pub recognized_spend: Money,
     /// Province
     pub province: String,
     /// Is township/rural based
@@ -518,9 +520,9 @@ impl Default for SupplierClassification {
             is_qse: false,
             enterprise_size: EnterpriseSize::Generic,
             annual_turnover: None,
-            total_spend: 0.0,
+            total_spend: Money::ZERO,
             recognition_level: 0.0,
-            recognized_spend: 0.0,
+            recognized_spend: Money::ZERO,
             province: String::new(),
             is_township_rural: false,
             verification_status: VerificationStatus::Unverified,
@@ -542,17 +544,17 @@ pub enum EnterpriseSize {
 
 impl EnterpriseSize {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             EnterpriseSize::EME => "EME (< R10m)",
             EnterpriseSize::QSE => "QSE (R10m - R50m)",
             EnterpriseSize::Generic => "Generic (> R50m)",
-        }
+        })
     }
 
-    pub fn from_turnover(turnover: f64) -> Self {
-        if turnover <= 10_000_000.0 {
+    pub fn from_turnover(turnover: Money) -> Self {
+        if turnover <= Money::rands(10_000_000) {
             EnterpriseSize::EME
-        } else if turnover <= 50_000_000.0 {
+        } else if turnover <= Money::rands(50_000_000) {
             EnterpriseSize::QSE
         } else {
             EnterpriseSize::Generic
@@ -583,13 +585,13 @@ pub enum VerificationStatus {
 
 impl VerificationStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             VerificationStatus::Verified => "Verified",
             VerificationStatus::Expired => "Expired",
             VerificationStatus::Pending => "Pending",
             VerificationStatus::Unverified => "Unverified",
             VerificationStatus::SwornAffidavit => "Sworn Affidavit",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -604,18 +606,18 @@ impl VerificationStatus {
 }
 
 /// B-BBEE Goals Summary KPIs
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct BbbeeKpis {
     /// Overall B-BBEE spend percentage
     pub overall_bbbee_percent: f64,
     /// Target B-BBEE spend percentage
     pub target_bbbee_percent: f64,
     /// Total measurable procurement spend
-    pub total_mpsp: f64,
+    pub total_mpsp: Money,
     /// Total B-BBEE compliant spend
-    pub total_bbbee_spend: f64,
+    pub total_bbbee_spend: Money,
     /// Total recognized spend
-    pub total_recognized_spend: f64,
+    pub total_recognized_spend: Money,
     /// Level 1-2 supplier spend percentage
     pub level_1_2_percent: f64,
     /// Level 1-4 supplier spend percentage
@@ -646,8 +648,8 @@ pub struct BbbeeKpis {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BbbeeTrendPoint {
     pub period: String,
-    pub total_spend: f64,
-    pub bbbee_spend: f64,
+    pub total_spend: Money,
+    pub bbbee_spend: Money,
     pub bbbee_percent: f64,
     pub target_percent: f64,
 }
@@ -670,7 +672,7 @@ pub struct BbbeeFilter {
 pub struct DesignatedGroupSpend {
     pub classification: OwnershipClassification,
     pub supplier_count: u32,
-    pub spend_amount: f64,
+    pub spend_amount: Money,
     pub spend_percentage: f64,
     pub target_percentage: f64,
     pub variance: f64,
@@ -681,8 +683,8 @@ pub struct DesignatedGroupSpend {
 pub struct ProvincialDistribution {
     pub province: String,
     pub supplier_count: u32,
-    pub total_spend: f64,
-    pub bbbee_spend: f64,
+    pub total_spend: Money,
+    pub bbbee_spend: Money,
     pub bbbee_percentage: f64,
-    pub township_rural_spend: f64,
+    pub township_rural_spend: Money,
 }
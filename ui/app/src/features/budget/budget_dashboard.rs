@@ -0,0 +1,324 @@
+//! Budget control dashboard
+
+use components::prelude::*;
+use crate::Route;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table, DataTableColumn, DataTableRow,
+    kpi_card, KpiColor, KpiDelta,
+    progress_bar, ProgressColor,
+    status_badge, StatusType,
+    notice_bar, NoticeType,
+};
+use crate::util::datetime::FiscalYear;
+use crate::util::format::{format_currency, format_currency_full, format_date, format_percentage};
+use super::store::BudgetStore;
+use super::types::{BudgetAlertLevel, BudgetCategory, BudgetLine, BudgetTotals, CommitmentStatus};
+use super::service;
+use crate::util::i18n::{t, tf, tn};
+
+fn utilisation_color(line_alert: Option<BudgetAlertLevel>) -> ProgressColor {
+    match line_alert {
+        Some(BudgetAlertLevel::Exceeded) => ProgressColor::Red,
+        Some(BudgetAlertLevel::Critical) => ProgressColor::Orange,
+        Some(BudgetAlertLevel::Warning) => ProgressColor::Accent,
+        None => ProgressColor::Green,
+    }
+}
+
+fn alert_message(line: &BudgetLine, level: BudgetAlertLevel) -> String {
+    match level {
+        BudgetAlertLevel::Exceeded => tf(
+            "{name} ({code}) exceeds its budget by {amount}",
+            &[
+                ("name", line.cost_center_name.clone()),
+                ("code", line.cost_center.clone()),
+                ("amount", format_currency_full(-line.available())),
+            ],
+        ),
+        _ => tf(
+            "{name} ({code}) at {percent} utilisation",
+            &[
+                ("name", line.cost_center_name.clone()),
+                ("code", line.cost_center.clone()),
+                ("percent", format_percentage(line.utilisation(), 0)),
+            ],
+        ),
+    }
+}
+
+/// Budget dashboard: totals, utilisation by category, alerts, budget lines
+/// and the commitment register
+#[component]
+pub fn budget_dashboard() -> View {
+    let store = use_context::<BudgetStore>();
+
+    effect({
+        let store = store.clone();
+        move || {
+            let store = store.clone();
+            spawn(async move {
+                service::load_budget(&store).await;
+            });
+        }
+    });
+
+    let year = FiscalYear::current().label();
+    let lines: Vec<BudgetLine> = store.lines.get().into_iter().filter(|l| l.financial_year == year).collect();
+    let totals = BudgetTotals::of(&lines);
+
+    let by_category: Vec<(BudgetCategory, BudgetTotals)> = BudgetCategory::all()
+        .into_iter()
+        .map(|category| (category, BudgetTotals::of(lines.iter().filter(|l| l.category == category))))
+        .filter(|(_, totals)| !totals.budget.is_zero())
+        .collect();
+
+    let mut alerts: Vec<(BudgetAlertLevel, String)> = lines
+        .iter()
+        .filter_map(|l| l.alert().map(|level| (level, alert_message(l, level))))
+        .collect();
+    alerts.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let line_columns = vec![
+        DataTableColumn { key: "cost_center".to_string(), label: t("Cost Centre").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "category".to_string(), label: t("Category").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "budget".to_string(), label: t("Budget").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "committed".to_string(), label: t("Committed").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "expended".to_string(), label: t("Expended").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "available".to_string(), label: t("Available").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "utilisation".to_string(), label: t("Utilisation").to_string(), width: Some("160px".to_string()), align: None, cell_class: None },
+    ];
+    let line_rows: Vec<DataTableRow> = lines.iter().map(|line| {
+        DataTableRow {
+            id: line.id.clone(),
+            cells: vec![
+                view! {
+                    <span class="budget-cost-center">
+                        <span>{line.cost_center.clone()}</span>
+                        <span class="budget-muted">{line.cost_center_name.clone()}</span>
+                    </span>
+                },
+                view! { <span>{format!("{} {}", line.gl_account, line.category.label())}</span> },
+                view! { <span>{format_currency(line.budget())}</span> },
+                view! { <span>{format_currency(line.committed)}</span> },
+                view! { <span>{format_currency(line.expended)}</span> },
+                view! { <span class="amount-cell">{format_currency(line.available())}</span> },
+                view! {
+                    <div class="budget-utilisation">
+                        {progress_bar(line.utilisation(), utilisation_color(line.alert()), false, Some(6))}
+                        <span>{format_percentage(line.utilisation(), 0)}</span>
+                    </div>
+                },
+            ],
+        }
+    }).collect();
+
+    let commitment_columns = vec![
+        DataTableColumn { key: "id".to_string(), label: t("Commitment").to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "source".to_string(), label: t("Source").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "line".to_string(), label: t("Cost Centre").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "amount".to_string(), label: t("Amount").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "outstanding".to_string(), label: t("Outstanding").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "created".to_string(), label: t("Created").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+    ];
+    let commitment_rows: Vec<DataTableRow> = store.commitments.get().iter().map(|c| {
+        let cost_center = lines.iter()
+            .find(|l| l.id == c.budget_line_id)
+            .map(|l| l.cost_center.clone())
+            .unwrap_or_else(|| c.budget_line_id.clone());
+        DataTableRow {
+            id: c.id.clone(),
+            cells: vec![
+                view! { <span>{c.id.clone()}</span> },
+                view! { <span>{format!("{} {}", c.source.label(), c.source_id)}</span> },
+                view! { <span>{cost_center}</span> },
+                view! { <span>{format_currency(c.amount)}</span> },
+                view! { <span class="amount-cell">{format_currency(c.outstanding())}</span> },
+                view! { <span>{format_date(&c.created_at)}</span> },
+                status_badge(match c.status {
+                    CommitmentStatus::Active => StatusType::Active,
+                    CommitmentStatus::Released => StatusType::Cancelled,
+                    CommitmentStatus::Converted => StatusType::Complete,
+                }),
+            ],
+        }
+    }).collect();
+
+    let icon_wallet = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><rect x="2" y="5" width="20" height="14" rx="2"/><line x1="2" y1="10" x2="22" y2="10"/></svg>"#;
+    let icon_lock = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><rect x="3" y="11" width="18" height="11" rx="2" ry="2"/><path d="M7 11V7a5 5 0 0 1 10 0v4"/></svg>"#;
+    let icon_receipt = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M14 2H6a2 2 0 0 0-2 2v16a2 2 0 0 0 2 2h12a2 2 0 0 0 2-2V8z"/><polyline points="14 2 14 8 20 8"/></svg>"#;
+    let icon_check = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M22 11.08V12a10 10 0 1 1-5.93-9.14"/><polyline points="22 4 12 14.01 9 11.01"/></svg>"#;
+
+    view! {
+        style {
+            r#"
+            .budget-dashboard { display: flex; flex-direction: column; gap: var(--space-6); }
+            .budget-grid {
+                display: grid;
+                grid-template-columns: 2fr 1fr;
+                gap: 24px;
+            }
+            .budget-category { padding: 8px 0; }
+            .budget-category-head {
+                display: flex;
+                justify-content: space-between;
+                font-size: 13px;
+                margin-bottom: 6px;
+            }
+            .budget-muted { font-size: 12px; color: var(--text-muted); }
+            .budget-cost-center { display: flex; flex-direction: column; }
+            .budget-utilisation { display: flex; align-items: center; gap: 8px; font-size: 12px; }
+            .budget-utilisation > :first-child { flex: 1; }
+            .budget-alert {
+                display: flex;
+                gap: 8px;
+                align-items: baseline;
+                padding: 8px 0;
+                font-size: 13px;
+                border-bottom: 1px solid var(--border);
+            }
+            .budget-alert:last-child { border-bottom: none; }
+            .budget-alert-level {
+                font-size: 11px;
+                font-weight: 600;
+                text-transform: uppercase;
+                min-width: 70px;
+            }
+            .budget-alert-level.warning { color: var(--orange); }
+            .budget-alert-level.critical { color: var(--accent); }
+            .budget-alert-level.exceeded { color: var(--red); }
+            @media (max-width: 1024px) {
+                .budget-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="budget-dashboard" data-testid="budget-dashboard">
+            {page_header(
+                t("Budget Control").to_string(),
+                Some(tf("Budgets, commitments and spend for FY {year}", &[("year", year.to_string())])),
+                vec![
+                    view! { <a href={Route::BudgetTransfers.to_path()} class="btn btn-secondary">{t("Budget Transfers")}</a> },
+                ]
+            )}
+
+            if let Some(err) = store.error.get() {
+                {notice_bar(err, NoticeType::Error, None)}
+            }
+
+            <div class="kpi-grid">
+                {kpi_card(
+                    t("Total Budget").to_string(),
+                    format_currency(totals.budget),
+                    KpiColor::Blue,
+                    icon_wallet.to_string(),
+                    Some(KpiDelta {
+                        value: tn("{n} line", "{n} lines", lines.len() as i64),
+                        is_positive: None,
+                        suffix: String::new(),
+                    }),
+                    None
+                )}
+                {kpi_card(
+                    t("Committed").to_string(),
+                    format_currency(totals.committed),
+                    KpiColor::Orange,
+                    icon_lock.to_string(),
+                    Some(KpiDelta {
+                        value: format_percentage(totals.committed.percent_of(totals.budget), 1),
+                        is_positive: None,
+                        suffix: t("of budget").to_string(),
+                    }),
+                    None
+                )}
+                {kpi_card(
+                    t("Expended").to_string(),
+                    format_currency(totals.expended),
+                    KpiColor::Purple,
+                    icon_receipt.to_string(),
+                    Some(KpiDelta {
+                        value: format_percentage(totals.expended.percent_of(totals.budget), 1),
+                        is_positive: None,
+                        suffix: t("of budget").to_string(),
+                    }),
+                    None
+                )}
+                {kpi_card(
+                    t("Available").to_string(),
+                    format_currency(totals.available),
+                    if totals.available.is_negative() { KpiColor::Red } else { KpiColor::Green },
+                    icon_check.to_string(),
+                    Some(KpiDelta {
+                        value: format_percentage(totals.available.percent_of(totals.budget), 1),
+                        is_positive: Some(totals.available.is_positive()),
+                        suffix: t("of budget").to_string(),
+                    }),
+                    None
+                )}
+            </div>
+
+            <div class="budget-grid">
+                {panel(
+                    t("Budget by Category").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div data-testid="budget-by-category">
+                            for (category, category_totals) in by_category.clone() {
+                                <div class="budget-category">
+                                    <div class="budget-category-head">
+                                        <span>{category.label()}</span>
+                                        <span class="budget-muted">
+                                            {format!(
+                                                "{} / {} · {}",
+                                                format_currency(category_totals.committed + category_totals.expended),
+                                                format_currency(category_totals.budget),
+                                                format_percentage(category_totals.utilisation(), 0)
+                                            )}
+                                        </span>
+                                    </div>
+                                    {progress_bar(
+                                        category_totals.utilisation(),
+                                        utilisation_color(category_totals.alert()),
+                                        false,
+                                        Some(8)
+                                    )}
+                                </div>
+                            }
+                        </div>
+                    }]
+                )}
+
+                {panel(
+                    t("Budget Alerts").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div data-testid="budget-alerts">
+                            if alerts.is_empty() {
+                                <div class="budget-muted">{t("All budgets are within their thresholds")}</div>
+                            }
+                            for (level, message) in alerts.clone() {
+                                <div class="budget-alert">
+                                    <span class={format!("budget-alert-level {}", level.class())}>{level.label()}</span>
+                                    <span>{message}</span>
+                                </div>
+                            }
+                        </div>
+                    }]
+                )}
+            </div>
+
+            {panel(
+                t("Budget Lines").to_string(),
+                vec![],
+                vec![data_table(line_columns, line_rows, None)]
+            )}
+
+            {panel(
+                t("Commitments").to_string(),
+                vec![],
+                vec![data_table(commitment_columns, commitment_rows, None)]
+            )}
+        </div>
+    }
+}
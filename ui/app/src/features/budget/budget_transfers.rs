@@ -0,0 +1,283 @@
+//! Budget transfer requests
+//!
+//! Moves uncommitted budget between lines of the same financial year.
+//! Requests are routed through the budget-transfer approval chain and funds
+//! only move once the last stage approves.
+
+use components::prelude::*;
+use crate::Route;
+use crate::features::workflow::approval_timeline::{approval_timeline, approval_actions};
+use crate::features::workflow::types::ApprovalDecision;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table_with_testid, DataTableColumn, DataTableRow,
+    status_badge, StatusType,
+    notice_bar, NoticeType,
+    empty_state,
+};
+use crate::shared::forms::{select_with_testid, SelectOption, currency_input, textarea};
+use crate::util::auth::AuthState;
+use crate::util::datetime::FiscalYear;
+use crate::util::format::{format_currency, format_currency_full, format_date};
+use crate::util::i18n::t;
+use crate::util::money::Money;
+use crate::util::permissions::{self, Action};
+use super::store::BudgetStore;
+use super::types::{BudgetLineStatus, BudgetTransfer, TransferStatus, MAX_TRANSFER};
+use super::service;
+
+fn transfer_status(status: TransferStatus) -> View {
+    status_badge(match status {
+        TransferStatus::Pending => StatusType::Pending,
+        TransferStatus::Approved => StatusType::Approved,
+        TransferStatus::Rejected => StatusType::Rejected,
+    })
+}
+
+/// Budget transfers page: request form, transfer register and approval of
+/// the selected transfer
+#[component]
+pub fn budget_transfers() -> View {
+    let store = use_context::<BudgetStore>();
+    let auth_state = use_context::<Signal<AuthState>>();
+
+    effect({
+        let store = store.clone();
+        move || {
+            let store = store.clone();
+            spawn(async move {
+                service::load_budget(&store).await;
+            });
+        }
+    });
+
+    let from_line = signal(String::new());
+    let to_line = signal(String::new());
+    let amount = signal(Money::ZERO);
+    let justification = signal(String::new());
+    let selected = signal(None::<String>);
+    let saved = signal(None::<String>);
+
+    let handle_request = Callback::<()>::new({
+        let store = store.clone();
+        let from_line = from_line.clone();
+        let to_line = to_line.clone();
+        let amount = amount.clone();
+        let justification = justification.clone();
+        let selected = selected.clone();
+        let saved = saved.clone();
+        move |_| {
+            let transfer = BudgetTransfer {
+                from_line_id: from_line.get(),
+                to_line_id: to_line.get(),
+                amount: amount.get(),
+                justification: justification.get(),
+                ..Default::default()
+            };
+            let store = store.clone();
+            let amount = amount.clone();
+            let justification = justification.clone();
+            let selected = selected.clone();
+            let saved = saved.clone();
+            saved.set(None);
+            spawn(async move {
+                if let Ok(created) = service::request_transfer(&store, transfer).await {
+                    saved.set(Some(format!(
+                        "{} {} {}",
+                        created.id,
+                        t("submitted for approval:"),
+                        format_currency_full(created.amount)
+                    )));
+                    amount.set(Money::ZERO);
+                    justification.set(String::new());
+                    selected.set(Some(created.id));
+                }
+            });
+        }
+    });
+
+    let handle_decide = Callback::new({
+        let store = store.clone();
+        let selected = selected.clone();
+        move |decision: ApprovalDecision| {
+            let Some(id) = selected.get() else { return };
+            let store = store.clone();
+            spawn(async move {
+                let _ = service::decide_transfer(&store, &id, &decision).await;
+            });
+        }
+    });
+
+    let handle_select = Callback::new({
+        let selected = selected.clone();
+        move |id: String| selected.set(Some(id))
+    });
+
+    let can_request = permissions::can_perform(&auth_state.get(), Action::TransferBudget);
+    let year = FiscalYear::current().label();
+    let lines = store.lines.get();
+    let source_options: Vec<SelectOption> = lines.iter()
+        .filter(|l| l.financial_year == year && l.status != BudgetLineStatus::Closed && l.available().is_positive())
+        .map(|l| SelectOption {
+            value: l.id.clone(),
+            label: format!("{} ({} {})", l.display_name(), format_currency(l.available()), t("available")),
+        })
+        .collect();
+    let target_options: Vec<SelectOption> = lines.iter()
+        .filter(|l| l.financial_year == year && l.status == BudgetLineStatus::Active)
+        .map(|l| SelectOption { value: l.id.clone(), label: l.display_name() })
+        .collect();
+    let cost_center = |id: &str| {
+        lines.iter()
+            .find(|l| l.id == id)
+            .map(|l| l.cost_center.clone())
+            .unwrap_or_else(|| id.to_string())
+    };
+
+    let columns = vec![
+        DataTableColumn { key: "id".to_string(), label: t("Transfer").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "from".to_string(), label: t("From").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "to".to_string(), label: t("To").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "amount".to_string(), label: t("Amount").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "requested".to_string(), label: t("Requested").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+    ];
+
+    let transfers = store.transfers.get();
+    let rows: Vec<DataTableRow> = transfers.iter().map(|tr| {
+        DataTableRow {
+            id: tr.id.clone(),
+            cells: vec![
+                view! { <span>{tr.id.clone()}</span> },
+                view! { <span>{cost_center(&tr.from_line_id)}</span> },
+                view! { <span>{cost_center(&tr.to_line_id)}</span> },
+                view! { <span class="amount-cell">{format_currency(tr.amount)}</span> },
+                view! { <span>{format!("{} · {}", tr.requested_by, format_date(&tr.requested_at))}</span> },
+                transfer_status(tr.status),
+            ],
+        }
+    }).collect();
+
+    let current = selected.get().and_then(|id| transfers.iter().find(|tr| tr.id == id).cloned());
+
+    view! {
+        style {
+            r#"
+            .budget-transfers { display: flex; flex-direction: column; gap: var(--space-6); }
+            .transfer-form {
+                display: grid;
+                grid-template-columns: repeat(3, 1fr);
+                gap: 16px;
+            }
+            .transfer-form .span-3 { grid-column: 1 / -1; }
+            .transfer-form-actions { display: flex; justify-content: flex-end; }
+            .transfer-justification { font-size: 13px; margin-bottom: 16px; }
+            @media (max-width: 1024px) {
+                .transfer-form { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="budget-transfers" data-testid="budget-transfers">
+            {page_header(
+                t("Budget Transfers").to_string(),
+                Some(format!("{} {}", t("Virements between budget lines for FY"), year)),
+                vec![
+                    view! { <a href={Route::BudgetDashboard.to_path()} class="btn btn-secondary">{t("Budget Control")}</a> },
+                ]
+            )}
+
+            if let Some(err) = store.error.get() {
+                {notice_bar(err, NoticeType::Error, None)}
+            }
+            if let Some(msg) = saved.get() {
+                {notice_bar(msg, NoticeType::Success, None)}
+            }
+
+            if can_request {
+                {panel(
+                    t("Request Transfer").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div class="transfer-form" data-testid="transfer-form">
+                            {select_with_testid(
+                                t("From budget").to_string(),
+                                from_line.clone(),
+                                source_options.clone(),
+                                Some(t("Select source budget").to_string()),
+                                true,
+                                false,
+                                None,
+                                Some("transfer-from".to_string()),
+                            )}
+                            {select_with_testid(
+                                t("To budget").to_string(),
+                                to_line.clone(),
+                                target_options.clone(),
+                                Some(t("Select receiving budget").to_string()),
+                                true,
+                                false,
+                                None,
+                                Some("transfer-to".to_string()),
+                            )}
+                            {currency_input(
+                                t("Amount").to_string(),
+                                amount.clone(),
+                                true,
+                                false,
+                                None,
+                                Some(format!("{} {}", t("Maximum per transfer:"), format_currency(MAX_TRANSFER))),
+                            )}
+                            <div class="span-3">
+                                {textarea(
+                                    t("Justification").to_string(),
+                                    justification.clone(),
+                                    Some(t("Why the funds are needed and why the source budget can release them").to_string()),
+                                    true,
+                                    false,
+                                    Some(3),
+                                    None,
+                                    None,
+                                )}
+                            </div>
+                            <div class="transfer-form-actions span-3">
+                                <button
+                                    class="btn btn-primary"
+                                    disabled={store.saving.get()}
+                                    data-testid="transfer-submit"
+                                    on:click={handle_request}
+                                >
+                                    {t("Submit for Approval")}
+                                </button>
+                            </div>
+                        </div>
+                    }]
+                )}
+            }
+
+            {panel(
+                t("Transfers").to_string(),
+                vec![],
+                vec![
+                    if rows.is_empty() {
+                        empty_state(t("No budget transfers").to_string(), None, None, None)
+                    } else {
+                        data_table_with_testid(columns, rows, Some(handle_select), Some("transfers-table".to_string()), None)
+                    },
+                ]
+            )}
+
+            if let Some(transfer) = current {
+                {panel(
+                    format!("{} {}", t("Approval"), transfer.id),
+                    vec![],
+                    vec![
+                        view! { <p class="transfer-justification">{transfer.justification.clone()}</p> },
+                        approval_timeline(transfer.approval_workflow.clone()),
+                        approval_actions(transfer.approval_workflow.clone(), transfer.requested_by_id.clone(), store.saving.get(), handle_decide),
+                    ]
+                )}
+            }
+        </div>
+    }
+}
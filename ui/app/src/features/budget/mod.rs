@@ -0,0 +1,12 @@
+//! Budget control feature module
+//!
+//! Holds cost-centre budget lines, checks and pre-commits funds when
+//! requisitions are submitted, commits them when purchase orders are
+//! approved, records spend from receipts and routes budget transfers for
+//! approval.
+
+pub mod types;
+pub mod store;
+pub mod service;
+pub mod budget_dashboard;
+pub mod budget_transfers;
@@ -0,0 +1,567 @@
+//! Budget control service
+//!
+//! Requisitions pre-commit funds when they are submitted, purchase orders
+//! commit them when they are approved and goods receipts turn commitments
+//! into expenditure. Each step checks the cost centre's budget line for the
+//! current financial year, so an over-budget document is stopped where it
+//! is raised rather than discovered at payment.
+
+use crate::features::audit::{self, AuditActionType, AuditEntityType, AuditEntry, FieldChange};
+use crate::features::purchase_orders::types::PurchaseOrder;
+use crate::features::requisitions::types::Requisition;
+use crate::features::workflow::service as workflow;
+use crate::features::workflow::types::{
+    ApprovalDecision, ApprovalDocument, ApprovalRequest, WorkflowState,
+};
+use crate::util::api::HttpMethod;
+use crate::util::auth;
+use crate::util::backend;
+use crate::util::datetime::{now_iso, FiscalYear};
+use crate::util::format::format_currency_full;
+use crate::util::money::Money;
+use crate::util::permissions::{self, Action};
+use crate::util::reference;
+use super::store::{self, BudgetStore, load_mock_budget};
+use super::types::{
+    BudgetCheck, BudgetLine, BudgetLineStatus, BudgetTransfer, Commitment, CommitmentHandover, CommitmentSource,
+    CommitmentStatus, Expenditure, ExpenditureSource, TransferStatus, MAX_TRANSFER,
+};
+
+/// Load budget lines, commitments, expenditure and transfers.
+///
+/// The mock ledger is only loaded once, so commitments made in the session
+/// survive revisiting the dashboard.
+pub async fn load_budget(store: &BudgetStore) {
+    store.loading.set(true);
+    store.error.set(None);
+
+    if backend::is_mock() {
+        if store.lines.get().is_empty() {
+            load_mock_budget(store);
+        }
+    } else {
+        let loaded = async {
+            backend::fetch_with_snapshot("/budget/lines", &store.lines).await?;
+            backend::fetch_with_snapshot("/budget/commitments", &store.commitments).await?;
+            backend::fetch_with_snapshot("/budget/expenditures", &store.expenditures).await?;
+            backend::fetch_with_snapshot("/budget/transfers", &store.transfers).await
+        };
+        if let Err(e) = loaded.await {
+            store.error.set(Some(e.to_string()));
+        }
+    }
+    reroute_approvals(store);
+
+    store.loading.set(false);
+}
+
+/// Point pending transfer approvals at whoever holds their approver's
+/// authority today
+fn reroute_approvals(store: &BudgetStore) {
+    let mut transfers = store.transfers.get();
+    let mut changed = false;
+    for transfer in transfers.iter_mut() {
+        changed |= workflow::reroute(&mut transfer.approval_workflow, ApprovalDocument::BudgetTransfer, transfer.amount);
+    }
+    if changed {
+        store.transfers.set(transfers);
+    }
+}
+
+/// The installed store, with the ledger loaded for services that check
+/// budgets outside the budget pages
+async fn ledger() -> Result<BudgetStore, String> {
+    let store = store::installed();
+    if store.lines.get().is_empty() {
+        load_budget(&store).await;
+        if let Some(e) = store.error.get() {
+            return Err(format!("Could not load budgets: {}", e));
+        }
+    }
+    Ok(store)
+}
+
+/// The cost centre's budget line for the current financial year
+pub fn line_for(lines: &[BudgetLine], cost_center: &str) -> Option<BudgetLine> {
+    let year = FiscalYear::current().label();
+    lines
+        .iter()
+        .find(|l| l.financial_year == year && l.cost_center.eq_ignore_ascii_case(cost_center.trim()))
+        .cloned()
+}
+
+/// Whether the cost centre can fund `amount` this financial year
+pub async fn check_budget(cost_center: &str, amount: Money) -> Result<BudgetCheck, String> {
+    let store = ledger().await?;
+    let line = check_line(&store.lines.get(), cost_center)?;
+    Ok(BudgetCheck { available: line.available(), line, requested: amount })
+}
+
+fn check_line(lines: &[BudgetLine], cost_center: &str) -> Result<BudgetLine, String> {
+    if cost_center.trim().is_empty() {
+        return Err("Enter the cost centre the purchase is funded from".to_string());
+    }
+    let line = line_for(lines, cost_center).ok_or_else(|| {
+        format!("Cost centre {} has no {} budget", cost_center.trim(), FiscalYear::current().label())
+    })?;
+    match line.status {
+        BudgetLineStatus::Active => Ok(line),
+        BudgetLineStatus::Frozen => Err(format!("The budget for {} is frozen", line.cost_center)),
+        BudgetLineStatus::Closed => Err(format!("The budget for {} is closed", line.cost_center)),
+    }
+}
+
+fn insufficient(check: &BudgetCheck) -> String {
+    format!(
+        "Insufficient budget on {}: {} available, {} needed (short by {})",
+        check.line.cost_center,
+        format_currency_full(check.available),
+        format_currency_full(check.requested),
+        format_currency_full(check.shortfall())
+    )
+}
+
+/// Apply `f` to budget line `id` in the store
+fn update_line(store: &BudgetStore, id: &str, f: impl FnOnce(&mut BudgetLine)) {
+    let mut lines = store.lines.get();
+    if let Some(line) = lines.iter_mut().find(|l| l.id == id) {
+        f(line);
+    }
+    store.lines.set(lines);
+}
+
+fn active_commitment(store: &BudgetStore, source: CommitmentSource, source_id: &str) -> Option<Commitment> {
+    store
+        .commitments
+        .get()
+        .into_iter()
+        .find(|c| c.source == source && c.source_id == source_id && c.status == CommitmentStatus::Active)
+}
+
+/// Reserve `amount` on `line` for a document. When `replaces` is given,
+/// that commitment is released in the same request.
+async fn commit(
+    store: &BudgetStore,
+    line: &BudgetLine,
+    source: CommitmentSource,
+    source_id: &str,
+    amount: Money,
+    replaces: Option<Commitment>,
+) -> Result<Commitment, String> {
+    let commitment = Commitment {
+        id: reference::unique_id("CMT"),
+        budget_line_id: line.id.clone(),
+        source,
+        source_id: source_id.to_string(),
+        amount,
+        spent: Money::ZERO,
+        status: CommitmentStatus::Active,
+        created_at: now_iso(),
+        released_at: None,
+    };
+    let commitment = match replaces {
+        Some(mut released) => {
+            let outstanding = released.outstanding();
+            released.status = CommitmentStatus::Released;
+            released.released_at = Some(now_iso());
+            let handover = CommitmentHandover { released, committed: commitment };
+            let handover = backend::save(HttpMethod::Post, "/budget/commitments/handover", &handover)
+                .await
+                .map_err(|e| e.to_string())?;
+            released_in_store(store, &handover.released, outstanding);
+            handover.committed
+        }
+        None => backend::save(HttpMethod::Post, "/budget/commitments", &commitment)
+            .await
+            .map_err(|e| e.to_string())?,
+    };
+
+    let committed_before = store.line(&line.id).map_or(line.committed, |l| l.committed);
+    update_line(store, &line.id, |l| l.committed += commitment.amount);
+    let mut commitments = store.commitments.get();
+    commitments.insert(0, commitment.clone());
+    store.commitments.set(commitments);
+
+    audit::service::record(AuditEntry {
+        action: AuditActionType::Create,
+        entity_type: AuditEntityType::Budget,
+        entity_id: commitment.id.clone(),
+        entity_name: Some(line.display_name()),
+        changes: vec![FieldChange::new(
+            "Committed",
+            Some(format_currency_full(committed_before).as_str()),
+            Some(format_currency_full(committed_before + commitment.amount).as_str()),
+        )],
+        description: Some(format!(
+            "Committed {} for {} {}",
+            format_currency_full(commitment.amount),
+            source.label(),
+            source_id
+        )),
+        ..Default::default()
+    });
+    Ok(commitment)
+}
+
+/// Apply a saved release of `outstanding` to the store and the audit trail
+fn released_in_store(store: &BudgetStore, commitment: &Commitment, outstanding: Money) {
+    update_line(store, &commitment.budget_line_id, |l| l.committed -= outstanding);
+    let mut commitments = store.commitments.get();
+    if let Some(existing) = commitments.iter_mut().find(|c| c.id == commitment.id) {
+        *existing = commitment.clone();
+    }
+    store.commitments.set(commitments);
+
+    audit::service::record(AuditEntry {
+        action: AuditActionType::Cancel,
+        entity_type: AuditEntityType::Budget,
+        entity_id: commitment.id.clone(),
+        entity_name: Some(format!("{} {}", commitment.source.label(), commitment.source_id)),
+        description: Some(format!(
+            "Released {} committed for {} {}",
+            format_currency_full(outstanding),
+            commitment.source.label(),
+            commitment.source_id
+        )),
+        ..Default::default()
+    });
+}
+
+/// Return what is left of a document's commitment to the available balance.
+/// Does nothing when the document holds no active commitment.
+pub async fn release_commitment(source: CommitmentSource, source_id: &str) -> Result<(), String> {
+    let store = ledger().await?;
+    let Some(mut commitment) = active_commitment(&store, source, source_id) else {
+        return Ok(());
+    };
+
+    let outstanding = commitment.outstanding();
+    commitment.status = CommitmentStatus::Released;
+    commitment.released_at = Some(now_iso());
+    let endpoint = format!("/budget/commitments/{}/release", commitment.id);
+    let commitment = backend::save(HttpMethod::Post, &endpoint, &commitment)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    released_in_store(&store, &commitment, outstanding);
+    Ok(())
+}
+
+/// Pre-commitment budget check when a requisition is submitted.
+///
+/// Blocks the submission when the cost centre cannot fund it, otherwise
+/// reserves the amount. A requisition resubmitted after being returned
+/// replaces its earlier pre-commitment: the earlier one is released and the
+/// new one made in one save, so only any difference has to be available.
+pub async fn pre_commit_requisition(requisition: &Requisition) -> Result<Commitment, String> {
+    let store = ledger().await?;
+    let earlier = active_commitment(&store, CommitmentSource::Requisition, &requisition.id);
+
+    let line = check_line(&store.lines.get(), &requisition.cost_center)?;
+    let carried = earlier
+        .as_ref()
+        .filter(|c| c.budget_line_id == line.id)
+        .map_or(Money::ZERO, |c| c.outstanding());
+    let check = BudgetCheck {
+        available: line.available() + carried,
+        line,
+        requested: requisition.amount,
+    };
+    if !check.sufficient() {
+        return Err(insufficient(&check));
+    }
+    commit(&store, &check.line, CommitmentSource::Requisition, &requisition.id, requisition.amount, earlier).await
+}
+
+/// Commit funds for an approved purchase order.
+///
+/// The order takes over its requisition's pre-commitment, so only any
+/// difference has to be available. The pre-commitment is released and the
+/// order's commitment made in one save. Orders without a requisition are
+/// funded from their own cost centre.
+pub async fn commit_purchase_order(po: &PurchaseOrder) -> Result<Commitment, String> {
+    let store = ledger().await?;
+    let requisition_commitment = po
+        .requisition_ref
+        .as_deref()
+        .and_then(|id| active_commitment(&store, CommitmentSource::Requisition, id));
+
+    let lines = store.lines.get();
+    let line = match &requisition_commitment {
+        Some(c) if po.cost_center.trim().is_empty() => lines
+            .iter()
+            .find(|l| l.id == c.budget_line_id)
+            .cloned()
+            .ok_or_else(|| format!("Budget line {} not found", c.budget_line_id))?,
+        _ => check_line(&lines, &po.cost_center)?,
+    };
+
+    let carried = requisition_commitment
+        .as_ref()
+        .filter(|c| c.budget_line_id == line.id)
+        .map_or(Money::ZERO, |c| c.outstanding());
+    let check = BudgetCheck {
+        available: line.available() + carried,
+        line,
+        requested: po.total_amount,
+    };
+    if !check.sufficient() {
+        return Err(insufficient(&check));
+    }
+
+    commit(&store, &check.line, CommitmentSource::PurchaseOrder, &po.po_number, po.total_amount, requisition_commitment).await
+}
+
+/// Undo [`commit_purchase_order`] when the order's approval could not be
+/// saved: the order's commitment is released and its requisition's
+/// pre-commitment reinstated in one save.
+pub async fn uncommit_purchase_order(po: &PurchaseOrder) -> Result<(), String> {
+    let store = ledger().await?;
+    let Some(po_commitment) = active_commitment(&store, CommitmentSource::PurchaseOrder, &po.po_number) else {
+        return Ok(());
+    };
+    let taken_over = po.requisition_ref.as_deref().and_then(|id| {
+        store
+            .commitments
+            .get()
+            .into_iter()
+            .filter(|c| c.source == CommitmentSource::Requisition && c.source_id == id)
+            .find(|c| c.status == CommitmentStatus::Released)
+    });
+
+    match taken_over {
+        Some(requisition_commitment) => {
+            let line = store
+                .line(&requisition_commitment.budget_line_id)
+                .ok_or_else(|| format!("Budget line {} not found", requisition_commitment.budget_line_id))?;
+            commit(
+                &store,
+                &line,
+                CommitmentSource::Requisition,
+                &requisition_commitment.source_id,
+                requisition_commitment.amount - requisition_commitment.spent,
+                Some(po_commitment),
+            )
+            .await
+            .map(|_| ())
+        }
+        None => release_commitment(CommitmentSource::PurchaseOrder, &po.po_number).await,
+    }
+}
+
+/// Record actual spend against a purchase order's commitment.
+///
+/// The spend is drawn from what is left of the commitment; anything above
+/// it still counts as expenditure on the same budget line.
+pub async fn record_expenditure(
+    source: ExpenditureSource,
+    source_id: &str,
+    po_number: &str,
+    amount: Money,
+) -> Result<Expenditure, String> {
+    let store = ledger().await?;
+    let mut commitment = active_commitment(&store, CommitmentSource::PurchaseOrder, po_number)
+        .ok_or_else(|| format!("Purchase order {} has no budget commitment", po_number))?;
+
+    let drawn = if amount < commitment.outstanding() { amount } else { commitment.outstanding() };
+    commitment.spent += drawn;
+    if commitment.outstanding().is_zero() {
+        commitment.status = CommitmentStatus::Converted;
+    }
+
+    let expenditure = Expenditure {
+        id: reference::unique_id("EXP"),
+        budget_line_id: commitment.budget_line_id.clone(),
+        source,
+        source_id: source_id.to_string(),
+        commitment_id: Some(commitment.id.clone()),
+        amount,
+        recorded_at: now_iso(),
+    };
+    let expenditure = backend::save(HttpMethod::Post, "/budget/expenditures", &expenditure)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    update_line(&store, &commitment.budget_line_id, |l| {
+        l.committed -= drawn;
+        l.expended += amount;
+    });
+    let mut commitments = store.commitments.get();
+    if let Some(existing) = commitments.iter_mut().find(|c| c.id == commitment.id) {
+        *existing = commitment;
+    }
+    store.commitments.set(commitments);
+    let mut expenditures = store.expenditures.get();
+    expenditures.insert(0, expenditure.clone());
+    store.expenditures.set(expenditures);
+
+    audit::service::record(AuditEntry {
+        action: AuditActionType::Create,
+        entity_type: AuditEntityType::Budget,
+        entity_id: expenditure.id.clone(),
+        entity_name: Some(format!("{} {}", source.label(), source_id)),
+        description: Some(format!(
+            "Recorded {} spend on {} from {} {}",
+            format_currency_full(amount),
+            po_number,
+            source.label(),
+            source_id
+        )),
+        ..Default::default()
+    });
+    Ok(expenditure)
+}
+
+/// Request a transfer between two budget lines and route it for approval
+pub async fn request_transfer(store: &BudgetStore, mut transfer: BudgetTransfer) -> Result<BudgetTransfer, String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::TransferBudget)?;
+        let from = store.line(&transfer.from_line_id).ok_or("Choose the budget to transfer from")?;
+        let to = store.line(&transfer.to_line_id).ok_or("Choose the budget to transfer to")?;
+        validate_transfer(&from, &to, &transfer)?;
+
+        let request = ApprovalRequest::new(ApprovalDocument::BudgetTransfer, transfer.amount)
+            .department(&from.department);
+        transfer.approval_workflow = workflow::approval_chain(&request).await?;
+        transfer.id = reference::unique_id("BTR");
+        transfer.justification = transfer.justification.trim().to_string();
+        transfer.status = TransferStatus::Pending;
+        (transfer.requested_by, transfer.requested_by_id) = auth::current().user.map(|u| (u.name, u.id)).unwrap_or_default();
+        transfer.requested_at = now_iso();
+
+        let saved = backend::save(HttpMethod::Post, "/budget/transfers", &transfer)
+            .await
+            .map_err(|e| e.to_string())?;
+        audit::service::record(AuditEntry {
+            action: AuditActionType::Submit,
+            entity_type: AuditEntityType::Budget,
+            entity_id: saved.id.clone(),
+            entity_name: Some(format!("{} to {}", from.cost_center, to.cost_center)),
+            description: Some(format!(
+                "Requested transfer of {} from {} to {}",
+                format_currency_full(saved.amount),
+                from.display_name(),
+                to.display_name()
+            )),
+            ..Default::default()
+        });
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    match &result {
+        Ok(saved) => {
+            let mut transfers = store.transfers.get();
+            transfers.insert(0, saved.clone());
+            store.transfers.set(transfers);
+        }
+        Err(e) => store.error.set(Some(e.clone())),
+    }
+    store.saving.set(false);
+    result
+}
+
+fn validate_transfer(from: &BudgetLine, to: &BudgetLine, transfer: &BudgetTransfer) -> Result<(), String> {
+    if from.id == to.id {
+        return Err("Choose two different budgets".to_string());
+    }
+    if from.financial_year != to.financial_year {
+        return Err("Budget cannot be transferred between financial years".to_string());
+    }
+    if to.status != BudgetLineStatus::Active || from.status == BudgetLineStatus::Closed {
+        return Err("Transfers are only possible between open budgets".to_string());
+    }
+    if !transfer.amount.is_positive() {
+        return Err("Enter an amount to transfer".to_string());
+    }
+    if transfer.amount > MAX_TRANSFER {
+        return Err(format!("A single transfer may not exceed {}", format_currency_full(MAX_TRANSFER)));
+    }
+    if transfer.amount > from.available() {
+        return Err(format!(
+            "Only {} of {} is uncommitted and can be transferred",
+            format_currency_full(from.available()),
+            from.cost_center
+        ));
+    }
+    if transfer.justification.trim().is_empty() {
+        return Err("Please justify the transfer".to_string());
+    }
+    Ok(())
+}
+
+/// Record an approver's decision on a budget transfer.
+///
+/// Funds move once the last stage approves; a rejection or a return closes
+/// the request, and a new one must be raised.
+pub async fn decide_transfer(store: &BudgetStore, id: &str, decision: &ApprovalDecision) -> Result<(), String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        let mut transfer = store
+            .transfers
+            .get()
+            .into_iter()
+            .find(|t| t.id == id)
+            .ok_or("Budget transfer not found")?;
+        if transfer.status != TransferStatus::Pending {
+            return Err("Only pending transfers can be approved".to_string());
+        }
+
+        // Applied to the local copy only; nothing is audited or saved until
+        // the transfer has been validated
+        let applied = workflow::apply(ApprovalDocument::BudgetTransfer, id, &transfer.requested_by_id, &mut transfer.approval_workflow, decision)?;
+        match applied.state {
+            WorkflowState::Approved => {
+                let from = store.line(&transfer.from_line_id).ok_or("Source budget not found")?;
+                if transfer.amount > from.available() {
+                    return Err(format!(
+                        "{} no longer has {} uncommitted",
+                        from.cost_center,
+                        format_currency_full(transfer.amount)
+                    ));
+                }
+                transfer.status = TransferStatus::Approved;
+                transfer.decided_at = Some(now_iso());
+            }
+            WorkflowState::Rejected | WorkflowState::Returned => {
+                transfer.status = TransferStatus::Rejected;
+                transfer.decided_at = Some(now_iso());
+            }
+            WorkflowState::InProgress { .. } | WorkflowState::NotStarted => {}
+        }
+
+        let endpoint = format!("/budget/transfers/{}/approval", id);
+        let saved = backend::save(HttpMethod::Post, &endpoint, &transfer)
+            .await
+            .map_err(|e| e.to_string())?;
+        applied.record();
+        Ok(saved)
+    }
+    .await;
+
+    match result {
+        Ok(transfer) => {
+            if transfer.status == TransferStatus::Approved {
+                update_line(store, &transfer.from_line_id, |l| l.transferred_out += transfer.amount);
+                update_line(store, &transfer.to_line_id, |l| l.transferred_in += transfer.amount);
+            }
+            let mut transfers = store.transfers.get();
+            if let Some(existing) = transfers.iter_mut().find(|t| t.id == transfer.id) {
+                *existing = transfer;
+            }
+            store.transfers.set(transfers);
+            store.saving.set(false);
+            Ok(())
+        }
+        Err(e) => {
+            store.error.set(Some(e.clone()));
+            store.saving.set(false);
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! Budget control store and mock budget ledger
+
+use std::cell::RefCell;
+use components::prelude::*;
+use crate::features::workflow::types::{ApprovalStep, StepStatus};
+use crate::util::money::Money;
+use super::types::{
+    BudgetCategory, BudgetLine, BudgetLineStatus, BudgetTransfer, Commitment, CommitmentSource,
+    CommitmentStatus, Expenditure, ExpenditureSource, TransferStatus,
+};
+
+/// Budget control state store
+#[derive(Clone)]
+pub struct BudgetStore {
+    pub lines: Signal<Vec<BudgetLine>>,
+    pub commitments: Signal<Vec<Commitment>>,
+    pub expenditures: Signal<Vec<Expenditure>>,
+    pub transfers: Signal<Vec<BudgetTransfer>>,
+    pub loading: Signal<bool>,
+    pub saving: Signal<bool>,
+    pub error: Signal<Option<String>>,
+}
+
+impl BudgetStore {
+    pub fn new() -> Self {
+        Self {
+            lines: signal(Vec::new()),
+            commitments: signal(Vec::new()),
+            expenditures: signal(Vec::new()),
+            transfers: signal(Vec::new()),
+            loading: signal(false),
+            saving: signal(false),
+            error: signal(None),
+        }
+    }
+
+    pub fn line(&self, id: &str) -> Option<BudgetLine> {
+        self.lines.get().into_iter().find(|l| l.id == id)
+    }
+}
+
+thread_local! {
+    static STORE: RefCell<Option<BudgetStore>> = RefCell::new(None);
+}
+
+/// Register the app's budget store so requisitions, purchase orders and
+/// receipts can check and commit funds without being handed it
+pub fn install(store: BudgetStore) {
+    STORE.with(|s| *s.borrow_mut() = Some(store));
+}
+
+/// The installed budget store, or a fresh one outside the app component
+pub fn installed() -> BudgetStore {
+    STORE.with(|s| s.borrow().clone()).unwrap_or_else(|| {
+        let store = BudgetStore::new();
+        install(store.clone());
+        store
+    })
+}
+
+fn line(
+    id: &str,
+    department: &str,
+    cost_center: &str,
+    cost_center_name: &str,
+    gl_account: &str,
+    category: BudgetCategory,
+    allocated: i64,
+    committed: i64,
+    expended: i64,
+) -> BudgetLine {
+    BudgetLine {
+        id: id.to_string(),
+        financial_year: "2024/25".to_string(),
+        department: department.to_string(),
+        cost_center: cost_center.to_string(),
+        cost_center_name: cost_center_name.to_string(),
+        gl_account: gl_account.to_string(),
+        category,
+        allocated: Money::rands(allocated),
+        committed: Money::rands(committed),
+        expended: Money::rands(expended),
+        ..Default::default()
+    }
+}
+
+/// Mock 2024/25 budget lines, one goods-and-services line per cost centre
+pub fn mock_lines() -> Vec<BudgetLine> {
+    use BudgetCategory::*;
+
+    vec![
+        BudgetLine {
+            transferred_in: Money::rands(1_500_000),
+            ..line("BUD-2425-001", "Information Technology", "CC-IT-001", "ICT Infrastructure", "5120", ItTechnology,
+                15_000_000, 9_850_000, 5_420_000)
+        },
+        line("BUD-2425-002", "Administration", "CC-ADM-001", "Head Office Administration", "5010", Opex,
+            2_400_000, 625_000, 1_180_000),
+        line("BUD-2425-003", "Facilities", "CC-FAC-001", "Building Maintenance", "5310", Maintenance,
+            12_000_000, 8_750_000, 2_310_000),
+        line("BUD-2425-004", "Facilities", "CC-FAC-002", "Office Furniture & Fittings", "6110", Capex,
+            3_500_000, 420_000, 1_950_000),
+        line("BUD-2425-005", "Transport", "CC-TRN-001", "Fleet Services", "5420", Opex,
+            6_800_000, 2_950_000, 2_120_000),
+        line("BUD-2425-006", "Human Resources", "CC-HR-001", "Training & Development", "5610", ProfessionalServices,
+            4_200_000, 1_150_000, 1_740_000),
+        line("BUD-2425-007", "Health Services", "CC-HS-001", "Occupational Health", "5230", Opex,
+            1_100_000, 310_000, 640_000),
+        BudgetLine {
+            transferred_out: Money::rands(1_500_000),
+            ..line("BUD-2425-008", "Security", "CC-SEC-001", "Physical Security", "5330", ProfessionalServices,
+                9_500_000, 3_200_000, 2_650_000)
+        },
+        line("BUD-2425-009", "Finance", "CC-FIN-001", "Financial Systems", "5130", ItTechnology,
+            5_000_000, 1_200_000, 1_450_000),
+        line("BUD-2425-010", "Operations", "CC-OPS-003", "Regional Operations Travel", "5510", Travel,
+            1_800_000, 180_000, 1_230_000),
+        BudgetLine {
+            status: BudgetLineStatus::Frozen,
+            ..line("BUD-2425-011", "Operations", "CC-OPS-001", "Regional Office Refurbishment", "6120", Capex,
+                7_500_000, 0, 0)
+        },
+    ]
+}
+
+fn commitment(
+    id: &str,
+    budget_line_id: &str,
+    source: CommitmentSource,
+    source_id: &str,
+    amount: i64,
+    spent: i64,
+    status: CommitmentStatus,
+    created_at: &str,
+) -> Commitment {
+    Commitment {
+        id: id.to_string(),
+        budget_line_id: budget_line_id.to_string(),
+        source,
+        source_id: source_id.to_string(),
+        amount: Money::rands(amount),
+        spent: Money::rands(spent),
+        status,
+        created_at: created_at.to_string(),
+        released_at: None,
+    }
+}
+
+/// Mock commitment register
+pub fn mock_commitments() -> Vec<Commitment> {
+    use CommitmentSource::*;
+    use CommitmentStatus::*;
+
+    vec![
+        commitment("CMT-2025-0112", "BUD-2425-001", Requisition, "REQ-2025-0847", 2_450_000, 0, Active, "2025-02-24T10:30:00Z"),
+        commitment("CMT-2025-0109", "BUD-2425-002", Requisition, "REQ-2025-0846", 125_000, 0, Active, "2025-02-20T14:00:00Z"),
+        commitment("CMT-2025-0104", "BUD-2425-003", Requisition, "REQ-2025-0845", 8_750_000, 0, Active, "2025-02-12T09:15:00Z"),
+        commitment("CMT-2025-0101", "BUD-2425-005", Requisition, "REQ-2025-0844", 1_200_000, 0, Active, "2025-01-28T11:45:00Z"),
+        commitment("CMT-2025-0097", "BUD-2425-007", Requisition, "REQ-2025-0842", 85_000, 0, Active, "2025-02-26T08:20:00Z"),
+        commitment("CMT-2025-0093", "BUD-2425-001", PurchaseOrder, "PO-2025-0456", 1_437_500, 1_437_500, Converted, "2025-02-01T09:00:00Z"),
+        commitment("CMT-2025-0090", "BUD-2425-001", PurchaseOrder, "PO-2025-0453", 131_560, 96_600, Active, "2025-01-25T13:30:00Z"),
+        commitment("CMT-2025-0071", "BUD-2425-002", PurchaseOrder, "PO-2025-0234", 500_000, 0, Active, "2025-01-15T10:00:00Z"),
+        commitment("CMT-2025-0064", "BUD-2425-004", PurchaseOrder, "PO-2025-0156", 420_000, 0, Active, "2025-01-08T15:40:00Z"),
+        Commitment {
+            released_at: Some("2025-01-07T16:05:00Z".to_string()),
+            ..commitment("CMT-2025-0058", "BUD-2425-004", Requisition, "REQ-2025-0841", 450_000, 0, Released, "2025-01-05T09:10:00Z")
+        },
+    ]
+}
+
+/// Mock expenditure recorded from receipts
+pub fn mock_expenditures() -> Vec<Expenditure> {
+    vec![
+        Expenditure {
+            id: "EXP-2025-0231".to_string(),
+            budget_line_id: "BUD-2425-001".to_string(),
+            source: ExpenditureSource::GoodsReceipt,
+            source_id: "GR-2025-0080".to_string(),
+            commitment_id: Some("CMT-2025-0093".to_string()),
+            amount: Money::rands(1_437_500),
+            recorded_at: "2025-02-14T16:30:00Z".to_string(),
+        },
+        Expenditure {
+            id: "EXP-2025-0218".to_string(),
+            budget_line_id: "BUD-2425-001".to_string(),
+            source: ExpenditureSource::GoodsReceipt,
+            source_id: "GR-2025-0081".to_string(),
+            commitment_id: Some("CMT-2025-0090".to_string()),
+            amount: Money::rands(96_600),
+            recorded_at: "2025-02-19T15:10:00Z".to_string(),
+        },
+    ]
+}
+
+/// Mock budget transfer requests
+pub fn mock_transfers() -> Vec<BudgetTransfer> {
+    vec![
+        BudgetTransfer {
+            id: "BTR-2025-007".to_string(),
+            from_line_id: "BUD-2425-010".to_string(),
+            to_line_id: "BUD-2425-006".to_string(),
+            amount: Money::rands(250_000),
+            justification: "Travel freeze in Q4 frees funds for the supply chain certification programme".to_string(),
+            status: TransferStatus::Pending,
+            requested_by: "Nomvula Khumalo".to_string(),
+            requested_by_id: "U021".to_string(),
+            requested_at: "2025-02-25T09:40:00Z".to_string(),
+            decided_at: None,
+            approval_workflow: vec![ApprovalStep::new(1, "CFO")],
+        },
+        BudgetTransfer {
+            id: "BTR-2025-004".to_string(),
+            from_line_id: "BUD-2425-008".to_string(),
+            to_line_id: "BUD-2425-001".to_string(),
+            amount: Money::rands(1_500_000),
+            justification: "Additional server infrastructure required for the ERP migration".to_string(),
+            status: TransferStatus::Approved,
+            requested_by: "Thabo Mokoena".to_string(),
+            requested_by_id: "U001".to_string(),
+            requested_at: "2025-01-20T08:30:00Z".to_string(),
+            decided_at: Some("2025-01-31T16:00:00Z".to_string()),
+            approval_workflow: vec![
+                ApprovalStep {
+                    step: 1,
+                    role: "CFO".to_string(),
+                    approver: Some("Sarah Dlamini".to_string()),
+                    approver_id: Some("U002".to_string()),
+                    status: StepStatus::Approved,
+                    date: Some("2025-01-24T10:15:00Z".to_string()),
+                    comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+                ApprovalStep {
+                    step: 2,
+                    role: "Board".to_string(),
+                    approver: Some("Board resolution BR-2025-03".to_string()),
+                    approver_id: None,
+                    status: StepStatus::Approved,
+                    date: Some("2025-01-31T16:00:00Z".to_string()),
+                    comments: Some("Approved at the January board meeting".to_string()),
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+            ],
+        },
+    ]
+}
+
+/// Load the mock budget ledger into the store
+pub fn load_mock_budget(store: &BudgetStore) {
+    store.lines.set(mock_lines());
+    store.commitments.set(mock_commitments());
+    store.expenditures.set(mock_expenditures());
+    store.transfers.set(mock_transfers());
+}
@@ -0,0 +1,393 @@
+//! Budget control domain types
+
+use serde::{Deserialize, Serialize};
+use crate::features::workflow::types::ApprovalStep;
+use crate::util::i18n::t;
+use crate::util::money::Money;
+
+/// Utilisation at which a budget line starts warning (SRS FR-3.5.2)
+pub const WARNING_UTILISATION: f64 = 75.0;
+/// Utilisation at which a budget line is critical
+pub const CRITICAL_UTILISATION: f64 = 90.0;
+/// Largest single transfer allowed (SRS BR-03)
+pub const MAX_TRANSFER: Money = Money::rands(5_000_000);
+
+/// Standard budget categories
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BudgetCategory {
+    Capex,
+    Opex,
+    Personnel,
+    ItTechnology,
+    ProfessionalServices,
+    Maintenance,
+    Travel,
+}
+
+impl BudgetCategory {
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            BudgetCategory::Capex => "Capital Expenditure",
+            BudgetCategory::Opex => "Operational Expenditure",
+            BudgetCategory::Personnel => "Personnel Costs",
+            BudgetCategory::ItTechnology => "IT & Technology",
+            BudgetCategory::ProfessionalServices => "Professional Services",
+            BudgetCategory::Maintenance => "Maintenance & Repairs",
+            BudgetCategory::Travel => "Travel & Subsistence",
+        })
+    }
+
+    pub fn all() -> Vec<BudgetCategory> {
+        vec![
+            BudgetCategory::Capex,
+            BudgetCategory::Opex,
+            BudgetCategory::Personnel,
+            BudgetCategory::ItTechnology,
+            BudgetCategory::ProfessionalServices,
+            BudgetCategory::Maintenance,
+            BudgetCategory::Travel,
+        ]
+    }
+}
+
+/// Budget line status
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BudgetLineStatus {
+    #[default]
+    Active,
+    /// No new commitments may be made against the line
+    Frozen,
+    Closed,
+}
+
+impl BudgetLineStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BudgetLineStatus::Active => "Active",
+            BudgetLineStatus::Frozen => "Frozen",
+            BudgetLineStatus::Closed => "Closed",
+        }
+    }
+}
+
+/// How far a budget line has been used up
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BudgetAlertLevel {
+    /// Over 75% utilised
+    Warning,
+    /// Over 90% utilised
+    Critical,
+    /// Over budget
+    Exceeded,
+}
+
+impl BudgetAlertLevel {
+    /// Alert for a budget with the given balance and utilisation, if any
+    pub fn of(available: Money, utilisation: f64) -> Option<BudgetAlertLevel> {
+        if available.is_negative() {
+            Some(BudgetAlertLevel::Exceeded)
+        } else if utilisation > CRITICAL_UTILISATION {
+            Some(BudgetAlertLevel::Critical)
+        } else if utilisation > WARNING_UTILISATION {
+            Some(BudgetAlertLevel::Warning)
+        } else {
+            None
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            BudgetAlertLevel::Warning => "Warning",
+            BudgetAlertLevel::Critical => "Critical",
+            BudgetAlertLevel::Exceeded => "Exceeded",
+        })
+    }
+
+    pub fn class(&self) -> &'static str {
+        match self {
+            BudgetAlertLevel::Warning => "warning",
+            BudgetAlertLevel::Critical => "critical",
+            BudgetAlertLevel::Exceeded => "exceeded",
+        }
+    }
+}
+
+/// A cost centre's allocation against one GL account for a financial year
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BudgetLine {
+    pub id: String,
+    /// e.g. "2024/25"
+    pub financial_year: String,
+    pub department: String,
+    pub cost_center: String,
+    pub cost_center_name: String,
+    pub gl_account: String,
+    pub category: BudgetCategory,
+    /// Original allocation
+    pub allocated: Money,
+    /// Funds reserved by requisitions and purchase orders
+    pub committed: Money,
+    /// Actual spend recorded from receipts and invoices
+    pub expended: Money,
+    pub transferred_in: Money,
+    pub transferred_out: Money,
+    pub status: BudgetLineStatus,
+}
+
+impl Default for BudgetLine {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            financial_year: String::new(),
+            department: String::new(),
+            cost_center: String::new(),
+            cost_center_name: String::new(),
+            gl_account: String::new(),
+            category: BudgetCategory::Opex,
+            allocated: Money::ZERO,
+            committed: Money::ZERO,
+            expended: Money::ZERO,
+            transferred_in: Money::ZERO,
+            transferred_out: Money::ZERO,
+            status: BudgetLineStatus::Active,
+        }
+    }
+}
+
+impl BudgetLine {
+    /// Allocation after transfers
+    pub fn budget(&self) -> Money {
+        self.allocated + self.transferred_in - self.transferred_out
+    }
+
+    /// Budget neither committed nor spent
+    pub fn available(&self) -> Money {
+        self.budget() - self.committed - self.expended
+    }
+
+    /// Committed and spent funds as a percentage of the budget
+    pub fn utilisation(&self) -> f64 {
+        (self.committed + self.expended).percent_of(self.budget())
+    }
+
+    pub fn alert(&self) -> Option<BudgetAlertLevel> {
+        BudgetAlertLevel::of(self.available(), self.utilisation())
+    }
+
+    /// "CC-IT-001 · 5120 IT & Technology"
+    pub fn display_name(&self) -> String {
+        format!("{} · {} {}", self.cost_center, self.gl_account, self.category.label())
+    }
+}
+
+/// Document that reserved funds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommitmentSource {
+    /// Pre-commitment made when the requisition is submitted
+    Requisition,
+    PurchaseOrder,
+    Contract,
+}
+
+impl CommitmentSource {
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            CommitmentSource::Requisition => "Requisition",
+            CommitmentSource::PurchaseOrder => "Purchase Order",
+            CommitmentSource::Contract => "Contract",
+        })
+    }
+}
+
+/// Commitment status
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommitmentStatus {
+    #[default]
+    Active,
+    /// Returned to the available balance
+    Released,
+    /// Fully turned into expenditure
+    Converted,
+}
+
+impl CommitmentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommitmentStatus::Active => "Active",
+            CommitmentStatus::Released => "Released",
+            CommitmentStatus::Converted => "Converted",
+        }
+    }
+}
+
+/// Funds reserved against a budget line by a procurement document
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Commitment {
+    pub id: String,
+    pub budget_line_id: String,
+    pub source: CommitmentSource,
+    /// Requisition ID or PO number
+    pub source_id: String,
+    pub amount: Money,
+    /// Part of the commitment already turned into expenditure
+    pub spent: Money,
+    pub status: CommitmentStatus,
+    pub created_at: String,
+    pub released_at: Option<String>,
+}
+
+impl Commitment {
+    /// Amount still reserved
+    pub fn outstanding(&self) -> Money {
+        if self.status == CommitmentStatus::Active {
+            self.amount - self.spent
+        } else {
+            Money::ZERO
+        }
+    }
+}
+
+/// One commitment released and another made in its place, saved in a
+/// single request so the funds are never left unreserved in between
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CommitmentHandover {
+    pub released: Commitment,
+    pub committed: Commitment,
+}
+
+/// Document that recorded actual spend
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExpenditureSource {
+    GoodsReceipt,
+    Invoice,
+}
+
+impl ExpenditureSource {
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            ExpenditureSource::GoodsReceipt => "Goods Receipt",
+            ExpenditureSource::Invoice => "Invoice",
+        })
+    }
+}
+
+/// Actual spend recorded against a budget line
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Expenditure {
+    pub id: String,
+    pub budget_line_id: String,
+    pub source: ExpenditureSource,
+    pub source_id: String,
+    /// Commitment the spend was drawn from
+    pub commitment_id: Option<String>,
+    pub amount: Money,
+    pub recorded_at: String,
+}
+
+/// Outcome of checking whether a cost centre can fund an amount
+#[derive(Clone, Debug, PartialEq)]
+pub struct BudgetCheck {
+    pub line: BudgetLine,
+    pub requested: Money,
+    pub available: Money,
+}
+
+impl BudgetCheck {
+    pub fn sufficient(&self) -> bool {
+        self.requested <= self.available
+    }
+
+    /// Amount the request is short by
+    pub fn shortfall(&self) -> Money {
+        if self.sufficient() {
+            Money::ZERO
+        } else {
+            self.requested - self.available
+        }
+    }
+}
+
+/// Budget transfer status
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransferStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl TransferStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransferStatus::Pending => "Pending",
+            TransferStatus::Approved => "Approved",
+            TransferStatus::Rejected => "Rejected",
+        }
+    }
+}
+
+/// Request to move budget between lines of the same financial year
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BudgetTransfer {
+    pub id: String,
+    pub from_line_id: String,
+    pub to_line_id: String,
+    pub amount: Money,
+    pub justification: String,
+    pub status: TransferStatus,
+    pub requested_by: String,
+    /// Id of the user who requested the transfer
+    #[serde(default)]
+    pub requested_by_id: String,
+    pub requested_at: String,
+    pub decided_at: Option<String>,
+    #[serde(default)]
+    pub approval_workflow: Vec<ApprovalStep>,
+}
+
+impl Default for BudgetTransfer {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            from_line_id: String::new(),
+            to_line_id: String::new(),
+            amount: Money::ZERO,
+            justification: String::new(),
+            status: TransferStatus::Pending,
+            requested_by: String::new(),
+            requested_by_id: String::new(),
+            requested_at: String::new(),
+            decided_at: None,
+            approval_workflow: Vec::new(),
+        }
+    }
+}
+
+/// Totals across a set of budget lines
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BudgetTotals {
+    pub budget: Money,
+    pub committed: Money,
+    pub expended: Money,
+    pub available: Money,
+}
+
+impl BudgetTotals {
+    pub fn of<'a>(lines: impl IntoIterator<Item = &'a BudgetLine>) -> Self {
+        lines.into_iter().fold(Self::default(), |mut totals, line| {
+            totals.budget += line.budget();
+            totals.committed += line.committed;
+            totals.expended += line.expended;
+            totals.available += line.available();
+            totals
+        })
+    }
+
+    pub fn utilisation(&self) -> f64 {
+        (self.committed + self.expended).percent_of(self.budget)
+    }
+
+    pub fn alert(&self) -> Option<BudgetAlertLevel> {
+        BudgetAlertLevel::of(self.available, self.utilisation())
+    }
+}
@@ -19,6 +19,7 @@ use crate::util::format::{format_currency, format_number};
 use super::store::CatalogueStore;
 use super::types::{CatalogueItem, CatalogueItemStatus, CatalogueCategory};
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Catalogue admin page
 #[component]
@@ -276,13 +277,13 @@ pub fn catalogue_admin() -> View {
 
     // Table columns
     let columns = vec![
-        DataTableColumn { key: "code".to_string(), label: "Item Code".to_string(), width: Some("110px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "name".to_string(), label: "Item Name".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "category".to_string(), label: "Category".to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "supplier".to_string(), label: "Supplier".to_string(), width: Some("180px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "price".to_string(), label: "Unit Price".to_string(), width: Some("120px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "stock".to_string(), label: "Stock".to_string(), width: Some("100px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "code".to_string(), label: t("Item Code").to_string(), width: Some("110px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
+        DataTableColumn { key: "name".to_string(), label: t("Item Name").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "category".to_string(), label: t("Category").to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: Some("180px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "price".to_string(), label: t("Unit Price").to_string(), width: Some("120px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "stock".to_string(), label: t("Stock").to_string(), width: Some("100px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
         DataTableColumn { key: "actions".to_string(), label: "".to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
     ];
 
@@ -291,9 +292,9 @@ pub fn catalogue_admin() -> View {
         let status_view = get_status_badge(&item.status);
         let bbbee_view = get_bbbee_badge(item.supplier.bbbee_level);
         let stock_view = if item.in_stock {
-            tag("In Stock".to_string(), TagType::Green)
+            tag(t("In Stock").to_string(), TagType::Green)
         } else {
-            tag("Out".to_string(), TagType::Red)
+            tag(t("Out").to_string(), TagType::Red)
         };
 
         let item_id = item.id.clone();
@@ -308,7 +309,7 @@ pub fn catalogue_admin() -> View {
                     <div class="item-info">
                         <span class="item-name">{item.name.clone()}</span>
                         if item.featured {
-                            <span class="featured-tag">"Featured"</span>
+                            <span class="featured-tag">{t("Featured")}</span>
                         }
                     </div>
                 },
@@ -328,7 +329,7 @@ pub fn catalogue_admin() -> View {
                 status_view,
                 view! {
                     <div class="row-actions">
-                        <button class="btn btn-sm btn-secondary">"Edit"</button>
+                        <button class="btn btn-sm btn-secondary">{t("Edit")}</button>
                     </div>
                 },
             ],
@@ -461,19 +462,23 @@ pub fn catalogue_admin() -> View {
 
         <div class="catalogue-admin" data-testid="catalogue-admin">
             {page_header(
-                "Catalogue Management".to_string(),
-                Some("Add, edit, and manage catalogue items".to_string()),
+                t("Catalogue Management").to_string(),
+                Some(t("Add, edit, and manage catalogue items").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Import CSV"</button> },
-                    view! { <button class="btn btn-secondary">"Export"</button> },
-                    view! { <button class="btn btn-primary" on:click={handle_add_click.clone()}>"Add Item"</button> },
+                    view! { <button class="btn btn-secondary">{t("Import CSV")}</button> },
+                    view! { <button class="btn btn-secondary">{t("Export")}</button> },
+                    view! { <button class="btn btn-primary" on:click={handle_add_click.clone()}>{t("Add Item")}</button> },
                 ]
             )}
 
             // Pending approval notice
             if pending_count > 0 {
                 {notice_bar(
-                    format!("{} item(s) pending approval. Review and approve to make them available.", pending_count),
+                    tn(
+                        "{n} item pending approval. Review and approve to make it available.",
+                        "{n} items pending approval. Review and approve to make them available.",
+                        pending_count as i64,
+                    ),
                     NoticeType::Warning,
                     None
                 )}
@@ -482,7 +487,7 @@ pub fn catalogue_admin() -> View {
             // KPI summary
             <div class="kpi-grid">
                 {kpi_card(
-                    "Total Items".to_string(),
+                    t("Total Items").to_string(),
                     format_number(kpis_data.total_items),
                     KpiColor::Blue,
                     icon_package.to_string(),
@@ -490,7 +495,7 @@ pub fn catalogue_admin() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Active Items".to_string(),
+                    t("Active Items").to_string(),
                     format_number(kpis_data.active_items),
                     KpiColor::Green,
                     icon_check.to_string(),
@@ -498,7 +503,7 @@ pub fn catalogue_admin() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Pending Approval".to_string(),
+                    t("Pending Approval").to_string(),
                     kpis_data.pending_approval.to_string(),
                     KpiColor::Orange,
                     icon_clock.to_string(),
@@ -506,7 +511,7 @@ pub fn catalogue_admin() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Out of Stock".to_string(),
+                    t("Out of Stock").to_string(),
                     kpis_data.out_of_stock.to_string(),
                     KpiColor::Red,
                     icon_alert.to_string(),
@@ -519,21 +524,21 @@ pub fn catalogue_admin() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Status:"</label>
+                        <label>{t("Status:")}</label>
                         <select on:change={handle_status_change} value={status_filter.get()}>
-                            <option value="">"All Statuses"</option>
-                            <option value="active">"Active"</option>
-                            <option value="inactive">"Inactive"</option>
-                            <option value="discontinued">"Discontinued"</option>
-                            <option value="pending_approval">"Pending Approval"</option>
+                            <option value="">{t("All Statuses")}</option>
+                            <option value="active">{t("Active")}</option>
+                            <option value="inactive">{t("Inactive")}</option>
+                            <option value="discontinued">{t("Discontinued")}</option>
+                            <option value="pending_approval">{t("Pending Approval")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Category:"</label>
+                        <label>{t("Category:")}</label>
                         <select on:change={handle_category_change} value={category_filter.get()}>
-                            <option value="">"All Categories"</option>
+                            <option value="">{t("All Categories")}</option>
                             for cat in cats.iter() {
                                 <option value={cat.id.clone()}>{cat.name.clone()}</option>
                             }
@@ -546,30 +551,30 @@ pub fn catalogue_admin() -> View {
                         <input
                             type="text"
                             class="search-input"
-                            placeholder="Search items..."
+                            placeholder={t("Search items...")}
                             value={search_query.get()}
                             on:input={handle_search}
                         />
                     </div>
                 },
                 view! {
-                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>"Clear"</button>
+                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>{t("Clear")}</button>
                 },
             ])}
 
             // Items table
             {panel(
-                format!("Catalogue Items ({})", filtered_items.len()),
+                tf("Catalogue Items ({count})", &[("count", filtered_items.len().to_string())]),
                 vec![],
                 vec![
                     if loading.get() {
-                        view! { <div class="loading-state">"Loading items..."</div> }
+                        view! { <div class="loading-state">{t("Loading items...")}</div> }
                     } else if rows.is_empty() {
                         {empty_state(
-                            "No items found".to_string(),
-                            Some("Try adjusting your filters or add a new item".to_string()),
+                            t("No items found").to_string(),
+                            Some(t("Try adjusting your filters or add a new item").to_string()),
                             None,
-                            Some(view! { <button class="btn btn-primary" on:click={handle_add_click.clone()}>"Add Item"</button> })
+                            Some(view! { <button class="btn btn-primary" on:click={handle_add_click.clone()}>{t("Add Item")}</button> })
                         )}
                     } else {
                         view! {
@@ -586,7 +591,7 @@ pub fn catalogue_admin() -> View {
         // Add Item Modal
         if show_add_modal.get() {
             {modal(
-                "Add Catalogue Item".to_string(),
+                t("Add Catalogue Item").to_string(),
                 ModalSize::Medium,
                 show_add_modal.clone(),
                 handle_close_add.clone(),
@@ -594,10 +599,10 @@ pub fn catalogue_admin() -> View {
                     <div class="modal-form">
                         <div class="form-row">
                             <div class="form-field">
-                                <label>"Item Code"</label>
+                                <label>{t("Item Code")}</label>
                                 <input
                                     type="text"
-                                    placeholder="e.g., IT-LAP-001"
+                                    placeholder={t("e.g., IT-LAP-001")}
                                     value={form_code.get()}
                                     on:input={Callback::new({
                                         let form_code = form_code.clone();
@@ -610,7 +615,7 @@ pub fn catalogue_admin() -> View {
                                 />
                             </div>
                             <div class="form-field">
-                                <label>"Category"</label>
+                                <label>{t("Category")}</label>
                                 <select
                                     value={form_category.get()}
                                     on:change={Callback::new({
@@ -622,7 +627,7 @@ pub fn catalogue_admin() -> View {
                                         }
                                     })}
                                 >
-                                    <option value="">"Select category..."</option>
+                                    <option value="">{t("Select category...")}</option>
                                     for cat in cats.iter() {
                                         <option value={cat.id.clone()}>{cat.name.clone()}</option>
                                     }
@@ -630,10 +635,10 @@ pub fn catalogue_admin() -> View {
                             </div>
                         </div>
                         <div class="form-field">
-                            <label>"Item Name"</label>
+                            <label>{t("Item Name")}</label>
                             <input
                                 type="text"
-                                placeholder="Enter item name"
+                                placeholder={t("Enter item name")}
                                 value={form_name.get()}
                                 on:input={Callback::new({
                                     let form_name = form_name.clone();
@@ -646,9 +651,9 @@ pub fn catalogue_admin() -> View {
                             />
                         </div>
                         <div class="form-field">
-                            <label>"Description"</label>
+                            <label>{t("Description")}</label>
                             <textarea
-                                placeholder="Enter item description"
+                                placeholder={t("Enter item description")}
                                 value={form_description.get()}
                                 on:input={Callback::new({
                                     let form_description = form_description.clone();
@@ -662,7 +667,7 @@ pub fn catalogue_admin() -> View {
                         </div>
                         <div class="form-row">
                             <div class="form-field">
-                                <label>"Unit Price (ZAR)"</label>
+                                <label>{t("Unit Price (ZAR)")}</label>
                                 <input
                                     type="number"
                                     step="0.01"
@@ -679,7 +684,7 @@ pub fn catalogue_admin() -> View {
                                 />
                             </div>
                             <div class="form-field">
-                                <label>"Supplier"</label>
+                                <label>{t("Supplier")}</label>
                                 <select
                                     value={form_supplier.get()}
                                     on:change={Callback::new({
@@ -691,7 +696,7 @@ pub fn catalogue_admin() -> View {
                                         }
                                     })}
                                 >
-                                    <option value="">"Select supplier..."</option>
+                                    <option value="">{t("Select supplier...")}</option>
                                     <option value="SUP-001">"TechSolutions SA (Pty) Ltd"</option>
                                     <option value="SUP-002">"Office Essentials SA"</option>
                                     <option value="SUP-004">"SecureGuard Holdings"</option>
@@ -703,8 +708,8 @@ pub fn catalogue_admin() -> View {
                     </div>
                 }],
                 vec![
-                    view! { <button class="btn btn-secondary" on:click={handle_close_add}>"Cancel"</button> },
-                    view! { <button class="btn btn-primary">"Add Item"</button> },
+                    view! { <button class="btn btn-secondary" on:click={handle_close_add}>{t("Cancel")}</button> },
+                    view! { <button class="btn btn-primary">{t("Add Item")}</button> },
                 ]
             )}
         }
@@ -712,7 +717,7 @@ pub fn catalogue_admin() -> View {
         // Edit Item Modal
         if show_edit_modal.get() {
             {modal(
-                "Edit Catalogue Item".to_string(),
+                t("Edit Catalogue Item").to_string(),
                 ModalSize::Medium,
                 show_edit_modal.clone(),
                 handle_close_edit.clone(),
@@ -720,20 +725,20 @@ pub fn catalogue_admin() -> View {
                     <div>
                         // Status actions
                         <div class="status-actions">
-                            <button class="btn btn-sm btn-success" on:click={handle_approve}>"Approve"</button>
-                            <button class="btn btn-sm btn-secondary" on:click={handle_activate}>"Activate"</button>
-                            <button class="btn btn-sm btn-warning" on:click={handle_deactivate}>"Deactivate"</button>
-                            <button class="btn btn-sm btn-danger" on:click={handle_delete}>"Delete"</button>
+                            <button class="btn btn-sm btn-success" on:click={handle_approve}>{t("Approve")}</button>
+                            <button class="btn btn-sm btn-secondary" on:click={handle_activate}>{t("Activate")}</button>
+                            <button class="btn btn-sm btn-warning" on:click={handle_deactivate}>{t("Deactivate")}</button>
+                            <button class="btn btn-sm btn-danger" on:click={handle_delete}>{t("Delete")}</button>
                         </div>
 
                         <div class="modal-form">
                             <div class="form-row">
                                 <div class="form-field">
-                                    <label>"Item Code"</label>
+                                    <label>{t("Item Code")}</label>
                                     <input type="text" value={form_code.get()} disabled={true} />
                                 </div>
                                 <div class="form-field">
-                                    <label>"Category"</label>
+                                    <label>{t("Category")}</label>
                                     <select value={form_category.get()}>
                                         for cat in cats.iter() {
                                             <option value={cat.id.clone()}>{cat.name.clone()}</option>
@@ -742,20 +747,20 @@ pub fn catalogue_admin() -> View {
                                 </div>
                             </div>
                             <div class="form-field">
-                                <label>"Item Name"</label>
+                                <label>{t("Item Name")}</label>
                                 <input type="text" value={form_name.get()} />
                             </div>
                             <div class="form-field">
-                                <label>"Description"</label>
+                                <label>{t("Description")}</label>
                                 <textarea value={form_description.get()}></textarea>
                             </div>
                             <div class="form-row">
                                 <div class="form-field">
-                                    <label>"Unit Price (ZAR)"</label>
+                                    <label>{t("Unit Price (ZAR)")}</label>
                                     <input type="number" step="0.01" value={form_price.get()} />
                                 </div>
                                 <div class="form-field">
-                                    <label>"Supplier"</label>
+                                    <label>{t("Supplier")}</label>
                                     <input type="text" value={form_supplier.get()} disabled={true} />
                                 </div>
                             </div>
@@ -763,8 +768,8 @@ pub fn catalogue_admin() -> View {
                     </div>
                 }],
                 vec![
-                    view! { <button class="btn btn-secondary" on:click={handle_close_edit}>"Cancel"</button> },
-                    view! { <button class="btn btn-primary">"Save Changes"</button> },
+                    view! { <button class="btn btn-secondary" on:click={handle_close_edit}>{t("Cancel")}</button> },
+                    view! { <button class="btn btn-primary">{t("Save Changes")}</button> },
                 ]
             )}
         }
@@ -772,18 +777,18 @@ pub fn catalogue_admin() -> View {
         // Delete Confirmation Modal
         if show_delete_modal.get() {
             {modal(
-                "Delete Item".to_string(),
+                t("Delete Item").to_string(),
                 ModalSize::Small,
                 show_delete_modal.clone(),
                 handle_close_delete.clone(),
                 vec![view! {
                     <div class="delete-confirm">
-                        <p>"Are you sure you want to delete this catalogue item? This action cannot be undone."</p>
+                        <p>{t("Are you sure you want to delete this catalogue item? This action cannot be undone.")}</p>
                     </div>
                 }],
                 vec![
-                    view! { <button class="btn btn-secondary" on:click={handle_close_delete}>"Cancel"</button> },
-                    view! { <button class="btn btn-danger" on:click={handle_confirm_delete}>"Delete Item"</button> },
+                    view! { <button class="btn btn-secondary" on:click={handle_close_delete}>{t("Cancel")}</button> },
+                    view! { <button class="btn btn-danger" on:click={handle_confirm_delete}>{t("Delete Item")}</button> },
                 ]
             )}
         }
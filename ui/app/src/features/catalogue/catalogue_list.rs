@@ -16,6 +16,7 @@ use crate::util::format::{format_currency, format_number};
 use super::store::CatalogueStore;
 use super::types::{CatalogueItemStatus, CatalogueCategory, CatalogueSortBy};
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Catalogue browse page
 #[component]
@@ -431,17 +432,17 @@ pub fn catalogue_list() -> View {
 
         <div class="catalogue-page" data-testid="catalogue-list">
             {page_header(
-                "Catalogue".to_string(),
-                Some("Browse and search available items".to_string()),
+                t("Catalogue").to_string(),
+                Some(t("Browse and search available items").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export"</button> },
+                    view! { <button class="btn btn-secondary">{t("Export")}</button> },
                 ]
             )}
 
             // KPI summary
             <div class="kpi-grid">
                 {kpi_card(
-                    "Total Items".to_string(),
+                    t("Total Items").to_string(),
                     format_number(kpis_data.total_items),
                     KpiColor::Blue,
                     icon_package.to_string(),
@@ -449,7 +450,7 @@ pub fn catalogue_list() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Categories".to_string(),
+                    t("Categories").to_string(),
                     kpis_data.categories_count.to_string(),
                     KpiColor::Accent,
                     icon_layers.to_string(),
@@ -457,7 +458,7 @@ pub fn catalogue_list() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Suppliers".to_string(),
+                    t("Suppliers").to_string(),
                     kpis_data.suppliers_count.to_string(),
                     KpiColor::Green,
                     icon_users.to_string(),
@@ -465,7 +466,7 @@ pub fn catalogue_list() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Out of Stock".to_string(),
+                    t("Out of Stock").to_string(),
                     kpis_data.out_of_stock.to_string(),
                     KpiColor::Red,
                     icon_alert.to_string(),
@@ -478,9 +479,9 @@ pub fn catalogue_list() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Category"</label>
+                        <label>{t("Category")}</label>
                         <select on:change={handle_category_change}>
-                            <option value="">"All Categories"</option>
+                            <option value="">{t("All Categories")}</option>
                             for cat in cats.iter() {
                                 <option value={cat.id.clone()}>{cat.name.clone()}</option>
                             }
@@ -489,26 +490,26 @@ pub fn catalogue_list() -> View {
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"B-BBEE Level"</label>
+                        <label>{t("B-BBEE Level")}</label>
                         <select on:change={handle_bbbee_change}>
-                            <option value="">"All Levels"</option>
-                            <option value="1">"Level 1"</option>
-                            <option value="2">"Level 1-2"</option>
-                            <option value="3">"Level 1-3"</option>
-                            <option value="4">"Level 1-4"</option>
+                            <option value="">{t("All Levels")}</option>
+                            <option value="1">{t("Level 1")}</option>
+                            <option value="2">{t("Level 1-2")}</option>
+                            <option value="3">{t("Level 1-3")}</option>
+                            <option value="4">{t("Level 1-4")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Sort By"</label>
+                        <label>{t("Sort By")}</label>
                         <select on:change={handle_sort_change}>
-                            <option value="name_asc">"Name (A-Z)"</option>
-                            <option value="name_desc">"Name (Z-A)"</option>
-                            <option value="price_asc">"Price (Low to High)"</option>
-                            <option value="price_desc">"Price (High to Low)"</option>
-                            <option value="category">"Category"</option>
-                            <option value="recent">"Recently Added"</option>
+                            <option value="name_asc">{t("Name (A-Z)")}</option>
+                            <option value="name_desc">{t("Name (Z-A)")}</option>
+                            <option value="price_asc">{t("Price (Low to High)")}</option>
+                            <option value="price_desc">{t("Price (High to Low)")}</option>
+                            <option value="category">{t("Category")}</option>
+                            <option value="recent">{t("Recently Added")}</option>
                         </select>
                     </div>
                 },
@@ -519,7 +520,7 @@ pub fn catalogue_list() -> View {
                             checked={in_stock_only.get()}
                             on:change={handle_stock_toggle}
                         />
-                        "In stock only"
+                        {t("In stock only")}
                     </label>
                 },
                 view! { <div class="filter-spacer"></div> },
@@ -528,7 +529,7 @@ pub fn catalogue_list() -> View {
                         <input
                             type="text"
                             class="search-input"
-                            placeholder="Search items..."
+                            placeholder={t("Search items...")}
                             value={search_query.get()}
                             on:input={handle_search}
                         />
@@ -542,32 +543,32 @@ pub fn catalogue_list() -> View {
                                 let handle_toggle_view = handle_toggle_view.clone();
                                 move |_| handle_toggle_view.call("grid".to_string())
                             })}
-                        >"Grid"</button>
+                        >{t("Grid")}</button>
                         <button
                             class={if view_mode.get() == "list" { "active" } else { "" }}
                             on:click={Callback::<web_sys::MouseEvent>::new({
                                 let handle_toggle_view = handle_toggle_view.clone();
                                 move |_| handle_toggle_view.call("list".to_string())
                             })}
-                        >"List"</button>
+                        >{t("List")}</button>
                     </div>
                 },
                 view! {
-                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>"Clear"</button>
+                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>{t("Clear")}</button>
                 },
             ])}
 
             // Main content
             {panel(
-                format!("Items ({} found)", filtered_items.len()),
+                tf("Items ({count} found)", &[("count", filtered_items.len().to_string())]),
                 vec![],
                 vec![
                     if loading.get() {
-                        view! { <div class="loading-state">"Loading catalogue..."</div> }
+                        view! { <div class="loading-state">{t("Loading catalogue...")}</div> }
                     } else if filtered_items.is_empty() {
                         {empty_state(
-                            "No items found".to_string(),
-                            Some("Try adjusting your search or filters".to_string()),
+                            t("No items found").to_string(),
+                            Some(t("Try adjusting your search or filters").to_string()),
                             None,
                             None
                         )}
@@ -624,10 +625,10 @@ fn item_card(item: super::types::CatalogueItem, on_click: Callback<String>) -> V
         <div class={card_class} on:click={handle_click}>
             <div class="item-image">
                 if item.featured {
-                    <span class="featured-badge">"Featured"</span>
+                    <span class="featured-badge">{t("Featured")}</span>
                 }
                 if !item.in_stock {
-                    <span class="out-of-stock-badge">"Out of Stock"</span>
+                    <span class="out-of-stock-badge">{t("Out of Stock")}</span>
                 }
                 // Placeholder icon if no image
                 <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5" width="48" height="48">
@@ -643,8 +644,8 @@ fn item_card(item: super::types::CatalogueItem, on_click: Callback<String>) -> V
                 <div class="item-code">{item.item_code.clone()}</div>
                 <div class="item-price">
                     <span class="currency">"R"</span>
-                    {format!("{:.2}", item.unit_price)}
-                    <span class="vat">" incl. VAT"</span>
+                    {item.price_incl_vat().to_string()}
+                    <span class="vat">{t(" incl. VAT")}</span>
                 </div>
                 <div class="item-footer">
                     <div class="item-supplier">
@@ -677,9 +678,9 @@ fn item_row(item: super::types::CatalogueItem, on_click: Callback<String>) -> Vi
     };
 
     let stock_tag = if item.in_stock {
-        tag("In Stock".to_string(), TagType::Green)
+        tag(t("In Stock").to_string(), TagType::Green)
     } else {
-        tag("Out of Stock".to_string(), TagType::Red)
+        tag(t("Out of Stock").to_string(), TagType::Red)
     };
 
     view! {
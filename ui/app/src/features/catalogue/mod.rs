@@ -3,5 +3,6 @@
 pub mod types;
 pub mod store;
 pub mod service;
+pub mod search;
 pub mod catalogue_list;
 pub mod catalogue_admin;
@@ -0,0 +1,49 @@
+//! Catalogue item search provider
+
+use crate::Route;
+use crate::features::search::provider::{SearchFuture, SearchProvider};
+use crate::features::search::types::{match_score, SearchEntity, SearchHit};
+use super::store::CatalogueStore;
+use super::service;
+
+/// Searches the catalogue items held in the store, loading them on first use
+pub struct CatalogueSearch {
+    store: CatalogueStore,
+}
+
+impl CatalogueSearch {
+    pub fn new(store: CatalogueStore) -> Self {
+        Self { store }
+    }
+}
+
+impl SearchProvider for CatalogueSearch {
+    fn entity(&self) -> SearchEntity {
+        SearchEntity::CatalogueItem
+    }
+
+    fn search(&self, query: String) -> SearchFuture {
+        let store = self.store.clone();
+        Box::pin(async move {
+            if store.items.get().is_empty() {
+                service::load_catalogue(&store).await;
+                if let Some(e) = store.error.get() {
+                    return Err(e);
+                }
+            }
+
+            let hits = store.items.get().iter().filter_map(|item| {
+                let score = match_score(&query, &[&item.item_code], &[&item.name, &item.category_name, &item.supplier.name])?;
+                Some(SearchHit {
+                    entity: SearchEntity::CatalogueItem,
+                    id: item.id.clone(),
+                    title: item.name.clone(),
+                    subtitle: format!("{} - {} - {}", item.item_code, item.category_name, item.supplier.name),
+                    route: Route::CatalogueList,
+                    score,
+                })
+            }).collect();
+            Ok(hits)
+        })
+    }
+}
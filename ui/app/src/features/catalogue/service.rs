@@ -1,5 +1,8 @@
 //! Catalogue service - API calls
 
+use crate::util::api::{self, ApiError, HttpMethod};
+use crate::util::backend;
+use crate::util::money::Money;
 use super::store::{CatalogueStore, load_mock_data, select_item, clear_selection};
 use super::types::{CatalogueItem, CatalogueCategory, CatalogueFilter, CatalogueItemStatus};
 
@@ -8,10 +11,7 @@ pub async fn load_catalogue(store: &CatalogueStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API:
-    // let response = api::get("/api/catalogue/items", &store.filter.get()).await;
-    // For now, load mock data
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -27,11 +27,15 @@ pub async fn get_item(store: &CatalogueStore, item_id: &str) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::get(&format!("/api/catalogue/items/{}", item_id)).await;
-    // For now, find in mock data
     select_item(store, item_id);
 
+    if store.selected.get().is_none() && !backend::is_mock() {
+        match api::get::<CatalogueItem>(&format!("/catalogue/items/{}", item_id)).await {
+            Ok(item) => store.selected.set(Some(item)),
+            Err(e) => store.error.set(Some(e.to_string())),
+        }
+    }
+
     store.loading.set(false);
 }
 
@@ -40,9 +44,12 @@ pub async fn load_categories(store: &CatalogueStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::get("/api/catalogue/categories").await;
-    // Categories are loaded with mock data
+    // Mock categories are loaded together with the mock items
+    if !backend::is_mock() {
+        if let Err(e) = backend::fetch_into("/catalogue/categories", &store.categories).await {
+            store.error.set(Some(e.to_string()));
+        }
+    }
 
     store.loading.set(false);
 }
@@ -52,10 +59,7 @@ pub async fn create_item(store: &CatalogueStore, item: CatalogueItem) -> Result<
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::post("/api/catalogue/items", &item).await;
-
-    // Generate a new ID for mock
+    // Provisional ID, replaced by the server's when using the REST backend
     let mut new_item = item;
     let count = store.items.get().len() + 1;
     new_item.id = format!("ITEM-{:03}", count);
@@ -63,6 +67,15 @@ pub async fn create_item(store: &CatalogueStore, item: CatalogueItem) -> Result<
     new_item.created_at = "2025-02-15".to_string();
     new_item.updated_at = "2025-02-15".to_string();
 
+    match backend::save(HttpMethod::Post, "/catalogue/items", &new_item).await {
+        Ok(saved) => new_item = saved,
+        Err(e) => {
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+
     // Add to list
     let mut items = store.items.get();
     items.push(new_item.clone());
@@ -77,14 +90,20 @@ pub async fn update_item(store: &CatalogueStore, item: CatalogueItem) -> Result<
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::put(&format!("/api/catalogue/items/{}", item.id), &item).await;
-
-    // Update in mock list
     let mut items = store.items.get();
     if let Some(pos) = items.iter().position(|i| i.id == item.id) {
         let mut updated_item = item.clone();
         updated_item.updated_at = "2025-02-15".to_string();
+
+        let endpoint = format!("/catalogue/items/{}", item.id);
+        match backend::save(HttpMethod::Put, &endpoint, &updated_item).await {
+            Ok(saved) => updated_item = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         items[pos] = updated_item.clone();
         store.items.set(items);
         store.selected.set(Some(updated_item.clone()));
@@ -102,11 +121,14 @@ pub async fn delete_item(store: &CatalogueStore, item_id: &str) -> Result<(), St
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::delete(&format!("/api/catalogue/items/{}", item_id)).await;
-
     let mut items = store.items.get();
     if let Some(pos) = items.iter().position(|i| i.id == item_id) {
+        if let Err(e) = backend::remove(&format!("/catalogue/items/{}", item_id)).await {
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+
         items.remove(pos);
         store.items.set(items);
         clear_selection(store);
@@ -124,13 +146,19 @@ pub async fn activate_item(store: &CatalogueStore, item_id: &str) -> Result<(),
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::post(&format!("/api/catalogue/items/{}/activate", item_id), &{}).await;
-
     let mut items = store.items.get();
     if let Some(pos) = items.iter().position(|i| i.id == item_id) {
         items[pos].status = CatalogueItemStatus::Active;
         items[pos].updated_at = "2025-02-15".to_string();
+        let endpoint = format!("/catalogue/items/{}/activate", item_id);
+        match backend::save(HttpMethod::Post, &endpoint, &items[pos]).await {
+            Ok(saved) => items[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.items.set(items.clone());
         store.selected.set(Some(items[pos].clone()));
         store.loading.set(false);
@@ -151,6 +179,15 @@ pub async fn deactivate_item(store: &CatalogueStore, item_id: &str) -> Result<()
     if let Some(pos) = items.iter().position(|i| i.id == item_id) {
         items[pos].status = CatalogueItemStatus::Inactive;
         items[pos].updated_at = "2025-02-15".to_string();
+        let endpoint = format!("/catalogue/items/{}/deactivate", item_id);
+        match backend::save(HttpMethod::Post, &endpoint, &items[pos]).await {
+            Ok(saved) => items[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.items.set(items.clone());
         store.selected.set(Some(items[pos].clone()));
         store.loading.set(false);
@@ -171,6 +208,15 @@ pub async fn discontinue_item(store: &CatalogueStore, item_id: &str) -> Result<(
     if let Some(pos) = items.iter().position(|i| i.id == item_id) {
         items[pos].status = CatalogueItemStatus::Discontinued;
         items[pos].updated_at = "2025-02-15".to_string();
+        let endpoint = format!("/catalogue/items/{}/discontinue", item_id);
+        match backend::save(HttpMethod::Post, &endpoint, &items[pos]).await {
+            Ok(saved) => items[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.items.set(items.clone());
         store.selected.set(Some(items[pos].clone()));
         store.loading.set(false);
@@ -196,6 +242,15 @@ pub async fn approve_item(store: &CatalogueStore, item_id: &str) -> Result<(), S
         }
         items[pos].status = CatalogueItemStatus::Active;
         items[pos].updated_at = "2025-02-15".to_string();
+        let endpoint = format!("/catalogue/items/{}/approve", item_id);
+        match backend::save(HttpMethod::Post, &endpoint, &items[pos]).await {
+            Ok(saved) => items[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.items.set(items.clone());
         store.selected.set(Some(items[pos].clone()));
         store.loading.set(false);
@@ -217,6 +272,15 @@ pub async fn update_stock(store: &CatalogueStore, item_id: &str, in_stock: bool,
         items[pos].in_stock = in_stock;
         items[pos].stock_quantity = quantity;
         items[pos].updated_at = "2025-02-15".to_string();
+        let endpoint = format!("/catalogue/items/{}/stock", item_id);
+        match backend::save(HttpMethod::Post, &endpoint, &items[pos]).await {
+            Ok(saved) => items[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.items.set(items.clone());
         store.selected.set(Some(items[pos].clone()));
         store.loading.set(false);
@@ -229,7 +293,7 @@ pub async fn update_stock(store: &CatalogueStore, item_id: &str, in_stock: bool,
 }
 
 /// Update item price
-pub async fn update_price(store: &CatalogueStore, item_id: &str, new_price: f64) -> Result<(), String> {
+pub async fn update_price(store: &CatalogueStore, item_id: &str, new_price: Money) -> Result<(), String> {
     store.loading.set(true);
     store.error.set(None);
 
@@ -237,6 +301,15 @@ pub async fn update_price(store: &CatalogueStore, item_id: &str, new_price: f64)
     if let Some(pos) = items.iter().position(|i| i.id == item_id) {
         items[pos].unit_price = new_price;
         items[pos].updated_at = "2025-02-15".to_string();
+        let endpoint = format!("/catalogue/items/{}/price", item_id);
+        match backend::save(HttpMethod::Post, &endpoint, &items[pos]).await {
+            Ok(saved) => items[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.items.set(items.clone());
         store.selected.set(Some(items[pos].clone()));
         store.loading.set(false);
@@ -289,14 +362,20 @@ pub async fn create_category(store: &CatalogueStore, category: CatalogueCategory
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::post("/api/catalogue/categories", &category).await;
-
     let mut new_category = category;
     let count = store.categories.get().len() + 1;
     new_category.id = format!("CAT-{:03}", count);
     new_category.item_count = 0;
 
+    match backend::save(HttpMethod::Post, "/catalogue/categories", &new_category).await {
+        Ok(saved) => new_category = saved,
+        Err(e) => {
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+
     let mut categories = store.categories.get();
     categories.push(new_category.clone());
     store.categories.set(categories);
@@ -312,6 +391,15 @@ pub async fn update_category(store: &CatalogueStore, category: CatalogueCategory
 
     let mut categories = store.categories.get();
     if let Some(pos) = categories.iter().position(|c| c.id == category.id) {
+        let endpoint = format!("/catalogue/categories/{}", category.id);
+        let category = match backend::save(HttpMethod::Put, &endpoint, &category).await {
+            Ok(saved) => saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        };
         categories[pos] = category.clone();
         store.categories.set(categories);
         store.loading.set(false);
@@ -338,6 +426,12 @@ pub async fn delete_category(store: &CatalogueStore, category_id: &str) -> Resul
 
     let mut categories = store.categories.get();
     if let Some(pos) = categories.iter().position(|c| c.id == category_id) {
+        if let Err(e) = backend::remove(&format!("/catalogue/categories/{}", category_id)).await {
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+
         categories.remove(pos);
         store.categories.set(categories);
         store.loading.set(false);
@@ -360,3 +454,28 @@ pub async fn export_items_csv(_store: &CatalogueStore) -> Result<String, String>
     // In production, this would generate CSV from items
     Ok(String::new())
 }
+
+/// Refresh catalogue items and categories from the configured backend
+async fn load_from_backend(store: &CatalogueStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/catalogue/items", &store.items).await?;
+        backend::fetch_into("/catalogue/categories", &store.categories).await?;
+        backend::fetch_into("/catalogue/kpis", &store.kpis).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    } else {
+        let mut pagination = store.pagination.get();
+        pagination.total_items = store.items.get().len() as u32;
+        pagination.total_pages = (pagination.total_items + pagination.page_size - 1) / pagination.page_size;
+        store.pagination.set(pagination);
+    }
+}
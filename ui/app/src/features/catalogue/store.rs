@@ -1,6 +1,8 @@
 //! Catalogue store
 
 use components::prelude::*;
+use crate::features::search::types::match_score;
+use crate::util::money::Money;
 use super::types::{
     CatalogueItem, CatalogueCategory, CatalogueFilter, CatalogueItemStatus,
     CatalogueKpis, PaginationState, CatalogueSortBy, CatalogueSupplier,
@@ -38,6 +40,27 @@ impl CatalogueStore {
         }
     }
 
+    /// Best matches for `query` by item code, name, category and supplier
+    pub fn search_items(&self, query: &str, limit: usize) -> Vec<CatalogueItem> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut hits: Vec<(u32, CatalogueItem)> = self.items.get().into_iter()
+            .filter_map(|item| {
+                let score = match_score(&query, &[&item.item_code], &[&item.name, &item.category_name, &item.supplier.name])?;
+                Some((score, item))
+            })
+            .collect();
+        hits.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.name.cmp(&b.1.name)));
+        hits.into_iter().take(limit).map(|(_, item)| item).collect()
+    }
+
+    /// Catalogue item by ID
+    pub fn item(&self, id: &str) -> Option<CatalogueItem> {
+        self.items.get().into_iter().find(|item| item.id == id)
+    }
+
     /// Get filtered and sorted items
     pub fn get_filtered_items(&self) -> Vec<CatalogueItem> {
         let items = self.items.get();
@@ -123,8 +146,8 @@ impl CatalogueStore {
         match sort_by {
             CatalogueSortBy::NameAsc => filtered.sort_by(|a, b| a.name.cmp(&b.name)),
             CatalogueSortBy::NameDesc => filtered.sort_by(|a, b| b.name.cmp(&a.name)),
-            CatalogueSortBy::PriceAsc => filtered.sort_by(|a, b| a.unit_price.partial_cmp(&b.unit_price).unwrap()),
-            CatalogueSortBy::PriceDesc => filtered.sort_by(|a, b| b.unit_price.partial_cmp(&a.unit_price).unwrap()),
+            CatalogueSortBy::PriceAsc => filtered.sort_by(|a, b| a.unit_price.cmp(&b.unit_price)),
+            CatalogueSortBy::PriceDesc => filtered.sort_by(|a, b| b.unit_price.cmp(&a.unit_price)),
             CatalogueSortBy::CategoryAsc => filtered.sort_by(|a, b| a.category_name.cmp(&b.category_name)),
             CatalogueSortBy::RecentlyAdded => filtered.sort_by(|a, b| b.created_at.cmp(&a.created_at)),
             CatalogueSortBy::MostPopular => filtered.sort_by(|a, b| b.featured.cmp(&a.featured)),
@@ -240,12 +263,12 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-001".to_string(),
             category_name: "IT Equipment".to_string(),
             category_code: "IT".to_string(),
-            unit_price: 24999.00,
+            unit_price: Money::rands(24_999),
             currency: "ZAR".to_string(),
             price_tiers: vec![
-                PriceTier { min_quantity: 1, max_quantity: Some(9), unit_price: 24999.00 },
-                PriceTier { min_quantity: 10, max_quantity: Some(49), unit_price: 23999.00 },
-                PriceTier { min_quantity: 50, max_quantity: None, unit_price: 22999.00 },
+                PriceTier { min_quantity: 1, max_quantity: Some(9), unit_price: Money::rands(24_999) },
+                PriceTier { min_quantity: 10, max_quantity: Some(49), unit_price: Money::rands(23_999) },
+                PriceTier { min_quantity: 50, max_quantity: None, unit_price: Money::rands(22_999) },
             ],
             vat_inclusive: true,
             vat_rate: 15.0,
@@ -259,6 +282,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: Some("CTR-2025-0234".to_string()),
             specifications: vec![
                 ItemSpecification { key: "Processor".to_string(), value: "Intel Core i7-1365U".to_string(), unit: None },
                 ItemSpecification { key: "RAM".to_string(), value: "16".to_string(), unit: Some("GB".to_string()) },
@@ -289,7 +313,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-001".to_string(),
             category_name: "IT Equipment".to_string(),
             category_code: "IT".to_string(),
-            unit_price: 7499.00,
+            unit_price: Money::rands(7499),
             currency: "ZAR".to_string(),
             price_tiers: vec![],
             vat_inclusive: true,
@@ -304,6 +328,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: Some("CTR-2025-0234".to_string()),
             specifications: vec![
                 ItemSpecification { key: "Resolution".to_string(), value: "3840 x 2160".to_string(), unit: None },
                 ItemSpecification { key: "Panel".to_string(), value: "IPS".to_string(), unit: None },
@@ -332,12 +357,12 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-002".to_string(),
             category_name: "Office Supplies".to_string(),
             category_code: "OFFICE".to_string(),
-            unit_price: 389.00,
+            unit_price: Money::rands(389),
             currency: "ZAR".to_string(),
             price_tiers: vec![
-                PriceTier { min_quantity: 1, max_quantity: Some(9), unit_price: 389.00 },
-                PriceTier { min_quantity: 10, max_quantity: Some(49), unit_price: 369.00 },
-                PriceTier { min_quantity: 50, max_quantity: None, unit_price: 349.00 },
+                PriceTier { min_quantity: 1, max_quantity: Some(9), unit_price: Money::rands(389) },
+                PriceTier { min_quantity: 10, max_quantity: Some(49), unit_price: Money::rands(369) },
+                PriceTier { min_quantity: 50, max_quantity: None, unit_price: Money::rands(349) },
             ],
             vat_inclusive: true,
             vat_rate: 15.0,
@@ -351,6 +376,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: Some("CTR-2025-0198".to_string()),
             specifications: vec![
                 ItemSpecification { key: "Size".to_string(), value: "A4".to_string(), unit: None },
                 ItemSpecification { key: "Weight".to_string(), value: "80".to_string(), unit: Some("gsm".to_string()) },
@@ -380,7 +406,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-003".to_string(),
             category_name: "Furniture".to_string(),
             category_code: "FURN".to_string(),
-            unit_price: 8999.00,
+            unit_price: Money::rands(8999),
             currency: "ZAR".to_string(),
             price_tiers: vec![],
             vat_inclusive: true,
@@ -395,6 +421,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: None,
             specifications: vec![
                 ItemSpecification { key: "Width".to_string(), value: "1800".to_string(), unit: Some("mm".to_string()) },
                 ItemSpecification { key: "Depth".to_string(), value: "1600".to_string(), unit: Some("mm".to_string()) },
@@ -425,12 +452,12 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-003".to_string(),
             category_name: "Furniture".to_string(),
             category_code: "FURN".to_string(),
-            unit_price: 4599.00,
+            unit_price: Money::rands(4599),
             currency: "ZAR".to_string(),
             price_tiers: vec![
-                PriceTier { min_quantity: 1, max_quantity: Some(9), unit_price: 4599.00 },
-                PriceTier { min_quantity: 10, max_quantity: Some(24), unit_price: 4399.00 },
-                PriceTier { min_quantity: 25, max_quantity: None, unit_price: 4199.00 },
+                PriceTier { min_quantity: 1, max_quantity: Some(9), unit_price: Money::rands(4599) },
+                PriceTier { min_quantity: 10, max_quantity: Some(24), unit_price: Money::rands(4399) },
+                PriceTier { min_quantity: 25, max_quantity: None, unit_price: Money::rands(4199) },
             ],
             vat_inclusive: true,
             vat_rate: 15.0,
@@ -444,6 +471,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: None,
             specifications: vec![
                 ItemSpecification { key: "Max Weight".to_string(), value: "120".to_string(), unit: Some("kg".to_string()) },
                 ItemSpecification { key: "Seat Height".to_string(), value: "440-540".to_string(), unit: Some("mm".to_string()) },
@@ -473,7 +501,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-004".to_string(),
             category_name: "Cleaning Supplies".to_string(),
             category_code: "CLEAN".to_string(),
-            unit_price: 189.00,
+            unit_price: Money::rands(189),
             currency: "ZAR".to_string(),
             price_tiers: vec![],
             vat_inclusive: true,
@@ -488,6 +516,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: None,
             specifications: vec![
                 ItemSpecification { key: "Volume".to_string(), value: "5".to_string(), unit: Some("Litres".to_string()) },
                 ItemSpecification { key: "Dilution".to_string(), value: "1:20".to_string(), unit: None },
@@ -516,12 +545,12 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-005".to_string(),
             category_name: "Safety & PPE".to_string(),
             category_code: "SAFETY".to_string(),
-            unit_price: 149.00,
+            unit_price: Money::rands(149),
             currency: "ZAR".to_string(),
             price_tiers: vec![
-                PriceTier { min_quantity: 1, max_quantity: Some(19), unit_price: 149.00 },
-                PriceTier { min_quantity: 20, max_quantity: Some(99), unit_price: 139.00 },
-                PriceTier { min_quantity: 100, max_quantity: None, unit_price: 129.00 },
+                PriceTier { min_quantity: 1, max_quantity: Some(19), unit_price: Money::rands(149) },
+                PriceTier { min_quantity: 20, max_quantity: Some(99), unit_price: Money::rands(139) },
+                PriceTier { min_quantity: 100, max_quantity: None, unit_price: Money::rands(129) },
             ],
             vat_inclusive: true,
             vat_rate: 15.0,
@@ -535,6 +564,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: None,
             specifications: vec![
                 ItemSpecification { key: "Standard".to_string(), value: "SANS 1397".to_string(), unit: None },
                 ItemSpecification { key: "Material".to_string(), value: "HDPE".to_string(), unit: None },
@@ -563,7 +593,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-001".to_string(),
             category_name: "IT Equipment".to_string(),
             category_code: "IT".to_string(),
-            unit_price: 6999.00,
+            unit_price: Money::rands(6999),
             currency: "ZAR".to_string(),
             price_tiers: vec![],
             vat_inclusive: true,
@@ -578,6 +608,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: Some("CTR-2025-0234".to_string()),
             specifications: vec![
                 ItemSpecification { key: "Print Speed".to_string(), value: "28".to_string(), unit: Some("ppm".to_string()) },
                 ItemSpecification { key: "Duplex".to_string(), value: "Automatic".to_string(), unit: None },
@@ -606,7 +637,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-002".to_string(),
             category_name: "Office Supplies".to_string(),
             category_code: "OFFICE".to_string(),
-            unit_price: 89.00,
+            unit_price: Money::rands(89),
             currency: "ZAR".to_string(),
             price_tiers: vec![],
             vat_inclusive: true,
@@ -621,6 +652,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: Some("CTR-2025-0198".to_string()),
             specifications: vec![
                 ItemSpecification { key: "Ink Color".to_string(), value: "Black".to_string(), unit: None },
                 ItemSpecification { key: "Point Size".to_string(), value: "Medium (1.0mm)".to_string(), unit: None },
@@ -649,7 +681,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-006".to_string(),
             category_name: "Electrical".to_string(),
             category_code: "ELEC".to_string(),
-            unit_price: 2499.00,
+            unit_price: Money::rands(2499),
             currency: "ZAR".to_string(),
             price_tiers: vec![],
             vat_inclusive: true,
@@ -664,6 +696,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: Some("CTR-2025-0234".to_string()),
             specifications: vec![
                 ItemSpecification { key: "Category".to_string(), value: "CAT6".to_string(), unit: None },
                 ItemSpecification { key: "Type".to_string(), value: "UTP".to_string(), unit: None },
@@ -692,12 +725,12 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-005".to_string(),
             category_name: "Safety & PPE".to_string(),
             category_code: "SAFETY".to_string(),
-            unit_price: 199.00,
+            unit_price: Money::rands(199),
             currency: "ZAR".to_string(),
             price_tiers: vec![
-                PriceTier { min_quantity: 1, max_quantity: Some(9), unit_price: 199.00 },
-                PriceTier { min_quantity: 10, max_quantity: Some(49), unit_price: 189.00 },
-                PriceTier { min_quantity: 50, max_quantity: None, unit_price: 179.00 },
+                PriceTier { min_quantity: 1, max_quantity: Some(9), unit_price: Money::rands(199) },
+                PriceTier { min_quantity: 10, max_quantity: Some(49), unit_price: Money::rands(189) },
+                PriceTier { min_quantity: 50, max_quantity: None, unit_price: Money::rands(179) },
             ],
             vat_inclusive: true,
             vat_rate: 15.0,
@@ -711,6 +744,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: false,
             },
             alternate_suppliers: vec![],
+            contract_ref: None,
             specifications: vec![
                 ItemSpecification { key: "Material".to_string(), value: "Nitrile".to_string(), unit: None },
                 ItemSpecification { key: "Powder".to_string(), value: "Powder-free".to_string(), unit: None },
@@ -740,7 +774,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
             category_id: "CAT-001".to_string(),
             category_name: "IT Equipment".to_string(),
             category_code: "IT".to_string(),
-            unit_price: 699.00,
+            unit_price: Money::rands(699),
             currency: "ZAR".to_string(),
             price_tiers: vec![],
             vat_inclusive: true,
@@ -755,6 +789,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
                 is_preferred: true,
             },
             alternate_suppliers: vec![],
+            contract_ref: Some("CTR-2025-0234".to_string()),
             specifications: vec![
                 ItemSpecification { key: "Connectivity".to_string(), value: "2.4GHz Wireless".to_string(), unit: None },
                 ItemSpecification { key: "Battery Life".to_string(), value: "12".to_string(), unit: Some("months".to_string()) },
@@ -785,7 +820,7 @@ pub fn load_mock_data(store: &CatalogueStore) {
     let active = mock_items.iter().filter(|i| i.status == CatalogueItemStatus::Active).count() as u32;
     let out_of_stock = mock_items.iter().filter(|i| !i.in_stock).count() as u32;
     let pending = mock_items.iter().filter(|i| i.status == CatalogueItemStatus::PendingApproval).count() as u32;
-    let avg_price: f64 = mock_items.iter().map(|i| i.unit_price).sum::<f64>() / total as f64;
+    let avg_price = mock_items.iter().map(|i| i.unit_price).sum::<Money>().times(1.0 / total as f64);
 
     let unique_suppliers: std::collections::HashSet<_> = mock_items.iter().map(|i| &i.supplier.id).collect();
 
@@ -1,6 +1,8 @@
 //! Catalogue domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::{Money, VAT_RATE};
 
 /// Catalogue item status
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -17,12 +19,12 @@ pub enum CatalogueItemStatus {
 
 impl CatalogueItemStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             CatalogueItemStatus::Active => "Active",
             CatalogueItemStatus::Inactive => "Inactive",
             CatalogueItemStatus::Discontinued => "Discontinued",
             CatalogueItemStatus::PendingApproval => "Pending Approval",
-        }
+        })
     }
 
     pub fn from_str(s: &str) -> Self {
@@ -123,7 +125,7 @@ impl Default for CatalogueSupplier {
 pub struct PriceTier {
     pub min_quantity: u32,
     pub max_quantity: Option<u32>,
-    pub unit_price: f64,
+    pub unit_price: Money,
 }
 
 /// Main Catalogue Item entity
@@ -140,7 +142,7 @@ pub struct CatalogueItem {
     pub category_code: String,
 
     // Pricing
-    pub unit_price: f64,
+    pub unit_price: Money,
     pub currency: String,
     pub price_tiers: Vec<PriceTier>,
     pub vat_inclusive: bool,
@@ -152,6 +154,9 @@ pub struct CatalogueItem {
     // Supplier
     pub supplier: CatalogueSupplier,
     pub alternate_suppliers: Vec<CatalogueSupplier>,
+    /// Contract the item's price was negotiated under, if any
+    #[serde(default)]
+    pub contract_ref: Option<String>,
 
     // Specifications
     pub specifications: Vec<ItemSpecification>,
@@ -189,14 +194,15 @@ impl Default for CatalogueItem {
             category_id: String::new(),
             category_name: String::new(),
             category_code: String::new(),
-            unit_price: 0.0,
+            unit_price: Money::ZERO,
             currency: "ZAR".to_string(),
             price_tiers: Vec::new(),
             vat_inclusive: true,
-            vat_rate: 15.0,
+            vat_rate: VAT_RATE,
             unit_of_measure: UnitOfMeasure::default(),
             supplier: CatalogueSupplier::default(),
             alternate_suppliers: Vec::new(),
+            contract_ref: None,
             specifications: Vec::new(),
             brand: None,
             manufacturer: None,
@@ -216,6 +222,64 @@ impl Default for CatalogueItem {
     }
 }
 
+impl CatalogueItem {
+    /// Unit price before VAT
+    pub fn price_excl_vat(&self) -> Money {
+        if self.vat_inclusive {
+            self.unit_price.excluding_vat(self.vat_rate)
+        } else {
+            self.unit_price
+        }
+    }
+
+    /// Unit price including VAT
+    pub fn price_incl_vat(&self) -> Money {
+        if self.vat_inclusive {
+            self.unit_price
+        } else {
+            self.unit_price.with_vat(self.vat_rate)
+        }
+    }
+
+    /// VAT on one unit
+    pub fn vat_amount(&self) -> Money {
+        self.price_incl_vat() - self.price_excl_vat()
+    }
+
+    /// Unit price before VAT for an order of `quantity`, taken from the
+    /// price tier the quantity falls in
+    pub fn price_for(&self, quantity: u32) -> Money {
+        let price = self.price_tiers.iter()
+            .find(|tier| quantity >= tier.min_quantity && quantity <= tier.max_quantity.unwrap_or(u32::MAX))
+            .map_or(self.unit_price, |tier| tier.unit_price);
+        if self.vat_inclusive {
+            price.excluding_vat(self.vat_rate)
+        } else {
+            price
+        }
+    }
+
+    /// Reasons an order of `quantity` may not be fulfilled as catalogued
+    pub fn ordering_warnings(&self, quantity: u32) -> Vec<String> {
+        let mut warnings = Vec::new();
+        match self.status {
+            CatalogueItemStatus::Active => {}
+            CatalogueItemStatus::Inactive => warnings.push("Item is inactive in the catalogue".to_string()),
+            CatalogueItemStatus::Discontinued => warnings.push("Item has been discontinued".to_string()),
+            CatalogueItemStatus::PendingApproval => warnings.push("Item is awaiting catalogue approval".to_string()),
+        }
+        if !self.in_stock {
+            warnings.push("Out of stock".to_string());
+        } else if let Some(stock) = self.stock_quantity.filter(|stock| *stock < quantity) {
+            warnings.push(format!("Only {} in stock", stock));
+        }
+        if quantity < self.supplier.minimum_order_quantity {
+            warnings.push(format!("Minimum order is {} {}", self.supplier.minimum_order_quantity, self.unit_of_measure.name));
+        }
+        warnings
+    }
+}
+
 /// Filter criteria for catalogue items
 #[derive(Clone, Debug, Default)]
 pub struct CatalogueFilter {
@@ -223,8 +287,8 @@ pub struct CatalogueFilter {
     pub category_id: Option<String>,
     pub supplier_id: Option<String>,
     pub status: Option<CatalogueItemStatus>,
-    pub min_price: Option<f64>,
-    pub max_price: Option<f64>,
+    pub min_price: Option<Money>,
+    pub max_price: Option<Money>,
     pub in_stock_only: bool,
     pub featured_only: bool,
     pub bbbee_level: Option<u8>,
@@ -251,7 +315,7 @@ impl Default for PaginationState {
 }
 
 /// Catalogue summary KPIs
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct CatalogueKpis {
     pub total_items: u32,
     pub active_items: u32,
@@ -259,7 +323,7 @@ pub struct CatalogueKpis {
     pub suppliers_count: u32,
     pub out_of_stock: u32,
     pub pending_approval: u32,
-    pub avg_price: f64,
+    pub avg_price: Money,
 }
 
 /// Sort options for catalogue
@@ -282,7 +346,7 @@ impl Default for CatalogueSortBy {
 
 impl CatalogueSortBy {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             CatalogueSortBy::NameAsc => "Name (A-Z)",
             CatalogueSortBy::NameDesc => "Name (Z-A)",
             CatalogueSortBy::PriceAsc => "Price (Low to High)",
@@ -290,7 +354,7 @@ impl CatalogueSortBy {
             CatalogueSortBy::CategoryAsc => "Category",
             CatalogueSortBy::RecentlyAdded => "Recently Added",
             CatalogueSortBy::MostPopular => "Most Popular",
-        }
+        })
     }
 
     pub fn from_str(s: &str) -> Self {
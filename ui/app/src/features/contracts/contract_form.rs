@@ -1,6 +1,10 @@
 //! Contract create/edit form
 
 use components::prelude::*;
+use crate::Route;
+use crate::features::workflow::approval_timeline::{approval_timeline, approval_actions, approval_reassign};
+use crate::features::workflow::types::{ApprovalDecision, ApprovalReassignment};
+use crate::page::not_found::entity_not_found;
 use crate::shared::layout::page_header;
 use crate::shared::components::{
     panel, panel_with_footer,
@@ -11,9 +15,12 @@ use crate::shared::forms::{
     text_input, textarea, select, SelectOption, date_picker, currency_input,
 };
 use crate::util::format::format_currency;
+use crate::util::money::Money;
+use crate::util::api::field_error;
 use super::types::{Contract, ContractStatus, ContractTerms, ContractSla, ContractDeliverable};
 use super::store::ContractsStore;
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Contract form page (create/edit)
 #[component]
@@ -31,7 +38,7 @@ pub fn contract_form(contract_id: Option<String>) -> View {
     let reference_number = signal(String::new());
     let supplier_id = signal(String::new());
     let supplier_name = signal(String::new());
-    let value = signal(0.0f64);
+    let value = signal(Money::ZERO);
     let start_date = signal(String::new());
     let end_date = signal(String::new());
     let tender_id = signal(String::new());
@@ -59,6 +66,12 @@ pub fn contract_form(contract_id: Option<String>) -> View {
     // Form state
     let form_error = signal::<Option<String>>(None);
     let saving = store.saving.clone();
+    // Set when the contract being edited cannot be found
+    let missing = signal(false);
+
+    // Validation errors from the last save, keyed by field
+    let field_errors = store.field_errors.clone();
+    let field_err = |field: &str| field_error(&field_errors.get(), field);
 
     // Load existing contract if editing
     if let Some(id) = &contract_id {
@@ -76,6 +89,7 @@ pub fn contract_form(contract_id: Option<String>) -> View {
             let end_date = end_date.clone();
             let payment_terms = payment_terms.clone();
             let sla_enabled = sla_enabled.clone();
+            let missing = missing.clone();
 
             move || {
                 let store = store.clone();
@@ -91,11 +105,12 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                 let end_date = end_date.clone();
                 let payment_terms = payment_terms.clone();
                 let sla_enabled = sla_enabled.clone();
+                let missing = missing.clone();
 
                 spawn(async move {
                     service::load_contract(&store, &id).await;
 
-                    if let Some(contract) = store.selected.get() {
+                    if let Some(contract) = store.selected.get().filter(|c| c.id == id) {
                         title.set(contract.title);
                         description.set(contract.description);
                         contract_type.set(contract.contract_type);
@@ -107,6 +122,8 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                         end_date.set(contract.end_date);
                         payment_terms.set(contract.terms.payment_terms);
                         sla_enabled.set(contract.sla.is_some());
+                    } else {
+                        missing.set(true);
                     }
                 });
             }
@@ -240,10 +257,12 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                 milestones: Vec::new(),
                 documents: Vec::new(),
                 created_by: "Current User".to_string(),
+                created_by_id: String::new(),
                 created_at: "2025-02-27T10:00:00Z".to_string(),
                 updated_at: "2025-02-27T10:00:00Z".to_string(),
                 approved_by: None,
                 approved_at: None,
+                approval_workflow: Vec::new(),
             };
 
             spawn(async move {
@@ -292,17 +311,63 @@ pub fn contract_form(contract_id: Option<String>) -> View {
         }
     });
 
+    let handle_decide = Callback::new({
+        let store = store.clone();
+        let contract_id = contract_id.clone();
+        let form_error = form_error.clone();
+        move |decision: ApprovalDecision| {
+            if let Some(id) = &contract_id {
+                let store = store.clone();
+                let id = id.clone();
+                let form_error = form_error.clone();
+                spawn(async move {
+                    if let Err(e) = service::decide_contract(&store, &id, &decision).await {
+                        form_error.set(Some(e));
+                    }
+                });
+            }
+        }
+    });
+
+    let handle_reassign = Callback::new({
+        let store = store.clone();
+        let contract_id = contract_id.clone();
+        let form_error = form_error.clone();
+        move |reassignment: ApprovalReassignment| {
+            if let Some(id) = &contract_id {
+                let store = store.clone();
+                let id = id.clone();
+                let form_error = form_error.clone();
+                spawn(async move {
+                    if let Err(e) = service::reassign_contract_step(&store, &id, &reassignment).await {
+                        form_error.set(Some(e));
+                    }
+                });
+            }
+        }
+    });
+
+    // Approval chain of the contract being edited, once it has been submitted
+    let approval_workflow = store.selected.get()
+        .filter(|c| Some(&c.id) == contract_id.as_ref())
+        .map(|c| (c.created_by_id, c.approval_workflow))
+        .filter(|(_, steps)| !steps.is_empty());
+
+    if let (true, Some(id)) = (missing.get(), contract_id.as_ref()) {
+        return entity_not_found(t("Contract"), id, store.error.get(), Route::ContractsList, t("Back to Contracts"));
+    }
+
     // Stepper items
     let step = current_step.get();
     let steps = vec![
         StepperItem {
             number: 1,
-            label: "Contract Details".to_string(),
+            label: t("Contract Details").to_string(),
             status: if step > 1 { StepStatus::Completed } else if step == 1 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 2,
-            label: "Terms".to_string(),
+            label: t("Terms").to_string(),
             status: if step > 2 { StepStatus::Completed } else if step == 2 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
@@ -312,7 +377,7 @@ pub fn contract_form(contract_id: Option<String>) -> View {
         },
         StepperItem {
             number: 4,
-            label: "Deliverables".to_string(),
+            label: t("Deliverables").to_string(),
             status: if step == 4 { StepStatus::Active } else { StepStatus::Pending },
         },
     ];
@@ -405,10 +470,10 @@ pub fn contract_form(contract_id: Option<String>) -> View {
 
         <div class="contract-form" data-testid="contract-form">
             {page_header(
-                if is_edit { "Edit Contract".to_string() } else { "New Contract".to_string() },
-                Some(if is_edit { "Update contract details".to_string() } else { "Create a new supplier contract".to_string() }),
+                if is_edit { t("Edit Contract").to_string() } else { t("New Contract").to_string() },
+                Some(if is_edit { t("Update contract details").to_string() } else { t("Create a new supplier contract").to_string() }),
                 vec![
-                    view! { <a href="/contracts" class="btn btn-secondary">"Cancel"</a> },
+                    view! { <a href="/contracts" class="btn btn-secondary">{t("Cancel")}</a> },
                 ]
             )}
 
@@ -420,6 +485,18 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                 })))}
             }
 
+            if let Some((requester, workflow)) = approval_workflow {
+                {panel(
+                    t("Approval Workflow").to_string(),
+                    vec![],
+                    vec![
+                        approval_timeline(workflow.clone()),
+                        approval_actions(workflow.clone(), requester, saving.get(), handle_decide),
+                        approval_reassign(workflow, saving.get(), handle_reassign),
+                    ]
+                )}
+            }
+
             // Stepper
             {stepper(steps, Some(Callback::<u32>::new({
                 let current_step = current_step.clone();
@@ -429,72 +506,72 @@ pub fn contract_form(contract_id: Option<String>) -> View {
             // Step 1: Contract Details
             if step == 1 {
                 {panel_with_footer(
-                    "Contract Details".to_string(),
+                    t("Contract Details").to_string(),
                     vec![],
                     vec![
                         view! {
                             <div class="form-grid">
                                 {text_input(
-                                    "Contract Title".to_string(),
+                                    t("Contract Title").to_string(),
                                     title.clone(),
-                                    Some("Enter contract title".to_string()),
-                                    true, false, None, None, None
+                                    Some(t("Enter contract title").to_string()),
+                                    true, false, field_err("title"), None, None
                                 )}
                                 {select(
-                                    "Contract Type".to_string(),
+                                    t("Contract Type").to_string(),
                                     contract_type.clone(),
                                     vec![
-                                        SelectOption { value: "Goods".to_string(), label: "Goods".to_string() },
-                                        SelectOption { value: "Services".to_string(), label: "Services".to_string() },
-                                        SelectOption { value: "Works".to_string(), label: "Works".to_string() },
-                                        SelectOption { value: "Framework".to_string(), label: "Framework Agreement".to_string() },
+                                        SelectOption { value: "Goods".to_string(), label: t("Goods").to_string() },
+                                        SelectOption { value: "Services".to_string(), label: t("Services").to_string() },
+                                        SelectOption { value: "Works".to_string(), label: t("Works").to_string() },
+                                        SelectOption { value: "Framework".to_string(), label: t("Framework Agreement").to_string() },
                                     ],
-                                    Some("Select type".to_string()),
-                                    true, false, None
+                                    Some(t("Select type").to_string()),
+                                    true, false, field_err("contract_type")
                                 )}
                                 <div class="span-2">
                                     {textarea(
-                                        "Description".to_string(),
+                                        t("Description").to_string(),
                                         description.clone(),
-                                        Some("Describe the contract scope and objectives".to_string()),
-                                        true, false, Some(4), None, None
+                                        Some(t("Describe the contract scope and objectives").to_string()),
+                                        true, false, Some(4), field_err("description"), None
                                     )}
                                 </div>
                                 {text_input(
-                                    "Reference Number".to_string(),
+                                    t("Reference Number").to_string(),
                                     reference_number.clone(),
-                                    Some("e.g., PFMA/2025/IT/001".to_string()),
-                                    false, false, None, None, None
+                                    Some(t("e.g., PFMA/2025/IT/001").to_string()),
+                                    false, false, field_err("reference_number"), None, None
                                 )}
                                 {text_input(
-                                    "Linked Tender ID".to_string(),
+                                    t("Linked Tender ID").to_string(),
                                     tender_id.clone(),
-                                    Some("e.g., TND-2025-0001".to_string()),
-                                    false, false, None, None, None
+                                    Some(t("e.g., TND-2025-0001").to_string()),
+                                    false, false, field_err("tender_id"), None, None
                                 )}
                                 {select(
-                                    "Supplier".to_string(),
+                                    t("Supplier").to_string(),
                                     supplier_id.clone(),
                                     supplier_options.iter().map(|(v, l)| {
                                         SelectOption { value: v.to_string(), label: l.to_string() }
                                     }).collect(),
-                                    Some("Select supplier".to_string()),
-                                    true, false, None
+                                    Some(t("Select supplier").to_string()),
+                                    true, false, field_err("supplier_id")
                                 )}
                                 {currency_input(
-                                    "Contract Value".to_string(),
+                                    t("Contract Value").to_string(),
                                     value.clone(),
-                                    true, false, None, None
+                                    true, false, field_err("value"), None
                                 )}
                                 {date_picker(
-                                    "Start Date".to_string(),
+                                    t("Start Date").to_string(),
                                     start_date.clone(),
-                                    true, false, None, None, None
+                                    true, false, None, None, field_err("start_date")
                                 )}
                                 {date_picker(
-                                    "End Date".to_string(),
+                                    t("End Date").to_string(),
                                     end_date.clone(),
-                                    true, false, None, None, None
+                                    true, false, None, None, field_err("end_date")
                                 )}
                             </div>
                         }
@@ -503,7 +580,7 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                         view! { <div class="form-actions-left"></div> },
                         view! {
                             <div class="form-actions-right">
-                                <button class="btn btn-primary" on:click={next_step.clone()}>"Next: Terms"</button>
+                                <button class="btn btn-primary" on:click={next_step.clone()}>{t("Next: Terms")}</button>
                             </div>
                         },
                     ]
@@ -513,56 +590,56 @@ pub fn contract_form(contract_id: Option<String>) -> View {
             // Step 2: Terms
             if step == 2 {
                 {panel_with_footer(
-                    "Contract Terms".to_string(),
+                    t("Contract Terms").to_string(),
                     vec![],
                     vec![
                         view! {
                             <div class="form-grid">
                                 {text_input(
-                                    "Payment Terms".to_string(),
+                                    t("Payment Terms").to_string(),
                                     payment_terms.clone(),
-                                    Some("e.g., 30 days from invoice".to_string()),
-                                    true, false, None, None, None
+                                    Some(t("e.g., 30 days from invoice").to_string()),
+                                    true, false, field_err("terms.payment_terms"), None, None
                                 )}
                                 {text_input(
-                                    "Warranty Period (months)".to_string(),
+                                    t("Warranty Period (months)").to_string(),
                                     signal(warranty_months.get().to_string()),
-                                    Some("e.g., 12".to_string()),
-                                    false, false, None, None, Some("number".to_string())
+                                    Some(t("e.g., 12").to_string()),
+                                    false, false, field_err("terms.warranty_period_months"), None, Some("number".to_string())
                                 )}
                                 {text_input(
-                                    "Notice Period (days)".to_string(),
+                                    t("Notice Period (days)").to_string(),
                                     signal(notice_days.get().to_string()),
-                                    Some("e.g., 30".to_string()),
-                                    false, false, None, None, Some("number".to_string())
+                                    Some(t("e.g., 30").to_string()),
+                                    false, false, field_err("terms.notice_period_days"), None, Some("number".to_string())
                                 )}
                                 {text_input(
-                                    "Dispute Resolution".to_string(),
+                                    t("Dispute Resolution").to_string(),
                                     dispute_resolution.clone(),
-                                    Some("e.g., Arbitration".to_string()),
-                                    false, false, None, None, None
+                                    Some(t("e.g., Arbitration").to_string()),
+                                    false, false, field_err("terms.dispute_resolution"), None, None
                                 )}
                                 <div class="span-2">
                                     {textarea(
-                                        "Renewal Terms".to_string(),
+                                        t("Renewal Terms").to_string(),
                                         renewal_terms.clone(),
-                                        Some("Describe renewal conditions".to_string()),
-                                        false, false, Some(3), None, None
+                                        Some(t("Describe renewal conditions").to_string()),
+                                        false, false, Some(3), field_err("terms.renewal_terms"), None
                                     )}
                                 </div>
                                 <div class="span-2">
                                     {textarea(
-                                        "Termination Clause".to_string(),
+                                        t("Termination Clause").to_string(),
                                         termination_clause.clone(),
-                                        Some("Describe termination conditions".to_string()),
-                                        false, false, Some(3), None, None
+                                        Some(t("Describe termination conditions").to_string()),
+                                        false, false, Some(3), field_err("terms.termination_clause"), None
                                     )}
                                 </div>
                                 {text_input(
-                                    "Governing Law".to_string(),
+                                    t("Governing Law").to_string(),
                                     governing_law.clone(),
-                                    Some("e.g., South African Law".to_string()),
-                                    false, false, None, None, None
+                                    Some(t("e.g., South African Law").to_string()),
+                                    false, false, field_err("terms.governing_law"), None, None
                                 )}
                             </div>
                         }
@@ -570,12 +647,12 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                     vec![
                         view! {
                             <div class="form-actions-left">
-                                <button class="btn btn-secondary" on:click={prev_step.clone()}>"Back"</button>
+                                <button class="btn btn-secondary" on:click={prev_step.clone()}>{t("Back")}</button>
                             </div>
                         },
                         view! {
                             <div class="form-actions-right">
-                                <button class="btn btn-primary" on:click={next_step.clone()}>"Next: SLA"</button>
+                                <button class="btn btn-primary" on:click={next_step.clone()}>{t("Next: SLA")}</button>
                             </div>
                         },
                     ]
@@ -585,7 +662,7 @@ pub fn contract_form(contract_id: Option<String>) -> View {
             // Step 3: SLA
             if step == 3 {
                 {panel_with_footer(
-                    "Service Level Agreement".to_string(),
+                    t("Service Level Agreement").to_string(),
                     vec![],
                     vec![
                         view! {
@@ -600,54 +677,54 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                                                 move |_| sla_enabled.set(!sla_enabled.get())
                                             })}
                                         />
-                                        "Include SLA Terms"
+                                        {t("Include SLA Terms")}
                                     </label>
                                     <span style="color: var(--text-muted); font-size: 12px;">
-                                        "Enable this to define service level requirements and penalties"
+                                        {t("Enable this to define service level requirements and penalties")}
                                     </span>
                                 </div>
 
                                 if sla_enabled.get() {
                                     <div class="form-grid">
                                         {text_input(
-                                            "Response Time (hours)".to_string(),
+                                            t("Response Time (hours)").to_string(),
                                             signal(response_time.get().to_string()),
-                                            Some("e.g., 4".to_string()),
-                                            true, false, None,
-                                            Some("Maximum time to acknowledge an issue".to_string()),
+                                            Some(t("e.g., 4").to_string()),
+                                            true, false, field_err("sla.response_time_hours"),
+                                            Some(t("Maximum time to acknowledge an issue").to_string()),
                                             Some("number".to_string())
                                         )}
                                         {text_input(
-                                            "Resolution Time (hours)".to_string(),
+                                            t("Resolution Time (hours)").to_string(),
                                             signal(resolution_time.get().to_string()),
-                                            Some("e.g., 24".to_string()),
-                                            true, false, None,
-                                            Some("Maximum time to resolve an issue".to_string()),
+                                            Some(t("e.g., 24").to_string()),
+                                            true, false, field_err("sla.resolution_time_hours"),
+                                            Some(t("Maximum time to resolve an issue").to_string()),
                                             Some("number".to_string())
                                         )}
                                         {text_input(
-                                            "Availability (%)".to_string(),
+                                            t("Availability (%)").to_string(),
                                             signal(format!("{:.1}", availability.get())),
-                                            Some("e.g., 99.9".to_string()),
-                                            true, false, None,
-                                            Some("Required uptime percentage".to_string()),
+                                            Some(t("e.g., 99.9").to_string()),
+                                            true, false, field_err("sla.availability_percent"),
+                                            Some(t("Required uptime percentage").to_string()),
                                             Some("number".to_string())
                                         )}
                                         <div></div>
                                         <div class="span-2">
                                             {textarea(
-                                                "Penalty Clause".to_string(),
+                                                t("Penalty Clause").to_string(),
                                                 penalty_clause.clone(),
-                                                Some("Describe penalties for SLA breaches".to_string()),
-                                                false, false, Some(3), None, None
+                                                Some(t("Describe penalties for SLA breaches").to_string()),
+                                                false, false, Some(3), field_err("sla.penalty_clause"), None
                                             )}
                                         </div>
                                         <div class="span-2">
                                             {textarea(
-                                                "Escalation Procedure".to_string(),
+                                                t("Escalation Procedure").to_string(),
                                                 escalation_procedure.clone(),
-                                                Some("Describe escalation levels and contacts".to_string()),
-                                                false, false, Some(3), None, None
+                                                Some(t("Describe escalation levels and contacts").to_string()),
+                                                false, false, Some(3), field_err("sla.escalation_procedure"), None
                                             )}
                                         </div>
                                     </div>
@@ -658,12 +735,12 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                     vec![
                         view! {
                             <div class="form-actions-left">
-                                <button class="btn btn-secondary" on:click={prev_step.clone()}>"Back"</button>
+                                <button class="btn btn-secondary" on:click={prev_step.clone()}>{t("Back")}</button>
                             </div>
                         },
                         view! {
                             <div class="form-actions-right">
-                                <button class="btn btn-primary" on:click={next_step.clone()}>"Next: Deliverables"</button>
+                                <button class="btn btn-primary" on:click={next_step.clone()}>{t("Next: Deliverables")}</button>
                             </div>
                         },
                     ]
@@ -673,7 +750,7 @@ pub fn contract_form(contract_id: Option<String>) -> View {
             // Step 4: Deliverables
             if step == 4 {
                 {panel_with_footer(
-                    "Deliverables".to_string(),
+                    t("Deliverables").to_string(),
                     vec![],
                     vec![
                         view! {
@@ -681,7 +758,7 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                                 for (idx, deliverable) in deliverables.get().iter().enumerate() {
                                     <div class="deliverable-item">
                                         <div class="deliverable-header">
-                                            <h4>{format!("Deliverable #{}", idx + 1)}</h4>
+                                            <h4>{tf("Deliverable #{number}", &[("number", (idx + 1).to_string())])}</h4>
                                             <button
                                                 class="btn btn-sm btn-danger"
                                                 on:click={Callback::<()>::new({
@@ -689,30 +766,30 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                                                     move |_| remove_deliverable(idx)
                                                 })}
                                             >
-                                                "Remove"
+                                                {t("Remove")}
                                             </button>
                                         </div>
                                         <div class="deliverable-grid">
                                             {text_input(
-                                                "Description".to_string(),
+                                                t("Description").to_string(),
                                                 signal(deliverable.description.clone()),
-                                                Some("Item description".to_string()),
+                                                Some(t("Item description").to_string()),
                                                 true, false, None, None, None
                                             )}
                                             {text_input(
-                                                "Quantity".to_string(),
+                                                t("Quantity").to_string(),
                                                 signal(deliverable.quantity.to_string()),
                                                 Some("1".to_string()),
                                                 true, false, None, None, Some("number".to_string())
                                             )}
                                             {text_input(
-                                                "Unit".to_string(),
+                                                t("Unit").to_string(),
                                                 signal(deliverable.unit.clone()),
-                                                Some("e.g., Each, Months".to_string()),
+                                                Some(t("e.g., Each, Months").to_string()),
                                                 true, false, None, None, None
                                             )}
                                             {currency_input(
-                                                "Unit Price".to_string(),
+                                                t("Unit Price").to_string(),
                                                 signal(deliverable.unit_price),
                                                 true, false, None, None
                                             )}
@@ -725,7 +802,7 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                                         <line x1="12" y1="5" x2="12" y2="19"/>
                                         <line x1="5" y1="12" x2="19" y2="12"/>
                                     </svg>
-                                    "Add Deliverable"
+                                    {t("Add Deliverable")}
                                 </button>
                             </div>
                         }
@@ -733,21 +810,21 @@ pub fn contract_form(contract_id: Option<String>) -> View {
                     vec![
                         view! {
                             <div class="form-actions-left">
-                                <button class="btn btn-secondary" on:click={prev_step.clone()}>"Back"</button>
+                                <button class="btn btn-secondary" on:click={prev_step.clone()}>{t("Back")}</button>
                             </div>
                         },
                         view! {
                             <div class="form-actions-right">
                                 <button class="btn btn-secondary" on:click={handle_save.clone()} disabled={saving.get()}>
-                                    {if saving.get() { "Saving..." } else { "Save Draft" }}
+                                    {if saving.get() { t("Saving...") } else { t("Save Draft") }}
                                 </button>
                                 if is_edit {
                                     <button class="btn btn-primary" on:click={handle_submit_for_approval} disabled={saving.get()}>
-                                        "Submit for Approval"
+                                        {t("Submit for Approval")}
                                     </button>
                                 } else {
                                     <button class="btn btn-primary" on:click={handle_save.clone()} disabled={saving.get()}>
-                                        "Create Contract"
+                                        {t("Create Contract")}
                                     </button>
                                 }
                             </div>
@@ -13,9 +13,11 @@ use crate::shared::components::{
 };
 use crate::shared::forms::filter_bar;
 use crate::util::format::{format_currency, format_date};
+use crate::util::url;
 use super::types::{ContractStatus, ContractSummary};
 use super::store::ContractsStore;
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Contract list page
 #[component]
@@ -33,11 +35,13 @@ pub fn contract_list() -> View {
         }
     });
 
-    // Filter signals
-    let status_filter = signal(String::new());
-    let supplier_filter = signal(String::new());
-    let search_filter = signal(String::new());
-    let show_expiring = signal(false);
+    // Filter signals, seeded from the URL
+    url::sync_filter(&store.filter);
+    let initial = store.filter.get();
+    let status_filter = signal(initial.status.map(|s| s.as_str().to_string()).unwrap_or_default());
+    let supplier_filter = signal(initial.supplier_id.clone().unwrap_or_default());
+    let search_filter = signal(initial.search.clone().unwrap_or_default());
+    let show_expiring = signal(initial.expiring_within_days.is_some());
 
     // Handle filter changes
     let handle_status_change = Callback::new({
@@ -122,13 +126,13 @@ pub fn contract_list() -> View {
 
     // Table columns
     let columns = vec![
-        DataTableColumn { key: "id".to_string(), label: "Contract ID".to_string(), width: Some("120px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "title".to_string(), label: "Title".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "supplier".to_string(), label: "Supplier".to_string(), width: Some("200px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "value".to_string(), label: "Value".to_string(), width: Some("120px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "period".to_string(), label: "Period".to_string(), width: Some("180px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "progress".to_string(), label: "Progress".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "id".to_string(), label: t("Contract ID").to_string(), width: Some("120px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
+        DataTableColumn { key: "title".to_string(), label: t("Title").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: Some("200px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "value".to_string(), label: t("Value").to_string(), width: Some("120px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "period".to_string(), label: t("Period").to_string(), width: Some("180px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "progress".to_string(), label: t("Progress").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
         DataTableColumn { key: "actions".to_string(), label: "".to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
     ];
 
@@ -163,7 +167,7 @@ pub fn contract_list() -> View {
                 status_view,
                 view! {
                     <div class="row-actions">
-                        <a href={format!("/contracts/{}", contract.id)} class="btn btn-sm btn-secondary">"View"</a>
+                        <a href={format!("/contracts/{}", contract.id)} class="btn btn-sm btn-secondary">{t("View")}</a>
                     </div>
                 },
             ],
@@ -254,18 +258,22 @@ pub fn contract_list() -> View {
 
         <div class="contracts-page" data-testid="contract-list">
             {page_header(
-                "Contracts".to_string(),
-                Some("Manage supplier contracts and agreements".to_string()),
+                t("Contracts").to_string(),
+                Some(t("Manage supplier contracts and agreements").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export"</button> },
-                    view! { <a href="/contracts/new" class="btn btn-primary">"New Contract"</a> },
+                    view! { <button class="btn btn-secondary">{t("Export")}</button> },
+                    view! { <a href="/contracts/new" class="btn btn-primary">{t("New Contract")}</a> },
                 ]
             )}
 
             // Expiry warning notice
             if expiring_count > 0 {
                 {notice_bar(
-                    format!("{} contract(s) expiring within 30 days. Review and initiate renewal process.", expiring_count),
+                    tn(
+                        "{n} contract expiring within 30 days. Review and initiate renewal process.",
+                        "{n} contracts expiring within 30 days. Review and initiate renewal process.",
+                        expiring_count as i64,
+                    ),
                     NoticeType::Warning,
                     None
                 )}
@@ -274,15 +282,15 @@ pub fn contract_list() -> View {
             // Stats row
             <div class="stats-row">
                 <div class="stat-item">
-                    <span class="label">"Total Contracts:"</span>
+                    <span class="label">{t("Total Contracts:")}</span>
                     <span class="count">{store.contracts.get().len().to_string()}</span>
                 </div>
                 <div class={if expiring_count > 0 { "stat-item warning" } else { "stat-item" }}>
-                    <span class="label">"Expiring (30 days):"</span>
+                    <span class="label">{t("Expiring (30 days):")}</span>
                     <span class="count">{expiring_count.to_string()}</span>
                 </div>
                 <div class="stat-item">
-                    <span class="label">"Active:"</span>
+                    <span class="label">{t("Active:")}</span>
                     <span class="count">{
                         store.contracts.get().iter().filter(|c| c.status == ContractStatus::Active).count().to_string()
                     }</span>
@@ -293,25 +301,25 @@ pub fn contract_list() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Status:"</label>
-                        <select on:change={handle_status_change}>
-                            <option value="">"All Statuses"</option>
-                            <option value="draft">"Draft"</option>
-                            <option value="pending_approval">"Pending Approval"</option>
-                            <option value="active">"Active"</option>
-                            <option value="suspended">"Suspended"</option>
-                            <option value="expired">"Expired"</option>
-                            <option value="terminated">"Terminated"</option>
-                            <option value="completed">"Completed"</option>
+                        <label>{t("Status:")}</label>
+                        <select on:change={handle_status_change} value={status_filter.get()}>
+                            <option value="">{t("All Statuses")}</option>
+                            <option value="draft">{t("Draft")}</option>
+                            <option value="pending_approval">{t("Pending Approval")}</option>
+                            <option value="active">{t("Active")}</option>
+                            <option value="suspended">{t("Suspended")}</option>
+                            <option value="expired">{t("Expired")}</option>
+                            <option value="terminated">{t("Terminated")}</option>
+                            <option value="completed">{t("Completed")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Supplier:"</label>
+                        <label>{t("Supplier:")}</label>
                         <input
                             type="text"
-                            placeholder="Search supplier..."
+                            placeholder={t("Search supplier...")}
                             value={supplier_filter.get()}
                             on:input={handle_supplier_change}
                         />
@@ -324,7 +332,7 @@ pub fn contract_list() -> View {
                             checked={show_expiring.get()}
                             on:change={handle_expiring_toggle}
                         />
-                        "Expiring within 30 days"
+                        {t("Expiring within 30 days")}
                     </label>
                 },
                 view! { <div class="filter-spacer"></div> },
@@ -332,30 +340,30 @@ pub fn contract_list() -> View {
                     <div class="filter-group">
                         <input
                             type="text"
-                            placeholder="Search contracts..."
+                            placeholder={t("Search contracts...")}
                             value={search_filter.get()}
                             on:input={handle_search_change}
                         />
                     </div>
                 },
                 view! {
-                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>"Clear"</button>
+                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>{t("Clear")}</button>
                 },
             ])}
 
             // Contracts table
             if loading {
-                <div class="loading-state">"Loading contracts..."</div>
+                <div class="loading-state">{t("Loading contracts...")}</div>
             } else if rows.is_empty() {
                 {empty_state(
-                    "No contracts found".to_string(),
-                    Some("Try adjusting your filters or create a new contract".to_string()),
+                    t("No contracts found").to_string(),
+                    Some(t("Try adjusting your filters or create a new contract").to_string()),
                     None,
-                    Some(view! { <a href="/contracts/new" class="btn btn-primary">"Create Contract"</a> })
+                    Some(view! { <a href="/contracts/new" class="btn btn-primary">{t("Create Contract")}</a> })
                 )}
             } else {
                 {panel(
-                    format!("Contracts ({})", rows.len()),
+                    tf("Contracts ({count})", &[("count", rows.len().to_string())]),
                     vec![],
                     vec![data_table(columns, rows, None)]
                 )}
@@ -405,7 +413,7 @@ fn get_expiry_warning(contract: &ContractSummary) -> View {
                         <line x1="12" y1="8" x2="12" y2="12"/>
                         <line x1="12" y1="16" x2="12.01" y2="16"/>
                     </svg>
-                    "Expired"
+                    {t("Expired")}
                 </span>
             }
         }
@@ -417,7 +425,7 @@ fn get_expiry_warning(contract: &ContractSummary) -> View {
                         <line x1="12" y1="8" x2="12" y2="12"/>
                         <line x1="12" y1="16" x2="12.01" y2="16"/>
                     </svg>
-                    {format!("Expires in {} days", days)}
+                    {tf("Expires in {days} days", &[("days", days.to_string())])}
                 </span>
             }
         }
@@ -428,7 +436,7 @@ fn get_expiry_warning(contract: &ContractSummary) -> View {
                         <circle cx="12" cy="12" r="10"/>
                         <polyline points="12 6 12 12 16 14"/>
                     </svg>
-                    {format!("Expires in {} days", days)}
+                    {tf("Expires in {days} days", &[("days", days.to_string())])}
                 </span>
             }
         }
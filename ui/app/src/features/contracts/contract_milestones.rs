@@ -1,6 +1,8 @@
 //! Contract milestones tracking page
 
 use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
 use crate::shared::layout::page_header;
 use crate::shared::components::{
     panel, panel_with_footer,
@@ -14,6 +16,7 @@ use crate::util::format::{format_currency, format_currency_full, format_date};
 use super::types::{Contract, ContractMilestone, MilestoneStatus};
 use super::store::ContractsStore;
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Contract milestones tracking page
 #[component]
@@ -34,7 +37,8 @@ pub fn contract_milestones(contract_id: String) -> View {
     });
 
     let loading = store.loading.get();
-    let contract = store.selected.get();
+    let error = store.error.get();
+    let contract = store.selected.get().filter(|c| c.id == contract_id);
 
     view! {
         style {
@@ -242,15 +246,11 @@ pub fn contract_milestones(contract_id: String) -> View {
 
         <div class="milestones-page" data-testid="contract-milestones">
             if loading {
-                <div class="loading-state">"Loading contract..."</div>
+                <div class="loading-state">{t("Loading contract...")}</div>
             } else if let Some(contract) = contract {
                 {render_milestones_content(contract)}
             } else {
-                {notice_bar(
-                    "Contract not found".to_string(),
-                    NoticeType::Error,
-                    None
-                )}
+                {entity_not_found(t("Contract"), &contract_id, error, Route::ContractsList, t("Back to Contracts"))}
             }
         </div>
     }
@@ -269,26 +269,26 @@ fn render_milestones_content(contract: Contract) -> View {
     let completed_payments = contract.completed_milestone_payments();
     let progress = contract.milestone_progress();
 
-    let payment_progress = if total_payments > 0.0 {
-        (completed_payments / total_payments) * 100.0
-    } else {
-        0.0
-    };
+    let payment_progress = completed_payments.percent_of(total_payments);
 
     view! {
         {page_header(
-            format!("Milestones: {}", contract.title),
-            Some(format!("Contract {} - {}", contract.id, contract.supplier_name)),
+            tf("Milestones: {title}", &[("title", contract.title.to_string())]),
+            Some(tf("Contract {id} - {supplier_name}", &[("id", contract.id.to_string()), ("supplier_name", contract.supplier_name.to_string())])),
             vec![
-                view! { <a href={format!("/contracts/{}", contract.id)} class="btn btn-secondary">"View Contract"</a> },
-                view! { <button class="btn btn-primary">"Add Milestone"</button> },
+                view! { <a href={format!("/contracts/{}", contract.id)} class="btn btn-secondary">{t("View Contract")}</a> },
+                view! { <button class="btn btn-primary">{t("Add Milestone")}</button> },
             ]
         )}
 
         // Overdue warning
         if overdue_milestones > 0 {
             {notice_bar(
-                format!("{} milestone(s) are overdue. Please review and take action.", overdue_milestones),
+                tn(
+                    "{n} milestone is overdue. Please review and take action.",
+                    "{n} milestones are overdue. Please review and take action.",
+                    overdue_milestones as i64,
+                ),
                 NoticeType::Warning,
                 None
             )}
@@ -297,24 +297,24 @@ fn render_milestones_content(contract: Contract) -> View {
         // Summary cards
         <div class="milestone-summary">
             <div class="summary-card">
-                <div class="label">"Total Milestones"</div>
+                <div class="label">{t("Total Milestones")}</div>
                 <div class="value">{total_milestones.to_string()}</div>
-                <div class="sub">{format!("{} completed", completed_milestones)}</div>
+                <div class="sub">{tn("{n} completed", "{n} completed", completed_milestones as i64)}</div>
             </div>
             <div class="summary-card">
-                <div class="label">"Completion Progress"</div>
+                <div class="label">{t("Completion Progress")}</div>
                 <div class="value">{format!("{:.0}%", progress)}</div>
-                <div class="sub">{format!("{} of {} milestones", completed_milestones, total_milestones)}</div>
+                <div class="sub">{tf("{done} of {total} milestones", &[("done", completed_milestones.to_string()), ("total", total_milestones.to_string())])}</div>
             </div>
             <div class="summary-card">
-                <div class="label">"Total Contract Value"</div>
+                <div class="label">{t("Total Contract Value")}</div>
                 <div class="value currency">{format_currency(contract.value)}</div>
-                <div class="sub">"Including all milestones"</div>
+                <div class="sub">{t("Including all milestones")}</div>
             </div>
             <div class="summary-card">
-                <div class="label">"Payments Made"</div>
+                <div class="label">{t("Payments Made")}</div>
                 <div class="value currency">{format_currency(completed_payments)}</div>
-                <div class="sub">{format!("{:.0}% of total", payment_progress)}</div>
+                <div class="sub">{tf("{percent} of total", &[("percent", format!("{:.0}%", payment_progress))])}</div>
             </div>
         </div>
 
@@ -322,7 +322,7 @@ fn render_milestones_content(contract: Contract) -> View {
         <div class="milestones-grid">
             // Timeline
             {panel(
-                "Milestone Timeline".to_string(),
+                t("Milestone Timeline").to_string(),
                 vec![],
                 vec![
                     view! {
@@ -339,22 +339,22 @@ fn render_milestones_content(contract: Contract) -> View {
             <div>
                 // Progress overview
                 <div class="progress-overview">
-                    <div style="font-size: 13px; font-weight: 600; margin-bottom: 12px;">"Overall Progress"</div>
+                    <div style="font-size: 13px; font-weight: 600; margin-bottom: 12px;">{t("Overall Progress")}</div>
                     {progress_bar(progress, ProgressColor::Blue, true, None)}
                     <div class="progress-stats">
                         <div class="progress-stat">
-                            <span class="label">"Paid"</span>
+                            <span class="label">{t("Paid")}</span>
                             <span class="value">{format_currency(completed_payments)}</span>
                         </div>
                         <div class="progress-stat">
-                            <span class="label">"Remaining"</span>
+                            <span class="label">{t("Remaining")}</span>
                             <span class="value">{format_currency(total_payments - completed_payments)}</span>
                         </div>
                     </div>
                 </div>
 
                 {panel(
-                    "Payment Schedule".to_string(),
+                    t("Payment Schedule").to_string(),
                     vec![],
                     vec![
                         view! {
@@ -389,9 +389,9 @@ fn render_milestone_card(milestone: ContractMilestone) -> View {
     };
 
     let date_display = if let Some(completed) = &milestone.completed_date {
-        format!("Completed: {}", format_date(completed))
+        tf("Completed: {date}", &[("date", format_date(completed))])
     } else {
-        format!("Due: {}", format_date(&milestone.due_date))
+        tf("Due: {date}", &[("date", format_date(&milestone.due_date))])
     };
 
     view! {
@@ -411,12 +411,12 @@ fn render_milestone_card(milestone: ContractMilestone) -> View {
                     <div>
                         <span class="amount">{format_currency_full(milestone.payment_amount)}</span>
                     </div>
-                    <span class="percentage">{format!("{:.0}% of contract", milestone.payment_percentage)}</span>
+                    <span class="percentage">{tf("{percent} of contract", &[("percent", format!("{:.0}%", milestone.payment_percentage))])}</span>
                 </div>
 
                 if !milestone.deliverables.is_empty() {
                     <div class="milestone-deliverables">
-                        <h5>"Deliverables"</h5>
+                        <h5>{t("Deliverables")}</h5>
                         for deliverable in milestone.deliverables.iter() {
                             <div class={if milestone.status == MilestoneStatus::Completed { "deliverable-item" } else { "deliverable-item pending" }}>
                                 if milestone.status == MilestoneStatus::Completed {
@@ -436,7 +436,7 @@ fn render_milestone_card(milestone: ContractMilestone) -> View {
 
                 if let Some(notes) = &milestone.notes {
                     <div class="milestone-notes">
-                        <strong>"Note: "</strong>{notes.clone()}
+                        <strong>{t("Note: ")}</strong>{notes.clone()}
                     </div>
                 }
             </div>
@@ -449,9 +449,9 @@ fn render_payment_item(milestone: ContractMilestone) -> View {
     let class = if is_completed { "payment-item completed" } else { "payment-item pending" };
 
     let date_str = if let Some(completed) = &milestone.completed_date {
-        format!("Paid: {}", format_date(completed))
+        tf("Paid: {date}", &[("date", format_date(completed))])
     } else {
-        format!("Due: {}", format_date(&milestone.due_date))
+        tf("Due: {date}", &[("date", format_date(&milestone.due_date))])
     };
 
     view! {
@@ -486,7 +486,8 @@ pub fn milestone_detail(contract_id: String, milestone_id: String) -> View {
     });
 
     let loading = store.loading.get();
-    let contract = store.selected.get();
+    let error = store.error.get();
+    let contract = store.selected.get().filter(|c| c.id == contract_id);
 
     let milestone = contract.as_ref().and_then(|c| {
         c.milestones.iter().find(|m| m.id == milestone_id).cloned()
@@ -495,15 +496,15 @@ pub fn milestone_detail(contract_id: String, milestone_id: String) -> View {
     view! {
         <div class="milestone-detail" data-testid="milestone-detail">
             if loading {
-                <div class="loading-state">"Loading..."</div>
+                <div class="loading-state">{t("Loading...")}</div>
             } else if let Some(milestone) = milestone {
                 {page_header(
                     milestone.title.clone(),
-                    Some(format!("Milestone {} - Due: {}", milestone.id, format_date(&milestone.due_date))),
+                    Some(tf("Milestone {id} - Due: {date}", &[("id", milestone.id.to_string()), ("date", format_date(&milestone.due_date))])),
                     vec![
-                        view! { <a href={format!("/contracts/{}/milestones", contract_id)} class="btn btn-secondary">"Back to Milestones"</a> },
+                        view! { <a href={format!("/contracts/{}/milestones", contract_id)} class="btn btn-secondary">{t("Back to Milestones")}</a> },
                         if milestone.status == MilestoneStatus::InProgress {
-                            view! { <button class="btn btn-primary">"Mark Complete"</button> }
+                            view! { <button class="btn btn-primary">{t("Mark Complete")}</button> }
                         } else {
                             view! { <span></span> }
                         },
@@ -511,25 +512,25 @@ pub fn milestone_detail(contract_id: String, milestone_id: String) -> View {
                 )}
 
                 {panel(
-                    "Milestone Details".to_string(),
+                    t("Milestone Details").to_string(),
                     vec![],
                     vec![
                         view! {
                             <div class="form-grid">
                                 <div class="detail-row">
-                                    <span class="label">"Description"</span>
+                                    <span class="label">{t("Description")}</span>
                                     <span class="value">{milestone.description.clone()}</span>
                                 </div>
                                 <div class="detail-row">
-                                    <span class="label">"Payment Amount"</span>
+                                    <span class="label">{t("Payment Amount")}</span>
                                     <span class="value">{format_currency_full(milestone.payment_amount)}</span>
                                 </div>
                                 <div class="detail-row">
-                                    <span class="label">"Percentage"</span>
+                                    <span class="label">{t("Percentage")}</span>
                                     <span class="value">{format!("{:.0}%", milestone.payment_percentage)}</span>
                                 </div>
                                 <div class="detail-row">
-                                    <span class="label">"Status"</span>
+                                    <span class="label">{t("Status")}</span>
                                     <span class="value">{milestone.status.as_str()}</span>
                                 </div>
                             </div>
@@ -537,11 +538,7 @@ pub fn milestone_detail(contract_id: String, milestone_id: String) -> View {
                     ]
                 )}
             } else {
-                {notice_bar(
-                    "Milestone not found".to_string(),
-                    NoticeType::Error,
-                    None
-                )}
+                {entity_not_found(t("Milestone"), &milestone_id, error, Route::ContractsMilestones(contract_id.clone()), t("Back to Milestones"))}
             }
         </div>
     }
@@ -3,6 +3,7 @@
 pub mod types;
 pub mod store;
 pub mod service;
+pub mod search;
 pub mod contract_list;
 pub mod contract_form;
 pub mod contract_milestones;
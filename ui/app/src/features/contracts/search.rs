@@ -0,0 +1,50 @@
+//! Contract search provider
+
+use crate::Route;
+use crate::features::search::provider::{SearchFuture, SearchProvider};
+use crate::features::search::types::{match_score, SearchEntity, SearchHit};
+use crate::util::format::format_currency;
+use super::store::ContractsStore;
+use super::service;
+
+/// Searches the contracts held in the store, loading them on first use
+pub struct ContractSearch {
+    store: ContractsStore,
+}
+
+impl ContractSearch {
+    pub fn new(store: ContractsStore) -> Self {
+        Self { store }
+    }
+}
+
+impl SearchProvider for ContractSearch {
+    fn entity(&self) -> SearchEntity {
+        SearchEntity::Contract
+    }
+
+    fn search(&self, query: String) -> SearchFuture {
+        let store = self.store.clone();
+        Box::pin(async move {
+            if store.contracts.get().is_empty() {
+                service::load_contracts(&store).await;
+                if let Some(e) = store.error.get() {
+                    return Err(e);
+                }
+            }
+
+            let hits = store.contracts.get().iter().filter_map(|c| {
+                let score = match_score(&query, &[&c.id], &[&c.title, &c.supplier_name])?;
+                Some(SearchHit {
+                    entity: SearchEntity::Contract,
+                    id: c.id.clone(),
+                    title: c.title.clone(),
+                    subtitle: format!("{} - {} - {}", c.id, c.supplier_name, format_currency(c.value)),
+                    route: Route::ContractsMilestones(c.id.clone()),
+                    score,
+                })
+            }).collect();
+            Ok(hits)
+        })
+    }
+}
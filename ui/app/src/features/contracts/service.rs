@@ -1,5 +1,14 @@
 //! Contracts service - API calls
 
+use crate::features::workflow::service as workflow;
+use crate::features::workflow::types::{
+    ApprovalDecision, ApprovalDocument, ApprovalReassignment, ApprovalRequest, WorkflowState,
+};
+use crate::util::api::{self, HttpMethod};
+use crate::util::auth;
+use crate::util::backend;
+use crate::util::datetime::now_iso;
+use crate::util::reference::{self, DocumentType};
 use super::store::{ContractsStore, load_mock_contracts, get_mock_contract};
 use super::types::{Contract, ContractStatus};
 
@@ -8,9 +17,7 @@ pub async fn load_contracts(store: &ContractsStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_contracts(store);
+    load_contract_list(store).await;
 
     store.loading.set(false);
 }
@@ -20,25 +27,30 @@ pub async fn load_contract(store: &ContractsStore, id: &str) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, get mock data
-    if let Some(contract) = get_mock_contract(id) {
-        store.selected.set(Some(contract));
+    let loaded = if backend::is_mock() {
+        get_mock_contract(id).ok_or_else(|| format!("Contract {} not found", id))
     } else {
-        store.error.set(Some(format!("Contract {} not found", id)));
+        api::get::<Contract>(&format!("/contracts/{}", id)).await.map_err(|e| e.to_string())
+    };
+    match loaded {
+        Ok(mut contract) => {
+            workflow::reroute(&mut contract.approval_workflow, ApprovalDocument::Contract, contract.value);
+            store.selected.set(Some(contract));
+        }
+        Err(e) => {
+            store.selected.set(None);
+            store.error.set(Some(e));
+        }
     }
 
     store.loading.set(false);
 }
 
 /// Create a new contract
-pub async fn create_contract(store: &ContractsStore, contract: Contract) -> Result<String, String> {
+pub async fn create_contract(store: &ContractsStore, mut contract: Contract) -> Result<String, String> {
     store.saving.set(true);
     store.error.set(None);
-
-    // In production, this would call the API
-    // Simulate API delay
-    // await sleep(500);
+    store.field_errors.set(Vec::new());
 
     // Validate required fields
     if contract.title.is_empty() {
@@ -51,7 +63,7 @@ pub async fn create_contract(store: &ContractsStore, contract: Contract) -> Resu
         return Err("Supplier is required".to_string());
     }
 
-    if contract.value <= 0.0 {
+    if !contract.value.is_positive() {
         store.saving.set(false);
         return Err("Contract value must be greater than zero".to_string());
     }
@@ -61,22 +73,42 @@ pub async fn create_contract(store: &ContractsStore, contract: Contract) -> Resu
         return Err("Contract dates are required".to_string());
     }
 
-    // Generate new ID
-    let new_id = format!("CTR-2025-{:04}", rand_id());
+    // Provisional ID, replaced by the server's when using the REST backend
+    if backend::is_mock() && store.contracts.get().is_empty() {
+        load_mock_contracts(store);
+    }
+    let existing = store.contracts.get();
+    contract.id = reference::issue(DocumentType::Contract, existing.iter().map(|c| c.id.as_str())).number;
+    if let Some(user) = auth::current().user {
+        contract.created_by = user.name;
+        contract.created_by_id = user.id;
+    }
+    match backend::save(HttpMethod::Post, "/contracts", &contract).await {
+        Ok(saved) => {
+            reference::reconcile(DocumentType::Contract, &contract.id, &saved.id);
+            contract = saved;
+        }
+        Err(e) => {
+            store.field_errors.set(e.field_errors().to_vec());
+            store.saving.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+    let new_id = contract.id.clone();
 
-    // In production, POST to API and reload list
-    load_mock_contracts(store);
+    load_contract_list(store).await;
 
     store.saving.set(false);
     Ok(new_id)
 }
 
 /// Update an existing contract
-pub async fn update_contract(store: &ContractsStore, contract: Contract) -> Result<(), String> {
+pub async fn update_contract(store: &ContractsStore, mut contract: Contract) -> Result<(), String> {
     store.saving.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
-    // In production, this would call the API
     // Validate required fields
     if contract.id.is_empty() {
         store.saving.set(false);
@@ -88,9 +120,19 @@ pub async fn update_contract(store: &ContractsStore, contract: Contract) -> Resu
         return Err("Contract title is required".to_string());
     }
 
-    // In production, PUT to API and reload
+    let endpoint = format!("/contracts/{}", contract.id);
+    match backend::save(HttpMethod::Put, &endpoint, &contract).await {
+        Ok(saved) => contract = saved,
+        Err(e) => {
+            store.field_errors.set(e.field_errors().to_vec());
+            store.saving.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+
     store.selected.set(Some(contract));
-    load_mock_contracts(store);
+    load_contract_list(store).await;
 
     store.saving.set(false);
     Ok(())
@@ -105,8 +147,6 @@ pub async fn update_contract_status(
     store.saving.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // Validate status transition
     if let Some(mut contract) = store.selected.get() {
         if contract.id == contract_id {
             // Validate status transition
@@ -132,25 +172,75 @@ pub async fn update_contract_status(
             }
 
             contract.status = new_status;
+            let endpoint = format!("/contracts/{}/status", contract_id);
+            match backend::save(HttpMethod::Post, &endpoint, &contract).await {
+                Ok(saved) => contract = saved,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
+            }
             store.selected.set(Some(contract));
         }
     }
 
-    load_mock_contracts(store);
+    load_contract_list(store).await;
     store.saving.set(false);
     Ok(())
 }
 
-/// Submit contract for approval
+/// Submit contract for approval, routing it along the chain the
+/// delegation-of-authority matrix prescribes for its value
 pub async fn submit_for_approval(store: &ContractsStore, contract_id: &str) -> Result<(), String> {
-    update_contract_status(store, contract_id, ContractStatus::PendingApproval).await
+    store.saving.set(true);
+    store.error.set(None);
+
+    if let Some(mut contract) = store.selected.get() {
+        if contract.id == contract_id {
+            if contract.status != ContractStatus::Draft {
+                store.saving.set(false);
+                return Err("Only draft contracts can be submitted for approval".to_string());
+            }
+
+            let request = ApprovalRequest::new(ApprovalDocument::Contract, contract.value)
+                .categories([contract.contract_type.clone()]);
+            match workflow::approval_chain(&request).await {
+                Ok(chain) => contract.approval_workflow = chain,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.clone()));
+                    return Err(e);
+                }
+            }
+
+            contract.status = ContractStatus::PendingApproval;
+            let endpoint = format!("/contracts/{}/submit", contract_id);
+            match backend::save(HttpMethod::Post, &endpoint, &contract).await {
+                Ok(saved) => contract = saved,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
+            }
+            store.selected.set(Some(contract));
+        }
+    }
+
+    load_contract_list(store).await;
+    store.saving.set(false);
+    Ok(())
 }
 
-/// Approve a contract
-pub async fn approve_contract(
+/// Record an approver's decision on the contract's current approval stage.
+///
+/// The contract becomes active once the last stage approves. A rejection or
+/// a return sends it back to draft, with the decision kept on its chain.
+pub async fn decide_contract(
     store: &ContractsStore,
     contract_id: &str,
-    approver: &str,
+    decision: &ApprovalDecision,
 ) -> Result<(), String> {
     store.saving.set(true);
     store.error.set(None);
@@ -162,14 +252,73 @@ pub async fn approve_contract(
                 return Err("Contract must be in Pending Approval status to approve".to_string());
             }
 
-            contract.status = ContractStatus::Active;
-            contract.approved_by = Some(approver.to_string());
-            contract.approved_at = Some(chrono_now());
+            let applied = match workflow::apply(ApprovalDocument::Contract, contract_id, &contract.created_by_id, &mut contract.approval_workflow, decision) {
+                Ok(applied) => applied,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.clone()));
+                    return Err(e);
+                }
+            };
+            match applied.state {
+                WorkflowState::Approved => {
+                    contract.status = ContractStatus::Active;
+                    contract.approved_by = auth::current().user.map(|u| u.name);
+                    contract.approved_at = Some(now_iso());
+                }
+                WorkflowState::Rejected | WorkflowState::Returned => contract.status = ContractStatus::Draft,
+                WorkflowState::InProgress { .. } | WorkflowState::NotStarted => {}
+            }
+
+            let endpoint = format!("/contracts/{}/approval", contract_id);
+            match backend::save(HttpMethod::Post, &endpoint, &contract).await {
+                Ok(saved) => contract = saved,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
+            }
+            applied.record();
+            store.selected.set(Some(contract));
+        }
+    }
+
+    load_contract_list(store).await;
+    store.saving.set(false);
+    Ok(())
+}
+
+/// Move a stuck approval step of the contract to another approver
+pub async fn reassign_contract_step(
+    store: &ContractsStore,
+    contract_id: &str,
+    reassignment: &ApprovalReassignment,
+) -> Result<(), String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    if let Some(mut contract) = store.selected.get() {
+        if contract.id == contract_id {
+            if let Err(e) = workflow::reassign(ApprovalDocument::Contract, contract_id, &mut contract.approval_workflow, reassignment) {
+                store.saving.set(false);
+                store.error.set(Some(e.clone()));
+                return Err(e);
+            }
+
+            let endpoint = format!("/contracts/{}/approval", contract_id);
+            match backend::save(HttpMethod::Post, &endpoint, &contract).await {
+                Ok(saved) => contract = saved,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
+            }
             store.selected.set(Some(contract));
         }
     }
 
-    load_mock_contracts(store);
     store.saving.set(false);
     Ok(())
 }
@@ -203,9 +352,14 @@ pub async fn delete_contract(store: &ContractsStore, contract_id: &str) -> Resul
         }
     }
 
-    // In production, DELETE to API
+    if let Err(e) = backend::remove(&format!("/contracts/{}", contract_id)).await {
+        store.saving.set(false);
+        store.error.set(Some(e.to_string()));
+        return Err(e.to_string());
+    }
+
     store.selected.set(None);
-    load_mock_contracts(store);
+    load_contract_list(store).await;
 
     store.saving.set(false);
     Ok(())
@@ -227,19 +381,17 @@ pub async fn get_expiring_contracts(store: &ContractsStore, days: u32) {
     filter.status = Some(ContractStatus::Active);
     store.filter.set(filter);
 
-    load_mock_contracts(store);
+    load_contract_list(store).await;
     store.loading.set(false);
 }
 
 // Helper functions
-fn rand_id() -> u32 {
-    // Simple pseudo-random for demo
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let duration = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-    (duration.as_millis() % 10000) as u32
-}
 
-fn chrono_now() -> String {
-    // In production, use chrono crate
-    "2025-02-27T10:00:00Z".to_string()
+/// Refresh the contract list from the configured backend
+async fn load_contract_list(store: &ContractsStore) {
+    if backend::is_mock() {
+        load_mock_contracts(store);
+    } else if let Err(e) = backend::fetch_into("/contracts", &store.contracts).await {
+        store.error.set(Some(e.to_string()));
+    }
 }
@@ -1,6 +1,10 @@
 //! Contracts store
 
 use components::prelude::*;
+use crate::features::workflow::types::{ApprovalStep, StepStatus};
+use crate::util::api::FieldError;
+use crate::util::datetime;
+use crate::util::money::Money;
 use super::types::{Contract, ContractSummary, ContractFilter, ContractStatus, ContractMilestone, MilestoneStatus, ContractTerms, ContractSla, ContractDeliverable};
 
 /// Contracts state store
@@ -11,6 +15,8 @@ pub struct ContractsStore {
     pub filter: Signal<ContractFilter>,
     pub loading: Signal<bool>,
     pub error: Signal<Option<String>>,
+    /// Per-field validation errors from the last failed save
+    pub field_errors: Signal<Vec<FieldError>>,
     pub saving: Signal<bool>,
 }
 
@@ -22,6 +28,7 @@ impl ContractsStore {
             filter: signal(ContractFilter::default()),
             loading: signal(false),
             error: signal(None),
+            field_errors: signal(Vec::new()),
             saving: signal(false),
         }
     }
@@ -114,13 +121,13 @@ impl ContractsStore {
 
 /// Load mock contracts data for demo
 pub fn load_mock_contracts(store: &ContractsStore) {
-    let contracts = vec![
+    let mut contracts = vec![
         ContractSummary {
             id: "CTR-2025-0234".to_string(),
             title: "IT Infrastructure Support Services".to_string(),
             supplier_name: "TechSolutions SA (Pty) Ltd".to_string(),
             supplier_bbbee_level: 1,
-            value: 12_500_000.0,
+            value: Money::rands(12_500_000),
             start_date: "2025-01-01".to_string(),
             end_date: "2027-12-31".to_string(),
             status: ContractStatus::Active,
@@ -132,7 +139,7 @@ pub fn load_mock_contracts(store: &ContractsStore) {
             title: "Office Supplies Framework Agreement".to_string(),
             supplier_name: "Office Pro Distributors".to_string(),
             supplier_bbbee_level: 2,
-            value: 3_200_000.0,
+            value: Money::rands(3_200_000),
             start_date: "2025-02-01".to_string(),
             end_date: "2026-01-31".to_string(),
             status: ContractStatus::Active,
@@ -144,7 +151,7 @@ pub fn load_mock_contracts(store: &ContractsStore) {
             title: "Security Services - Head Office".to_string(),
             supplier_name: "SecureGuard Holdings".to_string(),
             supplier_bbbee_level: 1,
-            value: 8_750_000.0,
+            value: Money::rands(8_750_000),
             start_date: "2024-04-01".to_string(),
             end_date: "2025-03-31".to_string(),
             status: ContractStatus::Active,
@@ -156,7 +163,7 @@ pub fn load_mock_contracts(store: &ContractsStore) {
             title: "Fleet Maintenance Services".to_string(),
             supplier_name: "AutoCare Fleet Management".to_string(),
             supplier_bbbee_level: 3,
-            value: 4_500_000.0,
+            value: Money::rands(4_500_000),
             start_date: "2024-07-01".to_string(),
             end_date: "2025-06-30".to_string(),
             status: ContractStatus::Active,
@@ -168,7 +175,7 @@ pub fn load_mock_contracts(store: &ContractsStore) {
             title: "Catering Services - Annual".to_string(),
             supplier_name: "Gourmet Corporate Catering".to_string(),
             supplier_bbbee_level: 2,
-            value: 1_800_000.0,
+            value: Money::rands(1_800_000),
             start_date: "2024-01-01".to_string(),
             end_date: "2024-12-31".to_string(),
             status: ContractStatus::Expired,
@@ -180,7 +187,7 @@ pub fn load_mock_contracts(store: &ContractsStore) {
             title: "Cloud Hosting Services".to_string(),
             supplier_name: "CloudFirst SA".to_string(),
             supplier_bbbee_level: 1,
-            value: 6_200_000.0,
+            value: Money::rands(6_200_000),
             start_date: "2025-03-01".to_string(),
             end_date: "2028-02-28".to_string(),
             status: ContractStatus::PendingApproval,
@@ -192,7 +199,7 @@ pub fn load_mock_contracts(store: &ContractsStore) {
             title: "Professional Training Services".to_string(),
             supplier_name: "Skills Development Academy".to_string(),
             supplier_bbbee_level: 1,
-            value: 2_400_000.0,
+            value: Money::rands(2_400_000),
             start_date: "2025-04-01".to_string(),
             end_date: "2026-03-31".to_string(),
             status: ContractStatus::Draft,
@@ -204,7 +211,7 @@ pub fn load_mock_contracts(store: &ContractsStore) {
             title: "Cleaning Services - Regional Offices".to_string(),
             supplier_name: "CleanCorp Services".to_string(),
             supplier_bbbee_level: 2,
-            value: 2_100_000.0,
+            value: Money::rands(2_100_000),
             start_date: "2024-03-01".to_string(),
             end_date: "2025-02-28".to_string(),
             status: ContractStatus::Active,
@@ -213,6 +220,11 @@ pub fn load_mock_contracts(store: &ContractsStore) {
         },
     ];
 
+    // Contracts already under way count down from today rather than the fixture's
+    for contract in contracts.iter_mut().filter(|c| c.days_to_expiry.is_some()) {
+        contract.days_to_expiry = datetime::days_until(&contract.end_date).map(|d| d as i32);
+    }
+
     store.contracts.set(contracts);
 }
 
@@ -226,7 +238,7 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
             supplier_id: "SUP-001".to_string(),
             supplier_name: "TechSolutions SA (Pty) Ltd".to_string(),
             supplier_bbbee_level: 1,
-            value: 12_500_000.0,
+            value: Money::rands(12_500_000),
             start_date: "2025-01-01".to_string(),
             end_date: "2027-12-31".to_string(),
             status: ContractStatus::Active,
@@ -261,8 +273,8 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "24/7 Help Desk Support".to_string(),
                     quantity: 12,
                     unit: "Months".to_string(),
-                    unit_price: 450_000.0,
-                    total_price: 5_400_000.0,
+                    unit_price: Money::rands(450_000),
+                    total_price: Money::rands(5_400_000),
                     delivery_date: "2025-12-31".to_string(),
                     delivered: false,
                 },
@@ -271,8 +283,8 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "Network Infrastructure Management".to_string(),
                     quantity: 12,
                     unit: "Months".to_string(),
-                    unit_price: 350_000.0,
-                    total_price: 4_200_000.0,
+                    unit_price: Money::rands(350_000),
+                    total_price: Money::rands(4_200_000),
                     delivery_date: "2025-12-31".to_string(),
                     delivered: false,
                 },
@@ -281,8 +293,8 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "Hardware Maintenance & Support".to_string(),
                     quantity: 12,
                     unit: "Months".to_string(),
-                    unit_price: 241_667.0,
-                    total_price: 2_900_000.0,
+                    unit_price: Money::rands(241_667),
+                    total_price: Money::rands(2_900_000),
                     delivery_date: "2025-12-31".to_string(),
                     delivered: false,
                 },
@@ -295,7 +307,7 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "Complete project initiation, knowledge transfer, and systems handover from previous vendor".to_string(),
                     due_date: "2025-01-31".to_string(),
                     completed_date: Some("2025-01-28".to_string()),
-                    payment_amount: 1_250_000.0,
+                    payment_amount: Money::rands(1_250_000),
                     payment_percentage: 10.0,
                     status: MilestoneStatus::Completed,
                     deliverables: vec![
@@ -312,7 +324,7 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "First quarter service delivery with full SLA compliance".to_string(),
                     due_date: "2025-03-31".to_string(),
                     completed_date: None,
-                    payment_amount: 3_125_000.0,
+                    payment_amount: Money::rands(3_125_000),
                     payment_percentage: 25.0,
                     status: MilestoneStatus::InProgress,
                     deliverables: vec![
@@ -329,7 +341,7 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "Second quarter service delivery with continuous improvement".to_string(),
                     due_date: "2025-06-30".to_string(),
                     completed_date: None,
-                    payment_amount: 3_125_000.0,
+                    payment_amount: Money::rands(3_125_000),
                     payment_percentage: 25.0,
                     status: MilestoneStatus::Pending,
                     deliverables: vec![
@@ -346,7 +358,7 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "Complete first year of service delivery with annual review".to_string(),
                     due_date: "2025-12-31".to_string(),
                     completed_date: None,
-                    payment_amount: 5_000_000.0,
+                    payment_amount: Money::rands(5_000_000),
                     payment_percentage: 40.0,
                     status: MilestoneStatus::Pending,
                     deliverables: vec![
@@ -365,10 +377,58 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                 "bbbee_certificate.pdf".to_string(),
             ],
             created_by: "John Smith".to_string(),
+            created_by_id: "U017".to_string(),
             created_at: "2024-12-15T10:30:00Z".to_string(),
             updated_at: "2025-02-26T14:22:00Z".to_string(),
             approved_by: Some("Sarah Johnson".to_string()),
             approved_at: Some("2024-12-20T09:15:00Z".to_string()),
+            approval_workflow: vec![
+                ApprovalStep {
+                    step: 1,
+                    role: "Contract Manager".to_string(),
+                    approver: Some("John Smith".to_string()),
+                    approver_id: None,
+                    status: StepStatus::Approved,
+                    date: Some("2024-12-16T11:00:00Z".to_string()),
+                    comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+                ApprovalStep {
+                    step: 2,
+                    role: "CFO".to_string(),
+                    approver: Some("Thandi Nkosi".to_string()),
+                    approver_id: None,
+                    status: StepStatus::Approved,
+                    date: Some("2024-12-18T15:40:00Z".to_string()),
+                    comments: Some("Within the approved IT budget".to_string()),
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+                ApprovalStep {
+                    step: 3,
+                    role: "Accounting Officer".to_string(),
+                    approver: Some("Sarah Johnson".to_string()),
+                    approver_id: Some("U008".to_string()),
+                    status: StepStatus::Approved,
+                    date: Some("2024-12-20T09:15:00Z".to_string()),
+                    comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+            ],
         }),
         "CTR-2025-0156" => Some(Contract {
             id: "CTR-2025-0156".to_string(),
@@ -377,7 +437,7 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
             supplier_id: "SUP-003".to_string(),
             supplier_name: "SecureGuard Holdings".to_string(),
             supplier_bbbee_level: 1,
-            value: 8_750_000.0,
+            value: Money::rands(8_750_000),
             start_date: "2024-04-01".to_string(),
             end_date: "2025-03-31".to_string(),
             status: ContractStatus::Active,
@@ -402,7 +462,7 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "First quarter security services delivery".to_string(),
                     due_date: "2024-06-30".to_string(),
                     completed_date: Some("2024-06-30".to_string()),
-                    payment_amount: 2_187_500.0,
+                    payment_amount: Money::rands(2_187_500),
                     payment_percentage: 25.0,
                     status: MilestoneStatus::Completed,
                     deliverables: Vec::new(),
@@ -415,7 +475,7 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "Second quarter security services delivery".to_string(),
                     due_date: "2024-09-30".to_string(),
                     completed_date: Some("2024-09-30".to_string()),
-                    payment_amount: 2_187_500.0,
+                    payment_amount: Money::rands(2_187_500),
                     payment_percentage: 25.0,
                     status: MilestoneStatus::Completed,
                     deliverables: Vec::new(),
@@ -428,7 +488,7 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "Third quarter security services delivery".to_string(),
                     due_date: "2024-12-31".to_string(),
                     completed_date: Some("2024-12-31".to_string()),
-                    payment_amount: 2_187_500.0,
+                    payment_amount: Money::rands(2_187_500),
                     payment_percentage: 25.0,
                     status: MilestoneStatus::Completed,
                     deliverables: Vec::new(),
@@ -441,7 +501,7 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
                     description: "Fourth quarter and contract completion".to_string(),
                     due_date: "2025-03-31".to_string(),
                     completed_date: None,
-                    payment_amount: 2_187_500.0,
+                    payment_amount: Money::rands(2_187_500),
                     payment_percentage: 25.0,
                     status: MilestoneStatus::InProgress,
                     deliverables: Vec::new(),
@@ -450,10 +510,43 @@ pub fn get_mock_contract(id: &str) -> Option<Contract> {
             ],
             documents: Vec::new(),
             created_by: "Jane Doe".to_string(),
+            created_by_id: "U018".to_string(),
             created_at: "2024-03-01T08:00:00Z".to_string(),
             updated_at: "2025-02-25T16:45:00Z".to_string(),
             approved_by: Some("Mike Wilson".to_string()),
             approved_at: Some("2024-03-15T11:30:00Z".to_string()),
+            approval_workflow: vec![
+                ApprovalStep {
+                    step: 1,
+                    role: "Contract Manager".to_string(),
+                    approver: Some("Jane Doe".to_string()),
+                    approver_id: None,
+                    status: StepStatus::Approved,
+                    date: Some("2024-03-05T10:20:00Z".to_string()),
+                    comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+                ApprovalStep {
+                    step: 2,
+                    role: "CFO".to_string(),
+                    approver: Some("Mike Wilson".to_string()),
+                    approver_id: None,
+                    status: StepStatus::Approved,
+                    date: Some("2024-03-15T11:30:00Z".to_string()),
+                    comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+            ],
         }),
         _ => None,
     }
@@ -1,6 +1,10 @@
 //! Contract domain types
 
 use serde::{Deserialize, Serialize};
+use crate::features::workflow::types::ApprovalStep;
+use crate::util::datetime;
+use crate::util::money::Money;
+use crate::util::url::{QueryFilter, QueryParams};
 
 /// Contract status
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -84,7 +88,7 @@ pub struct ContractMilestone {
     pub description: String,
     pub due_date: String,
     pub completed_date: Option<String>,
-    pub payment_amount: f64,
+    pub payment_amount: Money,
     pub payment_percentage: f64,
     pub status: MilestoneStatus,
     pub deliverables: Vec<String>,
@@ -100,7 +104,7 @@ impl Default for ContractMilestone {
             description: String::new(),
             due_date: String::new(),
             completed_date: None,
-            payment_amount: 0.0,
+            payment_amount: Money::ZERO,
             payment_percentage: 0.0,
             status: MilestoneStatus::Pending,
             deliverables: Vec::new(),
@@ -138,8 +142,8 @@ pub struct ContractDeliverable {
     pub description: String,
     pub quantity: u32,
     pub unit: String,
-    pub unit_price: f64,
-    pub total_price: f64,
+    pub unit_price: Money,
+    pub total_price: Money,
     pub delivery_date: String,
     pub delivered: bool,
 }
@@ -151,8 +155,8 @@ impl Default for ContractDeliverable {
             description: String::new(),
             quantity: 1,
             unit: "Each".to_string(),
-            unit_price: 0.0,
-            total_price: 0.0,
+            unit_price: Money::ZERO,
+            total_price: Money::ZERO,
             delivery_date: String::new(),
             delivered: false,
         }
@@ -196,7 +200,7 @@ pub struct Contract {
     pub supplier_id: String,
     pub supplier_name: String,
     pub supplier_bbbee_level: u8,
-    pub value: f64,
+    pub value: Money,
     pub start_date: String,
     pub end_date: String,
     pub status: ContractStatus,
@@ -210,10 +214,15 @@ pub struct Contract {
     pub milestones: Vec<ContractMilestone>,
     pub documents: Vec<String>,
     pub created_by: String,
+    /// Id of the user who created the contract
+    #[serde(default)]
+    pub created_by_id: String,
     pub created_at: String,
     pub updated_at: String,
     pub approved_by: Option<String>,
     pub approved_at: Option<String>,
+    #[serde(default)]
+    pub approval_workflow: Vec<ApprovalStep>,
 }
 
 impl Default for Contract {
@@ -225,7 +234,7 @@ impl Default for Contract {
             supplier_id: String::new(),
             supplier_name: String::new(),
             supplier_bbbee_level: 4,
-            value: 0.0,
+            value: Money::ZERO,
             start_date: String::new(),
             end_date: String::new(),
             status: ContractStatus::Draft,
@@ -239,33 +248,35 @@ impl Default for Contract {
             milestones: Vec::new(),
             documents: Vec::new(),
             created_by: String::new(),
+            created_by_id: String::new(),
             created_at: String::new(),
             updated_at: String::new(),
             approved_by: None,
             approved_at: None,
+            approval_workflow: Vec::new(),
         }
     }
 }
 
 impl Contract {
-    /// Check if contract is expiring within given days
+    /// Check if an active contract ends within the next `days` days
     pub fn is_expiring_within_days(&self, days: u32) -> bool {
-        // Simplified check - in production would use proper date comparison
-        // For now, just check if end_date is within range
-        if self.end_date.is_empty() {
-            return false;
-        }
-        // Mock implementation - would compare with current date
-        days <= 90 && self.status == ContractStatus::Active
+        self.status == ContractStatus::Active
+            && self.days_to_expiry().map_or(false, |d| (0..=days as i64).contains(&d))
+    }
+
+    /// Calendar days until the end date; negative once it has passed
+    pub fn days_to_expiry(&self) -> Option<i64> {
+        datetime::days_until(&self.end_date)
     }
 
     /// Calculate total milestone payments
-    pub fn total_milestone_payments(&self) -> f64 {
+    pub fn total_milestone_payments(&self) -> Money {
         self.milestones.iter().map(|m| m.payment_amount).sum()
     }
 
     /// Calculate completed milestone payments
-    pub fn completed_milestone_payments(&self) -> f64 {
+    pub fn completed_milestone_payments(&self) -> Money {
         self.milestones
             .iter()
             .filter(|m| m.status == MilestoneStatus::Completed)
@@ -293,6 +304,26 @@ pub struct ContractFilter {
     pub expiring_within_days: Option<u32>,
 }
 
+impl QueryFilter for ContractFilter {
+    fn from_query(params: &QueryParams) -> Self {
+        Self {
+            status: params.get("status").map(|s| ContractStatus::from_str(&s)),
+            supplier_id: params.get("supplier"),
+            contract_type: params.get("type"),
+            search: params.get("q"),
+            expiring_within_days: params.get_parsed("expiring"),
+        }
+    }
+
+    fn write_query(&self, params: &mut QueryParams) {
+        params.set("status", self.status.map(|s| s.as_str()));
+        params.set("supplier", self.supplier_id.as_ref());
+        params.set("type", self.contract_type.as_ref());
+        params.set("q", self.search.as_ref());
+        params.set("expiring", self.expiring_within_days);
+    }
+}
+
 /// Contract summary for list view
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ContractSummary {
@@ -300,7 +331,7 @@ pub struct ContractSummary {
     pub title: String,
     pub supplier_name: String,
     pub supplier_bbbee_level: u8,
-    pub value: f64,
+    pub value: Money,
     pub start_date: String,
     pub end_date: String,
     pub status: ContractStatus,
@@ -319,7 +350,7 @@ impl From<&Contract> for ContractSummary {
             start_date: contract.start_date.clone(),
             end_date: contract.end_date.clone(),
             status: contract.status,
-            days_to_expiry: None, // Would be calculated from end_date
+            days_to_expiry: contract.days_to_expiry().map(|d| d as i32),
             milestone_progress: contract.milestone_progress(),
         }
     }
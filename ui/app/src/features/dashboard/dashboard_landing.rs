@@ -13,6 +13,7 @@ use crate::shared::charts::{bar_chart, BarChartData, pie_chart, PieChartData};
 use crate::util::format::{format_currency, format_number};
 use super::store::{DashboardStore, load_mock_data};
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Dashboard landing page
 #[component]
@@ -38,10 +39,10 @@ pub fn dashboard_landing() -> View {
 
     // Table columns
     let columns = vec![
-        DataTableColumn { key: "id".to_string(), label: "Req ID".to_string(), width: None, align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "description".to_string(), label: "Description".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "amount".to_string(), label: "Amount".to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "id".to_string(), label: t("Req ID").to_string(), width: None, align: None, cell_class: Some("id-cell".to_string()) },
+        DataTableColumn { key: "description".to_string(), label: t("Description").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "amount".to_string(), label: t("Amount").to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: None, align: None, cell_class: None },
     ];
 
     // Transform requisitions to table rows
@@ -78,7 +79,7 @@ pub fn dashboard_landing() -> View {
     let spend_data: Vec<BarChartData> = spend.get().iter().map(|s| {
         BarChartData {
             label: s.name.clone(),
-            value: s.amount,
+            value: s.amount.to_rands(),
             color: None,
         }
     }).collect();
@@ -86,10 +87,10 @@ pub fn dashboard_landing() -> View {
     // BBBEE chart data
     let bbbee_data = bbbee.get();
     let bbbee_pie: Vec<PieChartData> = vec![
-        PieChartData { label: "Level 1".to_string(), value: bbbee_data.level_1, color: "var(--green)".to_string() },
-        PieChartData { label: "Level 2".to_string(), value: bbbee_data.level_2, color: "var(--blue)".to_string() },
-        PieChartData { label: "Level 3-4".to_string(), value: bbbee_data.level_3_4, color: "var(--orange)".to_string() },
-        PieChartData { label: "Other".to_string(), value: bbbee_data.other, color: "var(--text-muted)".to_string() },
+        PieChartData { label: t("Level 1").to_string(), value: bbbee_data.level_1, color: "var(--green)".to_string() },
+        PieChartData { label: t("Level 2").to_string(), value: bbbee_data.level_2, color: "var(--blue)".to_string() },
+        PieChartData { label: t("Level 3-4").to_string(), value: bbbee_data.level_3_4, color: "var(--orange)".to_string() },
+        PieChartData { label: t("Other").to_string(), value: bbbee_data.other, color: "var(--text-muted)".to_string() },
     ];
 
     // Icons
@@ -114,46 +115,46 @@ pub fn dashboard_landing() -> View {
 
         <div class="dashboard" data-testid="dashboard-landing">
             {page_header(
-                "Procurement Dashboard".to_string(),
-                Some("Overview of procurement activities for FY 2025/26".to_string()),
+                t("Procurement Dashboard").to_string(),
+                Some(t("Overview of procurement activities for FY 2025/26").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export Report"</button> },
-                    view! { <a href="#" class="btn btn-primary">"New Requisition"</a> },
+                    view! { <button class="btn btn-secondary">{t("Export Report")}</button> },
+                    view! { <a href="#" class="btn btn-primary">{t("New Requisition")}</a> },
                 ]
             )}
 
             // KPI Row 1
             <div class="kpi-grid">
                 {kpi_card(
-                    "Active Requisitions".to_string(),
+                    t("Active Requisitions").to_string(),
                     kpis.get().active_requisitions.to_string(),
                     KpiColor::Blue,
                     icon_doc.to_string(),
-                    Some(KpiDelta { value: "12%".to_string(), is_positive: Some(true), suffix: "from last month".to_string() }),
+                    Some(KpiDelta { value: "12%".to_string(), is_positive: Some(true), suffix: t("from last month").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Open Tenders".to_string(),
+                    t("Open Tenders").to_string(),
                     kpis.get().open_tenders.to_string(),
                     KpiColor::Green,
                     icon_check.to_string(),
-                    Some(KpiDelta { value: "8%".to_string(), is_positive: Some(true), suffix: "from last month".to_string() }),
+                    Some(KpiDelta { value: "8%".to_string(), is_positive: Some(true), suffix: t("from last month").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "YTD Spend".to_string(),
+                    t("YTD Spend").to_string(),
                     format_currency(kpis.get().ytd_spend),
                     KpiColor::Accent,
                     icon_dollar.to_string(),
-                    Some(KpiDelta { value: "3%".to_string(), is_positive: Some(false), suffix: "vs budget".to_string() }),
+                    Some(KpiDelta { value: "3%".to_string(), is_positive: Some(false), suffix: t("vs budget").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Active Suppliers".to_string(),
+                    t("Active Suppliers").to_string(),
                     format_number(kpis.get().active_suppliers),
                     KpiColor::Purple,
                     icon_users.to_string(),
-                    Some(KpiDelta { value: "45".to_string(), is_positive: Some(true), suffix: "new this month".to_string() }),
+                    Some(KpiDelta { value: "45".to_string(), is_positive: Some(true), suffix: t("new this month").to_string() }),
                     None
                 )}
             </div>
@@ -161,35 +162,35 @@ pub fn dashboard_landing() -> View {
             // KPI Row 2
             <div class="kpi-grid">
                 {kpi_card(
-                    "Pending Approvals".to_string(),
+                    t("Pending Approvals").to_string(),
                     kpis.get().pending_approvals.to_string(),
                     KpiColor::Orange,
                     icon_clock.to_string(),
-                    Some(KpiDelta { value: "8 urgent (>5 days)".to_string(), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: tn("{n} urgent (>5 days)", "{n} urgent (>5 days)", 8), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Active Contracts".to_string(),
+                    t("Active Contracts").to_string(),
                     kpis.get().active_contracts.to_string(),
                     KpiColor::Cyan,
                     icon_file.to_string(),
-                    Some(KpiDelta { value: "12 expiring in 30 days".to_string(), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: tn("{n} expiring in 30 days", "{n} expiring in 30 days", 12), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "B-BBEE Spend".to_string(),
+                    t("B-BBEE Spend").to_string(),
                     format!("{:.1}%", kpis.get().bbbee_spend_percent),
                     KpiColor::Green,
                     icon_layers.to_string(),
-                    Some(KpiDelta { value: "Target: 80%".to_string(), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: tf("Target: {value}", &[("value", "80%".to_string())]), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Compliance Issues".to_string(),
+                    t("Compliance Issues").to_string(),
                     kpis.get().compliance_issues.to_string(),
                     KpiColor::Red,
                     icon_alert.to_string(),
-                    Some(KpiDelta { value: "3 critical attention needed".to_string(), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: tn("{n} critical, attention needed", "{n} critical, attention needed", 3), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
             </div>
@@ -198,14 +199,14 @@ pub fn dashboard_landing() -> View {
             <div class="grid-3-2">
                 // Recent Requisitions
                 {panel(
-                    "Recent Requisitions".to_string(),
-                    vec![view! { <a href="#" class="btn btn-sm btn-secondary">"View All"</a> }],
+                    t("Recent Requisitions").to_string(),
+                    vec![view! { <a href="#" class="btn btn-sm btn-secondary">{t("View All")}</a> }],
                     vec![data_table(columns, rows, None)]
                 )}
 
                 // Activity Timeline
                 {panel(
-                    "Recent Activity".to_string(),
+                    t("Recent Activity").to_string(),
                     vec![],
                     vec![timeline(timeline_items, None)]
                 )}
@@ -215,14 +216,14 @@ pub fn dashboard_landing() -> View {
             <div class="grid-2">
                 // Spend by Category
                 {panel(
-                    "Spend by Category".to_string(),
+                    t("Spend by Category").to_string(),
                     vec![],
                     vec![bar_chart(spend_data, Some(200))]
                 )}
 
                 // B-BBEE Performance
                 {panel(
-                    "B-BBEE Performance".to_string(),
+                    t("B-BBEE Performance").to_string(),
                     vec![],
                     vec![
                         pie_chart(bbbee_pie, None),
@@ -1,5 +1,7 @@
 //! Dashboard service - API calls
 
+use crate::util::api::ApiError;
+use crate::util::backend;
 use super::store::{DashboardStore, load_mock_data};
 
 /// Load dashboard data
@@ -7,9 +9,29 @@ pub async fn load_dashboard(store: &DashboardStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
+
+/// Refresh dashboard widgets from the configured backend
+async fn load_from_backend(store: &DashboardStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/dashboard/kpis", &store.kpis).await?;
+        backend::fetch_into("/dashboard/recent-requisitions", &store.recent_requisitions).await?;
+        backend::fetch_into("/dashboard/activities", &store.activities).await?;
+        backend::fetch_into("/dashboard/spend-by-category", &store.spend_by_category).await?;
+        backend::fetch_into("/dashboard/bbbee-breakdown", &store.bbbee_breakdown).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    }
+}
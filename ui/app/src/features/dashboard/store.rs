@@ -1,6 +1,7 @@
 //! Dashboard store
 
 use components::prelude::*;
+use crate::util::money::Money;
 use super::types::{DashboardKpi, RecentRequisition, ActivityItem, SpendCategory, BbbeeBreakdown};
 
 /// Dashboard state store
@@ -35,31 +36,31 @@ pub fn load_mock_data(store: &DashboardStore) {
         RecentRequisition {
             id: "REQ-2025-0847".to_string(),
             description: "IT Hardware - Laptops Q4".to_string(),
-            amount: 2_450_000.0,
+            amount: Money::rands(2_450_000),
             status: "pending".to_string(),
         },
         RecentRequisition {
             id: "REQ-2025-0846".to_string(),
             description: "Office Supplies - Stationery".to_string(),
-            amount: 125_000.0,
+            amount: Money::rands(125_000),
             status: "approved".to_string(),
         },
         RecentRequisition {
             id: "REQ-2025-0845".to_string(),
             description: "Security Services - Annual".to_string(),
-            amount: 8_750_000.0,
+            amount: Money::rands(8_750_000),
             status: "evaluation".to_string(),
         },
         RecentRequisition {
             id: "REQ-2025-0844".to_string(),
             description: "Fleet Maintenance Services".to_string(),
-            amount: 1_200_000.0,
+            amount: Money::rands(1_200_000),
             status: "approved".to_string(),
         },
         RecentRequisition {
             id: "REQ-2025-0843".to_string(),
             description: "Training - Staff Development".to_string(),
-            amount: 560_000.0,
+            amount: Money::rands(560_000),
             status: "draft".to_string(),
         },
     ]);
@@ -98,10 +99,10 @@ pub fn load_mock_data(store: &DashboardStore) {
     ]);
 
     store.spend_by_category.set(vec![
-        SpendCategory { name: "IT".to_string(), amount: 250_000_000.0 },
-        SpendCategory { name: "Facilities".to_string(), amount: 180_000_000.0 },
-        SpendCategory { name: "Prof. Svcs".to_string(), amount: 200_000_000.0 },
-        SpendCategory { name: "Security".to_string(), amount: 120_000_000.0 },
-        SpendCategory { name: "Fleet".to_string(), amount: 97_000_000.0 },
+        SpendCategory { name: "IT".to_string(), amount: Money::rands(250_000_000) },
+        SpendCategory { name: "Facilities".to_string(), amount: Money::rands(180_000_000) },
+        SpendCategory { name: "Prof. Svcs".to_string(), amount: Money::rands(200_000_000) },
+        SpendCategory { name: "Security".to_string(), amount: Money::rands(120_000_000) },
+        SpendCategory { name: "Fleet".to_string(), amount: Money::rands(97_000_000) },
     ]);
 }
@@ -1,13 +1,14 @@
 //! Dashboard domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::money::Money;
 
 /// Dashboard KPI data
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DashboardKpi {
     pub active_requisitions: u32,
     pub open_tenders: u32,
-    pub ytd_spend: f64,
+    pub ytd_spend: Money,
     pub active_suppliers: u32,
     pub pending_approvals: u32,
     pub active_contracts: u32,
@@ -20,7 +21,7 @@ impl Default for DashboardKpi {
         Self {
             active_requisitions: 127,
             open_tenders: 23,
-            ytd_spend: 847_000_000.0,
+            ytd_spend: Money::rands(847_000_000),
             active_suppliers: 1284,
             pending_approvals: 34,
             active_contracts: 456,
@@ -35,7 +36,7 @@ impl Default for DashboardKpi {
 pub struct RecentRequisition {
     pub id: String,
     pub description: String,
-    pub amount: f64,
+    pub amount: Money,
     pub status: String,
 }
 
@@ -52,7 +53,7 @@ pub struct ActivityItem {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SpendCategory {
     pub name: String,
-    pub amount: f64,
+    pub amount: Money,
 }
 
 /// B-BBEE breakdown data
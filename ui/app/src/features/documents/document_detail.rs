@@ -0,0 +1,172 @@
+//! Document detail page
+
+use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table, DataTableColumn, DataTableRow,
+    tag, TagType,
+    notice_bar, NoticeType,
+};
+use crate::util::format::format_date;
+use super::types::{Document, format_file_size};
+use super::store::DocumentsStore;
+use super::service;
+use crate::util::i18n::{t, tf};
+
+/// Document detail page
+#[component]
+pub fn document_detail(document_id: String) -> View {
+    let store = use_context::<DocumentsStore>();
+
+    // Load document on mount
+    effect({
+        let store = store.clone();
+        let document_id = document_id.clone();
+        move || {
+            let store = store.clone();
+            let document_id = document_id.clone();
+            spawn(async move {
+                service::load_document(&store, &document_id).await;
+            });
+        }
+    });
+
+    let loading = store.loading.get();
+    let error = store.error.get();
+    let document = store.selected.get().filter(|d| d.id == document_id);
+
+    view! {
+        style {
+            r#"
+            .document-detail { display: flex; flex-direction: column; gap: var(--space-6); }
+            .document-detail-grid {
+                display: grid;
+                grid-template-columns: 2fr 1fr;
+                gap: 24px;
+            }
+            .document-detail .detail-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 8px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .document-detail .detail-row:last-child { border-bottom: none; }
+            .document-detail .detail-row .label { color: var(--text-muted); }
+            .document-detail .detail-row .value { font-weight: 500; text-align: right; }
+            .document-detail .document-tags { display: flex; flex-wrap: wrap; gap: 6px; margin-top: 12px; }
+            @media (max-width: 1024px) {
+                .document-detail-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="document-detail" data-testid="document-detail">
+            if loading {
+                <div class="loading-state">{t("Loading document...")}</div>
+            } else if let Some(document) = document {
+                {render_document(document)}
+            } else {
+                {entity_not_found(t("Document"), &document_id, error, Route::DocumentsLibrary, t("Back to Library"))}
+            }
+        </div>
+    }
+}
+
+fn render_document(document: Document) -> View {
+    let columns = vec![
+        DataTableColumn { key: "version".to_string(), label: t("Version").to_string(), width: Some("80px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "uploaded_by".to_string(), label: t("Uploaded By").to_string(), width: Some("160px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "date".to_string(), label: t("Date").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "size".to_string(), label: t("Size").to_string(), width: Some("90px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "comment".to_string(), label: t("Comment").to_string(), width: None, align: None, cell_class: None },
+    ];
+
+    let rows: Vec<DataTableRow> = document.versions.iter().rev().map(|version| {
+        DataTableRow {
+            id: version.version.to_string(),
+            cells: vec![
+                view! { <span class="id-cell">{format!("v{}", version.version)}</span> },
+                view! { <span>{version.uploaded_by_name.clone()}</span> },
+                view! { <span>{format_date(&version.uploaded_at)}</span> },
+                view! { <span>{format_file_size(version.size)}</span> },
+                view! { <span>{version.comment.clone().unwrap_or_default()}</span> },
+            ],
+        }
+    }).collect();
+
+    let related = match (&document.related_entity_type, &document.related_entity_name) {
+        (Some(entity_type), Some(name)) => format!("{}: {}", entity_type, name),
+        (Some(entity_type), None) => format!("{}: {}", entity_type, document.related_entity_id.clone().unwrap_or_default()),
+        _ => "-".to_string(),
+    };
+
+    view! {
+        {page_header(
+            document.name.clone(),
+            Some(format!("{} - {}", document.file_name, document.folder_path)),
+            vec![
+                view! { <a href="/documents" class="btn btn-secondary">{t("Back to Library")}</a> },
+                view! { <a href={document.file_url.clone()} class="btn btn-primary" target="_blank">{t("Download")}</a> },
+            ]
+        )}
+
+        if document.is_locked {
+            {notice_bar(
+                tf("Locked by {user}", &[("user", document.locked_by.clone().unwrap_or_else(|| t("another user").to_string()))]),
+                NoticeType::Warning,
+                None
+            )}
+        }
+
+        <div class="document-detail-grid">
+            {panel(
+                t("Version History").to_string(),
+                vec![],
+                vec![data_table(columns, rows, None)]
+            )}
+
+            {panel(
+                t("Details").to_string(),
+                vec![],
+                vec![
+                    view! {
+                        <div>
+                            <div class="detail-row">
+                                <span class="label">{t("Type")}</span>
+                                <span class="value">{document.document_type.label()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Category")}</span>
+                                <span class="value">{document.category.label()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Size")}</span>
+                                <span class="value">{document.formatted_size()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Uploaded By")}</span>
+                                <span class="value">{document.uploaded_by_name.clone()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Updated")}</span>
+                                <span class="value">{format_date(&document.updated_at)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Related To")}</span>
+                                <span class="value">{related}</span>
+                            </div>
+                            <div class="document-tags">
+                                for label in document.tags.iter() {
+                                    {tag(label.clone(), TagType::Default)}
+                                }
+                            </div>
+                        </div>
+                    }
+                ]
+            )}
+        </div>
+    }
+}
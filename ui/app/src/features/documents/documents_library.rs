@@ -19,6 +19,7 @@ use super::types::{
     DocumentSortBy, DocumentViewMode, format_file_size,
 };
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Document library page
 #[component]
@@ -578,13 +579,13 @@ pub fn documents_library() -> View {
 
         <div class="documents-page" data-testid="documents-library">
             {page_header(
-                "Document Library".to_string(),
-                Some("Manage and organize procurement documents".to_string()),
+                t("Document Library").to_string(),
+                Some(t("Manage and organize procurement documents").to_string()),
                 vec![
                     view! {
                         <button class="btn btn-primary" on:click={handle_open_upload}>
                             <span inner_html={icon_upload}></span>
-                            " Upload Document"
+                            {t(" Upload Document")}
                         </button>
                     },
                 ]
@@ -593,7 +594,7 @@ pub fn documents_library() -> View {
             // KPI summary
             <div class="kpi-grid">
                 {kpi_card(
-                    "Total Documents".to_string(),
+                    t("Total Documents").to_string(),
                     format_number(stats_data.total_documents),
                     KpiColor::Blue,
                     icon_file.to_string(),
@@ -601,7 +602,7 @@ pub fn documents_library() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Total Storage".to_string(),
+                    t("Total Storage").to_string(),
                     format_file_size(stats_data.total_size),
                     KpiColor::Green,
                     icon_storage.to_string(),
@@ -609,7 +610,7 @@ pub fn documents_library() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Folders".to_string(),
+                    t("Folders").to_string(),
                     stats_data.folder_count.to_string(),
                     KpiColor::Accent,
                     icon_folder.to_string(),
@@ -617,7 +618,7 @@ pub fn documents_library() -> View {
                     None
                 )}
                 {kpi_card(
-                    "This Month".to_string(),
+                    t("This Month").to_string(),
                     stats_data.documents_this_month.to_string(),
                     KpiColor::Orange,
                     icon_calendar.to_string(),
@@ -630,41 +631,41 @@ pub fn documents_library() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Type"</label>
+                        <label>{t("Type")}</label>
                         <select on:change={handle_type_change}>
-                            <option value="">"All Types"</option>
+                            <option value="">{t("All Types")}</option>
                             <option value="pdf">"PDF"</option>
-                            <option value="docx">"Word"</option>
-                            <option value="xlsx">"Excel"</option>
-                            <option value="png">"Image"</option>
-                            <option value="zip">"Archive"</option>
+                            <option value="docx">{t("Word")}</option>
+                            <option value="xlsx">{t("Excel")}</option>
+                            <option value="png">{t("Image")}</option>
+                            <option value="zip">{t("Archive")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Category"</label>
+                        <label>{t("Category")}</label>
                         <select on:change={handle_category_change}>
-                            <option value="">"All Categories"</option>
-                            <option value="tender">"Tender"</option>
-                            <option value="contract">"Contract"</option>
-                            <option value="bid">"Bid"</option>
-                            <option value="compliance">"Compliance"</option>
-                            <option value="report">"Report"</option>
-                            <option value="template">"Template"</option>
+                            <option value="">{t("All Categories")}</option>
+                            <option value="tender">{t("Tender")}</option>
+                            <option value="contract">{t("Contract")}</option>
+                            <option value="bid">{t("Bid")}</option>
+                            <option value="compliance">{t("Compliance")}</option>
+                            <option value="report">{t("Report")}</option>
+                            <option value="template">{t("Template")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Sort By"</label>
+                        <label>{t("Sort By")}</label>
                         <select on:change={handle_sort_change}>
-                            <option value="date_desc">"Date (Newest)"</option>
-                            <option value="date_asc">"Date (Oldest)"</option>
-                            <option value="name_asc">"Name (A-Z)"</option>
-                            <option value="name_desc">"Name (Z-A)"</option>
-                            <option value="size_desc">"Size (Largest)"</option>
-                            <option value="size_asc">"Size (Smallest)"</option>
+                            <option value="date_desc">{t("Date (Newest)")}</option>
+                            <option value="date_asc">{t("Date (Oldest)")}</option>
+                            <option value="name_asc">{t("Name (A-Z)")}</option>
+                            <option value="name_desc">{t("Name (Z-A)")}</option>
+                            <option value="size_desc">{t("Size (Largest)")}</option>
+                            <option value="size_asc">{t("Size (Smallest)")}</option>
                         </select>
                     </div>
                 },
@@ -674,7 +675,7 @@ pub fn documents_library() -> View {
                         <input
                             type="text"
                             class="search-input"
-                            placeholder="Search documents..."
+                            placeholder={t("Search documents...")}
                             value={search_query.get()}
                             on:input={handle_search}
                         />
@@ -685,15 +686,15 @@ pub fn documents_library() -> View {
                         <button
                             class={if matches!(view_mode.get(), DocumentViewMode::Grid) { "active" } else { "" }}
                             on:click={handle_toggle_grid}
-                        >"Grid"</button>
+                        >{t("Grid")}</button>
                         <button
                             class={if matches!(view_mode.get(), DocumentViewMode::List) { "active" } else { "" }}
                             on:click={handle_toggle_list}
-                        >"List"</button>
+                        >{t("List")}</button>
                     </div>
                 },
                 view! {
-                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>"Clear"</button>
+                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>{t("Clear")}</button>
                 },
             ])}
 
@@ -701,7 +702,7 @@ pub fn documents_library() -> View {
             <div class="documents-layout">
                 // Folders sidebar
                 <div class="folders-sidebar">
-                    <div class="folders-title">"Folders"</div>
+                    <div class="folders-title">{t("Folders")}</div>
                     <div class="folder-list">
                         <div
                             class={if current_path.get() == "/" { "folder-item active" } else { "folder-item" }}
@@ -711,7 +712,7 @@ pub fn documents_library() -> View {
                             })}
                         >
                             <span inner_html={icon_folder}></span>
-                            <span>"All Documents"</span>
+                            <span>{t("All Documents")}</span>
                         </div>
                         for folder in folders.get().iter() {
                             {folder_sidebar_item(folder.clone(), current_path.get(), handle_folder_click.clone())}
@@ -743,15 +744,15 @@ pub fn documents_library() -> View {
                     </div>
 
                     {panel(
-                        format!("Documents ({} found)", filtered_documents.len()),
+                        tf("Documents ({count} found)", &[("count", filtered_documents.len().to_string())]),
                         vec![],
                         vec![
                             if loading.get() {
-                                view! { <div class="loading-state">"Loading documents..."</div> }
+                                view! { <div class="loading-state">{t("Loading documents...")}</div> }
                             } else if filtered_documents.is_empty() && current_folders.is_empty() {
                                 {empty_state(
-                                    "No documents found".to_string(),
-                                    Some("Upload documents or adjust your filters".to_string()),
+                                    t("No documents found").to_string(),
+                                    Some(t("Upload documents or adjust your filters").to_string()),
                                     None,
                                     None
                                 )}
@@ -797,7 +798,7 @@ pub fn documents_library() -> View {
             // Preview modal
             if show_preview_modal.get() {
                 {modal(
-                    "Document Preview".to_string(),
+                    t("Document Preview").to_string(),
                     ModalSize::Large,
                     show_preview_modal.clone(),
                     handle_close_preview.clone(),
@@ -810,15 +811,15 @@ pub fn documents_library() -> View {
                                     } else {
                                         <div class="preview-placeholder">
                                             <span inner_html={service::get_document_type_icon(&doc.document_type)}></span>
-                                            <div>"Preview: " {doc.name.clone()}</div>
-                                            <a href={doc.file_url.clone()} target="_blank" class="btn btn-primary">"Open in New Tab"</a>
+                                            <div>{tf("Preview: {name}", &[("name", doc.name.clone())])}</div>
+                                            <a href={doc.file_url.clone()} target="_blank" class="btn btn-primary">{t("Open in New Tab")}</a>
                                         </div>
                                     }
                                 } else {
                                     <div class="preview-placeholder">
                                         <span inner_html={icon_file}></span>
-                                        <div>"Preview not available for this file type"</div>
-                                        <a href={doc.file_url.clone()} download={doc.file_name.clone()} class="btn btn-primary">"Download"</a>
+                                        <div>{t("Preview not available for this file type")}</div>
+                                        <a href={doc.file_url.clone()} download={doc.file_name.clone()} class="btn btn-primary">{t("Download")}</a>
                                     </div>
                                 }
                             }
@@ -831,7 +832,7 @@ pub fn documents_library() -> View {
             // Upload modal
             if show_upload_modal.get() {
                 {modal(
-                    "Upload Document".to_string(),
+                    t("Upload Document").to_string(),
                     ModalSize::Medium,
                     show_upload_modal.clone(),
                     handle_close_upload.clone(),
@@ -839,16 +840,16 @@ pub fn documents_library() -> View {
                         <div class="upload-modal-content">
                             <div class="upload-dropzone">
                                 <span inner_html={icon_upload}></span>
-                                <div class="upload-dropzone-text">"Drop files here or click to browse"</div>
-                                <div class="upload-dropzone-hint">"Maximum file size: 50MB"</div>
+                                <div class="upload-dropzone-text">{t("Drop files here or click to browse")}</div>
+                                <div class="upload-dropzone-hint">{t("Maximum file size: 50MB")}</div>
                             </div>
 
                             <div class="upload-form">
                                 <div class="upload-form-group">
-                                    <label>"Document Name"</label>
+                                    <label>{t("Document Name")}</label>
                                     <input
                                         type="text"
-                                        placeholder="Enter document name"
+                                        placeholder={t("Enter document name")}
                                         value={upload_name.get()}
                                         on:input={Callback::new({
                                             let upload_name = upload_name.clone();
@@ -862,7 +863,7 @@ pub fn documents_library() -> View {
                                 </div>
 
                                 <div class="upload-form-group">
-                                    <label>"Category"</label>
+                                    <label>{t("Category")}</label>
                                     <select
                                         value={upload_category.get()}
                                         on:change={Callback::new({
@@ -874,21 +875,21 @@ pub fn documents_library() -> View {
                                             }
                                         })}
                                     >
-                                        <option value="general">"General"</option>
-                                        <option value="tender">"Tender"</option>
-                                        <option value="contract">"Contract"</option>
-                                        <option value="bid">"Bid"</option>
-                                        <option value="compliance">"Compliance"</option>
-                                        <option value="report">"Report"</option>
-                                        <option value="template">"Template"</option>
+                                        <option value="general">{t("General")}</option>
+                                        <option value="tender">{t("Tender")}</option>
+                                        <option value="contract">{t("Contract")}</option>
+                                        <option value="bid">{t("Bid")}</option>
+                                        <option value="compliance">{t("Compliance")}</option>
+                                        <option value="report">{t("Report")}</option>
+                                        <option value="template">{t("Template")}</option>
                                     </select>
                                 </div>
 
                                 <div class="upload-form-group">
-                                    <label>"Tags (comma-separated)"</label>
+                                    <label>{t("Tags (comma-separated)")}</label>
                                     <input
                                         type="text"
-                                        placeholder="e.g. important, q1, project-x"
+                                        placeholder={t("e.g. important, q1, project-x")}
                                         value={upload_tags.get()}
                                         on:input={Callback::new({
                                             let upload_tags = upload_tags.clone();
@@ -902,8 +903,8 @@ pub fn documents_library() -> View {
                                 </div>
 
                                 <div class="upload-actions">
-                                    <button class="btn btn-secondary" on:click={handle_close_upload.clone()}>"Cancel"</button>
-                                    <button class="btn btn-primary">"Upload"</button>
+                                    <button class="btn btn-secondary" on:click={handle_close_upload.clone()}>{t("Cancel")}</button>
+                                    <button class="btn btn-primary">{t("Upload")}</button>
                                 </div>
                             </div>
                         </div>
@@ -962,7 +963,7 @@ fn folder_card(folder: DocumentFolder, on_click: Callback<String>) -> View {
             <span inner_html={icon_folder}></span>
             <div class="folder-card-info">
                 <div class="folder-card-name">{folder.name.clone()}</div>
-                <div class="folder-card-count">{format!("{} documents", folder.document_count)}</div>
+                <div class="folder-card-count">{tn("{n} document", "{n} documents", folder.document_count as i64)}</div>
             </div>
         </div>
     }
@@ -999,10 +1000,10 @@ fn document_card(
                 <span class={type_class} inner_html={service::get_document_type_icon(&doc.document_type)}></span>
                 <div class="document-badges">
                     if doc.is_locked {
-                        <span class="document-badge locked">"Locked"</span>
+                        <span class="document-badge locked">{t("Locked")}</span>
                     }
                     if doc.is_archived {
-                        <span class="document-badge archived">"Archived"</span>
+                        <span class="document-badge archived">{t("Archived")}</span>
                     }
                     if doc.version > 1 {
                         <span class="document-badge version">{format!("v{}", doc.version)}</span>
@@ -1022,8 +1023,8 @@ fn document_card(
                         e.stop_propagation();
                         on_preview.call(doc_id_preview.clone())
                     }
-                })}>"Preview"</button>
-                <button>"Download"</button>
+                })}>{t("Preview")}</button>
+                <button>{t("Download")}</button>
             </div>
         </div>
     }
@@ -1062,7 +1063,7 @@ fn document_row(
                     {doc.name.clone()}
                     if doc.is_locked {
                         " "
-                        {tag("Locked".to_string(), TagType::Red)}
+                        {tag(t("Locked").to_string(), TagType::Red)}
                     }
                 </div>
                 <div class="document-row-file">{doc.file_name.clone()}</div>
@@ -1076,7 +1077,7 @@ fn document_row(
                         e.stop_propagation();
                         on_preview.call(doc_id_preview.clone())
                     }
-                })}>"Preview"</button>
+                })}>{t("Preview")}</button>
             </div>
         </div>
     }
@@ -3,4 +3,6 @@
 pub mod types;
 pub mod store;
 pub mod service;
+pub mod search;
 pub mod documents_library;
+pub mod document_detail;
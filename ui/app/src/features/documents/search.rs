@@ -0,0 +1,49 @@
+//! Document search provider
+
+use crate::Route;
+use crate::features::search::provider::{SearchFuture, SearchProvider};
+use crate::features::search::types::{match_score, SearchEntity, SearchHit};
+use super::store::DocumentsStore;
+use super::service;
+
+/// Searches the documents held in the store, loading them on first use
+pub struct DocumentSearch {
+    store: DocumentsStore,
+}
+
+impl DocumentSearch {
+    pub fn new(store: DocumentsStore) -> Self {
+        Self { store }
+    }
+}
+
+impl SearchProvider for DocumentSearch {
+    fn entity(&self) -> SearchEntity {
+        SearchEntity::Document
+    }
+
+    fn search(&self, query: String) -> SearchFuture {
+        let store = self.store.clone();
+        Box::pin(async move {
+            if store.documents.get().is_empty() {
+                service::load_documents(&store).await;
+                if let Some(e) = store.error.get() {
+                    return Err(e);
+                }
+            }
+
+            let hits = store.documents.get().iter().filter_map(|d| {
+                let score = match_score(&query, &[&d.id], &[&d.name, &d.file_name, &d.folder_path])?;
+                Some(SearchHit {
+                    entity: SearchEntity::Document,
+                    id: d.id.clone(),
+                    title: d.name.clone(),
+                    subtitle: format!("{} - {}", d.file_name, d.folder_path),
+                    route: Route::DocumentsDetail(d.id.clone()),
+                    score,
+                })
+            }).collect();
+            Ok(hits)
+        })
+    }
+}
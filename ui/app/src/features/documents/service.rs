@@ -1,5 +1,7 @@
 //! Documents service - API calls
 
+use crate::util::api::ApiError;
+use crate::util::backend;
 use super::store::{DocumentsStore, load_mock_data, select_document, clear_selection};
 use super::types::{
     Document, DocumentFolder, DocumentFilter, DocumentUploadRequest,
@@ -11,10 +13,7 @@ pub async fn load_documents(store: &DocumentsStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API:
-    // let response = api::get("/api/documents").await;
-    // For now, load mock data
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -29,7 +28,7 @@ pub async fn load_filtered_documents(store: &DocumentsStore, filter: DocumentFil
 
     // Ensure documents are loaded
     if store.documents.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     store.loading.set(false);
@@ -42,7 +41,7 @@ pub async fn load_document(store: &DocumentsStore, document_id: &str) {
 
     // Ensure documents are loaded
     if store.documents.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Select the document
@@ -62,7 +61,7 @@ pub async fn load_entity_documents(
 
     // Ensure documents are loaded
     if store.documents.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Set filter for specific entity
@@ -81,7 +80,7 @@ pub async fn load_folder_documents(store: &DocumentsStore, folder_id: &str) {
 
     // Ensure documents are loaded
     if store.documents.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Find folder and navigate to it
@@ -100,7 +99,7 @@ pub async fn search_documents(store: &DocumentsStore, query: &str) {
 
     // Ensure documents are loaded
     if store.documents.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Set search filter
@@ -570,3 +569,25 @@ pub fn get_document_type_color(doc_type: &DocumentType) -> &'static str {
         DocumentType::Other => "#9CA3AF",
     }
 }
+
+/// Refresh documents and folders from the configured backend
+async fn load_from_backend(store: &DocumentsStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/documents", &store.documents).await?;
+        backend::fetch_into("/documents/folders", &store.folders).await?;
+        backend::fetch_into("/documents/stats", &store.stats).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    } else {
+        store.total_count.set(store.documents.get().len());
+    }
+}
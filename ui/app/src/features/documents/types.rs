@@ -1,6 +1,7 @@
 //! Document domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
 
 /// Document type classification
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -23,7 +24,7 @@ pub enum DocumentType {
 
 impl DocumentType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             DocumentType::Pdf => "PDF",
             DocumentType::Word => "Word",
             DocumentType::Excel => "Excel",
@@ -31,7 +32,7 @@ impl DocumentType {
             DocumentType::Text => "Text",
             DocumentType::Archive => "Archive",
             DocumentType::Other => "Other",
-        }
+        })
     }
 
     pub fn from_extension(ext: &str) -> Self {
@@ -105,7 +106,7 @@ pub enum DocumentCategory {
 
 impl DocumentCategory {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             DocumentCategory::Tender => "Tender",
             DocumentCategory::Contract => "Contract",
             DocumentCategory::Bid => "Bid",
@@ -116,7 +117,7 @@ impl DocumentCategory {
             DocumentCategory::Report => "Report",
             DocumentCategory::Template => "Template",
             DocumentCategory::General => "General",
-        }
+        })
     }
 
     pub fn from_str(s: &str) -> Self {
@@ -412,7 +413,7 @@ impl Default for DocumentSortBy {
 
 impl DocumentSortBy {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             DocumentSortBy::NameAsc => "Name (A-Z)",
             DocumentSortBy::NameDesc => "Name (Z-A)",
             DocumentSortBy::DateAsc => "Date (Oldest)",
@@ -421,7 +422,7 @@ impl DocumentSortBy {
             DocumentSortBy::SizeDesc => "Size (Largest)",
             DocumentSortBy::TypeAsc => "Type (A-Z)",
             DocumentSortBy::TypeDesc => "Type (Z-A)",
-        }
+        })
     }
 
     pub fn from_str(s: &str) -> Self {
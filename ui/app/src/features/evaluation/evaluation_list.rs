@@ -7,10 +7,15 @@ use crate::shared::components::{
     status_badge, StatusType,
     tag, TagType,
 };
+use crate::util::datetime;
 use crate::util::format::{format_currency, format_date};
 use super::store::EvaluationStore;
 use super::types::EvaluationStatus;
 use super::service;
+use crate::util::i18n::t;
+
+/// Open evaluations this close to their deadline are highlighted
+const DEADLINE_WARNING_BUSINESS_DAYS: i64 = 3;
 
 /// Evaluation list page
 #[component]
@@ -64,49 +69,49 @@ pub fn evaluation_list() -> View {
     let columns = vec![
         DataTableColumn {
             key: "reference".to_string(),
-            label: "Tender Ref".to_string(),
+            label: t("Tender Ref").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: Some("id-cell".to_string())
         },
         DataTableColumn {
             key: "title".to_string(),
-            label: "Tender Title".to_string(),
+            label: t("Tender Title").to_string(),
             width: None,
             align: None,
             cell_class: None
         },
         DataTableColumn {
             key: "value".to_string(),
-            label: "Value".to_string(),
+            label: t("Value").to_string(),
             width: Some("120px".to_string()),
             align: Some("right".to_string()),
             cell_class: Some("amount-cell".to_string())
         },
         DataTableColumn {
             key: "bids".to_string(),
-            label: "Bids".to_string(),
+            label: t("Bids").to_string(),
             width: Some("80px".to_string()),
             align: Some("center".to_string()),
             cell_class: None
         },
         DataTableColumn {
             key: "deadline".to_string(),
-            label: "Eval Deadline".to_string(),
+            label: t("Eval Deadline").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None
         },
         DataTableColumn {
             key: "progress".to_string(),
-            label: "Scoring Progress".to_string(),
+            label: t("Scoring Progress").to_string(),
             width: Some("150px".to_string()),
             align: None,
             cell_class: None
         },
         DataTableColumn {
             key: "status".to_string(),
-            label: "Status".to_string(),
+            label: t("Status").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None
@@ -137,11 +142,12 @@ pub fn evaluation_list() -> View {
                 0.0
             };
 
-            // Deadline urgency
-            let deadline_class = if eval.status == EvaluationStatus::InProgress {
-                "deadline-urgent"
-            } else {
-                ""
+            // Deadline urgency, in business days left for an open evaluation
+            let business_days_left = datetime::business_days_until(&eval.evaluation_deadline);
+            let deadline_class = match (eval.status == EvaluationStatus::InProgress, business_days_left) {
+                (true, Some(days)) if days < 0 => "deadline-overdue",
+                (true, Some(days)) if days <= DEADLINE_WARNING_BUSINESS_DAYS => "deadline-urgent",
+                _ => "",
             };
 
             DataTableRow {
@@ -320,6 +326,10 @@ pub fn evaluation_list() -> View {
                 color: var(--orange);
                 font-weight: 500;
             }
+            .deadline-overdue {
+                color: var(--red);
+                font-weight: 600;
+            }
             .tender-title {
                 max-width: 300px;
                 white-space: nowrap;
@@ -331,10 +341,10 @@ pub fn evaluation_list() -> View {
 
         <div class="evaluation-list" data-testid="evaluation-list">
             {page_header(
-                "Bid Evaluation".to_string(),
-                Some("Review and score bids for open tenders".to_string()),
+                t("Bid Evaluation").to_string(),
+                Some(t("Review and score bids for open tenders").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export Report"</button> },
+                    view! { <button class="btn btn-secondary">{t("Export Report")}</button> },
                 ]
             )}
 
@@ -347,7 +357,7 @@ pub fn evaluation_list() -> View {
                     <div class="summary-icon pending" inner_html={icon_clipboard}></div>
                     <div class="summary-content">
                         <h3>{evaluations.get().len().to_string()}</h3>
-                        <p>"Total Evaluations"</p>
+                        <p>{t("Total Evaluations")}</p>
                     </div>
                 </div>
                 <div
@@ -357,7 +367,7 @@ pub fn evaluation_list() -> View {
                     <div class="summary-icon pending" inner_html={icon_clock}></div>
                     <div class="summary-content">
                         <h3>{pending_count.to_string()}</h3>
-                        <p>"Pending Start"</p>
+                        <p>{t("Pending Start")}</p>
                     </div>
                 </div>
                 <div
@@ -367,7 +377,7 @@ pub fn evaluation_list() -> View {
                     <div class="summary-icon in-progress" inner_html={icon_clipboard}></div>
                     <div class="summary-content">
                         <h3>{in_progress_count.to_string()}</h3>
-                        <p>"In Progress"</p>
+                        <p>{t("In Progress")}</p>
                     </div>
                 </div>
                 <div
@@ -377,14 +387,14 @@ pub fn evaluation_list() -> View {
                     <div class="summary-icon completed" inner_html={icon_check}></div>
                     <div class="summary-content">
                         <h3>{completed_count.to_string()}</h3>
-                        <p>"Completed"</p>
+                        <p>{t("Completed")}</p>
                     </div>
                 </div>
             </div>
 
             // Evaluations table
             {panel(
-                "Tenders Pending Evaluation".to_string(),
+                t("Tenders Pending Evaluation").to_string(),
                 vec![],
                 vec![data_table(columns, rows, Some(handle_row_click))]
             )}
@@ -1,6 +1,8 @@
 //! Evaluation scoring page - criteria grid, bid comparison, committee notes
 
 use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
 use crate::shared::layout::page_header;
 use crate::shared::components::{
     panel, panel_with_footer,
@@ -12,33 +14,45 @@ use crate::shared::components::{
     notice_bar, NoticeType,
 };
 use crate::util::format::{format_currency, format_currency_full, format_date, format_datetime};
+use crate::util::auth::AuthState;
+use crate::util::permissions::{self, Action};
 use super::store::EvaluationStore;
 use super::types::{EvaluationStatus, CriterionCategory, ScoreSubmission, CriterionScore};
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Evaluation scoring page
 #[component]
-pub fn evaluation_scoring() -> View {
+pub fn evaluation_scoring(evaluation_id: String) -> View {
     let store = use_context::<EvaluationStore>();
+    let auth_state = use_context::<Signal<AuthState>>();
+    let can_finalize = permissions::can_perform(&auth_state.get(), Action::FinalizeEvaluation);
 
-    // Load data on mount if not already loaded
+    let missing = signal(false);
+
+    // Load the evaluation named in the URL
     effect({
         let store = store.clone();
+        let evaluation_id = evaluation_id.clone();
+        let missing = missing.clone();
         move || {
             let store = store.clone();
+            let evaluation_id = evaluation_id.clone();
+            let missing = missing.clone();
             spawn(async move {
-                if store.evaluations.get().is_empty() {
-                    service::load_evaluations(&store).await;
-                }
-                // Select first evaluation if none selected
-                if store.selected.get().is_none() && !store.evaluations.get().is_empty() {
-                    let first_id = store.evaluations.get()[0].id.clone();
-                    store.select_evaluation(&first_id);
+                service::load_evaluation(&store, &evaluation_id).await;
+                if store.selected.get().map_or(true, |e| e.id != evaluation_id) {
+                    store.clear_selection();
+                    missing.set(true);
                 }
             });
         }
     });
 
+    if missing.get() {
+        return entity_not_found(t("Evaluation"), &evaluation_id, store.error.get(), Route::EvaluationList, t("Back to Evaluations"));
+    }
+
     let selected = store.selected.clone();
     let selected_bid = store.selected_bid.clone();
     let scoring_in_progress = store.scoring_in_progress.clone();
@@ -78,6 +92,20 @@ pub fn evaluation_scoring() -> View {
         })
     };
 
+    let handle_finalize = {
+        let store = store.clone();
+        Callback::<()>::new(move |_| {
+            let store = store.clone();
+            spawn(async move {
+                if let Some(eval) = store.selected.get() {
+                    if let Err(e) = service::finalize_evaluation(&store, &eval.id).await {
+                        store.error.set(Some(e));
+                    }
+                }
+            });
+        })
+    };
+
     let handle_back = {
         let store = store.clone();
         Callback::<()>::new(move |_| {
@@ -474,7 +502,7 @@ pub fn evaluation_scoring() -> View {
             // Back link
             <a class="back-link" on:click={handle_back}>
                 <span inner_html={icon_back}></span>
-                "Back to Evaluation List"
+                {t("Back to Evaluation List")}
             </a>
 
             if let Some(eval) = selected.get().as_ref() {
@@ -484,14 +512,14 @@ pub fn evaluation_scoring() -> View {
                         <h1>{eval.tender_title.clone()}</h1>
                         <div class="eval-meta">
                             <span class="eval-meta-item">
-                                <strong>"Ref:"</strong> {eval.tender_reference.clone()}
+                                <strong>{t("Ref:")}</strong> {eval.tender_reference.clone()}
                             </span>
                             <span class="eval-meta-item">
-                                <strong>"Value:"</strong> {format_currency(eval.tender_value)}
+                                <strong>{t("Value:")}</strong> {format_currency(eval.tender_value)}
                             </span>
                             <span class="eval-meta-item">
                                 <span inner_html={icon_clock}></span>
-                                "Deadline: " {format_date(&eval.evaluation_deadline)}
+                                {tf("Deadline: {date}", &[("date", format_date(&eval.evaluation_deadline))])}
                             </span>
                             <span class="eval-meta-item">
                                 {match eval.status {
@@ -507,7 +535,17 @@ pub fn evaluation_scoring() -> View {
                     <div class="eval-actions">
                         if !scoring_in_progress.get() {
                             <button class="btn btn-primary" on:click={handle_start_scoring.clone()}>
-                                "Start Scoring"
+                                {t("Start Scoring")}
+                            </button>
+                        }
+                        if can_finalize && eval.status == EvaluationStatus::InProgress {
+                            <button
+                                class="btn btn-accent"
+                                data-testid="finalize-evaluation"
+                                on:click={handle_finalize.clone()}
+                                disabled={loading.get()}
+                            >
+                                {t("Finalize Evaluation")}
                             </button>
                         }
                     </div>
@@ -515,11 +553,11 @@ pub fn evaluation_scoring() -> View {
 
                 // Notice bar for scoring info
                 {notice_bar(
-                    format!("Scoring Method: {} | Min Technical Score: {}% | Price Weight: {}%",
-                        eval.scoring_method.as_str(),
-                        eval.min_technical_score,
-                        eval.price_weight
-                    ),
+                    tf("Scoring Method: {method} | Min Technical Score: {score}% | Price Weight: {weight}%", &[
+                        ("method", eval.scoring_method.as_str().to_string()),
+                        ("score", eval.min_technical_score.to_string()),
+                        ("weight", eval.price_weight.to_string()),
+                    ]),
                     NoticeType::Info,
                     None
                 )}
@@ -528,7 +566,7 @@ pub fn evaluation_scoring() -> View {
                 <div class="scoring-layout">
                     // Left sidebar - Bid list
                     {panel(
-                        format!("Bids ({})", eval.bids.len()),
+                        tf("Bids ({count})", &[("count", eval.bids.len().to_string())]),
                         vec![],
                         vec![view! {
                             <div class="bid-list">
@@ -546,7 +584,7 @@ pub fn evaluation_scoring() -> View {
                     // Center - Criteria scoring grid
                     if let Some(bid) = selected_bid.get().as_ref() {
                         {panel_with_footer(
-                            format!("Score: {}", bid.supplier_name),
+                            tf("Score: {supplier_name}", &[("supplier_name", bid.supplier_name.to_string())]),
                             vec![
                                 bbbee_badge(match bid.bbbee_level {
                                     1 => BbbeeLevel::Level1,
@@ -566,23 +604,23 @@ pub fn evaluation_scoring() -> View {
                                 view! {
                                     <div class="score-summary">
                                         <div class="score-summary-row">
-                                            <span class="score-summary-label">"Bid Price"</span>
+                                            <span class="score-summary-label">{t("Bid Price")}</span>
                                             <span class="score-summary-value">{format_currency_full(bid.total_price)}</span>
                                         </div>
                                         <div class="score-summary-row">
-                                            <span class="score-summary-label">"Technical Score"</span>
+                                            <span class="score-summary-label">{t("Technical Score")}</span>
                                             <span class="score-summary-value">
                                                 {bid.technical_score.map(|s| format!("{:.1}%", s)).unwrap_or("-".to_string())}
                                             </span>
                                         </div>
                                         <div class="score-summary-row">
-                                            <span class="score-summary-label">"Financial Score"</span>
+                                            <span class="score-summary-label">{t("Financial Score")}</span>
                                             <span class="score-summary-value">
                                                 {bid.financial_score.map(|s| format!("{:.1}%", s)).unwrap_or("-".to_string())}
                                             </span>
                                         </div>
                                         <div class="score-summary-row">
-                                            <span class="score-summary-label">"Total Score"</span>
+                                            <span class="score-summary-label">{t("Total Score")}</span>
                                             <span class="score-summary-value">
                                                 {bid.total_score.map(|s| format!("{:.1}%", s)).unwrap_or("-".to_string())}
                                             </span>
@@ -591,27 +629,27 @@ pub fn evaluation_scoring() -> View {
                                 },
                             ],
                             vec![
-                                view! { <button class="btn btn-secondary">"Save Draft"</button> },
+                                view! { <button class="btn btn-secondary">{t("Save Draft")}</button> },
                                 view! {
                                     <button
                                         class="btn btn-primary"
                                         on:click={handle_submit_scores.clone()}
                                         disabled={!scoring_in_progress.get() || loading.get()}
                                     >
-                                        "Submit Scores"
+                                        {t("Submit Scores")}
                                     </button>
                                 },
                             ]
                         )}
                     } else {
                         {panel(
-                            "Scoring".to_string(),
+                            t("Scoring").to_string(),
                             vec![],
                             vec![view! {
                                 <div class="no-selection">
                                     <span inner_html={icon_alert}></span>
-                                    <h3>"Select a Bid to Score"</h3>
-                                    <p>"Choose a bid from the list on the left to begin scoring."</p>
+                                    <h3>{t("Select a Bid to Score")}</h3>
+                                    <p>{t("Choose a bid from the list on the left to begin scoring.")}</p>
                                 </div>
                             }]
                         )}
@@ -621,7 +659,7 @@ pub fn evaluation_scoring() -> View {
                     <div class="sidebar">
                         // Committee members
                         {panel(
-                            "Committee".to_string(),
+                            t("Committee").to_string(),
                             vec![],
                             vec![view! {
                                 <div class="committee-list">
@@ -649,8 +687,8 @@ pub fn evaluation_scoring() -> View {
 
                         // Committee notes
                         {panel(
-                            "Committee Notes".to_string(),
-                            vec![view! { <button class="btn btn-sm btn-secondary">"Add Note"</button> }],
+                            t("Committee Notes").to_string(),
+                            vec![view! { <button class="btn btn-sm btn-secondary">{t("Add Note")}</button> }],
                             vec![view! {
                                 <div class="notes-list">
                                     for note in eval.notes.iter() {
@@ -664,7 +702,7 @@ pub fn evaluation_scoring() -> View {
                                     }
                                     if eval.notes.is_empty() {
                                         <p style="color: var(--text-muted); font-size: 13px; text-align: center; padding: 20px;">
-                                            "No committee notes yet."
+                                            {t("No committee notes yet.")}
                                         </p>
                                     }
                                 </div>
@@ -675,16 +713,16 @@ pub fn evaluation_scoring() -> View {
 
                 // Bid comparison table
                 {panel(
-                    "Bid Comparison".to_string(),
-                    vec![view! { <button class="btn btn-sm btn-secondary">"Export Comparison"</button> }],
+                    t("Bid Comparison").to_string(),
+                    vec![view! { <button class="btn btn-sm btn-secondary">{t("Export Comparison")}</button> }],
                     vec![bid_comparison_table(eval.bids.clone(), eval.criteria.clone())]
                 )}
             } else {
                 // No evaluation selected
                 <div class="no-selection">
                     <span inner_html={icon_alert}></span>
-                    <h3>"No Evaluation Selected"</h3>
-                    <p>"Please select an evaluation from the list to begin scoring."</p>
+                    <h3>{t("No Evaluation Selected")}</h3>
+                    <p>{t("Please select an evaluation from the list to begin scoring.")}</p>
                 </div>
             }
         </div>
@@ -777,19 +815,19 @@ fn criteria_scoring_grid(
     view! {
         <div class="criteria-grid">
             if !technical.is_empty() {
-                {criterion_category("Technical", technical, current_scores.clone(), scoring_enabled, store.clone())}
+                {criterion_category(t("Technical"), technical, current_scores.clone(), scoring_enabled, store.clone())}
             }
             if !experience.is_empty() {
-                {criterion_category("Experience", experience, current_scores.clone(), scoring_enabled, store.clone())}
+                {criterion_category(t("Experience"), experience, current_scores.clone(), scoring_enabled, store.clone())}
             }
             if !bbbee.is_empty() {
-                {criterion_category("B-BBEE", bbbee, current_scores.clone(), scoring_enabled, store.clone())}
+                {criterion_category(t("B-BBEE"), bbbee, current_scores.clone(), scoring_enabled, store.clone())}
             }
             if !local_content.is_empty() {
-                {criterion_category("Local Content", local_content, current_scores.clone(), scoring_enabled, store.clone())}
+                {criterion_category(t("Local Content"), local_content, current_scores.clone(), scoring_enabled, store.clone())}
             }
             if !financial.is_empty() {
-                {criterion_category("Financial", financial, current_scores.clone(), scoring_enabled, store.clone())}
+                {criterion_category(t("Financial"), financial, current_scores.clone(), scoring_enabled, store.clone())}
             }
         </div>
     }
@@ -831,7 +869,7 @@ fn criterion_row(
                 <p>{criterion.description.clone()}</p>
             </div>
             <div class="criterion-weight">
-                {format!("Weight: {}%", criterion.weight)}
+                {tf("Weight: {weight}%", &[("weight", criterion.weight.to_string())])}
             </div>
             <div class="criterion-score">
                 for score in 0..=criterion.max_score {
@@ -892,7 +930,7 @@ fn bid_comparison_table(
     // Find lowest price for highlighting
     let lowest_price = sorted_bids.iter()
         .map(|b| b.total_price)
-        .fold(f64::INFINITY, f64::min);
+        .min();
 
     // Find highest score for highlighting
     let highest_score = sorted_bids.iter()
@@ -903,13 +941,13 @@ fn bid_comparison_table(
         <table class="comparison-table">
             <thead>
                 <tr>
-                    <th>"Supplier"</th>
-                    <th>"B-BBEE"</th>
-                    <th>"Price"</th>
-                    <th>"Technical"</th>
-                    <th>"Financial"</th>
-                    <th>"Total"</th>
-                    <th>"Rank"</th>
+                    <th>{t("Supplier")}</th>
+                    <th>{t("B-BBEE")}</th>
+                    <th>{t("Price")}</th>
+                    <th>{t("Technical")}</th>
+                    <th>{t("Financial")}</th>
+                    <th>{t("Total")}</th>
+                    <th>{t("Rank")}</th>
                 </tr>
             </thead>
             <tbody>
@@ -918,8 +956,8 @@ fn bid_comparison_table(
                         <td>
                             <strong>{bid.supplier_name.clone()}</strong>
                         </td>
-                        <td>{format!("Level {}", bid.bbbee_level)}</td>
-                        <td class={if bid.total_price == lowest_price { "highlight" } else { "" }}>
+                        <td>{tf("Level {level}", &[("level", bid.bbbee_level.to_string())])}</td>
+                        <td class={if Some(bid.total_price) == lowest_price { "highlight" } else { "" }}>
                             {format_currency(bid.total_price)}
                         </td>
                         <td>{bid.technical_score.map(|s| format!("{:.1}%", s)).unwrap_or("-".to_string())}</td>
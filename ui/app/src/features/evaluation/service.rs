@@ -1,5 +1,8 @@
 //! Evaluation service - API calls
 
+use crate::util::api::{ApiError, HttpMethod};
+use crate::util::backend;
+use crate::util::permissions::{self, Action};
 use super::store::{EvaluationStore, load_mock_data};
 use super::types::{ScoreSubmission, CriterionScore};
 
@@ -8,9 +11,7 @@ pub async fn load_evaluations(store: &EvaluationStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -22,7 +23,7 @@ pub async fn load_evaluation(store: &EvaluationStore, evaluation_id: &str) {
 
     // Ensure evaluations are loaded
     if store.evaluations.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Select the evaluation
@@ -45,8 +46,6 @@ pub async fn submit_scores(
         return Err("No scores to submit".to_string());
     }
 
-    // In production, this would POST to the API
-    // For now, update local state
     let mut evaluations = store.evaluations.get().clone();
 
     if let Some(eval) = evaluations.iter_mut().find(|e| e.id == submission.evaluation_id) {
@@ -81,6 +80,16 @@ pub async fn submit_scores(
         {
             member.has_scored = true;
         }
+
+        let endpoint = format!("/evaluations/{}/scores", submission.evaluation_id);
+        match backend::save(HttpMethod::Post, &endpoint, &*eval).await {
+            Ok(saved) => *eval = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
     }
 
     store.evaluations.set(evaluations);
@@ -119,6 +128,16 @@ pub async fn add_committee_note(
             is_confidential,
         };
         eval.notes.push(note);
+
+        let endpoint = format!("/evaluations/{}/notes", evaluation_id);
+        match backend::save(HttpMethod::Post, &endpoint, &*eval).await {
+            Ok(saved) => *eval = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
     }
 
     store.evaluations.set(evaluations);
@@ -137,6 +156,11 @@ pub async fn finalize_evaluation(
     store: &EvaluationStore,
     evaluation_id: &str,
 ) -> Result<(), String> {
+    if let Err(e) = permissions::require(Action::FinalizeEvaluation) {
+        store.error.set(Some(e.clone()));
+        return Err(e);
+    }
+
     store.loading.set(true);
 
     let mut evaluations = store.evaluations.get().clone();
@@ -171,6 +195,16 @@ pub async fn finalize_evaluation(
                 bid.rank = Some((i + 1) as u32);
             }
         }
+
+        let endpoint = format!("/evaluations/{}/finalize", evaluation_id);
+        match backend::save(HttpMethod::Post, &endpoint, &*eval).await {
+            Ok(saved) => *eval = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
     }
 
     store.evaluations.set(evaluations);
@@ -199,3 +233,21 @@ pub fn calculate_bid_scores(scores: &[CriterionScore], max_score: u32) -> f64 {
         0.0
     }
 }
+
+/// Refresh tender evaluations from the configured backend
+async fn load_from_backend(store: &EvaluationStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/evaluations", &store.evaluations).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    }
+}
@@ -1,6 +1,7 @@
 //! Evaluation store
 
 use components::prelude::*;
+use crate::util::money::Money;
 use super::types::{
     TenderEvaluation, Bid, EvaluationCriterion, CommitteeMember,
     CommitteeNote, CriterionScore, EvaluationStatus, CriterionCategory,
@@ -190,7 +191,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
             tender_id: "tender_001".to_string(),
             tender_reference: "RFP-2025-0089".to_string(),
             tender_title: "IT Infrastructure Upgrade - Data Centre Modernization".to_string(),
-            tender_value: 45_000_000.0,
+            tender_value: Money::rands(45_000_000),
             closing_date: "2025-02-15".to_string(),
             evaluation_start: Some("2025-02-18".to_string()),
             evaluation_deadline: "2025-03-05".to_string(),
@@ -202,7 +203,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "TechSolutions SA (Pty) Ltd".to_string(),
                     bbbee_level: 1,
                     submitted_at: "2025-02-14T16:45:00Z".to_string(),
-                    total_price: 42_500_000.0,
+                    total_price: Money::rands(42_500_000),
                     technical_score: Some(82.5),
                     financial_score: Some(94.1),
                     total_score: Some(84.8),
@@ -223,7 +224,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "DataCore Systems".to_string(),
                     bbbee_level: 2,
                     submitted_at: "2025-02-15T09:30:00Z".to_string(),
-                    total_price: 39_800_000.0,
+                    total_price: Money::rands(39_800_000),
                     technical_score: Some(75.0),
                     financial_score: Some(100.0),
                     total_score: Some(80.0),
@@ -244,7 +245,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "Innovate IT Holdings".to_string(),
                     bbbee_level: 1,
                     submitted_at: "2025-02-14T11:00:00Z".to_string(),
-                    total_price: 47_200_000.0,
+                    total_price: Money::rands(47_200_000),
                     technical_score: Some(88.0),
                     financial_score: Some(84.3),
                     total_score: Some(87.3),
@@ -265,7 +266,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "GlobalTech Partners".to_string(),
                     bbbee_level: 4,
                     submitted_at: "2025-02-15T14:20:00Z".to_string(),
-                    total_price: 41_000_000.0,
+                    total_price: Money::rands(41_000_000),
                     technical_score: Some(68.0),
                     financial_score: Some(97.1),
                     total_score: Some(73.8),
@@ -311,7 +312,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
             tender_id: "tender_002".to_string(),
             tender_reference: "RFP-2025-0076".to_string(),
             tender_title: "Security Services - Perimeter and Access Control".to_string(),
-            tender_value: 18_500_000.0,
+            tender_value: Money::rands(18_500_000),
             closing_date: "2025-02-10".to_string(),
             evaluation_start: Some("2025-02-12".to_string()),
             evaluation_deadline: "2025-02-28".to_string(),
@@ -323,7 +324,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "SecureGuard SA".to_string(),
                     bbbee_level: 1,
                     submitted_at: "2025-02-09T15:00:00Z".to_string(),
-                    total_price: 17_800_000.0,
+                    total_price: Money::rands(17_800_000),
                     technical_score: Some(85.0),
                     financial_score: Some(96.2),
                     total_score: Some(87.2),
@@ -337,7 +338,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "ProtectCo Services".to_string(),
                     bbbee_level: 2,
                     submitted_at: "2025-02-10T08:45:00Z".to_string(),
-                    total_price: 19_200_000.0,
+                    total_price: Money::rands(19_200_000),
                     technical_score: Some(78.0),
                     financial_score: Some(89.1),
                     total_score: Some(80.2),
@@ -359,7 +360,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
             tender_id: "tender_003".to_string(),
             tender_reference: "RFP-2025-0092".to_string(),
             tender_title: "Fleet Management Services - Vehicle Leasing".to_string(),
-            tender_value: 32_000_000.0,
+            tender_value: Money::rands(32_000_000),
             closing_date: "2025-02-20".to_string(),
             evaluation_start: None,
             evaluation_deadline: "2025-03-10".to_string(),
@@ -371,7 +372,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "FleetMaster SA".to_string(),
                     bbbee_level: 2,
                     submitted_at: "2025-02-19T16:30:00Z".to_string(),
-                    total_price: 30_500_000.0,
+                    total_price: Money::rands(30_500_000),
                     technical_score: None,
                     financial_score: None,
                     total_score: None,
@@ -385,7 +386,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "AutoLease Holdings".to_string(),
                     bbbee_level: 3,
                     submitted_at: "2025-02-20T11:00:00Z".to_string(),
-                    total_price: 29_800_000.0,
+                    total_price: Money::rands(29_800_000),
                     technical_score: None,
                     financial_score: None,
                     total_score: None,
@@ -399,7 +400,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "DriveRight Solutions".to_string(),
                     bbbee_level: 1,
                     submitted_at: "2025-02-20T14:45:00Z".to_string(),
-                    total_price: 31_200_000.0,
+                    total_price: Money::rands(31_200_000),
                     technical_score: None,
                     financial_score: None,
                     total_score: None,
@@ -421,7 +422,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
             tender_id: "tender_004".to_string(),
             tender_reference: "RFP-2025-0065".to_string(),
             tender_title: "Professional Services - Internal Audit".to_string(),
-            tender_value: 8_500_000.0,
+            tender_value: Money::rands(8_500_000),
             closing_date: "2025-01-31".to_string(),
             evaluation_start: Some("2025-02-03".to_string()),
             evaluation_deadline: "2025-02-20".to_string(),
@@ -433,7 +434,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "AuditPro Consulting".to_string(),
                     bbbee_level: 1,
                     submitted_at: "2025-01-30T12:00:00Z".to_string(),
-                    total_price: 8_200_000.0,
+                    total_price: Money::rands(8_200_000),
                     technical_score: Some(92.0),
                     financial_score: Some(96.4),
                     total_score: Some(92.9),
@@ -455,7 +456,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
             tender_id: "tender_005".to_string(),
             tender_reference: "RFP-2025-0088".to_string(),
             tender_title: "Office Furniture Supply and Installation".to_string(),
-            tender_value: 5_200_000.0,
+            tender_value: Money::rands(5_200_000),
             closing_date: "2025-02-25".to_string(),
             evaluation_start: None,
             evaluation_deadline: "2025-03-15".to_string(),
@@ -467,7 +468,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "OfficePro Furniture".to_string(),
                     bbbee_level: 1,
                     submitted_at: "2025-02-24T10:00:00Z".to_string(),
-                    total_price: 4_950_000.0,
+                    total_price: Money::rands(4_950_000),
                     technical_score: None,
                     financial_score: None,
                     total_score: None,
@@ -481,7 +482,7 @@ pub fn load_mock_data(store: &EvaluationStore) {
                     supplier_name: "WorkSpace Solutions".to_string(),
                     bbbee_level: 2,
                     submitted_at: "2025-02-25T09:30:00Z".to_string(),
-                    total_price: 5_100_000.0,
+                    total_price: Money::rands(5_100_000),
                     technical_score: None,
                     financial_score: None,
                     total_score: None,
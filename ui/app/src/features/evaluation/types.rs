@@ -1,6 +1,7 @@
 //! Evaluation domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::money::Money;
 
 /// Evaluation status enum
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -86,7 +87,7 @@ pub struct Bid {
     pub supplier_name: String,
     pub bbbee_level: u8,
     pub submitted_at: String,
-    pub total_price: f64,
+    pub total_price: Money,
     pub technical_score: Option<f64>,
     pub financial_score: Option<f64>,
     pub total_score: Option<f64>,
@@ -113,7 +114,7 @@ pub struct TenderEvaluation {
     pub tender_id: String,
     pub tender_reference: String,
     pub tender_title: String,
-    pub tender_value: f64,
+    pub tender_value: Money,
     pub closing_date: String,
     pub evaluation_start: Option<String>,
     pub evaluation_deadline: String,
@@ -168,7 +169,7 @@ impl Default for TenderEvaluation {
             tender_id: String::new(),
             tender_reference: String::new(),
             tender_title: String::new(),
-            tender_value: 0.0,
+            tender_value: Money::ZERO,
             closing_date: String::new(),
             evaluation_start: None,
             evaluation_deadline: String::new(),
@@ -0,0 +1,179 @@
+//! Goods Receipt detail view
+
+use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table, DataTableColumn, DataTableRow,
+    status_badge, StatusType,
+    notice_bar, NoticeType,
+};
+use crate::util::format::{format_currency, format_date};
+use super::types::{GoodsReceipt, GoodsReceiptStatus, InspectionStatus};
+use super::store::GoodsReceiptStore;
+use super::service;
+use crate::util::i18n::{t, tf};
+
+/// Goods Receipt detail page
+#[component]
+pub fn gr_detail(receipt_id: String) -> View {
+    let store = use_context::<GoodsReceiptStore>();
+
+    // Load receipt on mount
+    effect({
+        let store = store.clone();
+        let receipt_id = receipt_id.clone();
+        move || {
+            let store = store.clone();
+            let receipt_id = receipt_id.clone();
+            spawn(async move {
+                service::load_receipt(&store, &receipt_id).await;
+            });
+        }
+    });
+
+    let loading = store.loading.get();
+    let error = store.error.get();
+    let receipt = store.selected.get().filter(|r| r.id == receipt_id);
+
+    view! {
+        style {
+            r#"
+            .gr-detail { display: flex; flex-direction: column; gap: var(--space-6); }
+            .gr-detail-grid {
+                display: grid;
+                grid-template-columns: 2fr 1fr;
+                gap: 24px;
+            }
+            .gr-detail .detail-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 8px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .gr-detail .detail-row:last-child { border-bottom: none; }
+            .gr-detail .detail-row .label { color: var(--text-muted); }
+            .gr-detail .detail-row .value { font-weight: 500; text-align: right; }
+            .gr-detail .rejected-qty { color: var(--red); }
+            @media (max-width: 1024px) {
+                .gr-detail-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="gr-detail" data-testid="gr-detail">
+            if loading {
+                <div class="loading-state">{t("Loading goods receipt...")}</div>
+            } else if let Some(receipt) = receipt {
+                {render_receipt(receipt)}
+            } else {
+                {entity_not_found(t("Goods Receipt"), &receipt_id, error, Route::GoodsReceiptList, t("Back to Goods Receipts"))}
+            }
+        </div>
+    }
+}
+
+fn render_receipt(receipt: GoodsReceipt) -> View {
+    let status_type = match receipt.status {
+        GoodsReceiptStatus::Draft => StatusType::Draft,
+        GoodsReceiptStatus::Pending => StatusType::Pending,
+        GoodsReceiptStatus::PartiallyReceived => StatusType::InProgress,
+        GoodsReceiptStatus::Completed => StatusType::Complete,
+        GoodsReceiptStatus::Rejected => StatusType::Cancelled,
+        GoodsReceiptStatus::Cancelled => StatusType::Cancelled,
+    };
+
+    let columns = vec![
+        DataTableColumn { key: "code".to_string(), label: t("Item Code").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "description".to_string(), label: t("Description").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "ordered".to_string(), label: t("Ordered").to_string(), width: Some("90px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "received".to_string(), label: t("Received").to_string(), width: Some("90px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "accepted".to_string(), label: t("Accepted").to_string(), width: Some("90px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "rejected".to_string(), label: t("Rejected").to_string(), width: Some("90px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "inspection".to_string(), label: t("Inspection").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+    ];
+
+    let rows: Vec<DataTableRow> = receipt.items_received.iter().map(|item| {
+        DataTableRow {
+            id: item.id.clone(),
+            cells: vec![
+                view! { <span class="id-cell">{item.item_code.clone()}</span> },
+                view! { <span>{item.description.clone()}</span> },
+                view! { <span>{format!("{} {}", item.ordered_quantity, item.unit)}</span> },
+                view! { <span>{item.received_quantity.to_string()}</span> },
+                view! { <span>{item.accepted_quantity.to_string()}</span> },
+                view! { <span class="rejected-qty">{item.rejected_quantity.to_string()}</span> },
+                view! { <span>{item.inspection_status.as_str()}</span> },
+            ],
+        }
+    }).collect();
+
+    let inspection_notice = if receipt.inspection_status == InspectionStatus::Failed {
+        Some(t("Inspection failed. Rejected items must be returned to the supplier.").to_string())
+    } else {
+        None
+    };
+
+    view! {
+        {page_header(
+            tf("Goods Receipt {id}", &[("id", receipt.id.to_string())]),
+            Some(format!("PO {} - {}", receipt.po_reference.po_number, receipt.supplier.name)),
+            vec![
+                view! { <a href="/goods-receipt" class="btn btn-secondary">{t("Back to Goods Receipts")}</a> },
+            ]
+        )}
+
+        if let Some(message) = inspection_notice {
+            {notice_bar(message, NoticeType::Warning, None)}
+        }
+
+        <div class="gr-detail-grid">
+            {panel(
+                t("Items Received").to_string(),
+                vec![],
+                vec![data_table(columns, rows, None)]
+            )}
+
+            {panel(
+                t("Receipt Details").to_string(),
+                vec![],
+                vec![
+                    view! {
+                        <div>
+                            <div class="detail-row">
+                                <span class="label">{t("Status")}</span>
+                                <span class="value">{status_badge(status_type)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Inspection")}</span>
+                                <span class="value">{receipt.inspection_status.as_str()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Received")}</span>
+                                <span class="value">{format_date(&receipt.receipt_date)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Received By")}</span>
+                                <span class="value">{receipt.received_by.clone()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Delivery Note")}</span>
+                                <span class="value">{receipt.delivery_note_number.clone().unwrap_or_else(|| "-".to_string())}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Warehouse")}</span>
+                                <span class="value">{receipt.warehouse_location.clone()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("PO Value")}</span>
+                                <span class="value">{format_currency(receipt.po_reference.total_value)}</span>
+                            </div>
+                        </div>
+                    }
+                ]
+            )}
+        </div>
+    }
+}
@@ -17,6 +17,7 @@ use crate::util::format::{format_currency, format_date};
 use super::types::{GoodsReceiptStatus, InspectionStatus, GoodsReceiptSummary};
 use super::store::GoodsReceiptStore;
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Goods Receipt list page
 #[component]
@@ -139,19 +140,19 @@ pub fn gr_list() -> View {
     let tabs = vec![
         Tab {
             id: "pending".to_string(),
-            label: format!("Pending Receipts ({})", pending_count),
+            label: tf("Pending Receipts ({count})", &[("count", pending_count.to_string())]),
             icon: None,
             active: active_tab.get() == "pending",
         },
         Tab {
             id: "completed".to_string(),
-            label: format!("Completed ({})", completed_receipts.len()),
+            label: tf("Completed ({count})", &[("count", completed_receipts.len().to_string())]),
             icon: None,
             active: active_tab.get() == "completed",
         },
         Tab {
             id: "all".to_string(),
-            label: format!("All Receipts ({})", store.receipts.get().len()),
+            label: tf("All Receipts ({count})", &[("count", store.receipts.get().len().to_string())]),
             icon: None,
             active: active_tab.get() == "all",
         },
@@ -161,63 +162,63 @@ pub fn gr_list() -> View {
     let columns = vec![
         DataTableColumn {
             key: "id".to_string(),
-            label: "GR Number".to_string(),
+            label: t("GR Number").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: Some("id-cell".to_string()),
         },
         DataTableColumn {
             key: "po_number".to_string(),
-            label: "PO Reference".to_string(),
+            label: t("PO Reference").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: Some("id-cell".to_string()),
         },
         DataTableColumn {
             key: "supplier".to_string(),
-            label: "Supplier".to_string(),
+            label: t("Supplier").to_string(),
             width: Some("200px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "items".to_string(),
-            label: "Items".to_string(),
+            label: t("Items").to_string(),
             width: Some("80px".to_string()),
             align: Some("center".to_string()),
             cell_class: None,
         },
         DataTableColumn {
             key: "value".to_string(),
-            label: "Value".to_string(),
+            label: t("Value").to_string(),
             width: Some("120px".to_string()),
             align: Some("right".to_string()),
             cell_class: Some("amount-cell".to_string()),
         },
         DataTableColumn {
             key: "progress".to_string(),
-            label: "Progress".to_string(),
+            label: t("Progress").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "inspection".to_string(),
-            label: "Inspection".to_string(),
+            label: t("Inspection").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "status".to_string(),
-            label: "Status".to_string(),
+            label: t("Status").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "received_by".to_string(),
-            label: "Received By".to_string(),
+            label: t("Received By").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
@@ -274,7 +275,7 @@ pub fn gr_list() -> View {
                     view! {
                         <div class="row-actions">
                             <a href={format!("/goods-receipt/{}", receipt.id)} class="btn btn-sm btn-secondary">
-                                {if receipt.status == GoodsReceiptStatus::Pending { "Process" } else { "View" }}
+                                {if receipt.status == GoodsReceiptStatus::Pending { t("Process") } else { t("View") }}
                             </a>
                         </div>
                     },
@@ -387,18 +388,22 @@ pub fn gr_list() -> View {
 
         <div class="gr-page" data-testid="gr-list">
             {page_header(
-                "Goods Receipt".to_string(),
-                Some("Process and track goods received from suppliers".to_string()),
+                t("Goods Receipt").to_string(),
+                Some(t("Process and track goods received from suppliers").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export"</button> },
-                    view! { <a href="/goods-receipt/new" class="btn btn-primary">"New Receipt"</a> },
+                    view! { <button class="btn btn-secondary">{t("Export")}</button> },
+                    view! { <a href="/goods-receipt/new" class="btn btn-primary">{t("New Receipt")}</a> },
                 ]
             )}
 
             // Pending inspection warning
             if pending_inspection_count > 0 {
                 {notice_bar(
-                    format!("{} receipt(s) pending inspection. Complete quality checks before acceptance.", pending_inspection_count),
+                    tn(
+                        "{n} receipt pending inspection. Complete quality checks before acceptance.",
+                        "{n} receipts pending inspection. Complete quality checks before acceptance.",
+                        pending_inspection_count as i64,
+                    ),
                     NoticeType::Warning,
                     None
                 )}
@@ -407,15 +412,15 @@ pub fn gr_list() -> View {
             // Stats row
             <div class="stats-row">
                 <div class={if pending_count > 0 { "stat-item warning" } else { "stat-item" }}>
-                    <span class="label">"Pending Processing:"</span>
+                    <span class="label">{t("Pending Processing:")}</span>
                     <span class="count">{pending_count.to_string()}</span>
                 </div>
                 <div class={if pending_inspection_count > 0 { "stat-item info" } else { "stat-item" }}>
-                    <span class="label">"Awaiting Inspection:"</span>
+                    <span class="label">{t("Awaiting Inspection:")}</span>
                     <span class="count">{pending_inspection_count.to_string()}</span>
                 </div>
                 <div class="stat-item">
-                    <span class="label">"Completed Today:"</span>
+                    <span class="label">{t("Completed Today:")}</span>
                     <span class="count">{
                         store.receipts.get().iter()
                             .filter(|r| r.status == GoodsReceiptStatus::Completed && r.receipt_date == "2025-02-27")
@@ -432,29 +437,29 @@ pub fn gr_list() -> View {
                 {filter_bar(vec![
                     view! {
                         <div class="filter-group">
-                            <label>"Status:"</label>
+                            <label>{t("Status:")}</label>
                             <select on:change={handle_status_change}>
-                                <option value="">"All Statuses"</option>
-                                <option value="draft">"Draft"</option>
-                                <option value="pending">"Pending"</option>
-                                <option value="partially_received">"Partially Received"</option>
-                                <option value="completed">"Completed"</option>
-                                <option value="rejected">"Rejected"</option>
-                                <option value="cancelled">"Cancelled"</option>
+                                <option value="">{t("All Statuses")}</option>
+                                <option value="draft">{t("Draft")}</option>
+                                <option value="pending">{t("Pending")}</option>
+                                <option value="partially_received">{t("Partially Received")}</option>
+                                <option value="completed">{t("Completed")}</option>
+                                <option value="rejected">{t("Rejected")}</option>
+                                <option value="cancelled">{t("Cancelled")}</option>
                             </select>
                         </div>
                     },
                     view! {
                         <div class="filter-group">
-                            <label>"Inspection:"</label>
+                            <label>{t("Inspection:")}</label>
                             <select on:change={handle_inspection_change}>
-                                <option value="">"All"</option>
-                                <option value="pending">"Pending"</option>
-                                <option value="in_progress">"In Progress"</option>
-                                <option value="passed">"Passed"</option>
-                                <option value="failed">"Failed"</option>
-                                <option value="partial_pass">"Partial Pass"</option>
-                                <option value="waived">"Waived"</option>
+                                <option value="">{t("All")}</option>
+                                <option value="pending">{t("Pending")}</option>
+                                <option value="in_progress">{t("In Progress")}</option>
+                                <option value="passed">{t("Passed")}</option>
+                                <option value="failed">{t("Failed")}</option>
+                                <option value="partial_pass">{t("Partial Pass")}</option>
+                                <option value="waived">{t("Waived")}</option>
                             </select>
                         </div>
                     },
@@ -463,44 +468,44 @@ pub fn gr_list() -> View {
                         <div class="filter-group">
                             <input
                                 type="text"
-                                placeholder="Search GR, PO, supplier..."
+                                placeholder={t("Search GR, PO, supplier...")}
                                 value={search_filter.get()}
                                 on:input={handle_search_change}
                             />
                         </div>
                     },
                     view! {
-                        <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>"Clear"</button>
+                        <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>{t("Clear")}</button>
                     },
                 ])}
             }
 
             // Receipts table
             if loading {
-                <div class="loading-state">"Loading goods receipts..."</div>
+                <div class="loading-state">{t("Loading goods receipts...")}</div>
             } else if rows.is_empty() {
                 {empty_state(
-                    match active_tab.get().as_str() {
-                        "pending" => "No pending receipts".to_string(),
-                        "completed" => "No completed receipts".to_string(),
-                        _ => "No goods receipts found".to_string()
-                    },
-                    Some(match active_tab.get().as_str() {
-                        "pending" => "All incoming deliveries have been processed".to_string(),
-                        "completed" => "No receipts have been completed yet".to_string(),
-                        _ => "Try adjusting your filters or create a new goods receipt".to_string()
-                    }),
+                    t(match active_tab.get().as_str() {
+                        "pending" => "No pending receipts",
+                        "completed" => "No completed receipts",
+                        _ => "No goods receipts found"
+                    }).to_string(),
+                    Some(t(match active_tab.get().as_str() {
+                        "pending" => "All incoming deliveries have been processed",
+                        "completed" => "No receipts have been completed yet",
+                        _ => "Try adjusting your filters or create a new goods receipt"
+                    }).to_string()),
                     None,
-                    Some(view! { <a href="/goods-receipt/new" class="btn btn-primary">"New Receipt"</a> })
+                    Some(view! { <a href="/goods-receipt/new" class="btn btn-primary">{t("New Receipt")}</a> })
                 )}
             } else {
                 {panel(
                     format!("{} ({})",
-                        match active_tab.get().as_str() {
+                        t(match active_tab.get().as_str() {
                             "pending" => "Pending Receipts",
                             "completed" => "Completed Receipts",
                             _ => "All Receipts"
-                        },
+                        }),
                         rows.len()
                     ),
                     vec![],
@@ -536,7 +541,7 @@ fn get_inspection_badge(status: &InspectionStatus) -> View {
     };
 
     view! {
-        <span class={class}>{label}</span>
+        <span class={class}>{t(label)}</span>
     }
 }
 
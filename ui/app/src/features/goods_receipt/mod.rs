@@ -4,3 +4,4 @@ pub mod types;
 pub mod store;
 pub mod service;
 pub mod gr_list;
+pub mod gr_detail;
@@ -1,6 +1,13 @@
 //! Goods Receipt service - API calls
 
-use super::store::{GoodsReceiptStore, load_mock_receipts, get_mock_receipt};
+use crate::features::budget::service as budget;
+use crate::features::budget::types::ExpenditureSource;
+use crate::features::purchase_orders::service as purchase_orders;
+use crate::util::api::{self, HttpMethod};
+use crate::util::backend;
+use crate::util::datetime::now_iso;
+use crate::util::reference::{self, DocumentType};
+use super::store::{GoodsReceiptStore, load_mock_receipts, get_mock_receipt, get_mock_receipts_for_po};
 use super::types::{GoodsReceipt, GoodsReceiptStatus, InspectionStatus, ReceivedItem};
 
 /// Load all goods receipts
@@ -8,9 +15,7 @@ pub async fn load_receipts(store: &GoodsReceiptStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_receipts(store);
+    load_receipt_list(store).await;
 
     store.loading.set(false);
 }
@@ -20,12 +25,21 @@ pub async fn load_receipt(store: &GoodsReceiptStore, id: &str) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, get mock data
-    if let Some(receipt) = get_mock_receipt(id) {
-        store.selected.set(Some(receipt));
+    if backend::is_mock() {
+        if let Some(receipt) = get_mock_receipt(id) {
+            store.selected.set(Some(receipt));
+        } else {
+            store.selected.set(None);
+            store.error.set(Some(format!("Goods Receipt {} not found", id)));
+        }
     } else {
-        store.error.set(Some(format!("Goods Receipt {} not found", id)));
+        match api::get::<GoodsReceipt>(&format!("/goods-receipts/{}", id)).await {
+            Ok(receipt) => store.selected.set(Some(receipt)),
+            Err(e) => {
+                store.selected.set(None);
+                store.error.set(Some(e.to_string()));
+            }
+        }
     }
 
     store.loading.set(false);
@@ -34,7 +48,7 @@ pub async fn load_receipt(store: &GoodsReceiptStore, id: &str) {
 /// Create a new goods receipt
 pub async fn create_receipt(
     store: &GoodsReceiptStore,
-    receipt: GoodsReceipt,
+    mut receipt: GoodsReceipt,
 ) -> Result<String, String> {
     store.saving.set(true);
     store.error.set(None);
@@ -60,11 +74,24 @@ pub async fn create_receipt(
         return Err("Warehouse location is required".to_string());
     }
 
-    // Generate new ID
-    let new_id = format!("GR-2025-{:04}", rand_id());
+    // Provisional ID, replaced by the server's when using the REST backend
+    if backend::is_mock() && store.receipts.get().is_empty() {
+        load_mock_receipts(store);
+    }
+    let existing = store.receipts.get();
+    receipt.id = reference::issue(DocumentType::GoodsReceipt, existing.iter().map(|r| r.id.as_str())).number;
+    let label = format!("Create goods receipt {} for {}", receipt.id, receipt.po_reference.po_number);
+    match backend::create_or_queue("/goods-receipts", &receipt, &label, DocumentType::GoodsReceipt, "id").await {
+        Ok(saved) => receipt = saved,
+        Err(e) => {
+            store.saving.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+    let new_id = receipt.id.clone();
 
-    // In production, POST to API and reload list
-    load_mock_receipts(store);
+    load_receipt_list(store).await;
 
     store.saving.set(false);
     Ok(new_id)
@@ -73,7 +100,7 @@ pub async fn create_receipt(
 /// Update an existing goods receipt
 pub async fn update_receipt(
     store: &GoodsReceiptStore,
-    receipt: GoodsReceipt,
+    mut receipt: GoodsReceipt,
 ) -> Result<(), String> {
     store.saving.set(true);
     store.error.set(None);
@@ -84,9 +111,18 @@ pub async fn update_receipt(
         return Err("Goods Receipt ID is required for update".to_string());
     }
 
-    // In production, PUT to API and reload
+    let endpoint = format!("/goods-receipts/{}", receipt.id);
+    match backend::save(HttpMethod::Put, &endpoint, &receipt).await {
+        Ok(saved) => receipt = saved,
+        Err(e) => {
+            store.saving.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+
     store.selected.set(Some(receipt));
-    load_mock_receipts(store);
+    load_receipt_list(store).await;
 
     store.saving.set(false);
     Ok(())
@@ -127,17 +163,27 @@ pub async fn update_receipt_status(
             }
 
             receipt.status = new_status;
-            receipt.updated_at = chrono_now();
+            receipt.updated_at = now_iso();
 
             if new_status == GoodsReceiptStatus::Completed {
-                receipt.completed_at = Some(chrono_now());
+                receipt.completed_at = Some(now_iso());
+            }
+
+            let endpoint = format!("/goods-receipts/{}/status", receipt_id);
+            match backend::save(HttpMethod::Post, &endpoint, &receipt).await {
+                Ok(saved) => receipt = saved,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
             }
 
             store.selected.set(Some(receipt));
         }
     }
 
-    load_mock_receipts(store);
+    load_receipt_list(store).await;
     store.saving.set(false);
     Ok(())
 }
@@ -157,6 +203,7 @@ pub async fn record_item_receipt(
 
     if let Some(mut receipt) = store.selected.get() {
         if receipt.id == receipt_id {
+            let base_version = Some(receipt.updated_at.clone());
             if let Some(item) = receipt.items_received.iter_mut().find(|i| i.id == item_id) {
                 if received_quantity > item.ordered_quantity {
                     store.saving.set(false);
@@ -168,7 +215,7 @@ pub async fn record_item_receipt(
                 item.serial_numbers = serial_numbers;
                 item.storage_location = storage_location;
 
-                receipt.updated_at = chrono_now();
+                receipt.updated_at = now_iso();
 
                 // Update receipt status based on items
                 if receipt.is_fully_received() {
@@ -177,6 +224,17 @@ pub async fn record_item_receipt(
                     receipt.status = GoodsReceiptStatus::PartiallyReceived;
                 }
 
+                let endpoint = format!("/goods-receipts/{}/items", receipt_id);
+                let label = format!("Record receipt of {} item {}", receipt_id, item_id);
+                match backend::save_or_queue(HttpMethod::Post, &endpoint, &receipt, &label, base_version).await {
+                    Ok(saved) => receipt = saved,
+                    Err(e) => {
+                        store.saving.set(false);
+                        store.error.set(Some(e.to_string()));
+                        return Err(e.to_string());
+                    }
+                }
+
                 store.selected.set(Some(receipt));
             } else {
                 store.saving.set(false);
@@ -204,6 +262,7 @@ pub async fn record_inspection(
 
     if let Some(mut receipt) = store.selected.get() {
         if receipt.id == receipt_id {
+            let base_version = Some(receipt.updated_at.clone());
             if let Some(item) = receipt.items_received.iter_mut().find(|i| i.id == item_id) {
                 if accepted_quantity + rejected_quantity > item.received_quantity {
                     store.saving.set(false);
@@ -217,11 +276,22 @@ pub async fn record_inspection(
                 item.rejected_quantity = rejected_quantity;
                 item.inspection_notes = notes;
 
-                receipt.updated_at = chrono_now();
+                receipt.updated_at = now_iso();
 
                 // Update overall inspection status
                 update_overall_inspection_status(&mut receipt);
 
+                let endpoint = format!("/goods-receipts/{}/inspection", receipt_id);
+                let label = format!("Record inspection of {} item {}", receipt_id, item_id);
+                match backend::save_or_queue(HttpMethod::Post, &endpoint, &receipt, &label, base_version).await {
+                    Ok(saved) => receipt = saved,
+                    Err(e) => {
+                        store.saving.set(false);
+                        store.error.set(Some(e.to_string()));
+                        return Err(e.to_string());
+                    }
+                }
+
                 store.selected.set(Some(receipt));
             } else {
                 store.saving.set(false);
@@ -255,16 +325,43 @@ pub async fn complete_receipt(
                 ));
             }
 
+            let base_version = Some(receipt.updated_at.clone());
             receipt.status = GoodsReceiptStatus::Completed;
-            receipt.completed_at = Some(chrono_now());
+            receipt.completed_at = Some(now_iso());
             receipt.completed_by = Some(completed_by.to_string());
-            receipt.updated_at = chrono_now();
+            receipt.updated_at = now_iso();
+
+            let endpoint = format!("/goods-receipts/{}/complete", receipt_id);
+            let label = format!("Complete goods receipt {}", receipt_id);
+            match backend::save_or_queue(HttpMethod::Post, &endpoint, &receipt, &label, base_version).await {
+                Ok(saved) => receipt = saved,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
+            }
+
+            // Accepted goods become actual spend against the order's
+            // commitment, with VAT at each order line's own rate
+            let recorded = match purchase_orders::find_purchase_order(&receipt.po_reference.po_number).await {
+                Ok(po) => budget::record_expenditure(
+                    ExpenditureSource::GoodsReceipt,
+                    &receipt.id,
+                    &receipt.po_reference.po_number,
+                    receipt.accepted_value_with_vat(&po),
+                ).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = recorded {
+                store.error.set(Some(format!("Receipt completed, but the spend was not recorded: {}", e)));
+            }
 
             store.selected.set(Some(receipt));
         }
     }
 
-    load_mock_receipts(store);
+    load_receipt_list(store).await;
     store.saving.set(false);
     Ok(())
 }
@@ -291,13 +388,23 @@ pub async fn reject_receipt(
                 reason,
                 receipt.notes.map(|n| format!("\n\nPrevious notes: {}", n)).unwrap_or_default()
             ));
-            receipt.updated_at = chrono_now();
+            receipt.updated_at = now_iso();
+
+            let endpoint = format!("/goods-receipts/{}/reject", receipt_id);
+            match backend::save(HttpMethod::Post, &endpoint, &receipt).await {
+                Ok(saved) => receipt = saved,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
+            }
 
             store.selected.set(Some(receipt));
         }
     }
 
-    load_mock_receipts(store);
+    load_receipt_list(store).await;
     store.saving.set(false);
     Ok(())
 }
@@ -340,9 +447,14 @@ pub async fn delete_receipt(store: &GoodsReceiptStore, receipt_id: &str) -> Resu
         }
     }
 
-    // In production, DELETE to API
+    if let Err(e) = backend::remove(&format!("/goods-receipts/{}", receipt_id)).await {
+        store.saving.set(false);
+        store.error.set(Some(e.to_string()));
+        return Err(e.to_string());
+    }
+
     store.selected.set(None);
-    load_mock_receipts(store);
+    load_receipt_list(store).await;
 
     store.saving.set(false);
     Ok(())
@@ -356,10 +468,21 @@ pub async fn get_receipts_by_po(store: &GoodsReceiptStore, po_number: &str) {
     filter.search = Some(po_number.to_string());
     store.filter.set(filter);
 
-    load_mock_receipts(store);
+    load_receipt_list(store).await;
     store.loading.set(false);
 }
 
+/// Fetch the receipts recorded against a purchase order, with their items
+pub async fn receipts_for_po(po_number: &str) -> Result<Vec<GoodsReceipt>, String> {
+    if backend::is_mock() {
+        Ok(get_mock_receipts_for_po(po_number))
+    } else {
+        api::get::<Vec<GoodsReceipt>>(&format!("/purchase-orders/{}/goods-receipts", po_number))
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
 /// Export goods receipt as PDF
 pub async fn export_receipt_pdf(receipt_id: &str) -> Result<Vec<u8>, String> {
     // In production, this would call the API to generate PDF
@@ -374,16 +497,14 @@ pub async fn print_receipt_note(receipt_id: &str) -> Result<(), String> {
 }
 
 // Helper functions
-fn rand_id() -> u32 {
-    // Simple pseudo-random for demo
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let duration = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-    (duration.as_millis() % 10000) as u32
-}
 
-fn chrono_now() -> String {
-    // In production, use chrono crate
-    "2025-02-27T10:00:00Z".to_string()
+/// Refresh the goods receipt list from the configured backend
+async fn load_receipt_list(store: &GoodsReceiptStore) {
+    if backend::is_mock() {
+        load_mock_receipts(store);
+    } else if let Err(e) = backend::fetch_with_snapshot("/goods-receipts", &store.receipts).await {
+        store.error.set(Some(e.to_string()));
+    }
 }
 
 fn update_overall_inspection_status(receipt: &mut GoodsReceipt) {
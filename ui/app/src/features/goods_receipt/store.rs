@@ -1,6 +1,7 @@
 //! Goods Receipt store
 
 use components::prelude::*;
+use crate::util::money::Money;
 use super::types::{
     GoodsReceipt, GoodsReceiptSummary, GoodsReceiptFilter, GoodsReceiptStatus,
     InspectionStatus, ReceivedItem, GoodsReceiptSupplier, POReference,
@@ -171,7 +172,7 @@ pub fn load_mock_receipts(store: &GoodsReceiptStore) {
             supplier_name: "TechSolutions SA (Pty) Ltd".to_string(),
             supplier_bbbee_level: 1,
             total_items: 5,
-            total_value: 125_000.0,
+            total_value: Money::rands(125_000),
             receipt_date: "2025-02-27".to_string(),
             status: GoodsReceiptStatus::Pending,
             inspection_status: InspectionStatus::Pending,
@@ -184,7 +185,7 @@ pub fn load_mock_receipts(store: &GoodsReceiptStore) {
             supplier_name: "Office Pro Distributors".to_string(),
             supplier_bbbee_level: 2,
             total_items: 12,
-            total_value: 45_600.0,
+            total_value: Money::rands(45_600),
             receipt_date: "2025-02-26".to_string(),
             status: GoodsReceiptStatus::PartiallyReceived,
             inspection_status: InspectionStatus::InProgress,
@@ -197,7 +198,7 @@ pub fn load_mock_receipts(store: &GoodsReceiptStore) {
             supplier_name: "Industrial Supplies Co".to_string(),
             supplier_bbbee_level: 1,
             total_items: 8,
-            total_value: 89_200.0,
+            total_value: Money::rands(89_200),
             receipt_date: "2025-02-25".to_string(),
             status: GoodsReceiptStatus::Completed,
             inspection_status: InspectionStatus::Passed,
@@ -210,7 +211,7 @@ pub fn load_mock_receipts(store: &GoodsReceiptStore) {
             supplier_name: "SafetyFirst Equipment".to_string(),
             supplier_bbbee_level: 2,
             total_items: 3,
-            total_value: 156_800.0,
+            total_value: Money::rands(156_800),
             receipt_date: "2025-02-24".to_string(),
             status: GoodsReceiptStatus::Completed,
             inspection_status: InspectionStatus::Passed,
@@ -223,7 +224,7 @@ pub fn load_mock_receipts(store: &GoodsReceiptStore) {
             supplier_name: "Green Energy Solutions".to_string(),
             supplier_bbbee_level: 1,
             total_items: 15,
-            total_value: 234_500.0,
+            total_value: Money::rands(234_500),
             receipt_date: "2025-02-23".to_string(),
             status: GoodsReceiptStatus::Pending,
             inspection_status: InspectionStatus::Pending,
@@ -236,7 +237,7 @@ pub fn load_mock_receipts(store: &GoodsReceiptStore) {
             supplier_name: "CleanCorp Services".to_string(),
             supplier_bbbee_level: 2,
             total_items: 20,
-            total_value: 32_000.0,
+            total_value: Money::rands(32_000),
             receipt_date: "2025-02-22".to_string(),
             status: GoodsReceiptStatus::Completed,
             inspection_status: InspectionStatus::Passed,
@@ -249,7 +250,7 @@ pub fn load_mock_receipts(store: &GoodsReceiptStore) {
             supplier_name: "MedEquip Suppliers".to_string(),
             supplier_bbbee_level: 1,
             total_items: 6,
-            total_value: 445_000.0,
+            total_value: Money::rands(445_000),
             receipt_date: "2025-02-21".to_string(),
             status: GoodsReceiptStatus::PartiallyReceived,
             inspection_status: InspectionStatus::PartialPass,
@@ -262,18 +263,82 @@ pub fn load_mock_receipts(store: &GoodsReceiptStore) {
             supplier_name: "IT Hardware Direct".to_string(),
             supplier_bbbee_level: 3,
             total_items: 10,
-            total_value: 178_900.0,
+            total_value: Money::rands(178_900),
             receipt_date: "2025-02-20".to_string(),
             status: GoodsReceiptStatus::Rejected,
             inspection_status: InspectionStatus::Failed,
             received_by: "Sarah Johnson".to_string(),
             completion_percentage: 100.0,
         },
+        GoodsReceiptSummary {
+            id: "GR-2025-0081".to_string(),
+            po_number: "PO-2025-0453".to_string(),
+            supplier_name: "AutoCare Fleet Management".to_string(),
+            supplier_bbbee_level: 3,
+            total_items: 3,
+            total_value: Money::rands(84_000),
+            receipt_date: "2025-02-19".to_string(),
+            status: GoodsReceiptStatus::PartiallyReceived,
+            inspection_status: InspectionStatus::Passed,
+            received_by: "David Mthembu".to_string(),
+            completion_percentage: 73.9,
+        },
+        GoodsReceiptSummary {
+            id: "GR-2025-0080".to_string(),
+            po_number: "PO-2025-0456".to_string(),
+            supplier_name: "TechSolutions SA (Pty) Ltd".to_string(),
+            supplier_bbbee_level: 1,
+            total_items: 4,
+            total_value: Money::rands(1_250_000),
+            receipt_date: "2025-02-14".to_string(),
+            status: GoodsReceiptStatus::Completed,
+            inspection_status: InspectionStatus::Passed,
+            received_by: "Thabo Molefe".to_string(),
+            completion_percentage: 100.0,
+        },
     ];
 
     store.receipts.set(receipts);
 }
 
+/// Mock receipts with full detail
+const MOCK_DETAILED_RECEIPTS: [&str; 4] = ["GR-2025-0089", "GR-2025-0087", "GR-2025-0081", "GR-2025-0080"];
+
+/// Load mock goods receipts recorded against a purchase order
+pub fn get_mock_receipts_for_po(po_number: &str) -> Vec<GoodsReceipt> {
+    MOCK_DETAILED_RECEIPTS
+        .iter()
+        .filter_map(|id| get_mock_receipt(id))
+        .filter(|r| r.po_reference.po_number == po_number)
+        .collect()
+}
+
+fn received(
+    id: &str,
+    item_code: &str,
+    description: &str,
+    unit: &str,
+    unit_price: Money,
+    ordered_quantity: u32,
+    accepted_quantity: u32,
+) -> ReceivedItem {
+    ReceivedItem {
+        id: id.to_string(),
+        // Mock receipt items follow the order's line numbering
+        po_line_id: Some(id.replace("ITEM", "LI")),
+        item_code: item_code.to_string(),
+        description: description.to_string(),
+        ordered_quantity,
+        received_quantity: accepted_quantity,
+        accepted_quantity,
+        rejected_quantity: 0,
+        unit: unit.to_string(),
+        unit_price,
+        inspection_status: InspectionStatus::Passed,
+        ..Default::default()
+    }
+}
+
 /// Load mock goods receipt details
 pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
     match id {
@@ -284,7 +349,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                 po_date: "2025-02-20".to_string(),
                 contract_id: Some("CTR-2025-0234".to_string()),
                 delivery_date: "2025-02-27".to_string(),
-                total_value: 125_000.0,
+                total_value: Money::rands(125_000),
             },
             supplier: GoodsReceiptSupplier {
                 id: "SUP-001".to_string(),
@@ -296,6 +361,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
             items_received: vec![
                 ReceivedItem {
                     id: "ITEM-001".to_string(),
+                    po_line_id: Some("LI-001".to_string()),
                     item_code: "LAP-DEL-001".to_string(),
                     description: "Dell Latitude 5540 Laptop".to_string(),
                     ordered_quantity: 10,
@@ -303,7 +369,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                     accepted_quantity: 0,
                     rejected_quantity: 0,
                     unit: "Each".to_string(),
-                    unit_price: 18_500.0,
+                    unit_price: Money::rands(18_500),
                     inspection_status: InspectionStatus::Pending,
                     inspection_notes: None,
                     batch_number: None,
@@ -312,6 +378,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                 },
                 ReceivedItem {
                     id: "ITEM-002".to_string(),
+                    po_line_id: Some("LI-002".to_string()),
                     item_code: "MON-DEL-001".to_string(),
                     description: "Dell 27\" Monitor P2722H".to_string(),
                     ordered_quantity: 10,
@@ -319,7 +386,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                     accepted_quantity: 0,
                     rejected_quantity: 0,
                     unit: "Each".to_string(),
-                    unit_price: 5_200.0,
+                    unit_price: Money::rands(5200),
                     inspection_status: InspectionStatus::Pending,
                     inspection_notes: None,
                     batch_number: None,
@@ -328,6 +395,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                 },
                 ReceivedItem {
                     id: "ITEM-003".to_string(),
+                    po_line_id: Some("LI-003".to_string()),
                     item_code: "DOC-STN-001".to_string(),
                     description: "Dell Docking Station WD19".to_string(),
                     ordered_quantity: 10,
@@ -335,7 +403,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                     accepted_quantity: 0,
                     rejected_quantity: 0,
                     unit: "Each".to_string(),
-                    unit_price: 3_800.0,
+                    unit_price: Money::rands(3800),
                     inspection_status: InspectionStatus::Pending,
                     inspection_notes: None,
                     batch_number: None,
@@ -344,6 +412,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                 },
                 ReceivedItem {
                     id: "ITEM-004".to_string(),
+                    po_line_id: Some("LI-004".to_string()),
                     item_code: "KEY-LOG-001".to_string(),
                     description: "Logitech MX Keys Keyboard".to_string(),
                     ordered_quantity: 10,
@@ -351,7 +420,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                     accepted_quantity: 0,
                     rejected_quantity: 0,
                     unit: "Each".to_string(),
-                    unit_price: 1_850.0,
+                    unit_price: Money::rands(1850),
                     inspection_status: InspectionStatus::Pending,
                     inspection_notes: None,
                     batch_number: None,
@@ -360,6 +429,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                 },
                 ReceivedItem {
                     id: "ITEM-005".to_string(),
+                    po_line_id: Some("LI-005".to_string()),
                     item_code: "MOU-LOG-001".to_string(),
                     description: "Logitech MX Master 3 Mouse".to_string(),
                     ordered_quantity: 10,
@@ -367,7 +437,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                     accepted_quantity: 0,
                     rejected_quantity: 0,
                     unit: "Each".to_string(),
-                    unit_price: 1_650.0,
+                    unit_price: Money::rands(1650),
                     inspection_status: InspectionStatus::Pending,
                     inspection_notes: None,
                     batch_number: None,
@@ -396,7 +466,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                 po_date: "2025-02-15".to_string(),
                 contract_id: Some("CTR-2025-0156".to_string()),
                 delivery_date: "2025-02-25".to_string(),
-                total_value: 89_200.0,
+                total_value: Money::rands(89_200),
             },
             supplier: GoodsReceiptSupplier {
                 id: "SUP-003".to_string(),
@@ -408,6 +478,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
             items_received: vec![
                 ReceivedItem {
                     id: "ITEM-001".to_string(),
+                    po_line_id: Some("LI-001".to_string()),
                     item_code: "PPE-HEL-001".to_string(),
                     description: "Safety Helmet - White".to_string(),
                     ordered_quantity: 100,
@@ -415,7 +486,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                     accepted_quantity: 100,
                     rejected_quantity: 0,
                     unit: "Each".to_string(),
-                    unit_price: 185.0,
+                    unit_price: Money::rands(185),
                     inspection_status: InspectionStatus::Passed,
                     inspection_notes: Some("All items meet SABS standards".to_string()),
                     batch_number: Some("BATCH-2025-001".to_string()),
@@ -424,6 +495,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                 },
                 ReceivedItem {
                     id: "ITEM-002".to_string(),
+                    po_line_id: Some("LI-002".to_string()),
                     item_code: "PPE-GLV-001".to_string(),
                     description: "Safety Gloves - Large".to_string(),
                     ordered_quantity: 200,
@@ -431,7 +503,7 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
                     accepted_quantity: 200,
                     rejected_quantity: 0,
                     unit: "Pair".to_string(),
-                    unit_price: 125.0,
+                    unit_price: Money::rands(125),
                     inspection_status: InspectionStatus::Passed,
                     inspection_notes: Some("All items meet quality standards".to_string()),
                     batch_number: Some("BATCH-2025-002".to_string()),
@@ -457,6 +529,77 @@ pub fn get_mock_receipt(id: &str) -> Option<GoodsReceipt> {
             completed_at: Some("2025-02-25T14:45:00Z".to_string()),
             completed_by: Some("Mike Wilson".to_string()),
         }),
+        "GR-2025-0081" => Some(GoodsReceipt {
+            id: "GR-2025-0081".to_string(),
+            po_reference: POReference {
+                po_number: "PO-2025-0453".to_string(),
+                po_date: "2025-02-10".to_string(),
+                contract_id: Some("CTR-2025-0089".to_string()),
+                delivery_date: "2025-02-28".to_string(),
+                total_value: Money::rands(131_560),
+            },
+            supplier: GoodsReceiptSupplier {
+                id: "SUP-004".to_string(),
+                name: "AutoCare Fleet Management".to_string(),
+                contact_person: Some("Pieter van der Berg".to_string()),
+                contact_phone: Some("+27 11 555 4567".to_string()),
+                bbbee_level: 3,
+            },
+            items_received: vec![
+                received("ITEM-001", "SVC-FSC-001", "Full Vehicle Service - Sedan", "Service", Money::rands(4500), 10, 6),
+                received("ITEM-002", "SVC-FSC-002", "Full Vehicle Service - SUV", "Service", Money::rands(6200), 5, 3),
+                received("ITEM-003", "PRT-TYR-001", "Replacement Tyres (Set of 4)", "Set", Money::rands(4800), 8, 8),
+            ],
+            inspection_status: InspectionStatus::Passed,
+            received_by: "David Mthembu".to_string(),
+            status: GoodsReceiptStatus::PartiallyReceived,
+            receipt_date: "2025-02-19".to_string(),
+            delivery_note_number: Some("ACF-JC-3391".to_string()),
+            invoice_number: None,
+            warehouse_location: "Government Fleet Depot".to_string(),
+            notes: Some("Remaining services scheduled for the week of 24 February".to_string()),
+            documents: vec!["job_cards.pdf".to_string()],
+            created_at: "2025-02-19T15:10:00Z".to_string(),
+            updated_at: "2025-02-19T15:10:00Z".to_string(),
+            completed_at: None,
+            completed_by: None,
+        }),
+        "GR-2025-0080" => Some(GoodsReceipt {
+            id: "GR-2025-0080".to_string(),
+            po_reference: POReference {
+                po_number: "PO-2025-0456".to_string(),
+                po_date: "2025-02-01".to_string(),
+                contract_id: Some("CTR-2025-0234".to_string()),
+                delivery_date: "2025-02-15".to_string(),
+                total_value: Money::rands(1_437_500),
+            },
+            supplier: GoodsReceiptSupplier {
+                id: "SUP-001".to_string(),
+                name: "TechSolutions SA (Pty) Ltd".to_string(),
+                contact_person: Some("James Ndlovu".to_string()),
+                contact_phone: Some("+27 11 555 1234".to_string()),
+                bbbee_level: 1,
+            },
+            items_received: vec![
+                received("ITEM-001", "HW-SVR-001", "Dell PowerEdge R750 Server", "Each", Money::rands(185_000), 5, 5),
+                received("ITEM-002", "HW-STO-001", "NetApp Storage Array", "Each", Money::rands(95_000), 2, 2),
+                received("ITEM-003", "SW-LIC-001", "VMware vSphere Enterprise Plus License", "License", Money::rands(8500), 10, 10),
+                received("ITEM-004", "SVC-INS-001", "Installation and Configuration Services", "Hours", Money::rands(1250), 40, 40),
+            ],
+            inspection_status: InspectionStatus::Passed,
+            received_by: "Thabo Molefe".to_string(),
+            status: GoodsReceiptStatus::Completed,
+            receipt_date: "2025-02-14".to_string(),
+            delivery_note_number: Some("DN-TS-88412".to_string()),
+            invoice_number: Some("TS-INV-20931".to_string()),
+            warehouse_location: "Government Data Centre - Block B".to_string(),
+            notes: Some("Installation signed off by the data centre manager".to_string()),
+            documents: vec!["delivery_note.pdf".to_string(), "installation_signoff.pdf".to_string()],
+            created_at: "2025-02-14T09:00:00Z".to_string(),
+            updated_at: "2025-02-14T16:30:00Z".to_string(),
+            completed_at: Some("2025-02-14T16:30:00Z".to_string()),
+            completed_by: Some("Thabo Molefe".to_string()),
+        }),
         _ => None,
     }
 }
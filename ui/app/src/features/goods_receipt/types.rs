@@ -1,6 +1,8 @@
 //! Goods Receipt domain types
 
 use serde::{Deserialize, Serialize};
+use crate::features::purchase_orders::types::PurchaseOrder;
+use crate::util::money::{Money, VAT_RATE};
 
 /// Inspection status for goods received
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -90,6 +92,9 @@ impl Default for GoodsReceiptStatus {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ReceivedItem {
     pub id: String,
+    /// Purchase order line this item was delivered against
+    #[serde(default)]
+    pub po_line_id: Option<String>,
     pub item_code: String,
     pub description: String,
     pub ordered_quantity: u32,
@@ -97,7 +102,7 @@ pub struct ReceivedItem {
     pub accepted_quantity: u32,
     pub rejected_quantity: u32,
     pub unit: String,
-    pub unit_price: f64,
+    pub unit_price: Money,
     pub inspection_status: InspectionStatus,
     pub inspection_notes: Option<String>,
     pub batch_number: Option<String>,
@@ -109,6 +114,7 @@ impl Default for ReceivedItem {
     fn default() -> Self {
         Self {
             id: String::new(),
+            po_line_id: None,
             item_code: String::new(),
             description: String::new(),
             ordered_quantity: 0,
@@ -116,7 +122,7 @@ impl Default for ReceivedItem {
             accepted_quantity: 0,
             rejected_quantity: 0,
             unit: "Each".to_string(),
-            unit_price: 0.0,
+            unit_price: Money::ZERO,
             inspection_status: InspectionStatus::Pending,
             inspection_notes: None,
             batch_number: None,
@@ -137,13 +143,13 @@ impl ReceivedItem {
     }
 
     /// Calculate total value of received items
-    pub fn received_value(&self) -> f64 {
-        self.received_quantity as f64 * self.unit_price
+    pub fn received_value(&self) -> Money {
+        self.unit_price * self.received_quantity
     }
 
     /// Calculate total value of accepted items
-    pub fn accepted_value(&self) -> f64 {
-        self.accepted_quantity as f64 * self.unit_price
+    pub fn accepted_value(&self) -> Money {
+        self.unit_price * self.accepted_quantity
     }
 }
 
@@ -176,7 +182,7 @@ pub struct POReference {
     pub po_date: String,
     pub contract_id: Option<String>,
     pub delivery_date: String,
-    pub total_value: f64,
+    pub total_value: Money,
 }
 
 impl Default for POReference {
@@ -186,7 +192,7 @@ impl Default for POReference {
             po_date: String::new(),
             contract_id: None,
             delivery_date: String::new(),
-            total_value: 0.0,
+            total_value: Money::ZERO,
         }
     }
 }
@@ -239,15 +245,27 @@ impl Default for GoodsReceipt {
 
 impl GoodsReceipt {
     /// Calculate total received value
-    pub fn total_received_value(&self) -> f64 {
+    pub fn total_received_value(&self) -> Money {
         self.items_received.iter().map(|i| i.received_value()).sum()
     }
 
     /// Calculate total accepted value
-    pub fn total_accepted_value(&self) -> f64 {
+    pub fn total_accepted_value(&self) -> Money {
         self.items_received.iter().map(|i| i.accepted_value()).sum()
     }
 
+    /// Accepted value with VAT at the rate of the order line each item was
+    /// delivered against; items not on the order carry standard VAT
+    pub fn accepted_value_with_vat(&self, po: &PurchaseOrder) -> Money {
+        self.items_received
+            .iter()
+            .map(|i| {
+                let rate = po.line_for(i.po_line_id.as_deref(), &i.item_code).map_or(VAT_RATE, |l| l.tax_rate);
+                i.accepted_value().with_vat(rate)
+            })
+            .sum()
+    }
+
     /// Get receipt completion percentage
     pub fn completion_percentage(&self) -> f64 {
         if self.items_received.is_empty() {
@@ -311,7 +329,7 @@ pub struct GoodsReceiptSummary {
     pub supplier_name: String,
     pub supplier_bbbee_level: u8,
     pub total_items: usize,
-    pub total_value: f64,
+    pub total_value: Money,
     pub receipt_date: String,
     pub status: GoodsReceiptStatus,
     pub inspection_status: InspectionStatus,
@@ -17,6 +17,7 @@ use super::types::{
     ControlEffectiveness, ControlOperatingStatus,
 };
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// GRC Dashboard page
 #[component]
@@ -86,12 +87,12 @@ pub fn grc_dashboard() -> View {
             color: Some("var(--green)".to_string()),
         },
         BarChartData {
-            label: "B-BBEE".to_string(),
+            label: t("B-BBEE").to_string(),
             value: checks.iter().filter(|c| c.category == ComplianceCategory::BBBEE && c.status == ComplianceStatus::Compliant).count() as f64,
             color: Some("var(--green)".to_string()),
         },
         BarChartData {
-            label: "Treasury".to_string(),
+            label: t("Treasury").to_string(),
             value: checks.iter().filter(|c| c.category == ComplianceCategory::Treasury && c.status == ComplianceStatus::Compliant).count() as f64,
             color: Some("var(--green)".to_string()),
         },
@@ -104,31 +105,31 @@ pub fn grc_dashboard() -> View {
 
     // Risk distribution pie chart
     let risk_distribution: Vec<PieChartData> = vec![
-        PieChartData { label: "Low".to_string(), value: kpi_data.low_risks as f64, color: "var(--green)".to_string() },
-        PieChartData { label: "Medium".to_string(), value: kpi_data.medium_risks as f64, color: "var(--orange)".to_string() },
-        PieChartData { label: "High".to_string(), value: kpi_data.high_risks as f64, color: "var(--red)".to_string() },
-        PieChartData { label: "Extreme".to_string(), value: kpi_data.extreme_risks as f64, color: "#8b0000".to_string() },
+        PieChartData { label: t("Low").to_string(), value: kpi_data.low_risks as f64, color: "var(--green)".to_string() },
+        PieChartData { label: t("Medium").to_string(), value: kpi_data.medium_risks as f64, color: "var(--orange)".to_string() },
+        PieChartData { label: t("High").to_string(), value: kpi_data.high_risks as f64, color: "var(--red)".to_string() },
+        PieChartData { label: t("Extreme").to_string(), value: kpi_data.extreme_risks as f64, color: "#8b0000".to_string() },
     ];
 
     // Control effectiveness pie chart
     let control_effectiveness: Vec<PieChartData> = vec![
         PieChartData {
-            label: "Effective".to_string(),
+            label: t("Effective").to_string(),
             value: ctrl_list.iter().filter(|c| c.effectiveness == ControlEffectiveness::Effective).count() as f64,
             color: "var(--green)".to_string(),
         },
         PieChartData {
-            label: "Partially".to_string(),
+            label: t("Partially").to_string(),
             value: ctrl_list.iter().filter(|c| c.effectiveness == ControlEffectiveness::PartiallyEffective).count() as f64,
             color: "var(--orange)".to_string(),
         },
         PieChartData {
-            label: "Ineffective".to_string(),
+            label: t("Ineffective").to_string(),
             value: ctrl_list.iter().filter(|c| c.effectiveness == ControlEffectiveness::Ineffective).count() as f64,
             color: "var(--red)".to_string(),
         },
         PieChartData {
-            label: "Not Tested".to_string(),
+            label: t("Not Tested").to_string(),
             value: ctrl_list.iter().filter(|c| c.effectiveness == ControlEffectiveness::NotTested).count() as f64,
             color: "var(--text-muted)".to_string(),
         },
@@ -137,27 +138,27 @@ pub fn grc_dashboard() -> View {
     // Violations by type
     let violations_by_type: Vec<BarChartData> = vec![
         BarChartData {
-            label: "Threshold".to_string(),
+            label: t("Threshold").to_string(),
             value: violations.iter().filter(|v| v.violation_type == ViolationType::Threshold).count() as f64,
             color: Some("var(--red)".to_string()),
         },
         BarChartData {
-            label: "Conflict".to_string(),
+            label: t("Conflict").to_string(),
             value: violations.iter().filter(|v| v.violation_type == ViolationType::Conflict).count() as f64,
             color: Some("var(--red)".to_string()),
         },
         BarChartData {
-            label: "Documentation".to_string(),
+            label: t("Documentation").to_string(),
             value: violations.iter().filter(|v| v.violation_type == ViolationType::Documentation).count() as f64,
             color: Some("var(--orange)".to_string()),
         },
         BarChartData {
-            label: "Authorization".to_string(),
+            label: t("Authorization").to_string(),
             value: violations.iter().filter(|v| v.violation_type == ViolationType::Authorization).count() as f64,
             color: Some("var(--orange)".to_string()),
         },
         BarChartData {
-            label: "Segregation".to_string(),
+            label: t("Segregation").to_string(),
             value: violations.iter().filter(|v| v.violation_type == ViolationType::Segregation).count() as f64,
             color: Some("var(--purple)".to_string()),
         },
@@ -165,11 +166,11 @@ pub fn grc_dashboard() -> View {
 
     // Compliance table columns
     let compliance_columns = vec![
-        DataTableColumn { key: "name".to_string(), label: "Compliance Check".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "category".to_string(), label: "Category".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "score".to_string(), label: "Score".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "next_review".to_string(), label: "Next Review".to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "name".to_string(), label: t("Compliance Check").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "category".to_string(), label: t("Category").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "score".to_string(), label: t("Score").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "next_review".to_string(), label: t("Next Review").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
     ];
 
     // Transform compliance to table rows
@@ -185,8 +186,8 @@ pub fn grc_dashboard() -> View {
         let category_tag = match check.category {
             ComplianceCategory::PFMA => tag("PFMA".to_string(), TagType::Blue),
             ComplianceCategory::SCM => tag("SCM".to_string(), TagType::Green),
-            ComplianceCategory::BBBEE => tag("B-BBEE".to_string(), TagType::Orange),
-            ComplianceCategory::Treasury => tag("Treasury".to_string(), TagType::Purple),
+            ComplianceCategory::BBBEE => tag(t("B-BBEE").to_string(), TagType::Orange),
+            ComplianceCategory::Treasury => tag(t("Treasury").to_string(), TagType::Purple),
             ComplianceCategory::DataProtection => tag("POPIA".to_string(), TagType::Cyan),
             _ => tag(check.category.label().to_string(), TagType::Default),
         };
@@ -231,44 +232,44 @@ pub fn grc_dashboard() -> View {
 
     // Risk table columns
     let risk_columns = vec![
-        DataTableColumn { key: "name".to_string(), label: "Risk".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "category".to_string(), label: "Category".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "inherent".to_string(), label: "Inherent".to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "residual".to_string(), label: "Residual".to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "trend".to_string(), label: "Trend".to_string(), width: Some("90px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "owner".to_string(), label: "Owner".to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "name".to_string(), label: t("Risk").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "category".to_string(), label: t("Category").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "inherent".to_string(), label: t("Inherent").to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "residual".to_string(), label: t("Residual").to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "trend".to_string(), label: t("Trend").to_string(), width: Some("90px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "owner".to_string(), label: t("Owner").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
     ];
 
     // Transform risks to table rows
     let risk_rows: Vec<DataTableRow> = risks.iter().map(|risk| {
         let category_tag = match risk.risk_category {
-            RiskCategory::Financial => tag("Financial".to_string(), TagType::Blue),
-            RiskCategory::Fraud => tag("Fraud".to_string(), TagType::Red),
-            RiskCategory::Compliance => tag("Compliance".to_string(), TagType::Orange),
-            RiskCategory::SupplyChain => tag("Supply Chain".to_string(), TagType::Green),
-            RiskCategory::Cybersecurity => tag("Cyber".to_string(), TagType::Purple),
-            RiskCategory::Reputational => tag("Reputation".to_string(), TagType::Cyan),
+            RiskCategory::Financial => tag(t("Financial").to_string(), TagType::Blue),
+            RiskCategory::Fraud => tag(t("Fraud").to_string(), TagType::Red),
+            RiskCategory::Compliance => tag(t("Compliance").to_string(), TagType::Orange),
+            RiskCategory::SupplyChain => tag(t("Supply Chain").to_string(), TagType::Green),
+            RiskCategory::Cybersecurity => tag(t("Cyber").to_string(), TagType::Purple),
+            RiskCategory::Reputational => tag(t("Reputation").to_string(), TagType::Cyan),
             _ => tag(risk.risk_category.label().to_string(), TagType::Default),
         };
 
         let inherent_tag = match risk.inherent_risk {
-            RiskLevel::Extreme => tag("Extreme".to_string(), TagType::Red),
-            RiskLevel::High => tag("High".to_string(), TagType::Red),
-            RiskLevel::Medium => tag("Medium".to_string(), TagType::Orange),
-            RiskLevel::Low => tag("Low".to_string(), TagType::Green),
+            RiskLevel::Extreme => tag(t("Extreme").to_string(), TagType::Red),
+            RiskLevel::High => tag(t("High").to_string(), TagType::Red),
+            RiskLevel::Medium => tag(t("Medium").to_string(), TagType::Orange),
+            RiskLevel::Low => tag(t("Low").to_string(), TagType::Green),
         };
 
         let residual_tag = match risk.residual_risk {
-            RiskLevel::Extreme => tag("Extreme".to_string(), TagType::Red),
-            RiskLevel::High => tag("High".to_string(), TagType::Red),
-            RiskLevel::Medium => tag("Medium".to_string(), TagType::Orange),
-            RiskLevel::Low => tag("Low".to_string(), TagType::Green),
+            RiskLevel::Extreme => tag(t("Extreme").to_string(), TagType::Red),
+            RiskLevel::High => tag(t("High").to_string(), TagType::Red),
+            RiskLevel::Medium => tag(t("Medium").to_string(), TagType::Orange),
+            RiskLevel::Low => tag(t("Low").to_string(), TagType::Green),
         };
 
         let trend_icon = match risk.trend {
-            RiskTrend::Increasing => view! { <span class="trend-up" title="Increasing">"↑"</span> },
-            RiskTrend::Stable => view! { <span class="trend-stable" title="Stable">"→"</span> },
-            RiskTrend::Decreasing => view! { <span class="trend-down" title="Decreasing">"↓"</span> },
+            RiskTrend::Increasing => view! { <span class="trend-up" title={t("Increasing")}>"↑"</span> },
+            RiskTrend::Stable => view! { <span class="trend-stable" title={t("Stable")}>"→"</span> },
+            RiskTrend::Decreasing => view! { <span class="trend-down" title={t("Decreasing")}>"↓"</span> },
         };
 
         DataTableRow {
@@ -292,30 +293,30 @@ pub fn grc_dashboard() -> View {
     // Violations table columns
     let violation_columns = vec![
         DataTableColumn { key: "id".to_string(), label: "ID".to_string(), width: Some("90px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "policy".to_string(), label: "Policy".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "type".to_string(), label: "Type".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "severity".to_string(), label: "Severity".to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "impact".to_string(), label: "Financial Impact".to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "policy".to_string(), label: t("Policy").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "type".to_string(), label: t("Type").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "severity".to_string(), label: t("Severity").to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "impact".to_string(), label: t("Financial Impact").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: None },
     ];
 
     // Transform violations to table rows
     let violation_rows: Vec<DataTableRow> = violations.iter().map(|violation| {
         let type_tag = match violation.violation_type {
-            ViolationType::Threshold => tag("Threshold".to_string(), TagType::Red),
-            ViolationType::Conflict => tag("Conflict".to_string(), TagType::Red),
-            ViolationType::Documentation => tag("Docs".to_string(), TagType::Orange),
-            ViolationType::Authorization => tag("Auth".to_string(), TagType::Orange),
-            ViolationType::Segregation => tag("SoD".to_string(), TagType::Purple),
+            ViolationType::Threshold => tag(t("Threshold").to_string(), TagType::Red),
+            ViolationType::Conflict => tag(t("Conflict").to_string(), TagType::Red),
+            ViolationType::Documentation => tag(t("Docs").to_string(), TagType::Orange),
+            ViolationType::Authorization => tag(t("Auth").to_string(), TagType::Orange),
+            ViolationType::Segregation => tag(t("SoD").to_string(), TagType::Purple),
             _ => tag(violation.violation_type.label().to_string(), TagType::Default),
         };
 
         let severity_tag = match violation.severity {
-            Severity::Critical => tag("Critical".to_string(), TagType::Red),
-            Severity::High => tag("High".to_string(), TagType::Red),
-            Severity::Medium => tag("Medium".to_string(), TagType::Orange),
-            Severity::Low => tag("Low".to_string(), TagType::Blue),
-            Severity::Informational => tag("Info".to_string(), TagType::Default),
+            Severity::Critical => tag(t("Critical").to_string(), TagType::Red),
+            Severity::High => tag(t("High").to_string(), TagType::Red),
+            Severity::Medium => tag(t("Medium").to_string(), TagType::Orange),
+            Severity::Low => tag(t("Low").to_string(), TagType::Blue),
+            Severity::Informational => tag(t("Info").to_string(), TagType::Default),
         };
 
         let status = match violation.status {
@@ -351,28 +352,28 @@ pub fn grc_dashboard() -> View {
 
     // Controls table columns
     let control_columns = vec![
-        DataTableColumn { key: "name".to_string(), label: "Control".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "type".to_string(), label: "Type".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "effectiveness".to_string(), label: "Effectiveness".to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "score".to_string(), label: "Score".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Operating".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "last_tested".to_string(), label: "Last Test".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "name".to_string(), label: t("Control").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "type".to_string(), label: t("Type").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "effectiveness".to_string(), label: t("Effectiveness").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "score".to_string(), label: t("Score").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Operating").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "last_tested".to_string(), label: t("Last Test").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
     ];
 
     // Transform controls to table rows
     let control_rows: Vec<DataTableRow> = ctrl_list.iter().map(|control| {
         let type_tag = match control.control_type {
-            crate::features::grc::types::ControlType::Preventive => tag("Preventive".to_string(), TagType::Green),
-            crate::features::grc::types::ControlType::Detective => tag("Detective".to_string(), TagType::Blue),
-            crate::features::grc::types::ControlType::Corrective => tag("Corrective".to_string(), TagType::Orange),
-            crate::features::grc::types::ControlType::Directive => tag("Directive".to_string(), TagType::Purple),
+            crate::features::grc::types::ControlType::Preventive => tag(t("Preventive").to_string(), TagType::Green),
+            crate::features::grc::types::ControlType::Detective => tag(t("Detective").to_string(), TagType::Blue),
+            crate::features::grc::types::ControlType::Corrective => tag(t("Corrective").to_string(), TagType::Orange),
+            crate::features::grc::types::ControlType::Directive => tag(t("Directive").to_string(), TagType::Purple),
         };
 
         let effectiveness_tag = match control.effectiveness {
-            ControlEffectiveness::Effective => tag("Effective".to_string(), TagType::Green),
-            ControlEffectiveness::PartiallyEffective => tag("Partial".to_string(), TagType::Orange),
-            ControlEffectiveness::Ineffective => tag("Ineffective".to_string(), TagType::Red),
-            ControlEffectiveness::NotTested => tag("Not Tested".to_string(), TagType::Default),
+            ControlEffectiveness::Effective => tag(t("Effective").to_string(), TagType::Green),
+            ControlEffectiveness::PartiallyEffective => tag(t("Partial").to_string(), TagType::Orange),
+            ControlEffectiveness::Ineffective => tag(t("Ineffective").to_string(), TagType::Red),
+            ControlEffectiveness::NotTested => tag(t("Not Tested").to_string(), TagType::Default),
         };
 
         let status = match control.status {
@@ -397,7 +398,7 @@ pub fn grc_dashboard() -> View {
                 view! {
                     <div class="control-info">
                         <span class="control-name">{control.name.clone()}</span>
-                        <span class="control-owner">{format!("Owner: {}", control.owner.clone())}</span>
+                        <span class="control-owner">{tf("Owner: {owner}", &[("owner", control.owner.clone())])}</span>
                     </div>
                 },
                 type_tag,
@@ -440,7 +441,7 @@ pub fn grc_dashboard() -> View {
     let high_extreme_risks = kpi_data.high_risks + kpi_data.extreme_risks;
     let risks_kpi_color = if high_extreme_risks == 0 { KpiColor::Green } else { KpiColor::Orange };
     let risks_is_positive = if high_extreme_risks > 0 { Some(false) } else { None };
-    let high_extreme_risks_str = format!("{} high/extreme", high_extreme_risks);
+    let high_extreme_risks_str = tn("{n} high/extreme", "{n} high/extreme", high_extreme_risks as i64);
 
     let violations_kpi_color = if kpi_data.critical_violations > 0 {
         KpiColor::Red
@@ -627,11 +628,11 @@ pub fn grc_dashboard() -> View {
 
         <div class="grc-dashboard" data-testid="grc-dashboard">
             {page_header(
-                "GRC Dashboard".to_string(),
-                Some("Governance, Risk Management, and Compliance Overview".to_string()),
+                t("GRC Dashboard").to_string(),
+                Some(t("Governance, Risk Management, and Compliance Overview").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export Report"</button> },
-                    view! { <button class="btn btn-primary">"Run Assessment"</button> },
+                    view! { <button class="btn btn-secondary">{t("Export Report")}</button> },
+                    view! { <button class="btn btn-primary">{t("Run Assessment")}</button> },
                 ]
             )}
 
@@ -640,23 +641,23 @@ pub fn grc_dashboard() -> View {
                 <button
                     class={if active_tab.get() == "overview" { "tab-button active" } else { "tab-button" }}
                     on:click={set_tab_overview}
-                >"Overview"</button>
+                >{t("Overview")}</button>
                 <button
                     class={if active_tab.get() == "compliance" { "tab-button active" } else { "tab-button" }}
                     on:click={set_tab_compliance}
-                >"Compliance"</button>
+                >{t("Compliance")}</button>
                 <button
                     class={if active_tab.get() == "risks" { "tab-button active" } else { "tab-button" }}
                     on:click={set_tab_risks}
-                >"Risks"</button>
+                >{t("Risks")}</button>
                 <button
                     class={if active_tab.get() == "violations" { "tab-button active" } else { "tab-button" }}
                     on:click={set_tab_violations}
-                >"Violations"</button>
+                >{t("Violations")}</button>
                 <button
                     class={if active_tab.get() == "controls" { "tab-button active" } else { "tab-button" }}
                     on:click={set_tab_controls}
-                >"Controls"</button>
+                >{t("Controls")}</button>
             </div>
 
             // Overview tab content
@@ -665,19 +666,19 @@ pub fn grc_dashboard() -> View {
                     // KPI summary
                     <div class="kpi-grid">
                         {kpi_card(
-                            "Compliance Score".to_string(),
+                            t("Compliance Score").to_string(),
                             format!("{:.0}%", kpi_data.compliance_score),
                             compliance_kpi_color,
                             icon_check.to_string(),
                             Some(KpiDelta {
-                                value: format!("{} checks", kpi_data.total_checks),
+                                value: tn("{n} check", "{n} checks", kpi_data.total_checks as i64),
                                 is_positive: None,
-                                suffix: "assessed".to_string(),
+                                suffix: t("assessed").to_string(),
                             }),
                             None
                         )}
                         {kpi_card(
-                            "Active Risks".to_string(),
+                            t("Active Risks").to_string(),
                             format!("{}", kpi_data.total_risks),
                             risks_kpi_color,
                             icon_alert.to_string(),
@@ -689,24 +690,24 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Open Violations".to_string(),
+                            t("Open Violations").to_string(),
                             format!("{}", kpi_data.open_violations),
                             violations_kpi_color,
                             icon_warning.to_string(),
                             Some(KpiDelta {
-                                value: format!("{} critical", kpi_data.critical_violations),
+                                value: tn("{n} critical", "{n} critical", kpi_data.critical_violations as i64),
                                 is_positive: violations_is_positive,
                                 suffix: "".to_string(),
                             }),
                             None
                         )}
                         {kpi_card(
-                            "Control Effectiveness".to_string(),
+                            t("Control Effectiveness").to_string(),
                             format!("{:.0}%", kpi_data.control_coverage),
                             controls_kpi_color,
                             icon_settings.to_string(),
                             Some(KpiDelta {
-                                value: format!("{} ineffective", kpi_data.ineffective_controls),
+                                value: tn("{n} ineffective", "{n} ineffective", kpi_data.ineffective_controls as i64),
                                 is_positive: controls_is_positive,
                                 suffix: "".to_string(),
                             }),
@@ -718,21 +719,21 @@ pub fn grc_dashboard() -> View {
                     <div class="charts-grid">
                         // Risk Distribution
                         {panel(
-                            "Risk Distribution".to_string(),
+                            t("Risk Distribution").to_string(),
                             vec![],
                             vec![pie_chart(risk_distribution.clone(), None)]
                         )}
 
                         // Control Effectiveness
                         {panel(
-                            "Control Effectiveness".to_string(),
+                            t("Control Effectiveness").to_string(),
                             vec![],
                             vec![pie_chart(control_effectiveness.clone(), None)]
                         )}
 
                         // Violations by Type
                         {panel(
-                            "Violations by Type".to_string(),
+                            t("Violations by Type").to_string(),
                             vec![],
                             vec![bar_chart(violations_by_type.clone(), Some(180))]
                         )}
@@ -741,7 +742,7 @@ pub fn grc_dashboard() -> View {
                     // Risk Matrix
                     <div class="grid-2">
                         {panel(
-                            "Risk Heat Map".to_string(),
+                            t("Risk Heat Map").to_string(),
                             vec![],
                             vec![{
                                 // Pre-compute all matrix cell values before the view! block
@@ -786,14 +787,14 @@ pub fn grc_dashboard() -> View {
                                     <div class="risk-matrix">
                                         // Header row
                                         <div class="matrix-header"></div>
-                                        <div class="matrix-header">"Insignificant"</div>
-                                        <div class="matrix-header">"Minor"</div>
-                                        <div class="matrix-header">"Moderate"</div>
-                                        <div class="matrix-header">"Major"</div>
-                                        <div class="matrix-header">"Catastrophic"</div>
+                                        <div class="matrix-header">{t("Insignificant")}</div>
+                                        <div class="matrix-header">{t("Minor")}</div>
+                                        <div class="matrix-header">{t("Moderate")}</div>
+                                        <div class="matrix-header">{t("Major")}</div>
+                                        <div class="matrix-header">{t("Catastrophic")}</div>
 
                                         // Almost Certain row
-                                        <div class="matrix-row-header">"Almost Certain"</div>
+                                        <div class="matrix-row-header">{t("Almost Certain")}</div>
                                         <div class="matrix-cell medium">{ac_insig}</div>
                                         <div class="matrix-cell high">{ac_minor}</div>
                                         <div class="matrix-cell high">{ac_mod}</div>
@@ -801,7 +802,7 @@ pub fn grc_dashboard() -> View {
                                         <div class="matrix-cell extreme">{ac_cat}</div>
 
                                         // Likely row
-                                        <div class="matrix-row-header">"Likely"</div>
+                                        <div class="matrix-row-header">{t("Likely")}</div>
                                         <div class="matrix-cell low">{li_insig}</div>
                                         <div class="matrix-cell medium">{li_minor}</div>
                                         <div class="matrix-cell high">{li_mod}</div>
@@ -809,7 +810,7 @@ pub fn grc_dashboard() -> View {
                                         <div class="matrix-cell extreme">{li_cat}</div>
 
                                         // Possible row
-                                        <div class="matrix-row-header">"Possible"</div>
+                                        <div class="matrix-row-header">{t("Possible")}</div>
                                         <div class="matrix-cell low">{po_insig}</div>
                                         <div class="matrix-cell medium">{po_minor}</div>
                                         <div class="matrix-cell medium">{po_mod}</div>
@@ -817,7 +818,7 @@ pub fn grc_dashboard() -> View {
                                         <div class="matrix-cell high">{po_cat}</div>
 
                                         // Unlikely row
-                                        <div class="matrix-row-header">"Unlikely"</div>
+                                        <div class="matrix-row-header">{t("Unlikely")}</div>
                                         <div class="matrix-cell low">{un_insig}</div>
                                         <div class="matrix-cell low">{un_minor}</div>
                                         <div class="matrix-cell medium">{un_mod}</div>
@@ -825,7 +826,7 @@ pub fn grc_dashboard() -> View {
                                         <div class="matrix-cell high">{un_cat}</div>
 
                                         // Rare row
-                                        <div class="matrix-row-header">"Rare"</div>
+                                        <div class="matrix-row-header">{t("Rare")}</div>
                                         <div class="matrix-cell low">{ra_insig}</div>
                                         <div class="matrix-cell low">{ra_minor}</div>
                                         <div class="matrix-cell low">{ra_mod}</div>
@@ -838,7 +839,7 @@ pub fn grc_dashboard() -> View {
 
                         // Critical Alerts
                         {panel(
-                            "Critical Alerts".to_string(),
+                            t("Critical Alerts").to_string(),
                             vec![],
                             vec![{
                                 let critical_items: Vec<_> = violations.iter()
@@ -870,12 +871,12 @@ pub fn grc_dashboard() -> View {
                                                 </div>
                                                 <div class="alert-content">
                                                     <div class="alert-title">{check.name.clone()}</div>
-                                                    <div class="alert-desc">"Non-compliant - requires immediate attention"</div>
+                                                    <div class="alert-desc">{t("Non-compliant - requires immediate attention")}</div>
                                                 </div>
                                             </div>
                                         }
                                         if critical_items.is_empty() && non_compliant.is_empty() {
-                                            <div class="loading-overlay">"No critical alerts at this time"</div>
+                                            <div class="loading-overlay">{t("No critical alerts at this time")}</div>
                                         }
                                     </div>
                                 }
@@ -891,7 +892,7 @@ pub fn grc_dashboard() -> View {
                     // Compliance overview cards
                     <div class="kpi-grid">
                         {kpi_card(
-                            "Total Checks".to_string(),
+                            t("Total Checks").to_string(),
                             format!("{}", kpi_data.total_checks),
                             KpiColor::Blue,
                             icon_check.to_string(),
@@ -899,7 +900,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Compliant".to_string(),
+                            t("Compliant").to_string(),
                             format!("{}", kpi_data.compliant_checks),
                             KpiColor::Green,
                             icon_shield.to_string(),
@@ -907,7 +908,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Non-Compliant".to_string(),
+                            t("Non-Compliant").to_string(),
                             format!("{}", kpi_data.non_compliant_checks),
                             KpiColor::Red,
                             icon_alert.to_string(),
@@ -915,7 +916,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Pending Review".to_string(),
+                            t("Pending Review").to_string(),
                             format!("{}", kpi_data.pending_reviews),
                             KpiColor::Orange,
                             icon_warning.to_string(),
@@ -926,11 +927,11 @@ pub fn grc_dashboard() -> View {
 
                     // Compliance table
                     {panel(
-                        format!("Compliance Checks ({} total)", checks.len()),
-                        vec![view! { <button class="btn btn-sm btn-primary">"New Assessment"</button> }],
+                        tf("Compliance Checks ({count} total)", &[("count", checks.len().to_string())]),
+                        vec![view! { <button class="btn btn-sm btn-primary">{t("New Assessment")}</button> }],
                         vec![
                             if loading.get() {
-                                view! { <div class="loading-overlay">"Loading compliance data..."</div> }
+                                view! { <div class="loading-overlay">{t("Loading compliance data...")}</div> }
                             } else {
                                 view! {
                                     <div>
@@ -949,7 +950,7 @@ pub fn grc_dashboard() -> View {
                     // Risk overview cards
                     <div class="kpi-grid">
                         {kpi_card(
-                            "Total Risks".to_string(),
+                            t("Total Risks").to_string(),
                             format!("{}", kpi_data.total_risks),
                             KpiColor::Blue,
                             icon_alert.to_string(),
@@ -957,7 +958,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Extreme/High".to_string(),
+                            t("Extreme/High").to_string(),
                             format!("{}", kpi_data.extreme_risks + kpi_data.high_risks),
                             KpiColor::Red,
                             icon_alert.to_string(),
@@ -965,7 +966,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Medium".to_string(),
+                            t("Medium").to_string(),
                             format!("{}", kpi_data.medium_risks),
                             KpiColor::Orange,
                             icon_warning.to_string(),
@@ -973,7 +974,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Low".to_string(),
+                            t("Low").to_string(),
                             format!("{}", kpi_data.low_risks),
                             KpiColor::Green,
                             icon_shield.to_string(),
@@ -984,11 +985,11 @@ pub fn grc_dashboard() -> View {
 
                     // Risk table
                     {panel(
-                        format!("Risk Register ({} total)", risks.len()),
-                        vec![view! { <button class="btn btn-sm btn-primary">"Add Risk"</button> }],
+                        tf("Risk Register ({count} total)", &[("count", risks.len().to_string())]),
+                        vec![view! { <button class="btn btn-sm btn-primary">{t("Add Risk")}</button> }],
                         vec![
                             if loading.get() {
-                                view! { <div class="loading-overlay">"Loading risk data..."</div> }
+                                view! { <div class="loading-overlay">{t("Loading risk data...")}</div> }
                             } else {
                                 view! {
                                     <div>
@@ -1007,7 +1008,7 @@ pub fn grc_dashboard() -> View {
                     // Violations overview cards
                     <div class="kpi-grid">
                         {kpi_card(
-                            "Total Violations".to_string(),
+                            t("Total Violations").to_string(),
                             format!("{}", violations.len()),
                             KpiColor::Blue,
                             icon_warning.to_string(),
@@ -1015,7 +1016,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Open".to_string(),
+                            t("Open").to_string(),
                             format!("{}", kpi_data.open_violations),
                             KpiColor::Orange,
                             icon_alert.to_string(),
@@ -1023,7 +1024,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Critical".to_string(),
+                            t("Critical").to_string(),
                             format!("{}", kpi_data.critical_violations),
                             KpiColor::Red,
                             icon_alert.to_string(),
@@ -1031,7 +1032,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Resolved".to_string(),
+                            t("Resolved").to_string(),
                             format!("{}", resolved_violations_count),
                             KpiColor::Green,
                             icon_check.to_string(),
@@ -1042,11 +1043,11 @@ pub fn grc_dashboard() -> View {
 
                     // Violations table
                     {panel(
-                        format!("Policy Violations ({} total)", violations.len()),
-                        vec![view! { <button class="btn btn-sm btn-primary">"Report Violation"</button> }],
+                        tf("Policy Violations ({count} total)", &[("count", violations.len().to_string())]),
+                        vec![view! { <button class="btn btn-sm btn-primary">{t("Report Violation")}</button> }],
                         vec![
                             if loading.get() {
-                                view! { <div class="loading-overlay">"Loading violations data..."</div> }
+                                view! { <div class="loading-overlay">{t("Loading violations data...")}</div> }
                             } else {
                                 view! {
                                     <div>
@@ -1065,7 +1066,7 @@ pub fn grc_dashboard() -> View {
                     // Controls overview cards
                     <div class="kpi-grid">
                         {kpi_card(
-                            "Total Controls".to_string(),
+                            t("Total Controls").to_string(),
                             format!("{}", kpi_data.total_controls),
                             KpiColor::Blue,
                             icon_settings.to_string(),
@@ -1073,7 +1074,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Effective".to_string(),
+                            t("Effective").to_string(),
                             format!("{}", kpi_data.effective_controls),
                             KpiColor::Green,
                             icon_check.to_string(),
@@ -1081,7 +1082,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Partially Effective".to_string(),
+                            t("Partially Effective").to_string(),
                             format!("{}", partially_effective_controls_count),
                             KpiColor::Orange,
                             icon_warning.to_string(),
@@ -1089,7 +1090,7 @@ pub fn grc_dashboard() -> View {
                             None
                         )}
                         {kpi_card(
-                            "Ineffective".to_string(),
+                            t("Ineffective").to_string(),
                             format!("{}", kpi_data.ineffective_controls),
                             KpiColor::Red,
                             icon_alert.to_string(),
@@ -1100,11 +1101,11 @@ pub fn grc_dashboard() -> View {
 
                     // Controls table
                     {panel(
-                        format!("Internal Controls ({} total)", ctrl_list.len()),
-                        vec![view! { <button class="btn btn-sm btn-primary">"Add Control"</button> }],
+                        tf("Internal Controls ({count} total)", &[("count", ctrl_list.len().to_string())]),
+                        vec![view! { <button class="btn btn-sm btn-primary">{t("Add Control")}</button> }],
                         vec![
                             if loading.get() {
-                                view! { <div class="loading-overlay">"Loading controls data..."</div> }
+                                view! { <div class="loading-overlay">{t("Loading controls data...")}</div> }
                             } else {
                                 view! {
                                     <div>
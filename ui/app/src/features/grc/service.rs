@@ -1,5 +1,8 @@
 //! GRC service - API calls
 
+use crate::util::api::ApiError;
+use crate::util::backend;
+use crate::util::datetime::today;
 use super::store::{GrcStore, load_mock_data, select_compliance, select_risk, select_violation, select_control};
 use super::types::{
     ComplianceCheck, ComplianceStatus, RiskAssessment, RiskStatus, RiskLevel,
@@ -11,10 +14,7 @@ pub async fn load_grc_data(store: &GrcStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API:
-    // let response = api::get("/api/grc/dashboard").await;
-    // For now, load mock data
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -28,9 +28,7 @@ pub async fn load_compliance_checks(store: &GrcStore, filter: Option<GrcFilter>)
         store.filter.set(f);
     }
 
-    // In production:
-    // let response = api::get("/api/grc/compliance", &store.filter.get()).await;
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -84,9 +82,7 @@ pub async fn load_risk_assessments(store: &GrcStore, filter: Option<GrcFilter>)
         store.filter.set(f);
     }
 
-    // In production:
-    // let response = api::get("/api/grc/risks", &store.filter.get()).await;
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -162,9 +158,7 @@ pub async fn load_policy_violations(store: &GrcStore, filter: Option<GrcFilter>)
         store.filter.set(f);
     }
 
-    // In production:
-    // let response = api::get("/api/grc/violations", &store.filter.get()).await;
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -197,7 +191,7 @@ pub async fn update_violation_status(
         if let Some(res) = resolution {
             violations[pos].resolution = Some(res);
             if status == ViolationStatus::Resolved || status == ViolationStatus::Closed {
-                violations[pos].resolution_date = Some("2025-02-15".to_string()); // Mock date
+                violations[pos].resolution_date = Some(today().to_iso());
             }
         }
         store.policy_violations.set(violations.clone());
@@ -226,7 +220,7 @@ pub async fn report_violation(
     let count = store.policy_violations.get().len() + 1;
     new_violation.id = format!("VIO-{:03}", count);
     new_violation.status = ViolationStatus::Open;
-    new_violation.detected_date = "2025-02-15".to_string(); // Mock date
+    new_violation.detected_date = today().to_iso();
 
     let mut violations = store.policy_violations.get();
     violations.push(new_violation.clone());
@@ -245,9 +239,7 @@ pub async fn load_controls(store: &GrcStore, filter: Option<GrcFilter>) {
         store.filter.set(f);
     }
 
-    // In production:
-    // let response = api::get("/api/grc/controls", &store.filter.get()).await;
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -353,7 +345,7 @@ pub async fn run_compliance_assessment(
     // Mock: update last assessed date
     let mut checks = store.compliance_checks.get();
     if let Some(pos) = checks.iter().position(|c| c.id == compliance_id) {
-        checks[pos].last_assessed = "2025-02-15".to_string();
+        checks[pos].last_assessed = today().to_iso();
         store.compliance_checks.set(checks.clone());
         store.selected_compliance.set(Some(checks[pos].clone()));
         store.loading.set(false);
@@ -379,7 +371,7 @@ pub async fn test_control(
     // Mock: update last tested date
     let mut controls = store.controls.get();
     if let Some(pos) = controls.iter().position(|c| c.id == control_id) {
-        controls[pos].last_tested = "2025-02-15".to_string();
+        controls[pos].last_tested = today().to_iso();
         store.controls.set(controls.clone());
         store.selected_control.set(Some(controls[pos].clone()));
         store.loading.set(false);
@@ -390,3 +382,25 @@ pub async fn test_control(
         Err("Control not found".to_string())
     }
 }
+
+/// Refresh GRC registers from the configured backend
+async fn load_from_backend(store: &GrcStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/grc/compliance-checks", &store.compliance_checks).await?;
+        backend::fetch_into("/grc/risk-assessments", &store.risk_assessments).await?;
+        backend::fetch_into("/grc/policy-violations", &store.policy_violations).await?;
+        backend::fetch_into("/grc/controls", &store.controls).await?;
+        backend::fetch_into("/grc/kpis", &store.kpis).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    }
+}
@@ -1,6 +1,7 @@
 //! GRC store
 
 use components::prelude::*;
+use crate::util::money::Money;
 use super::types::{
     ComplianceCheck, ComplianceStatus, ComplianceCategory, ComplianceFinding, FindingStatus,
     RiskAssessment, RiskCategory, RiskLevel, RiskStatus, RiskTrend, Likelihood, Impact,
@@ -424,7 +425,7 @@ pub fn load_mock_data(store: &GrcStore) {
             assigned_to: "SCM Manager".to_string(),
             resolution: None,
             resolution_date: None,
-            financial_impact: Some(520_000.0),
+            financial_impact: Some(Money::rands(520_000)),
             corrective_actions: vec![
                 CorrectiveAction {
                     id: "CA-001".to_string(),
@@ -451,7 +452,7 @@ pub fn load_mock_data(store: &GrcStore) {
             assigned_to: "Chief Risk Officer".to_string(),
             resolution: None,
             resolution_date: None,
-            financial_impact: Some(2_500_000.0),
+            financial_impact: Some(Money::rands(2_500_000)),
             corrective_actions: vec![
                 CorrectiveAction {
                     id: "CA-002".to_string(),
@@ -513,7 +514,7 @@ pub fn load_mock_data(store: &GrcStore) {
             assigned_to: "Internal Audit".to_string(),
             resolution: None,
             resolution_date: None,
-            financial_impact: Some(85_000.0),
+            financial_impact: Some(Money::rands(85_000)),
             corrective_actions: vec![
                 CorrectiveAction {
                     id: "CA-005".to_string(),
@@ -540,7 +541,7 @@ pub fn load_mock_data(store: &GrcStore) {
             assigned_to: "SCM Manager".to_string(),
             resolution: Some("Supplier registration completed retrospectively, process reviewed".to_string()),
             resolution_date: Some("2025-02-10".to_string()),
-            financial_impact: Some(350_000.0),
+            financial_impact: Some(Money::rands(350_000)),
             corrective_actions: vec![
                 CorrectiveAction {
                     id: "CA-006".to_string(),
@@ -1,6 +1,8 @@
 //! GRC domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::Money;
 
 /// Compliance check status
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -14,13 +16,13 @@ pub enum ComplianceStatus {
 
 impl ComplianceStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ComplianceStatus::Compliant => "Compliant",
             ComplianceStatus::NonCompliant => "Non-Compliant",
             ComplianceStatus::PartiallyCompliant => "Partially Compliant",
             ComplianceStatus::PendingReview => "Pending Review",
             ComplianceStatus::NotApplicable => "N/A",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -96,7 +98,7 @@ pub enum ComplianceCategory {
 
 impl ComplianceCategory {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ComplianceCategory::PFMA => "PFMA",
             ComplianceCategory::MFMA => "MFMA",
             ComplianceCategory::PPPFA => "PPPFA",
@@ -108,7 +110,7 @@ impl ComplianceCategory {
             ComplianceCategory::DataProtection => "POPIA",
             ComplianceCategory::AntiCorruption => "Anti-Corruption",
             ComplianceCategory::Internal => "Internal Policy",
-        }
+        })
     }
 }
 
@@ -142,13 +144,13 @@ pub enum FindingStatus {
 
 impl FindingStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             FindingStatus::Open => "Open",
             FindingStatus::InProgress => "In Progress",
             FindingStatus::Resolved => "Resolved",
             FindingStatus::Closed => "Closed",
             FindingStatus::Overdue => "Overdue",
-        }
+        })
     }
 }
 
@@ -164,13 +166,13 @@ pub enum Severity {
 
 impl Severity {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             Severity::Critical => "Critical",
             Severity::High => "High",
             Severity::Medium => "Medium",
             Severity::Low => "Low",
             Severity::Informational => "Info",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -195,12 +197,12 @@ pub enum Priority {
 
 impl Priority {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             Priority::Critical => "Critical",
             Priority::High => "High",
             Priority::Medium => "Medium",
             Priority::Low => "Low",
-        }
+        })
     }
 }
 
@@ -216,13 +218,13 @@ pub enum Likelihood {
 
 impl Likelihood {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             Likelihood::AlmostCertain => "Almost Certain",
             Likelihood::Likely => "Likely",
             Likelihood::Possible => "Possible",
             Likelihood::Unlikely => "Unlikely",
             Likelihood::Rare => "Rare",
-        }
+        })
     }
 
     pub fn score(&self) -> u8 {
@@ -248,13 +250,13 @@ pub enum Impact {
 
 impl Impact {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             Impact::Catastrophic => "Catastrophic",
             Impact::Major => "Major",
             Impact::Moderate => "Moderate",
             Impact::Minor => "Minor",
             Impact::Insignificant => "Insignificant",
-        }
+        })
     }
 
     pub fn score(&self) -> u8 {
@@ -279,12 +281,12 @@ pub enum RiskLevel {
 
 impl RiskLevel {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             RiskLevel::Extreme => "Extreme",
             RiskLevel::High => "High",
             RiskLevel::Medium => "Medium",
             RiskLevel::Low => "Low",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -377,7 +379,7 @@ pub enum RiskCategory {
 
 impl RiskCategory {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             RiskCategory::Financial => "Financial",
             RiskCategory::Operational => "Operational",
             RiskCategory::Compliance => "Compliance",
@@ -388,7 +390,7 @@ impl RiskCategory {
             RiskCategory::SupplyChain => "Supply Chain",
             RiskCategory::Environmental => "Environmental",
             RiskCategory::Legal => "Legal",
-        }
+        })
     }
 }
 
@@ -422,12 +424,12 @@ pub enum MitigationType {
 
 impl MitigationType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             MitigationType::Avoid => "Avoid",
             MitigationType::Reduce => "Reduce",
             MitigationType::Transfer => "Transfer",
             MitigationType::Accept => "Accept",
-        }
+        })
     }
 }
 
@@ -443,13 +445,13 @@ pub enum MitigationStatus {
 
 impl MitigationStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             MitigationStatus::Planned => "Planned",
             MitigationStatus::InProgress => "In Progress",
             MitigationStatus::Implemented => "Implemented",
             MitigationStatus::Verified => "Verified",
             MitigationStatus::Ineffective => "Ineffective",
-        }
+        })
     }
 }
 
@@ -464,12 +466,12 @@ pub enum RiskStatus {
 
 impl RiskStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             RiskStatus::Active => "Active",
             RiskStatus::Monitoring => "Monitoring",
             RiskStatus::Closed => "Closed",
             RiskStatus::Escalated => "Escalated",
-        }
+        })
     }
 }
 
@@ -483,11 +485,11 @@ pub enum RiskTrend {
 
 impl RiskTrend {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             RiskTrend::Increasing => "Increasing",
             RiskTrend::Stable => "Stable",
             RiskTrend::Decreasing => "Decreasing",
-        }
+        })
     }
 
     pub fn icon(&self) -> &'static str {
@@ -516,7 +518,7 @@ pub struct PolicyViolation {
     pub assigned_to: String,
     pub resolution: Option<String>,
     pub resolution_date: Option<String>,
-    pub financial_impact: Option<f64>,
+    pub financial_impact: Option<Money>,
     pub corrective_actions: Vec<CorrectiveAction>,
 }
 
@@ -558,7 +560,7 @@ pub enum ViolationType {
 
 impl ViolationType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ViolationType::Procedural => "Procedural",
             ViolationType::Financial => "Financial",
             ViolationType::Authorization => "Authorization",
@@ -567,7 +569,7 @@ impl ViolationType {
             ViolationType::Threshold => "Threshold Breach",
             ViolationType::Timeline => "Timeline",
             ViolationType::Segregation => "Segregation of Duties",
-        }
+        })
     }
 }
 
@@ -584,14 +586,14 @@ pub enum ViolationStatus {
 
 impl ViolationStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ViolationStatus::Open => "Open",
             ViolationStatus::UnderInvestigation => "Under Investigation",
             ViolationStatus::PendingAction => "Pending Action",
             ViolationStatus::Resolved => "Resolved",
             ViolationStatus::Closed => "Closed",
             ViolationStatus::Escalated => "Escalated",
-        }
+        })
     }
 }
 
@@ -610,7 +612,7 @@ pub enum EntityType {
 
 impl EntityType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             EntityType::Requisition => "Requisition",
             EntityType::Tender => "Tender",
             EntityType::Contract => "Contract",
@@ -619,7 +621,7 @@ impl EntityType {
             EntityType::User => "User",
             EntityType::Transaction => "Transaction",
             EntityType::Department => "Department",
-        }
+        })
     }
 }
 
@@ -646,13 +648,13 @@ pub enum ActionStatus {
 
 impl ActionStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ActionStatus::Pending => "Pending",
             ActionStatus::InProgress => "In Progress",
             ActionStatus::Completed => "Completed",
             ActionStatus::Overdue => "Overdue",
             ActionStatus::Cancelled => "Cancelled",
-        }
+        })
     }
 }
 
@@ -709,12 +711,12 @@ pub enum ControlType {
 
 impl ControlType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ControlType::Preventive => "Preventive",
             ControlType::Detective => "Detective",
             ControlType::Corrective => "Corrective",
             ControlType::Directive => "Directive",
-        }
+        })
     }
 }
 
@@ -730,13 +732,13 @@ pub enum ControlCategory {
 
 impl ControlCategory {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ControlCategory::Financial => "Financial",
             ControlCategory::Operational => "Operational",
             ControlCategory::IT => "IT",
             ControlCategory::Compliance => "Compliance",
             ControlCategory::Strategic => "Strategic",
-        }
+        })
     }
 }
 
@@ -753,14 +755,14 @@ pub enum ControlFrequency {
 
 impl ControlFrequency {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ControlFrequency::Continuous => "Continuous",
             ControlFrequency::Daily => "Daily",
             ControlFrequency::Weekly => "Weekly",
             ControlFrequency::Monthly => "Monthly",
             ControlFrequency::Quarterly => "Quarterly",
             ControlFrequency::Annually => "Annually",
-        }
+        })
     }
 }
 
@@ -775,12 +777,12 @@ pub enum ControlEffectiveness {
 
 impl ControlEffectiveness {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ControlEffectiveness::Effective => "Effective",
             ControlEffectiveness::PartiallyEffective => "Partially Effective",
             ControlEffectiveness::Ineffective => "Ineffective",
             ControlEffectiveness::NotTested => "Not Tested",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -805,13 +807,13 @@ pub enum ControlOperatingStatus {
 
 impl ControlOperatingStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ControlOperatingStatus::Operating => "Operating",
             ControlOperatingStatus::PartiallyOperating => "Partially Operating",
             ControlOperatingStatus::NotOperating => "Not Operating",
             ControlOperatingStatus::UnderReview => "Under Review",
             ControlOperatingStatus::Retired => "Retired",
-        }
+        })
     }
 }
 
@@ -825,11 +827,11 @@ pub enum AutomationLevel {
 
 impl AutomationLevel {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             AutomationLevel::Manual => "Manual",
             AutomationLevel::SemiAutomated => "Semi-Automated",
             AutomationLevel::FullyAutomated => "Fully Automated",
-        }
+        })
     }
 }
 
@@ -856,17 +858,17 @@ pub enum TestResult {
 
 impl TestResult {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             TestResult::Pass => "Pass",
             TestResult::Fail => "Fail",
             TestResult::PartialPass => "Partial Pass",
             TestResult::NotApplicable => "N/A",
-        }
+        })
     }
 }
 
 /// GRC KPI summary
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct GrcKpis {
     pub compliance_score: f64,
     pub total_checks: u32,
@@ -0,0 +1,422 @@
+//! Invoice detail view
+//!
+//! Shows the three-way match line by line and lets finance resolve
+//! exceptions, re-run the match and approve matched invoices for payment.
+
+use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table, DataTableColumn, DataTableRow,
+    notice_bar, NoticeType,
+};
+use crate::shared::forms::{select_with_testid, SelectOption, textarea};
+use crate::util::auth::AuthState;
+use crate::util::datetime::today;
+use crate::util::format::{format_currency, format_currency_full, format_date};
+use crate::util::i18n::t;
+use crate::util::permissions::{self, Action};
+use super::invoice_list::{invoice_status, match_class};
+use super::store::InvoicesStore;
+use super::types::{ExceptionAction, Invoice, InvoiceStatus, LineMatchResult, MatchStatus};
+use super::service;
+
+fn line_result(result: LineMatchResult) -> (&'static str, &'static str) {
+    match result {
+        LineMatchResult::Matched => ("match-full", t("Matched")),
+        LineMatchResult::WithinTolerance => ("match-partial", t("Within tolerance")),
+        LineMatchResult::Exception => ("match-exception", t("Exception")),
+        LineMatchResult::NotOrdered => ("match-exception", t("Not ordered")),
+    }
+}
+
+fn variance(value: f64) -> String {
+    if value == 0.0 {
+        "-".to_string()
+    } else {
+        format!("{:+.1}%", value)
+    }
+}
+
+/// Invoice detail page
+#[component]
+pub fn invoice_detail(invoice_id: String) -> View {
+    let store = use_context::<InvoicesStore>();
+
+    effect({
+        let store = store.clone();
+        let invoice_id = invoice_id.clone();
+        move || {
+            let store = store.clone();
+            let invoice_id = invoice_id.clone();
+            spawn(async move {
+                service::load_invoice(&store, &invoice_id).await;
+            });
+        }
+    });
+
+    let loading = store.loading.get();
+    let error = store.error.get();
+    let invoice = store.selected.get().filter(|i| i.id == invoice_id);
+
+    view! {
+        style {
+            r#"
+            .invoice-detail { display: flex; flex-direction: column; gap: var(--space-6); }
+            .invoice-detail-grid {
+                display: grid;
+                grid-template-columns: 2fr 1fr;
+                gap: 24px;
+            }
+            .invoice-detail .detail-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 8px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .invoice-detail .detail-row:last-child { border-bottom: none; }
+            .invoice-detail .detail-row .label { color: var(--text-muted); }
+            .invoice-detail .detail-row .value { font-weight: 500; text-align: right; }
+            .invoice-detail .overdue { color: var(--red); }
+            .invoice-match { font-size: 12px; font-weight: 600; }
+            .invoice-match.match-full { color: var(--green); }
+            .invoice-match.match-partial { color: var(--accent); }
+            .invoice-match.match-exception { color: var(--red); }
+            .invoice-match.match-none { color: var(--text-muted); }
+            .match-summary {
+                display: flex;
+                justify-content: space-between;
+                align-items: baseline;
+                margin-bottom: 12px;
+                font-size: 13px;
+            }
+            .match-exceptions { margin: 12px 0 0; padding-left: 18px; font-size: 13px; color: var(--red); }
+            .resolution-form { display: flex; flex-direction: column; gap: 12px; }
+            .resolution-record { font-size: 13px; }
+            .resolution-record .by { font-size: 12px; color: var(--text-muted); margin-top: 4px; }
+            .invoice-actions { display: flex; gap: 8px; justify-content: flex-end; }
+            @media (max-width: 1024px) {
+                .invoice-detail-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="invoice-detail" data-testid="invoice-detail">
+            if loading {
+                <div class="loading-state">{t("Loading invoice...")}</div>
+            } else if let Some(invoice) = invoice {
+                {render_invoice(store.clone(), invoice)}
+            } else {
+                {entity_not_found(t("Invoice"), &invoice_id, error, Route::InvoicesList, t("Back to Invoices"))}
+            }
+        </div>
+    }
+}
+
+fn render_invoice(store: InvoicesStore, invoice: Invoice) -> View {
+    let auth_state = use_context::<Signal<AuthState>>();
+    let auth = auth_state.get();
+    let can_register = permissions::can_perform(&auth, Action::RegisterInvoice);
+    let can_approve = permissions::can_perform(&auth, Action::ApproveInvoice);
+
+    let action = signal(String::new());
+    let justification = signal(String::new());
+
+    let handle_resolve = Callback::<()>::new({
+        let store = store.clone();
+        let id = invoice.id.clone();
+        let action = action.clone();
+        let justification = justification.clone();
+        move |_| {
+            let Some(chosen) = ExceptionAction::ALL.iter().copied().find(|a| a.label() == action.get()) else {
+                store.error.set(Some(t("Choose how to resolve the exception").to_string()));
+                return;
+            };
+            let store = store.clone();
+            let id = id.clone();
+            let justification = justification.clone();
+            spawn(async move {
+                if service::resolve_exception(&store, &id, chosen, &justification.get()).await.is_ok() {
+                    justification.set(String::new());
+                }
+            });
+        }
+    });
+
+    let handle_rematch = Callback::<()>::new({
+        let store = store.clone();
+        let id = invoice.id.clone();
+        move |_| {
+            let store = store.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::rematch_invoice(&store, &id).await;
+            });
+        }
+    });
+
+    let handle_approve = Callback::<()>::new({
+        let store = store.clone();
+        let id = invoice.id.clone();
+        move |_| {
+            let store = store.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::approve_invoice(&store, &id).await;
+            });
+        }
+    });
+
+    let today = today();
+    let days_to_due = invoice.days_to_due(today);
+    let match_status = invoice.match_status();
+    let result = invoice.match_result.clone().unwrap_or_default();
+
+    let columns = vec![
+        DataTableColumn { key: "description".to_string(), label: t("Description").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "ordered".to_string(), label: t("Ordered").to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "accepted".to_string(), label: t("Accepted").to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "invoiced".to_string(), label: t("Invoiced").to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "qty_var".to_string(), label: t("Qty Var.").to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "po_price".to_string(), label: t("Order Price").to_string(), width: Some("120px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "inv_price".to_string(), label: t("Invoiced Price").to_string(), width: Some("120px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "price_var".to_string(), label: t("Price Var.").to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "result".to_string(), label: t("Result").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+    ];
+    let rows: Vec<DataTableRow> = result.lines.iter().map(|line| {
+        let (class, label) = line_result(line.result);
+        DataTableRow {
+            id: line.invoice_line_id.clone(),
+            cells: vec![
+                view! { <span>{line.description.clone()}</span> },
+                view! { <span>{line.ordered_quantity.to_string()}</span> },
+                view! { <span>{line.accepted_quantity.to_string()}</span> },
+                view! { <span>{line.invoiced_quantity.to_string()}</span> },
+                view! { <span>{variance(line.quantity_variance)}</span> },
+                view! { <span>{format_currency_full(line.ordered_price)}</span> },
+                view! { <span>{format_currency_full(line.invoiced_price)}</span> },
+                view! { <span>{variance(line.price_variance)}</span> },
+                view! { <span class={format!("invoice-match {}", class)}>{label}</span> },
+            ],
+        }
+    }).collect();
+
+    let action_options: Vec<SelectOption> = ExceptionAction::ALL
+        .iter()
+        .filter(|a| **a != ExceptionAction::AcceptVariance || match_status != MatchStatus::NoMatch)
+        .map(|a| SelectOption { value: a.label().to_string(), label: a.label().to_string() })
+        .collect();
+    let can_rematch = can_register
+        && matches!(invoice.status, InvoiceStatus::Registered | InvoiceStatus::Exception | InvoiceStatus::OnHold);
+
+    view! {
+        {page_header(
+            format!("{} {}", t("Invoice"), invoice.id),
+            Some(format!("{} · {}", invoice.supplier_name, invoice.invoice_number)),
+            vec![
+                view! { <a href={Route::InvoicesList.to_path()} class="btn btn-secondary">{t("Back to Invoices")}</a> },
+            ]
+        )}
+
+        if let Some(err) = store.error.get() {
+            {notice_bar(err, NoticeType::Error, None)}
+        }
+        if let Some(dup) = invoice.duplicate_of.clone() {
+            {notice_bar(
+                format!("{} {}. {}", t("This invoice may duplicate"), dup, t("Confirm it is a separate charge before accepting it.")),
+                NoticeType::Warning,
+                None
+            )}
+        }
+
+        <div class="invoice-detail-grid">
+            <div>
+                {panel(
+                    t("Three-Way Match").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div data-testid="invoice-match">
+                            <div class="match-summary">
+                                <span class={format!("invoice-match {}", match_class(match_status))}>{match_status.label()}</span>
+                                if !result.expected_total.is_zero() {
+                                    <span>
+                                        {format!(
+                                            "{} {} · {} {} ({})",
+                                            t("Accepted goods"),
+                                            format_currency_full(result.expected_total),
+                                            t("invoiced"),
+                                            format_currency_full(invoice.total_amount),
+                                            variance(result.total_variance)
+                                        )}
+                                    </span>
+                                }
+                            </div>
+                            if !rows.is_empty() {
+                                {data_table(columns.clone(), rows.clone(), None)}
+                            }
+                            if !result.exceptions.is_empty() {
+                                <ul class="match-exceptions">
+                                    for exception in result.exceptions.clone() {
+                                        <li>{exception}</li>
+                                    }
+                                </ul>
+                            }
+                        </div>
+                    }]
+                )}
+
+                if invoice.status.needs_attention() && can_approve {
+                    {panel(
+                        t("Resolve Exception").to_string(),
+                        vec![],
+                        vec![view! {
+                            <div class="resolution-form" data-testid="invoice-resolution">
+                                {select_with_testid(
+                                    t("Action").to_string(),
+                                    action.clone(),
+                                    action_options.clone(),
+                                    Some(t("Select action").to_string()),
+                                    true,
+                                    false,
+                                    None,
+                                    Some("resolution-action".to_string()),
+                                )}
+                                {textarea(
+                                    t("Justification").to_string(),
+                                    justification.clone(),
+                                    Some(t("Why the variance is acceptable, or what the supplier must correct").to_string()),
+                                    true,
+                                    false,
+                                    Some(3),
+                                    None,
+                                    None,
+                                )}
+                                <div class="invoice-actions">
+                                    <button
+                                        class="btn btn-primary"
+                                        disabled={store.saving.get()}
+                                        data-testid="resolution-submit"
+                                        on:click={handle_resolve}
+                                    >
+                                        {t("Record Resolution")}
+                                    </button>
+                                </div>
+                            </div>
+                        }]
+                    )}
+                }
+            </div>
+
+            <div>
+                {panel(
+                    t("Invoice").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div>
+                            <div class="detail-row">
+                                <span class="label">{t("Status")}</span>
+                                <span class="value">{invoice_status(invoice.status)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Purchase Order")}</span>
+                                <span class="value">
+                                    <a href={Route::PurchaseOrdersDetail(invoice.po_number.clone()).to_path()}>{invoice.po_number.clone()}</a>
+                                </span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Invoice Date")}</span>
+                                <span class="value">{format_date(&invoice.invoice_date)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Received")}</span>
+                                <span class="value">{format_date(&invoice.received_date)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Due")}</span>
+                                <span class={if invoice.is_overdue_on(today) { "value overdue" } else { "value" }}>
+                                    {if invoice.is_overdue_on(today) {
+                                        format!("{} ({} {})", format_date(&invoice.due_date), -days_to_due, t("days overdue"))
+                                    } else {
+                                        format_date(&invoice.due_date)
+                                    }}
+                                </span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Aging")}</span>
+                                <span class="value">{invoice.aging_on(today).label()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Subtotal")}</span>
+                                <span class="value">{format_currency_full(invoice.subtotal)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("VAT")}</span>
+                                <span class="value">{format_currency_full(invoice.vat_amount)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Total")}</span>
+                                <span class="value">{format_currency_full(invoice.total_amount)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Registered by")}</span>
+                                <span class="value">{invoice.registered_by.clone()}</span>
+                            </div>
+                            if let Some(approver) = invoice.approved_by.clone() {
+                                <div class="detail-row">
+                                    <span class="label">{t("Approved by")}</span>
+                                    <span class="value">{approver}</span>
+                                </div>
+                            }
+                            if let Some(run) = invoice.payment_run.clone() {
+                                <div class="detail-row">
+                                    <span class="label">{t("Payment run")}</span>
+                                    <a class="value" href={Route::PaymentRunDetail(run.clone()).to_path()}>{run.clone()}</a>
+                                </div>
+                            }
+                            if let Some(paid) = invoice.paid_date.clone() {
+                                <div class="detail-row">
+                                    <span class="label">{t("Paid")}</span>
+                                    <span class="value">{format!("{} · {}", format_date(&paid), format_currency(invoice.paid_amount))}</span>
+                                </div>
+                            }
+                        </div>
+                    }]
+                )}
+
+                if let Some(resolution) = invoice.resolution.clone() {
+                    {panel(
+                        t("Resolution").to_string(),
+                        vec![],
+                        vec![view! {
+                            <div class="resolution-record">
+                                <strong>{resolution.action.label()}</strong>
+                                <div>{resolution.justification.clone()}</div>
+                                <div class="by">{format!("{} · {}", resolution.resolved_by, format_date(&resolution.resolved_at))}</div>
+                            </div>
+                        }]
+                    )}
+                }
+
+                <div class="invoice-actions">
+                    if can_rematch {
+                        <button class="btn btn-secondary" disabled={store.saving.get()} on:click={handle_rematch}>
+                            {t("Re-run Match")}
+                        </button>
+                    }
+                    if can_approve && invoice.status == InvoiceStatus::Matched {
+                        <button
+                            class="btn btn-primary"
+                            disabled={store.saving.get()}
+                            data-testid="invoice-approve"
+                            on:click={handle_approve}
+                        >
+                            {t("Approve for Payment")}
+                        </button>
+                    }
+                </div>
+            </div>
+        </div>
+    }
+}
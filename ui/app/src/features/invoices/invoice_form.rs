@@ -0,0 +1,315 @@
+//! Invoice registration form
+//!
+//! Captures a supplier invoice against a purchase order. Lines are copied
+//! from the order at the quantities delivered so the clerk only corrects
+//! what the supplier billed differently.
+
+use components::prelude::*;
+use crate::Route;
+use crate::features::purchase_orders::service as purchase_orders;
+use crate::shared::layout::page_header;
+use crate::shared::components::{panel, notice_bar, NoticeType, empty_state};
+use crate::shared::forms::{text_input, text_input_with_testid, currency_input, date_picker, textarea};
+use crate::util::datetime::today;
+use crate::util::format::{format_currency, format_currency_full};
+use crate::util::i18n::t;
+use crate::util::money::Money;
+use super::store::InvoicesStore;
+use super::types::{Invoice, InvoiceLine, PAYMENT_TERM_DAYS};
+use super::service;
+
+/// Invoice line being captured, with editable quantity and price
+#[derive(Clone)]
+struct LineInput {
+    line: InvoiceLine,
+    quantity: Signal<String>,
+    unit_price: Signal<Money>,
+}
+
+impl LineInput {
+    fn to_line(&self) -> InvoiceLine {
+        InvoiceLine {
+            quantity: self.quantity.get().trim().parse().unwrap_or(0),
+            unit_price: self.unit_price.get(),
+            ..self.line.clone()
+        }
+    }
+}
+
+/// Register a supplier invoice
+#[component]
+pub fn invoice_form() -> View {
+    let store = use_context::<InvoicesStore>();
+
+    let po_number = signal(String::new());
+    let invoice_number = signal(String::new());
+    let invoice_date = signal(String::new());
+    let received_date = signal(today().to_iso());
+    let description = signal(String::new());
+    let supplier = signal(None::<String>);
+    let lines = signal(Vec::<LineInput>::new());
+    let form_error = signal(None::<String>);
+    let registered = signal(None::<Invoice>);
+
+    let handle_load_order = Callback::<()>::new({
+        let po_number = po_number.clone();
+        let supplier = supplier.clone();
+        let lines = lines.clone();
+        let form_error = form_error.clone();
+        move |_| {
+            let po_number = po_number.clone();
+            let supplier = supplier.clone();
+            let lines = lines.clone();
+            let form_error = form_error.clone();
+            form_error.set(None);
+            spawn(async move {
+                match purchase_orders::find_purchase_order(po_number.get().trim()).await {
+                    Ok(po) => {
+                        po_number.set(po.po_number.clone());
+                        supplier.set(Some(po.supplier.name.clone()));
+                        lines.set(po.line_items.iter().map(|item| LineInput {
+                            line: InvoiceLine {
+                                po_line_id: Some(item.id.clone()),
+                                item_code: item.item_code.clone(),
+                                description: item.description.clone(),
+                                tax_rate: item.tax_rate,
+                                ..Default::default()
+                            },
+                            quantity: signal(item.delivered_quantity.to_string()),
+                            unit_price: signal(item.unit_price),
+                        }).collect());
+                    }
+                    Err(e) => {
+                        supplier.set(None);
+                        lines.set(Vec::new());
+                        form_error.set(Some(e));
+                    }
+                }
+            });
+        }
+    });
+
+    let handle_submit = Callback::<()>::new({
+        let store = store.clone();
+        let po_number = po_number.clone();
+        let invoice_number = invoice_number.clone();
+        let invoice_date = invoice_date.clone();
+        let received_date = received_date.clone();
+        let description = description.clone();
+        let lines = lines.clone();
+        let form_error = form_error.clone();
+        let registered = registered.clone();
+        move |_| {
+            let invoice = Invoice {
+                invoice_number: invoice_number.get(),
+                po_number: po_number.get(),
+                invoice_date: invoice_date.get(),
+                received_date: received_date.get(),
+                description: Some(description.get()).filter(|d| !d.trim().is_empty()),
+                // Lines billed at nothing were not invoiced
+                lines: lines.get().iter().map(LineInput::to_line).filter(|l| l.quantity > 0).collect(),
+                ..Default::default()
+            };
+            let store = store.clone();
+            let form_error = form_error.clone();
+            let registered = registered.clone();
+            let invoice_number = invoice_number.clone();
+            form_error.set(None);
+            spawn(async move {
+                match service::register_invoice(&store, invoice).await {
+                    Ok(invoice) => {
+                        invoice_number.set(String::new());
+                        registered.set(Some(invoice));
+                    }
+                    Err(e) => form_error.set(Some(e)),
+                }
+            });
+        }
+    });
+
+    let captured: Vec<InvoiceLine> = lines.get().iter().map(LineInput::to_line).collect();
+    let subtotal: Money = captured.iter().map(|l| l.total()).sum();
+    let vat: Money = captured.iter().map(|l| l.vat()).sum();
+
+    view! {
+        style {
+            r#"
+            .invoice-form { display: flex; flex-direction: column; gap: var(--space-6); }
+            .invoice-form-grid {
+                display: grid;
+                grid-template-columns: repeat(3, 1fr);
+                gap: 16px;
+            }
+            .invoice-form-grid .span-3 { grid-column: 1 / -1; }
+            .invoice-po-lookup { display: flex; gap: 8px; align-items: flex-end; }
+            .invoice-po-lookup > :first-child { flex: 1; }
+            .invoice-registered { display: flex; flex-direction: column; gap: 8px; font-size: 13px; }
+            .invoice-line {
+                display: grid;
+                grid-template-columns: 2fr 100px 160px 140px;
+                gap: 12px;
+                align-items: end;
+                padding: 8px 0;
+                border-bottom: 1px solid var(--border);
+            }
+            .invoice-line-desc { display: flex; flex-direction: column; font-size: 13px; padding-bottom: 10px; }
+            .invoice-line-desc .code { font-size: 12px; color: var(--text-muted); }
+            .invoice-line-total { text-align: right; font-weight: 500; padding-bottom: 10px; }
+            .invoice-totals {
+                display: flex;
+                flex-direction: column;
+                align-items: flex-end;
+                gap: 4px;
+                padding-top: 12px;
+                font-size: 13px;
+            }
+            .invoice-totals .grand { font-size: 15px; font-weight: 600; }
+            .invoice-form-actions { display: flex; justify-content: flex-end; gap: 8px; }
+            @media (max-width: 1024px) {
+                .invoice-form-grid, .invoice-line { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="invoice-form" data-testid="invoice-form">
+            {page_header(
+                t("Register Invoice").to_string(),
+                Some(format!("{} {} {}", t("Payment is due"), PAYMENT_TERM_DAYS, t("days after the invoice is received"))),
+                vec![
+                    view! { <a href={Route::InvoicesList.to_path()} class="btn btn-secondary">{t("Back to Invoices")}</a> },
+                ]
+            )}
+
+            if let Some(err) = form_error.get() {
+                {notice_bar(err, NoticeType::Error, None)}
+            }
+            if let Some(invoice) = registered.get() {
+                <div class="invoice-registered">
+                    {notice_bar(
+                        format!(
+                            "{} {} ({}): {}",
+                            t("Registered"),
+                            invoice.id,
+                            invoice.invoice_number,
+                            if invoice.duplicate_of.is_some() {
+                                t("held as a possible duplicate").to_string()
+                            } else {
+                                invoice.match_status().label().to_string()
+                            }
+                        ),
+                        if invoice.status.needs_attention() { NoticeType::Warning } else { NoticeType::Success },
+                        None
+                    )}
+                    <a href={Route::InvoicesDetail(invoice.id.clone()).to_path()}>{t("View invoice")}</a>
+                </div>
+            }
+
+            {panel(
+                t("Invoice Details").to_string(),
+                vec![],
+                vec![view! {
+                    <div class="invoice-form-grid">
+                        <div class="invoice-po-lookup">
+                            {text_input_with_testid(
+                                t("Purchase Order").to_string(),
+                                po_number.clone(),
+                                Some("PO-2025-0456".to_string()),
+                                true,
+                                false,
+                                None,
+                                supplier.get(),
+                                None,
+                                Some("invoice-po".to_string()),
+                            )}
+                            <button class="btn btn-secondary" data-testid="invoice-load-po" on:click={handle_load_order}>
+                                {t("Load Order")}
+                            </button>
+                        </div>
+                        {text_input_with_testid(
+                            t("Supplier Invoice Number").to_string(),
+                            invoice_number.clone(),
+                            None,
+                            true,
+                            false,
+                            None,
+                            None,
+                            None,
+                            Some("invoice-number".to_string()),
+                        )}
+                        <div></div>
+                        {date_picker(t("Invoice Date").to_string(), invoice_date.clone(), true, false, None, Some(today().to_iso()), None)}
+                        {date_picker(
+                            t("Date Received").to_string(),
+                            received_date.clone(),
+                            true,
+                            false,
+                            None,
+                            Some(today().to_iso()),
+                            None,
+                        )}
+                        <div></div>
+                        <div class="span-3">
+                            {textarea(t("Description").to_string(), description.clone(), None, false, false, Some(2), None, None)}
+                        </div>
+                    </div>
+                }]
+            )}
+
+            {panel(
+                t("Invoice Lines").to_string(),
+                vec![],
+                vec![
+                    if lines.get().is_empty() {
+                        empty_state(
+                            t("No order loaded").to_string(),
+                            Some(t("Enter the purchase order number and load the order to capture the lines").to_string()),
+                            None,
+                            None,
+                        )
+                    } else {
+                        view! {
+                            <div data-testid="invoice-lines">
+                                for (input, line) in lines.get().into_iter().zip(captured.clone()) {
+                                    <div class="invoice-line">
+                                        <div class="invoice-line-desc">
+                                            <span>{input.line.description.clone()}</span>
+                                            <span class="code">{input.line.item_code.clone()}</span>
+                                        </div>
+                                        {text_input(
+                                            t("Qty").to_string(),
+                                            input.quantity.clone(),
+                                            None,
+                                            false,
+                                            false,
+                                            None,
+                                            None,
+                                            Some("number".to_string()),
+                                        )}
+                                        {currency_input(t("Unit Price").to_string(), input.unit_price.clone(), false, false, None, None)}
+                                        <span class="invoice-line-total">{format_currency(line.total())}</span>
+                                    </div>
+                                }
+                                <div class="invoice-totals">
+                                    <span>{format!("{} {}", t("Subtotal"), format_currency_full(subtotal))}</span>
+                                    <span>{format!("{} {}", t("VAT"), format_currency_full(vat))}</span>
+                                    <span class="grand">{format!("{} {}", t("Total"), format_currency_full(subtotal + vat))}</span>
+                                </div>
+                            </div>
+                        }
+                    },
+                ]
+            )}
+
+            <div class="invoice-form-actions">
+                <button
+                    class="btn btn-primary"
+                    disabled={store.saving.get() || lines.get().is_empty()}
+                    data-testid="invoice-submit"
+                    on:click={handle_submit}
+                >
+                    {t("Register and Match")}
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,397 @@
+//! Invoice register
+//!
+//! Payables overview: outstanding and overdue totals, PFMA aging, the
+//! exception queue and the full register. Procurement managers also set the
+//! three-way match tolerances here.
+
+use components::prelude::*;
+use crate::Route;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table_with_testid, DataTableColumn, DataTableRow,
+    kpi_card, KpiColor, KpiDelta,
+    status_badge, StatusType,
+    notice_bar, NoticeType,
+    empty_state,
+};
+use crate::shared::forms::{select, SelectOption, text_input, checkbox, percentage_input};
+use crate::util::auth::AuthState;
+use crate::util::datetime::today;
+use crate::util::format::{format_currency, format_date};
+use crate::util::i18n::t;
+use crate::util::money::Money;
+use crate::util::permissions::{self, Action};
+use super::store::InvoicesStore;
+use super::types::{AgingBucket, Invoice, InvoiceStatus, MatchStatus, MatchTolerances, PAYMENT_TERM_DAYS};
+use super::service;
+
+pub(super) fn invoice_status(status: InvoiceStatus) -> View {
+    status_badge(match status {
+        InvoiceStatus::Registered => StatusType::New,
+        InvoiceStatus::Matched => StatusType::Pending,
+        InvoiceStatus::Exception => StatusType::Failed,
+        InvoiceStatus::OnHold => StatusType::OnHold,
+        InvoiceStatus::Approved => StatusType::Approved,
+        InvoiceStatus::Scheduled => StatusType::Scheduled,
+        InvoiceStatus::Paid => StatusType::Complete,
+        InvoiceStatus::Rejected => StatusType::Rejected,
+        InvoiceStatus::Cancelled => StatusType::Cancelled,
+    })
+}
+
+pub(super) fn match_class(status: MatchStatus) -> &'static str {
+    match status {
+        MatchStatus::FullMatch => "match-full",
+        MatchStatus::PartialMatch => "match-partial",
+        MatchStatus::Exception | MatchStatus::NoMatch => "match-exception",
+        MatchStatus::Unmatched => "match-none",
+    }
+}
+
+const STATUS_FILTERS: [InvoiceStatus; 8] = [
+    InvoiceStatus::Registered,
+    InvoiceStatus::Matched,
+    InvoiceStatus::Exception,
+    InvoiceStatus::OnHold,
+    InvoiceStatus::Approved,
+    InvoiceStatus::Scheduled,
+    InvoiceStatus::Paid,
+    InvoiceStatus::Rejected,
+];
+
+/// Invoice register page
+#[component]
+pub fn invoice_list() -> View {
+    let store = use_context::<InvoicesStore>();
+    let auth_state = use_context::<Signal<AuthState>>();
+
+    effect({
+        let store = store.clone();
+        move || {
+            let store = store.clone();
+            spawn(async move {
+                service::load_invoices(&store).await;
+            });
+        }
+    });
+
+    let status_filter = signal(String::new());
+    let search = signal(String::new());
+    let overdue_only = signal(false);
+
+    effect({
+        let store = store.clone();
+        let status_filter = status_filter.clone();
+        let search = search.clone();
+        let overdue_only = overdue_only.clone();
+        move || {
+            let status = status_filter.get();
+            store.set_filter_status((!status.is_empty()).then(|| InvoiceStatus::from_str(&status)));
+            store.set_filter_search(Some(search.get()));
+            store.set_overdue_only(overdue_only.get());
+        }
+    });
+
+    let tolerances = store.tolerances.get();
+    let quantity_tolerance = signal(tolerances.quantity);
+    let price_tolerance = signal(tolerances.price);
+    let total_tolerance = signal(tolerances.total);
+    let tolerances_saved = signal(false);
+
+    let handle_save_tolerances = Callback::<()>::new({
+        let store = store.clone();
+        let quantity_tolerance = quantity_tolerance.clone();
+        let price_tolerance = price_tolerance.clone();
+        let total_tolerance = total_tolerance.clone();
+        let tolerances_saved = tolerances_saved.clone();
+        move |_| {
+            let tolerances = MatchTolerances {
+                quantity: quantity_tolerance.get(),
+                price: price_tolerance.get(),
+                total: total_tolerance.get(),
+            };
+            let store = store.clone();
+            let tolerances_saved = tolerances_saved.clone();
+            tolerances_saved.set(false);
+            spawn(async move {
+                if service::save_tolerances(&store, tolerances).await.is_ok() {
+                    tolerances_saved.set(true);
+                }
+            });
+        }
+    });
+
+    let auth = auth_state.get();
+    let can_register = permissions::can_perform(&auth, Action::RegisterInvoice);
+    let can_configure = permissions::can_perform(&auth, Action::ConfigureMatching);
+
+    let today = today();
+    let all = store.invoices.get();
+    let open: Vec<&Invoice> = all.iter().filter(|i| i.status.is_open()).collect();
+    let outstanding: Money = open.iter().map(|i| i.total_amount).sum();
+    let overdue: Vec<&&Invoice> = open.iter().filter(|i| i.is_overdue_on(today)).collect();
+    let overdue_value: Money = overdue.iter().map(|i| i.total_amount).sum();
+    let due_this_week: Money = open
+        .iter()
+        .filter(|i| (0..=7).contains(&i.days_to_due(today)))
+        .map(|i| i.total_amount)
+        .sum();
+    let queue = store.exception_queue();
+    let aging = store.aging(today);
+
+    let columns = vec![
+        DataTableColumn { key: "id".to_string(), label: t("Invoice").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "po".to_string(), label: t("Order").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "amount".to_string(), label: t("Amount").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "match".to_string(), label: t("Match").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "due".to_string(), label: t("Due").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+    ];
+    let rows: Vec<DataTableRow> = store.get_filtered_invoices(today).iter().map(|inv| {
+        let days = inv.days_to_due(today);
+        let due = if !inv.status.is_open() {
+            inv.paid_date.as_deref().map(format_date).unwrap_or_default()
+        } else if days < 0 {
+            format!("{} {}", -days, t("days overdue"))
+        } else {
+            format_date(&inv.due_date)
+        };
+        DataTableRow {
+            id: inv.id.clone(),
+            cells: vec![
+                view! {
+                    <a class="invoice-ref" href={Route::InvoicesDetail(inv.id.clone()).to_path()}>
+                        <span>{inv.id.clone()}</span>
+                        <span class="invoice-muted">{inv.invoice_number.clone()}</span>
+                    </a>
+                },
+                view! { <span>{inv.supplier_name.clone()}</span> },
+                view! { <span>{inv.po_number.clone()}</span> },
+                view! { <span class="amount-cell">{format_currency(inv.total_amount)}</span> },
+                view! { <span class={format!("invoice-match {}", match_class(inv.match_status()))}>{inv.match_status().label()}</span> },
+                view! { <span class={if inv.is_overdue_on(today) { "invoice-overdue" } else { "" }}>{due}</span> },
+                invoice_status(inv.status),
+            ],
+        }
+    }).collect();
+
+    let status_options: Vec<SelectOption> = STATUS_FILTERS
+        .iter()
+        .map(|s| SelectOption { value: s.as_str().to_string(), label: t(s.as_str()).to_string() })
+        .collect();
+
+    let icon_invoice = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M14 2H6a2 2 0 0 0-2 2v16a2 2 0 0 0 2 2h12a2 2 0 0 0 2-2V8z"/><polyline points="14 2 14 8 20 8"/><line x1="8" y1="13" x2="16" y2="13"/><line x1="8" y1="17" x2="16" y2="17"/></svg>"#;
+    let icon_alert = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M10.29 3.86L1.82 18a2 2 0 0 0 1.71 3h16.94a2 2 0 0 0 1.71-3L13.71 3.86a2 2 0 0 0-3.42 0z"/><line x1="12" y1="9" x2="12" y2="13"/><line x1="12" y1="17" x2="12.01" y2="17"/></svg>"#;
+    let icon_clock = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><circle cx="12" cy="12" r="10"/><polyline points="12 6 12 12 16 14"/></svg>"#;
+    let icon_calendar = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><rect x="3" y="4" width="18" height="18" rx="2" ry="2"/><line x1="16" y1="2" x2="16" y2="6"/><line x1="8" y1="2" x2="8" y2="6"/><line x1="3" y1="10" x2="21" y2="10"/></svg>"#;
+
+    view! {
+        style {
+            r#"
+            .invoice-register { display: flex; flex-direction: column; gap: var(--space-6); }
+            .invoice-grid {
+                display: grid;
+                grid-template-columns: 1fr 2fr;
+                gap: 24px;
+            }
+            .invoice-muted { font-size: 12px; color: var(--text-muted); }
+            .invoice-ref { display: flex; flex-direction: column; }
+            .invoice-overdue { color: var(--red); font-weight: 500; }
+            .invoice-match { font-size: 12px; font-weight: 600; }
+            .invoice-match.match-full { color: var(--green); }
+            .invoice-match.match-partial { color: var(--accent); }
+            .invoice-match.match-exception { color: var(--red); }
+            .invoice-match.match-none { color: var(--text-muted); }
+            .aging-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 8px 0;
+                font-size: 13px;
+                border-bottom: 1px solid var(--border);
+            }
+            .aging-row:last-child { border-bottom: none; }
+            .aging-row.late { color: var(--red); }
+            .exception-item {
+                display: flex;
+                justify-content: space-between;
+                gap: 12px;
+                padding: 8px 0;
+                font-size: 13px;
+                border-bottom: 1px solid var(--border);
+            }
+            .exception-item:last-child { border-bottom: none; }
+            .exception-item .reason { font-size: 12px; color: var(--text-muted); }
+            .invoice-filters {
+                display: grid;
+                grid-template-columns: 200px 1fr auto;
+                gap: 16px;
+                align-items: end;
+                margin-bottom: 16px;
+            }
+            .tolerance-form {
+                display: grid;
+                grid-template-columns: repeat(3, 1fr) auto;
+                gap: 16px;
+                align-items: end;
+            }
+            @media (max-width: 1024px) {
+                .invoice-grid, .invoice-filters, .tolerance-form { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="invoice-register" data-testid="invoice-register">
+            {page_header(
+                t("Invoices").to_string(),
+                Some(t("Supplier invoices, three-way matching and payment aging").to_string()),
+                if can_register {
+                    vec![view! { <a href={Route::InvoicesCreate.to_path()} class="btn btn-primary">{t("Register Invoice")}</a> }]
+                } else {
+                    vec![]
+                }
+            )}
+
+            if let Some(err) = store.error.get() {
+                {notice_bar(err, NoticeType::Error, None)}
+            }
+            if !overdue.is_empty() {
+                {notice_bar(
+                    format!(
+                        "{} {} ({}) {} {} {}",
+                        overdue.len(),
+                        t("invoices"),
+                        format_currency(overdue_value),
+                        t("are past the"),
+                        PAYMENT_TERM_DAYS,
+                        t("day payment term")
+                    ),
+                    NoticeType::Warning,
+                    None
+                )}
+            }
+
+            <div class="kpi-grid">
+                {kpi_card(
+                    t("Outstanding").to_string(),
+                    format_currency(outstanding),
+                    KpiColor::Blue,
+                    icon_invoice.to_string(),
+                    Some(KpiDelta { value: format!("{} {}", open.len(), t("invoices")), is_positive: None, suffix: String::new() }),
+                    None
+                )}
+                {kpi_card(
+                    t("Exceptions").to_string(),
+                    queue.len().to_string(),
+                    if queue.is_empty() { KpiColor::Green } else { KpiColor::Orange },
+                    icon_alert.to_string(),
+                    None,
+                    None
+                )}
+                {kpi_card(
+                    t("Overdue").to_string(),
+                    format_currency(overdue_value),
+                    if overdue.is_empty() { KpiColor::Green } else { KpiColor::Red },
+                    icon_clock.to_string(),
+                    Some(KpiDelta { value: format!("{} {}", overdue.len(), t("invoices")), is_positive: Some(overdue.is_empty()), suffix: String::new() }),
+                    None
+                )}
+                {kpi_card(
+                    t("Due in 7 Days").to_string(),
+                    format_currency(due_this_week),
+                    KpiColor::Purple,
+                    icon_calendar.to_string(),
+                    None,
+                    None
+                )}
+            </div>
+
+            <div class="invoice-grid">
+                {panel(
+                    t("Payment Aging").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div data-testid="invoice-aging">
+                            for (bucket, count, value) in aging.clone() {
+                                <div class={if bucket == AgingBucket::Current { "aging-row" } else { "aging-row late" }}>
+                                    <span>{bucket.label()}</span>
+                                    <span>{format!("{} · {}", count, format_currency(value))}</span>
+                                </div>
+                            }
+                        </div>
+                    }]
+                )}
+
+                {panel(
+                    format!("{} ({})", t("Exception Queue"), queue.len()),
+                    vec![],
+                    vec![view! {
+                        <div data-testid="invoice-exceptions">
+                            if queue.is_empty() {
+                                <div class="invoice-muted">{t("No invoices need attention")}</div>
+                            }
+                            for inv in queue.clone() {
+                                <div class="exception-item">
+                                    <div>
+                                        <a href={Route::InvoicesDetail(inv.id.clone()).to_path()}>
+                                            {format!("{} · {} {}", inv.id, inv.supplier_name, inv.invoice_number)}
+                                        </a>
+                                        <div class="reason">
+                                            {match (&inv.duplicate_of, &inv.match_result) {
+                                                (Some(dup), _) => format!("{} {}", t("Possible duplicate of"), dup),
+                                                (None, Some(m)) => m.exceptions.first().cloned().unwrap_or_default(),
+                                                (None, None) => t("Not matched").to_string(),
+                                            }}
+                                        </div>
+                                    </div>
+                                    <span class="amount-cell">{format_currency(inv.total_amount)}</span>
+                                </div>
+                            }
+                        </div>
+                    }]
+                )}
+            </div>
+
+            {panel(
+                t("Invoice Register").to_string(),
+                vec![],
+                vec![
+                    view! {
+                        <div class="invoice-filters">
+                            {select(t("Status").to_string(), status_filter.clone(), status_options.clone(), Some(t("All statuses").to_string()), false, false, None)}
+                            {text_input(t("Search").to_string(), search.clone(), Some(t("Invoice, supplier or order").to_string()), false, false, None, None, None)}
+                            {checkbox(t("Overdue only").to_string(), overdue_only.clone(), false)}
+                        </div>
+                    },
+                    if rows.is_empty() {
+                        empty_state(t("No invoices found").to_string(), None, None, None)
+                    } else {
+                        data_table_with_testid(columns, rows, None, Some("invoices-table".to_string()), None)
+                    },
+                ]
+            )}
+
+            if can_configure {
+                {panel(
+                    t("Match Tolerances").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div>
+                            if tolerances_saved.get() {
+                                {notice_bar(t("Match tolerances saved").to_string(), NoticeType::Success, None)}
+                            }
+                            <div class="tolerance-form" data-testid="match-tolerances">
+                                {percentage_input(t("Quantity").to_string(), quantity_tolerance.clone(), true, false, None, Some(t("Invoiced against accepted").to_string()))}
+                                {percentage_input(t("Unit Price").to_string(), price_tolerance.clone(), true, false, None, Some(t("Invoiced against ordered").to_string()))}
+                                {percentage_input(t("Invoice Total").to_string(), total_tolerance.clone(), true, false, None, None)}
+                                <button class="btn btn-secondary" disabled={store.saving.get()} on:click={handle_save_tolerances}>
+                                    {t("Save Tolerances")}
+                                </button>
+                            </div>
+                        </div>
+                    }]
+                )}
+            }
+        </div>
+    }
+}
@@ -0,0 +1,12 @@
+//! Invoice Management feature module
+//!
+//! Registers supplier invoices against purchase orders, matches them three
+//! ways against order lines and accepted goods, queues exceptions for
+//! resolution and tracks PFMA payment aging.
+
+pub mod types;
+pub mod store;
+pub mod service;
+pub mod invoice_list;
+pub mod invoice_form;
+pub mod invoice_detail;
@@ -0,0 +1,817 @@
+//! Invoice Management service
+//!
+//! Invoices are checked for duplicates when they are registered and then
+//! matched three ways: each line against the purchase order line it bills
+//! and against the quantities accepted on goods receipts. Invoices that
+//! match within tolerance wait for approval; the rest go to the exception
+//! queue until someone accepts the variance, holds or rejects them.
+
+use std::collections::HashMap;
+use crate::features::audit::{self, AuditActionType, AuditEntityType, AuditEntry, FieldChange};
+use crate::features::goods_receipt::service as goods_receipt;
+use crate::features::goods_receipt::types::{GoodsReceipt, GoodsReceiptStatus};
+use crate::features::purchase_orders::service as purchase_orders;
+use crate::features::purchase_orders::types::PurchaseOrder;
+use crate::util::api::{self, HttpMethod};
+use crate::util::auth;
+use crate::util::backend;
+use crate::util::datetime::{now_iso, today, Date};
+use crate::util::format::format_currency_full;
+use crate::util::money::Money;
+use crate::util::permissions::{self, Action};
+use crate::util::reference::{self, DocumentType};
+use super::store::{InvoicesStore, load_mock_invoices};
+use super::types::{
+    DuplicateMatch, DuplicateReason, ExceptionAction, ExceptionResolution, Invoice, InvoiceStatus,
+    LineMatch, LineMatchResult, MatchResult, MatchStatus, MatchTolerances, DUPLICATE_WINDOW_DAYS,
+    PAYMENT_TERM_DAYS,
+};
+
+/// Load the invoice register and the organisation's matching tolerances
+pub async fn load_invoices(store: &InvoicesStore) {
+    store.loading.set(true);
+    store.error.set(None);
+
+    if backend::is_mock() {
+        if store.invoices.get().is_empty() {
+            load_mock_invoices(store);
+        }
+    } else {
+        let loaded = async {
+            backend::fetch_with_snapshot("/invoices", &store.invoices).await?;
+            backend::fetch_with_snapshot("/settings/match-tolerances", &store.tolerances).await
+        };
+        if let Err(e) = loaded.await {
+            store.error.set(Some(e.to_string()));
+        }
+    }
+
+    store.loading.set(false);
+}
+
+/// Load a single invoice by ID
+pub async fn load_invoice(store: &InvoicesStore, id: &str) {
+    let id = reference::resolve(id);
+    load_invoices(store).await;
+
+    let loaded = if backend::is_mock() {
+        store
+            .invoices
+            .get()
+            .into_iter()
+            .find(|i| i.id == id)
+            .ok_or_else(|| format!("Invoice {} not found", id))
+    } else {
+        api::get::<Invoice>(&format!("/invoices/{}", id)).await.map_err(|e| e.to_string())
+    };
+    match loaded {
+        Ok(invoice) => store.selected.set(Some(invoice)),
+        Err(e) => {
+            store.selected.set(None);
+            store.error.set(Some(e));
+        }
+    }
+}
+
+/// Normalise a supplier's invoice number so "INV-00123" and "inv 123" compare equal
+fn normalise_number(number: &str) -> String {
+    let mut normalised = String::new();
+    let mut in_digits = false;
+    for c in number.chars().filter(|c| c.is_ascii_alphanumeric()) {
+        if c == '0' && !in_digits {
+            continue;
+        }
+        in_digits = c.is_ascii_digit();
+        normalised.push(c.to_ascii_uppercase());
+    }
+    normalised
+}
+
+/// Registered invoices the candidate may duplicate (SRS FR-3.1.3, BR-03)
+pub fn find_duplicates(existing: &[Invoice], candidate: &Invoice) -> Vec<DuplicateMatch> {
+    let number = candidate.invoice_number.trim();
+    let normalised = normalise_number(number);
+    let received = Date::parse(&candidate.received_date);
+
+    existing
+        .iter()
+        .filter(|i| i.id != candidate.id && i.supplier_id == candidate.supplier_id)
+        .filter(|i| !matches!(i.status, InvoiceStatus::Rejected | InvoiceStatus::Cancelled))
+        .filter_map(|i| {
+            let reason = if i.invoice_number.trim().eq_ignore_ascii_case(number) {
+                DuplicateReason::SameNumber
+            } else if !normalised.is_empty() && normalise_number(&i.invoice_number) == normalised {
+                DuplicateReason::SimilarNumber
+            } else if i.total_amount == candidate.total_amount
+                && received
+                    .zip(Date::parse(&i.received_date))
+                    .map_or(false, |(a, b)| a.days_until(b).abs() <= DUPLICATE_WINDOW_DAYS)
+            {
+                DuplicateReason::SameAmount
+            } else {
+                return None;
+            };
+            Some(DuplicateMatch {
+                invoice_id: i.id.clone(),
+                invoice_number: i.invoice_number.clone(),
+                reason,
+            })
+        })
+        .collect()
+}
+
+/// Signed variance of `actual` over `expected`, in percent
+fn variance(actual: f64, expected: f64) -> f64 {
+    if expected == 0.0 {
+        if actual == 0.0 { 0.0 } else { 100.0 }
+    } else {
+        (actual - expected) / expected * 100.0
+    }
+}
+
+/// Match an invoice against its purchase order and the goods accepted on
+/// receipts (SRS FR-3.2).
+///
+/// Accepted quantities are keyed on the PO line, so two lines for the same
+/// item code are matched separately. `billed` holds quantities other invoices
+/// have already claimed per PO line, so a second invoice for the same
+/// delivery shows as a quantity variance. Only billing more than was accepted
+/// or at more than was ordered is an exception; a part invoice, or a price or
+/// total below the order, is not. VAT is expected at each order line's own
+/// rate. The caller stamps `matched_at`.
+pub fn three_way_match(
+    invoice: &Invoice,
+    po: Option<&PurchaseOrder>,
+    receipts: &[GoodsReceipt],
+    billed: &HashMap<String, u32>,
+    tolerances: &MatchTolerances,
+) -> MatchResult {
+    let Some(po) = po else {
+        return MatchResult {
+            status: MatchStatus::NoMatch,
+            exceptions: vec![format!("Purchase order {} not found", invoice.po_number)],
+            ..Default::default()
+        };
+    };
+
+    let mut accepted: HashMap<String, u32> = HashMap::new();
+    for receipt in receipts.iter().filter(|r| {
+        matches!(r.status, GoodsReceiptStatus::PartiallyReceived | GoodsReceiptStatus::Completed)
+    }) {
+        for item in &receipt.items_received {
+            if let Some(po_line) = po.line_for(item.po_line_id.as_deref(), &item.item_code) {
+                *accepted.entry(po_line.id.clone()).or_default() += item.accepted_quantity;
+            }
+        }
+    }
+    if accepted.values().all(|q| *q == 0) {
+        return MatchResult {
+            status: MatchStatus::NoMatch,
+            exceptions: vec![format!("No goods have been accepted against {}", po.po_number)],
+            ..Default::default()
+        };
+    }
+
+    let mut exceptions = Vec::new();
+    if !invoice.supplier_id.is_empty() && invoice.supplier_id != po.supplier.id {
+        exceptions.push(format!("Supplier does not match {} ({})", po.po_number, po.supplier.name));
+    }
+
+    let mut expected_total = Money::ZERO;
+    let lines: Vec<LineMatch> = invoice.lines.iter().map(|line| {
+        let Some(po_line) = po.line_for(line.po_line_id.as_deref(), &line.item_code) else {
+            exceptions.push(format!("\"{}\" is not on {}", line.description, po.po_number));
+            return LineMatch {
+                invoice_line_id: line.id.clone(),
+                po_line_id: None,
+                description: line.description.clone(),
+                ordered_quantity: 0,
+                accepted_quantity: 0,
+                invoiced_quantity: line.quantity,
+                ordered_price: Money::ZERO,
+                invoiced_price: line.unit_price,
+                quantity_variance: 100.0,
+                price_variance: 0.0,
+                result: LineMatchResult::NotOrdered,
+            };
+        };
+
+        let received = accepted.get(&po_line.id).copied().unwrap_or(0);
+        let available = received.saturating_sub(billed.get(&po_line.id).copied().unwrap_or(0));
+        let quantity_variance = variance(line.quantity as f64, available as f64);
+        let price_variance = variance(line.unit_price.to_rands(), po_line.unit_price.to_rands());
+        expected_total += (po_line.unit_price * line.quantity.min(available)).with_vat(po_line.tax_rate);
+
+        // Negative variances are a part invoice or a discount, which is fine
+        let result = if quantity_variance <= 0.0 && price_variance == 0.0 {
+            LineMatchResult::Matched
+        } else if quantity_variance <= tolerances.quantity && price_variance <= tolerances.price {
+            LineMatchResult::WithinTolerance
+        } else {
+            if quantity_variance > tolerances.quantity {
+                exceptions.push(format!(
+                    "{}: invoiced {} but {} accepted and not yet billed",
+                    po_line.description, line.quantity, available
+                ));
+            }
+            if price_variance > tolerances.price {
+                exceptions.push(format!(
+                    "{}: invoiced at {} against {} ordered ({:+.1}%)",
+                    po_line.description,
+                    format_currency_full(line.unit_price),
+                    format_currency_full(po_line.unit_price),
+                    price_variance
+                ));
+            }
+            LineMatchResult::Exception
+        };
+
+        LineMatch {
+            invoice_line_id: line.id.clone(),
+            po_line_id: Some(po_line.id.clone()),
+            description: line.description.clone(),
+            ordered_quantity: po_line.quantity,
+            accepted_quantity: available,
+            invoiced_quantity: line.quantity,
+            ordered_price: po_line.unit_price,
+            invoiced_price: line.unit_price,
+            quantity_variance,
+            price_variance,
+            result,
+        }
+    }).collect();
+
+    let total_variance = variance(invoice.total_amount.to_rands(), expected_total.to_rands());
+    if total_variance > tolerances.total {
+        exceptions.push(format!(
+            "Invoice total {} differs from accepted goods of {} ({:+.1}%)",
+            format_currency_full(invoice.total_amount),
+            format_currency_full(expected_total),
+            total_variance
+        ));
+    }
+
+    let status = if !exceptions.is_empty() {
+        MatchStatus::Exception
+    } else if total_variance == 0.0 && lines.iter().all(|l| l.result == LineMatchResult::Matched) {
+        MatchStatus::FullMatch
+    } else {
+        MatchStatus::PartialMatch
+    };
+
+    MatchResult { status, lines, expected_total, total_variance, exceptions, ..Default::default() }
+}
+
+/// Fetch the order and receipts for an invoice and match it
+async fn run_match(store: &InvoicesStore, invoice: &Invoice) -> Result<MatchResult, String> {
+    let po = purchase_orders::find_purchase_order(&invoice.po_number).await.ok();
+    let receipts = match &po {
+        Some(po) => goods_receipt::receipts_for_po(&po.po_number).await?,
+        None => Vec::new(),
+    };
+
+    let mut billed: HashMap<String, u32> = HashMap::new();
+    for other in store.invoices.get().iter().filter(|i| {
+        i.id != invoice.id
+            && i.po_number == invoice.po_number
+            && !matches!(i.status, InvoiceStatus::Rejected | InvoiceStatus::Cancelled)
+            && i.duplicate_of.is_none()
+    }) {
+        for line in &other.lines {
+            if let Some(po_line) = po.as_ref().and_then(|po| po.line_for(line.po_line_id.as_deref(), &line.item_code)) {
+                *billed.entry(po_line.id.clone()).or_default() += line.quantity;
+            }
+        }
+    }
+
+    let result = three_way_match(invoice, po.as_ref(), &receipts, &billed, &store.tolerances.get());
+    Ok(MatchResult { matched_at: now_iso(), ..result })
+}
+
+/// Status an invoice moves to after matching
+fn status_after(result: &MatchResult) -> InvoiceStatus {
+    match result.status {
+        MatchStatus::FullMatch | MatchStatus::PartialMatch => InvoiceStatus::Matched,
+        _ => InvoiceStatus::Exception,
+    }
+}
+
+fn validate_invoice(invoice: &Invoice) -> Result<(), String> {
+    if invoice.invoice_number.trim().is_empty() {
+        return Err("Supplier invoice number is required".to_string());
+    }
+    if invoice.po_number.trim().is_empty() {
+        return Err("Purchase order reference is required".to_string());
+    }
+    let invoice_date = Date::parse(&invoice.invoice_date).ok_or("Invoice date is required")?;
+    let received = Date::parse(&invoice.received_date).ok_or("Date received is required")?;
+    if received < invoice_date {
+        return Err("An invoice cannot be received before it is dated".to_string());
+    }
+    if received > today() {
+        return Err("Date received cannot be in the future".to_string());
+    }
+    if invoice.lines.is_empty() {
+        return Err("Add at least one invoice line".to_string());
+    }
+    if invoice.lines.iter().any(|l| l.quantity == 0 || !l.unit_price.is_positive()) {
+        return Err("Every invoice line needs a quantity and a unit price".to_string());
+    }
+    Ok(())
+}
+
+/// Register a supplier invoice, check it for duplicates and match it
+pub async fn register_invoice(store: &InvoicesStore, invoice: Invoice) -> Result<Invoice, String> {
+    register(store, invoice, true).await
+}
+
+/// Register a captured invoice as a draft without matching it yet, for
+/// invoices read from scanned documents that a clerk still has to check
+pub async fn register_draft_invoice(store: &InvoicesStore, invoice: Invoice) -> Result<Invoice, String> {
+    register(store, invoice, false).await
+}
+
+async fn register(store: &InvoicesStore, mut invoice: Invoice, run_matching: bool) -> Result<Invoice, String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::RegisterInvoice)?;
+        validate_invoice(&invoice)?;
+        if store.invoices.get().is_empty() {
+            load_invoices(store).await;
+        }
+
+        let po = purchase_orders::find_purchase_order(&invoice.po_number).await?;
+        invoice.po_number = po.po_number.clone();
+        invoice.supplier_id = po.supplier.id.clone();
+        invoice.supplier_name = po.supplier.name.clone();
+        invoice.invoice_number = invoice.invoice_number.trim().to_string();
+        for (n, line) in invoice.lines.iter_mut().enumerate() {
+            line.id = format!("IL-{:03}", n + 1);
+        }
+        invoice.calculate_totals();
+        if invoice.due_date.is_empty() {
+            invoice.due_date = Date::parse(&invoice.received_date)
+                .map(|d| d.add_days(PAYMENT_TERM_DAYS).to_iso())
+                .unwrap_or_default();
+        }
+
+        let existing = store.invoices.get();
+        let duplicates = find_duplicates(&existing, &invoice);
+        if let Some(dup) = duplicates.iter().find(|d| d.reason.is_blocking()) {
+            return Err(format!(
+                "Invoice {} from {} is already registered as {}",
+                invoice.invoice_number, invoice.supplier_name, dup.invoice_id
+            ));
+        }
+
+        invoice.id = reference::issue(DocumentType::Invoice, existing.iter().map(|i| i.id.as_str())).number;
+        invoice.registered_by = auth::current().user.map(|u| u.name).unwrap_or_default();
+        invoice.created_at = now_iso();
+
+        if let Some(dup) = duplicates.first() {
+            // Possible duplicates are held rather than matched, so they cannot be paid twice
+            invoice.duplicate_of = Some(dup.invoice_id.clone());
+            invoice.status = InvoiceStatus::OnHold;
+            invoice.resolution = Some(ExceptionResolution {
+                action: ExceptionAction::Hold,
+                justification: format!("Possible duplicate of {} ({})", dup.invoice_id, dup.reason.label()),
+                resolved_by: "System".to_string(),
+                resolved_at: now_iso(),
+            });
+        } else if run_matching {
+            let matched = run_match(store, &invoice).await?;
+            invoice.status = status_after(&matched);
+            invoice.match_result = Some(matched);
+        } else {
+            invoice.status = InvoiceStatus::Registered;
+        }
+
+        let saved = backend::save(HttpMethod::Post, "/invoices", &invoice)
+            .await
+            .map_err(|e| e.to_string())?;
+        reference::reconcile(DocumentType::Invoice, &invoice.id, &saved.id);
+        audit::service::record(AuditEntry {
+            action: AuditActionType::Create,
+            entity_type: AuditEntityType::Invoice,
+            entity_id: saved.id.clone(),
+            entity_name: Some(format!("{} {}", saved.supplier_name, saved.invoice_number)),
+            description: Some(format!(
+                "Registered invoice {} for {} against {}: {}",
+                saved.invoice_number,
+                format_currency_full(saved.total_amount),
+                saved.po_number,
+                match &saved.duplicate_of {
+                    Some(dup) => format!("held as a possible duplicate of {}", dup),
+                    None => saved.match_status().label().to_string(),
+                }
+            )),
+            ..Default::default()
+        });
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    match &result {
+        Ok(saved) => {
+            let mut invoices = store.invoices.get();
+            invoices.insert(0, saved.clone());
+            store.invoices.set(invoices);
+        }
+        Err(e) => store.error.set(Some(e.clone())),
+    }
+    store.saving.set(false);
+    result
+}
+
+/// Replace an invoice in the register and the selection
+fn put_invoice(store: &InvoicesStore, invoice: &Invoice) {
+    let mut invoices = store.invoices.get();
+    if let Some(existing) = invoices.iter_mut().find(|i| i.id == invoice.id) {
+        *existing = invoice.clone();
+    }
+    store.invoices.set(invoices);
+    if store.selected.get().map_or(false, |s| s.id == invoice.id) {
+        store.selected.set(Some(invoice.clone()));
+    }
+}
+
+fn find_invoice(store: &InvoicesStore, id: &str) -> Result<Invoice, String> {
+    store
+        .invoices
+        .get()
+        .into_iter()
+        .find(|i| i.id == id)
+        .ok_or_else(|| format!("Invoice {} not found", id))
+}
+
+/// Match an invoice again, e.g. once outstanding goods are received or a
+/// credit note has arrived
+pub async fn rematch_invoice(store: &InvoicesStore, id: &str) -> Result<(), String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::RegisterInvoice)?;
+        let mut invoice = find_invoice(store, id)?;
+        if !matches!(invoice.status, InvoiceStatus::Registered | InvoiceStatus::Exception | InvoiceStatus::OnHold) {
+            return Err(format!("A {} invoice cannot be matched again", invoice.status.as_str().to_lowercase()));
+        }
+        let previous = invoice.match_status();
+        let matched = run_match(store, &invoice).await?;
+        invoice.status = status_after(&matched);
+        invoice.match_result = Some(matched);
+
+        let endpoint = format!("/invoices/{}/match", id);
+        let saved = backend::save(HttpMethod::Post, &endpoint, &invoice)
+            .await
+            .map_err(|e| e.to_string())?;
+        audit::service::record(AuditEntry {
+            action: AuditActionType::Update,
+            entity_type: AuditEntityType::Invoice,
+            entity_id: saved.id.clone(),
+            entity_name: Some(format!("{} {}", saved.supplier_name, saved.invoice_number)),
+            changes: vec![FieldChange::new("match_status", Some(previous.label()), Some(saved.match_status().label()))],
+            description: Some(format!("Re-ran three-way match against {}", saved.po_number)),
+            ..Default::default()
+        });
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    match result {
+        Ok(saved) => put_invoice(store, &saved),
+        Err(ref e) => store.error.set(Some(e.clone())),
+    }
+    store.saving.set(false);
+    result.map(|_| ())
+}
+
+/// Handle an invoice in the exception queue (SRS FR-3.3.2)
+pub async fn resolve_exception(
+    store: &InvoicesStore,
+    id: &str,
+    action: ExceptionAction,
+    justification: &str,
+) -> Result<(), String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::ApproveInvoice)?;
+        let mut invoice = find_invoice(store, id)?;
+        if !invoice.status.needs_attention() {
+            return Err("Only invoices in the exception queue can be resolved".to_string());
+        }
+        let justification = justification.trim();
+        if justification.is_empty() {
+            return Err(format!("Give a reason to {}", action.label().to_lowercase()));
+        }
+
+        let previous = invoice.status;
+        invoice.status = match action {
+            ExceptionAction::AcceptVariance => {
+                if invoice.match_status() == MatchStatus::NoMatch {
+                    return Err("A variance cannot be accepted until the order and goods receipt are found".to_string());
+                }
+                if invoice.duplicate_of.is_some() && invoice.match_result.is_none() {
+                    return Err("Match the invoice before accepting it".to_string());
+                }
+                InvoiceStatus::Matched
+            }
+            ExceptionAction::RequestCreditNote | ExceptionAction::Hold => InvoiceStatus::OnHold,
+            ExceptionAction::Reject => InvoiceStatus::Rejected,
+        };
+        invoice.resolution = Some(ExceptionResolution {
+            action,
+            justification: justification.to_string(),
+            resolved_by: auth::current().user.map(|u| u.name).unwrap_or_default(),
+            resolved_at: now_iso(),
+        });
+
+        let endpoint = format!("/invoices/{}/resolution", id);
+        let saved = backend::save(HttpMethod::Post, &endpoint, &invoice)
+            .await
+            .map_err(|e| e.to_string())?;
+        audit::service::record(AuditEntry {
+            action: if action == ExceptionAction::Reject { AuditActionType::Reject } else { AuditActionType::Update },
+            entity_type: AuditEntityType::Invoice,
+            entity_id: saved.id.clone(),
+            entity_name: Some(format!("{} {}", saved.supplier_name, saved.invoice_number)),
+            changes: vec![FieldChange::new("status", Some(previous.as_str()), Some(saved.status.as_str()))],
+            description: Some(format!("{}: {}", action.label(), justification)),
+            ..Default::default()
+        });
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    match result {
+        Ok(saved) => put_invoice(store, &saved),
+        Err(ref e) => store.error.set(Some(e.clone())),
+    }
+    store.saving.set(false);
+    result.map(|_| ())
+}
+
+/// Approve a matched invoice for payment
+pub async fn approve_invoice(store: &InvoicesStore, id: &str) -> Result<(), String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::ApproveInvoice)?;
+        let mut invoice = find_invoice(store, id)?;
+        if invoice.status != InvoiceStatus::Matched {
+            return Err("Only matched invoices can be approved for payment".to_string());
+        }
+        let approver = auth::current().user.map(|u| u.name).unwrap_or_default();
+        if approver.eq_ignore_ascii_case(&invoice.registered_by) {
+            return Err("Invoices must be approved by someone other than the person who registered them".to_string());
+        }
+        invoice.status = InvoiceStatus::Approved;
+        invoice.approved_by = Some(approver);
+        invoice.approved_at = Some(now_iso());
+
+        let endpoint = format!("/invoices/{}/approve", id);
+        let saved = backend::save(HttpMethod::Post, &endpoint, &invoice)
+            .await
+            .map_err(|e| e.to_string())?;
+        audit::service::record(AuditEntry {
+            action: AuditActionType::Approve,
+            entity_type: AuditEntityType::Invoice,
+            entity_id: saved.id.clone(),
+            entity_name: Some(format!("{} {}", saved.supplier_name, saved.invoice_number)),
+            description: Some(format!(
+                "Approved {} for payment by {}",
+                format_currency_full(saved.total_amount),
+                saved.due_date
+            )),
+            ..Default::default()
+        });
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    match result {
+        Ok(saved) => put_invoice(store, &saved),
+        Err(ref e) => store.error.set(Some(e.clone())),
+    }
+    store.saving.set(false);
+    result.map(|_| ())
+}
+
+/// Bring loaded invoices in line with a payment run. The backend moves a
+/// run's invoices itself when the run changes; this keeps the register
+/// current without reloading it.
+pub fn apply_payment_run(
+    store: &InvoicesStore,
+    invoice_ids: &[String],
+    run_id: Option<&str>,
+    status: InvoiceStatus,
+    paid_date: Option<&str>,
+) {
+    for mut invoice in store.invoices.get().into_iter().filter(|i| invoice_ids.contains(&i.id)) {
+        invoice.status = status;
+        invoice.payment_run = run_id.map(str::to_string);
+        if status == InvoiceStatus::Paid {
+            invoice.paid_date = paid_date.map(str::to_string);
+            invoice.paid_amount = invoice.total_amount;
+        }
+        put_invoice(store, &invoice);
+    }
+}
+
+/// Change the organisation's three-way match tolerances
+pub async fn save_tolerances(store: &InvoicesStore, tolerances: MatchTolerances) -> Result<(), String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::ConfigureMatching)?;
+        for (name, value) in [("Quantity", tolerances.quantity), ("Price", tolerances.price), ("Total", tolerances.total)] {
+            if !(0.0..=25.0).contains(&value) {
+                return Err(format!("{} tolerance must be between 0% and 25%", name));
+            }
+        }
+        let previous = store.tolerances.get();
+        let saved = backend::save(HttpMethod::Put, "/settings/match-tolerances", &tolerances)
+            .await
+            .map_err(|e| e.to_string())?;
+        audit::service::record(AuditEntry {
+            action: AuditActionType::Update,
+            entity_type: AuditEntityType::System,
+            entity_id: "match-tolerances".to_string(),
+            entity_name: Some("Three-way match tolerances".to_string()),
+            changes: vec![
+                FieldChange::new("quantity", Some(&format!("{}%", previous.quantity)), Some(&format!("{}%", saved.quantity))),
+                FieldChange::new("price", Some(&format!("{}%", previous.price)), Some(&format!("{}%", saved.price))),
+                FieldChange::new("total", Some(&format!("{}%", previous.total)), Some(&format!("{}%", saved.total))),
+            ],
+            description: Some("Changed the three-way match tolerances".to_string()),
+            ..Default::default()
+        });
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    match result {
+        Ok(saved) => store.tolerances.set(saved),
+        Err(ref e) => store.error.set(Some(e.clone())),
+    }
+    store.saving.set(false);
+    result.map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::goods_receipt::types::ReceivedItem;
+    use crate::features::invoices::types::InvoiceLine;
+    use crate::features::purchase_orders::types::LineItem;
+    use crate::util::money::VAT_RATE;
+
+    fn invoice(id: &str, number: &str, supplier: &str, amount: i64, received: &str) -> Invoice {
+        Invoice {
+            id: id.to_string(),
+            invoice_number: number.to_string(),
+            supplier_id: supplier.to_string(),
+            total_amount: Money::rands(amount),
+            received_date: received.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn duplicates_are_found_by_number_and_by_amount() {
+        let existing = [
+            invoice("INV-1", "SI-00123", "SUP-001", 1_000, "2025-03-03"),
+            invoice("INV-2", "SI-456", "SUP-001", 2_500, "2025-03-03"),
+            Invoice { status: InvoiceStatus::Rejected, ..invoice("INV-3", "SI-789", "SUP-001", 3_000, "2025-03-03") },
+        ];
+        let cases = [
+            (invoice("NEW", "si-00123", "SUP-001", 1, "2025-03-04"), Some(DuplicateReason::SameNumber)),
+            (invoice("NEW", "SI 123", "SUP-001", 1, "2025-03-04"), Some(DuplicateReason::SimilarNumber)),
+            (invoice("NEW", "SI-999", "SUP-001", 2_500, "2025-03-10"), Some(DuplicateReason::SameAmount)),
+            (invoice("NEW", "SI-999", "SUP-001", 2_500, "2025-03-11"), None),
+            (invoice("NEW", "SI-00123", "SUP-002", 1_000, "2025-03-03"), None),
+            (invoice("NEW", "SI-789", "SUP-001", 3_000, "2025-03-03"), None),
+            (invoice("INV-1", "SI-00123", "SUP-001", 1_000, "2025-03-03"), None),
+        ];
+        for (candidate, expected) in cases {
+            let found = find_duplicates(&existing, &candidate);
+            assert_eq!(found.first().map(|d| d.reason), expected, "{}", candidate.invoice_number);
+            assert!(found.len() <= 1, "{}", candidate.invoice_number);
+        }
+    }
+
+    fn order() -> PurchaseOrder {
+        PurchaseOrder {
+            po_number: "PO-2025-0001".to_string(),
+            line_items: vec![LineItem {
+                id: "POL-1".to_string(),
+                item_code: "PAPER-A4".to_string(),
+                description: "A4 paper".to_string(),
+                quantity: 10,
+                unit_price: Money::rands(100),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn receipt(status: GoodsReceiptStatus, accepted: u32) -> GoodsReceipt {
+        GoodsReceipt {
+            status,
+            items_received: vec![ReceivedItem {
+                po_line_id: Some("POL-1".to_string()),
+                item_code: "PAPER-A4".to_string(),
+                accepted_quantity: accepted,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn billing(item_code: &str, quantity: u32, rands: i64) -> Invoice {
+        let mut invoice = Invoice {
+            po_number: "PO-2025-0001".to_string(),
+            lines: vec![InvoiceLine {
+                item_code: item_code.to_string(),
+                description: item_code.to_string(),
+                quantity,
+                unit_price: Money::rands(rands),
+                tax_rate: VAT_RATE,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        invoice.calculate_totals();
+        invoice
+    }
+
+    fn zero_rated(mut invoice: Invoice) -> Invoice {
+        invoice.lines[0].tax_rate = 0.0;
+        invoice.calculate_totals();
+        invoice
+    }
+
+    #[test]
+    fn three_way_match_grades_lines_against_accepted_goods() {
+        // (invoice, already billed, line result, overall status)
+        let cases = [
+            (billing("PAPER-A4", 10, 100), 0, LineMatchResult::Matched, MatchStatus::FullMatch),
+            (billing("PAPER-A4", 5, 100), 0, LineMatchResult::Matched, MatchStatus::FullMatch),
+            (billing("PAPER-A4", 10, 101), 0, LineMatchResult::WithinTolerance, MatchStatus::PartialMatch),
+            (billing("PAPER-A4", 10, 103), 0, LineMatchResult::Exception, MatchStatus::Exception),
+            (billing("PAPER-A4", 10, 90), 0, LineMatchResult::WithinTolerance, MatchStatus::PartialMatch),
+            (billing("PAPER-A4", 10, 100), 10, LineMatchResult::Exception, MatchStatus::Exception),
+            (billing("TONER", 1, 100), 0, LineMatchResult::NotOrdered, MatchStatus::Exception),
+        ];
+        let po = order();
+        let receipts = [receipt(GoodsReceiptStatus::Completed, 10)];
+        for (i, (invoice, billed, line, status)) in cases.into_iter().enumerate() {
+            let billed = HashMap::from([("POL-1".to_string(), billed)]);
+            let result = three_way_match(&invoice, Some(&po), &receipts, &billed, &MatchTolerances::default());
+            assert_eq!(result.lines[0].result, line, "case {}", i);
+            assert_eq!(result.status, status, "case {}", i);
+            assert_eq!(result.exceptions.is_empty(), status != MatchStatus::Exception, "case {}", i);
+        }
+    }
+
+    #[test]
+    fn three_way_match_expects_vat_on_what_was_accepted() {
+        let invoice = billing("PAPER-A4", 5, 100);
+        let receipts = [receipt(GoodsReceiptStatus::PartiallyReceived, 5)];
+        let result = three_way_match(&invoice, Some(&order()), &receipts, &HashMap::new(), &MatchTolerances::default());
+        assert_eq!(result.expected_total, Money::rands(500).with_vat(VAT_RATE));
+        assert_eq!(result.total_variance, 0.0);
+    }
+
+    #[test]
+    fn three_way_match_expects_vat_at_each_order_line_rate() {
+        let mut po = order();
+        po.line_items[0].tax_rate = 0.0;
+        let receipts = [receipt(GoodsReceiptStatus::Completed, 10)];
+        let tolerances = MatchTolerances::default();
+
+        let invoice = zero_rated(billing("PAPER-A4", 10, 100));
+        assert_eq!(invoice.vat_amount, Money::ZERO);
+        let result = three_way_match(&invoice, Some(&po), &receipts, &HashMap::new(), &tolerances);
+        assert_eq!(result.status, MatchStatus::FullMatch);
+
+        let result = three_way_match(&billing("PAPER-A4", 10, 100), Some(&po), &receipts, &HashMap::new(), &tolerances);
+        assert_eq!(result.status, MatchStatus::Exception);
+        assert!(result.total_variance > 0.0);
+    }
+
+    #[test]
+    fn three_way_match_needs_an_order_and_accepted_goods() {
+        let invoice = billing("PAPER-A4", 10, 100);
+        let tolerances = MatchTolerances::default();
+        let drafts = [receipt(GoodsReceiptStatus::Draft, 10)];
+        assert_eq!(three_way_match(&invoice, None, &[], &HashMap::new(), &tolerances).status, MatchStatus::NoMatch);
+        assert_eq!(three_way_match(&invoice, Some(&order()), &drafts, &HashMap::new(), &tolerances).status, MatchStatus::NoMatch);
+    }
+}
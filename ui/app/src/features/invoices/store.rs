@@ -0,0 +1,310 @@
+//! Invoice Management store and mock invoice register
+
+use std::collections::HashMap;
+use components::prelude::*;
+use crate::features::goods_receipt::store::get_mock_receipts_for_po;
+use crate::features::purchase_orders::store::get_mock_purchase_order;
+use crate::util::datetime::Date;
+use crate::util::money::{Money, VAT_RATE};
+use super::service::three_way_match;
+use super::types::{
+    AgingBucket, ExceptionAction, ExceptionResolution, Invoice, InvoiceFilter, InvoiceLine,
+    InvoiceStatus, MatchResult, MatchStatus, MatchTolerances,
+};
+
+/// Invoice Management state store
+#[derive(Clone)]
+pub struct InvoicesStore {
+    pub invoices: Signal<Vec<Invoice>>,
+    pub selected: Signal<Option<Invoice>>,
+    pub filter: Signal<InvoiceFilter>,
+    pub tolerances: Signal<MatchTolerances>,
+    pub loading: Signal<bool>,
+    pub saving: Signal<bool>,
+    pub error: Signal<Option<String>>,
+}
+
+impl InvoicesStore {
+    pub fn new() -> Self {
+        Self {
+            invoices: signal(Vec::new()),
+            selected: signal(None),
+            filter: signal(InvoiceFilter::default()),
+            tolerances: signal(MatchTolerances::default()),
+            loading: signal(false),
+            saving: signal(false),
+            error: signal(None),
+        }
+    }
+
+    /// Update filter status
+    pub fn set_filter_status(&self, status: Option<InvoiceStatus>) {
+        let mut filter = self.filter.get();
+        filter.status = status;
+        self.filter.set(filter);
+    }
+
+    /// Update filter search
+    pub fn set_filter_search(&self, search: Option<String>) {
+        let mut filter = self.filter.get();
+        filter.search = search;
+        self.filter.set(filter);
+    }
+
+    /// Show only overdue invoices
+    pub fn set_overdue_only(&self, overdue_only: bool) {
+        let mut filter = self.filter.get();
+        filter.overdue_only = overdue_only;
+        self.filter.set(filter);
+    }
+
+    /// Get invoices matching the current filter
+    pub fn get_filtered_invoices(&self, today: Date) -> Vec<Invoice> {
+        let filter = self.filter.get();
+        let search = filter.search.map(|s| s.to_lowercase()).filter(|s| !s.is_empty());
+        self.invoices
+            .get()
+            .into_iter()
+            .filter(|i| filter.status.map_or(true, |s| i.status == s))
+            .filter(|i| !filter.overdue_only || i.is_overdue_on(today))
+            .filter(|i| {
+                search.as_ref().map_or(true, |q| {
+                    i.id.to_lowercase().contains(q)
+                        || i.invoice_number.to_lowercase().contains(q)
+                        || i.supplier_name.to_lowercase().contains(q)
+                        || i.po_number.to_lowercase().contains(q)
+                })
+            })
+            .collect()
+    }
+
+    /// Invoices waiting in the exception queue, oldest first
+    pub fn exception_queue(&self) -> Vec<Invoice> {
+        let mut queue: Vec<Invoice> = self
+            .invoices
+            .get()
+            .into_iter()
+            .filter(|i| i.status.needs_attention())
+            .collect();
+        queue.sort_by(|a, b| a.received_date.cmp(&b.received_date));
+        queue
+    }
+
+    /// Count and value of unpaid invoices per aging bucket
+    pub fn aging(&self, today: Date) -> Vec<(AgingBucket, usize, Money)> {
+        let mut buckets: HashMap<AgingBucket, (usize, Money)> = HashMap::new();
+        for invoice in self.invoices.get().iter().filter(|i| i.status.is_open()) {
+            let entry = buckets.entry(invoice.aging_on(today)).or_insert((0, Money::ZERO));
+            entry.0 += 1;
+            entry.1 += invoice.total_amount;
+        }
+        AgingBucket::ALL
+            .iter()
+            .map(|b| {
+                let (count, value) = buckets.get(b).copied().unwrap_or((0, Money::ZERO));
+                (*b, count, value)
+            })
+            .collect()
+    }
+}
+
+fn line(id: &str, item_code: &str, description: &str, quantity: u32, unit_price: i64) -> InvoiceLine {
+    InvoiceLine {
+        id: format!("IL-{}", &id[3..]),
+        po_line_id: Some(id.to_string()),
+        item_code: item_code.to_string(),
+        description: description.to_string(),
+        quantity,
+        unit_price: Money::rands(unit_price),
+        tax_rate: VAT_RATE,
+    }
+}
+
+fn invoice(
+    id: &str,
+    invoice_number: &str,
+    supplier_id: &str,
+    supplier_name: &str,
+    po_number: &str,
+    invoice_date: &str,
+    received_date: &str,
+    due_date: &str,
+    status: InvoiceStatus,
+    lines: Vec<InvoiceLine>,
+) -> Invoice {
+    let mut invoice = Invoice {
+        id: id.to_string(),
+        invoice_number: invoice_number.to_string(),
+        supplier_id: supplier_id.to_string(),
+        supplier_name: supplier_name.to_string(),
+        po_number: po_number.to_string(),
+        invoice_date: invoice_date.to_string(),
+        received_date: received_date.to_string(),
+        due_date: due_date.to_string(),
+        status,
+        lines,
+        registered_by: "Nomvula Khumalo".to_string(),
+        created_at: format!("{}T09:30:00Z", received_date),
+        ..Default::default()
+    };
+    invoice.calculate_totals();
+    invoice
+}
+
+/// Match a mock invoice against the mock order and receipts, or record the
+/// result for orders that have no detailed fixtures
+fn matched(mut invoice: Invoice, status: MatchStatus, exception: Option<&str>) -> Invoice {
+    let result = match get_mock_purchase_order(&invoice.po_number) {
+        Some(po) => three_way_match(
+            &invoice,
+            Some(&po),
+            &get_mock_receipts_for_po(&po.po_number),
+            &HashMap::new(),
+            &MatchTolerances::default(),
+        ),
+        None => MatchResult {
+            status,
+            expected_total: if status == MatchStatus::FullMatch { invoice.total_amount } else { Money::ZERO },
+            exceptions: exception.map(|e| vec![e.to_string()]).unwrap_or_default(),
+            ..Default::default()
+        },
+    };
+    invoice.match_result = Some(MatchResult { matched_at: invoice.created_at.clone(), ..result });
+    invoice
+}
+
+/// Load mock invoices for development
+pub fn load_mock_invoices(store: &InvoicesStore) {
+    let approved = |mut i: Invoice, by: &str, at: &str| {
+        i.approved_by = Some(by.to_string());
+        i.approved_at = Some(at.to_string());
+        i
+    };
+
+    let techsolutions = approved(
+        matched(
+            invoice("INV-2025-0112", "TS-INV-20931", "SUP-001", "TechSolutions SA (Pty) Ltd", "PO-2025-0456",
+                "2025-02-14", "2025-02-17", "2025-03-19", InvoiceStatus::Approved, vec![
+                    line("LI-001", "HW-SVR-001", "Dell PowerEdge R750 Server", 5, 185_000),
+                    line("LI-002", "HW-STO-001", "Dell EMC PowerStore 500T", 2, 95_000),
+                    line("LI-003", "SW-LIC-001", "VMware vSphere Enterprise Plus", 10, 8500),
+                    line("LI-004", "SVC-INS-001", "Installation and Configuration", 40, 1250),
+                ]),
+            MatchStatus::FullMatch,
+            None,
+        ),
+        "Sipho Dlamini",
+        "2025-02-19T11:20:00Z",
+    );
+
+    let autocare = matched(
+        invoice("INV-2025-0114", "ACF-55821", "SUP-004", "AutoCare Fleet Management", "PO-2025-0453",
+            "2025-02-21", "2025-02-24", "2025-03-26", InvoiceStatus::Exception, vec![
+                line("LI-001", "SVC-FSC-001", "Full Vehicle Service - Sedan", 6, 4500),
+                line("LI-002", "SVC-FSC-002", "Full Vehicle Service - SUV", 3, 6450),
+                line("LI-003", "PRT-TYR-001", "Replacement Tyres (Set of 4)", 8, 4800),
+            ]),
+        MatchStatus::Exception,
+        None,
+    );
+
+    let catering = Invoice {
+        payment_run: Some("PAY-2025-0001".to_string()),
+        paid_date: Some("2025-02-20".to_string()),
+        paid_amount: Money::rands(44_850),
+        ..approved(
+            matched(
+                invoice("INV-2025-0113", "GCC-2025-0117", "SUP-005", "Gourmet Corporate Catering", "PO-2025-0452",
+                    "2025-02-03", "2025-02-05", "2025-03-07", InvoiceStatus::Paid, vec![
+                        InvoiceLine {
+                            id: "IL-001".to_string(),
+                            po_line_id: Some("LI-001".to_string()),
+                            description: "Executive board catering (per head)".to_string(),
+                            quantity: 300,
+                            unit_price: Money::rands(130),
+                            ..Default::default()
+                        },
+                    ]),
+                MatchStatus::FullMatch,
+                None,
+            ),
+            "Sipho Dlamini",
+            "2025-02-10T14:05:00Z",
+        )
+    };
+
+    let cleaning = Invoice {
+        payment_run: Some("PAY-2025-0002".to_string()),
+        ..approved(
+            matched(
+                invoice("INV-2025-0098", "CCS/1224/0871", "SUP-008", "CleanCorp Services", "PO-2025-0449",
+                    "2024-12-06", "2024-12-10", "2025-01-09", InvoiceStatus::Approved, vec![
+                        InvoiceLine {
+                            id: "IL-001".to_string(),
+                            po_line_id: Some("LI-001".to_string()),
+                            description: "Office cleaning services - December".to_string(),
+                            quantity: 1,
+                            unit_price: Money::rands(51_000),
+                            ..Default::default()
+                        },
+                    ]),
+                MatchStatus::FullMatch,
+                None,
+            ),
+            "Sipho Dlamini",
+            "2024-12-18T10:45:00Z",
+        )
+    };
+
+    // Approved before the supplier was suspended, so payment runs leave it out
+    let trading = approved(
+        matched(
+            invoice("INV-2025-0109", "RVT-0042", "SUP-011", "Risky Ventures Trading", "PO-2025-0447",
+                "2025-01-20", "2025-01-22", "2025-02-21", InvoiceStatus::Approved, vec![
+                    InvoiceLine {
+                        id: "IL-001".to_string(),
+                        po_line_id: Some("LI-001".to_string()),
+                        description: "Stationery and printer consumables".to_string(),
+                        quantity: 1,
+                        unit_price: Money::rands(17_500),
+                        ..Default::default()
+                    },
+                ]),
+            MatchStatus::FullMatch,
+            None,
+        ),
+        "Sipho Dlamini",
+        "2025-01-29T09:15:00Z",
+    );
+
+    let security = matched(
+        invoice("INV-2025-0115", "SGH-INV-3301", "SUP-003", "SecureGuard Holdings", "PO-2025-0454",
+            "2025-02-24", "2025-02-25", "2025-03-27", InvoiceStatus::Exception, vec![
+                InvoiceLine {
+                    id: "IL-001".to_string(),
+                    po_line_id: Some("LI-001".to_string()),
+                    description: "Guarding services - February".to_string(),
+                    quantity: 1,
+                    unit_price: Money::from_cents(7_608_696),
+                    ..Default::default()
+                },
+            ]),
+        MatchStatus::NoMatch,
+        Some("No goods have been accepted against PO-2025-0454"),
+    );
+
+    let duplicate = Invoice {
+        duplicate_of: Some("INV-2025-0114".to_string()),
+        resolution: Some(ExceptionResolution {
+            action: ExceptionAction::Hold,
+            justification: "Possible duplicate of INV-2025-0114 (similar invoice number)".to_string(),
+            resolved_by: "System".to_string(),
+            resolved_at: "2025-02-26T08:15:00Z".to_string(),
+        }),
+        created_at: "2025-02-26T08:15:00Z".to_string(),
+        ..invoice("INV-2025-0116", "ACF-055821", "SUP-004", "AutoCare Fleet Management", "PO-2025-0453",
+            "2025-02-21", "2025-02-26", "2025-03-28", InvoiceStatus::OnHold, autocare.lines.clone())
+    };
+
+    store.invoices.set(vec![duplicate, security, autocare, techsolutions, catering, trading, cleaning]);
+}
@@ -0,0 +1,412 @@
+//! Invoice Management domain types
+
+use serde::{Deserialize, Serialize};
+use crate::util::datetime::Date;
+use crate::util::i18n::t;
+use crate::util::money::{Money, VAT_RATE};
+
+/// Days within which the PFMA requires suppliers to be paid
+pub const PAYMENT_TERM_DAYS: i64 = 30;
+/// Window in which a same-amount invoice from the same supplier is flagged (SRS BR-03)
+pub const DUPLICATE_WINDOW_DAYS: i64 = 7;
+
+/// Variances the three-way match accepts, as percentages (SRS BR-02)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MatchTolerances {
+    /// Invoiced against accepted quantity
+    pub quantity: f64,
+    /// Invoiced against ordered unit price
+    pub price: f64,
+    /// Invoice total against the value of accepted goods
+    pub total: f64,
+}
+
+impl Default for MatchTolerances {
+    fn default() -> Self {
+        Self { quantity: 5.0, price: 2.0, total: 2.0 }
+    }
+}
+
+/// Invoice status
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InvoiceStatus {
+    #[default]
+    Registered,
+    /// Matched within tolerance, awaiting approval for payment
+    Matched,
+    /// Failed the three-way match and waiting in the exception queue
+    Exception,
+    /// Held for investigation or a credit note
+    OnHold,
+    Approved,
+    Scheduled,
+    Paid,
+    Rejected,
+    Cancelled,
+}
+
+impl InvoiceStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvoiceStatus::Registered => "Registered",
+            InvoiceStatus::Matched => "Matched",
+            InvoiceStatus::Exception => "Exception",
+            InvoiceStatus::OnHold => "On Hold",
+            InvoiceStatus::Approved => "Approved",
+            InvoiceStatus::Scheduled => "Scheduled",
+            InvoiceStatus::Paid => "Paid",
+            InvoiceStatus::Rejected => "Rejected",
+            InvoiceStatus::Cancelled => "Cancelled",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "registered" => InvoiceStatus::Registered,
+            "matched" => InvoiceStatus::Matched,
+            "exception" => InvoiceStatus::Exception,
+            "on_hold" | "on hold" => InvoiceStatus::OnHold,
+            "approved" => InvoiceStatus::Approved,
+            "scheduled" => InvoiceStatus::Scheduled,
+            "paid" => InvoiceStatus::Paid,
+            "rejected" => InvoiceStatus::Rejected,
+            "cancelled" => InvoiceStatus::Cancelled,
+            _ => InvoiceStatus::Registered,
+        }
+    }
+
+    /// Still owed to the supplier
+    pub fn is_open(&self) -> bool {
+        !matches!(self, InvoiceStatus::Paid | InvoiceStatus::Rejected | InvoiceStatus::Cancelled)
+    }
+
+    /// Needs someone to resolve it before it can be paid
+    pub fn needs_attention(&self) -> bool {
+        matches!(self, InvoiceStatus::Exception | InvoiceStatus::OnHold)
+    }
+}
+
+/// Outcome of matching an invoice to its purchase order and receipts (SRS FR-3.2.3)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchStatus {
+    #[default]
+    Unmatched,
+    /// Every line agrees with the order and the goods accepted
+    FullMatch,
+    /// Variances, all within tolerance
+    PartialMatch,
+    /// Variances beyond tolerance
+    Exception,
+    /// Purchase order or receipts not found
+    NoMatch,
+}
+
+impl MatchStatus {
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            MatchStatus::Unmatched => "Not Matched",
+            MatchStatus::FullMatch => "Full Match",
+            MatchStatus::PartialMatch => "Partial Match",
+            MatchStatus::Exception => "Exception",
+            MatchStatus::NoMatch => "No Match",
+        })
+    }
+}
+
+/// Line on a supplier invoice
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InvoiceLine {
+    pub id: String,
+    /// Purchase order line the supplier is billing for
+    pub po_line_id: Option<String>,
+    pub item_code: String,
+    pub description: String,
+    pub quantity: u32,
+    pub unit_price: Money,
+    /// VAT rate charged on the line, in percent
+    pub tax_rate: f64,
+}
+
+impl Default for InvoiceLine {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            po_line_id: None,
+            item_code: String::new(),
+            description: String::new(),
+            quantity: 1,
+            unit_price: Money::ZERO,
+            tax_rate: VAT_RATE,
+        }
+    }
+}
+
+impl InvoiceLine {
+    pub fn total(&self) -> Money {
+        self.unit_price * self.quantity
+    }
+
+    pub fn vat(&self) -> Money {
+        self.total().vat_on(self.tax_rate)
+    }
+}
+
+/// How one invoice line compares to the order and the goods accepted
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LineMatchResult {
+    Matched,
+    WithinTolerance,
+    Exception,
+    /// Not on the purchase order
+    NotOrdered,
+}
+
+/// Three-way comparison for one invoice line
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LineMatch {
+    pub invoice_line_id: String,
+    pub po_line_id: Option<String>,
+    pub description: String,
+    pub ordered_quantity: u32,
+    /// Accepted on goods receipts, less what other invoices have billed
+    pub accepted_quantity: u32,
+    pub invoiced_quantity: u32,
+    pub ordered_price: Money,
+    pub invoiced_price: Money,
+    /// Invoiced over accepted quantity, in percent
+    pub quantity_variance: f64,
+    /// Invoiced over ordered price, in percent
+    pub price_variance: f64,
+    pub result: LineMatchResult,
+}
+
+/// Result of a three-way match
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MatchResult {
+    pub status: MatchStatus,
+    pub lines: Vec<LineMatch>,
+    /// Value of the goods accepted at order prices, VAT inclusive
+    pub expected_total: Money,
+    /// Invoice total over the expected total, in percent
+    pub total_variance: f64,
+    /// Why the invoice failed to match
+    pub exceptions: Vec<String>,
+    pub matched_at: String,
+}
+
+/// Action taken on an invoice in the exception queue (SRS FR-3.3.2)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExceptionAction {
+    AcceptVariance,
+    RequestCreditNote,
+    Hold,
+    Reject,
+}
+
+impl ExceptionAction {
+    pub const ALL: [ExceptionAction; 4] = [
+        ExceptionAction::AcceptVariance,
+        ExceptionAction::RequestCreditNote,
+        ExceptionAction::Hold,
+        ExceptionAction::Reject,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            ExceptionAction::AcceptVariance => "Accept Variance",
+            ExceptionAction::RequestCreditNote => "Request Credit Note",
+            ExceptionAction::Hold => "Hold for Investigation",
+            ExceptionAction::Reject => "Reject Invoice",
+        })
+    }
+}
+
+/// Record of how an exception was handled
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExceptionResolution {
+    pub action: ExceptionAction,
+    pub justification: String,
+    pub resolved_by: String,
+    pub resolved_at: String,
+}
+
+/// Payment aging bucket (SRS FR-3.5.2)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AgingBucket {
+    Current,
+    Days30To60,
+    Days60To90,
+    Over90,
+}
+
+impl AgingBucket {
+    pub const ALL: [AgingBucket; 4] = [
+        AgingBucket::Current,
+        AgingBucket::Days30To60,
+        AgingBucket::Days60To90,
+        AgingBucket::Over90,
+    ];
+
+    pub fn of(days: i64) -> Self {
+        match days {
+            d if d <= PAYMENT_TERM_DAYS => AgingBucket::Current,
+            d if d <= 60 => AgingBucket::Days30To60,
+            d if d <= 90 => AgingBucket::Days60To90,
+            _ => AgingBucket::Over90,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            AgingBucket::Current => "Current (0-30 days)",
+            AgingBucket::Days30To60 => "31-60 days",
+            AgingBucket::Days60To90 => "61-90 days",
+            AgingBucket::Over90 => "90+ days",
+        })
+    }
+}
+
+/// Supplier invoice
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Invoice {
+    pub id: String,
+    /// Number printed on the supplier's invoice
+    pub invoice_number: String,
+    pub supplier_id: String,
+    pub supplier_name: String,
+    pub po_number: String,
+    pub invoice_date: String,
+    /// Date the invoice reached us; the PFMA payment clock starts here
+    pub received_date: String,
+    pub due_date: String,
+    pub currency: String,
+    pub lines: Vec<InvoiceLine>,
+    pub subtotal: Money,
+    pub vat_amount: Money,
+    pub total_amount: Money,
+    pub description: Option<String>,
+    pub status: InvoiceStatus,
+    #[serde(default)]
+    pub match_result: Option<MatchResult>,
+    /// Invoice this one appears to duplicate
+    #[serde(default)]
+    pub duplicate_of: Option<String>,
+    #[serde(default)]
+    pub resolution: Option<ExceptionResolution>,
+    pub registered_by: String,
+    pub created_at: String,
+    pub approved_by: Option<String>,
+    pub approved_at: Option<String>,
+    /// Payment run that pays, or is due to pay, the invoice
+    #[serde(default)]
+    pub payment_run: Option<String>,
+    pub paid_date: Option<String>,
+    pub paid_amount: Money,
+}
+
+impl Default for Invoice {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            invoice_number: String::new(),
+            supplier_id: String::new(),
+            supplier_name: String::new(),
+            po_number: String::new(),
+            invoice_date: String::new(),
+            received_date: String::new(),
+            due_date: String::new(),
+            currency: "ZAR".to_string(),
+            lines: Vec::new(),
+            subtotal: Money::ZERO,
+            vat_amount: Money::ZERO,
+            total_amount: Money::ZERO,
+            description: None,
+            status: InvoiceStatus::Registered,
+            match_result: None,
+            duplicate_of: None,
+            resolution: None,
+            registered_by: String::new(),
+            created_at: String::new(),
+            approved_by: None,
+            approved_at: None,
+            payment_run: None,
+            paid_date: None,
+            paid_amount: Money::ZERO,
+        }
+    }
+}
+
+impl Invoice {
+    /// Calculate totals from the invoice lines, with VAT at each line's rate
+    pub fn calculate_totals(&mut self) {
+        self.subtotal = self.lines.iter().map(|l| l.total()).sum();
+        self.vat_amount = self.lines.iter().map(|l| l.vat()).sum();
+        self.total_amount = self.subtotal + self.vat_amount;
+    }
+
+    pub fn match_status(&self) -> MatchStatus {
+        self.match_result.as_ref().map(|m| m.status).unwrap_or_default()
+    }
+
+    /// Days since the invoice was received, up to payment
+    pub fn age_on(&self, date: Date) -> i64 {
+        let Some(received) = Date::parse(&self.received_date) else { return 0 };
+        let until = self.paid_date.as_deref().and_then(Date::parse).unwrap_or(date);
+        received.days_until(until).max(0)
+    }
+
+    pub fn aging_on(&self, date: Date) -> AgingBucket {
+        AgingBucket::of(self.age_on(date))
+    }
+
+    /// Unpaid past its due date
+    pub fn is_overdue_on(&self, date: Date) -> bool {
+        self.status.is_open() && Date::parse(&self.due_date).map_or(false, |due| date > due)
+    }
+
+    /// Days until the due date; negative once overdue
+    pub fn days_to_due(&self, date: Date) -> i64 {
+        Date::parse(&self.due_date).map_or(0, |due| date.days_until(due))
+    }
+}
+
+/// A registered invoice that a new one may duplicate (SRS FR-3.1.3)
+#[derive(Clone, Debug, PartialEq)]
+pub struct DuplicateMatch {
+    pub invoice_id: String,
+    pub invoice_number: String,
+    pub reason: DuplicateReason,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuplicateReason {
+    /// Same supplier and invoice number: the invoice is refused
+    SameNumber,
+    /// Supplier's number differs only in punctuation or leading zeros
+    SimilarNumber,
+    /// Same supplier and amount within a week
+    SameAmount,
+}
+
+impl DuplicateReason {
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            DuplicateReason::SameNumber => "same invoice number",
+            DuplicateReason::SimilarNumber => "similar invoice number",
+            DuplicateReason::SameAmount => "same amount within 7 days",
+        })
+    }
+
+    /// Blocks registration rather than flagging it
+    pub fn is_blocking(&self) -> bool {
+        *self == DuplicateReason::SameNumber
+    }
+}
+
+/// Invoice filter criteria
+#[derive(Clone, Debug, Default)]
+pub struct InvoiceFilter {
+    pub status: Option<InvoiceStatus>,
+    pub search: Option<String>,
+    pub overdue_only: bool,
+}
@@ -19,6 +19,7 @@ use super::types::{
     NotificationCategory,
 };
 use super::service;
+use crate::util::i18n::{t, tn};
 
 /// Mobile App Preview and Configuration component
 #[component]
@@ -530,46 +531,46 @@ pub fn mobile_app() -> View {
 
         <div class="mobile-app-page" data-testid="mobile-app-page">
             {page_header(
-                "Mobile Supplier App".to_string(),
-                Some("Preview and configure the mobile app for suppliers".to_string()),
+                t("Mobile Supplier App").to_string(),
+                Some(t("Preview and configure the mobile app for suppliers").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Download QR Code"</button> },
-                    view! { <button class="btn btn-primary">"Publish Update"</button> },
+                    view! { <button class="btn btn-secondary">{t("Download QR Code")}</button> },
+                    view! { <button class="btn btn-primary">{t("Publish Update")}</button> },
                 ]
             )}
 
             // KPI Cards
             <div class="kpi-grid">
                 {kpi_card(
-                    "Total App Users".to_string(),
+                    t("Total App Users").to_string(),
                     stats.get().total_users.to_string(),
                     KpiColor::Blue,
                     icon_users.to_string(),
-                    Some(KpiDelta { value: format!("{} active", stats.get().active_users_30d), is_positive: None, suffix: "in last 30 days".to_string() }),
+                    Some(KpiDelta { value: tn("{n} active", "{n} active", stats.get().active_users_30d as i64), is_positive: None, suffix: t("in last 30 days").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Push Enabled".to_string(),
+                    t("Push Enabled").to_string(),
                     stats.get().push_enabled_users.to_string(),
                     KpiColor::Green,
                     icon_bell.to_string(),
-                    Some(KpiDelta { value: format!("{:.0}%", push_enabled_percent), is_positive: Some(true), suffix: "of users".to_string() }),
+                    Some(KpiDelta { value: format!("{:.0}%", push_enabled_percent), is_positive: Some(true), suffix: t("of users").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Mobile Bids".to_string(),
+                    t("Mobile Bids").to_string(),
                     stats.get().bids_submitted_mobile.to_string(),
                     KpiColor::Orange,
                     icon_file.to_string(),
-                    Some(KpiDelta { value: "This month".to_string(), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: t("This month").to_string(), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Docs Scanned".to_string(),
+                    t("Docs Scanned").to_string(),
                     stats.get().documents_scanned.to_string(),
                     KpiColor::Purple,
                     icon_camera.to_string(),
-                    Some(KpiDelta { value: "12%".to_string(), is_positive: Some(true), suffix: "from last month".to_string() }),
+                    Some(KpiDelta { value: "12%".to_string(), is_positive: Some(true), suffix: t("from last month").to_string() }),
                     None
                 )}
             </div>
@@ -580,25 +581,25 @@ pub fn mobile_app() -> View {
                     class={if active_tab.get() == "preview" { "mobile-tab active" } else { "mobile-tab" }}
                     on:click={let set_tab = set_tab.clone(); move || set_tab.call("preview".to_string())}
                 >
-                    "App Preview"
+                    {t("App Preview")}
                 </button>
                 <button
                     class={if active_tab.get() == "config" { "mobile-tab active" } else { "mobile-tab" }}
                     on:click={let set_tab = set_tab.clone(); move || set_tab.call("config".to_string())}
                 >
-                    "Configuration"
+                    {t("Configuration")}
                 </button>
                 <button
                     class={if active_tab.get() == "features" { "mobile-tab active" } else { "mobile-tab" }}
                     on:click={let set_tab = set_tab.clone(); move || set_tab.call("features".to_string())}
                 >
-                    "Features"
+                    {t("Features")}
                 </button>
                 <button
                     class={if active_tab.get() == "users" { "mobile-tab active" } else { "mobile-tab" }}
                     on:click={let set_tab = set_tab.clone(); move || set_tab.call("users".to_string())}
                 >
-                    "Users"
+                    {t("Users")}
                 </button>
             </div>
 
@@ -607,12 +608,12 @@ pub fn mobile_app() -> View {
                 <div class="preview-container">
                     <div class="preview-sidebar">
                         {panel(
-                            "Preview Controls".to_string(),
+                            t("Preview Controls").to_string(),
                             vec![],
                             vec![view! {
                                 <div class="preview-controls">
                                     <div class="control-group">
-                                        <span class="control-label">"Device"</span>
+                                        <span class="control-label">{t("Device")}</span>
                                         <div class="control-buttons">
                                             for mode in PreviewMode::all() {
                                                 <button
@@ -626,25 +627,25 @@ pub fn mobile_app() -> View {
                                     </div>
 
                                     <div class="control-group">
-                                        <span class="control-label">"Theme"</span>
+                                        <span class="control-label">{t("Theme")}</span>
                                         <div class="control-buttons">
                                             <button
                                                 class={if !preview_dark_mode.get() { "control-btn active" } else { "control-btn" }}
                                                 on:click={let toggle_dark = toggle_dark.clone(); move || if preview_dark_mode.get() { toggle_dark.call(()) }}
                                             >
-                                                "Light"
+                                                {t("Light")}
                                             </button>
                                             <button
                                                 class={if preview_dark_mode.get() { "control-btn active" } else { "control-btn" }}
                                                 on:click={let toggle_dark = toggle_dark.clone(); move || if !preview_dark_mode.get() { toggle_dark.call(()) }}
                                             >
-                                                "Dark"
+                                                {t("Dark")}
                                             </button>
                                         </div>
                                     </div>
 
                                     <div class="control-group">
-                                        <span class="control-label">"Screen"</span>
+                                        <span class="control-label">{t("Screen")}</span>
                                         <div class="screen-list">
                                             for screen in PreviewScreen::all() {
                                                 <div
@@ -682,23 +683,23 @@ pub fn mobile_app() -> View {
                                     <div class="app-kpi-grid">
                                         <div class="app-kpi-card">
                                             <div class="app-kpi-value" style={format!("color: {};", config_draft.get().secondary_color)}>"5"</div>
-                                            <div class="app-kpi-label">"Open Tenders"</div>
+                                            <div class="app-kpi-label">{t("Open Tenders")}</div>
                                         </div>
                                         <div class="app-kpi-card">
                                             <div class="app-kpi-value" style={format!("color: {};", config_draft.get().secondary_color)}>"3"</div>
-                                            <div class="app-kpi-label">"Active Bids"</div>
+                                            <div class="app-kpi-label">{t("Active Bids")}</div>
                                         </div>
                                         <div class="app-kpi-card">
                                             <div class="app-kpi-value" style={format!("color: {};", config_draft.get().secondary_color)}>"2"</div>
-                                            <div class="app-kpi-label">"Contracts"</div>
+                                            <div class="app-kpi-label">{t("Contracts")}</div>
                                         </div>
                                         <div class="app-kpi-card">
                                             <div class="app-kpi-value" style={format!("color: {};", config_draft.get().secondary_color)}>"1"</div>
-                                            <div class="app-kpi-label">"Notifications"</div>
+                                            <div class="app-kpi-label">{t("Notifications")}</div>
                                         </div>
                                     </div>
 
-                                    <div class="app-section-title">"Recent Opportunities"</div>
+                                    <div class="app-section-title">{t("Recent Opportunities")}</div>
 
                                     <div class="app-list-item">
                                         <div class="app-list-icon" style={format!("background: {}20;", config_draft.get().secondary_color)}>
@@ -735,19 +736,19 @@ pub fn mobile_app() -> View {
                                 <div class="app-nav-bar">
                                     <div class="app-nav-item active">
                                         <span inner_html={icon_smartphone}></span>
-                                        "Home"
+                                        {t("Home")}
                                     </div>
                                     <div class="app-nav-item">
                                         <span inner_html={icon_file}></span>
-                                        "Tenders"
+                                        {t("Tenders")}
                                     </div>
                                     <div class="app-nav-item">
                                         <span inner_html={icon_activity}></span>
-                                        "Bids"
+                                        {t("Bids")}
                                     </div>
                                     <div class="app-nav-item">
                                         <span inner_html={icon_bell}></span>
-                                        "Alerts"
+                                        {t("Alerts")}
                                     </div>
                                 </div>
                             </div>
@@ -761,24 +762,24 @@ pub fn mobile_app() -> View {
             // Tab Content - Configuration
             <div class={if active_tab.get() == "config" { "tab-content active" } else { "tab-content" }}>
                 {panel(
-                    "App Configuration".to_string(),
+                    t("App Configuration").to_string(),
                     vec![],
                     vec![view! {
                         <div>
                             if config_dirty.get() {
                                 {notice_bar(
-                                    "You have unsaved changes".to_string(),
+                                    t("You have unsaved changes").to_string(),
                                     NoticeType::Warning,
                                     None
                                 )}
                             }
 
                             <div class="config-section">
-                                <div class="config-section-title">"Branding"</div>
+                                <div class="config-section-title">{t("Branding")}</div>
                                 <div class="config-grid">
                                     <div>
                                         {text_input(
-                                            "App Name".to_string(),
+                                            t("App Name").to_string(),
                                             signal(config_draft.get().app_name.clone()),
                                             None,
                                             false,
@@ -790,7 +791,7 @@ pub fn mobile_app() -> View {
                                     </div>
                                     <div>
                                         {text_input(
-                                            "Organization Name".to_string(),
+                                            t("Organization Name").to_string(),
                                             signal(config_draft.get().organization_name.clone()),
                                             None,
                                             false,
@@ -801,14 +802,14 @@ pub fn mobile_app() -> View {
                                         )}
                                     </div>
                                     <div>
-                                        <label class="form-label">"Primary Color"</label>
+                                        <label class="form-label">{t("Primary Color")}</label>
                                         <div class="color-picker-row">
                                             <div class="color-preview" style={format!("background: {};", config_draft.get().primary_color)}></div>
                                             <input type="color" value={config_draft.get().primary_color} style="flex: 1; height: 40px;" />
                                         </div>
                                     </div>
                                     <div>
-                                        <label class="form-label">"Secondary Color"</label>
+                                        <label class="form-label">{t("Secondary Color")}</label>
                                         <div class="color-picker-row">
                                             <div class="color-preview" style={format!("background: {};", config_draft.get().secondary_color)}></div>
                                             <input type="color" value={config_draft.get().secondary_color} style="flex: 1; height: 40px;" />
@@ -818,17 +819,17 @@ pub fn mobile_app() -> View {
                             </div>
 
                             <div class="config-section">
-                                <div class="config-section-title">"Security"</div>
+                                <div class="config-section-title">{t("Security")}</div>
                                 <div class="config-grid">
                                     <div>
                                         {select(
-                                            "Session Timeout".to_string(),
+                                            t("Session Timeout").to_string(),
                                             signal(config_draft.get().session_timeout_minutes.to_string()),
                                             vec![
-                                                SelectOption { value: "15".to_string(), label: "15 minutes".to_string() },
-                                                SelectOption { value: "30".to_string(), label: "30 minutes".to_string() },
-                                                SelectOption { value: "60".to_string(), label: "1 hour".to_string() },
-                                                SelectOption { value: "120".to_string(), label: "2 hours".to_string() },
+                                                SelectOption { value: "15".to_string(), label: t("15 minutes").to_string() },
+                                                SelectOption { value: "30".to_string(), label: t("30 minutes").to_string() },
+                                                SelectOption { value: "60".to_string(), label: t("1 hour").to_string() },
+                                                SelectOption { value: "120".to_string(), label: t("2 hours").to_string() },
                                             ],
                                             None,
                                             false,
@@ -838,7 +839,7 @@ pub fn mobile_app() -> View {
                                     </div>
                                     <div style="display: flex; flex-direction: column; justify-content: flex-end;">
                                         {checkbox(
-                                            "Require Biometric Authentication".to_string(),
+                                            t("Require Biometric Authentication").to_string(),
                                             signal(config_draft.get().require_biometric),
                                             false,
                                         )}
@@ -847,24 +848,24 @@ pub fn mobile_app() -> View {
                             </div>
 
                             <div class="config-section">
-                                <div class="config-section-title">"Offline Mode"</div>
+                                <div class="config-section-title">{t("Offline Mode")}</div>
                                 <div class="config-grid">
                                     <div>
                                         {checkbox(
-                                            "Enable Offline Mode".to_string(),
+                                            t("Enable Offline Mode").to_string(),
                                             signal(config_draft.get().offline_mode_enabled),
                                             false,
                                         )}
                                     </div>
                                     <div>
                                         {select(
-                                            "Max Offline Duration".to_string(),
+                                            t("Max Offline Duration").to_string(),
                                             signal(config_draft.get().max_offline_days.to_string()),
                                             vec![
-                                                SelectOption { value: "1".to_string(), label: "1 day".to_string() },
-                                                SelectOption { value: "3".to_string(), label: "3 days".to_string() },
-                                                SelectOption { value: "7".to_string(), label: "7 days".to_string() },
-                                                SelectOption { value: "14".to_string(), label: "14 days".to_string() },
+                                                SelectOption { value: "1".to_string(), label: t("1 day").to_string() },
+                                                SelectOption { value: "3".to_string(), label: t("3 days").to_string() },
+                                                SelectOption { value: "7".to_string(), label: t("7 days").to_string() },
+                                                SelectOption { value: "14".to_string(), label: t("14 days").to_string() },
                                             ],
                                             None,
                                             false,
@@ -876,11 +877,11 @@ pub fn mobile_app() -> View {
                             </div>
 
                             <div class="config-section">
-                                <div class="config-section-title">"Support"</div>
+                                <div class="config-section-title">{t("Support")}</div>
                                 <div class="config-grid">
                                     <div>
                                         {text_input(
-                                            "Support Email".to_string(),
+                                            t("Support Email").to_string(),
                                             signal(config_draft.get().support_email.clone()),
                                             None,
                                             false,
@@ -892,7 +893,7 @@ pub fn mobile_app() -> View {
                                     </div>
                                     <div>
                                         {text_input(
-                                            "Support Phone".to_string(),
+                                            t("Support Phone").to_string(),
                                             signal(config_draft.get().support_phone.clone()),
                                             None,
                                             false,
@@ -906,9 +907,9 @@ pub fn mobile_app() -> View {
                             </div>
 
                             <div class="config-actions">
-                                <button class="btn btn-secondary" on:click={let reset_config = reset_config.clone(); move || reset_config.call(())}>"Reset"</button>
+                                <button class="btn btn-secondary" on:click={let reset_config = reset_config.clone(); move || reset_config.call(())}>{t("Reset")}</button>
                                 <button class="btn btn-primary" disabled={!config_dirty.get() || saving.get()} on:click={let save_config = save_config.clone(); move || save_config.call(())}>
-                                    {if saving.get() { "Saving..." } else { "Save Configuration" }}
+                                    {if saving.get() { t("Saving...") } else { t("Save Configuration") }}
                                 </button>
                             </div>
                         </div>
@@ -919,15 +920,15 @@ pub fn mobile_app() -> View {
             // Tab Content - Features
             <div class={if active_tab.get() == "features" { "tab-content active" } else { "tab-content" }}>
                 {panel(
-                    "App Features".to_string(),
+                    t("App Features").to_string(),
                     vec![
-                        view! { <button class="btn btn-sm btn-secondary">"Enable All"</button> },
-                        view! { <button class="btn btn-sm btn-secondary">"Disable All"</button> },
+                        view! { <button class="btn btn-sm btn-secondary">{t("Enable All")}</button> },
+                        view! { <button class="btn btn-sm btn-secondary">{t("Disable All")}</button> },
                     ],
                     vec![view! {
                         <div>
                             <p style="color: var(--text-muted); margin-bottom: 24px;">
-                                "Enable or disable features available to suppliers in the mobile app."
+                                {t("Enable or disable features available to suppliers in the mobile app.")}
                             </p>
 
                             <div class="feature-grid">
@@ -944,16 +945,16 @@ pub fn mobile_app() -> View {
                                         <div class="feature-info">
                                             <div class="feature-name">{feature.label()}</div>
                                             <div class="feature-description">{feature.description()}</div>
-                                            <div class="feature-category">{feature.category()}</div>
+                                            <div class="feature-category">{t(feature.category())}</div>
                                         </div>
                                     </div>
                                 }
                             </div>
 
                             <div class="config-actions">
-                                <button class="btn btn-secondary" on:click={let reset_config = reset_config.clone(); move || reset_config.call(())}>"Reset"</button>
+                                <button class="btn btn-secondary" on:click={let reset_config = reset_config.clone(); move || reset_config.call(())}>{t("Reset")}</button>
                                 <button class="btn btn-primary" disabled={!config_dirty.get() || saving.get()} on:click={let save_config = save_config.clone(); move || save_config.call(())}>
-                                    {if saving.get() { "Saving..." } else { "Save Features" }}
+                                    {if saving.get() { t("Saving...") } else { t("Save Features") }}
                                 </button>
                             </div>
                         </div>
@@ -964,20 +965,20 @@ pub fn mobile_app() -> View {
             // Tab Content - Users
             <div class={if active_tab.get() == "users" { "tab-content active" } else { "tab-content" }}>
                 {panel(
-                    "Mobile App Users".to_string(),
+                    t("Mobile App Users").to_string(),
                     vec![
-                        view! { <button class="btn btn-sm btn-secondary">"Export"</button> },
-                        view! { <button class="btn btn-sm btn-secondary">"Send Notification"</button> },
+                        view! { <button class="btn btn-sm btn-secondary">{t("Export")}</button> },
+                        view! { <button class="btn btn-sm btn-secondary">{t("Send Notification")}</button> },
                     ],
                     vec![{
                         let columns = vec![
-                            DataTableColumn { key: "supplier".to_string(), label: "Supplier".to_string(), width: None, align: None, cell_class: None },
-                            DataTableColumn { key: "email".to_string(), label: "Email".to_string(), width: None, align: None, cell_class: None },
-                            DataTableColumn { key: "device".to_string(), label: "Device".to_string(), width: None, align: None, cell_class: None },
-                            DataTableColumn { key: "version".to_string(), label: "App Version".to_string(), width: None, align: None, cell_class: None },
-                            DataTableColumn { key: "last_active".to_string(), label: "Last Active".to_string(), width: None, align: None, cell_class: None },
-                            DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: None, align: None, cell_class: None },
-                            DataTableColumn { key: "actions".to_string(), label: "Actions".to_string(), width: None, align: None, cell_class: None },
+                            DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: None, align: None, cell_class: None },
+                            DataTableColumn { key: "email".to_string(), label: t("Email").to_string(), width: None, align: None, cell_class: None },
+                            DataTableColumn { key: "device".to_string(), label: t("Device").to_string(), width: None, align: None, cell_class: None },
+                            DataTableColumn { key: "version".to_string(), label: t("App Version").to_string(), width: None, align: None, cell_class: None },
+                            DataTableColumn { key: "last_active".to_string(), label: t("Last Active").to_string(), width: None, align: None, cell_class: None },
+                            DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: None, align: None, cell_class: None },
+                            DataTableColumn { key: "actions".to_string(), label: t("Actions").to_string(), width: None, align: None, cell_class: None },
                         ];
 
                         let rows: Vec<DataTableRow> = users.get().iter().map(|user| {
@@ -1009,9 +1010,9 @@ pub fn mobile_app() -> View {
                                     },
                                     view! {
                                         <div style="display: flex; gap: 4px;">
-                                            <button class="btn btn-sm btn-secondary">"View"</button>
+                                            <button class="btn btn-sm btn-secondary">{t("View")}</button>
                                             if user.push_token.is_some() {
-                                                <button class="btn btn-sm btn-secondary">"Notify"</button>
+                                                <button class="btn btn-sm btn-secondary">{t("Notify")}</button>
                                             }
                                         </div>
                                     },
@@ -1026,21 +1027,21 @@ pub fn mobile_app() -> View {
                 // Device breakdown
                 <div class="grid-2" style="margin-top: 24px;">
                     {panel(
-                        "Device Breakdown".to_string(),
+                        t("Device Breakdown").to_string(),
                         vec![],
                         vec![view! {
                             <div style="display: flex; flex-direction: column; gap: 16px;">
                                 <div>
                                     <div style="display: flex; justify-content: space-between; margin-bottom: 8px;">
                                         <span>"iOS"</span>
-                                        <span>{format!("{} users ({:.0}%)", ios_users_count, ios_percent)}</span>
+                                        <span>{format!("{} ({:.0}%)", tn("{n} user", "{n} users", ios_users_count as i64), ios_percent)}</span>
                                     </div>
                                     {progress_bar(ios_percent, ProgressColor::Gray, false, None)}
                                 </div>
                                 <div>
                                     <div style="display: flex; justify-content: space-between; margin-bottom: 8px;">
                                         <span>"Android"</span>
-                                        <span>{format!("{} users ({:.0}%)", android_users_count, android_percent)}</span>
+                                        <span>{format!("{} ({:.0}%)", tn("{n} user", "{n} users", android_users_count as i64), android_percent)}</span>
                                     </div>
                                     {progress_bar(android_percent, ProgressColor::Green, false, None)}
                                 </div>
@@ -1049,28 +1050,28 @@ pub fn mobile_app() -> View {
                     )}
 
                     {panel(
-                        "Feature Usage".to_string(),
+                        t("Feature Usage").to_string(),
                         vec![],
                         vec![view! {
                             <div style="display: flex; flex-direction: column; gap: 12px;">
                                 <div style="display: flex; justify-content: space-between; align-items: center;">
-                                    <span>"Bids Submitted"</span>
+                                    <span>{t("Bids Submitted")}</span>
                                     <span style="font-weight: 600;">{stats.get().bids_submitted_mobile}</span>
                                 </div>
                                 <div style="display: flex; justify-content: space-between; align-items: center;">
-                                    <span>"Documents Scanned"</span>
+                                    <span>{t("Documents Scanned")}</span>
                                     <span style="font-weight: 600;">{stats.get().documents_scanned}</span>
                                 </div>
                                 <div style="display: flex; justify-content: space-between; align-items: center;">
-                                    <span>"Briefing Check-ins"</span>
+                                    <span>{t("Briefing Check-ins")}</span>
                                     <span style="font-weight: 600;">{stats.get().briefing_checkins}</span>
                                 </div>
                                 <div style="display: flex; justify-content: space-between; align-items: center;">
-                                    <span>"Deliveries Confirmed"</span>
+                                    <span>{t("Deliveries Confirmed")}</span>
                                     <span style="font-weight: 600;">{stats.get().deliveries_confirmed}</span>
                                 </div>
                                 <div style="display: flex; justify-content: space-between; align-items: center;">
-                                    <span>"Auction Participations"</span>
+                                    <span>{t("Auction Participations")}</span>
                                     <span style="font-weight: 600;">{stats.get().auction_participations}</span>
                                 </div>
                             </div>
@@ -1,5 +1,8 @@
 //! Mobile service - API calls
 
+use crate::util::api::ApiError;
+use crate::util::backend;
+use crate::util::datetime::now_iso;
 use super::store::{MobileStore, load_mock_mobile_data, get_mock_user};
 use super::types::{
     AppFeature, MobileUser, MobileAppConfig, MobileAppStats,
@@ -11,9 +14,7 @@ pub async fn load_mobile_data(store: &MobileStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_mobile_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -26,7 +27,7 @@ pub async fn load_config(store: &MobileStore) {
     // In production, this would call the API
     // Mock data is loaded via load_mobile_data
     if store.config.get().id.is_empty() {
-        load_mock_mobile_data(store);
+        load_from_backend(store).await;
     }
 
     store.loading.set(false);
@@ -63,7 +64,7 @@ pub async fn save_config(store: &MobileStore) -> Result<(), String> {
     // In production, this would call the API
     // For now, just update the config
     let mut updated_config = config.clone();
-    updated_config.updated_at = chrono_now();
+    updated_config.updated_at = now_iso();
     store.config.set(updated_config);
     store.config_dirty.set(false);
 
@@ -78,7 +79,7 @@ pub async fn load_users(store: &MobileStore) {
 
     // In production, this would call the API with filters
     if store.users.get().is_empty() {
-        load_mock_mobile_data(store);
+        load_from_backend(store).await;
     }
 
     store.loading.set(false);
@@ -298,7 +299,24 @@ pub async fn refresh_mobile_data(store: &MobileStore) {
 }
 
 // Helper functions
-fn chrono_now() -> String {
-    // In production, use chrono crate
-    "2025-02-27T10:00:00Z".to_string()
+/// Refresh mobile app configuration and users from the configured backend
+async fn load_from_backend(store: &MobileStore) {
+    if backend::is_mock() {
+        load_mock_mobile_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/mobile/config", &store.config).await?;
+        backend::fetch_into("/mobile/users", &store.users).await?;
+        backend::fetch_into("/mobile/stats", &store.stats).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    } else {
+        store.config_draft.set(store.config.get());
+    }
 }
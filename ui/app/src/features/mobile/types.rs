@@ -1,6 +1,7 @@
 //! Mobile domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
 
 /// Mobile app feature that can be enabled/disabled
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -33,7 +34,7 @@ pub enum AppFeature {
 
 impl AppFeature {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             AppFeature::TenderNotifications => "Tender Notifications",
             AppFeature::BidStatusAlerts => "Bid Status Alerts",
             AppFeature::ContractReminders => "Contract Reminders",
@@ -46,11 +47,11 @@ impl AppFeature {
             AppFeature::MobileInvoicing => "Mobile Invoicing",
             AppFeature::DeliveryConfirmation => "Delivery Confirmation",
             AppFeature::QrCodeScanning => "QR Code Scanning",
-        }
+        })
     }
 
     pub fn description(&self) -> &'static str {
-        match self {
+        t(match self {
             AppFeature::TenderNotifications => "Receive push notifications when new tenders matching your profile are published",
             AppFeature::BidStatusAlerts => "Get instant alerts when your bid status changes during evaluation",
             AppFeature::ContractReminders => "Reminders for upcoming contract milestones, renewals, and deliverables",
@@ -63,7 +64,7 @@ impl AppFeature {
             AppFeature::MobileInvoicing => "Submit and track invoices directly from the mobile app",
             AppFeature::DeliveryConfirmation => "Confirm deliveries with photo evidence and timestamps",
             AppFeature::QrCodeScanning => "Scan QR codes on purchase orders for quick goods receipt",
-        }
+        })
     }
 
     pub fn icon(&self) -> &'static str {
@@ -180,11 +181,11 @@ pub enum DeviceType {
 
 impl DeviceType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             DeviceType::IOS => "iOS",
             DeviceType::Android => "Android",
             DeviceType::Unknown => "Unknown",
-        }
+        })
     }
 
     pub fn icon(&self) -> &'static str {
@@ -257,11 +258,17 @@ pub enum NotificationCategory {
     System,
     /// Auction events
     Auctions,
+    /// Approvals awaiting the user
+    Approvals,
+    /// Compliance certificates nearing expiry
+    Certificates,
+    /// Committee meetings scheduled
+    Meetings,
 }
 
 impl NotificationCategory {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             NotificationCategory::Tenders => "Tenders",
             NotificationCategory::Bids => "Bids",
             NotificationCategory::Contracts => "Contracts",
@@ -269,7 +276,10 @@ impl NotificationCategory {
             NotificationCategory::Documents => "Documents",
             NotificationCategory::System => "System",
             NotificationCategory::Auctions => "Auctions",
-        }
+            NotificationCategory::Approvals => "Approvals",
+            NotificationCategory::Certificates => "Certificates",
+            NotificationCategory::Meetings => "Meetings",
+        })
     }
 
     pub fn all() -> Vec<NotificationCategory> {
@@ -281,6 +291,9 @@ impl NotificationCategory {
             NotificationCategory::Documents,
             NotificationCategory::System,
             NotificationCategory::Auctions,
+            NotificationCategory::Approvals,
+            NotificationCategory::Certificates,
+            NotificationCategory::Meetings,
         ]
     }
 }
@@ -300,12 +313,12 @@ pub enum NotificationPriority {
 
 impl NotificationPriority {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             NotificationPriority::Low => "Low",
             NotificationPriority::Medium => "Medium",
             NotificationPriority::High => "High",
             NotificationPriority::Critical => "Critical",
-        }
+        })
     }
 
     pub fn all() -> Vec<NotificationPriority> {
@@ -431,12 +444,12 @@ pub enum PreviewMode {
 
 impl PreviewMode {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             PreviewMode::IPhone => "iPhone",
             PreviewMode::AndroidPhone => "Android Phone",
             PreviewMode::IPad => "iPad",
             PreviewMode::AndroidTablet => "Android Tablet",
-        }
+        })
     }
 
     pub fn dimensions(&self) -> (u32, u32) {
@@ -489,7 +502,7 @@ pub enum PreviewScreen {
 
 impl PreviewScreen {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             PreviewScreen::Login => "Login",
             PreviewScreen::Dashboard => "Dashboard",
             PreviewScreen::Opportunities => "Opportunities",
@@ -502,7 +515,7 @@ impl PreviewScreen {
             PreviewScreen::Settings => "Settings",
             PreviewScreen::Profile => "Profile",
             PreviewScreen::DocumentScanner => "Document Scanner",
-        }
+        })
     }
 
     pub fn all() -> Vec<PreviewScreen> {
@@ -21,3 +21,10 @@ pub mod sourcing_plan;
 pub mod bbbee;
 pub mod agsa;
 pub mod mobile;
+pub mod search;
+pub mod notifications;
+pub mod workflow;
+pub mod budget;
+pub mod invoices;
+pub mod ocr;
+pub mod payments;
@@ -4,3 +4,4 @@ pub mod types;
 pub mod store;
 pub mod service;
 pub mod nbac_reviews;
+pub mod review_detail;
@@ -14,6 +14,7 @@ use crate::util::format::{format_currency, format_date};
 use super::store::NbacStore;
 use super::types::{ReviewStatus, ReviewCategory, Priority, MeetingStatus, DecisionType};
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// NBAC Reviews page
 #[component]
@@ -67,49 +68,49 @@ pub fn nbac_reviews() -> View {
     let review_columns = vec![
         DataTableColumn {
             key: "reference".to_string(),
-            label: "Reference".to_string(),
+            label: t("Reference").to_string(),
             width: Some("140px".to_string()),
             align: None,
             cell_class: Some("id-cell".to_string()),
         },
         DataTableColumn {
             key: "title".to_string(),
-            label: "Title".to_string(),
+            label: t("Title").to_string(),
             width: None,
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "category".to_string(),
-            label: "Category".to_string(),
+            label: t("Category").to_string(),
             width: Some("130px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "value".to_string(),
-            label: "Value".to_string(),
+            label: t("Value").to_string(),
             width: Some("120px".to_string()),
             align: Some("right".to_string()),
             cell_class: Some("amount-cell".to_string()),
         },
         DataTableColumn {
             key: "department".to_string(),
-            label: "Department".to_string(),
+            label: t("Department").to_string(),
             width: Some("150px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "priority".to_string(),
-            label: "Priority".to_string(),
+            label: t("Priority").to_string(),
             width: Some("100px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "status".to_string(),
-            label: "Status".to_string(),
+            label: t("Status").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
@@ -130,21 +131,21 @@ pub fn nbac_reviews() -> View {
             };
 
             let priority_tag = match review.priority {
-                Priority::Critical => tag("Critical".to_string(), TagType::Danger),
-                Priority::High => tag("High".to_string(), TagType::Warning),
-                Priority::Medium => tag("Medium".to_string(), TagType::Info),
-                Priority::Low => tag("Low".to_string(), TagType::Default),
+                Priority::Critical => tag(t("Critical").to_string(), TagType::Danger),
+                Priority::High => tag(t("High").to_string(), TagType::Warning),
+                Priority::Medium => tag(t("Medium").to_string(), TagType::Info),
+                Priority::Low => tag(t("Low").to_string(), TagType::Default),
             };
 
             let category_tag = match review.category {
-                ReviewCategory::BidAward => tag("Bid Award".to_string(), TagType::Primary),
-                ReviewCategory::Deviation => tag("Deviation".to_string(), TagType::Warning),
-                ReviewCategory::ContractVariation => tag("Variation".to_string(), TagType::Info),
-                ReviewCategory::ContractExtension => tag("Extension".to_string(), TagType::Info),
-                ReviewCategory::Cancellation => tag("Cancellation".to_string(), TagType::Danger),
-                ReviewCategory::EmergencyProcurement => tag("Emergency".to_string(), TagType::Danger),
-                ReviewCategory::SingleSource => tag("Single Source".to_string(), TagType::Warning),
-                ReviewCategory::Confinement => tag("Confinement".to_string(), TagType::Warning),
+                ReviewCategory::BidAward => tag(t("Bid Award").to_string(), TagType::Primary),
+                ReviewCategory::Deviation => tag(t("Deviation").to_string(), TagType::Warning),
+                ReviewCategory::ContractVariation => tag(t("Variation").to_string(), TagType::Info),
+                ReviewCategory::ContractExtension => tag(t("Extension").to_string(), TagType::Info),
+                ReviewCategory::Cancellation => tag(t("Cancellation").to_string(), TagType::Danger),
+                ReviewCategory::EmergencyProcurement => tag(t("Emergency").to_string(), TagType::Danger),
+                ReviewCategory::SingleSource => tag(t("Single Source").to_string(), TagType::Warning),
+                ReviewCategory::Confinement => tag(t("Confinement").to_string(), TagType::Warning),
             };
 
             DataTableRow {
@@ -166,42 +167,42 @@ pub fn nbac_reviews() -> View {
     let meeting_columns = vec![
         DataTableColumn {
             key: "number".to_string(),
-            label: "Meeting No.".to_string(),
+            label: t("Meeting No.").to_string(),
             width: Some("160px".to_string()),
             align: None,
             cell_class: Some("id-cell".to_string()),
         },
         DataTableColumn {
             key: "date".to_string(),
-            label: "Date".to_string(),
+            label: t("Date").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "time".to_string(),
-            label: "Time".to_string(),
+            label: t("Time").to_string(),
             width: Some("80px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "venue".to_string(),
-            label: "Venue".to_string(),
+            label: t("Venue").to_string(),
             width: None,
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "items".to_string(),
-            label: "Agenda Items".to_string(),
+            label: t("Agenda Items").to_string(),
             width: Some("100px".to_string()),
             align: Some("center".to_string()),
             cell_class: None,
         },
         DataTableColumn {
             key: "status".to_string(),
-            label: "Status".to_string(),
+            label: t("Status").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
@@ -241,42 +242,42 @@ pub fn nbac_reviews() -> View {
     let decision_columns = vec![
         DataTableColumn {
             key: "resolution".to_string(),
-            label: "Resolution No.".to_string(),
+            label: t("Resolution No.").to_string(),
             width: Some("160px".to_string()),
             align: None,
             cell_class: Some("id-cell".to_string()),
         },
         DataTableColumn {
             key: "date".to_string(),
-            label: "Date".to_string(),
+            label: t("Date").to_string(),
             width: Some("100px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "summary".to_string(),
-            label: "Summary".to_string(),
+            label: t("Summary").to_string(),
             width: None,
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "type".to_string(),
-            label: "Type".to_string(),
+            label: t("Type").to_string(),
             width: Some("100px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "value".to_string(),
-            label: "Value".to_string(),
+            label: t("Value").to_string(),
             width: Some("120px".to_string()),
             align: Some("right".to_string()),
             cell_class: Some("amount-cell".to_string()),
         },
         DataTableColumn {
             key: "votes".to_string(),
-            label: "Votes".to_string(),
+            label: t("Votes").to_string(),
             width: Some("100px".to_string()),
             align: Some("center".to_string()),
             cell_class: None,
@@ -287,17 +288,17 @@ pub fn nbac_reviews() -> View {
     let decision_rows: Vec<DataTableRow> = {
         decisions.get().iter().map(|decision| {
             let type_tag = match decision.decision_type {
-                DecisionType::Award => tag("Award".to_string(), TagType::Success),
-                DecisionType::Reject => tag("Reject".to_string(), TagType::Danger),
-                DecisionType::Defer => tag("Defer".to_string(), TagType::Warning),
-                DecisionType::RequestInfo => tag("Info Req".to_string(), TagType::Info),
-                DecisionType::Cancellation => tag("Cancel".to_string(), TagType::Danger),
-                DecisionType::Variation => tag("Variation".to_string(), TagType::Info),
-                DecisionType::Extension => tag("Extension".to_string(), TagType::Info),
+                DecisionType::Award => tag(t("Award").to_string(), TagType::Success),
+                DecisionType::Reject => tag(t("Reject").to_string(), TagType::Danger),
+                DecisionType::Defer => tag(t("Defer").to_string(), TagType::Warning),
+                DecisionType::RequestInfo => tag(t("Info Req").to_string(), TagType::Info),
+                DecisionType::Cancellation => tag(t("Cancel").to_string(), TagType::Danger),
+                DecisionType::Variation => tag(t("Variation").to_string(), TagType::Info),
+                DecisionType::Extension => tag(t("Extension").to_string(), TagType::Info),
             };
 
             let vote_display = if decision.is_unanimous {
-                view! { <span class="vote-unanimous">"Unanimous"</span> }
+                view! { <span class="vote-unanimous">{t("Unanimous")}</span> }
             } else {
                 view! {
                     <span class="vote-count">
@@ -385,9 +386,9 @@ pub fn nbac_reviews() -> View {
     let meeting_timeline: Vec<TimelineItem> = {
         upcoming_meetings.iter().map(|meeting| {
             TimelineItem {
-                date: format!("{} at {}", format_date(&meeting.date), meeting.time),
+                date: tf("{date} at {time}", &[("date", format_date(&meeting.date)), ("time", meeting.time.clone())]),
                 title: meeting.meeting_number.clone(),
-                description: format!("{} - {} items", meeting.venue, meeting.agenda.items.len()),
+                description: format!("{} - {}", meeting.venue, tn("{n} item", "{n} items", meeting.agenda.items.len() as i64)),
                 status: TimelineStatus::Pending,
             }
         }).collect()
@@ -547,46 +548,46 @@ pub fn nbac_reviews() -> View {
 
         <div class="nbac-reviews" data-testid="nbac-reviews">
             {page_header(
-                "NBAC - Bid Adjudication".to_string(),
-                Some("National Bid Adjudication Committee reviews and decisions".to_string()),
+                t("NBAC - Bid Adjudication").to_string(),
+                Some(t("National Bid Adjudication Committee reviews and decisions").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export Report"</button> },
-                    view! { <button class="btn btn-primary">"Schedule Meeting"</button> },
+                    view! { <button class="btn btn-secondary">{t("Export Report")}</button> },
+                    view! { <button class="btn btn-primary">{t("Schedule Meeting")}</button> },
                 ]
             )}
 
             // KPI Row
             <div class="kpi-grid">
                 {kpi_card(
-                    "Pending Reviews".to_string(),
+                    t("Pending Reviews").to_string(),
                     kpis.get().pending_reviews.to_string(),
                     KpiColor::Orange,
                     icon_clipboard.to_string(),
-                    Some(KpiDelta { value: "3 urgent".to_string(), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: tn("{n} urgent", "{n} urgent", 3), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Decisions YTD".to_string(),
+                    t("Decisions YTD").to_string(),
                     kpis.get().decisions_ytd.to_string(),
                     KpiColor::Green,
                     icon_check.to_string(),
-                    Some(KpiDelta { value: format!("{:.0}% approval rate", kpis.get().approval_rate), is_positive: Some(true), suffix: "".to_string() }),
+                    Some(KpiDelta { value: tf("{percent} approval rate", &[("percent", format!("{:.0}%", kpis.get().approval_rate))]), is_positive: Some(true), suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Total Approved YTD".to_string(),
+                    t("Total Approved YTD").to_string(),
                     format_currency(kpis.get().total_value_approved_ytd),
                     KpiColor::Blue,
                     icon_dollar.to_string(),
-                    Some(KpiDelta { value: "4 this month".to_string(), is_positive: Some(true), suffix: "".to_string() }),
+                    Some(KpiDelta { value: tn("{n} this month", "{n} this month", 4), is_positive: Some(true), suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Avg Turnaround".to_string(),
-                    format!("{:.1} days", kpis.get().average_turnaround_days),
+                    t("Avg Turnaround").to_string(),
+                    tf("{days} days", &[("days", format!("{:.1}", kpis.get().average_turnaround_days))]),
                     KpiColor::Cyan,
                     icon_trending.to_string(),
-                    Some(KpiDelta { value: "Target: 14 days".to_string(), is_positive: Some(true), suffix: "".to_string() }),
+                    Some(KpiDelta { value: tf("Target: {value}", &[("value", tn("{n} day", "{n} days", 14))]), is_positive: Some(true), suffix: "".to_string() }),
                     None
                 )}
             </div>
@@ -600,7 +601,7 @@ pub fn nbac_reviews() -> View {
                     <div class="summary-icon pending" inner_html={icon_clipboard}></div>
                     <div class="summary-content">
                         <h3>{reviews.get().len().to_string()}</h3>
-                        <p>"Total Reviews"</p>
+                        <p>{t("Total Reviews")}</p>
                     </div>
                 </div>
                 <div
@@ -610,7 +611,7 @@ pub fn nbac_reviews() -> View {
                     <div class="summary-icon pending" inner_html={icon_clock}></div>
                     <div class="summary-content">
                         <h3>{pending_count.to_string()}</h3>
-                        <p>"Pending"</p>
+                        <p>{t("Pending")}</p>
                     </div>
                 </div>
                 <div
@@ -620,7 +621,7 @@ pub fn nbac_reviews() -> View {
                     <div class="summary-icon scheduled" inner_html={icon_calendar}></div>
                     <div class="summary-content">
                         <h3>{scheduled_count.to_string()}</h3>
-                        <p>"Scheduled"</p>
+                        <p>{t("Scheduled")}</p>
                     </div>
                 </div>
                 <div
@@ -630,7 +631,7 @@ pub fn nbac_reviews() -> View {
                     <div class="summary-icon approved" inner_html={icon_check}></div>
                     <div class="summary-content">
                         <h3>{approved_count.to_string()}</h3>
-                        <p>"Approved"</p>
+                        <p>{t("Approved")}</p>
                     </div>
                 </div>
             </div>
@@ -641,9 +642,9 @@ pub fn nbac_reviews() -> View {
                 <div class="main-content">
                     {tabs(
                         vec![
-                            Tab { id: "reviews".to_string(), label: "Pending Reviews".to_string(), icon: None, active: false },
-                            Tab { id: "meetings".to_string(), label: "Meeting Schedule".to_string(), icon: None, active: false },
-                            Tab { id: "decisions".to_string(), label: "Decision History".to_string(), icon: None, active: false },
+                            Tab { id: "reviews".to_string(), label: t("Pending Reviews").to_string(), icon: None, active: false },
+                            Tab { id: "meetings".to_string(), label: t("Meeting Schedule").to_string(), icon: None, active: false },
+                            Tab { id: "decisions".to_string(), label: t("Decision History").to_string(), icon: None, active: false },
                         ],
                         active_tab.clone(),
                         vec![]
@@ -651,9 +652,9 @@ pub fn nbac_reviews() -> View {
 
                     // Tab content - Reviews panel (default shown)
                     {panel(
-                        "Reviews Pending Adjudication".to_string(),
+                        t("Reviews Pending Adjudication").to_string(),
                         vec![
-                            view! { <button class="btn btn-sm btn-secondary">"Filter"</button> },
+                            view! { <button class="btn btn-sm btn-secondary">{t("Filter")}</button> },
                         ],
                         vec![data_table(review_columns.clone(), review_rows.clone(), Some(handle_review_click.clone()))]
                     )}
@@ -663,48 +664,48 @@ pub fn nbac_reviews() -> View {
                 <div class="sidebar">
                     // Upcoming meetings
                     {panel(
-                        "Upcoming Meetings".to_string(),
+                        t("Upcoming Meetings").to_string(),
                         vec![],
                         vec![timeline(meeting_timeline.clone(), None)]
                     )}
 
                     // Quick stats
                     <div class="quick-stats">
-                        <h4>"Committee Statistics"</h4>
+                        <h4>{t("Committee Statistics")}</h4>
                         <div class="stat-row">
-                            <span class="stat-label">"Decisions This Month"</span>
+                            <span class="stat-label">{t("Decisions This Month")}</span>
                             <span class="stat-value">{kpis.get().decisions_this_month.to_string()}</span>
                         </div>
                         <div class="stat-row">
-                            <span class="stat-label">"Approval Rate"</span>
+                            <span class="stat-label">{t("Approval Rate")}</span>
                             <span class="stat-value highlight">{format!("{:.0}%", kpis.get().approval_rate)}</span>
                         </div>
                         <div class="stat-row">
-                            <span class="stat-label">"Overdue Reviews"</span>
+                            <span class="stat-label">{t("Overdue Reviews")}</span>
                             <span class="stat-value warning">{kpis.get().overdue_reviews.to_string()}</span>
                         </div>
                         <div class="stat-row">
-                            <span class="stat-label">"Upcoming Meetings"</span>
+                            <span class="stat-label">{t("Upcoming Meetings")}</span>
                             <span class="stat-value">{kpis.get().upcoming_meetings.to_string()}</span>
                         </div>
                     </div>
 
                     // Committee members quick view
                     {panel(
-                        "Committee Members".to_string(),
-                        vec![view! { <a href="#" class="btn btn-sm btn-secondary">"View All"</a> }],
+                        t("Committee Members").to_string(),
+                        vec![view! { <a href="#" class="btn btn-sm btn-secondary">{t("View All")}</a> }],
                         vec![view! {
                             <div class="committee-quick">
                                 <div class="stat-row">
-                                    <span class="stat-label">"Active Members"</span>
+                                    <span class="stat-label">{t("Active Members")}</span>
                                     <span class="stat-value">"7"</span>
                                 </div>
                                 <div class="stat-row">
-                                    <span class="stat-label">"Quorum Required"</span>
+                                    <span class="stat-label">{t("Quorum Required")}</span>
                                     <span class="stat-value">"5"</span>
                                 </div>
                                 <div class="stat-row">
-                                    <span class="stat-label">"Avg Attendance"</span>
+                                    <span class="stat-label">{t("Avg Attendance")}</span>
                                     <span class="stat-value highlight">"93%"</span>
                                 </div>
                             </div>
@@ -0,0 +1,227 @@
+//! NBAC review item detail page
+
+use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, status_badge, StatusType,
+    tag, TagType,
+};
+use crate::util::format::{format_currency, format_date};
+use super::types::{ReviewItem, ReviewStatus};
+use super::store::NbacStore;
+use super::service;
+use crate::util::i18n::{t, tf};
+
+/// NBAC review item detail page
+#[component]
+pub fn review_detail(review_id: String) -> View {
+    let store = use_context::<NbacStore>();
+
+    // Load review on mount
+    effect({
+        let store = store.clone();
+        let review_id = review_id.clone();
+        move || {
+            let store = store.clone();
+            let review_id = review_id.clone();
+            spawn(async move {
+                service::load_review(&store, &review_id).await;
+            });
+        }
+    });
+
+    let loading = store.loading.get();
+    let error = store.error.get();
+    let review = store.selected_review.get().filter(|r| r.id == review_id);
+
+    view! {
+        style {
+            r#"
+            .review-detail { display: flex; flex-direction: column; gap: var(--space-6); }
+            .review-detail-grid {
+                display: grid;
+                grid-template-columns: 2fr 1fr;
+                gap: 24px;
+            }
+            .review-detail .detail-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 8px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .review-detail .detail-row:last-child { border-bottom: none; }
+            .review-detail .detail-row .label { color: var(--text-muted); }
+            .review-detail .detail-row .value { font-weight: 500; text-align: right; }
+            .review-detail .summary-text {
+                font-size: 13px;
+                line-height: 1.5;
+                color: var(--text-muted);
+            }
+            .review-detail .review-note {
+                padding: 12px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .review-detail .review-note:last-child { border-bottom: none; }
+            .review-detail .review-note .meta { font-size: 11px; color: var(--text-muted); margin-bottom: 4px; }
+            @media (max-width: 1024px) {
+                .review-detail-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="review-detail" data-testid="nbac-review-detail">
+            if loading {
+                <div class="loading-state">{t("Loading review...")}</div>
+            } else if let Some(review) = review {
+                {render_review(review)}
+            } else {
+                {entity_not_found(t("Review"), &review_id, error, Route::NbacReviews, t("Back to Reviews"))}
+            }
+        </div>
+    }
+}
+
+fn render_review(review: ReviewItem) -> View {
+    let status = match review.status {
+        ReviewStatus::Pending => status_badge(StatusType::Pending),
+        ReviewStatus::Scheduled => status_badge(StatusType::Scheduled),
+        ReviewStatus::InReview => status_badge(StatusType::InProgress),
+        ReviewStatus::Approved => status_badge(StatusType::Approved),
+        ReviewStatus::Rejected => status_badge(StatusType::Rejected),
+        ReviewStatus::Deferred => status_badge(StatusType::OnHold),
+        ReviewStatus::RequiresInfo => status_badge(StatusType::Pending),
+    };
+
+    let recommended = review.recommended_supplier_name.clone().unwrap_or_else(|| "-".to_string());
+    let recommended_value = review.recommended_value
+        .map(format_currency)
+        .unwrap_or_else(|| "-".to_string());
+
+    view! {
+        {page_header(
+            review.tender_title.clone(),
+            Some(tf(
+                "{reference} - Tender {tender}",
+                &[("reference", review.reference_number.clone()), ("tender", review.tender_reference.clone())],
+            )),
+            vec![
+                view! { <a href="/nbac" class="btn btn-secondary">{t("Back to Reviews")}</a> },
+            ]
+        )}
+
+        <div class="review-detail-grid">
+            <div>
+                {panel(
+                    t("Evaluation Summary").to_string(),
+                    vec![],
+                    vec![view! { <p class="summary-text">{review.evaluation_summary.clone()}</p> }]
+                )}
+
+                {panel(
+                    t("Recommendation").to_string(),
+                    vec![],
+                    vec![view! { <p class="summary-text">{review.recommendation.clone()}</p> }]
+                )}
+
+                {panel(
+                    t("Compliance Checks").to_string(),
+                    vec![],
+                    vec![
+                        view! {
+                            <div>
+                                for check in review.compliance_checks.iter() {
+                                    <div class="detail-row">
+                                        <span class="label">{check.check_name.clone()}</span>
+                                        <span class="value">
+                                            if check.passed {
+                                                {tag(t("Passed").to_string(), TagType::Green)}
+                                            } else {
+                                                {tag(t("Failed").to_string(), TagType::Red)}
+                                            }
+                                        </span>
+                                    </div>
+                                }
+                            </div>
+                        }
+                    ]
+                )}
+
+                if !review.notes.is_empty() {
+                    {panel(
+                        t("Notes").to_string(),
+                        vec![],
+                        vec![
+                            view! {
+                                <div>
+                                    for note in review.notes.iter() {
+                                        <div class="review-note">
+                                            <div class="meta">{format!("{} - {}", note.author_name, format_date(&note.created_at))}</div>
+                                            <div>{note.content.clone()}</div>
+                                        </div>
+                                    }
+                                </div>
+                            }
+                        ]
+                    )}
+                }
+            </div>
+
+            {panel(
+                t("Review Details").to_string(),
+                vec![],
+                vec![
+                    view! {
+                        <div>
+                            <div class="detail-row">
+                                <span class="label">{t("Status")}</span>
+                                <span class="value">{status}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Category")}</span>
+                                <span class="value">{review.category.label()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Priority")}</span>
+                                <span class="value">{review.priority.label()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Risk")}</span>
+                                <span class="value">{review.risk_rating.label()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Department")}</span>
+                                <span class="value">{review.department.clone()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Tender Value")}</span>
+                                <span class="value">{format_currency(review.tender_value)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Recommended Supplier")}</span>
+                                <span class="value">{recommended}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Recommended Value")}</span>
+                                <span class="value">{recommended_value}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Submitted")}</span>
+                                <span class="value">{tf("{date} by {name}", &[("date", format_date(&review.submitted_date)), ("name", review.submitted_by.clone())])}</span>
+                            </div>
+                            if let Some(decision) = review.decision.clone() {
+                                <div class="detail-row">
+                                    <span class="label">{t("Decision")}</span>
+                                    <span class="value">{format!("{} ({})", decision.decision_type.label(), decision.resolution_number)}</span>
+                                </div>
+                            }
+                        </div>
+                    }
+                ]
+            )}
+        </div>
+    }
+}
@@ -1,5 +1,10 @@
 //! NBAC service - API calls
 
+use crate::util::api::{ApiError, HttpMethod};
+use crate::util::backend;
+use crate::util::money::Money;
+use crate::util::permissions::{self, Action};
+use crate::util::reference::{self, DocumentType};
 use super::store::{NbacStore, load_mock_data};
 use super::types::{
     ReviewItem, Decision, Meeting, ReviewStatus, DecisionType, VoteType, Vote,
@@ -11,9 +16,7 @@ pub async fn load_nbac_data(store: &NbacStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -25,7 +28,7 @@ pub async fn load_reviews(store: &NbacStore) {
 
     // Ensure data is loaded
     if store.reviews.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     store.loading.set(false);
@@ -38,7 +41,7 @@ pub async fn load_review(store: &NbacStore, review_id: &str) {
 
     // Ensure reviews are loaded
     if store.reviews.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Select the review
@@ -54,7 +57,7 @@ pub async fn load_meetings(store: &NbacStore) {
 
     // Ensure data is loaded
     if store.meetings.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     store.loading.set(false);
@@ -67,7 +70,7 @@ pub async fn load_meeting(store: &NbacStore, meeting_id: &str) {
 
     // Ensure meetings are loaded
     if store.meetings.get().is_empty() {
-        load_mock_data(store);
+        load_from_backend(store).await;
     }
 
     // Select the meeting
@@ -96,6 +99,16 @@ pub async fn schedule_review(
         review.scheduled_meeting_id = Some(meeting_id.to_string());
         review.scheduled_meeting_date = Some(meeting.date.clone());
         review.status = ReviewStatus::Scheduled;
+
+        let endpoint = format!("/nbac/reviews/{}/schedule", review_id);
+        match backend::save(HttpMethod::Post, &endpoint, &*review).await {
+            Ok(saved) => *review = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
     } else {
         store.loading.set(false);
         return Err("Review not found".to_string());
@@ -122,11 +135,16 @@ pub async fn record_decision(
     summary: &str,
     rationale: &str,
     votes: Vec<Vote>,
-    approved_value: Option<f64>,
+    approved_value: Option<Money>,
     awarded_supplier_id: Option<String>,
     awarded_supplier_name: Option<String>,
     conditions: Vec<String>,
 ) -> Result<Decision, String> {
+    if let Err(e) = permissions::require(Action::RecordDecision) {
+        store.error.set(Some(e.clone()));
+        return Err(e);
+    }
+
     store.loading.set(true);
 
     let mut reviews = store.reviews.get().clone();
@@ -139,13 +157,17 @@ pub async fn record_decision(
     let is_unanimous = votes_against == 0 && votes_abstain == 0;
 
     // Create decision
-    let decision = Decision {
+    let mut decision = Decision {
         id: format!("dec_{:03}", decisions.len() + 1),
         review_id: review_id.to_string(),
         decision_type,
         decision_date: "2025-02-27".to_string(),
         meeting_id: "mtg_001".to_string(),
-        resolution_number: format!("NBAC/RES/2025/{:03}", decisions.len() + 24),
+        resolution_number: reference::issue(
+            DocumentType::NbacResolution,
+            decisions.iter().map(|d| d.resolution_number.as_str()),
+        )
+        .number,
         summary: summary.to_string(),
         rationale: rationale.to_string(),
         conditions,
@@ -164,6 +186,19 @@ pub async fn record_decision(
         confirmed_at: None,
     };
 
+    let endpoint = format!("/nbac/reviews/{}/decisions", review_id);
+    match backend::save(HttpMethod::Post, &endpoint, &decision).await {
+        Ok(saved) => {
+            reference::reconcile(DocumentType::NbacResolution, &decision.resolution_number, &saved.resolution_number);
+            decision = saved;
+        }
+        Err(e) => {
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+
     // Update review status based on decision type
     if let Some(review) = reviews.iter_mut().find(|r| r.id == review_id) {
         review.status = match decision_type {
@@ -244,6 +279,16 @@ pub async fn update_review_status(
 
     if let Some(review) = reviews.iter_mut().find(|r| r.id == review_id) {
         review.status = status;
+
+        let endpoint = format!("/nbac/reviews/{}/status", review_id);
+        match backend::save(HttpMethod::Post, &endpoint, &*review).await {
+            Ok(saved) => *review = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
     } else {
         store.loading.set(false);
         return Err("Review not found".to_string());
@@ -315,6 +360,16 @@ pub async fn confirm_decision(
     if let Some(decision) = decisions.iter_mut().find(|d| d.id == decision_id) {
         decision.confirmed_by = Some(confirmed_by.to_string());
         decision.confirmed_at = Some("2025-02-27T14:00:00Z".to_string());
+
+        let endpoint = format!("/nbac/decisions/{}/confirm", decision_id);
+        match backend::save(HttpMethod::Post, &endpoint, &*decision).await {
+            Ok(saved) => *decision = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
     } else {
         store.loading.set(false);
         return Err("Decision not found".to_string());
@@ -349,7 +404,7 @@ pub fn get_decision_stats(store: &NbacStore) -> DecisionStats {
     let variations = decisions.iter().filter(|d| matches!(d.decision_type, DecisionType::Variation)).count();
     let extensions = decisions.iter().filter(|d| matches!(d.decision_type, DecisionType::Extension)).count();
 
-    let total_value: f64 = decisions.iter()
+    let total_value: Money = decisions.iter()
         .filter_map(|d| d.approved_value)
         .sum();
 
@@ -376,7 +431,29 @@ pub struct DecisionStats {
     pub deferrals: usize,
     pub variations: usize,
     pub extensions: usize,
-    pub total_value: f64,
+    pub total_value: Money,
     pub unanimous_count: usize,
     pub unanimous_rate: f64,
 }
+
+/// Refresh NBAC reviews, meetings and decisions from the configured backend
+async fn load_from_backend(store: &NbacStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/nbac/reviews", &store.reviews).await?;
+        backend::fetch_into("/nbac/committee-members", &store.committee_members).await?;
+        backend::fetch_into("/nbac/meetings", &store.meetings).await?;
+        backend::fetch_into("/nbac/decisions", &store.decisions).await?;
+        backend::fetch_into("/nbac/kpis", &store.kpis).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    }
+}
@@ -1,6 +1,7 @@
 //! NBAC store
 
 use components::prelude::*;
+use crate::util::money::Money;
 use super::types::{
     ReviewItem, CommitteeMember, Decision, Meeting, Agenda, AgendaItem,
     ReviewStatus, ReviewCategory, Priority, RiskRating, MeetingStatus,
@@ -242,7 +243,7 @@ pub fn load_mock_data(store: &NbacStore) {
             tender_id: "tender_001".to_string(),
             tender_reference: "RFP-2025-0089".to_string(),
             tender_title: "IT Infrastructure Upgrade - Data Centre Modernization".to_string(),
-            tender_value: 45_000_000.0,
+            tender_value: Money::rands(45_000_000),
             procurement_method: "Open Tender".to_string(),
             department: "Information Technology".to_string(),
             category: ReviewCategory::BidAward,
@@ -255,7 +256,7 @@ pub fn load_mock_data(store: &NbacStore) {
             evaluation_summary: "Four bids received. Technical evaluation completed with Innovate IT Holdings scoring highest (87.3%). Recommended for award based on 80/20 scoring methodology.".to_string(),
             recommended_supplier_id: Some("sup_003".to_string()),
             recommended_supplier_name: Some("Innovate IT Holdings".to_string()),
-            recommended_value: Some(47_200_000.0),
+            recommended_value: Some(Money::rands(47_200_000)),
             recommendation: "Award contract to Innovate IT Holdings based on highest combined score".to_string(),
             bbbee_level: Some(1),
             local_content_percent: Some(65.0),
@@ -296,7 +297,7 @@ pub fn load_mock_data(store: &NbacStore) {
             tender_id: "tender_002".to_string(),
             tender_reference: "RFP-2025-0076".to_string(),
             tender_title: "Security Services - Perimeter and Access Control".to_string(),
-            tender_value: 18_500_000.0,
+            tender_value: Money::rands(18_500_000),
             procurement_method: "Open Tender".to_string(),
             department: "Facilities Management".to_string(),
             category: ReviewCategory::BidAward,
@@ -309,7 +310,7 @@ pub fn load_mock_data(store: &NbacStore) {
             evaluation_summary: "Two bids received. SecureGuard SA recommended based on superior technical proposal and competitive pricing.".to_string(),
             recommended_supplier_id: Some("sup_005".to_string()),
             recommended_supplier_name: Some("SecureGuard SA".to_string()),
-            recommended_value: Some(17_800_000.0),
+            recommended_value: Some(Money::rands(17_800_000)),
             recommendation: "Award to SecureGuard SA".to_string(),
             bbbee_level: Some(1),
             local_content_percent: Some(85.0),
@@ -329,7 +330,7 @@ pub fn load_mock_data(store: &NbacStore) {
             tender_id: "tender_006".to_string(),
             tender_reference: "RFP-2025-0092".to_string(),
             tender_title: "Emergency Generator Installation - Head Office".to_string(),
-            tender_value: 8_500_000.0,
+            tender_value: Money::rands(8_500_000),
             procurement_method: "Emergency Procurement".to_string(),
             department: "Facilities Management".to_string(),
             category: ReviewCategory::EmergencyProcurement,
@@ -342,7 +343,7 @@ pub fn load_mock_data(store: &NbacStore) {
             evaluation_summary: "Emergency procurement required due to load shedding impact on critical operations. Single quote obtained from pre-qualified supplier.".to_string(),
             recommended_supplier_id: Some("sup_015".to_string()),
             recommended_supplier_name: Some("PowerGen Solutions".to_string()),
-            recommended_value: Some(8_500_000.0),
+            recommended_value: Some(Money::rands(8_500_000)),
             recommendation: "Approve emergency procurement".to_string(),
             bbbee_level: Some(2),
             local_content_percent: Some(70.0),
@@ -361,7 +362,7 @@ pub fn load_mock_data(store: &NbacStore) {
             tender_id: "tender_004".to_string(),
             tender_reference: "RFP-2025-0065".to_string(),
             tender_title: "Professional Services - Internal Audit".to_string(),
-            tender_value: 8_500_000.0,
+            tender_value: Money::rands(8_500_000),
             procurement_method: "Open Tender".to_string(),
             department: "Internal Audit".to_string(),
             category: ReviewCategory::BidAward,
@@ -374,7 +375,7 @@ pub fn load_mock_data(store: &NbacStore) {
             evaluation_summary: "AuditPro Consulting demonstrated exceptional technical capability and experience in public sector auditing.".to_string(),
             recommended_supplier_id: Some("sup_010".to_string()),
             recommended_supplier_name: Some("AuditPro Consulting".to_string()),
-            recommended_value: Some(8_200_000.0),
+            recommended_value: Some(Money::rands(8_200_000)),
             recommendation: "Award to AuditPro Consulting".to_string(),
             bbbee_level: Some(1),
             local_content_percent: Some(90.0),
@@ -404,7 +405,7 @@ pub fn load_mock_data(store: &NbacStore) {
                 votes_abstain: 0,
                 is_unanimous: true,
                 effective_date: "2025-02-15".to_string(),
-                approved_value: Some(8_200_000.0),
+                approved_value: Some(Money::rands(8_200_000)),
                 awarded_supplier_id: Some("sup_010".to_string()),
                 awarded_supplier_name: Some("AuditPro Consulting".to_string()),
                 attachments: Vec::new(),
@@ -420,7 +421,7 @@ pub fn load_mock_data(store: &NbacStore) {
             tender_id: "ctr_012".to_string(),
             tender_reference: "CTR-2024-0156".to_string(),
             tender_title: "Cleaning Services Contract - 20% Value Increase".to_string(),
-            tender_value: 12_000_000.0,
+            tender_value: Money::rands(12_000_000),
             procurement_method: "Contract Variation".to_string(),
             department: "Facilities Management".to_string(),
             category: ReviewCategory::ContractVariation,
@@ -433,7 +434,7 @@ pub fn load_mock_data(store: &NbacStore) {
             evaluation_summary: "Request for 20% contract value increase due to expanded scope including new building. Variation within Treasury limits.".to_string(),
             recommended_supplier_id: None,
             recommended_supplier_name: Some("CleanCorp Services (existing contractor)".to_string()),
-            recommended_value: Some(14_400_000.0),
+            recommended_value: Some(Money::rands(14_400_000)),
             recommendation: "Approve variation subject to Treasury approval".to_string(),
             bbbee_level: Some(2),
             local_content_percent: Some(95.0),
@@ -588,7 +589,7 @@ pub fn load_mock_data(store: &NbacStore) {
             votes_abstain: 1,
             is_unanimous: false,
             effective_date: "2025-02-15".to_string(),
-            approved_value: Some(8_200_000.0),
+            approved_value: Some(Money::rands(8_200_000)),
             awarded_supplier_id: Some("sup_010".to_string()),
             awarded_supplier_name: Some("AuditPro Consulting".to_string()),
             attachments: Vec::new(),
@@ -612,7 +613,7 @@ pub fn load_mock_data(store: &NbacStore) {
             votes_abstain: 0,
             is_unanimous: true,
             effective_date: "2025-02-15".to_string(),
-            approved_value: Some(2_500_000.0),
+            approved_value: Some(Money::rands(2_500_000)),
             awarded_supplier_id: Some("sup_020".to_string()),
             awarded_supplier_name: Some("OfficeMax SA".to_string()),
             attachments: Vec::new(),
@@ -660,7 +661,7 @@ pub fn load_mock_data(store: &NbacStore) {
             votes_abstain: 0,
             is_unanimous: true,
             effective_date: "2025-02-01".to_string(),
-            approved_value: Some(15_000_000.0),
+            approved_value: Some(Money::rands(15_000_000)),
             awarded_supplier_id: Some("sup_005".to_string()),
             awarded_supplier_name: Some("SecureGuard SA".to_string()),
             attachments: Vec::new(),
@@ -676,7 +677,7 @@ pub fn load_mock_data(store: &NbacStore) {
         scheduled_reviews: 2,
         decisions_this_month: 4,
         decisions_ytd: 23,
-        total_value_approved_ytd: 156_500_000.0,
+        total_value_approved_ytd: Money::rands(156_500_000),
         average_turnaround_days: 12.5,
         approval_rate: 87.5,
         upcoming_meetings: 2,
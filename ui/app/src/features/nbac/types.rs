@@ -1,6 +1,8 @@
 //! NBAC domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::Money;
 
 /// Review item status
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -28,7 +30,7 @@ impl ReviewStatus {
     }
 
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ReviewStatus::Pending => "Pending",
             ReviewStatus::Scheduled => "Scheduled",
             ReviewStatus::InReview => "In Review",
@@ -36,7 +38,7 @@ impl ReviewStatus {
             ReviewStatus::Rejected => "Rejected",
             ReviewStatus::Deferred => "Deferred",
             ReviewStatus::RequiresInfo => "Info Required",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -72,7 +74,7 @@ pub enum DecisionType {
 
 impl DecisionType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             DecisionType::Award => "Award",
             DecisionType::Reject => "Reject",
             DecisionType::Defer => "Defer",
@@ -80,7 +82,7 @@ impl DecisionType {
             DecisionType::Cancellation => "Cancellation",
             DecisionType::Variation => "Contract Variation",
             DecisionType::Extension => "Contract Extension",
-        }
+        })
     }
 }
 
@@ -99,7 +101,7 @@ pub enum MemberRole {
 
 impl MemberRole {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             MemberRole::Chairperson => "Chairperson",
             MemberRole::DeputyChairperson => "Deputy Chairperson",
             MemberRole::VotingMember => "Voting Member",
@@ -108,7 +110,7 @@ impl MemberRole {
             MemberRole::LegalAdvisor => "Legal Advisor",
             MemberRole::TechnicalAdvisor => "Technical Advisor",
             MemberRole::FinanceRepresentative => "Finance Representative",
-        }
+        })
     }
 
     pub fn is_voting(&self) -> bool {
@@ -178,12 +180,12 @@ pub enum VoteType {
 
 impl VoteType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             VoteType::For => "For",
             VoteType::Against => "Against",
             VoteType::Abstain => "Abstain",
             VoteType::Recused => "Recused",
-        }
+        })
     }
 }
 
@@ -205,7 +207,7 @@ pub struct Decision {
     pub votes_abstain: u32,
     pub is_unanimous: bool,
     pub effective_date: String,
-    pub approved_value: Option<f64>,
+    pub approved_value: Option<Money>,
     pub awarded_supplier_id: Option<String>,
     pub awarded_supplier_name: Option<String>,
     pub attachments: Vec<String>,
@@ -251,7 +253,7 @@ pub struct ReviewItem {
     pub tender_id: String,
     pub tender_reference: String,
     pub tender_title: String,
-    pub tender_value: f64,
+    pub tender_value: Money,
     pub procurement_method: String,
     pub department: String,
     pub category: ReviewCategory,
@@ -264,7 +266,7 @@ pub struct ReviewItem {
     pub evaluation_summary: String,
     pub recommended_supplier_id: Option<String>,
     pub recommended_supplier_name: Option<String>,
-    pub recommended_value: Option<f64>,
+    pub recommended_value: Option<Money>,
     pub recommendation: String,
     pub bbbee_level: Option<u8>,
     pub local_content_percent: Option<f64>,
@@ -283,7 +285,7 @@ impl Default for ReviewItem {
             tender_id: String::new(),
             tender_reference: String::new(),
             tender_title: String::new(),
-            tender_value: 0.0,
+            tender_value: Money::ZERO,
             procurement_method: String::new(),
             department: String::new(),
             category: ReviewCategory::BidAward,
@@ -324,7 +326,7 @@ pub enum ReviewCategory {
 
 impl ReviewCategory {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ReviewCategory::BidAward => "Bid Award",
             ReviewCategory::Deviation => "Deviation",
             ReviewCategory::ContractVariation => "Contract Variation",
@@ -333,7 +335,7 @@ impl ReviewCategory {
             ReviewCategory::EmergencyProcurement => "Emergency Procurement",
             ReviewCategory::SingleSource => "Single Source",
             ReviewCategory::Confinement => "Confinement",
-        }
+        })
     }
 }
 
@@ -354,12 +356,12 @@ pub enum Priority {
 
 impl Priority {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             Priority::Critical => "Critical",
             Priority::High => "High",
             Priority::Medium => "Medium",
             Priority::Low => "Low",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -382,11 +384,11 @@ pub enum RiskRating {
 
 impl RiskRating {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             RiskRating::High => "High",
             RiskRating::Medium => "Medium",
             RiskRating::Low => "Low",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -435,7 +437,7 @@ pub enum DocumentType {
 
 impl DocumentType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             DocumentType::EvaluationReport => "Evaluation Report",
             DocumentType::TechnicalReport => "Technical Report",
             DocumentType::FinancialAnalysis => "Financial Analysis",
@@ -446,7 +448,7 @@ impl DocumentType {
             DocumentType::LegalOpinion => "Legal Opinion",
             DocumentType::MotivationMemo => "Motivation Memo",
             DocumentType::Other => "Other",
-        }
+        })
     }
 }
 
@@ -473,13 +475,13 @@ pub enum MeetingStatus {
 
 impl MeetingStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             MeetingStatus::Scheduled => "Scheduled",
             MeetingStatus::InProgress => "In Progress",
             MeetingStatus::Completed => "Completed",
             MeetingStatus::Cancelled => "Cancelled",
             MeetingStatus::Postponed => "Postponed",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -564,12 +566,12 @@ pub enum MeetingType {
 
 impl MeetingType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             MeetingType::Ordinary => "Ordinary",
             MeetingType::Special => "Special",
             MeetingType::Emergency => "Emergency",
             MeetingType::AdHoc => "Ad Hoc",
-        }
+        })
     }
 }
 
@@ -631,13 +633,13 @@ pub enum AgendaItemStatus {
 
 impl AgendaItemStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             AgendaItemStatus::Pending => "Pending",
             AgendaItemStatus::InProgress => "In Progress",
             AgendaItemStatus::Completed => "Completed",
             AgendaItemStatus::Deferred => "Deferred",
             AgendaItemStatus::Withdrawn => "Withdrawn",
-        }
+        })
     }
 }
 
@@ -648,13 +650,13 @@ impl Default for AgendaItemStatus {
 }
 
 /// NBAC KPI summary
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct NbacKpis {
     pub pending_reviews: u32,
     pub scheduled_reviews: u32,
     pub decisions_this_month: u32,
     pub decisions_ytd: u32,
-    pub total_value_approved_ytd: f64,
+    pub total_value_approved_ytd: Money,
     pub average_turnaround_days: f64,
     pub approval_rate: f64,
     pub upcoming_meetings: u32,
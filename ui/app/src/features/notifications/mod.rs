@@ -0,0 +1,6 @@
+//! Notification centre feature module
+
+pub mod types;
+pub mod store;
+pub mod service;
+pub mod notification_centre;
@@ -0,0 +1,251 @@
+//! Notification bell and inbox dropdown shown in the topbar
+
+use components::prelude::*;
+use crate::Route;
+use crate::features::mobile::types::{NotificationCategory, NotificationPriority};
+use crate::util::auth::AuthState;
+use crate::util::format::format_datetime;
+use crate::util::i18n::{t, tn};
+use super::store::NotificationsStore;
+use super::types::{Notification, inbox_categories};
+use super::service;
+
+/// Topbar bell with unread badge and inbox dropdown
+#[component]
+pub fn notification_centre(on_navigate: Callback<Route>) -> View {
+    let store = use_context::<NotificationsStore>();
+    let auth_state = use_context::<Signal<AuthState>>();
+
+    // Load the inbox once per session
+    effect({
+        let store = store.clone();
+        move || {
+            if store.loaded.get() || store.loading.get() {
+                return;
+            }
+            let store = store.clone();
+            spawn(async move {
+                service::load_notifications(&store).await;
+            });
+        }
+    });
+
+    let handle_toggle = Callback::<()>::new({
+        let store = store.clone();
+        move |_| {
+            store.open.set(!store.open.get());
+            store.show_preferences.set(false);
+        }
+    });
+
+    let handle_mark_all = Callback::<()>::new({
+        let store = store.clone();
+        move |_| {
+            let store = store.clone();
+            spawn(async move {
+                service::mark_all_read(&store).await;
+            });
+        }
+    });
+
+    let handle_preferences = Callback::<()>::new({
+        let store = store.clone();
+        move |_| store.show_preferences.set(!store.show_preferences.get())
+    });
+
+    let auth = auth_state.get();
+    let open = store.open.get();
+    let show_preferences = store.show_preferences.get();
+    let notifications = store.visible(&auth);
+    let unread = store.unread_count(&auth);
+    let bell_title = if unread > 0 {
+        tn("{n} unread notification", "{n} unread notifications", unread as i64)
+    } else {
+        t("Notifications").to_string()
+    };
+    let preferences = store.preferences.get();
+    let error = store.error.get();
+
+    view! {
+        style {
+            r#"
+            .notification-centre { position: relative; }
+            .notification-count {
+                position: absolute;
+                top: -4px;
+                right: -4px;
+                min-width: 18px;
+                height: 18px;
+                padding: 0 5px;
+                background: var(--red);
+                color: #fff;
+                border-radius: 9px;
+                border: 2px solid var(--surface);
+                font-size: 10px;
+                font-weight: 600;
+                line-height: 14px;
+                text-align: center;
+            }
+            .notification-inbox {
+                position: absolute;
+                top: calc(100% + 8px);
+                right: 0;
+                width: 380px;
+                max-height: 520px;
+                overflow-y: auto;
+                background: var(--surface);
+                border: 1px solid var(--border);
+                border-radius: var(--radius);
+                box-shadow: 0 8px 24px rgba(0, 0, 0, 0.12);
+                z-index: 60;
+            }
+            .notification-inbox-header {
+                display: flex;
+                align-items: center;
+                justify-content: space-between;
+                padding: 12px 16px;
+                border-bottom: 1px solid var(--border);
+                font-weight: 600;
+                font-size: 14px;
+            }
+            .notification-inbox-actions { display: flex; gap: 8px; }
+            .notification-inbox-actions button {
+                border: none;
+                background: none;
+                color: var(--blue);
+                font-size: 12px;
+                cursor: pointer;
+            }
+            .notification-item {
+                display: flex;
+                gap: 10px;
+                padding: 12px 16px;
+                border-bottom: 1px solid var(--border);
+                cursor: pointer;
+            }
+            .notification-item:last-child { border-bottom: none; }
+            .notification-item:hover { background: var(--bg); }
+            .notification-item.unread { background: rgba(37, 99, 235, 0.04); }
+            .notification-marker {
+                flex-shrink: 0;
+                width: 8px;
+                height: 8px;
+                margin-top: 6px;
+                border-radius: 50%;
+                background: transparent;
+            }
+            .notification-item.unread .notification-marker { background: var(--blue); }
+            .notification-item.high .notification-marker { background: var(--red); }
+            .notification-item:not(.unread) .notification-marker { background: transparent; }
+            .notification-kind { font-size: 11px; color: var(--text-muted); text-transform: uppercase; letter-spacing: 0.04em; }
+            .notification-title { font-size: 13px; font-weight: 500; margin: 2px 0; }
+            .notification-message { font-size: 12px; color: var(--text-muted); line-height: 1.4; }
+            .notification-time { font-size: 11px; color: var(--text-muted); margin-top: 4px; }
+            .notification-empty, .notification-error { padding: 24px 16px; font-size: 13px; color: var(--text-muted); text-align: center; }
+            .notification-error { color: var(--red); padding: 8px 16px; text-align: left; }
+            .notification-preference {
+                display: flex;
+                align-items: center;
+                justify-content: space-between;
+                padding: 10px 16px;
+                font-size: 13px;
+                border-bottom: 1px solid var(--border);
+            }
+            .notification-preference input { width: 16px; height: 16px; accent-color: var(--blue); cursor: pointer; }
+            "#
+        }
+
+        <div class="notification-centre" data-testid="notification-centre">
+            <button class="topbar-btn" title={bell_title} data-testid="notification-bell" on:click={handle_toggle}>
+                <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                    <path d="M18 8A6 6 0 0 0 6 8c0 7-3 9-3 9h18s-3-2-3-9"/>
+                    <path d="M13.73 21a2 2 0 0 1-3.46 0"/>
+                </svg>
+                if unread > 0 {
+                    <span class="notification-count" data-testid="notification-unread-count">
+                        {if unread > 9 { "9+".to_string() } else { unread.to_string() }}
+                    </span>
+                }
+            </button>
+            if open {
+                <div class="notification-inbox" data-testid="notification-inbox">
+                    <div class="notification-inbox-header">
+                        <span>{if show_preferences { t("Notification settings") } else { t("Notifications") }}</span>
+                        <div class="notification-inbox-actions">
+                            if !show_preferences && unread > 0 {
+                                <button data-testid="notification-mark-all" on:click={handle_mark_all}>{t("Mark all read")}</button>
+                            }
+                            <button data-testid="notification-settings" on:click={handle_preferences}>
+                                {if show_preferences { t("Back") } else { t("Settings") }}
+                            </button>
+                        </div>
+                    </div>
+                    if let Some(err) = error {
+                        <div class="notification-error">{err}</div>
+                    }
+                    if show_preferences {
+                        for category in inbox_categories() {
+                            {preference_row(category, preferences.is_enabled(category), store.clone())}
+                        }
+                    } else if notifications.is_empty() {
+                        <div class="notification-empty">{t("You're all caught up")}</div>
+                    } else {
+                        for notification in notifications.into_iter() {
+                            {notification_item(notification, store.clone(), on_navigate.clone())}
+                        }
+                    }
+                </div>
+            }
+        </div>
+    }
+}
+
+fn notification_item(notification: Notification, store: NotificationsStore, on_navigate: Callback<Route>) -> View {
+    let mut class = "notification-item".to_string();
+    if !notification.read {
+        class.push_str(" unread");
+    }
+    if matches!(notification.priority, NotificationPriority::High | NotificationPriority::Critical) {
+        class.push_str(" high");
+    }
+
+    let handle_open = Callback::<()>::new({
+        let id = notification.id.clone();
+        let route = notification.route();
+        move |_| {
+            store.open.set(false);
+            let store = store.clone();
+            let id = id.clone();
+            spawn(async move {
+                service::mark_read(&store, &id).await;
+            });
+            on_navigate.call(route.clone());
+        }
+    });
+
+    view! {
+        <div class={class} data-testid="notification-item" on:click={handle_open}>
+            <span class="notification-marker"></span>
+            <div>
+                <div class="notification-kind">{notification.kind.label()}</div>
+                <div class="notification-title">{notification.title.clone()}</div>
+                <div class="notification-message">{notification.message.clone()}</div>
+                <div class="notification-time">{format_datetime(&notification.created_at)}</div>
+            </div>
+        </div>
+    }
+}
+
+fn preference_row(category: NotificationCategory, enabled: bool, store: NotificationsStore) -> View {
+    let handle_change = Callback::<()>::new(move |_| {
+        service::set_category_enabled(&store, category, !enabled);
+    });
+    let testid = format!("notification-pref-{:?}", category).to_lowercase();
+
+    view! {
+        <label class="notification-preference">
+            <span>{category.label()}</span>
+            <input type="checkbox" checked={enabled} data-testid={testid} on:change={handle_change} />
+        </label>
+    }
+}
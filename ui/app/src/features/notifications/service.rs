@@ -0,0 +1,115 @@
+//! Notification centre service - API calls
+
+use crate::features::mobile::types::NotificationCategory;
+use components::prelude::*;
+use crate::util::api::HttpMethod;
+use crate::util::backend;
+use crate::util::datetime::now_iso;
+use super::store::{self, NotificationsStore, load_mock_data};
+use super::types::{Notification, NotificationPreferences};
+
+/// localStorage key for the per-category preferences
+const PREFERENCES_KEY: &str = "eprocurement.notification-preferences";
+
+/// Load the inbox and the saved preferences
+pub async fn load_notifications(store: &NotificationsStore) {
+    store.loading.set(true);
+    store.error.set(None);
+    store.preferences.set(load_preferences());
+
+    if backend::is_mock() {
+        // Keep what the session raised on top of the fixtures
+        let raised = store.notifications.get();
+        load_mock_data(store);
+        let mut notifications = store.notifications.get();
+        for notification in raised {
+            if !notifications.iter().any(|n| n.id == notification.id) {
+                notifications.push(notification);
+            }
+        }
+        store.notifications.set(notifications);
+    } else if let Err(e) = backend::fetch_into("/notifications", &store.notifications).await {
+        store.error.set(Some(e.to_string()));
+    }
+
+    store.loaded.set(true);
+    store.loading.set(false);
+}
+
+/// Raise a notification in the installed store.
+///
+/// One with the id of an earlier notification replaces it, unread again,
+/// so repeated reminders about the same step do not pile up.
+pub fn notify(mut notification: Notification) {
+    notification.created_at = now_iso();
+    notification.read = false;
+
+    let store = store::installed();
+    let mut notifications = store.notifications.get();
+    notifications.retain(|n| n.id != notification.id);
+    notifications.insert(0, notification.clone());
+    store.notifications.set(notifications);
+
+    if !backend::is_mock() {
+        spawn(async move {
+            let _ = backend::save(HttpMethod::Post, "/notifications", &notification).await;
+        });
+    }
+}
+
+/// Mark one notification read
+pub async fn mark_read(store: &NotificationsStore, id: &str) {
+    let Some(mut notification) = store.notifications.get().into_iter().find(|n| n.id == id) else {
+        return;
+    };
+    if notification.read {
+        return;
+    }
+
+    notification.read = true;
+    store.set_read(id, true);
+    let endpoint = format!("/notifications/{}/read", id);
+    if let Err(e) = backend::save(HttpMethod::Post, &endpoint, &notification).await {
+        store.set_read(id, false);
+        store.error.set(Some(e.to_string()));
+    }
+}
+
+/// Mark every notification read
+pub async fn mark_all_read(store: &NotificationsStore) {
+    let previous = store.notifications.get();
+    let mut notifications = previous.clone();
+    for n in notifications.iter_mut() {
+        n.read = true;
+    }
+    store.notifications.set(notifications.clone());
+
+    if let Err(e) = backend::save(HttpMethod::Post, "/notifications/read-all", &notifications).await {
+        store.notifications.set(previous);
+        store.error.set(Some(e.to_string()));
+    }
+}
+
+/// Switch a category on or off and remember the choice in this browser
+pub fn set_category_enabled(store: &NotificationsStore, category: NotificationCategory, enabled: bool) {
+    let mut preferences = store.preferences.get();
+    preferences.set_enabled(category, enabled);
+    save_preferences(&preferences);
+    store.preferences.set(preferences);
+}
+
+fn load_preferences() -> NotificationPreferences {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(PREFERENCES_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_preferences(preferences: &NotificationPreferences) {
+    if let Some(Ok(Some(storage))) = web_sys::window().map(|w| w.local_storage()) {
+        if let Ok(json) = serde_json::to_string(preferences) {
+            let _ = storage.set_item(PREFERENCES_KEY, &json);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Notification centre store
+
+use std::cell::RefCell;
+use components::prelude::*;
+use crate::features::mobile::types::NotificationPriority;
+use crate::util::auth::AuthState;
+use crate::util::permissions;
+use super::types::{Notification, NotificationKind, NotificationPreferences};
+
+/// Notification centre state store
+#[derive(Clone)]
+pub struct NotificationsStore {
+    pub notifications: Signal<Vec<Notification>>,
+    pub preferences: Signal<NotificationPreferences>,
+    /// Inbox dropdown is showing
+    pub open: Signal<bool>,
+    /// Dropdown shows the category preferences instead of the inbox
+    pub show_preferences: Signal<bool>,
+    pub loaded: Signal<bool>,
+    pub loading: Signal<bool>,
+    pub error: Signal<Option<String>>,
+}
+
+impl NotificationsStore {
+    pub fn new() -> Self {
+        Self {
+            notifications: signal(Vec::new()),
+            preferences: signal(NotificationPreferences::default()),
+            open: signal(false),
+            show_preferences: signal(false),
+            loaded: signal(false),
+            loading: signal(false),
+            error: signal(None),
+        }
+    }
+
+    /// Notifications the user may open, in categories they have not muted, newest first
+    pub fn visible(&self, auth: &AuthState) -> Vec<Notification> {
+        let preferences = self.preferences.get();
+        let mut visible: Vec<Notification> = self.notifications.get()
+            .into_iter()
+            .filter(|n| preferences.is_enabled(n.category()))
+            .filter(|n| permissions::can_access(auth, &n.route()))
+            .collect();
+        visible.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        visible
+    }
+
+    pub fn unread_count(&self, auth: &AuthState) -> usize {
+        self.visible(auth).iter().filter(|n| !n.read).count()
+    }
+
+    /// Set the read flag on one notification
+    pub fn set_read(&self, id: &str, read: bool) {
+        let mut notifications = self.notifications.get();
+        if let Some(n) = notifications.iter_mut().find(|n| n.id == id) {
+            n.read = read;
+        }
+        self.notifications.set(notifications);
+    }
+}
+
+thread_local! {
+    static STORE: RefCell<Option<NotificationsStore>> = RefCell::new(None);
+}
+
+/// Register the app's notifications store so other features can raise
+/// notifications
+pub fn install(store: NotificationsStore) {
+    STORE.with(|s| *s.borrow_mut() = Some(store));
+}
+
+/// The installed notifications store, or a fresh one outside the app component
+pub fn installed() -> NotificationsStore {
+    STORE.with(|s| s.borrow().clone()).unwrap_or_else(|| {
+        let store = NotificationsStore::new();
+        install(store.clone());
+        store
+    })
+}
+
+/// Load mock notifications linked to the other features' fixtures
+pub fn load_mock_data(store: &NotificationsStore) {
+    let notifications = vec![
+        Notification {
+            id: "ntf_001".to_string(),
+            kind: NotificationKind::ApprovalAwaiting,
+            title: "REQ-2025-0847 awaits your approval".to_string(),
+            message: "IT Hardware - Laptops Q4 (R 2.45m) was submitted for approval.".to_string(),
+            entity_id: "REQ-2025-0847".to_string(),
+            priority: NotificationPriority::High,
+            created_at: "2025-02-27T08:15:00".to_string(),
+            read: false,
+        },
+        Notification {
+            id: "ntf_002".to_string(),
+            kind: NotificationKind::TenderClosing,
+            title: "TND-2025-0089 closes tomorrow".to_string(),
+            message: "Bids close on 28 Feb 2025 at 11:00. Late submissions will not be accepted.".to_string(),
+            entity_id: "TND-2025-0089".to_string(),
+            priority: NotificationPriority::Medium,
+            created_at: "2025-02-27T07:00:00".to_string(),
+            read: false,
+        },
+        Notification {
+            id: "ntf_003".to_string(),
+            kind: NotificationKind::ContractExpiring,
+            title: "CTR-2024-0123 expires in 2 days".to_string(),
+            message: "Cleaning Services - Regional Offices with CleanCorp Services ends on 28 Feb 2025.".to_string(),
+            entity_id: "CTR-2024-0123".to_string(),
+            priority: NotificationPriority::High,
+            created_at: "2025-02-26T06:00:00".to_string(),
+            read: false,
+        },
+        Notification {
+            id: "ntf_004".to_string(),
+            kind: NotificationKind::CertificateExpiring,
+            title: "B-BBEE certificate expiring for Cape Catering Services CC".to_string(),
+            message: "Certificate BEE-2024-23456 lapses on 30 Jun 2025. Request an updated certificate before new awards.".to_string(),
+            entity_id: "SUP-003".to_string(),
+            priority: NotificationPriority::Medium,
+            created_at: "2025-02-25T09:30:00".to_string(),
+            read: false,
+        },
+        Notification {
+            id: "ntf_005".to_string(),
+            kind: NotificationKind::NbacMeetingScheduled,
+            title: "NBAC/2025/0089 scheduled for 5 Mar 2025".to_string(),
+            message: "IT Infrastructure Upgrade - Data Centre Modernization is on the agenda for the next NBAC meeting.".to_string(),
+            entity_id: "rev_001".to_string(),
+            priority: NotificationPriority::Medium,
+            created_at: "2025-02-20T14:10:00".to_string(),
+            read: true,
+        },
+        Notification {
+            id: "ntf_006".to_string(),
+            kind: NotificationKind::TenderClosing,
+            title: "TND-2025-0091 has closed".to_string(),
+            message: "Bid submissions closed on 31 Jan 2025. Evaluation can begin.".to_string(),
+            entity_id: "TND-2025-0091".to_string(),
+            priority: NotificationPriority::Low,
+            created_at: "2025-01-31T11:00:00".to_string(),
+            read: true,
+        },
+    ];
+
+    store.notifications.set(notifications);
+}
@@ -0,0 +1,116 @@
+//! Notification centre types
+
+use serde::{Deserialize, Serialize};
+use crate::Route;
+use crate::features::mobile::types::{NotificationCategory, NotificationPriority};
+use crate::util::i18n::t;
+
+/// What a notification is about; decides its category and deep link
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum NotificationKind {
+    /// A requisition is waiting for the user's approval
+    ApprovalAwaiting,
+    /// A requisition approval step is due or past its SLA
+    ApprovalReminder,
+    /// An overdue requisition approval step moved on to the next authority
+    ApprovalEscalated,
+    /// A published tender is about to close
+    TenderClosing,
+    /// A contract is nearing its end date
+    ContractExpiring,
+    /// A supplier's B-BBEE or tax certificate is about to lapse
+    CertificateExpiring,
+    /// An item has been placed on an NBAC meeting agenda
+    NbacMeetingScheduled,
+}
+
+impl NotificationKind {
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            NotificationKind::ApprovalAwaiting => "Approval awaiting you",
+            NotificationKind::ApprovalReminder => "Approval due",
+            NotificationKind::ApprovalEscalated => "Approval escalated",
+            NotificationKind::TenderClosing => "Tender closing",
+            NotificationKind::ContractExpiring => "Contract expiring",
+            NotificationKind::CertificateExpiring => "Certificate expiring",
+            NotificationKind::NbacMeetingScheduled => "NBAC meeting scheduled",
+        })
+    }
+
+    pub fn category(&self) -> NotificationCategory {
+        match self {
+            NotificationKind::ApprovalAwaiting
+            | NotificationKind::ApprovalReminder
+            | NotificationKind::ApprovalEscalated => NotificationCategory::Approvals,
+            NotificationKind::TenderClosing => NotificationCategory::Tenders,
+            NotificationKind::ContractExpiring => NotificationCategory::Contracts,
+            NotificationKind::CertificateExpiring => NotificationCategory::Certificates,
+            NotificationKind::NbacMeetingScheduled => NotificationCategory::Meetings,
+        }
+    }
+}
+
+/// An inbox entry
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: String,
+    pub kind: NotificationKind,
+    pub title: String,
+    pub message: String,
+    /// Id of the record the notification links to
+    pub entity_id: String,
+    pub priority: NotificationPriority,
+    pub created_at: String,
+    pub read: bool,
+}
+
+impl Notification {
+    pub fn category(&self) -> NotificationCategory {
+        self.kind.category()
+    }
+
+    /// Page that opens the record the notification is about
+    pub fn route(&self) -> Route {
+        let id = self.entity_id.clone();
+        match self.kind {
+            NotificationKind::ApprovalAwaiting
+            | NotificationKind::ApprovalReminder
+            | NotificationKind::ApprovalEscalated => Route::RequisitionsDetail(id),
+            NotificationKind::TenderClosing => Route::TendersEdit(id),
+            NotificationKind::ContractExpiring => Route::ContractsMilestones(id),
+            NotificationKind::CertificateExpiring => Route::SuppliersDetail(id),
+            NotificationKind::NbacMeetingScheduled => Route::NbacReviewDetail(id),
+        }
+    }
+}
+
+/// Categories shown in the notification centre, in display order
+pub fn inbox_categories() -> Vec<NotificationCategory> {
+    vec![
+        NotificationCategory::Approvals,
+        NotificationCategory::Tenders,
+        NotificationCategory::Contracts,
+        NotificationCategory::Certificates,
+        NotificationCategory::Meetings,
+    ]
+}
+
+/// Per-category inbox preferences, kept in the browser
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct NotificationPreferences {
+    /// Categories the user has switched off
+    pub muted: Vec<NotificationCategory>,
+}
+
+impl NotificationPreferences {
+    pub fn is_enabled(&self, category: NotificationCategory) -> bool {
+        !self.muted.contains(&category)
+    }
+
+    pub fn set_enabled(&mut self, category: NotificationCategory, enabled: bool) {
+        self.muted.retain(|c| *c != category);
+        if !enabled {
+            self.muted.push(category);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Extraction engine registry
+//!
+//! The intake pipeline hands each uploaded file to the installed engine and
+//! gets back the document type and the fields it could read, each with a
+//! confidence. An engine backed by an OCR service is installed at start-up;
+//! dev builds and the mock backend fall back to the local stand-in, which
+//! makes up its readings. Anywhere else, without an installed engine the
+//! intake screen says no engine is configured rather than inventing data.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use crate::shared::forms::UploadedFile;
+use crate::util::backend;
+use crate::util::money::Money;
+use super::types::{ExtractedField, ExtractedLine, OcrDocumentType};
+
+/// Future returned by an engine; extraction runs on the single browser thread
+pub type ExtractionFuture = Pin<Box<dyn Future<Output = Result<Extraction, String>>>>;
+
+/// What an engine read from one document
+#[derive(Clone, Debug, Default)]
+pub struct Extraction {
+    pub document_type: Option<OcrDocumentType>,
+    /// Confidence in the document type, 100 when the user chose it
+    pub classification_confidence: f64,
+    pub page_count: u32,
+    pub fields: Vec<ExtractedField>,
+    pub lines: Vec<ExtractedLine>,
+}
+
+/// Reads procurement documents
+pub trait ExtractionEngine {
+    /// Name recorded on each scan
+    fn name(&self) -> &'static str;
+
+    /// Classify the document unless `document_type` is given, then extract
+    /// the fields of that type
+    fn extract(&self, file: UploadedFile, document_type: Option<OcrDocumentType>) -> ExtractionFuture;
+}
+
+thread_local! {
+    static ENGINE: RefCell<Option<Rc<dyn ExtractionEngine>>> = RefCell::new(None);
+}
+
+/// Install the engine used for every following scan
+pub fn install(engine: Rc<dyn ExtractionEngine>) {
+    ENGINE.with(|e| *e.borrow_mut() = Some(engine));
+}
+
+/// The installed engine; in a dev build or on the mock backend the local
+/// stand-in if none was installed
+pub fn engine() -> Option<Rc<dyn ExtractionEngine>> {
+    ENGINE.with(|e| {
+        let mut installed = e.borrow_mut();
+        if installed.is_none() && (cfg!(debug_assertions) || backend::is_mock()) {
+            *installed = Some(Rc::new(LocalEngine));
+        }
+        installed.clone()
+    })
+}
+
+/// Whether documents can be read at all
+pub fn is_configured() -> bool {
+    engine().is_some()
+}
+
+/// Stand-in engine for development and demos.
+///
+/// It does not read the file: the document type is guessed from the file
+/// name and the fields come from a sample document of that type. The
+/// confidences vary with the file name so different uploads show different
+/// review states, including a misread VAT number to correct.
+pub struct LocalEngine;
+
+const DELIVERY_NOTE_HINTS: &[&str] = &["delivery", "dn", "waybill", "grn", "pod"];
+const CERTIFICATE_HINTS: &[&str] = &["bee", "bbbee", "b-bbee", "certificate", "cert", "affidavit"];
+const INVOICE_HINTS: &[&str] = &["inv", "invoice", "tax", "bill"];
+
+impl LocalEngine {
+    /// Guess the document type from the words in the file name
+    fn classify(file_name: &str) -> (OcrDocumentType, f64) {
+        let name = file_name.to_lowercase();
+        let words: Vec<&str> = name.split(|c: char| !c.is_ascii_alphanumeric() && c != '-').collect();
+        let mentions = |hints: &[&str]| words.iter().any(|w| hints.iter().any(|h| w == h || w.starts_with(h)));
+        if mentions(CERTIFICATE_HINTS) {
+            (OcrDocumentType::BbbeeCertificate, 96.0)
+        } else if mentions(DELIVERY_NOTE_HINTS) {
+            (OcrDocumentType::DeliveryNote, 94.0)
+        } else if mentions(INVOICE_HINTS) {
+            (OcrDocumentType::Invoice, 97.0)
+        } else {
+            // Most documents received are invoices
+            (OcrDocumentType::Invoice, 68.0)
+        }
+    }
+
+    /// Sample value and base confidence of each field
+    fn sample(document_type: OcrDocumentType) -> Vec<(&'static str, &'static str, f64)> {
+        match document_type {
+            OcrDocumentType::Invoice => vec![
+                ("invoice_number", "TS-INV-21107", 97.0),
+                ("invoice_date", "2025-02-25", 95.0),
+                ("due_date", "2025-03-27", 78.0),
+                ("supplier_name", "TechSolutions SA (Pty) Ltd", 96.0),
+                // A zero read as the letter O, for the reviewer to correct
+                ("supplier_vat", "4O12345678", 62.0),
+                ("po_number", "PO-2025-0456", 93.0),
+                ("subtotal", "10000.00", 94.0),
+                ("vat_amount", "1500.00", 91.0),
+                ("total_amount", "11500.00", 96.0),
+            ],
+            OcrDocumentType::DeliveryNote => vec![
+                ("delivery_note_number", "DN-ACF-7731", 95.0),
+                ("po_number", "PO-2025-0453", 92.0),
+                ("supplier_name", "AutoCare Fleet Management", 90.0),
+                ("delivery_date", "2025-02-26", 88.0),
+                ("received_by", "T. Mokoena", 58.0),
+            ],
+            OcrDocumentType::BbbeeCertificate => vec![
+                ("supplier_name", "TechSolutions SA (Pty) Ltd", 95.0),
+                ("registration_number", "2015/123456/07", 93.0),
+                ("certificate_number", "BEE-2025-11842", 91.0),
+                ("bbbee_level", "1", 96.0),
+                ("issue_date", "2025-02-10", 89.0),
+                ("expiry_date", "2026-02-09", 87.0),
+                ("verification_agency", "Empowerdex", 84.0),
+            ],
+        }
+    }
+
+    fn sample_lines(document_type: OcrDocumentType) -> Vec<ExtractedLine> {
+        let line = |item_code: &str, description: &str, quantity: u32, unit: &str, unit_price: i64, confidence: f64| {
+            ExtractedLine {
+                item_code: item_code.to_string(),
+                description: description.to_string(),
+                quantity,
+                unit: unit.to_string(),
+                unit_price: Money::rands(unit_price),
+                confidence,
+            }
+        };
+        match document_type {
+            OcrDocumentType::Invoice => vec![
+                line("SVC-INS-001", "Installation and Configuration", 8, "hour", 1250, 92.0),
+            ],
+            OcrDocumentType::DeliveryNote => vec![
+                line("SVC-FSC-001", "Full Vehicle Service - Sedan", 4, "service", 4500, 89.0),
+            ],
+            OcrDocumentType::BbbeeCertificate => Vec::new(),
+        }
+    }
+
+    /// Deterministic variation of -3 to +3 points per file and field
+    fn jitter(file_name: &str, index: usize) -> f64 {
+        let seed = file_name.bytes().fold(index as u32 * 31, |acc, b| acc.wrapping_mul(33).wrapping_add(b as u32));
+        (seed % 7) as f64 - 3.0
+    }
+}
+
+impl ExtractionEngine for LocalEngine {
+    fn name(&self) -> &'static str {
+        "Local stand-in"
+    }
+
+    fn extract(&self, file: UploadedFile, document_type: Option<OcrDocumentType>) -> ExtractionFuture {
+        Box::pin(async move {
+            let (document_type, classification_confidence) = match document_type {
+                Some(chosen) => (chosen, 100.0),
+                None => Self::classify(&file.name),
+            };
+            let samples = Self::sample(document_type);
+            let fields = document_type
+                .fields()
+                .iter()
+                .enumerate()
+                .map(|(i, spec)| {
+                    let (value, confidence) = samples
+                        .iter()
+                        .find(|(key, _, _)| *key == spec.key)
+                        .map(|(_, value, confidence)| (*value, *confidence))
+                        .unwrap_or(("", 0.0));
+                    let confidence = (confidence + Self::jitter(&file.name, i)).clamp(0.0, 99.0);
+                    ExtractedField::from_spec(spec, value, confidence)
+                })
+                .collect();
+            let page_count = match file.extension().as_str() {
+                "pdf" | "tif" | "tiff" => 1 + (file.size / (512 * 1024)).min(4) as u32,
+                _ => 1,
+            };
+            Ok(Extraction {
+                document_type: Some(document_type),
+                classification_confidence,
+                page_count,
+                fields,
+                lines: Self::sample_lines(document_type),
+            })
+        })
+    }
+}
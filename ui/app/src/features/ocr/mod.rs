@@ -0,0 +1,14 @@
+//! Document intake feature module
+//!
+//! Reads scanned invoices, delivery notes and B-BBEE certificates through a
+//! pluggable extraction engine, shows the confidence of every field, checks
+//! the values against the business rules and saves reviewed documents as
+//! draft invoices, draft goods receipts or supplier certificates.
+
+pub mod types;
+pub mod engine;
+pub mod validation;
+pub mod store;
+pub mod service;
+pub mod ocr_intake;
+pub mod ocr_review;
@@ -0,0 +1,371 @@
+//! Document intake
+//!
+//! Upload scanned invoices, delivery notes and B-BBEE certificates, have them
+//! read and open each one for review. Also lists the documents processed so
+//! far and the records they were saved as.
+
+use components::prelude::*;
+use crate::Route;
+use crate::features::goods_receipt::store::GoodsReceiptStore;
+use crate::features::invoices::store::InvoicesStore;
+use crate::features::suppliers::store::SuppliersStore;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table_with_testid, DataTableColumn, DataTableRow,
+    kpi_card, KpiColor,
+    status_badge, StatusType,
+    notice_bar, NoticeType,
+    empty_state,
+};
+use crate::shared::forms::{file_upload, select_with_testid, SelectOption, UploadedFile};
+use crate::util::format::{format_datetime, format_file_size};
+use crate::util::i18n::t;
+use super::engine;
+use super::service::{self, IntakeStores};
+use super::store::OcrStore;
+use super::types::{
+    OcrDocumentType, OcrScan, ScanStatus, ACCEPTED_FORMATS, MAX_FILES, MAX_FILE_SIZE,
+};
+
+pub(super) fn scan_status(status: ScanStatus) -> View {
+    status_badge(match status {
+        ScanStatus::Processing => StatusType::InProgress,
+        ScanStatus::Complete => StatusType::Review,
+        ScanStatus::Failed => StatusType::Failed,
+        ScanStatus::Saved => StatusType::Complete,
+    })
+}
+
+/// Page of the record a saved scan created
+pub(super) fn record_route(document_type: OcrDocumentType, record: &str) -> Route {
+    match document_type {
+        OcrDocumentType::Invoice => Route::InvoicesDetail(record.to_string()),
+        OcrDocumentType::DeliveryNote => Route::GoodsReceiptDetail(record.to_string()),
+        OcrDocumentType::BbbeeCertificate => Route::SuppliersDetail(record.to_string()),
+    }
+}
+
+pub(super) fn intake_stores() -> IntakeStores {
+    IntakeStores {
+        invoices: use_context::<InvoicesStore>(),
+        receipts: use_context::<GoodsReceiptStore>(),
+        suppliers: use_context::<SuppliersStore>(),
+    }
+}
+
+/// Document intake page
+#[component]
+pub fn ocr_intake() -> View {
+    let store = use_context::<OcrStore>();
+    let stores = intake_stores();
+    let navigate = use_context::<Callback<Route>>();
+
+    effect({
+        let store = store.clone();
+        move || {
+            let store = store.clone();
+            spawn(async move {
+                service::load_scans(&store).await;
+            });
+        }
+    });
+
+    let files: Signal<Vec<UploadedFile>> = signal(Vec::new());
+    let document_type = signal(String::new());
+    let rejected = signal(Vec::<String>::new());
+
+    let handle_remove = Callback::new({
+        let files = files.clone();
+        move |index: usize| {
+            let mut list = files.get();
+            if index < list.len() {
+                list.remove(index);
+            }
+            files.set(list);
+        }
+    });
+
+    let handle_process = Callback::<()>::new({
+        let store = store.clone();
+        let stores = stores.clone();
+        let files = files.clone();
+        let document_type = document_type.clone();
+        let rejected = rejected.clone();
+        let navigate = navigate.clone();
+        move |_| {
+            let queue = files.get();
+            if queue.len() > MAX_FILES {
+                store.error.set(Some(format!("{} {} {}", t("Upload at most"), MAX_FILES, t("documents at a time"))));
+                return;
+            }
+            let chosen = OcrDocumentType::from_code(&document_type.get());
+            let store = store.clone();
+            let stores = stores.clone();
+            let files = files.clone();
+            let rejected = rejected.clone();
+            let navigate = navigate.clone();
+            spawn(async move {
+                let mut errors = Vec::new();
+                let mut ready = Vec::new();
+                for file in queue {
+                    match service::process_document(&store, &stores, file, chosen).await {
+                        Ok(scan) if scan.status == ScanStatus::Complete => ready.push(scan.id),
+                        Ok(scan) => errors.push(format!("{}: {}", scan.file_name, scan.error.unwrap_or_default())),
+                        Err(e) => errors.push(e),
+                    }
+                }
+                files.set(Vec::new());
+                store.error.set(None);
+                rejected.set(errors);
+                // A single document goes straight to review; a batch waits in the queue
+                if let [id] = ready.as_slice() {
+                    navigate.call(Route::OcrReview(id.clone()));
+                }
+            });
+        }
+    });
+
+    let on_row_click = {
+        let navigate = navigate.clone();
+        Callback::new(move |id: String| navigate.call(Route::OcrReview(id)))
+    };
+
+    let scans = store.scans.get();
+    let awaiting = store.awaiting_review();
+    let saved = scans.iter().filter(|s| s.status == ScanStatus::Saved).count();
+    let read: Vec<&OcrScan> = scans.iter().filter(|s| s.status != ScanStatus::Failed).collect();
+    let average_confidence = if read.is_empty() {
+        0.0
+    } else {
+        read.iter().map(|s| s.overall_confidence).sum::<f64>() / read.len() as f64
+    };
+
+    let engine_ready = engine::is_configured();
+    let mut type_options = vec![SelectOption { value: String::new(), label: t("Detect automatically").to_string() }];
+    type_options.extend(OcrDocumentType::ALL.iter().map(|d| SelectOption {
+        value: d.code().to_string(),
+        label: d.label().to_string(),
+    }));
+
+    let columns = vec![
+        DataTableColumn { key: "file".to_string(), label: t("Document").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "type".to_string(), label: t("Type").to_string(), width: Some("150px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "confidence".to_string(), label: t("Confidence").to_string(), width: Some("110px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "processed".to_string(), label: t("Processed").to_string(), width: Some("170px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "record".to_string(), label: t("Record").to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
+    ];
+
+    let rows: Vec<DataTableRow> = scans.iter().map(|scan| {
+        DataTableRow {
+            id: scan.id.clone(),
+            cells: vec![
+                view! {
+                    <span class="ocr-file">
+                        <span>{scan.file_name.clone()}</span>
+                        <span class="ocr-muted">{format!("{} · {} {}", format_file_size(scan.file_size), scan.page_count, t("page(s)"))}</span>
+                    </span>
+                },
+                view! { <span>{scan.document_type.map(|d| d.label()).unwrap_or(t("Unknown"))}</span> },
+                if scan.status == ScanStatus::Failed {
+                    view! { <span class="ocr-muted">{"-"}</span> }
+                } else {
+                    view! {
+                        <span class={format!("ocr-confidence {}", scan.level().class())}>
+                            {format!("{:.0}%", scan.overall_confidence)}
+                        </span>
+                    }
+                },
+                scan_status(scan.status),
+                view! { <span>{format_datetime(&scan.processed_at)}</span> },
+                match (scan.document_type, &scan.saved_record) {
+                    (Some(document_type), Some(record)) => view! {
+                        <a href={record_route(document_type, record).to_path()}>{record.clone()}</a>
+                    },
+                    _ => view! { <span class="ocr-muted">{"-"}</span> },
+                },
+            ],
+        }
+    }).collect();
+
+    let icon_scan = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M3 7V5a2 2 0 0 1 2-2h2"/><path d="M17 3h2a2 2 0 0 1 2 2v2"/><path d="M21 17v2a2 2 0 0 1-2 2h-2"/><path d="M7 21H5a2 2 0 0 1-2-2v-2"/><line x1="7" y1="12" x2="17" y2="12"/></svg>"#;
+    let icon_check = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><polyline points="20 6 9 17 4 12"/></svg>"#;
+    let icon_gauge = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><circle cx="12" cy="12" r="10"/><line x1="12" y1="12" x2="16" y2="8"/></svg>"#;
+
+    view! {
+        style {
+            r#"
+            .ocr-intake { display: flex; flex-direction: column; gap: var(--space-6); }
+            .ocr-upload { display: grid; grid-template-columns: 2fr 1fr; gap: 24px; align-items: start; }
+            .ocr-upload-options { display: flex; flex-direction: column; gap: 16px; }
+            .ocr-file { display: flex; flex-direction: column; }
+            .ocr-muted { font-size: 12px; color: var(--text-muted); }
+            .ocr-confidence { font-weight: 600; }
+            .ocr-confidence.high { color: var(--green); }
+            .ocr-confidence.medium { color: var(--orange); }
+            .ocr-confidence.low { color: var(--red); }
+            .ocr-queue { display: flex; flex-direction: column; }
+            .ocr-queue-item {
+                display: flex;
+                justify-content: space-between;
+                align-items: center;
+                padding: 10px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .ocr-queue-item:last-child { border-bottom: none; }
+            @media (max-width: 1024px) {
+                .ocr-upload { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="ocr-intake" data-testid="ocr-intake">
+            {page_header(
+                t("Document Intake").to_string(),
+                Some(t("Scan invoices, delivery notes and B-BBEE certificates into draft records").to_string()),
+                vec![]
+            )}
+
+            if let Some(err) = store.error.get() {
+                {notice_bar(err, NoticeType::Error, None)}
+            }
+            for message in rejected.get() {
+                {notice_bar(message, NoticeType::Warning, None)}
+            }
+
+            <div class="kpi-grid">
+                {kpi_card(
+                    t("Awaiting Review").to_string(),
+                    awaiting.len().to_string(),
+                    if awaiting.is_empty() { KpiColor::Green } else { KpiColor::Orange },
+                    icon_scan.to_string(),
+                    None,
+                    None
+                )}
+                {kpi_card(
+                    t("Saved as Records").to_string(),
+                    saved.to_string(),
+                    KpiColor::Blue,
+                    icon_check.to_string(),
+                    None,
+                    None
+                )}
+                {kpi_card(
+                    t("Average Confidence").to_string(),
+                    format!("{:.0}%", average_confidence),
+                    KpiColor::Purple,
+                    icon_gauge.to_string(),
+                    None,
+                    None
+                )}
+            </div>
+
+            {panel(
+                t("Upload Documents").to_string(),
+                vec![],
+                vec![
+                    if !engine_ready {
+                        empty_state(
+                            t("No extraction engine configured").to_string(),
+                            Some(t("Scanned documents cannot be read until an OCR service is connected. Capture them by hand for now.").to_string()),
+                            None,
+                            None,
+                        )
+                    } else {
+                        view! {
+                            <div class="ocr-upload" data-testid="ocr-upload">
+                                {file_upload(
+                                    t("Documents").to_string(),
+                                    files.clone(),
+                                    Some(ACCEPTED_FORMATS.to_string()),
+                                    true,
+                                    false,
+                                    Some(format!(
+                                        "{} {} {}, {} {}",
+                                        t("PDF, JPEG, PNG, TIFF or WebP, up to"),
+                                        format_file_size(MAX_FILE_SIZE),
+                                        t("each"),
+                                        MAX_FILES,
+                                        t("at a time")
+                                    )),
+                                    handle_remove
+                                )}
+                                <div class="ocr-upload-options">
+                                    {select_with_testid(
+                                        t("Document Type").to_string(),
+                                        document_type.clone(),
+                                        type_options,
+                                        None,
+                                        false,
+                                        false,
+                                        None,
+                                        Some("ocr-document-type".to_string()),
+                                    )}
+                                    <button
+                                        class="btn btn-primary"
+                                        disabled={store.processing.get() || files.get().is_empty()}
+                                        data-testid="ocr-process"
+                                        on:click={handle_process}
+                                    >
+                                        {if store.processing.get() { t("Reading...") } else { t("Read Documents") }}
+                                    </button>
+                                </div>
+                            </div>
+                        }
+                    },
+                ]
+            )}
+
+            if !awaiting.is_empty() {
+                {panel(
+                    t("Awaiting Review").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div class="ocr-queue" data-testid="ocr-queue">
+                            for scan in awaiting.clone() {
+                                <div class="ocr-queue-item">
+                                    <span class="ocr-file">
+                                        <span>{scan.file_name.clone()}</span>
+                                        <span class="ocr-muted">
+                                            {format!(
+                                                "{} · {} {}",
+                                                scan.document_type.map(|d| d.label()).unwrap_or(t("Unknown")),
+                                                scan.fields_to_review(),
+                                                t("field(s) to check")
+                                            )}
+                                        </span>
+                                    </span>
+                                    <a href={Route::OcrReview(scan.id.clone()).to_path()} class="btn btn-secondary btn-sm">{t("Review")}</a>
+                                </div>
+                            }
+                        </div>
+                    }]
+                )}
+            }
+
+            {panel(
+                t("Processed Documents").to_string(),
+                vec![],
+                vec![
+                    if rows.is_empty() {
+                        empty_state(
+                            t("No documents processed").to_string(),
+                            Some(t("Upload a scanned document to get started").to_string()),
+                            None,
+                            None,
+                        )
+                    } else {
+                        data_table_with_testid(
+                            columns,
+                            rows,
+                            Some(on_row_click),
+                            Some("ocr-history".to_string()),
+                            Some("ocr-row".to_string()),
+                        )
+                    },
+                ]
+            )}
+        </div>
+    }
+}
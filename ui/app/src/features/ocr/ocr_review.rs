@@ -0,0 +1,324 @@
+//! Scan review
+//!
+//! Side-by-side correction view: the uploaded document on one side and the
+//! extracted fields, with their confidence and the business-rule checks, on
+//! the other. Saving creates the draft record the document describes.
+
+use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
+use crate::shared::layout::page_header;
+use crate::shared::components::{panel, notice_bar, NoticeType, empty_state};
+use crate::shared::forms::text_input;
+use crate::util::format::{format_currency, format_file_size};
+use crate::util::i18n::t;
+use super::ocr_intake::{intake_stores, record_route, scan_status};
+use super::service::{self, IntakeStores};
+use super::store::OcrStore;
+use super::types::{ExtractedField, FieldKind, OcrScan, ScanStatus, ValidationStatus};
+
+/// Scan review page
+#[component]
+pub fn ocr_review(scan_id: String) -> View {
+    let store = use_context::<OcrStore>();
+    let loaded = signal(false);
+
+    effect({
+        let store = store.clone();
+        let scan_id = scan_id.clone();
+        let loaded = loaded.clone();
+        move || {
+            let store = store.clone();
+            let scan_id = scan_id.clone();
+            let loaded = loaded.clone();
+            spawn(async move {
+                service::load_scans(&store).await;
+                service::open_scan(&store, &scan_id);
+                loaded.set(true);
+            });
+        }
+    });
+
+    let scan = store.current.get().filter(|s| s.id == scan_id);
+
+    view! {
+        style {
+            r#"
+            .ocr-review { display: flex; flex-direction: column; gap: var(--space-6); }
+            .ocr-review-grid {
+                display: grid;
+                grid-template-columns: 1fr 1fr;
+                gap: 24px;
+                align-items: start;
+            }
+            .ocr-preview { width: 100%; height: 640px; border: 1px solid var(--border); border-radius: var(--radius-md); }
+            img.ocr-preview { height: auto; max-height: 640px; object-fit: contain; }
+            .ocr-summary { display: flex; flex-wrap: wrap; gap: 16px; font-size: 13px; color: var(--text-muted); }
+            .ocr-summary strong { color: var(--text); }
+            .ocr-field { display: grid; grid-template-columns: 1fr 110px; gap: 12px; align-items: start; }
+            .ocr-badge {
+                margin-top: 26px;
+                padding: 4px 8px;
+                border-radius: var(--radius-sm);
+                font-size: 12px;
+                font-weight: 600;
+                text-align: center;
+            }
+            .ocr-badge.high { background: var(--green-light); color: var(--green); }
+            .ocr-badge.medium { background: var(--orange-light); color: var(--orange); }
+            .ocr-badge.low { background: var(--red-light); color: var(--red); }
+            .ocr-fields { display: flex; flex-direction: column; gap: 8px; }
+            .ocr-checks { list-style: none; margin: 0; padding: 0; font-size: 13px; }
+            .ocr-checks li { display: flex; gap: 8px; padding: 6px 0; border-bottom: 1px solid var(--border); }
+            .ocr-checks li:last-child { border-bottom: none; }
+            .ocr-checks .rule { font-weight: 500; min-width: 160px; }
+            .ocr-checks .pass .rule { color: var(--green); }
+            .ocr-checks .warning .rule { color: var(--orange); }
+            .ocr-checks .fail .rule { color: var(--red); }
+            .ocr-lines { width: 100%; border-collapse: collapse; font-size: 13px; }
+            .ocr-lines th, .ocr-lines td { padding: 6px 8px; border-bottom: 1px solid var(--border); text-align: left; }
+            .ocr-lines .num { text-align: right; }
+            .ocr-actions { display: flex; gap: 8px; justify-content: flex-end; }
+            @media (max-width: 1024px) {
+                .ocr-review-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="ocr-review" data-testid="ocr-review">
+            if let Some(scan) = scan {
+                {render_review(store.clone(), intake_stores(), scan)}
+            } else if !loaded.get() || store.processing.get() {
+                <div class="loading-state">{t("Loading document...")}</div>
+            } else {
+                {entity_not_found(t("Scanned document"), &scan_id, store.error.get(), Route::OcrIntake, t("Back to Document Intake"))}
+            }
+        </div>
+    }
+}
+
+fn confidence_badge(field: &ExtractedField) -> View {
+    let level = field.level();
+    view! {
+        <span class={format!("ocr-badge {}", level.class())}>
+            {if field.is_corrected() {
+                t("Corrected").to_string()
+            } else {
+                format!("{} {:.0}%", level.label(), field.confidence)
+            }}
+        </span>
+    }
+}
+
+fn render_preview(scan: &OcrScan) -> View {
+    match &scan.preview_url {
+        Some(url) if scan.mime_type == "application/pdf" => view! {
+            <iframe class="ocr-preview" src={url.clone()} title={scan.file_name.clone()}></iframe>
+        },
+        Some(url) => view! {
+            <img class="ocr-preview" src={url.clone()} alt={scan.file_name.clone()} />
+        },
+        None => empty_state(
+            t("Preview not available").to_string(),
+            Some(t("The original is only shown in the session it was uploaded in").to_string()),
+            None,
+            None,
+        ),
+    }
+}
+
+fn render_review(store: OcrStore, stores: IntakeStores, scan: OcrScan) -> View {
+    let editable = scan.status == ScanStatus::Complete;
+    let inputs: Vec<(ExtractedField, Signal<String>)> = scan
+        .fields
+        .iter()
+        .map(|f| (f.clone(), signal(f.value.clone())))
+        .collect();
+
+    let corrections = {
+        let inputs = inputs.clone();
+        move || inputs.iter().map(|(f, value)| (f.key.clone(), value.get())).collect::<Vec<_>>()
+    };
+
+    let handle_check = Callback::<()>::new({
+        let store = store.clone();
+        let stores = stores.clone();
+        let corrections = corrections.clone();
+        move |_| {
+            let store = store.clone();
+            let stores = stores.clone();
+            let corrections = corrections();
+            store.error.set(None);
+            spawn(async move {
+                if let Err(e) = service::apply_corrections(&store, &stores, corrections).await {
+                    store.error.set(Some(e));
+                }
+            });
+        }
+    });
+
+    let handle_save = Callback::<()>::new({
+        let store = store.clone();
+        let stores = stores.clone();
+        move |_| {
+            let store = store.clone();
+            let stores = stores.clone();
+            let corrections = corrections();
+            spawn(async move {
+                let _ = service::save_scan(&store, &stores, corrections).await;
+            });
+        }
+    });
+
+    let document_type = scan.document_type;
+    let field_error = |key: &str| {
+        scan.validation
+            .iter()
+            .find(|v| v.status == ValidationStatus::Fail && v.field.as_deref() == Some(key))
+            .map(|v| v.message.clone())
+    };
+
+    view! {
+        {page_header(
+            scan.file_name.clone(),
+            Some(format!(
+                "{} · {}",
+                document_type.map(|d| d.label()).unwrap_or(t("Unknown document")),
+                if scan.auto_detected {
+                    format!("{} ({:.0}%)", t("detected automatically"), scan.classification_confidence)
+                } else {
+                    t("type chosen on upload").to_string()
+                }
+            )),
+            vec![
+                view! { <a href={Route::OcrIntake.to_path()} class="btn btn-secondary">{t("Back to Document Intake")}</a> },
+            ]
+        )}
+
+        if let Some(err) = store.error.get() {
+            {notice_bar(err, NoticeType::Error, None)}
+        }
+        if let (Some(document_type), Some(record)) = (document_type, scan.saved_record.clone()) {
+            <div data-testid="ocr-saved">
+                {notice_bar(
+                    format!("{} {} {}", t("Saved as"), document_type.target(), record),
+                    NoticeType::Success,
+                    None
+                )}
+                <a href={record_route(document_type, &record).to_path()}>{t("Open record")}</a>
+            </div>
+        }
+        if let Some(err) = scan.error.clone() {
+            {notice_bar(err, NoticeType::Error, None)}
+        }
+
+        <div class="ocr-summary" data-testid="ocr-summary">
+            {scan_status(scan.status)}
+            <span>{format!("{} ", t("Overall confidence"))}<strong>{format!("{:.0}%", scan.overall_confidence)}</strong></span>
+            <span>{format!("{} ", t("Fields to check"))}<strong>{scan.fields_to_review().to_string()}</strong></span>
+            <span>{format!("{} ", t("Corrections"))}<strong>{scan.corrections().to_string()}</strong></span>
+            <span>{format!("{} · {} {} · {:.1} s", format_file_size(scan.file_size), scan.page_count, t("page(s)"), scan.processing_time_ms as f64 / 1000.0)}</span>
+            <span>{format!("{} {}", t("Read by"), scan.engine)}</span>
+        </div>
+
+        <div class="ocr-review-grid">
+            <div>
+                {panel(
+                    t("Document").to_string(),
+                    vec![],
+                    vec![render_preview(&scan)]
+                )}
+            </div>
+            <div class="ocr-fields">
+                {panel(
+                    t("Extracted Fields").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div class="ocr-fields" data-testid="ocr-fields">
+                            for (field, value) in inputs.clone() {
+                                <div class="ocr-field">
+                                    {text_input(
+                                        field.label.clone(),
+                                        value.clone(),
+                                        None,
+                                        field.required,
+                                        !editable,
+                                        field_error(&field.key),
+                                        field.original_value.as_ref().map(|o| format!("{} {}", t("Read as"), o)),
+                                        Some(if field.kind == FieldKind::Date { "date" } else { "text" }.to_string()),
+                                    )}
+                                    {confidence_badge(&field)}
+                                </div>
+                            }
+                        </div>
+                    }]
+                )}
+
+                if !scan.lines.is_empty() {
+                    {panel(
+                        t("Line Items").to_string(),
+                        vec![],
+                        vec![view! {
+                            <table class="ocr-lines">
+                                <thead>
+                                    <tr>
+                                        <th>{t("Item")}</th>
+                                        <th>{t("Description")}</th>
+                                        <th class="num">{t("Qty")}</th>
+                                        <th class="num">{t("Unit Price")}</th>
+                                        <th class="num">{t("Confidence")}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    for line in scan.lines.clone() {
+                                        <tr>
+                                            <td>{line.item_code.clone()}</td>
+                                            <td>{line.description.clone()}</td>
+                                            <td class="num">{format!("{} {}", line.quantity, line.unit)}</td>
+                                            <td class="num">{format_currency(line.unit_price)}</td>
+                                            <td class="num">{format!("{:.0}%", line.confidence)}</td>
+                                        </tr>
+                                    }
+                                </tbody>
+                            </table>
+                        }]
+                    )}
+                }
+
+                {panel(
+                    t("Checks").to_string(),
+                    vec![],
+                    vec![view! {
+                        <ul class="ocr-checks" data-testid="ocr-checks">
+                            for check in scan.validation.clone() {
+                                <li class={check.status.class().to_string()}>
+                                    <span class="rule">{check.rule.clone()}</span>
+                                    <span>{check.message.clone()}</span>
+                                </li>
+                            }
+                        </ul>
+                    }]
+                )}
+
+                if editable {
+                    <div class="ocr-actions">
+                        <button class="btn btn-secondary" data-testid="ocr-check" on:click={handle_check}>
+                            {t("Apply Corrections")}
+                        </button>
+                        <button
+                            class="btn btn-primary"
+                            disabled={store.saving.get()}
+                            data-testid="ocr-save"
+                            on:click={handle_save}
+                        >
+                            {match document_type {
+                                Some(d) => format!("{} {}", t("Save as"), d.target()),
+                                None => t("Save").to_string(),
+                            }}
+                        </button>
+                    </div>
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,390 @@
+//! OCR intake service
+//!
+//! Uploaded documents are read by the installed extraction engine, checked
+//! against the business rules and opened for review. Saving a reviewed scan
+//! creates the record it describes: a draft invoice, a draft goods receipt or
+//! a supplier certificate awaiting verification. Nothing is created from a
+//! scan that still fails a check.
+
+use crate::features::audit::{self, AuditActionType, AuditEntityType, AuditEntry, FieldChange};
+use crate::features::goods_receipt::service as goods_receipt;
+use crate::features::goods_receipt::store::GoodsReceiptStore;
+use crate::features::goods_receipt::types::{
+    GoodsReceipt, GoodsReceiptStatus, GoodsReceiptSupplier, POReference, ReceivedItem,
+};
+use crate::features::invoices::service as invoices;
+use crate::features::invoices::store::InvoicesStore;
+use crate::features::invoices::types::{Invoice, InvoiceLine};
+use crate::features::purchase_orders::service as purchase_orders;
+use crate::features::purchase_orders::types::PurchaseOrder;
+use crate::features::suppliers::service as suppliers;
+use crate::features::suppliers::store::SuppliersStore;
+use crate::features::suppliers::types::{BbbeeLevel, SupplierCertificate};
+use crate::shared::forms::UploadedFile;
+use crate::util::api::HttpMethod;
+use crate::util::auth;
+use crate::util::backend;
+use crate::util::datetime::{now_iso, today};
+use crate::util::format::format_file_size;
+use crate::util::money::{Money, VAT_RATE};
+use crate::util::permissions::{self, Action};
+use crate::util::reference::unique_id;
+use super::engine;
+use super::store::{OcrStore, load_mock_scans};
+use super::types::{
+    OcrDocumentType, OcrScan, ScanStatus, ValidationResult, ACCEPTED_FORMATS, MAX_FILE_SIZE,
+};
+use super::validation::{validate, ValidationContext};
+
+/// Stores of the records a scan can be saved as
+#[derive(Clone)]
+pub struct IntakeStores {
+    pub invoices: InvoicesStore,
+    pub receipts: GoodsReceiptStore,
+    pub suppliers: SuppliersStore,
+}
+
+/// Load the scan history
+pub async fn load_scans(store: &OcrStore) {
+    store.error.set(None);
+
+    if backend::is_mock() {
+        if store.scans.get().is_empty() {
+            load_mock_scans(store);
+        }
+    } else if let Err(e) = backend::fetch_with_snapshot("/ocr/scans", &store.scans).await {
+        store.error.set(Some(e.to_string()));
+    }
+}
+
+/// Refuse files the engine cannot take (SRS BR-01)
+pub fn check_file(file: &UploadedFile) -> Result<(), String> {
+    let extension = file.extension();
+    if !ACCEPTED_FORMATS.split(',').any(|f| f.trim_start_matches('.') == extension) {
+        return Err(format!("{}: only PDF, JPEG, PNG, TIFF and WebP files can be scanned", file.name));
+    }
+    if file.size > MAX_FILE_SIZE {
+        return Err(format!(
+            "{} is {}; files must be {} or smaller",
+            file.name,
+            format_file_size(file.size),
+            format_file_size(MAX_FILE_SIZE)
+        ));
+    }
+    Ok(())
+}
+
+/// Run the business rules against the registers the scan refers to
+async fn validation_for(stores: &IntakeStores, scan: &OcrScan) -> Vec<ValidationResult> {
+    if stores.suppliers.suppliers.get().is_empty() {
+        suppliers::load_suppliers(&stores.suppliers).await;
+    }
+    if stores.invoices.invoices.get().is_empty() {
+        invoices::load_invoices(&stores.invoices).await;
+    }
+    let po_number = scan.value("po_number");
+    let purchase_order = if po_number.is_empty() {
+        None
+    } else {
+        purchase_orders::find_purchase_order(&po_number).await.ok()
+    };
+    validate(
+        scan,
+        &ValidationContext {
+            suppliers: &stores.suppliers.suppliers.get(),
+            purchase_order: purchase_order.as_ref(),
+            invoices: &stores.invoices.invoices.get(),
+            today: today(),
+        },
+    )
+}
+
+/// Read an uploaded document and open it for review. A document the engine
+/// cannot read is kept in the history as a failed scan.
+pub async fn process_document(
+    store: &OcrStore,
+    stores: &IntakeStores,
+    file: UploadedFile,
+    document_type: Option<OcrDocumentType>,
+) -> Result<OcrScan, String> {
+    store.processing.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::ProcessDocuments)?;
+        check_file(&file)?;
+
+        let engine = engine::engine().ok_or("No extraction engine is configured")?;
+        let started = js_sys::Date::now();
+        let mut scan = OcrScan {
+            id: unique_id("OCR"),
+            file_name: file.name.clone(),
+            mime_type: file.mime_type.clone(),
+            file_size: file.size,
+            preview_url: file.file.as_ref().and_then(|f| web_sys::Url::create_object_url_with_blob(f).ok()),
+            document_type,
+            engine: engine.name().to_string(),
+            processed_by: auth::current().user.map(|u| u.name).unwrap_or_default(),
+            processed_at: now_iso(),
+            ..Default::default()
+        };
+        match engine.extract(file, document_type).await {
+            Ok(extraction) => {
+                scan.auto_detected = document_type.is_none();
+                scan.document_type = extraction.document_type;
+                scan.classification_confidence = extraction.classification_confidence;
+                scan.page_count = extraction.page_count;
+                scan.fields = extraction.fields;
+                scan.lines = extraction.lines;
+                scan.overall_confidence = scan.calculate_confidence();
+                scan.validation = validation_for(stores, &scan).await;
+                scan.status = ScanStatus::Complete;
+            }
+            Err(e) => {
+                scan.status = ScanStatus::Failed;
+                scan.error = Some(e);
+            }
+        }
+        scan.processing_time_ms = (js_sys::Date::now() - started) as u32;
+
+        let mut saved = backend::save(HttpMethod::Post, "/ocr/scans", &scan)
+            .await
+            .map_err(|e| e.to_string())?;
+        saved.preview_url = scan.preview_url;
+        Ok(saved)
+    }
+    .await;
+
+    match &result {
+        Ok(scan) => {
+            store.put(scan);
+            if scan.status == ScanStatus::Complete {
+                store.current.set(Some(scan.clone()));
+            }
+        }
+        Err(e) => store.error.set(Some(e.clone())),
+    }
+    store.processing.set(false);
+    result
+}
+
+/// Open a scan from the history in the correction view
+pub fn open_scan(store: &OcrStore, id: &str) {
+    store.current.set(store.scans.get().into_iter().find(|s| s.id == id));
+}
+
+/// Apply the reviewer's corrections to the open scan and check it again
+pub async fn apply_corrections(
+    store: &OcrStore,
+    stores: &IntakeStores,
+    corrections: Vec<(String, String)>,
+) -> Result<OcrScan, String> {
+    let mut scan = store.current.get().ok_or("No document is open for review")?;
+    match scan.status {
+        ScanStatus::Complete => {}
+        ScanStatus::Saved => return Err(format!("{} has already been saved", scan.file_name)),
+        _ => return Err(format!("{} could not be read; upload it again", scan.file_name)),
+    }
+    for (key, value) in &corrections {
+        scan.correct(key, value);
+    }
+    scan.validation = validation_for(stores, &scan).await;
+    store.put(&scan);
+    Ok(scan)
+}
+
+/// Invoice lines as read, or a single line for the subtotal when the
+/// engine found no line items
+fn invoice_lines(scan: &OcrScan, po: &PurchaseOrder) -> Vec<InvoiceLine> {
+    if scan.lines.is_empty() {
+        return vec![InvoiceLine {
+            description: "As per supplier invoice".to_string(),
+            quantity: 1,
+            unit_price: scan.value("subtotal").parse().unwrap_or(Money::ZERO),
+            ..Default::default()
+        }];
+    }
+    scan.lines
+        .iter()
+        .map(|line| {
+            let po_line = po.line_items.iter().find(|i| i.item_code == line.item_code);
+            InvoiceLine {
+                po_line_id: po_line.map(|i| i.id.clone()),
+                item_code: line.item_code.clone(),
+                description: line.description.clone(),
+                quantity: line.quantity,
+                unit_price: line.unit_price,
+                tax_rate: po_line.map_or(VAT_RATE, |i| i.tax_rate),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+async fn save_invoice(stores: &IntakeStores, scan: &OcrScan) -> Result<String, String> {
+    let po = purchase_orders::find_purchase_order(&scan.value("po_number")).await?;
+    let invoice = Invoice {
+        invoice_number: scan.value("invoice_number"),
+        po_number: po.po_number.clone(),
+        invoice_date: scan.value("invoice_date"),
+        received_date: today().to_iso(),
+        due_date: scan.value("due_date"),
+        description: Some(format!("Captured from {}", scan.file_name)),
+        lines: invoice_lines(scan, &po),
+        ..Default::default()
+    };
+    invoices::register_draft_invoice(&stores.invoices, invoice).await.map(|i| i.id)
+}
+
+async fn save_receipt(stores: &IntakeStores, scan: &OcrScan) -> Result<String, String> {
+    let po = purchase_orders::find_purchase_order(&scan.value("po_number")).await?;
+    let signed_for = scan.value("received_by");
+    let receipt = GoodsReceipt {
+        po_reference: POReference {
+            po_number: po.po_number.clone(),
+            po_date: po.order_date.clone(),
+            contract_id: po.contract_ref.clone(),
+            delivery_date: po.expected_delivery_date.clone(),
+            total_value: po.total_amount,
+        },
+        supplier: GoodsReceiptSupplier {
+            id: po.supplier.id.clone(),
+            name: po.supplier.name.clone(),
+            contact_person: Some(po.supplier.contact_person.clone()),
+            contact_phone: Some(po.supplier.contact_phone.clone()),
+            bbbee_level: po.supplier.bbbee_level,
+        },
+        items_received: scan
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(n, line)| {
+                let ordered = po.line_items.iter().find(|i| i.item_code == line.item_code);
+                ReceivedItem {
+                    id: ordered.map(|i| i.id.clone()).unwrap_or_else(|| format!("RI-{:03}", n + 1)),
+                    po_line_id: ordered.map(|i| i.id.clone()),
+                    item_code: line.item_code.clone(),
+                    description: line.description.clone(),
+                    ordered_quantity: ordered.map_or(0, |i| i.quantity),
+                    received_quantity: line.quantity,
+                    unit: line.unit.clone(),
+                    unit_price: line.unit_price,
+                    ..Default::default()
+                }
+            })
+            .collect(),
+        received_by: if signed_for.is_empty() {
+            auth::current().user.map(|u| u.name).unwrap_or_default()
+        } else {
+            signed_for
+        },
+        status: GoodsReceiptStatus::Draft,
+        receipt_date: scan.value("delivery_date"),
+        delivery_note_number: Some(scan.value("delivery_note_number")),
+        warehouse_location: po.delivery_address.address_line1.clone(),
+        notes: Some(format!("Captured from {}", scan.file_name)),
+        documents: vec![scan.file_name.clone()],
+        created_at: now_iso(),
+        ..Default::default()
+    };
+    goods_receipt::create_receipt(&stores.receipts, receipt).await
+}
+
+async fn save_certificate(stores: &IntakeStores, scan: &OcrScan) -> Result<String, String> {
+    let registration = scan.value("registration_number");
+    let supplier = stores
+        .suppliers
+        .suppliers
+        .get()
+        .into_iter()
+        .find(|s| s.registration_number == registration)
+        .ok_or_else(|| format!("No supplier is registered under {}", registration))?;
+    let certificate = SupplierCertificate {
+        supplier_id: supplier.id.clone(),
+        supplier_name: supplier.name.clone(),
+        certificate_number: scan.value("certificate_number"),
+        bbbee_level: BbbeeLevel::parse(scan.value("bbbee_level").trim_start_matches("Level ")).unwrap_or_default(),
+        issue_date: scan.value("issue_date"),
+        expiry_date: scan.value("expiry_date"),
+        verification_agency: scan.value("verification_agency"),
+        source_document: Some(scan.id.clone()),
+        submitted_by: auth::current().user.map(|u| u.name).unwrap_or_default(),
+        ..Default::default()
+    };
+    suppliers::submit_certificate(&stores.suppliers, certificate).await.map(|c| c.supplier_id)
+}
+
+/// Save the open scan, with the reviewer's last corrections, as the record
+/// it describes. Returns the id of the invoice, goods receipt or supplier.
+pub async fn save_scan(
+    store: &OcrStore,
+    stores: &IntakeStores,
+    corrections: Vec<(String, String)>,
+) -> Result<String, String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::ProcessDocuments)?;
+        let mut scan = apply_corrections(store, stores, corrections).await?;
+        let document_type = scan.document_type.ok_or("Choose the document type before saving")?;
+        if scan.has_failures() {
+            return Err("Resolve the failed checks before saving".to_string());
+        }
+
+        let record = match document_type {
+            OcrDocumentType::Invoice => save_invoice(stores, &scan).await?,
+            OcrDocumentType::DeliveryNote => save_receipt(stores, &scan).await?,
+            OcrDocumentType::BbbeeCertificate => save_certificate(stores, &scan).await?,
+        };
+        scan.status = ScanStatus::Saved;
+        scan.saved_record = Some(record.clone());
+        let mut saved = backend::save(HttpMethod::Put, &format!("/ocr/scans/{}", scan.id), &scan)
+            .await
+            .map_err(|e| e.to_string())?;
+        saved.preview_url = scan.preview_url.clone();
+
+        audit::service::record(AuditEntry {
+            action: AuditActionType::Import,
+            entity_type: match document_type {
+                OcrDocumentType::Invoice => AuditEntityType::Invoice,
+                OcrDocumentType::DeliveryNote => AuditEntityType::GoodsReceipt,
+                OcrDocumentType::BbbeeCertificate => AuditEntityType::Supplier,
+            },
+            entity_id: record,
+            entity_name: Some(saved.file_name.clone()),
+            changes: saved
+                .fields
+                .iter()
+                .filter_map(|f| {
+                    f.original_value
+                        .as_deref()
+                        .map(|original| FieldChange::new(&f.label, Some(original), Some(&f.value)))
+                })
+                .collect(),
+            description: Some(format!(
+                "Captured {} from {} at {:.0}% confidence with {} correction(s)",
+                document_type.target(),
+                saved.file_name,
+                saved.overall_confidence,
+                saved.corrections()
+            )),
+            ..Default::default()
+        });
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    let result = match result {
+        Ok(saved) => {
+            store.put(&saved);
+            Ok(saved.saved_record.unwrap_or_default())
+        }
+        Err(e) => {
+            store.error.set(Some(e.clone()));
+            Err(e)
+        }
+    };
+    store.saving.set(false);
+    result
+}
@@ -0,0 +1,162 @@
+//! OCR intake store and mock scan history
+
+use components::prelude::*;
+use super::types::{
+    ExtractedField, OcrDocumentType, OcrScan, ScanStatus, ValidationResult, ValidationStatus,
+};
+
+/// OCR intake state store
+#[derive(Clone)]
+pub struct OcrStore {
+    pub scans: Signal<Vec<OcrScan>>,
+    /// Scan open in the correction view
+    pub current: Signal<Option<OcrScan>>,
+    pub processing: Signal<bool>,
+    pub saving: Signal<bool>,
+    pub error: Signal<Option<String>>,
+}
+
+impl OcrStore {
+    pub fn new() -> Self {
+        Self {
+            scans: signal(Vec::new()),
+            current: signal(None),
+            processing: signal(false),
+            saving: signal(false),
+            error: signal(None),
+        }
+    }
+
+    /// Scans read but not yet saved or discarded
+    pub fn awaiting_review(&self) -> Vec<OcrScan> {
+        self.scans.get().into_iter().filter(|s| s.status == ScanStatus::Complete).collect()
+    }
+
+    /// Replace a scan in the history and the correction view
+    pub fn put(&self, scan: &OcrScan) {
+        let mut scans = self.scans.get();
+        match scans.iter_mut().find(|s| s.id == scan.id) {
+            Some(existing) => *existing = scan.clone(),
+            None => scans.insert(0, scan.clone()),
+        }
+        self.scans.set(scans);
+        if self.current.get().is_some_and(|c| c.id == scan.id) {
+            self.current.set(Some(scan.clone()));
+        }
+    }
+}
+
+fn fields(document_type: OcrDocumentType, values: &[(&str, &str, f64)]) -> Vec<ExtractedField> {
+    document_type
+        .fields()
+        .iter()
+        .map(|spec| {
+            let (value, confidence) = values
+                .iter()
+                .find(|(key, _, _)| *key == spec.key)
+                .map(|(_, value, confidence)| (*value, *confidence))
+                .unwrap_or(("", 0.0));
+            ExtractedField::from_spec(spec, value, confidence)
+        })
+        .collect()
+}
+
+fn scan(
+    id: &str,
+    file_name: &str,
+    file_size: u64,
+    document_type: OcrDocumentType,
+    fields: Vec<ExtractedField>,
+    status: ScanStatus,
+    processed_at: &str,
+) -> OcrScan {
+    let mut scan = OcrScan {
+        id: id.to_string(),
+        file_name: file_name.to_string(),
+        mime_type: if file_name.ends_with(".pdf") { "application/pdf" } else { "image/jpeg" }.to_string(),
+        file_size,
+        page_count: 1,
+        document_type: Some(document_type),
+        auto_detected: true,
+        classification_confidence: 95.0,
+        engine: "Local stand-in".to_string(),
+        fields,
+        status,
+        processed_by: "Nomvula Khumalo".to_string(),
+        processed_at: processed_at.to_string(),
+        processing_time_ms: 1840,
+        ..Default::default()
+    };
+    scan.overall_confidence = scan.calculate_confidence();
+    scan
+}
+
+/// Load mock scan history for development
+pub fn load_mock_scans(store: &OcrStore) {
+    let mut gourmet = scan(
+        "OCR-M6XK2P01A7QZ",
+        "GCC-2025-0117.pdf",
+        184_320,
+        OcrDocumentType::Invoice,
+        fields(OcrDocumentType::Invoice, &[
+            ("invoice_number", "GCC-2025-0117", 98.0),
+            ("invoice_date", "2025-02-03", 96.0),
+            ("due_date", "2025-03-05", 94.0),
+            ("supplier_name", "Gourmet Corporate Catering", 97.0),
+            ("supplier_vat", "4023456789", 95.0),
+            ("po_number", "PO-2025-0452", 92.0),
+            ("subtotal", "39000.00", 97.0),
+            ("vat_amount", "5850.00", 96.0),
+            ("total_amount", "44850.00", 98.0),
+        ]),
+        ScanStatus::Saved,
+        "2025-02-05T08:42:00Z",
+    );
+    gourmet.saved_record = Some("INV-2025-0113".to_string());
+    gourmet.validation = vec![ValidationResult {
+        rule: "PO exists".to_string(),
+        field: Some("po_number".to_string()),
+        status: ValidationStatus::Pass,
+        message: "PO-2025-0452 issued to Gourmet Corporate Catering".to_string(),
+    }];
+
+    let mut blurred = scan(
+        "OCR-M6ZR8T03C1WD",
+        "IMG_20250224_1532.jpg",
+        2_411_724,
+        OcrDocumentType::DeliveryNote,
+        Vec::new(),
+        ScanStatus::Failed,
+        "2025-02-24T13:35:00Z",
+    );
+    blurred.auto_detected = false;
+    blurred.classification_confidence = 41.0;
+    blurred.document_type = None;
+    blurred.error = Some("The image is too blurred to read; rescan at 300 DPI or higher".to_string());
+
+    let mut certificate = scan(
+        "OCR-M6YQ4R02B9XC",
+        "ubuntu-bbbee-certificate-2025.pdf",
+        356_812,
+        OcrDocumentType::BbbeeCertificate,
+        fields(OcrDocumentType::BbbeeCertificate, &[
+            ("supplier_name", "Ubuntu Construction Group", 94.0),
+            ("registration_number", "2012/789012/07", 93.0),
+            ("certificate_number", "BEE-2025-10377", 88.0),
+            ("bbbee_level", "2", 95.0),
+            ("issue_date", "2025-01-20", 91.0),
+            ("expiry_date", "2026-01-19", 90.0),
+            ("verification_agency", "AQRate", 86.0),
+        ]),
+        ScanStatus::Saved,
+        "2025-02-18T10:05:00Z",
+    );
+    certificate.saved_record = Some("SUP-002".to_string());
+    // The reviewer fixed a misread certificate number before saving
+    if let Some(field) = certificate.fields.iter_mut().find(|f| f.key == "certificate_number") {
+        field.original_value = Some("BEE-2025-1O377".to_string());
+    }
+    certificate.overall_confidence = certificate.calculate_confidence();
+
+    store.scans.set(vec![blurred, certificate, gourmet]);
+}
@@ -0,0 +1,358 @@
+//! OCR document intake types
+
+use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::Money;
+
+/// Largest file accepted for scanning (SRS BR-01)
+pub const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+/// Files that can be queued in one upload (SRS FR-3.1.1)
+pub const MAX_FILES: usize = 10;
+/// `accept` attribute for the upload zone
+pub const ACCEPTED_FORMATS: &str = ".pdf,.jpg,.jpeg,.png,.tif,.tiff,.webp";
+/// Confidence from which a field is accepted without review (SRS BR-02)
+pub const HIGH_CONFIDENCE: f64 = 90.0;
+/// Confidence below which a field must be verified by hand
+pub const MEDIUM_CONFIDENCE: f64 = 70.0;
+
+/// Documents the intake pipeline can read
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OcrDocumentType {
+    Invoice,
+    DeliveryNote,
+    BbbeeCertificate,
+}
+
+impl OcrDocumentType {
+    pub const ALL: [OcrDocumentType; 3] = [
+        OcrDocumentType::Invoice,
+        OcrDocumentType::DeliveryNote,
+        OcrDocumentType::BbbeeCertificate,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            OcrDocumentType::Invoice => "INV",
+            OcrDocumentType::DeliveryNote => "DN",
+            OcrDocumentType::BbbeeCertificate => "BEE",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            OcrDocumentType::Invoice => "Invoice",
+            OcrDocumentType::DeliveryNote => "Delivery Note",
+            OcrDocumentType::BbbeeCertificate => "B-BBEE Certificate",
+        })
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|d| d.code().eq_ignore_ascii_case(code))
+    }
+
+    /// Record created when a scan of this type is saved
+    pub fn target(&self) -> &'static str {
+        t(match self {
+            OcrDocumentType::Invoice => "draft invoice",
+            OcrDocumentType::DeliveryNote => "draft goods receipt",
+            OcrDocumentType::BbbeeCertificate => "supplier certificate",
+        })
+    }
+
+    /// Fields extracted for this document type (SRS FR-3.3.3)
+    pub fn fields(&self) -> &'static [FieldSpec] {
+        match self {
+            OcrDocumentType::Invoice => INVOICE_FIELDS,
+            OcrDocumentType::DeliveryNote => DELIVERY_NOTE_FIELDS,
+            OcrDocumentType::BbbeeCertificate => CERTIFICATE_FIELDS,
+        }
+    }
+
+    /// Whether the document carries line items
+    pub fn has_lines(&self) -> bool {
+        *self != OcrDocumentType::BbbeeCertificate
+    }
+}
+
+/// How an extracted value is entered and checked
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FieldKind {
+    Text,
+    Date,
+    Amount,
+}
+
+/// Field the engine is asked to extract
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FieldSpec {
+    pub key: &'static str,
+    pub label: &'static str,
+    pub kind: FieldKind,
+    pub required: bool,
+    /// Weighted higher in the overall confidence (SRS FR-3.4.2)
+    pub critical: bool,
+}
+
+const fn spec(key: &'static str, label: &'static str, kind: FieldKind, required: bool, critical: bool) -> FieldSpec {
+    FieldSpec { key, label, kind, required, critical }
+}
+
+pub const INVOICE_FIELDS: &[FieldSpec] = &[
+    spec("invoice_number", "Invoice Number", FieldKind::Text, true, true),
+    spec("invoice_date", "Invoice Date", FieldKind::Date, true, false),
+    spec("due_date", "Due Date", FieldKind::Date, false, false),
+    spec("supplier_name", "Supplier Name", FieldKind::Text, true, false),
+    spec("supplier_vat", "Supplier VAT Number", FieldKind::Text, true, false),
+    spec("po_number", "PO Reference", FieldKind::Text, true, true),
+    spec("subtotal", "Subtotal", FieldKind::Amount, true, false),
+    spec("vat_amount", "VAT Amount", FieldKind::Amount, true, false),
+    spec("total_amount", "Total Amount", FieldKind::Amount, true, true),
+];
+
+pub const DELIVERY_NOTE_FIELDS: &[FieldSpec] = &[
+    spec("delivery_note_number", "Delivery Note Number", FieldKind::Text, true, true),
+    spec("po_number", "PO Reference", FieldKind::Text, true, true),
+    spec("supplier_name", "Supplier Name", FieldKind::Text, true, false),
+    spec("delivery_date", "Delivery Date", FieldKind::Date, true, false),
+    spec("received_by", "Signed For By", FieldKind::Text, false, false),
+];
+
+pub const CERTIFICATE_FIELDS: &[FieldSpec] = &[
+    spec("supplier_name", "Entity Name", FieldKind::Text, true, false),
+    spec("registration_number", "Registration Number", FieldKind::Text, true, true),
+    spec("certificate_number", "Certificate Number", FieldKind::Text, true, true),
+    spec("bbbee_level", "B-BBEE Level", FieldKind::Text, true, true),
+    spec("issue_date", "Date of Issue", FieldKind::Date, true, false),
+    spec("expiry_date", "Expiry Date", FieldKind::Date, true, false),
+    spec("verification_agency", "Verification Agency", FieldKind::Text, true, false),
+];
+
+/// Confidence band of an extracted value (SRS FR-3.4.1)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfidenceLevel {
+    High,
+    Medium,
+    Low,
+}
+
+impl ConfidenceLevel {
+    pub fn of(confidence: f64) -> Self {
+        if confidence >= HIGH_CONFIDENCE {
+            ConfidenceLevel::High
+        } else if confidence >= MEDIUM_CONFIDENCE {
+            ConfidenceLevel::Medium
+        } else {
+            ConfidenceLevel::Low
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            ConfidenceLevel::High => "High",
+            ConfidenceLevel::Medium => "Review",
+            ConfidenceLevel::Low => "Verify",
+        })
+    }
+
+    /// CSS modifier class
+    pub fn class(&self) -> &'static str {
+        match self {
+            ConfidenceLevel::High => "high",
+            ConfidenceLevel::Medium => "medium",
+            ConfidenceLevel::Low => "low",
+        }
+    }
+}
+
+/// Value read from the document
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExtractedField {
+    pub key: String,
+    pub label: String,
+    pub kind: FieldKind,
+    pub value: String,
+    pub confidence: f64,
+    pub required: bool,
+    pub critical: bool,
+    /// What the engine read, once the value has been corrected by hand
+    #[serde(default)]
+    pub original_value: Option<String>,
+}
+
+impl ExtractedField {
+    pub fn from_spec(spec: &FieldSpec, value: &str, confidence: f64) -> Self {
+        Self {
+            key: spec.key.to_string(),
+            label: spec.label.to_string(),
+            kind: spec.kind,
+            value: value.to_string(),
+            confidence,
+            required: spec.required,
+            critical: spec.critical,
+            original_value: None,
+        }
+    }
+
+    pub fn is_corrected(&self) -> bool {
+        self.original_value.is_some()
+    }
+
+    /// Confidence after review: a corrected value is taken as certain
+    pub fn effective_confidence(&self) -> f64 {
+        if self.is_corrected() { 100.0 } else { self.confidence }
+    }
+
+    pub fn level(&self) -> ConfidenceLevel {
+        ConfidenceLevel::of(self.effective_confidence())
+    }
+
+    fn weight(&self) -> f64 {
+        if self.critical {
+            3.0
+        } else if self.required {
+            2.0
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Line item read from an invoice or delivery note
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExtractedLine {
+    pub item_code: String,
+    pub description: String,
+    pub quantity: u32,
+    pub unit: String,
+    pub unit_price: Money,
+    pub confidence: f64,
+}
+
+/// Outcome of one business-rule check (SRS FR-3.5.2)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ValidationStatus {
+    Pass,
+    Warning,
+    Fail,
+}
+
+impl ValidationStatus {
+    pub fn class(&self) -> &'static str {
+        match self {
+            ValidationStatus::Pass => "pass",
+            ValidationStatus::Warning => "warning",
+            ValidationStatus::Fail => "fail",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ValidationResult {
+    pub rule: String,
+    /// Field the rule concerns, highlighted in the correction form
+    pub field: Option<String>,
+    pub status: ValidationStatus,
+    pub message: String,
+}
+
+/// Scan lifecycle
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScanStatus {
+    #[default]
+    Processing,
+    /// Extracted and waiting for review
+    Complete,
+    Failed,
+    /// Turned into a procurement record
+    Saved,
+}
+
+impl ScanStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScanStatus::Processing => "Processing",
+            ScanStatus::Complete => "Ready for Review",
+            ScanStatus::Failed => "Failed",
+            ScanStatus::Saved => "Saved",
+        }
+    }
+}
+
+/// One processed document (SRS 6.1.1)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OcrScan {
+    pub id: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub file_size: u64,
+    /// Object URL of the uploaded file, for the preview during this session
+    #[serde(skip)]
+    pub preview_url: Option<String>,
+    pub page_count: u32,
+    pub document_type: Option<OcrDocumentType>,
+    /// Type chosen by the engine rather than the user
+    pub auto_detected: bool,
+    pub classification_confidence: f64,
+    /// Engine that read the document
+    pub engine: String,
+    pub fields: Vec<ExtractedField>,
+    pub lines: Vec<ExtractedLine>,
+    pub overall_confidence: f64,
+    pub validation: Vec<ValidationResult>,
+    pub status: ScanStatus,
+    pub error: Option<String>,
+    pub processed_by: String,
+    pub processed_at: String,
+    pub processing_time_ms: u32,
+    /// Record created from the scan
+    pub saved_record: Option<String>,
+}
+
+impl OcrScan {
+    pub fn field(&self, key: &str) -> Option<&ExtractedField> {
+        self.fields.iter().find(|f| f.key == key)
+    }
+
+    /// Current value of a field, empty when it was not found
+    pub fn value(&self, key: &str) -> String {
+        self.field(key).map(|f| f.value.trim().to_string()).unwrap_or_default()
+    }
+
+    /// Correct a field by hand, keeping what the engine read for the audit trail
+    pub fn correct(&mut self, key: &str, value: &str) {
+        let Some(field) = self.fields.iter_mut().find(|f| f.key == key) else { return };
+        if field.value == value {
+            return;
+        }
+        let original = field.original_value.clone().unwrap_or_else(|| field.value.clone());
+        field.original_value = (original != value).then_some(original);
+        field.value = value.to_string();
+        self.overall_confidence = self.calculate_confidence();
+    }
+
+    /// Weighted average of the field confidences (SRS FR-3.4.2)
+    pub fn calculate_confidence(&self) -> f64 {
+        let total_weight: f64 = self.fields.iter().map(|f| f.weight()).sum();
+        if total_weight == 0.0 {
+            return 0.0;
+        }
+        self.fields.iter().map(|f| f.effective_confidence() * f.weight()).sum::<f64>() / total_weight
+    }
+
+    pub fn corrections(&self) -> usize {
+        self.fields.iter().filter(|f| f.is_corrected()).count()
+    }
+
+    /// Fields that still need a person to check them
+    pub fn fields_to_review(&self) -> usize {
+        self.fields.iter().filter(|f| f.level() != ConfidenceLevel::High).count()
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.validation.iter().any(|v| v.status == ValidationStatus::Fail)
+    }
+
+    pub fn level(&self) -> ConfidenceLevel {
+        ConfidenceLevel::of(self.overall_confidence)
+    }
+}
@@ -0,0 +1,298 @@
+//! Business-rule checks on extracted documents
+//!
+//! Rules run against the values as corrected by the reviewer, so fixing a
+//! field clears the failures it caused. A failure blocks saving; a warning
+//! is shown but the reviewer may save anyway.
+
+use crate::features::invoices::service::find_duplicates;
+use crate::features::invoices::types::Invoice;
+use crate::features::purchase_orders::types::PurchaseOrder;
+use crate::features::suppliers::types::Supplier;
+use crate::util::datetime::Date;
+use crate::util::format::format_currency_full;
+use crate::util::money::{Money, VAT_RATE};
+use super::types::{FieldKind, OcrDocumentType, OcrScan, ValidationResult, ValidationStatus};
+use crate::util::i18n::{t, tf};
+
+/// Largest difference accepted between the printed and calculated VAT
+const VAT_TOLERANCE: Money = Money::rands(1);
+/// B-BBEE certificates are issued for twelve months
+const CERTIFICATE_VALIDITY_DAYS: i64 = 366;
+
+/// Registers the extracted values are checked against
+pub struct ValidationContext<'a> {
+    pub suppliers: &'a [Supplier],
+    /// Order named on the document, if it was found
+    pub purchase_order: Option<&'a PurchaseOrder>,
+    pub invoices: &'a [Invoice],
+    pub today: Date,
+}
+
+/// South African VAT numbers are ten digits starting with 4
+pub fn is_valid_vat_number(value: &str) -> bool {
+    let digits: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    digits.len() == 10 && digits.starts_with('4') && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// CIPC company registration numbers read `YYYY/NNNNNN/NN`
+pub fn is_valid_registration_number(value: &str) -> bool {
+    let parts: Vec<&str> = value.trim().split('/').collect();
+    parts.len() == 3
+        && [4, 6, 2].iter().zip(&parts).all(|(len, part)| part.len() == *len && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn result(rule: &'static str, field: Option<&str>, status: ValidationStatus, message: String) -> ValidationResult {
+    ValidationResult {
+        rule: t(rule).to_string(),
+        field: field.map(str::to_string),
+        status,
+        message,
+    }
+}
+
+fn pass(rule: &'static str, field: Option<&str>, message: String) -> ValidationResult {
+    result(rule, field, ValidationStatus::Pass, message)
+}
+
+fn warn(rule: &'static str, field: Option<&str>, message: String) -> ValidationResult {
+    result(rule, field, ValidationStatus::Warning, message)
+}
+
+fn fail(rule: &'static str, field: Option<&str>, message: String) -> ValidationResult {
+    result(rule, field, ValidationStatus::Fail, message)
+}
+
+/// Check a scan against the business rules of its document type
+pub fn validate(scan: &OcrScan, ctx: &ValidationContext) -> Vec<ValidationResult> {
+    let Some(document_type) = scan.document_type else {
+        return vec![fail("Document type", None, t("The document type could not be determined").to_string())];
+    };
+
+    let mut results = Vec::new();
+    for field in &scan.fields {
+        let value = field.value.trim();
+        if value.is_empty() {
+            if field.required {
+                results.push(fail("Required field", Some(&field.key), tf("{field} was not found", &[("field", field.label.to_string())])));
+            }
+            continue;
+        }
+        let readable = match field.kind {
+            FieldKind::Text => true,
+            FieldKind::Date => Date::parse(value).is_some(),
+            FieldKind::Amount => value.parse::<Money>().is_ok(),
+        };
+        if !readable {
+            results.push(fail("Format", Some(&field.key), tf("{field} \"{value}\" is not a valid value", &[("field", field.label.to_string()), ("value", value.to_string())])));
+        }
+    }
+
+    match document_type {
+        OcrDocumentType::Invoice => validate_invoice(scan, ctx, &mut results),
+        OcrDocumentType::DeliveryNote => validate_delivery_note(scan, ctx, &mut results),
+        OcrDocumentType::BbbeeCertificate => validate_certificate(scan, ctx, &mut results),
+    }
+    results
+}
+
+fn date_of(scan: &OcrScan, key: &str) -> Option<Date> {
+    Date::parse(&scan.value(key))
+}
+
+fn amount_of(scan: &OcrScan, key: &str) -> Option<Money> {
+    scan.value(key).parse().ok()
+}
+
+fn check_purchase_order(scan: &OcrScan, ctx: &ValidationContext, results: &mut Vec<ValidationResult>) {
+    let po_number = scan.value("po_number");
+    if po_number.is_empty() {
+        return;
+    }
+    let Some(po) = ctx.purchase_order else {
+        results.push(fail("PO exists", Some("po_number"), tf("Purchase order {po_number} was not found", &[("po_number", po_number.to_string())])));
+        return;
+    };
+    results.push(pass("PO exists", Some("po_number"), tf("{po_number} issued to {supplier}", &[("po_number", po.po_number.clone()), ("supplier", po.supplier.name.clone())])));
+
+    let supplier = scan.value("supplier_name");
+    if !supplier.is_empty() && !po.supplier.name.eq_ignore_ascii_case(&supplier) {
+        results.push(warn(
+            "Supplier matches PO",
+            Some("supplier_name"),
+            tf(
+                "{po_number} was issued to {supplier}, not {scanned}",
+                &[("po_number", po.po_number.clone()), ("supplier", po.supplier.name.clone()), ("scanned", supplier.clone())],
+            ),
+        ));
+    }
+}
+
+fn validate_invoice(scan: &OcrScan, ctx: &ValidationContext, results: &mut Vec<ValidationResult>) {
+    let vat_number = scan.value("supplier_vat");
+    if !vat_number.is_empty() {
+        results.push(if is_valid_vat_number(&vat_number) {
+            pass("VAT number format", Some("supplier_vat"), tf("{number} is a valid VAT number", &[("number", vat_number.clone())]))
+        } else {
+            fail("VAT number format", Some("supplier_vat"), tf("{number} is not a 10-digit VAT number starting with 4", &[("number", vat_number.clone())]))
+        });
+    }
+
+    if let (Some(subtotal), Some(vat)) = (amount_of(scan, "subtotal"), amount_of(scan, "vat_amount")) {
+        let expected = subtotal.vat_on(VAT_RATE);
+        results.push(if (vat - expected).abs() <= VAT_TOLERANCE {
+            pass("VAT calculation", Some("vat_amount"), tf("VAT is {rate}% of the subtotal", &[("rate", VAT_RATE.to_string())]))
+        } else {
+            fail(
+                "VAT calculation",
+                Some("vat_amount"),
+                tf("VAT of {vat} should be {expected}", &[("vat", format_currency_full(vat)), ("expected", format_currency_full(expected))]),
+            )
+        });
+        if let Some(total) = amount_of(scan, "total_amount") {
+            results.push(if total == subtotal + vat {
+                pass("Total calculation", Some("total_amount"), t("Total is the subtotal plus VAT").to_string())
+            } else {
+                fail(
+                    "Total calculation",
+                    Some("total_amount"),
+                    tf("Total of {total} should be {expected}", &[("total", format_currency_full(total)), ("expected", format_currency_full(subtotal + vat))]),
+                )
+            });
+        }
+        let lines: Money = scan.lines.iter().map(|l| l.unit_price * l.quantity).sum();
+        if !scan.lines.is_empty() && lines != subtotal {
+            results.push(warn(
+                "Line items",
+                Some("subtotal"),
+                tf("Lines add up to {lines}, not the subtotal of {subtotal}", &[("lines", format_currency_full(lines)), ("subtotal", format_currency_full(subtotal))]),
+            ));
+        }
+    }
+
+    if let Some(invoice_date) = date_of(scan, "invoice_date") {
+        if invoice_date > ctx.today {
+            results.push(fail("Invoice date", Some("invoice_date"), t("The invoice is dated in the future").to_string()));
+        }
+        if let Some(due) = date_of(scan, "due_date") {
+            if due <= invoice_date {
+                results.push(warn("Due date", Some("due_date"), t("The due date is not after the invoice date").to_string()));
+            }
+        }
+    }
+
+    let supplier = scan.value("supplier_name");
+    let registered = ctx.suppliers.iter().find(|s| {
+        s.name.eq_ignore_ascii_case(&supplier)
+            || (!vat_number.is_empty() && s.vat_number.as_deref() == Some(vat_number.as_str()))
+    });
+    if !supplier.is_empty() {
+        results.push(match registered {
+            Some(s) => pass("Supplier exists", Some("supplier_name"), tf("{name} is in the supplier registry", &[("name", s.name.clone())])),
+            None => warn("Supplier exists", Some("supplier_name"), tf("{name} is not in the supplier registry", &[("name", supplier.clone())])),
+        });
+    }
+
+    check_purchase_order(scan, ctx, results);
+
+    let number = scan.value("invoice_number");
+    if let (false, Some(po)) = (number.is_empty(), ctx.purchase_order) {
+        let candidate = Invoice {
+            invoice_number: number.clone(),
+            supplier_id: po.supplier.id.clone(),
+            received_date: ctx.today.to_iso(),
+            total_amount: amount_of(scan, "total_amount").unwrap_or(Money::ZERO),
+            ..Default::default()
+        };
+        let duplicates = find_duplicates(ctx.invoices, &candidate);
+        results.push(match duplicates.first() {
+            Some(dup) if dup.reason.is_blocking() => fail(
+                "Duplicate invoice",
+                Some("invoice_number"),
+                tf("{number} is already registered as {invoice_id}", &[("number", number.clone()), ("invoice_id", dup.invoice_id.to_string())]),
+            ),
+            Some(dup) => warn(
+                "Duplicate invoice",
+                Some("invoice_number"),
+                tf("Possible duplicate of {invoice_id} ({reason})", &[("invoice_id", dup.invoice_id.to_string()), ("reason", dup.reason.label().to_string())]),
+            ),
+            None => pass("Duplicate invoice", Some("invoice_number"), tf("{number} has not been registered", &[("number", number.clone())])),
+        });
+    }
+}
+
+fn validate_delivery_note(scan: &OcrScan, ctx: &ValidationContext, results: &mut Vec<ValidationResult>) {
+    if let Some(delivered) = date_of(scan, "delivery_date") {
+        if delivered > ctx.today {
+            results.push(fail("Delivery date", Some("delivery_date"), t("The delivery is dated in the future").to_string()));
+        }
+    }
+
+    check_purchase_order(scan, ctx, results);
+
+    let Some(po) = ctx.purchase_order else { return };
+    for line in &scan.lines {
+        match po.line_items.iter().find(|item| item.item_code == line.item_code) {
+            None => results.push(warn(
+                "Item on order",
+                None,
+                tf("{item} is not on {po_number}", &[("item", line.item_code.clone()), ("po_number", po.po_number.clone())]),
+            )),
+            Some(item) if line.quantity > item.quantity.saturating_sub(item.delivered_quantity) => results.push(warn(
+                "Quantity outstanding",
+                None,
+                tf(
+                    "{quantity} {item} delivered but only {outstanding} outstanding on {po_number}",
+                    &[
+                        ("quantity", line.quantity.to_string()),
+                        ("item", line.item_code.clone()),
+                        ("outstanding", item.quantity.saturating_sub(item.delivered_quantity).to_string()),
+                        ("po_number", po.po_number.clone()),
+                    ],
+                ),
+            )),
+            Some(_) => {}
+        }
+    }
+}
+
+fn validate_certificate(scan: &OcrScan, ctx: &ValidationContext, results: &mut Vec<ValidationResult>) {
+    let registration = scan.value("registration_number");
+    if !registration.is_empty() {
+        if is_valid_registration_number(&registration) {
+            results.push(pass("Registration number format", Some("registration_number"), tf("{number} is a valid CIPC number", &[("number", registration.clone())])));
+        } else {
+            results.push(fail(
+                "Registration number format",
+                Some("registration_number"),
+                tf("{number} is not in the form YYYY/NNNNNN/NN", &[("number", registration.clone())]),
+            ));
+        }
+        results.push(match ctx.suppliers.iter().find(|s| s.registration_number == registration) {
+            Some(s) => pass("Supplier exists", Some("registration_number"), tf("Registered as {name} ({id})", &[("name", s.name.to_string()), ("id", s.id.to_string())])),
+            None => fail("Supplier exists", Some("registration_number"), tf("No supplier is registered under {registration}", &[("registration", registration.to_string())])),
+        });
+    }
+
+    let level = scan.value("bbbee_level");
+    if !level.is_empty() && !matches!(level.trim_start_matches("Level ").parse::<u8>(), Ok(1..=8)) {
+        results.push(fail("B-BBEE level", Some("bbbee_level"), tf("\"{level}\" is not a level from 1 to 8", &[("level", level.clone())])));
+    }
+
+    if let Some(expiry) = date_of(scan, "expiry_date") {
+        results.push(if expiry < ctx.today {
+            fail("Certificate expiry", Some("expiry_date"), tf("The certificate expired on {expiry}", &[("expiry", expiry.to_iso())]))
+        } else {
+            pass("Certificate expiry", Some("expiry_date"), tf("Valid until {expiry}", &[("expiry", expiry.to_iso())]))
+        });
+        if let Some(issued) = date_of(scan, "issue_date") {
+            let validity = issued.days_until(expiry);
+            if validity <= 0 || validity > CERTIFICATE_VALIDITY_DAYS {
+                results.push(warn(
+                    "Certificate period",
+                    Some("expiry_date"),
+                    t("B-BBEE certificates are valid for twelve months from the date of issue").to_string(),
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! Bank payment files and remittance advices
+//!
+//! The fixed-width file is a simplified bulk EFT import modelled on the ACB
+//! credit transfer file: a header, one record per beneficiary and a trailer
+//! carrying the record count, the total in cents and a hash of the
+//! beneficiary account numbers. It is NOT the 180-character ACB service file
+//! the banks exchange with BankservAfrica; every record here is
+//! `RECORD_LENGTH` characters, CRLF terminated, and the field order has to be
+//! agreed with the receiving bank before it is used in production.
+
+use crate::util::format::{format_currency_full, format_date};
+use super::types::{PayerAccount, PaymentFile, PaymentFileFormat, PaymentRun, SupplierPaymentTotal};
+
+const RECORD_LENGTH: usize = 120;
+
+/// Upper-case ASCII, left aligned and space filled to `width`
+fn alpha(value: &str, width: usize) -> String {
+    let cleaned: String = value
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || " -/.&".contains(c) { c.to_ascii_uppercase() } else { ' ' })
+        .take(width)
+        .collect();
+    format!("{:<width$}", cleaned, width = width)
+}
+
+/// Digits only, right aligned and zero filled to `width`
+///
+/// A value with more digits than the field is an error: dropping the high
+/// digits of an amount or account number would pay the wrong figure or the
+/// wrong account.
+fn numeric(value: &str, width: usize) -> Result<String, String> {
+    let digits: String = value.chars().filter(|c| c.is_ascii_digit()).collect();
+    if digits.len() > width {
+        return Err(format!("{} does not fit in a {}-digit field", value, width));
+    }
+    Ok(format!("{:0>width$}", digits, width = width))
+}
+
+fn record(fields: &[String]) -> String {
+    format!("{:<width$}", fields.concat(), width = RECORD_LENGTH)
+}
+
+/// `YYYYMMDD`
+fn compact_date(iso: &str) -> String {
+    iso.get(..10).unwrap_or(iso).replace('-', "")
+}
+
+/// Bank payment file for an authorised run
+pub fn payment_file(run: &PaymentRun, payer: &PayerAccount, format: PaymentFileFormat) -> Result<PaymentFile, String> {
+    let content = match format {
+        PaymentFileFormat::AcbEft => acb_file(run, payer)?,
+        PaymentFileFormat::Csv => csv_file(run, payer),
+    };
+    Ok(PaymentFile {
+        file_name: format!("{}-{}.{}", run.id, compact_date(&run.payment_date), format.extension()),
+        mime_type: format.mime_type().to_string(),
+        content,
+    })
+}
+
+fn acb_file(run: &PaymentRun, payer: &PayerAccount) -> Result<String, String> {
+    let mut records = vec![record(&[
+        "H".to_string(),
+        alpha(&payer.user_code, 4),
+        numeric(&payer.branch_code, 6)?,
+        numeric(&payer.account_number, 11)?,
+        compact_date(&run.payment_date),
+        compact_date(&run.created_at),
+        alpha(&run.id, 20),
+        alpha(&payer.name, 30),
+    ])];
+
+    let mut hash_total: u64 = 0;
+    for (sequence, payment) in run.payments.iter().enumerate() {
+        let account = numeric(&payment.account_number, 11)
+            .map_err(|e| format!("{}: account number {}", payment.supplier_name, e))?;
+        hash_total += account.parse::<u64>().unwrap_or(0);
+        if payment.total().cents() < 0 {
+            return Err(format!("{}: a payment cannot be negative", payment.supplier_name));
+        }
+        records.push(record(&[
+            "D".to_string(),
+            numeric(&(sequence + 1).to_string(), 6)?,
+            numeric(&payment.branch_code, 6)
+                .map_err(|e| format!("{}: branch code {}", payment.supplier_name, e))?,
+            account,
+            // Account type 1: current/cheque
+            "1".to_string(),
+            numeric(&payment.total().cents().to_string(), 11)
+                .map_err(|e| format!("{}: amount {}", payment.supplier_name, e))?,
+            alpha(&payment.supplier_name, 30),
+            alpha(&format!("{} {}", payer.short_name, payment.reference), 30),
+        ]));
+    }
+
+    records.push(record(&[
+        "T".to_string(),
+        numeric(&run.payments.len().to_string(), 6)?,
+        numeric(&run.total().cents().to_string(), 15)?,
+        // The bank keeps the low eleven digits of the account hash
+        numeric(&(hash_total % 100_000_000_000).to_string(), 11)?,
+    ]));
+
+    let mut content = records.join("\r\n");
+    content.push_str("\r\n");
+    Ok(content)
+}
+
+/// Quote a CSV field when it holds a separator, quote or line break
+///
+/// A field a spreadsheet would read as a formula (leading `=`, `+`, `-` or
+/// `@`, or a tab or carriage return that hides one) is prefixed with an
+/// apostrophe so opening the file cannot run it.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn csv_row<S: AsRef<str>>(fields: &[S]) -> String {
+    fields.iter().map(|f| csv_field(f.as_ref())).collect::<Vec<_>>().join(",")
+}
+
+fn csv_file(run: &PaymentRun, payer: &PayerAccount) -> String {
+    let mut rows = vec![csv_row(&[
+        "Payment Date", "Beneficiary", "Bank", "Branch Code", "Account Number",
+        "Amount", "Beneficiary Reference", "Own Reference",
+    ])];
+    for payment in &run.payments {
+        rows.push(csv_row(&[
+            &run.payment_date,
+            &payment.supplier_name,
+            &payment.bank_name,
+            &payment.branch_code,
+            &payment.account_number,
+            &payment.total().to_string(),
+            &format!("{} {}", payer.short_name, payment.reference),
+            &run.id,
+        ]));
+    }
+    let mut content = rows.join("\r\n");
+    content.push_str("\r\n");
+    content
+}
+
+/// Account number with all but the last four digits hidden
+pub fn masked_account(account_number: &str) -> String {
+    let digits: Vec<char> = account_number.chars().filter(|c| c.is_ascii_digit()).collect();
+    let shown: String = digits[digits.len().saturating_sub(4)..].iter().collect();
+    format!("****{}", shown)
+}
+
+/// Remittance advice telling a supplier which invoices a payment settles
+pub fn remittance_advice(run: &PaymentRun, payer: &PayerAccount, supplier_id: &str) -> Option<PaymentFile> {
+    let payment = run.payment_for(supplier_id)?;
+    let mut lines = vec![
+        "REMITTANCE ADVICE".to_string(),
+        String::new(),
+        format!("From:            {}", payer.name),
+        format!("To:              {}", payment.supplier_name),
+        format!("Payment date:    {}", format_date(&run.payment_date)),
+        format!("Payment run:     {}", run.id),
+        format!("Paid into:       {} {} (branch {})", payment.bank_name, masked_account(&payment.account_number), payment.branch_code),
+        format!("Your reference:  {} {}", payer.short_name, payment.reference),
+        String::new(),
+        format!("{:<20} {:<16} {:<12} {:>18}", "Invoice", "Order", "Invoice Date", "Amount"),
+        "-".repeat(69),
+    ];
+    for line in &payment.lines {
+        lines.push(format!(
+            "{:<20} {:<16} {:<12} {:>18}",
+            line.invoice_number,
+            line.po_number,
+            line.invoice_date,
+            format_currency_full(line.amount)
+        ));
+    }
+    lines.push("-".repeat(69));
+    lines.push(format!("{:<50} {:>18}", "Total paid", format_currency_full(payment.total())));
+    lines.push(String::new());
+    lines.push(format!("Queries: {} quoting {}", payer.remittance_email, run.id));
+
+    Some(PaymentFile {
+        file_name: format!("remittance-{}-{}.txt", run.id, payment.supplier_id),
+        mime_type: "text/plain".to_string(),
+        content: lines.join("\r\n"),
+    })
+}
+
+/// Payment report as CSV, one row per supplier
+pub fn payment_report(totals: &[SupplierPaymentTotal], period: &str) -> PaymentFile {
+    let mut rows = vec![csv_row(&["Supplier ID", "Supplier", "Runs", "Invoices", "Amount", "Last Paid"])];
+    for total in totals {
+        rows.push(csv_row(&[
+            &total.supplier_id,
+            &total.supplier_name,
+            &total.runs.to_string(),
+            &total.invoices.to_string(),
+            &total.amount.to_string(),
+            &total.last_paid,
+        ]));
+    }
+    PaymentFile {
+        file_name: format!("payment-report-{}.csv", period.replace('/', "-")),
+        mime_type: "text/csv".to_string(),
+        content: rows.join("\r\n"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::money::Money;
+    use crate::features::payments::types::{PaymentLine, SupplierPayment};
+
+    fn payment(name: &str, branch: &str, account: &str, amounts: &[i64]) -> SupplierPayment {
+        SupplierPayment {
+            supplier_name: name.to_string(),
+            branch_code: branch.to_string(),
+            account_number: account.to_string(),
+            reference: "PR-0001".to_string(),
+            lines: amounts.iter().map(|cents| PaymentLine { amount: Money::from_cents(*cents), ..Default::default() }).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn run(payments: Vec<SupplierPayment>) -> PaymentRun {
+        PaymentRun {
+            id: "PR-0001".to_string(),
+            payment_date: "2025-03-31".to_string(),
+            created_at: "2025-03-28T08:00:00Z".to_string(),
+            payments,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn acb_file_has_fixed_width_header_details_and_trailer() {
+        let run = run(vec![
+            payment("Acme Supplies (Pty) Ltd", "250655", "620-1234-5678", &[100_000, 23_456]),
+            payment("Bongi's Catering", "051001", "1234567", &[50_000]),
+        ]);
+        let content = acb_file(&run, &PayerAccount::default()).unwrap();
+        assert!(content.ends_with("\r\n"));
+        let records: Vec<&str> = content.trim_end_matches("\r\n").split("\r\n").collect();
+        assert_eq!(records.len(), 4);
+        assert!(records.iter().all(|r| r.len() == RECORD_LENGTH));
+
+        assert!(records[0].starts_with("HSR01632005405300678122025033120250328PR-0001"));
+        assert_eq!(
+            records[1].trim_end(),
+            "D00000125065562012345678100000123456ACME SUPPLIES  PTY  LTD       SARS PR-0001"
+        );
+        assert!(records[2].starts_with("D00000205100100001234567100000050000BONGI S CATERING"));
+        // Two records, R 1 734,56 and the sum of the two account numbers
+        assert_eq!(records[3].trim_end(), "T00000200000000017345662013580245");
+    }
+
+    #[test]
+    fn acb_file_refuses_values_that_do_not_fit() {
+        let cases = [
+            payment("Long account", "250655", "123456789012", &[100]),
+            payment("Long branch", "2506550", "1234567", &[100]),
+            payment("Refund", "250655", "1234567", &[-100]),
+        ];
+        for case in cases {
+            let name = case.supplier_name.clone();
+            let error = acb_file(&run(vec![case]), &PayerAccount::default()).unwrap_err();
+            assert!(error.starts_with(&name), "{}", error);
+        }
+    }
+
+    #[test]
+    fn csv_file_writes_exact_amounts() {
+        let run = run(vec![payment("Acme Supplies (Pty) Ltd", "250655", "6201234567", &[100_000, 23_456, 1])]);
+        let content = csv_file(&run, &PayerAccount::default());
+        let row: Vec<&str> = content.split("\r\n").nth(1).unwrap().split(',').collect();
+        assert_eq!(row[5], "1234.57");
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_and_defused() {
+        let cases = [
+            ("plain", "plain"),
+            ("Smith, J", "\"Smith, J\""),
+            ("6\" pipe", "\"6\"\" pipe\""),
+            ("=SUM(A1:A9)", "'=SUM(A1:A9)"),
+            ("-1+1", "'-1+1"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(csv_field(value), expected);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Payment runs feature module
+//!
+//! Batches approved invoices into payment runs, leaves out suppliers that
+//! may not be paid, takes the run through dual authorisation and produces
+//! the bank payment files, remittance advices and payment report.
+
+pub mod types;
+pub mod files;
+pub mod store;
+pub mod service;
+pub mod payment_runs;
+pub mod payment_run_detail;
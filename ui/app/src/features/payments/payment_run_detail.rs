@@ -0,0 +1,396 @@
+//! Payment run detail
+//!
+//! Shows what a run pays each supplier and what it left out, takes the two
+//! authorisations, releases the bank files once the run is authorised and
+//! offers a remittance advice for every supplier paid.
+
+use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table, DataTableColumn, DataTableRow,
+    notice_bar, NoticeType,
+};
+use crate::shared::forms::textarea;
+use crate::util::auth::AuthState;
+use crate::util::format::{format_currency, format_currency_full, format_date, format_datetime};
+use crate::util::i18n::t;
+use crate::util::permissions::{self, Action};
+use super::files::{self, masked_account};
+use super::payment_runs::{excluded_list, payment_stores, run_status};
+use super::service::{self, PaymentStores};
+use super::store::PaymentsStore;
+use super::types::{PaymentFile, PaymentFileFormat, PaymentRun, PaymentRunStatus, REQUIRED_AUTHORISATIONS};
+
+/// Payment run detail page
+#[component]
+pub fn payment_run_detail(run_id: String) -> View {
+    let store = use_context::<PaymentsStore>();
+
+    effect({
+        let store = store.clone();
+        let run_id = run_id.clone();
+        move || {
+            let store = store.clone();
+            let run_id = run_id.clone();
+            spawn(async move {
+                service::load_run(&store, &run_id).await;
+            });
+        }
+    });
+
+    let loading = store.loading.get();
+    let error = store.error.get();
+    let run = store.selected.get().filter(|r| r.id == run_id);
+
+    view! {
+        style {
+            r#"
+            .payment-detail { display: flex; flex-direction: column; gap: var(--space-6); }
+            .payment-detail-grid {
+                display: grid;
+                grid-template-columns: 2fr 1fr;
+                gap: 24px;
+                align-items: start;
+            }
+            .payment-detail .detail-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 8px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .payment-detail .detail-row:last-child { border-bottom: none; }
+            .payment-detail .detail-row .label { color: var(--text-muted); }
+            .payment-detail .detail-row .value { font-weight: 500; text-align: right; }
+            .payment-muted { font-size: 12px; color: var(--text-muted); }
+            .payment-invoices { display: flex; flex-direction: column; font-size: 12px; }
+            .payment-authorisations { display: flex; flex-direction: column; gap: 12px; }
+            .payment-files { display: flex; flex-wrap: wrap; gap: 8px; align-items: center; }
+            .payment-excluded-item {
+                display: flex;
+                justify-content: space-between;
+                gap: 12px;
+                padding: 8px 0;
+                font-size: 13px;
+                border-bottom: 1px solid var(--border);
+            }
+            .payment-excluded-item:last-child { border-bottom: none; }
+            .payment-excluded-item .reason { font-size: 12px; color: var(--red); }
+            .payment-actions { display: flex; gap: 8px; justify-content: flex-end; }
+            @media (max-width: 1024px) {
+                .payment-detail-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="payment-detail" data-testid="payment-run-detail">
+            if loading {
+                <div class="loading-state">{t("Loading payment run...")}</div>
+            } else if let Some(run) = run {
+                {render_run(store.clone(), payment_stores(), run)}
+            } else {
+                {entity_not_found(t("Payment run"), &run_id, error, Route::PaymentRunsList, t("Back to Payment Runs"))}
+            }
+        </div>
+    }
+}
+
+fn render_run(store: PaymentsStore, stores: PaymentStores, run: PaymentRun) -> View {
+    let auth_state = use_context::<Signal<AuthState>>();
+    let auth = auth_state.get();
+    let user_id = auth.user.as_ref().map(|u| u.id.clone()).unwrap_or_default();
+    let can_prepare = permissions::can_perform(&auth, Action::PreparePaymentRun);
+    let can_authorise = permissions::can_perform(&auth, Action::AuthorisePaymentRun)
+        && run.status.awaits_authorisation()
+        && !run.prepared_by(&user_id)
+        && !run.authorised_by(&user_id);
+    let can_cancel = (can_prepare || permissions::can_perform(&auth, Action::AuthorisePaymentRun))
+        && run.status.is_open()
+        && run.files_generated.is_empty();
+    let released = matches!(run.status, PaymentRunStatus::Authorised | PaymentRunStatus::Paid);
+
+    let generated: Signal<Option<PaymentFile>> = signal(None);
+    let cancel_reason = signal(String::new());
+
+    let handle_authorise = Callback::<()>::new({
+        let store = store.clone();
+        let stores = stores.clone();
+        let id = run.id.clone();
+        move |_| {
+            let store = store.clone();
+            let stores = stores.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::authorise_run(&store, &stores, &id).await;
+            });
+        }
+    });
+
+    let handle_generate = Callback::new({
+        let store = store.clone();
+        let generated = generated.clone();
+        let id = run.id.clone();
+        move |format: PaymentFileFormat| {
+            let store = store.clone();
+            let generated = generated.clone();
+            let id = id.clone();
+            spawn(async move {
+                if let Ok(file) = service::generate_payment_file(&store, &id, format).await {
+                    generated.set(Some(file));
+                }
+            });
+        }
+    });
+
+    let handle_paid = Callback::<()>::new({
+        let store = store.clone();
+        let stores = stores.clone();
+        let id = run.id.clone();
+        move |_| {
+            let store = store.clone();
+            let stores = stores.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::confirm_paid(&store, &stores, &id).await;
+            });
+        }
+    });
+
+    let handle_cancel = Callback::<()>::new({
+        let store = store.clone();
+        let stores = stores.clone();
+        let cancel_reason = cancel_reason.clone();
+        let id = run.id.clone();
+        move |_| {
+            let store = store.clone();
+            let stores = stores.clone();
+            let reason = cancel_reason.get();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::cancel_run(&store, &stores, &id, &reason).await;
+            });
+        }
+    });
+
+    let payer = store.payer.get();
+    let columns = vec![
+        DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "bank".to_string(), label: t("Bank Account").to_string(), width: Some("190px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "invoices".to_string(), label: t("Invoices").to_string(), width: Some("170px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "amount".to_string(), label: t("Amount").to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "remittance".to_string(), label: t("Remittance").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+    ];
+    let rows: Vec<DataTableRow> = run.payments.iter().map(|payment| {
+        let advice = files::remittance_advice(&run, &payer, &payment.supplier_id);
+        DataTableRow {
+            id: payment.supplier_id.clone(),
+            cells: vec![
+                view! {
+                    <div>
+                        <div>{payment.supplier_name.clone()}</div>
+                        <div class="payment-muted">{payment.reference.clone()}</div>
+                    </div>
+                },
+                view! {
+                    <div>
+                        <div>{payment.bank_name.clone()}</div>
+                        <div class="payment-muted">{format!("{} · {}", masked_account(&payment.account_number), payment.branch_code)}</div>
+                    </div>
+                },
+                view! {
+                    <div class="payment-invoices">
+                        for line in payment.lines.clone() {
+                            <a href={Route::InvoicesDetail(line.invoice_id.clone()).to_path()}>
+                                {format!("{} · {}", line.invoice_id, line.invoice_number)}
+                            </a>
+                        }
+                    </div>
+                },
+                view! { <span class="amount-cell">{format_currency(payment.total())}</span> },
+                match advice {
+                    Some(advice) if released => view! {
+                        <a href={advice.data_url()} download={advice.file_name.clone()}>{t("Download")}</a>
+                    },
+                    _ => view! { <span class="payment-muted">{t("After authorisation")}</span> },
+                },
+            ],
+        }
+    }).collect();
+
+    view! {
+        {page_header(
+            format!("{} {}", t("Payment Run"), run.id),
+            Some(format!("{} {}", t("Paying on"), format_date(&run.payment_date))),
+            vec![
+                view! { <a href={Route::PaymentRunsList.to_path()} class="btn btn-secondary">{t("Back to Payment Runs")}</a> },
+            ]
+        )}
+
+        if let Some(err) = store.error.get() {
+            {notice_bar(err, NoticeType::Error, None)}
+        }
+        if let Some(reason) = run.cancelled_reason.clone() {
+            {notice_bar(format!("{}: {}", t("Cancelled"), reason), NoticeType::Warning, None)}
+        }
+
+        <div class="payment-detail-grid">
+            <div class="payment-authorisations">
+                {panel(
+                    format!("{} ({})", t("Payments"), run.payments.len()),
+                    vec![],
+                    vec![data_table(columns, rows, None)]
+                )}
+
+                if !run.excluded.is_empty() {
+                    {panel(
+                        format!("{} ({})", t("Excluded Invoices"), run.excluded.len()),
+                        vec![],
+                        vec![excluded_list(run.excluded.clone())]
+                    )}
+                }
+            </div>
+
+            <div class="payment-authorisations">
+                {panel(
+                    t("Summary").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div>
+                            <div class="detail-row">
+                                <span class="label">{t("Status")}</span>
+                                <span class="value">{run_status(run.status)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Payment date")}</span>
+                                <span class="value">{format_date(&run.payment_date)}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Invoices")}</span>
+                                <span class="value">{run.invoice_count().to_string()}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Total")}</span>
+                                <span class="value" data-testid="payment-run-total">{format_currency_full(run.total())}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Paid from")}</span>
+                                <span class="value">{format!("{} {}", payer.bank_name, masked_account(&payer.account_number))}</span>
+                            </div>
+                            <div class="detail-row">
+                                <span class="label">{t("Prepared by")}</span>
+                                <span class="value">{format!("{} · {}", run.created_by, format_datetime(&run.created_at))}</span>
+                            </div>
+                            if let (Some(by), Some(at)) = (run.paid_by.clone(), run.paid_at.clone()) {
+                                <div class="detail-row">
+                                    <span class="label">{t("Payment confirmed by")}</span>
+                                    <span class="value">{format!("{} · {}", by, format_datetime(&at))}</span>
+                                </div>
+                            }
+                        </div>
+                    }]
+                )}
+
+                {panel(
+                    format!("{} ({} {} {})", t("Authorisation"), run.authorisations.len().min(REQUIRED_AUTHORISATIONS), t("of"), REQUIRED_AUTHORISATIONS),
+                    vec![],
+                    vec![view! {
+                        <div data-testid="payment-authorisations">
+                            for authorisation in run.authorisations.clone() {
+                                <div class="detail-row">
+                                    <span class="label">{authorisation.by.clone()}</span>
+                                    <span class="value">{format_datetime(&authorisation.at)}</span>
+                                </div>
+                            }
+                            if run.status.awaits_authorisation() {
+                                <div class="payment-muted">
+                                    {format!(
+                                        "{} {} {}",
+                                        run.authorisations_outstanding(),
+                                        t("more authorisation(s) needed from someone other than"),
+                                        run.created_by
+                                    )}
+                                </div>
+                            }
+                            if can_authorise {
+                                <div class="payment-actions">
+                                    <button class="btn btn-primary" disabled={store.saving.get()} data-testid="payment-authorise" on:click={handle_authorise}>
+                                        {t("Authorise Run")}
+                                    </button>
+                                </div>
+                            }
+                        </div>
+                    }]
+                )}
+
+                if released && can_prepare {
+                    {panel(
+                        t("Bank Files").to_string(),
+                        vec![],
+                        vec![view! {
+                            <div class="payment-authorisations" data-testid="payment-files">
+                                <div class="payment-files">
+                                    for format in PaymentFileFormat::ALL {
+                                        <button
+                                            class="btn btn-secondary btn-sm"
+                                            on:click={Callback::<()>::new({
+                                                let handle_generate = handle_generate.clone();
+                                                move |_| handle_generate.call(format)
+                                            })}
+                                        >
+                                            {format!("{} {}", t("Generate"), format.label())}
+                                        </button>
+                                    }
+                                </div>
+                                if let Some(file) = generated.get() {
+                                    <a class="btn btn-primary btn-sm" href={file.data_url()} download={file.file_name.clone()}>
+                                        {format!("{} {}", t("Download"), file.file_name)}
+                                    </a>
+                                }
+                                if run.status == PaymentRunStatus::Authorised {
+                                    <div class="payment-actions">
+                                        <button
+                                            class="btn btn-primary"
+                                            disabled={store.saving.get() || run.files_generated.is_empty()}
+                                            data-testid="payment-confirm-paid"
+                                            on:click={handle_paid}
+                                        >
+                                            {t("Confirm Paid")}
+                                        </button>
+                                    </div>
+                                }
+                            </div>
+                        }]
+                    )}
+                }
+
+                if can_cancel {
+                    {panel(
+                        t("Cancel Run").to_string(),
+                        vec![],
+                        vec![view! {
+                            <div class="payment-authorisations">
+                                {textarea(
+                                    t("Reason").to_string(),
+                                    cancel_reason.clone(),
+                                    Some(t("Why the run is being cancelled").to_string()),
+                                    true,
+                                    false,
+                                    Some(3),
+                                    None,
+                                    Some(t("Its invoices return to the approved list for another run").to_string())
+                                )}
+                                <div class="payment-actions">
+                                    <button class="btn btn-danger" disabled={store.saving.get()} on:click={handle_cancel}>
+                                        {t("Cancel Run")}
+                                    </button>
+                                </div>
+                            </div>
+                        }]
+                    )}
+                }
+            </div>
+        </div>
+    }
+}
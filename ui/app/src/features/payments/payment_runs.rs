@@ -0,0 +1,335 @@
+//! Payment runs
+//!
+//! Prepares new runs from the approved invoices due by a payment date,
+//! previewing what will be paid and what is left out, lists the runs with
+//! their authorisation progress and shows the payment report for the
+//! financial year (SRS FR-3.6.3).
+
+use components::prelude::*;
+use crate::Route;
+use crate::features::invoices::service::load_invoices;
+use crate::features::invoices::store::InvoicesStore;
+use crate::features::invoices::types::InvoiceStatus;
+use crate::features::suppliers::service::load_suppliers;
+use crate::features::suppliers::store::SuppliersStore;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table_with_testid, DataTableColumn, DataTableRow,
+    kpi_card, KpiColor, KpiDelta,
+    status_badge, StatusType,
+    notice_bar, NoticeType,
+    empty_state,
+};
+use crate::shared::forms::date_picker;
+use crate::util::auth::AuthState;
+use crate::util::datetime::{next_business_day, today, Date, FiscalYear};
+use crate::util::format::{format_currency, format_date};
+use crate::util::i18n::t;
+use crate::util::money::Money;
+use crate::util::permissions::{self, Action};
+use super::files;
+use super::service::{self, PaymentStores};
+use super::store::PaymentsStore;
+use super::types::{ExcludedInvoice, PaymentRunStatus, REQUIRED_AUTHORISATIONS};
+
+pub(super) fn run_status(status: PaymentRunStatus) -> View {
+    status_badge(match status {
+        PaymentRunStatus::Prepared => StatusType::Pending,
+        PaymentRunStatus::PartiallyAuthorised => StatusType::Review,
+        PaymentRunStatus::Authorised => StatusType::Scheduled,
+        PaymentRunStatus::Paid => StatusType::Complete,
+        PaymentRunStatus::Cancelled => StatusType::Cancelled,
+    })
+}
+
+pub(super) fn payment_stores() -> PaymentStores {
+    PaymentStores {
+        invoices: use_context::<InvoicesStore>(),
+        suppliers: use_context::<SuppliersStore>(),
+    }
+}
+
+pub(super) fn excluded_list(excluded: Vec<ExcludedInvoice>) -> View {
+    view! {
+        <div class="payment-excluded" data-testid="payment-excluded">
+            for item in excluded {
+                <div class="payment-excluded-item">
+                    <div>
+                        <a href={Route::InvoicesDetail(item.invoice_id.clone()).to_path()}>
+                            {format!("{} · {} {}", item.invoice_id, item.supplier_name, item.invoice_number)}
+                        </a>
+                        <div class="reason">{item.reason.label()}</div>
+                    </div>
+                    <span class="amount-cell">{format_currency(item.amount)}</span>
+                </div>
+            }
+        </div>
+    }
+}
+
+/// Payment runs page
+#[component]
+pub fn payment_runs() -> View {
+    let store = use_context::<PaymentsStore>();
+    let stores = payment_stores();
+    let auth_state = use_context::<Signal<AuthState>>();
+    let navigate = use_context::<Callback<Route>>();
+
+    effect({
+        let store = store.clone();
+        let stores = stores.clone();
+        move || {
+            let store = store.clone();
+            let stores = stores.clone();
+            spawn(async move {
+                service::load_runs(&store).await;
+                load_invoices(&stores.invoices).await;
+                load_suppliers(&stores.suppliers).await;
+            });
+        }
+    });
+
+    let today = today();
+    let payment_date = signal(next_business_day(today.add_days(1)).to_iso());
+
+    let handle_prepare = Callback::<()>::new({
+        let store = store.clone();
+        let stores = stores.clone();
+        let payment_date = payment_date.clone();
+        let navigate = navigate.clone();
+        move |_| {
+            let store = store.clone();
+            let stores = stores.clone();
+            let payment_date = payment_date.get();
+            let navigate = navigate.clone();
+            spawn(async move {
+                if let Ok(run) = service::prepare_run(&store, &stores, &payment_date).await {
+                    navigate.call(Route::PaymentRunDetail(run.id));
+                }
+            });
+        }
+    });
+
+    let on_row_click = {
+        let navigate = navigate.clone();
+        Callback::new(move |id: String| navigate.call(Route::PaymentRunDetail(id)))
+    };
+
+    let auth = auth_state.get();
+    let can_prepare = permissions::can_perform(&auth, Action::PreparePaymentRun);
+
+    let runs = store.runs.get();
+    let on_runs = store.open_invoices();
+    let invoices = stores.invoices.invoices.get();
+    let unscheduled: Money = invoices
+        .iter()
+        .filter(|i| i.status == InvoiceStatus::Approved && !on_runs.contains_key(&i.id))
+        .map(|i| i.total_amount)
+        .sum();
+    let awaiting = store.awaiting_authorisation();
+    let scheduled: Money = runs
+        .iter()
+        .filter(|r| r.status == PaymentRunStatus::Authorised)
+        .map(|r| r.total())
+        .sum();
+    let year = FiscalYear::current();
+    let report = store.payment_report(year);
+    let paid_this_year: Money = report.iter().map(|r| r.amount).sum();
+
+    // What a run prepared now would pay
+    let preview = Date::parse(&payment_date.get()).map(|date| {
+        service::select_invoices(&invoices, &stores.suppliers.suppliers.get(), &on_runs, date, "")
+    });
+
+    let columns = vec![
+        DataTableColumn { key: "id".to_string(), label: t("Run").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "date".to_string(), label: t("Payment Date").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "suppliers".to_string(), label: t("Suppliers").to_string(), width: Some("100px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "invoices".to_string(), label: t("Invoices").to_string(), width: Some("100px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "amount".to_string(), label: t("Amount").to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "authorised".to_string(), label: t("Authorisations").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("150px".to_string()), align: None, cell_class: None },
+    ];
+    let rows: Vec<DataTableRow> = runs.iter().map(|run| DataTableRow {
+        id: run.id.clone(),
+        cells: vec![
+            view! { <a href={Route::PaymentRunDetail(run.id.clone()).to_path()}>{run.id.clone()}</a> },
+            view! { <span>{format_date(&run.payment_date)}</span> },
+            view! { <span>{run.payments.len().to_string()}</span> },
+            view! { <span>{run.invoice_count().to_string()}</span> },
+            view! { <span class="amount-cell">{format_currency(run.total())}</span> },
+            view! { <span>{format!("{} {} {}", run.authorisations.len().min(REQUIRED_AUTHORISATIONS), t("of"), REQUIRED_AUTHORISATIONS)}</span> },
+            run_status(run.status),
+        ],
+    }).collect();
+
+    let report_columns = vec![
+        DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "runs".to_string(), label: t("Runs").to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "invoices".to_string(), label: t("Invoices").to_string(), width: Some("90px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "amount".to_string(), label: t("Paid").to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "last".to_string(), label: t("Last Paid").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+    ];
+    let report_rows: Vec<DataTableRow> = report.iter().map(|r| DataTableRow {
+        id: r.supplier_id.clone(),
+        cells: vec![
+            view! { <span>{r.supplier_name.clone()}</span> },
+            view! { <span>{r.runs.to_string()}</span> },
+            view! { <span>{r.invoices.to_string()}</span> },
+            view! { <span class="amount-cell">{format_currency(r.amount)}</span> },
+            view! { <span>{format_date(&r.last_paid)}</span> },
+        ],
+    }).collect();
+    let report_file = files::payment_report(&report, &year.label());
+
+    let icon_wallet = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><rect x="2" y="6" width="20" height="12" rx="2"/><circle cx="12" cy="12" r="2"/></svg>"#;
+    let icon_shield = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M12 22s8-4 8-10V5l-8-3-8 3v7c0 6 8 10 8 10z"/></svg>"#;
+    let icon_calendar = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><rect x="3" y="4" width="18" height="18" rx="2" ry="2"/><line x1="16" y1="2" x2="16" y2="6"/><line x1="8" y1="2" x2="8" y2="6"/><line x1="3" y1="10" x2="21" y2="10"/></svg>"#;
+    let icon_check = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><polyline points="20 6 9 17 4 12"/></svg>"#;
+
+    view! {
+        style {
+            r#"
+            .payment-runs { display: flex; flex-direction: column; gap: var(--space-6); }
+            .payment-prepare {
+                display: grid;
+                grid-template-columns: 220px 1fr auto;
+                gap: 16px;
+                align-items: end;
+            }
+            .payment-preview { font-size: 13px; color: var(--text-muted); padding-bottom: 10px; }
+            .payment-preview strong { color: var(--text); }
+            .payment-excluded { margin-top: 12px; }
+            .payment-excluded-item {
+                display: flex;
+                justify-content: space-between;
+                gap: 12px;
+                padding: 8px 0;
+                font-size: 13px;
+                border-bottom: 1px solid var(--border);
+            }
+            .payment-excluded-item:last-child { border-bottom: none; }
+            .payment-excluded-item .reason { font-size: 12px; color: var(--red); }
+            @media (max-width: 1024px) {
+                .payment-prepare { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="payment-runs" data-testid="payment-runs">
+            {page_header(
+                t("Payment Runs").to_string(),
+                Some(t("Batch approved invoices, authorise runs and release bank payment files").to_string()),
+                vec![]
+            )}
+
+            if let Some(err) = store.error.get() {
+                {notice_bar(err, NoticeType::Error, None)}
+            }
+
+            <div class="kpi-grid">
+                {kpi_card(
+                    t("Approved, Not on a Run").to_string(),
+                    format_currency(unscheduled),
+                    KpiColor::Blue,
+                    icon_wallet.to_string(),
+                    None,
+                    None
+                )}
+                {kpi_card(
+                    t("Awaiting Authorisation").to_string(),
+                    awaiting.len().to_string(),
+                    if awaiting.is_empty() { KpiColor::Green } else { KpiColor::Orange },
+                    icon_shield.to_string(),
+                    None,
+                    None
+                )}
+                {kpi_card(
+                    t("Scheduled").to_string(),
+                    format_currency(scheduled),
+                    KpiColor::Purple,
+                    icon_calendar.to_string(),
+                    None,
+                    None
+                )}
+                {kpi_card(
+                    t("Paid This Year").to_string(),
+                    format_currency(paid_this_year),
+                    KpiColor::Green,
+                    icon_check.to_string(),
+                    Some(KpiDelta { value: year.label(), is_positive: None, suffix: String::new() }),
+                    None
+                )}
+            </div>
+
+            if can_prepare {
+                {panel(
+                    t("Prepare Payment Run").to_string(),
+                    vec![],
+                    vec![view! {
+                        <div data-testid="payment-prepare">
+                            <div class="payment-prepare">
+                                {date_picker(t("Payment Date").to_string(), payment_date.clone(), true, false, Some(today.to_iso()), None, None)}
+                                <div class="payment-preview">
+                                    if let Some((payments, excluded)) = preview.clone() {
+                                        <strong>{format!(
+                                            "{} {} · {} {}",
+                                            payments.iter().map(|p| p.lines.len()).sum::<usize>(),
+                                            t("invoices"),
+                                            payments.len(),
+                                            t("suppliers")
+                                        )}</strong>
+                                        {format!(
+                                            " · {} · {} {}",
+                                            format_currency(payments.iter().map(|p| p.total()).sum::<Money>()),
+                                            excluded.len(),
+                                            t("excluded")
+                                        )}
+                                    }
+                                </div>
+                                <button class="btn btn-primary" disabled={store.saving.get()} data-testid="payment-prepare-run" on:click={handle_prepare}>
+                                    {t("Prepare Run")}
+                                </button>
+                            </div>
+                            if let Some((_, excluded)) = preview.clone() {
+                                {excluded_list(excluded)}
+                            }
+                        </div>
+                    }]
+                )}
+            }
+
+            {panel(
+                t("Runs").to_string(),
+                vec![],
+                vec![if rows.is_empty() {
+                    empty_state(t("No payment runs yet").to_string(), None, None, None)
+                } else {
+                    data_table_with_testid(columns, rows, Some(on_row_click), Some("payment-runs-table".to_string()), None)
+                }]
+            )}
+
+            {panel(
+                format!("{} {}", t("Payment Report"), year.label()),
+                if report.is_empty() {
+                    vec![]
+                } else {
+                    vec![view! {
+                        <a class="btn btn-secondary btn-sm" href={report_file.data_url()} download={report_file.file_name.clone()}>
+                            {t("Download CSV")}
+                        </a>
+                    }]
+                },
+                vec![view! {
+                    <div data-testid="payment-report">
+                        if report_rows.is_empty() {
+                            {empty_state(t("Nothing paid this financial year").to_string(), None, None, None)}
+                        } else {
+                            {data_table_with_testid(report_columns, report_rows, None, None, None)}
+                        }
+                    </div>
+                }]
+            )}
+        </div>
+    }
+}
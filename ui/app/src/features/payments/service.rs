@@ -0,0 +1,535 @@
+//! Payment run service
+//!
+//! A run collects the approved invoices due on or before its payment date,
+//! oldest due date first so overdue invoices are paid before the rest (SRS
+//! BR-05), and groups them into one credit transfer per supplier. Invoices
+//! of suspended or blacklisted suppliers, or of suppliers whose bank details
+//! are missing or malformed, are listed on the run as excluded instead.
+//! Two authorisers other than the preparer must authorise a run before its
+//! bank file can be generated; its invoices are then scheduled, and marked
+//! paid once the bank has processed the file.
+
+use std::collections::HashMap;
+use crate::features::audit::{self, AuditActionType, AuditEntityType, AuditEntry, FieldChange};
+use crate::features::invoices::service as invoices;
+use crate::features::invoices::store::InvoicesStore;
+use crate::features::invoices::types::{Invoice, InvoiceStatus};
+use crate::features::suppliers::service as suppliers;
+use crate::features::suppliers::store::SuppliersStore;
+use crate::features::suppliers::types::{Supplier, SupplierStatus};
+use crate::util::api::{self, HttpMethod};
+use crate::util::auth;
+use crate::util::backend;
+use crate::util::datetime::{is_business_day, now_iso, today, Date};
+use crate::util::format::{format_currency_full, format_date};
+use crate::util::permissions::{self, Action};
+use crate::util::reference::{self, DocumentType};
+use super::files;
+use super::store::{PaymentsStore, load_mock_runs};
+use super::types::{
+    Authorisation, ExcludedInvoice, ExclusionReason, PaymentFile, PaymentFileFormat, PaymentLine,
+    PaymentRun, PaymentRunStatus, SupplierPayment, REQUIRED_AUTHORISATIONS,
+};
+
+/// Registers a payment run reads and updates
+#[derive(Clone)]
+pub struct PaymentStores {
+    pub invoices: InvoicesStore,
+    pub suppliers: SuppliersStore,
+}
+
+/// Load payment runs and the account they are paid from
+pub async fn load_runs(store: &PaymentsStore) {
+    store.loading.set(true);
+    store.error.set(None);
+
+    if backend::is_mock() {
+        if store.runs.get().is_empty() {
+            load_mock_runs(store);
+        }
+    } else {
+        let loaded = async {
+            backend::fetch_with_snapshot("/payment-runs", &store.runs).await?;
+            backend::fetch_with_snapshot("/settings/payer-account", &store.payer).await
+        };
+        if let Err(e) = loaded.await {
+            store.error.set(Some(e.to_string()));
+        }
+    }
+
+    store.loading.set(false);
+}
+
+/// Load a single payment run by ID
+pub async fn load_run(store: &PaymentsStore, id: &str) {
+    let id = reference::resolve(id);
+    load_runs(store).await;
+
+    let loaded = if backend::is_mock() {
+        find_run(store, &id)
+    } else {
+        api::get::<PaymentRun>(&format!("/payment-runs/{}", id)).await.map_err(|e| e.to_string())
+    };
+    match loaded {
+        Ok(run) => store.selected.set(Some(run)),
+        Err(e) => {
+            store.selected.set(None);
+            store.error.set(Some(e));
+        }
+    }
+}
+
+async fn load_registers(stores: &PaymentStores) {
+    if stores.invoices.invoices.get().is_empty() {
+        invoices::load_invoices(&stores.invoices).await;
+    }
+    if stores.suppliers.suppliers.get().is_empty() {
+        suppliers::load_suppliers(&stores.suppliers).await;
+    }
+}
+
+/// South African bank account numbers run to 11 digits
+pub fn is_valid_account_number(account_number: &str) -> bool {
+    (6..=11).contains(&account_number.len()) && account_number.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Universal and branch codes are six digits
+pub fn is_valid_branch_code(branch_code: &str) -> bool {
+    branch_code.len() == 6 && branch_code.chars().all(|c| c.is_ascii_digit())
+}
+
+/// The supplier's registry record if it may be paid, or why it may not
+fn payable(supplier: Option<&Supplier>) -> Result<&Supplier, ExclusionReason> {
+    let supplier = supplier.ok_or(ExclusionReason::SupplierNotRegistered)?;
+    match supplier.status {
+        SupplierStatus::Suspended => return Err(ExclusionReason::SupplierSuspended),
+        SupplierStatus::Blacklisted => return Err(ExclusionReason::SupplierBlacklisted),
+        _ => {}
+    }
+    match (&supplier.bank_account_number, &supplier.bank_branch_code) {
+        (Some(account), Some(branch)) if !account.trim().is_empty() && !branch.trim().is_empty() => {
+            if is_valid_account_number(account.trim()) && is_valid_branch_code(branch.trim()) {
+                Ok(supplier)
+            } else {
+                Err(ExclusionReason::InvalidBankDetails)
+            }
+        }
+        _ => Err(ExclusionReason::MissingBankDetails),
+    }
+}
+
+/// Split the approved invoices due by `payment_date` into one payment per
+/// supplier and the invoices that cannot be paid. `on_runs` maps invoices
+/// already held by an open run to that run.
+pub fn select_invoices(
+    register: &[Invoice],
+    registry: &[Supplier],
+    on_runs: &HashMap<String, String>,
+    payment_date: Date,
+    run_id: &str,
+) -> (Vec<SupplierPayment>, Vec<ExcludedInvoice>) {
+    let mut due: Vec<&Invoice> = register
+        .iter()
+        .filter(|i| i.status == InvoiceStatus::Approved)
+        .filter(|i| Date::parse(&i.due_date).is_some_and(|d| d <= payment_date))
+        .collect();
+    due.sort_by(|a, b| a.due_date.cmp(&b.due_date).then_with(|| a.id.cmp(&b.id)));
+
+    let mut payments: Vec<SupplierPayment> = Vec::new();
+    let mut excluded = Vec::new();
+    for invoice in due {
+        let eligible = match on_runs.get(&invoice.id) {
+            Some(run) => Err(ExclusionReason::OnRun(run.clone())),
+            None => payable(registry.iter().find(|s| s.id == invoice.supplier_id)),
+        };
+        let supplier = match eligible {
+            Ok(supplier) => supplier,
+            Err(reason) => {
+                excluded.push(ExcludedInvoice {
+                    invoice_id: invoice.id.clone(),
+                    invoice_number: invoice.invoice_number.clone(),
+                    supplier_name: invoice.supplier_name.clone(),
+                    due_date: invoice.due_date.clone(),
+                    amount: invoice.total_amount,
+                    reason,
+                });
+                continue;
+            }
+        };
+
+        let line = PaymentLine {
+            invoice_id: invoice.id.clone(),
+            invoice_number: invoice.invoice_number.clone(),
+            po_number: invoice.po_number.clone(),
+            invoice_date: invoice.invoice_date.clone(),
+            due_date: invoice.due_date.clone(),
+            amount: invoice.total_amount,
+        };
+        match payments.iter_mut().find(|p| p.supplier_id == supplier.id) {
+            Some(payment) => payment.lines.push(line),
+            None => payments.push(SupplierPayment {
+                supplier_id: supplier.id.clone(),
+                supplier_name: invoice.supplier_name.clone(),
+                bank_name: supplier.bank_name.clone().unwrap_or_default(),
+                account_number: supplier.bank_account_number.clone().unwrap_or_default().trim().to_string(),
+                branch_code: supplier.bank_branch_code.clone().unwrap_or_default().trim().to_string(),
+                reference: String::new(),
+                lines: vec![line],
+            }),
+        }
+    }
+
+    for payment in payments.iter_mut() {
+        payment.reference = match payment.lines.as_slice() {
+            [only] => format!("{} {}", run_id, only.invoice_number),
+            lines => format!("{} {} INVOICES", run_id, lines.len()),
+        };
+    }
+    payments.sort_by(|a, b| b.total().cmp(&a.total()));
+    (payments, excluded)
+}
+
+fn find_run(store: &PaymentsStore, id: &str) -> Result<PaymentRun, String> {
+    store
+        .runs
+        .get()
+        .into_iter()
+        .find(|r| r.id == id)
+        .ok_or_else(|| format!("Payment run {} not found", id))
+}
+
+/// Replace a run in the list and the selection
+fn put_run(store: &PaymentsStore, run: &PaymentRun) {
+    let mut runs = store.runs.get();
+    match runs.iter_mut().find(|r| r.id == run.id) {
+        Some(existing) => *existing = run.clone(),
+        None => runs.insert(0, run.clone()),
+    }
+    store.runs.set(runs);
+    if store.selected.get().map_or(false, |s| s.id == run.id) {
+        store.selected.set(Some(run.clone()));
+    }
+}
+
+/// Name and user id of the signed-in user
+fn current_user() -> (String, String) {
+    auth::current().user.map(|u| (u.name, u.id)).unwrap_or_default()
+}
+
+fn record(run: &PaymentRun, action: AuditActionType, changes: Vec<FieldChange>, description: String) {
+    audit::service::record(AuditEntry {
+        action,
+        entity_type: AuditEntityType::PaymentRun,
+        entity_id: run.id.clone(),
+        entity_name: Some(format!("{} {}", run.id, format_date(&run.payment_date))),
+        changes,
+        description: Some(description),
+        ..Default::default()
+    });
+}
+
+/// Select the invoices due by `payment_date` into a new run (SRS FR-3.4.1)
+pub async fn prepare_run(store: &PaymentsStore, stores: &PaymentStores, payment_date: &str) -> Result<PaymentRun, String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::PreparePaymentRun)?;
+        let date = Date::parse(payment_date).ok_or("Choose a payment date")?;
+        if date < today() {
+            return Err("The payment date cannot be in the past".to_string());
+        }
+        if !is_business_day(date) {
+            return Err(format!("{} is not a business day; banks only process payments on business days", format_date(payment_date)));
+        }
+        if store.runs.get().is_empty() {
+            load_runs(store).await;
+        }
+        load_registers(stores).await;
+
+        let existing = store.runs.get();
+        let id = reference::issue(DocumentType::PaymentRun, existing.iter().map(|r| r.id.as_str())).number;
+        let (payments, excluded) = select_invoices(
+            &stores.invoices.invoices.get(),
+            &stores.suppliers.suppliers.get(),
+            &store.open_invoices(),
+            date,
+            &id,
+        );
+        if payments.is_empty() {
+            return Err(match excluded.len() {
+                0 => format!("No approved invoices are due by {}", format_date(payment_date)),
+                n => format!("None of the {} invoices due by {} can be paid; see the reasons listed", n, format_date(payment_date)),
+            });
+        }
+
+        let (created_by, created_by_id) = current_user();
+        let run = PaymentRun {
+            id,
+            payment_date: date.to_iso(),
+            status: PaymentRunStatus::Prepared,
+            payments,
+            excluded,
+            created_by,
+            created_by_id,
+            created_at: now_iso(),
+            ..Default::default()
+        };
+        let saved = backend::save(HttpMethod::Post, "/payment-runs", &run)
+            .await
+            .map_err(|e| e.to_string())?;
+        reference::reconcile(DocumentType::PaymentRun, &run.id, &saved.id);
+        record(&saved, AuditActionType::Create, Vec::new(), format!(
+            "Prepared payment of {} invoices to {} suppliers, {}; {} due invoices excluded",
+            saved.invoice_count(),
+            saved.payments.len(),
+            format_currency_full(saved.total()),
+            saved.excluded.len()
+        ));
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    match &result {
+        Ok(saved) => {
+            put_run(store, saved);
+            invoices::apply_payment_run(&stores.invoices, &saved.invoice_ids(), Some(&saved.id), InvoiceStatus::Approved, None);
+        }
+        Err(e) => store.error.set(Some(e.clone())),
+    }
+    store.saving.set(false);
+    result
+}
+
+/// Payments whose supplier was suspended, or whose bank details changed,
+/// after the run was prepared
+fn changed_since_prepared(run: &PaymentRun, registry: &[Supplier]) -> Vec<String> {
+    run.payments
+        .iter()
+        .filter(|p| {
+            match payable(registry.iter().find(|s| s.id == p.supplier_id)) {
+                Ok(supplier) => {
+                    supplier.bank_account_number.as_deref().map(str::trim) != Some(p.account_number.as_str())
+                        || supplier.bank_branch_code.as_deref().map(str::trim) != Some(p.branch_code.as_str())
+                }
+                Err(_) => true,
+            }
+        })
+        .map(|p| p.supplier_name.clone())
+        .collect()
+}
+
+/// Authorise a run. The second authorisation, by someone other than the
+/// first authoriser and the preparer, releases it for payment.
+pub async fn authorise_run(store: &PaymentsStore, stores: &PaymentStores, id: &str) -> Result<(), String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::AuthorisePaymentRun)?;
+        let mut run = find_run(store, id)?;
+        if !run.status.awaits_authorisation() {
+            return Err(format!("{} is {} and cannot be authorised", run.id, run.status.as_str().to_lowercase()));
+        }
+        let (by, by_id) = current_user();
+        if run.prepared_by(&by_id) {
+            return Err("Payment runs must be authorised by someone other than the person who prepared them".to_string());
+        }
+        if run.authorised_by(&by_id) {
+            return Err(format!("You have already authorised {}; a second authoriser is needed", run.id));
+        }
+        load_registers(stores).await;
+        let changed = changed_since_prepared(&run, &stores.suppliers.suppliers.get());
+        if !changed.is_empty() {
+            return Err(format!(
+                "The status or bank details of {} changed after {} was prepared; cancel the run and prepare it again",
+                changed.join(", "),
+                run.id
+            ));
+        }
+
+        let previous = run.status;
+        run.authorisations.push(Authorisation { by, by_id, at: now_iso() });
+        run.status = if run.authorisations_outstanding() == 0 {
+            PaymentRunStatus::Authorised
+        } else {
+            PaymentRunStatus::PartiallyAuthorised
+        };
+
+        let endpoint = format!("/payment-runs/{}/authorise", run.id);
+        let saved = backend::save(HttpMethod::Post, &endpoint, &run)
+            .await
+            .map_err(|e| e.to_string())?;
+        record(
+            &saved,
+            AuditActionType::Approve,
+            vec![FieldChange::new("status", Some(previous.as_str()), Some(saved.status.as_str()))],
+            format!(
+                "Authorisation {} of {} for {}",
+                saved.authorisations.len(),
+                REQUIRED_AUTHORISATIONS,
+                format_currency_full(saved.total())
+            ),
+        );
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    match &result {
+        Ok(saved) => {
+            put_run(store, saved);
+            if saved.status == PaymentRunStatus::Authorised {
+                invoices::apply_payment_run(&stores.invoices, &saved.invoice_ids(), Some(&saved.id), InvoiceStatus::Scheduled, None);
+            }
+        }
+        Err(e) => store.error.set(Some(e.clone())),
+    }
+    store.saving.set(false);
+    result.map(|_| ())
+}
+
+/// Generate the bank file of an authorised run (SRS FR-3.4.3)
+pub async fn generate_payment_file(store: &PaymentsStore, id: &str, format: PaymentFileFormat) -> Result<PaymentFile, String> {
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::PreparePaymentRun)?;
+        let mut run = find_run(store, id)?;
+        if !matches!(run.status, PaymentRunStatus::Authorised | PaymentRunStatus::Paid) {
+            return Err(format!(
+                "{} needs {} more authorisation(s) before its bank file can be generated",
+                run.id,
+                run.authorisations_outstanding()
+            ));
+        }
+        let file = files::payment_file(&run, &store.payer.get(), format)?;
+        if !run.files_generated.contains(&format) {
+            run.files_generated.push(format);
+            let endpoint = format!("/payment-runs/{}", run.id);
+            run = backend::save(HttpMethod::Put, &endpoint, &run)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        record(&run, AuditActionType::Export, Vec::new(), format!(
+            "Generated {} bank file {}: {} payments, {}",
+            format.label(),
+            file.file_name,
+            run.payments.len(),
+            format_currency_full(run.total())
+        ));
+        Ok::<_, String>((run, file))
+    }
+    .await;
+
+    match result {
+        Ok((run, file)) => {
+            put_run(store, &run);
+            Ok(file)
+        }
+        Err(e) => {
+            store.error.set(Some(e.clone()));
+            Err(e)
+        }
+    }
+}
+
+/// Record that the bank has processed the run's file; its invoices are paid
+pub async fn confirm_paid(store: &PaymentsStore, stores: &PaymentStores, id: &str) -> Result<(), String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::PreparePaymentRun)?;
+        let mut run = find_run(store, id)?;
+        if run.status != PaymentRunStatus::Authorised {
+            return Err(format!("Only authorised runs can be confirmed as paid; {} is {}", run.id, run.status.as_str().to_lowercase()));
+        }
+        if run.files_generated.is_empty() {
+            return Err("Generate the bank file and submit it to the bank before confirming payment".to_string());
+        }
+        run.status = PaymentRunStatus::Paid;
+        run.paid_by = Some(current_user().0);
+        run.paid_at = Some(now_iso());
+
+        let endpoint = format!("/payment-runs/{}/paid", run.id);
+        let saved = backend::save(HttpMethod::Post, &endpoint, &run)
+            .await
+            .map_err(|e| e.to_string())?;
+        record(
+            &saved,
+            AuditActionType::Update,
+            vec![FieldChange::new("status", Some(PaymentRunStatus::Authorised.as_str()), Some(saved.status.as_str()))],
+            format!(
+                "Confirmed payment of {} invoices to {} suppliers, {}",
+                saved.invoice_count(),
+                saved.payments.len(),
+                format_currency_full(saved.total())
+            ),
+        );
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    match &result {
+        Ok(saved) => {
+            put_run(store, saved);
+            invoices::apply_payment_run(&stores.invoices, &saved.invoice_ids(), Some(&saved.id), InvoiceStatus::Paid, Some(&saved.payment_date));
+        }
+        Err(e) => store.error.set(Some(e.clone())),
+    }
+    store.saving.set(false);
+    result.map(|_| ())
+}
+
+/// Cancel a run that has not been sent to the bank, releasing its invoices
+/// for another run
+pub async fn cancel_run(store: &PaymentsStore, stores: &PaymentStores, id: &str, reason: &str) -> Result<(), String> {
+    store.saving.set(true);
+    store.error.set(None);
+
+    let result = async {
+        if !permissions::can_perform(&auth::current(), Action::PreparePaymentRun) {
+            permissions::require(Action::AuthorisePaymentRun)?;
+        }
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err("Give a reason for cancelling the run".to_string());
+        }
+        let mut run = find_run(store, id)?;
+        if !run.status.is_open() {
+            return Err(format!("{} is {} and cannot be cancelled", run.id, run.status.as_str().to_lowercase()));
+        }
+        if !run.files_generated.is_empty() {
+            return Err(format!(
+                "The bank file for {} has been generated; recall it at the bank or confirm payment",
+                run.id
+            ));
+        }
+        let previous = run.status;
+        run.status = PaymentRunStatus::Cancelled;
+        run.cancelled_reason = Some(reason.to_string());
+
+        let endpoint = format!("/payment-runs/{}/cancel", run.id);
+        let saved = backend::save(HttpMethod::Post, &endpoint, &run)
+            .await
+            .map_err(|e| e.to_string())?;
+        record(
+            &saved,
+            AuditActionType::Cancel,
+            vec![FieldChange::new("status", Some(previous.as_str()), Some(saved.status.as_str()))],
+            format!("Cancelled: {}", reason),
+        );
+        Ok::<_, String>(saved)
+    }
+    .await;
+
+    match &result {
+        Ok(saved) => {
+            put_run(store, saved);
+            invoices::apply_payment_run(&stores.invoices, &saved.invoice_ids(), None, InvoiceStatus::Approved, None);
+        }
+        Err(e) => store.error.set(Some(e.clone())),
+    }
+    store.saving.set(false);
+    result.map(|_| ())
+}
@@ -0,0 +1,155 @@
+//! Payment run store and mock runs
+
+use std::collections::HashMap;
+use components::prelude::*;
+use crate::util::datetime::{Date, FiscalYear};
+use crate::util::money::Money;
+use super::types::{
+    Authorisation, ExcludedInvoice, ExclusionReason, PayerAccount, PaymentFileFormat, PaymentLine,
+    PaymentRun, PaymentRunStatus, SupplierPayment, SupplierPaymentTotal,
+};
+
+/// Payment run state store
+#[derive(Clone)]
+pub struct PaymentsStore {
+    pub runs: Signal<Vec<PaymentRun>>,
+    pub selected: Signal<Option<PaymentRun>>,
+    pub payer: Signal<PayerAccount>,
+    pub loading: Signal<bool>,
+    pub saving: Signal<bool>,
+    pub error: Signal<Option<String>>,
+}
+
+impl PaymentsStore {
+    pub fn new() -> Self {
+        Self {
+            runs: signal(Vec::new()),
+            selected: signal(None),
+            payer: signal(PayerAccount::default()),
+            loading: signal(false),
+            saving: signal(false),
+            error: signal(None),
+        }
+    }
+
+    /// Runs still holding their invoices, by invoice ID
+    pub fn open_invoices(&self) -> HashMap<String, String> {
+        self.runs
+            .get()
+            .iter()
+            .filter(|r| r.status.is_open())
+            .flat_map(|r| r.invoice_ids().into_iter().map(move |id| (id, r.id.clone())))
+            .collect()
+    }
+
+    /// Runs waiting for an authorisation
+    pub fn awaiting_authorisation(&self) -> Vec<PaymentRun> {
+        self.runs.get().into_iter().filter(|r| r.status.awaits_authorisation()).collect()
+    }
+
+    /// Paid runs per supplier in a financial year, largest total first
+    pub fn payment_report(&self, year: FiscalYear) -> Vec<SupplierPaymentTotal> {
+        let mut totals: HashMap<String, SupplierPaymentTotal> = HashMap::new();
+        for run in self.runs.get().iter().filter(|r| r.status == PaymentRunStatus::Paid) {
+            if !Date::parse(&run.payment_date).is_some_and(|d| year.contains(d)) {
+                continue;
+            }
+            for payment in &run.payments {
+                let total = totals.entry(payment.supplier_id.clone()).or_insert_with(|| SupplierPaymentTotal {
+                    supplier_id: payment.supplier_id.clone(),
+                    supplier_name: payment.supplier_name.clone(),
+                    ..Default::default()
+                });
+                total.runs += 1;
+                total.invoices += payment.lines.len();
+                total.amount += payment.total();
+                if run.payment_date > total.last_paid {
+                    total.last_paid = run.payment_date.clone();
+                }
+            }
+        }
+        let mut report: Vec<SupplierPaymentTotal> = totals.into_values().collect();
+        report.sort_by(|a, b| b.amount.cmp(&a.amount).then_with(|| a.supplier_name.cmp(&b.supplier_name)));
+        report
+    }
+}
+
+fn authorisation(by: &str, by_id: &str, at: &str) -> Authorisation {
+    Authorisation { by: by.to_string(), by_id: by_id.to_string(), at: at.to_string() }
+}
+
+/// Load mock payment runs for development
+pub fn load_mock_runs(store: &PaymentsStore) {
+    let paid = PaymentRun {
+        id: "PAY-2025-0001".to_string(),
+        payment_date: "2025-02-20".to_string(),
+        status: PaymentRunStatus::Paid,
+        payments: vec![SupplierPayment {
+            supplier_id: "SUP-005".to_string(),
+            supplier_name: "Gourmet Corporate Catering".to_string(),
+            bank_name: "Investec".to_string(),
+            account_number: "10003422345".to_string(),
+            branch_code: "580105".to_string(),
+            reference: "PAY-2025-0001 GCC-2025-0117".to_string(),
+            lines: vec![PaymentLine {
+                invoice_id: "INV-2025-0113".to_string(),
+                invoice_number: "GCC-2025-0117".to_string(),
+                po_number: "PO-2025-0452".to_string(),
+                invoice_date: "2025-02-03".to_string(),
+                due_date: "2025-03-07".to_string(),
+                amount: Money::rands(44_850),
+            }],
+        }],
+        excluded: Vec::new(),
+        created_by: "Nomvula Khumalo".to_string(),
+        created_by_id: "U021".to_string(),
+        created_at: "2025-02-17T09:10:00Z".to_string(),
+        authorisations: vec![
+            authorisation("Sipho Dlamini", "U022", "2025-02-17T14:32:00Z"),
+            authorisation("Lindiwe Nkosi", "U024", "2025-02-18T08:05:00Z"),
+        ],
+        files_generated: vec![PaymentFileFormat::AcbEft],
+        paid_by: Some("Nomvula Khumalo".to_string()),
+        paid_at: Some("2025-02-20T15:40:00Z".to_string()),
+        cancelled_reason: None,
+    };
+
+    // Waiting for its second authorisation; the overdue cleaning invoice
+    // goes out first (SRS BR-05)
+    let pending = PaymentRun {
+        id: "PAY-2025-0002".to_string(),
+        payment_date: "2025-02-28".to_string(),
+        status: PaymentRunStatus::PartiallyAuthorised,
+        payments: vec![SupplierPayment {
+            supplier_id: "SUP-008".to_string(),
+            supplier_name: "CleanCorp Services".to_string(),
+            bank_name: "ABSA".to_string(),
+            account_number: "40728814567".to_string(),
+            branch_code: "632005".to_string(),
+            reference: "PAY-2025-0002 CCS/1224/0871".to_string(),
+            lines: vec![PaymentLine {
+                invoice_id: "INV-2025-0098".to_string(),
+                invoice_number: "CCS/1224/0871".to_string(),
+                po_number: "PO-2025-0449".to_string(),
+                invoice_date: "2024-12-06".to_string(),
+                due_date: "2025-01-09".to_string(),
+                amount: Money::rands(58_650),
+            }],
+        }],
+        excluded: vec![ExcludedInvoice {
+            invoice_id: "INV-2025-0109".to_string(),
+            invoice_number: "RVT-0042".to_string(),
+            supplier_name: "Risky Ventures Trading".to_string(),
+            due_date: "2025-02-21".to_string(),
+            amount: Money::rands(20_125),
+            reason: ExclusionReason::SupplierSuspended,
+        }],
+        created_by: "Nomvula Khumalo".to_string(),
+        created_by_id: "U021".to_string(),
+        created_at: "2025-02-26T10:20:00Z".to_string(),
+        authorisations: vec![authorisation("Sipho Dlamini", "U022", "2025-02-26T15:02:00Z")],
+        ..Default::default()
+    };
+
+    store.runs.set(vec![pending, paid]);
+}
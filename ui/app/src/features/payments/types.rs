@@ -0,0 +1,285 @@
+//! Payment run domain types
+
+use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::Money;
+
+/// Distinct authorisers a run needs before its bank file can be released
+pub const REQUIRED_AUTHORISATIONS: usize = 2;
+
+/// Payment run status
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentRunStatus {
+    /// Invoices selected, waiting for the first authorisation
+    #[default]
+    Prepared,
+    /// One of the two authorisations given
+    PartiallyAuthorised,
+    /// Both authorisations given; invoices are scheduled for payment
+    Authorised,
+    /// The bank has processed the file
+    Paid,
+    Cancelled,
+}
+
+impl PaymentRunStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentRunStatus::Prepared => "Prepared",
+            PaymentRunStatus::PartiallyAuthorised => "Partially Authorised",
+            PaymentRunStatus::Authorised => "Authorised",
+            PaymentRunStatus::Paid => "Paid",
+            PaymentRunStatus::Cancelled => "Cancelled",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "prepared" => PaymentRunStatus::Prepared,
+            "partially_authorised" | "partially authorised" => PaymentRunStatus::PartiallyAuthorised,
+            "authorised" => PaymentRunStatus::Authorised,
+            "paid" => PaymentRunStatus::Paid,
+            "cancelled" => PaymentRunStatus::Cancelled,
+            _ => PaymentRunStatus::Prepared,
+        }
+    }
+
+    /// Still holds its invoices; they cannot be put on another run
+    pub fn is_open(&self) -> bool {
+        !matches!(self, PaymentRunStatus::Paid | PaymentRunStatus::Cancelled)
+    }
+
+    /// Waiting for an authorisation
+    pub fn awaits_authorisation(&self) -> bool {
+        matches!(self, PaymentRunStatus::Prepared | PaymentRunStatus::PartiallyAuthorised)
+    }
+}
+
+/// Bank payment file layouts (SRS FR-3.4.3)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentFileFormat {
+    /// Fixed-width bulk EFT file modelled on, but shorter than, the ACB layout
+    AcbEft,
+    /// One row per beneficiary, for banking portals that import spreadsheets
+    Csv,
+}
+
+impl PaymentFileFormat {
+    pub const ALL: [PaymentFileFormat; 2] = [PaymentFileFormat::AcbEft, PaymentFileFormat::Csv];
+
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            PaymentFileFormat::AcbEft => "Bulk EFT (fixed width)",
+            PaymentFileFormat::Csv => "CSV",
+        })
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            PaymentFileFormat::AcbEft => "txt",
+            PaymentFileFormat::Csv => "csv",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            PaymentFileFormat::AcbEft => "text/plain",
+            PaymentFileFormat::Csv => "text/csv",
+        }
+    }
+}
+
+/// The organisation's account the run is paid from
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PayerAccount {
+    pub name: String,
+    /// Abbreviated name the bank shows on beneficiary statements
+    pub short_name: String,
+    pub bank_name: String,
+    pub account_number: String,
+    pub branch_code: String,
+    /// Code the bank issued for submitting EFT files
+    pub user_code: String,
+    /// Address remittance advices ask suppliers to query payments at
+    pub remittance_email: String,
+}
+
+impl Default for PayerAccount {
+    fn default() -> Self {
+        Self {
+            name: "South African Revenue Service".to_string(),
+            short_name: "SARS".to_string(),
+            bank_name: "ABSA".to_string(),
+            account_number: "40530067812".to_string(),
+            branch_code: "632005".to_string(),
+            user_code: "SR01".to_string(),
+            remittance_email: "creditors@sars.gov.za".to_string(),
+        }
+    }
+}
+
+/// Invoice settled by a payment
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PaymentLine {
+    pub invoice_id: String,
+    /// Number printed on the supplier's invoice
+    pub invoice_number: String,
+    pub po_number: String,
+    pub invoice_date: String,
+    pub due_date: String,
+    pub amount: Money,
+}
+
+/// One credit transfer: everything a run pays a supplier
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SupplierPayment {
+    pub supplier_id: String,
+    pub supplier_name: String,
+    pub bank_name: String,
+    pub account_number: String,
+    pub branch_code: String,
+    /// Reference on the supplier's bank statement
+    pub reference: String,
+    pub lines: Vec<PaymentLine>,
+}
+
+impl SupplierPayment {
+    pub fn total(&self) -> Money {
+        self.lines.iter().map(|l| l.amount).sum()
+    }
+}
+
+/// Why a due invoice was left off a run
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ExclusionReason {
+    SupplierSuspended,
+    SupplierBlacklisted,
+    SupplierNotRegistered,
+    MissingBankDetails,
+    InvalidBankDetails,
+    /// Already on another open run
+    OnRun(String),
+}
+
+impl ExclusionReason {
+    pub fn label(&self) -> String {
+        match self {
+            ExclusionReason::SupplierSuspended => t("Supplier suspended").to_string(),
+            ExclusionReason::SupplierBlacklisted => t("Supplier blacklisted").to_string(),
+            ExclusionReason::SupplierNotRegistered => t("Supplier not in the registry").to_string(),
+            ExclusionReason::MissingBankDetails => t("No bank details on record").to_string(),
+            ExclusionReason::InvalidBankDetails => t("Bank account or branch code invalid").to_string(),
+            ExclusionReason::OnRun(run) => format!("{} {}", t("Already on"), run),
+        }
+    }
+}
+
+/// Due invoice left off a run, and why
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExcludedInvoice {
+    pub invoice_id: String,
+    pub invoice_number: String,
+    pub supplier_name: String,
+    pub due_date: String,
+    pub amount: Money,
+    pub reason: ExclusionReason,
+}
+
+/// Authorisation given to a run
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Authorisation {
+    pub by: String,
+    /// User id of the authoriser, which dual control compares
+    #[serde(default)]
+    pub by_id: String,
+    pub at: String,
+}
+
+/// Bank file or remittance advice produced from a run
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaymentFile {
+    pub file_name: String,
+    pub mime_type: String,
+    pub content: String,
+}
+
+impl PaymentFile {
+    /// Link target that downloads the file without a round trip
+    pub fn data_url(&self) -> String {
+        format!(
+            "data:{};charset=utf-8,{}",
+            self.mime_type,
+            String::from(js_sys::encode_uri_component(&self.content))
+        )
+    }
+}
+
+/// Batch of approved invoices paid together on one date (SRS FR-3.4.1)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PaymentRun {
+    pub id: String,
+    pub payment_date: String,
+    pub status: PaymentRunStatus,
+    /// One payment per supplier, largest first
+    pub payments: Vec<SupplierPayment>,
+    /// Due invoices that could not be paid on this run
+    pub excluded: Vec<ExcludedInvoice>,
+    pub created_by: String,
+    /// User id of the preparer, who may not authorise the run
+    #[serde(default)]
+    pub created_by_id: String,
+    pub created_at: String,
+    pub authorisations: Vec<Authorisation>,
+    /// Formats of the bank files released so far
+    #[serde(default)]
+    pub files_generated: Vec<PaymentFileFormat>,
+    pub paid_by: Option<String>,
+    pub paid_at: Option<String>,
+    pub cancelled_reason: Option<String>,
+}
+
+impl PaymentRun {
+    pub fn total(&self) -> Money {
+        self.payments.iter().map(|p| p.total()).sum()
+    }
+
+    pub fn invoice_count(&self) -> usize {
+        self.payments.iter().map(|p| p.lines.len()).sum()
+    }
+
+    pub fn invoice_ids(&self) -> Vec<String> {
+        self.payments
+            .iter()
+            .flat_map(|p| p.lines.iter().map(|l| l.invoice_id.clone()))
+            .collect()
+    }
+
+    pub fn payment_for(&self, supplier_id: &str) -> Option<&SupplierPayment> {
+        self.payments.iter().find(|p| p.supplier_id == supplier_id)
+    }
+
+    /// Whether the user with `user_id` prepared the run
+    pub fn prepared_by(&self, user_id: &str) -> bool {
+        !user_id.is_empty() && self.created_by_id == user_id
+    }
+
+    /// Whether the user with `user_id` has already authorised the run
+    pub fn authorised_by(&self, user_id: &str) -> bool {
+        !user_id.is_empty() && self.authorisations.iter().any(|a| a.by_id == user_id)
+    }
+
+    pub fn authorisations_outstanding(&self) -> usize {
+        REQUIRED_AUTHORISATIONS.saturating_sub(self.authorisations.len())
+    }
+}
+
+/// Payments to one supplier over a period (SRS FR-3.6.3)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SupplierPaymentTotal {
+    pub supplier_id: String,
+    pub supplier_name: String,
+    pub runs: usize,
+    pub invoices: usize,
+    pub amount: Money,
+    pub last_paid: String,
+}
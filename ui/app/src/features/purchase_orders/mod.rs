@@ -3,5 +3,7 @@
 pub mod types;
 pub mod store;
 pub mod service;
+pub mod search;
 pub mod po_list;
 pub mod po_form;
+pub mod po_detail;
@@ -0,0 +1,273 @@
+//! Purchase Order detail view
+
+use components::prelude::*;
+use crate::Route;
+use crate::features::workflow::approval_timeline::{approval_timeline, approval_actions, approval_reassign};
+use crate::features::workflow::types::{ApprovalDecision, ApprovalReassignment};
+use crate::page::not_found::entity_not_found;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table, DataTableColumn, DataTableRow,
+    status_badge, StatusType,
+    progress_bar, ProgressColor,
+    notice_bar, NoticeType,
+};
+use crate::util::format::{format_currency, format_currency_full, format_date};
+use super::types::{PurchaseOrder, PurchaseOrderStatus};
+use super::store::PurchaseOrdersStore;
+use super::service;
+use crate::util::i18n::{t, tf};
+
+/// Purchase Order detail page
+#[component]
+pub fn po_detail(po_id: String) -> View {
+    let store = use_context::<PurchaseOrdersStore>();
+
+    // Load purchase order on mount
+    effect({
+        let store = store.clone();
+        let po_id = po_id.clone();
+        move || {
+            let store = store.clone();
+            let po_id = po_id.clone();
+            spawn(async move {
+                service::load_purchase_order(&store, &po_id).await;
+            });
+        }
+    });
+
+    let loading = store.loading.get();
+    let error = store.error.get();
+    let po = store.selected.get().filter(|po| po.id == po_id);
+
+    view! {
+        style {
+            r#"
+            .po-detail { display: flex; flex-direction: column; gap: var(--space-6); }
+            .po-detail-grid {
+                display: grid;
+                grid-template-columns: 2fr 1fr;
+                gap: 24px;
+            }
+            .po-detail .detail-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 8px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .po-detail .detail-row:last-child { border-bottom: none; }
+            .po-detail .detail-row .label { color: var(--text-muted); }
+            .po-detail .detail-row .value { font-weight: 500; text-align: right; }
+            .po-detail .detail-row.total .value { font-family: IBM Plex Mono, monospace; }
+            @media (max-width: 1024px) {
+                .po-detail-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="po-detail" data-testid="po-detail">
+            if loading {
+                <div class="loading-state">{t("Loading purchase order...")}</div>
+            } else if let Some(po) = po {
+                {render_po(po, store.clone())}
+            } else {
+                {entity_not_found(t("Purchase Order"), &po_id, error, Route::PurchaseOrdersList, t("Back to Purchase Orders"))}
+            }
+        </div>
+    }
+}
+
+fn render_po(po: PurchaseOrder, store: PurchaseOrdersStore) -> View {
+    let status_type = match po.status {
+        PurchaseOrderStatus::Draft => StatusType::Draft,
+        PurchaseOrderStatus::PendingApproval => StatusType::Pending,
+        PurchaseOrderStatus::Approved => StatusType::Approved,
+        PurchaseOrderStatus::Sent => StatusType::InProgress,
+        PurchaseOrderStatus::Acknowledged => StatusType::InProgress,
+        PurchaseOrderStatus::PartiallyDelivered => StatusType::InProgress,
+        PurchaseOrderStatus::Delivered => StatusType::Complete,
+        PurchaseOrderStatus::Invoiced => StatusType::Active,
+        PurchaseOrderStatus::Closed => StatusType::Complete,
+        PurchaseOrderStatus::Cancelled => StatusType::Cancelled,
+    };
+
+    let ordered: u32 = po.line_items.iter().map(|i| i.quantity).sum();
+    let delivered: u32 = po.line_items.iter().map(|i| i.delivered_quantity).sum();
+    let delivery_progress = if ordered > 0 {
+        delivered as f64 / ordered as f64 * 100.0
+    } else {
+        0.0
+    };
+
+    let columns = vec![
+        DataTableColumn { key: "code".to_string(), label: t("Item Code").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "description".to_string(), label: t("Description").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "quantity".to_string(), label: t("Qty").to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "delivered".to_string(), label: t("Delivered").to_string(), width: Some("90px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "unit_price".to_string(), label: t("Unit Price").to_string(), width: Some("120px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "total".to_string(), label: t("Total").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+    ];
+
+    let rows: Vec<DataTableRow> = po.line_items.iter().map(|item| {
+        DataTableRow {
+            id: item.id.clone(),
+            cells: vec![
+                view! { <span class="id-cell">{item.item_code.clone()}</span> },
+                view! { <span>{item.description.clone()}</span> },
+                view! { <span>{format!("{} {}", item.quantity, item.unit)}</span> },
+                view! { <span>{item.delivered_quantity.to_string()}</span> },
+                view! { <span>{format_currency_full(item.unit_price)}</span> },
+                view! { <span class="amount-cell">{format_currency_full(item.total_price)}</span> },
+            ],
+        }
+    }).collect();
+
+    let handle_decide = Callback::new({
+        let store = store.clone();
+        let id = po.id.clone();
+        move |decision: ApprovalDecision| {
+            let store = store.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::decide_purchase_order(&store, &id, &decision).await;
+            });
+        }
+    });
+
+    let handle_reassign = Callback::new({
+        let store = store.clone();
+        let id = po.id.clone();
+        move |reassignment: ApprovalReassignment| {
+            let store = store.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::reassign_purchase_order_step(&store, &id, &reassignment).await;
+            });
+        }
+    });
+
+    let address = &po.delivery_address;
+    let address_line = format!("{}, {}, {}", address.address_line1, address.city, address.province);
+
+    view! {
+        {page_header(
+            tf("Purchase Order {po_number}", &[("po_number", po.po_number.to_string())]),
+            Some(tf("{supplier} - ordered {date}", &[("supplier", po.supplier.name.clone()), ("date", format_date(&po.order_date))])),
+            vec![
+                view! { <a href="/purchase-orders" class="btn btn-secondary">{t("Back to Purchase Orders")}</a> },
+                if po.status == PurchaseOrderStatus::Draft {
+                    view! { <a href={format!("/purchase-orders/{}/edit", po.id)} class="btn btn-primary">{t("Edit")}</a> }
+                } else {
+                    view! { <span></span> }
+                },
+            ]
+        )}
+
+        <div class="po-detail-grid">
+            <div>
+                {panel(
+                    t("Line Items").to_string(),
+                    vec![],
+                    vec![data_table(columns, rows, None)]
+                )}
+
+                {panel(
+                    t("Delivery").to_string(),
+                    vec![],
+                    vec![
+                        view! {
+                            <div>
+                                {progress_bar(delivery_progress, ProgressColor::Green, true, None)}
+                                <div class="detail-row">
+                                    <span class="label">{t("Address")}</span>
+                                    <span class="value">{address_line}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Contact")}</span>
+                                    <span class="value">{address.contact_person.clone()}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Expected")}</span>
+                                    <span class="value">{format_date(&po.expected_delivery_date)}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Delivered")}</span>
+                                    <span class="value">{po.actual_delivery_date.as_ref().map(|d| format_date(d)).unwrap_or_else(|| "-".to_string())}</span>
+                                </div>
+                            </div>
+                        }
+                    ]
+                )}
+            </div>
+
+            <div>
+                {panel(
+                    t("Summary").to_string(),
+                    vec![],
+                    vec![
+                        view! {
+                            <div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Status")}</span>
+                                    <span class="value">{status_badge(status_type)}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Supplier")}</span>
+                                    <span class="value">{po.supplier.name.clone()}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Contract")}</span>
+                                    <span class="value">{po.contract_ref.clone().unwrap_or_else(|| "-".to_string())}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Requisition")}</span>
+                                    if let Some(requisition_ref) = po.requisition_ref.clone() {
+                                        <a class="value" href={Route::RequisitionsDetail(requisition_ref.clone()).to_path()}>{requisition_ref.clone()}</a>
+                                    } else {
+                                        <span class="value">"-"</span>
+                                    }
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Cost Centre")}</span>
+                                    <span class="value">{if po.cost_center.is_empty() { "-".to_string() } else { po.cost_center.clone() }}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Payment Terms")}</span>
+                                    <span class="value">{po.payment_terms.clone()}</span>
+                                </div>
+                                <div class="detail-row total">
+                                    <span class="label">{t("Subtotal")}</span>
+                                    <span class="value">{format_currency_full(po.subtotal)}</span>
+                                </div>
+                                <div class="detail-row total">
+                                    <span class="label">{t("VAT")}</span>
+                                    <span class="value">{format_currency_full(po.tax_total)}</span>
+                                </div>
+                                <div class="detail-row total">
+                                    <span class="label">{t("Total")}</span>
+                                    <span class="value">{format_currency(po.total_amount)}</span>
+                                </div>
+                            </div>
+                        }
+                    ]
+                )}
+
+                {panel(
+                    t("Approval Workflow").to_string(),
+                    vec![],
+                    vec![
+                        approval_timeline(po.approval_workflow.clone()),
+                        approval_actions(po.approval_workflow.clone(), po.created_by_id.clone(), store.saving.get(), handle_decide),
+                        approval_reassign(po.approval_workflow.clone(), store.saving.get(), handle_reassign),
+                        if let Some(err) = store.error.get() {
+                            notice_bar(err, NoticeType::Error, None)
+                        } else {
+                            view! { <span></span> }
+                        },
+                    ]
+                )}
+            </div>
+        </div>
+    }
+}
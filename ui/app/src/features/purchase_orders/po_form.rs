@@ -1,6 +1,8 @@
 //! Purchase Order create/edit form
 
 use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
 use crate::shared::layout::page_header;
 use crate::shared::components::{
     panel, panel_with_footer,
@@ -11,11 +13,14 @@ use crate::shared::forms::{
     text_input, textarea, select, SelectOption, date_picker, currency_input,
 };
 use crate::util::format::format_currency;
+use crate::util::money::Money;
+use crate::util::api::field_error;
 use super::types::{
     PurchaseOrder, PurchaseOrderStatus, LineItem, DeliveryAddress, Supplier,
 };
 use super::store::PurchaseOrdersStore;
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Purchase Order form page (create/edit)
 #[component]
@@ -34,6 +39,7 @@ pub fn po_form(po_id: Option<String>) -> View {
     let supplier_phone = signal(String::new());
     let contract_ref = signal(String::new());
     let requisition_ref = signal(String::new());
+    let cost_center = signal(String::new());
 
     // Line Items (Step 2)
     let line_items: Signal<Vec<LineItem>> = signal(Vec::new());
@@ -58,6 +64,12 @@ pub fn po_form(po_id: Option<String>) -> View {
     // Form state
     let form_error = signal::<Option<String>>(None);
     let saving = store.saving.clone();
+    // Set when the purchase order being edited cannot be found
+    let missing = signal(false);
+
+    // Validation errors from the last save, keyed by field
+    let field_errors = store.field_errors.clone();
+    let field_err = |field: &str| field_error(&field_errors.get(), field);
 
     // Load existing PO if editing
     if let Some(id) = &po_id {
@@ -70,6 +82,7 @@ pub fn po_form(po_id: Option<String>) -> View {
             let supplier_email = supplier_email.clone();
             let supplier_phone = supplier_phone.clone();
             let contract_ref = contract_ref.clone();
+            let cost_center = cost_center.clone();
             let line_items = line_items.clone();
             let address_line1 = address_line1.clone();
             let city = city.clone();
@@ -82,6 +95,7 @@ pub fn po_form(po_id: Option<String>) -> View {
             let expected_delivery_date = expected_delivery_date.clone();
             let payment_terms = payment_terms.clone();
             let notes = notes.clone();
+            let missing = missing.clone();
 
             move || {
                 let store = store.clone();
@@ -92,6 +106,7 @@ pub fn po_form(po_id: Option<String>) -> View {
                 let supplier_email = supplier_email.clone();
                 let supplier_phone = supplier_phone.clone();
                 let contract_ref = contract_ref.clone();
+                let cost_center = cost_center.clone();
                 let line_items = line_items.clone();
                 let address_line1 = address_line1.clone();
                 let city = city.clone();
@@ -104,17 +119,19 @@ pub fn po_form(po_id: Option<String>) -> View {
                 let expected_delivery_date = expected_delivery_date.clone();
                 let payment_terms = payment_terms.clone();
                 let notes = notes.clone();
+                let missing = missing.clone();
 
                 spawn(async move {
                     service::load_purchase_order(&store, &id).await;
 
-                    if let Some(po) = store.selected.get() {
+                    if let Some(po) = store.selected.get().filter(|po| po.id == id) {
                         supplier_id.set(po.supplier.id);
                         supplier_name.set(po.supplier.name);
                         supplier_contact.set(po.supplier.contact_person);
                         supplier_email.set(po.supplier.contact_email);
                         supplier_phone.set(po.supplier.contact_phone);
                         contract_ref.set(po.contract_ref.unwrap_or_default());
+                        cost_center.set(po.cost_center);
                         line_items.set(po.line_items);
                         address_line1.set(po.delivery_address.address_line1);
                         city.set(po.delivery_address.city);
@@ -127,6 +144,8 @@ pub fn po_form(po_id: Option<String>) -> View {
                         expected_delivery_date.set(po.expected_delivery_date);
                         payment_terms.set(po.payment_terms);
                         notes.set(po.notes.unwrap_or_default());
+                    } else {
+                        missing.set(true);
                     }
                 });
             }
@@ -194,7 +213,7 @@ pub fn po_form(po_id: Option<String>) -> View {
                     }
                     "unit" => items[idx].unit = value,
                     "unit_price" => {
-                        items[idx].unit_price = value.parse().unwrap_or(0.0);
+                        items[idx].unit_price = value.parse().unwrap_or_default();
                         items[idx].calculate_totals();
                     }
                     "delivery_date" => items[idx].delivery_date = value,
@@ -211,8 +230,8 @@ pub fn po_form(po_id: Option<String>) -> View {
         let line_items = line_items.clone();
         move || {
             let items = line_items.get();
-            let subtotal: f64 = items.iter().map(|i| i.total_price).sum();
-            let tax_total: f64 = items.iter().map(|i| i.tax_amount).sum();
+            let subtotal: Money = items.iter().map(|i| i.total_price).sum();
+            let tax_total: Money = items.iter().map(|i| i.tax_amount).sum();
             (subtotal, tax_total, subtotal + tax_total)
         }
     };
@@ -228,6 +247,7 @@ pub fn po_form(po_id: Option<String>) -> View {
         let supplier_phone = supplier_phone.clone();
         let contract_ref = contract_ref.clone();
         let requisition_ref = requisition_ref.clone();
+        let cost_center = cost_center.clone();
         let line_items = line_items.clone();
         let address_line1 = address_line1.clone();
         let address_line2 = address_line2.clone();
@@ -263,6 +283,7 @@ pub fn po_form(po_id: Option<String>) -> View {
                     let rr = requisition_ref.get();
                     if rr.is_empty() { None } else { Some(rr) }
                 },
+                cost_center: cost_center.get(),
                 tender_ref: None,
                 supplier: Supplier {
                     id: supplier_id.get(),
@@ -313,12 +334,14 @@ pub fn po_form(po_id: Option<String>) -> View {
                 },
                 attachments: Vec::new(),
                 created_by: "Current User".to_string(),
+                created_by_id: String::new(),
                 created_at: String::new(),
                 updated_at: String::new(),
                 approved_by: None,
                 approved_at: None,
                 sent_at: None,
                 acknowledged_at: None,
+                approval_workflow: Vec::new(),
             };
 
             spawn(async move {
@@ -367,27 +390,31 @@ pub fn po_form(po_id: Option<String>) -> View {
         }
     });
 
+    if let (true, Some(id)) = (missing.get(), po_id.as_ref()) {
+        return entity_not_found(t("Purchase Order"), id, store.error.get(), Route::PurchaseOrdersList, t("Back to Purchase Orders"));
+    }
+
     // Stepper items
     let step = current_step.get();
     let steps = vec![
         StepperItem {
             number: 1,
-            label: "Supplier".to_string(),
+            label: t("Supplier").to_string(),
             status: if step > 1 { StepStatus::Completed } else if step == 1 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 2,
-            label: "Line Items".to_string(),
+            label: t("Line Items").to_string(),
             status: if step > 2 { StepStatus::Completed } else if step == 2 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 3,
-            label: "Delivery".to_string(),
+            label: t("Delivery").to_string(),
             status: if step > 3 { StepStatus::Completed } else if step == 3 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 4,
-            label: "Review".to_string(),
+            label: t("Review").to_string(),
             status: if step == 4 { StepStatus::Active } else { StepStatus::Pending },
         },
     ];
@@ -541,10 +568,10 @@ pub fn po_form(po_id: Option<String>) -> View {
 
         <div class="po-form" data-testid="po-form">
             {page_header(
-                if is_edit { "Edit Purchase Order".to_string() } else { "New Purchase Order".to_string() },
-                Some(if is_edit { "Update purchase order details".to_string() } else { "Create a new purchase order".to_string() }),
+                if is_edit { t("Edit Purchase Order").to_string() } else { t("New Purchase Order").to_string() },
+                Some(if is_edit { t("Update purchase order details").to_string() } else { t("Create a new purchase order").to_string() }),
                 vec![
-                    view! { <a href="/purchase-orders" class="btn btn-secondary">"Cancel"</a> },
+                    view! { <a href="/purchase-orders" class="btn btn-secondary">{t("Cancel")}</a> },
                 ]
             )}
 
@@ -565,52 +592,60 @@ pub fn po_form(po_id: Option<String>) -> View {
             // Step 1: Supplier
             if step == 1 {
                 {panel_with_footer(
-                    "Supplier Information".to_string(),
+                    t("Supplier Information").to_string(),
                     vec![],
                     vec![
                         view! {
                             <div class="form-grid">
                                 {select(
-                                    "Supplier".to_string(),
+                                    t("Supplier").to_string(),
                                     supplier_id.clone(),
                                     supplier_options.iter().map(|(v, l)| {
                                         SelectOption { value: v.to_string(), label: l.to_string() }
                                     }).collect(),
-                                    Some("Select supplier".to_string()),
-                                    true, false, None
+                                    Some(t("Select supplier").to_string()),
+                                    true, false, field_err("supplier.id")
                                 )}
                                 {text_input(
-                                    "Contact Person".to_string(),
+                                    t("Contact Person").to_string(),
                                     supplier_contact.clone(),
-                                    Some("Supplier contact name".to_string()),
-                                    false, false, None, None, None
+                                    Some(t("Supplier contact name").to_string()),
+                                    false, false, field_err("supplier.contact_person"), None, None
                                 )}
                                 {text_input(
-                                    "Contact Email".to_string(),
+                                    t("Contact Email").to_string(),
                                     supplier_email.clone(),
                                     Some("supplier@example.com".to_string()),
-                                    false, false, None, None, Some("email".to_string())
+                                    false, false, field_err("supplier.contact_email"), None, Some("email".to_string())
                                 )}
                                 {text_input(
-                                    "Contact Phone".to_string(),
+                                    t("Contact Phone").to_string(),
                                     supplier_phone.clone(),
                                     Some("+27 11 555 1234".to_string()),
-                                    false, false, None, None, Some("tel".to_string())
+                                    false, false, field_err("supplier.contact_phone"), None, Some("tel".to_string())
                                 )}
                                 {text_input(
-                                    "Contract Reference".to_string(),
+                                    t("Contract Reference").to_string(),
                                     contract_ref.clone(),
-                                    Some("e.g., CTR-2025-0001".to_string()),
-                                    false, false, None,
-                                    Some("Link to existing contract (optional)".to_string()),
+                                    Some(t("e.g., CTR-2025-0001").to_string()),
+                                    false, false, field_err("contract_ref"),
+                                    Some(t("Link to existing contract (optional)").to_string()),
                                     None
                                 )}
                                 {text_input(
-                                    "Requisition Reference".to_string(),
+                                    t("Requisition Reference").to_string(),
                                     requisition_ref.clone(),
-                                    Some("e.g., REQ-2025-0001".to_string()),
-                                    false, false, None,
-                                    Some("Link to requisition (optional)".to_string()),
+                                    Some(t("e.g., REQ-2025-0001").to_string()),
+                                    false, false, field_err("requisition_ref"),
+                                    Some(t("Link to requisition (optional)").to_string()),
+                                    None
+                                )}
+                                {text_input(
+                                    t("Cost Centre").to_string(),
+                                    cost_center.clone(),
+                                    Some(t("e.g., CC-IT-001").to_string()),
+                                    false, false, field_err("cost_center"),
+                                    Some(t("Funds are committed against this cost centre when approved").to_string()),
                                     None
                                 )}
                             </div>
@@ -620,7 +655,7 @@ pub fn po_form(po_id: Option<String>) -> View {
                         view! { <div class="form-actions-left"></div> },
                         view! {
                             <div class="form-actions-right">
-                                <button class="btn btn-primary" on:click={next_step.clone()}>"Next: Line Items"</button>
+                                <button class="btn btn-primary" on:click={next_step.clone()}>{t("Next: Line Items")}</button>
                             </div>
                         },
                     ]
@@ -630,7 +665,7 @@ pub fn po_form(po_id: Option<String>) -> View {
             // Step 2: Line Items
             if step == 2 {
                 {panel_with_footer(
-                    "Line Items".to_string(),
+                    t("Line Items").to_string(),
                     vec![],
                     vec![
                         view! {
@@ -638,7 +673,7 @@ pub fn po_form(po_id: Option<String>) -> View {
                                 for (idx, item) in line_items.get().iter().enumerate() {
                                     <div class="line-item">
                                         <div class="line-item-header">
-                                            <h4>{format!("Item #{}", idx + 1)}</h4>
+                                            <h4>{tf("Item #{number}", &[("number", (idx + 1).to_string())])}</h4>
                                             <button
                                                 class="btn btn-sm btn-danger"
                                                 on:click={Callback::<()>::new({
@@ -646,46 +681,46 @@ pub fn po_form(po_id: Option<String>) -> View {
                                                     move |_| remove_line_item(idx)
                                                 })}
                                             >
-                                                "Remove"
+                                                {t("Remove")}
                                             </button>
                                         </div>
                                         <div class="line-item-grid">
                                             {text_input(
-                                                "Item Code".to_string(),
+                                                t("Item Code").to_string(),
                                                 signal(item.item_code.clone()),
                                                 Some("SKU".to_string()),
                                                 false, false, None, None, None
                                             )}
                                             {text_input(
-                                                "Description".to_string(),
+                                                t("Description").to_string(),
                                                 signal(item.description.clone()),
-                                                Some("Item description".to_string()),
+                                                Some(t("Item description").to_string()),
                                                 true, false, None, None, None
                                             )}
                                             {text_input(
-                                                "Qty".to_string(),
+                                                t("Qty").to_string(),
                                                 signal(item.quantity.to_string()),
                                                 Some("1".to_string()),
                                                 true, false, None, None, Some("number".to_string())
                                             )}
                                             {text_input(
-                                                "Unit".to_string(),
+                                                t("Unit").to_string(),
                                                 signal(item.unit.clone()),
-                                                Some("Each".to_string()),
+                                                Some(t("Each").to_string()),
                                                 true, false, None, None, None
                                             )}
                                             {currency_input(
-                                                "Unit Price".to_string(),
+                                                t("Unit Price").to_string(),
                                                 signal(item.unit_price),
                                                 true, false, None, None
                                             )}
                                             <div class="line-item-total">
-                                                <label>"Total"</label>
+                                                <label>{t("Total")}</label>
                                                 <span class="total-value">{format_currency(item.total_price)}</span>
                                             </div>
                                         </div>
                                         <div class="line-item-totals">
-                                            <span class="total-label">"Tax (15%):"</span>
+                                            <span class="total-label">{t("Tax (15%):")}</span>
                                             <span class="total-value">{format_currency(item.tax_amount)}</span>
                                         </div>
                                     </div>
@@ -696,21 +731,21 @@ pub fn po_form(po_id: Option<String>) -> View {
                                         <line x1="12" y1="5" x2="12" y2="19"/>
                                         <line x1="5" y1="12" x2="19" y2="12"/>
                                     </svg>
-                                    "Add Line Item"
+                                    {t("Add Line Item")}
                                 </button>
 
                                 // Order totals
                                 <div class="totals-section">
                                     <div class="totals-row">
-                                        <span class="label">"Subtotal:"</span>
+                                        <span class="label">{t("Subtotal:")}</span>
                                         <span class="value">{format_currency(subtotal)}</span>
                                     </div>
                                     <div class="totals-row">
-                                        <span class="label">"VAT (15%):"</span>
+                                        <span class="label">{t("VAT (15%):")}</span>
                                         <span class="value">{format_currency(tax_total)}</span>
                                     </div>
                                     <div class="totals-row total">
-                                        <span class="label">"Total:"</span>
+                                        <span class="label">{t("Total:")}</span>
                                         <span class="value">{format_currency(total)}</span>
                                     </div>
                                 </div>
@@ -720,12 +755,12 @@ pub fn po_form(po_id: Option<String>) -> View {
                     vec![
                         view! {
                             <div class="form-actions-left">
-                                <button class="btn btn-secondary" on:click={prev_step.clone()}>"Back"</button>
+                                <button class="btn btn-secondary" on:click={prev_step.clone()}>{t("Back")}</button>
                             </div>
                         },
                         view! {
                             <div class="form-actions-right">
-                                <button class="btn btn-primary" on:click={next_step.clone()}>"Next: Delivery"</button>
+                                <button class="btn btn-primary" on:click={next_step.clone()}>{t("Next: Delivery")}</button>
                             </div>
                         },
                     ]
@@ -735,78 +770,78 @@ pub fn po_form(po_id: Option<String>) -> View {
             // Step 3: Delivery
             if step == 3 {
                 {panel_with_footer(
-                    "Delivery Information".to_string(),
+                    t("Delivery Information").to_string(),
                     vec![],
                     vec![
                         view! {
                             <div class="form-grid">
                                 <div class="span-2">
                                     {text_input(
-                                        "Delivery Address Line 1".to_string(),
+                                        t("Delivery Address Line 1").to_string(),
                                         address_line1.clone(),
-                                        Some("Street address".to_string()),
-                                        true, false, None, None, None
+                                        Some(t("Street address").to_string()),
+                                        true, false, field_err("delivery_address.address_line1"), None, None
                                     )}
                                 </div>
                                 <div class="span-2">
                                     {text_input(
-                                        "Address Line 2".to_string(),
+                                        t("Address Line 2").to_string(),
                                         address_line2.clone(),
-                                        Some("Building, floor, etc. (optional)".to_string()),
-                                        false, false, None, None, None
+                                        Some(t("Building, floor, etc. (optional)").to_string()),
+                                        false, false, field_err("delivery_address.address_line2"), None, None
                                     )}
                                 </div>
                                 {text_input(
-                                    "City".to_string(),
+                                    t("City").to_string(),
                                     city.clone(),
-                                    Some("City".to_string()),
-                                    true, false, None, None, None
+                                    Some(t("City").to_string()),
+                                    true, false, field_err("delivery_address.city"), None, None
                                 )}
                                 {select(
-                                    "Province".to_string(),
+                                    t("Province").to_string(),
                                     province.clone(),
                                     province_options.iter().map(|(v, l)| {
-                                        SelectOption { value: v.to_string(), label: l.to_string() }
+                                        SelectOption { value: v.to_string(), label: t(l).to_string() }
                                     }).collect(),
-                                    Some("Select province".to_string()),
-                                    true, false, None
+                                    Some(t("Select province").to_string()),
+                                    true, false, field_err("delivery_address.province")
                                 )}
                                 {text_input(
-                                    "Postal Code".to_string(),
+                                    t("Postal Code").to_string(),
                                     postal_code.clone(),
                                     Some("0000".to_string()),
-                                    true, false, None, None, None
+                                    true, false, field_err("delivery_address.postal_code"), None, None
                                 )}
                                 {date_picker(
-                                    "Expected Delivery Date".to_string(),
+                                    t("Expected Delivery Date").to_string(),
                                     expected_delivery_date.clone(),
-                                    true, false, None, None, None
+                                    true, false, None, None, field_err("expected_delivery_date")
                                 )}
                                 {text_input(
-                                    "Contact Person".to_string(),
+                                    t("Contact Person").to_string(),
                                     contact_person.clone(),
-                                    Some("Receiver's name".to_string()),
-                                    true, false, None, None, None
+                                    Some(t("Receiver's name").to_string()),
+                                    true, false, field_err("delivery_address.contact_person"), None, None
                                 )}
                                 {text_input(
-                                    "Contact Phone".to_string(),
+                                    t("Contact Phone").to_string(),
                                     contact_phone.clone(),
                                     Some("+27 XX XXX XXXX".to_string()),
-                                    true, false, None, None, Some("tel".to_string())
+                                    true, false, field_err("delivery_address.contact_phone"), None, Some("tel".to_string())
                                 )}
                                 {text_input(
-                                    "Contact Email".to_string(),
+                                    t("Contact Email").to_string(),
                                     contact_email.clone(),
                                     Some("receiver@example.com".to_string()),
-                                    false, false, None, None, Some("email".to_string())
+                                    false, false, field_err("delivery_address.contact_email"), None, Some("email".to_string())
                                 )}
                                 <div></div>
                                 <div class="span-2">
                                     {textarea(
-                                        "Delivery Instructions".to_string(),
+                                        t("Delivery Instructions").to_string(),
                                         delivery_instructions.clone(),
-                                        Some("Special delivery instructions, access requirements, etc.".to_string()),
-                                        false, false, Some(3), None, None
+                                        Some(t("Special delivery instructions, access requirements, etc.").to_string()),
+                                        false, false, Some(3), field_err("delivery_address.delivery_instructions"), None
                                     )}
                                 </div>
                             </div>
@@ -815,12 +850,12 @@ pub fn po_form(po_id: Option<String>) -> View {
                     vec![
                         view! {
                             <div class="form-actions-left">
-                                <button class="btn btn-secondary" on:click={prev_step.clone()}>"Back"</button>
+                                <button class="btn btn-secondary" on:click={prev_step.clone()}>{t("Back")}</button>
                             </div>
                         },
                         view! {
                             <div class="form-actions-right">
-                                <button class="btn btn-primary" on:click={next_step.clone()}>"Next: Review"</button>
+                                <button class="btn btn-primary" on:click={next_step.clone()}>{t("Next: Review")}</button>
                             </div>
                         },
                     ]
@@ -830,46 +865,46 @@ pub fn po_form(po_id: Option<String>) -> View {
             // Step 4: Review & Additional Info
             if step == 4 {
                 {panel_with_footer(
-                    "Review & Submit".to_string(),
+                    t("Review & Submit").to_string(),
                     vec![],
                     vec![
                         view! {
                             <div>
                                 // Supplier summary
                                 <div class="review-section">
-                                    <h4>"Supplier"</h4>
+                                    <h4>{t("Supplier")}</h4>
                                     <div class="review-grid">
-                                        <span class="label">"Supplier:"</span>
+                                        <span class="label">{t("Supplier:")}</span>
                                         <span class="value">{supplier_name.get()}</span>
-                                        <span class="label">"Contact:"</span>
+                                        <span class="label">{t("Contact:")}</span>
                                         <span class="value">{supplier_contact.get()}</span>
-                                        <span class="label">"Contract:"</span>
+                                        <span class="label">{t("Contract:")}</span>
                                         <span class="value">{if contract_ref.get().is_empty() { "-".to_string() } else { contract_ref.get() }}</span>
                                     </div>
                                 </div>
 
                                 // Line items summary
                                 <div class="review-section">
-                                    <h4>{format!("Line Items ({})", line_items.get().len())}</h4>
+                                    <h4>{tf("Line Items ({count})", &[("count", line_items.get().len().to_string())])}</h4>
                                     <div class="review-grid">
-                                        <span class="label">"Subtotal:"</span>
+                                        <span class="label">{t("Subtotal:")}</span>
                                         <span class="value">{format_currency(subtotal)}</span>
-                                        <span class="label">"VAT:"</span>
+                                        <span class="label">{t("VAT:")}</span>
                                         <span class="value">{format_currency(tax_total)}</span>
-                                        <span class="label">"Total:"</span>
+                                        <span class="label">{t("Total:")}</span>
                                         <span class="value" style="font-size: 16px;">{format_currency(total)}</span>
                                     </div>
                                 </div>
 
                                 // Delivery summary
                                 <div class="review-section">
-                                    <h4>"Delivery"</h4>
+                                    <h4>{t("Delivery")}</h4>
                                     <div class="review-grid">
-                                        <span class="label">"Address:"</span>
+                                        <span class="label">{t("Address:")}</span>
                                         <span class="value">{format!("{}, {}, {}", address_line1.get(), city.get(), province.get())}</span>
-                                        <span class="label">"Contact:"</span>
+                                        <span class="label">{t("Contact:")}</span>
                                         <span class="value">{contact_person.get()}</span>
-                                        <span class="label">"Expected Date:"</span>
+                                        <span class="label">{t("Expected Date:")}</span>
                                         <span class="value">{expected_delivery_date.get()}</span>
                                     </div>
                                 </div>
@@ -877,26 +912,26 @@ pub fn po_form(po_id: Option<String>) -> View {
                                 // Additional information
                                 <div class="form-grid" style="margin-top: 24px;">
                                     {text_input(
-                                        "Payment Terms".to_string(),
+                                        t("Payment Terms").to_string(),
                                         payment_terms.clone(),
-                                        Some("e.g., 30 days from invoice".to_string()),
-                                        false, false, None, None, None
+                                        Some(t("e.g., 30 days from invoice").to_string()),
+                                        false, false, field_err("payment_terms"), None, None
                                     )}
                                     <div></div>
                                     <div class="span-2">
                                         {textarea(
-                                            "Notes (visible to supplier)".to_string(),
+                                            t("Notes (visible to supplier)").to_string(),
                                             notes.clone(),
-                                            Some("Any notes or comments for the supplier".to_string()),
-                                            false, false, Some(3), None, None
+                                            Some(t("Any notes or comments for the supplier").to_string()),
+                                            false, false, Some(3), field_err("notes"), None
                                         )}
                                     </div>
                                     <div class="span-2">
                                         {textarea(
-                                            "Internal Notes".to_string(),
+                                            t("Internal Notes").to_string(),
                                             internal_notes.clone(),
-                                            Some("Internal notes (not visible to supplier)".to_string()),
-                                            false, false, Some(3), None, None
+                                            Some(t("Internal notes (not visible to supplier)").to_string()),
+                                            false, false, Some(3), field_err("internal_notes"), None
                                         )}
                                     </div>
                                 </div>
@@ -906,21 +941,21 @@ pub fn po_form(po_id: Option<String>) -> View {
                     vec![
                         view! {
                             <div class="form-actions-left">
-                                <button class="btn btn-secondary" on:click={prev_step.clone()}>"Back"</button>
+                                <button class="btn btn-secondary" on:click={prev_step.clone()}>{t("Back")}</button>
                             </div>
                         },
                         view! {
                             <div class="form-actions-right">
                                 <button class="btn btn-secondary" on:click={handle_save.clone()} disabled={saving.get()}>
-                                    {if saving.get() { "Saving..." } else { "Save Draft" }}
+                                    {if saving.get() { t("Saving...") } else { t("Save Draft") }}
                                 </button>
                                 if is_edit {
                                     <button class="btn btn-primary" on:click={handle_submit_for_approval} disabled={saving.get()}>
-                                        "Submit for Approval"
+                                        {t("Submit for Approval")}
                                     </button>
                                 } else {
                                     <button class="btn btn-primary" on:click={handle_save.clone()} disabled={saving.get()}>
-                                        "Create Purchase Order"
+                                        {t("Create Purchase Order")}
                                     </button>
                                 }
                             </div>
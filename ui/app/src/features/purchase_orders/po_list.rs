@@ -13,14 +13,20 @@ use crate::shared::components::{
 };
 use crate::shared::forms::filter_bar;
 use crate::util::format::{format_currency, format_date};
+use crate::util::money::Money;
+use crate::util::auth::AuthState;
+use crate::util::permissions::{self, Action};
 use super::types::{PurchaseOrderStatus, PurchaseOrderSummary};
 use super::store::PurchaseOrdersStore;
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Purchase Order list page
 #[component]
 pub fn po_list() -> View {
     let store = use_context::<PurchaseOrdersStore>();
+    let auth_state = use_context::<Signal<AuthState>>();
+    let can_approve = permissions::can_perform(&auth_state.get(), Action::ApprovePurchaseOrder);
 
     // Load data on mount
     effect({
@@ -138,17 +144,17 @@ pub fn po_list() -> View {
         .count();
 
     // Calculate total value
-    let total_value: f64 = purchase_orders.iter().map(|po| po.total_amount).sum();
+    let total_value: Money = purchase_orders.iter().map(|po| po.total_amount).sum();
 
     // Table columns
     let columns = vec![
-        DataTableColumn { key: "po_number".to_string(), label: "PO Number".to_string(), width: Some("120px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "contract".to_string(), label: "Contract".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "supplier".to_string(), label: "Supplier".to_string(), width: Some("200px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "amount".to_string(), label: "Amount".to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "order_date".to_string(), label: "Order Date".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "delivery".to_string(), label: "Delivery".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "po_number".to_string(), label: t("PO Number").to_string(), width: Some("120px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
+        DataTableColumn { key: "contract".to_string(), label: t("Contract").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: Some("200px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "amount".to_string(), label: t("Amount").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "order_date".to_string(), label: t("Order Date").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "delivery".to_string(), label: t("Delivery").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
         DataTableColumn { key: "actions".to_string(), label: "".to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
     ];
 
@@ -157,6 +163,20 @@ pub fn po_list() -> View {
         let status_view = get_status_badge(&po.status);
         let bbbee_view = get_bbbee_badge(po.supplier_bbbee_level);
         let delivery_view = get_delivery_view(po);
+        let show_approve = can_approve && po.status == PurchaseOrderStatus::PendingApproval;
+
+        let handle_approve = Callback::<()>::new({
+            let store = store.clone();
+            let po_id = po.id.clone();
+            move |_| {
+                let store = store.clone();
+                let po_id = po_id.clone();
+                spawn(async move {
+                    service::load_purchase_order(&store, &po_id).await;
+                    let _ = service::approve_purchase_order(&store, &po_id).await;
+                });
+            }
+        });
 
         DataTableRow {
             id: po.id.clone(),
@@ -180,7 +200,7 @@ pub fn po_list() -> View {
                 view! {
                     <div class="amount-cell">
                         <span class="amount">{format_currency(po.total_amount)}</span>
-                        <span class="items-count">{format!("{} items", po.line_item_count)}</span>
+                        <span class="items-count">{tn("{n} item", "{n} items", po.line_item_count as i64)}</span>
                     </div>
                 },
                 view! { <span class="date-cell">{format_date(&po.order_date)}</span> },
@@ -188,7 +208,10 @@ pub fn po_list() -> View {
                 status_view,
                 view! {
                     <div class="row-actions">
-                        <a href={format!("/purchase-orders/{}", po.id)} class="btn btn-sm btn-secondary">"View"</a>
+                        if show_approve {
+                            <button class="btn btn-sm btn-primary" data-testid="po-approve" on:click={handle_approve}>{t("Approve")}</button>
+                        }
+                        <a href={format!("/purchase-orders/{}", po.id)} class="btn btn-sm btn-secondary">{t("View")}</a>
                     </div>
                 },
             ],
@@ -292,18 +315,22 @@ pub fn po_list() -> View {
 
         <div class="purchase-orders-page" data-testid="po-list">
             {page_header(
-                "Purchase Orders".to_string(),
-                Some("Manage purchase orders and track deliveries".to_string()),
+                t("Purchase Orders").to_string(),
+                Some(t("Manage purchase orders and track deliveries").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export"</button> },
-                    view! { <a href="/purchase-orders/new" class="btn btn-primary">"New Purchase Order"</a> },
+                    view! { <button class="btn btn-secondary">{t("Export")}</button> },
+                    view! { <a href="/purchase-orders/new" class="btn btn-primary">{t("New Purchase Order")}</a> },
                 ]
             )}
 
             // Pending delivery notice
             if pending_delivery_count > 0 {
                 {notice_bar(
-                    format!("{} purchase order(s) pending delivery. Track and confirm deliveries.", pending_delivery_count),
+                    tn(
+                        "{n} purchase order pending delivery. Track and confirm deliveries.",
+                        "{n} purchase orders pending delivery. Track and confirm deliveries.",
+                        pending_delivery_count as i64,
+                    ),
                     NoticeType::Info,
                     None
                 )}
@@ -312,19 +339,19 @@ pub fn po_list() -> View {
             // Stats row
             <div class="stats-row">
                 <div class="stat-item">
-                    <span class="label">"Total POs:"</span>
+                    <span class="label">{t("Total POs:")}</span>
                     <span class="count">{store.purchase_orders.get().len().to_string()}</span>
                 </div>
                 <div class={if pending_delivery_count > 0 { "stat-item warning" } else { "stat-item" }}>
-                    <span class="label">"Pending Delivery:"</span>
+                    <span class="label">{t("Pending Delivery:")}</span>
                     <span class="count">{pending_delivery_count.to_string()}</span>
                 </div>
                 <div class="stat-item">
-                    <span class="label">"Total Value:"</span>
+                    <span class="label">{t("Total Value:")}</span>
                     <span class="value">{format_currency(total_value)}</span>
                 </div>
                 <div class="stat-item">
-                    <span class="label">"Draft:"</span>
+                    <span class="label">{t("Draft:")}</span>
                     <span class="count">{store.get_count_by_status(PurchaseOrderStatus::Draft).to_string()}</span>
                 </div>
             </div>
@@ -333,28 +360,28 @@ pub fn po_list() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Status:"</label>
+                        <label>{t("Status:")}</label>
                         <select on:change={handle_status_change}>
-                            <option value="">"All Statuses"</option>
-                            <option value="draft">"Draft"</option>
-                            <option value="pending_approval">"Pending Approval"</option>
-                            <option value="approved">"Approved"</option>
-                            <option value="sent">"Sent to Supplier"</option>
-                            <option value="acknowledged">"Acknowledged"</option>
-                            <option value="partially_delivered">"Partially Delivered"</option>
-                            <option value="delivered">"Delivered"</option>
-                            <option value="invoiced">"Invoiced"</option>
-                            <option value="closed">"Closed"</option>
-                            <option value="cancelled">"Cancelled"</option>
+                            <option value="">{t("All Statuses")}</option>
+                            <option value="draft">{t("Draft")}</option>
+                            <option value="pending_approval">{t("Pending Approval")}</option>
+                            <option value="approved">{t("Approved")}</option>
+                            <option value="sent">{t("Sent to Supplier")}</option>
+                            <option value="acknowledged">{t("Acknowledged")}</option>
+                            <option value="partially_delivered">{t("Partially Delivered")}</option>
+                            <option value="delivered">{t("Delivered")}</option>
+                            <option value="invoiced">{t("Invoiced")}</option>
+                            <option value="closed">{t("Closed")}</option>
+                            <option value="cancelled">{t("Cancelled")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Supplier:"</label>
+                        <label>{t("Supplier:")}</label>
                         <input
                             type="text"
-                            placeholder="Search supplier..."
+                            placeholder={t("Search supplier...")}
                             value={supplier_filter.get()}
                             on:input={handle_supplier_change}
                         />
@@ -362,10 +389,10 @@ pub fn po_list() -> View {
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Contract:"</label>
+                        <label>{t("Contract:")}</label>
                         <input
                             type="text"
-                            placeholder="Contract ref..."
+                            placeholder={t("Contract ref...")}
                             value={contract_filter.get()}
                             on:input={handle_contract_change}
                         />
@@ -378,7 +405,7 @@ pub fn po_list() -> View {
                             checked={show_pending_delivery.get()}
                             on:change={handle_pending_delivery_toggle}
                         />
-                        "Pending delivery only"
+                        {t("Pending delivery only")}
                     </label>
                 },
                 view! { <div class="filter-spacer"></div> },
@@ -386,30 +413,30 @@ pub fn po_list() -> View {
                     <div class="filter-group">
                         <input
                             type="text"
-                            placeholder="Search POs..."
+                            placeholder={t("Search POs...")}
                             value={search_filter.get()}
                             on:input={handle_search_change}
                         />
                     </div>
                 },
                 view! {
-                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>"Clear"</button>
+                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>{t("Clear")}</button>
                 },
             ])}
 
             // Purchase orders table
             if loading {
-                <div class="loading-state">"Loading purchase orders..."</div>
+                <div class="loading-state">{t("Loading purchase orders...")}</div>
             } else if rows.is_empty() {
                 {empty_state(
-                    "No purchase orders found".to_string(),
-                    Some("Try adjusting your filters or create a new purchase order".to_string()),
+                    t("No purchase orders found").to_string(),
+                    Some(t("Try adjusting your filters or create a new purchase order").to_string()),
                     None,
-                    Some(view! { <a href="/purchase-orders/new" class="btn btn-primary">"Create Purchase Order"</a> })
+                    Some(view! { <a href="/purchase-orders/new" class="btn btn-primary">{t("Create Purchase Order")}</a> })
                 )}
             } else {
                 {panel(
-                    format!("Purchase Orders ({})", rows.len()),
+                    tf("Purchase Orders ({count})", &[("count", rows.len().to_string())]),
                     vec![],
                     vec![data_table(columns, rows, None)]
                 )}
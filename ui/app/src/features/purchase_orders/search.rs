@@ -0,0 +1,50 @@
+//! Purchase order search provider
+
+use crate::Route;
+use crate::features::search::provider::{SearchFuture, SearchProvider};
+use crate::features::search::types::{match_score, SearchEntity, SearchHit};
+use crate::util::format::format_currency;
+use super::store::PurchaseOrdersStore;
+use super::service;
+
+/// Searches the purchase orders held in the store, loading them on first use
+pub struct PurchaseOrderSearch {
+    store: PurchaseOrdersStore,
+}
+
+impl PurchaseOrderSearch {
+    pub fn new(store: PurchaseOrdersStore) -> Self {
+        Self { store }
+    }
+}
+
+impl SearchProvider for PurchaseOrderSearch {
+    fn entity(&self) -> SearchEntity {
+        SearchEntity::PurchaseOrder
+    }
+
+    fn search(&self, query: String) -> SearchFuture {
+        let store = self.store.clone();
+        Box::pin(async move {
+            if store.purchase_orders.get().is_empty() {
+                service::load_purchase_orders(&store).await;
+                if let Some(e) = store.error.get() {
+                    return Err(e);
+                }
+            }
+
+            let hits = store.purchase_orders.get().iter().filter_map(|po| {
+                let score = match_score(&query, &[&po.id, &po.po_number], &[&po.supplier_name])?;
+                Some(SearchHit {
+                    entity: SearchEntity::PurchaseOrder,
+                    id: po.id.clone(),
+                    title: po.po_number.clone(),
+                    subtitle: format!("{} - {}", po.supplier_name, format_currency(po.total_amount)),
+                    route: Route::PurchaseOrdersDetail(po.id.clone()),
+                    score,
+                })
+            }).collect();
+            Ok(hits)
+        })
+    }
+}
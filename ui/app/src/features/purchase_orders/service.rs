@@ -1,5 +1,17 @@
 //! Purchase Orders service - API calls
 
+use crate::features::budget::service as budget;
+use crate::features::budget::types::CommitmentSource;
+use crate::features::workflow::service as workflow;
+use crate::features::workflow::types::{
+    ApprovalAction, ApprovalDecision, ApprovalDocument, ApprovalReassignment, ApprovalRequest, WorkflowState,
+};
+use crate::util::api::{self, HttpMethod};
+use crate::util::auth;
+use crate::util::backend;
+use crate::util::datetime::{now_iso, today};
+use crate::util::reference::{self, DocumentType};
+use crate::util::permissions::{self, Action};
 use super::store::{PurchaseOrdersStore, load_mock_purchase_orders, get_mock_purchase_order};
 use super::types::{PurchaseOrder, PurchaseOrderStatus};
 
@@ -8,9 +20,7 @@ pub async fn load_purchase_orders(store: &PurchaseOrdersStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_purchase_orders(store);
+    load_purchase_order_list(store).await;
 
     store.loading.set(false);
 }
@@ -19,22 +29,60 @@ pub async fn load_purchase_orders(store: &PurchaseOrdersStore) {
 pub async fn load_purchase_order(store: &PurchaseOrdersStore, id: &str) {
     store.loading.set(true);
     store.error.set(None);
+    // Links made before the server confirmed a provisional number still resolve
+    let id = reference::resolve(id);
+    let id = id.as_str();
 
-    // In production, this would call the API
-    // For now, get mock data
-    if let Some(po) = get_mock_purchase_order(id) {
-        store.selected.set(Some(po));
+    let loaded = if backend::is_mock() {
+        get_mock_purchase_order(id).ok_or_else(|| format!("Purchase Order {} not found", id))
     } else {
-        store.error.set(Some(format!("Purchase Order {} not found", id)));
+        api::get::<PurchaseOrder>(&format!("/purchase-orders/{}", id)).await.map_err(|e| e.to_string())
+    };
+    match loaded {
+        Ok(mut po) => {
+            workflow::reroute(&mut po.approval_workflow, ApprovalDocument::PurchaseOrder, po.total_amount);
+            store.selected.set(Some(po));
+        }
+        Err(e) => {
+            store.selected.set(None);
+            store.error.set(Some(e));
+        }
     }
 
     store.loading.set(false);
 }
 
+/// Fetch a purchase order by its number, for documents that quote it
+pub async fn find_purchase_order(po_number: &str) -> Result<PurchaseOrder, String> {
+    let po_number = reference::resolve(po_number.trim());
+    if backend::is_mock() {
+        get_mock_purchase_order(&po_number).ok_or_else(|| format!("Purchase Order {} not found", po_number))
+    } else {
+        api::get::<PurchaseOrder>(&format!("/purchase-orders/by-number/{}", po_number))
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Orders already raised from a requisition, so a resumed conversion finds
+/// what an interrupted one created
+pub async fn purchase_orders_for_requisition(requisition_id: &str) -> Result<Vec<PurchaseOrder>, String> {
+    if backend::is_mock() {
+        // Mock orders only live in the list summaries, which carry no
+        // requisition; the conversion's own record covers the mock backend
+        Ok(Vec::new())
+    } else {
+        api::get::<Vec<PurchaseOrder>>(&format!("/requisitions/{}/purchase-orders", requisition_id))
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
 /// Create a new purchase order
 pub async fn create_purchase_order(store: &PurchaseOrdersStore, mut po: PurchaseOrder) -> Result<String, String> {
     store.saving.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
     // Validate required fields
     if po.supplier.id.is_empty() {
@@ -67,25 +115,43 @@ pub async fn create_purchase_order(store: &PurchaseOrdersStore, mut po: Purchase
             store.saving.set(false);
             return Err(format!("Line item {} quantity must be greater than zero", idx + 1));
         }
-        if item.unit_price <= 0.0 {
+        if !item.unit_price.is_positive() {
             store.saving.set(false);
             return Err(format!("Line item {} unit price must be greater than zero", idx + 1));
         }
     }
 
+    if let Some(user) = auth::current().user {
+        po.created_by = user.name;
+        po.created_by_id = user.id;
+    }
+
     // Calculate totals
     po.calculate_totals();
 
-    // Generate new ID
-    let new_id = format!("PO-2025-{:04}", rand_id());
-    po.id = new_id.clone();
-    po.po_number = new_id.clone();
-    po.order_date = chrono_now_date();
-    po.created_at = chrono_now();
-    po.updated_at = chrono_now();
+    // Provisional ID, replaced by the server's when using the REST backend
+    let provisional_id = next_po_number(store);
+    po.id = provisional_id.clone();
+    po.po_number = provisional_id;
+    po.order_date = today().to_iso();
+    po.created_at = now_iso();
+    po.updated_at = now_iso();
+
+    match backend::save(HttpMethod::Post, "/purchase-orders", &po).await {
+        Ok(saved) => {
+            reference::reconcile(DocumentType::PurchaseOrder, &po.po_number, &saved.po_number);
+            po = saved;
+        }
+        Err(e) => {
+            store.field_errors.set(e.field_errors().to_vec());
+            store.saving.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+    let new_id = po.id.clone();
 
-    // In production, POST to API and reload list
-    load_mock_purchase_orders(store);
+    load_purchase_order_list(store).await;
 
     store.saving.set(false);
     Ok(new_id)
@@ -95,6 +161,7 @@ pub async fn create_purchase_order(store: &PurchaseOrdersStore, mut po: Purchase
 pub async fn update_purchase_order(store: &PurchaseOrdersStore, mut po: PurchaseOrder) -> Result<(), String> {
     store.saving.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
     // Validate required fields
     if po.id.is_empty() {
@@ -113,11 +180,21 @@ pub async fn update_purchase_order(store: &PurchaseOrdersStore, mut po: Purchase
 
     // Calculate totals
     po.calculate_totals();
-    po.updated_at = chrono_now();
+    po.updated_at = now_iso();
+
+    let endpoint = format!("/purchase-orders/{}", po.id);
+    match backend::save(HttpMethod::Put, &endpoint, &po).await {
+        Ok(saved) => po = saved,
+        Err(e) => {
+            store.field_errors.set(e.field_errors().to_vec());
+            store.saving.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
 
-    // In production, PUT to API and reload
     store.selected.set(Some(po));
-    load_mock_purchase_orders(store);
+    load_purchase_order_list(store).await;
 
     store.saving.set(false);
     Ok(())
@@ -165,42 +242,119 @@ pub async fn update_purchase_order_status(
             }
 
             po.status = new_status;
-            po.updated_at = chrono_now();
+            po.updated_at = now_iso();
 
             // Set timestamps based on status
             match new_status {
                 PurchaseOrderStatus::Sent => {
-                    po.sent_at = Some(chrono_now());
+                    po.sent_at = Some(now_iso());
                 }
                 PurchaseOrderStatus::Acknowledged => {
-                    po.acknowledged_at = Some(chrono_now());
+                    po.acknowledged_at = Some(now_iso());
                 }
                 PurchaseOrderStatus::Delivered => {
-                    po.actual_delivery_date = Some(chrono_now_date());
+                    po.actual_delivery_date = Some(today().to_iso());
                 }
                 _ => {}
             }
 
+            let endpoint = format!("/purchase-orders/{}/status", po_id);
+            match backend::save(HttpMethod::Post, &endpoint, &po).await {
+                Ok(saved) => po = saved,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
+            }
             store.selected.set(Some(po));
         }
     }
 
-    load_mock_purchase_orders(store);
+    load_purchase_order_list(store).await;
     store.saving.set(false);
     Ok(())
 }
 
-/// Submit purchase order for approval
+/// Submit purchase order for approval, routing it along the chain the
+/// delegation-of-authority matrix prescribes for its total
 pub async fn submit_for_approval(store: &PurchaseOrdersStore, po_id: &str) -> Result<(), String> {
-    update_purchase_order_status(store, po_id, PurchaseOrderStatus::PendingApproval).await
+    store.saving.set(true);
+    store.error.set(None);
+
+    if let Some(mut po) = store.selected.get() {
+        if po.id == po_id {
+            if po.status != PurchaseOrderStatus::Draft {
+                store.saving.set(false);
+                return Err("Only draft purchase orders can be submitted for approval".to_string());
+            }
+
+            let request = ApprovalRequest::new(ApprovalDocument::PurchaseOrder, po.total_amount);
+            match workflow::approval_chain(&request).await {
+                Ok(chain) => po.approval_workflow = chain,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.clone()));
+                    return Err(e);
+                }
+            }
+
+            po.status = PurchaseOrderStatus::PendingApproval;
+            po.updated_at = now_iso();
+            let endpoint = format!("/purchase-orders/{}/submit", po_id);
+            match backend::save(HttpMethod::Post, &endpoint, &po).await {
+                Ok(saved) => po = saved,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
+            }
+            store.selected.set(Some(po));
+        }
+    }
+
+    load_purchase_order_list(store).await;
+    store.saving.set(false);
+    Ok(())
 }
 
-/// Approve a purchase order
-pub async fn approve_purchase_order(
+/// Approve the current stage from the list.
+///
+/// Signs for the first role still waiting at that stage; approvers sharing a
+/// stage choose their role on the detail page.
+pub async fn approve_purchase_order(store: &PurchaseOrdersStore, po_id: &str) -> Result<(), String> {
+    let role = store.selected.get()
+        .filter(|po| po.id == po_id)
+        .and_then(|po| workflow::awaiting(&po.approval_workflow).first().map(|s| s.role.clone()))
+        .ok_or_else(|| "This purchase order is not awaiting approval".to_string());
+    let role = match role {
+        Ok(role) => role,
+        Err(e) => {
+            store.error.set(Some(e.clone()));
+            return Err(e);
+        }
+    };
+
+    let decision = ApprovalDecision { role, action: ApprovalAction::Approve, comments: None };
+    decide_purchase_order(store, po_id, &decision).await
+}
+
+/// Record an approver's decision on the purchase order's current approval
+/// stage.
+///
+/// The order is approved once the last stage approves. A rejection or a
+/// return sends it back to draft.
+pub async fn decide_purchase_order(
     store: &PurchaseOrdersStore,
     po_id: &str,
-    approver: &str,
+    decision: &ApprovalDecision,
 ) -> Result<(), String> {
+    if let Err(e) = permissions::require(Action::ApprovePurchaseOrder) {
+        store.error.set(Some(e.clone()));
+        return Err(e);
+    }
+
     store.saving.set(true);
     store.error.set(None);
 
@@ -211,52 +365,85 @@ pub async fn approve_purchase_order(
                 return Err("Purchase Order must be in Pending Approval status to approve".to_string());
             }
 
-            po.status = PurchaseOrderStatus::Approved;
-            po.approved_by = Some(approver.to_string());
-            po.approved_at = Some(chrono_now());
-            po.updated_at = chrono_now();
+            let applied = match workflow::apply(ApprovalDocument::PurchaseOrder, po_id, &po.created_by_id, &mut po.approval_workflow, decision) {
+                Ok(applied) => applied,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.clone()));
+                    return Err(e);
+                }
+            };
+            match applied.state {
+                WorkflowState::Approved => {
+                    // Funds are committed when the last approver signs
+                    if let Err(e) = budget::commit_purchase_order(&po).await {
+                        store.saving.set(false);
+                        store.error.set(Some(e.clone()));
+                        return Err(e);
+                    }
+                    po.status = PurchaseOrderStatus::Approved;
+                    po.approved_by = auth::current().user.map(|u| u.name);
+                    po.approved_at = Some(now_iso());
+                }
+                WorkflowState::Rejected | WorkflowState::Returned => po.status = PurchaseOrderStatus::Draft,
+                WorkflowState::InProgress { .. } | WorkflowState::NotStarted => {}
+            }
+
+            po.updated_at = now_iso();
+            let endpoint = format!("/purchase-orders/{}/approval", po_id);
+            match backend::save(HttpMethod::Post, &endpoint, &po).await {
+                Ok(saved) => po = saved,
+                Err(e) => {
+                    // An order that was not approved holds no funds
+                    if applied.state == WorkflowState::Approved {
+                        let _ = budget::uncommit_purchase_order(&po).await;
+                    }
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
+            }
+            applied.record();
             store.selected.set(Some(po));
         }
     }
 
-    load_mock_purchase_orders(store);
+    load_purchase_order_list(store).await;
     store.saving.set(false);
     Ok(())
 }
 
-/// Reject a purchase order (return to draft)
-pub async fn reject_purchase_order(
+/// Move a stuck approval step of the purchase order to another approver
+pub async fn reassign_purchase_order_step(
     store: &PurchaseOrdersStore,
     po_id: &str,
-    reason: &str,
+    reassignment: &ApprovalReassignment,
 ) -> Result<(), String> {
     store.saving.set(true);
     store.error.set(None);
 
-    if reason.is_empty() {
-        store.saving.set(false);
-        return Err("Rejection reason is required".to_string());
-    }
-
     if let Some(mut po) = store.selected.get() {
         if po.id == po_id {
-            if po.status != PurchaseOrderStatus::PendingApproval {
+            if let Err(e) = workflow::reassign(ApprovalDocument::PurchaseOrder, po_id, &mut po.approval_workflow, reassignment) {
                 store.saving.set(false);
-                return Err("Purchase Order must be in Pending Approval status to reject".to_string());
+                store.error.set(Some(e.clone()));
+                return Err(e);
             }
 
-            po.status = PurchaseOrderStatus::Draft;
-            po.internal_notes = Some(format!(
-                "{}Rejected: {}",
-                po.internal_notes.map(|n| format!("{}\n\n", n)).unwrap_or_default(),
-                reason
-            ));
-            po.updated_at = chrono_now();
+            po.updated_at = now_iso();
+            let endpoint = format!("/purchase-orders/{}/approval", po_id);
+            match backend::save(HttpMethod::Post, &endpoint, &po).await {
+                Ok(saved) => po = saved,
+                Err(e) => {
+                    store.saving.set(false);
+                    store.error.set(Some(e.to_string()));
+                    return Err(e.to_string());
+                }
+            }
             store.selected.set(Some(po));
         }
     }
 
-    load_mock_purchase_orders(store);
     store.saving.set(false);
     Ok(())
 }
@@ -329,7 +516,14 @@ pub async fn cancel_purchase_order(
         }
     }
 
-    update_purchase_order_status(store, po_id, PurchaseOrderStatus::Cancelled).await
+    update_purchase_order_status(store, po_id, PurchaseOrderStatus::Cancelled).await?;
+
+    let po_number = store.selected.get().filter(|po| po.id == po_id).map_or_else(|| po_id.to_string(), |po| po.po_number);
+    if let Err(e) = budget::release_commitment(CommitmentSource::PurchaseOrder, &po_number).await {
+        store.error.set(Some(e.clone()));
+        return Err(e);
+    }
+    Ok(())
 }
 
 /// Delete a draft purchase order
@@ -344,9 +538,14 @@ pub async fn delete_purchase_order(store: &PurchaseOrdersStore, po_id: &str) ->
         }
     }
 
-    // In production, DELETE to API
+    if let Err(e) = backend::remove(&format!("/purchase-orders/{}", po_id)).await {
+        store.saving.set(false);
+        store.error.set(Some(e.to_string()));
+        return Err(e.to_string());
+    }
+
     store.selected.set(None);
-    load_mock_purchase_orders(store);
+    load_purchase_order_list(store).await;
 
     store.saving.set(false);
     Ok(())
@@ -364,30 +563,50 @@ pub async fn duplicate_purchase_order(store: &PurchaseOrdersStore, po_id: &str)
     store.saving.set(true);
     store.error.set(None);
 
-    if let Some(existing_po) = get_mock_purchase_order(po_id) {
+    let existing = if backend::is_mock() {
+        get_mock_purchase_order(po_id)
+    } else {
+        api::get::<PurchaseOrder>(&format!("/purchase-orders/{}", po_id)).await.ok()
+    };
+
+    if let Some(existing_po) = existing {
         let mut new_po = existing_po.clone();
-        let new_id = format!("PO-2025-{:04}", rand_id());
+        let new_id = next_po_number(store);
 
         new_po.id = new_id.clone();
         new_po.po_number = new_id.clone();
         new_po.status = PurchaseOrderStatus::Draft;
-        new_po.order_date = chrono_now_date();
+        new_po.order_date = today().to_iso();
         new_po.expected_delivery_date = String::new();
         new_po.actual_delivery_date = None;
-        new_po.created_at = chrono_now();
-        new_po.updated_at = chrono_now();
+        new_po.created_at = now_iso();
+        new_po.updated_at = now_iso();
         new_po.approved_by = None;
         new_po.approved_at = None;
         new_po.sent_at = None;
         new_po.acknowledged_at = None;
+        new_po.approval_workflow = Vec::new();
 
         // Reset delivery quantities
         for item in &mut new_po.line_items {
             item.delivered_quantity = 0;
         }
 
+        match backend::save(HttpMethod::Post, "/purchase-orders", &new_po).await {
+            Ok(saved) => {
+                reference::reconcile(DocumentType::PurchaseOrder, &new_id, &saved.po_number);
+                new_po = saved;
+            }
+            Err(e) => {
+                store.saving.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
+        let new_id = new_po.id.clone();
+
         store.selected.set(Some(new_po));
-        load_mock_purchase_orders(store);
+        load_purchase_order_list(store).await;
 
         store.saving.set(false);
         return Ok(new_id);
@@ -401,7 +620,7 @@ pub async fn duplicate_purchase_order(store: &PurchaseOrdersStore, po_id: &str)
 pub async fn get_pending_delivery_orders(store: &PurchaseOrdersStore) {
     store.loading.set(true);
     store.set_filter_pending_delivery(Some(true));
-    load_mock_purchase_orders(store);
+    load_purchase_order_list(store).await;
     store.loading.set(false);
 }
 
@@ -409,7 +628,7 @@ pub async fn get_pending_delivery_orders(store: &PurchaseOrdersStore) {
 pub async fn get_orders_by_supplier(store: &PurchaseOrdersStore, supplier_id: &str) {
     store.loading.set(true);
     store.set_filter_supplier(Some(supplier_id.to_string()));
-    load_mock_purchase_orders(store);
+    load_purchase_order_list(store).await;
     store.loading.set(false);
 }
 
@@ -417,24 +636,26 @@ pub async fn get_orders_by_supplier(store: &PurchaseOrdersStore, supplier_id: &s
 pub async fn get_orders_by_contract(store: &PurchaseOrdersStore, contract_ref: &str) {
     store.loading.set(true);
     store.set_filter_contract(Some(contract_ref.to_string()));
-    load_mock_purchase_orders(store);
+    load_purchase_order_list(store).await;
     store.loading.set(false);
 }
 
 // Helper functions
-fn rand_id() -> u32 {
-    // Simple pseudo-random for demo
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let duration = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-    (duration.as_millis() % 10000) as u32
-}
 
-fn chrono_now() -> String {
-    // In production, use chrono crate
-    "2025-02-27T10:00:00Z".to_string()
+/// Refresh the purchase order list from the configured backend
+async fn load_purchase_order_list(store: &PurchaseOrdersStore) {
+    if backend::is_mock() {
+        load_mock_purchase_orders(store);
+    } else if let Err(e) = backend::fetch_with_snapshot("/purchase-orders", &store.purchase_orders).await {
+        store.error.set(Some(e.to_string()));
+    }
 }
 
-fn chrono_now_date() -> String {
-    // In production, use chrono crate
-    "2025-02-27".to_string()
+/// Next provisional PO number, continuing after the orders already loaded
+fn next_po_number(store: &PurchaseOrdersStore) -> String {
+    if backend::is_mock() && store.purchase_orders.get().is_empty() {
+        load_mock_purchase_orders(store);
+    }
+    let existing = store.purchase_orders.get();
+    reference::issue(DocumentType::PurchaseOrder, existing.iter().map(|po| po.po_number.as_str())).number
 }
@@ -1,6 +1,9 @@
 //! Purchase Orders store
 
 use components::prelude::*;
+use crate::features::workflow::types::{ApprovalStep, StepStatus};
+use crate::util::api::FieldError;
+use crate::util::money::Money;
 use super::types::{
     PurchaseOrder, PurchaseOrderSummary, PurchaseOrderFilter, PurchaseOrderStatus,
     LineItem, DeliveryAddress, Supplier,
@@ -14,6 +17,8 @@ pub struct PurchaseOrdersStore {
     pub filter: Signal<PurchaseOrderFilter>,
     pub loading: Signal<bool>,
     pub error: Signal<Option<String>>,
+    /// Per-field validation errors from the last failed save
+    pub field_errors: Signal<Vec<FieldError>>,
     pub saving: Signal<bool>,
 }
 
@@ -25,6 +30,7 @@ impl PurchaseOrdersStore {
             filter: signal(PurchaseOrderFilter::default()),
             loading: signal(false),
             error: signal(None),
+            field_errors: signal(Vec::new()),
             saving: signal(false),
         }
     }
@@ -154,7 +160,7 @@ impl PurchaseOrdersStore {
     }
 
     /// Get total value of all POs
-    pub fn get_total_value(&self) -> f64 {
+    pub fn get_total_value(&self) -> Money {
         self.purchase_orders
             .get()
             .iter()
@@ -172,7 +178,7 @@ pub fn load_mock_purchase_orders(store: &PurchaseOrdersStore) {
             contract_ref: Some("CTR-2025-0234".to_string()),
             supplier_name: "TechSolutions SA (Pty) Ltd".to_string(),
             supplier_bbbee_level: 1,
-            total_amount: 1_250_000.0,
+            total_amount: Money::rands(1_250_000),
             currency: "ZAR".to_string(),
             status: PurchaseOrderStatus::Delivered,
             order_date: "2025-02-01".to_string(),
@@ -186,7 +192,7 @@ pub fn load_mock_purchase_orders(store: &PurchaseOrdersStore) {
             contract_ref: Some("CTR-2025-0198".to_string()),
             supplier_name: "Office Pro Distributors".to_string(),
             supplier_bbbee_level: 2,
-            total_amount: 85_500.0,
+            total_amount: Money::rands(85_500),
             currency: "ZAR".to_string(),
             status: PurchaseOrderStatus::Acknowledged,
             order_date: "2025-02-20".to_string(),
@@ -200,7 +206,7 @@ pub fn load_mock_purchase_orders(store: &PurchaseOrdersStore) {
             contract_ref: None,
             supplier_name: "SecureGuard Holdings".to_string(),
             supplier_bbbee_level: 1,
-            total_amount: 350_000.0,
+            total_amount: Money::rands(350_000),
             currency: "ZAR".to_string(),
             status: PurchaseOrderStatus::Sent,
             order_date: "2025-02-22".to_string(),
@@ -214,7 +220,7 @@ pub fn load_mock_purchase_orders(store: &PurchaseOrdersStore) {
             contract_ref: Some("CTR-2025-0089".to_string()),
             supplier_name: "AutoCare Fleet Management".to_string(),
             supplier_bbbee_level: 3,
-            total_amount: 125_800.0,
+            total_amount: Money::rands(125_800),
             currency: "ZAR".to_string(),
             status: PurchaseOrderStatus::PartiallyDelivered,
             order_date: "2025-02-10".to_string(),
@@ -228,7 +234,7 @@ pub fn load_mock_purchase_orders(store: &PurchaseOrdersStore) {
             contract_ref: None,
             supplier_name: "Gourmet Corporate Catering".to_string(),
             supplier_bbbee_level: 2,
-            total_amount: 45_000.0,
+            total_amount: Money::rands(45_000),
             currency: "ZAR".to_string(),
             status: PurchaseOrderStatus::Invoiced,
             order_date: "2025-02-05".to_string(),
@@ -242,7 +248,7 @@ pub fn load_mock_purchase_orders(store: &PurchaseOrdersStore) {
             contract_ref: Some("CTR-2025-0301".to_string()),
             supplier_name: "CloudFirst SA".to_string(),
             supplier_bbbee_level: 1,
-            total_amount: 620_000.0,
+            total_amount: Money::rands(620_000),
             currency: "ZAR".to_string(),
             status: PurchaseOrderStatus::PendingApproval,
             order_date: "2025-02-25".to_string(),
@@ -256,7 +262,7 @@ pub fn load_mock_purchase_orders(store: &PurchaseOrdersStore) {
             contract_ref: None,
             supplier_name: "Skills Development Academy".to_string(),
             supplier_bbbee_level: 1,
-            total_amount: 180_000.0,
+            total_amount: Money::rands(180_000),
             currency: "ZAR".to_string(),
             status: PurchaseOrderStatus::Draft,
             order_date: "2025-02-26".to_string(),
@@ -270,7 +276,7 @@ pub fn load_mock_purchase_orders(store: &PurchaseOrdersStore) {
             contract_ref: Some("CTR-2024-0123".to_string()),
             supplier_name: "CleanCorp Services".to_string(),
             supplier_bbbee_level: 2,
-            total_amount: 175_000.0,
+            total_amount: Money::rands(175_000),
             currency: "ZAR".to_string(),
             status: PurchaseOrderStatus::Closed,
             order_date: "2025-01-15".to_string(),
@@ -291,6 +297,7 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
             po_number: "PO-2025-0456".to_string(),
             contract_ref: Some("CTR-2025-0234".to_string()),
             requisition_ref: Some("REQ-2025-0089".to_string()),
+            cost_center: "CC-IT-001".to_string(),
             tender_ref: Some("TND-2024-0089".to_string()),
             supplier: Supplier {
                 id: "SUP-001".to_string(),
@@ -310,10 +317,10 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                     description: "Dell PowerEdge R750 Server".to_string(),
                     quantity: 5,
                     unit: "Each".to_string(),
-                    unit_price: 185_000.0,
-                    total_price: 925_000.0,
+                    unit_price: Money::rands(185_000),
+                    total_price: Money::rands(925_000),
                     tax_rate: 15.0,
-                    tax_amount: 138_750.0,
+                    tax_amount: Money::rands(138_750),
                     delivery_date: "2025-02-15".to_string(),
                     delivered_quantity: 5,
                     notes: None,
@@ -324,10 +331,10 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                     description: "NetApp Storage Array".to_string(),
                     quantity: 2,
                     unit: "Each".to_string(),
-                    unit_price: 95_000.0,
-                    total_price: 190_000.0,
+                    unit_price: Money::rands(95_000),
+                    total_price: Money::rands(190_000),
                     tax_rate: 15.0,
-                    tax_amount: 28_500.0,
+                    tax_amount: Money::rands(28_500),
                     delivery_date: "2025-02-15".to_string(),
                     delivered_quantity: 2,
                     notes: None,
@@ -338,10 +345,10 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                     description: "VMware vSphere Enterprise Plus License".to_string(),
                     quantity: 10,
                     unit: "License".to_string(),
-                    unit_price: 8_500.0,
-                    total_price: 85_000.0,
+                    unit_price: Money::rands(8500),
+                    total_price: Money::rands(85_000),
                     tax_rate: 15.0,
-                    tax_amount: 12_750.0,
+                    tax_amount: Money::rands(12_750),
                     delivery_date: "2025-02-10".to_string(),
                     delivered_quantity: 10,
                     notes: Some("License keys delivered via email".to_string()),
@@ -352,10 +359,10 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                     description: "Installation and Configuration Services".to_string(),
                     quantity: 40,
                     unit: "Hours".to_string(),
-                    unit_price: 1_250.0,
-                    total_price: 50_000.0,
+                    unit_price: Money::rands(1250),
+                    total_price: Money::rands(50_000),
                     tax_rate: 15.0,
-                    tax_amount: 7_500.0,
+                    tax_amount: Money::rands(7500),
                     delivery_date: "2025-02-28".to_string(),
                     delivered_quantity: 40,
                     notes: None,
@@ -374,9 +381,9 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                 delivery_instructions: Some("Delivery must be during office hours (08:00-16:00). Security clearance required.".to_string()),
             },
             status: PurchaseOrderStatus::Delivered,
-            subtotal: 1_250_000.0,
-            tax_total: 187_500.0,
-            total_amount: 1_437_500.0,
+            subtotal: Money::rands(1_250_000),
+            tax_total: Money::rands(187_500),
+            total_amount: Money::rands(1_437_500),
             currency: "ZAR".to_string(),
             payment_terms: "30 days from invoice".to_string(),
             order_date: "2025-02-01".to_string(),
@@ -390,18 +397,67 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                 "supplier_quote.pdf".to_string(),
             ],
             created_by: "Sarah Johnson".to_string(),
+            created_by_id: "U008".to_string(),
             created_at: "2025-01-28T09:30:00Z".to_string(),
             updated_at: "2025-02-14T16:45:00Z".to_string(),
             approved_by: Some("Michael Chen".to_string()),
             approved_at: Some("2025-01-30T11:00:00Z".to_string()),
             sent_at: Some("2025-02-01T08:00:00Z".to_string()),
             acknowledged_at: Some("2025-02-01T10:30:00Z".to_string()),
+            approval_workflow: vec![
+                ApprovalStep {
+                    step: 1,
+                    role: "Budget Holder".to_string(),
+                    approver: Some("Peter Mokwena".to_string()),
+                    approver_id: Some("U003".to_string()),
+                    status: StepStatus::Approved,
+                    date: Some("2025-01-29T08:45:00Z".to_string()),
+                    comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+                ApprovalStep {
+                    step: 1,
+                    role: "Procurement Manager".to_string(),
+                    approver: Some("Sarah Johnson".to_string()),
+                    approver_id: Some("U008".to_string()),
+                    status: StepStatus::Approved,
+                    date: Some("2025-01-29T10:10:00Z".to_string()),
+                    comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+                ApprovalStep {
+                    step: 2,
+                    role: "CFO".to_string(),
+                    approver: Some("Michael Chen".to_string()),
+                    approver_id: Some("U009".to_string()),
+                    status: StepStatus::Approved,
+                    date: Some("2025-01-30T11:00:00Z".to_string()),
+                    comments: Some("Funded from the 2024/25 ICT capital allocation".to_string()),
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+            ],
         }),
         "PO-2025-0453" => Some(PurchaseOrder {
             id: "PO-2025-0453".to_string(),
             po_number: "PO-2025-0453".to_string(),
             contract_ref: Some("CTR-2025-0089".to_string()),
             requisition_ref: Some("REQ-2025-0056".to_string()),
+            cost_center: "CC-IT-001".to_string(),
             tender_ref: None,
             supplier: Supplier {
                 id: "SUP-004".to_string(),
@@ -421,10 +477,10 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                     description: "Full Vehicle Service - Sedan".to_string(),
                     quantity: 10,
                     unit: "Service".to_string(),
-                    unit_price: 4_500.0,
-                    total_price: 45_000.0,
+                    unit_price: Money::rands(4500),
+                    total_price: Money::rands(45_000),
                     tax_rate: 15.0,
-                    tax_amount: 6_750.0,
+                    tax_amount: Money::rands(6750),
                     delivery_date: "2025-02-28".to_string(),
                     delivered_quantity: 6,
                     notes: None,
@@ -435,10 +491,10 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                     description: "Full Vehicle Service - SUV".to_string(),
                     quantity: 5,
                     unit: "Service".to_string(),
-                    unit_price: 6_200.0,
-                    total_price: 31_000.0,
+                    unit_price: Money::rands(6200),
+                    total_price: Money::rands(31_000),
                     tax_rate: 15.0,
-                    tax_amount: 4_650.0,
+                    tax_amount: Money::rands(4650),
                     delivery_date: "2025-02-28".to_string(),
                     delivered_quantity: 3,
                     notes: None,
@@ -449,10 +505,10 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                     description: "Replacement Tyres (Set of 4)".to_string(),
                     quantity: 8,
                     unit: "Set".to_string(),
-                    unit_price: 4_800.0,
-                    total_price: 38_400.0,
+                    unit_price: Money::rands(4800),
+                    total_price: Money::rands(38_400),
                     tax_rate: 15.0,
-                    tax_amount: 5_760.0,
+                    tax_amount: Money::rands(5760),
                     delivery_date: "2025-02-20".to_string(),
                     delivered_quantity: 8,
                     notes: Some("Continental tyres as specified".to_string()),
@@ -471,9 +527,9 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                 delivery_instructions: Some("Vehicles to be collected from various departments. Schedule to be coordinated with fleet manager.".to_string()),
             },
             status: PurchaseOrderStatus::PartiallyDelivered,
-            subtotal: 114_400.0,
-            tax_total: 17_160.0,
-            total_amount: 131_560.0,
+            subtotal: Money::rands(114_400),
+            tax_total: Money::rands(17_160),
+            total_amount: Money::rands(131_560),
             currency: "ZAR".to_string(),
             payment_terms: "30 days from invoice".to_string(),
             order_date: "2025-02-10".to_string(),
@@ -486,12 +542,45 @@ pub fn get_mock_purchase_order(id: &str) -> Option<PurchaseOrder> {
                 "vehicle_list.xlsx".to_string(),
             ],
             created_by: "Linda Nkosi".to_string(),
+            created_by_id: "U016".to_string(),
             created_at: "2025-02-08T14:00:00Z".to_string(),
             updated_at: "2025-02-20T11:30:00Z".to_string(),
             approved_by: Some("Robert Williams".to_string()),
             approved_at: Some("2025-02-09T09:15:00Z".to_string()),
             sent_at: Some("2025-02-10T08:00:00Z".to_string()),
             acknowledged_at: Some("2025-02-10T09:45:00Z".to_string()),
+            approval_workflow: vec![
+                ApprovalStep {
+                    step: 1,
+                    role: "Budget Holder".to_string(),
+                    approver: Some("Thabo Molefe".to_string()),
+                    approver_id: None,
+                    status: StepStatus::Approved,
+                    date: Some("2025-02-08T16:20:00Z".to_string()),
+                    comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+                ApprovalStep {
+                    step: 2,
+                    role: "Procurement Manager".to_string(),
+                    approver: Some("Robert Williams".to_string()),
+                    approver_id: None,
+                    status: StepStatus::Approved,
+                    date: Some("2025-02-09T09:15:00Z".to_string()),
+                    comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+            ],
         }),
         _ => None,
     }
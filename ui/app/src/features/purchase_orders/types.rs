@@ -1,6 +1,8 @@
 //! Purchase Order domain types
 
 use serde::{Deserialize, Serialize};
+use crate::features::workflow::types::ApprovalStep;
+use crate::util::money::{Money, VAT_RATE};
 
 /// Purchase Order status
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -64,10 +66,10 @@ pub struct LineItem {
     pub description: String,
     pub quantity: u32,
     pub unit: String,
-    pub unit_price: f64,
-    pub total_price: f64,
+    pub unit_price: Money,
+    pub total_price: Money,
     pub tax_rate: f64,
-    pub tax_amount: f64,
+    pub tax_amount: Money,
     pub delivery_date: String,
     pub delivered_quantity: u32,
     pub notes: Option<String>,
@@ -81,10 +83,10 @@ impl Default for LineItem {
             description: String::new(),
             quantity: 1,
             unit: "Each".to_string(),
-            unit_price: 0.0,
-            total_price: 0.0,
-            tax_rate: 15.0, // VAT rate in South Africa
-            tax_amount: 0.0,
+            unit_price: Money::ZERO,
+            total_price: Money::ZERO,
+            tax_rate: VAT_RATE,
+            tax_amount: Money::ZERO,
             delivery_date: String::new(),
             delivered_quantity: 0,
             notes: None,
@@ -95,8 +97,8 @@ impl Default for LineItem {
 impl LineItem {
     /// Calculate total price from quantity and unit price
     pub fn calculate_totals(&mut self) {
-        self.total_price = self.quantity as f64 * self.unit_price;
-        self.tax_amount = self.total_price * (self.tax_rate / 100.0);
+        self.total_price = self.unit_price * self.quantity;
+        self.tax_amount = self.total_price.vat_on(self.tax_rate);
     }
 
     /// Get outstanding quantity to be delivered
@@ -183,14 +185,17 @@ pub struct PurchaseOrder {
     pub po_number: String,
     pub contract_ref: Option<String>,
     pub requisition_ref: Option<String>,
+    /// Cost centre the order is funded from
+    #[serde(default)]
+    pub cost_center: String,
     pub tender_ref: Option<String>,
     pub supplier: Supplier,
     pub line_items: Vec<LineItem>,
     pub delivery_address: DeliveryAddress,
     pub status: PurchaseOrderStatus,
-    pub subtotal: f64,
-    pub tax_total: f64,
-    pub total_amount: f64,
+    pub subtotal: Money,
+    pub tax_total: Money,
+    pub total_amount: Money,
     pub currency: String,
     pub payment_terms: String,
     pub order_date: String,
@@ -200,12 +205,17 @@ pub struct PurchaseOrder {
     pub internal_notes: Option<String>,
     pub attachments: Vec<String>,
     pub created_by: String,
+    /// Id of the user who created the order
+    #[serde(default)]
+    pub created_by_id: String,
     pub created_at: String,
     pub updated_at: String,
     pub approved_by: Option<String>,
     pub approved_at: Option<String>,
     pub sent_at: Option<String>,
     pub acknowledged_at: Option<String>,
+    #[serde(default)]
+    pub approval_workflow: Vec<ApprovalStep>,
 }
 
 impl Default for PurchaseOrder {
@@ -215,14 +225,15 @@ impl Default for PurchaseOrder {
             po_number: String::new(),
             contract_ref: None,
             requisition_ref: None,
+            cost_center: String::new(),
             tender_ref: None,
             supplier: Supplier::default(),
             line_items: Vec::new(),
             delivery_address: DeliveryAddress::default(),
             status: PurchaseOrderStatus::Draft,
-            subtotal: 0.0,
-            tax_total: 0.0,
-            total_amount: 0.0,
+            subtotal: Money::ZERO,
+            tax_total: Money::ZERO,
+            total_amount: Money::ZERO,
             currency: "ZAR".to_string(),
             payment_terms: "30 days from invoice".to_string(),
             order_date: String::new(),
@@ -232,12 +243,14 @@ impl Default for PurchaseOrder {
             internal_notes: None,
             attachments: Vec::new(),
             created_by: String::new(),
+            created_by_id: String::new(),
             created_at: String::new(),
             updated_at: String::new(),
             approved_by: None,
             approved_at: None,
             sent_at: None,
             acknowledged_at: None,
+            approval_workflow: Vec::new(),
         }
     }
 }
@@ -255,6 +268,15 @@ impl PurchaseOrder {
         self.line_items.len()
     }
 
+    /// Line an invoice or receipt line refers to: by line id, falling back
+    /// to the item code for lines captured without one
+    pub fn line_for(&self, line_id: Option<&str>, item_code: &str) -> Option<&LineItem> {
+        self.line_items
+            .iter()
+            .find(|l| Some(l.id.as_str()) == line_id)
+            .or_else(|| self.line_items.iter().find(|l| !item_code.is_empty() && l.item_code == item_code))
+    }
+
     /// Check if PO is fully delivered
     pub fn is_fully_delivered(&self) -> bool {
         !self.line_items.is_empty() && self.line_items.iter().all(|item| item.is_fully_delivered())
@@ -318,7 +340,7 @@ pub struct PurchaseOrderSummary {
     pub contract_ref: Option<String>,
     pub supplier_name: String,
     pub supplier_bbbee_level: u8,
-    pub total_amount: f64,
+    pub total_amount: Money,
     pub currency: String,
     pub status: PurchaseOrderStatus,
     pub order_date: String,
@@ -3,5 +3,7 @@
 pub mod types;
 pub mod store;
 pub mod service;
+pub mod search;
 pub mod requisition_list;
 pub mod requisition_form;
+pub mod requisition_detail;
@@ -0,0 +1,426 @@
+//! Requisition detail page
+
+use components::prelude::*;
+use crate::Route;
+use crate::features::catalogue::store::CatalogueStore;
+use crate::features::contracts::store::ContractsStore;
+use crate::features::purchase_orders::store::PurchaseOrdersStore;
+use crate::features::tenders::store::TendersStore;
+use crate::features::tenders::types::{TenderType, RFT_THRESHOLD};
+use crate::page::not_found::entity_not_found;
+use crate::shared::layout::page_header;
+use crate::features::workflow::approval_timeline::{approval_timeline, approval_actions, approval_reassign};
+use crate::features::workflow::types::{ApprovalDecision, ApprovalReassignment};
+use crate::shared::components::{
+    panel, data_table, DataTableColumn, DataTableRow,
+    status_badge, StatusType,
+    notice_bar, NoticeType,
+};
+use crate::util::auth::AuthState;
+use crate::util::format::{format_currency, format_currency_full, format_date};
+use crate::util::permissions::{self, Action};
+use super::types::{ConversionTarget, Requisition, RequisitionStatus};
+use super::store::RequisitionsStore;
+use super::service::{self, ConversionStores};
+use crate::util::i18n::{t, tf, tn};
+
+/// Requisition detail page
+#[component]
+pub fn requisition_detail(requisition_id: String) -> View {
+    let store = use_context::<RequisitionsStore>();
+    let stores = ConversionStores {
+        tenders: use_context::<TendersStore>(),
+        purchase_orders: use_context::<PurchaseOrdersStore>(),
+        catalogue: use_context::<CatalogueStore>(),
+        contracts: use_context::<ContractsStore>(),
+    };
+    let auth_state = use_context::<Signal<AuthState>>();
+    let loading = signal(true);
+
+    // Load requisition on mount, with the catalogue and contracts that
+    // decide whether it can be ordered directly
+    effect({
+        let store = store.clone();
+        let stores = stores.clone();
+        let requisition_id = requisition_id.clone();
+        let loading = loading.clone();
+        move || {
+            let store = store.clone();
+            let stores = stores.clone();
+            let requisition_id = requisition_id.clone();
+            let loading = loading.clone();
+            spawn(async move {
+                let requisition = service::get_requisition(&store, &requisition_id).await;
+                store.selected.set(requisition);
+                service::load_conversion_sources(&stores).await;
+                loading.set(false);
+            });
+        }
+    });
+
+    let can_convert = permissions::can_perform(&auth_state.get(), Action::ConvertRequisition);
+
+    let is_loading = loading.get();
+    let error = store.error.get();
+    let requisition = store.selected.get().filter(|r| r.id == requisition_id);
+
+    view! {
+        style {
+            r#"
+            .requisition-detail { display: flex; flex-direction: column; gap: var(--space-6); }
+            .requisition-detail-grid {
+                display: grid;
+                grid-template-columns: 2fr 1fr;
+                gap: 24px;
+            }
+            .requisition-detail .detail-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 8px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .requisition-detail .detail-row:last-child { border-bottom: none; }
+            .requisition-detail .detail-row .label { color: var(--text-muted); }
+            .requisition-detail .detail-row .value { font-weight: 500; text-align: right; }
+            .requisition-detail .sourcing-note {
+                font-size: 13px;
+                color: var(--text-muted);
+                line-height: 1.5;
+                margin-bottom: 12px;
+            }
+            .requisition-detail .sourcing-actions {
+                display: flex;
+                flex-direction: column;
+                gap: 8px;
+            }
+            .requisition-detail .justification {
+                font-size: 13px;
+                color: var(--text-muted);
+                line-height: 1.5;
+            }
+            @media (max-width: 1024px) {
+                .requisition-detail-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="requisition-detail" data-testid="requisition-detail">
+            if is_loading {
+                <div class="loading-state">{t("Loading requisition...")}</div>
+            } else if let Some(requisition) = requisition {
+                {render_requisition(requisition, store.clone(), stores.clone(), can_convert)}
+            } else {
+                {entity_not_found(t("Requisition"), &requisition_id, error, Route::RequisitionsList, t("Back to Requisitions"))}
+            }
+        </div>
+    }
+}
+
+fn render_requisition(requisition: Requisition, store: RequisitionsStore, stores: ConversionStores, can_convert: bool) -> View {
+    let status = match requisition.status {
+        RequisitionStatus::Draft => status_badge(StatusType::Draft),
+        RequisitionStatus::Submitted => status_badge(StatusType::Submitted),
+        RequisitionStatus::PendingApproval => status_badge(StatusType::Pending),
+        RequisitionStatus::Approved => status_badge(StatusType::Approved),
+        RequisitionStatus::Rejected => status_badge(StatusType::Rejected),
+        RequisitionStatus::Cancelled => status_badge(StatusType::Cancelled),
+        RequisitionStatus::InProgress => status_badge(StatusType::InProgress),
+        RequisitionStatus::Complete => status_badge(StatusType::Complete),
+    };
+
+    let columns = vec![
+        DataTableColumn { key: "description".to_string(), label: t("Description").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "category".to_string(), label: t("Category").to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "quantity".to_string(), label: t("Qty").to_string(), width: Some("80px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "unit_price".to_string(), label: t("Unit Price").to_string(), width: Some("120px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "total".to_string(), label: t("Total").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+    ];
+
+    let rows: Vec<DataTableRow> = requisition.line_items.iter().map(|item| {
+        DataTableRow {
+            id: item.id.clone(),
+            cells: vec![
+                view! { <span>{item.description.clone()}</span> },
+                view! { <span>{item.category.clone()}</span> },
+                view! { <span>{format!("{} {}", item.quantity, item.unit)}</span> },
+                view! { <span>{format_currency_full(item.unit_price)}</span> },
+                view! { <span class="amount-cell">{format_currency_full(item.total)}</span> },
+            ],
+        }
+    }).collect();
+
+    let handle_submit = Callback::<()>::new({
+        let store = store.clone();
+        let id = requisition.id.clone();
+        move |_| {
+            let store = store.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::submit_requisition(&store, &id).await;
+            });
+        }
+    });
+
+    let handle_decide = Callback::new({
+        let store = store.clone();
+        let id = requisition.id.clone();
+        move |decision: ApprovalDecision| {
+            let store = store.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::decide_requisition(&store, &id, &decision).await;
+            });
+        }
+    });
+
+    let handle_reassign = Callback::new({
+        let store = store.clone();
+        let id = requisition.id.clone();
+        move |reassignment: ApprovalReassignment| {
+            let store = store.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::reassign_requisition_step(&store, &id, &reassignment).await;
+            });
+        }
+    });
+
+    let handle_convert_tender = Callback::<()>::new({
+        let store = store.clone();
+        let stores = stores.clone();
+        let id = requisition.id.clone();
+        move |_| {
+            let store = store.clone();
+            let stores = stores.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::convert_to_tender(&store, &stores, &id).await;
+            });
+        }
+    });
+
+    let handle_convert_orders = Callback::<()>::new({
+        let store = store.clone();
+        let stores = stores.clone();
+        let id = requisition.id.clone();
+        move |_| {
+            let store = store.clone();
+            let stores = stores.clone();
+            let id = id.clone();
+            spawn(async move {
+                let _ = service::convert_to_purchase_orders(&store, &stores, &id).await;
+            });
+        }
+    });
+
+    let busy = store.loading.get();
+    let action_error = store.error.get();
+    let is_draft = requisition.status == RequisitionStatus::Draft;
+    let edit_href = format!("/requisitions/{}/edit", requisition.id);
+
+    view! {
+        {page_header(
+            requisition.description.clone(),
+            Some(tf("Requisition {id} - {department}", &[("id", requisition.id.to_string()), ("department", requisition.department.to_string())])),
+            vec![
+                view! { <a href="/requisitions" class="btn btn-secondary">{t("Back to Requisitions")}</a> },
+                if is_draft {
+                    view! { <a href={edit_href} class="btn btn-secondary">{t("Edit")}</a> }
+                } else {
+                    view! { <span></span> }
+                },
+                if is_draft {
+                    view! {
+                        <button class="btn btn-primary" disabled={busy} data-testid="requisition-submit" on:click={handle_submit}>
+                            {t("Submit for Approval")}
+                        </button>
+                    }
+                } else {
+                    view! { <span></span> }
+                },
+            ]
+        )}
+
+        <div class="requisition-detail-grid">
+            <div>
+                {panel(
+                    t("Line Items").to_string(),
+                    vec![],
+                    vec![data_table(columns, rows, None)]
+                )}
+
+                {panel(
+                    t("Justification").to_string(),
+                    vec![],
+                    vec![view! { <p class="justification">{requisition.justification.clone()}</p> }]
+                )}
+            </div>
+
+            <div>
+                {panel(
+                    t("Summary").to_string(),
+                    vec![],
+                    vec![
+                        view! {
+                            <div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Status")}</span>
+                                    <span class="value">{status}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Amount")}</span>
+                                    <span class="value">{format_currency(requisition.amount)}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Priority")}</span>
+                                    <span class="value">{requisition.priority.label()}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Cost Centre")}</span>
+                                    <span class="value">{requisition.cost_center.clone()}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Requester")}</span>
+                                    <span class="value">{requisition.requester.clone()}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Created")}</span>
+                                    <span class="value">{format_date(&requisition.created_at)}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Required By")}</span>
+                                    <span class="value">{requisition.required_by.as_ref().map(|d| format_date(d)).unwrap_or_else(|| "-".to_string())}</span>
+                                </div>
+                            </div>
+                        }
+                    ]
+                )}
+
+                {sourcing_panel(&requisition, &stores, can_convert && !busy, handle_convert_tender, handle_convert_orders)}
+
+                {panel(
+                    t("Approval Workflow").to_string(),
+                    vec![],
+                    vec![
+                        approval_timeline(requisition.approval_workflow.clone()),
+                        approval_actions(requisition.approval_workflow.clone(), requisition.requester_id.clone(), busy, handle_decide),
+                        approval_reassign(requisition.approval_workflow.clone(), busy, handle_reassign),
+                        if let Some(err) = action_error {
+                            notice_bar(err, NoticeType::Error, None)
+                        } else {
+                            view! { <span></span> }
+                        },
+                    ]
+                )}
+            </div>
+        </div>
+    }
+}
+
+/// How the requisition is being sourced, or the ways it can be once approved
+fn sourcing_panel(
+    requisition: &Requisition,
+    stores: &ConversionStores,
+    can_convert: bool,
+    on_tender: Callback<()>,
+    on_orders: Callback<()>,
+) -> View {
+    let converted = requisition.tender_ref.is_some() || !requisition.purchase_order_refs.is_empty();
+    if !converted && requisition.status != RequisitionStatus::Approved {
+        return view! { <span></span> };
+    }
+
+    let tender_ref = requisition.tender_ref.clone();
+    let order_refs = requisition.purchase_order_refs.clone();
+    let tender_type = TenderType::for_value(requisition.amount);
+    let method_note = tf(
+        if requisition.amount < RFT_THRESHOLD {
+            "Estimated at {amount}, below the {threshold} threshold: goes out as an {method} ({description})."
+        } else {
+            "Estimated at {amount}, at or above the {threshold} threshold: goes out as an {method} ({description})."
+        },
+        &[
+            ("amount", format_currency(requisition.amount)),
+            ("threshold", format_currency(RFT_THRESHOLD)),
+            ("method", tender_type.label().to_string()),
+            ("description", tender_type.description().to_string()),
+        ],
+    );
+    let orders = service::contracted_orders(
+        requisition,
+        &stores.catalogue.items.get(),
+        &stores.contracts.contracts.get(),
+    );
+    let order_note = match &orders {
+        Ok(orders) => tn(
+            "All items are catalogue items under contract: {n} purchase order can be raised directly.",
+            "All items are catalogue items under contract: {n} purchase orders can be raised directly.",
+            orders.len() as i64,
+        ),
+        Err(reason) => tf("Cannot be ordered directly: {reason}.", &[("reason", reason.to_string())]),
+    };
+    let can_order = orders.is_ok();
+
+    // An interrupted conversion can only be resumed, not started another way
+    let resuming = requisition.pending_conversion.as_ref().map(|p| p.target);
+    let pending_note = requisition.pending_conversion.as_ref().map(|p| tf(
+        "A conversion started by {name} on {date} did not finish. Resuming it reuses anything it already created.",
+        &[("name", p.started_by.clone()), ("date", format_date(&p.started_at))],
+    ));
+    let show_tender = resuming != Some(ConversionTarget::PurchaseOrders);
+    let show_orders = can_order && resuming != Some(ConversionTarget::Tender);
+    let tender_label = if resuming.is_some() {
+        t("Resume Conversion").to_string()
+    } else {
+        tf("Create {tender_type}", &[("tender_type", tender_type.label().to_string())])
+    };
+    let orders_label = if resuming.is_some() { t("Resume Conversion") } else { t("Create Purchase Order") };
+
+    panel(
+        t("Sourcing").to_string(),
+        vec![],
+        vec![view! {
+            <div data-testid="requisition-sourcing">
+                if converted {
+                    <div>
+                        if let Some(tender_id) = tender_ref {
+                            <div class="detail-row">
+                                <span class="label">{t("Tender")}</span>
+                                <a class="value" href={Route::TendersEdit(tender_id.clone()).to_path()}>{tender_id.clone()}</a>
+                            </div>
+                        }
+                        for po_id in order_refs {
+                            <div class="detail-row">
+                                <span class="label">{t("Purchase Order")}</span>
+                                <a class="value" href={Route::PurchaseOrdersDetail(po_id.clone()).to_path()}>{po_id.clone()}</a>
+                            </div>
+                        }
+                    </div>
+                } else {
+                    <div>
+                        <p class="sourcing-note">{method_note}</p>
+                        <p class="sourcing-note">{order_note}</p>
+                        if let Some(note) = pending_note {
+                            <p class="sourcing-note" data-testid="requisition-conversion-pending">{note}</p>
+                        }
+                        if can_convert {
+                            <div class="sourcing-actions">
+                                if show_tender {
+                                    <button class="btn btn-primary" data-testid="requisition-convert-tender" on:click={on_tender}>
+                                        {tender_label}
+                                    </button>
+                                }
+                                if show_orders {
+                                    <button class="btn btn-secondary" data-testid="requisition-convert-orders" on:click={on_orders}>
+                                        {orders_label}
+                                    </button>
+                                }
+                            </div>
+                        }
+                    </div>
+                }
+            </div>
+        }],
+    )
+}
@@ -2,6 +2,11 @@
 
 use components::prelude::*;
 use wasm_bindgen::JsCast;
+use crate::Route;
+use crate::features::catalogue::service as catalogue_service;
+use crate::features::catalogue::store::CatalogueStore;
+use crate::features::catalogue::types::CatalogueItem;
+use crate::page::not_found::entity_not_found;
 use crate::shared::layout::page_header;
 use crate::shared::components::{
     panel, panel_with_footer,
@@ -12,9 +17,12 @@ use crate::shared::forms::{
     currency_input, file_upload, UploadedFile,
 };
 use crate::util::format::format_currency;
+use crate::util::money::Money;
+use crate::util::api::field_error;
 use super::store::RequisitionsStore;
-use super::types::{Requisition, LineItem, Priority};
+use super::types::{Requisition, LineItem, Priority, SupplierGroup, group_by_supplier};
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Requisition form steps
 #[derive(Clone, Copy, PartialEq)]
@@ -37,10 +45,12 @@ impl FormStep {
     }
 }
 
-/// Requisition form component
+/// Requisition form component; `requisition_id` is set when editing
 #[component]
-pub fn requisition_form() -> View {
+pub fn requisition_form(requisition_id: Option<String>) -> View {
     let store = use_context::<RequisitionsStore>();
+    let catalogue = use_context::<CatalogueStore>();
+    let is_edit = requisition_id.is_some();
 
     // Form step state
     let current_step = store.form_step.clone();
@@ -58,33 +68,107 @@ pub fn requisition_form() -> View {
     // Line items state
     let line_items: Signal<Vec<LineItem>> = signal(vec![LineItem::new()]);
 
+    // Catalogue picker search
+    let catalogue_query = signal(String::new());
+
     // Attachments state
     let attachments: Signal<Vec<UploadedFile>> = signal(Vec::new());
 
-    // Validation errors
-    let errors: Signal<Vec<(String, String)>> = signal(Vec::new());
+    // Requisition being edited, as loaded
+    let editing: Signal<Option<Requisition>> = signal(None);
+    // Set when the requisition being edited cannot be found
+    let missing = signal(false);
+
+    // Load existing requisition if editing
+    effect({
+        let store = store.clone();
+        let requisition_id = requisition_id.clone();
+        let editing = editing.clone();
+        let missing = missing.clone();
+        let description = description.clone();
+        let justification = justification.clone();
+        let department = department.clone();
+        let cost_center = cost_center.clone();
+        let priority = priority.clone();
+        let required_by = required_by.clone();
+        let delivery_address = delivery_address.clone();
+        let notes = notes.clone();
+        let line_items = line_items.clone();
+        move || {
+            if let Some(ref id) = requisition_id {
+                let store = store.clone();
+                let id = id.clone();
+                let editing = editing.clone();
+                let missing = missing.clone();
+                let description = description.clone();
+                let justification = justification.clone();
+                let department = department.clone();
+                let cost_center = cost_center.clone();
+                let priority = priority.clone();
+                let required_by = required_by.clone();
+                let delivery_address = delivery_address.clone();
+                let notes = notes.clone();
+                let line_items = line_items.clone();
+                spawn(async move {
+                    if let Some(req) = service::get_requisition(&store, &id).await {
+                        description.set(req.description.clone());
+                        justification.set(req.justification.clone());
+                        department.set(req.department.clone());
+                        cost_center.set(req.cost_center.clone());
+                        priority.set(req.priority.as_str().to_string());
+                        required_by.set(req.required_by.clone().unwrap_or_default());
+                        delivery_address.set(req.delivery_address.clone());
+                        notes.set(req.notes.clone().unwrap_or_default());
+                        if !req.line_items.is_empty() {
+                            line_items.set(req.line_items.clone());
+                        }
+                        editing.set(Some(req));
+                    } else {
+                        missing.set(true);
+                    }
+                });
+            }
+        }
+    });
+
+    // Load the catalogue the picker searches
+    effect({
+        let catalogue = catalogue.clone();
+        move || {
+            let catalogue = catalogue.clone();
+            spawn(async move {
+                if catalogue.items.get().is_empty() {
+                    catalogue_service::load_catalogue(&catalogue).await;
+                }
+            });
+        }
+    });
+
+    // Validation errors from the last save, keyed by field
+    let field_errors = store.field_errors.clone();
+    let field_err = |field: &str| field_error(&field_errors.get(), field);
 
     // Build stepper items
     let step = current_step.get();
     let stepper_items = vec![
         StepperItem {
             number: 1,
-            label: "Details".to_string(),
+            label: t("Details").to_string(),
             status: if step > 1 { StepStatus::Completed } else if step == 1 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 2,
-            label: "Line Items".to_string(),
+            label: t("Line Items").to_string(),
             status: if step > 2 { StepStatus::Completed } else if step == 2 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 3,
-            label: "Attachments".to_string(),
+            label: t("Attachments").to_string(),
             status: if step > 3 { StepStatus::Completed } else if step == 3 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 4,
-            label: "Review".to_string(),
+            label: t("Review").to_string(),
             status: if step == 4 { StepStatus::Active } else { StepStatus::Pending },
         },
     ];
@@ -130,6 +214,37 @@ pub fn requisition_form() -> View {
         })
     };
 
+    // Catalogue picker handlers
+    let on_catalogue_search = {
+        let catalogue_query = catalogue_query.clone();
+        Callback::new(move |e: web_sys::Event| {
+            let target = e.target().unwrap();
+            let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
+            catalogue_query.set(input.value());
+        })
+    };
+
+    // Picking an item already in the cart orders more of it; a blank
+    // starting line is replaced rather than left empty
+    let on_add_catalogue_item = {
+        let catalogue = catalogue.clone();
+        let line_items = line_items.clone();
+        Callback::new(move |item_id: String| {
+            let Some(item) = catalogue.item(&item_id) else {
+                return;
+            };
+            let mut items = line_items.get();
+            if let Some(line) = items.iter_mut().find(|li| li.catalogue_item_id.as_deref() == Some(item.id.as_str())) {
+                line.quantity += item.supplier.minimum_order_quantity.max(1);
+                line.apply_catalogue_price(&item);
+            } else {
+                items.retain(|li| !li.description.is_empty() || li.catalogue_item_id.is_some());
+                items.push(LineItem::from_catalogue(&item));
+            }
+            line_items.set(items);
+        })
+    };
+
     // Remove line item handler
     let on_remove_line_item = {
         let line_items = line_items.clone();
@@ -155,6 +270,7 @@ pub fn requisition_form() -> View {
     // Save draft handler
     let on_save_draft = {
         let store = store.clone();
+        let editing = editing.clone();
         let description = description.clone();
         let justification = justification.clone();
         let department = department.clone();
@@ -165,7 +281,9 @@ pub fn requisition_form() -> View {
         let notes = notes.clone();
         let line_items = line_items.clone();
         Callback::<()>::new(move |_| {
-            let mut requisition = Requisition::default();
+            let existing = editing.get();
+            let is_update = existing.is_some();
+            let mut requisition = existing.unwrap_or_default();
             requisition.description = description.get();
             requisition.justification = justification.get();
             requisition.department = department.get();
@@ -180,12 +298,19 @@ pub fn requisition_form() -> View {
             requisition.delivery_address = delivery_address.get();
             requisition.notes = if notes.get().is_empty() { None } else { Some(notes.get()) };
             requisition.line_items = line_items.get();
-            requisition.requester = "Current User".to_string();
-            requisition.requester_email = "user@gov.za".to_string();
+            if !is_update {
+                requisition.requester = "Current User".to_string();
+                requisition.requester_email = "user@gov.za".to_string();
+            }
 
             let store = store.clone();
             spawn(async move {
-                match service::create_requisition(&store, requisition).await {
+                let result = if is_update {
+                    service::update_requisition(&store, requisition).await
+                } else {
+                    service::create_requisition(&store, requisition).await
+                };
+                match result {
                     Ok(req) => {
                         web_sys::console::log_1(&format!("Requisition {} saved as draft", req.id).into());
                     }
@@ -209,24 +334,30 @@ pub fn requisition_form() -> View {
 
     // Department options
     let department_options = vec![
-        SelectOption { value: "Information Technology".to_string(), label: "Information Technology".to_string() },
-        SelectOption { value: "Administration".to_string(), label: "Administration".to_string() },
-        SelectOption { value: "Facilities".to_string(), label: "Facilities".to_string() },
-        SelectOption { value: "Transport".to_string(), label: "Transport".to_string() },
-        SelectOption { value: "Human Resources".to_string(), label: "Human Resources".to_string() },
-        SelectOption { value: "Health Services".to_string(), label: "Health Services".to_string() },
-        SelectOption { value: "Finance".to_string(), label: "Finance".to_string() },
+        SelectOption { value: "Information Technology".to_string(), label: t("Information Technology").to_string() },
+        SelectOption { value: "Administration".to_string(), label: t("Administration").to_string() },
+        SelectOption { value: "Facilities".to_string(), label: t("Facilities").to_string() },
+        SelectOption { value: "Transport".to_string(), label: t("Transport").to_string() },
+        SelectOption { value: "Human Resources".to_string(), label: t("Human Resources").to_string() },
+        SelectOption { value: "Health Services".to_string(), label: t("Health Services").to_string() },
+        SelectOption { value: "Finance".to_string(), label: t("Finance").to_string() },
     ];
 
     let priority_options = vec![
-        SelectOption { value: "low".to_string(), label: "Low".to_string() },
-        SelectOption { value: "medium".to_string(), label: "Medium".to_string() },
-        SelectOption { value: "high".to_string(), label: "High".to_string() },
-        SelectOption { value: "urgent".to_string(), label: "Urgent".to_string() },
+        SelectOption { value: "low".to_string(), label: t("Low").to_string() },
+        SelectOption { value: "medium".to_string(), label: t("Medium").to_string() },
+        SelectOption { value: "high".to_string(), label: t("High").to_string() },
+        SelectOption { value: "urgent".to_string(), label: t("Urgent").to_string() },
     ];
 
+    if let (true, Some(id)) = (missing.get(), requisition_id.as_ref()) {
+        return entity_not_found(t("Requisition"), id, None, Route::RequisitionsList, t("Back to Requisitions"));
+    }
+
     // Calculate total
-    let total: f64 = line_items.get().iter().map(|li| li.total).sum();
+    let total: Money = line_items.get().iter().map(|li| li.total).sum();
+    let supplier_groups = group_by_supplier(&line_items.get());
+    let catalogue_results = catalogue.search_items(&catalogue_query.get(), 8);
 
     view! {
         style {
@@ -325,6 +456,74 @@ pub fn requisition_form() -> View {
                 font-weight: 600;
                 color: var(--navy);
             }
+            .catalogue-picker {
+                margin-bottom: 16px;
+            }
+            .catalogue-picker input {
+                width: 100%;
+                padding: 8px 12px;
+                border: 1px solid var(--border);
+                border-radius: var(--radius-sm);
+                font-size: 13px;
+            }
+            .catalogue-results {
+                border: 1px solid var(--border);
+                border-radius: var(--radius);
+                margin-top: 8px;
+            }
+            .catalogue-result {
+                display: flex;
+                justify-content: space-between;
+                align-items: center;
+                gap: 12px;
+                padding: 10px 12px;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .catalogue-result:last-child { border-bottom: none; }
+            .catalogue-result-name { font-weight: 500; }
+            .catalogue-result-meta,
+            .line-item-source {
+                font-size: 12px;
+                color: var(--text-muted);
+                margin-top: 2px;
+            }
+            .catalogue-result-price {
+                font-family: IBM Plex Mono, monospace;
+                text-align: right;
+                white-space: nowrap;
+            }
+            .catalogue-warning {
+                font-size: 12px;
+                color: var(--amber);
+                margin-top: 2px;
+            }
+            .supplier-groups {
+                margin-top: 16px;
+                padding: 12px 16px;
+                border: 1px solid var(--border);
+                border-radius: var(--radius);
+            }
+            .supplier-groups-title {
+                font-weight: 600;
+                font-size: 13px;
+                color: var(--navy);
+                margin-bottom: 8px;
+            }
+            .supplier-group {
+                display: flex;
+                justify-content: space-between;
+                padding: 6px 0;
+                font-size: 13px;
+            }
+            .supplier-group-total {
+                font-family: IBM Plex Mono, monospace;
+            }
+            .supplier-groups-note {
+                font-size: 12px;
+                color: var(--text-muted);
+                margin-top: 8px;
+            }
             .review-section {
                 margin-bottom: 24px;
             }
@@ -362,10 +561,14 @@ pub fn requisition_form() -> View {
 
         <div class="requisition-form" data-testid="requisition-form">
             {page_header(
-                "New Requisition".to_string(),
-                Some("Create a new purchase requisition".to_string()),
+                if is_edit { t("Edit Requisition").to_string() } else { t("New Requisition").to_string() },
+                if is_edit {
+                    Some(tf("Editing {id}", &[("id", requisition_id.clone().unwrap_or_default())]))
+                } else {
+                    Some(t("Create a new purchase requisition").to_string())
+                },
                 vec![
-                    view! { <a href="/requisitions" class="btn btn-secondary">"Cancel"</a> },
+                    view! { <a href="/requisitions" class="btn btn-secondary">{t("Cancel")}</a> },
                 ]
             )}
 
@@ -375,31 +578,31 @@ pub fn requisition_form() -> View {
             // Step 1: Details
             if current_step.get() == 1 {
                 {panel_with_footer(
-                    "Requisition Details".to_string(),
+                    t("Requisition Details").to_string(),
                     vec![],
                     vec![
                         form_group(
-                            Some("Basic Information".to_string()),
+                            Some(t("Basic Information").to_string()),
                             2,
                             vec![
                                 text_input(
-                                    "Description".to_string(),
+                                    t("Description").to_string(),
                                     description.clone(),
-                                    Some("Brief description of the requisition".to_string()),
+                                    Some(t("Brief description of the requisition").to_string()),
                                     true,
                                     false,
-                                    None,
+                                    field_err("description"),
                                     None,
                                     None,
                                 ),
                                 select(
-                                    "Priority".to_string(),
+                                    t("Priority").to_string(),
                                     priority.clone(),
                                     priority_options,
                                     None,
                                     true,
                                     false,
-                                    None,
+                                    field_err("priority"),
                                 ),
                             ]
                         ),
@@ -408,63 +611,63 @@ pub fn requisition_form() -> View {
                             1,
                             vec![
                                 textarea(
-                                    "Justification".to_string(),
+                                    t("Justification").to_string(),
                                     justification.clone(),
-                                    Some("Explain why this requisition is needed...".to_string()),
+                                    Some(t("Explain why this requisition is needed...").to_string()),
                                     true,
                                     false,
                                     Some(4),
-                                    None,
-                                    Some("Provide detailed justification for the procurement".to_string()),
+                                    field_err("justification"),
+                                    Some(t("Provide detailed justification for the procurement").to_string()),
                                 ),
                             ]
                         ),
                         form_group(
-                            Some("Department & Budget".to_string()),
+                            Some(t("Department & Budget").to_string()),
                             2,
                             vec![
                                 select(
-                                    "Department".to_string(),
+                                    t("Department").to_string(),
                                     department.clone(),
                                     department_options,
-                                    Some("Select department".to_string()),
+                                    Some(t("Select department").to_string()),
                                     true,
                                     false,
-                                    None,
+                                    field_err("department"),
                                 ),
                                 text_input(
-                                    "Cost Center".to_string(),
+                                    t("Cost Center").to_string(),
                                     cost_center.clone(),
-                                    Some("e.g., CC-IT-001".to_string()),
+                                    Some(t("e.g., CC-IT-001").to_string()),
                                     true,
                                     false,
-                                    None,
+                                    field_err("cost_center"),
                                     None,
                                     None,
                                 ),
                             ]
                         ),
                         form_group(
-                            Some("Delivery Information".to_string()),
+                            Some(t("Delivery Information").to_string()),
                             2,
                             vec![
                                 text_input(
-                                    "Required By".to_string(),
+                                    t("Required By").to_string(),
                                     required_by.clone(),
                                     Some("YYYY-MM-DD".to_string()),
                                     false,
                                     false,
-                                    None,
+                                    field_err("required_by"),
                                     None,
                                     Some("date".to_string()),
                                 ),
                                 text_input(
-                                    "Delivery Address".to_string(),
+                                    t("Delivery Address").to_string(),
                                     delivery_address.clone(),
-                                    Some("Delivery location".to_string()),
+                                    Some(t("Delivery location").to_string()),
                                     true,
                                     false,
-                                    None,
+                                    field_err("delivery_address"),
                                     None,
                                     None,
                                 ),
@@ -475,9 +678,9 @@ pub fn requisition_form() -> View {
                             1,
                             vec![
                                 textarea(
-                                    "Additional Notes".to_string(),
+                                    t("Additional Notes").to_string(),
                                     notes.clone(),
-                                    Some("Any additional information...".to_string()),
+                                    Some(t("Any additional information...").to_string()),
                                     false,
                                     false,
                                     Some(3),
@@ -488,8 +691,8 @@ pub fn requisition_form() -> View {
                         ),
                     ],
                     vec![
-                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>"Save Draft"</button> },
-                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>"Continue to Line Items"</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>{t("Save Draft")}</button> },
+                        view! { <button class="btn btn-primary" data-testid="requisition-form-next" on:click={on_next.clone()}>{t("Continue to Line Items")}</button> },
                     ]
                 )}
             }
@@ -497,25 +700,49 @@ pub fn requisition_form() -> View {
             // Step 2: Line Items
             if current_step.get() == 2 {
                 {panel_with_footer(
-                    "Line Items".to_string(),
+                    t("Line Items").to_string(),
                     vec![],
                     vec![
+                        view! {
+                            <div class="catalogue-picker" data-testid="catalogue-picker">
+                                <input
+                                    type="search"
+                                    value={catalogue_query.get()}
+                                    placeholder={t("Search the catalogue by name, item code or supplier")}
+                                    data-testid="catalogue-picker-search"
+                                    on:input={on_catalogue_search}
+                                />
+                                if !catalogue_results.is_empty() {
+                                    <div class="catalogue-results">
+                                        for item in catalogue_results.iter() {
+                                            {catalogue_result(item.clone(), on_add_catalogue_item.clone())}
+                                        }
+                                    </div>
+                                }
+                            </div>
+                        },
                         view! {
                             <table class="line-items-table">
                                 <thead>
                                     <tr>
-                                        <th style="width: 30%;">"Description"</th>
-                                        <th style="width: 15%;">"Category"</th>
-                                        <th style="width: 10%;">"Qty"</th>
-                                        <th style="width: 10%;">"Unit"</th>
-                                        <th style="width: 15%;">"Unit Price"</th>
-                                        <th style="width: 15%;">"Total"</th>
+                                        <th style="width: 30%;">{t("Description")}</th>
+                                        <th style="width: 15%;">{t("Category")}</th>
+                                        <th style="width: 10%;">{t("Qty")}</th>
+                                        <th style="width: 10%;">{t("Unit")}</th>
+                                        <th style="width: 15%;">{t("Unit Price")}</th>
+                                        <th style="width: 15%;">{t("Total")}</th>
                                         <th style="width: 5%;"></th>
                                     </tr>
                                 </thead>
                                 <tbody>
                                     for (idx, item) in line_items.get().iter().enumerate() {
-                                        {line_item_row(idx, item.clone(), line_items.clone(), on_remove_line_item.clone())}
+                                        {line_item_row(
+                                            idx,
+                                            item.clone(),
+                                            item.catalogue_item_id.as_ref().and_then(|id| catalogue.item(id)),
+                                            line_items.clone(),
+                                            on_remove_line_item.clone(),
+                                        )}
                                     }
                                 </tbody>
                             </table>
@@ -523,21 +750,22 @@ pub fn requisition_form() -> View {
                         view! {
                             <div class="add-line-item">
                                 <button class="btn btn-secondary" on:click={on_add_line_item.clone()}>
-                                    "+ Add Line Item"
+                                    {t("+ Add Line Item")}
                                 </button>
                             </div>
                         },
                         view! {
                             <div class="line-items-total">
-                                <span class="line-items-total-label">"Total Amount:"</span>
+                                <span class="line-items-total-label">{t("Total Amount:")}</span>
                                 <span class="line-items-total-value">{format_currency(total)}</span>
                             </div>
                         },
+                        supplier_groups_summary(supplier_groups.clone()),
                     ],
                     vec![
-                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>"Back"</button> },
-                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>"Save Draft"</button> },
-                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>"Continue to Attachments"</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>{t("Back")}</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>{t("Save Draft")}</button> },
+                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>{t("Continue to Attachments")}</button> },
                     ]
                 )}
             }
@@ -545,28 +773,28 @@ pub fn requisition_form() -> View {
             // Step 3: Attachments
             if current_step.get() == 3 {
                 {panel_with_footer(
-                    "Attachments".to_string(),
+                    t("Attachments").to_string(),
                     vec![],
                     vec![
                         view! {
                             <p style="color: var(--text-muted); margin-bottom: 16px;">
-                                "Upload supporting documents such as specifications, quotes, or budget approvals."
+                                {t("Upload supporting documents such as specifications, quotes, or budget approvals.")}
                             </p>
                         },
                         file_upload(
-                            "Supporting Documents".to_string(),
+                            t("Supporting Documents").to_string(),
                             attachments.clone(),
                             Some(".pdf,.doc,.docx,.xls,.xlsx,.jpg,.png".to_string()),
                             true,
                             false,
-                            Some("PDF, Word, Excel, or images. Max 10MB per file.".to_string()),
+                            Some(t("PDF, Word, Excel, or images. Max 10MB per file.").to_string()),
                             on_file_remove.clone(),
                         ),
                     ],
                     vec![
-                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>"Back"</button> },
-                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>"Save Draft"</button> },
-                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>"Continue to Review"</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>{t("Back")}</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>{t("Save Draft")}</button> },
+                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>{t("Continue to Review")}</button> },
                     ]
                 )}
             }
@@ -574,35 +802,35 @@ pub fn requisition_form() -> View {
             // Step 4: Review
             if current_step.get() == 4 {
                 {panel_with_footer(
-                    "Review & Submit".to_string(),
+                    t("Review & Submit").to_string(),
                     vec![],
                     vec![
                         view! {
                             <div class="review-section">
-                                <div class="review-section-title">"Requisition Details"</div>
+                                <div class="review-section-title">{t("Requisition Details")}</div>
                                 <div class="review-grid">
                                     <div class="review-item">
-                                        <span class="review-item-label">"Description"</span>
+                                        <span class="review-item-label">{t("Description")}</span>
                                         <span class="review-item-value">{description.get()}</span>
                                     </div>
                                     <div class="review-item">
-                                        <span class="review-item-label">"Priority"</span>
+                                        <span class="review-item-label">{t("Priority")}</span>
                                         <span class="review-item-value">{priority.get()}</span>
                                     </div>
                                     <div class="review-item">
-                                        <span class="review-item-label">"Department"</span>
+                                        <span class="review-item-label">{t("Department")}</span>
                                         <span class="review-item-value">{department.get()}</span>
                                     </div>
                                     <div class="review-item">
-                                        <span class="review-item-label">"Cost Center"</span>
+                                        <span class="review-item-label">{t("Cost Center")}</span>
                                         <span class="review-item-value">{cost_center.get()}</span>
                                     </div>
                                     <div class="review-item">
-                                        <span class="review-item-label">"Required By"</span>
+                                        <span class="review-item-label">{t("Required By")}</span>
                                         <span class="review-item-value">{required_by.get()}</span>
                                     </div>
                                     <div class="review-item">
-                                        <span class="review-item-label">"Delivery Address"</span>
+                                        <span class="review-item-label">{t("Delivery Address")}</span>
                                         <span class="review-item-value">{delivery_address.get()}</span>
                                     </div>
                                 </div>
@@ -610,14 +838,14 @@ pub fn requisition_form() -> View {
                         },
                         view! {
                             <div class="review-section">
-                                <div class="review-section-title">"Line Items Summary"</div>
+                                <div class="review-section-title">{t("Line Items Summary")}</div>
                                 <table class="line-items-table">
                                     <thead>
                                         <tr>
-                                            <th>"Description"</th>
-                                            <th>"Qty"</th>
-                                            <th>"Unit Price"</th>
-                                            <th>"Total"</th>
+                                            <th>{t("Description")}</th>
+                                            <th>{t("Qty")}</th>
+                                            <th>{t("Unit Price")}</th>
+                                            <th>{t("Total")}</th>
                                         </tr>
                                     </thead>
                                     <tbody>
@@ -632,16 +860,17 @@ pub fn requisition_form() -> View {
                                     </tbody>
                                 </table>
                                 <div class="line-items-total">
-                                    <span class="line-items-total-label">"Total Amount:"</span>
+                                    <span class="line-items-total-label">{t("Total Amount:")}</span>
                                     <span class="line-items-total-value">{format_currency(total)}</span>
                                 </div>
+                                {supplier_groups_summary(supplier_groups.clone())}
                             </div>
                         },
                         view! {
                             <div class="review-section">
-                                <div class="review-section-title">"Attachments"</div>
+                                <div class="review-section-title">{t("Attachments")}</div>
                                 if attachments.get().is_empty() {
-                                    <p style="color: var(--text-muted);">"No attachments added"</p>
+                                    <p style="color: var(--text-muted);">{t("No attachments added")}</p>
                                 } else {
                                     <ul>
                                         for file in attachments.get().iter() {
@@ -653,15 +882,15 @@ pub fn requisition_form() -> View {
                         },
                         view! {
                             <div class="review-section">
-                                <div class="review-section-title">"Justification"</div>
+                                <div class="review-section-title">{t("Justification")}</div>
                                 <p>{justification.get()}</p>
                             </div>
                         },
                     ],
                     vec![
-                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>"Back"</button> },
-                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>"Save Draft"</button> },
-                        view! { <button class="btn btn-primary" on:click={on_submit.clone()}>"Submit for Approval"</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>{t("Back")}</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>{t("Save Draft")}</button> },
+                        view! { <button class="btn btn-primary" on:click={on_submit.clone()}>{t("Submit for Approval")}</button> },
                     ]
                 )}
             }
@@ -673,6 +902,7 @@ pub fn requisition_form() -> View {
 fn line_item_row(
     idx: usize,
     item: LineItem,
+    catalogue_item: Option<CatalogueItem>,
     line_items: Signal<Vec<LineItem>>,
     on_remove: Callback<usize>,
 ) -> View {
@@ -702,15 +932,20 @@ fn line_item_row(
         })
     };
 
+    // Catalogue lines are re-priced from the price tier for the new quantity
     let on_qty_change = {
         let line_items = line_items.clone();
+        let catalogue_item = catalogue_item.clone();
         Callback::new(move |e: web_sys::Event| {
             let target = e.target().unwrap();
             let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
             let mut items = line_items.get();
             if idx < items.len() {
                 items[idx].quantity = input.value().parse().unwrap_or(1);
-                items[idx].calculate_total();
+                match &catalogue_item {
+                    Some(catalogue_item) => items[idx].apply_catalogue_price(catalogue_item),
+                    None => items[idx].calculate_total(),
+                }
                 line_items.set(items);
             }
         })
@@ -736,7 +971,7 @@ fn line_item_row(
             let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
             let mut items = line_items.get();
             if idx < items.len() {
-                items[idx].unit_price = input.value().replace(",", "").parse().unwrap_or(0.0);
+                items[idx].unit_price = input.value().parse().unwrap_or_default();
                 items[idx].calculate_total();
                 line_items.set(items);
             }
@@ -750,28 +985,42 @@ fn line_item_row(
         })
     };
 
+    let is_catalogue = catalogue_item.is_some();
+    let source = catalogue_item.as_ref()
+        .map(|c| format!("{} - {}", c.item_code, c.supplier.name))
+        .or_else(|| item.supplier_name.clone());
+    let warnings = catalogue_item.as_ref()
+        .map(|c| c.ordering_warnings(item.quantity))
+        .unwrap_or_default();
+
     view! {
         <tr>
             <td>
                 <input
                     type="text"
                     value={item.description.clone()}
-                    placeholder="Item description"
+                    placeholder={t("Item description")}
                     on:input={on_description_change}
                 />
+                if let Some(source) = source {
+                    <div class="line-item-source">{source}</div>
+                }
+                for warning in warnings {
+                    <div class="catalogue-warning" data-testid="catalogue-warning">{warning}</div>
+                }
             </td>
             <td>
                 <select value={item.category.clone()} on:change={on_category_change}>
-                    <option value="">"Select..."</option>
-                    <option value="IT Equipment">"IT Equipment"</option>
-                    <option value="Software">"Software"</option>
-                    <option value="Office Supplies">"Office Supplies"</option>
-                    <option value="Furniture">"Furniture"</option>
-                    <option value="Services">"Services"</option>
-                    <option value="Fleet Services">"Fleet Services"</option>
-                    <option value="Training">"Training"</option>
-                    <option value="Medical">"Medical"</option>
-                    <option value="Other">"Other"</option>
+                    <option value="">{t("Select...")}</option>
+                    <option value="IT Equipment">{t("IT Equipment")}</option>
+                    <option value="Software">{t("Software")}</option>
+                    <option value="Office Supplies">{t("Office Supplies")}</option>
+                    <option value="Furniture">{t("Furniture")}</option>
+                    <option value="Services">{t("Services")}</option>
+                    <option value="Fleet Services">{t("Fleet Services")}</option>
+                    <option value="Training">{t("Training")}</option>
+                    <option value="Medical">{t("Medical")}</option>
+                    <option value="Other">{t("Other")}</option>
                 </select>
             </td>
             <td>
@@ -785,23 +1034,25 @@ fn line_item_row(
             </td>
             <td>
                 <select value={item.unit.clone()} on:change={on_unit_change}>
-                    <option value="Each">"Each"</option>
-                    <option value="Box">"Box"</option>
-                    <option value="Pack">"Pack"</option>
-                    <option value="License">"License"</option>
-                    <option value="Month">"Month"</option>
-                    <option value="Service">"Service"</option>
-                    <option value="Tyre">"Tyre"</option>
-                    <option value="Participant">"Participant"</option>
-                    <option value="Kit">"Kit"</option>
-                    <option value="Container">"Container"</option>
+                    <option value="Each">{t("Each")}</option>
+                    <option value="Box">{t("Box")}</option>
+                    <option value="Pack">{t("Pack")}</option>
+                    <option value="License">{t("License")}</option>
+                    <option value="Month">{t("Month")}</option>
+                    <option value="Service">{t("Service")}</option>
+                    <option value="Tyre">{t("Tyre")}</option>
+                    <option value="Participant">{t("Participant")}</option>
+                    <option value="Kit">{t("Kit")}</option>
+                    <option value="Container">{t("Container")}</option>
                 </select>
             </td>
             <td>
                 <input
                     type="text"
                     class="price-input"
-                    value={format!("{:.2}", item.unit_price)}
+                    value={item.unit_price.to_string()}
+                    disabled={is_catalogue}
+                    title={if is_catalogue { t("Priced from the catalogue") } else { "" }}
                     on:input={on_price_change}
                 />
             </td>
@@ -810,7 +1061,7 @@ fn line_item_row(
             </td>
             <td>
                 <div class="line-item-actions">
-                    <button class="remove-btn" on:click={handle_remove} title="Remove">
+                    <button class="remove-btn" on:click={handle_remove} title={t("Remove")}>
                         "×"
                     </button>
                 </div>
@@ -818,3 +1069,83 @@ fn line_item_row(
         </tr>
     }
 }
+
+/// One catalogue search hit with its price at the minimum order quantity
+fn catalogue_result(item: CatalogueItem, on_add: Callback<String>) -> View {
+    let minimum = item.supplier.minimum_order_quantity.max(1);
+    let price = format!("{} / {}", format_currency(item.price_for(minimum)), item.unit_of_measure.name);
+    let tiers = if item.price_tiers.len() > 1 {
+        Some(
+            item.price_tiers.iter()
+                .map(|tier| tf(
+                    "{quantity}+ at {price}",
+                    &[
+                        ("quantity", tier.min_quantity.to_string()),
+                        ("price", format_currency(item.price_for(tier.min_quantity))),
+                    ],
+                ))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    } else {
+        None
+    };
+    let meta = format!("{} - {} - {}", item.item_code, item.category_name, item.supplier.name);
+    let warnings = item.ordering_warnings(minimum);
+    let handle_add = Callback::<()>::new({
+        let on_add = on_add.clone();
+        let id = item.id.clone();
+        move |_| on_add.call(id.clone())
+    });
+
+    view! {
+        <div class="catalogue-result" data-testid="catalogue-result">
+            <div>
+                <div class="catalogue-result-name">{item.name.clone()}</div>
+                <div class="catalogue-result-meta">{meta}</div>
+                if let Some(tiers) = tiers {
+                    <div class="catalogue-result-meta">{tiers}</div>
+                }
+                for warning in warnings {
+                    <div class="catalogue-warning">{warning}</div>
+                }
+            </div>
+            <div class="catalogue-result-price">
+                <div>{price}</div>
+                <button class="btn btn-secondary btn-sm" data-testid="catalogue-picker-add" on:click={handle_add}>
+                    {t("Add")}
+                </button>
+            </div>
+        </div>
+    }
+}
+
+/// Cart grouped by supplier, one purchase order each
+fn supplier_groups_summary(groups: Vec<SupplierGroup>) -> View {
+    if groups.iter().all(|g| g.supplier_id.is_none()) {
+        return view! { <span></span> };
+    }
+    let orders = groups.iter().filter(|g| g.supplier_id.is_some()).count();
+    let mut note = tn(
+        "Catalogue lines split into {n} purchase order, one per supplier.",
+        "Catalogue lines split into {n} purchase orders, one per supplier.",
+        orders as i64,
+    );
+    if groups.iter().any(|g| g.supplier_id.is_none()) {
+        note.push(' ');
+        note.push_str(t("Lines without a supplier go out for quotation."));
+    }
+
+    view! {
+        <div class="supplier-groups" data-testid="requisition-supplier-groups">
+            <div class="supplier-groups-title">{t("Orders by Supplier")}</div>
+            for group in groups {
+                <div class="supplier-group">
+                    <span>{format!("{} ({})", group.supplier_name, tn("{n} line", "{n} lines", group.line_ids.len() as i64))}</span>
+                    <span class="supplier-group-total">{format_currency(group.total)}</span>
+                </div>
+            }
+            <p class="supplier-groups-note">{note}</p>
+        </div>
+    }
+}
@@ -10,9 +10,12 @@ use crate::shared::components::{
 };
 use crate::shared::forms::filter_bar;
 use crate::util::format::{format_currency, format_date};
+use crate::util::url;
+use crate::Route;
 use super::store::{RequisitionsStore, get_paginated_requisitions, get_filtered_requisitions};
-use super::types::{RequisitionStatus, RequisitionFilter};
+use super::types::RequisitionStatus;
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Requisition list page
 #[component]
@@ -30,10 +33,12 @@ pub fn requisition_list() -> View {
         }
     });
 
-    // Filter signals
-    let search = signal(String::new());
-    let status_filter = signal(String::new());
-    let department_filter = signal(String::new());
+    // Filter signals, seeded from the URL
+    url::sync_filter(&store.filter);
+    let initial = store.filter.get();
+    let search = signal(initial.search.clone());
+    let status_filter = signal(initial.status.map(|s| s.as_str().to_string()).unwrap_or_default());
+    let department_filter = signal(initial.department.clone().unwrap_or_default());
 
     // Update store filter when inputs change
     let update_filter = {
@@ -42,7 +47,7 @@ pub fn requisition_list() -> View {
         let status_filter = status_filter.clone();
         let department_filter = department_filter.clone();
         Callback::new(move |_: ()| {
-            let mut filter = RequisitionFilter::default();
+            let mut filter = store.filter.get();
             filter.search = search.get();
             filter.status = if status_filter.get().is_empty() {
                 None
@@ -113,22 +118,22 @@ pub fn requisition_list() -> View {
     // Handle row click
     let on_row_click = {
         let store = store.clone();
+        let navigate = use_context::<Callback<Route>>();
         Callback::new(move |id: String| {
             store.set_selected(&id);
-            // In production, navigate to detail view
-            web_sys::console::log_1(&format!("Selected requisition: {}", id).into());
+            navigate.call(Route::RequisitionsDetail(id));
         })
     };
 
     // Table columns
     let columns = vec![
-        DataTableColumn { key: "id".to_string(), label: "Req ID".to_string(), width: Some("120px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "description".to_string(), label: "Description".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "department".to_string(), label: "Department".to_string(), width: Some("150px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "requester".to_string(), label: "Requester".to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "amount".to_string(), label: "Amount".to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "date".to_string(), label: "Date".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "id".to_string(), label: t("Req ID").to_string(), width: Some("120px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
+        DataTableColumn { key: "description".to_string(), label: t("Description").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "department".to_string(), label: t("Department").to_string(), width: Some("150px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "requester".to_string(), label: t("Requester").to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "amount".to_string(), label: t("Amount").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "date".to_string(), label: t("Date").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
     ];
 
     // Transform requisitions to table rows
@@ -188,11 +193,11 @@ pub fn requisition_list() -> View {
 
         <div class="requisition-list" data-testid="requisition-list">
             {page_header(
-                "Requisitions".to_string(),
-                Some("Manage purchase requisitions and approvals".to_string()),
+                t("Requisitions").to_string(),
+                Some(t("Manage purchase requisitions and approvals").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export"</button> },
-                    view! { <a href="/requisitions/new" class="btn btn-primary">"+ New Requisition"</a> },
+                    view! { <button class="btn btn-secondary">{t("Export")}</button> },
+                    view! { <a href="/requisitions/new" class="btn btn-primary">{t("+ New Requisition")}</a> },
                 ]
             )}
 
@@ -200,10 +205,10 @@ pub fn requisition_list() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Search"</label>
+                        <label>{t("Search")}</label>
                         <input
                             type="text"
-                            placeholder="Search by ID, description, requester..."
+                            placeholder={t("Search by ID, description, requester...")}
                             value={search.get()}
                             on:input={on_search}
                             style="width: 250px;"
@@ -212,48 +217,48 @@ pub fn requisition_list() -> View {
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Status"</label>
-                        <select on:change={on_status_change}>
-                            <option value="">"All Statuses"</option>
-                            <option value="draft">"Draft"</option>
-                            <option value="submitted">"Submitted"</option>
-                            <option value="pending">"Pending Approval"</option>
-                            <option value="approved">"Approved"</option>
-                            <option value="rejected">"Rejected"</option>
-                            <option value="in_progress">"In Progress"</option>
-                            <option value="complete">"Complete"</option>
+                        <label>{t("Status")}</label>
+                        <select on:change={on_status_change} value={status_filter.get()}>
+                            <option value="">{t("All Statuses")}</option>
+                            <option value="draft">{t("Draft")}</option>
+                            <option value="submitted">{t("Submitted")}</option>
+                            <option value="pending">{t("Pending Approval")}</option>
+                            <option value="approved">{t("Approved")}</option>
+                            <option value="rejected">{t("Rejected")}</option>
+                            <option value="in_progress">{t("In Progress")}</option>
+                            <option value="complete">{t("Complete")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Department"</label>
-                        <select on:change={on_department_change}>
-                            <option value="">"All Departments"</option>
-                            <option value="Information Technology">"Information Technology"</option>
-                            <option value="Administration">"Administration"</option>
-                            <option value="Facilities">"Facilities"</option>
-                            <option value="Transport">"Transport"</option>
-                            <option value="Human Resources">"Human Resources"</option>
-                            <option value="Health Services">"Health Services"</option>
-                            <option value="Finance">"Finance"</option>
+                        <label>{t("Department")}</label>
+                        <select on:change={on_department_change} value={department_filter.get()}>
+                            <option value="">{t("All Departments")}</option>
+                            <option value="Information Technology">{t("Information Technology")}</option>
+                            <option value="Administration">{t("Administration")}</option>
+                            <option value="Facilities">{t("Facilities")}</option>
+                            <option value="Transport">{t("Transport")}</option>
+                            <option value="Human Resources">{t("Human Resources")}</option>
+                            <option value="Health Services">{t("Health Services")}</option>
+                            <option value="Finance">{t("Finance")}</option>
                         </select>
                     </div>
                 },
                 view! { <div class="filter-spacer"></div> },
                 view! {
-                    <button class="btn btn-sm btn-secondary">"Clear Filters"</button>
+                    <button class="btn btn-sm btn-secondary">{t("Clear Filters")}</button>
                 },
             ])}
 
             // Requisitions table
             {panel(
-                "All Requisitions".to_string(),
+                t("All Requisitions").to_string(),
                 vec![],
                 vec![
                     view! {
                         <div class="list-info">
-                            <span>{format!("Showing {} of {} requisitions", rows.len(), total_filtered)}</span>
+                            <span>{tf("Showing {count} of {total} requisitions", &[("count", rows.len().to_string()), ("total", total_filtered.to_string())])}</span>
                             <div class="export-buttons">
                                 <button class="btn btn-sm btn-secondary">"CSV"</button>
                                 <button class="btn btn-sm btn-secondary">"PDF"</button>
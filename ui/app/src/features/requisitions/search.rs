@@ -0,0 +1,50 @@
+//! Requisition search provider
+
+use crate::Route;
+use crate::features::search::provider::{SearchFuture, SearchProvider};
+use crate::features::search::types::{match_score, SearchEntity, SearchHit};
+use crate::util::format::format_currency;
+use super::store::RequisitionsStore;
+use super::service;
+
+/// Searches the requisitions held in the store, loading them on first use
+pub struct RequisitionSearch {
+    store: RequisitionsStore,
+}
+
+impl RequisitionSearch {
+    pub fn new(store: RequisitionsStore) -> Self {
+        Self { store }
+    }
+}
+
+impl SearchProvider for RequisitionSearch {
+    fn entity(&self) -> SearchEntity {
+        SearchEntity::Requisition
+    }
+
+    fn search(&self, query: String) -> SearchFuture {
+        let store = self.store.clone();
+        Box::pin(async move {
+            if store.requisitions.get().is_empty() {
+                service::load_requisitions(&store).await;
+                if let Some(e) = store.error.get() {
+                    return Err(e);
+                }
+            }
+
+            let hits = store.requisitions.get().iter().filter_map(|r| {
+                let score = match_score(&query, &[&r.id], &[&r.description, &r.department, &r.requester])?;
+                Some(SearchHit {
+                    entity: SearchEntity::Requisition,
+                    id: r.id.clone(),
+                    title: r.description.clone(),
+                    subtitle: format!("{} - {} - {}", r.id, r.department, format_currency(r.amount)),
+                    route: Route::RequisitionsDetail(r.id.clone()),
+                    score,
+                })
+            }).collect();
+            Ok(hits)
+        })
+    }
+}
@@ -1,37 +1,289 @@
 //! Requisitions service - API calls
 
+use std::cell::RefCell;
+use components::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::features::budget::service as budget;
+use crate::features::budget::types::CommitmentSource;
+use crate::features::catalogue::service as catalogue;
+use crate::features::catalogue::store::CatalogueStore;
+use crate::features::catalogue::types::{CatalogueItem, CatalogueItemStatus};
+use crate::features::contracts::service as contracts;
+use crate::features::contracts::store::ContractsStore;
+use crate::features::contracts::types::{ContractStatus, ContractSummary};
+use crate::features::mobile::types::NotificationPriority;
+use crate::features::notifications::service as notifications;
+use crate::features::notifications::types::{Notification, NotificationKind};
+use crate::features::purchase_orders::service as purchase_orders;
+use crate::features::purchase_orders::store::PurchaseOrdersStore;
+use crate::features::purchase_orders::types::{self as po, DeliveryAddress, PurchaseOrder};
+use crate::features::tenders::service as tenders;
+use crate::features::tenders::store::TendersStore;
+use crate::features::tenders::types::{Tender, TenderLineItem, TenderType};
+use crate::features::workflow::service as workflow;
+use crate::features::workflow::types::{
+    ApprovalDecision, ApprovalDocument, ApprovalReassignment, ApprovalRequest, OverdueApproval, SlaOutcome,
+    WorkflowState,
+};
+use crate::util::api::{self, HttpMethod};
+use crate::util::auth;
+use crate::util::backend;
+use crate::util::datetime::{now_iso, today};
+use crate::util::permissions::{self, Action};
+use crate::util::reference::{self, DocumentType};
 use super::store::{RequisitionsStore, load_mock_data};
-use super::types::{Requisition, RequisitionStatus};
+use super::types::{ConversionTarget, PendingConversion, Priority, Requisition, RequisitionStatus};
 
-/// Load requisitions data
+/// Load requisitions data.
+///
+/// The mock fixtures are only loaded once, so approvals escalated in the
+/// session are kept across visits. Loading never writes; overdue steps are
+/// escalated by [`enforce_approval_slas`].
 pub async fn load_requisitions(store: &RequisitionsStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_data(store);
+    if backend::is_mock() {
+        if store.requisitions.get().is_empty() {
+            load_mock_data(store);
+        }
+    } else if let Err(e) = backend::fetch_with_snapshot("/requisitions", &store.requisitions).await {
+        store.error.set(Some(e.to_string()));
+    } else {
+        let mut pagination = store.pagination.get();
+        pagination.update_totals(store.requisitions.get().len() as u32);
+        store.pagination.set(pagination);
+    }
+    reroute_approvals(store);
 
     store.loading.set(false);
 }
 
+/// Point pending approval steps at whoever holds their approver's
+/// authority today. The reroute is only shown; it is saved with the next
+/// decision or SLA run on the requisition.
+fn reroute_approvals(store: &RequisitionsStore) {
+    let mut requisitions = store.requisitions.get();
+    let mut changed = false;
+    for requisition in requisitions.iter_mut() {
+        changed |= workflow::reroute(&mut requisition.approval_workflow, ApprovalDocument::Requisition, requisition.amount);
+    }
+    if changed {
+        store.requisitions.set(requisitions);
+    }
+}
+
+/// Remind approvers of steps coming due and escalate overdue ones on every
+/// requisition, saving each one that changed together with any reroute.
+/// Notifications and escalation audit entries only follow a successful
+/// save. Returns how many requisitions were updated.
+pub async fn enforce_approval_slas(store: &RequisitionsStore) -> Result<usize, String> {
+    permissions::require(Action::EnforceApprovalSla)?;
+    store.error.set(None);
+
+    let mut requisitions = store.requisitions.get();
+    let mut updated = 0;
+    let mut failed = Vec::new();
+    for current in requisitions.iter_mut() {
+        let mut requisition = current.clone();
+        let rerouted = workflow::reroute(&mut requisition.approval_workflow, ApprovalDocument::Requisition, requisition.amount);
+        let outcome = workflow::enforce_sla(
+            ApprovalDocument::Requisition,
+            &requisition.id,
+            &mut requisition.approval_workflow,
+            requisition.priority,
+        );
+        if !rerouted && !outcome.changed() {
+            continue;
+        }
+        requisition.updated_at = now_iso();
+
+        let endpoint = format!("/requisitions/{}/approval", requisition.id);
+        match backend::save(HttpMethod::Post, &endpoint, &requisition).await {
+            Ok(saved) => {
+                notify_sla(&saved, &outcome);
+                outcome.record();
+                *current = saved;
+                updated += 1;
+            }
+            Err(e) => failed.push(format!("{}: {}", current.id, e)),
+        }
+    }
+    if updated > 0 {
+        store.requisitions.set(requisitions);
+    }
+
+    if failed.is_empty() {
+        Ok(updated)
+    } else {
+        let message = format!("Could not apply approval SLAs to {}", failed.join("; "));
+        store.error.set(Some(message.clone()));
+        Err(message)
+    }
+}
+
+/// Time between automatic SLA passes
+const SLA_PASS_INTERVAL_MS: i32 = 15 * 60 * 1000;
+
+thread_local! {
+    /// User the automatic SLA passes run for, and the interval id
+    static SLA_SCHEDULE: RefCell<Option<(String, i32)>> = RefCell::new(None);
+}
+
+/// Apply approval SLAs now and every [`SLA_PASS_INTERVAL_MS`] while the
+/// signed-in user may enforce them, so reminders and escalations do not
+/// wait for someone to open the overdue approvals page. Starting again for
+/// the same user, e.g. after a token refresh, leaves the schedule running.
+pub fn start_sla_schedule(store: &RequisitionsStore) {
+    let auth = auth::current();
+    let Some(user) = auth.user.as_ref().filter(|_| permissions::can_perform(&auth, Action::EnforceApprovalSla)) else {
+        stop_sla_schedule();
+        return;
+    };
+    if SLA_SCHEDULE.with(|s| s.borrow().as_ref().is_some_and(|(id, _)| *id == user.id)) {
+        return;
+    }
+    stop_sla_schedule();
+
+    let pass = {
+        let store = store.clone();
+        move || {
+            let store = store.clone();
+            spawn(async move {
+                load_requisitions(&store).await;
+                // Failures are left on the store for the requisitions pages
+                let _ = enforce_approval_slas(&store).await;
+            });
+        }
+    };
+    pass();
+
+    let Some(window) = web_sys::window() else { return };
+    let closure = Closure::<dyn Fn()>::new(pass);
+    if let Ok(id) = window.set_interval_with_callback_and_timeout_and_arguments_0(
+        closure.as_ref().unchecked_ref(),
+        SLA_PASS_INTERVAL_MS,
+    ) {
+        SLA_SCHEDULE.with(|s| *s.borrow_mut() = Some((user.id.clone(), id)));
+    }
+    closure.forget(); // Leak closure to keep it alive
+}
+
+/// Stop the automatic SLA passes, e.g. on sign-out
+pub fn stop_sla_schedule() {
+    if let (Some((_, id)), Some(window)) = (SLA_SCHEDULE.with(|s| s.borrow_mut().take()), web_sys::window()) {
+        window.clear_interval_with_handle(id);
+    }
+}
+
+/// Tell approvers about reminders and escalations on a requisition
+fn notify_sla(requisition: &Requisition, outcome: &SlaOutcome) {
+    let priority = match requisition.priority {
+        Priority::Urgent => NotificationPriority::Critical,
+        Priority::High => NotificationPriority::High,
+        Priority::Low | Priority::Medium => NotificationPriority::Medium,
+    };
+
+    for role in &outcome.reminded {
+        let due = requisition.approval_workflow
+            .iter()
+            .find(|s| &s.role == role)
+            .and_then(|s| s.due_date.clone())
+            .unwrap_or_default();
+        notifications::notify(Notification {
+            id: format!("ntf-sla-{}-{}", requisition.id, role.to_lowercase().replace(' ', "-")),
+            kind: NotificationKind::ApprovalReminder,
+            title: format!("{} approval is due", requisition.id),
+            message: format!("The {} step on {} is due by {}.", role, requisition.description, due),
+            entity_id: requisition.id.clone(),
+            priority,
+            created_at: String::new(),
+            read: false,
+        });
+    }
+
+    for (from, to) in &outcome.escalated {
+        notifications::notify(Notification {
+            id: format!("ntf-esc-{}-{}", requisition.id, to.to_lowercase().replace(' ', "-")),
+            kind: NotificationKind::ApprovalEscalated,
+            title: format!("{} escalated to the {}", requisition.id, to),
+            message: format!(
+                "The {} step on {} was not acted on in time and now awaits the {}.",
+                from, requisition.description, to
+            ),
+            entity_id: requisition.id.clone(),
+            priority: if requisition.priority == Priority::Urgent {
+                NotificationPriority::Critical
+            } else {
+                NotificationPriority::High
+            },
+            created_at: String::new(),
+            read: false,
+        });
+    }
+}
+
+/// Pending approval steps that are overdue or were escalated, longest
+/// overdue first
+pub fn overdue_approvals(requisitions: &[Requisition]) -> Vec<OverdueApproval> {
+    let date = today();
+    let mut overdue: Vec<OverdueApproval> = requisitions
+        .iter()
+        .filter(|r| r.status == RequisitionStatus::PendingApproval || r.status == RequisitionStatus::Submitted)
+        .flat_map(|r| {
+            workflow::overdue_steps(&r.approval_workflow, date)
+                .into_iter()
+                .map(move |(step, days_overdue)| OverdueApproval {
+                    document: ApprovalDocument::Requisition,
+                    document_id: r.id.clone(),
+                    title: r.description.clone(),
+                    priority: r.priority,
+                    amount: r.amount,
+                    step,
+                    days_overdue,
+                })
+        })
+        .collect();
+    overdue.sort_by(|a, b| b.days_overdue.cmp(&a.days_overdue).then_with(|| a.document_id.cmp(&b.document_id)));
+    overdue
+}
+
 /// Create a new requisition
 pub async fn create_requisition(store: &RequisitionsStore, mut requisition: Requisition) -> Result<Requisition, String> {
     store.loading.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
-    // Generate ID
-    let count = store.requisitions.get().len() + 1;
-    requisition.id = format!("REQ-2025-{:04}", 847 + count);
+    // Provisional number, replaced by the server's when using the REST backend
+    if backend::is_mock() && store.requisitions.get().is_empty() {
+        load_mock_data(store);
+    }
+    let existing = store.requisitions.get();
+    requisition.id = reference::issue(DocumentType::Requisition, existing.iter().map(|r| r.id.as_str())).number;
     requisition.status = RequisitionStatus::Draft;
-    requisition.created_at = chrono_now();
-    requisition.updated_at = chrono_now();
+    requisition.created_at = now_iso();
+    requisition.updated_at = now_iso();
+    if let Some(user) = auth::current().user {
+        requisition.requester = user.name;
+        requisition.requester_email = user.email;
+        requisition.requester_id = user.id;
+    }
 
     // Calculate total
     requisition.calculate_total();
 
-    // In production, this would POST to the API
-    // For now, add to local store
+    let label = format!("Create requisition {}", requisition.id);
+    let requisition = match backend::create_or_queue("/requisitions", &requisition, &label, DocumentType::Requisition, "id").await {
+        Ok(saved) => saved,
+        Err(e) => {
+            store.field_errors.set(e.field_errors().to_vec());
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    };
+
     let mut requisitions = store.requisitions.get();
     requisitions.insert(0, requisition.clone());
     store.requisitions.set(requisitions);
@@ -50,22 +302,32 @@ pub async fn create_requisition(store: &RequisitionsStore, mut requisition: Requ
 pub async fn update_requisition(store: &RequisitionsStore, requisition: Requisition) -> Result<Requisition, String> {
     store.loading.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
+    let base_version = Some(requisition.updated_at.clone());
     let mut updated = requisition.clone();
-    updated.updated_at = chrono_now();
+    updated.updated_at = now_iso();
     updated.calculate_total();
 
-    // In production, this would PUT to the API
-    // For now, update in local store
+    let endpoint = format!("/requisitions/{}", updated.id);
+    let label = format!("Update requisition {}", updated.id);
+    let updated = match backend::save_or_queue(HttpMethod::Put, &endpoint, &updated, &label, base_version).await {
+        Ok(saved) => saved,
+        Err(e) => {
+            store.field_errors.set(e.field_errors().to_vec());
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    };
+
+    // Deep-linked edits may target a requisition the list has not loaded
     let mut requisitions = store.requisitions.get();
-    if let Some(pos) = requisitions.iter().position(|r| r.id == updated.id) {
-        requisitions[pos] = updated.clone();
-        store.requisitions.set(requisitions);
-    } else {
-        store.loading.set(false);
-        store.error.set(Some("Requisition not found".to_string()));
-        return Err("Requisition not found".to_string());
+    match requisitions.iter().position(|r| r.id == updated.id) {
+        Some(pos) => requisitions[pos] = updated.clone(),
+        None => requisitions.insert(0, updated.clone()),
     }
+    store.requisitions.set(requisitions);
 
     store.loading.set(false);
 
@@ -76,26 +338,59 @@ pub async fn update_requisition(store: &RequisitionsStore, requisition: Requisit
 pub async fn submit_requisition(store: &RequisitionsStore, id: &str) -> Result<Requisition, String> {
     store.loading.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
     let mut requisitions = store.requisitions.get();
     if let Some(pos) = requisitions.iter().position(|r| r.id == id) {
-        requisitions[pos].status = RequisitionStatus::Submitted;
-        requisitions[pos].updated_at = chrono_now();
-
-        // Add initial approval workflow step if empty
-        if requisitions[pos].approval_workflow.is_empty() {
-            requisitions[pos].approval_workflow.push(super::types::ApprovalStep {
-                step: 1,
-                role: "Line Manager".to_string(),
-                approver: None,
-                status: "pending".to_string(),
-                date: None,
-                comments: None,
-            });
+        if requisitions[pos].status != RequisitionStatus::Draft {
+            store.loading.set(false);
+            store.error.set(Some("Only draft requisitions can be submitted".to_string()));
+            return Err("Only draft requisitions can be submitted".to_string());
+        }
+
+        // A returned requisition gets a fresh chain, since its amount or
+        // line items may have changed
+        let request = ApprovalRequest::new(ApprovalDocument::Requisition, requisitions[pos].amount)
+            .department(&requisitions[pos].department)
+            .categories(requisitions[pos].line_items.iter().map(|li| li.category.clone()));
+        match workflow::approval_chain(&request).await {
+            Ok(mut chain) => {
+                workflow::schedule(&mut chain, ApprovalDocument::Requisition, requisitions[pos].priority);
+                requisitions[pos].approval_workflow = chain;
+            }
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.clone()));
+                return Err(e);
+            }
+        }
+
+        // Pre-commitment budget check: the cost centre must be able to fund
+        // the requisition before it goes out for approval
+        if let Err(e) = budget::pre_commit_requisition(&requisitions[pos]).await {
+            store.loading.set(false);
+            store.error.set(Some(e.clone()));
+            return Err(e);
+        }
+
+        requisitions[pos].status = RequisitionStatus::PendingApproval;
+        requisitions[pos].updated_at = now_iso();
+
+        let endpoint = format!("/requisitions/{}/submit", id);
+        match backend::save(HttpMethod::Post, &endpoint, &requisitions[pos]).await {
+            Ok(saved) => requisitions[pos] = saved,
+            Err(e) => {
+                let _ = budget::release_commitment(CommitmentSource::Requisition, id).await;
+                store.field_errors.set(e.field_errors().to_vec());
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
         }
 
         let updated = requisitions[pos].clone();
         store.requisitions.set(requisitions);
+        store.selected.set(Some(updated.clone()));
         store.loading.set(false);
 
         Ok(updated)
@@ -106,6 +401,432 @@ pub async fn submit_requisition(store: &RequisitionsStore, id: &str) -> Result<R
     }
 }
 
+/// Record an approver's decision on the requisition's current approval stage
+pub async fn decide_requisition(store: &RequisitionsStore, id: &str, decision: &ApprovalDecision) -> Result<Requisition, String> {
+    store.loading.set(true);
+    store.error.set(None);
+
+    let mut requisitions = store.requisitions.get();
+    let Some(pos) = requisitions.iter().position(|r| r.id == id) else {
+        store.loading.set(false);
+        store.error.set(Some("Requisition not found".to_string()));
+        return Err("Requisition not found".to_string());
+    };
+
+    let mut requisition = requisitions[pos].clone();
+    let applied = match workflow::apply(ApprovalDocument::Requisition, id, &requisition.requester_id, &mut requisition.approval_workflow, decision) {
+        Ok(applied) => applied,
+        Err(e) => {
+            store.loading.set(false);
+            store.error.set(Some(e.clone()));
+            return Err(e);
+        }
+    };
+    // The SLA clock starts for the next stage's approvers
+    workflow::schedule(&mut requisition.approval_workflow, ApprovalDocument::Requisition, requisition.priority);
+    requisition.status = match applied.state {
+        WorkflowState::Approved => RequisitionStatus::Approved,
+        WorkflowState::Rejected => RequisitionStatus::Rejected,
+        WorkflowState::Returned => RequisitionStatus::Draft,
+        WorkflowState::InProgress { .. } | WorkflowState::NotStarted => RequisitionStatus::PendingApproval,
+    };
+    requisition.updated_at = now_iso();
+
+    let endpoint = format!("/requisitions/{}/approval", id);
+    match backend::save(HttpMethod::Post, &endpoint, &requisition).await {
+        Ok(saved) => requisitions[pos] = saved,
+        Err(e) => {
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+    applied.record();
+
+    // A rejected requisition gives its pre-commitment back
+    if requisitions[pos].status == RequisitionStatus::Rejected {
+        if let Err(e) = budget::release_commitment(CommitmentSource::Requisition, id).await {
+            store.error.set(Some(e));
+        }
+    }
+
+    let updated = requisitions[pos].clone();
+    store.requisitions.set(requisitions);
+    store.selected.set(Some(updated.clone()));
+    store.loading.set(false);
+
+    Ok(updated)
+}
+
+/// Move a stuck approval step of the requisition to another approver
+pub async fn reassign_requisition_step(
+    store: &RequisitionsStore,
+    id: &str,
+    reassignment: &ApprovalReassignment,
+) -> Result<Requisition, String> {
+    store.loading.set(true);
+    store.error.set(None);
+
+    let mut requisitions = store.requisitions.get();
+    let Some(pos) = requisitions.iter().position(|r| r.id == id) else {
+        store.loading.set(false);
+        store.error.set(Some("Requisition not found".to_string()));
+        return Err("Requisition not found".to_string());
+    };
+
+    let mut requisition = requisitions[pos].clone();
+    if let Err(e) = workflow::reassign(ApprovalDocument::Requisition, id, &mut requisition.approval_workflow, reassignment) {
+        store.loading.set(false);
+        store.error.set(Some(e.clone()));
+        return Err(e);
+    }
+    requisition.updated_at = now_iso();
+
+    let endpoint = format!("/requisitions/{}/approval", id);
+    match backend::save(HttpMethod::Post, &endpoint, &requisition).await {
+        Ok(saved) => requisitions[pos] = saved,
+        Err(e) => {
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+
+    let updated = requisitions[pos].clone();
+    store.requisitions.set(requisitions);
+    store.selected.set(Some(updated.clone()));
+    store.loading.set(false);
+
+    Ok(updated)
+}
+
+/// Stores an approved requisition is converted into
+#[derive(Clone)]
+pub struct ConversionStores {
+    pub tenders: TendersStore,
+    pub purchase_orders: PurchaseOrdersStore,
+    pub catalogue: CatalogueStore,
+    pub contracts: ContractsStore,
+}
+
+/// Error unless the requisition is approved and not yet sourced
+pub fn check_convertible(requisition: &Requisition) -> Result<(), String> {
+    if requisition.status != RequisitionStatus::Approved {
+        return Err("Only approved requisitions can be converted".to_string());
+    }
+    if requisition.tender_ref.is_some() || !requisition.purchase_order_refs.is_empty() {
+        return Err(format!("Requisition {} has already been converted", requisition.id));
+    }
+    if requisition.line_items.is_empty() {
+        return Err(format!("Requisition {} has no line items", requisition.id));
+    }
+    Ok(())
+}
+
+/// Draft tender carrying over the requisition's line items and scope. The
+/// estimated value decides between an RFQ and an RFT.
+pub fn tender_from(requisition: &Requisition) -> Tender {
+    let (created_by, created_by_id) = auth::current().user.map(|u| (u.name, u.id)).unwrap_or_default();
+    let scope_of_work = requisition.line_items.iter()
+        .map(|li| format!("{} x {} {}", li.quantity, li.unit, li.description))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let technical_requirements = requisition.line_items.iter()
+        .filter_map(|li| li.specifications.as_ref().map(|spec| format!("{}: {}", li.description, spec)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Tender {
+        title: requisition.description.clone(),
+        description: requisition.justification.clone(),
+        tender_type: TenderType::for_value(requisition.amount),
+        estimated_value: requisition.amount,
+        category: requisition.line_items.first().map(|li| li.category.clone()).unwrap_or_default(),
+        department: requisition.department.clone(),
+        cost_center: requisition.cost_center.clone(),
+        delivery_location: requisition.delivery_address.clone(),
+        scope_of_work,
+        technical_requirements,
+        requisition_ref: Some(requisition.id.clone()),
+        line_items: requisition.line_items.iter().map(|li| TenderLineItem {
+            id: li.id.clone(),
+            description: li.description.clone(),
+            category: li.category.clone(),
+            quantity: li.quantity,
+            unit: li.unit.clone(),
+            estimated_unit_price: li.unit_price,
+            estimated_total: li.total,
+            specifications: li.specifications.clone(),
+        }).collect(),
+        created_by: created_by.clone(),
+        created_by_id,
+        last_modified_by: created_by,
+        ..Default::default()
+    }
+}
+
+/// Draft purchase orders for a requisition made up entirely of catalogue
+/// items priced under an active contract, one per supplier and contract.
+///
+/// Errors with the first line that has to go out to tender instead.
+pub fn contracted_orders(
+    requisition: &Requisition,
+    catalogue: &[CatalogueItem],
+    contracts: &[ContractSummary],
+) -> Result<Vec<PurchaseOrder>, String> {
+    let (created_by, created_by_id) = auth::current().user.map(|u| (u.name, u.id)).unwrap_or_default();
+    let mut orders: Vec<PurchaseOrder> = Vec::new();
+
+    for line in &requisition.line_items {
+        let item = line.catalogue_item_id.as_ref()
+            .and_then(|id| catalogue.iter().find(|item| &item.id == id))
+            .ok_or_else(|| format!("{} is not a catalogue item", line.description))?;
+        if item.status != CatalogueItemStatus::Active {
+            return Err(format!("{} is {} in the catalogue", item.name, item.status.label().to_lowercase()));
+        }
+        let contract_ref = item.contract_ref.as_ref()
+            .ok_or_else(|| format!("{} is not priced under a contract", item.name))?;
+        let contract = contracts.iter()
+            .find(|c| &c.id == contract_ref)
+            .filter(|c| c.status == ContractStatus::Active && c.days_to_expiry.unwrap_or(0) >= 0)
+            .ok_or_else(|| format!("Contract {} for {} is not active", contract_ref, item.name))?;
+
+        let expected_delivery_date = requisition.required_by.clone()
+            .unwrap_or_else(|| today().add_days(item.supplier.lead_time_days as i64).to_iso());
+        let mut po_line = po::LineItem {
+            id: line.id.clone(),
+            item_code: item.item_code.clone(),
+            description: line.description.clone(),
+            quantity: line.quantity,
+            unit: line.unit.clone(),
+            unit_price: line.unit_price,
+            tax_rate: item.vat_rate,
+            delivery_date: expected_delivery_date.clone(),
+            notes: line.specifications.clone(),
+            ..Default::default()
+        };
+        po_line.calculate_totals();
+
+        let existing = orders.iter_mut().find(|o| {
+            o.supplier.id == item.supplier.id && o.contract_ref.as_deref() == Some(contract.id.as_str())
+        });
+        match existing {
+            Some(order) => {
+                order.expected_delivery_date = order.expected_delivery_date.clone().max(expected_delivery_date);
+                order.line_items.push(po_line);
+            }
+            None => orders.push(PurchaseOrder {
+                contract_ref: Some(contract.id.clone()),
+                requisition_ref: Some(requisition.id.clone()),
+                cost_center: requisition.cost_center.clone(),
+                supplier: po::Supplier {
+                    id: item.supplier.id.clone(),
+                    name: item.supplier.name.clone(),
+                    bbbee_level: item.supplier.bbbee_level,
+                    ..Default::default()
+                },
+                line_items: vec![po_line],
+                delivery_address: DeliveryAddress {
+                    address_line1: requisition.delivery_address.clone(),
+                    contact_person: requisition.requester.clone(),
+                    contact_email: requisition.requester_email.clone(),
+                    ..Default::default()
+                },
+                expected_delivery_date,
+                notes: Some(format!("Raised from requisition {}", requisition.id)),
+                created_by: created_by.clone(),
+                created_by_id: created_by_id.clone(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    for order in orders.iter_mut() {
+        order.calculate_totals();
+    }
+    Ok(orders)
+}
+
+/// Load what a conversion reads from when the stores are still empty
+pub async fn load_conversion_sources(stores: &ConversionStores) {
+    if stores.catalogue.items.get().is_empty() {
+        catalogue::load_catalogue(&stores.catalogue).await;
+    }
+    if stores.contracts.contracts.get().is_empty() {
+        contracts::load_contracts(&stores.contracts).await;
+    }
+}
+
+/// Key a conversion records its tender under
+const TENDER_KEY: &str = "tender";
+
+/// Key a direct order is recorded under: one order per supplier and contract
+fn order_key(order: &PurchaseOrder) -> String {
+    format!("{}/{}", order.supplier.id, order.contract_ref.as_deref().unwrap_or_default())
+}
+
+fn put_requisition(store: &RequisitionsStore, saved: &Requisition) {
+    let mut requisitions = store.requisitions.get();
+    if let Some(existing) = requisitions.iter_mut().find(|r| r.id == saved.id) {
+        *existing = saved.clone();
+    }
+    store.requisitions.set(requisitions);
+    store.selected.set(Some(saved.clone()));
+}
+
+/// Save the requisition's conversion record
+async fn save_conversion(store: &RequisitionsStore, requisition: &Requisition) -> Result<Requisition, String> {
+    let endpoint = format!("/requisitions/{}/conversion", requisition.id);
+    let saved = backend::save(HttpMethod::Put, &endpoint, requisition)
+        .await
+        .map_err(|e| e.to_string())?;
+    put_requisition(store, &saved);
+    Ok(saved)
+}
+
+/// Record what the requisition is about to become before anything is
+/// created, or pick up the conversion already under way
+async fn begin_conversion(
+    store: &RequisitionsStore,
+    mut requisition: Requisition,
+    target: ConversionTarget,
+) -> Result<Requisition, String> {
+    check_convertible(&requisition)?;
+    match &requisition.pending_conversion {
+        Some(pending) if pending.target == target => Ok(requisition),
+        Some(_) => Err(format!(
+            "Requisition {} is part way through another conversion; resume that one instead",
+            requisition.id
+        )),
+        None => {
+            requisition.pending_conversion = Some(PendingConversion {
+                target,
+                started_by: auth::current().user.map(|u| u.name).unwrap_or_default(),
+                started_at: now_iso(),
+                created: Vec::new(),
+            });
+            requisition.updated_at = now_iso();
+            save_conversion(store, &requisition).await
+        }
+    }
+}
+
+/// Note a record the conversion raised, so a resumed run reuses it
+async fn note_created(store: &RequisitionsStore, requisition: &mut Requisition, key: &str, record_id: &str) -> Result<(), String> {
+    if let Some(pending) = requisition.pending_conversion.as_mut() {
+        pending.created.push((key.to_string(), record_id.to_string()));
+    }
+    *requisition = save_conversion(store, requisition).await?;
+    Ok(())
+}
+
+/// Mark the requisition as being sourced and save its links to the new
+/// records, closing the conversion record
+async fn record_conversion(store: &RequisitionsStore, mut requisition: Requisition) -> Result<Requisition, String> {
+    requisition.status = RequisitionStatus::InProgress;
+    requisition.pending_conversion = None;
+    requisition.updated_at = now_iso();
+
+    let endpoint = format!("/requisitions/{}/convert", requisition.id);
+    let saved = backend::save(HttpMethod::Post, &endpoint, &requisition)
+        .await
+        .map_err(|e| e.to_string())?;
+    put_requisition(store, &saved);
+    Ok(saved)
+}
+
+/// Raise a draft RFQ or RFT from an approved requisition.
+///
+/// The conversion is recorded on the requisition first. If a step fails the
+/// record stays, and running the conversion again resumes it: a tender
+/// already raised for the requisition is reused rather than raised twice.
+pub async fn convert_to_tender(store: &RequisitionsStore, stores: &ConversionStores, id: &str) -> Result<Tender, String> {
+    store.loading.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::ConvertRequisition)?;
+        let requisition = get_requisition(store, id).await
+            .ok_or_else(|| "Requisition not found".to_string())?;
+        let mut requisition = begin_conversion(store, requisition, ConversionTarget::Tender).await?;
+
+        // A requisition is sourced through at most one tender
+        let raised = tenders::tenders_for_requisition(&stores.tenders, &requisition.id).await?;
+        let tender = match raised.into_iter().next() {
+            Some(tender) => tender,
+            None => {
+                let tender = tenders::create_tender(&stores.tenders, tender_from(&requisition)).await?;
+                note_created(store, &mut requisition, TENDER_KEY, &tender.id).await?;
+                tender
+            }
+        };
+        requisition.tender_ref = Some(tender.id.clone());
+        record_conversion(store, requisition).await?;
+        Ok::<_, String>(tender)
+    }
+    .await;
+
+    if let Err(e) = &result {
+        store.error.set(Some(e.clone()));
+    }
+    store.loading.set(false);
+    result
+}
+
+/// Order the requisition's contracted catalogue items directly, one
+/// purchase order per supplier, skipping the tender.
+///
+/// Like a tender conversion this is recorded first and resumable: each order
+/// is keyed by the requisition and its supplier and contract, and an order
+/// already raised under that key, whether noted in the conversion record or
+/// found on the server, is reused instead of raised again.
+pub async fn convert_to_purchase_orders(
+    store: &RequisitionsStore,
+    stores: &ConversionStores,
+    id: &str,
+) -> Result<Vec<String>, String> {
+    store.loading.set(true);
+    store.error.set(None);
+
+    let result = async {
+        permissions::require(Action::ConvertRequisition)?;
+        let requisition = get_requisition(store, id).await
+            .ok_or_else(|| "Requisition not found".to_string())?;
+        load_conversion_sources(stores).await;
+        let orders = contracted_orders(&requisition, &stores.catalogue.items.get(), &stores.contracts.contracts.get())?;
+        let mut requisition = begin_conversion(store, requisition, ConversionTarget::PurchaseOrders).await?;
+
+        let raised = purchase_orders::purchase_orders_for_requisition(&requisition.id).await?;
+        let mut po_ids = Vec::new();
+        for order in orders {
+            let key = order_key(&order);
+            let existing = requisition.pending_conversion.as_ref()
+                .and_then(|p| p.created_for(&key))
+                .map(str::to_string)
+                .or_else(|| raised.iter().find(|po| order_key(po) == key).map(|po| po.id.clone()));
+            let po_id = match existing {
+                Some(po_id) => po_id,
+                None => {
+                    let po_id = purchase_orders::create_purchase_order(&stores.purchase_orders, order).await?;
+                    note_created(store, &mut requisition, &key, &po_id).await?;
+                    po_id
+                }
+            };
+            po_ids.push(po_id);
+        }
+        requisition.purchase_order_refs = po_ids.clone();
+        record_conversion(store, requisition).await?;
+        Ok::<_, String>(po_ids)
+    }
+    .await;
+
+    if let Err(e) = &result {
+        store.error.set(Some(e.clone()));
+    }
+    store.loading.set(false);
+    result
+}
+
 /// Delete a requisition (only drafts)
 pub async fn delete_requisition(store: &RequisitionsStore, id: &str) -> Result<(), String> {
     store.loading.set(true);
@@ -119,6 +840,12 @@ pub async fn delete_requisition(store: &RequisitionsStore, id: &str) -> Result<(
             return Err("Only draft requisitions can be deleted".to_string());
         }
 
+        if let Err(e) = backend::remove(&format!("/requisitions/{}", id)).await {
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+
         requisitions.remove(pos);
         store.requisitions.set(requisitions);
 
@@ -138,15 +865,24 @@ pub async fn delete_requisition(store: &RequisitionsStore, id: &str) -> Result<(
 
 /// Get a single requisition by ID
 pub async fn get_requisition(store: &RequisitionsStore, id: &str) -> Option<Requisition> {
-    store.requisitions.get()
+    // A deep link can land here before the list page has populated the store
+    if backend::is_mock() && store.requisitions.get().is_empty() {
+        load_mock_data(store);
+    }
+    reroute_approvals(store);
+    // Links made before the server confirmed a provisional number still resolve
+    let id = reference::resolve(id);
+
+    let cached = store.requisitions.get()
         .iter()
         .find(|r| r.id == id)
-        .cloned()
-}
+        .cloned();
+
+    if cached.is_some() || backend::is_mock() {
+        return cached;
+    }
 
-/// Helper to get current timestamp
-fn chrono_now() -> String {
-    // In production, use chrono crate
-    // For now, return a fixed timestamp
-    "2025-01-17T16:00:00Z".to_string()
+    let mut requisition = api::get::<Requisition>(&format!("/requisitions/{}", id)).await.ok()?;
+    workflow::reroute(&mut requisition.approval_workflow, ApprovalDocument::Requisition, requisition.amount);
+    Some(requisition)
 }
@@ -1,9 +1,12 @@
 //! Requisitions store
 
 use components::prelude::*;
+use crate::features::workflow::types::{ApprovalStep, StepStatus};
+use crate::util::api::FieldError;
+use crate::util::money::Money;
 use super::types::{
     Requisition, RequisitionStatus, RequisitionFilter, PaginationState,
-    LineItem, Attachment, ApprovalStep, Priority,
+    LineItem, Attachment, Priority,
 };
 
 /// Requisitions state store
@@ -15,6 +18,8 @@ pub struct RequisitionsStore {
     pub pagination: Signal<PaginationState>,
     pub loading: Signal<bool>,
     pub error: Signal<Option<String>>,
+    /// Per-field validation errors from the last failed save
+    pub field_errors: Signal<Vec<FieldError>>,
     pub form_step: Signal<u32>,
     pub form_data: Signal<Requisition>,
 }
@@ -28,6 +33,7 @@ impl RequisitionsStore {
             pagination: signal(PaginationState::default()),
             loading: signal(false),
             error: signal(None),
+            field_errors: signal(Vec::new()),
             form_step: signal(1),
             form_data: signal(Requisition::default()),
         }
@@ -54,12 +60,13 @@ pub fn load_mock_data(store: &RequisitionsStore) {
             id: "REQ-2025-0847".to_string(),
             description: "IT Hardware - Laptops Q4".to_string(),
             justification: "Replacement of outdated laptops for the IT department to improve productivity and support latest software requirements.".to_string(),
-            amount: 2_450_000.0,
+            amount: Money::rands(2_450_000),
             status: RequisitionStatus::PendingApproval,
             priority: Priority::High,
             department: "Information Technology".to_string(),
             cost_center: "CC-IT-001".to_string(),
             requester: "John Ndlovu".to_string(),
+            requester_id: "U010".to_string(),
             requester_email: "john.ndlovu@gov.za".to_string(),
             line_items: vec![
                 LineItem {
@@ -68,10 +75,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "IT Equipment".to_string(),
                     quantity: 50,
                     unit: "Each".to_string(),
-                    unit_price: 28_500.0,
-                    total: 1_425_000.0,
+                    unit_price: Money::rands(28_500),
+                    total: Money::rands(1_425_000),
                     specifications: Some("Intel i7, 16GB RAM, 512GB SSD".to_string()),
-                    catalogue_item_id: Some("CAT-IT-001".to_string()),
+                    catalogue_item_id: Some("ITEM-001".to_string()),
+                    supplier_id: Some("SUP-001".to_string()),
+                    supplier_name: Some("TechSolutions SA (Pty) Ltd".to_string()),
                 },
                 LineItem {
                     id: "LI-002".to_string(),
@@ -79,10 +88,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "IT Equipment".to_string(),
                     quantity: 50,
                     unit: "Each".to_string(),
-                    unit_price: 4_500.0,
-                    total: 225_000.0,
+                    unit_price: Money::rands(4500),
+                    total: Money::rands(225_000),
                     specifications: None,
                     catalogue_item_id: Some("CAT-IT-002".to_string()),
+                    supplier_id: None,
+                    supplier_name: None,
                 },
                 LineItem {
                     id: "LI-003".to_string(),
@@ -90,10 +101,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Software".to_string(),
                     quantity: 50,
                     unit: "License".to_string(),
-                    unit_price: 16_000.0,
-                    total: 800_000.0,
+                    unit_price: Money::rands(16_000),
+                    total: Money::rands(800_000),
                     specifications: None,
                     catalogue_item_id: Some("CAT-SW-001".to_string()),
+                    supplier_id: None,
+                    supplier_name: None,
                 },
             ],
             attachments: vec![
@@ -117,25 +130,46 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     step: 1,
                     role: "Line Manager".to_string(),
                     approver: Some("Sarah Dlamini".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U002".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-01-16T09:00:00Z".to_string()),
                     comments: Some("Approved - aligns with IT refresh strategy".to_string()),
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-15T10:00:00Z".to_string()),
+                    due_date: Some("2025-01-17".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
                 ApprovalStep {
                     step: 2,
                     role: "Budget Holder".to_string(),
                     approver: Some("Peter Mokwena".to_string()),
-                    status: "pending".to_string(),
+                    approver_id: Some("U003".to_string()),
+                    status: StepStatus::Pending,
                     date: None,
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-16T09:00:00Z".to_string()),
+                    due_date: Some("2025-01-20".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
                 ApprovalStep {
                     step: 3,
                     role: "CFO".to_string(),
                     approver: None,
-                    status: "pending".to_string(),
+                    approver_id: None,
+                    status: StepStatus::Pending,
                     date: None,
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
                 },
             ],
             created_at: "2025-01-15T10:00:00Z".to_string(),
@@ -143,17 +177,21 @@ pub fn load_mock_data(store: &RequisitionsStore) {
             required_by: Some("2025-03-31".to_string()),
             delivery_address: "123 Government Building, Pretoria, 0001".to_string(),
             notes: Some("Urgent - required for new financial year".to_string()),
+            tender_ref: None,
+            purchase_order_refs: Vec::new(),
+            pending_conversion: None,
         },
         Requisition {
             id: "REQ-2025-0846".to_string(),
             description: "Office Supplies - Stationery Q1".to_string(),
             justification: "Quarterly stationery replenishment for all departments.".to_string(),
-            amount: 125_000.0,
+            amount: Money::rands(125_000),
             status: RequisitionStatus::Approved,
             priority: Priority::Medium,
             department: "Administration".to_string(),
             cost_center: "CC-ADM-001".to_string(),
             requester: "Mary Khumalo".to_string(),
+            requester_id: "U011".to_string(),
             requester_email: "mary.khumalo@gov.za".to_string(),
             line_items: vec![
                 LineItem {
@@ -162,21 +200,25 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Office Supplies".to_string(),
                     quantity: 200,
                     unit: "Box".to_string(),
-                    unit_price: 350.0,
-                    total: 70_000.0,
+                    unit_price: Money::rands(350),
+                    total: Money::rands(70_000),
                     specifications: None,
-                    catalogue_item_id: Some("CAT-OS-001".to_string()),
+                    catalogue_item_id: Some("ITEM-003".to_string()),
+                    supplier_id: Some("SUP-002".to_string()),
+                    supplier_name: Some("Office Essentials SA".to_string()),
                 },
                 LineItem {
                     id: "LI-005".to_string(),
-                    description: "Assorted Stationery Pack".to_string(),
+                    description: "Ballpoint Pens (Box of 50)".to_string(),
                     category: "Office Supplies".to_string(),
                     quantity: 100,
-                    unit: "Pack".to_string(),
-                    unit_price: 550.0,
-                    total: 55_000.0,
-                    specifications: Some("Pens, pencils, staplers, tape".to_string()),
-                    catalogue_item_id: Some("CAT-OS-002".to_string()),
+                    unit: "Box".to_string(),
+                    unit_price: Money::rands(550),
+                    total: Money::rands(55_000),
+                    specifications: Some("Medium point, black ink".to_string()),
+                    catalogue_item_id: Some("ITEM-009".to_string()),
+                    supplier_id: Some("SUP-002".to_string()),
+                    supplier_name: Some("Office Essentials SA".to_string()),
                 },
             ],
             attachments: vec![],
@@ -185,9 +227,16 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     step: 1,
                     role: "Line Manager".to_string(),
                     approver: Some("David Sithole".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U006".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-01-14T11:00:00Z".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-14T08:00:00Z".to_string()),
+                    due_date: Some("2025-01-17".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
             ],
             created_at: "2025-01-14T08:00:00Z".to_string(),
@@ -195,17 +244,21 @@ pub fn load_mock_data(store: &RequisitionsStore) {
             required_by: Some("2025-02-15".to_string()),
             delivery_address: "456 Admin Block, Cape Town, 8001".to_string(),
             notes: None,
+            tender_ref: None,
+            purchase_order_refs: Vec::new(),
+            pending_conversion: None,
         },
         Requisition {
             id: "REQ-2025-0845".to_string(),
             description: "Security Services - Annual Contract".to_string(),
             justification: "Renewal of security services contract for all government buildings in Gauteng province.".to_string(),
-            amount: 8_750_000.0,
+            amount: Money::rands(8_750_000),
             status: RequisitionStatus::InProgress,
             priority: Priority::High,
             department: "Facilities".to_string(),
             cost_center: "CC-FAC-001".to_string(),
             requester: "Thabo Mokoena".to_string(),
+            requester_id: "U001".to_string(),
             requester_email: "thabo.mokoena@gov.za".to_string(),
             line_items: vec![
                 LineItem {
@@ -214,10 +267,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Services".to_string(),
                     quantity: 12,
                     unit: "Month".to_string(),
-                    unit_price: 650_000.0,
-                    total: 7_800_000.0,
+                    unit_price: Money::rands(650_000),
+                    total: Money::rands(7_800_000),
                     specifications: Some("Armed response, access control, CCTV monitoring".to_string()),
                     catalogue_item_id: None,
+                    supplier_id: None,
+                    supplier_name: None,
                 },
                 LineItem {
                     id: "LI-007".to_string(),
@@ -225,10 +280,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Services".to_string(),
                     quantity: 12,
                     unit: "Month".to_string(),
-                    unit_price: 79_166.67,
-                    total: 950_000.0,
+                    unit_price: Money::from_cents(7_916_667),
+                    total: Money::rands(950_000),
                     specifications: None,
                     catalogue_item_id: None,
+                    supplier_id: None,
+                    supplier_name: None,
                 },
             ],
             attachments: vec![
@@ -245,25 +302,46 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     step: 1,
                     role: "Line Manager".to_string(),
                     approver: Some("Grace Nkosi".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U004".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-01-11T09:00:00Z".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-10T10:00:00Z".to_string()),
+                    due_date: Some("2025-01-14".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
                 ApprovalStep {
                     step: 2,
                     role: "Budget Holder".to_string(),
                     approver: Some("Peter Mokwena".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U003".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-01-12T10:00:00Z".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-11T09:00:00Z".to_string()),
+                    due_date: Some("2025-01-14".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
                 ApprovalStep {
                     step: 3,
                     role: "CFO".to_string(),
                     approver: Some("Nomvula Zulu".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U005".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-01-13T15:00:00Z".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-12T10:00:00Z".to_string()),
+                    due_date: Some("2025-01-15".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
             ],
             created_at: "2025-01-10T10:00:00Z".to_string(),
@@ -271,17 +349,21 @@ pub fn load_mock_data(store: &RequisitionsStore) {
             required_by: Some("2025-02-01".to_string()),
             delivery_address: "All Gauteng Government Buildings".to_string(),
             notes: Some("Existing contract expires 31 Jan 2025".to_string()),
+            tender_ref: None,
+            purchase_order_refs: Vec::new(),
+            pending_conversion: None,
         },
         Requisition {
             id: "REQ-2025-0844".to_string(),
             description: "Fleet Maintenance Services".to_string(),
             justification: "Annual vehicle maintenance and servicing for government fleet.".to_string(),
-            amount: 1_200_000.0,
+            amount: Money::rands(1_200_000),
             status: RequisitionStatus::Approved,
             priority: Priority::Medium,
             department: "Transport".to_string(),
             cost_center: "CC-TRN-001".to_string(),
             requester: "Samuel Mthembu".to_string(),
+            requester_id: "U012".to_string(),
             requester_email: "samuel.mthembu@gov.za".to_string(),
             line_items: vec![
                 LineItem {
@@ -290,10 +372,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Fleet Services".to_string(),
                     quantity: 50,
                     unit: "Service".to_string(),
-                    unit_price: 8_500.0,
-                    total: 425_000.0,
+                    unit_price: Money::rands(8500),
+                    total: Money::rands(425_000),
                     specifications: Some("Full service including oil change, filters, brake check".to_string()),
                     catalogue_item_id: None,
+                    supplier_id: None,
+                    supplier_name: None,
                 },
                 LineItem {
                     id: "LI-009".to_string(),
@@ -301,10 +385,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Fleet Services".to_string(),
                     quantity: 35,
                     unit: "Service".to_string(),
-                    unit_price: 12_500.0,
-                    total: 437_500.0,
+                    unit_price: Money::rands(12_500),
+                    total: Money::rands(437_500),
                     specifications: Some("Full service including 4x4 drivetrain check".to_string()),
                     catalogue_item_id: None,
+                    supplier_id: None,
+                    supplier_name: None,
                 },
                 LineItem {
                     id: "LI-010".to_string(),
@@ -312,10 +398,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Fleet Services".to_string(),
                     quantity: 150,
                     unit: "Tyre".to_string(),
-                    unit_price: 2_250.0,
-                    total: 337_500.0,
+                    unit_price: Money::rands(2250),
+                    total: Money::rands(337_500),
                     specifications: None,
                     catalogue_item_id: None,
+                    supplier_id: None,
+                    supplier_name: None,
                 },
             ],
             attachments: vec![],
@@ -324,17 +412,31 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     step: 1,
                     role: "Line Manager".to_string(),
                     approver: Some("Linda Mbeki".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: None,
+                    status: StepStatus::Approved,
                     date: Some("2025-01-08T14:00:00Z".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-07T11:00:00Z".to_string()),
+                    due_date: Some("2025-01-10".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
                 ApprovalStep {
                     step: 2,
                     role: "Budget Holder".to_string(),
                     approver: Some("James Botha".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: None,
+                    status: StepStatus::Approved,
                     date: Some("2025-01-09T09:00:00Z".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-08T14:00:00Z".to_string()),
+                    due_date: Some("2025-01-13".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
             ],
             created_at: "2025-01-07T11:00:00Z".to_string(),
@@ -342,17 +444,21 @@ pub fn load_mock_data(store: &RequisitionsStore) {
             required_by: Some("2025-01-31".to_string()),
             delivery_address: "Government Fleet Depot, Johannesburg".to_string(),
             notes: None,
+            tender_ref: None,
+            purchase_order_refs: Vec::new(),
+            pending_conversion: None,
         },
         Requisition {
             id: "REQ-2025-0843".to_string(),
             description: "Training - Staff Development Programme".to_string(),
             justification: "Leadership and management training for senior staff across departments.".to_string(),
-            amount: 560_000.0,
+            amount: Money::rands(560_000),
             status: RequisitionStatus::Draft,
             priority: Priority::Low,
             department: "Human Resources".to_string(),
             cost_center: "CC-HR-001".to_string(),
             requester: "Patricia Venter".to_string(),
+            requester_id: "U013".to_string(),
             requester_email: "patricia.venter@gov.za".to_string(),
             line_items: vec![
                 LineItem {
@@ -361,10 +467,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Training".to_string(),
                     quantity: 20,
                     unit: "Participant".to_string(),
-                    unit_price: 18_000.0,
-                    total: 360_000.0,
+                    unit_price: Money::rands(18_000),
+                    total: Money::rands(360_000),
                     specifications: Some("5-day intensive leadership course".to_string()),
                     catalogue_item_id: None,
+                    supplier_id: None,
+                    supplier_name: None,
                 },
                 LineItem {
                     id: "LI-012".to_string(),
@@ -372,10 +480,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Training".to_string(),
                     quantity: 10,
                     unit: "Participant".to_string(),
-                    unit_price: 20_000.0,
-                    total: 200_000.0,
+                    unit_price: Money::rands(20_000),
+                    total: Money::rands(200_000),
                     specifications: Some("PMP certification training and exam".to_string()),
                     catalogue_item_id: None,
+                    supplier_id: None,
+                    supplier_name: None,
                 },
             ],
             attachments: vec![],
@@ -385,17 +495,21 @@ pub fn load_mock_data(store: &RequisitionsStore) {
             required_by: Some("2025-04-30".to_string()),
             delivery_address: "N/A - Virtual Training".to_string(),
             notes: Some("Awaiting training needs analysis completion".to_string()),
+            tender_ref: None,
+            purchase_order_refs: Vec::new(),
+            pending_conversion: None,
         },
         Requisition {
             id: "REQ-2025-0842".to_string(),
             description: "Medical Supplies - Clinic Replenishment".to_string(),
             justification: "Emergency replenishment of medical supplies for on-site clinic.".to_string(),
-            amount: 85_000.0,
+            amount: Money::rands(85_000),
             status: RequisitionStatus::Submitted,
             priority: Priority::Urgent,
             department: "Health Services".to_string(),
             cost_center: "CC-HS-001".to_string(),
             requester: "Dr. Fatima Patel".to_string(),
+            requester_id: "U014".to_string(),
             requester_email: "fatima.patel@gov.za".to_string(),
             line_items: vec![
                 LineItem {
@@ -404,10 +518,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Medical".to_string(),
                     quantity: 50,
                     unit: "Kit".to_string(),
-                    unit_price: 850.0,
-                    total: 42_500.0,
+                    unit_price: Money::rands(850),
+                    total: Money::rands(42_500),
                     specifications: Some("Comprehensive workplace first aid kit".to_string()),
                     catalogue_item_id: Some("CAT-MED-001".to_string()),
+                    supplier_id: None,
+                    supplier_name: None,
                 },
                 LineItem {
                     id: "LI-014".to_string(),
@@ -415,10 +531,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Medical".to_string(),
                     quantity: 100,
                     unit: "Box".to_string(),
-                    unit_price: 250.0,
-                    total: 25_000.0,
+                    unit_price: Money::rands(250),
+                    total: Money::rands(25_000),
                     specifications: Some("50 masks per box".to_string()),
                     catalogue_item_id: Some("CAT-MED-002".to_string()),
+                    supplier_id: None,
+                    supplier_name: None,
                 },
                 LineItem {
                     id: "LI-015".to_string(),
@@ -426,10 +544,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Medical".to_string(),
                     quantity: 50,
                     unit: "Container".to_string(),
-                    unit_price: 350.0,
-                    total: 17_500.0,
+                    unit_price: Money::rands(350),
+                    total: Money::rands(17_500),
                     specifications: Some("70% alcohol content".to_string()),
                     catalogue_item_id: Some("CAT-MED-003".to_string()),
+                    supplier_id: None,
+                    supplier_name: None,
                 },
             ],
             attachments: vec![],
@@ -438,9 +558,16 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     step: 1,
                     role: "Line Manager".to_string(),
                     approver: None,
-                    status: "pending".to_string(),
+                    approver_id: None,
+                    status: StepStatus::Pending,
                     date: None,
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-17T14:00:00Z".to_string()),
+                    due_date: Some("2025-01-20".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
             ],
             created_at: "2025-01-17T14:00:00Z".to_string(),
@@ -448,17 +575,21 @@ pub fn load_mock_data(store: &RequisitionsStore) {
             required_by: Some("2025-01-25".to_string()),
             delivery_address: "Government Clinic, Building A, Pretoria".to_string(),
             notes: Some("URGENT - Current stock critically low".to_string()),
+            tender_ref: None,
+            purchase_order_refs: Vec::new(),
+            pending_conversion: None,
         },
         Requisition {
             id: "REQ-2025-0841".to_string(),
             description: "Furniture - New Office Setup".to_string(),
             justification: "Office furniture for new regional office in Durban.".to_string(),
-            amount: 450_000.0,
+            amount: Money::rands(450_000),
             status: RequisitionStatus::Rejected,
             priority: Priority::Medium,
             department: "Facilities".to_string(),
             cost_center: "CC-FAC-002".to_string(),
             requester: "Michael van der Merwe".to_string(),
+            requester_id: "U015".to_string(),
             requester_email: "michael.vdm@gov.za".to_string(),
             line_items: vec![
                 LineItem {
@@ -467,10 +598,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Furniture".to_string(),
                     quantity: 5,
                     unit: "Each".to_string(),
-                    unit_price: 25_000.0,
-                    total: 125_000.0,
+                    unit_price: Money::rands(25_000),
+                    total: Money::rands(125_000),
                     specifications: None,
                     catalogue_item_id: None,
+                    supplier_id: None,
+                    supplier_name: None,
                 },
                 LineItem {
                     id: "LI-017".to_string(),
@@ -478,10 +611,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Furniture".to_string(),
                     quantity: 25,
                     unit: "Each".to_string(),
-                    unit_price: 8_500.0,
-                    total: 212_500.0,
+                    unit_price: Money::rands(8500),
+                    total: Money::rands(212_500),
                     specifications: None,
                     catalogue_item_id: None,
+                    supplier_id: None,
+                    supplier_name: None,
                 },
                 LineItem {
                     id: "LI-018".to_string(),
@@ -489,10 +624,12 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     category: "Furniture".to_string(),
                     quantity: 30,
                     unit: "Each".to_string(),
-                    unit_price: 3_750.0,
-                    total: 112_500.0,
+                    unit_price: Money::rands(3750),
+                    total: Money::rands(112_500),
                     specifications: None,
                     catalogue_item_id: None,
+                    supplier_id: None,
+                    supplier_name: None,
                 },
             ],
             attachments: vec![],
@@ -501,17 +638,31 @@ pub fn load_mock_data(store: &RequisitionsStore) {
                     step: 1,
                     role: "Line Manager".to_string(),
                     approver: Some("Grace Nkosi".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U004".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-01-05T10:00:00Z".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-04T09:00:00Z".to_string()),
+                    due_date: Some("2025-01-08".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
                 ApprovalStep {
                     step: 2,
                     role: "Budget Holder".to_string(),
                     approver: Some("Peter Mokwena".to_string()),
-                    status: "rejected".to_string(),
+                    approver_id: Some("U003".to_string()),
+                    status: StepStatus::Rejected,
                     date: Some("2025-01-06T15:00:00Z".to_string()),
                     comments: Some("Budget not available for Q1. Please resubmit for Q2.".to_string()),
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: Some("2025-01-05T10:00:00Z".to_string()),
+                    due_date: Some("2025-01-08".to_string()),
+                    reminded_at: None,
+                    escalated_from: None,
                 },
             ],
             created_at: "2025-01-04T09:00:00Z".to_string(),
@@ -519,6 +670,9 @@ pub fn load_mock_data(store: &RequisitionsStore) {
             required_by: Some("2025-02-28".to_string()),
             delivery_address: "New Regional Office, Durban, 4001".to_string(),
             notes: None,
+            tender_ref: None,
+            purchase_order_refs: Vec::new(),
+            pending_conversion: None,
         },
     ];
 
@@ -1,6 +1,12 @@
 //! Requisition domain types
 
 use serde::{Deserialize, Serialize};
+use crate::features::catalogue::types::CatalogueItem;
+use crate::features::workflow::types::ApprovalStep;
+use crate::util::i18n::t;
+use crate::util::money::Money;
+use crate::util::reference;
+use crate::util::url::{QueryFilter, QueryParams};
 
 /// Requisition status
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -30,7 +36,7 @@ impl RequisitionStatus {
     }
 
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             RequisitionStatus::Draft => "Draft",
             RequisitionStatus::Submitted => "Submitted",
             RequisitionStatus::PendingApproval => "Pending Approval",
@@ -39,7 +45,7 @@ impl RequisitionStatus {
             RequisitionStatus::Cancelled => "Cancelled",
             RequisitionStatus::InProgress => "In Progress",
             RequisitionStatus::Complete => "Complete",
-        }
+        })
     }
 
     pub fn from_str(s: &str) -> Self {
@@ -77,12 +83,12 @@ impl Priority {
     }
 
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             Priority::Low => "Low",
             Priority::Medium => "Medium",
             Priority::High => "High",
             Priority::Urgent => "Urgent",
-        }
+        })
     }
 }
 
@@ -94,30 +100,113 @@ pub struct LineItem {
     pub category: String,
     pub quantity: u32,
     pub unit: String,
-    pub unit_price: f64,
-    pub total: f64,
+    pub unit_price: Money,
+    pub total: Money,
     pub specifications: Option<String>,
     pub catalogue_item_id: Option<String>,
+    /// Supplier of the catalogue item the line was picked from
+    #[serde(default)]
+    pub supplier_id: Option<String>,
+    #[serde(default)]
+    pub supplier_name: Option<String>,
 }
 
 impl LineItem {
     pub fn new() -> Self {
         Self {
-            id: format!("LI-{}", uuid_short()),
+            id: reference::unique_id("LI"),
             description: String::new(),
             category: String::new(),
             quantity: 1,
             unit: "Each".to_string(),
-            unit_price: 0.0,
-            total: 0.0,
+            unit_price: Money::ZERO,
+            total: Money::ZERO,
             specifications: None,
             catalogue_item_id: None,
+            supplier_id: None,
+            supplier_name: None,
         }
     }
 
+    /// Line for a catalogue item, ordered at the supplier's minimum quantity
+    pub fn from_catalogue(item: &CatalogueItem) -> Self {
+        let specifications = item.specifications.iter()
+            .map(|spec| format!("{}: {}{}", spec.key, spec.value, spec.unit.as_deref().map(|u| format!(" {}", u)).unwrap_or_default()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut line = Self {
+            description: item.name.clone(),
+            category: item.category_name.clone(),
+            quantity: item.supplier.minimum_order_quantity.max(1),
+            unit: item.unit_of_measure.name.clone(),
+            specifications: (!specifications.is_empty()).then_some(specifications),
+            catalogue_item_id: Some(item.id.clone()),
+            supplier_id: Some(item.supplier.id.clone()),
+            supplier_name: Some(item.supplier.name.clone()),
+            ..Self::new()
+        };
+        line.apply_catalogue_price(item);
+        line
+    }
+
+    /// Price the line from the catalogue item's tier for its quantity
+    pub fn apply_catalogue_price(&mut self, item: &CatalogueItem) {
+        self.unit_price = item.price_for(self.quantity);
+        self.calculate_total();
+    }
+
     pub fn calculate_total(&mut self) {
-        self.total = self.quantity as f64 * self.unit_price;
+        self.total = self.unit_price * self.quantity;
+    }
+}
+
+/// Requisition lines that one supplier would fill, becoming one purchase order
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupplierGroup {
+    /// `None` for free-text lines that still need a supplier
+    pub supplier_id: Option<String>,
+    pub supplier_name: String,
+    pub line_ids: Vec<String>,
+    pub total: Money,
+}
+
+/// Group lines by supplier in the order suppliers first appear, with lines
+/// that have no supplier last
+pub fn group_by_supplier(lines: &[LineItem]) -> Vec<SupplierGroup> {
+    let mut groups: Vec<SupplierGroup> = Vec::new();
+    let mut unassigned = SupplierGroup {
+        supplier_id: None,
+        supplier_name: t("No supplier").to_string(),
+        line_ids: Vec::new(),
+        total: Money::ZERO,
+    };
+    for line in lines {
+        let group = match &line.supplier_id {
+            Some(id) => {
+                let existing = groups.iter().position(|g| g.supplier_id.as_ref() == Some(id));
+                let pos = match existing {
+                    Some(pos) => pos,
+                    None => {
+                        groups.push(SupplierGroup {
+                            supplier_id: Some(id.clone()),
+                            supplier_name: line.supplier_name.clone().unwrap_or_else(|| id.clone()),
+                            line_ids: Vec::new(),
+                            total: Money::ZERO,
+                        });
+                        groups.len() - 1
+                    }
+                };
+                &mut groups[pos]
+            }
+            None => &mut unassigned,
+        };
+        group.line_ids.push(line.id.clone());
+        group.total += line.total;
+    }
+    if !unassigned.line_ids.is_empty() {
+        groups.push(unassigned);
     }
+    groups
 }
 
 /// Attachment for requisition
@@ -130,29 +219,21 @@ pub struct Attachment {
     pub uploaded_at: String,
 }
 
-/// Approval step in workflow
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct ApprovalStep {
-    pub step: u32,
-    pub role: String,
-    pub approver: Option<String>,
-    pub status: String, // "pending", "approved", "rejected"
-    pub date: Option<String>,
-    pub comments: Option<String>,
-}
-
 /// Complete requisition entity
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Requisition {
     pub id: String,
     pub description: String,
     pub justification: String,
-    pub amount: f64,
+    pub amount: Money,
     pub status: RequisitionStatus,
     pub priority: Priority,
     pub department: String,
     pub cost_center: String,
     pub requester: String,
+    /// Id of the user who raised it; approvals are checked against this, not the name
+    #[serde(default)]
+    pub requester_id: String,
     pub requester_email: String,
     pub line_items: Vec<LineItem>,
     pub attachments: Vec<Attachment>,
@@ -162,6 +243,15 @@ pub struct Requisition {
     pub required_by: Option<String>,
     pub delivery_address: String,
     pub notes: Option<String>,
+    /// Tender the requisition was converted into
+    #[serde(default)]
+    pub tender_ref: Option<String>,
+    /// Purchase orders raised directly from the requisition
+    #[serde(default)]
+    pub purchase_order_refs: Vec<String>,
+    /// Conversion started but not yet finished
+    #[serde(default)]
+    pub pending_conversion: Option<PendingConversion>,
 }
 
 impl Default for Requisition {
@@ -170,12 +260,13 @@ impl Default for Requisition {
             id: String::new(),
             description: String::new(),
             justification: String::new(),
-            amount: 0.0,
+            amount: Money::ZERO,
             status: RequisitionStatus::Draft,
             priority: Priority::Medium,
             department: String::new(),
             cost_center: String::new(),
             requester: String::new(),
+            requester_id: String::new(),
             requester_email: String::new(),
             line_items: Vec::new(),
             attachments: Vec::new(),
@@ -185,6 +276,9 @@ impl Default for Requisition {
             required_by: None,
             delivery_address: String::new(),
             notes: None,
+            tender_ref: None,
+            purchase_order_refs: Vec::new(),
+            pending_conversion: None,
         }
     }
 }
@@ -195,6 +289,36 @@ impl Requisition {
     }
 }
 
+/// What an approved requisition is sourced through
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ConversionTarget {
+    Tender,
+    PurchaseOrders,
+}
+
+/// A conversion recorded on the requisition before anything is created.
+///
+/// Each tender or order is added to `created` as soon as it exists, keyed by
+/// what it was raised for ("tender", or the supplier id of a direct order).
+/// While the record is there the conversion can only be resumed, so a retry
+/// after a failure picks up the records already raised instead of raising
+/// them again.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PendingConversion {
+    pub target: ConversionTarget,
+    pub started_by: String,
+    pub started_at: String,
+    #[serde(default)]
+    pub created: Vec<(String, String)>,
+}
+
+impl PendingConversion {
+    /// Record already raised for `key`
+    pub fn created_for(&self, key: &str) -> Option<&str> {
+        self.created.iter().find(|(k, _)| k == key).map(|(_, id)| id.as_str())
+    }
+}
+
 /// Filter options for requisition list
 #[derive(Clone, Debug, Default)]
 pub struct RequisitionFilter {
@@ -203,8 +327,32 @@ pub struct RequisitionFilter {
     pub department: Option<String>,
     pub date_from: Option<String>,
     pub date_to: Option<String>,
-    pub min_amount: Option<f64>,
-    pub max_amount: Option<f64>,
+    pub min_amount: Option<Money>,
+    pub max_amount: Option<Money>,
+}
+
+impl QueryFilter for RequisitionFilter {
+    fn from_query(params: &QueryParams) -> Self {
+        Self {
+            search: params.get("q").unwrap_or_default(),
+            status: params.get("status").map(|s| RequisitionStatus::from_str(&s)),
+            department: params.get("department"),
+            date_from: params.get("from"),
+            date_to: params.get("to"),
+            min_amount: params.get_parsed("min"),
+            max_amount: params.get_parsed("max"),
+        }
+    }
+
+    fn write_query(&self, params: &mut QueryParams) {
+        params.set("q", Some(&self.search));
+        params.set("status", self.status.map(|s| s.as_str()));
+        params.set("department", self.department.as_ref());
+        params.set("from", self.date_from.as_ref());
+        params.set("to", self.date_to.as_ref());
+        params.set("min", self.min_amount);
+        params.set("max", self.max_amount);
+    }
 }
 
 /// Pagination state
@@ -236,13 +384,3 @@ impl PaginationState {
         }
     }
 }
-
-/// Helper to generate short UUID
-fn uuid_short() -> String {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let time = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_millis();
-    format!("{:x}", time)
-}
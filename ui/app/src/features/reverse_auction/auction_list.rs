@@ -10,9 +10,11 @@ use crate::shared::components::{
 };
 use crate::shared::forms::filter_bar;
 use crate::util::format::{format_currency, format_date};
+use crate::util::money::Money;
 use super::store::ReverseAuctionStore;
 use super::types::{AuctionStatus, AuctionFilter};
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Auction list page
 #[component]
@@ -146,49 +148,49 @@ pub fn auction_list() -> View {
     let columns = vec![
         DataTableColumn {
             key: "reference".to_string(),
-            label: "Reference".to_string(),
+            label: t("Reference").to_string(),
             width: Some("130px".to_string()),
             align: None,
             cell_class: Some("id-cell".to_string()),
         },
         DataTableColumn {
             key: "title".to_string(),
-            label: "Title".to_string(),
+            label: t("Title").to_string(),
             width: None,
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "starting_price".to_string(),
-            label: "Starting Price".to_string(),
+            label: t("Starting Price").to_string(),
             width: Some("140px".to_string()),
             align: Some("right".to_string()),
             cell_class: Some("amount-cell".to_string()),
         },
         DataTableColumn {
             key: "current_bid".to_string(),
-            label: "Current Bid".to_string(),
+            label: t("Current Bid").to_string(),
             width: Some("140px".to_string()),
             align: Some("right".to_string()),
             cell_class: Some("amount-cell".to_string()),
         },
         DataTableColumn {
             key: "bidders".to_string(),
-            label: "Bidders".to_string(),
+            label: t("Bidders").to_string(),
             width: Some("80px".to_string()),
             align: Some("center".to_string()),
             cell_class: None,
         },
         DataTableColumn {
             key: "end_time".to_string(),
-            label: "End Time".to_string(),
+            label: t("End Time").to_string(),
             width: Some("140px".to_string()),
             align: None,
             cell_class: None,
         },
         DataTableColumn {
             key: "status".to_string(),
-            label: "Status".to_string(),
+            label: t("Status").to_string(),
             width: Some("120px".to_string()),
             align: None,
             cell_class: None,
@@ -382,11 +384,11 @@ pub fn auction_list() -> View {
 
         <div class="auction-list" data-testid="auction-list">
             {page_header(
-                "Reverse Auctions".to_string(),
-                Some("Manage live reverse auctions for competitive bidding".to_string()),
+                t("Reverse Auctions").to_string(),
+                Some(t("Manage live reverse auctions for competitive bidding").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export"</button> },
-                    view! { <a href="#/reverse-auctions/new" class="btn btn-primary">"New Auction"</a> },
+                    view! { <button class="btn btn-secondary">{t("Export")}</button> },
+                    view! { <a href="#/reverse-auctions/new" class="btn btn-primary">{t("New Auction")}</a> },
                 ]
             )}
 
@@ -402,27 +404,27 @@ pub fn auction_list() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Status"</label>
+                        <label>{t("Status")}</label>
                         <select on:change={handle_filter_status}>
-                            <option value="">"All Statuses"</option>
-                            <option value="draft">"Draft"</option>
-                            <option value="scheduled">"Scheduled"</option>
-                            <option value="live">"Live"</option>
-                            <option value="ended">"Ended"</option>
-                            <option value="awarded">"Awarded"</option>
-                            <option value="cancelled">"Cancelled"</option>
+                            <option value="">{t("All Statuses")}</option>
+                            <option value="draft">{t("Draft")}</option>
+                            <option value="scheduled">{t("Scheduled")}</option>
+                            <option value="live">{t("Live")}</option>
+                            <option value="ended">{t("Ended")}</option>
+                            <option value="awarded">{t("Awarded")}</option>
+                            <option value="cancelled">{t("Cancelled")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"From"</label>
+                        <label>{t("From")}</label>
                         <input type="date" on:change={handle_date_from} />
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"To"</label>
+                        <label>{t("To")}</label>
                         <input type="date" on:change={handle_date_to} />
                     </div>
                 },
@@ -432,30 +434,30 @@ pub fn auction_list() -> View {
                         <input
                             type="text"
                             class="search-input"
-                            placeholder="Search auctions..."
+                            placeholder={t("Search auctions...")}
                             on:input={handle_search}
                         />
                     </div>
                 },
                 view! {
-                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>"Clear"</button>
+                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>{t("Clear")}</button>
                 },
                 view! {
-                    <button class="btn btn-sm btn-primary" on:click={handle_apply_filters}>"Apply"</button>
+                    <button class="btn btn-sm btn-primary" on:click={handle_apply_filters}>{t("Apply")}</button>
                 },
             ])}
 
             // Data table
             {panel(
-                format!("Auctions ({} total)", filtered_auctions.len()),
+                tf("Auctions ({count} total)", &[("count", filtered_auctions.len().to_string())]),
                 vec![],
                 vec![
                     if loading.get() {
-                        view! { <div class="loading-overlay">"Loading auctions..."</div> }
+                        view! { <div class="loading-overlay">{t("Loading auctions...")}</div> }
                     } else if rows.is_empty() {
                         view! {
                             <div class="loading-overlay">
-                                "No auctions found. Create a new auction to get started."
+                                {t("No auctions found. Create a new auction to get started.")}
                             </div>
                         }
                     } else {
@@ -473,13 +475,13 @@ pub fn auction_list() -> View {
 }
 
 /// KPI card component
-fn kpi_card(label: &str, value: String, is_live: bool) -> View {
+fn kpi_card(label: &'static str, value: String, is_live: bool) -> View {
     let card_class = if is_live { "kpi-card live" } else { "kpi-card" };
     let value_class = if is_live { "kpi-value live" } else { "kpi-value" };
 
     view! {
         <div class={card_class}>
-            <span class="kpi-label">{label}</span>
+            <span class="kpi-label">{t(label)}</span>
             <span class={value_class}>{value}</span>
         </div>
     }
@@ -503,7 +505,7 @@ fn scheduled_count(auctions: &[super::types::ReverseAuction]) -> String {
 
 /// Calculate total savings from completed auctions
 fn total_savings(auctions: &[super::types::ReverseAuction]) -> String {
-    let savings: f64 = auctions.iter()
+    let savings: Money = auctions.iter()
         .filter(|a| a.status == AuctionStatus::Awarded || a.status == AuctionStatus::Ended)
         .filter_map(|a| {
             a.current_bid.map(|bid| a.starting_price - bid)
@@ -531,19 +533,8 @@ fn format_datetime_short(iso_datetime: &str) -> String {
         let time_part = &iso_datetime[11..16];
         let parts: Vec<&str> = date_part.split('-').collect();
         if parts.len() == 3 {
-            let month = match parts[1] {
-                "01" => "Jan",
-                "02" => "Feb",
-                "03" => "Mar",
-                "04" => "Apr",
-                "05" => "May",
-                "06" => "Jun",
-                "07" => "Jul",
-                "08" => "Aug",
-                "09" => "Sep",
-                "10" => "Oct",
-                "11" => "Nov",
-                "12" => "Dec",
+            let month = match parts[1].parse::<usize>() {
+                Ok(m @ 1..=12) => crate::util::i18n::current().month_abbreviations()[m - 1],
                 _ => parts[1],
             };
             return format!("{} {} {}", parts[2], month, time_part);
@@ -2,42 +2,59 @@
 
 use components::prelude::*;
 use wasm_bindgen::JsCast;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
 use crate::shared::components::{
     panel,
     notice_bar, NoticeType,
     progress_bar, ProgressColor,
 };
 use crate::util::format::{format_currency, format_currency_full, format_number};
+use crate::util::money::Money;
 use super::store::ReverseAuctionStore;
 use super::types::{AuctionStatus, PlaceBidRequest, BidHistoryEntry};
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Live auction page component
 #[component]
-pub fn auction_live() -> View {
+pub fn auction_live(auction_id: String) -> View {
     let store = use_context::<ReverseAuctionStore>();
 
     // Local state for bid input
     let bid_amount = signal(String::new());
+    let missing = signal(false);
 
     // Load auction data on mount
     effect({
         let store = store.clone();
+        let auction_id = auction_id.clone();
+        let missing = missing.clone();
         move || {
             let store = store.clone();
+            let auction_id = auction_id.clone();
+            let missing = missing.clone();
             spawn(async move {
-                // In production, get auction ID from route params
-                // For demo, load first live auction
-                service::load_auctions(&store).await;
-                if let Some(auction) = store.auctions.get().iter().find(|a| a.status == AuctionStatus::Live) {
-                    service::get_auction(&store, &auction.id).await;
-                    service::connect_websocket(&store, &auction.id);
+                if store.auctions.get().is_empty() {
+                    service::load_auctions(&store).await;
+                }
+                service::get_auction(&store, &auction_id).await;
+                missing.set(store.selected.get().map_or(true, |a| a.id != auction_id));
+                let is_live = store.selected.get()
+                    .map(|a| a.id == auction_id && a.status == AuctionStatus::Live)
+                    .unwrap_or(false);
+                if is_live {
+                    service::connect_websocket(&store, &auction_id);
                     service::start_countdown_timer(&store);
                 }
             });
         }
     });
 
+    if missing.get() {
+        return entity_not_found(t("Auction"), &auction_id, store.error.get(), Route::ReverseAuctionList, t("Back to Auctions"));
+    }
+
     let selected = store.selected.clone();
     let bid_history = store.bid_history.clone();
     let countdown = store.countdown.clone();
@@ -73,10 +90,10 @@ pub fn auction_live() -> View {
             let bid_amount = bid_amount.clone();
 
             // Parse bid amount
-            let amount: f64 = match amount_str.replace(",", "").replace(" ", "").parse() {
+            let amount: Money = match amount_str.parse() {
                 Ok(a) => a,
                 Err(_) => {
-                    store.bid_error.set(Some("Please enter a valid amount".to_string()));
+                    store.bid_error.set(Some(t("Please enter a valid amount").to_string()));
                     return;
                 }
             };
@@ -97,7 +114,7 @@ pub fn auction_live() -> View {
     // Handle quick bid buttons
     let handle_quick_bid = {
         let store = store.clone();
-        move |decrement: f64| {
+        move |decrement: Money| {
             let store = store.clone();
             Callback::<()>::new(move |_| {
                 let store = store.clone();
@@ -141,18 +158,18 @@ pub fn auction_live() -> View {
     let selected_for_quick_bid = store.selected.clone();
     let quick_bid_data = selected_for_quick_bid.get().as_ref().map(|auction| {
         let min_dec = auction.min_decrement;
-        let dec_2x = min_dec * 2.0;
-        let dec_5x = min_dec * 5.0;
-        let dec_10x = min_dec * 10.0;
+        let dec_2x = min_dec * 2i64;
+        let dec_5x = min_dec * 5i64;
+        let dec_10x = min_dec * 10i64;
         (
             min_dec,
             dec_2x,
             dec_5x,
             dec_10x,
-            format!("-R {}", format_number(min_dec as i64)),
-            format!("-R {}", format_number(dec_2x as i64)),
-            format!("-R {}", format_number(dec_5x as i64)),
-            format!("-R {}", format_number(dec_10x as i64)),
+            format!("-R {}", format_number(min_dec.cents() / 100)),
+            format!("-R {}", format_number(dec_2x.cents() / 100)),
+            format!("-R {}", format_number(dec_5x.cents() / 100)),
+            format!("-R {}", format_number(dec_10x.cents() / 100)),
         )
     });
 
@@ -641,7 +658,7 @@ pub fn auction_live() -> View {
             // Back link
             <a class="back-link" on:click={handle_back}>
                 <span inner_html={icon_back}></span>
-                "Back to Auction List"
+                {t("Back to Auction List")}
             </a>
 
             if let Some(auction) = selected.get().as_ref() {
@@ -653,17 +670,17 @@ pub fn auction_live() -> View {
                             if auction.status == AuctionStatus::Live {
                                 <span class="live-badge">
                                     <span class="dot"></span>
-                                    "LIVE"
+                                    {t("LIVE")}
                                 </span>
                             }
                         </h1>
                         <div class="auction-meta">
                             <span class="auction-meta-item">
-                                <strong>"Ref:"</strong> {auction.reference_number.clone()}
+                                <strong>{t("Ref:")}</strong> {auction.reference_number.clone()}
                             </span>
                             <span class="auction-meta-item">
                                 <span inner_html={icon_users}></span>
-                                {format!("{} bidders", active_bidders.get())}
+                                {tn("{n} bidder", "{n} bidders", active_bidders.get() as i64)}
                             </span>
                             <span class="auction-meta-item">
                                 {connection_status(ws_connected.get(), icon_wifi, icon_wifi_off)}
@@ -671,7 +688,7 @@ pub fn auction_live() -> View {
                         </div>
                     </div>
                     <div class="countdown-display">
-                        <div class="countdown-label">"Time Remaining"</div>
+                        <div class="countdown-label">{t("Time Remaining")}</div>
                         <div class={countdown_class(&countdown.get())}>
                             {countdown.get().display()}
                         </div>
@@ -681,9 +698,12 @@ pub fn auction_live() -> View {
                 // Auto-extend notice
                 if auction.auto_extend {
                     {notice_bar(
-                        format!("Auto-extend: Auction extends by {} minutes if bid placed in last {} seconds",
-                            auction.extension_minutes,
-                            auction.extension_threshold_seconds
+                        tf(
+                            "Auto-extend: Auction extends by {minutes} minutes if bid placed in last {seconds} seconds",
+                            &[
+                                ("minutes", auction.extension_minutes.to_string()),
+                                ("seconds", auction.extension_threshold_seconds.to_string()),
+                            ],
                         ),
                         NoticeType::Info,
                         None
@@ -696,13 +716,13 @@ pub fn auction_live() -> View {
                         // Bidding panel
                         <div class={if is_leading.get() { "bidding-panel is-leading" } else { "bidding-panel" }}>
                             <div class="current-bid-section">
-                                <div class="current-bid-label">"Current Lowest Bid"</div>
+                                <div class="current-bid-label">{t("Current Lowest Bid")}</div>
                                 <div class="current-bid-amount">
                                     {format_currency_full(auction.current_bid.unwrap_or(auction.starting_price))}
                                 </div>
                                 <div class="current-bid-info">
-                                    <span>"Starting: " {format_currency(auction.starting_price)}</span>
-                                    <span>"Min Decrement: " {format_currency(auction.min_decrement)}</span>
+                                    <span>{tf("Starting: {amount}", &[("amount", format_currency(auction.starting_price))])}</span>
+                                    <span>{tf("Min Decrement: {amount}", &[("amount", format_currency(auction.min_decrement))])}</span>
                                     {savings_badge(auction.starting_price, auction.current_bid)}
                                 </div>
                             </div>
@@ -716,7 +736,7 @@ pub fn auction_live() -> View {
                                             <input
                                                 type="text"
                                                 class={if bid_error.get().is_some() { "bid-input error" } else { "bid-input" }}
-                                                placeholder="Enter your bid"
+                                                placeholder={t("Enter your bid")}
                                                 value={bid_amount.get()}
                                                 on:input={handle_bid_input}
                                                 disabled={bid_submitting.get()}
@@ -727,11 +747,7 @@ pub fn auction_live() -> View {
                                             on:click={handle_place_bid}
                                             disabled={bid_submitting.get() || bid_amount.get().is_empty()}
                                         >
-                                            if bid_submitting.get() {
-                                                "Placing Bid..."
-                                            } else {
-                                                "Place Bid"
-                                            }
+                                            {if bid_submitting.get() { t("Placing Bid...") } else { t("Place Bid") }}
                                         </button>
                                     </div>
                                     if let Some(error) = bid_error.get().as_ref() {
@@ -741,7 +757,7 @@ pub fn auction_live() -> View {
 
                                 // Quick bid buttons
                                 <div class="quick-bid-section">
-                                    <div class="quick-bid-label">"Quick Bid"</div>
+                                    <div class="quick-bid-label">{t("Quick Bid")}</div>
                                     <div class="quick-bid-buttons">
                                         if let Some((min_dec, dec_2x, dec_5x, dec_10x, label_1x, label_2x, label_5x, label_10x)) = quick_bid_data.clone() {
                                             <button
@@ -781,7 +797,7 @@ pub fn auction_live() -> View {
                             } else {
                                 <div class="user-status">
                                     <span inner_html={icon_clock}></span>
-                                    "Auction is not currently live"
+                                    {t("Auction is not currently live")}
                                 </div>
                             }
                         </div>
@@ -791,30 +807,30 @@ pub fn auction_live() -> View {
 
                         // Item details
                         {panel(
-                            "Item Details".to_string(),
+                            t("Item Details").to_string(),
                             vec![],
                             vec![view! {
                                 <div class="item-details">
                                     <div class="item-detail-row">
-                                        <span class="item-detail-label">"Item"</span>
+                                        <span class="item-detail-label">{t("Item")}</span>
                                         <span class="item-detail-value">{auction.item.name.clone()}</span>
                                     </div>
                                     <div class="item-detail-row">
-                                        <span class="item-detail-label">"Quantity"</span>
+                                        <span class="item-detail-label">{t("Quantity")}</span>
                                         <span class="item-detail-value">
                                             {format!("{} {}", auction.item.quantity, auction.item.unit)}
                                         </span>
                                     </div>
                                     <div class="item-detail-row">
-                                        <span class="item-detail-label">"Category"</span>
+                                        <span class="item-detail-label">{t("Category")}</span>
                                         <span class="item-detail-value">{auction.item.category.clone()}</span>
                                     </div>
                                     <div class="item-detail-row">
-                                        <span class="item-detail-label">"Specifications"</span>
+                                        <span class="item-detail-label">{t("Specifications")}</span>
                                         <span class="item-detail-value">{auction.item.specifications.clone()}</span>
                                     </div>
                                     <div class="item-detail-row">
-                                        <span class="item-detail-label">"Department"</span>
+                                        <span class="item-detail-label">{t("Department")}</span>
                                         <span class="item-detail-value">{auction.department.clone()}</span>
                                     </div>
                                 </div>
@@ -826,13 +842,13 @@ pub fn auction_live() -> View {
                     <div class="sidebar">
                         // Bid history
                         {panel(
-                            format!("Bid History ({})", bid_history.get().len()),
+                            tf("Bid History ({count})", &[("count", bid_history.get().len().to_string())]),
                             vec![],
                             vec![view! {
                                 <div class="bid-history-list">
                                     if bid_history.get().is_empty() {
                                         <div style="text-align: center; padding: 24px; color: var(--text-muted);">
-                                            "No bids yet"
+                                            {t("No bids yet")}
                                         </div>
                                     } else {
                                         for entry in bid_history.get().iter() {
@@ -845,7 +861,7 @@ pub fn auction_live() -> View {
 
                         // Active bidders
                         {panel(
-                            format!("Active Bidders ({})", auction.bidders.iter().filter(|b| b.is_active).count()),
+                            tf("Active Bidders ({count})", &[("count", auction.bidders.iter().filter(|b| b.is_active).count().to_string())]),
                             vec![],
                             vec![view! {
                                 <div class="bidders-list">
@@ -857,7 +873,7 @@ pub fn auction_live() -> View {
                                             <div class="bidder-info">
                                                 <div class="bidder-name">
                                                     if auction.anonymous_bidding {
-                                                        {format!("Bidder {}", bidder.id.chars().last().unwrap_or('?'))}
+                                                        {tf("Bidder {id}", &[("id", bidder.id.chars().last().unwrap_or('?').to_string())])}
                                                     } else {
                                                         {bidder.supplier_name.clone()}
                                                     }
@@ -865,9 +881,9 @@ pub fn auction_live() -> View {
                                                 <div class="bidder-last-bid">
                                                     {
                                                         if let Some(last_bid) = bidder.last_bid_at.clone() {
-                                                            format!("Last bid: {}", format_time_short(last_bid.as_str()))
+                                                            tf("Last bid: {date}", &[("date", format_time_short(last_bid.as_str()))])
                                                         } else {
-                                                            "No bids yet".to_string()
+                                                            t("No bids yet").to_string()
                                                         }
                                                     }
                                                 </div>
@@ -884,9 +900,9 @@ pub fn auction_live() -> View {
                 // No auction selected
                 <div class="no-auction">
                     <span inner_html={icon_clock}></span>
-                    <h2>"No Live Auction"</h2>
-                    <p>"There is no live auction currently available."</p>
-                    <a href="#/reverse-auctions" class="btn btn-primary">"View All Auctions"</a>
+                    <h2>{t("No Live Auction")}</h2>
+                    <p>{t("There is no live auction currently available.")}</p>
+                    <a href="#/reverse-auctions" class="btn btn-primary">{t("View All Auctions")}</a>
                 </div>
             }
         </div>
@@ -899,14 +915,14 @@ fn connection_status(connected: bool, icon_on: &str, icon_off: &str) -> View {
         view! {
             <div class="connection-status connected">
                 <span inner_html={icon_on}></span>
-                "Connected"
+                {t("Connected")}
             </div>
         }
     } else {
         view! {
             <div class="connection-status disconnected">
                 <span inner_html={icon_off}></span>
-                "Disconnected"
+                {t("Disconnected")}
             </div>
         }
     }
@@ -924,14 +940,14 @@ fn countdown_class(state: &super::types::CountdownState) -> &'static str {
 }
 
 /// Savings badge showing percentage saved
-fn savings_badge(starting: f64, current: Option<f64>) -> View {
+fn savings_badge(starting: Money, current: Option<Money>) -> View {
     if let Some(current_bid) = current {
         let savings = starting - current_bid;
-        let percentage = (savings / starting) * 100.0;
-        if savings > 0.0 {
+        let percentage = savings.percent_of(starting);
+        if savings.is_positive() {
             return view! {
                 <span class="savings-badge">
-                    {format!("{:.1}% saved", percentage)}
+                    {tf("{percent} saved", &[("percent", format!("{:.1}%", percentage))])}
                 </span>
             };
         }
@@ -943,7 +959,7 @@ fn savings_badge(starting: f64, current: Option<f64>) -> View {
 fn user_status_display(
     is_leading: bool,
     _rank: Option<u32>,
-    current_bid: Option<f64>,
+    current_bid: Option<Money>,
     icon_check: &str,
     icon_alert: &str,
 ) -> View {
@@ -951,20 +967,20 @@ fn user_status_display(
         view! {
             <div class="user-status leading">
                 <span inner_html={icon_check}></span>
-                "You are the leading bidder!"
+                {t("You are the leading bidder!")}
             </div>
         }
     } else if let Some(bid) = current_bid {
         view! {
             <div class="user-status outbid">
                 <span inner_html={icon_alert}></span>
-                {format!("You have been outbid. Your last bid: R {:.2}", bid)}
+                {tf("You have been outbid. Your last bid: R {bid}", &[("bid", bid.to_string())])}
             </div>
         }
     } else {
         view! {
             <div class="user-status">
-                "Place your first bid to participate"
+                {t("Place your first bid to participate")}
             </div>
         }
     }
@@ -987,7 +1003,7 @@ fn bid_history_item(entry: BidHistoryEntry, anonymous: bool) -> View {
             <div class="bid-history-amount">
                 <div class="bid-history-price">{format_currency(entry.amount)}</div>
                 if let Some(delta) = entry.delta {
-                    <div class="bid-history-delta">{format!("-R {}", format_number(delta as i64))}</div>
+                    <div class="bid-history-delta">{format!("-R {}", format_number(delta.cents() / 100))}</div>
                 }
             </div>
         </div>
@@ -995,31 +1011,27 @@ fn bid_history_item(entry: BidHistoryEntry, anonymous: bool) -> View {
 }
 
 /// Price progress panel
-fn price_progress_panel(starting: f64, reserve: Option<f64>, current: Option<f64>) -> View {
+fn price_progress_panel(starting: Money, reserve: Option<Money>, current: Option<Money>) -> View {
     let current_bid = current.unwrap_or(starting);
-    let progress = if starting > 0.0 {
-        ((starting - current_bid) / starting * 100.0).min(100.0).max(0.0)
-    } else {
-        0.0
-    };
+    let progress = (starting - current_bid).percent_of(starting).min(100.0).max(0.0);
 
     let reserve_progress = reserve.map(|r| {
-        ((starting - r) / starting * 100.0).min(100.0).max(0.0)
+        (starting - r).percent_of(starting).min(100.0).max(0.0)
     });
 
     view! {
         <div class="price-progress">
             <div class="price-labels">
-                <span>{format!("Starting: {}", format_currency(starting))}</span>
+                <span>{tf("Starting: {amount}", &[("amount", format_currency(starting))])}</span>
                 if let Some(r) = reserve {
-                    <span>{format!("Reserve: {}", format_currency(r))}</span>
+                    <span>{tf("Reserve: {amount}", &[("amount", format_currency(r))])}</span>
                 }
-                <span>{format!("Current: {}", format_currency(current_bid))}</span>
+                <span>{tf("Current: {amount}", &[("amount", format_currency(current_bid))])}</span>
             </div>
             {progress_bar(progress, ProgressColor::Green, false, None)}
             if let Some(rp) = reserve_progress {
                 <div style="margin-top: 4px; font-size: 11px; color: var(--text-muted);">
-                    {format!("Reserve threshold at {:.0}%", rp)}
+                    {tf("Reserve threshold at {percent}%", &[("percent", format!("{:.0}", rp))])}
                 </div>
             }
         </div>
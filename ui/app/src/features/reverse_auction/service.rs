@@ -1,5 +1,12 @@
 //! Reverse Auction service - API calls and WebSocket handling
 
+use std::cell::Cell;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::util::api::{self, ApiError};
+use crate::util::backend;
+use crate::util::datetime::{now, Timestamp};
+use crate::util::reference;
 use super::store::{ReverseAuctionStore, load_mock_data, load_mock_bid_history, select_auction, clear_selection};
 use super::types::{
     ReverseAuction, AuctionFilter, AuctionStatus, AuctionBid, WsMessage,
@@ -11,10 +18,7 @@ pub async fn load_auctions(store: &ReverseAuctionStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API:
-    // let response = api::get("/api/reverse-auctions", &store.filter.get()).await;
-    // For now, load mock data
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -30,15 +34,19 @@ pub async fn get_auction(store: &ReverseAuctionStore, auction_id: &str) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::get(&format!("/api/reverse-auctions/{}", auction_id)).await;
-    // For now, find in mock data
     select_auction(store, auction_id);
 
+    if store.selected.get().is_none() && !backend::is_mock() {
+        match api::get::<ReverseAuction>(&format!("/reverse-auctions/{}", auction_id)).await {
+            Ok(auction) => store.selected.set(Some(auction)),
+            Err(e) => store.error.set(Some(e.to_string())),
+        }
+    }
+
     // Load bid history for live auctions
     if let Some(auction) = store.selected.get() {
         if auction.status == AuctionStatus::Live {
-            load_mock_bid_history(store);
+            load_bids_from_backend(store, auction_id).await;
         }
     }
 
@@ -266,7 +274,7 @@ pub async fn place_bid(store: &ReverseAuctionStore, request: PlaceBidRequest) ->
 
     // Simulate successful bid for demo
     let bid = AuctionBid {
-        id: format!("BID-{:06}", rand_id()),
+        id: reference::unique_id("BID"),
         auction_id: request.auction_id.clone(),
         bidder_id: "current-user".to_string(),
         bidder_name: "Current User".to_string(),
@@ -348,52 +356,57 @@ pub fn send_heartbeat(_store: &ReverseAuctionStore) {
 pub async fn load_bid_history(store: &ReverseAuctionStore, auction_id: &str) {
     store.loading.set(true);
 
-    // In production:
-    // let response = api::get(&format!("/api/reverse-auctions/{}/bids", auction_id)).await;
-
-    // Load mock data
-    load_mock_bid_history(store);
+    load_bids_from_backend(store, auction_id).await;
 
     store.loading.set(false);
 }
 
-/// Helper function to generate random ID
-fn rand_id() -> u32 {
-    // In production, use proper random generation
-    // For demo, return a fixed value
-    123456
-}
-
 /// Calculate time remaining until auction end
 pub fn calculate_time_remaining(end_time: &str) -> i64 {
-    // In production, parse ISO timestamp and calculate difference
-    // For demo, return mock value (4 hours 15 minutes 30 seconds)
-    15330
+    Timestamp::parse(end_time)
+        .map(|end| now().seconds_until(end).max(0))
+        .unwrap_or(0)
 }
 
-/// Start countdown timer
-pub fn start_countdown_timer(store: &ReverseAuctionStore) {
-    // In production, this would use setInterval to update countdown every second
-    //
-    // let interval = setInterval(move |_| {
-    //     if let Some(auction) = store.selected.get() {
-    //         let end_time = auction.extended_end_time.as_ref().unwrap_or(&auction.end_time);
-    //         let remaining = calculate_time_remaining(end_time);
-    //         store.update_countdown(remaining);
-    //
-    //         if remaining <= 0 {
-    //             clearInterval(interval);
-    //         }
-    //     }
-    // }, 1000);
+thread_local! {
+    /// Interval id of the running countdown
+    static COUNTDOWN: Cell<Option<i32>> = Cell::new(None);
+}
 
-    // For demo, set initial countdown value
-    store.update_countdown(15330); // 4h 15m 30s
+/// Start countdown timer, ticking every second until the auction ends
+pub fn start_countdown_timer(store: &ReverseAuctionStore) {
+    stop_countdown_timer();
+
+    let tick = {
+        let store = store.clone();
+        move || {
+            let Some(auction) = store.selected.get() else { return };
+            let end_time = auction.extended_end_time.as_ref().unwrap_or(&auction.end_time);
+            let remaining = calculate_time_remaining(end_time);
+            store.update_countdown(remaining);
+            if remaining <= 0 {
+                stop_countdown_timer();
+            }
+        }
+    };
+    tick();
+
+    let Some(window) = web_sys::window() else { return };
+    let closure = Closure::<dyn Fn()>::new(tick);
+    if let Ok(id) = window.set_interval_with_callback_and_timeout_and_arguments_0(
+        closure.as_ref().unchecked_ref(),
+        1000,
+    ) {
+        COUNTDOWN.with(|c| c.set(Some(id)));
+    }
+    closure.forget(); // Leak closure to keep it alive
 }
 
 /// Stop countdown timer
 pub fn stop_countdown_timer() {
-    // In production, clear the interval
+    if let (Some(id), Some(window)) = (COUNTDOWN.with(|c| c.take()), web_sys::window()) {
+        window.clear_interval_with_handle(id);
+    }
 }
 
 /// Format remaining time for display
@@ -412,3 +425,39 @@ pub fn format_time_remaining(seconds: i64) -> String {
         format!("{:02}:{:02}", minutes, secs)
     }
 }
+
+/// Refresh the auction list from the configured backend
+async fn load_from_backend(store: &ReverseAuctionStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/reverse-auctions", &store.auctions).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    } else {
+        let mut pagination = store.pagination.get();
+        pagination.total_items = store.auctions.get().len() as u32;
+        pagination.total_pages = (pagination.total_items + pagination.page_size - 1) / pagination.page_size;
+        store.pagination.set(pagination);
+    }
+}
+
+/// Refresh the bid history of an auction from the configured backend
+async fn load_bids_from_backend(store: &ReverseAuctionStore, auction_id: &str) {
+    if backend::is_mock() {
+        load_mock_bid_history(store);
+        return;
+    }
+
+    let endpoint = format!("/reverse-auctions/{}/bids", auction_id);
+    if let Err(e) = backend::fetch_into(&endpoint, &store.bid_history).await {
+        store.error.set(Some(e.to_string()));
+    }
+}
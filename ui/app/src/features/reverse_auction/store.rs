@@ -1,6 +1,7 @@
 //! Reverse Auction store
 
 use components::prelude::*;
+use crate::util::money::Money;
 use super::types::{
     ReverseAuction, AuctionStatus, AuctionFilter, PaginationState,
     AuctionItem, Bidder, AuctionBid, BidHistoryEntry, CountdownState,
@@ -21,7 +22,7 @@ pub struct ReverseAuctionStore {
     pub active_bidders: Signal<u32>,
 
     // Bidding state
-    pub current_user_bid: Signal<Option<f64>>,
+    pub current_user_bid: Signal<Option<Money>>,
     pub bid_input: Signal<String>,
     pub is_leading: Signal<bool>,
     pub user_rank: Signal<Option<u32>>,
@@ -72,7 +73,7 @@ impl ReverseAuctionStore {
     }
 
     /// Validate bid amount
-    pub fn validate_bid(&self, amount: f64) -> Result<(), String> {
+    pub fn validate_bid(&self, amount: Money) -> Result<(), String> {
         let auction = self.selected.get();
         if auction.is_none() {
             return Err("No auction selected".to_string());
@@ -88,14 +89,14 @@ impl ReverseAuctionStore {
 
         if amount >= current_bid {
             return Err(format!(
-                "Bid must be lower than current bid of R {:.2}",
+                "Bid must be lower than current bid of R {}",
                 current_bid
             ));
         }
 
         if amount > max_allowed {
             return Err(format!(
-                "Bid must be at least R {:.2} lower than current bid",
+                "Bid must be at least R {} lower than current bid",
                 auction.min_decrement
             ));
         }
@@ -203,10 +204,10 @@ pub fn load_mock_data(store: &ReverseAuctionStore) {
             end_time: "2025-02-27T15:00:00Z".to_string(),
             extended_end_time: None,
             created_at: "2025-02-20".to_string(),
-            starting_price: 2_500_000.0,
-            reserve_price: Some(1_800_000.0),
-            current_bid: Some(2_150_000.0),
-            min_decrement: 5_000.0,
+            starting_price: Money::rands(2_500_000),
+            reserve_price: Some(Money::rands(1_800_000)),
+            current_bid: Some(Money::rands(2_150_000)),
+            min_decrement: Money::rands(5000),
             currency: "ZAR".to_string(),
             bidders: vec![
                 Bidder {
@@ -273,10 +274,10 @@ pub fn load_mock_data(store: &ReverseAuctionStore) {
             end_time: "2025-03-01T14:00:00Z".to_string(),
             extended_end_time: None,
             created_at: "2025-02-15".to_string(),
-            starting_price: 750_000.0,
-            reserve_price: Some(550_000.0),
+            starting_price: Money::rands(750_000),
+            reserve_price: Some(Money::rands(550_000)),
             current_bid: None,
-            min_decrement: 2_500.0,
+            min_decrement: Money::rands(2500),
             currency: "ZAR".to_string(),
             bidders: vec![
                 Bidder {
@@ -333,10 +334,10 @@ pub fn load_mock_data(store: &ReverseAuctionStore) {
             end_time: "2025-02-20T13:00:00Z".to_string(),
             extended_end_time: Some("2025-02-20T13:15:00Z".to_string()),
             created_at: "2025-02-10".to_string(),
-            starting_price: 1_200_000.0,
-            reserve_price: Some(900_000.0),
-            current_bid: Some(950_000.0),
-            min_decrement: 5_000.0,
+            starting_price: Money::rands(1_200_000),
+            reserve_price: Some(Money::rands(900_000)),
+            current_bid: Some(Money::rands(950_000)),
+            min_decrement: Money::rands(5000),
             currency: "ZAR".to_string(),
             bidders: vec![
                 Bidder {
@@ -383,10 +384,10 @@ pub fn load_mock_data(store: &ReverseAuctionStore) {
             end_time: "2025-02-15T12:00:00Z".to_string(),
             extended_end_time: None,
             created_at: "2025-02-05".to_string(),
-            starting_price: 1_800_000.0,
-            reserve_price: Some(1_400_000.0),
-            current_bid: Some(1_425_000.0),
-            min_decrement: 5_000.0,
+            starting_price: Money::rands(1_800_000),
+            reserve_price: Some(Money::rands(1_400_000)),
+            current_bid: Some(Money::rands(1_425_000)),
+            min_decrement: Money::rands(5000),
             currency: "ZAR".to_string(),
             bidders: vec![],
             total_bids: 15,
@@ -422,10 +423,10 @@ pub fn load_mock_data(store: &ReverseAuctionStore) {
             end_time: String::new(),
             extended_end_time: None,
             created_at: "2025-02-25".to_string(),
-            starting_price: 850_000.0,
+            starting_price: Money::rands(850_000),
             reserve_price: None,
             current_bid: None,
-            min_decrement: 2_500.0,
+            min_decrement: Money::rands(2500),
             currency: "ZAR".to_string(),
             bidders: vec![],
             total_bids: 0,
@@ -460,72 +461,72 @@ pub fn load_mock_bid_history(store: &ReverseAuctionStore) {
             id: "BH-012".to_string(),
             bidder_name: "TechSolutions SA (Pty) Ltd".to_string(),
             bidder_masked: "Bidder A".to_string(),
-            amount: 2_150_000.0,
+            amount: Money::rands(2_150_000),
             timestamp: "2025-02-27T10:45:23Z".to_string(),
-            delta: Some(25_000.0),
+            delta: Some(Money::rands(25_000)),
             is_leading: true,
         },
         BidHistoryEntry {
             id: "BH-011".to_string(),
             bidder_name: "DataCore Systems".to_string(),
             bidder_masked: "Bidder B".to_string(),
-            amount: 2_175_000.0,
+            amount: Money::rands(2_175_000),
             timestamp: "2025-02-27T10:42:15Z".to_string(),
-            delta: Some(15_000.0),
+            delta: Some(Money::rands(15_000)),
             is_leading: false,
         },
         BidHistoryEntry {
             id: "BH-010".to_string(),
             bidder_name: "TechSolutions SA (Pty) Ltd".to_string(),
             bidder_masked: "Bidder A".to_string(),
-            amount: 2_190_000.0,
+            amount: Money::rands(2_190_000),
             timestamp: "2025-02-27T10:38:45Z".to_string(),
-            delta: Some(30_000.0),
+            delta: Some(Money::rands(30_000)),
             is_leading: false,
         },
         BidHistoryEntry {
             id: "BH-009".to_string(),
             bidder_name: "DataCore Systems".to_string(),
             bidder_masked: "Bidder B".to_string(),
-            amount: 2_220_000.0,
+            amount: Money::rands(2_220_000),
             timestamp: "2025-02-27T10:35:12Z".to_string(),
-            delta: Some(20_000.0),
+            delta: Some(Money::rands(20_000)),
             is_leading: false,
         },
         BidHistoryEntry {
             id: "BH-008".to_string(),
             bidder_name: "Enterprise IT Solutions".to_string(),
             bidder_masked: "Bidder C".to_string(),
-            amount: 2_240_000.0,
+            amount: Money::rands(2_240_000),
             timestamp: "2025-02-27T10:30:00Z".to_string(),
-            delta: Some(10_000.0),
+            delta: Some(Money::rands(10_000)),
             is_leading: false,
         },
         BidHistoryEntry {
             id: "BH-007".to_string(),
             bidder_name: "TechSolutions SA (Pty) Ltd".to_string(),
             bidder_masked: "Bidder A".to_string(),
-            amount: 2_250_000.0,
+            amount: Money::rands(2_250_000),
             timestamp: "2025-02-27T10:25:30Z".to_string(),
-            delta: Some(50_000.0),
+            delta: Some(Money::rands(50_000)),
             is_leading: false,
         },
         BidHistoryEntry {
             id: "BH-006".to_string(),
             bidder_name: "DataCore Systems".to_string(),
             bidder_masked: "Bidder B".to_string(),
-            amount: 2_300_000.0,
+            amount: Money::rands(2_300_000),
             timestamp: "2025-02-27T10:20:15Z".to_string(),
-            delta: Some(25_000.0),
+            delta: Some(Money::rands(25_000)),
             is_leading: false,
         },
         BidHistoryEntry {
             id: "BH-005".to_string(),
             bidder_name: "Enterprise IT Solutions".to_string(),
             bidder_masked: "Bidder C".to_string(),
-            amount: 2_325_000.0,
+            amount: Money::rands(2_325_000),
             timestamp: "2025-02-27T10:15:00Z".to_string(),
-            delta: Some(50_000.0),
+            delta: Some(Money::rands(50_000)),
             is_leading: false,
         },
     ];
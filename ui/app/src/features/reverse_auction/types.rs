@@ -1,6 +1,8 @@
 //! Reverse Auction domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::Money;
 
 /// Auction status enumeration
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -21,14 +23,14 @@ pub enum AuctionStatus {
 
 impl AuctionStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             AuctionStatus::Draft => "Draft",
             AuctionStatus::Scheduled => "Scheduled",
             AuctionStatus::Live => "Live",
             AuctionStatus::Ended => "Ended",
             AuctionStatus::Awarded => "Awarded",
             AuctionStatus::Cancelled => "Cancelled",
-        }
+        })
     }
 
     pub fn is_active(&self) -> bool {
@@ -92,7 +94,7 @@ pub struct AuctionBid {
     pub auction_id: String,
     pub bidder_id: String,
     pub bidder_name: String,
-    pub amount: f64,
+    pub amount: Money,
     pub timestamp: String,
     pub is_leading: bool,
     pub rank: u32,
@@ -104,9 +106,9 @@ pub struct BidHistoryEntry {
     pub id: String,
     pub bidder_name: String,
     pub bidder_masked: String, // e.g., "Bidder A" for anonymous display
-    pub amount: f64,
+    pub amount: Money,
     pub timestamp: String,
-    pub delta: Option<f64>, // difference from previous bid
+    pub delta: Option<Money>, // difference from previous bid
     pub is_leading: bool,
 }
 
@@ -129,10 +131,10 @@ pub struct ReverseAuction {
     pub created_at: String,
 
     // Pricing
-    pub starting_price: f64,
-    pub reserve_price: Option<f64>,
-    pub current_bid: Option<f64>,
-    pub min_decrement: f64,
+    pub starting_price: Money,
+    pub reserve_price: Option<Money>,
+    pub current_bid: Option<Money>,
+    pub min_decrement: Money,
     pub currency: String,
 
     // Participants
@@ -171,10 +173,10 @@ impl Default for ReverseAuction {
             end_time: String::new(),
             extended_end_time: None,
             created_at: String::new(),
-            starting_price: 0.0,
+            starting_price: Money::ZERO,
             reserve_price: None,
             current_bid: None,
-            min_decrement: 100.0,
+            min_decrement: Money::rands(100),
             currency: "ZAR".to_string(),
             bidders: Vec::new(),
             total_bids: 0,
@@ -319,7 +321,7 @@ impl CountdownState {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PlaceBidRequest {
     pub auction_id: String,
-    pub amount: f64,
+    pub amount: Money,
 }
 
 /// Bid placement response
@@ -0,0 +1,192 @@
+//! Global search box shown in the topbar
+
+use components::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::Route;
+use crate::util::i18n::t;
+use super::store::SearchStore;
+use super::types::{SearchGroup, SearchHit, MIN_QUERY_LEN};
+use super::service;
+
+/// Search box with grouped, keyboard-navigable results
+#[component]
+pub fn global_search(on_navigate: Callback<Route>) -> View {
+    let store = use_context::<SearchStore>();
+
+    let handle_input = Callback::new({
+        let store = store.clone();
+        move |e: web_sys::Event| {
+            let input: web_sys::HtmlInputElement = e.target().unwrap().dyn_into().unwrap();
+            let query = input.value();
+            store.query.set(query.clone());
+            store.open.set(true);
+            let store = store.clone();
+            spawn(async move {
+                service::search(&store, &query).await;
+            });
+        }
+    });
+
+    let open_hit = {
+        let store = store.clone();
+        let on_navigate = on_navigate.clone();
+        move |hit: SearchHit| {
+            store.reset();
+            on_navigate.call(hit.route);
+        }
+    };
+
+    let handle_keydown = Callback::new({
+        let store = store.clone();
+        let open_hit = open_hit.clone();
+        move |e: web_sys::Event| {
+            let Some(key) = e.dyn_ref::<web_sys::KeyboardEvent>().map(|k| k.key()) else {
+                return;
+            };
+            match key.as_str() {
+                "ArrowDown" => {
+                    e.prevent_default();
+                    store.open.set(true);
+                    store.move_active(1);
+                }
+                "ArrowUp" => {
+                    e.prevent_default();
+                    store.move_active(-1);
+                }
+                "Enter" => {
+                    if let Some(hit) = store.active_hit() {
+                        e.prevent_default();
+                        open_hit(hit);
+                    }
+                }
+                "Escape" => store.open.set(false),
+                _ => {}
+            }
+        }
+    });
+
+    let handle_focus = Callback::<()>::new({
+        let store = store.clone();
+        move |_| store.open.set(true)
+    });
+
+    // Results are picked on mousedown, which fires before the input loses focus
+    let handle_blur = Callback::<()>::new({
+        let store = store.clone();
+        move |_| store.open.set(false)
+    });
+
+    let query = store.query.get();
+    let show_results = store.open.get() && query.trim().chars().count() >= MIN_QUERY_LEN;
+    let loading = store.loading.get();
+    let error = store.error.get();
+    let groups = store.groups.get();
+    let active = store.active.get();
+
+    // Offset of each group's first hit in the flattened list the arrow keys walk
+    let mut offset = 0;
+    let groups: Vec<(SearchGroup, usize)> = groups.into_iter().map(|group| {
+        let start = offset;
+        offset += group.hits.len();
+        (group, start)
+    }).collect();
+
+    view! {
+        style {
+            r#"
+            .global-search { position: relative; }
+            .search-results {
+                position: absolute;
+                top: calc(100% + 6px);
+                left: 0;
+                width: 420px;
+                max-height: 480px;
+                overflow-y: auto;
+                background: var(--surface);
+                border: 1px solid var(--border);
+                border-radius: var(--radius);
+                box-shadow: 0 8px 24px rgba(0, 0, 0, 0.12);
+                z-index: 60;
+                padding: 6px 0;
+            }
+            .search-group-title {
+                font-size: 11px;
+                font-weight: 600;
+                text-transform: uppercase;
+                letter-spacing: 0.04em;
+                color: var(--text-muted);
+                padding: 8px 14px 4px;
+            }
+            .search-hit {
+                padding: 8px 14px;
+                cursor: pointer;
+            }
+            .search-hit:hover, .search-hit.active { background: var(--bg); }
+            .search-hit-title { font-size: 13px; font-weight: 500; }
+            .search-hit-sub { font-size: 11px; color: var(--text-muted); margin-top: 2px; }
+            .search-status { padding: 12px 14px; font-size: 13px; color: var(--text-muted); }
+            .search-error { padding: 8px 14px; font-size: 12px; color: var(--red); }
+            "#
+        }
+
+        <div class="global-search" data-testid="global-search">
+            <div class="search-box">
+                <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                    <circle cx="11" cy="11" r="8"/>
+                    <line x1="21" y1="21" x2="16.65" y2="16.65"/>
+                </svg>
+                <input
+                    type="text"
+                    placeholder={t("Search requisitions, tenders, suppliers...")}
+                    value={query}
+                    on:input={handle_input}
+                    on:keydown={handle_keydown}
+                    on:focus={handle_focus}
+                    on:blur={handle_blur}
+                    data-testid="global-search-input"
+                />
+            </div>
+            if show_results {
+                <div class="search-results" data-testid="global-search-results">
+                    if let Some(err) = error {
+                        <div class="search-error">{err}</div>
+                    }
+                    if loading && groups.is_empty() {
+                        <div class="search-status">{t("Searching...")}</div>
+                    } else if groups.is_empty() {
+                        <div class="search-status">{t("No matches")}</div>
+                    }
+                    for (group, start) in groups.into_iter() {
+                        {search_group(group, start, active, open_hit.clone())}
+                    }
+                </div>
+            }
+        </div>
+    }
+}
+
+fn search_group(group: SearchGroup, start: usize, active: Option<usize>, open_hit: impl Fn(SearchHit) + Clone + 'static) -> View {
+    view! {
+        <div class="search-group" data-testid="search-group">
+            <div class="search-group-title">{group.entity.label()}</div>
+            for (i, hit) in group.hits.into_iter().enumerate() {
+                {search_hit(hit, active == Some(start + i), open_hit.clone())}
+            }
+        </div>
+    }
+}
+
+fn search_hit(hit: SearchHit, is_active: bool, open_hit: impl Fn(SearchHit) + 'static) -> View {
+    let class = if is_active { "search-hit active" } else { "search-hit" };
+    let title = hit.title.clone();
+    let subtitle = hit.subtitle.clone();
+
+    let handle_select = Callback::<()>::new(move |_| open_hit(hit.clone()));
+
+    view! {
+        <div class={class} data-testid="search-hit" on:mousedown={handle_select}>
+            <div class="search-hit-title">{title}</div>
+            <div class="search-hit-sub">{subtitle}</div>
+        </div>
+    }
+}
@@ -0,0 +1,7 @@
+//! Global search feature module
+
+pub mod types;
+pub mod provider;
+pub mod store;
+pub mod service;
+pub mod global_search;
@@ -0,0 +1,66 @@
+//! Search provider registry
+//!
+//! Each feature registers one provider for its entity type. The local
+//! providers search the feature's store; a server-backed provider can later be
+//! registered for the same entity and will replace the local one without any
+//! change to the search box.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use components::prelude::*;
+use super::types::{SearchEntity, SearchHit};
+
+/// Future returned by a provider; search runs on the single browser thread
+pub type SearchFuture = Pin<Box<dyn Future<Output = Result<Vec<SearchHit>, String>>>>;
+
+/// Source of search hits for one entity type
+pub trait SearchProvider {
+    /// Entity type this provider answers for
+    fn entity(&self) -> SearchEntity;
+
+    /// Hits for a lower-cased, trimmed query, in any order
+    fn search(&self, query: String) -> SearchFuture;
+}
+
+thread_local! {
+    static PROVIDERS: RefCell<Vec<Rc<dyn SearchProvider>>> = RefCell::new(Vec::new());
+}
+
+/// Register a provider, replacing any existing one for the same entity
+pub fn register(provider: Rc<dyn SearchProvider>) {
+    PROVIDERS.with(|p| {
+        let mut providers = p.borrow_mut();
+        providers.retain(|existing| existing.entity() != provider.entity());
+        providers.push(provider);
+        providers.sort_by_key(|p| p.entity());
+    });
+}
+
+/// Registered providers in display order
+pub fn providers() -> Vec<Rc<dyn SearchProvider>> {
+    PROVIDERS.with(|p| p.borrow().clone())
+}
+
+/// Register the local, store-backed provider of every searchable feature.
+///
+/// Call from within the app component, after the feature stores have been
+/// provided.
+pub fn install() {
+    use crate::features::requisitions::{search::RequisitionSearch, store::RequisitionsStore};
+    use crate::features::tenders::{search::TenderSearch, store::TendersStore};
+    use crate::features::contracts::{search::ContractSearch, store::ContractsStore};
+    use crate::features::purchase_orders::{search::PurchaseOrderSearch, store::PurchaseOrdersStore};
+    use crate::features::suppliers::{search::SupplierSearch, store::SuppliersStore};
+    use crate::features::catalogue::{search::CatalogueSearch, store::CatalogueStore};
+    use crate::features::documents::{search::DocumentSearch, store::DocumentsStore};
+
+    register(Rc::new(RequisitionSearch::new(use_context::<RequisitionsStore>())));
+    register(Rc::new(TenderSearch::new(use_context::<TendersStore>())));
+    register(Rc::new(ContractSearch::new(use_context::<ContractsStore>())));
+    register(Rc::new(PurchaseOrderSearch::new(use_context::<PurchaseOrdersStore>())));
+    register(Rc::new(SupplierSearch::new(use_context::<SuppliersStore>())));
+    register(Rc::new(CatalogueSearch::new(use_context::<CatalogueStore>())));
+    register(Rc::new(DocumentSearch::new(use_context::<DocumentsStore>())));
+}
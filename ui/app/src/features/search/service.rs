@@ -0,0 +1,52 @@
+//! Global search service - fans a query out to every registered provider
+
+use super::provider;
+use super::store::SearchStore;
+use super::types::{SearchGroup, MIN_QUERY_LEN, MAX_HITS_PER_GROUP};
+
+/// Run `query` against every provider and group the hits by entity type.
+///
+/// A provider that fails is reported in `store.error` without hiding the
+/// results of the others.
+pub async fn search(store: &SearchStore, query: &str) {
+    let query = query.trim().to_lowercase();
+    let generation = store.generation.get() + 1;
+    store.generation.set(generation);
+
+    if query.chars().count() < MIN_QUERY_LEN {
+        store.groups.set(Vec::new());
+        store.active.set(None);
+        store.loading.set(false);
+        store.error.set(None);
+        return;
+    }
+
+    store.loading.set(true);
+    store.error.set(None);
+
+    let mut groups = Vec::new();
+    let mut failures = Vec::new();
+    for provider in provider::providers() {
+        match provider.search(query.clone()).await {
+            Ok(mut hits) if !hits.is_empty() => {
+                hits.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.title.cmp(&b.title)));
+                hits.truncate(MAX_HITS_PER_GROUP);
+                groups.push(SearchGroup { entity: provider.entity(), hits });
+            }
+            Ok(_) => {}
+            Err(e) => failures.push(format!("{}: {}", provider.entity().label(), e)),
+        }
+
+        // A newer keystroke has started its own search
+        if store.generation.get() != generation {
+            return;
+        }
+    }
+
+    store.active.set(if groups.is_empty() { None } else { Some(0) });
+    store.groups.set(groups);
+    if !failures.is_empty() {
+        store.error.set(Some(format!("Some results are unavailable ({})", failures.join("; "))));
+    }
+    store.loading.set(false);
+}
@@ -0,0 +1,68 @@
+//! Global search store
+
+use components::prelude::*;
+use super::types::{SearchGroup, SearchHit};
+
+/// Global search state store
+#[derive(Clone)]
+pub struct SearchStore {
+    pub query: Signal<String>,
+    pub groups: Signal<Vec<SearchGroup>>,
+    /// Index into `hits()` of the keyboard-highlighted result
+    pub active: Signal<Option<usize>>,
+    pub open: Signal<bool>,
+    pub loading: Signal<bool>,
+    pub error: Signal<Option<String>>,
+    /// Bumped per search so a slow, superseded search cannot overwrite newer results
+    pub generation: Signal<u32>,
+}
+
+impl SearchStore {
+    pub fn new() -> Self {
+        Self {
+            query: signal(String::new()),
+            groups: signal(Vec::new()),
+            active: signal(None),
+            open: signal(false),
+            loading: signal(false),
+            error: signal(None),
+            generation: signal(0),
+        }
+    }
+
+    /// All hits in display order, as walked by the arrow keys
+    pub fn hits(&self) -> Vec<SearchHit> {
+        self.groups.get().into_iter().flat_map(|g| g.hits).collect()
+    }
+
+    /// Move the highlight by `delta`, wrapping at either end
+    pub fn move_active(&self, delta: i32) {
+        let count = self.hits().len() as i32;
+        if count == 0 {
+            self.active.set(None);
+            return;
+        }
+        let next = match self.active.get() {
+            Some(current) => (current as i32 + delta).rem_euclid(count),
+            None if delta < 0 => count - 1,
+            None => 0,
+        };
+        self.active.set(Some(next as usize));
+    }
+
+    /// The highlighted hit, if any
+    pub fn active_hit(&self) -> Option<SearchHit> {
+        self.active.get().and_then(|i| self.hits().into_iter().nth(i))
+    }
+
+    /// Close the results and forget the query
+    pub fn reset(&self) {
+        self.generation.set(self.generation.get() + 1);
+        self.query.set(String::new());
+        self.groups.set(Vec::new());
+        self.active.set(None);
+        self.open.set(false);
+        self.loading.set(false);
+        self.error.set(None);
+    }
+}
@@ -0,0 +1,91 @@
+//! Global search types
+
+use crate::Route;
+use crate::util::i18n::t;
+
+/// Kind of record a search hit points at, in display order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SearchEntity {
+    Requisition,
+    Tender,
+    Contract,
+    PurchaseOrder,
+    Supplier,
+    CatalogueItem,
+    Document,
+}
+
+impl SearchEntity {
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            SearchEntity::Requisition => "Requisitions",
+            SearchEntity::Tender => "Tenders",
+            SearchEntity::Contract => "Contracts",
+            SearchEntity::PurchaseOrder => "Purchase Orders",
+            SearchEntity::Supplier => "Suppliers",
+            SearchEntity::CatalogueItem => "Catalogue",
+            SearchEntity::Document => "Documents",
+        })
+    }
+}
+
+/// A single matching record
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchHit {
+    pub entity: SearchEntity,
+    pub id: String,
+    pub title: String,
+    pub subtitle: String,
+    pub route: Route,
+    /// Higher ranks first within a group
+    pub score: u32,
+}
+
+/// Hits for one entity type
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchGroup {
+    pub entity: SearchEntity,
+    pub hits: Vec<SearchHit>,
+}
+
+/// Minimum query length before any provider is asked
+pub const MIN_QUERY_LEN: usize = 2;
+
+/// Maximum hits shown per entity type
+pub const MAX_HITS_PER_GROUP: usize = 5;
+
+/// Score `fields` against a lower-cased query.
+///
+/// Identifier fields (`keys`) rank above free text: an exact key match beats
+/// a key prefix, which beats a substring anywhere. Returns `None` when nothing
+/// matches.
+pub fn match_score(query: &str, keys: &[&str], text: &[&str]) -> Option<u32> {
+    let mut best = None;
+    for key in keys {
+        let key = key.to_lowercase();
+        let score = if key == query {
+            Some(100)
+        } else if key.starts_with(query) {
+            Some(80)
+        } else if key.contains(query) {
+            Some(60)
+        } else {
+            None
+        };
+        best = best.max(score);
+    }
+    for field in text {
+        let field = field.to_lowercase();
+        let score = if field.starts_with(query) {
+            Some(40)
+        } else if field.split_whitespace().any(|word| word.starts_with(query)) {
+            Some(30)
+        } else if field.contains(query) {
+            Some(20)
+        } else {
+            None
+        };
+        best = best.max(score);
+    }
+    best
+}
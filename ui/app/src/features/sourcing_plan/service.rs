@@ -1,16 +1,21 @@
 //! Sourcing Plan service - API calls
 
+use crate::features::workflow::service as workflow;
+use crate::features::workflow::types::{ApprovalDecision, ApprovalDocument, ApprovalRequest, WorkflowState};
+use crate::util::api::ApiError;
+use crate::util::auth;
+use crate::util::backend;
+use crate::util::money::Money;
+use crate::util::datetime::now_iso;
 use super::store::{SourcingPlanStore, load_mock_data};
-use super::types::{SourcingPlan, SourcingPlanStatus, PlanApprovalStep};
+use super::types::{SourcingPlan, SourcingPlanStatus};
 
 /// Load sourcing plans data
 pub async fn load_sourcing_plans(store: &SourcingPlanStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -25,8 +30,13 @@ pub async fn create_sourcing_plan(store: &SourcingPlanStore, mut plan: SourcingP
     let year = plan.fiscal_year.split('/').next().unwrap_or("2025");
     plan.id = format!("SP-{}-{:03}", year, count);
     plan.status = SourcingPlanStatus::Draft;
-    plan.created_at = chrono_now();
-    plan.updated_at = chrono_now();
+    plan.created_at = now_iso();
+    plan.updated_at = now_iso();
+    if let Some(user) = auth::current().user {
+        plan.owner = user.name;
+        plan.owner_email = user.email;
+        plan.owner_id = user.id;
+    }
 
     // Calculate totals
     plan.calculate_totals();
@@ -53,7 +63,7 @@ pub async fn update_sourcing_plan(store: &SourcingPlanStore, plan: SourcingPlan)
     store.error.set(None);
 
     let mut updated = plan.clone();
-    updated.updated_at = chrono_now();
+    updated.updated_at = now_iso();
     updated.calculate_totals();
 
     // In production, this would PUT to the API
@@ -86,36 +96,20 @@ pub async fn submit_for_review(store: &SourcingPlanStore, id: &str) -> Result<So
             return Err("Only draft plans can be submitted for review".to_string());
         }
 
-        plans[pos].status = SourcingPlanStatus::UnderReview;
-        plans[pos].updated_at = chrono_now();
-
-        // Add initial approval workflow step if empty
-        if plans[pos].approval_workflow.is_empty() {
-            plans[pos].approval_workflow.push(PlanApprovalStep {
-                step: 1,
-                role: "SCM Manager".to_string(),
-                approver: None,
-                status: "pending".to_string(),
-                date: None,
-                comments: None,
-            });
-            plans[pos].approval_workflow.push(PlanApprovalStep {
-                step: 2,
-                role: "CFO".to_string(),
-                approver: None,
-                status: "pending".to_string(),
-                date: None,
-                comments: None,
-            });
-            plans[pos].approval_workflow.push(PlanApprovalStep {
-                step: 3,
-                role: "Accounting Officer".to_string(),
-                approver: None,
-                status: "pending".to_string(),
-                date: None,
-                comments: None,
-            });
+        // A plan returned for information gets a fresh chain
+        let request = ApprovalRequest::new(ApprovalDocument::SourcingPlan, plans[pos].budget.total_budget)
+            .department(&plans[pos].department)
+            .categories(plans[pos].categories.iter().map(|c| c.name.clone()));
+        match workflow::approval_chain(&request).await {
+            Ok(chain) => plans[pos].approval_workflow = chain,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.clone()));
+                return Err(e);
+            }
         }
+        plans[pos].status = SourcingPlanStatus::UnderReview;
+        plans[pos].updated_at = now_iso();
 
         let updated = plans[pos].clone();
         store.plans.set(plans);
@@ -129,29 +123,34 @@ pub async fn submit_for_review(store: &SourcingPlanStore, id: &str) -> Result<So
     }
 }
 
-/// Approve a sourcing plan
-pub async fn approve_plan(store: &SourcingPlanStore, id: &str, approver: &str, comments: Option<String>) -> Result<SourcingPlan, String> {
+/// Record an approver's decision on the plan's current approval stage
+pub async fn decide_plan(store: &SourcingPlanStore, id: &str, decision: &ApprovalDecision) -> Result<SourcingPlan, String> {
     store.loading.set(true);
     store.error.set(None);
 
     let mut plans = store.plans.get();
     if let Some(pos) = plans.iter().position(|p| p.id == id) {
-        // Find the next pending approval step
-        if let Some(step_idx) = plans[pos].approval_workflow.iter().position(|s| s.status == "pending") {
-            plans[pos].approval_workflow[step_idx].status = "approved".to_string();
-            plans[pos].approval_workflow[step_idx].approver = Some(approver.to_string());
-            plans[pos].approval_workflow[step_idx].date = Some(chrono_now());
-            plans[pos].approval_workflow[step_idx].comments = comments;
-
-            // Check if all steps are approved
-            let all_approved = plans[pos].approval_workflow.iter().all(|s| s.status == "approved");
-            if all_approved {
+        let owner = plans[pos].owner_id.clone();
+        let applied = match workflow::apply(ApprovalDocument::SourcingPlan, id, &owner, &mut plans[pos].approval_workflow, decision) {
+            Ok(applied) => applied,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.clone()));
+                return Err(e);
+            }
+        };
+        match applied.state {
+            WorkflowState::Approved => {
                 plans[pos].status = SourcingPlanStatus::Approved;
-                plans[pos].approved_at = Some(chrono_now());
+                plans[pos].approved_at = Some(now_iso());
             }
+            WorkflowState::Rejected => plans[pos].status = SourcingPlanStatus::Cancelled,
+            WorkflowState::Returned => plans[pos].status = SourcingPlanStatus::Draft,
+            WorkflowState::InProgress { .. } | WorkflowState::NotStarted => {}
         }
 
-        plans[pos].updated_at = chrono_now();
+        plans[pos].updated_at = now_iso();
+        applied.record();
 
         let updated = plans[pos].clone();
         store.plans.set(plans);
@@ -179,7 +178,7 @@ pub async fn activate_plan(store: &SourcingPlanStore, id: &str) -> Result<Sourci
         }
 
         plans[pos].status = SourcingPlanStatus::Active;
-        plans[pos].updated_at = chrono_now();
+        plans[pos].updated_at = now_iso();
 
         let updated = plans[pos].clone();
         store.plans.set(plans);
@@ -207,7 +206,7 @@ pub async fn complete_plan(store: &SourcingPlanStore, id: &str) -> Result<Sourci
         }
 
         plans[pos].status = SourcingPlanStatus::Completed;
-        plans[pos].updated_at = chrono_now();
+        plans[pos].updated_at = now_iso();
 
         let updated = plans[pos].clone();
         store.plans.set(plans);
@@ -273,18 +272,18 @@ pub async fn clone_plan_for_new_year(store: &SourcingPlanStore, id: &str, new_fi
         new_plan.id = format!("SP-{}-{:03}", year, count);
         new_plan.fiscal_year = new_fiscal_year.to_string();
         new_plan.status = SourcingPlanStatus::Draft;
-        new_plan.created_at = chrono_now();
-        new_plan.updated_at = chrono_now();
+        new_plan.created_at = now_iso();
+        new_plan.updated_at = now_iso();
         new_plan.approved_at = None;
         new_plan.approval_workflow = Vec::new();
 
         // Reset spent amounts and completed tenders
         for category in &mut new_plan.categories {
-            category.spent_amount = 0.0;
+            category.spent_amount = Money::ZERO;
             category.completed_tenders = 0;
         }
-        new_plan.budget.committed_amount = 0.0;
-        new_plan.budget.spent_amount = 0.0;
+        new_plan.budget.committed_amount = Money::ZERO;
+        new_plan.budget.spent_amount = Money::ZERO;
 
         // Reset timeline milestones
         for milestone in &mut new_plan.timeline {
@@ -311,9 +310,24 @@ pub async fn clone_plan_for_new_year(store: &SourcingPlanStore, id: &str, new_fi
     }
 }
 
-/// Helper to get current timestamp
-fn chrono_now() -> String {
-    // In production, use chrono crate
-    // For now, return a fixed timestamp
-    "2025-07-17T16:00:00Z".to_string()
+/// Refresh sourcing plans from the configured backend
+async fn load_from_backend(store: &SourcingPlanStore) {
+    if backend::is_mock() {
+        load_mock_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/sourcing-plans", &store.plans).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    } else {
+        let mut pagination = store.pagination.get();
+        pagination.update_totals(store.plans.get().len() as u32);
+        store.pagination.set(pagination);
+    }
 }
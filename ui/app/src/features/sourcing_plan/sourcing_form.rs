@@ -12,12 +12,14 @@ use crate::shared::forms::{
     currency_input, date_picker,
 };
 use crate::util::format::format_currency;
+use crate::util::money::Money;
 use super::store::SourcingPlanStore;
 use super::types::{
     SourcingPlan, ProcurementCategory, CategoryPriority,
     TimelineMilestone, MilestoneStatus, BudgetAllocation,
 };
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Sourcing plan form steps
 #[derive(Clone, Copy, PartialEq)]
@@ -66,7 +68,7 @@ pub fn sourcing_form() -> View {
     let categories: Signal<Vec<ProcurementCategory>> = signal(vec![ProcurementCategory::new()]);
 
     // Budget state
-    let total_budget = signal(0.0f64);
+    let total_budget = signal(Money::ZERO);
     let budget_currency = signal("ZAR".to_string());
 
     // Timeline milestones
@@ -80,27 +82,27 @@ pub fn sourcing_form() -> View {
     let stepper_items = vec![
         StepperItem {
             number: 1,
-            label: "Basic Info".to_string(),
+            label: t("Basic Info").to_string(),
             status: if step > 1 { StepStatus::Completed } else if step == 1 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 2,
-            label: "Categories".to_string(),
+            label: t("Categories").to_string(),
             status: if step > 2 { StepStatus::Completed } else if step == 2 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 3,
-            label: "Budget".to_string(),
+            label: t("Budget").to_string(),
             status: if step > 3 { StepStatus::Completed } else if step == 3 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 4,
-            label: "Timeline".to_string(),
+            label: t("Timeline").to_string(),
             status: if step > 4 { StepStatus::Completed } else if step == 4 { StepStatus::Active } else { StepStatus::Pending },
         },
         StepperItem {
             number: 5,
-            label: "Review".to_string(),
+            label: t("Review").to_string(),
             status: if step == 5 { StepStatus::Active } else { StepStatus::Pending },
         },
     ];
@@ -231,8 +233,8 @@ pub fn sourcing_form() -> View {
             plan.budget = BudgetAllocation {
                 total_budget: total_budget.get(),
                 allocated_amount: categories.get().iter().map(|c| c.allocated_budget).sum(),
-                committed_amount: 0.0,
-                spent_amount: 0.0,
+                committed_amount: Money::ZERO,
+                spent_amount: Money::ZERO,
                 currency: "ZAR".to_string(),
                 fiscal_year_start: start_date.get(),
                 fiscal_year_end: end_date.get(),
@@ -266,13 +268,13 @@ pub fn sourcing_form() -> View {
 
     // Department options
     let department_options = vec![
-        SelectOption { value: "Procurement Division".to_string(), label: "Procurement Division".to_string() },
-        SelectOption { value: "Information Technology".to_string(), label: "Information Technology".to_string() },
-        SelectOption { value: "Facilities".to_string(), label: "Facilities".to_string() },
-        SelectOption { value: "Transport".to_string(), label: "Transport".to_string() },
-        SelectOption { value: "Human Resources".to_string(), label: "Human Resources".to_string() },
-        SelectOption { value: "Finance".to_string(), label: "Finance".to_string() },
-        SelectOption { value: "Health Services".to_string(), label: "Health Services".to_string() },
+        SelectOption { value: "Procurement Division".to_string(), label: t("Procurement Division").to_string() },
+        SelectOption { value: "Information Technology".to_string(), label: t("Information Technology").to_string() },
+        SelectOption { value: "Facilities".to_string(), label: t("Facilities").to_string() },
+        SelectOption { value: "Transport".to_string(), label: t("Transport").to_string() },
+        SelectOption { value: "Human Resources".to_string(), label: t("Human Resources").to_string() },
+        SelectOption { value: "Finance".to_string(), label: t("Finance").to_string() },
+        SelectOption { value: "Health Services".to_string(), label: t("Health Services").to_string() },
     ];
 
     let fiscal_year_options = vec![
@@ -282,19 +284,19 @@ pub fn sourcing_form() -> View {
     ];
 
     let priority_options = vec![
-        SelectOption { value: "low".to_string(), label: "Low".to_string() },
-        SelectOption { value: "medium".to_string(), label: "Medium".to_string() },
-        SelectOption { value: "high".to_string(), label: "High".to_string() },
-        SelectOption { value: "critical".to_string(), label: "Critical".to_string() },
+        SelectOption { value: "low".to_string(), label: t("Low").to_string() },
+        SelectOption { value: "medium".to_string(), label: t("Medium").to_string() },
+        SelectOption { value: "high".to_string(), label: t("High").to_string() },
+        SelectOption { value: "critical".to_string(), label: t("Critical").to_string() },
     ];
 
     // Calculate totals (explicit type annotation for sum to help type inference)
-    let categories_total: f64 = categories.get().iter().map(|c| c.allocated_budget).sum::<f64>();
-    let budget_remaining: f64 = total_budget.get() - categories_total;
+    let categories_total: Money = categories.get().iter().map(|c| c.allocated_budget).sum::<Money>();
+    let budget_remaining: Money = total_budget.get() - categories_total;
 
     // Pre-compute budget display values to avoid type inference issues in view! macro
-    let budget_is_positive = budget_remaining >= 0.0;
-    let budget_is_negative = budget_remaining < 0.0;
+    let budget_is_positive = !budget_remaining.is_negative();
+    let budget_is_negative = budget_remaining.is_negative();
     let budget_class = if budget_is_positive { "positive" } else { "negative" };
     let budget_over_amount = format_currency(-budget_remaining);
 
@@ -466,10 +468,10 @@ pub fn sourcing_form() -> View {
 
         <div class="sourcing-form" data-testid="sourcing-form">
             {page_header(
-                "New Sourcing Plan".to_string(),
-                Some("Create an annual procurement sourcing plan".to_string()),
+                t("New Sourcing Plan").to_string(),
+                Some(t("Create an annual procurement sourcing plan").to_string()),
                 vec![
-                    view! { <a href="/sourcing-plans" class="btn btn-secondary">"Cancel"</a> },
+                    view! { <a href="/sourcing-plans" class="btn btn-secondary">{t("Cancel")}</a> },
                 ]
             )}
 
@@ -479,17 +481,17 @@ pub fn sourcing_form() -> View {
             // Step 1: Basic Info
             if current_step.get() == 1 {
                 {panel_with_footer(
-                    "Basic Information".to_string(),
+                    t("Basic Information").to_string(),
                     vec![],
                     vec![
                         form_group(
-                            Some("Plan Details".to_string()),
+                            Some(t("Plan Details").to_string()),
                             2,
                             vec![
                                 text_input(
-                                    "Plan Title".to_string(),
+                                    t("Plan Title").to_string(),
                                     title.clone(),
-                                    Some("e.g., Annual Procurement Plan FY 2025/26".to_string()),
+                                    Some(t("e.g., Annual Procurement Plan FY 2025/26").to_string()),
                                     true,
                                     false,
                                     None,
@@ -497,7 +499,7 @@ pub fn sourcing_form() -> View {
                                     None,
                                 ),
                                 select(
-                                    "Fiscal Year".to_string(),
+                                    t("Fiscal Year").to_string(),
                                     fiscal_year.clone(),
                                     fiscal_year_options,
                                     None,
@@ -512,32 +514,32 @@ pub fn sourcing_form() -> View {
                             1,
                             vec![
                                 textarea(
-                                    "Description".to_string(),
+                                    t("Description").to_string(),
                                     description.clone(),
-                                    Some("Describe the scope and purpose of this sourcing plan...".to_string()),
+                                    Some(t("Describe the scope and purpose of this sourcing plan...").to_string()),
                                     true,
                                     false,
                                     Some(4),
                                     None,
-                                    Some("Provide a detailed description of the procurement plan".to_string()),
+                                    Some(t("Provide a detailed description of the procurement plan").to_string()),
                                 ),
                             ]
                         ),
                         form_group(
-                            Some("Department & Timeline".to_string()),
+                            Some(t("Department & Timeline").to_string()),
                             2,
                             vec![
                                 select(
-                                    "Department".to_string(),
+                                    t("Department").to_string(),
                                     department.clone(),
                                     department_options.clone(),
-                                    Some("Select department".to_string()),
+                                    Some(t("Select department").to_string()),
                                     true,
                                     false,
                                     None,
                                 ),
                                 text_input(
-                                    "Start Date".to_string(),
+                                    t("Start Date").to_string(),
                                     start_date.clone(),
                                     Some("YYYY-MM-DD".to_string()),
                                     true,
@@ -553,7 +555,7 @@ pub fn sourcing_form() -> View {
                             2,
                             vec![
                                 text_input(
-                                    "End Date".to_string(),
+                                    t("End Date").to_string(),
                                     end_date.clone(),
                                     Some("YYYY-MM-DD".to_string()),
                                     true,
@@ -567,14 +569,14 @@ pub fn sourcing_form() -> View {
                         ),
                         view! {
                             <div class="form-section">
-                                <label class="form-label">"Strategic Objectives"</label>
+                                <label class="form-label">{t("Strategic Objectives")}</label>
                                 <div class="objectives-list">
                                     for (idx, obj) in objectives.get().iter().enumerate() {
                                         {objective_row(idx, obj.clone(), objectives.clone(), on_remove_objective.clone())}
                                     }
                                 </div>
                                 <button class="btn btn-secondary add-btn" on:click={on_add_objective.clone()}>
-                                    "+ Add Objective"
+                                    {t("+ Add Objective")}
                                 </button>
                             </div>
                         },
@@ -583,9 +585,9 @@ pub fn sourcing_form() -> View {
                             1,
                             vec![
                                 textarea(
-                                    "Additional Notes".to_string(),
+                                    t("Additional Notes").to_string(),
                                     notes.clone(),
-                                    Some("Any additional information...".to_string()),
+                                    Some(t("Any additional information...").to_string()),
                                     false,
                                     false,
                                     Some(3),
@@ -596,8 +598,8 @@ pub fn sourcing_form() -> View {
                         ),
                     ],
                     vec![
-                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>"Save Draft"</button> },
-                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>"Continue to Categories"</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>{t("Save Draft")}</button> },
+                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>{t("Continue to Categories")}</button> },
                     ]
                 )}
             }
@@ -605,25 +607,25 @@ pub fn sourcing_form() -> View {
             // Step 2: Categories
             if current_step.get() == 2 {
                 {panel_with_footer(
-                    "Procurement Categories".to_string(),
+                    t("Procurement Categories").to_string(),
                     vec![],
                     vec![
                         view! {
                             <p style="color: var(--text-muted); margin-bottom: 16px;">
-                                "Define the procurement categories and their budget allocations for this plan."
+                                {t("Define the procurement categories and their budget allocations for this plan.")}
                             </p>
                         },
                         view! {
                             <table class="categories-table">
                                 <thead>
                                     <tr>
-                                        <th style="width: 20%;">"Category Name"</th>
-                                        <th style="width: 10%;">"Code"</th>
-                                        <th style="width: 18%;">"Allocated Budget"</th>
-                                        <th style="width: 10%;">"Tenders"</th>
-                                        <th style="width: 10%;">"Priority"</th>
-                                        <th style="width: 10%;">"B-BBEE %"</th>
-                                        <th style="width: 10%;">"Local %"</th>
+                                        <th style="width: 20%;">{t("Category Name")}</th>
+                                        <th style="width: 10%;">{t("Code")}</th>
+                                        <th style="width: 18%;">{t("Allocated Budget")}</th>
+                                        <th style="width: 10%;">{t("Tenders")}</th>
+                                        <th style="width: 10%;">{t("Priority")}</th>
+                                        <th style="width: 10%;">{t("B-BBEE %")}</th>
+                                        <th style="width: 10%;">{t("Local %")}</th>
                                         <th style="width: 5%;"></th>
                                     </tr>
                                 </thead>
@@ -636,22 +638,22 @@ pub fn sourcing_form() -> View {
                         },
                         view! {
                             <button class="btn btn-secondary add-btn" on:click={on_add_category.clone()}>
-                                "+ Add Category"
+                                {t("+ Add Category")}
                             </button>
                         },
                         view! {
                             <div class="budget-summary">
                                 <div class="budget-item">
-                                    <span class="budget-item-label">"Total Allocated"</span>
+                                    <span class="budget-item-label">{t("Total Allocated")}</span>
                                     <span class="budget-item-value">{categories_total_display.clone()}</span>
                                 </div>
                             </div>
                         },
                     ],
                     vec![
-                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>"Back"</button> },
-                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>"Save Draft"</button> },
-                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>"Continue to Budget"</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>{t("Back")}</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>{t("Save Draft")}</button> },
+                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>{t("Continue to Budget")}</button> },
                     ]
                 )}
             }
@@ -659,20 +661,20 @@ pub fn sourcing_form() -> View {
             // Step 3: Budget
             if current_step.get() == 3 {
                 {panel_with_footer(
-                    "Budget Allocation".to_string(),
+                    t("Budget Allocation").to_string(),
                     vec![],
                     vec![
                         form_group(
-                            Some("Total Budget".to_string()),
+                            Some(t("Total Budget").to_string()),
                             2,
                             vec![
                                 currency_input(
-                                    "Total Budget Amount".to_string(),
+                                    t("Total Budget Amount").to_string(),
                                     total_budget.clone(),
                                     true,
                                     false,
                                     None,
-                                    Some("Total budget for this sourcing plan".to_string()),
+                                    Some(t("Total budget for this sourcing plan").to_string()),
                                 ),
                                 view! { <div></div> },
                             ]
@@ -680,15 +682,15 @@ pub fn sourcing_form() -> View {
                         view! {
                             <div class="budget-summary">
                                 <div class="budget-item">
-                                    <span class="budget-item-label">"Total Budget"</span>
+                                    <span class="budget-item-label">{t("Total Budget")}</span>
                                     <span class="budget-item-value">{total_budget_display.clone()}</span>
                                 </div>
                                 <div class="budget-item">
-                                    <span class="budget-item-label">"Allocated to Categories"</span>
+                                    <span class="budget-item-label">{t("Allocated to Categories")}</span>
                                     <span class="budget-item-value">{categories_total_display.clone()}</span>
                                 </div>
                                 <div class="budget-item">
-                                    <span class="budget-item-label">"Remaining / Unallocated"</span>
+                                    <span class="budget-item-label">{t("Remaining / Unallocated")}</span>
                                     <span class={format!("budget-item-value {}", budget_class)}>
                                         {budget_remaining_display.clone()}
                                     </span>
@@ -698,7 +700,7 @@ pub fn sourcing_form() -> View {
                         if budget_is_negative {
                             view! {
                                 <div class="alert alert-warning" style="margin-top: 16px;">
-                                    "Warning: Category allocations exceed total budget by "
+                                    {t("Warning: Category allocations exceed total budget by ")}
                                     <strong>{budget_over_amount.clone()}</strong>
                                 </div>
                             }
@@ -707,9 +709,9 @@ pub fn sourcing_form() -> View {
                         },
                     ],
                     vec![
-                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>"Back"</button> },
-                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>"Save Draft"</button> },
-                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>"Continue to Timeline"</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>{t("Back")}</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>{t("Save Draft")}</button> },
+                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>{t("Continue to Timeline")}</button> },
                     ]
                 )}
             }
@@ -717,22 +719,22 @@ pub fn sourcing_form() -> View {
             // Step 4: Timeline
             if current_step.get() == 4 {
                 {panel_with_footer(
-                    "Timeline & Milestones".to_string(),
+                    t("Timeline & Milestones").to_string(),
                     vec![],
                     vec![
                         view! {
                             <p style="color: var(--text-muted); margin-bottom: 16px;">
-                                "Define key milestones and target dates for the procurement plan execution."
+                                {t("Define key milestones and target dates for the procurement plan execution.")}
                             </p>
                         },
                         view! {
                             <table class="milestones-table">
                                 <thead>
                                     <tr>
-                                        <th style="width: 25%;">"Milestone Name"</th>
-                                        <th style="width: 30%;">"Description"</th>
-                                        <th style="width: 15%;">"Target Date"</th>
-                                        <th style="width: 20%;">"Responsible Party"</th>
+                                        <th style="width: 25%;">{t("Milestone Name")}</th>
+                                        <th style="width: 30%;">{t("Description")}</th>
+                                        <th style="width: 15%;">{t("Target Date")}</th>
+                                        <th style="width: 20%;">{t("Responsible Party")}</th>
                                         <th style="width: 5%;"></th>
                                     </tr>
                                 </thead>
@@ -745,14 +747,14 @@ pub fn sourcing_form() -> View {
                         },
                         view! {
                             <button class="btn btn-secondary add-btn" on:click={on_add_milestone.clone()}>
-                                "+ Add Milestone"
+                                {t("+ Add Milestone")}
                             </button>
                         },
                     ],
                     vec![
-                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>"Back"</button> },
-                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>"Save Draft"</button> },
-                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>"Continue to Review"</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>{t("Back")}</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>{t("Save Draft")}</button> },
+                        view! { <button class="btn btn-primary" on:click={on_next.clone()}>{t("Continue to Review")}</button> },
                     ]
                 )}
             }
@@ -760,35 +762,35 @@ pub fn sourcing_form() -> View {
             // Step 5: Review
             if current_step.get() == 5 {
                 {panel_with_footer(
-                    "Review & Submit".to_string(),
+                    t("Review & Submit").to_string(),
                     vec![],
                     vec![
                         view! {
                             <div class="review-section">
-                                <div class="review-section-title">"Plan Details"</div>
+                                <div class="review-section-title">{t("Plan Details")}</div>
                                 <div class="review-grid">
                                     <div class="review-item">
-                                        <span class="review-item-label">"Title"</span>
+                                        <span class="review-item-label">{t("Title")}</span>
                                         <span class="review-item-value">{title.get()}</span>
                                     </div>
                                     <div class="review-item">
-                                        <span class="review-item-label">"Fiscal Year"</span>
+                                        <span class="review-item-label">{t("Fiscal Year")}</span>
                                         <span class="review-item-value">{fiscal_year.get()}</span>
                                     </div>
                                     <div class="review-item">
-                                        <span class="review-item-label">"Department"</span>
+                                        <span class="review-item-label">{t("Department")}</span>
                                         <span class="review-item-value">{department.get()}</span>
                                     </div>
                                     <div class="review-item">
-                                        <span class="review-item-label">"Period"</span>
-                                        <span class="review-item-value">{format!("{} to {}", start_date.get(), end_date.get())}</span>
+                                        <span class="review-item-label">{t("Period")}</span>
+                                        <span class="review-item-value">{tf("{start} to {end}", &[("start", start_date.get()), ("end", end_date.get())])}</span>
                                     </div>
                                 </div>
                             </div>
                         },
                         view! {
                             <div class="review-section">
-                                <div class="review-section-title">"Strategic Objectives"</div>
+                                <div class="review-section-title">{t("Strategic Objectives")}</div>
                                 <ul class="review-objectives">
                                     for obj in objectives.get().iter().filter(|o| !o.is_empty()) {
                                         <li>{obj.clone()}</li>
@@ -798,14 +800,14 @@ pub fn sourcing_form() -> View {
                         },
                         view! {
                             <div class="review-section">
-                                <div class="review-section-title">"Budget Summary"</div>
+                                <div class="review-section-title">{t("Budget Summary")}</div>
                                 <div class="review-grid">
                                     <div class="review-item">
-                                        <span class="review-item-label">"Total Budget"</span>
+                                        <span class="review-item-label">{t("Total Budget")}</span>
                                         <span class="review-item-value amount">{total_budget_display.clone()}</span>
                                     </div>
                                     <div class="review-item">
-                                        <span class="review-item-label">"Allocated"</span>
+                                        <span class="review-item-label">{t("Allocated")}</span>
                                         <span class="review-item-value amount">{categories_total_display.clone()}</span>
                                     </div>
                                 </div>
@@ -813,14 +815,14 @@ pub fn sourcing_form() -> View {
                         },
                         view! {
                             <div class="review-section">
-                                <div class="review-section-title">{format!("Categories ({} total)", categories_count)}</div>
+                                <div class="review-section-title">{tf("Categories ({count} total)", &[("count", categories_count.to_string())])}</div>
                                 <table class="categories-table">
                                     <thead>
                                         <tr>
-                                            <th>"Category"</th>
-                                            <th>"Budget"</th>
-                                            <th>"Tenders"</th>
-                                            <th>"Priority"</th>
+                                            <th>{t("Category")}</th>
+                                            <th>{t("Budget")}</th>
+                                            <th>{t("Tenders")}</th>
+                                            <th>{t("Priority")}</th>
                                         </tr>
                                     </thead>
                                     <tbody>
@@ -838,13 +840,13 @@ pub fn sourcing_form() -> View {
                         },
                         view! {
                             <div class="review-section">
-                                <div class="review-section-title">{format!("Timeline ({} milestones)", milestones_count)}</div>
+                                <div class="review-section-title">{tf("Timeline ({count} milestones)", &[("count", milestones_count.to_string())])}</div>
                                 <table class="milestones-table">
                                     <thead>
                                         <tr>
-                                            <th>"Milestone"</th>
-                                            <th>"Target Date"</th>
-                                            <th>"Responsible"</th>
+                                            <th>{t("Milestone")}</th>
+                                            <th>{t("Target Date")}</th>
+                                            <th>{t("Responsible")}</th>
                                         </tr>
                                     </thead>
                                     <tbody>
@@ -861,9 +863,9 @@ pub fn sourcing_form() -> View {
                         },
                     ],
                     vec![
-                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>"Back"</button> },
-                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>"Save Draft"</button> },
-                        view! { <button class="btn btn-primary" on:click={on_submit.clone()}>"Submit for Review"</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_prev.clone()}>{t("Back")}</button> },
+                        view! { <button class="btn btn-secondary" on:click={on_save_draft.clone()}>{t("Save Draft")}</button> },
+                        view! { <button class="btn btn-primary" on:click={on_submit.clone()}>{t("Submit for Review")}</button> },
                     ]
                 )}
             }
@@ -911,7 +913,7 @@ fn category_row(
             let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
             let mut items = categories.get();
             if idx < items.len() {
-                items[idx].allocated_budget = input.value().replace(",", "").parse().unwrap_or(0.0);
+                items[idx].allocated_budget = input.value().parse().unwrap_or_default();
                 categories.set(items);
             }
         })
@@ -989,7 +991,7 @@ fn category_row(
                 <input
                     type="text"
                     value={cat.name.clone()}
-                    placeholder="Category name"
+                    placeholder={t("Category name")}
                     on:input={on_name_change}
                 />
             </td>
@@ -997,7 +999,7 @@ fn category_row(
                 <input
                     type="text"
                     value={cat.code.clone()}
-                    placeholder="Code"
+                    placeholder={t("Code")}
                     on:input={on_code_change}
                 />
             </td>
@@ -1005,7 +1007,7 @@ fn category_row(
                 <input
                     type="text"
                     class="budget-input"
-                    value={format!("{:.2}", cat.allocated_budget)}
+                    value={cat.allocated_budget.to_string()}
                     on:input={on_budget_change}
                 />
             </td>
@@ -1019,10 +1021,10 @@ fn category_row(
             </td>
             <td>
                 <select value={cat.priority.as_str()} on:change={on_priority_change}>
-                    <option value="low">"Low"</option>
-                    <option value="medium">"Medium"</option>
-                    <option value="high">"High"</option>
-                    <option value="critical">"Critical"</option>
+                    <option value="low">{t("Low")}</option>
+                    <option value="medium">{t("Medium")}</option>
+                    <option value="high">{t("High")}</option>
+                    <option value="critical">{t("Critical")}</option>
                 </select>
             </td>
             <td>
@@ -1048,7 +1050,7 @@ fn category_row(
                 />
             </td>
             <td>
-                <button class="remove-btn" on:click={handle_remove} title="Remove">
+                <button class="remove-btn" on:click={handle_remove} title={t("Remove")}>
                     "x"
                 </button>
             </td>
@@ -1128,7 +1130,7 @@ fn milestone_row(
                 <input
                     type="text"
                     value={ms.name.clone()}
-                    placeholder="Milestone name"
+                    placeholder={t("Milestone name")}
                     on:input={on_name_change}
                 />
             </td>
@@ -1136,7 +1138,7 @@ fn milestone_row(
                 <input
                     type="text"
                     value={ms.description.clone().unwrap_or_default()}
-                    placeholder="Description"
+                    placeholder={t("Description")}
                     on:input={on_desc_change}
                 />
             </td>
@@ -1151,12 +1153,12 @@ fn milestone_row(
                 <input
                     type="text"
                     value={ms.responsible_party.clone().unwrap_or_default()}
-                    placeholder="Responsible party"
+                    placeholder={t("Responsible party")}
                     on:input={on_responsible_change}
                 />
             </td>
             <td>
-                <button class="remove-btn" on:click={handle_remove} title="Remove">
+                <button class="remove-btn" on:click={handle_remove} title={t("Remove")}>
                     "x"
                 </button>
             </td>
@@ -1196,10 +1198,10 @@ fn objective_row(
             <input
                 type="text"
                 value={obj}
-                placeholder="Enter strategic objective..."
+                placeholder={t("Enter strategic objective...")}
                 on:input={on_change}
             />
-            <button class="remove-btn" on:click={handle_remove} title="Remove">
+            <button class="remove-btn" on:click={handle_remove} title={t("Remove")}>
                 "x"
             </button>
         </div>
@@ -11,9 +11,11 @@ use crate::shared::components::{
 };
 use crate::shared::forms::filter_bar;
 use crate::util::format::{format_currency, format_date};
+use crate::util::money::Money;
 use super::store::{SourcingPlanStore, get_paginated_plans, get_filtered_plans};
 use super::types::{SourcingPlanStatus, SourcingPlanFilter};
 use super::service;
+use crate::util::i18n::{t, tf, tn};
 
 /// Sourcing plan list page
 #[component]
@@ -163,13 +165,13 @@ pub fn sourcing_list() -> View {
 
     // Table columns
     let columns = vec![
-        DataTableColumn { key: "id".to_string(), label: "Plan ID".to_string(), width: Some("110px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "title".to_string(), label: "Title".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "fiscal_year".to_string(), label: "Fiscal Year".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "department".to_string(), label: "Department".to_string(), width: Some("150px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "budget".to_string(), label: "Total Budget".to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "progress".to_string(), label: "Progress".to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "id".to_string(), label: t("Plan ID").to_string(), width: Some("110px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
+        DataTableColumn { key: "title".to_string(), label: t("Title").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "fiscal_year".to_string(), label: t("Fiscal Year").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "department".to_string(), label: t("Department").to_string(), width: Some("150px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "budget".to_string(), label: t("Total Budget").to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "progress".to_string(), label: t("Progress").to_string(), width: Some("130px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
     ];
 
     // Transform sourcing plans to table rows
@@ -189,7 +191,13 @@ pub fn sourcing_list() -> View {
 
         // Pre-compute progress bar before view! block
         let progress = progress_bar(completion, ProgressColor::Blue, true, None);
-        let progress_label = format!("{}/{} tenders", plan.total_completed_tenders(), plan.total_planned_tenders());
+        let progress_label = tf(
+            "{completed}/{planned} tenders",
+            &[
+                ("completed", plan.total_completed_tenders().to_string()),
+                ("planned", plan.total_planned_tenders().to_string()),
+            ],
+        );
 
         DataTableRow {
             id: plan.id.clone(),
@@ -198,7 +206,7 @@ pub fn sourcing_list() -> View {
                 view! {
                     <div class="title-cell">
                         <span class="plan-title">{plan.title.clone()}</span>
-                        <span class="plan-owner">{format!("Owner: {}", plan.owner)}</span>
+                        <span class="plan-owner">{tf("Owner: {owner}", &[("owner", plan.owner.to_string())])}</span>
                     </div>
                 },
                 view! { <span class="fiscal-year">{plan.fiscal_year.clone()}</span> },
@@ -221,17 +229,17 @@ pub fn sourcing_list() -> View {
     // Calculate summary stats
     let all_plans = store.plans.get();
     let active_plans = all_plans.iter().filter(|p| p.status == SourcingPlanStatus::Active).count();
-    let total_budget: f64 = all_plans.iter()
+    let total_budget: Money = all_plans.iter()
         .filter(|p| p.status == SourcingPlanStatus::Active)
         .map(|p| p.budget.total_budget)
         .sum();
-    let total_spent: f64 = all_plans.iter()
+    let total_spent: Money = all_plans.iter()
         .filter(|p| p.status == SourcingPlanStatus::Active)
         .map(|p| p.budget.spent_amount)
         .sum();
 
     // Pre-compute budget utilization percentage
-    let budget_util_pct = if total_budget > 0.0 { (total_spent / total_budget) * 100.0 } else { 0.0 };
+    let budget_util_pct = total_spent.percent_of(total_budget);
 
     view! {
         style {
@@ -314,35 +322,35 @@ pub fn sourcing_list() -> View {
 
         <div class="sourcing-list" data-testid="sourcing-list">
             {page_header(
-                "Sourcing Plans".to_string(),
-                Some("Annual procurement planning and budget management".to_string()),
+                t("Sourcing Plans").to_string(),
+                Some(t("Annual procurement planning and budget management").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export"</button> },
-                    view! { <a href="/sourcing-plans/new" class="btn btn-primary">"+ New Sourcing Plan"</a> },
+                    view! { <button class="btn btn-secondary">{t("Export")}</button> },
+                    view! { <a href="/sourcing-plans/new" class="btn btn-primary">{t("+ New Sourcing Plan")}</a> },
                 ]
             )}
 
             // Summary cards
             <div class="summary-cards">
                 <div class="summary-card">
-                    <div class="summary-card-label">"Active Plans"</div>
+                    <div class="summary-card-label">{t("Active Plans")}</div>
                     <div class="summary-card-value">{active_plans}</div>
-                    <div class="summary-card-sub">{format!("{} total plans", all_plans.len())}</div>
+                    <div class="summary-card-sub">{tn("{n} plan in total", "{n} plans in total", all_plans.len() as i64)}</div>
                 </div>
                 <div class="summary-card">
-                    <div class="summary-card-label">"Total Active Budget"</div>
+                    <div class="summary-card-label">{t("Total Active Budget")}</div>
                     <div class="summary-card-value currency">{format_currency(total_budget)}</div>
-                    <div class="summary-card-sub">"FY 2025/26"</div>
+                    <div class="summary-card-sub">{t("FY 2025/26")}</div>
                 </div>
                 <div class="summary-card">
-                    <div class="summary-card-label">"Budget Utilized"</div>
+                    <div class="summary-card-label">{t("Budget Utilized")}</div>
                     <div class="summary-card-value currency">{format_currency(total_spent)}</div>
-                    <div class="summary-card-sub">{format!("{:.1}% of total", budget_util_pct)}</div>
+                    <div class="summary-card-sub">{tf("{percent} of total", &[("percent", format!("{:.1}%", budget_util_pct))])}</div>
                 </div>
                 <div class="summary-card">
-                    <div class="summary-card-label">"Available Budget"</div>
+                    <div class="summary-card-label">{t("Available Budget")}</div>
                     <div class="summary-card-value currency">{format_currency(total_budget - total_spent)}</div>
-                    <div class="summary-card-sub">"Remaining"</div>
+                    <div class="summary-card-sub">{t("Remaining")}</div>
                 </div>
             </div>
 
@@ -350,10 +358,10 @@ pub fn sourcing_list() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Search"</label>
+                        <label>{t("Search")}</label>
                         <input
                             type="text"
-                            placeholder="Search by ID, title, owner..."
+                            placeholder={t("Search by ID, title, owner...")}
                             value={search.get()}
                             on:input={on_search}
                             style="width: 220px;"
@@ -362,23 +370,23 @@ pub fn sourcing_list() -> View {
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Status"</label>
+                        <label>{t("Status")}</label>
                         <select on:change={on_status_change}>
-                            <option value="">"All Statuses"</option>
-                            <option value="draft">"Draft"</option>
-                            <option value="under_review">"Under Review"</option>
-                            <option value="approved">"Approved"</option>
-                            <option value="active">"Active"</option>
-                            <option value="completed">"Completed"</option>
-                            <option value="cancelled">"Cancelled"</option>
+                            <option value="">{t("All Statuses")}</option>
+                            <option value="draft">{t("Draft")}</option>
+                            <option value="under_review">{t("Under Review")}</option>
+                            <option value="approved">{t("Approved")}</option>
+                            <option value="active">{t("Active")}</option>
+                            <option value="completed">{t("Completed")}</option>
+                            <option value="cancelled">{t("Cancelled")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Fiscal Year"</label>
+                        <label>{t("Fiscal Year")}</label>
                         <select on:change={on_fiscal_year_change}>
-                            <option value="">"All Years"</option>
+                            <option value="">{t("All Years")}</option>
                             <option value="2025/26">"2025/26"</option>
                             <option value="2024/25">"2024/25"</option>
                             <option value="2023/24">"2023/24"</option>
@@ -387,32 +395,32 @@ pub fn sourcing_list() -> View {
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Department"</label>
+                        <label>{t("Department")}</label>
                         <select on:change={on_department_change}>
-                            <option value="">"All Departments"</option>
-                            <option value="Procurement Division">"Procurement Division"</option>
-                            <option value="Information Technology">"Information Technology"</option>
-                            <option value="Facilities">"Facilities"</option>
-                            <option value="Transport">"Transport"</option>
-                            <option value="Human Resources">"Human Resources"</option>
-                            <option value="Finance">"Finance"</option>
+                            <option value="">{t("All Departments")}</option>
+                            <option value="Procurement Division">{t("Procurement Division")}</option>
+                            <option value="Information Technology">{t("Information Technology")}</option>
+                            <option value="Facilities">{t("Facilities")}</option>
+                            <option value="Transport">{t("Transport")}</option>
+                            <option value="Human Resources">{t("Human Resources")}</option>
+                            <option value="Finance">{t("Finance")}</option>
                         </select>
                     </div>
                 },
                 view! { <div class="filter-spacer"></div> },
                 view! {
-                    <button class="btn btn-sm btn-secondary" on:click={on_clear_filters}>"Clear Filters"</button>
+                    <button class="btn btn-sm btn-secondary" on:click={on_clear_filters}>{t("Clear Filters")}</button>
                 },
             ])}
 
             // Sourcing plans table
             {panel(
-                "All Sourcing Plans".to_string(),
+                t("All Sourcing Plans").to_string(),
                 vec![],
                 vec![
                     view! {
                         <div class="list-info">
-                            <span>{format!("Showing {} of {} plans", rows.len(), total_filtered)}</span>
+                            <span>{tf("Showing {count} of {total} plans", &[("count", rows.len().to_string()), ("total", total_filtered.to_string())])}</span>
                             <div class="export-buttons">
                                 <button class="btn btn-sm btn-secondary">"CSV"</button>
                                 <button class="btn btn-sm btn-secondary">"PDF"</button>
@@ -1,10 +1,12 @@
 //! Sourcing Plan store
 
 use components::prelude::*;
+use crate::features::workflow::types::{ApprovalStep, StepStatus};
+use crate::util::money::Money;
 use super::types::{
     SourcingPlan, SourcingPlanStatus, SourcingPlanFilter, PaginationState,
     ProcurementCategory, CategoryPriority, TimelineMilestone, MilestoneStatus,
-    BudgetAllocation, PlanApprovalStep,
+    BudgetAllocation,
 };
 
 /// Sourcing Plan state store
@@ -64,8 +66,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Information Technology".to_string(),
                     code: "IT-HW-SW".to_string(),
                     description: Some("Hardware, software, and IT services".to_string()),
-                    allocated_budget: 45_000_000.0,
-                    spent_amount: 12_500_000.0,
+                    allocated_budget: Money::rands(45_000_000),
+                    spent_amount: Money::rands(12_500_000),
                     planned_tenders: 15,
                     completed_tenders: 4,
                     priority: CategoryPriority::High,
@@ -77,8 +79,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Facilities Management".to_string(),
                     code: "FAC-MGT".to_string(),
                     description: Some("Building maintenance, security, and cleaning services".to_string()),
-                    allocated_budget: 28_000_000.0,
-                    spent_amount: 8_750_000.0,
+                    allocated_budget: Money::rands(28_000_000),
+                    spent_amount: Money::rands(8_750_000),
                     planned_tenders: 8,
                     completed_tenders: 3,
                     priority: CategoryPriority::Medium,
@@ -90,8 +92,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Fleet & Transport".to_string(),
                     code: "FLT-TRN".to_string(),
                     description: Some("Vehicle procurement, maintenance, and fuel".to_string()),
-                    allocated_budget: 18_500_000.0,
-                    spent_amount: 4_200_000.0,
+                    allocated_budget: Money::rands(18_500_000),
+                    spent_amount: Money::rands(4_200_000),
                     planned_tenders: 6,
                     completed_tenders: 2,
                     priority: CategoryPriority::Medium,
@@ -103,8 +105,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Professional Services".to_string(),
                     code: "PRO-SVC".to_string(),
                     description: Some("Consulting, legal, and audit services".to_string()),
-                    allocated_budget: 22_000_000.0,
-                    spent_amount: 5_500_000.0,
+                    allocated_budget: Money::rands(22_000_000),
+                    spent_amount: Money::rands(5_500_000),
                     planned_tenders: 10,
                     completed_tenders: 3,
                     priority: CategoryPriority::High,
@@ -113,10 +115,10 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                 },
             ],
             budget: BudgetAllocation {
-                total_budget: 120_000_000.0,
-                allocated_amount: 113_500_000.0,
-                committed_amount: 45_000_000.0,
-                spent_amount: 30_950_000.0,
+                total_budget: Money::rands(120_000_000),
+                allocated_amount: Money::rands(113_500_000),
+                committed_amount: Money::rands(45_000_000),
+                spent_amount: Money::rands(30_950_000),
                 currency: "ZAR".to_string(),
                 fiscal_year_start: "2025-04-01".to_string(),
                 fiscal_year_end: "2026-03-31".to_string(),
@@ -169,32 +171,54 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                 },
             ],
             approval_workflow: vec![
-                PlanApprovalStep {
+                ApprovalStep {
                     step: 1,
                     role: "SCM Manager".to_string(),
                     approver: Some("Thandi Nkosi".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: None,
+                    status: StepStatus::Approved,
                     date: Some("2025-03-01".to_string()),
                     comments: Some("Plan aligns with departmental objectives".to_string()),
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
                 },
-                PlanApprovalStep {
+                ApprovalStep {
                     step: 2,
                     role: "CFO".to_string(),
                     approver: Some("Nomvula Zulu".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U005".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-03-08".to_string()),
                     comments: Some("Budget allocation approved".to_string()),
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
                 },
-                PlanApprovalStep {
+                ApprovalStep {
                     step: 3,
                     role: "Accounting Officer".to_string(),
                     approver: Some("Dr. James Molefe".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U007".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-03-12".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
                 },
             ],
             owner: "Sipho Dlamini".to_string(),
+            owner_id: "U022".to_string(),
             owner_email: "sipho.dlamini@gov.za".to_string(),
             created_at: "2025-02-01T10:00:00Z".to_string(),
             updated_at: "2025-07-15T14:30:00Z".to_string(),
@@ -223,8 +247,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Cloud Services".to_string(),
                     code: "ICT-CLD".to_string(),
                     description: Some("Public and private cloud infrastructure".to_string()),
-                    allocated_budget: 15_000_000.0,
-                    spent_amount: 3_200_000.0,
+                    allocated_budget: Money::rands(15_000_000),
+                    spent_amount: Money::rands(3_200_000),
                     planned_tenders: 4,
                     completed_tenders: 1,
                     priority: CategoryPriority::Critical,
@@ -236,8 +260,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Cybersecurity".to_string(),
                     code: "ICT-SEC".to_string(),
                     description: Some("Security software, hardware, and managed services".to_string()),
-                    allocated_budget: 12_000_000.0,
-                    spent_amount: 4_500_000.0,
+                    allocated_budget: Money::rands(12_000_000),
+                    spent_amount: Money::rands(4_500_000),
                     planned_tenders: 5,
                     completed_tenders: 2,
                     priority: CategoryPriority::Critical,
@@ -249,8 +273,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Network Infrastructure".to_string(),
                     code: "ICT-NET".to_string(),
                     description: Some("LAN, WAN, and telecommunications".to_string()),
-                    allocated_budget: 8_000_000.0,
-                    spent_amount: 1_800_000.0,
+                    allocated_budget: Money::rands(8_000_000),
+                    spent_amount: Money::rands(1_800_000),
                     planned_tenders: 3,
                     completed_tenders: 1,
                     priority: CategoryPriority::High,
@@ -259,10 +283,10 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                 },
             ],
             budget: BudgetAllocation {
-                total_budget: 40_000_000.0,
-                allocated_amount: 35_000_000.0,
-                committed_amount: 15_000_000.0,
-                spent_amount: 9_500_000.0,
+                total_budget: Money::rands(40_000_000),
+                allocated_amount: Money::rands(35_000_000),
+                committed_amount: Money::rands(15_000_000),
+                spent_amount: Money::rands(9_500_000),
                 currency: "ZAR".to_string(),
                 fiscal_year_start: "2025-04-01".to_string(),
                 fiscal_year_end: "2026-03-31".to_string(),
@@ -288,24 +312,39 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                 },
             ],
             approval_workflow: vec![
-                PlanApprovalStep {
+                ApprovalStep {
                     step: 1,
                     role: "CIO".to_string(),
                     approver: Some("Dr. Sarah Chen".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: None,
+                    status: StepStatus::Approved,
                     date: Some("2025-02-20".to_string()),
                     comments: Some("Aligned with IT strategy".to_string()),
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
                 },
-                PlanApprovalStep {
+                ApprovalStep {
                     step: 2,
                     role: "CFO".to_string(),
                     approver: Some("Nomvula Zulu".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U005".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-02-25".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
                 },
             ],
             owner: "Peter Mokwena".to_string(),
+            owner_id: "U003".to_string(),
             owner_email: "peter.mokwena@gov.za".to_string(),
             created_at: "2025-01-15T08:00:00Z".to_string(),
             updated_at: "2025-07-10T11:00:00Z".to_string(),
@@ -333,8 +372,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Building Maintenance".to_string(),
                     code: "FAC-BLD".to_string(),
                     description: Some("Routine and preventive maintenance".to_string()),
-                    allocated_budget: 18_000_000.0,
-                    spent_amount: 0.0,
+                    allocated_budget: Money::rands(18_000_000),
+                    spent_amount: Money::rands(0),
                     planned_tenders: 6,
                     completed_tenders: 0,
                     priority: CategoryPriority::High,
@@ -346,8 +385,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "HVAC Systems".to_string(),
                     code: "FAC-HVC".to_string(),
                     description: Some("Heating, ventilation, and air conditioning".to_string()),
-                    allocated_budget: 8_500_000.0,
-                    spent_amount: 0.0,
+                    allocated_budget: Money::rands(8_500_000),
+                    spent_amount: Money::rands(0),
                     planned_tenders: 3,
                     completed_tenders: 0,
                     priority: CategoryPriority::Medium,
@@ -356,10 +395,10 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                 },
             ],
             budget: BudgetAllocation {
-                total_budget: 30_000_000.0,
-                allocated_amount: 26_500_000.0,
-                committed_amount: 0.0,
-                spent_amount: 0.0,
+                total_budget: Money::rands(30_000_000),
+                allocated_amount: Money::rands(26_500_000),
+                committed_amount: Money::rands(0),
+                spent_amount: Money::rands(0),
                 currency: "ZAR".to_string(),
                 fiscal_year_start: "2025-04-01".to_string(),
                 fiscal_year_end: "2026-03-31".to_string(),
@@ -376,24 +415,39 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                 },
             ],
             approval_workflow: vec![
-                PlanApprovalStep {
+                ApprovalStep {
                     step: 1,
                     role: "Facilities Manager".to_string(),
                     approver: Some("Grace Nkosi".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U004".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-03-20".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
                 },
-                PlanApprovalStep {
+                ApprovalStep {
                     step: 2,
                     role: "CFO".to_string(),
                     approver: Some("Nomvula Zulu".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: Some("U005".to_string()),
+                    status: StepStatus::Approved,
                     date: Some("2025-03-25".to_string()),
                     comments: Some("Proceed with Q1 priorities".to_string()),
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
                 },
             ],
             owner: "Grace Nkosi".to_string(),
+            owner_id: "U004".to_string(),
             owner_email: "grace.nkosi@gov.za".to_string(),
             created_at: "2025-03-01T09:00:00Z".to_string(),
             updated_at: "2025-03-25T16:00:00Z".to_string(),
@@ -421,8 +475,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Vehicle Procurement".to_string(),
                     code: "FLT-VEH".to_string(),
                     description: Some("New vehicle purchases".to_string()),
-                    allocated_budget: 25_000_000.0,
-                    spent_amount: 0.0,
+                    allocated_budget: Money::rands(25_000_000),
+                    spent_amount: Money::rands(0),
                     planned_tenders: 3,
                     completed_tenders: 0,
                     priority: CategoryPriority::High,
@@ -431,34 +485,49 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                 },
             ],
             budget: BudgetAllocation {
-                total_budget: 35_000_000.0,
-                allocated_amount: 25_000_000.0,
-                committed_amount: 0.0,
-                spent_amount: 0.0,
+                total_budget: Money::rands(35_000_000),
+                allocated_amount: Money::rands(25_000_000),
+                committed_amount: Money::rands(0),
+                spent_amount: Money::rands(0),
                 currency: "ZAR".to_string(),
                 fiscal_year_start: "2025-04-01".to_string(),
                 fiscal_year_end: "2026-03-31".to_string(),
             },
             timeline: vec![],
             approval_workflow: vec![
-                PlanApprovalStep {
+                ApprovalStep {
                     step: 1,
                     role: "Transport Manager".to_string(),
                     approver: Some("Samuel Mthembu".to_string()),
-                    status: "approved".to_string(),
+                    approver_id: None,
+                    status: StepStatus::Approved,
                     date: Some("2025-03-10".to_string()),
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
                 },
-                PlanApprovalStep {
+                ApprovalStep {
                     step: 2,
                     role: "CFO".to_string(),
                     approver: None,
-                    status: "pending".to_string(),
+                    approver_id: None,
+                    status: StepStatus::Pending,
                     date: None,
                     comments: None,
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
                 },
             ],
             owner: "Samuel Mthembu".to_string(),
+            owner_id: "U012".to_string(),
             owner_email: "samuel.mthembu@gov.za".to_string(),
             created_at: "2025-03-05T11:00:00Z".to_string(),
             updated_at: "2025-03-10T14:00:00Z".to_string(),
@@ -485,8 +554,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Leadership Training".to_string(),
                     code: "TRN-LDR".to_string(),
                     description: Some("Executive and management development".to_string()),
-                    allocated_budget: 3_500_000.0,
-                    spent_amount: 0.0,
+                    allocated_budget: Money::rands(3_500_000),
+                    spent_amount: Money::rands(0),
                     planned_tenders: 2,
                     completed_tenders: 0,
                     priority: CategoryPriority::Medium,
@@ -498,8 +567,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "Technical Skills".to_string(),
                     code: "TRN-TEC".to_string(),
                     description: Some("IT, finance, and specialized training".to_string()),
-                    allocated_budget: 4_500_000.0,
-                    spent_amount: 0.0,
+                    allocated_budget: Money::rands(4_500_000),
+                    spent_amount: Money::rands(0),
                     planned_tenders: 4,
                     completed_tenders: 0,
                     priority: CategoryPriority::High,
@@ -508,10 +577,10 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                 },
             ],
             budget: BudgetAllocation {
-                total_budget: 10_000_000.0,
-                allocated_amount: 8_000_000.0,
-                committed_amount: 0.0,
-                spent_amount: 0.0,
+                total_budget: Money::rands(10_000_000),
+                allocated_amount: Money::rands(8_000_000),
+                committed_amount: Money::rands(0),
+                spent_amount: Money::rands(0),
                 currency: "ZAR".to_string(),
                 fiscal_year_start: "2025-04-01".to_string(),
                 fiscal_year_end: "2026-03-31".to_string(),
@@ -519,6 +588,7 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
             timeline: vec![],
             approval_workflow: vec![],
             owner: "Patricia Venter".to_string(),
+            owner_id: "U013".to_string(),
             owner_email: "patricia.venter@gov.za".to_string(),
             created_at: "2025-03-15T10:00:00Z".to_string(),
             updated_at: "2025-03-15T10:00:00Z".to_string(),
@@ -545,8 +615,8 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                     name: "General Procurement".to_string(),
                     code: "GEN-PRO".to_string(),
                     description: Some("All categories".to_string()),
-                    allocated_budget: 95_000_000.0,
-                    spent_amount: 92_500_000.0,
+                    allocated_budget: Money::rands(95_000_000),
+                    spent_amount: Money::rands(92_500_000),
                     planned_tenders: 45,
                     completed_tenders: 43,
                     priority: CategoryPriority::High,
@@ -555,10 +625,10 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
                 },
             ],
             budget: BudgetAllocation {
-                total_budget: 100_000_000.0,
-                allocated_amount: 95_000_000.0,
-                committed_amount: 0.0,
-                spent_amount: 92_500_000.0,
+                total_budget: Money::rands(100_000_000),
+                allocated_amount: Money::rands(95_000_000),
+                committed_amount: Money::rands(0),
+                spent_amount: Money::rands(92_500_000),
                 currency: "ZAR".to_string(),
                 fiscal_year_start: "2024-04-01".to_string(),
                 fiscal_year_end: "2025-03-31".to_string(),
@@ -566,6 +636,7 @@ pub fn load_mock_data(store: &SourcingPlanStore) {
             timeline: vec![],
             approval_workflow: vec![],
             owner: "Sipho Dlamini".to_string(),
+            owner_id: "U022".to_string(),
             owner_email: "sipho.dlamini@gov.za".to_string(),
             created_at: "2024-02-01T10:00:00Z".to_string(),
             updated_at: "2025-03-31T23:59:59Z".to_string(),
@@ -1,6 +1,10 @@
 //! Sourcing Plan domain types
 
 use serde::{Deserialize, Serialize};
+use crate::features::workflow::types::ApprovalStep;
+use crate::util::i18n::t;
+use crate::util::money::Money;
+use crate::util::reference;
 
 /// Sourcing Plan status
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -26,14 +30,14 @@ impl SourcingPlanStatus {
     }
 
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             SourcingPlanStatus::Draft => "Draft",
             SourcingPlanStatus::UnderReview => "Under Review",
             SourcingPlanStatus::Approved => "Approved",
             SourcingPlanStatus::Active => "Active",
             SourcingPlanStatus::Completed => "Completed",
             SourcingPlanStatus::Cancelled => "Cancelled",
-        }
+        })
     }
 
     pub fn from_str(s: &str) -> Self {
@@ -56,8 +60,8 @@ pub struct ProcurementCategory {
     pub name: String,
     pub code: String,
     pub description: Option<String>,
-    pub allocated_budget: f64,
-    pub spent_amount: f64,
+    pub allocated_budget: Money,
+    pub spent_amount: Money,
     pub planned_tenders: u32,
     pub completed_tenders: u32,
     pub priority: CategoryPriority,
@@ -68,12 +72,12 @@ pub struct ProcurementCategory {
 impl ProcurementCategory {
     pub fn new() -> Self {
         Self {
-            id: format!("CAT-{}", uuid_short()),
+            id: reference::unique_id("CAT"),
             name: String::new(),
             code: String::new(),
             description: None,
-            allocated_budget: 0.0,
-            spent_amount: 0.0,
+            allocated_budget: Money::ZERO,
+            spent_amount: Money::ZERO,
             planned_tenders: 0,
             completed_tenders: 0,
             priority: CategoryPriority::Medium,
@@ -82,16 +86,12 @@ impl ProcurementCategory {
         }
     }
 
-    pub fn remaining_budget(&self) -> f64 {
+    pub fn remaining_budget(&self) -> Money {
         self.allocated_budget - self.spent_amount
     }
 
     pub fn utilization_percentage(&self) -> f64 {
-        if self.allocated_budget > 0.0 {
-            (self.spent_amount / self.allocated_budget) * 100.0
-        } else {
-            0.0
-        }
+        self.spent_amount.percent_of(self.allocated_budget)
     }
 }
 
@@ -115,12 +115,12 @@ impl CategoryPriority {
     }
 
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             CategoryPriority::Low => "Low",
             CategoryPriority::Medium => "Medium",
             CategoryPriority::High => "High",
             CategoryPriority::Critical => "Critical",
-        }
+        })
     }
 }
 
@@ -139,7 +139,7 @@ pub struct TimelineMilestone {
 impl TimelineMilestone {
     pub fn new() -> Self {
         Self {
-            id: format!("MS-{}", uuid_short()),
+            id: reference::unique_id("MS"),
             name: String::new(),
             description: None,
             planned_date: String::new(),
@@ -172,23 +172,23 @@ impl MilestoneStatus {
     }
 
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             MilestoneStatus::Pending => "Pending",
             MilestoneStatus::InProgress => "In Progress",
             MilestoneStatus::Completed => "Completed",
             MilestoneStatus::Delayed => "Delayed",
             MilestoneStatus::Skipped => "Skipped",
-        }
+        })
     }
 }
 
 /// Budget allocation within a sourcing plan
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BudgetAllocation {
-    pub total_budget: f64,
-    pub allocated_amount: f64,
-    pub committed_amount: f64,
-    pub spent_amount: f64,
+    pub total_budget: Money,
+    pub allocated_amount: Money,
+    pub committed_amount: Money,
+    pub spent_amount: Money,
     pub currency: String,
     pub fiscal_year_start: String,
     pub fiscal_year_end: String,
@@ -197,10 +197,10 @@ pub struct BudgetAllocation {
 impl Default for BudgetAllocation {
     fn default() -> Self {
         Self {
-            total_budget: 0.0,
-            allocated_amount: 0.0,
-            committed_amount: 0.0,
-            spent_amount: 0.0,
+            total_budget: Money::ZERO,
+            allocated_amount: Money::ZERO,
+            committed_amount: Money::ZERO,
+            spent_amount: Money::ZERO,
             currency: "ZAR".to_string(),
             fiscal_year_start: String::new(),
             fiscal_year_end: String::new(),
@@ -209,30 +209,15 @@ impl Default for BudgetAllocation {
 }
 
 impl BudgetAllocation {
-    pub fn available_budget(&self) -> f64 {
+    pub fn available_budget(&self) -> Money {
         self.total_budget - self.committed_amount - self.spent_amount
     }
 
     pub fn utilization_percentage(&self) -> f64 {
-        if self.total_budget > 0.0 {
-            ((self.committed_amount + self.spent_amount) / self.total_budget) * 100.0
-        } else {
-            0.0
-        }
+        (self.committed_amount + self.spent_amount).percent_of(self.total_budget)
     }
 }
 
-/// Approval step for sourcing plan
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct PlanApprovalStep {
-    pub step: u32,
-    pub role: String,
-    pub approver: Option<String>,
-    pub status: String,
-    pub date: Option<String>,
-    pub comments: Option<String>,
-}
-
 /// Complete Sourcing Plan entity
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SourcingPlan {
@@ -245,8 +230,11 @@ pub struct SourcingPlan {
     pub categories: Vec<ProcurementCategory>,
     pub budget: BudgetAllocation,
     pub timeline: Vec<TimelineMilestone>,
-    pub approval_workflow: Vec<PlanApprovalStep>,
+    pub approval_workflow: Vec<ApprovalStep>,
     pub owner: String,
+    /// Id of the plan's owner
+    #[serde(default)]
+    pub owner_id: String,
     pub owner_email: String,
     pub created_at: String,
     pub updated_at: String,
@@ -272,6 +260,7 @@ impl Default for SourcingPlan {
             timeline: Vec::new(),
             approval_workflow: Vec::new(),
             owner: String::new(),
+            owner_id: String::new(),
             owner_email: String::new(),
             created_at: String::new(),
             updated_at: String::new(),
@@ -324,8 +313,8 @@ pub struct SourcingPlanFilter {
     pub status: Option<SourcingPlanStatus>,
     pub fiscal_year: Option<String>,
     pub department: Option<String>,
-    pub min_budget: Option<f64>,
-    pub max_budget: Option<f64>,
+    pub min_budget: Option<Money>,
+    pub max_budget: Option<Money>,
 }
 
 /// Pagination state
@@ -357,13 +346,3 @@ impl PaginationState {
         }
     }
 }
-
-/// Helper to generate short UUID
-fn uuid_short() -> String {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let time = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_millis();
-    format!("{:x}", time)
-}
@@ -17,6 +17,7 @@ use crate::util::format::{format_currency, format_date};
 use super::store::{SupplierPortalStore, load_mock_portal_data};
 use super::types::{OpportunityStatus, BidSubmissionStatus, ContractAwardStatus};
 use super::service;
+use crate::util::i18n::{t, tn};
 
 /// Portal Dashboard component
 #[component]
@@ -71,13 +72,13 @@ pub fn portal_dashboard() -> View {
 
     // Pre-compute submissions table data (must be outside view! macro for type inference)
     let submissions_columns = vec![
-        DataTableColumn { key: "ref".to_string(), label: "Reference".to_string(), width: None, align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "title".to_string(), label: "Tender Title".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "price".to_string(), label: "Bid Price".to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "docs".to_string(), label: "Documents".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "score".to_string(), label: "Score".to_string(), width: None, align: Some("right".to_string()), cell_class: None },
-        DataTableColumn { key: "actions".to_string(), label: "Actions".to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "ref".to_string(), label: t("Reference").to_string(), width: None, align: None, cell_class: Some("id-cell".to_string()) },
+        DataTableColumn { key: "title".to_string(), label: t("Tender Title").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "price".to_string(), label: t("Bid Price").to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "docs".to_string(), label: t("Documents").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "score".to_string(), label: t("Score").to_string(), width: None, align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "actions".to_string(), label: t("Actions").to_string(), width: None, align: None, cell_class: None },
     ];
 
     let submissions_rows: Vec<DataTableRow> = submissions.get().iter().map(|sub| {
@@ -90,7 +91,7 @@ pub fn portal_dashboard() -> View {
         };
 
         // Pre-compute values to avoid type inference issues in view! macro
-        let price_display = if sub.total_price > 0.0 { format_currency(sub.total_price) } else { "-".to_string() };
+        let price_display = if sub.total_price.is_positive() { format_currency(sub.total_price) } else { "-".to_string() };
         let score_display = if let Some(score) = sub.total_score { format!("{:.1}%", score) } else { "-".to_string() };
         let progress_style = format!("width: {}%", doc_progress);
         let docs_display = format!("{}/{}", sub.documents_uploaded, sub.documents_required);
@@ -114,9 +115,9 @@ pub fn portal_dashboard() -> View {
                 view! { <span>{score_display}</span> },
                 view! {
                     <div style="display: flex; gap: 4px;">
-                        <button class="btn btn-sm btn-secondary">"View"</button>
+                        <button class="btn btn-sm btn-secondary">{t("View")}</button>
                         if is_draft {
-                            <button class="btn btn-sm btn-primary">"Edit"</button>
+                            <button class="btn btn-sm btn-primary">{t("Edit")}</button>
                         }
                     </div>
                 },
@@ -126,13 +127,13 @@ pub fn portal_dashboard() -> View {
 
     // Pre-compute awards table data
     let awards_columns = vec![
-        DataTableColumn { key: "contract".to_string(), label: "Contract No.".to_string(), width: None, align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "title".to_string(), label: "Title".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "value".to_string(), label: "Value".to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "period".to_string(), label: "Contract Period".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "next".to_string(), label: "Next Milestone".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "actions".to_string(), label: "Actions".to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "contract".to_string(), label: t("Contract No.").to_string(), width: None, align: None, cell_class: Some("id-cell".to_string()) },
+        DataTableColumn { key: "title".to_string(), label: t("Title").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "value".to_string(), label: t("Value").to_string(), width: None, align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "period".to_string(), label: t("Contract Period").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "next".to_string(), label: t("Next Milestone").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "actions".to_string(), label: t("Actions").to_string(), width: None, align: None, cell_class: None },
     ];
 
     let awards_rows: Vec<DataTableRow> = awards.get().iter().map(|award| {
@@ -155,8 +156,8 @@ pub fn portal_dashboard() -> View {
                 view! { <span>{next_milestone_display}</span> },
                 view! {
                     <div style="display: flex; gap: 4px;">
-                        <button class="btn btn-sm btn-secondary">"View"</button>
-                        <button class="btn btn-sm btn-secondary">"Documents"</button>
+                        <button class="btn btn-sm btn-secondary">{t("View")}</button>
+                        <button class="btn btn-sm btn-secondary">{t("Documents")}</button>
                     </div>
                 },
             ],
@@ -181,10 +182,10 @@ pub fn portal_dashboard() -> View {
         .map(|opp| {
             let days_class = if opp.days_remaining <= 3 { "urgent" } else if opp.days_remaining <= 7 { "soon" } else { "normal" };
             let days_badge_class = format!("days-badge {}", days_class);
-            let days_text = format!("{} days left", opp.days_remaining);
+            let days_text = tn("{n} day left", "{n} days left", opp.days_remaining as i64);
             let est_value = format_currency(opp.estimated_value);
             let closing_date = format_date(&opp.closing_date);
-            let doc_count = format!("{} files", opp.document_count);
+            let doc_count = tn("{n} file", "{n} files", opp.document_count as i64);
             let show_start_bid = opp.status != OpportunityStatus::BidSubmitted;
             let show_briefing = opp.mandatory_briefing && opp.briefing_date.is_some();
             let status_class = opp.status.css_class();
@@ -204,30 +205,30 @@ pub fn portal_dashboard() -> View {
                     </div>
                     <div class="opportunity-meta">
                         <div class="opportunity-meta-item">
-                            <span class="opportunity-meta-label">"Category"</span>
+                            <span class="opportunity-meta-label">{t("Category")}</span>
                             <span class="opportunity-meta-value">{opp.category.clone()}</span>
                         </div>
                         <div class="opportunity-meta-item">
-                            <span class="opportunity-meta-label">"Est. Value"</span>
+                            <span class="opportunity-meta-label">{t("Est. Value")}</span>
                             <span class="opportunity-meta-value">{est_value}</span>
                         </div>
                         <div class="opportunity-meta-item">
-                            <span class="opportunity-meta-label">"Closing Date"</span>
+                            <span class="opportunity-meta-label">{t("Closing Date")}</span>
                             <span class="opportunity-meta-value">{closing_date}</span>
                         </div>
                         <div class="opportunity-meta-item">
-                            <span class="opportunity-meta-label">"Documents"</span>
+                            <span class="opportunity-meta-label">{t("Documents")}</span>
                             <span class="opportunity-meta-value">{doc_count}</span>
                         </div>
                     </div>
                     <div class="opportunity-actions">
-                        <button class="btn btn-sm btn-secondary">"View Details"</button>
-                        <button class="btn btn-sm btn-secondary">"Download Docs"</button>
+                        <button class="btn btn-sm btn-secondary">{t("View Details")}</button>
+                        <button class="btn btn-sm btn-secondary">{t("Download Docs")}</button>
                         if show_start_bid {
-                            <button class="btn btn-sm btn-primary">"Start Bid"</button>
+                            <button class="btn btn-sm btn-primary">{t("Start Bid")}</button>
                         }
                         if show_briefing {
-                            <button class="btn btn-sm btn-accent">"Register for Briefing"</button>
+                            <button class="btn btn-sm btn-accent">{t("Register for Briefing")}</button>
                         }
                     </div>
                 </div>
@@ -256,7 +257,7 @@ pub fn portal_dashboard() -> View {
 
     // Pre-compute KPI display values
     let kpi_open_opportunities = kpis.get().open_opportunities.to_string();
-    let kpi_closing_soon = format!("{} closing soon", kpis.get().closing_soon);
+    let kpi_closing_soon = tn("{n} closing soon", "{n} closing soon", kpis.get().closing_soon as i64);
     let kpi_active_bids = kpis.get().active_bids.to_string();
     let kpi_awarded_contracts = kpis.get().awarded_contracts.to_string();
     let kpi_total_contract_value = format_currency(kpis.get().total_contract_value);
@@ -265,7 +266,7 @@ pub fn portal_dashboard() -> View {
 
     // Pre-compute notification banner message
     let unread = unread_count.get();
-    let notification_banner_msg = format!("You have {} unread notification{}", unread, if unread == 1 { "" } else { "s" });
+    let notification_banner_msg = tn("You have {n} unread notification", "You have {n} unread notifications", unread as i64);
     let has_unread = unread > 0;
     let contract_utilization: f64 = 28.0;
 
@@ -500,11 +501,11 @@ pub fn portal_dashboard() -> View {
 
         <div class="portal-dashboard" data-testid="portal-dashboard">
             {page_header(
-                "Supplier Portal".to_string(),
-                Some("Manage your tenders, bids, and contracts".to_string()),
+                t("Supplier Portal").to_string(),
+                Some(t("Manage your tenders, bids, and contracts").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"My Profile"</button> },
-                    view! { <button class="btn btn-primary">"Browse Opportunities"</button> },
+                    view! { <button class="btn btn-secondary">{t("My Profile")}</button> },
+                    view! { <button class="btn btn-primary">{t("Browse Opportunities")}</button> },
                 ]
             )}
 
@@ -520,7 +521,7 @@ pub fn portal_dashboard() -> View {
             // KPI Cards
             <div class="kpi-grid">
                 {kpi_card(
-                    "Open Opportunities".to_string(),
+                    t("Open Opportunities").to_string(),
                     kpi_open_opportunities.clone(),
                     KpiColor::Blue,
                     icon_briefcase.to_string(),
@@ -528,27 +529,27 @@ pub fn portal_dashboard() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Active Bids".to_string(),
+                    t("Active Bids").to_string(),
                     kpi_active_bids.clone(),
                     KpiColor::Orange,
                     icon_file.to_string(),
-                    Some(KpiDelta { value: "2 under evaluation".to_string(), is_positive: None, suffix: "".to_string() }),
+                    Some(KpiDelta { value: tn("{n} under evaluation", "{n} under evaluation", 2), is_positive: None, suffix: "".to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Awarded Contracts".to_string(),
+                    t("Awarded Contracts").to_string(),
                     kpi_awarded_contracts.clone(),
                     KpiColor::Green,
                     icon_award.to_string(),
-                    Some(KpiDelta { value: kpi_total_contract_value.clone(), is_positive: None, suffix: "total value".to_string() }),
+                    Some(KpiDelta { value: kpi_total_contract_value.clone(), is_positive: None, suffix: t("total value").to_string() }),
                     None
                 )}
                 {kpi_card(
-                    "Success Rate".to_string(),
+                    t("Success Rate").to_string(),
                     kpi_success_rate.clone(),
                     KpiColor::Purple,
                     icon_percent.to_string(),
-                    Some(KpiDelta { value: "5%".to_string(), is_positive: Some(true), suffix: "from last year".to_string() }),
+                    Some(KpiDelta { value: "5%".to_string(), is_positive: Some(true), suffix: t("from last year").to_string() }),
                     None
                 )}
             </div>
@@ -559,21 +560,21 @@ pub fn portal_dashboard() -> View {
                     class={if active_tab.get() == "opportunities" { "portal-tab active" } else { "portal-tab" }}
                     on:click={let set_tab = set_tab.clone(); move || set_tab.call("opportunities".to_string())}
                 >
-                    "Opportunities"
+                    {t("Opportunities")}
                     <span class="badge">{open_opportunities_count.to_string()}</span>
                 </button>
                 <button
                     class={if active_tab.get() == "submissions" { "portal-tab active" } else { "portal-tab" }}
                     on:click={let set_tab = set_tab.clone(); move || set_tab.call("submissions".to_string())}
                 >
-                    "My Submissions"
+                    {t("My Submissions")}
                     <span class="badge">{submissions_count.to_string()}</span>
                 </button>
                 <button
                     class={if active_tab.get() == "awards" { "portal-tab active" } else { "portal-tab" }}
                     on:click={let set_tab = set_tab.clone(); move || set_tab.call("awards".to_string())}
                 >
-                    "Contract Awards"
+                    {t("Contract Awards")}
                     <span class="badge">{active_awards_count.to_string()}</span>
                 </button>
             </div>
@@ -589,8 +590,8 @@ pub fn portal_dashboard() -> View {
 
                     // Notifications sidebar
                     {panel(
-                        "Notifications".to_string(),
-                        vec![view! { <button class="btn btn-sm btn-secondary">"Mark All Read"</button> }],
+                        t("Notifications").to_string(),
+                        vec![view! { <button class="btn btn-sm btn-secondary">{t("Mark All Read")}</button> }],
                         vec![view! {
                             <div class="notification-list">
                                 for item in notification_items.iter() {
@@ -605,10 +606,10 @@ pub fn portal_dashboard() -> View {
             // Tab Content - Submissions
             <div class={if active_tab.get() == "submissions" { "tab-content active" } else { "tab-content" }}>
                 {panel(
-                    "My Bid Submissions".to_string(),
+                    t("My Bid Submissions").to_string(),
                     vec![
-                        view! { <button class="btn btn-sm btn-secondary">"Filter"</button> },
-                        view! { <button class="btn btn-sm btn-secondary">"Export"</button> },
+                        view! { <button class="btn btn-sm btn-secondary">{t("Filter")}</button> },
+                        view! { <button class="btn btn-sm btn-secondary">{t("Export")}</button> },
                     ],
                     vec![data_table(submissions_columns.clone(), submissions_rows.clone(), None)]
                 )}
@@ -617,10 +618,10 @@ pub fn portal_dashboard() -> View {
             // Tab Content - Awards
             <div class={if active_tab.get() == "awards" { "tab-content active" } else { "tab-content" }}>
                 {panel(
-                    "Contract Awards".to_string(),
+                    t("Contract Awards").to_string(),
                     vec![
-                        view! { <button class="btn btn-sm btn-secondary">"Filter"</button> },
-                        view! { <button class="btn btn-sm btn-secondary">"Export"</button> },
+                        view! { <button class="btn btn-sm btn-secondary">{t("Filter")}</button> },
+                        view! { <button class="btn btn-sm btn-secondary">{t("Export")}</button> },
                     ],
                     vec![data_table(awards_columns.clone(), awards_rows.clone(), None)]
                 )}
@@ -628,21 +629,21 @@ pub fn portal_dashboard() -> View {
                 // Contract summary cards
                 <div class="grid-2" style="margin-top: 24px;">
                     {panel(
-                        "Revenue Summary".to_string(),
+                        t("Revenue Summary").to_string(),
                         vec![],
                         vec![view! {
                             <div style="display: flex; flex-direction: column; gap: 16px;">
                                 <div style="display: flex; justify-content: space-between; align-items: center;">
-                                    <span style="color: var(--text-muted);">"YTD Revenue"</span>
+                                    <span style="color: var(--text-muted);">{t("YTD Revenue")}</span>
                                     <span style="font-size: 24px; font-weight: 600; color: var(--green);">{kpi_ytd_revenue.clone()}</span>
                                 </div>
                                 <div style="display: flex; justify-content: space-between; align-items: center;">
-                                    <span style="color: var(--text-muted);">"Total Contract Value"</span>
+                                    <span style="color: var(--text-muted);">{t("Total Contract Value")}</span>
                                     <span style="font-size: 18px; font-weight: 500;">{kpi_total_contract_value.clone()}</span>
                                 </div>
                                 <div>
                                     <div style="display: flex; justify-content: space-between; margin-bottom: 8px;">
-                                        <span style="font-size: 12px; color: var(--text-muted);">"Contract Utilization"</span>
+                                        <span style="font-size: 12px; color: var(--text-muted);">{t("Contract Utilization")}</span>
                                         <span style="font-size: 12px; color: var(--text-muted);">"28%"</span>
                                     </div>
                                     {progress_bar(contract_utilization, ProgressColor::Blue, false, None)}
@@ -652,7 +653,7 @@ pub fn portal_dashboard() -> View {
                     )}
 
                     {panel(
-                        "Upcoming Milestones".to_string(),
+                        t("Upcoming Milestones").to_string(),
                         vec![],
                         vec![timeline(milestone_items.clone(), None)]
                     )}
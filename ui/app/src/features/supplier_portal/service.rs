@@ -1,5 +1,10 @@
 //! Supplier Portal service - API calls
 
+use crate::util::api::ApiError;
+use crate::util::backend;
+use crate::util::money::Money;
+use crate::util::datetime::now_iso;
+use crate::util::reference;
 use super::store::{
     SupplierPortalStore, load_mock_portal_data,
     get_mock_opportunity, get_mock_submission, get_mock_award,
@@ -14,9 +19,7 @@ pub async fn load_portal_data(store: &SupplierPortalStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API
-    // For now, load mock data
-    load_mock_portal_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -26,9 +29,7 @@ pub async fn load_opportunities(store: &SupplierPortalStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API with filters
-    // For now, mock data is already loaded
-    load_mock_portal_data(store);
+    load_from_backend(store).await;
 
     store.loading.set(false);
 }
@@ -56,7 +57,7 @@ pub async fn load_submissions(store: &SupplierPortalStore) {
     // In production, this would call the API
     // Mock data is already loaded via load_portal_data
     if store.submissions.get().is_empty() {
-        load_mock_portal_data(store);
+        load_from_backend(store).await;
     }
 
     store.loading.set(false);
@@ -114,7 +115,7 @@ pub async fn create_bid_submission(
     }
 
     // Generate new bid ID
-    let new_id = format!("BID-2025-{:04}", rand_id());
+    let new_id = reference::unique_id("BID");
 
     // Create draft submission using extracted values
     let new_submission = BidSubmission {
@@ -123,7 +124,7 @@ pub async fn create_bid_submission(
         tender_reference: opp_reference_number,
         tender_title: opp_title,
         submitted_at: None,
-        total_price: 0.0,
+        total_price: Money::ZERO,
         currency: opp_currency,
         status: BidSubmissionStatus::Draft,
         technical_compliance: None,
@@ -133,8 +134,8 @@ pub async fn create_bid_submission(
         documents_uploaded: 0,
         documents_required: opp_document_count,
         notes: None,
-        created_at: chrono_now(),
-        updated_at: chrono_now(),
+        created_at: now_iso(),
+        updated_at: now_iso(),
     };
 
     // Add to submissions
@@ -215,7 +216,7 @@ pub async fn submit_bid(store: &SupplierPortalStore, submission_id: &str) -> Res
         return Err("Can only submit draft bids".to_string());
     }
 
-    if total_price <= 0.0 {
+    if !total_price.is_positive() {
         store.saving.set(false);
         return Err("Total price must be greater than zero".to_string());
     }
@@ -231,8 +232,8 @@ pub async fn submit_bid(store: &SupplierPortalStore, submission_id: &str) -> Res
     // Submit
     let mut updated = submissions[idx].clone();
     updated.status = BidSubmissionStatus::Submitted;
-    updated.submitted_at = Some(chrono_now());
-    updated.updated_at = chrono_now();
+    updated.submitted_at = Some(now_iso());
+    updated.updated_at = now_iso();
     submissions[idx] = updated.clone();
 
     store.submissions.set(submissions);
@@ -282,7 +283,7 @@ pub async fn withdraw_bid(store: &SupplierPortalStore, submission_id: &str, reas
     let mut updated = submissions[idx].clone();
     updated.status = BidSubmissionStatus::Withdrawn;
     updated.notes = Some(format!("Withdrawn: {}", reason));
-    updated.updated_at = chrono_now();
+    updated.updated_at = now_iso();
     submissions[idx] = updated.clone();
 
     store.submissions.set(submissions);
@@ -299,7 +300,7 @@ pub async fn load_awards(store: &SupplierPortalStore) {
 
     // In production, this would call the API
     if store.awards.get().is_empty() {
-        load_mock_portal_data(store);
+        load_from_backend(store).await;
     }
 
     store.loading.set(false);
@@ -351,12 +352,12 @@ pub async fn upload_document(
     }
 
     // Generate document ID
-    let doc_id = format!("DOC-{:06}", rand_id());
+    let doc_id = reference::unique_id("DOC");
 
     // Create document record
     let mut new_doc = document;
     new_doc.id = doc_id.clone();
-    new_doc.uploaded_at = chrono_now();
+    new_doc.uploaded_at = now_iso();
     new_doc.is_uploaded = true;
     new_doc.reference_id = submission_id.to_string();
     new_doc.reference_type = "bid".to_string();
@@ -370,7 +371,7 @@ pub async fn upload_document(
     let mut submissions = store.submissions.get();
     if let Some(idx) = submissions.iter().position(|s| s.id == submission_id) {
         submissions[idx].documents_uploaded += 1;
-        submissions[idx].updated_at = chrono_now();
+        submissions[idx].updated_at = now_iso();
         store.submissions.set(submissions);
     }
 
@@ -423,7 +424,7 @@ pub async fn delete_document(
             if submissions[idx].documents_uploaded > 0 {
                 submissions[idx].documents_uploaded -= 1;
             }
-            submissions[idx].updated_at = chrono_now();
+            submissions[idx].updated_at = now_iso();
             store.submissions.set(submissions);
         }
     }
@@ -487,14 +488,27 @@ pub async fn refresh_portal_data(store: &SupplierPortalStore) {
     load_portal_data(store).await;
 }
 
-// Helper functions
-fn rand_id() -> u32 {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let duration = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-    (duration.as_millis() % 1_000_000) as u32
-}
+/// Refresh supplier portal data from the configured backend
+async fn load_from_backend(store: &SupplierPortalStore) {
+    if backend::is_mock() {
+        load_mock_portal_data(store);
+        return;
+    }
+
+    let result = async {
+        backend::fetch_into("/portal/kpis", &store.kpis).await?;
+        backend::fetch_into("/portal/opportunities", &store.opportunities).await?;
+        backend::fetch_into("/portal/submissions", &store.submissions).await?;
+        backend::fetch_into("/portal/awards", &store.awards).await?;
+        backend::fetch_into("/portal/notifications", &store.notifications).await?;
+        Ok::<_, ApiError>(())
+    }
+    .await;
 
-fn chrono_now() -> String {
-    // In production, use chrono crate
-    "2025-02-27T10:00:00Z".to_string()
+    if let Err(e) = result {
+        store.error.set(Some(e.to_string()));
+    } else {
+        let unread = store.notifications.get().iter().filter(|n| !n.read).count() as u32;
+        store.unread_count.set(unread);
+    }
 }
@@ -1,6 +1,7 @@
 //! Supplier Portal store
 
 use components::prelude::*;
+use crate::util::money::Money;
 use super::types::{
     TenderOpportunity, BidSubmission, ContractAward, PortalDocument,
     PortalKpi, PortalNotification, OpportunityStatus, BidSubmissionStatus,
@@ -301,7 +302,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             title: "IT Infrastructure Upgrade - Data Center Modernization".to_string(),
             description: "Supply and installation of server infrastructure, storage solutions, and network equipment for data center upgrade project.".to_string(),
             category: "IT".to_string(),
-            estimated_value: 15_000_000.0,
+            estimated_value: Money::rands(15_000_000),
             currency: "ZAR".to_string(),
             publish_date: "2025-02-15".to_string(),
             closing_date: "2025-03-15".to_string(),
@@ -321,7 +322,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             title: "Office Furniture Supply - Regional Offices".to_string(),
             description: "Supply and delivery of office furniture including desks, chairs, and storage units for 5 regional offices.".to_string(),
             category: "Facilities".to_string(),
-            estimated_value: 2_500_000.0,
+            estimated_value: Money::rands(2_500_000),
             currency: "ZAR".to_string(),
             publish_date: "2025-02-20".to_string(),
             closing_date: "2025-03-05".to_string(),
@@ -341,7 +342,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             title: "Security Services - National Coverage".to_string(),
             description: "Provision of physical security services including access control, CCTV monitoring, and armed response for all premises nationally.".to_string(),
             category: "Security".to_string(),
-            estimated_value: 25_000_000.0,
+            estimated_value: Money::rands(25_000_000),
             currency: "ZAR".to_string(),
             publish_date: "2025-02-18".to_string(),
             closing_date: "2025-03-25".to_string(),
@@ -361,7 +362,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             title: "Staff Training Program - Leadership Development".to_string(),
             description: "Design and delivery of leadership development training program for senior management.".to_string(),
             category: "Training".to_string(),
-            estimated_value: 1_800_000.0,
+            estimated_value: Money::rands(1_800_000),
             currency: "ZAR".to_string(),
             publish_date: "2025-02-10".to_string(),
             closing_date: "2025-02-28".to_string(),
@@ -385,7 +386,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             tender_reference: "RFP/2025/TRAIN/087".to_string(),
             tender_title: "Staff Training Program - Leadership Development".to_string(),
             submitted_at: Some("2025-02-25T14:30:00Z".to_string()),
-            total_price: 1_650_000.0,
+            total_price: Money::rands(1_650_000),
             currency: "ZAR".to_string(),
             status: BidSubmissionStatus::UnderEvaluation,
             technical_compliance: Some(85.0),
@@ -404,7 +405,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             tender_reference: "RFQ/2025/IT/078".to_string(),
             tender_title: "Network Equipment Supply".to_string(),
             submitted_at: Some("2025-02-15T11:45:00Z".to_string()),
-            total_price: 3_200_000.0,
+            total_price: Money::rands(3_200_000),
             currency: "ZAR".to_string(),
             status: BidSubmissionStatus::Shortlisted,
             technical_compliance: Some(92.0),
@@ -423,7 +424,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             tender_reference: "RFT/2025/FAC/065".to_string(),
             tender_title: "Cleaning Services - Head Office".to_string(),
             submitted_at: Some("2025-02-01T09:00:00Z".to_string()),
-            total_price: 1_200_000.0,
+            total_price: Money::rands(1_200_000),
             currency: "ZAR".to_string(),
             status: BidSubmissionStatus::Awarded,
             technical_compliance: Some(95.0),
@@ -442,7 +443,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             tender_reference: "RFP/2025/IT/045".to_string(),
             tender_title: "Software Development Services".to_string(),
             submitted_at: Some("2025-01-20T15:00:00Z".to_string()),
-            total_price: 8_500_000.0,
+            total_price: Money::rands(8_500_000),
             currency: "ZAR".to_string(),
             status: BidSubmissionStatus::Unsuccessful,
             technical_compliance: Some(78.0),
@@ -461,7 +462,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             tender_reference: "RFP/2025/IT/089".to_string(),
             tender_title: "IT Infrastructure Upgrade - Data Center Modernization".to_string(),
             submitted_at: None,
-            total_price: 0.0,
+            total_price: Money::rands(0),
             currency: "ZAR".to_string(),
             status: BidSubmissionStatus::Draft,
             technical_compliance: None,
@@ -484,7 +485,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             tender_reference: "RFT/2025/FAC/065".to_string(),
             title: "Cleaning Services - Head Office".to_string(),
             description: "Provision of daily cleaning services for head office premises including consumables supply.".to_string(),
-            value: 1_200_000.0,
+            value: Money::rands(1_200_000),
             currency: "ZAR".to_string(),
             start_date: "2025-03-01".to_string(),
             end_date: "2026-02-28".to_string(),
@@ -495,8 +496,8 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             payment_terms: "Monthly in arrears, 30 days".to_string(),
             next_milestone: Some("Contract Signing".to_string()),
             next_milestone_date: Some("2025-02-28".to_string()),
-            total_invoiced: 0.0,
-            total_paid: 0.0,
+            total_invoiced: Money::rands(0),
+            total_paid: Money::rands(0),
             documents: Vec::new(),
         },
         ContractAward {
@@ -505,7 +506,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             tender_reference: "RFP/2024/IT/156".to_string(),
             title: "IT Support Services".to_string(),
             description: "Provision of IT support services including help desk, network support, and hardware maintenance.".to_string(),
-            value: 8_500_000.0,
+            value: Money::rands(8_500_000),
             currency: "ZAR".to_string(),
             start_date: "2024-07-01".to_string(),
             end_date: "2025-06-30".to_string(),
@@ -516,8 +517,8 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             payment_terms: "Monthly in arrears, 30 days".to_string(),
             next_milestone: Some("Q3 Review".to_string()),
             next_milestone_date: Some("2025-03-31".to_string()),
-            total_invoiced: 5_666_666.0,
-            total_paid: 4_958_333.0,
+            total_invoiced: Money::rands(5_666_666),
+            total_paid: Money::rands(4_958_333),
             documents: Vec::new(),
         },
         ContractAward {
@@ -526,7 +527,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             tender_reference: "RFT/2024/SEC/089".to_string(),
             title: "Security Services - Regional Offices".to_string(),
             description: "Provision of physical security services for 3 regional offices.".to_string(),
-            value: 4_200_000.0,
+            value: Money::rands(4_200_000),
             currency: "ZAR".to_string(),
             start_date: "2024-04-01".to_string(),
             end_date: "2025-03-31".to_string(),
@@ -537,8 +538,8 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             payment_terms: "Monthly in arrears, 30 days".to_string(),
             next_milestone: Some("Contract Renewal Decision".to_string()),
             next_milestone_date: Some("2025-02-28".to_string()),
-            total_invoiced: 3_850_000.0,
-            total_paid: 3_850_000.0,
+            total_invoiced: Money::rands(3_850_000),
+            total_paid: Money::rands(3_850_000),
             documents: Vec::new(),
         },
         ContractAward {
@@ -547,7 +548,7 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             tender_reference: "RFQ/2023/FAC/034".to_string(),
             title: "Office Supplies Framework Agreement".to_string(),
             description: "Supply of general office supplies and stationery on an as-needed basis.".to_string(),
-            value: 1_500_000.0,
+            value: Money::rands(1_500_000),
             currency: "ZAR".to_string(),
             start_date: "2023-07-01".to_string(),
             end_date: "2024-06-30".to_string(),
@@ -558,8 +559,8 @@ pub fn load_mock_portal_data(store: &SupplierPortalStore) {
             payment_terms: "30 days from invoice".to_string(),
             next_milestone: None,
             next_milestone_date: None,
-            total_invoiced: 1_450_000.0,
-            total_paid: 1_450_000.0,
+            total_invoiced: Money::rands(1_450_000),
+            total_paid: Money::rands(1_450_000),
             documents: Vec::new(),
         },
     ]);
@@ -632,7 +633,7 @@ pub fn get_mock_opportunity(id: &str) -> Option<TenderOpportunity> {
             title: "IT Infrastructure Upgrade - Data Center Modernization".to_string(),
             description: "Supply and installation of server infrastructure, storage solutions, and network equipment for data center upgrade project.".to_string(),
             category: "IT".to_string(),
-            estimated_value: 15_000_000.0,
+            estimated_value: Money::rands(15_000_000),
             currency: "ZAR".to_string(),
             publish_date: "2025-02-15".to_string(),
             closing_date: "2025-03-15".to_string(),
@@ -660,7 +661,7 @@ pub fn get_mock_submission(id: &str) -> Option<BidSubmission> {
             tender_reference: "RFP/2025/TRAIN/087".to_string(),
             tender_title: "Staff Training Program - Leadership Development".to_string(),
             submitted_at: Some("2025-02-25T14:30:00Z".to_string()),
-            total_price: 1_650_000.0,
+            total_price: Money::rands(1_650_000),
             currency: "ZAR".to_string(),
             status: BidSubmissionStatus::UnderEvaluation,
             technical_compliance: Some(85.0),
@@ -687,7 +688,7 @@ pub fn get_mock_award(id: &str) -> Option<ContractAward> {
             tender_reference: "RFT/2025/FAC/065".to_string(),
             title: "Cleaning Services - Head Office".to_string(),
             description: "Provision of daily cleaning services for head office premises including consumables supply.".to_string(),
-            value: 1_200_000.0,
+            value: Money::rands(1_200_000),
             currency: "ZAR".to_string(),
             start_date: "2025-03-01".to_string(),
             end_date: "2026-02-28".to_string(),
@@ -698,8 +699,8 @@ pub fn get_mock_award(id: &str) -> Option<ContractAward> {
             payment_terms: "Monthly in arrears, 30 days".to_string(),
             next_milestone: Some("Contract Signing".to_string()),
             next_milestone_date: Some("2025-02-28".to_string()),
-            total_invoiced: 0.0,
-            total_paid: 0.0,
+            total_invoiced: Money::rands(0),
+            total_paid: Money::rands(0),
             documents: Vec::new(),
         },
     ];
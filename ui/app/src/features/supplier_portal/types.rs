@@ -1,6 +1,8 @@
 //! Supplier Portal domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::Money;
 
 /// Tender opportunity status from supplier perspective
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -19,13 +21,13 @@ pub enum OpportunityStatus {
 
 impl OpportunityStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             OpportunityStatus::Open => "Open",
             OpportunityStatus::ClosingSoon => "Closing Soon",
             OpportunityStatus::Closed => "Closed",
             OpportunityStatus::BidSubmitted => "Bid Submitted",
             OpportunityStatus::Shortlisted => "Shortlisted",
-        }
+        })
     }
 
     pub fn css_class(&self) -> &'static str {
@@ -47,7 +49,7 @@ pub struct TenderOpportunity {
     pub title: String,
     pub description: String,
     pub category: String,
-    pub estimated_value: f64,
+    pub estimated_value: Money,
     pub currency: String,
     pub publish_date: String,
     pub closing_date: String,
@@ -70,7 +72,7 @@ impl Default for TenderOpportunity {
             title: String::new(),
             description: String::new(),
             category: String::new(),
-            estimated_value: 0.0,
+            estimated_value: Money::ZERO,
             currency: "ZAR".to_string(),
             publish_date: String::new(),
             closing_date: String::new(),
@@ -112,7 +114,7 @@ pub enum BidSubmissionStatus {
 
 impl BidSubmissionStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             BidSubmissionStatus::Draft => "Draft",
             BidSubmissionStatus::Submitted => "Submitted",
             BidSubmissionStatus::UnderEvaluation => "Under Evaluation",
@@ -122,7 +124,7 @@ impl BidSubmissionStatus {
             BidSubmissionStatus::Awarded => "Awarded",
             BidSubmissionStatus::Unsuccessful => "Unsuccessful",
             BidSubmissionStatus::Withdrawn => "Withdrawn",
-        }
+        })
     }
 
     pub fn css_class(&self) -> &'static str {
@@ -148,7 +150,7 @@ pub struct BidSubmission {
     pub tender_reference: String,
     pub tender_title: String,
     pub submitted_at: Option<String>,
-    pub total_price: f64,
+    pub total_price: Money,
     pub currency: String,
     pub status: BidSubmissionStatus,
     pub technical_compliance: Option<f64>,
@@ -170,7 +172,7 @@ impl Default for BidSubmission {
             tender_reference: String::new(),
             tender_title: String::new(),
             submitted_at: None,
-            total_price: 0.0,
+            total_price: Money::ZERO,
             currency: "ZAR".to_string(),
             status: BidSubmissionStatus::Draft,
             technical_compliance: None,
@@ -203,13 +205,13 @@ pub enum ContractAwardStatus {
 
 impl ContractAwardStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             ContractAwardStatus::Notified => "Notified",
             ContractAwardStatus::AwaitingSignature => "Awaiting Signature",
             ContractAwardStatus::Active => "Active",
             ContractAwardStatus::Completed => "Completed",
             ContractAwardStatus::Terminated => "Terminated",
-        }
+        })
     }
 
     pub fn css_class(&self) -> &'static str {
@@ -231,7 +233,7 @@ pub struct ContractAward {
     pub tender_reference: String,
     pub title: String,
     pub description: String,
-    pub value: f64,
+    pub value: Money,
     pub currency: String,
     pub start_date: String,
     pub end_date: String,
@@ -242,8 +244,8 @@ pub struct ContractAward {
     pub payment_terms: String,
     pub next_milestone: Option<String>,
     pub next_milestone_date: Option<String>,
-    pub total_invoiced: f64,
-    pub total_paid: f64,
+    pub total_invoiced: Money,
+    pub total_paid: Money,
     pub documents: Vec<PortalDocument>,
 }
 
@@ -255,7 +257,7 @@ impl Default for ContractAward {
             tender_reference: String::new(),
             title: String::new(),
             description: String::new(),
-            value: 0.0,
+            value: Money::ZERO,
             currency: "ZAR".to_string(),
             start_date: String::new(),
             end_date: String::new(),
@@ -266,8 +268,8 @@ impl Default for ContractAward {
             payment_terms: String::new(),
             next_milestone: None,
             next_milestone_date: None,
-            total_invoiced: 0.0,
-            total_paid: 0.0,
+            total_invoiced: Money::ZERO,
+            total_paid: Money::ZERO,
             documents: Vec::new(),
         }
     }
@@ -312,7 +314,7 @@ pub enum PortalDocumentType {
 
 impl PortalDocumentType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             PortalDocumentType::TenderSpecification => "Tender Specification",
             PortalDocumentType::TermsConditions => "Terms & Conditions",
             PortalDocumentType::EvaluationCriteria => "Evaluation Criteria",
@@ -329,7 +331,7 @@ impl PortalDocumentType {
             PortalDocumentType::Invoice => "Invoice",
             PortalDocumentType::DeliveryNote => "Delivery Note",
             PortalDocumentType::Other => "Other",
-        }
+        })
     }
 }
 
@@ -375,8 +377,8 @@ pub struct OpportunityFilter {
     pub category: Option<String>,
     pub tender_type: Option<String>,
     pub status: Option<OpportunityStatus>,
-    pub min_value: Option<f64>,
-    pub max_value: Option<f64>,
+    pub min_value: Option<Money>,
+    pub max_value: Option<Money>,
     pub search_query: Option<String>,
     pub closing_within_days: Option<u32>,
 }
@@ -405,10 +407,10 @@ pub struct PortalKpi {
     pub closing_soon: u32,
     pub active_bids: u32,
     pub awarded_contracts: u32,
-    pub total_contract_value: f64,
+    pub total_contract_value: Money,
     pub pending_documents: u32,
     pub success_rate: f64,
-    pub ytd_revenue: f64,
+    pub ytd_revenue: Money,
 }
 
 impl Default for PortalKpi {
@@ -418,10 +420,10 @@ impl Default for PortalKpi {
             closing_soon: 3,
             active_bids: 5,
             awarded_contracts: 8,
-            total_contract_value: 45_000_000.0,
+            total_contract_value: Money::rands(45_000_000),
             pending_documents: 2,
             success_rate: 35.5,
-            ytd_revenue: 12_500_000.0,
+            ytd_revenue: Money::rands(12_500_000),
         }
     }
 }
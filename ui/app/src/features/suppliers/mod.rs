@@ -3,6 +3,8 @@
 pub mod types;
 pub mod store;
 pub mod service;
+pub mod search;
 pub mod supplier_registry;
 pub mod supplier_performance;
 pub mod supplier_risk;
+pub mod supplier_detail;
@@ -0,0 +1,50 @@
+//! Supplier search provider
+
+use crate::Route;
+use crate::util::i18n::t;
+use crate::features::search::provider::{SearchFuture, SearchProvider};
+use crate::features::search::types::{match_score, SearchEntity, SearchHit};
+use super::store::SuppliersStore;
+use super::service;
+
+/// Searches the suppliers held in the store, loading them on first use
+pub struct SupplierSearch {
+    store: SuppliersStore,
+}
+
+impl SupplierSearch {
+    pub fn new(store: SuppliersStore) -> Self {
+        Self { store }
+    }
+}
+
+impl SearchProvider for SupplierSearch {
+    fn entity(&self) -> SearchEntity {
+        SearchEntity::Supplier
+    }
+
+    fn search(&self, query: String) -> SearchFuture {
+        let store = self.store.clone();
+        Box::pin(async move {
+            if store.suppliers.get().is_empty() {
+                service::load_suppliers(&store).await;
+                if let Some(e) = store.error.get() {
+                    return Err(e);
+                }
+            }
+
+            let hits = store.suppliers.get().iter().filter_map(|s| {
+                let score = match_score(&query, &[&s.id, &s.registration_number, s.csd_number.as_deref().unwrap_or_default()], &[&s.name, s.trading_name.as_deref().unwrap_or_default()])?;
+                Some(SearchHit {
+                    entity: SearchEntity::Supplier,
+                    id: s.id.clone(),
+                    title: s.name.clone(),
+                    subtitle: format!("{} - {}", s.registration_number, s.csd_number.clone().unwrap_or_else(|| t("No CSD number").to_string())),
+                    route: Route::SuppliersDetail(s.id.clone()),
+                    score,
+                })
+            }).collect();
+            Ok(hits)
+        })
+    }
+}
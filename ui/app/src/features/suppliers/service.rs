@@ -1,17 +1,30 @@
 //! Suppliers service - API calls
 
+use crate::util::api::{self, HttpMethod};
+use crate::util::backend;
+use crate::util::datetime::now_iso;
+use crate::util::reference::unique_id;
+use crate::util::permissions::{self, Action};
 use super::store::{SuppliersStore, load_mock_data, select_supplier, clear_selection};
-use super::types::{Supplier, SupplierFilter, SupplierStatus, BbbeeLevel, RiskRating};
+use super::types::{Supplier, SupplierCertificate, SupplierFilter, SupplierStatus, BbbeeLevel, RiskRating};
 
 /// Load suppliers list
 pub async fn load_suppliers(store: &SuppliersStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API:
-    // let response = api::get("/api/suppliers", &store.filter.get()).await;
-    // For now, load mock data
-    load_mock_data(store);
+    if backend::is_mock() {
+        load_mock_data(store);
+    } else if let Err(e) = backend::fetch_into("/suppliers", &store.suppliers).await {
+        store.error.set(Some(e.to_string()));
+    } else if let Err(e) = backend::fetch_into("/suppliers/kpis", &store.kpis).await {
+        store.error.set(Some(e.to_string()));
+    } else {
+        let mut pagination = store.pagination.get();
+        pagination.total_items = store.suppliers.get().len() as u32;
+        pagination.total_pages = (pagination.total_items + pagination.page_size - 1) / pagination.page_size;
+        store.pagination.set(pagination);
+    }
 
     store.loading.set(false);
 }
@@ -27,11 +40,20 @@ pub async fn get_supplier(store: &SuppliersStore, supplier_id: &str) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::get(&format!("/api/suppliers/{}", supplier_id)).await;
-    // For now, find in mock data
+    // A deep link can land here before the registry has populated the store
+    if backend::is_mock() && store.suppliers.get().is_empty() {
+        load_mock_data(store);
+    }
+
     select_supplier(store, supplier_id);
 
+    if store.selected.get().is_none() && !backend::is_mock() {
+        match api::get::<Supplier>(&format!("/suppliers/{}", supplier_id)).await {
+            Ok(supplier) => store.selected.set(Some(supplier)),
+            Err(e) => store.error.set(Some(e.to_string())),
+        }
+    }
+
     store.loading.set(false);
 }
 
@@ -40,16 +62,22 @@ pub async fn create_supplier(store: &SuppliersStore, supplier: Supplier) -> Resu
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::post("/api/suppliers", &supplier).await;
-
-    // Generate a new ID for mock
+    // Provisional ID, replaced by the server's when using the REST backend
     let mut new_supplier = supplier;
     let count = store.suppliers.get().len() + 1;
     new_supplier.id = format!("SUP-{:03}", count);
     new_supplier.status = SupplierStatus::Pending;
     new_supplier.registered_at = "2025-02-15".to_string();
 
+    match backend::save(HttpMethod::Post, "/suppliers", &new_supplier).await {
+        Ok(saved) => new_supplier = saved,
+        Err(e) => {
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+
     // Add to list
     let mut suppliers = store.suppliers.get();
     suppliers.push(new_supplier.clone());
@@ -64,10 +92,17 @@ pub async fn update_supplier(store: &SuppliersStore, supplier: Supplier) -> Resu
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::put(&format!("/api/suppliers/{}", supplier.id), &supplier).await;
+    let mut supplier = supplier;
+    let endpoint = format!("/suppliers/{}", supplier.id);
+    match backend::save(HttpMethod::Put, &endpoint, &supplier).await {
+        Ok(saved) => supplier = saved,
+        Err(e) => {
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
 
-    // Update in mock list
     let mut suppliers = store.suppliers.get();
     if let Some(pos) = suppliers.iter().position(|s| s.id == supplier.id) {
         suppliers[pos] = supplier.clone();
@@ -87,13 +122,19 @@ pub async fn verify_supplier(store: &SuppliersStore, supplier_id: &str) -> Resul
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::post(&format!("/api/suppliers/{}/verify", supplier_id), &{}).await;
-
     let mut suppliers = store.suppliers.get();
     if let Some(pos) = suppliers.iter().position(|s| s.id == supplier_id) {
         suppliers[pos].status = SupplierStatus::Active;
         suppliers[pos].verified_at = Some("2025-02-15".to_string());
+        let endpoint = format!("/suppliers/{}/verify", supplier_id);
+        match backend::save(HttpMethod::Post, &endpoint, &suppliers[pos]).await {
+            Ok(saved) => suppliers[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.suppliers.set(suppliers.clone());
         store.selected.set(Some(suppliers[pos].clone()));
         store.loading.set(false);
@@ -110,12 +151,18 @@ pub async fn suspend_supplier(store: &SuppliersStore, supplier_id: &str, reason:
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::post(&format!("/api/suppliers/{}/suspend", supplier_id), &{ reason }).await;
-
     let mut suppliers = store.suppliers.get();
     if let Some(pos) = suppliers.iter().position(|s| s.id == supplier_id) {
         suppliers[pos].status = SupplierStatus::Suspended;
+        let endpoint = format!("/suppliers/{}/suspend", supplier_id);
+        match backend::save(HttpMethod::Post, &endpoint, &suppliers[pos]).await {
+            Ok(saved) => suppliers[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.suppliers.set(suppliers.clone());
         store.selected.set(Some(suppliers[pos].clone()));
         store.loading.set(false);
@@ -140,6 +187,15 @@ pub async fn reactivate_supplier(store: &SuppliersStore, supplier_id: &str) -> R
             return Err("Supplier is not suspended".to_string());
         }
         suppliers[pos].status = SupplierStatus::Active;
+        let endpoint = format!("/suppliers/{}/reactivate", supplier_id);
+        match backend::save(HttpMethod::Post, &endpoint, &suppliers[pos]).await {
+            Ok(saved) => suppliers[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.suppliers.set(suppliers.clone());
         store.selected.set(Some(suppliers[pos].clone()));
         store.loading.set(false);
@@ -153,12 +209,26 @@ pub async fn reactivate_supplier(store: &SuppliersStore, supplier_id: &str) -> R
 
 /// Blacklist supplier
 pub async fn blacklist_supplier(store: &SuppliersStore, supplier_id: &str, reason: &str) -> Result<(), String> {
+    if let Err(e) = permissions::require(Action::BlacklistSupplier) {
+        store.error.set(Some(e.clone()));
+        return Err(e);
+    }
+
     store.loading.set(true);
     store.error.set(None);
 
     let mut suppliers = store.suppliers.get();
     if let Some(pos) = suppliers.iter().position(|s| s.id == supplier_id) {
         suppliers[pos].status = SupplierStatus::Blacklisted;
+        let endpoint = format!("/suppliers/{}/blacklist", supplier_id);
+        match backend::save(HttpMethod::Post, &endpoint, &suppliers[pos]).await {
+            Ok(saved) => suppliers[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.suppliers.set(suppliers.clone());
         store.selected.set(Some(suppliers[pos].clone()));
         store.loading.set(false);
@@ -192,6 +262,15 @@ pub async fn update_performance(
         suppliers[pos].performance_score.responsiveness = responsiveness;
         suppliers[pos].performance_score.compliance = compliance;
         suppliers[pos].performance_score.overall = overall;
+        let endpoint = format!("/suppliers/{}/performance", supplier_id);
+        match backend::save(HttpMethod::Post, &endpoint, &suppliers[pos]).await {
+            Ok(saved) => suppliers[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.suppliers.set(suppliers.clone());
         store.selected.set(Some(suppliers[pos].clone()));
         store.loading.set(false);
@@ -217,6 +296,15 @@ pub async fn update_risk_rating(
     if let Some(pos) = suppliers.iter().position(|s| s.id == supplier_id) {
         suppliers[pos].risk_rating = risk_rating;
         suppliers[pos].risk_score = risk_score;
+        let endpoint = format!("/suppliers/{}/risk", supplier_id);
+        match backend::save(HttpMethod::Post, &endpoint, &suppliers[pos]).await {
+            Ok(saved) => suppliers[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.suppliers.set(suppliers.clone());
         store.selected.set(Some(suppliers[pos].clone()));
         store.loading.set(false);
@@ -233,14 +321,21 @@ pub async fn verify_csd(store: &SuppliersStore, supplier_id: &str) -> Result<boo
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the CSD API:
-    // let response = api::get(&format!("/api/csd/verify/{}", supplier_id)).await;
-
     let mut suppliers = store.suppliers.get();
     if let Some(pos) = suppliers.iter().position(|s| s.id == supplier_id) {
-        // Mock: assume verification passes if CSD number exists
-        let verified = suppliers[pos].csd_number.is_some();
-        suppliers[pos].csd_verified = verified;
+        // Mock: assume verification passes if CSD number exists;
+        // the REST backend returns the CSD outcome on the saved supplier
+        suppliers[pos].csd_verified = suppliers[pos].csd_number.is_some();
+        let endpoint = format!("/suppliers/{}/csd-verification", supplier_id);
+        match backend::save(HttpMethod::Post, &endpoint, &suppliers[pos]).await {
+            Ok(saved) => suppliers[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
+        let verified = suppliers[pos].csd_verified;
         store.suppliers.set(suppliers.clone());
         store.selected.set(Some(suppliers[pos].clone()));
         store.loading.set(false);
@@ -252,6 +347,38 @@ pub async fn verify_csd(store: &SuppliersStore, supplier_id: &str) -> Result<boo
     }
 }
 
+/// Submit a B-BBEE certificate for verification. The supplier keeps its
+/// current level until the certificate has been verified.
+pub async fn submit_certificate(
+    store: &SuppliersStore,
+    mut certificate: SupplierCertificate,
+) -> Result<SupplierCertificate, String> {
+    store.error.set(None);
+
+    if backend::is_mock() && store.suppliers.get().is_empty() {
+        load_mock_data(store);
+    }
+    if !store.suppliers.get().iter().any(|s| s.id == certificate.supplier_id) {
+        store.error.set(Some("Supplier not found".to_string()));
+        return Err("Supplier not found".to_string());
+    }
+    if certificate.certificate_number.trim().is_empty() {
+        return Err("Certificate number is required".to_string());
+    }
+
+    certificate.id = unique_id("CERT");
+    certificate.verified = false;
+    certificate.submitted_at = now_iso();
+    let endpoint = format!("/suppliers/{}/certificates", certificate.supplier_id);
+    match backend::save(HttpMethod::Post, &endpoint, &certificate).await {
+        Ok(saved) => Ok(saved),
+        Err(e) => {
+            store.error.set(Some(e.to_string()));
+            Err(e.to_string())
+        }
+    }
+}
+
 /// Search suppliers by query
 pub async fn search_suppliers(store: &SuppliersStore, query: &str) {
     let filter = SupplierFilter {
@@ -1,6 +1,7 @@
 //! Suppliers store
 
 use components::prelude::*;
+use crate::util::money::Money;
 use super::types::{
     Supplier, SupplierFilter, SupplierStatus, SupplierKpis, PaginationState,
     BbbeeLevel, RiskRating, PerformanceScore, PerformanceTrend,
@@ -84,20 +85,20 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "Gauteng".to_string(),
             city: "Johannesburg".to_string(),
             bank_name: Some("First National Bank".to_string()),
-            bank_account_number: Some("****5678".to_string()),
+            bank_account_number: Some("62845125678".to_string()),
             bank_branch_code: Some("250655".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2019-03-15".to_string(),
             verified_at: Some("2019-03-20".to_string()),
             last_active: "2025-02-10".to_string(),
             active_contracts: 3,
-            total_contract_value: 45_000_000.0,
+            total_contract_value: Money::rands(45_000_000),
             contracts: vec![
                 SupplierContract {
                     id: "CTR-2024-001".to_string(),
                     reference: "IT Infrastructure Support".to_string(),
                     description: "Annual IT support and maintenance".to_string(),
-                    value: 12_000_000.0,
+                    value: Money::rands(12_000_000),
                     start_date: "2024-04-01".to_string(),
                     end_date: "2027-03-31".to_string(),
                     status: "Active".to_string(),
@@ -164,14 +165,14 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "Gauteng".to_string(),
             city: "Pretoria".to_string(),
             bank_name: Some("Standard Bank".to_string()),
-            bank_account_number: Some("****9012".to_string()),
+            bank_account_number: Some("10041189012".to_string()),
             bank_branch_code: Some("051001".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2018-06-20".to_string(),
             verified_at: Some("2018-07-01".to_string()),
             last_active: "2025-02-08".to_string(),
             active_contracts: 2,
-            total_contract_value: 28_500_000.0,
+            total_contract_value: Money::rands(28_500_000),
             contracts: vec![],
             csd_number: Some("MAAA0067890".to_string()),
             csd_verified: true,
@@ -219,14 +220,14 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "Western Cape".to_string(),
             city: "Cape Town".to_string(),
             bank_name: Some("ABSA".to_string()),
-            bank_account_number: Some("****3456".to_string()),
+            bank_account_number: Some("40712633456".to_string()),
             bank_branch_code: Some("632005".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2017-01-10".to_string(),
             verified_at: Some("2017-01-15".to_string()),
             last_active: "2025-02-12".to_string(),
             active_contracts: 4,
-            total_contract_value: 8_200_000.0,
+            total_contract_value: Money::rands(8_200_000),
             contracts: vec![],
             csd_number: Some("MAAA0034567".to_string()),
             csd_verified: true,
@@ -279,14 +280,14 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "KwaZulu-Natal".to_string(),
             city: "Durban".to_string(),
             bank_name: Some("Nedbank".to_string()),
-            bank_account_number: Some("****7890".to_string()),
+            bank_account_number: Some("11987057890".to_string()),
             bank_branch_code: Some("198765".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2016-09-01".to_string(),
             verified_at: Some("2016-09-10".to_string()),
             last_active: "2025-02-11".to_string(),
             active_contracts: 5,
-            total_contract_value: 35_000_000.0,
+            total_contract_value: Money::rands(35_000_000),
             contracts: vec![],
             csd_number: Some("MAAA0089012".to_string()),
             csd_verified: true,
@@ -339,14 +340,14 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "Gauteng".to_string(),
             city: "Johannesburg".to_string(),
             bank_name: Some("Investec".to_string()),
-            bank_account_number: Some("****2345".to_string()),
+            bank_account_number: Some("10003422345".to_string()),
             bank_branch_code: Some("580105".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2020-02-14".to_string(),
             verified_at: Some("2020-02-20".to_string()),
             last_active: "2025-02-09".to_string(),
             active_contracts: 2,
-            total_contract_value: 15_500_000.0,
+            total_contract_value: Money::rands(15_500_000),
             contracts: vec![],
             csd_number: Some("MAAA0056789".to_string()),
             csd_verified: true,
@@ -409,14 +410,14 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "Western Cape".to_string(),
             city: "Cape Town".to_string(),
             bank_name: Some("Standard Bank".to_string()),
-            bank_account_number: Some("****6789".to_string()),
+            bank_account_number: Some("27105536789".to_string()),
             bank_branch_code: Some("051001".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2018-11-05".to_string(),
             verified_at: Some("2018-11-12".to_string()),
             last_active: "2025-01-28".to_string(),
             active_contracts: 1,
-            total_contract_value: 4_500_000.0,
+            total_contract_value: Money::rands(4_500_000),
             contracts: vec![],
             csd_number: Some("MAAA0023456".to_string()),
             csd_verified: true,
@@ -469,14 +470,14 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "Mpumalanga".to_string(),
             city: "Mbombela".to_string(),
             bank_name: Some("First National Bank".to_string()),
-            bank_account_number: Some("****0123".to_string()),
+            bank_account_number: Some("62319070123".to_string()),
             bank_branch_code: Some("250655".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2019-07-22".to_string(),
             verified_at: Some("2019-07-30".to_string()),
             last_active: "2025-02-07".to_string(),
             active_contracts: 3,
-            total_contract_value: 18_000_000.0,
+            total_contract_value: Money::rands(18_000_000),
             contracts: vec![],
             csd_number: Some("MAAA0045678".to_string()),
             csd_verified: true,
@@ -543,14 +544,14 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "Eastern Cape".to_string(),
             city: "Gqeberha".to_string(),
             bank_name: Some("ABSA".to_string()),
-            bank_account_number: Some("****4567".to_string()),
+            bank_account_number: Some("40728814567".to_string()),
             bank_branch_code: Some("632005".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2017-04-18".to_string(),
             verified_at: Some("2017-04-25".to_string()),
             last_active: "2025-01-20".to_string(),
             active_contracts: 1,
-            total_contract_value: 6_800_000.0,
+            total_contract_value: Money::rands(6_800_000),
             contracts: vec![],
             csd_number: Some("MAAA0078901".to_string()),
             csd_verified: true,
@@ -598,14 +599,14 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "Limpopo".to_string(),
             city: "Polokwane".to_string(),
             bank_name: Some("Capitec".to_string()),
-            bank_account_number: Some("****8901".to_string()),
+            bank_account_number: Some("13562408901".to_string()),
             bank_branch_code: Some("470010".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2021-08-30".to_string(),
             verified_at: Some("2021-09-05".to_string()),
             last_active: "2025-02-10".to_string(),
             active_contracts: 2,
-            total_contract_value: 3_200_000.0,
+            total_contract_value: Money::rands(3_200_000),
             contracts: vec![],
             csd_number: Some("MAAA0090123".to_string()),
             csd_verified: true,
@@ -653,14 +654,14 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "Free State".to_string(),
             city: "Bloemfontein".to_string(),
             bank_name: Some("Standard Bank".to_string()),
-            bank_account_number: Some("****2345".to_string()),
+            bank_account_number: Some("27044192345".to_string()),
             bank_branch_code: Some("051001".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2018-03-12".to_string(),
             verified_at: Some("2018-03-20".to_string()),
             last_active: "2025-02-05".to_string(),
             active_contracts: 1,
-            total_contract_value: 2_100_000.0,
+            total_contract_value: Money::rands(2_100_000),
             contracts: vec![],
             csd_number: Some("MAAA0012346".to_string()),
             csd_verified: true,
@@ -743,7 +744,7 @@ pub fn load_mock_data(store: &SuppliersStore) {
             verified_at: None,
             last_active: "2024-10-15".to_string(),
             active_contracts: 0,
-            total_contract_value: 500_000.0,
+            total_contract_value: Money::rands(500_000),
             contracts: vec![],
             csd_number: None,
             csd_verified: false,
@@ -806,14 +807,14 @@ pub fn load_mock_data(store: &SuppliersStore) {
             province: "North West".to_string(),
             city: "Rustenburg".to_string(),
             bank_name: Some("First National Bank".to_string()),
-            bank_account_number: Some("****5678".to_string()),
+            bank_account_number: Some("62903845678".to_string()),
             bank_branch_code: Some("250655".to_string()),
             status: SupplierStatus::Active,
             registered_at: "2016-11-08".to_string(),
             verified_at: Some("2016-11-15".to_string()),
             last_active: "2025-02-03".to_string(),
             active_contracts: 2,
-            total_contract_value: 22_000_000.0,
+            total_contract_value: Money::rands(22_000_000),
             contracts: vec![],
             csd_number: Some("MAAA0034568".to_string()),
             csd_verified: true,
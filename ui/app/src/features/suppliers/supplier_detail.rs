@@ -0,0 +1,224 @@
+//! Supplier profile page
+
+use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table, DataTableColumn, DataTableRow,
+    status_badge, StatusType,
+    tag, TagType,
+    progress_bar, ProgressColor,
+};
+use crate::util::format::{format_currency, format_date};
+use super::store::SuppliersStore;
+use super::types::{Supplier, SupplierStatus};
+use super::service;
+use crate::util::i18n::t;
+
+/// Supplier profile page
+#[component]
+pub fn supplier_detail(supplier_id: String) -> View {
+    let store = use_context::<SuppliersStore>();
+
+    // Load supplier on mount
+    effect({
+        let store = store.clone();
+        let supplier_id = supplier_id.clone();
+        move || {
+            let store = store.clone();
+            let supplier_id = supplier_id.clone();
+            spawn(async move {
+                service::get_supplier(&store, &supplier_id).await;
+            });
+        }
+    });
+
+    let loading = store.loading.get();
+    let error = store.error.get();
+    let supplier = store.selected.get().filter(|s| s.id == supplier_id);
+
+    view! {
+        style {
+            r#"
+            .supplier-detail { display: flex; flex-direction: column; gap: var(--space-6); }
+            .supplier-detail-grid {
+                display: grid;
+                grid-template-columns: 2fr 1fr;
+                gap: 24px;
+            }
+            .supplier-detail .detail-row {
+                display: flex;
+                justify-content: space-between;
+                padding: 8px 0;
+                border-bottom: 1px solid var(--border);
+                font-size: 13px;
+            }
+            .supplier-detail .detail-row:last-child { border-bottom: none; }
+            .supplier-detail .detail-row .label { color: var(--text-muted); }
+            .supplier-detail .detail-row .value { font-weight: 500; text-align: right; }
+            .supplier-detail .score-row { margin-bottom: 12px; }
+            .supplier-detail .score-row .label {
+                font-size: 12px;
+                color: var(--text-muted);
+                margin-bottom: 4px;
+            }
+            @media (max-width: 1024px) {
+                .supplier-detail-grid { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="supplier-detail" data-testid="supplier-detail">
+            if loading {
+                <div class="loading-state">{t("Loading supplier...")}</div>
+            } else if let Some(supplier) = supplier {
+                {render_supplier(supplier)}
+            } else {
+                {entity_not_found(t("Supplier"), &supplier_id, error, Route::SuppliersRegistry, t("Back to Suppliers"))}
+            }
+        </div>
+    }
+}
+
+fn render_supplier(supplier: Supplier) -> View {
+    let status = match supplier.status {
+        SupplierStatus::Active => status_badge(StatusType::Active),
+        SupplierStatus::Pending => status_badge(StatusType::Pending),
+        SupplierStatus::Suspended => status_badge(StatusType::Cancelled),
+        SupplierStatus::Blacklisted => status_badge(StatusType::Rejected),
+        SupplierStatus::Expired => status_badge(StatusType::Draft),
+        SupplierStatus::Inactive => status_badge(StatusType::Complete),
+    };
+
+    let csd = if supplier.csd_verified {
+        tag(t("CSD Verified").to_string(), TagType::Green)
+    } else {
+        tag(t("CSD Unverified").to_string(), TagType::Orange)
+    };
+
+    let columns = vec![
+        DataTableColumn { key: "reference".to_string(), label: t("Reference").to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "description".to_string(), label: t("Description").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "period".to_string(), label: t("Period").to_string(), width: Some("200px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "value".to_string(), label: t("Value").to_string(), width: Some("130px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+    ];
+
+    let rows: Vec<DataTableRow> = supplier.contracts.iter().map(|contract| {
+        DataTableRow {
+            id: contract.id.clone(),
+            cells: vec![
+                view! { <span class="id-cell">{contract.reference.clone()}</span> },
+                view! { <span>{contract.description.clone()}</span> },
+                view! { <span>{format!("{} - {}", format_date(&contract.start_date), format_date(&contract.end_date))}</span> },
+                view! { <span class="amount-cell">{format_currency(contract.value)}</span> },
+            ],
+        }
+    }).collect();
+
+    let scores = vec![
+        ("Quality", supplier.performance_score.quality),
+        ("Delivery", supplier.performance_score.delivery),
+        ("Price", supplier.performance_score.price),
+        ("Responsiveness", supplier.performance_score.responsiveness),
+        ("Compliance", supplier.performance_score.compliance),
+    ];
+
+    let categories = supplier.categories.iter()
+        .map(|c| c.name.clone())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    view! {
+        {page_header(
+            supplier.name.clone(),
+            Some(format!("{} - {}, {}", supplier.registration_number, supplier.city, supplier.province)),
+            vec![
+                view! { <a href="/suppliers" class="btn btn-secondary">{t("Back to Registry")}</a> },
+                csd,
+            ]
+        )}
+
+        <div class="supplier-detail-grid">
+            <div>
+                {panel(
+                    t("Contracts").to_string(),
+                    vec![],
+                    vec![data_table(columns, rows, None)]
+                )}
+
+                {panel(
+                    t("Performance").to_string(),
+                    vec![],
+                    vec![
+                        view! {
+                            <div>
+                                for (label, score) in scores.into_iter() {
+                                    <div class="score-row">
+                                        <div class="label">{t(label)}</div>
+                                        {progress_bar(score, ProgressColor::Blue, true, None)}
+                                    </div>
+                                }
+                            </div>
+                        }
+                    ]
+                )}
+            </div>
+
+            <div>
+                {panel(
+                    t("Profile").to_string(),
+                    vec![],
+                    vec![
+                        view! {
+                            <div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Status")}</span>
+                                    <span class="value">{status}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("B-BBEE")}</span>
+                                    <span class="value">{supplier.bbbee_level.label()}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Risk Rating")}</span>
+                                    <span class="value" style={format!("color: {}", supplier.risk_rating.color())}>
+                                        {supplier.risk_rating.label()}
+                                    </span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Categories")}</span>
+                                    <span class="value">{categories}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Contact")}</span>
+                                    <span class="value">{supplier.contact_person.clone()}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Email")}</span>
+                                    <span class="value">{supplier.email.clone()}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Phone")}</span>
+                                    <span class="value">{supplier.phone.clone()}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Registered")}</span>
+                                    <span class="value">{format_date(&supplier.registered_at)}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Active Contracts")}</span>
+                                    <span class="value">{supplier.active_contracts.to_string()}</span>
+                                </div>
+                                <div class="detail-row">
+                                    <span class="label">{t("Total Contract Value")}</span>
+                                    <span class="value">{format_currency(supplier.total_contract_value)}</span>
+                                </div>
+                            </div>
+                        }
+                    ]
+                )}
+            </div>
+        </div>
+    }
+}
@@ -12,6 +12,7 @@ use crate::shared::forms::filter_bar;
 use super::store::SuppliersStore;
 use super::types::SupplierStatus;
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Supplier performance dashboard page
 #[component]
@@ -131,23 +132,23 @@ pub fn supplier_performance() -> View {
 
     // Category performance
     let category_performance = vec![
-        BarChartData { label: "IT Services".to_string(), value: 89.0, color: Some("var(--blue)".to_string()) },
-        BarChartData { label: "Construction".to_string(), value: 82.0, color: Some("var(--blue)".to_string()) },
-        BarChartData { label: "Security".to_string(), value: 86.0, color: Some("var(--blue)".to_string()) },
-        BarChartData { label: "Catering".to_string(), value: 91.0, color: Some("var(--blue)".to_string()) },
-        BarChartData { label: "Transport".to_string(), value: 84.0, color: Some("var(--blue)".to_string()) },
-        BarChartData { label: "Professional".to_string(), value: 78.0, color: Some("var(--orange)".to_string()) },
+        BarChartData { label: t("IT Services").to_string(), value: 89.0, color: Some("var(--blue)".to_string()) },
+        BarChartData { label: t("Construction").to_string(), value: 82.0, color: Some("var(--blue)".to_string()) },
+        BarChartData { label: t("Security").to_string(), value: 86.0, color: Some("var(--blue)".to_string()) },
+        BarChartData { label: t("Catering").to_string(), value: 91.0, color: Some("var(--blue)".to_string()) },
+        BarChartData { label: t("Transport").to_string(), value: 84.0, color: Some("var(--blue)".to_string()) },
+        BarChartData { label: t("Professional").to_string(), value: 78.0, color: Some("var(--orange)".to_string()) },
     ];
 
     // Table columns
     let columns = vec![
-        DataTableColumn { key: "supplier".to_string(), label: "Supplier".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "overall".to_string(), label: "Overall".to_string(), width: Some("90px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "quality".to_string(), label: "Quality".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "delivery".to_string(), label: "Delivery".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "price".to_string(), label: "Price".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "responsive".to_string(), label: "Response".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "trend".to_string(), label: "Trend".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "overall".to_string(), label: t("Overall").to_string(), width: Some("90px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "quality".to_string(), label: t("Quality").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "delivery".to_string(), label: t("Delivery").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "price".to_string(), label: t("Price").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "responsive".to_string(), label: t("Response").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "trend".to_string(), label: t("Trend").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
     ];
 
     // Transform suppliers to table rows
@@ -237,11 +238,11 @@ pub fn supplier_performance() -> View {
     let is_loading = loading.get();
     let suppliers_count = filtered_suppliers.len();
     let table_content = if is_loading {
-        view! { <div class="loading-overlay">"Loading performance data..."</div> }
+        view! { <div class="loading-overlay">{t("Loading performance data...")}</div> }
     } else if filtered_suppliers.is_empty() {
         view! {
             <div class="loading-overlay">
-                "No suppliers match the selected criteria."
+                {t("No suppliers match the selected criteria.")}
             </div>
         }
     } else {
@@ -396,17 +397,17 @@ pub fn supplier_performance() -> View {
 
         <div class="supplier-performance" data-testid="supplier-performance">
             {page_header(
-                "Supplier Performance".to_string(),
-                Some("Monitor and analyze supplier performance metrics".to_string()),
+                t("Supplier Performance").to_string(),
+                Some(t("Monitor and analyze supplier performance metrics").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export Report"</button> },
+                    view! { <button class="btn btn-secondary">{t("Export Report")}</button> },
                 ]
             )}
 
             // KPI summary
             <div class="kpi-grid">
                 {kpi_card(
-                    "Avg. Performance".to_string(),
+                    t("Avg. Performance").to_string(),
                     format!("{:.1}%", avg_performance),
                     KpiColor::Blue,
                     icon_star.to_string(),
@@ -414,7 +415,7 @@ pub fn supplier_performance() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Top Performers".to_string(),
+                    t("Top Performers").to_string(),
                     format!("{}", top_performers),
                     KpiColor::Green,
                     icon_trending.to_string(),
@@ -422,7 +423,7 @@ pub fn supplier_performance() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Underperforming".to_string(),
+                    t("Underperforming").to_string(),
                     format!("{}", underperformers),
                     KpiColor::Orange,
                     icon_alert.to_string(),
@@ -430,7 +431,7 @@ pub fn supplier_performance() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Evaluated".to_string(),
+                    t("Evaluated").to_string(),
                     format!("{}", active_suppliers.len()),
                     KpiColor::Accent,
                     icon_check.to_string(),
@@ -442,12 +443,12 @@ pub fn supplier_performance() -> View {
             // Charts row
             <div class="charts-grid">
                 {panel(
-                    "Performance Distribution".to_string(),
+                    t("Performance Distribution").to_string(),
                     vec![],
                     vec![bar_chart(perf_distribution, Some(chart_height))]
                 )}
                 {panel(
-                    "Performance by Category".to_string(),
+                    t("Performance by Category").to_string(),
                     vec![],
                     vec![bar_chart(category_performance, Some(chart_height))]
                 )}
@@ -455,8 +456,8 @@ pub fn supplier_performance() -> View {
 
             // Top performers
             {panel(
-                "Top Performing Suppliers".to_string(),
-                vec![view! { <a href="#/suppliers?filter=top" class="btn btn-sm btn-secondary">"View All"</a> }],
+                t("Top Performing Suppliers").to_string(),
+                vec![view! { <a href="#/suppliers?filter=top" class="btn btn-sm btn-secondary">{t("View All")}</a> }],
                 vec![top_performers_view]
             )}
 
@@ -464,25 +465,25 @@ pub fn supplier_performance() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Performance"</label>
+                        <label>{t("Performance")}</label>
                         <select on:change={handle_performance_filter}>
-                            <option value="">"All Suppliers"</option>
-                            <option value="top">"Top Performers (85%+)"</option>
-                            <option value="underperforming">"Underperforming (<70%)"</option>
+                            <option value="">{t("All Suppliers")}</option>
+                            <option value="top">{t("Top Performers (85%+)")}</option>
+                            <option value="underperforming">{t("Underperforming (<70%)")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Category"</label>
+                        <label>{t("Category")}</label>
                         <select on:change={handle_category_filter}>
-                            <option value="">"All Categories"</option>
-                            <option value="IT">"IT Services"</option>
-                            <option value="CON">"Construction"</option>
-                            <option value="SEC">"Security"</option>
-                            <option value="CAT">"Catering"</option>
-                            <option value="TRN">"Transport"</option>
-                            <option value="PRO">"Professional Services"</option>
+                            <option value="">{t("All Categories")}</option>
+                            <option value="IT">{t("IT Services")}</option>
+                            <option value="CON">{t("Construction")}</option>
+                            <option value="SEC">{t("Security")}</option>
+                            <option value="CAT">{t("Catering")}</option>
+                            <option value="TRN">{t("Transport")}</option>
+                            <option value="PRO">{t("Professional Services")}</option>
                         </select>
                     </div>
                 },
@@ -492,7 +493,7 @@ pub fn supplier_performance() -> View {
                         <input
                             type="text"
                             class="search-input"
-                            placeholder="Search suppliers..."
+                            placeholder={t("Search suppliers...")}
                             on:input={handle_search}
                         />
                     </div>
@@ -501,7 +502,7 @@ pub fn supplier_performance() -> View {
 
             // Performance table
             {panel(
-                format!("Supplier Performance Scores ({} suppliers)", suppliers_count),
+                tf("Supplier Performance Scores ({count} suppliers)", &[("count", suppliers_count.to_string())]),
                 vec![],
                 vec![table_content]
             )}
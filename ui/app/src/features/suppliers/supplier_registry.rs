@@ -13,21 +13,28 @@ use crate::shared::components::{
 };
 use crate::shared::forms::filter_bar;
 use crate::util::format::{format_currency, format_number};
+use crate::util::url;
+use crate::Route;
 use super::store::SuppliersStore;
 use super::types::{SupplierStatus, BbbeeLevel, RiskRating, SupplierFilter};
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Supplier registry page
 #[component]
 pub fn supplier_registry() -> View {
     let store = use_context::<SuppliersStore>();
 
-    // Filter signals
-    let filter_status = signal(String::new());
-    let filter_bbbee = signal(String::new());
-    let filter_risk = signal(String::new());
-    let filter_province = signal(String::new());
-    let search_query = signal(String::new());
+    let navigate = use_context::<Callback<Route>>();
+
+    // Filter signals, seeded from the URL
+    url::sync_filter(&store.filter);
+    let initial = store.filter.get();
+    let filter_status = signal(initial.status.map(|s| s.as_str().to_string()).unwrap_or_default());
+    let filter_bbbee = signal(initial.bbbee_level.map(|l| l.as_param()).unwrap_or_default());
+    let filter_risk = signal(initial.risk_rating.map(|r| r.as_str().to_string()).unwrap_or_default());
+    let filter_province = signal(initial.province.clone().unwrap_or_default());
+    let search_query = signal(initial.search_query.clone().unwrap_or_default());
     let view_mode = signal("table".to_string()); // "table" or "cards"
 
     // Load data on mount
@@ -46,49 +53,78 @@ pub fn supplier_registry() -> View {
     let pagination_state = store.pagination.clone();
     let loading = store.loading.clone();
 
+    // Mirror the filter inputs into the store so they reach the URL
+    let update_filter = Callback::new({
+        let store = store.clone();
+        let filter_status = filter_status.clone();
+        let filter_bbbee = filter_bbbee.clone();
+        let filter_risk = filter_risk.clone();
+        let filter_province = filter_province.clone();
+        let search_query = search_query.clone();
+        move |_: ()| {
+            let mut filter = store.filter.get();
+            filter.status = SupplierStatus::parse(&filter_status.get());
+            filter.bbbee_level = BbbeeLevel::parse(&filter_bbbee.get());
+            filter.risk_rating = RiskRating::parse(&filter_risk.get());
+            filter.province = Some(filter_province.get()).filter(|p| !p.is_empty());
+            filter.search_query = Some(search_query.get()).filter(|q| !q.is_empty());
+            store.filter.set(filter);
+        }
+    });
+
     // Handle filter changes
     let handle_filter_status = Callback::new({
         let filter_status = filter_status.clone();
+        let update_filter = update_filter.clone();
         move |e: web_sys::Event| {
             let target = e.target().unwrap();
             let select: web_sys::HtmlSelectElement = target.dyn_into().unwrap();
             filter_status.set(select.value());
+            update_filter.call(());
         }
     });
 
     let handle_filter_bbbee = Callback::new({
         let filter_bbbee = filter_bbbee.clone();
+        let update_filter = update_filter.clone();
         move |e: web_sys::Event| {
             let target = e.target().unwrap();
             let select: web_sys::HtmlSelectElement = target.dyn_into().unwrap();
             filter_bbbee.set(select.value());
+            update_filter.call(());
         }
     });
 
     let handle_filter_risk = Callback::new({
         let filter_risk = filter_risk.clone();
+        let update_filter = update_filter.clone();
         move |e: web_sys::Event| {
             let target = e.target().unwrap();
             let select: web_sys::HtmlSelectElement = target.dyn_into().unwrap();
             filter_risk.set(select.value());
+            update_filter.call(());
         }
     });
 
     let handle_filter_province = Callback::new({
         let filter_province = filter_province.clone();
+        let update_filter = update_filter.clone();
         move |e: web_sys::Event| {
             let target = e.target().unwrap();
             let select: web_sys::HtmlSelectElement = target.dyn_into().unwrap();
             filter_province.set(select.value());
+            update_filter.call(());
         }
     });
 
     let handle_search = Callback::new({
         let search_query = search_query.clone();
+        let update_filter = update_filter.clone();
         move |e: web_sys::Event| {
             let target = e.target().unwrap();
             let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
             search_query.set(input.value());
+            update_filter.call(());
         }
     });
 
@@ -120,12 +156,9 @@ pub fn supplier_registry() -> View {
 
     // Handle row click
     let handle_row_click = Callback::new({
+        let navigate = navigate.clone();
         move |supplier_id: String| {
-            web_sys::window()
-                .unwrap()
-                .location()
-                .set_href(&format!("#/suppliers/{}", supplier_id))
-                .ok();
+            navigate.call(Route::SuppliersDetail(supplier_id));
         }
     });
 
@@ -141,13 +174,13 @@ pub fn supplier_registry() -> View {
 
     // Table columns
     let columns = vec![
-        DataTableColumn { key: "name".to_string(), label: "Supplier".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "registration".to_string(), label: "Reg. No.".to_string(), width: Some("140px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "bbbee".to_string(), label: "B-BBEE".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "categories".to_string(), label: "Categories".to_string(), width: Some("180px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "performance".to_string(), label: "Performance".to_string(), width: Some("100px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "risk".to_string(), label: "Risk".to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "name".to_string(), label: t("Supplier").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "registration".to_string(), label: t("Reg. No.").to_string(), width: Some("140px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
+        DataTableColumn { key: "bbbee".to_string(), label: t("B-BBEE").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "categories".to_string(), label: t("Categories").to_string(), width: Some("180px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "performance".to_string(), label: t("Performance").to_string(), width: Some("100px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "risk".to_string(), label: t("Risk").to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
     ];
 
     // Filter suppliers based on current filters
@@ -221,10 +254,10 @@ pub fn supplier_registry() -> View {
         };
 
         let risk_tag = match supplier.risk_rating {
-            RiskRating::Low => tag("Low".to_string(), TagType::Green),
-            RiskRating::Medium => tag("Medium".to_string(), TagType::Orange),
-            RiskRating::High => tag("High".to_string(), TagType::Red),
-            RiskRating::Critical => tag("Critical".to_string(), TagType::Red),
+            RiskRating::Low => tag(t("Low").to_string(), TagType::Green),
+            RiskRating::Medium => tag(t("Medium").to_string(), TagType::Orange),
+            RiskRating::High => tag(t("High").to_string(), TagType::Red),
+            RiskRating::Critical => tag(t("Critical").to_string(), TagType::Red),
         };
 
         let status = match supplier.status {
@@ -424,18 +457,18 @@ pub fn supplier_registry() -> View {
 
         <div class="supplier-registry" data-testid="supplier-registry">
             {page_header(
-                "Supplier Registry".to_string(),
-                Some("Manage and monitor registered suppliers".to_string()),
+                t("Supplier Registry").to_string(),
+                Some(t("Manage and monitor registered suppliers").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export"</button> },
-                    view! { <a href="#/suppliers/new" class="btn btn-primary">"Add Supplier"</a> },
+                    view! { <button class="btn btn-secondary">{t("Export")}</button> },
+                    view! { <a href="#/suppliers/new" class="btn btn-primary">{t("Add Supplier")}</a> },
                 ]
             )}
 
             // KPI summary
             <div class="kpi-grid">
                 {kpi_card(
-                    "Total Suppliers".to_string(),
+                    t("Total Suppliers").to_string(),
                     format_number(kpis_data.total_suppliers),
                     KpiColor::Blue,
                     icon_users.to_string(),
@@ -443,7 +476,7 @@ pub fn supplier_registry() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Active Suppliers".to_string(),
+                    t("Active Suppliers").to_string(),
                     format_number(kpis_data.active_suppliers),
                     KpiColor::Green,
                     icon_check.to_string(),
@@ -451,7 +484,7 @@ pub fn supplier_registry() -> View {
                     None
                 )}
                 {kpi_card(
-                    "B-BBEE Compliant".to_string(),
+                    t("B-BBEE Compliant").to_string(),
                     format!("{:.1}%", kpis_data.bbbee_compliant_percent),
                     KpiColor::Accent,
                     icon_layers.to_string(),
@@ -459,7 +492,7 @@ pub fn supplier_registry() -> View {
                     None
                 )}
                 {kpi_card(
-                    "High Risk".to_string(),
+                    t("High Risk").to_string(),
                     kpis_data.high_risk_count.to_string(),
                     KpiColor::Red,
                     icon_alert.to_string(),
@@ -472,55 +505,55 @@ pub fn supplier_registry() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Status"</label>
-                        <select on:change={handle_filter_status}>
-                            <option value="">"All Statuses"</option>
-                            <option value="active">"Active"</option>
-                            <option value="pending">"Pending"</option>
-                            <option value="suspended">"Suspended"</option>
-                            <option value="blacklisted">"Blacklisted"</option>
+                        <label>{t("Status")}</label>
+                        <select on:change={handle_filter_status} value={filter_status.get()}>
+                            <option value="">{t("All Statuses")}</option>
+                            <option value="active">{t("Active")}</option>
+                            <option value="pending">{t("Pending")}</option>
+                            <option value="suspended">{t("Suspended")}</option>
+                            <option value="blacklisted">{t("Blacklisted")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"B-BBEE"</label>
-                        <select on:change={handle_filter_bbbee}>
-                            <option value="">"All Levels"</option>
-                            <option value="1">"Level 1"</option>
-                            <option value="2">"Level 2"</option>
-                            <option value="3">"Level 3"</option>
-                            <option value="4">"Level 4+"</option>
-                            <option value="non">"Non-Compliant"</option>
+                        <label>{t("B-BBEE")}</label>
+                        <select on:change={handle_filter_bbbee} value={filter_bbbee.get()}>
+                            <option value="">{t("All Levels")}</option>
+                            <option value="1">{t("Level 1")}</option>
+                            <option value="2">{t("Level 2")}</option>
+                            <option value="3">{t("Level 3")}</option>
+                            <option value="4">{t("Level 4+")}</option>
+                            <option value="non">{t("Non-Compliant")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Risk"</label>
-                        <select on:change={handle_filter_risk}>
-                            <option value="">"All Risk Levels"</option>
-                            <option value="low">"Low"</option>
-                            <option value="medium">"Medium"</option>
-                            <option value="high">"High"</option>
-                            <option value="critical">"Critical"</option>
+                        <label>{t("Risk")}</label>
+                        <select on:change={handle_filter_risk} value={filter_risk.get()}>
+                            <option value="">{t("All Risk Levels")}</option>
+                            <option value="low">{t("Low")}</option>
+                            <option value="medium">{t("Medium")}</option>
+                            <option value="high">{t("High")}</option>
+                            <option value="critical">{t("Critical")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Province"</label>
-                        <select on:change={handle_filter_province}>
-                            <option value="">"All Provinces"</option>
-                            <option value="gauteng">"Gauteng"</option>
-                            <option value="western cape">"Western Cape"</option>
-                            <option value="kwazulu-natal">"KwaZulu-Natal"</option>
-                            <option value="eastern cape">"Eastern Cape"</option>
-                            <option value="free state">"Free State"</option>
-                            <option value="mpumalanga">"Mpumalanga"</option>
-                            <option value="limpopo">"Limpopo"</option>
-                            <option value="north west">"North West"</option>
-                            <option value="northern cape">"Northern Cape"</option>
+                        <label>{t("Province")}</label>
+                        <select on:change={handle_filter_province} value={filter_province.get()}>
+                            <option value="">{t("All Provinces")}</option>
+                            <option value="gauteng">{t("Gauteng")}</option>
+                            <option value="western cape">{t("Western Cape")}</option>
+                            <option value="kwazulu-natal">{t("KwaZulu-Natal")}</option>
+                            <option value="eastern cape">{t("Eastern Cape")}</option>
+                            <option value="free state">{t("Free State")}</option>
+                            <option value="mpumalanga">{t("Mpumalanga")}</option>
+                            <option value="limpopo">{t("Limpopo")}</option>
+                            <option value="north west">{t("North West")}</option>
+                            <option value="northern cape">{t("Northern Cape")}</option>
                         </select>
                     </div>
                 },
@@ -530,7 +563,8 @@ pub fn supplier_registry() -> View {
                         <input
                             type="text"
                             class="search-input"
-                            placeholder="Search suppliers..."
+                            placeholder={t("Search suppliers...")}
+                            value={search_query.get()}
                             on:input={handle_search}
                         />
                     </div>
@@ -543,32 +577,32 @@ pub fn supplier_registry() -> View {
                                 let handle_toggle_view = handle_toggle_view.clone();
                                 move |_| handle_toggle_view.call("table".to_string())
                             })}
-                        >"Table"</button>
+                        >{t("Table")}</button>
                         <button
                             class={if view_mode.get() == "cards" { "active" } else { "" }}
                             on:click={Callback::<()>::new({
                                 let handle_toggle_view = handle_toggle_view.clone();
                                 move |_| handle_toggle_view.call("cards".to_string())
                             })}
-                        >"Cards"</button>
+                        >{t("Cards")}</button>
                     </div>
                 },
                 view! {
-                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>"Clear"</button>
+                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters}>{t("Clear")}</button>
                 },
             ])}
 
             // Data display (table or cards)
             {panel(
-                format!("Suppliers ({} total)", filtered_suppliers.len()),
+                tf("Suppliers ({count} total)", &[("count", filtered_suppliers.len().to_string())]),
                 vec![],
                 vec![
                     if loading.get() {
-                        view! { <div class="loading-overlay">"Loading suppliers..."</div> }
+                        view! { <div class="loading-overlay">{t("Loading suppliers...")}</div> }
                     } else if filtered_suppliers.is_empty() {
                         view! {
                             <div class="loading-overlay">
-                                "No suppliers found. Adjust filters or add a new supplier."
+                                {t("No suppliers found. Adjust filters or add a new supplier.")}
                             </div>
                         }
                     } else if view_mode.get() == "cards" {
@@ -612,10 +646,10 @@ fn supplier_card(supplier: super::types::Supplier, on_click: Callback<String>) -
     };
 
     let risk_tag = match supplier.risk_rating {
-        RiskRating::Low => tag("Low Risk".to_string(), TagType::Green),
-        RiskRating::Medium => tag("Medium Risk".to_string(), TagType::Orange),
-        RiskRating::High => tag("High Risk".to_string(), TagType::Red),
-        RiskRating::Critical => tag("Critical Risk".to_string(), TagType::Red),
+        RiskRating::Low => tag(t("Low Risk").to_string(), TagType::Green),
+        RiskRating::Medium => tag(t("Medium Risk").to_string(), TagType::Orange),
+        RiskRating::High => tag(t("High Risk").to_string(), TagType::Red),
+        RiskRating::Critical => tag(t("Critical Risk").to_string(), TagType::Red),
     };
 
     let perf_color = if supplier.performance_score.overall >= 80.0 {
@@ -637,21 +671,21 @@ fn supplier_card(supplier: super::types::Supplier, on_click: Callback<String>) -
             </div>
             <div class="supplier-card-body">
                 <div class="supplier-card-row">
-                    <span class="supplier-card-label">"Registration"</span>
+                    <span class="supplier-card-label">{t("Registration")}</span>
                     <span class="supplier-card-value">{supplier.registration_number}</span>
                 </div>
                 <div class="supplier-card-row">
-                    <span class="supplier-card-label">"Performance"</span>
+                    <span class="supplier-card-label">{t("Performance")}</span>
                     <span class="supplier-card-value" style={format!("color: {}", perf_color)}>
                         {format!("{:.0}%", supplier.performance_score.overall)}
                     </span>
                 </div>
                 <div class="supplier-card-row">
-                    <span class="supplier-card-label">"Active Contracts"</span>
+                    <span class="supplier-card-label">{t("Active Contracts")}</span>
                     <span class="supplier-card-value">{supplier.active_contracts.to_string()}</span>
                 </div>
                 <div class="supplier-card-row">
-                    <span class="supplier-card-label">"Total Value"</span>
+                    <span class="supplier-card-label">{t("Total Value")}</span>
                     <span class="supplier-card-value">{format_currency(supplier.total_contract_value)}</span>
                 </div>
             </div>
@@ -17,6 +17,7 @@ use crate::util::format::format_currency;
 use super::store::SuppliersStore;
 use super::types::{Supplier, SupplierStatus, BbbeeLevel, RiskRating, RiskIndicator, RiskIndicatorStatus};
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Supplier risk assessment page
 #[component]
@@ -137,37 +138,37 @@ pub fn supplier_risk() -> View {
 
     // Risk distribution for pie chart
     let risk_distribution = vec![
-        PieChartData { label: "Low".to_string(), value: low_risk as f64, color: "var(--green)".to_string() },
-        PieChartData { label: "Medium".to_string(), value: medium_risk as f64, color: "var(--orange)".to_string() },
-        PieChartData { label: "High".to_string(), value: high_risk as f64, color: "var(--red)".to_string() },
-        PieChartData { label: "Critical".to_string(), value: critical_risk as f64, color: "#8b0000".to_string() },
+        PieChartData { label: t("Low").to_string(), value: low_risk as f64, color: "var(--green)".to_string() },
+        PieChartData { label: t("Medium").to_string(), value: medium_risk as f64, color: "var(--orange)".to_string() },
+        PieChartData { label: t("High").to_string(), value: high_risk as f64, color: "var(--red)".to_string() },
+        PieChartData { label: t("Critical").to_string(), value: critical_risk as f64, color: "#8b0000".to_string() },
     ];
 
     // Risk by category
     let risk_by_category = vec![
-        BarChartData { label: "Financial".to_string(), value: all_indicators.iter().filter(|(_, r)| r.category == "Financial").count() as f64, color: Some("var(--blue)".to_string()) },
-        BarChartData { label: "Compliance".to_string(), value: all_indicators.iter().filter(|(_, r)| r.category == "Compliance").count() as f64, color: Some("var(--orange)".to_string()) },
-        BarChartData { label: "Performance".to_string(), value: all_indicators.iter().filter(|(_, r)| r.category == "Performance").count() as f64, color: Some("var(--purple)".to_string()) },
-        BarChartData { label: "Delivery".to_string(), value: all_indicators.iter().filter(|(_, r)| r.category == "Delivery").count() as f64, color: Some("var(--red)".to_string()) },
+        BarChartData { label: t("Financial").to_string(), value: all_indicators.iter().filter(|(_, r)| r.category == "Financial").count() as f64, color: Some("var(--blue)".to_string()) },
+        BarChartData { label: t("Compliance").to_string(), value: all_indicators.iter().filter(|(_, r)| r.category == "Compliance").count() as f64, color: Some("var(--orange)".to_string()) },
+        BarChartData { label: t("Performance").to_string(), value: all_indicators.iter().filter(|(_, r)| r.category == "Performance").count() as f64, color: Some("var(--purple)".to_string()) },
+        BarChartData { label: t("Delivery").to_string(), value: all_indicators.iter().filter(|(_, r)| r.category == "Delivery").count() as f64, color: Some("var(--red)".to_string()) },
     ];
 
     // Table columns for suppliers at risk
     let risk_columns = vec![
-        DataTableColumn { key: "supplier".to_string(), label: "Supplier".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "risk_rating".to_string(), label: "Risk Rating".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "risk_score".to_string(), label: "Score".to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "indicators".to_string(), label: "Open Issues".to_string(), width: Some("100px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "bbbee".to_string(), label: "B-BBEE".to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "contract_value".to_string(), label: "Contract Value".to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "risk_rating".to_string(), label: t("Risk Rating").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "risk_score".to_string(), label: t("Score").to_string(), width: Some("80px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "indicators".to_string(), label: t("Open Issues").to_string(), width: Some("100px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "bbbee".to_string(), label: t("B-BBEE").to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "contract_value".to_string(), label: t("Contract Value").to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: None },
     ];
 
     // Transform suppliers to risk table rows
     let risk_rows: Vec<DataTableRow> = filtered_suppliers.iter().map(|supplier| {
         let risk_tag = match supplier.risk_rating {
-            RiskRating::Low => tag("Low".to_string(), TagType::Green),
-            RiskRating::Medium => tag("Medium".to_string(), TagType::Orange),
-            RiskRating::High => tag("High".to_string(), TagType::Red),
-            RiskRating::Critical => tag("Critical".to_string(), TagType::Red),
+            RiskRating::Low => tag(t("Low").to_string(), TagType::Green),
+            RiskRating::Medium => tag(t("Medium").to_string(), TagType::Orange),
+            RiskRating::High => tag(t("High").to_string(), TagType::Red),
+            RiskRating::Critical => tag(t("Critical").to_string(), TagType::Red),
         };
 
         let bbbee = match supplier.bbbee_level {
@@ -219,12 +220,12 @@ pub fn supplier_risk() -> View {
 
     // Risk indicators table columns
     let indicator_columns = vec![
-        DataTableColumn { key: "supplier".to_string(), label: "Supplier".to_string(), width: Some("200px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "category".to_string(), label: "Category".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "indicator".to_string(), label: "Risk Indicator".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "severity".to_string(), label: "Severity".to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "detected".to_string(), label: "Detected".to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "supplier".to_string(), label: t("Supplier").to_string(), width: Some("200px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "category".to_string(), label: t("Category").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "indicator".to_string(), label: t("Risk Indicator").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "severity".to_string(), label: t("Severity").to_string(), width: Some("90px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "detected".to_string(), label: t("Detected").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
     ];
 
     // Filter indicators
@@ -257,10 +258,10 @@ pub fn supplier_risk() -> View {
             .unwrap_or_default();
 
         let severity_tag = match indicator.severity {
-            RiskRating::Low => tag("Low".to_string(), TagType::Green),
-            RiskRating::Medium => tag("Medium".to_string(), TagType::Orange),
-            RiskRating::High => tag("High".to_string(), TagType::Red),
-            RiskRating::Critical => tag("Critical".to_string(), TagType::Red),
+            RiskRating::Low => tag(t("Low").to_string(), TagType::Green),
+            RiskRating::Medium => tag(t("Medium").to_string(), TagType::Orange),
+            RiskRating::High => tag(t("High").to_string(), TagType::Red),
+            RiskRating::Critical => tag(t("Critical").to_string(), TagType::Red),
         };
 
         let status = match indicator.status {
@@ -438,18 +439,18 @@ pub fn supplier_risk() -> View {
 
         <div class="supplier-risk" data-testid="supplier-risk">
             {page_header(
-                "Supplier Risk Assessment".to_string(),
-                Some("Monitor and manage supplier risk exposure".to_string()),
+                t("Supplier Risk Assessment").to_string(),
+                Some(t("Monitor and manage supplier risk exposure").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export Report"</button> },
-                    view! { <button class="btn btn-primary">"Run Assessment"</button> },
+                    view! { <button class="btn btn-secondary">{t("Export Report")}</button> },
+                    view! { <button class="btn btn-primary">{t("Run Assessment")}</button> },
                 ]
             )}
 
             // KPI summary
             <div class="kpi-grid">
                 {kpi_card(
-                    "Low Risk".to_string(),
+                    t("Low Risk").to_string(),
                     format!("{}", low_risk),
                     KpiColor::Green,
                     icon_shield.to_string(),
@@ -457,7 +458,7 @@ pub fn supplier_risk() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Medium Risk".to_string(),
+                    t("Medium Risk").to_string(),
                     format!("{}", medium_risk),
                     KpiColor::Orange,
                     icon_warning.to_string(),
@@ -465,7 +466,7 @@ pub fn supplier_risk() -> View {
                     None
                 )}
                 {kpi_card(
-                    "High/Critical Risk".to_string(),
+                    t("High/Critical Risk").to_string(),
                     format!("{}", high_risk + critical_risk),
                     KpiColor::Red,
                     icon_alert.to_string(),
@@ -473,7 +474,7 @@ pub fn supplier_risk() -> View {
                     None
                 )}
                 {kpi_card(
-                    "Open Issues".to_string(),
+                    t("Open Issues").to_string(),
                     format!("{}", open_indicators),
                     KpiColor::Purple,
                     icon_check.to_string(),
@@ -485,7 +486,7 @@ pub fn supplier_risk() -> View {
             // Charts row
             <div class="charts-grid">
                 {panel(
-                    "Risk Distribution".to_string(),
+                    t("Risk Distribution").to_string(),
                     vec![],
                     vec![
                         pie_chart(risk_distribution, None),
@@ -493,26 +494,26 @@ pub fn supplier_risk() -> View {
                             <div class="risk-summary">
                                 <div class="risk-summary-item">
                                     <div class="risk-summary-count risk-low">{low_risk.to_string()}</div>
-                                    <div class="risk-summary-label">"Low Risk"</div>
+                                    <div class="risk-summary-label">{t("Low Risk")}</div>
                                 </div>
                                 <div class="risk-summary-item">
                                     <div class="risk-summary-count risk-medium">{medium_risk.to_string()}</div>
-                                    <div class="risk-summary-label">"Medium Risk"</div>
+                                    <div class="risk-summary-label">{t("Medium Risk")}</div>
                                 </div>
                                 <div class="risk-summary-item">
                                     <div class="risk-summary-count risk-high">{high_risk.to_string()}</div>
-                                    <div class="risk-summary-label">"High Risk"</div>
+                                    <div class="risk-summary-label">{t("High Risk")}</div>
                                 </div>
                                 <div class="risk-summary-item">
                                     <div class="risk-summary-count risk-critical">{critical_risk.to_string()}</div>
-                                    <div class="risk-summary-label">"Critical"</div>
+                                    <div class="risk-summary-label">{t("Critical")}</div>
                                 </div>
                             </div>
                         },
                     ]
                 )}
                 {panel(
-                    "Risk Indicators by Category".to_string(),
+                    t("Risk Indicators by Category").to_string(),
                     vec![],
                     vec![bar_chart(risk_by_category, Some(180))]
                 )}
@@ -521,7 +522,7 @@ pub fn supplier_risk() -> View {
             // Critical suppliers
             if critical_risk > 0 {
                 {panel(
-                    "Critical Risk Suppliers - Immediate Attention Required".to_string(),
+                    t("Critical Risk Suppliers - Immediate Attention Required").to_string(),
                     vec![],
                     vec![{
                         let critical_suppliers: Vec<_> = active_suppliers.iter()
@@ -535,15 +536,16 @@ pub fn supplier_risk() -> View {
                                         <div class="critical-supplier-info">
                                             <span class="critical-supplier-name">{supplier.name.clone()}</span>
                                             <span class="critical-supplier-issues">
-                                                {format!("{} open risk indicators - Risk Score: {:.0}",
-                                                    supplier.risk_indicators.iter()
+                                                {tf("{count} open risk indicators - Risk Score: {score}", &[
+                                                    ("count", supplier.risk_indicators.iter()
                                                         .filter(|r| r.status == RiskIndicatorStatus::Open)
-                                                        .count(),
-                                                    supplier.risk_score
-                                                )}
+                                                        .count()
+                                                        .to_string()),
+                                                    ("score", format!("{:.0}", supplier.risk_score)),
+                                                ])}
                                             </span>
                                         </div>
-                                        <a href={format!("#/suppliers/{}", supplier.id)} class="btn btn-sm btn-danger">"Review"</a>
+                                        <a href={format!("#/suppliers/{}", supplier.id)} class="btn btn-sm btn-danger">{t("Review")}</a>
                                     </div>
                                 }
                             </div>
@@ -556,13 +558,13 @@ pub fn supplier_risk() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Risk Level"</label>
+                        <label>{t("Risk Level")}</label>
                         <select on:change={handle_risk_filter}>
-                            <option value="">"All Risk Levels"</option>
-                            <option value="low">"Low"</option>
-                            <option value="medium">"Medium"</option>
-                            <option value="high">"High"</option>
-                            <option value="critical">"Critical"</option>
+                            <option value="">{t("All Risk Levels")}</option>
+                            <option value="low">{t("Low")}</option>
+                            <option value="medium">{t("Medium")}</option>
+                            <option value="high">{t("High")}</option>
+                            <option value="critical">{t("Critical")}</option>
                         </select>
                     </div>
                 },
@@ -572,7 +574,7 @@ pub fn supplier_risk() -> View {
                         <input
                             type="text"
                             class="search-input"
-                            placeholder="Search suppliers..."
+                            placeholder={t("Search suppliers...")}
                             on:input={handle_search}
                         />
                     </div>
@@ -581,15 +583,15 @@ pub fn supplier_risk() -> View {
 
             // Suppliers at risk table
             {panel(
-                format!("Suppliers at Risk ({} total)", filtered_suppliers.len()),
+                tf("Suppliers at Risk ({count} total)", &[("count", filtered_suppliers.len().to_string())]),
                 vec![],
                 vec![
                     if loading.get() {
-                        view! { <div class="loading-overlay">"Loading risk data..."</div> }
+                        view! { <div class="loading-overlay">{t("Loading risk data...")}</div> }
                     } else if filtered_suppliers.is_empty() {
                         view! {
                             <div class="loading-overlay">
-                                "No suppliers match the selected criteria."
+                                {t("No suppliers match the selected criteria.")}
                             </div>
                         }
                     } else {
@@ -606,25 +608,25 @@ pub fn supplier_risk() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Indicator Status"</label>
+                        <label>{t("Indicator Status")}</label>
                         <select on:change={handle_indicator_status}>
-                            <option value="">"All Statuses"</option>
-                            <option value="open">"Open"</option>
-                            <option value="review">"Under Review"</option>
-                            <option value="mitigated">"Mitigated"</option>
-                            <option value="closed">"Closed"</option>
+                            <option value="">{t("All Statuses")}</option>
+                            <option value="open">{t("Open")}</option>
+                            <option value="review">{t("Under Review")}</option>
+                            <option value="mitigated">{t("Mitigated")}</option>
+                            <option value="closed">{t("Closed")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Category"</label>
+                        <label>{t("Category")}</label>
                         <select on:change={handle_category_filter}>
-                            <option value="">"All Categories"</option>
-                            <option value="financial">"Financial"</option>
-                            <option value="compliance">"Compliance"</option>
-                            <option value="performance">"Performance"</option>
-                            <option value="delivery">"Delivery"</option>
+                            <option value="">{t("All Categories")}</option>
+                            <option value="financial">{t("Financial")}</option>
+                            <option value="compliance">{t("Compliance")}</option>
+                            <option value="performance">{t("Performance")}</option>
+                            <option value="delivery">{t("Delivery")}</option>
                         </select>
                     </div>
                 },
@@ -633,13 +635,13 @@ pub fn supplier_risk() -> View {
 
             // Risk indicators table
             {panel(
-                format!("Risk Indicators ({} total)", filtered_indicators.len()),
+                tf("Risk Indicators ({count} total)", &[("count", filtered_indicators.len().to_string())]),
                 vec![],
                 vec![
                     if filtered_indicators.is_empty() {
                         view! {
                             <div class="loading-overlay">
-                                "No risk indicators found."
+                                {t("No risk indicators found.")}
                             </div>
                         }
                     } else {
@@ -1,6 +1,9 @@
 //! Supplier domain types
 
 use serde::{Deserialize, Serialize};
+use crate::util::i18n::t;
+use crate::util::money::Money;
+use crate::util::url::{QueryFilter, QueryParams};
 
 /// B-BBEE certification levels
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -18,7 +21,7 @@ pub enum BbbeeLevel {
 
 impl BbbeeLevel {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             BbbeeLevel::Level1 => "Level 1",
             BbbeeLevel::Level2 => "Level 2",
             BbbeeLevel::Level3 => "Level 3",
@@ -28,7 +31,7 @@ impl BbbeeLevel {
             BbbeeLevel::Level7 => "Level 7",
             BbbeeLevel::Level8 => "Level 8",
             BbbeeLevel::NonCompliant => "Non-Compliant",
-        }
+        })
     }
 
     pub fn from_u8(level: u8) -> Self {
@@ -58,6 +61,18 @@ impl BbbeeLevel {
             BbbeeLevel::NonCompliant => None,
         }
     }
+
+    /// URL / filter value: the level number, or `non`
+    pub fn as_param(&self) -> String {
+        self.to_u8().map(|l| l.to_string()).unwrap_or_else(|| "non".to_string())
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "non" => Some(BbbeeLevel::NonCompliant),
+            _ => s.parse::<u8>().ok().filter(|l| (1..=8).contains(l)).map(Self::from_u8),
+        }
+    }
 }
 
 impl Default for BbbeeLevel {
@@ -77,12 +92,12 @@ pub enum RiskRating {
 
 impl RiskRating {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             RiskRating::Low => "Low",
             RiskRating::Medium => "Medium",
             RiskRating::High => "High",
             RiskRating::Critical => "Critical",
-        }
+        })
     }
 
     pub fn color(&self) -> &'static str {
@@ -93,6 +108,25 @@ impl RiskRating {
             RiskRating::Critical => "#8b0000",
         }
     }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskRating::Low => "low",
+            RiskRating::Medium => "medium",
+            RiskRating::High => "high",
+            RiskRating::Critical => "critical",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "low" => Some(RiskRating::Low),
+            "medium" => Some(RiskRating::Medium),
+            "high" => Some(RiskRating::High),
+            "critical" => Some(RiskRating::Critical),
+            _ => None,
+        }
+    }
 }
 
 impl Default for RiskRating {
@@ -120,13 +154,36 @@ pub enum SupplierStatus {
 
 impl SupplierStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             SupplierStatus::Pending => "Pending",
             SupplierStatus::Active => "Active",
             SupplierStatus::Suspended => "Suspended",
             SupplierStatus::Blacklisted => "Blacklisted",
             SupplierStatus::Expired => "Expired",
             SupplierStatus::Inactive => "Inactive",
+        })
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SupplierStatus::Pending => "pending",
+            SupplierStatus::Active => "active",
+            SupplierStatus::Suspended => "suspended",
+            SupplierStatus::Blacklisted => "blacklisted",
+            SupplierStatus::Expired => "expired",
+            SupplierStatus::Inactive => "inactive",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(SupplierStatus::Pending),
+            "active" => Some(SupplierStatus::Active),
+            "suspended" => Some(SupplierStatus::Suspended),
+            "blacklisted" => Some(SupplierStatus::Blacklisted),
+            "expired" => Some(SupplierStatus::Expired),
+            "inactive" => Some(SupplierStatus::Inactive),
+            _ => None,
         }
     }
 }
@@ -169,13 +226,13 @@ pub enum RiskIndicatorStatus {
 
 impl RiskIndicatorStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             RiskIndicatorStatus::Open => "Open",
             RiskIndicatorStatus::UnderReview => "Under Review",
             RiskIndicatorStatus::Mitigated => "Mitigated",
             RiskIndicatorStatus::Accepted => "Accepted",
             RiskIndicatorStatus::Closed => "Closed",
-        }
+        })
     }
 }
 
@@ -216,12 +273,31 @@ pub struct SupplierContract {
     pub id: String,
     pub reference: String,
     pub description: String,
-    pub value: f64,
+    pub value: Money,
     pub start_date: String,
     pub end_date: String,
     pub status: String,
 }
 
+/// B-BBEE certificate submitted for a supplier, applied to the supplier
+/// record once it has been verified
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SupplierCertificate {
+    pub id: String,
+    pub supplier_id: String,
+    pub supplier_name: String,
+    pub certificate_number: String,
+    pub bbbee_level: BbbeeLevel,
+    pub issue_date: String,
+    pub expiry_date: String,
+    pub verification_agency: String,
+    /// Scanned document the details were read from
+    pub source_document: Option<String>,
+    pub verified: bool,
+    pub submitted_by: String,
+    pub submitted_at: String,
+}
+
 /// Main Supplier entity
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Supplier {
@@ -272,7 +348,7 @@ pub struct Supplier {
 
     // Contracts
     pub active_contracts: u32,
-    pub total_contract_value: f64,
+    pub total_contract_value: Money,
     pub contracts: Vec<SupplierContract>,
 
     // CSD (Central Supplier Database) reference
@@ -314,7 +390,7 @@ impl Default for Supplier {
             verified_at: None,
             last_active: String::new(),
             active_contracts: 0,
-            total_contract_value: 0.0,
+            total_contract_value: Money::ZERO,
             contracts: Vec::new(),
             csd_number: None,
             csd_verified: false,
@@ -334,6 +410,30 @@ pub struct SupplierFilter {
     pub min_performance_score: Option<f64>,
 }
 
+impl QueryFilter for SupplierFilter {
+    fn from_query(params: &QueryParams) -> Self {
+        Self {
+            search_query: params.get("q"),
+            status: params.get("status").and_then(|s| SupplierStatus::parse(&s)),
+            bbbee_level: params.get("bbbee").and_then(|l| BbbeeLevel::parse(&l)),
+            risk_rating: params.get("risk").and_then(|r| RiskRating::parse(&r)),
+            category: params.get("category"),
+            province: params.get("province"),
+            min_performance_score: params.get_parsed("min_score"),
+        }
+    }
+
+    fn write_query(&self, params: &mut QueryParams) {
+        params.set("q", self.search_query.as_ref());
+        params.set("status", self.status.map(|s| s.as_str()));
+        params.set("bbbee", self.bbbee_level.map(|l| l.as_param()));
+        params.set("risk", self.risk_rating.map(|r| r.as_str()));
+        params.set("category", self.category.as_ref());
+        params.set("province", self.province.as_ref());
+        params.set("min_score", self.min_performance_score);
+    }
+}
+
 /// Pagination state
 #[derive(Clone, Debug)]
 pub struct PaginationState {
@@ -355,7 +455,7 @@ impl Default for PaginationState {
 }
 
 /// Supplier summary for KPIs
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct SupplierKpis {
     pub total_suppliers: u32,
     pub active_suppliers: u32,
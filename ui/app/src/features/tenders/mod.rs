@@ -3,6 +3,7 @@
 pub mod types;
 pub mod store;
 pub mod service;
+pub mod search;
 pub mod tender_list;
 pub mod tender_form;
 pub mod tender_publication;
@@ -0,0 +1,50 @@
+//! Tender search provider
+
+use crate::Route;
+use crate::features::search::provider::{SearchFuture, SearchProvider};
+use crate::features::search::types::{match_score, SearchEntity, SearchHit};
+use crate::util::format::format_currency;
+use super::store::TendersStore;
+use super::service;
+
+/// Searches the tenders held in the store, loading them on first use
+pub struct TenderSearch {
+    store: TendersStore,
+}
+
+impl TenderSearch {
+    pub fn new(store: TendersStore) -> Self {
+        Self { store }
+    }
+}
+
+impl SearchProvider for TenderSearch {
+    fn entity(&self) -> SearchEntity {
+        SearchEntity::Tender
+    }
+
+    fn search(&self, query: String) -> SearchFuture {
+        let store = self.store.clone();
+        Box::pin(async move {
+            if store.tenders.get().is_empty() {
+                service::load_tenders(&store).await;
+                if let Some(e) = store.error.get() {
+                    return Err(e);
+                }
+            }
+
+            let hits = store.tenders.get().iter().filter_map(|t| {
+                let score = match_score(&query, &[&t.id, &t.reference_number], &[&t.title, &t.category, &t.department])?;
+                Some(SearchHit {
+                    entity: SearchEntity::Tender,
+                    id: t.id.clone(),
+                    title: t.title.clone(),
+                    subtitle: format!("{} - {} - {}", t.reference_number, t.department, format_currency(t.estimated_value)),
+                    route: Route::TendersEdit(t.id.clone()),
+                    score,
+                })
+            }).collect();
+            Ok(hits)
+        })
+    }
+}
@@ -1,17 +1,31 @@
 //! Tenders service - API calls
 
+use crate::features::workflow::service as workflow;
+use crate::features::workflow::types::{ApprovalDecision, ApprovalDocument, ApprovalRequest, WorkflowState};
+use crate::util::api::{self, HttpMethod};
+use crate::util::auth;
+use crate::util::backend;
+use crate::util::datetime::{today, holiday_name, is_business_day, next_business_day, Date};
+use crate::util::format::format_date;
+use crate::util::reference;
 use super::store::{TendersStore, load_mock_data, select_tender, clear_selection};
-use super::types::{Tender, TenderFilter, TenderStatus};
+use super::types::{Tender, TenderFilter, TenderStatus, TenderType};
 
 /// Load tenders list
 pub async fn load_tenders(store: &TendersStore) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production, this would call the API:
-    // let response = api::get("/api/tenders", &store.filter.get()).await;
-    // For now, load mock data
-    load_mock_data(store);
+    if backend::is_mock() {
+        load_mock_data(store);
+    } else if let Err(e) = backend::fetch_into("/tenders", &store.tenders).await {
+        store.error.set(Some(e.to_string()));
+    } else {
+        let mut pagination = store.pagination.get();
+        pagination.total_items = store.tenders.get().len() as u32;
+        pagination.total_pages = (pagination.total_items + pagination.page_size - 1) / pagination.page_size;
+        store.pagination.set(pagination);
+    }
 
     store.loading.set(false);
 }
@@ -27,11 +41,20 @@ pub async fn get_tender(store: &TendersStore, tender_id: &str) {
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::get(&format!("/api/tenders/{}", tender_id)).await;
-    // For now, find in mock data
+    // A deep link can land here before the list page has populated the store
+    if backend::is_mock() && store.tenders.get().is_empty() {
+        load_mock_data(store);
+    }
+
     select_tender(store, tender_id);
 
+    if store.selected.get().is_none() && !backend::is_mock() {
+        match api::get::<Tender>(&format!("/tenders/{}", tender_id)).await {
+            Ok(tender) => store.selected.set(Some(tender)),
+            Err(e) => store.error.set(Some(e.to_string())),
+        }
+    }
+
     store.loading.set(false);
 }
 
@@ -39,21 +62,40 @@ pub async fn get_tender(store: &TendersStore, tender_id: &str) {
 pub async fn create_tender(store: &TendersStore, tender: Tender) -> Result<Tender, String> {
     store.loading.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
-    // In production:
-    // let response = api::post("/api/tenders", &tender).await;
-
-    // Generate a new ID for mock
+    // Provisional ID, replaced by the server's when using the REST backend
     let mut new_tender = tender;
-    let count = store.tenders.get().len() + 1;
-    new_tender.id = format!("TND-2025-{:04}", 100 + count);
-    new_tender.reference_number = format!("{}-2025-{:04}",
-        new_tender.tender_type.label(),
-        100 + count
-    );
+    let doc_type = new_tender.tender_type.document_type();
+    if backend::is_mock() && store.tenders.get().is_empty() {
+        load_mock_data(store);
+    }
+    let existing = store.tenders.get();
+    let issued = reference::issue(doc_type, existing.iter().map(|t| t.reference_number.as_str()));
+    // RFQs, RFPs and RFTs share one register, so the sequence alone identifies the tender
+    new_tender.id = format!("TND-{}-{:04}", issued.fiscal_year.end_year(), issued.sequence);
+    new_tender.reference_number = issued.number;
     new_tender.status = TenderStatus::Draft;
-    new_tender.created_at = "2025-02-15".to_string();
-    new_tender.last_modified_at = "2025-02-15".to_string();
+    new_tender.created_at = today().to_iso();
+    new_tender.last_modified_at = today().to_iso();
+    if let Some(user) = auth::current().user {
+        new_tender.created_by = user.name.clone();
+        new_tender.created_by_id = user.id;
+        new_tender.last_modified_by = user.name;
+    }
+
+    match backend::save(HttpMethod::Post, "/tenders", &new_tender).await {
+        Ok(saved) => {
+            reference::reconcile(doc_type, &new_tender.reference_number, &saved.reference_number);
+            new_tender = saved;
+        }
+        Err(e) => {
+            store.field_errors.set(e.field_errors().to_vec());
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
 
     // Add to list
     let mut tenders = store.tenders.get();
@@ -64,15 +106,48 @@ pub async fn create_tender(store: &TendersStore, tender: Tender) -> Result<Tende
     Ok(new_tender)
 }
 
+/// Tenders already raised from a requisition, so a resumed conversion finds
+/// what an interrupted one created
+pub async fn tenders_for_requisition(store: &TendersStore, requisition_id: &str) -> Result<Vec<Tender>, String> {
+    if backend::is_mock() {
+        if store.tenders.get().is_empty() {
+            load_mock_data(store);
+        }
+        Ok(store.tenders.get().into_iter().filter(|t| t.requisition_ref.as_deref() == Some(requisition_id)).collect())
+    } else {
+        api::get::<Vec<Tender>>(&format!("/requisitions/{}/tenders", requisition_id))
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
 /// Update existing tender
 pub async fn update_tender(store: &TendersStore, tender: Tender) -> Result<Tender, String> {
     store.loading.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
-    // In production:
-    // let response = api::put(&format!("/api/tenders/{}", tender.id), &tender).await;
+    // The form does not edit the source requisition or its bill of quantities
+    let mut tender = tender;
+    if let Some(existing) = store.tenders.get().iter().find(|t| t.id == tender.id) {
+        if tender.requisition_ref.is_none() {
+            tender.requisition_ref = existing.requisition_ref.clone();
+        }
+        if tender.line_items.is_empty() {
+            tender.line_items = existing.line_items.clone();
+        }
+    }
+    let endpoint = format!("/tenders/{}", tender.id);
+    match backend::save(HttpMethod::Put, &endpoint, &tender).await {
+        Ok(saved) => tender = saved,
+        Err(e) => {
+            store.field_errors.set(e.field_errors().to_vec());
+            store.loading.set(false);
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
 
-    // Update in mock list
     let mut tenders = store.tenders.get();
     if let Some(pos) = tenders.iter().position(|t| t.id == tender.id) {
         tenders[pos] = tender.clone();
@@ -87,19 +162,46 @@ pub async fn update_tender(store: &TendersStore, tender: Tender) -> Result<Tende
     }
 }
 
-/// Submit tender for approval
+/// Submit tender for approval, routing it along the chain the
+/// delegation-of-authority matrix prescribes for its value and any deviation
 pub async fn submit_for_approval(store: &TendersStore, tender_id: &str) -> Result<(), String> {
     store.loading.set(true);
     store.error.set(None);
+    store.field_errors.set(Vec::new());
 
-    // In production:
-    // let response = api::post(&format!("/api/tenders/{}/submit", tender_id), &{}).await;
-
-    // Update status in mock
     let mut tenders = store.tenders.get();
     if let Some(pos) = tenders.iter().position(|t| t.id == tender_id) {
+        if tenders[pos].status != TenderStatus::Draft {
+            store.loading.set(false);
+            store.error.set(Some("Only draft tenders can be submitted for approval".to_string()));
+            return Err("Only draft tenders can be submitted for approval".to_string());
+        }
+
+        let request = ApprovalRequest::new(ApprovalDocument::Tender, tenders[pos].estimated_value)
+            .department(&tenders[pos].department)
+            .categories([tenders[pos].category.clone()])
+            .deviation(tenders[pos].deviation_type);
+        match workflow::approval_chain(&request).await {
+            Ok(chain) => tenders[pos].approval_workflow = chain,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.clone()));
+                return Err(e);
+            }
+        }
+
         tenders[pos].status = TenderStatus::PendingApproval;
-        tenders[pos].last_modified_at = "2025-02-15".to_string();
+        tenders[pos].last_modified_at = today().to_iso();
+        let endpoint = format!("/tenders/{}/submit", tender_id);
+        match backend::save(HttpMethod::Post, &endpoint, &tenders[pos]).await {
+            Ok(saved) => tenders[pos] = saved,
+            Err(e) => {
+                store.field_errors.set(e.field_errors().to_vec());
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.tenders.set(tenders.clone());
         store.selected.set(Some(tenders[pos].clone()));
         store.loading.set(false);
@@ -111,15 +213,56 @@ pub async fn submit_for_approval(store: &TendersStore, tender_id: &str) -> Resul
     }
 }
 
-/// Approve tender
-pub async fn approve_tender(store: &TendersStore, tender_id: &str) -> Result<(), String> {
+/// Record an approver's decision on the tender's current approval stage.
+///
+/// The tender is approved once the last stage approves; a rejection cancels
+/// it and a return sends it back to draft. Approving a deviation records who
+/// signed it off.
+pub async fn decide_tender(store: &TendersStore, tender_id: &str, decision: &ApprovalDecision) -> Result<(), String> {
     store.loading.set(true);
     store.error.set(None);
 
     let mut tenders = store.tenders.get();
     if let Some(pos) = tenders.iter().position(|t| t.id == tender_id) {
-        tenders[pos].status = TenderStatus::Approved;
-        tenders[pos].last_modified_at = "2025-02-15".to_string();
+        if tenders[pos].status != TenderStatus::PendingApproval {
+            store.loading.set(false);
+            store.error.set(Some("Tender must be in Pending Approval status to approve".to_string()));
+            return Err("Tender must be in Pending Approval status to approve".to_string());
+        }
+
+        let requester = tenders[pos].created_by_id.clone();
+        let applied = match workflow::apply(ApprovalDocument::Tender, tender_id, &requester, &mut tenders[pos].approval_workflow, decision) {
+            Ok(applied) => applied,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.clone()));
+                return Err(e);
+            }
+        };
+        match applied.state {
+            WorkflowState::Approved => {
+                tenders[pos].status = TenderStatus::Approved;
+                if tenders[pos].deviation_type.is_some() {
+                    tenders[pos].deviation_approved_by = auth::current().user.map(|u| u.name);
+                    tenders[pos].deviation_approved_at = Some(today().to_iso());
+                }
+            }
+            WorkflowState::Rejected => tenders[pos].status = TenderStatus::Cancelled,
+            WorkflowState::Returned => tenders[pos].status = TenderStatus::Draft,
+            WorkflowState::InProgress { .. } | WorkflowState::NotStarted => {}
+        }
+
+        tenders[pos].last_modified_at = today().to_iso();
+        let endpoint = format!("/tenders/{}/approval", tender_id);
+        match backend::save(HttpMethod::Post, &endpoint, &tenders[pos]).await {
+            Ok(saved) => tenders[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
+        applied.record();
         store.tenders.set(tenders.clone());
         store.selected.set(Some(tenders[pos].clone()));
         store.loading.set(false);
@@ -142,16 +285,14 @@ pub async fn publish_tender(
     store.loading.set(true);
     store.error.set(None);
 
-    // In production:
-    // let response = api::post(&format!("/api/tenders/{}/publish", tender_id), &PublishRequest {
-    //     publish_date,
-    //     closing_date,
-    //     briefing_date,
-    // }).await;
-
-    // Update status and dates in mock
     let mut tenders = store.tenders.get();
     if let Some(pos) = tenders.iter().position(|t| t.id == tender_id) {
+        if let Err(e) = check_publication_dates(&tenders[pos], publish_date, closing_date, briefing_date) {
+            store.loading.set(false);
+            store.error.set(Some(e.clone()));
+            return Err(e);
+        }
+
         tenders[pos].status = TenderStatus::Published;
         tenders[pos].publish_date = Some(publish_date.to_string());
         tenders[pos].closing_date = Some(closing_date.to_string());
@@ -161,7 +302,18 @@ pub async fn publish_tender(
         let portal_ref = format!("eTender-{}", tender_id.replace("TND-", ""));
         tenders[pos].portal_reference = Some(portal_ref.clone());
         tenders[pos].portal_url = Some(format!("https://etenders.gov.za/tender/{}", portal_ref));
-        tenders[pos].last_modified_at = "2025-02-15".to_string();
+        tenders[pos].last_modified_at = today().to_iso();
+
+        let endpoint = format!("/tenders/{}/publish", tender_id);
+        match backend::save(HttpMethod::Post, &endpoint, &tenders[pos]).await {
+            Ok(saved) => tenders[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
+        let portal_ref = tenders[pos].portal_reference.clone().unwrap_or(portal_ref);
 
         store.tenders.set(tenders.clone());
         store.selected.set(Some(tenders[pos].clone()));
@@ -174,6 +326,56 @@ pub async fn publish_tender(
     }
 }
 
+/// Earliest date a tender published on `publish_date` may close: the
+/// minimum advertising period, rolled forward to a business day
+pub fn earliest_closing_date(tender_type: TenderType, publish_date: &str) -> Option<Date> {
+    let publish = Date::parse(publish_date)?;
+    Some(next_business_day(publish.add_days(tender_type.minimum_advert_days() as i64)))
+}
+
+/// Check publication dates against the Treasury advertising rules
+fn check_publication_dates(
+    tender: &Tender,
+    publish_date: &str,
+    closing_date: &str,
+    briefing_date: Option<&str>,
+) -> Result<(), String> {
+    let publish = Date::parse(publish_date).ok_or("Publication date is not a valid date")?;
+    let closing = Date::parse(closing_date).ok_or("Closing date is not a valid date")?;
+
+    if publish < today() {
+        return Err("Publication date cannot be in the past".to_string());
+    }
+    if !is_business_day(closing) {
+        return Err(match holiday_name(closing) {
+            Some(holiday) => format!("Closing date falls on {}; tenders must close on a business day", holiday),
+            None => "Closing date falls on a weekend; tenders must close on a business day".to_string(),
+        });
+    }
+    if let Some(earliest) = earliest_closing_date(tender.tender_type, publish_date) {
+        if closing < earliest {
+            return Err(format!(
+                "{} tenders must be advertised for at least {} days; the earliest closing date is {}",
+                tender.tender_type.label(),
+                tender.tender_type.minimum_advert_days(),
+                format_date(&earliest.to_iso())
+            ));
+        }
+    }
+
+    if let Some(briefing_date) = briefing_date {
+        let briefing = Date::parse(briefing_date).ok_or("Briefing date is not a valid date")?;
+        if briefing < publish || briefing >= closing {
+            return Err("Briefing session must be held after publication and before the closing date".to_string());
+        }
+        if !is_business_day(briefing) {
+            return Err("Briefing session must be held on a business day".to_string());
+        }
+    }
+
+    Ok(())
+}
+
 /// Cancel tender
 pub async fn cancel_tender(store: &TendersStore, tender_id: &str, reason: &str) -> Result<(), String> {
     store.loading.set(true);
@@ -182,7 +384,16 @@ pub async fn cancel_tender(store: &TendersStore, tender_id: &str, reason: &str)
     let mut tenders = store.tenders.get();
     if let Some(pos) = tenders.iter().position(|t| t.id == tender_id) {
         tenders[pos].status = TenderStatus::Cancelled;
-        tenders[pos].last_modified_at = "2025-02-15".to_string();
+        tenders[pos].last_modified_at = today().to_iso();
+        let endpoint = format!("/tenders/{}/cancel?reason={}", tender_id, js_sys::encode_uri_component(reason));
+        match backend::save(HttpMethod::Post, &endpoint, &tenders[pos]).await {
+            Ok(saved) => tenders[pos] = saved,
+            Err(e) => {
+                store.loading.set(false);
+                store.error.set(Some(e.to_string()));
+                return Err(e.to_string());
+            }
+        }
         store.tenders.set(tenders.clone());
         store.selected.set(Some(tenders[pos].clone()));
         store.loading.set(false);
@@ -208,6 +419,12 @@ pub async fn delete_tender(store: &TendersStore, tender_id: &str) -> Result<(),
         }
     }
 
+    if let Err(e) = backend::remove(&format!("/tenders/{}", tender_id)).await {
+        store.loading.set(false);
+        store.error.set(Some(e.to_string()));
+        return Err(e.to_string());
+    }
+
     let new_tenders: Vec<_> = tenders.into_iter()
         .filter(|t| t.id != tender_id)
         .collect();
@@ -1,6 +1,9 @@
 //! Tenders store
 
 use components::prelude::*;
+use crate::features::workflow::types::{ApprovalStep, StepStatus};
+use crate::util::api::FieldError;
+use crate::util::money::Money;
 use super::types::{Tender, TenderFilter, TenderType, TenderStatus, PaginationState, EvaluationCriterion, TenderDocument, Bid, BidStatus};
 
 /// Tenders state store
@@ -12,6 +15,8 @@ pub struct TendersStore {
     pub pagination: Signal<PaginationState>,
     pub loading: Signal<bool>,
     pub error: Signal<Option<String>>,
+    /// Per-field validation errors from the last failed save
+    pub field_errors: Signal<Vec<FieldError>>,
 }
 
 impl TendersStore {
@@ -23,6 +28,7 @@ impl TendersStore {
             pagination: signal(PaginationState::default()),
             loading: signal(false),
             error: signal(None),
+            field_errors: signal(Vec::new()),
         }
     }
 }
@@ -37,7 +43,7 @@ pub fn load_mock_data(store: &TendersStore) {
             description: "Comprehensive upgrade of data centre infrastructure including servers, storage, and network equipment.".to_string(),
             tender_type: TenderType::Rfp,
             status: TenderStatus::Open,
-            estimated_value: 15_500_000.0,
+            estimated_value: Money::rands(15_500_000),
             currency: "ZAR".to_string(),
             created_at: "2025-01-15".to_string(),
             publish_date: Some("2025-01-20".to_string()),
@@ -56,6 +62,8 @@ pub fn load_mock_data(store: &TendersStore) {
                 "Minimum 5 years experience".to_string(),
                 "ISO 27001 certification".to_string(),
             ],
+            requisition_ref: None,
+            line_items: Vec::new(),
             evaluation_criteria: vec![
                 EvaluationCriterion {
                     id: "EC-001".to_string(),
@@ -107,7 +115,7 @@ pub fn load_mock_data(store: &TendersStore) {
                     supplier_id: "SUP-001".to_string(),
                     supplier_name: "TechSolutions SA (Pty) Ltd".to_string(),
                     submitted_at: "2025-02-20".to_string(),
-                    total_price: 14_250_000.0,
+                    total_price: Money::rands(14_250_000),
                     bbbee_level: Some(1),
                     status: BidStatus::Compliant,
                     technical_score: Some(85.0),
@@ -121,7 +129,7 @@ pub fn load_mock_data(store: &TendersStore) {
                     supplier_id: "SUP-002".to_string(),
                     supplier_name: "DataCore Systems".to_string(),
                     submitted_at: "2025-02-22".to_string(),
-                    total_price: 15_800_000.0,
+                    total_price: Money::rands(15_800_000),
                     bbbee_level: Some(2),
                     status: BidStatus::UnderReview,
                     technical_score: None,
@@ -136,7 +144,9 @@ pub fn load_mock_data(store: &TendersStore) {
             deviation_justification: None,
             deviation_approved_by: None,
             deviation_approved_at: None,
+            approval_workflow: Vec::new(),
             created_by: "John Smith".to_string(),
+            created_by_id: "U017".to_string(),
             last_modified_by: "John Smith".to_string(),
             last_modified_at: "2025-01-20".to_string(),
         },
@@ -147,7 +157,7 @@ pub fn load_mock_data(store: &TendersStore) {
             description: "Supply of ergonomic office furniture for new building wing.".to_string(),
             tender_type: TenderType::Rfq,
             status: TenderStatus::Draft,
-            estimated_value: 850_000.0,
+            estimated_value: Money::rands(850_000),
             currency: "ZAR".to_string(),
             created_at: "2025-02-01".to_string(),
             publish_date: None,
@@ -165,6 +175,8 @@ pub fn load_mock_data(store: &TendersStore) {
                 "Valid B-BBEE certificate".to_string(),
                 "SABS certification".to_string(),
             ],
+            requisition_ref: None,
+            line_items: Vec::new(),
             evaluation_criteria: vec![],
             price_weight: 90.0,
             bbbee_weight: 10.0,
@@ -177,7 +189,9 @@ pub fn load_mock_data(store: &TendersStore) {
             deviation_justification: None,
             deviation_approved_by: None,
             deviation_approved_at: None,
+            approval_workflow: Vec::new(),
             created_by: "Jane Doe".to_string(),
+            created_by_id: "U018".to_string(),
             last_modified_by: "Jane Doe".to_string(),
             last_modified_at: "2025-02-01".to_string(),
         },
@@ -188,7 +202,7 @@ pub fn load_mock_data(store: &TendersStore) {
             description: "Provision of comprehensive security services for all facilities.".to_string(),
             tender_type: TenderType::Rft,
             status: TenderStatus::Evaluation,
-            estimated_value: 24_000_000.0,
+            estimated_value: Money::rands(24_000_000),
             currency: "ZAR".to_string(),
             created_at: "2024-12-01".to_string(),
             publish_date: Some("2024-12-10".to_string()),
@@ -208,6 +222,8 @@ pub fn load_mock_data(store: &TendersStore) {
                 "Minimum 10 years experience".to_string(),
                 "Armed response capability".to_string(),
             ],
+            requisition_ref: None,
+            line_items: Vec::new(),
             evaluation_criteria: vec![
                 EvaluationCriterion {
                     id: "EC-001".to_string(),
@@ -251,7 +267,7 @@ pub fn load_mock_data(store: &TendersStore) {
                     supplier_id: "SUP-003".to_string(),
                     supplier_name: "SecureGuard Holdings".to_string(),
                     submitted_at: "2025-01-30".to_string(),
-                    total_price: 22_500_000.0,
+                    total_price: Money::rands(22_500_000),
                     bbbee_level: Some(1),
                     status: BidStatus::Shortlisted,
                     technical_score: Some(88.0),
@@ -265,7 +281,7 @@ pub fn load_mock_data(store: &TendersStore) {
                     supplier_id: "SUP-004".to_string(),
                     supplier_name: "Protea Security Services".to_string(),
                     submitted_at: "2025-01-29".to_string(),
-                    total_price: 23_800_000.0,
+                    total_price: Money::rands(23_800_000),
                     bbbee_level: Some(2),
                     status: BidStatus::Shortlisted,
                     technical_score: Some(92.0),
@@ -280,7 +296,9 @@ pub fn load_mock_data(store: &TendersStore) {
             deviation_justification: None,
             deviation_approved_by: None,
             deviation_approved_at: None,
+            approval_workflow: Vec::new(),
             created_by: "Peter Jones".to_string(),
+            created_by_id: "U023".to_string(),
             last_modified_by: "Peter Jones".to_string(),
             last_modified_at: "2025-02-05".to_string(),
         },
@@ -291,7 +309,7 @@ pub fn load_mock_data(store: &TendersStore) {
             description: "Outsourced internal audit services for 3-year period.".to_string(),
             tender_type: TenderType::Rfp,
             status: TenderStatus::PendingApproval,
-            estimated_value: 4_500_000.0,
+            estimated_value: Money::rands(4_500_000),
             currency: "ZAR".to_string(),
             created_at: "2025-02-10".to_string(),
             publish_date: None,
@@ -310,6 +328,8 @@ pub fn load_mock_data(store: &TendersStore) {
                 "IRBA registration".to_string(),
                 "Professional indemnity insurance".to_string(),
             ],
+            requisition_ref: None,
+            line_items: Vec::new(),
             evaluation_criteria: vec![
                 EvaluationCriterion {
                     id: "EC-001".to_string(),
@@ -344,7 +364,26 @@ pub fn load_mock_data(store: &TendersStore) {
             deviation_justification: None,
             deviation_approved_by: None,
             deviation_approved_at: None,
+            approval_workflow: vec![
+                ApprovalStep {
+                    step: 1,
+                    role: "Procurement Manager".to_string(),
+                    approver: Some("Johan van der Merwe".to_string()),
+                    approver_id: None,
+                    status: StepStatus::Approved,
+                    date: Some("2025-02-11T10:30:00Z".to_string()),
+                    comments: Some("Specification and evaluation criteria in order".to_string()),
+                    delegated_from: None,
+                    delegated_from_id: None,
+                    assigned_at: None,
+                    due_date: None,
+                    reminded_at: None,
+                    escalated_from: None,
+                },
+                ApprovalStep::new(2, "CFO"),
+            ],
             created_by: "Sarah Williams".to_string(),
+            created_by_id: "U019".to_string(),
             last_modified_by: "Sarah Williams".to_string(),
             last_modified_at: "2025-02-10".to_string(),
         },
@@ -355,7 +394,7 @@ pub fn load_mock_data(store: &TendersStore) {
             description: "Maintenance services for corporate vehicle fleet.".to_string(),
             tender_type: TenderType::Rfq,
             status: TenderStatus::Awarded,
-            estimated_value: 1_200_000.0,
+            estimated_value: Money::rands(1_200_000),
             currency: "ZAR".to_string(),
             created_at: "2024-11-01".to_string(),
             publish_date: Some("2024-11-05".to_string()),
@@ -373,6 +412,8 @@ pub fn load_mock_data(store: &TendersStore) {
                 "Valid B-BBEE certificate".to_string(),
                 "Authorized dealer status".to_string(),
             ],
+            requisition_ref: None,
+            line_items: Vec::new(),
             evaluation_criteria: vec![],
             price_weight: 90.0,
             bbbee_weight: 10.0,
@@ -385,7 +426,7 @@ pub fn load_mock_data(store: &TendersStore) {
                     supplier_id: "SUP-005".to_string(),
                     supplier_name: "AutoCare Services".to_string(),
                     submitted_at: "2024-11-28".to_string(),
-                    total_price: 1_150_000.0,
+                    total_price: Money::rands(1_150_000),
                     bbbee_level: Some(2),
                     status: BidStatus::Awarded,
                     technical_score: Some(78.0),
@@ -400,7 +441,9 @@ pub fn load_mock_data(store: &TendersStore) {
             deviation_justification: None,
             deviation_approved_by: None,
             deviation_approved_at: None,
+            approval_workflow: Vec::new(),
             created_by: "Mike Brown".to_string(),
+            created_by_id: "U020".to_string(),
             last_modified_by: "Mike Brown".to_string(),
             last_modified_at: "2024-12-15".to_string(),
         },
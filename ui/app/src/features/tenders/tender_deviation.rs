@@ -1,6 +1,11 @@
 //! Tender deviation request form for single-source/emergency procurement
 
 use components::prelude::*;
+use crate::Route;
+use crate::features::workflow::service as workflow;
+use crate::features::workflow::store::WorkflowStore;
+use crate::features::workflow::types::{ApprovalDocument, ApprovalRequest};
+use crate::page::not_found::entity_not_found;
 use crate::shared::layout::page_header;
 use crate::shared::components::{
     panel, panel_with_footer,
@@ -13,20 +18,23 @@ use crate::shared::forms::{
     form_group, checkbox, radio_group, RadioOption,
 };
 use crate::util::format::format_currency;
+use crate::util::money::Money;
 use super::store::TendersStore;
 use super::types::{Tender, TenderType, TenderStatus, DeviationType};
 use super::service;
+use crate::util::i18n::{t, tf};
 
-/// Deviation request form
+/// Deviation request form for an existing tender
 #[component]
-pub fn tender_deviation() -> View {
+pub fn tender_deviation(tender_id: String) -> View {
     let store = use_context::<TendersStore>();
+    let workflow_store = use_context::<WorkflowStore>();
 
     // Form fields - Basic info
     let title = signal(String::new());
     let description = signal(String::new());
     let deviation_type = signal("single_source".to_string());
-    let estimated_value = signal(0.0);
+    let estimated_value = signal(Money::ZERO);
     let category = signal(String::new());
     let department = signal(String::new());
 
@@ -53,46 +61,100 @@ pub fn tender_deviation() -> View {
     let submitting = signal(false);
     let error = signal::<Option<String>>(None);
     let success = signal::<Option<String>>(None);
+    let missing = signal(false);
+
+    // Prefill from the tender the deviation is raised against
+    effect({
+        let store = store.clone();
+        let tender_id = tender_id.clone();
+        let title = title.clone();
+        let description = description.clone();
+        let deviation_type = deviation_type.clone();
+        let estimated_value = estimated_value.clone();
+        let category = category.clone();
+        let department = department.clone();
+        let justification = justification.clone();
+        let missing = missing.clone();
+        move || {
+            let store = store.clone();
+            let tender_id = tender_id.clone();
+            let title = title.clone();
+            let description = description.clone();
+            let deviation_type = deviation_type.clone();
+            let estimated_value = estimated_value.clone();
+            let category = category.clone();
+            let department = department.clone();
+            let justification = justification.clone();
+            let missing = missing.clone();
+            spawn(async move {
+                service::get_tender(&store, &tender_id).await;
+                if let Some(tender) = store.selected.get().filter(|t| t.id == tender_id) {
+                    title.set(tender.title);
+                    description.set(tender.description);
+                    estimated_value.set(tender.estimated_value);
+                    category.set(tender.category);
+                    department.set(tender.department);
+                    if let Some(dev_type) = tender.deviation_type {
+                        deviation_type.set(match dev_type {
+                            DeviationType::SingleSource => "single_source".to_string(),
+                            DeviationType::Emergency => "emergency".to_string(),
+                            DeviationType::SoleSupplier => "sole_supplier".to_string(),
+                            DeviationType::Strategic => "strategic".to_string(),
+                        });
+                    }
+                    if let Some(text) = tender.deviation_justification {
+                        justification.set(text);
+                    }
+                } else {
+                    missing.set(true);
+                }
+            });
+        }
+    });
+
+    if missing.get() {
+        return entity_not_found(t("Tender"), &tender_id, store.error.get(), Route::TendersList, t("Back to Tenders"));
+    }
 
     // Deviation type options
     let deviation_options = vec![
         RadioOption {
             value: "single_source".to_string(),
-            label: "Single Source - Only one supplier can provide the goods/services".to_string(),
+            label: t("Single Source - Only one supplier can provide the goods/services").to_string(),
         },
         RadioOption {
             value: "emergency".to_string(),
-            label: "Emergency - Urgent procurement due to unforeseen circumstances".to_string(),
+            label: t("Emergency - Urgent procurement due to unforeseen circumstances").to_string(),
         },
         RadioOption {
             value: "sole_supplier".to_string(),
-            label: "Sole Supplier - Supplier has exclusive rights (patents, licenses)".to_string(),
+            label: t("Sole Supplier - Supplier has exclusive rights (patents, licenses)").to_string(),
         },
         RadioOption {
             value: "strategic".to_string(),
-            label: "Strategic Partnership - Existing strategic relationship".to_string(),
+            label: t("Strategic Partnership - Existing strategic relationship").to_string(),
         },
     ];
 
     // Category options
     let category_options = vec![
-        SelectOption { value: "".to_string(), label: "Select Category".to_string() },
-        SelectOption { value: "it".to_string(), label: "Information Technology".to_string() },
-        SelectOption { value: "facilities".to_string(), label: "Facilities Management".to_string() },
-        SelectOption { value: "security".to_string(), label: "Security Services".to_string() },
-        SelectOption { value: "professional".to_string(), label: "Professional Services".to_string() },
-        SelectOption { value: "fleet".to_string(), label: "Fleet Services".to_string() },
-        SelectOption { value: "other".to_string(), label: "Other".to_string() },
+        SelectOption { value: "".to_string(), label: t("Select Category").to_string() },
+        SelectOption { value: "it".to_string(), label: t("Information Technology").to_string() },
+        SelectOption { value: "facilities".to_string(), label: t("Facilities Management").to_string() },
+        SelectOption { value: "security".to_string(), label: t("Security Services").to_string() },
+        SelectOption { value: "professional".to_string(), label: t("Professional Services").to_string() },
+        SelectOption { value: "fleet".to_string(), label: t("Fleet Services").to_string() },
+        SelectOption { value: "other".to_string(), label: t("Other").to_string() },
     ];
 
     // Department options
     let department_options = vec![
-        SelectOption { value: "".to_string(), label: "Select Department".to_string() },
-        SelectOption { value: "corporate".to_string(), label: "Corporate Services".to_string() },
-        SelectOption { value: "finance".to_string(), label: "Finance".to_string() },
-        SelectOption { value: "operations".to_string(), label: "Operations".to_string() },
-        SelectOption { value: "hr".to_string(), label: "Human Resources".to_string() },
-        SelectOption { value: "it".to_string(), label: "IT".to_string() },
+        SelectOption { value: "".to_string(), label: t("Select Department").to_string() },
+        SelectOption { value: "corporate".to_string(), label: t("Corporate Services").to_string() },
+        SelectOption { value: "finance".to_string(), label: t("Finance").to_string() },
+        SelectOption { value: "operations".to_string(), label: t("Operations").to_string() },
+        SelectOption { value: "hr".to_string(), label: t("Human Resources").to_string() },
+        SelectOption { value: "it".to_string(), label: t("IT").to_string() },
     ];
 
     // Update emergency flag when deviation type changes
@@ -119,6 +181,7 @@ pub fn tender_deviation() -> View {
     // Handle submit
     let handle_submit = Callback::<()>::new({
         let store = store.clone();
+        let tender_id = tender_id.clone();
         let title = title.clone();
         let description = description.clone();
         let deviation_type = deviation_type.clone();
@@ -139,36 +202,36 @@ pub fn tender_deviation() -> View {
 
             // Validation
             if title.get().is_empty() {
-                error.set(Some("Please enter a title".to_string()));
+                error.set(Some(t("Please enter a title").to_string()));
                 return;
             }
             if justification.get().is_empty() {
-                error.set(Some("Please provide justification".to_string()));
+                error.set(Some(t("Please provide justification").to_string()));
                 return;
             }
             if supplier_name.get().is_empty() {
-                error.set(Some("Please enter supplier name".to_string()));
+                error.set(Some(t("Please enter supplier name").to_string()));
                 return;
             }
 
             // Build tender with deviation
-            let dev_type = match deviation_type.get().as_str() {
-                "emergency" => Some(DeviationType::Emergency),
-                "sole_supplier" => Some(DeviationType::SoleSupplier),
-                "strategic" => Some(DeviationType::Strategic),
-                _ => Some(DeviationType::SingleSource),
-            };
+            let dev_type = Some(parse_deviation_type(&deviation_type.get()));
 
+            // Attach the deviation to the loaded tender, or raise a new one
+            let existing = store.selected.get().filter(|t| t.id == tender_id);
+            let is_existing = existing.is_some();
             let tender = Tender {
                 title: title.get(),
                 description: description.get(),
-                tender_type: TenderType::Rfq, // Deviations typically use simplified process
                 estimated_value: estimated_value.get(),
                 category: category.get(),
                 department: department.get(),
                 deviation_type: dev_type,
                 deviation_justification: Some(justification.get()),
-                ..Default::default()
+                ..existing.unwrap_or_else(|| Tender {
+                    tender_type: TenderType::Rfq, // Deviations typically use simplified process
+                    ..Default::default()
+                })
             };
 
             spawn(async move {
@@ -176,11 +239,16 @@ pub fn tender_deviation() -> View {
                 error.set(None);
                 success.set(None);
 
-                match service::create_tender(&store, tender).await {
-                    Ok(t) => {
-                        success.set(Some(format!(
-                            "Deviation request {} created successfully. It will be routed for approval.",
-                            t.reference_number
+                let result = if is_existing {
+                    service::update_tender(&store, tender).await
+                } else {
+                    service::create_tender(&store, tender).await
+                };
+                match result {
+                    Ok(created) => {
+                        success.set(Some(tf(
+                            "Deviation request {reference} created successfully. It will be routed for approval.",
+                            &[("reference", created.reference_number)],
                         )));
                     }
                     Err(e) => {
@@ -201,11 +269,18 @@ pub fn tender_deviation() -> View {
         }
     });
 
-    // Value threshold for approval requirements
+    // Approvers the delegation-of-authority matrix adds for this deviation
     let value = estimated_value.get();
-    let requires_cfo = value > 500_000.0;
-    let requires_ao = value > 2_000_000.0;
-    let requires_treasury = value > 10_000_000.0;
+    let request = ApprovalRequest::new(ApprovalDocument::Tender, value)
+        .department(&department.get())
+        .categories([category.get()])
+        .deviation(Some(parse_deviation_type(&deviation_type.get())));
+    let chain = workflow::build_chain(&workflow_store.matrix.get(), &request);
+    let requires = |role: &str| chain.iter().any(|s| s.role == role);
+    let requires_cfo = requires("CFO");
+    let requires_ao = requires("Accounting Officer");
+    let requires_treasury = requires("National Treasury");
+    let ao_by_value = value > Money::rands(2_000_000);
 
     view! {
         style {
@@ -306,10 +381,10 @@ pub fn tender_deviation() -> View {
 
         <div class="tender-deviation" data-testid="tender-deviation">
             {page_header(
-                "Deviation Request".to_string(),
-                Some("Request deviation from competitive bidding process".to_string()),
+                t("Deviation Request").to_string(),
+                Some(t("Request deviation from competitive bidding process").to_string()),
                 vec![
-                    view! { <a href="#/tenders" class="btn btn-secondary">"Cancel"</a> },
+                    view! { <a href="#/tenders" class="btn btn-secondary">{t("Cancel")}</a> },
                 ]
             )}
 
@@ -323,18 +398,18 @@ pub fn tender_deviation() -> View {
 
             // Warning notice
             {notice_bar(
-                "Deviations from competitive bidding must be justified in terms of Treasury Regulation 16A6.4 and require appropriate approval based on value.".to_string(),
+                t("Deviations from competitive bidding must be justified in terms of Treasury Regulation 16A6.4 and require appropriate approval based on value.").to_string(),
                 NoticeType::Warning,
                 None,
             )}
 
             // Deviation type selection
             {panel(
-                "Deviation Type".to_string(),
-                vec![tag("Requires Justification".to_string(), TagType::Limited)],
+                t("Deviation Type").to_string(),
+                vec![tag(t("Requires Justification").to_string(), TagType::Limited)],
                 vec![
                     radio_group(
-                        "Select Deviation Type".to_string(),
+                        t("Select Deviation Type").to_string(),
                         "deviation_type".to_string(),
                         deviation_type.clone(),
                         deviation_options,
@@ -347,20 +422,20 @@ pub fn tender_deviation() -> View {
                     view! {
                         <div class="deviation-type-info">
                             if deviation_type.get() == "single_source" {
-                                <h4>"Single Source Procurement"</h4>
-                                <p>"Applicable when only one supplier can provide the specific goods or services required. Must demonstrate that no reasonable alternative exists."</p>
+                                <h4>{t("Single Source Procurement")}</h4>
+                                <p>{t("Applicable when only one supplier can provide the specific goods or services required. Must demonstrate that no reasonable alternative exists.")}</p>
                             }
                             if deviation_type.get() == "emergency" {
-                                <h4>"Emergency Procurement"</h4>
-                                <p>"Applicable for urgent requirements due to unforeseen circumstances. Must demonstrate immediate need and inability to follow normal process."</p>
+                                <h4>{t("Emergency Procurement")}</h4>
+                                <p>{t("Applicable for urgent requirements due to unforeseen circumstances. Must demonstrate immediate need and inability to follow normal process.")}</p>
                             }
                             if deviation_type.get() == "sole_supplier" {
-                                <h4>"Sole Supplier"</h4>
-                                <p>"Applicable when supplier has exclusive rights through patents, licenses, or proprietary technology. Must provide evidence of exclusivity."</p>
+                                <h4>{t("Sole Supplier")}</h4>
+                                <p>{t("Applicable when supplier has exclusive rights through patents, licenses, or proprietary technology. Must provide evidence of exclusivity.")}</p>
                             }
                             if deviation_type.get() == "strategic" {
-                                <h4>"Strategic Partnership"</h4>
-                                <p>"Applicable for existing strategic relationships that provide significant value. Must demonstrate strategic importance and value."</p>
+                                <h4>{t("Strategic Partnership")}</h4>
+                                <p>{t("Applicable for existing strategic relationships that provide significant value. Must demonstrate strategic importance and value.")}</p>
                             }
                         </div>
                     }
@@ -369,17 +444,17 @@ pub fn tender_deviation() -> View {
 
             // Basic information
             {panel(
-                "Procurement Details".to_string(),
+                t("Procurement Details").to_string(),
                 vec![],
                 vec![
                     form_group(
-                        Some("Basic Information".to_string()),
+                        Some(t("Basic Information").to_string()),
                         2,
                         vec![
                             text_input(
-                                "Title".to_string(),
+                                t("Title").to_string(),
                                 title.clone(),
-                                Some("Brief description of procurement".to_string()),
+                                Some(t("Brief description of procurement").to_string()),
                                 true,
                                 false,
                                 None,
@@ -387,7 +462,7 @@ pub fn tender_deviation() -> View {
                                 None,
                             ),
                             currency_input(
-                                "Estimated Value".to_string(),
+                                t("Estimated Value").to_string(),
                                 estimated_value.clone(),
                                 true,
                                 false,
@@ -401,9 +476,9 @@ pub fn tender_deviation() -> View {
                         1,
                         vec![
                             textarea(
-                                "Description".to_string(),
+                                t("Description").to_string(),
                                 description.clone(),
-                                Some("Detailed description of goods/services required".to_string()),
+                                Some(t("Detailed description of goods/services required").to_string()),
                                 true,
                                 false,
                                 Some(4),
@@ -413,11 +488,11 @@ pub fn tender_deviation() -> View {
                         ]
                     ),
                     form_group(
-                        Some("Classification".to_string()),
+                        Some(t("Classification").to_string()),
                         2,
                         vec![
                             select(
-                                "Category".to_string(),
+                                t("Category").to_string(),
                                 category.clone(),
                                 category_options,
                                 None,
@@ -426,7 +501,7 @@ pub fn tender_deviation() -> View {
                                 None,
                             ),
                             select(
-                                "Department".to_string(),
+                                t("Department").to_string(),
                                 department.clone(),
                                 department_options,
                                 None,
@@ -441,20 +516,20 @@ pub fn tender_deviation() -> View {
 
             // Supplier information
             {panel(
-                "Proposed Supplier".to_string(),
+                t("Proposed Supplier").to_string(),
                 vec![],
                 vec![
                     view! {
                         <div class="supplier-section">
-                            <h4>"Supplier Details"</h4>
+                            <h4>{t("Supplier Details")}</h4>
                             {form_group(
                                 None,
                                 2,
                                 vec![
                                     text_input(
-                                        "Supplier Name".to_string(),
+                                        t("Supplier Name").to_string(),
                                         supplier_name.clone(),
-                                        Some("Name of proposed supplier".to_string()),
+                                        Some(t("Name of proposed supplier").to_string()),
                                         true,
                                         false,
                                         None,
@@ -468,9 +543,9 @@ pub fn tender_deviation() -> View {
                                 1,
                                 vec![
                                     textarea(
-                                        "Why This Supplier".to_string(),
+                                        t("Why This Supplier").to_string(),
                                         supplier_reason.clone(),
-                                        Some("Explain why this specific supplier is required".to_string()),
+                                        Some(t("Explain why this specific supplier is required").to_string()),
                                         true,
                                         false,
                                         Some(3),
@@ -487,18 +562,18 @@ pub fn tender_deviation() -> View {
             // Emergency section (if applicable)
             if is_emergency.get() {
                 {panel(
-                    "Emergency Details".to_string(),
-                    vec![tag("Emergency".to_string(), TagType::Limited)],
+                    t("Emergency Details").to_string(),
+                    vec![tag(t("Emergency").to_string(), TagType::Limited)],
                     vec![
                         view! {
                             <div class="emergency-section">
-                                <h4>"Emergency Circumstances"</h4>
+                                <h4>{t("Emergency Circumstances")}</h4>
                                 {form_group(
                                     None,
                                     2,
                                     vec![
                                         date_picker(
-                                            "Date Emergency Arose".to_string(),
+                                            t("Date Emergency Arose").to_string(),
                                             emergency_date.clone(),
                                             true,
                                             false,
@@ -513,9 +588,9 @@ pub fn tender_deviation() -> View {
                                     1,
                                     vec![
                                         textarea(
-                                            "Emergency Circumstances".to_string(),
+                                            t("Emergency Circumstances").to_string(),
                                             emergency_reason.clone(),
-                                            Some("Describe the emergency and why normal procurement cannot be followed".to_string()),
+                                            Some(t("Describe the emergency and why normal procurement cannot be followed").to_string()),
                                             true,
                                             false,
                                             Some(4),
@@ -532,38 +607,38 @@ pub fn tender_deviation() -> View {
 
             // Justification
             {panel(
-                "Justification".to_string(),
+                t("Justification").to_string(),
                 vec![],
                 vec![
                     form_group(
-                        Some("Deviation Justification".to_string()),
+                        Some(t("Deviation Justification").to_string()),
                         1,
                         vec![
                             textarea(
-                                "Full Justification".to_string(),
+                                t("Full Justification").to_string(),
                                 justification.clone(),
-                                Some("Provide detailed justification for deviating from competitive bidding".to_string()),
+                                Some(t("Provide detailed justification for deviating from competitive bidding").to_string()),
                                 true,
                                 false,
                                 Some(6),
                                 None,
-                                Some("Include: reasons why competitive bidding is not possible, value for money considerations, risk assessment".to_string()),
+                                Some(t("Include: reasons why competitive bidding is not possible, value for money considerations, risk assessment").to_string()),
                             ),
                         ]
                     ),
                     form_group(
-                        Some("Market Analysis".to_string()),
+                        Some(t("Market Analysis").to_string()),
                         1,
                         vec![
                             textarea(
-                                "Market Research".to_string(),
+                                t("Market Research").to_string(),
                                 market_analysis.clone(),
-                                Some("Describe market research conducted".to_string()),
+                                Some(t("Describe market research conducted").to_string()),
                                 false,
                                 false,
                                 Some(4),
                                 None,
-                                Some("What alternatives were considered? Why were they not suitable?".to_string()),
+                                Some(t("What alternatives were considered? Why were they not suitable?").to_string()),
                             ),
                         ]
                     ),
@@ -572,20 +647,20 @@ pub fn tender_deviation() -> View {
 
             // Supporting documents
             {panel(
-                "Supporting Documents".to_string(),
+                t("Supporting Documents").to_string(),
                 vec![],
                 vec![
                     file_upload(
-                        "Upload Supporting Documents".to_string(),
+                        t("Upload Supporting Documents").to_string(),
                         documents.clone(),
                         Some(".pdf,.doc,.docx,.xls,.xlsx".to_string()),
                         true,
                         false,
-                        Some("Upload quotations, market research, supplier documentation".to_string()),
+                        Some(t("Upload quotations, market research, supplier documentation").to_string()),
                         handle_file_remove,
                     ),
                     notice_bar(
-                        "Required documents: Supplier quotation, Market research evidence, Supplier B-BBEE certificate, Tax clearance".to_string(),
+                        t("Required documents: Supplier quotation, Market research evidence, Supplier B-BBEE certificate, Tax clearance").to_string(),
                         NoticeType::Info,
                         None,
                     ),
@@ -594,7 +669,7 @@ pub fn tender_deviation() -> View {
 
             // Approval requirements
             {panel(
-                "Approval Requirements".to_string(),
+                t("Approval Requirements").to_string(),
                 vec![],
                 vec![
                     view! {
@@ -604,12 +679,12 @@ pub fn tender_deviation() -> View {
                                     if requires_cfo { "1" } else { "-" }
                                 </div>
                                 <div class="approval-content">
-                                    <h4>"CFO Approval"</h4>
+                                    <h4>{t("CFO Approval")}</h4>
                                     <p>
                                         if requires_cfo {
-                                            "Required for values above R500,000"
+                                            {t("Required for values above R500,000")}
                                         } else {
-                                            "Not required (value below R500,000)"
+                                            {t("Not required (value below R500,000)")}
                                         }
                                     </p>
                                 </div>
@@ -620,12 +695,14 @@ pub fn tender_deviation() -> View {
                                     if requires_ao { "2" } else { "-" }
                                 </div>
                                 <div class="approval-content">
-                                    <h4>"Accounting Officer Approval"</h4>
+                                    <h4>{t("Accounting Officer Approval")}</h4>
                                     <p>
-                                        if requires_ao {
-                                            "Required for values above R2,000,000"
+                                        if requires_ao && ao_by_value {
+                                            {t("Required for values above R2,000,000")}
+                                        } else if requires_ao {
+                                            {t("Required to ratify emergency procurement")}
                                         } else {
-                                            "Not required (value below R2,000,000)"
+                                            {t("Not required (value below R2,000,000)")}
                                         }
                                     </p>
                                 </div>
@@ -636,12 +713,12 @@ pub fn tender_deviation() -> View {
                                     if requires_treasury { "3" } else { "-" }
                                 </div>
                                 <div class="approval-content">
-                                    <h4>"National Treasury Approval"</h4>
+                                    <h4>{t("National Treasury Approval")}</h4>
                                     <p>
                                         if requires_treasury {
-                                            "Required for values above R10,000,000"
+                                            {t("Required for values above R10,000,000")}
                                         } else {
-                                            "Not required (value below R10,000,000)"
+                                            {t("Not required (value below R10,000,000)")}
                                         }
                                     </p>
                                 </div>
@@ -652,9 +729,9 @@ pub fn tender_deviation() -> View {
                     view! {
                         <div style="margin-top: 16px;">
                             <p style="font-size: 12px; color: var(--text-muted);">
-                                "Based on estimated value of "
+                                {t("Based on estimated value of ")}
                                 <strong>{format_currency(value)}</strong>
-                                ", the following approvals will be required before proceeding."
+                                {t(", the following approvals will be required before proceeding.")}
                             </p>
                         </div>
                     }
@@ -663,20 +740,20 @@ pub fn tender_deviation() -> View {
 
             // Form actions
             {panel_with_footer(
-                "Submit Request".to_string(),
+                t("Submit Request").to_string(),
                 vec![],
                 vec![
                     notice_bar(
-                        "By submitting this request, you confirm that you have conducted appropriate market research and that deviation from competitive bidding is justified.".to_string(),
+                        t("By submitting this request, you confirm that you have conducted appropriate market research and that deviation from competitive bidding is justified.").to_string(),
                         NoticeType::Warning,
                         None,
                     ),
                 ],
                 vec![
-                    view! { <button class="btn btn-secondary" on:click={handle_save_draft}>"Save Draft"</button> },
+                    view! { <button class="btn btn-secondary" on:click={handle_save_draft}>{t("Save Draft")}</button> },
                     view! {
                         <button class="btn btn-primary" on:click={handle_submit} disabled={submitting.get()}>
-                            if submitting.get() { "Submitting..." } else { "Submit for Approval" }
+                            {if submitting.get() { t("Submitting...") } else { t("Submit for Approval") }}
                         </button>
                     },
                 ]
@@ -684,3 +761,12 @@ pub fn tender_deviation() -> View {
         </div>
     }
 }
+
+fn parse_deviation_type(value: &str) -> DeviationType {
+    match value {
+        "emergency" => DeviationType::Emergency,
+        "sole_supplier" => DeviationType::SoleSupplier,
+        "strategic" => DeviationType::Strategic,
+        _ => DeviationType::SingleSource,
+    }
+}
@@ -1,21 +1,29 @@
 //! Tender create/edit form with multi-step wizard
 
 use components::prelude::*;
+use crate::Route;
+use crate::features::workflow::approval_timeline::{approval_timeline, approval_actions};
+use crate::features::workflow::types::ApprovalDecision;
+use crate::page::not_found::entity_not_found;
 use crate::shared::layout::page_header;
 use crate::shared::components::{
     panel, panel_with_footer,
     stepper_with_testid, StepperItem, StepStatus,
     notice_bar, NoticeType,
+    data_table, DataTableColumn, DataTableRow,
 };
 use crate::shared::forms::{
     text_input, text_input_with_testid, textarea, select, select_with_testid, SelectOption,
-    currency_input, currency_input_with_testid, date_picker, file_upload, UploadedFile,
+    currency_input, currency_input_with_testid, percentage_input, date_picker, file_upload, UploadedFile,
     form_group, checkbox,
 };
-use crate::util::format::format_currency;
+use crate::util::format::{format_currency, format_currency_full};
+use crate::util::money::Money;
+use crate::util::api::field_error;
 use super::store::TendersStore;
-use super::types::{Tender, TenderType, EvaluationCriterion, TenderDocument};
+use super::types::{Tender, TenderType, EvaluationCriterion, TenderDocument, TenderLineItem};
 use super::service;
+use crate::util::i18n::{t, tn};
 
 /// Form steps
 #[derive(Clone, Copy, PartialEq)]
@@ -39,13 +47,13 @@ impl FormStep {
     }
 
     fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             FormStep::BasicInfo => "Basic Info",
             FormStep::Scope => "Scope",
             FormStep::Criteria => "Criteria",
             FormStep::Documents => "Documents",
             FormStep::Review => "Review",
-        }
+        })
     }
 }
 
@@ -62,7 +70,7 @@ pub fn tender_form(tender_id: Option<String>) -> View {
     let title = signal(String::new());
     let description = signal(String::new());
     let tender_type = signal("rfq".to_string());
-    let estimated_value = signal(0.0);
+    let estimated_value = signal(Money::ZERO);
     let category = signal(String::new());
     let department = signal(String::new());
 
@@ -91,6 +99,12 @@ pub fn tender_form(tender_id: Option<String>) -> View {
     // Form state
     let saving = signal(false);
     let error = signal::<Option<String>>(None);
+    // Set when the tender being edited cannot be found
+    let missing = signal(false);
+
+    // Validation errors from the last save, keyed by field
+    let field_errors = store.field_errors.clone();
+    let field_err = |field: &str| field_error(&field_errors.get(), field);
 
     // Load existing tender if editing
     effect({
@@ -111,6 +125,7 @@ pub fn tender_form(tender_id: Option<String>) -> View {
         let bbbee_weight = bbbee_weight.clone();
         let functionality_threshold = functionality_threshold.clone();
         let criteria = criteria.clone();
+        let missing = missing.clone();
 
         move || {
             if let Some(ref id) = tender_id {
@@ -131,10 +146,11 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                 let bbbee_weight = bbbee_weight.clone();
                 let functionality_threshold = functionality_threshold.clone();
                 let criteria = criteria.clone();
+                let missing = missing.clone();
 
                 spawn(async move {
                     service::get_tender(&store, &id).await;
-                    if let Some(tender) = store.selected.get() {
+                    if let Some(tender) = store.selected.get().filter(|t| t.id == id) {
                         title.set(tender.title);
                         description.set(tender.description);
                         tender_type.set(match tender.tender_type {
@@ -154,6 +170,8 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                         bbbee_weight.set(tender.bbbee_weight);
                         functionality_threshold.set(tender.functionality_threshold);
                         criteria.set(tender.evaluation_criteria);
+                    } else {
+                        missing.set(true);
                     }
                 });
             }
@@ -164,35 +182,35 @@ pub fn tender_form(tender_id: Option<String>) -> View {
     let steps = vec![
         StepperItem {
             number: 1,
-            label: "Basic Info".to_string(),
+            label: t("Basic Info").to_string(),
             status: if current_step.get().index() > 1 { StepStatus::Completed }
                    else if current_step.get() == FormStep::BasicInfo { StepStatus::Active }
                    else { StepStatus::Pending },
         },
         StepperItem {
             number: 2,
-            label: "Scope".to_string(),
+            label: t("Scope").to_string(),
             status: if current_step.get().index() > 2 { StepStatus::Completed }
                    else if current_step.get() == FormStep::Scope { StepStatus::Active }
                    else { StepStatus::Pending },
         },
         StepperItem {
             number: 3,
-            label: "Criteria".to_string(),
+            label: t("Criteria").to_string(),
             status: if current_step.get().index() > 3 { StepStatus::Completed }
                    else if current_step.get() == FormStep::Criteria { StepStatus::Active }
                    else { StepStatus::Pending },
         },
         StepperItem {
             number: 4,
-            label: "Documents".to_string(),
+            label: t("Documents").to_string(),
             status: if current_step.get().index() > 4 { StepStatus::Completed }
                    else if current_step.get() == FormStep::Documents { StepStatus::Active }
                    else { StepStatus::Pending },
         },
         StepperItem {
             number: 5,
-            label: "Review".to_string(),
+            label: t("Review").to_string(),
             status: if current_step.get() == FormStep::Review { StepStatus::Active }
                    else { StepStatus::Pending },
         },
@@ -401,38 +419,70 @@ pub fn tender_form(tender_id: Option<String>) -> View {
 
     // Category options
     let category_options = vec![
-        SelectOption { value: "".to_string(), label: "Select Category".to_string() },
-        SelectOption { value: "it".to_string(), label: "Information Technology".to_string() },
-        SelectOption { value: "facilities".to_string(), label: "Facilities Management".to_string() },
-        SelectOption { value: "security".to_string(), label: "Security Services".to_string() },
-        SelectOption { value: "professional".to_string(), label: "Professional Services".to_string() },
-        SelectOption { value: "fleet".to_string(), label: "Fleet Services".to_string() },
-        SelectOption { value: "furniture".to_string(), label: "Furniture & Fittings".to_string() },
-        SelectOption { value: "other".to_string(), label: "Other".to_string() },
+        SelectOption { value: "".to_string(), label: t("Select Category").to_string() },
+        SelectOption { value: "it".to_string(), label: t("Information Technology").to_string() },
+        SelectOption { value: "facilities".to_string(), label: t("Facilities Management").to_string() },
+        SelectOption { value: "security".to_string(), label: t("Security Services").to_string() },
+        SelectOption { value: "professional".to_string(), label: t("Professional Services").to_string() },
+        SelectOption { value: "fleet".to_string(), label: t("Fleet Services").to_string() },
+        SelectOption { value: "furniture".to_string(), label: t("Furniture & Fittings").to_string() },
+        SelectOption { value: "other".to_string(), label: t("Other").to_string() },
     ];
 
     // Department options
     let department_options = vec![
-        SelectOption { value: "".to_string(), label: "Select Department".to_string() },
-        SelectOption { value: "corporate".to_string(), label: "Corporate Services".to_string() },
-        SelectOption { value: "finance".to_string(), label: "Finance".to_string() },
-        SelectOption { value: "operations".to_string(), label: "Operations".to_string() },
-        SelectOption { value: "hr".to_string(), label: "Human Resources".to_string() },
-        SelectOption { value: "it".to_string(), label: "IT".to_string() },
+        SelectOption { value: "".to_string(), label: t("Select Department").to_string() },
+        SelectOption { value: "corporate".to_string(), label: t("Corporate Services").to_string() },
+        SelectOption { value: "finance".to_string(), label: t("Finance").to_string() },
+        SelectOption { value: "operations".to_string(), label: t("Operations").to_string() },
+        SelectOption { value: "hr".to_string(), label: t("Human Resources").to_string() },
+        SelectOption { value: "it".to_string(), label: t("IT").to_string() },
     ];
 
     // Tender type options
     let type_options = vec![
-        SelectOption { value: "rfq".to_string(), label: "RFQ - Request for Quotation".to_string() },
-        SelectOption { value: "rfp".to_string(), label: "RFP - Request for Proposal".to_string() },
-        SelectOption { value: "rft".to_string(), label: "RFT - Request for Tender".to_string() },
+        SelectOption { value: "rfq".to_string(), label: t("RFQ - Request for Quotation").to_string() },
+        SelectOption { value: "rfp".to_string(), label: t("RFP - Request for Proposal").to_string() },
+        SelectOption { value: "rft".to_string(), label: t("RFT - Request for Tender").to_string() },
     ];
 
-    let page_title = if is_edit { "Edit Tender" } else { "New Tender" };
+    let handle_decide = Callback::new({
+        let store = store.clone();
+        let error = error.clone();
+        let tender_id = tender_id.clone();
+        move |decision: ApprovalDecision| {
+            if let Some(id) = tender_id.clone() {
+                let store = store.clone();
+                let error = error.clone();
+                spawn(async move {
+                    if let Err(e) = service::decide_tender(&store, &id, &decision).await {
+                        error.set(Some(e));
+                    }
+                });
+            }
+        }
+    });
+
+    // Approval chain of the tender being edited, once it has been submitted
+    let approval_workflow = store.selected.get()
+        .filter(|t| Some(&t.id) == tender_id.as_ref())
+        .map(|t| (t.created_by_id, t.approval_workflow))
+        .filter(|(_, steps)| !steps.is_empty());
+
+    // Requisition the tender being edited was raised from
+    let source = store.selected.get()
+        .filter(|t| Some(&t.id) == tender_id.as_ref())
+        .and_then(|t| t.requisition_ref.map(|requisition_ref| (requisition_ref, t.line_items)));
+
+    if let (true, Some(id)) = (missing.get(), tender_id.as_ref()) {
+        return entity_not_found(t("Tender"), id, store.error.get(), Route::TendersList, t("Back to Tenders"));
+    }
+
+    let page_title = if is_edit { t("Edit Tender") } else { t("New Tender") };
     let page_subtitle = if is_edit {
-        "Update tender details"
+        t("Update tender details")
     } else {
-        "Create a new procurement tender"
+        t("Create a new procurement tender")
     };
 
     view! {
@@ -496,6 +546,11 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                 border-radius: var(--radius-sm);
                 font-size: 13px;
             }
+            .tender-source {
+                font-size: 13px;
+                color: var(--text-muted);
+                margin-bottom: 12px;
+            }
             .weight-summary {
                 margin-top: 16px;
                 padding: 12px;
@@ -511,7 +566,7 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                 page_title.to_string(),
                 Some(page_subtitle.to_string()),
                 vec![
-                    view! { <a href="#/tenders" class="btn btn-secondary">"Cancel"</a> },
+                    view! { <a href="#/tenders" class="btn btn-secondary">{t("Cancel")}</a> },
                 ]
             )}
 
@@ -520,6 +575,33 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                 {notice_bar(err, NoticeType::Error, None)}
             }
 
+            if let Some((requester, workflow)) = approval_workflow {
+                {panel(
+                    t("Approval Workflow").to_string(),
+                    vec![],
+                    vec![
+                        approval_timeline(workflow.clone()),
+                        approval_actions(workflow, requester, store.loading.get(), handle_decide),
+                    ]
+                )}
+            }
+
+            if let Some((requisition_ref, line_items)) = source {
+                {panel(
+                    t("Source Requisition").to_string(),
+                    vec![],
+                    vec![
+                        view! {
+                            <p class="tender-source" data-testid="tender-source">
+                                {t("Raised from approved requisition ")}
+                                <a href={Route::RequisitionsDetail(requisition_ref.clone()).to_path()}>{requisition_ref.clone()}</a>
+                            </p>
+                        },
+                        bill_of_quantities(line_items),
+                    ]
+                )}
+            }
+
             // Stepper
             {stepper_with_testid(steps, Some(handle_step_click), Some("tender-form-stepper".to_string()))}
 
@@ -534,28 +616,28 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                             if current_step.get() == FormStep::BasicInfo {
                                 <div data-testid="tender-form-step-1">
                                     {form_group(
-                                        Some("Tender Details".to_string()),
+                                        Some(t("Tender Details").to_string()),
                                         2,
                                         vec![
                                             text_input_with_testid(
-                                                "Tender Title".to_string(),
+                                                t("Tender Title").to_string(),
                                                 title.clone(),
-                                                Some("Enter a descriptive title".to_string()),
+                                                Some(t("Enter a descriptive title").to_string()),
                                                 true,
                                                 false,
-                                                None,
+                                                field_err("title"),
                                                 None,
                                                 None,
                                                 Some("tender-title-input".to_string()),
                                             ),
                                             select_with_testid(
-                                                "Tender Type".to_string(),
+                                                t("Tender Type").to_string(),
                                                 tender_type.clone(),
                                                 type_options.clone(),
                                                 None,
                                                 true,
                                                 false,
-                                                None,
+                                                field_err("tender_type"),
                                                 Some("tender-type-select".to_string()),
                                             ),
                                         ]
@@ -565,52 +647,52 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                                         1,
                                         vec![
                                             textarea(
-                                                "Description".to_string(),
+                                                t("Description").to_string(),
                                                 description.clone(),
-                                                Some("Brief description of the procurement".to_string()),
+                                                Some(t("Brief description of the procurement").to_string()),
                                                 true,
                                                 false,
                                                 Some(4),
-                                                None,
+                                                field_err("description"),
                                                 None,
                                             ),
                                         ]
                                     )}
                                     {form_group(
-                                        Some("Classification".to_string()),
+                                        Some(t("Classification").to_string()),
                                         2,
                                         vec![
                                             select(
-                                                "Category".to_string(),
+                                                t("Category").to_string(),
                                                 category.clone(),
                                                 category_options.clone(),
                                                 None,
                                                 true,
                                                 false,
-                                                None,
+                                                field_err("category"),
                                             ),
                                             select(
-                                                "Department".to_string(),
+                                                t("Department").to_string(),
                                                 department.clone(),
                                                 department_options.clone(),
                                                 None,
                                                 true,
                                                 false,
-                                                None,
+                                                field_err("department"),
                                             ),
                                         ]
                                     )}
                                     {form_group(
-                                        Some("Value".to_string()),
+                                        Some(t("Value").to_string()),
                                         2,
                                         vec![
                                             currency_input_with_testid(
-                                                "Estimated Value".to_string(),
+                                                t("Estimated Value").to_string(),
                                                 estimated_value.clone(),
                                                 true,
                                                 false,
-                                                None,
-                                                Some("Estimated contract value in ZAR".to_string()),
+                                                field_err("estimated_value"),
+                                                Some(t("Estimated contract value in ZAR").to_string()),
                                                 Some("tender-value-input".to_string()),
                                             ),
                                         ]
@@ -622,81 +704,81 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                             if current_step.get() == FormStep::Scope {
                                 <div>
                                     {form_group(
-                                        Some("Scope of Work".to_string()),
+                                        Some(t("Scope of Work").to_string()),
                                         1,
                                         vec![
                                             textarea(
-                                                "Scope Description".to_string(),
+                                                t("Scope Description").to_string(),
                                                 scope_of_work.clone(),
-                                                Some("Detailed description of work to be performed".to_string()),
+                                                Some(t("Detailed description of work to be performed").to_string()),
                                                 true,
                                                 false,
                                                 Some(6),
-                                                None,
+                                                field_err("scope_of_work"),
                                                 None,
                                             ),
                                         ]
                                     )}
                                     {form_group(
-                                        Some("Technical Requirements".to_string()),
+                                        Some(t("Technical Requirements").to_string()),
                                         1,
                                         vec![
                                             textarea(
-                                                "Technical Specifications".to_string(),
+                                                t("Technical Specifications").to_string(),
                                                 technical_requirements.clone(),
-                                                Some("Technical requirements and specifications".to_string()),
+                                                Some(t("Technical requirements and specifications").to_string()),
                                                 false,
                                                 false,
                                                 Some(4),
-                                                None,
+                                                field_err("technical_requirements"),
                                                 None,
                                             ),
                                         ]
                                     )}
                                     {form_group(
-                                        Some("Delivery Details".to_string()),
+                                        Some(t("Delivery Details").to_string()),
                                         2,
                                         vec![
                                             text_input(
-                                                "Delivery Location".to_string(),
+                                                t("Delivery Location").to_string(),
                                                 delivery_location.clone(),
-                                                Some("e.g., Head Office, Johannesburg".to_string()),
+                                                Some(t("e.g., Head Office, Johannesburg").to_string()),
                                                 true,
                                                 false,
-                                                None,
+                                                field_err("delivery_location"),
                                                 None,
                                                 None,
                                             ),
                                             text_input(
-                                                "Contract Duration".to_string(),
+                                                t("Contract Duration").to_string(),
                                                 contract_duration.clone(),
-                                                Some("e.g., 36 months".to_string()),
+                                                Some(t("e.g., 36 months").to_string()),
                                                 true,
                                                 false,
-                                                None,
+                                                field_err("contract_duration"),
                                                 None,
                                                 None,
                                             ),
                                             text_input(
-                                                "Cost Center".to_string(),
+                                                t("Cost Center").to_string(),
                                                 cost_center.clone(),
-                                                Some("e.g., CC-IT-001".to_string()),
+                                                Some(t("e.g., CC-IT-001").to_string()),
                                                 true,
                                                 false,
-                                                None,
+                                                field_err("cost_center"),
                                                 None,
                                                 None,
                                             ),
                                         ]
                                     )}
                                     {form_group(
-                                        Some("Mandatory Requirements".to_string()),
+                                        Some(t("Mandatory Requirements").to_string()),
                                         2,
                                         vec![
-                                            checkbox("Valid B-BBEE certificate".to_string(), req_bbbee.clone(), false),
-                                            checkbox("Tax clearance certificate".to_string(), req_tax.clone(), false),
-                                            checkbox("CIPC registration".to_string(), req_cipc.clone(), false),
-                                            checkbox("Minimum 3 years experience".to_string(), req_experience.clone(), false),
+                                            checkbox(t("Valid B-BBEE certificate").to_string(), req_bbbee.clone(), false),
+                                            checkbox(t("Tax clearance certificate").to_string(), req_tax.clone(), false),
+                                            checkbox(t("CIPC registration").to_string(), req_cipc.clone(), false),
+                                            checkbox(t("Minimum 3 years experience").to_string(), req_experience.clone(), false),
                                         ]
                                     )}
                                 </div>
@@ -706,63 +788,63 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                             if current_step.get() == FormStep::Criteria {
                                 <div>
                                     {form_group(
-                                        Some("Scoring Weights".to_string()),
+                                        Some(t("Scoring Weights").to_string()),
                                         3,
                                         vec![
-                                            currency_input(
-                                                "Price Weight (%)".to_string(),
+                                            percentage_input(
+                                                t("Price Weight (%)").to_string(),
                                                 price_weight.clone(),
                                                 true,
                                                 false,
-                                                None,
+                                                field_err("price_weight"),
                                                 None,
                                             ),
-                                            currency_input(
-                                                "B-BBEE Weight (%)".to_string(),
+                                            percentage_input(
+                                                t("B-BBEE Weight (%)").to_string(),
                                                 bbbee_weight.clone(),
                                                 true,
                                                 false,
-                                                None,
+                                                field_err("bbbee_weight"),
                                                 None,
                                             ),
-                                            currency_input(
-                                                "Functionality Threshold (%)".to_string(),
+                                            percentage_input(
+                                                t("Functionality Threshold (%)").to_string(),
                                                 functionality_threshold.clone(),
                                                 true,
                                                 false,
-                                                None,
-                                                Some("Minimum score to qualify".to_string()),
+                                                field_err("functionality_threshold"),
+                                                Some(t("Minimum score to qualify").to_string()),
                                             ),
                                         ]
                                     )}
 
                                     <div class="form-section">
                                         <div class="form-section-title">
-                                            "Evaluation Criteria"
+                                            {t("Evaluation Criteria")}
                                             <button class="btn btn-sm btn-secondary" style="float: right;" on:click={handle_add_criterion}>
-                                                "+ Add Criterion"
+                                                {t("+ Add Criterion")}
                                             </button>
                                         </div>
                                         <div class="criteria-list">
                                             <div class="criterion-row" style="background: var(--surface); font-weight: 600; font-size: 12px;">
-                                                <span>"Criterion Name"</span>
-                                                <span>"Description"</span>
-                                                <span>"Weight %"</span>
-                                                <span>"Max"</span>
+                                                <span>{t("Criterion Name")}</span>
+                                                <span>{t("Description")}</span>
+                                                <span>{t("Weight %")}</span>
+                                                <span>{t("Max")}</span>
                                             </div>
                                             for (idx, criterion) in criteria.get().iter().enumerate() {
                                                 <div class="criterion-row">
-                                                    <input type="text" value={criterion.name.clone()} placeholder="e.g., Technical Capability" />
-                                                    <input type="text" value={criterion.description.clone()} placeholder="Description" />
+                                                    <input type="text" value={criterion.name.clone()} placeholder={t("e.g., Technical Capability")} />
+                                                    <input type="text" value={criterion.description.clone()} placeholder={t("Description")} />
                                                     <input type="number" value={criterion.weight.to_string()} placeholder="%" />
                                                     <input type="number" value={criterion.max_score.to_string()} placeholder="100" />
                                                 </div>
                                             }
                                         </div>
                                         <div class="weight-summary">
-                                            "Total Functionality Weight: "
+                                            {t("Total Functionality Weight: ")}
                                             <strong>{criteria.get().iter().map(|c| c.weight).sum::<f64>()}"%"</strong>
-                                            " (must equal 100%)"
+                                            {t(" (must equal 100%)")}
                                         </div>
                                     </div>
                                 </div>
@@ -772,17 +854,17 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                             if current_step.get() == FormStep::Documents {
                                 <div>
                                     {file_upload(
-                                        "Tender Documents".to_string(),
+                                        t("Tender Documents").to_string(),
                                         documents.clone(),
                                         Some(".pdf,.doc,.docx,.xls,.xlsx".to_string()),
                                         true,
                                         false,
-                                        Some("Upload specifications, terms, and other tender documents (PDF, Word, Excel)".to_string()),
+                                        Some(t("Upload specifications, terms, and other tender documents (PDF, Word, Excel)").to_string()),
                                         handle_file_remove.clone(),
                                     )}
 
                                     {notice_bar(
-                                        "Recommended documents: Technical Specifications, Terms and Conditions, Evaluation Criteria, Pricing Schedule, Company Profile Template".to_string(),
+                                        t("Recommended documents: Technical Specifications, Terms and Conditions, Evaluation Criteria, Pricing Schedule, Company Profile Template").to_string(),
                                         NoticeType::Info,
                                         None,
                                     )}
@@ -793,81 +875,81 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                             if current_step.get() == FormStep::Review {
                                 <div>
                                     <div class="review-section">
-                                        <h4>"Basic Information"</h4>
+                                        <h4>{t("Basic Information")}</h4>
                                         <div class="review-grid">
                                             <div class="review-item">
-                                                <label>"Title"</label>
+                                                <label>{t("Title")}</label>
                                                 <span>{title.get()}</span>
                                             </div>
                                             <div class="review-item">
-                                                <label>"Type"</label>
+                                                <label>{t("Type")}</label>
                                                 <span>{tender_type.get().to_uppercase()}</span>
                                             </div>
                                             <div class="review-item">
-                                                <label>"Category"</label>
+                                                <label>{t("Category")}</label>
                                                 <span>{category.get()}</span>
                                             </div>
                                             <div class="review-item">
-                                                <label>"Department"</label>
+                                                <label>{t("Department")}</label>
                                                 <span>{department.get()}</span>
                                             </div>
                                             <div class="review-item">
-                                                <label>"Estimated Value"</label>
+                                                <label>{t("Estimated Value")}</label>
                                                 <span>{format_currency(estimated_value.get())}</span>
                                             </div>
                                         </div>
                                     </div>
 
                                     <div class="review-section">
-                                        <h4>"Scope"</h4>
+                                        <h4>{t("Scope")}</h4>
                                         <div class="review-grid">
                                             <div class="review-item">
-                                                <label>"Delivery Location"</label>
+                                                <label>{t("Delivery Location")}</label>
                                                 <span>{delivery_location.get()}</span>
                                             </div>
                                             <div class="review-item">
-                                                <label>"Contract Duration"</label>
+                                                <label>{t("Contract Duration")}</label>
                                                 <span>{contract_duration.get()}</span>
                                             </div>
                                             <div class="review-item">
-                                                <label>"Cost Center"</label>
+                                                <label>{t("Cost Center")}</label>
                                                 <span>{cost_center.get()}</span>
                                             </div>
                                         </div>
                                     </div>
 
                                     <div class="review-section">
-                                        <h4>"Evaluation Criteria"</h4>
+                                        <h4>{t("Evaluation Criteria")}</h4>
                                         <div class="review-grid">
                                             <div class="review-item">
-                                                <label>"Price Weight"</label>
+                                                <label>{t("Price Weight")}</label>
                                                 <span>{price_weight.get()}"%"</span>
                                             </div>
                                             <div class="review-item">
-                                                <label>"B-BBEE Weight"</label>
+                                                <label>{t("B-BBEE Weight")}</label>
                                                 <span>{bbbee_weight.get()}"%"</span>
                                             </div>
                                             <div class="review-item">
-                                                <label>"Functionality Threshold"</label>
+                                                <label>{t("Functionality Threshold")}</label>
                                                 <span>{functionality_threshold.get()}"%"</span>
                                             </div>
                                             <div class="review-item">
-                                                <label>"Criteria Count"</label>
-                                                <span>{criteria.get().len()}" criteria defined"</span>
+                                                <label>{t("Criteria Count")}</label>
+                                                <span>{tn("{n} criterion defined", "{n} criteria defined", criteria.get().len() as i64)}</span>
                                             </div>
                                         </div>
                                     </div>
 
                                     <div class="review-section">
-                                        <h4>"Documents"</h4>
+                                        <h4>{t("Documents")}</h4>
                                         <div class="review-item">
-                                            <label>"Uploaded Documents"</label>
-                                            <span>{documents.get().len()}" document(s)"</span>
+                                            <label>{t("Uploaded Documents")}</label>
+                                            <span>{tn("{n} document", "{n} documents", documents.get().len() as i64)}</span>
                                         </div>
                                     </div>
 
                                     {notice_bar(
-                                        "Please review all information before saving. Once submitted for approval, changes will require re-approval.".to_string(),
+                                        t("Please review all information before saving. Once submitted for approval, changes will require re-approval.").to_string(),
                                         NoticeType::Warning,
                                         None,
                                     )}
@@ -882,23 +964,23 @@ pub fn tender_form(tender_id: Option<String>) -> View {
                             <div class="form-actions-left">
                                 if current_step.get() != FormStep::BasicInfo {
                                     <button class="btn btn-secondary" on:click={handle_back} data-testid="tender-form-prev">
-                                        "Back"
+                                        {t("Back")}
                                     </button>
                                 }
                             </div>
                             <div class="form-actions-right">
                                 <button class="btn btn-secondary" on:click={handle_save_draft.clone()} disabled={saving.get()} data-testid="tender-form-save-draft">
-                                    if saving.get() { "Saving..." } else { "Save Draft" }
+                                    {if saving.get() { t("Saving...") } else { t("Save Draft") }}
                                 </button>
                                 if current_step.get() == FormStep::Review {
                                     if is_edit {
                                         <button class="btn btn-primary" on:click={handle_submit} disabled={saving.get()} data-testid="tender-form-submit">
-                                            "Submit for Approval"
+                                            {t("Submit for Approval")}
                                         </button>
                                     }
                                 } else {
                                     <button class="btn btn-primary" on:click={handle_next} data-testid="tender-form-next">
-                                        "Next"
+                                        {t("Next")}
                                     </button>
                                 }
                             </div>
@@ -909,3 +991,21 @@ pub fn tender_form(tender_id: Option<String>) -> View {
         </div>
     }
 }
+
+/// Line items carried over from the source requisition
+fn bill_of_quantities(line_items: Vec<TenderLineItem>) -> View {
+    let columns = vec![
+        DataTableColumn { key: "description".to_string(), label: t("Description").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "quantity".to_string(), label: t("Qty").to_string(), width: Some("100px".to_string()), align: Some("right".to_string()), cell_class: None },
+        DataTableColumn { key: "estimate".to_string(), label: t("Estimated Total").to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+    ];
+    let rows = line_items.iter().map(|item| DataTableRow {
+        id: item.id.clone(),
+        cells: vec![
+            view! { <span>{item.description.clone()}</span> },
+            view! { <span>{format!("{} {}", item.quantity, item.unit)}</span> },
+            view! { <span class="amount-cell">{format_currency_full(item.estimated_total)}</span> },
+        ],
+    }).collect();
+    data_table(columns, rows, None)
+}
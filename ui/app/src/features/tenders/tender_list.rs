@@ -11,21 +11,25 @@ use crate::shared::components::{
 };
 use crate::shared::forms::filter_bar;
 use crate::util::format::format_currency;
+use crate::util::url;
 use super::store::TendersStore;
 use super::types::{TenderType, TenderStatus, TenderFilter};
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Tender list page
 #[component]
 pub fn tender_list() -> View {
     let store = use_context::<TendersStore>();
 
-    // Filter signals
-    let filter_type = signal(String::new());
-    let filter_status = signal(String::new());
-    let filter_date_from = signal(String::new());
-    let filter_date_to = signal(String::new());
-    let search_query = signal(String::new());
+    // Filter signals, seeded from the URL
+    url::sync_filter(&store.filter);
+    let initial = store.filter.get();
+    let filter_type = signal(initial.tender_type.map(|t| t.as_str().to_string()).unwrap_or_default());
+    let filter_status = signal(initial.status.map(|s| s.as_str().to_string()).unwrap_or_default());
+    let filter_date_from = signal(initial.date_from.clone().unwrap_or_default());
+    let filter_date_to = signal(initial.date_to.clone().unwrap_or_default());
+    let search_query = signal(initial.search_query.clone().unwrap_or_default());
 
     // Load data on mount
     effect({
@@ -98,29 +102,13 @@ pub fn tender_list() -> View {
         let search_query = search_query.clone();
         move |_| {
             let filter = TenderFilter {
-                tender_type: match filter_type.get().as_str() {
-                    "rfq" => Some(TenderType::Rfq),
-                    "rfp" => Some(TenderType::Rfp),
-                    "rft" => Some(TenderType::Rft),
-                    _ => None,
-                },
-                status: match filter_status.get().as_str() {
-                    "draft" => Some(TenderStatus::Draft),
-                    "pending" => Some(TenderStatus::PendingApproval),
-                    "approved" => Some(TenderStatus::Approved),
-                    "published" => Some(TenderStatus::Published),
-                    "open" => Some(TenderStatus::Open),
-                    "closed" => Some(TenderStatus::Closed),
-                    "evaluation" => Some(TenderStatus::Evaluation),
-                    "awarded" => Some(TenderStatus::Awarded),
-                    "cancelled" => Some(TenderStatus::Cancelled),
-                    _ => None,
-                },
+                tender_type: TenderType::parse(&filter_type.get()),
+                status: TenderStatus::parse(&filter_status.get()),
                 date_from: if filter_date_from.get().is_empty() { None } else { Some(filter_date_from.get()) },
                 date_to: if filter_date_to.get().is_empty() { None } else { Some(filter_date_to.get()) },
                 search_query: if search_query.get().is_empty() { None } else { Some(search_query.get()) },
-                department: None,
-                category: None,
+                // Not editable here, but kept when they arrive via a shared link
+                ..store.filter.get()
             };
             store.filter.set(filter);
         }
@@ -168,13 +156,13 @@ pub fn tender_list() -> View {
 
     // Table columns
     let columns = vec![
-        DataTableColumn { key: "reference".to_string(), label: "Reference".to_string(), width: Some("120px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
-        DataTableColumn { key: "title".to_string(), label: "Title".to_string(), width: None, align: None, cell_class: None },
-        DataTableColumn { key: "type".to_string(), label: "Type".to_string(), width: Some("80px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "value".to_string(), label: "Est. Value".to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
-        DataTableColumn { key: "closing".to_string(), label: "Closing Date".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
-        DataTableColumn { key: "bids".to_string(), label: "Bids".to_string(), width: Some("60px".to_string()), align: Some("center".to_string()), cell_class: None },
-        DataTableColumn { key: "status".to_string(), label: "Status".to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "reference".to_string(), label: t("Reference").to_string(), width: Some("120px".to_string()), align: None, cell_class: Some("id-cell".to_string()) },
+        DataTableColumn { key: "title".to_string(), label: t("Title").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "type".to_string(), label: t("Type").to_string(), width: Some("80px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "value".to_string(), label: t("Est. Value").to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: Some("amount-cell".to_string()) },
+        DataTableColumn { key: "closing".to_string(), label: t("Closing Date").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "bids".to_string(), label: t("Bids").to_string(), width: Some("60px".to_string()), align: Some("center".to_string()), cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
     ];
 
     // Filter tenders based on current filter
@@ -279,11 +267,11 @@ pub fn tender_list() -> View {
 
         <div class="tender-list" data-testid="tender-list">
             {page_header(
-                "Tenders".to_string(),
-                Some("Manage procurement tenders and bids".to_string()),
+                t("Tenders").to_string(),
+                Some(t("Manage procurement tenders and bids").to_string()),
                 vec![
-                    view! { <button class="btn btn-secondary">"Export"</button> },
-                    view! { <a href="#/tenders/new" class="btn btn-primary" data-testid="create-tender-btn">"New Tender"</a> },
+                    view! { <button class="btn btn-secondary">{t("Export")}</button> },
+                    view! { <a href="#/tenders/new" class="btn btn-primary" data-testid="create-tender-btn">{t("New Tender")}</a> },
                 ]
             )}
 
@@ -291,9 +279,9 @@ pub fn tender_list() -> View {
             {filter_bar(vec![
                 view! {
                     <div class="filter-group">
-                        <label>"Type"</label>
-                        <select on:change={handle_filter_type} data-testid="tender-filter-type">
-                            <option value="">"All Types"</option>
+                        <label>{t("Type")}</label>
+                        <select on:change={handle_filter_type} value={filter_type.get()} data-testid="tender-filter-type">
+                            <option value="">{t("All Types")}</option>
                             <option value="rfq">"RFQ"</option>
                             <option value="rfp">"RFP"</option>
                             <option value="rft">"RFT"</option>
@@ -302,31 +290,31 @@ pub fn tender_list() -> View {
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"Status"</label>
-                        <select on:change={handle_filter_status} data-testid="tender-filter-status">
-                            <option value="">"All Statuses"</option>
-                            <option value="draft">"Draft"</option>
-                            <option value="pending">"Pending Approval"</option>
-                            <option value="approved">"Approved"</option>
-                            <option value="published">"Published"</option>
-                            <option value="open">"Open"</option>
-                            <option value="closed">"Closed"</option>
-                            <option value="evaluation">"Evaluation"</option>
-                            <option value="awarded">"Awarded"</option>
-                            <option value="cancelled">"Cancelled"</option>
+                        <label>{t("Status")}</label>
+                        <select on:change={handle_filter_status} value={filter_status.get()} data-testid="tender-filter-status">
+                            <option value="">{t("All Statuses")}</option>
+                            <option value="draft">{t("Draft")}</option>
+                            <option value="pending">{t("Pending Approval")}</option>
+                            <option value="approved">{t("Approved")}</option>
+                            <option value="published">{t("Published")}</option>
+                            <option value="open">{t("Open")}</option>
+                            <option value="closed">{t("Closed")}</option>
+                            <option value="evaluation">{t("Evaluation")}</option>
+                            <option value="awarded">{t("Awarded")}</option>
+                            <option value="cancelled">{t("Cancelled")}</option>
                         </select>
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"From"</label>
-                        <input type="date" on:change={handle_date_from} />
+                        <label>{t("From")}</label>
+                        <input type="date" value={filter_date_from.get()} on:change={handle_date_from} />
                     </div>
                 },
                 view! {
                     <div class="filter-group">
-                        <label>"To"</label>
-                        <input type="date" on:change={handle_date_to} />
+                        <label>{t("To")}</label>
+                        <input type="date" value={filter_date_to.get()} on:change={handle_date_to} />
                     </div>
                 },
                 view! { <div class="filter-spacer"></div> },
@@ -335,31 +323,32 @@ pub fn tender_list() -> View {
                         <input
                             type="text"
                             class="search-input"
-                            placeholder="Search tenders..."
+                            placeholder={t("Search tenders...")}
+                            value={search_query.get()}
                             on:input={handle_search}
                             data-testid="tender-filter-search"
                         />
                     </div>
                 },
                 view! {
-                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters} data-testid="tender-filter-clear">"Clear"</button>
+                    <button class="btn btn-sm btn-secondary" on:click={handle_clear_filters} data-testid="tender-filter-clear">{t("Clear")}</button>
                 },
                 view! {
-                    <button class="btn btn-sm btn-primary" on:click={handle_apply_filters} data-testid="tender-filter-apply">"Apply"</button>
+                    <button class="btn btn-sm btn-primary" on:click={handle_apply_filters} data-testid="tender-filter-apply">{t("Apply")}</button>
                 },
             ])}
 
             // Data table
             {panel(
-                format!("Tenders ({} total)", filtered_tenders.len()),
+                tf("Tenders ({count} total)", &[("count", filtered_tenders.len().to_string())]),
                 vec![],
                 vec![
                     if loading.get() {
-                        view! { <div class="loading-overlay">"Loading tenders..."</div> }
+                        view! { <div class="loading-overlay">{t("Loading tenders...")}</div> }
                     } else if rows.is_empty() {
                         view! {
                             <div class="loading-overlay">
-                                "No tenders found. Create a new tender to get started."
+                                {t("No tenders found. Create a new tender to get started.")}
                             </div>
                         }
                     } else {
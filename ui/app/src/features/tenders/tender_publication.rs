@@ -1,6 +1,8 @@
 //! Tender publication page for e-Tender portal
 
 use components::prelude::*;
+use crate::Route;
+use crate::page::not_found::entity_not_found;
 use crate::shared::layout::page_header;
 use crate::shared::components::{
     panel, panel_with_footer,
@@ -11,10 +13,12 @@ use crate::shared::components::{
 use crate::shared::forms::{
     text_input, date_picker, textarea, checkbox, form_group,
 };
-use crate::util::format::format_currency;
+use crate::util::datetime::today;
+use crate::util::format::{format_currency, format_date};
 use super::store::TendersStore;
 use super::types::{TenderStatus, TenderType};
 use super::service;
+use crate::util::i18n::{t, tf};
 
 /// Tender publication page
 #[component]
@@ -38,20 +42,28 @@ pub fn tender_publication(tender_id: String) -> View {
     let publishing = signal(false);
     let error = signal::<Option<String>>(None);
     let success = signal::<Option<String>>(None);
+    let missing = signal(false);
 
     // Load tender on mount
     effect({
         let store = store.clone();
         let tender_id = tender_id.clone();
+        let missing = missing.clone();
         move || {
             let store = store.clone();
             let tender_id = tender_id.clone();
+            let missing = missing.clone();
             spawn(async move {
                 service::get_tender(&store, &tender_id).await;
+                missing.set(store.selected.get().map_or(true, |t| t.id != tender_id));
             });
         }
     });
 
+    if missing.get() {
+        return entity_not_found(t("Tender"), &tender_id, store.error.get(), Route::TendersList, t("Back to Tenders"));
+    }
+
     let tender = store.selected.clone();
 
     // Check if tender can be published
@@ -91,11 +103,11 @@ pub fn tender_publication(tender_id: String) -> View {
 
             // Validation
             if publish_date_val.is_empty() {
-                error.set(Some("Please select a publication date".to_string()));
+                error.set(Some(t("Please select a publication date").to_string()));
                 return;
             }
             if closing_date_val.is_empty() {
-                error.set(Some("Please select a closing date".to_string()));
+                error.set(Some(t("Please select a closing date").to_string()));
                 return;
             }
 
@@ -112,9 +124,9 @@ pub fn tender_publication(tender_id: String) -> View {
                     briefing_date_val.as_deref(),
                 ).await {
                     Ok(portal_ref) => {
-                        success.set(Some(format!(
-                            "Tender successfully published to e-Tender portal. Reference: {}",
-                            portal_ref
+                        success.set(Some(tf(
+                            "Tender successfully published to e-Tender portal. Reference: {reference}",
+                            &[("reference", portal_ref)],
                         )));
                     }
                     Err(e) => {
@@ -126,6 +138,23 @@ pub fn tender_publication(tender_id: String) -> View {
         }
     });
 
+    // Advertising period for this tender type, measured from the chosen publication date
+    let advert_type = tender.get().map_or(TenderType::Rft, |t| t.tender_type);
+    let earliest_closing = service::earliest_closing_date(advert_type, &publish_date.get());
+    let bidding_period = match earliest_closing {
+        Some(date) => tf(
+            "{days} days (per Treasury Regulations); closes no earlier than {date}",
+            &[
+                ("days", advert_type.minimum_advert_days().to_string()),
+                ("date", format_date(&date.to_iso())),
+            ],
+        ),
+        None => tf(
+            "{days} days (per Treasury Regulations)",
+            &[("days", advert_type.minimum_advert_days().to_string())],
+        ),
+    };
+
     // Icons
     let icon_external = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" width="14" height="14"><path d="M18 13v6a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V8a2 2 0 0 1 2-2h6"/><polyline points="15 3 21 3 21 9"/><line x1="10" y1="14" x2="21" y2="3"/></svg>"#;
 
@@ -231,10 +260,10 @@ pub fn tender_publication(tender_id: String) -> View {
 
         <div class="tender-publication" data-testid="tender-publication">
             {page_header(
-                "Publish Tender".to_string(),
-                Some("Publish tender to e-Tender portal for bidding".to_string()),
+                t("Publish Tender").to_string(),
+                Some(t("Publish tender to e-Tender portal for bidding").to_string()),
                 vec![
-                    view! { <a href={format!("#/tenders/{}", tender_id)} class="btn btn-secondary">"Back to Tender"</a> },
+                    view! { <a href={format!("#/tenders/{}", tender_id)} class="btn btn-secondary">{t("Back to Tender")}</a> },
                 ]
             )}
 
@@ -247,16 +276,16 @@ pub fn tender_publication(tender_id: String) -> View {
             }
 
             // Tender summary
-            if let Some(t) = tender.get() {
+            if let Some(current) = tender.get() {
                 {panel(
-                    "Tender Summary".to_string(),
+                    t("Tender Summary").to_string(),
                     vec![
-                        match t.tender_type {
+                        match current.tender_type {
                             TenderType::Rfq => tag("RFQ".to_string(), TagType::Rfq),
                             TenderType::Rfp => tag("RFP".to_string(), TagType::Rfp),
                             TenderType::Rft => tag("RFT".to_string(), TagType::Tender),
                         },
-                        match t.status {
+                        match current.status {
                             TenderStatus::Approved => status_badge(StatusType::Approved),
                             TenderStatus::Published => status_badge(StatusType::Published),
                             TenderStatus::Open => status_badge(StatusType::Open),
@@ -267,20 +296,20 @@ pub fn tender_publication(tender_id: String) -> View {
                         view! {
                             <div class="tender-summary">
                                 <div class="summary-item">
-                                    <label>"Reference"</label>
-                                    <span>{t.reference_number.clone()}</span>
+                                    <label>{t("Reference")}</label>
+                                    <span>{current.reference_number.clone()}</span>
                                 </div>
                                 <div class="summary-item">
-                                    <label>"Title"</label>
-                                    <span>{t.title.clone()}</span>
+                                    <label>{t("Title")}</label>
+                                    <span>{current.title.clone()}</span>
                                 </div>
                                 <div class="summary-item">
-                                    <label>"Estimated Value"</label>
-                                    <span>{format_currency(t.estimated_value)}</span>
+                                    <label>{t("Estimated Value")}</label>
+                                    <span>{format_currency(current.estimated_value)}</span>
                                 </div>
                                 <div class="summary-item">
-                                    <label>"Category"</label>
-                                    <span>{t.category.clone()}</span>
+                                    <label>{t("Category")}</label>
+                                    <span>{current.category.clone()}</span>
                                 </div>
                             </div>
                         }
@@ -290,24 +319,24 @@ pub fn tender_publication(tender_id: String) -> View {
                 // If already published, show portal info
                 if is_published {
                     <div class="published-info">
-                        <h3>"Tender Published"</h3>
+                        <h3>{t("Tender Published")}</h3>
                         <div class="tender-summary">
                             <div class="summary-item">
-                                <label>"Portal Reference"</label>
-                                <span>{t.portal_reference.clone().unwrap_or_default()}</span>
+                                <label>{t("Portal Reference")}</label>
+                                <span>{current.portal_reference.clone().unwrap_or_default()}</span>
                             </div>
                             <div class="summary-item">
-                                <label>"Publication Date"</label>
-                                <span>{t.publish_date.clone().unwrap_or_default()}</span>
+                                <label>{t("Publication Date")}</label>
+                                <span>{current.publish_date.clone().unwrap_or_default()}</span>
                             </div>
                             <div class="summary-item">
-                                <label>"Closing Date"</label>
-                                <span>{t.closing_date.clone().unwrap_or_default()}</span>
+                                <label>{t("Closing Date")}</label>
+                                <span>{current.closing_date.clone().unwrap_or_default()}</span>
                             </div>
                             <div class="summary-item">
-                                <label>"Portal Link"</label>
-                                <a href={t.portal_url.clone().unwrap_or_default()} target="_blank" class="portal-link">
-                                    "View on e-Tender Portal"
+                                <label>{t("Portal Link")}</label>
+                                <a href={current.portal_url.clone().unwrap_or_default()} target="_blank" class="portal-link">
+                                    {t("View on e-Tender Portal")}
                                     <span inner_html={icon_external}></span>
                                 </a>
                             </div>
@@ -318,7 +347,7 @@ pub fn tender_publication(tender_id: String) -> View {
                 // If can publish, show publication form
                 if can_publish {
                     {panel_with_footer(
-                        "Publication Settings".to_string(),
+                        t("Publication Settings").to_string(),
                         vec![],
                         vec![
                             // Portal options
@@ -333,9 +362,9 @@ pub fn tender_publication(tender_id: String) -> View {
                                             </svg>
                                         </div>
                                         <div class="portal-option-content">
-                                            <h4>"e-Tender Portal"</h4>
-                                            <p>"Publish to the national e-Tender portal (etenders.gov.za)"</p>
-                                            {checkbox("Publish to e-Tender".to_string(), publish_etender.clone(), false)}
+                                            <h4>{t("e-Tender Portal")}</h4>
+                                            <p>{t("Publish to the national e-Tender portal (etenders.gov.za)")}</p>
+                                            {checkbox(t("Publish to e-Tender").to_string(), publish_etender.clone(), false)}
                                         </div>
                                     </div>
                                     <div class={if publish_internal.get() { "portal-option selected" } else { "portal-option" }}>
@@ -346,9 +375,9 @@ pub fn tender_publication(tender_id: String) -> View {
                                             </svg>
                                         </div>
                                         <div class="portal-option-content">
-                                            <h4>"Internal Portal"</h4>
-                                            <p>"Also publish to internal supplier portal"</p>
-                                            {checkbox("Publish internally".to_string(), publish_internal.clone(), false)}
+                                            <h4>{t("Internal Portal")}</h4>
+                                            <p>{t("Also publish to internal supplier portal")}</p>
+                                            {checkbox(t("Publish internally").to_string(), publish_internal.clone(), false)}
                                         </div>
                                     </div>
                                 </div>
@@ -357,32 +386,32 @@ pub fn tender_publication(tender_id: String) -> View {
                             // Publication dates
                             view! {
                                 {form_group(
-                                    Some("Publication Dates".to_string()),
+                                    Some(t("Publication Dates").to_string()),
                                     3,
                                     vec![
                                         date_picker(
-                                            "Publication Date".to_string(),
+                                            t("Publication Date").to_string(),
                                             publish_date.clone(),
                                             true,
                                             false,
-                                            Some("2025-01-01".to_string()),
+                                            Some(today().to_iso()),
                                             None,
                                             None,
                                         ),
                                         date_picker(
-                                            "Closing Date".to_string(),
+                                            t("Closing Date").to_string(),
                                             closing_date.clone(),
                                             true,
                                             false,
-                                            Some("2025-01-01".to_string()),
+                                            Some(earliest_closing.map(|d| d.to_iso()).unwrap_or_else(|| today().to_iso())),
                                             None,
                                             None,
                                         ),
                                         view! {
                                             <div class="form-group">
-                                                <label>"Minimum Bidding Period"</label>
-                                                <span style="font-size: 13px; color: var(--text-muted);">
-                                                    "21 days (per Treasury Regulations)"
+                                                <label>{t("Minimum Bidding Period")}</label>
+                                                <span style="font-size: 13px; color: var(--text-muted);" data-testid="minimum-bidding-period">
+                                                    {bidding_period.clone()}
                                                 </span>
                                             </div>
                                         },
@@ -393,7 +422,7 @@ pub fn tender_publication(tender_id: String) -> View {
                             // Briefing session
                             view! {
                                 <div class="briefing-options">
-                                    {checkbox("Include Briefing Session".to_string(), briefing_required.clone(), false)}
+                                    {checkbox(t("Include Briefing Session").to_string(), briefing_required.clone(), false)}
                                     if briefing_required.get() {
                                         <div style="margin-top: 16px;">
                                             {form_group(
@@ -401,7 +430,7 @@ pub fn tender_publication(tender_id: String) -> View {
                                                 2,
                                                 vec![
                                                     date_picker(
-                                                        "Briefing Date".to_string(),
+                                                        t("Briefing Date").to_string(),
                                                         briefing_date.clone(),
                                                         true,
                                                         false,
@@ -409,7 +438,7 @@ pub fn tender_publication(tender_id: String) -> View {
                                                         None,
                                                         None,
                                                     ),
-                                                    checkbox("Attendance Mandatory".to_string(), briefing_mandatory.clone(), false),
+                                                    checkbox(t("Attendance Mandatory").to_string(), briefing_mandatory.clone(), false),
                                                 ]
                                             )}
                                         </div>
@@ -420,10 +449,10 @@ pub fn tender_publication(tender_id: String) -> View {
                             // Notifications
                             view! {
                                 {form_group(
-                                    Some("Notifications".to_string()),
+                                    Some(t("Notifications").to_string()),
                                     1,
                                     vec![
-                                        checkbox("Notify registered suppliers in this category".to_string(), notify_suppliers.clone(), false),
+                                        checkbox(t("Notify registered suppliers in this category").to_string(), notify_suppliers.clone(), false),
                                     ]
                                 )}
                             },
@@ -431,13 +460,13 @@ pub fn tender_publication(tender_id: String) -> View {
                             // Portal notes
                             view! {
                                 {form_group(
-                                    Some("Portal Notes".to_string()),
+                                    Some(t("Portal Notes").to_string()),
                                     1,
                                     vec![
                                         textarea(
-                                            "Additional Information".to_string(),
+                                            t("Additional Information").to_string(),
                                             portal_notes.clone(),
-                                            Some("Any additional notes to display on the portal".to_string()),
+                                            Some(t("Any additional notes to display on the portal").to_string()),
                                             false,
                                             false,
                                             Some(3),
@@ -450,16 +479,16 @@ pub fn tender_publication(tender_id: String) -> View {
 
                             // Warning notice
                             {notice_bar(
-                                "Once published, the tender will be visible to all suppliers. Ensure all documents are finalized before publishing.".to_string(),
+                                t("Once published, the tender will be visible to all suppliers. Ensure all documents are finalized before publishing.").to_string(),
                                 NoticeType::Warning,
                                 None,
                             )}
                         ],
                         vec![
-                            view! { <a href="#/tenders" class="btn btn-secondary">"Cancel"</a> },
+                            view! { <a href="#/tenders" class="btn btn-secondary">{t("Cancel")}</a> },
                             view! {
                                 <button class="btn btn-primary" on:click={handle_publish} disabled={publishing.get()}>
-                                    if publishing.get() { "Publishing..." } else { "Publish to Portal" }
+                                    {if publishing.get() { t("Publishing...") } else { t("Publish to Portal") }}
                                 </button>
                             },
                         ]
@@ -469,7 +498,7 @@ pub fn tender_publication(tender_id: String) -> View {
                 // If not approved, show warning
                 if !can_publish && !is_published {
                     {notice_bar(
-                        "This tender must be approved before it can be published. Current status: ".to_string() + t.status.label(),
+                        t("This tender must be approved before it can be published. Current status: ").to_string() + current.status.label(),
                         NoticeType::Warning,
                         None,
                     )}
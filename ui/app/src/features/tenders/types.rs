@@ -1,6 +1,15 @@
 //! Tender domain types
 
 use serde::{Deserialize, Serialize};
+use crate::features::workflow::types::ApprovalStep;
+use crate::util::i18n::t;
+use crate::util::money::Money;
+use crate::util::reference::DocumentType;
+use crate::util::url::{QueryFilter, QueryParams};
+
+/// Estimated value from which a requisition goes out as a formal tender
+/// rather than a request for quotation
+pub const RFT_THRESHOLD: Money = Money::rands(1_000_000);
 
 /// Tender type enumeration
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -23,10 +32,53 @@ impl TenderType {
     }
 
     pub fn description(&self) -> &'static str {
-        match self {
+        t(match self {
             TenderType::Rfq => "Request for Quotation",
             TenderType::Rfp => "Request for Proposal",
             TenderType::Rft => "Request for Tender",
+        })
+    }
+
+    /// Minimum calendar days between publication and closing
+    pub fn minimum_advert_days(&self) -> u32 {
+        match self {
+            TenderType::Rfq => 7,
+            TenderType::Rfp | TenderType::Rft => 21,
+        }
+    }
+
+    /// Register the tender's reference number is issued from
+    pub fn document_type(&self) -> DocumentType {
+        match self {
+            TenderType::Rfq => DocumentType::Rfq,
+            TenderType::Rfp => DocumentType::Rfp,
+            TenderType::Rft => DocumentType::Rft,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TenderType::Rfq => "rfq",
+            TenderType::Rfp => "rfp",
+            TenderType::Rft => "rft",
+        }
+    }
+
+    /// Procurement method for a requirement of the given estimated value
+    pub fn for_value(value: Money) -> Self {
+        if value < RFT_THRESHOLD {
+            TenderType::Rfq
+        } else {
+            TenderType::Rft
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "rfq" => Some(TenderType::Rfq),
+            "rfp" => Some(TenderType::Rfp),
+            "rft" => Some(TenderType::Rft),
+            _ => None,
         }
     }
 }
@@ -64,7 +116,7 @@ pub enum TenderStatus {
 
 impl TenderStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             TenderStatus::Draft => "Draft",
             TenderStatus::PendingApproval => "Pending Approval",
             TenderStatus::Approved => "Approved",
@@ -75,6 +127,37 @@ impl TenderStatus {
             TenderStatus::Adjudication => "Adjudication",
             TenderStatus::Awarded => "Awarded",
             TenderStatus::Cancelled => "Cancelled",
+        })
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TenderStatus::Draft => "draft",
+            TenderStatus::PendingApproval => "pending",
+            TenderStatus::Approved => "approved",
+            TenderStatus::Published => "published",
+            TenderStatus::Open => "open",
+            TenderStatus::Closed => "closed",
+            TenderStatus::Evaluation => "evaluation",
+            TenderStatus::Adjudication => "adjudication",
+            TenderStatus::Awarded => "awarded",
+            TenderStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "draft" => Some(TenderStatus::Draft),
+            "pending" => Some(TenderStatus::PendingApproval),
+            "approved" => Some(TenderStatus::Approved),
+            "published" => Some(TenderStatus::Published),
+            "open" => Some(TenderStatus::Open),
+            "closed" => Some(TenderStatus::Closed),
+            "evaluation" => Some(TenderStatus::Evaluation),
+            "adjudication" => Some(TenderStatus::Adjudication),
+            "awarded" => Some(TenderStatus::Awarded),
+            "cancelled" => Some(TenderStatus::Cancelled),
+            _ => None,
         }
     }
 }
@@ -100,12 +183,12 @@ pub enum DeviationType {
 
 impl DeviationType {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             DeviationType::SingleSource => "Single Source",
             DeviationType::Emergency => "Emergency",
             DeviationType::SoleSupplier => "Sole Supplier",
             DeviationType::Strategic => "Strategic Partnership",
-        }
+        })
     }
 }
 
@@ -130,6 +213,19 @@ pub struct TenderDocument {
     pub category: String, // "specification", "terms", "evaluation", "annexure"
 }
 
+/// Bill of quantities line carried over from the source requisition
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TenderLineItem {
+    pub id: String,
+    pub description: String,
+    pub category: String,
+    pub quantity: u32,
+    pub unit: String,
+    pub estimated_unit_price: Money,
+    pub estimated_total: Money,
+    pub specifications: Option<String>,
+}
+
 /// Bid submission from a supplier
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Bid {
@@ -138,7 +234,7 @@ pub struct Bid {
     pub supplier_id: String,
     pub supplier_name: String,
     pub submitted_at: String,
-    pub total_price: f64,
+    pub total_price: Money,
     pub bbbee_level: Option<u8>,
     pub status: BidStatus,
     pub technical_score: Option<f64>,
@@ -161,7 +257,7 @@ pub enum BidStatus {
 
 impl BidStatus {
     pub fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             BidStatus::Received => "Received",
             BidStatus::UnderReview => "Under Review",
             BidStatus::Compliant => "Compliant",
@@ -169,7 +265,7 @@ impl BidStatus {
             BidStatus::Shortlisted => "Shortlisted",
             BidStatus::Awarded => "Awarded",
             BidStatus::Unsuccessful => "Unsuccessful",
-        }
+        })
     }
 }
 
@@ -182,7 +278,7 @@ pub struct Tender {
     pub description: String,
     pub tender_type: TenderType,
     pub status: TenderStatus,
-    pub estimated_value: f64,
+    pub estimated_value: Money,
     pub currency: String,
 
     // Dates
@@ -204,6 +300,14 @@ pub struct Tender {
     pub technical_requirements: String,
     pub mandatory_requirements: Vec<String>,
 
+    // Source
+    /// Requisition the tender was raised from
+    #[serde(default)]
+    pub requisition_ref: Option<String>,
+    /// Bill of quantities carried over from the requisition
+    #[serde(default)]
+    pub line_items: Vec<TenderLineItem>,
+
     // Evaluation
     pub evaluation_criteria: Vec<EvaluationCriterion>,
     pub price_weight: f64,
@@ -226,8 +330,15 @@ pub struct Tender {
     pub deviation_approved_by: Option<String>,
     pub deviation_approved_at: Option<String>,
 
+    // Approval
+    #[serde(default)]
+    pub approval_workflow: Vec<ApprovalStep>,
+
     // Audit
     pub created_by: String,
+    /// Id of the user who created the tender
+    #[serde(default)]
+    pub created_by_id: String,
     pub last_modified_by: String,
     pub last_modified_at: String,
 }
@@ -241,7 +352,7 @@ impl Default for Tender {
             description: String::new(),
             tender_type: TenderType::default(),
             status: TenderStatus::default(),
-            estimated_value: 0.0,
+            estimated_value: Money::ZERO,
             currency: "ZAR".to_string(),
             created_at: String::new(),
             publish_date: None,
@@ -256,6 +367,8 @@ impl Default for Tender {
             scope_of_work: String::new(),
             technical_requirements: String::new(),
             mandatory_requirements: Vec::new(),
+            requisition_ref: None,
+            line_items: Vec::new(),
             evaluation_criteria: Vec::new(),
             price_weight: 80.0,
             bbbee_weight: 20.0,
@@ -268,7 +381,9 @@ impl Default for Tender {
             deviation_justification: None,
             deviation_approved_by: None,
             deviation_approved_at: None,
+            approval_workflow: Vec::new(),
             created_by: String::new(),
+            created_by_id: String::new(),
             last_modified_by: String::new(),
             last_modified_at: String::new(),
         }
@@ -287,6 +402,30 @@ pub struct TenderFilter {
     pub category: Option<String>,
 }
 
+impl QueryFilter for TenderFilter {
+    fn from_query(params: &QueryParams) -> Self {
+        Self {
+            tender_type: params.get("type").and_then(|t| TenderType::parse(&t)),
+            status: params.get("status").and_then(|s| TenderStatus::parse(&s)),
+            date_from: params.get("from"),
+            date_to: params.get("to"),
+            search_query: params.get("q"),
+            department: params.get("department"),
+            category: params.get("category"),
+        }
+    }
+
+    fn write_query(&self, params: &mut QueryParams) {
+        params.set("type", self.tender_type.map(|t| t.as_str()));
+        params.set("status", self.status.map(|s| s.as_str()));
+        params.set("from", self.date_from.as_ref());
+        params.set("to", self.date_to.as_ref());
+        params.set("q", self.search_query.as_ref());
+        params.set("department", self.department.as_ref());
+        params.set("category", self.category.as_ref());
+    }
+}
+
 /// Pagination state
 #[derive(Clone, Debug)]
 pub struct PaginationState {
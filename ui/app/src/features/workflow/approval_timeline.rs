@@ -0,0 +1,347 @@
+//! Approval timeline and approver actions shared by every feature that routes
+//! documents for approval
+
+use components::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::shared::components::{status_badge, StatusType};
+use crate::util::auth::AuthState;
+use crate::util::datetime::today;
+use crate::util::format::{format_date, format_datetime};
+use crate::util::i18n::t;
+use crate::util::permissions::{self, Action};
+use super::service;
+use super::types::{ApprovalAction, ApprovalDecision, ApprovalReassignment, ApprovalStep, StepStatus, WorkflowState};
+
+fn step_badge(status: StepStatus) -> View {
+    status_badge(match status {
+        StepStatus::Pending => StatusType::Pending,
+        StepStatus::Approved => StatusType::Approved,
+        StepStatus::Rejected => StatusType::Rejected,
+        StepStatus::Returned => StatusType::OnHold,
+    })
+}
+
+/// Approval chain grouped by stage, with parallel approvers side by side
+#[component]
+pub fn approval_timeline(steps: Vec<ApprovalStep>) -> View {
+    let current = match service::state(&steps) {
+        WorkflowState::InProgress { stage } => Some(stage),
+        _ => None,
+    };
+    let mut stages: Vec<u32> = steps.iter().map(|s| s.step).collect();
+    stages.sort_unstable();
+    stages.dedup();
+
+    view! {
+        style {
+            r#"
+            .approval-timeline { display: flex; flex-direction: column; gap: 12px; }
+            .approval-stage {
+                border-left: 3px solid var(--border);
+                padding-left: 12px;
+            }
+            .approval-stage.current { border-left-color: var(--blue); }
+            .approval-stage.done { border-left-color: var(--green); }
+            .approval-stage-header {
+                font-size: 11px;
+                font-weight: 600;
+                text-transform: uppercase;
+                color: var(--text-muted);
+                margin-bottom: 6px;
+            }
+            .approval-step { padding: 6px 0; font-size: 13px; }
+            .approval-step-head {
+                display: flex;
+                justify-content: space-between;
+                align-items: center;
+                gap: 8px;
+            }
+            .approval-step-role { font-weight: 500; }
+            .approval-step-meta { font-size: 12px; color: var(--text-muted); margin-top: 2px; }
+            .approval-step-meta.overdue { color: var(--red); font-weight: 500; }
+            .approval-step-comments {
+                font-size: 12px;
+                margin-top: 4px;
+                padding: 6px 8px;
+                background: var(--bg);
+                border-radius: var(--radius);
+            }
+            .approval-empty { font-size: 13px; color: var(--text-muted); }
+            "#
+        }
+
+        <div class="approval-timeline" data-testid="approval-timeline">
+            if steps.is_empty() {
+                <div class="approval-empty">{t("Not yet submitted for approval")}</div>
+            }
+            for stage in stages {
+                {approval_stage(stage, steps.iter().filter(|s| s.step == stage).cloned().collect(), current)}
+            }
+        </div>
+    }
+}
+
+fn approval_stage(stage: u32, steps: Vec<ApprovalStep>, current: Option<u32>) -> View {
+    let class = if current == Some(stage) {
+        "approval-stage current"
+    } else if steps.iter().all(|s| s.status == StepStatus::Approved) {
+        "approval-stage done"
+    } else {
+        "approval-stage"
+    };
+    let date = today();
+    let header = if steps.len() > 1 {
+        format!("{} {} · {}", t("Stage"), stage, t("all must approve"))
+    } else {
+        format!("{} {}", t("Stage"), stage)
+    };
+
+    view! {
+        <div class={class}>
+            <div class="approval-stage-header">{header}</div>
+            for step in steps {
+                <div class="approval-step" data-testid="approval-step">
+                    <div class="approval-step-head">
+                        <span class="approval-step-role">{step.role.clone()}</span>
+                        {step_badge(step.status)}
+                    </div>
+                    <div class="approval-step-meta">
+                        {match (&step.approver, &step.date) {
+                            (Some(who), Some(date)) => format!("{} · {}", who, format_datetime(date)),
+                            (Some(who), None) => who.clone(),
+                            _ => t("Awaiting approver").to_string(),
+                        }}
+                    </div>
+                    if let Some(owner) = step.delegated_from.clone() {
+                        <div class="approval-step-meta" data-testid="approval-step-delegated">
+                            {format!("{} {}", t("On behalf of"), owner)}
+                        </div>
+                    }
+                    if let Some(from) = step.escalated_from.clone() {
+                        <div class="approval-step-meta" data-testid="approval-step-escalated">
+                            {format!("{} {}", t("Escalated from"), from)}
+                        </div>
+                    }
+                    if let Some(days) = step.days_overdue(date) {
+                        <div class="approval-step-meta overdue" data-testid="approval-step-overdue">
+                            {format!("{} {} {}", t("Overdue by"), days, t("business day(s)"))}
+                        </div>
+                    } else if let Some(due) = step.due_date.clone().filter(|_| step.status == StepStatus::Pending) {
+                        <div class="approval-step-meta">{format!("{} {}", t("Due"), format_date(&due))}</div>
+                    }
+                    if let Some(comments) = step.comments.clone() {
+                        <div class="approval-step-comments">{comments}</div>
+                    }
+                </div>
+            }
+        </div>
+    }
+}
+
+/// Approve, reject or return the current stage's step.
+///
+/// Renders nothing unless the chain is waiting on a step the user may act
+/// on: one assigned to them or their delegator, or an unassigned step for a
+/// role they hold, on a document they did not request. When they may act
+/// on several parallel steps they sign for them one at a time.
+#[component]
+pub fn approval_actions(steps: Vec<ApprovalStep>, requester_id: String, busy: bool, on_decide: Callback<ApprovalDecision>) -> View {
+    let auth_state = use_context::<Signal<AuthState>>();
+    let role = service::actionable(&steps, &requester_id).first().map(|s| s.role.clone());
+    let comments = signal(String::new());
+
+    let can_act = role.is_some() && permissions::can_perform(&auth_state.get(), Action::ActOnApproval);
+    let role = role.unwrap_or_default();
+
+    let decide = |action: ApprovalAction| {
+        let role = role.clone();
+        let comments = comments.clone();
+        let on_decide = on_decide.clone();
+        Callback::<()>::new(move |_| {
+            let text = comments.get();
+            on_decide.call(ApprovalDecision {
+                role: role.clone(),
+                action,
+                comments: if text.trim().is_empty() { None } else { Some(text) },
+            });
+        })
+    };
+    let handle_approve = decide(ApprovalAction::Approve);
+    let handle_reject = decide(ApprovalAction::Reject);
+    let handle_return = decide(ApprovalAction::ReturnForInfo);
+
+    let handle_comments = Callback::new({
+        let comments = comments.clone();
+        move |e: web_sys::Event| {
+            if let Some(input) = e.target().and_then(|t| t.dyn_into::<web_sys::HtmlTextAreaElement>().ok()) {
+                comments.set(input.value());
+            }
+        }
+    });
+
+    view! {
+        style {
+            r#"
+            .approval-actions {
+                display: flex;
+                flex-direction: column;
+                gap: 8px;
+                margin-top: 16px;
+                padding-top: 16px;
+                border-top: 1px solid var(--border);
+            }
+            .approval-actions select,
+            .approval-actions textarea {
+                padding: 8px 10px;
+                border: 1px solid var(--border);
+                border-radius: var(--radius);
+                font-size: 13px;
+                font-family: inherit;
+                background: var(--surface);
+            }
+            .approval-actions textarea { min-height: 64px; resize: vertical; }
+            .approval-action-buttons { display: flex; gap: 8px; flex-wrap: wrap; }
+            .approval-signing-as { font-size: 12px; color: var(--text-muted); }
+            "#
+        }
+
+        if can_act {
+            <div class="approval-actions" data-testid="approval-actions">
+                <div class="approval-signing-as" data-testid="approval-role">
+                    {format!("{} {}", t("Signing as"), role)}
+                </div>
+                <textarea
+                    placeholder={t("Comments (required to reject or return)")}
+                    data-testid="approval-comments"
+                    on:input={handle_comments}
+                ></textarea>
+                <div class="approval-action-buttons">
+                    <button class="btn btn-primary" disabled={busy} data-testid="approval-approve" on:click={handle_approve}>
+                        {ApprovalAction::Approve.label()}
+                    </button>
+                    <button class="btn btn-secondary" disabled={busy} data-testid="approval-return" on:click={handle_return}>
+                        {ApprovalAction::ReturnForInfo.label()}
+                    </button>
+                    <button class="btn btn-danger" disabled={busy} data-testid="approval-reject" on:click={handle_reject}>
+                        {ApprovalAction::Reject.label()}
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
+
+/// Move a pending step to another approver, for administrators unsticking a
+/// chain whose approver has left or is unavailable without a delegation
+#[component]
+pub fn approval_reassign(steps: Vec<ApprovalStep>, busy: bool, on_reassign: Callback<ApprovalReassignment>) -> View {
+    let auth_state = use_context::<Signal<AuthState>>();
+    let roles: Vec<String> = steps
+        .iter()
+        .filter(|s| s.status == StepStatus::Pending)
+        .map(|s| s.role.clone())
+        .collect();
+    let role = signal(roles.first().cloned().unwrap_or_default());
+    let to = signal(String::new());
+    let reason = signal(String::new());
+    let approvers = service::approvers();
+
+    let can_reassign = !roles.is_empty() && permissions::can_perform(&auth_state.get(), Action::ReassignApproval);
+
+    let handle_role = Callback::new({
+        let role = role.clone();
+        move |e: web_sys::Event| {
+            if let Some(select) = e.target().and_then(|t| t.dyn_into::<web_sys::HtmlSelectElement>().ok()) {
+                role.set(select.value());
+            }
+        }
+    });
+    let input = |value: Signal<String>| {
+        Callback::new(move |e: web_sys::Event| {
+            if let Some(input) = e.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok()) {
+                value.set(input.value());
+            }
+        })
+    };
+    let handle_to = Callback::new({
+        let to = to.clone();
+        move |e: web_sys::Event| {
+            if let Some(select) = e.target().and_then(|t| t.dyn_into::<web_sys::HtmlSelectElement>().ok()) {
+                to.set(select.value());
+            }
+        }
+    });
+    let handle_reason = input(reason.clone());
+    let handle_reassign = Callback::<()>::new({
+        let role = role.clone();
+        let to = to.clone();
+        let reason = reason.clone();
+        move |_| {
+            on_reassign.call(ApprovalReassignment {
+                role: role.get(),
+                to: to.get(),
+                reason: reason.get(),
+            });
+        }
+    });
+
+    view! {
+        style {
+            r#"
+            .approval-reassign {
+                display: grid;
+                grid-template-columns: 1fr 1fr;
+                gap: 8px;
+                margin-top: 16px;
+                padding-top: 16px;
+                border-top: 1px dashed var(--border);
+            }
+            .approval-reassign-title {
+                grid-column: 1 / -1;
+                font-size: 11px;
+                font-weight: 600;
+                text-transform: uppercase;
+                color: var(--text-muted);
+            }
+            .approval-reassign select,
+            .approval-reassign input {
+                padding: 8px 10px;
+                border: 1px solid var(--border);
+                border-radius: var(--radius);
+                font-size: 13px;
+                font-family: inherit;
+                background: var(--surface);
+            }
+            .approval-reassign .reason { grid-column: 1 / -1; }
+            .approval-reassign .btn { justify-self: start; }
+            "#
+        }
+
+        if can_reassign {
+            <div class="approval-reassign" data-testid="approval-reassign">
+                <div class="approval-reassign-title">{t("Reassign pending step")}</div>
+                <select data-testid="approval-reassign-role" on:change={handle_role}>
+                    for r in roles.clone() {
+                        <option value={r.clone()} selected={r == role.get()}>{r.clone()}</option>
+                    }
+                </select>
+                <select data-testid="approval-reassign-to" on:change={handle_to}>
+                    <option value="">{t("New approver")}</option>
+                    for approver in approvers.clone() {
+                        <option value={approver.id.clone()}>{approver.name.clone()}</option>
+                    }
+                </select>
+                <input
+                    type="text"
+                    class="reason"
+                    placeholder={t("Reason for reassignment")}
+                    data-testid="approval-reassign-reason"
+                    on:input={handle_reason}
+                />
+                <button class="btn btn-secondary" disabled={busy} data-testid="approval-reassign-submit" on:click={handle_reassign}>
+                    {t("Reassign")}
+                </button>
+            </div>
+        }
+    }
+}
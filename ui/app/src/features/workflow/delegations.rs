@@ -0,0 +1,290 @@
+//! Out-of-office delegation page
+//!
+//! Users hand their approval authority to a colleague while they are away;
+//! pending steps assigned to them are rerouted for the dates covered.
+
+use components::prelude::*;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table, DataTableColumn, DataTableRow,
+    status_badge, StatusType,
+    notice_bar, NoticeType,
+    empty_state,
+};
+use crate::shared::forms::{text_input_with_testid, select_with_testid, SelectOption, date_picker, currency_input, checkbox};
+use crate::util::auth::AuthState;
+use crate::util::datetime::today;
+use crate::util::format::{format_currency, format_date};
+use crate::util::i18n::t;
+use crate::util::money::Money;
+use crate::util::permissions::{self, Action};
+use super::store::WorkflowStore;
+use super::types::{ApprovalDocument, Delegation};
+use super::service;
+
+fn delegation_status(delegation: &Delegation) -> View {
+    let date = today();
+    status_badge(if delegation.revoked {
+        StatusType::Cancelled
+    } else if delegation.is_active_on(date) {
+        StatusType::Active
+    } else if delegation.is_over_by(date) {
+        StatusType::Expired
+    } else {
+        StatusType::Scheduled
+    })
+}
+
+/// Delegations page: new delegation form and the delegation register
+#[component]
+pub fn approval_delegations() -> View {
+    let store = use_context::<WorkflowStore>();
+    let auth_state = use_context::<Signal<AuthState>>();
+
+    effect({
+        let store = store.clone();
+        move || {
+            let store = store.clone();
+            spawn(async move {
+                service::load_delegations(&store).await;
+            });
+        }
+    });
+
+    let today_iso = today().to_iso();
+    let delegate = signal(String::new());
+    let starts_on = signal(today_iso.clone());
+    let ends_on = signal(today_iso.clone());
+    let max_amount = signal(Money::ZERO);
+    let reason = signal(String::new());
+    let documents: Vec<(ApprovalDocument, Signal<bool>)> = ApprovalDocument::ALL
+        .iter()
+        .map(|doc| (*doc, signal(false)))
+        .collect();
+    let saving = signal(false);
+    let saved = signal(None::<String>);
+
+    let handle_create = Callback::<()>::new({
+        let store = store.clone();
+        let delegate = delegate.clone();
+        let starts_on = starts_on.clone();
+        let ends_on = ends_on.clone();
+        let max_amount = max_amount.clone();
+        let reason = reason.clone();
+        let documents = documents.clone();
+        let saving = saving.clone();
+        let saved = saved.clone();
+        move |_| {
+            let delegation = Delegation {
+                delegate_id: delegate.get(),
+                starts_on: starts_on.get(),
+                ends_on: ends_on.get(),
+                max_amount: Some(max_amount.get()).filter(|m| *m != Money::ZERO),
+                documents: documents.iter().filter(|(_, on)| on.get()).map(|(doc, _)| *doc).collect(),
+                reason: Some(reason.get()),
+                ..Default::default()
+            };
+            let store = store.clone();
+            let delegate = delegate.clone();
+            let reason = reason.clone();
+            let saving = saving.clone();
+            let saved = saved.clone();
+            saving.set(true);
+            saved.set(None);
+            spawn(async move {
+                if let Ok(created) = service::create_delegation(&store, delegation).await {
+                    saved.set(Some(format!(
+                        "{} {} {} {}",
+                        t("Approvals delegated to"),
+                        created.delegate,
+                        t("until"),
+                        format_date(&created.ends_on)
+                    )));
+                    delegate.set(String::new());
+                    reason.set(String::new());
+                }
+                saving.set(false);
+            });
+        }
+    });
+
+    let auth = auth_state.get();
+    let user_id = auth.user.as_ref().map(|u| u.id.clone()).unwrap_or_default();
+    let can_manage_all = permissions::can_perform(&auth, Action::ReassignApproval);
+    let delegate_options: Vec<SelectOption> = service::approvers()
+        .into_iter()
+        .filter(|a| a.id != user_id)
+        .map(|a| SelectOption { value: a.id, label: a.name })
+        .collect();
+
+    let columns = vec![
+        DataTableColumn { key: "delegator".to_string(), label: t("Delegator").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "delegate".to_string(), label: t("Delegate").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "period".to_string(), label: t("Period").to_string(), width: Some("200px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "scope".to_string(), label: t("Scope").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "status".to_string(), label: t("Status").to_string(), width: Some("110px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "actions".to_string(), label: String::new(), width: Some("90px".to_string()), align: Some("right".to_string()), cell_class: None },
+    ];
+
+    let delegations = store.delegations.get();
+    let rows: Vec<DataTableRow> = delegations.iter().map(|d| {
+        let documents = if d.documents.is_empty() {
+            t("All documents").to_string()
+        } else {
+            d.documents.iter().map(|doc| doc.label()).collect::<Vec<_>>().join(", ")
+        };
+        let scope = match d.max_amount {
+            Some(max) => format!("{} · {} {}", documents, t("up to"), format_currency(max)),
+            None => documents,
+        };
+        let can_revoke = !d.is_over_by(today())
+            && (can_manage_all || d.delegator_id == user_id);
+        let handle_revoke = Callback::<()>::new({
+            let store = store.clone();
+            let id = d.id.clone();
+            move |_| {
+                let store = store.clone();
+                let id = id.clone();
+                spawn(async move {
+                    let _ = service::revoke_delegation(&store, &id).await;
+                });
+            }
+        });
+
+        DataTableRow {
+            id: d.id.clone(),
+            cells: vec![
+                view! { <span>{d.delegator.clone()}</span> },
+                view! {
+                    <span>
+                        {d.delegate.clone()}
+                        if let Some(reason) = d.reason.clone() {
+                            <span class="delegation-reason">{reason}</span>
+                        }
+                    </span>
+                },
+                view! { <span>{format!("{} – {}", format_date(&d.starts_on), format_date(&d.ends_on))}</span> },
+                view! { <span>{scope}</span> },
+                delegation_status(d),
+                if can_revoke {
+                    view! {
+                        <button class="btn btn-sm btn-secondary" data-testid="delegation-revoke" on:click={handle_revoke}>
+                            {t("Revoke")}
+                        </button>
+                    }
+                } else {
+                    view! { <span></span> }
+                },
+            ],
+        }
+    }).collect();
+
+    view! {
+        style {
+            r#"
+            .delegations-page { display: flex; flex-direction: column; gap: var(--space-6); }
+            .delegation-form {
+                display: grid;
+                grid-template-columns: repeat(3, 1fr);
+                gap: 16px;
+            }
+            .delegation-form .span-3 { grid-column: 1 / -1; }
+            .delegation-documents { display: flex; flex-wrap: wrap; gap: 16px; }
+            .delegation-form-actions { display: flex; justify-content: flex-end; }
+            .delegation-reason {
+                display: block;
+                font-size: 12px;
+                color: var(--text-muted);
+            }
+            @media (max-width: 1024px) {
+                .delegation-form { grid-template-columns: 1fr; }
+            }
+            "#
+        }
+
+        <div class="delegations-page" data-testid="delegations-page">
+            {page_header(
+                t("Approval Delegations").to_string(),
+                Some(t("Hand your approvals to a colleague while you are out of office").to_string()),
+                vec![]
+            )}
+
+            if let Some(err) = store.error.get() {
+                {notice_bar(err, NoticeType::Error, None)}
+            }
+            if let Some(msg) = saved.get() {
+                {notice_bar(msg, NoticeType::Success, None)}
+            }
+
+            {panel(
+                t("New Delegation").to_string(),
+                vec![],
+                vec![view! {
+                    <div class="delegation-form" data-testid="delegation-form">
+                        {select_with_testid(
+                            t("Delegate to").to_string(),
+                            delegate.clone(),
+                            delegate_options,
+                            Some(t("Choose a colleague").to_string()),
+                            true,
+                            false,
+                            None,
+                            Some("delegation-delegate".to_string()),
+                        )}
+                        {date_picker(t("From").to_string(), starts_on.clone(), true, false, Some(today_iso.clone()), None, None)}
+                        {date_picker(t("Until").to_string(), ends_on.clone(), true, false, Some(today_iso.clone()), None, None)}
+                        {currency_input(
+                            t("Amount limit").to_string(),
+                            max_amount.clone(),
+                            false,
+                            false,
+                            None,
+                            Some(t("Leave at zero to delegate any amount").to_string()),
+                        )}
+                        {text_input_with_testid(
+                            t("Reason").to_string(),
+                            reason.clone(),
+                            Some(t("e.g. Annual leave").to_string()),
+                            false,
+                            false,
+                            None,
+                            None,
+                            None,
+                            Some("delegation-reason".to_string()),
+                        )}
+                        <div class="span-3">
+                            <div class="delegation-documents">
+                                for (doc, on) in documents.clone() {
+                                    {checkbox(doc.label().to_string(), on, false)}
+                                }
+                            </div>
+                            <span class="form-hint">{t("Tick none to delegate every document type")}</span>
+                        </div>
+                        <div class="delegation-form-actions span-3">
+                            <button
+                                class="btn btn-primary"
+                                disabled={saving.get()}
+                                data-testid="delegation-create"
+                                on:click={handle_create}
+                            >
+                                {t("Delegate Approvals")}
+                            </button>
+                        </div>
+                    </div>
+                }]
+            )}
+
+            {panel(
+                t("Delegations").to_string(),
+                vec![],
+                vec![
+                    if rows.is_empty() {
+                        empty_state(t("No delegations").to_string(), None, None, None)
+                    } else {
+                        data_table(columns, rows, None)
+                    },
+                ]
+            )}
+        </div>
+    }
+}
@@ -0,0 +1,14 @@
+//! Approval workflow feature module
+//!
+//! Builds approval chains from the delegation-of-authority matrix and
+//! records approvers' decisions for requisitions, sourcing plans, tenders,
+//! contracts, purchase orders and budget transfers, rerouting pending steps
+//! to delegates while their approvers are out of office and escalating steps
+//! that outrun their approval SLA.
+
+pub mod types;
+pub mod store;
+pub mod service;
+pub mod approval_timeline;
+pub mod delegations;
+pub mod overdue_approvals;
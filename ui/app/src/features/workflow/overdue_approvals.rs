@@ -0,0 +1,204 @@
+//! Overdue approvals
+//!
+//! Pending approval steps past their SLA, and steps that went overdue and
+//! were escalated to the next authority, longest overdue first.
+
+use components::prelude::*;
+use crate::Route;
+use crate::features::requisitions::service as requisitions;
+use crate::features::requisitions::store::RequisitionsStore;
+use crate::features::requisitions::types::Priority;
+use crate::shared::layout::page_header;
+use crate::shared::components::{
+    panel, data_table_with_testid, DataTableColumn, DataTableRow,
+    kpi_card, KpiColor,
+    notice_bar, NoticeType,
+    empty_state,
+};
+use crate::util::auth::AuthState;
+use crate::util::format::{format_currency, format_date};
+use crate::util::i18n::{t, tn};
+use crate::util::permissions::{self, Action};
+
+fn priority_tag(priority: Priority) -> View {
+    let class = format!("overdue-priority {}", priority.as_str());
+    view! { <span class={class}>{priority.label()}</span> }
+}
+
+/// Overdue approvals page
+#[component]
+pub fn overdue_approvals() -> View {
+    let store = use_context::<RequisitionsStore>();
+    let navigate = use_context::<Callback<Route>>();
+    let auth_state = use_context::<Signal<AuthState>>();
+    let applied = signal(None::<String>);
+
+    effect({
+        let store = store.clone();
+        move || {
+            let store = store.clone();
+            spawn(async move {
+                requisitions::load_requisitions(&store).await;
+            });
+        }
+    });
+
+    let can_apply = permissions::can_perform(&auth_state.get(), Action::EnforceApprovalSla);
+    let handle_apply = Callback::<()>::new({
+        let store = store.clone();
+        let applied = applied.clone();
+        move |_| {
+            let store = store.clone();
+            let applied = applied.clone();
+            applied.set(None);
+            spawn(async move {
+                if let Ok(updated) = requisitions::enforce_approval_slas(&store).await {
+                    applied.set(Some(tn("{n} requisition updated", "{n} requisitions updated", updated as i64)));
+                }
+            });
+        }
+    });
+
+    let on_row_click = {
+        let navigate = navigate.clone();
+        Callback::new(move |id: String| navigate.call(Route::RequisitionsDetail(id)))
+    };
+
+    let overdue = requisitions::overdue_approvals(&store.requisitions.get());
+    let past_due = overdue.iter().filter(|o| o.days_overdue > 0).count();
+    let escalated = overdue.iter().filter(|o| o.step.escalated_from.is_some()).count();
+    let urgent = overdue.iter().filter(|o| o.priority == Priority::Urgent).count();
+
+    let icon_clock = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><circle cx="12" cy="12" r="10"/><polyline points="12 6 12 12 16 14"/></svg>"#;
+    let icon_up = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><polyline points="17 11 12 6 7 11"/><line x1="12" y1="6" x2="12" y2="18"/></svg>"#;
+    let icon_alert = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M10.29 3.86L1.82 18a2 2 0 0 0 1.71 3h16.94a2 2 0 0 0 1.71-3L13.71 3.86a2 2 0 0 0-3.42 0z"/><line x1="12" y1="9" x2="12" y2="13"/><line x1="12" y1="17" x2="12.01" y2="17"/></svg>"#;
+
+    let columns = vec![
+        DataTableColumn { key: "document".to_string(), label: t("Requisition").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "priority".to_string(), label: t("Priority").to_string(), width: Some("100px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "step".to_string(), label: t("Awaiting").to_string(), width: None, align: None, cell_class: None },
+        DataTableColumn { key: "due".to_string(), label: t("Due").to_string(), width: Some("120px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "overdue".to_string(), label: t("Overdue").to_string(), width: Some("140px".to_string()), align: None, cell_class: None },
+        DataTableColumn { key: "amount".to_string(), label: t("Amount").to_string(), width: Some("140px".to_string()), align: Some("right".to_string()), cell_class: None },
+    ];
+
+    let rows: Vec<DataTableRow> = overdue.iter().map(|o| {
+        let awaiting = match &o.step.approver {
+            Some(approver) => format!("{} ({})", o.step.role, approver),
+            None => o.step.role.clone(),
+        };
+        let overdue_label = if o.days_overdue > 0 {
+            format!("{} {}", o.days_overdue, t("business day(s)"))
+        } else {
+            t("Escalated").to_string()
+        };
+
+        DataTableRow {
+            id: o.document_id.clone(),
+            cells: vec![
+                view! {
+                    <span>
+                        <a href={Route::RequisitionsDetail(o.document_id.clone()).to_path()}>{o.document_id.clone()}</a>
+                        <span class="overdue-title">{o.title.clone()}</span>
+                    </span>
+                },
+                priority_tag(o.priority),
+                view! {
+                    <span>
+                        {awaiting}
+                        if let Some(from) = o.step.escalated_from.clone() {
+                            <span class="overdue-escalated" data-testid="overdue-escalated">{format!("{} {}", t("Escalated from"), from)}</span>
+                        }
+                    </span>
+                },
+                view! { <span>{o.step.due_date.as_deref().map(format_date).unwrap_or_default()}</span> },
+                view! {
+                    <span class={if o.days_overdue > 0 { "overdue-days late" } else { "overdue-days" }}>
+                        {overdue_label}
+                    </span>
+                },
+                view! { <span class="amount">{format_currency(o.amount)}</span> },
+            ],
+        }
+    }).collect();
+
+    view! {
+        style {
+            r#"
+            .overdue-approvals { display: flex; flex-direction: column; gap: var(--space-6); }
+            .overdue-title,
+            .overdue-escalated {
+                display: block;
+                font-size: 12px;
+                color: var(--text-muted);
+            }
+            .overdue-days.late { color: var(--red); font-weight: 500; }
+            .overdue-priority { font-size: 12px; font-weight: 500; }
+            .overdue-priority.urgent { color: var(--red); }
+            .overdue-priority.high { color: var(--orange); }
+            "#
+        }
+
+        <div class="overdue-approvals" data-testid="overdue-approvals">
+            {page_header(
+                t("Overdue Approvals").to_string(),
+                Some(t("Approval steps past their SLA and steps escalated to the next authority").to_string()),
+                if can_apply {
+                    vec![view! {
+                        <button class="btn btn-primary" on:click={handle_apply} data-testid="overdue-apply-slas">
+                            {t("Apply SLAs now")}
+                        </button>
+                    }]
+                } else {
+                    vec![]
+                }
+            )}
+
+            if let Some(err) = store.error.get() {
+                {notice_bar(err, NoticeType::Error, None)}
+            }
+            if let Some(msg) = applied.get() {
+                {notice_bar(msg, NoticeType::Success, None)}
+            }
+
+            <div class="kpi-grid">
+                {kpi_card(
+                    t("Past Due").to_string(),
+                    past_due.to_string(),
+                    if past_due == 0 { KpiColor::Green } else { KpiColor::Red },
+                    icon_clock.to_string(),
+                    None,
+                    None
+                )}
+                {kpi_card(
+                    t("Escalated").to_string(),
+                    escalated.to_string(),
+                    KpiColor::Orange,
+                    icon_up.to_string(),
+                    None,
+                    None
+                )}
+                {kpi_card(
+                    t("Urgent").to_string(),
+                    urgent.to_string(),
+                    if urgent == 0 { KpiColor::Green } else { KpiColor::Red },
+                    icon_alert.to_string(),
+                    None,
+                    None
+                )}
+            </div>
+
+            {panel(
+                t("Approvals Needing Attention").to_string(),
+                vec![],
+                vec![
+                    if rows.is_empty() {
+                        empty_state(t("No overdue approvals").to_string(), None, None, None)
+                    } else {
+                        data_table_with_testid(columns, rows, Some(on_row_click), Some("overdue-approvals-table".to_string()), None)
+                    },
+                ]
+            )}
+        </div>
+    }
+}
@@ -0,0 +1,794 @@
+//! Approval workflow engine
+//!
+//! Feature services call [`approval_chain`] when a document is submitted,
+//! [`reroute`] when it is loaded, [`enforce_sla`] when an SLA run is asked
+//! for and [`apply`] when an approver acts on it, recording the decision or
+//! escalation once the document is saved. The chain is stored on the
+//! document itself, so the engine holds no state beyond the
+//! delegation-of-authority matrix, out-of-office delegations, approval SLAs
+//! and the approver directory.
+
+use std::collections::BTreeMap;
+use crate::features::audit::{self, AuditActionType, AuditEntityType, AuditEntry, FieldChange};
+use crate::features::requisitions::types::Priority;
+use crate::util::api::HttpMethod;
+use crate::util::auth::{self, User};
+use crate::util::backend;
+use crate::util::datetime::{self, now_iso, today, Date, Timestamp};
+use crate::util::money::Money;
+use crate::util::permissions::{self, Action};
+use crate::util::reference;
+use super::store::{self, WorkflowStore, load_mock_approvers, load_mock_delegations, load_mock_matrix, load_mock_slas};
+use super::types::{
+    ApprovalAction, ApprovalDecision, ApprovalDocument, ApprovalReassignment, ApprovalRequest, ApprovalSla,
+    ApprovalStep, ApprovalTurnaround, Approver, Delegation, DoaRule, SlaOutcome, StepStatus, WorkflowState,
+};
+
+/// Load the delegation-of-authority matrix
+pub async fn load_matrix(store: &WorkflowStore) {
+    store.loading.set(true);
+    store.error.set(None);
+
+    if backend::is_mock() {
+        load_mock_matrix(store);
+    } else if let Err(e) = backend::fetch_with_snapshot("/workflow/doa-matrix", &store.matrix).await {
+        store.error.set(Some(e.to_string()));
+    }
+
+    store.loading.set(false);
+}
+
+/// Approval chain the matrix prescribes for `request`
+pub async fn approval_chain(request: &ApprovalRequest) -> Result<Vec<ApprovalStep>, String> {
+    let store = store::installed();
+    if store.matrix.get().is_empty() {
+        load_matrix(&store).await;
+        if let Some(e) = store.error.get() {
+            return Err(format!("Could not load the delegation-of-authority matrix: {}", e));
+        }
+    }
+
+    let chain = build_chain(&store.matrix.get(), request);
+    if chain.is_empty() {
+        return Err(format!(
+            "No delegation-of-authority rule covers this {}",
+            request.document.label().to_lowercase()
+        ));
+    }
+    Ok(chain)
+}
+
+/// Merge the stages of every rule that applies to `request`.
+///
+/// Stage n of each rule joins stage n of the chain, so a rule for a category
+/// or a deviation adds approvers alongside the amount band's. A role already
+/// in the chain is not added again, and stages left empty are dropped.
+pub fn build_chain(matrix: &[DoaRule], request: &ApprovalRequest) -> Vec<ApprovalStep> {
+    let mut stages: Vec<Vec<String>> = Vec::new();
+    for rule in matrix.iter().filter(|r| r.applies_to(request)) {
+        for (index, roles) in rule.stages.iter().enumerate() {
+            if stages.len() <= index {
+                stages.resize(index + 1, Vec::new());
+            }
+            for role in roles {
+                let already = stages.iter().flatten().any(|r| r.eq_ignore_ascii_case(role));
+                if !already {
+                    stages[index].push(role.clone());
+                }
+            }
+        }
+    }
+
+    stages
+        .into_iter()
+        .filter(|roles| !roles.is_empty())
+        .enumerate()
+        .flat_map(|(index, roles)| {
+            roles.into_iter().map(move |role| ApprovalStep::new(index as u32 + 1, &role))
+        })
+        .collect()
+}
+
+/// Where `steps` stand
+pub fn state(steps: &[ApprovalStep]) -> WorkflowState {
+    if steps.is_empty() {
+        return WorkflowState::NotStarted;
+    }
+    if steps.iter().any(|s| s.status == StepStatus::Rejected) {
+        return WorkflowState::Rejected;
+    }
+    if steps.iter().any(|s| s.status == StepStatus::Returned) {
+        return WorkflowState::Returned;
+    }
+    match current_stage(steps) {
+        Some(stage) => WorkflowState::InProgress { stage },
+        None => WorkflowState::Approved,
+    }
+}
+
+/// Stage awaiting action: the earliest one with a pending step
+pub fn current_stage(steps: &[ApprovalStep]) -> Option<u32> {
+    steps
+        .iter()
+        .filter(|s| s.status == StepStatus::Pending)
+        .map(|s| s.step)
+        .min()
+}
+
+/// Pending steps of the current stage
+pub fn awaiting(steps: &[ApprovalStep]) -> Vec<&ApprovalStep> {
+    match state(steps) {
+        WorkflowState::InProgress { stage } => steps
+            .iter()
+            .filter(|s| s.step == stage && s.status == StepStatus::Pending)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Load the directory of users approval steps can be assigned to
+pub async fn load_approvers(store: &WorkflowStore) {
+    store.loading.set(true);
+    store.error.set(None);
+
+    if backend::is_mock() {
+        load_mock_approvers(store);
+    } else if let Err(e) = backend::fetch_with_snapshot("/workflow/approvers", &store.approvers).await {
+        store.error.set(Some(e.to_string()));
+    }
+
+    store.loading.set(false);
+}
+
+/// Approver directory in the installed store, loading the mock fixtures on
+/// first use
+pub fn approvers() -> Vec<Approver> {
+    let store = store::installed();
+    if backend::is_mock() && store.approvers.get().is_empty() {
+        load_mock_approvers(&store);
+    }
+    store.approvers.get()
+}
+
+/// How `user` may act on `step`, or why they may not.
+///
+/// A step assigned to someone can only be acted on by them or, while it is
+/// delegated, by their delegate; `Ok(true)` means the user acts as the
+/// delegate. An unassigned step can be acted on by anyone holding its role.
+/// Users are matched on id, never on their display name.
+fn authority(step: &ApprovalStep, user: &User, approvers: &[Approver]) -> Result<bool, String> {
+    let is_user = |id: &str| id == user.id;
+    match (step.approver_id.as_deref(), step.delegated_from_id.as_deref()) {
+        (Some(delegate), Some(_)) if is_user(delegate) => Ok(true),
+        (_, Some(owner)) if is_user(owner) => Ok(false),
+        (Some(approver), None) if is_user(approver) => Ok(false),
+        (None, None) if approvers.iter().any(|a| a.id == user.id && a.holds(&step.role)) => Ok(false),
+        (None, None) => Err(format!("You do not hold the {} role", step.role)),
+        _ => Err(format!(
+            "The {} step is assigned to {}",
+            step.role,
+            step.approver.as_deref().unwrap_or("someone else")
+        )),
+    }
+}
+
+/// Pending steps of the current stage the signed-in user may act on. None
+/// when they requested the document, identified by `requester_id`, since
+/// nobody approves their own.
+pub fn actionable<'a>(steps: &'a [ApprovalStep], requester_id: &str) -> Vec<&'a ApprovalStep> {
+    let Some(user) = auth::current().user else {
+        return Vec::new();
+    };
+    if requester_id == user.id {
+        return Vec::new();
+    }
+    let approvers = approvers();
+    awaiting(steps)
+        .into_iter()
+        .filter(|step| authority(step, &user, &approvers).is_ok())
+        .collect()
+}
+
+/// A decision applied to a document's approval chain. The caller saves the
+/// document and then [`record`](Self::record)s the decision, so a failed
+/// save leaves nothing in the audit trail.
+#[must_use]
+pub struct AppliedDecision {
+    /// Where the chain now stands
+    pub state: WorkflowState,
+    entry: AuditEntry,
+}
+
+impl AppliedDecision {
+    /// Write the decision to the audit trail
+    pub fn record(self) {
+        audit::service::record(self.entry);
+    }
+}
+
+/// Apply the signed-in user's decision to the current stage's step for
+/// `decision.role` of document `id`, raised by the user `requester_id`.
+///
+/// Only the step's approver, their delegate or, for an unassigned step, a
+/// holder of its role may decide, and never the requester. The audit entry
+/// names the approver the user stood in for when they acted as the delegate.
+pub fn apply(
+    document: ApprovalDocument,
+    id: &str,
+    requester_id: &str,
+    steps: &mut [ApprovalStep],
+    decision: &ApprovalDecision,
+) -> Result<AppliedDecision, String> {
+    permissions::require(Action::ActOnApproval)?;
+    let user = auth::current().user.ok_or_else(|| "Sign in to act on approvals".to_string())?;
+    if requester_id == user.id {
+        return Err(format!("You cannot approve a {} you requested", document.label().to_lowercase()));
+    }
+
+    let stage = match state(steps) {
+        WorkflowState::InProgress { stage } => stage,
+        WorkflowState::NotStarted => return Err("This document has not been submitted for approval".to_string()),
+        _ => return Err("This approval has already been concluded".to_string()),
+    };
+
+    let comments = decision.comments.as_ref().map(|c| c.trim().to_string()).filter(|c| !c.is_empty());
+    if decision.action.requires_comments() && comments.is_none() {
+        return Err(format!("Please give a reason to {}", decision.action.label().to_lowercase()));
+    }
+
+    let step = steps
+        .iter_mut()
+        .find(|s| s.step == stage && s.status == StepStatus::Pending && s.role == decision.role)
+        .ok_or_else(|| format!("There is no pending {} step at this stage", decision.role))?;
+    let as_delegate = authority(step, &user, &approvers())?;
+
+    step.status = match decision.action {
+        ApprovalAction::Approve => StepStatus::Approved,
+        ApprovalAction::Reject => StepStatus::Rejected,
+        ApprovalAction::ReturnForInfo => StepStatus::Returned,
+    };
+    if !as_delegate {
+        step.delegated_from = None;
+        step.delegated_from_id = None;
+    }
+    step.approver = Some(user.name);
+    step.approver_id = Some(user.id);
+    step.date = Some(now_iso());
+    step.comments = comments;
+
+    let mut changes = vec![FieldChange::new(&step.role, Some(StepStatus::Pending.label()), Some(step.status.label()))];
+    let on_behalf = match step.delegated_from.as_ref().filter(|_| as_delegate) {
+        Some(owner) => {
+            changes.push(FieldChange::new("On behalf of", None, Some(owner.as_str())));
+            format!(" on behalf of {}", owner)
+        }
+        None => String::new(),
+    };
+    let entry = AuditEntry {
+        action: match decision.action {
+            ApprovalAction::Approve => AuditActionType::Approve,
+            ApprovalAction::Reject => AuditActionType::Reject,
+            ApprovalAction::ReturnForInfo => AuditActionType::Update,
+        },
+        entity_type: audit_entity(document),
+        entity_id: id.to_string(),
+        entity_name: Some(format!("{} {}", document.label(), id)),
+        changes,
+        description: Some(format!(
+            "{} {} step{}",
+            decision.action.label(),
+            step.role,
+            on_behalf
+        )),
+        ..Default::default()
+    };
+
+    Ok(AppliedDecision { state: state(steps), entry })
+}
+
+/// Hand the pending `role` step to `to`, for a step stuck with an approver
+/// who cannot act on it. Administrators only; recorded in the audit trail.
+pub fn reassign(
+    document: ApprovalDocument,
+    id: &str,
+    steps: &mut [ApprovalStep],
+    reassignment: &ApprovalReassignment,
+) -> Result<(), String> {
+    permissions::require(Action::ReassignApproval)?;
+
+    let to = approvers()
+        .into_iter()
+        .find(|a| a.id == reassignment.to)
+        .ok_or_else(|| "Choose who the step should be reassigned to".to_string())?;
+    let reason = reassignment.reason.trim();
+    if reason.is_empty() {
+        return Err("Please give a reason for the reassignment".to_string());
+    }
+
+    let step = steps
+        .iter_mut()
+        .find(|s| s.status == StepStatus::Pending && s.role == reassignment.role)
+        .ok_or_else(|| format!("There is no pending {} step", reassignment.role))?;
+    if step.approver_id.as_deref() == Some(to.id.as_str()) {
+        return Err(format!("The {} step is already with {}", step.role, to.name));
+    }
+
+    let from = step.approver.replace(to.name.clone());
+    step.approver_id = Some(to.id);
+    step.delegated_from = None;
+    step.delegated_from_id = None;
+
+    audit::service::record(AuditEntry {
+        action: AuditActionType::Reassign,
+        entity_type: audit_entity(document),
+        entity_id: id.to_string(),
+        entity_name: Some(format!("{} {}", document.label(), id)),
+        changes: vec![FieldChange::new(&format!("{} approver", step.role), from.as_deref(), Some(to.name.as_str()))],
+        description: Some(reason.to_string()),
+        ..Default::default()
+    });
+    Ok(())
+}
+
+fn audit_entity(document: ApprovalDocument) -> AuditEntityType {
+    match document {
+        ApprovalDocument::Requisition => AuditEntityType::Requisition,
+        ApprovalDocument::Tender => AuditEntityType::Tender,
+        ApprovalDocument::Contract => AuditEntityType::Contract,
+        ApprovalDocument::PurchaseOrder => AuditEntityType::PurchaseOrder,
+        ApprovalDocument::BudgetTransfer => AuditEntityType::Budget,
+        ApprovalDocument::SourcingPlan => AuditEntityType::System,
+    }
+}
+
+/// Load out-of-office delegations.
+///
+/// The mock fixtures are only loaded once, so delegations added in the
+/// session survive revisiting the page.
+pub async fn load_delegations(store: &WorkflowStore) {
+    store.loading.set(true);
+    store.error.set(None);
+
+    if backend::is_mock() {
+        if store.delegations.get().is_empty() {
+            load_mock_delegations(store);
+        }
+    } else if let Err(e) = backend::fetch_with_snapshot("/workflow/delegations", &store.delegations).await {
+        store.error.set(Some(e.to_string()));
+    }
+
+    store.loading.set(false);
+}
+
+/// Delegate the signed-in user's approval authority
+pub async fn create_delegation(store: &WorkflowStore, mut delegation: Delegation) -> Result<Delegation, String> {
+    store.error.set(None);
+
+    let user = auth::current().user.ok_or_else(|| "Sign in to delegate your approvals".to_string())?;
+    delegation.delegator_id = user.id;
+    delegation.delegator = user.name;
+    delegation.delegate = approvers()
+        .into_iter()
+        .find(|a| a.id == delegation.delegate_id)
+        .map(|a| a.name)
+        .unwrap_or_default();
+    delegation.reason = delegation.reason.map(|r| r.trim().to_string()).filter(|r| !r.is_empty());
+
+    if let Err(e) = validate_delegation(&store.delegations.get(), &delegation) {
+        store.error.set(Some(e.clone()));
+        return Err(e);
+    }
+
+    delegation.id = reference::unique_id("DLG");
+    delegation.created_at = now_iso();
+    delegation.revoked = false;
+    let delegation = match backend::save(HttpMethod::Post, "/workflow/delegations", &delegation).await {
+        Ok(saved) => saved,
+        Err(e) => {
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    };
+
+    let mut delegations = store.delegations.get();
+    delegations.insert(0, delegation.clone());
+    store.delegations.set(delegations);
+
+    audit::service::record(AuditEntry {
+        action: AuditActionType::Delegate,
+        entity_type: AuditEntityType::User,
+        entity_id: delegation.id.clone(),
+        entity_name: Some(format!("{} to {}", delegation.delegator, delegation.delegate)),
+        changes: vec![FieldChange::new("Delegate", None, Some(delegation.delegate.as_str()))],
+        description: Some(format!(
+            "{} delegated approvals to {} from {} to {}",
+            delegation.delegator, delegation.delegate, delegation.starts_on, delegation.ends_on
+        )),
+        ..Default::default()
+    });
+
+    Ok(delegation)
+}
+
+fn validate_delegation(existing: &[Delegation], delegation: &Delegation) -> Result<(), String> {
+    if delegation.delegate_id.is_empty() || delegation.delegate.is_empty() {
+        return Err("Choose who should act on your approvals".to_string());
+    }
+    if delegation.delegate_id == delegation.delegator_id {
+        return Err("You cannot delegate approvals to yourself".to_string());
+    }
+    let (Some(starts), Some(ends)) = (Date::parse(&delegation.starts_on), Date::parse(&delegation.ends_on)) else {
+        return Err("Enter the dates the delegation covers".to_string());
+    };
+    if ends < starts {
+        return Err("The delegation cannot end before it starts".to_string());
+    }
+    if ends < today() {
+        return Err("The delegation has already ended".to_string());
+    }
+    if delegation.max_amount.is_some_and(|max| !max.is_positive()) {
+        return Err("The amount limit must be more than zero".to_string());
+    }
+
+    let overlapping = existing.iter().find(|d| {
+        !d.revoked
+            && d.delegator_id == delegation.delegator_id
+            && Date::parse(&d.starts_on).is_some_and(|s| s <= ends)
+            && Date::parse(&d.ends_on).is_some_and(|e| starts <= e)
+            && (d.documents.is_empty()
+                || delegation.documents.is_empty()
+                || d.documents.iter().any(|doc| delegation.documents.contains(doc)))
+    });
+    if let Some(other) = overlapping {
+        return Err(format!(
+            "Your delegation to {} from {} to {} already covers some of these dates",
+            other.delegate, other.starts_on, other.ends_on
+        ));
+    }
+    Ok(())
+}
+
+/// Withdraw a delegation; steps rerouted under it return to the delegator
+pub async fn revoke_delegation(store: &WorkflowStore, id: &str) -> Result<(), String> {
+    store.error.set(None);
+
+    let mut delegations = store.delegations.get();
+    let Some(pos) = delegations.iter().position(|d| d.id == id) else {
+        store.error.set(Some("Delegation not found".to_string()));
+        return Err("Delegation not found".to_string());
+    };
+    let user = auth::current().user.map(|u| u.id).unwrap_or_default();
+    if delegations[pos].delegator_id != user
+        && !permissions::can_perform(&auth::current(), Action::ReassignApproval)
+    {
+        let e = "Only the delegator or an administrator can revoke a delegation".to_string();
+        store.error.set(Some(e.clone()));
+        return Err(e);
+    }
+
+    delegations[pos].revoked = true;
+    let endpoint = format!("/workflow/delegations/{}", id);
+    match backend::save(HttpMethod::Put, &endpoint, &delegations[pos]).await {
+        Ok(saved) => delegations[pos] = saved,
+        Err(e) => {
+            store.error.set(Some(e.to_string()));
+            return Err(e.to_string());
+        }
+    }
+    let revoked = delegations[pos].clone();
+    store.delegations.set(delegations);
+
+    audit::service::record(AuditEntry {
+        action: AuditActionType::Cancel,
+        entity_type: AuditEntityType::User,
+        entity_id: revoked.id.clone(),
+        entity_name: Some(format!("{} to {}", revoked.delegator, revoked.delegate)),
+        description: Some(format!("Revoked delegation from {} to {}", revoked.delegator, revoked.delegate)),
+        ..Default::default()
+    });
+    Ok(())
+}
+
+/// Delegations in the installed store, loading the mock fixtures on first use
+fn delegations() -> Vec<Delegation> {
+    let store = store::installed();
+    if backend::is_mock() && store.delegations.get().is_empty() {
+        load_mock_delegations(&store);
+    }
+    store.delegations.get()
+}
+
+/// Who stands in for the approver with user id `approver_id` today on
+/// `document` for `amount`, following delegations on when the delegate is
+/// away too. Returns the delegate's user id and name.
+pub fn delegate_for(approver_id: &str, document: ApprovalDocument, amount: Money) -> Option<(String, String)> {
+    let delegations = delegations();
+    let date = today();
+    let mut holder: Option<&Delegation> = None;
+    let mut seen = vec![approver_id];
+    while let Some(next) = delegations.iter().find(|d| {
+        d.delegator_id == holder.map_or(approver_id, |h| h.delegate_id.as_str()) && d.covers(document, amount, date)
+    }) {
+        if seen.contains(&next.delegate_id.as_str()) {
+            break;
+        }
+        seen.push(next.delegate_id.as_str());
+        holder = Some(next);
+    }
+    holder.map(|d| (d.delegate_id.clone(), d.delegate.clone()))
+}
+
+/// Route pending steps to whoever holds their approver's authority today,
+/// and hand steps back once a delegation ends. Returns whether any changed.
+pub fn reroute(steps: &mut [ApprovalStep], document: ApprovalDocument, amount: Money) -> bool {
+    let mut changed = false;
+    for step in steps.iter_mut().filter(|s| s.status == StepStatus::Pending) {
+        let (Some(owner_id), Some(owner)) = (step.owner_id().map(str::to_string), step.owner().map(str::to_string)) else {
+            continue;
+        };
+        let (approver_id, approver, delegated_from_id, delegated_from) = match delegate_for(&owner_id, document, amount) {
+            Some((delegate_id, delegate)) => (delegate_id, delegate, Some(owner_id), Some(owner)),
+            None => (owner_id, owner, None, None),
+        };
+        if step.approver_id.as_deref() != Some(approver_id.as_str()) || step.delegated_from_id != delegated_from_id {
+            step.approver_id = Some(approver_id);
+            step.approver = Some(approver);
+            step.delegated_from_id = delegated_from_id;
+            step.delegated_from = delegated_from;
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Load the approval SLAs
+pub async fn load_slas(store: &WorkflowStore) {
+    store.loading.set(true);
+    store.error.set(None);
+
+    if backend::is_mock() {
+        load_mock_slas(store);
+    } else if let Err(e) = backend::fetch_with_snapshot("/workflow/slas", &store.slas).await {
+        store.error.set(Some(e.to_string()));
+    }
+
+    store.loading.set(false);
+}
+
+/// SLAs in the installed store, loading the mock fixtures on first use
+fn slas() -> Vec<ApprovalSla> {
+    let store = store::installed();
+    if backend::is_mock() && store.slas.get().is_empty() {
+        load_mock_slas(&store);
+    }
+    store.slas.get()
+}
+
+/// SLA for the `role` step of `document`; the role's own SLA wins over the
+/// document-wide one
+pub fn sla_for<'a>(slas: &'a [ApprovalSla], document: ApprovalDocument, role: &str) -> Option<&'a ApprovalSla> {
+    slas.iter()
+        .filter(|sla| sla.covers(document, role))
+        .max_by_key(|sla| sla.role.is_some())
+}
+
+fn due_date(slas: &[ApprovalSla], document: ApprovalDocument, role: &str, priority: Priority, from: &str) -> Option<String> {
+    let sla = sla_for(slas, document, role)?;
+    datetime::sla_deadline(from, sla.business_days_for(priority)).map(|date| date.to_iso())
+}
+
+/// Start the SLA clock on the current stage's steps that have not got one.
+/// Returns how many steps were scheduled.
+pub fn schedule(steps: &mut [ApprovalStep], document: ApprovalDocument, priority: Priority) -> u32 {
+    let WorkflowState::InProgress { stage } = state(steps) else {
+        return 0;
+    };
+    let slas = slas();
+    let now = now_iso();
+    let mut scheduled = 0;
+    for step in steps
+        .iter_mut()
+        .filter(|s| s.step == stage && s.status == StepStatus::Pending && s.assigned_at.is_none())
+    {
+        step.due_date = due_date(&slas, document, &step.role, priority, &now);
+        step.assigned_at = Some(now.clone());
+        scheduled += 1;
+    }
+    scheduled
+}
+
+/// Remind approvers whose step is due within a business day, and move
+/// overdue steps on to the authority their SLA escalates to.
+///
+/// Steps of a stage that has just become current are scheduled first. A
+/// later step for the authority a step escalated to is dropped, since they
+/// would otherwise sign twice. Escalations are returned for the audit trail
+/// and only recorded once the caller has saved the document.
+pub fn enforce_sla(
+    document: ApprovalDocument,
+    id: &str,
+    steps: &mut Vec<ApprovalStep>,
+    priority: Priority,
+) -> SlaOutcome {
+    let mut outcome = SlaOutcome {
+        scheduled: schedule(steps, document, priority),
+        ..Default::default()
+    };
+    let WorkflowState::InProgress { stage } = state(steps) else {
+        return outcome;
+    };
+
+    let slas = slas();
+    let date = today();
+    let now = now_iso();
+    for step in steps.iter_mut().filter(|s| s.step == stage && s.status == StepStatus::Pending) {
+        let Some(due) = step.due_date.as_deref().and_then(Date::parse) else {
+            continue;
+        };
+        let escalate_to = sla_for(&slas, document, &step.role)
+            .and_then(|sla| sla.escalate_to.clone())
+            .filter(|to| !to.eq_ignore_ascii_case(&step.role));
+
+        if let Some(to) = escalate_to.filter(|_| due < date) {
+            let from = std::mem::replace(&mut step.role, to.clone());
+            let approver = step.approver.take();
+            step.approver_id = None;
+            step.escalated_from = step.escalated_from.take().or(Some(from.clone()));
+            step.delegated_from = None;
+            step.delegated_from_id = None;
+            step.reminded_at = None;
+            step.due_date = due_date(&slas, document, &to, priority, &now);
+            step.assigned_at = Some(now.clone());
+
+            outcome.entries.push(AuditEntry {
+                action: AuditActionType::Escalate,
+                entity_type: audit_entity(document),
+                entity_id: id.to_string(),
+                entity_name: Some(format!("{} {}", document.label(), id)),
+                changes: vec![FieldChange::new("Approval step", Some(from.as_str()), Some(to.as_str()))],
+                description: Some(format!(
+                    "{} step{} was due on {} and escalated to {}",
+                    from,
+                    approver.map(|a| format!(" with {}", a)).unwrap_or_default(),
+                    due.to_iso(),
+                    to
+                )),
+                ..Default::default()
+            });
+            outcome.escalated.push((from, to));
+            continue;
+        }
+
+        let reminded_today = step
+            .reminded_at
+            .as_deref()
+            .and_then(Timestamp::parse)
+            .is_some_and(|at| at.sast_date() == date);
+        if datetime::business_days_between(date, due) <= 1 && !reminded_today {
+            step.reminded_at = Some(now.clone());
+            outcome.reminded.push(step.role.clone());
+        }
+    }
+
+    for (_, to) in &outcome.escalated {
+        steps.retain(|s| !(s.step > stage && s.status == StepStatus::Pending && s.role.eq_ignore_ascii_case(to)));
+    }
+    outcome
+}
+
+/// Pending steps of the current stage that are overdue or were escalated,
+/// with the business days they are past due
+pub fn overdue_steps(steps: &[ApprovalStep], date: Date) -> Vec<(ApprovalStep, i64)> {
+    awaiting(steps)
+        .into_iter()
+        .filter_map(|step| match step.days_overdue(date) {
+            Some(days) => Some((step.clone(), days)),
+            None => step.escalated_from.is_some().then(|| (step.clone(), 0)),
+        })
+        .collect()
+}
+
+/// Turnaround per approver role across `steps`, for the cycle-time reports.
+///
+/// Completed steps count towards the role that acted on them; an
+/// escalation counts against the role the step was escalated from.
+pub fn turnaround<'a>(steps: impl IntoIterator<Item = &'a ApprovalStep>, date: Date) -> Vec<ApprovalTurnaround> {
+    let mut by_role: BTreeMap<String, (ApprovalTurnaround, i64)> = BTreeMap::new();
+    for step in steps {
+        if let Some(from) = &step.escalated_from {
+            role_stats(&mut by_role, from).0.escalated += 1;
+        }
+        let (stats, total_days) = role_stats(&mut by_role, &step.role);
+        if let Some(days) = step.turnaround_days() {
+            stats.completed += 1;
+            *total_days += days;
+            if step.met_sla() == Some(true) {
+                stats.within_sla += 1;
+            }
+        } else if step.days_overdue(date).is_some() {
+            stats.overdue += 1;
+        }
+    }
+
+    by_role
+        .into_values()
+        .map(|(mut stats, total_days)| {
+            if stats.completed > 0 {
+                stats.average_days = total_days as f64 / stats.completed as f64;
+            }
+            stats
+        })
+        .collect()
+}
+
+/// Running totals for `role`, with the business days its completed steps took
+fn role_stats<'m>(
+    by_role: &'m mut BTreeMap<String, (ApprovalTurnaround, i64)>,
+    role: &str,
+) -> &'m mut (ApprovalTurnaround, i64) {
+    by_role
+        .entry(role.to_string())
+        .or_insert_with(|| (ApprovalTurnaround { role: role.to_string(), ..Default::default() }, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::tenders::types::DeviationType;
+
+    fn rule(document: ApprovalDocument, band: (Option<i64>, Option<i64>), stages: &[&[&str]]) -> DoaRule {
+        DoaRule {
+            id: String::new(),
+            document,
+            description: String::new(),
+            above: band.0.map(Money::rands),
+            up_to: band.1.map(Money::rands),
+            department: None,
+            category: None,
+            deviation: None,
+            any_deviation: false,
+            stages: stages.iter().map(|roles| roles.iter().map(|r| r.to_string()).collect()).collect(),
+        }
+    }
+
+    fn matrix() -> Vec<DoaRule> {
+        use ApprovalDocument::{PurchaseOrder, Requisition};
+        vec![
+            rule(Requisition, (None, Some(50_000)), &[&["Line Manager"]]),
+            rule(Requisition, (Some(50_000), Some(500_000)), &[&["Line Manager"], &["Finance Manager"]]),
+            rule(Requisition, (Some(500_000), None), &[&["Line Manager"], &["Finance Manager"], &["CFO"]]),
+            DoaRule {
+                category: Some("ICT".to_string()),
+                ..rule(Requisition, (None, None), &[&[], &["ICT Manager", "finance manager"]])
+            },
+            DoaRule { any_deviation: true, ..rule(Requisition, (None, None), &[&[], &[], &["Accounting Officer"]]) },
+            rule(PurchaseOrder, (None, None), &[&["Procurement Manager"]]),
+        ]
+    }
+
+    #[test]
+    fn build_chain_merges_every_rule_that_applies() {
+        let requisition = |rands| ApprovalRequest::new(ApprovalDocument::Requisition, Money::rands(rands));
+        let cases: Vec<(ApprovalRequest, &[(u32, &str)])> = vec![
+            (requisition(10_000), &[(1, "Line Manager")]),
+            (requisition(50_000), &[(1, "Line Manager")]),
+            (requisition(50_001), &[(1, "Line Manager"), (2, "Finance Manager")]),
+            (requisition(750_000), &[(1, "Line Manager"), (2, "Finance Manager"), (3, "CFO")]),
+            (
+                requisition(100_000).categories(["ict".to_string()]),
+                &[(1, "Line Manager"), (2, "Finance Manager"), (2, "ICT Manager")],
+            ),
+            (
+                requisition(10_000).deviation(Some(DeviationType::Emergency)),
+                &[(1, "Line Manager"), (2, "Accounting Officer")],
+            ),
+            (ApprovalRequest::new(ApprovalDocument::Tender, Money::rands(10_000)), &[]),
+        ];
+        let matrix = matrix();
+        for (i, (request, expected)) in cases.into_iter().enumerate() {
+            let chain: Vec<(u32, String)> = build_chain(&matrix, &request).into_iter().map(|s| (s.step, s.role)).collect();
+            let expected: Vec<(u32, String)> = expected.iter().map(|(step, role)| (*step, role.to_string())).collect();
+            assert_eq!(chain, expected, "case {}", i);
+        }
+    }
+}
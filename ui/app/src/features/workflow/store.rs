@@ -0,0 +1,280 @@
+//! Approval workflow store and delegation-of-authority matrix fixture
+
+use std::cell::RefCell;
+use components::prelude::*;
+use crate::features::tenders::types::DeviationType;
+use crate::util::money::Money;
+use super::types::{ApprovalDocument, ApprovalSla, Approver, Delegation, DoaRule};
+
+/// Approval workflow state store
+#[derive(Clone)]
+pub struct WorkflowStore {
+    /// Delegation-of-authority matrix
+    pub matrix: Signal<Vec<DoaRule>>,
+    /// Out-of-office delegations, current and past
+    pub delegations: Signal<Vec<Delegation>>,
+    /// Approval turnaround SLAs and escalation paths
+    pub slas: Signal<Vec<ApprovalSla>>,
+    /// Users approval steps can be assigned to, with the DoA roles they hold
+    pub approvers: Signal<Vec<Approver>>,
+    pub loading: Signal<bool>,
+    pub error: Signal<Option<String>>,
+}
+
+impl WorkflowStore {
+    pub fn new() -> Self {
+        Self {
+            matrix: signal(Vec::new()),
+            delegations: signal(Vec::new()),
+            slas: signal(Vec::new()),
+            approvers: signal(Vec::new()),
+            loading: signal(false),
+            error: signal(None),
+        }
+    }
+}
+
+thread_local! {
+    static STORE: RefCell<Option<WorkflowStore>> = RefCell::new(None);
+}
+
+/// Register the app's workflow store so feature services can build approval
+/// chains without being handed it
+pub fn install(store: WorkflowStore) {
+    STORE.with(|s| *s.borrow_mut() = Some(store));
+}
+
+/// The installed workflow store, or a fresh one outside the app component
+pub fn installed() -> WorkflowStore {
+    STORE.with(|s| s.borrow().clone()).unwrap_or_else(|| {
+        let store = WorkflowStore::new();
+        install(store.clone());
+        store
+    })
+}
+
+fn rule(
+    id: &str,
+    document: ApprovalDocument,
+    description: &str,
+    above: Option<i64>,
+    up_to: Option<i64>,
+    stages: &[&[&str]],
+) -> DoaRule {
+    DoaRule {
+        id: id.to_string(),
+        document,
+        description: description.to_string(),
+        above: above.map(Money::rands),
+        up_to: up_to.map(Money::rands),
+        department: None,
+        category: None,
+        deviation: None,
+        any_deviation: false,
+        stages: stages
+            .iter()
+            .map(|stage| stage.iter().map(|role| role.to_string()).collect())
+            .collect(),
+    }
+}
+
+/// Mock delegation-of-authority matrix
+pub fn mock_matrix() -> Vec<DoaRule> {
+    use ApprovalDocument::*;
+
+    vec![
+        // Requisitions by value
+        rule("DOA-REQ-01", Requisition, "Requisitions up to R50 000", None, Some(50_000),
+            &[&["Line Manager"]]),
+        rule("DOA-REQ-02", Requisition, "Requisitions above R50 000 up to R500 000", Some(50_000), Some(500_000),
+            &[&["Line Manager"], &["Budget Holder"]]),
+        rule("DOA-REQ-03", Requisition, "Requisitions above R500 000", Some(500_000), None,
+            &[&["Line Manager"], &["Budget Holder"], &["CFO"]]),
+        DoaRule {
+            category: Some("IT Equipment".to_string()),
+            ..rule("DOA-REQ-ICT-HW", Requisition, "ICT hardware is signed off by the CIO alongside the budget holder", None, None,
+                &[&[], &["Chief Information Officer"]])
+        },
+        DoaRule {
+            category: Some("Software".to_string()),
+            ..rule("DOA-REQ-ICT-SW", Requisition, "Software is signed off by the CIO alongside the budget holder", None, None,
+                &[&[], &["Chief Information Officer"]])
+        },
+        DoaRule {
+            category: Some("Medical".to_string()),
+            ..rule("DOA-REQ-MED", Requisition, "Medical supplies need clinical sign-off", None, None,
+                &[&[], &["Chief Medical Officer"]])
+        },
+        DoaRule {
+            department: Some("Transport".to_string()),
+            ..rule("DOA-REQ-FLEET", Requisition, "Transport requisitions are checked by the fleet manager", None, None,
+                &[&["Fleet Manager"]])
+        },
+
+        // Sourcing plans
+        rule("DOA-SP-01", SourcingPlan, "Annual procurement plans", None, None,
+            &[&["SCM Manager"], &["CFO"], &["Accounting Officer"]]),
+
+        // Tenders
+        rule("DOA-TND-01", Tender, "All tenders", None, None,
+            &[&["Procurement Manager"]]),
+        rule("DOA-TND-02", Tender, "Tenders above R1 million", Some(1_000_000), None,
+            &[&[], &["CFO"]]),
+        DoaRule {
+            any_deviation: true,
+            ..rule("DOA-DEV-01", Tender, "Deviations above R500 000 (TR 16A6.4)", Some(500_000), None,
+                &[&[], &["CFO"]])
+        },
+        DoaRule {
+            any_deviation: true,
+            ..rule("DOA-DEV-02", Tender, "Deviations above R2 million", Some(2_000_000), None,
+                &[&[], &[], &["Accounting Officer"]])
+        },
+        DoaRule {
+            any_deviation: true,
+            ..rule("DOA-DEV-03", Tender, "Deviations above R10 million are reported to National Treasury", Some(10_000_000), None,
+                &[&[], &[], &[], &["National Treasury"]])
+        },
+        DoaRule {
+            deviation: Some(DeviationType::Emergency),
+            ..rule("DOA-DEV-EMG", Tender, "Emergency procurement is ratified by the Accounting Officer", None, None,
+                &[&[], &[], &["Accounting Officer"]])
+        },
+
+        // Contracts
+        rule("DOA-CTR-01", Contract, "Contracts up to R10 million", None, Some(10_000_000),
+            &[&["Contract Manager"], &["CFO"]]),
+        rule("DOA-CTR-02", Contract, "Contracts above R10 million", Some(10_000_000), None,
+            &[&["Contract Manager"], &["CFO"], &["Accounting Officer"]]),
+
+        // Purchase orders
+        rule("DOA-PO-01", PurchaseOrder, "Purchase orders up to R100 000", None, Some(100_000),
+            &[&["Budget Holder"]]),
+        rule("DOA-PO-02", PurchaseOrder, "Purchase orders above R100 000 up to R1 million", Some(100_000), Some(1_000_000),
+            &[&["Budget Holder"], &["Procurement Manager"]]),
+        rule("DOA-PO-03", PurchaseOrder, "Purchase orders above R1 million", Some(1_000_000), None,
+            &[&["Budget Holder", "Procurement Manager"], &["CFO"]]),
+
+        // Budget transfers (Budget Control SRS FR-3.4.2)
+        rule("DOA-BT-01", BudgetTransfer, "Budget transfers up to R100 000", None, Some(100_000),
+            &[&["Finance Manager"]]),
+        rule("DOA-BT-02", BudgetTransfer, "Budget transfers above R100 000 up to R500 000", Some(100_000), Some(500_000),
+            &[&["CFO"]]),
+        rule("DOA-BT-03", BudgetTransfer, "Budget transfers above R500 000", Some(500_000), None,
+            &[&["CFO"], &["Board"]]),
+    ]
+}
+
+/// Load the mock matrix into the store
+pub fn load_mock_matrix(store: &WorkflowStore) {
+    store.matrix.set(mock_matrix());
+}
+
+fn approver(id: &str, name: &str, roles: &[&str]) -> Approver {
+    Approver {
+        id: id.to_string(),
+        name: name.to_string(),
+        roles: roles.iter().map(|role| role.to_string()).collect(),
+    }
+}
+
+/// Mock approver directory
+pub fn mock_approvers() -> Vec<Approver> {
+    vec![
+        approver("U001", "Thabo Mokoena", &["Procurement Manager", "SCM Manager"]),
+        approver("U002", "Sarah Dlamini", &["Line Manager"]),
+        approver("U003", "Peter Mokwena", &["Budget Holder"]),
+        approver("U004", "Grace Nkosi", &["Line Manager", "Head of Department"]),
+        approver("U005", "Nomvula Zulu", &["CFO"]),
+        approver("U006", "David Sithole", &["Line Manager", "Finance Manager"]),
+        approver("U007", "Dr. James Molefe", &["Accounting Officer"]),
+        approver("U008", "Sarah Johnson", &["Procurement Manager", "Contract Manager"]),
+        approver("U009", "Michael Chen", &["Chief Information Officer"]),
+    ]
+}
+
+/// Load the mock approver directory into the store
+pub fn load_mock_approvers(store: &WorkflowStore) {
+    store.approvers.set(mock_approvers());
+}
+
+/// Mock out-of-office delegations
+pub fn mock_delegations() -> Vec<Delegation> {
+    vec![
+        Delegation {
+            id: "DLG-2025-002".to_string(),
+            delegator_id: "U003".to_string(),
+            delegator: "Peter Mokwena".to_string(),
+            delegate_id: "U001".to_string(),
+            delegate: "Thabo Mokoena".to_string(),
+            starts_on: "2025-02-24".to_string(),
+            ends_on: "2025-03-07".to_string(),
+            max_amount: Some(Money::rands(5_000_000)),
+            documents: vec![ApprovalDocument::Requisition, ApprovalDocument::PurchaseOrder],
+            reason: Some("Annual leave".to_string()),
+            created_at: "2025-02-20T15:10:00Z".to_string(),
+            revoked: false,
+        },
+        Delegation {
+            id: "DLG-2025-001".to_string(),
+            delegator_id: "U002".to_string(),
+            delegator: "Sarah Dlamini".to_string(),
+            delegate_id: "U004".to_string(),
+            delegate: "Grace Nkosi".to_string(),
+            starts_on: "2025-01-06".to_string(),
+            ends_on: "2025-01-10".to_string(),
+            max_amount: None,
+            documents: Vec::new(),
+            reason: Some("Executive leadership programme".to_string()),
+            created_at: "2024-12-18T09:00:00Z".to_string(),
+            revoked: false,
+        },
+    ]
+}
+
+/// Load the mock delegations into the store
+pub fn load_mock_delegations(store: &WorkflowStore) {
+    store.delegations.set(mock_delegations());
+}
+
+fn sla(
+    id: &str,
+    document: ApprovalDocument,
+    role: Option<&str>,
+    business_days: [u32; 3],
+    escalate_to: Option<&str>,
+) -> ApprovalSla {
+    let [business_days, high_business_days, urgent_business_days] = business_days;
+    ApprovalSla {
+        id: id.to_string(),
+        document,
+        role: role.map(str::to_string),
+        business_days,
+        high_business_days,
+        urgent_business_days,
+        escalate_to: escalate_to.map(str::to_string),
+    }
+}
+
+/// Mock approval SLAs: business days to act at medium, high and urgent
+/// priority, and who an overdue step escalates to
+pub fn mock_slas() -> Vec<ApprovalSla> {
+    use ApprovalDocument::*;
+
+    vec![
+        sla("SLA-REQ-00", Requisition, None, [3, 2, 1], None),
+        sla("SLA-REQ-LM", Requisition, Some("Line Manager"), [3, 2, 1], Some("Head of Department")),
+        sla("SLA-REQ-BH", Requisition, Some("Budget Holder"), [3, 2, 1], Some("CFO")),
+        sla("SLA-REQ-CFO", Requisition, Some("CFO"), [5, 3, 1], Some("Accounting Officer")),
+        sla("SLA-REQ-AO", Requisition, Some("Accounting Officer"), [5, 3, 2], None),
+        sla("SLA-PO-00", PurchaseOrder, None, [2, 2, 1], None),
+        sla("SLA-TND-00", Tender, None, [5, 3, 2], None),
+        sla("SLA-CTR-00", Contract, None, [10, 5, 3], None),
+        sla("SLA-BT-00", BudgetTransfer, None, [5, 3, 2], None),
+    ]
+}
+
+/// Load the mock SLAs into the store
+pub fn load_mock_slas(store: &WorkflowStore) {
+    store.slas.set(mock_slas());
+}
@@ -0,0 +1,506 @@
+//! Approval workflow domain types
+
+use serde::{Deserialize, Serialize};
+use crate::features::audit::{self, AuditEntry};
+use crate::features::requisitions::types::Priority;
+use crate::features::tenders::types::DeviationType;
+use crate::util::datetime::{self, Date, Timestamp};
+use crate::util::i18n::t;
+use crate::util::money::Money;
+
+/// Kind of document routed for approval
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApprovalDocument {
+    Requisition,
+    SourcingPlan,
+    Tender,
+    Contract,
+    PurchaseOrder,
+    BudgetTransfer,
+}
+
+impl ApprovalDocument {
+    pub const ALL: [ApprovalDocument; 6] = [
+        ApprovalDocument::Requisition,
+        ApprovalDocument::SourcingPlan,
+        ApprovalDocument::Tender,
+        ApprovalDocument::Contract,
+        ApprovalDocument::PurchaseOrder,
+        ApprovalDocument::BudgetTransfer,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            ApprovalDocument::Requisition => "Requisition",
+            ApprovalDocument::SourcingPlan => "Sourcing Plan",
+            ApprovalDocument::Tender => "Tender",
+            ApprovalDocument::Contract => "Contract",
+            ApprovalDocument::PurchaseOrder => "Purchase Order",
+            ApprovalDocument::BudgetTransfer => "Budget Transfer",
+        })
+    }
+}
+
+/// Outcome of one approver's step
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+    /// Sent back to the requester for more information
+    Returned,
+}
+
+impl StepStatus {
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            StepStatus::Pending => "Pending",
+            StepStatus::Approved => "Approved",
+            StepStatus::Rejected => "Rejected",
+            StepStatus::Returned => "Returned for Info",
+        })
+    }
+}
+
+/// One approver's step in an approval chain.
+///
+/// Steps sharing a `step` number form a stage whose approvers act in
+/// parallel, and every one of them must approve; stages run in order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApprovalStep {
+    pub step: u32,
+    pub role: String,
+    /// Who acted on the step, or who it is assigned to while pending
+    pub approver: Option<String>,
+    /// User id of `approver`
+    #[serde(default)]
+    pub approver_id: Option<String>,
+    pub status: StepStatus,
+    pub date: Option<String>,
+    pub comments: Option<String>,
+    /// Approver whose authority was delegated to `approver`
+    #[serde(default)]
+    pub delegated_from: Option<String>,
+    /// User id of `delegated_from`
+    #[serde(default)]
+    pub delegated_from_id: Option<String>,
+    /// When the step's stage became the one awaiting action
+    #[serde(default)]
+    pub assigned_at: Option<String>,
+    /// Last day the approver has to act, `YYYY-MM-DD`
+    #[serde(default)]
+    pub due_date: Option<String>,
+    /// When the approver was last reminded of the step
+    #[serde(default)]
+    pub reminded_at: Option<String>,
+    /// Role the step sat with before it went overdue and was escalated
+    #[serde(default)]
+    pub escalated_from: Option<String>,
+}
+
+impl ApprovalStep {
+    pub fn new(step: u32, role: &str) -> Self {
+        Self {
+            step,
+            role: role.to_string(),
+            approver: None,
+            approver_id: None,
+            status: StepStatus::Pending,
+            date: None,
+            comments: None,
+            delegated_from: None,
+            delegated_from_id: None,
+            assigned_at: None,
+            due_date: None,
+            reminded_at: None,
+            escalated_from: None,
+        }
+    }
+
+    /// Approver the step belongs to before any delegation
+    pub fn owner(&self) -> Option<&str> {
+        self.delegated_from.as_deref().or(self.approver.as_deref())
+    }
+
+    /// User id of [`owner`](Self::owner)
+    pub fn owner_id(&self) -> Option<&str> {
+        self.delegated_from_id.as_deref().or(self.approver_id.as_deref())
+    }
+
+    /// Business days the pending step is past its due date on `date`
+    pub fn days_overdue(&self, date: Date) -> Option<i64> {
+        if self.status != StepStatus::Pending {
+            return None;
+        }
+        let due = Date::parse(self.due_date.as_deref()?)?;
+        (due < date).then(|| datetime::business_days_between(due, date))
+    }
+
+    /// Business days the approver took to act on the step
+    pub fn turnaround_days(&self) -> Option<i64> {
+        if self.status == StepStatus::Pending {
+            return None;
+        }
+        let assigned = Timestamp::parse(self.assigned_at.as_deref()?)?.sast_date();
+        let acted = Timestamp::parse(self.date.as_deref()?)?.sast_date();
+        Some(datetime::business_days_between(assigned, acted).max(0))
+    }
+
+    /// Whether the step was acted on by its due date
+    pub fn met_sla(&self) -> Option<bool> {
+        let due = Date::parse(self.due_date.as_deref()?)?;
+        let acted = Timestamp::parse(self.date.as_deref()?)?.sast_date();
+        Some(acted <= due && self.escalated_from.is_none())
+    }
+}
+
+/// Time an approver has to act on a step before reminders and escalation.
+///
+/// An SLA covers one role on one document type, or every role when `role`
+/// is `None`; a role's own SLA wins over the document-wide one. The
+/// turnaround depends on the document's priority.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApprovalSla {
+    pub id: String,
+    pub document: ApprovalDocument,
+    #[serde(default)]
+    pub role: Option<String>,
+    /// Business days to act on low and medium priority documents
+    pub business_days: u32,
+    pub high_business_days: u32,
+    pub urgent_business_days: u32,
+    /// Next authority an overdue step escalates to; `None` keeps reminding
+    /// the approver
+    #[serde(default)]
+    pub escalate_to: Option<String>,
+}
+
+impl ApprovalSla {
+    pub fn covers(&self, document: ApprovalDocument, role: &str) -> bool {
+        if self.document != document {
+            return false;
+        }
+        match &self.role {
+            Some(r) => r.eq_ignore_ascii_case(role),
+            None => true,
+        }
+    }
+
+    pub fn business_days_for(&self, priority: Priority) -> u32 {
+        match priority {
+            Priority::Low | Priority::Medium => self.business_days,
+            Priority::High => self.high_business_days,
+            Priority::Urgent => self.urgent_business_days,
+        }
+    }
+}
+
+/// What SLA enforcement did to a document's pending steps. The caller saves
+/// the document and then [`record`](Self::record)s the escalations.
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct SlaOutcome {
+    /// Steps given a due date when their stage became current
+    pub scheduled: u32,
+    /// Roles reminded that their step is due or overdue
+    pub reminded: Vec<String>,
+    /// Overdue steps moved on, as (from role, to role)
+    pub escalated: Vec<(String, String)>,
+    pub(super) entries: Vec<AuditEntry>,
+}
+
+impl SlaOutcome {
+    pub fn changed(&self) -> bool {
+        self.scheduled > 0 || !self.reminded.is_empty() || !self.escalated.is_empty()
+    }
+
+    /// Write the escalations to the audit trail
+    pub fn record(self) {
+        for entry in self.entries {
+            audit::service::record(entry);
+        }
+    }
+}
+
+/// A pending step past its due date or escalated, for the overdue approvals view
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OverdueApproval {
+    pub document: ApprovalDocument,
+    pub document_id: String,
+    pub title: String,
+    pub priority: Priority,
+    pub amount: Money,
+    pub step: ApprovalStep,
+    /// Business days past due; zero for an escalated step not yet due again
+    pub days_overdue: i64,
+}
+
+/// Approval turnaround of one role, for the cycle-time reports
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ApprovalTurnaround {
+    pub role: String,
+    /// Steps acted on
+    pub completed: u32,
+    /// Mean business days from assignment to decision
+    pub average_days: f64,
+    /// Completed steps decided by their due date without escalation
+    pub within_sla: u32,
+    /// Pending steps past their due date
+    pub overdue: u32,
+    pub escalated: u32,
+}
+
+impl ApprovalTurnaround {
+    pub fn within_sla_percent(&self) -> f64 {
+        if self.completed == 0 {
+            return 0.0;
+        }
+        self.within_sla as f64 / self.completed as f64 * 100.0
+    }
+}
+
+/// What an approver does with their step
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApprovalAction {
+    Approve,
+    Reject,
+    ReturnForInfo,
+}
+
+impl ApprovalAction {
+    pub fn label(&self) -> &'static str {
+        t(match self {
+            ApprovalAction::Approve => "Approve",
+            ApprovalAction::Reject => "Reject",
+            ApprovalAction::ReturnForInfo => "Return for Info",
+        })
+    }
+
+    /// Rejections and returns must say why
+    pub fn requires_comments(&self) -> bool {
+        !matches!(self, ApprovalAction::Approve)
+    }
+}
+
+/// An approver's action as captured by the approval panel
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApprovalDecision {
+    /// Role of the step being acted on
+    pub role: String,
+    pub action: ApprovalAction,
+    pub comments: Option<String>,
+}
+
+/// An administrator moving a stuck step to another approver
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApprovalReassignment {
+    /// Role of the pending step being moved
+    pub role: String,
+    /// User id of the approver it moves to
+    pub to: String,
+    pub reason: String,
+}
+
+/// Where an approval chain stands
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkflowState {
+    /// No chain has been built yet
+    NotStarted,
+    /// Waiting on the approvers of this stage
+    InProgress { stage: u32 },
+    Approved,
+    Rejected,
+    Returned,
+}
+
+/// Facts about a document that the delegation-of-authority matrix is
+/// consulted with when it is submitted
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApprovalRequest {
+    pub document: ApprovalDocument,
+    pub amount: Money,
+    pub department: String,
+    /// Procurement categories involved, e.g. the line items' categories
+    pub categories: Vec<String>,
+    pub deviation: Option<DeviationType>,
+}
+
+impl ApprovalRequest {
+    pub fn new(document: ApprovalDocument, amount: Money) -> Self {
+        Self {
+            document,
+            amount,
+            department: String::new(),
+            categories: Vec::new(),
+            deviation: None,
+        }
+    }
+
+    pub fn department(mut self, department: &str) -> Self {
+        self.department = department.to_string();
+        self
+    }
+
+    pub fn categories(mut self, categories: impl IntoIterator<Item = String>) -> Self {
+        self.categories = categories.into_iter().filter(|c| !c.is_empty()).collect();
+        self
+    }
+
+    pub fn deviation(mut self, deviation: Option<DeviationType>) -> Self {
+        self.deviation = deviation;
+        self
+    }
+}
+
+/// Out-of-office hand-over of one user's approval authority to another.
+///
+/// Covers the dates from `starts_on` to `ends_on` inclusive, optionally only
+/// for some document types and amounts. Pending steps assigned to the
+/// delegator are rerouted to the delegate while it is in force. Both are
+/// matched on user id; the names are kept for display.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Delegation {
+    pub id: String,
+    #[serde(default)]
+    pub delegator_id: String,
+    pub delegator: String,
+    #[serde(default)]
+    pub delegate_id: String,
+    pub delegate: String,
+    /// First day of the delegation, `YYYY-MM-DD`
+    pub starts_on: String,
+    /// Last day of the delegation, `YYYY-MM-DD`
+    pub ends_on: String,
+    /// Only documents up to and including this amount are delegated
+    #[serde(default)]
+    pub max_amount: Option<Money>,
+    /// Document types delegated; empty means all of them
+    #[serde(default)]
+    pub documents: Vec<ApprovalDocument>,
+    pub reason: Option<String>,
+    pub created_at: String,
+    #[serde(default)]
+    pub revoked: bool,
+}
+
+impl Default for Delegation {
+    fn default() -> Self {
+        let today = datetime::today().to_iso();
+        Self {
+            id: String::new(),
+            delegator_id: String::new(),
+            delegator: String::new(),
+            delegate_id: String::new(),
+            delegate: String::new(),
+            starts_on: today.clone(),
+            ends_on: today,
+            max_amount: None,
+            documents: Vec::new(),
+            reason: None,
+            created_at: String::new(),
+            revoked: false,
+        }
+    }
+}
+
+impl Delegation {
+    /// Whether the delegation is in force on `date`
+    pub fn is_active_on(&self, date: Date) -> bool {
+        !self.revoked
+            && Date::parse(&self.starts_on).is_some_and(|start| start <= date)
+            && Date::parse(&self.ends_on).is_some_and(|end| date <= end)
+    }
+
+    /// Whether the delegation has ended or been revoked by `date`
+    pub fn is_over_by(&self, date: Date) -> bool {
+        self.revoked || Date::parse(&self.ends_on).map_or(true, |end| end < date)
+    }
+
+    /// Whether an approval of `document` for `amount` is delegated on `date`
+    pub fn covers(&self, document: ApprovalDocument, amount: Money, date: Date) -> bool {
+        self.is_active_on(date)
+            && (self.documents.is_empty() || self.documents.contains(&document))
+            && self.max_amount.map_or(true, |max| amount <= max)
+    }
+}
+
+/// Someone approval steps can be assigned to, with the
+/// delegation-of-authority roles they hold
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Approver {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+impl Approver {
+    /// Whether the approver holds the DoA `role`
+    pub fn holds(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r.eq_ignore_ascii_case(role))
+    }
+}
+
+/// Row of the delegation-of-authority (DoA) matrix.
+///
+/// A rule applies to one document type within an amount band and may be
+/// narrowed to a department, a procurement category or deviations. Every
+/// rule that applies contributes its approvers to the chain.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DoaRule {
+    pub id: String,
+    pub document: ApprovalDocument,
+    pub description: String,
+    /// The rule applies to amounts strictly above this
+    #[serde(default)]
+    pub above: Option<Money>,
+    /// ...and up to and including this
+    #[serde(default)]
+    pub up_to: Option<Money>,
+    #[serde(default)]
+    pub department: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    /// `Some` restricts the rule to deviations; `any_deviation` widens it
+    /// to every deviation type
+    #[serde(default)]
+    pub deviation: Option<DeviationType>,
+    #[serde(default)]
+    pub any_deviation: bool,
+    /// Approver roles per stage. Roles in the same stage approve in
+    /// parallel; an empty stage adds nobody at that position.
+    pub stages: Vec<Vec<String>>,
+}
+
+impl DoaRule {
+    pub fn applies_to(&self, request: &ApprovalRequest) -> bool {
+        if request.document != self.document {
+            return false;
+        }
+        if self.above.is_some_and(|floor| request.amount <= floor) {
+            return false;
+        }
+        if self.up_to.is_some_and(|ceiling| request.amount > ceiling) {
+            return false;
+        }
+        if let Some(department) = &self.department {
+            if !department.eq_ignore_ascii_case(&request.department) {
+                return false;
+            }
+        }
+        if let Some(category) = &self.category {
+            if !request.categories.iter().any(|c| c.eq_ignore_ascii_case(category)) {
+                return false;
+            }
+        }
+        if self.any_deviation {
+            return request.deviation.is_some();
+        }
+        match self.deviation {
+            Some(deviation) => request.deviation == Some(deviation),
+            None => true,
+        }
+    }
+}
@@ -13,6 +13,8 @@ use components::prelude::*;
 use page::app_shell::app_shell;
 use shared::layout::base_styles;
 use util::auth::{auth_provider, AuthState};
+use util::i18n::Locale;
+use util::offline::SyncState;
 
 // Feature stores
 use features::dashboard::store::DashboardStore;
@@ -22,6 +24,7 @@ use features::evaluation::store::EvaluationStore;
 use features::contracts::store::ContractsStore;
 use features::purchase_orders::store::PurchaseOrdersStore;
 use features::goods_receipt::store::GoodsReceiptStore;
+use features::invoices::store::InvoicesStore;
 use features::suppliers::store::SuppliersStore;
 use features::supplier_portal::store::SupplierPortalStore;
 use features::catalogue::store::CatalogueStore;
@@ -36,13 +39,19 @@ use features::sourcing_plan::store::SourcingPlanStore;
 use features::bbbee::store::BbbeeStore;
 use features::agsa::store::AgsaStore;
 use features::mobile::store::MobileStore;
+use features::search::store::SearchStore;
+use features::notifications::store::NotificationsStore;
+use features::workflow::store::WorkflowStore;
+use features::budget::store::BudgetStore;
+use features::ocr::store::OcrStore;
+use features::payments::store::PaymentsStore;
 
 /// WASM entry point
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen(start)]
 pub fn start() {
     inject_style(base_styles());
-    let _ = crate::util::api::api_base();
+    crate::util::backend::init();
 
     let window = web_sys::window().expect("no global window");
     let document = window.document().expect("no document");
@@ -59,23 +68,70 @@ pub fn start() {
 /// Root application component with context providers
 #[component]
 pub fn app() -> View {
-    let auth_state = signal(AuthState::default());
+    let auth_state = signal(AuthState::initial());
+    util::auth::install(auth_state.clone());
     provide_context(auth_state.clone());
+    page::app_shell::handle_auth_redirect(auth_state.clone());
+
+    let sync_state = signal(SyncState::default());
+    util::offline::install(sync_state.clone());
+    provide_context(sync_state);
+
+    let locale = signal(Locale::default());
+    util::i18n::install(locale.clone());
+    provide_context(locale);
+
+    // Feature services build approval chains from the installed store
+    let workflow_store = WorkflowStore::new();
+    features::workflow::store::install(workflow_store.clone());
+    let requisitions_store = RequisitionsStore::new();
+
+    // Each user's language choice and the organisation's numbering templates,
+    // delegation-of-authority matrix, delegations and approval SLAs are
+    // applied once they are signed in, and from then on the SLAs are
+    // enforced on a schedule
+    effect({
+        let auth_state = auth_state.clone();
+        let workflow_store = workflow_store.clone();
+        let requisitions_store = requisitions_store.clone();
+        move || {
+            if let Some(user) = auth_state.get().user {
+                util::i18n::restore_locale(&user.id);
+                spawn(util::reference::load_templates());
+                // Send whatever this user queued offline in an earlier session
+                spawn(util::offline::replay());
+                let workflow_store = workflow_store.clone();
+                let requisitions_store = requisitions_store.clone();
+                spawn(async move {
+                    features::workflow::service::load_matrix(&workflow_store).await;
+                    features::workflow::service::load_delegations(&workflow_store).await;
+                    features::workflow::service::load_slas(&workflow_store).await;
+                    features::workflow::service::load_approvers(&workflow_store).await;
+                    features::requisitions::service::start_sla_schedule(&requisitions_store);
+                });
+            } else {
+                features::requisitions::service::stop_sla_schedule();
+            }
+        }
+    });
 
     // Provide all feature stores
     provide_context(DashboardStore::new());
-    provide_context(RequisitionsStore::new());
+    provide_context(requisitions_store);
     provide_context(TendersStore::new());
     provide_context(EvaluationStore::new());
     provide_context(ContractsStore::new());
     provide_context(PurchaseOrdersStore::new());
     provide_context(GoodsReceiptStore::new());
+    provide_context(InvoicesStore::new());
     provide_context(SuppliersStore::new());
     provide_context(SupplierPortalStore::new());
     provide_context(CatalogueStore::new());
     provide_context(AnalyticsStore::new());
     provide_context(GrcStore::new());
-    provide_context(AuditStore::new());
+    let audit_store = AuditStore::new();
+    features::audit::store::install(audit_store.clone());
+    provide_context(audit_store);
     provide_context(NbacStore::new());
     provide_context(ReverseAuctionStore::new());
     provide_context(DocumentsStore::new());
@@ -84,6 +140,19 @@ pub fn app() -> View {
     provide_context(BbbeeStore::new());
     provide_context(AgsaStore::new());
     provide_context(MobileStore::new());
+    provide_context(SearchStore::new());
+    let notifications_store = NotificationsStore::new();
+    features::notifications::store::install(notifications_store.clone());
+    provide_context(notifications_store);
+    provide_context(workflow_store);
+    let budget_store = BudgetStore::new();
+    features::budget::store::install(budget_store.clone());
+    provide_context(budget_store);
+    provide_context(OcrStore::new());
+    provide_context(PaymentsStore::new());
+
+    // Global search reads the stores above
+    features::search::provider::install();
 
     auth_provider(auth_state, vec![app_shell()])
 }
@@ -95,6 +164,7 @@ pub enum Route {
     // Requisitions
     RequisitionsList,
     RequisitionsCreate,
+    RequisitionsDetail(String),
     RequisitionsEdit(String),
     // Tenders
     TendersList,
@@ -113,11 +183,20 @@ pub enum Route {
     // Purchase Orders
     PurchaseOrdersList,
     PurchaseOrdersCreate,
+    PurchaseOrdersDetail(String),
     PurchaseOrdersEdit(String),
     // Goods Receipt
     GoodsReceiptList,
+    GoodsReceiptDetail(String),
+    InvoicesList,
+    InvoicesCreate,
+    InvoicesDetail(String),
+    // Payment runs
+    PaymentRunsList,
+    PaymentRunDetail(String),
     // Suppliers
     SuppliersRegistry,
+    SuppliersDetail(String),
     SuppliersPerformance,
     SuppliersRisk,
     // Supplier Portal
@@ -131,13 +210,24 @@ pub enum Route {
     GrcDashboard,
     // Audit
     AuditTrail,
+    // Approval delegations
+    ApprovalDelegations,
+    OverdueApprovals,
+    // Budget control
+    BudgetDashboard,
+    BudgetTransfers,
     // NBAC
     NbacReviews,
+    NbacReviewDetail(String),
     // Reverse Auction
     ReverseAuctionList,
     ReverseAuctionLive(String),
     // Documents
     DocumentsLibrary,
+    DocumentsDetail(String),
+    // Document intake
+    OcrIntake,
+    OcrReview(String),
     // AI Assistant
     AiAssistantChat,
     // Sourcing Plan
@@ -148,8 +238,11 @@ pub enum Route {
     BbbeeGoals,
     // AGSA
     AgsaReviews,
+    AgsaFindingDetail(String),
     // Mobile
     MobileSupplierApp,
+    // Any path that matches no route
+    NotFound(String),
 }
 
 impl Default for Route {
@@ -166,6 +259,7 @@ impl Route {
             // Requisitions
             Route::RequisitionsList => "/requisitions".to_string(),
             Route::RequisitionsCreate => "/requisitions/new".to_string(),
+            Route::RequisitionsDetail(id) => format!("/requisitions/{}", id),
             Route::RequisitionsEdit(id) => format!("/requisitions/{}/edit", id),
             // Tenders
             Route::TendersList => "/tenders".to_string(),
@@ -184,11 +278,19 @@ impl Route {
             // Purchase Orders
             Route::PurchaseOrdersList => "/purchase-orders".to_string(),
             Route::PurchaseOrdersCreate => "/purchase-orders/new".to_string(),
+            Route::PurchaseOrdersDetail(id) => format!("/purchase-orders/{}", id),
             Route::PurchaseOrdersEdit(id) => format!("/purchase-orders/{}/edit", id),
             // Goods Receipt
             Route::GoodsReceiptList => "/goods-receipt".to_string(),
+            Route::GoodsReceiptDetail(id) => format!("/goods-receipt/{}", id),
+            Route::InvoicesList => "/invoices".to_string(),
+            Route::InvoicesCreate => "/invoices/new".to_string(),
+            Route::InvoicesDetail(id) => format!("/invoices/{}", id),
+            Route::PaymentRunsList => "/payments".to_string(),
+            Route::PaymentRunDetail(id) => format!("/payments/{}", id),
             // Suppliers
             Route::SuppliersRegistry => "/suppliers".to_string(),
+            Route::SuppliersDetail(id) => format!("/suppliers/{}", id),
             Route::SuppliersPerformance => "/suppliers/performance".to_string(),
             Route::SuppliersRisk => "/suppliers/risk".to_string(),
             // Supplier Portal
@@ -202,13 +304,24 @@ impl Route {
             Route::GrcDashboard => "/grc".to_string(),
             // Audit
             Route::AuditTrail => "/audit".to_string(),
+            // Approval delegations
+            Route::ApprovalDelegations => "/delegations".to_string(),
+            Route::OverdueApprovals => "/approvals/overdue".to_string(),
+            // Budget control
+            Route::BudgetDashboard => "/budget".to_string(),
+            Route::BudgetTransfers => "/budget/transfers".to_string(),
             // NBAC
             Route::NbacReviews => "/nbac".to_string(),
+            Route::NbacReviewDetail(id) => format!("/nbac/reviews/{}", id),
             // Reverse Auction
             Route::ReverseAuctionList => "/auctions".to_string(),
             Route::ReverseAuctionLive(id) => format!("/auctions/{}/live", id),
             // Documents
             Route::DocumentsLibrary => "/documents".to_string(),
+            Route::DocumentsDetail(id) => format!("/documents/{}", id),
+            // Document intake
+            Route::OcrIntake => "/intake".to_string(),
+            Route::OcrReview(id) => format!("/intake/{}", id),
             // AI Assistant
             Route::AiAssistantChat => "/ai-assistant".to_string(),
             // Sourcing Plan
@@ -219,8 +332,10 @@ impl Route {
             Route::BbbeeGoals => "/bbbee".to_string(),
             // AGSA
             Route::AgsaReviews => "/agsa".to_string(),
+            Route::AgsaFindingDetail(id) => format!("/agsa/findings/{}", id),
             // Mobile
             Route::MobileSupplierApp => "/mobile".to_string(),
+            Route::NotFound(path) => path.clone(),
         }
     }
 
@@ -230,10 +345,13 @@ impl Route {
         let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
 
         match segments.as_slice() {
-            [] | [""] => Route::Dashboard,
+            [] | [""] | ["dashboard"] => Route::Dashboard,
+            // The OIDC callback lands on the dashboard once the session is applied
+            ["auth", "callback"] => Route::Dashboard,
             // Requisitions
             ["requisitions"] => Route::RequisitionsList,
             ["requisitions", "new"] => Route::RequisitionsCreate,
+            ["requisitions", id] => Route::RequisitionsDetail(id.to_string()),
             ["requisitions", id, "edit"] => Route::RequisitionsEdit(id.to_string()),
             // Tenders
             ["tenders"] => Route::TendersList,
@@ -252,13 +370,21 @@ impl Route {
             // Purchase Orders
             ["purchase-orders"] => Route::PurchaseOrdersList,
             ["purchase-orders", "new"] => Route::PurchaseOrdersCreate,
+            ["purchase-orders", id] => Route::PurchaseOrdersDetail(id.to_string()),
             ["purchase-orders", id, "edit"] => Route::PurchaseOrdersEdit(id.to_string()),
             // Goods Receipt
             ["goods-receipt"] => Route::GoodsReceiptList,
+            ["goods-receipt", id] => Route::GoodsReceiptDetail(id.to_string()),
+            ["invoices"] => Route::InvoicesList,
+            ["invoices", "new"] => Route::InvoicesCreate,
+            ["invoices", id] => Route::InvoicesDetail(id.to_string()),
+            ["payments"] => Route::PaymentRunsList,
+            ["payments", id] => Route::PaymentRunDetail(id.to_string()),
             // Suppliers
             ["suppliers"] => Route::SuppliersRegistry,
             ["suppliers", "performance"] => Route::SuppliersPerformance,
             ["suppliers", "risk"] => Route::SuppliersRisk,
+            ["suppliers", id] => Route::SuppliersDetail(id.to_string()),
             // Supplier Portal
             ["supplier-portal"] => Route::SupplierPortalDashboard,
             // Catalogue
@@ -270,13 +396,24 @@ impl Route {
             ["grc"] => Route::GrcDashboard,
             // Audit
             ["audit"] => Route::AuditTrail,
+            // Approval delegations
+            ["delegations"] => Route::ApprovalDelegations,
+            ["approvals", "overdue"] => Route::OverdueApprovals,
+            // Budget control
+            ["budget"] => Route::BudgetDashboard,
+            ["budget", "transfers"] => Route::BudgetTransfers,
             // NBAC
             ["nbac"] => Route::NbacReviews,
+            ["nbac", "reviews", id] => Route::NbacReviewDetail(id.to_string()),
             // Reverse Auction
             ["auctions"] => Route::ReverseAuctionList,
             ["auctions", id, "live"] => Route::ReverseAuctionLive(id.to_string()),
             // Documents
             ["documents"] => Route::DocumentsLibrary,
+            ["documents", id] => Route::DocumentsDetail(id.to_string()),
+            // Document intake
+            ["intake"] => Route::OcrIntake,
+            ["intake", id] => Route::OcrReview(id.to_string()),
             // AI Assistant
             ["ai-assistant"] => Route::AiAssistantChat,
             // Sourcing Plan
@@ -287,10 +424,10 @@ impl Route {
             ["bbbee"] => Route::BbbeeGoals,
             // AGSA
             ["agsa"] => Route::AgsaReviews,
+            ["agsa", "findings", id] => Route::AgsaFindingDetail(id.to_string()),
             // Mobile
             ["mobile"] => Route::MobileSupplierApp,
-            // Default
-            _ => Route::Dashboard,
+            _ => Route::NotFound(format!("/{}", segments.join("/"))),
         }
     }
 }
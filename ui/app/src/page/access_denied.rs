@@ -0,0 +1,27 @@
+//! Access denied page for routes the user's role may not open
+
+use components::prelude::*;
+use crate::Route;
+use crate::shared::components::empty_state;
+use crate::util::i18n::t;
+
+/// Shown in place of a route the signed-in user is not permitted to view
+#[component]
+pub fn access_denied(on_navigate: Callback<Route>) -> View {
+    let icon = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><rect x="3" y="11" width="18" height="11" rx="2" ry="2"/><path d="M7 11V7a5 5 0 0 1 10 0v4"/></svg>"#;
+
+    let handle_home = Callback::<()>::new(move |_| {
+        on_navigate.call(Route::Dashboard);
+    });
+
+    view! {
+        <div data-testid="access-denied">
+            {empty_state(
+                t("Access denied").to_string(),
+                Some(t("Your role does not have permission to view this page. Contact your system administrator if you need access.").to_string()),
+                Some(icon.to_string()),
+                Some(view! { <button class="btn btn-primary" on:click={handle_home}>{t("Back to Dashboard")}</button> }),
+            )}
+        </div>
+    }
+}
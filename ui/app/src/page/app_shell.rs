@@ -3,12 +3,20 @@
 use components::prelude::*;
 use wasm_bindgen::prelude::*;
 use crate::Route;
-use crate::shared::layout::{sidebar, topbar};
+use crate::shared::layout::{sidebar, sync_banner, topbar};
+use crate::util::api;
+use crate::util::auth::{self, AuthState};
+use crate::util::oidc;
+use crate::util::permissions;
+use super::access_denied::access_denied;
+use super::error_boundary::{self, error_boundary, RouteFault};
+use super::not_found::not_found;
 
 // Feature imports
 use crate::features::dashboard::dashboard_landing::dashboard_landing;
 use crate::features::requisitions::requisition_list::requisition_list;
 use crate::features::requisitions::requisition_form::requisition_form;
+use crate::features::requisitions::requisition_detail::requisition_detail;
 use crate::features::tenders::tender_list::tender_list;
 use crate::features::tenders::tender_form::tender_form;
 use crate::features::tenders::tender_publication::tender_publication;
@@ -20,8 +28,18 @@ use crate::features::contracts::contract_form::contract_form;
 use crate::features::contracts::contract_milestones::contract_milestones;
 use crate::features::purchase_orders::po_list::po_list;
 use crate::features::purchase_orders::po_form::po_form;
+use crate::features::purchase_orders::po_detail::po_detail;
 use crate::features::goods_receipt::gr_list::gr_list;
+use crate::features::goods_receipt::gr_detail::gr_detail;
+use crate::features::invoices::invoice_list::invoice_list;
+use crate::features::invoices::invoice_form::invoice_form;
+use crate::features::invoices::invoice_detail::invoice_detail;
+use crate::features::ocr::ocr_intake::ocr_intake;
+use crate::features::ocr::ocr_review::ocr_review;
+use crate::features::payments::payment_runs::payment_runs;
+use crate::features::payments::payment_run_detail::payment_run_detail;
 use crate::features::suppliers::supplier_registry::supplier_registry;
+use crate::features::suppliers::supplier_detail::supplier_detail;
 use crate::features::suppliers::supplier_performance::supplier_performance;
 use crate::features::suppliers::supplier_risk::supplier_risk;
 use crate::features::supplier_portal::portal_dashboard::portal_dashboard;
@@ -30,16 +48,25 @@ use crate::features::catalogue::catalogue_admin::catalogue_admin;
 use crate::features::analytics::analytics_dashboard::analytics_dashboard;
 use crate::features::grc::grc_dashboard::grc_dashboard;
 use crate::features::audit::audit_trail::audit_trail;
+use crate::features::workflow::delegations::approval_delegations;
+use crate::features::workflow::overdue_approvals::overdue_approvals;
+use crate::features::budget::budget_dashboard::budget_dashboard;
+use crate::features::budget::budget_transfers::budget_transfers;
 use crate::features::nbac::nbac_reviews::nbac_reviews;
+use crate::features::nbac::review_detail::review_detail;
 use crate::features::reverse_auction::auction_list::auction_list;
 use crate::features::reverse_auction::auction_live::auction_live;
 use crate::features::documents::documents_library::documents_library;
+use crate::features::documents::document_detail::document_detail;
 use crate::features::ai_assistant::ai_chat::ai_chat_panel;
 use crate::features::sourcing_plan::sourcing_list::sourcing_list;
 use crate::features::sourcing_plan::sourcing_form::sourcing_form;
 use crate::features::bbbee::bbbee_goals::bbbee_goals;
 use crate::features::agsa::agsa_reviews::agsa_reviews;
+use crate::features::agsa::finding_detail::finding_detail;
 use crate::features::mobile::mobile_app::mobile_app;
+use crate::features::search::global_search::global_search;
+use crate::features::notifications::notification_centre::notification_centre;
 
 /// Get initial route from browser URL
 fn get_initial_route() -> Route {
@@ -57,32 +84,65 @@ fn push_history(route: &Route) {
     let _ = history.push_state_with_url(&JsValue::NULL, "", Some(&path));
 }
 
+/// Finish an OIDC login when the IdP redirects back to the app.
+///
+/// The callback URL is replaced with the app root before the session is
+/// applied, so the shell renders the dashboard rather than the callback path.
+pub fn handle_auth_redirect(auth_state: Signal<AuthState>) {
+    if !oidc::is_callback() {
+        return;
+    }
+
+    spawn(async move {
+        let state = auth::complete_login().await;
+        if let Some(history) = web_sys::window().and_then(|w| w.history().ok()) {
+            let home = format!("{}/", oidc::base_path());
+            let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&home));
+        }
+        auth_state.set(state);
+    });
+}
+
 /// Main application shell
 #[component]
 pub fn app_shell() -> View {
     // Initialize route from current URL
     let route = signal(get_initial_route());
 
+    // A panic while rendering a route is recorded here and shown in its place
+    let fault = signal(None::<RouteFault>);
+    error_boundary::install(route.clone(), fault.clone());
+
     // Handle navigation: update signal AND push to history
     let handle_navigate = Callback::<Route>::new({
         let route = route.clone();
+        let fault = fault.clone();
         move |new_route: Route| {
+            api::cancel_page_requests();
             push_history(&new_route);
+            fault.set(None);
             route.set(new_route);
         }
     });
 
+    // Views that link to detail pages navigate through the same callback
+    provide_context(handle_navigate.clone());
+
     // Listen for popstate (back/forward buttons)
     effect({
         let route = route.clone();
+        let fault = fault.clone();
         move || {
             let route = route.clone();
+            let fault = fault.clone();
             let window = web_sys::window().expect("no window");
             let closure = Closure::<dyn Fn()>::new(move || {
                 let window = web_sys::window().expect("no window");
                 let location = window.location();
                 let pathname = location.pathname().unwrap_or_default();
                 let new_route = Route::from_path(&pathname);
+                api::cancel_page_requests();
+                fault.set(None);
                 route.set(new_route);
             });
             window
@@ -113,37 +173,44 @@ pub fn app_shell() -> View {
         }
 
         <div class="app-shell" data-testid="app-shell">
-            {sidebar(route.clone(), handle_navigate)}
+            {sidebar(route.clone(), handle_navigate.clone())}
             <div class="app-main">
-                {topbar()}
+                {topbar(global_search(handle_navigate.clone()), notification_centre(handle_navigate.clone()))}
                 <main class="app-content page-enter">
-                    {route_view(route.clone())}
+                    {sync_banner()}
+                    {error_boundary(handle_navigate.clone(), || route_view(route.clone(), handle_navigate.clone()))}
                 </main>
             </div>
         </div>
     }
 }
 
-/// Route to view mapping
-fn route_view(route: Signal<Route>) -> View {
+/// Route to view mapping, guarded by the permission matrix
+fn route_view(route: Signal<Route>, on_navigate: Callback<Route>) -> View {
+    let auth_state = use_context::<Signal<AuthState>>();
+    if !permissions::can_access(&auth_state.get(), &route.get()) {
+        return access_denied(on_navigate);
+    }
+
     match route.get() {
         Route::Dashboard => dashboard_landing(),
 
         // Requisitions
         Route::RequisitionsList => requisition_list(),
-        Route::RequisitionsCreate => requisition_form(),
-        Route::RequisitionsEdit(_id) => requisition_form(),
+        Route::RequisitionsCreate => requisition_form(None),
+        Route::RequisitionsDetail(id) => requisition_detail(id),
+        Route::RequisitionsEdit(id) => requisition_form(Some(id)),
 
         // Tenders
         Route::TendersList => tender_list(),
         Route::TendersCreate => tender_form(None),
         Route::TendersEdit(id) => tender_form(Some(id)),
         Route::TendersPublication(id) => tender_publication(id),
-        Route::TendersDeviation(_id) => tender_deviation(),
+        Route::TendersDeviation(id) => tender_deviation(id),
 
         // Evaluation
         Route::EvaluationList => evaluation_list(),
-        Route::EvaluationScoring(_id) => evaluation_scoring(),
+        Route::EvaluationScoring(id) => evaluation_scoring(id),
 
         // Contracts
         Route::ContractsList => contract_list(),
@@ -154,13 +221,21 @@ fn route_view(route: Signal<Route>) -> View {
         // Purchase Orders
         Route::PurchaseOrdersList => po_list(),
         Route::PurchaseOrdersCreate => po_form(None),
+        Route::PurchaseOrdersDetail(id) => po_detail(id),
         Route::PurchaseOrdersEdit(id) => po_form(Some(id)),
 
         // Goods Receipt
         Route::GoodsReceiptList => gr_list(),
+        Route::GoodsReceiptDetail(id) => gr_detail(id),
+        Route::InvoicesList => invoice_list(),
+        Route::InvoicesCreate => invoice_form(),
+        Route::InvoicesDetail(id) => invoice_detail(id),
+        Route::PaymentRunsList => payment_runs(),
+        Route::PaymentRunDetail(id) => payment_run_detail(id),
 
         // Suppliers
         Route::SuppliersRegistry => supplier_registry(),
+        Route::SuppliersDetail(id) => supplier_detail(id),
         Route::SuppliersPerformance => supplier_performance(),
         Route::SuppliersRisk => supplier_risk(),
 
@@ -180,15 +255,29 @@ fn route_view(route: Signal<Route>) -> View {
         // Audit
         Route::AuditTrail => audit_trail(),
 
+        // Approval delegations
+        Route::ApprovalDelegations => approval_delegations(),
+        Route::OverdueApprovals => overdue_approvals(),
+
+        // Budget control
+        Route::BudgetDashboard => budget_dashboard(),
+        Route::BudgetTransfers => budget_transfers(),
+
         // NBAC
         Route::NbacReviews => nbac_reviews(),
+        Route::NbacReviewDetail(id) => review_detail(id),
 
         // Reverse Auction
         Route::ReverseAuctionList => auction_list(),
-        Route::ReverseAuctionLive(_id) => auction_live(),
+        Route::ReverseAuctionLive(id) => auction_live(id),
 
         // Documents
         Route::DocumentsLibrary => documents_library(),
+        Route::DocumentsDetail(id) => document_detail(id),
+
+        // Document intake
+        Route::OcrIntake => ocr_intake(),
+        Route::OcrReview(id) => ocr_review(id),
 
         // AI Assistant
         Route::AiAssistantChat => ai_chat_panel(),
@@ -203,8 +292,11 @@ fn route_view(route: Signal<Route>) -> View {
 
         // AGSA
         Route::AgsaReviews => agsa_reviews(),
+        Route::AgsaFindingDetail(id) => finding_detail(id),
 
         // Mobile
         Route::MobileSupplierApp => mobile_app(),
+
+        Route::NotFound(path) => not_found(path, on_navigate),
     }
 }
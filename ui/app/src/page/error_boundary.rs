@@ -0,0 +1,261 @@
+//! Error boundary around the routed page
+//!
+//! wasm32 aborts on panic, so a failing view cannot be unwound and retried in
+//! place. Instead the panic hook records a fault against the route that was
+//! showing, and on the next render the boundary replaces that route's view
+//! with a recovery panel. The sidebar, topbar and every other route keep
+//! working, and "Try again" re-renders the route from scratch.
+//!
+//! The panic may have left a signal borrowed, in which case that render never
+//! comes. So the hook first writes a static panel into the content area,
+//! touching only the DOM: it covers the routed page, leaves the shell around
+//! it alone, and offers a reload. The recovery panel takes its place as soon
+//! as the fault is rendered. The static panel's text is translated ahead of
+//! time, on every render of the routed page, so the hook never reads a
+//! signal.
+
+use std::cell::{Cell, RefCell};
+use components::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::Route;
+use crate::shared::components::empty_state;
+use crate::util::i18n::t;
+
+/// A failure captured while a route was showing
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteFault {
+    pub route: Route,
+    pub message: String,
+}
+
+#[derive(Clone)]
+struct Boundary {
+    route: Signal<Route>,
+    fault: Signal<Option<RouteFault>>,
+}
+
+/// Translated text of the static panel
+#[derive(Clone, Default)]
+struct CrashText {
+    title: String,
+    description: String,
+    reload: String,
+}
+
+thread_local! {
+    /// Installed by the app shell; read by the panic hook, which runs outside the component tree
+    static BOUNDARY: RefCell<Option<Boundary>> = RefCell::new(None);
+    /// Refreshed on every render so the panic hook has it in the current language
+    static CRASH_TEXT: RefCell<CrashText> = RefCell::new(CrashText::default());
+    /// Set while a panic is being reported; a panic meanwhile is only logged
+    static PANICKED: Cell<bool> = Cell::new(false);
+}
+
+/// Id of the static panel the panic hook writes into the content area
+const CRASH_PANEL_ID: &str = "route-crash";
+
+/// Attribute set on the content area while the static panel covers the page
+const CRASHED_ATTR: &str = "data-crashed";
+
+const ICON: &str = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M10.29 3.86L1.82 18a2 2 0 0 0 1.71 3h16.94a2 2 0 0 0 1.71-3L13.71 3.86a2 2 0 0 0-3.42 0z"/><line x1="12" y1="9" x2="12" y2="13"/><line x1="12" y1="17" x2="12.01" y2="17"/></svg>"#;
+
+/// Register the shell's route and fault signals and install the panic hook
+pub fn install(route: Signal<Route>, fault: Signal<Option<RouteFault>>) {
+    BOUNDARY.with(|b| *b.borrow_mut() = Some(Boundary { route, fault }));
+
+    std::panic::set_hook(Box::new(|info| {
+        web_sys::console::error_1(&info.to_string().into());
+        if PANICKED.with(|p| p.replace(true)) {
+            return;
+        }
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Unexpected error".to_string());
+        show_crash_panel(&message);
+        report(message);
+    }));
+}
+
+/// Record a fault against the current route, e.g. for a load that failed.
+///
+/// The signal is set on a fresh task: the caller may be a panic unwinding out
+/// of the render that would otherwise observe it.
+pub fn report(message: impl Into<String>) {
+    let Some(boundary) = BOUNDARY.with(|b| b.try_borrow().ok().and_then(|b| b.clone())) else {
+        return;
+    };
+    let message = message.into();
+    let record = Closure::once_into_js(move || {
+        let route = boundary.route.get();
+        boundary.fault.set(Some(RouteFault { route, message }));
+    });
+    if let Some(window) = web_sys::window() {
+        let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(record.unchecked_ref(), 0);
+    }
+}
+
+/// Clear any fault and render the current route again
+pub fn retry() {
+    if let Some(boundary) = BOUNDARY.with(|b| b.borrow().clone()) {
+        boundary.fault.set(None);
+        boundary.route.set(boundary.route.get());
+    }
+}
+
+/// Render `content` unless the current route has faulted
+pub fn error_boundary(on_navigate: Callback<Route>, content: impl FnOnce() -> View) -> View {
+    refresh_crash_text();
+    // Rendering again means the app survived: the live panel or page replaces the static one
+    dismiss_crash_panel();
+
+    let Some(boundary) = BOUNDARY.with(|b| b.borrow().clone()) else {
+        return content();
+    };
+
+    let route = boundary.route.get();
+    match boundary.fault.get() {
+        Some(fault) if fault.route == route => route_error(fault.message, on_navigate),
+        _ => content(),
+    }
+}
+
+/// Recovery panel shown in place of a faulted route
+fn route_error(message: String, on_navigate: Callback<Route>) -> View {
+    let handle_retry = Callback::<()>::new(move |_| retry());
+    let handle_home = Callback::<()>::new(move |_| {
+        on_navigate.call(Route::Dashboard);
+    });
+
+    view! {
+        style {
+            r#"
+            .route-error-actions { display: flex; gap: 12px; justify-content: center; }
+            .route-error-detail {
+                max-width: 560px;
+                margin: 16px auto 0;
+                padding: 12px;
+                background: var(--bg);
+                border-radius: var(--radius-sm);
+                font-family: IBM Plex Mono, monospace;
+                font-size: 12px;
+                color: var(--text-muted);
+                white-space: pre-wrap;
+            }
+            "#
+        }
+
+        <div data-testid="route-error">
+            {empty_state(
+                t("Something went wrong").to_string(),
+                Some(t("This page failed to load. The rest of the application is unaffected.").to_string()),
+                Some(ICON.to_string()),
+                Some(view! {
+                    <div class="route-error-actions">
+                        <button class="btn btn-primary" on:click={handle_retry}>{t("Try again")}</button>
+                        <button class="btn btn-secondary" on:click={handle_home}>{t("Back to Dashboard")}</button>
+                    </div>
+                }),
+            )}
+            <pre class="route-error-detail">{message}</pre>
+        </div>
+    }
+}
+
+fn refresh_crash_text() {
+    let text = CrashText {
+        title: t("Something went wrong").to_string(),
+        description: t("The application stopped after an unexpected error. Reload to continue; changes not yet saved on this page may be lost.").to_string(),
+        reload: t("Reload").to_string(),
+    };
+    CRASH_TEXT.with(|c| {
+        if let Ok(mut current) = c.try_borrow_mut() {
+            *current = text;
+        }
+    });
+}
+
+/// The shell's content area, which the routed page renders into
+fn content_area() -> Option<web_sys::Element> {
+    web_sys::window()?.document()?.query_selector("main.app-content").ok()?
+}
+
+/// Cover the routed page with a static panel. Touches only the DOM: the
+/// page's own nodes are hidden, not removed, so the renderer still finds
+/// them if it recovers.
+fn show_crash_panel(message: &str) {
+    let text = CRASH_TEXT.with(|c| c.try_borrow().map(|t| t.clone()).unwrap_or_default());
+    let or = |value: &str, fallback: &str| if value.is_empty() { fallback.to_string() } else { value.to_string() };
+
+    let Some(window) = web_sys::window() else { return };
+    let Some(document) = window.document() else { return };
+    let Some(main) = content_area() else { return };
+    let Ok(panel) = document.create_element("div") else { return };
+    let reload = window.location().href().unwrap_or_default();
+
+    panel.set_id(CRASH_PANEL_ID);
+    let _ = panel.set_attribute("data-testid", "route-crash");
+    panel.set_inner_html(&format!(
+        r#"<style>
+            main[{crashed}] > :not(#{id}) {{ display: none; }}
+            #{id} {{ text-align: center; padding: 60px 20px; color: var(--text-muted); }}
+            #{id} .route-crash-icon {{ width: 64px; height: 64px; margin: 0 auto 16px; opacity: 0.3; }}
+            #{id} h3 {{ font-size: 16px; font-weight: 500; margin-bottom: 8px; color: var(--text); }}
+            #{id} p {{ font-size: 14px; margin-bottom: 16px; }}
+            #{id} pre {{
+                max-width: 560px;
+                margin: 16px auto 0;
+                padding: 12px;
+                background: var(--bg);
+                border-radius: var(--radius-sm);
+                font-family: IBM Plex Mono, monospace;
+                font-size: 12px;
+                white-space: pre-wrap;
+            }}
+        </style>
+        <div class="route-crash-icon">{icon}</div>
+        <h3>{title}</h3>
+        <p>{description}</p>
+        <a class="btn btn-primary" href="{reload_href}" data-testid="route-crash-reload">{reload}</a>
+        <pre>{message}</pre>"#,
+        crashed = CRASHED_ATTR,
+        id = CRASH_PANEL_ID,
+        icon = ICON,
+        title = escape(&or(&text.title, "Something went wrong")),
+        description = escape(&or(&text.description, "The application stopped after an unexpected error. Reload to continue; changes not yet saved on this page may be lost.")),
+        reload_href = escape(&reload),
+        reload = escape(&or(&text.reload, "Reload")),
+        message = escape(message),
+    ));
+
+    if let Some(previous) = document.get_element_by_id(CRASH_PANEL_ID) {
+        previous.remove();
+    }
+    let _ = main.prepend_with_node_1(&panel);
+    let _ = main.set_attribute(CRASHED_ATTR, "");
+}
+
+/// Remove the static panel once the app renders again, so a later panic is
+/// reported afresh
+fn dismiss_crash_panel() {
+    if !PANICKED.with(|p| p.replace(false)) {
+        return;
+    }
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else { return };
+    if let Some(panel) = document.get_element_by_id(CRASH_PANEL_ID) {
+        panel.remove();
+    }
+    if let Some(main) = content_area() {
+        let _ = main.remove_attribute(CRASHED_ATTR);
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
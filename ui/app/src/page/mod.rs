@@ -1,3 +1,6 @@
 //! Page modules
 
+pub mod access_denied;
 pub mod app_shell;
+pub mod error_boundary;
+pub mod not_found;
@@ -0,0 +1,74 @@
+//! Not-found pages for unknown paths and missing entities
+
+use components::prelude::*;
+use crate::Route;
+use crate::shared::components::empty_state;
+use crate::util::i18n::{t, tf};
+use super::error_boundary;
+
+const SEARCH_ICON: &str = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><circle cx="11" cy="11" r="8"/><line x1="21" y1="21" x2="16.65" y2="16.65"/><line x1="8" y1="11" x2="14" y2="11"/></svg>"#;
+
+/// Shown for a path that matches no route
+#[component]
+pub fn not_found(path: String, on_navigate: Callback<Route>) -> View {
+    let handle_home = Callback::<()>::new(move |_| {
+        on_navigate.call(Route::Dashboard);
+    });
+
+    view! {
+        <div data-testid="not-found">
+            {empty_state(
+                t("Page not found").to_string(),
+                Some(tf("There is no page at {path}. Check the link or use the sidebar to find what you need.", &[("path", path)])),
+                Some(SEARCH_ICON.to_string()),
+                Some(view! { <button class="btn btn-primary" on:click={handle_home}>{t("Back to Dashboard")}</button> }),
+            )}
+        </div>
+    }
+}
+
+/// Shown by detail and edit pages when the id in the URL does not resolve.
+///
+/// `entity` and `back_label` come already translated. `error` is the store's
+/// load error, if any, so a failed request is not mistaken for a deleted
+/// record; "Try again" reloads the route.
+pub fn entity_not_found(entity: &str, id: &str, error: Option<String>, back: Route, back_label: &str) -> View {
+    let navigate = use_context::<Callback<Route>>();
+    let handle_back = Callback::<()>::new(move |_| {
+        navigate.call(back.clone());
+    });
+    let handle_retry = Callback::<()>::new(move |_| error_boundary::retry());
+
+    let description = match error {
+        Some(error) => tf(
+            "{entity} {id} could not be loaded: {error}",
+            &[("entity", entity.to_string()), ("id", id.to_string()), ("error", error)],
+        ),
+        None => tf(
+            "{entity} {id} does not exist or you no longer have access to it.",
+            &[("entity", entity.to_string()), ("id", id.to_string())],
+        ),
+    };
+
+    view! {
+        style {
+            r#"
+            .entity-not-found-actions { display: flex; gap: 12px; justify-content: center; }
+            "#
+        }
+
+        <div data-testid="entity-not-found">
+            {empty_state(
+                tf("{entity} not found", &[("entity", entity.to_string())]),
+                Some(description),
+                Some(SEARCH_ICON.to_string()),
+                Some(view! {
+                    <div class="entity-not-found-actions">
+                        <button class="btn btn-primary" on:click={handle_back}>{back_label.to_string()}</button>
+                        <button class="btn btn-secondary" on:click={handle_retry}>{t("Try again")}</button>
+                    </div>
+                }),
+            )}
+        </div>
+    }
+}
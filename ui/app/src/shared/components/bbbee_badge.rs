@@ -1,6 +1,7 @@
 //! B-BBEE level badge component
 
 use components::prelude::*;
+use crate::util::i18n::t;
 
 /// B-BBEE certification levels
 #[derive(Clone, Copy, PartialEq, Default)]
@@ -25,13 +26,13 @@ impl BbbeeLevel {
     }
 
     fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             BbbeeLevel::Level1 => "Level 1",
             BbbeeLevel::Level2 => "Level 2",
             BbbeeLevel::Level3 => "Level 3",
             BbbeeLevel::Level4 => "Level 4",
             BbbeeLevel::NonCompliant => "Non-Compliant",
-        }
+        })
     }
 }
 
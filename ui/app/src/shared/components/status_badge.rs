@@ -1,6 +1,7 @@
 //! Status badge component
 
 use components::prelude::*;
+use crate::util::i18n::t;
 
 /// Status types with associated styling
 #[derive(Clone, Copy, PartialEq, Default)]
@@ -57,7 +58,7 @@ impl StatusType {
     }
 
     fn label(&self) -> &'static str {
-        match self {
+        t(match self {
             StatusType::Approved => "Approved",
             StatusType::Active => "Active",
             StatusType::Published => "Published",
@@ -76,7 +77,7 @@ impl StatusType {
             StatusType::Review => "Review",
             StatusType::OnHold => "On Hold",
             StatusType::Scheduled => "Scheduled",
-        }
+        })
     }
 }
 
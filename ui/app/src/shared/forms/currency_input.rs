@@ -2,12 +2,14 @@
 
 use components::prelude::*;
 use wasm_bindgen::JsCast;
+use crate::util::i18n::{self, tf};
+use crate::util::money::Money;
 
 /// Currency input component with ZAR formatting
 #[component]
 pub fn currency_input(
     label: String,
-    value: Signal<f64>,
+    value: Signal<Money>,
     required: bool,
     disabled: bool,
     error: Option<String>,
@@ -20,7 +22,7 @@ pub fn currency_input(
 #[component]
 pub fn currency_input_with_testid(
     label: String,
-    value: Signal<f64>,
+    value: Signal<Money>,
     required: bool,
     disabled: bool,
     error: Option<String>,
@@ -28,18 +30,31 @@ pub fn currency_input_with_testid(
     testid: Option<String>,
 ) -> View {
     let input_testid = testid.unwrap_or_default();
+    let decimal = i18n::current().decimal_separator();
+
+    // Text that did not parse leaves the value alone and says so under the field
+    let parse_error = signal(None::<String>);
+    let error = error.or(parse_error.get());
     let has_error = error.is_some();
     let group_class = if has_error { "form-group has-error" } else { "form-group" };
 
-    let formatted_value = format!("{:.2}", value.get());
+    let formatted_value = value.get().to_input_string(decimal);
 
     let handle_input = Callback::new({
         let value = value.clone();
+        let parse_error = parse_error.clone();
         move |e: web_sys::Event| {
             let target = e.target().unwrap();
             let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
-            if let Ok(num) = input.value().replace(",", "").parse::<f64>() {
-                value.set(num);
+            match Money::parse_with_decimal(&input.value(), decimal) {
+                Ok(amount) => {
+                    parse_error.set(None);
+                    value.set(amount);
+                }
+                Err(_) => {
+                    let example = Money::from_cents(123_450).to_input_string(decimal);
+                    parse_error.set(Some(tf("Enter an amount such as {example}", &[("example", example)])));
+                }
             }
         }
     });
@@ -1,12 +1,24 @@
 //! File upload component
 
 use components::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::util::i18n::t;
 
 /// Uploaded file info
 #[derive(Clone)]
 pub struct UploadedFile {
     pub name: String,
     pub size: u64,
+    pub mime_type: String,
+    /// Browser handle for reading or uploading the contents
+    pub file: Option<web_sys::File>,
+}
+
+impl UploadedFile {
+    /// Lower-case extension of the file name, e.g. `pdf`
+    pub fn extension(&self) -> String {
+        self.name.rsplit_once('.').map(|(_, ext)| ext.to_lowercase()).unwrap_or_default()
+    }
 }
 
 /// File upload component with drop zone
@@ -20,6 +32,29 @@ pub fn file_upload(
     hint: Option<String>,
     on_remove: Callback<usize>,
 ) -> View {
+    let handle_change = Callback::new({
+        let files = files.clone();
+        move |e: web_sys::Event| {
+            let target = e.target().unwrap();
+            let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
+            let Some(list) = input.files() else { return };
+            let mut selected = if multiple { files.get() } else { Vec::new() };
+            for i in 0..list.length() {
+                if let Some(file) = list.get(i) {
+                    selected.push(UploadedFile {
+                        name: file.name(),
+                        size: file.size() as u64,
+                        mime_type: file.type_(),
+                        file: Some(file),
+                    });
+                }
+            }
+            files.set(selected);
+            // Clear the input so picking the same file again fires a change
+            input.set_value("");
+        }
+    });
+
     view! {
         style {
             r#"
@@ -37,6 +72,7 @@ pub fn file_upload(
                 color: var(--red);
             }
             .drop-zone {
+                display: block;
                 border: 2px dashed var(--border);
                 border-radius: var(--radius-lg);
                 padding: 40px;
@@ -130,7 +166,7 @@ pub fn file_upload(
                     <span class="required-mark">" *"</span>
                 }
             </label>
-            <div class="drop-zone">
+            <label class="drop-zone">
                 <div class="drop-zone-icon">
                     <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
                         <path d="M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4"/>
@@ -138,7 +174,7 @@ pub fn file_upload(
                         <line x1="12" y1="3" x2="12" y2="15"/>
                     </svg>
                 </div>
-                <div class="drop-zone-text">"Drop files here or click to browse"</div>
+                <div class="drop-zone-text">{t("Drop files here or click to browse")}</div>
                 if let Some(h) = hint {
                     <div class="drop-zone-hint">{h}</div>
                 }
@@ -147,8 +183,9 @@ pub fn file_upload(
                     accept={accept.unwrap_or_default()}
                     multiple={multiple}
                     style="display: none;"
+                    on:change={handle_change}
                 />
-            </div>
+            </label>
             if !files.get().is_empty() {
                 <div class="file-list">
                     for (idx, file) in files.get().iter().enumerate() {
@@ -2,6 +2,7 @@
 
 mod text_input;
 mod currency_input;
+mod percentage_input;
 mod select;
 mod multi_select;
 mod date_picker;
@@ -14,6 +15,7 @@ mod filter_bar;
 
 pub use text_input::{text_input, text_input_with_testid};
 pub use currency_input::{currency_input, currency_input_with_testid};
+pub use percentage_input::percentage_input;
 pub use select::{select, select_with_testid, SelectOption};
 pub use multi_select::multi_select;
 pub use date_picker::date_picker;
@@ -0,0 +1,119 @@
+//! Percentage input component
+
+use components::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Percentage input for weights and thresholds
+#[component]
+pub fn percentage_input(
+    label: String,
+    value: Signal<f64>,
+    required: bool,
+    disabled: bool,
+    error: Option<String>,
+    hint: Option<String>,
+) -> View {
+    let has_error = error.is_some();
+    let group_class = if has_error { "form-group has-error" } else { "form-group" };
+
+    let formatted_value = value.get().to_string();
+
+    let handle_input = Callback::new({
+        let value = value.clone();
+        move |e: web_sys::Event| {
+            let target = e.target().unwrap();
+            let input: web_sys::HtmlInputElement = target.dyn_into().unwrap();
+            if let Ok(percent) = input.value().trim().trim_end_matches('%').parse::<f64>() {
+                value.set(percent);
+            }
+        }
+    });
+
+    view! {
+        style {
+            r#"
+            .form-group {
+                display: flex;
+                flex-direction: column;
+                gap: 6px;
+            }
+            .form-group label {
+                font-size: 12px;
+                font-weight: 500;
+                color: var(--text-muted);
+            }
+            .required-mark {
+                color: var(--red);
+            }
+            .percentage-input-wrapper {
+                position: relative;
+                display: flex;
+                align-items: center;
+            }
+            .percentage-suffix {
+                position: absolute;
+                right: 12px;
+                font-size: 13px;
+                color: var(--text-muted);
+                font-weight: 500;
+            }
+            .percentage-input {
+                padding: 10px 28px 10px 12px;
+                border: 1px solid var(--border);
+                border-radius: var(--radius);
+                font-size: 13px;
+                font-family: IBM Plex Mono, monospace;
+                transition: all 0.15s;
+                background: var(--surface);
+                width: 100%;
+                text-align: right;
+            }
+            .percentage-input:focus {
+                outline: none;
+                border-color: var(--blue);
+                box-shadow: 0 0 0 3px var(--blue-light);
+            }
+            .percentage-input:disabled {
+                background: var(--bg);
+                color: var(--text-muted);
+                cursor: not-allowed;
+            }
+            .form-group.has-error .percentage-input {
+                border-color: var(--red);
+            }
+            .form-hint {
+                font-size: 11px;
+                color: var(--text-muted);
+            }
+            .form-error {
+                font-size: 11px;
+                color: var(--red);
+            }
+            "#
+        }
+
+        <div class={group_class}>
+            <label>
+                {label}
+                if required {
+                    <span class="required-mark">" *"</span>
+                }
+            </label>
+            <div class="percentage-input-wrapper">
+                <input
+                    type="text"
+                    class="percentage-input"
+                    value={formatted_value}
+                    disabled={disabled}
+                    on:input={handle_input}
+                />
+                <span class="percentage-suffix">"%"</span>
+            </div>
+            if let Some(err) = error {
+                <span class="form-error">{err}</span>
+            } else if let Some(h) = hint {
+                <span class="form-hint">{h}</span>
+            }
+        </div>
+    }
+}
@@ -723,6 +723,24 @@ h1, h2, h3, .heading {
     border: 3px solid var(--surface);
 }
 
+/* Login screen */
+.auth-required {
+    max-width: 420px;
+    margin: 120px auto;
+    padding: 32px;
+    background: var(--surface);
+    border: 1px solid var(--border);
+    border-radius: var(--radius);
+    text-align: center;
+}
+.auth-required p {
+    color: var(--text-muted);
+    margin: 12px 0 20px;
+}
+.auth-required .auth-error {
+    color: var(--red);
+}
+
 /* Responsive */
 @media (max-width: 768px) {
     .sidebar { transform: translateX(-100%); }
@@ -4,8 +4,10 @@ mod base_styles;
 mod sidebar;
 mod topbar;
 mod page_header;
+mod sync_banner;
 
 pub use base_styles::base_styles;
 pub use sidebar::sidebar;
 pub use topbar::topbar;
 pub use page_header::page_header;
+pub use sync_banner::sync_banner;
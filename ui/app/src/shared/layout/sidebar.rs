@@ -2,6 +2,9 @@
 
 use components::prelude::*;
 use crate::Route;
+use crate::util::auth::AuthState;
+use crate::util::i18n::t;
+use crate::util::permissions;
 
 /// Navigation section with title and items
 struct NavSection {
@@ -21,7 +24,8 @@ struct NavItem {
 /// Sidebar component with navigation
 #[component]
 pub fn sidebar(current_route: Signal<Route>, on_navigate: Callback<Route>) -> View {
-    let sections = nav_sections();
+    let auth_state = use_context::<Signal<AuthState>>();
+    let sections = visible_sections(&auth_state.get());
 
     view! {
         style {
@@ -156,7 +160,7 @@ fn nav_section_view(
 ) -> View {
     view! {
         <div class="nav-section">
-            <div class="nav-section-title">{section.title}</div>
+            <div class="nav-section-title">{t(section.title)}</div>
             for item in section.items.iter() {
                 {nav_item_view(item, current_route.clone(), on_navigate.clone())}
             }
@@ -185,7 +189,7 @@ fn nav_item_view(
     view! {
         <a class={class} data-testid={testid} on:click={handle_click}>
             <span inner_html={item.icon}></span>
-            <span>{item.label}</span>
+            <span>{t(item.label)}</span>
             if let Some(count) = item.badge {
                 <span class="nav-badge">{count.to_string()}</span>
             }
@@ -214,11 +218,25 @@ fn nav_sub_item_view(
 
     view! {
         <a class={class} on:click={handle_click}>
-            <span>{item.label}</span>
+            <span>{t(item.label)}</span>
         </a>
     }
 }
 
+/// Navigation sections filtered to the routes the user may open
+fn visible_sections(auth: &AuthState) -> Vec<NavSection> {
+    nav_sections()
+        .into_iter()
+        .filter_map(|mut section| {
+            section.items.retain(|item| permissions::can_access(auth, &item.route));
+            for item in section.items.iter_mut() {
+                item.sub_items.retain(|sub| permissions::can_access(auth, &sub.route));
+            }
+            if section.items.is_empty() { None } else { Some(section) }
+        })
+        .collect()
+}
+
 /// Returns all navigation sections
 fn nav_sections() -> Vec<NavSection> {
     vec![
@@ -279,6 +297,27 @@ fn nav_sections() -> Vec<NavSection> {
                     badge: None,
                     sub_items: vec![],
                 },
+                NavItem {
+                    label: "Invoices",
+                    route: Route::InvoicesList,
+                    icon: icon_receipt(),
+                    badge: None,
+                    sub_items: vec![],
+                },
+                NavItem {
+                    label: "Payment Runs",
+                    route: Route::PaymentRunsList,
+                    icon: icon_banknote(),
+                    badge: None,
+                    sub_items: vec![],
+                },
+                NavItem {
+                    label: "Document Intake",
+                    route: Route::OcrIntake,
+                    icon: icon_scan(),
+                    badge: None,
+                    sub_items: vec![],
+                },
             ],
         },
         NavSection {
@@ -395,6 +434,27 @@ fn nav_sections() -> Vec<NavSection> {
         NavSection {
             title: "Tools",
             items: vec![
+                NavItem {
+                    label: "Delegations",
+                    route: Route::ApprovalDelegations,
+                    icon: icon_users(),
+                    badge: None,
+                    sub_items: vec![],
+                },
+                NavItem {
+                    label: "Overdue Approvals",
+                    route: Route::OverdueApprovals,
+                    icon: icon_clock(),
+                    badge: None,
+                    sub_items: vec![],
+                },
+                NavItem {
+                    label: "Budget",
+                    route: Route::BudgetDashboard,
+                    icon: icon_bar_chart(),
+                    badge: None,
+                    sub_items: vec![],
+                },
                 NavItem {
                     label: "Analytics",
                     route: Route::AnalyticsDashboard,
@@ -450,6 +510,18 @@ fn icon_package() -> &'static str {
     r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M12.89 1.45l8 4A2 2 0 0 1 22 7.24v9.53a2 2 0 0 1-1.11 1.79l-8 4a2 2 0 0 1-1.79 0l-8-4a2 2 0 0 1-1.1-1.8V7.24a2 2 0 0 1 1.11-1.79l8-4a2 2 0 0 1 1.78 0z"/><polyline points="2.32 6.16 12 11 21.68 6.16"/><line x1="12" y1="22.76" x2="12" y2="11"/></svg>"#
 }
 
+fn icon_receipt() -> &'static str {
+    r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M4 2v20l3-2 3 2 3-2 3 2 3-2V2l-3 2-3-2-3 2-3-2-3 2z"/><line x1="8" y1="9" x2="16" y2="9"/><line x1="8" y1="13" x2="16" y2="13"/></svg>"#
+}
+
+fn icon_banknote() -> &'static str {
+    r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><rect x="2" y="6" width="20" height="12" rx="2"/><circle cx="12" cy="12" r="2"/><path d="M6 12h.01M18 12h.01"/></svg>"#
+}
+
+fn icon_scan() -> &'static str {
+    r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M3 7V5a2 2 0 0 1 2-2h2"/><path d="M17 3h2a2 2 0 0 1 2 2v2"/><path d="M21 17v2a2 2 0 0 1-2 2h-2"/><path d="M7 21H5a2 2 0 0 1-2-2v-2"/><line x1="7" y1="12" x2="17" y2="12"/></svg>"#
+}
+
 fn icon_users() -> &'static str {
     r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M17 21v-2a4 4 0 0 0-4-4H5a4 4 0 0 0-4 4v2"/><circle cx="9" cy="7" r="4"/><path d="M23 21v-2a4 4 0 0 0-3-3.87"/><path d="M16 3.13a4 4 0 0 1 0 7.75"/></svg>"#
 }
@@ -510,6 +582,10 @@ fn icon_folder() -> &'static str {
     r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M22 19a2 2 0 0 1-2 2H4a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2h5l2 3h9a2 2 0 0 1 2 2z"/></svg>"#
 }
 
+fn icon_clock() -> &'static str {
+    r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><circle cx="12" cy="12" r="10"/><polyline points="12 6 12 12 16 14"/></svg>"#
+}
+
 fn icon_message_circle() -> &'static str {
     r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2"><path d="M21 11.5a8.38 8.38 0 0 1-.9 3.8 8.5 8.5 0 0 1-7.6 4.7 8.38 8.38 0 0 1-3.8-.9L3 21l1.9-5.7a8.38 8.38 0 0 1-.9-3.8 8.5 8.5 0 0 1 4.7-7.6 8.38 8.38 0 0 1 3.8-.9h.5a8.48 8.48 0 0 1 8 8v.5z"/></svg>"#
 }
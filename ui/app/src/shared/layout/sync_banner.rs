@@ -0,0 +1,43 @@
+//! Offline and outbox status banner
+
+use components::prelude::*;
+use crate::shared::components::{notice_bar, NoticeType};
+use crate::util::offline::{self, SyncState};
+use crate::util::i18n::{t, tf, tn};
+
+/// Connectivity warning, queued change count and replay conflicts
+#[component]
+pub fn sync_banner() -> View {
+    let sync_state = use_context::<Signal<SyncState>>();
+    let state = sync_state.get();
+
+    let status = if !state.online {
+        Some(match state.pending {
+            0 => t("You are offline. Showing the data saved on this device.").to_string(),
+            n => tn(
+                "You are offline. {n} change saved on this device will be sent when the connection returns.",
+                "You are offline. {n} changes saved on this device will be sent when the connection returns.",
+                n as i64,
+            ),
+        })
+    } else if state.syncing && state.pending > 0 {
+        Some(tn("Back online. Sending {n} queued change...", "Back online. Sending {n} queued changes...", state.pending as i64))
+    } else {
+        None
+    };
+
+    view! {
+        <div class="sync-banner" data-testid="sync-banner">
+            if let Some(message) = status.clone() {
+                {notice_bar(message, NoticeType::Warning, None)}
+            }
+            for (idx, conflict) in state.conflicts.iter().enumerate() {
+                {notice_bar(
+                    tf("{change} was not applied: {reason}", &[("change", conflict.label.clone()), ("reason", conflict.message.clone())]),
+                    NoticeType::Error,
+                    Some(Callback::<()>::new(move |_| offline::dismiss_conflict(idx)))
+                )}
+            }
+        </div>
+    }
+}
@@ -1,10 +1,36 @@
 //! Top navigation bar component
 
 use components::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::util::auth::{self, AuthState};
+use crate::util::i18n::{self, t, Locale};
 
-/// Topbar with search, notifications, and user menu
+/// Topbar with search, notifications, language picker and user menu;
+/// `search` and `notifications` are the global search box and notification centre
 #[component]
-pub fn topbar() -> View {
+pub fn topbar(search: View, notifications: View) -> View {
+    let auth_state = use_context::<Signal<AuthState>>();
+    let user = auth_state.get().user.unwrap_or_default();
+
+    let locale = i18n::current();
+
+    let handle_language = Callback::new({
+        let user_id = user.id.clone();
+        move |e: web_sys::Event| {
+            let target = e.target().unwrap();
+            let select: web_sys::HtmlSelectElement = target.dyn_into().unwrap();
+            if let Some(locale) = Locale::from_code(&select.value()) {
+                i18n::set_locale(locale, &user_id);
+            }
+        }
+    });
+
+    let handle_logout = Callback::<()>::new(move |_| {
+        spawn(async move {
+            auth::logout().await;
+        });
+    });
+
     view! {
         style {
             r#"
@@ -73,15 +99,15 @@ pub fn topbar() -> View {
                 height: 18px;
                 color: var(--text-muted);
             }
-            .notification-dot {
-                position: absolute;
-                top: 6px;
-                right: 6px;
-                width: 8px;
-                height: 8px;
-                background: var(--red);
-                border-radius: 50%;
-                border: 2px solid var(--surface);
+            .language-picker {
+                height: 36px;
+                border: 1px solid var(--border);
+                border-radius: var(--radius);
+                background: var(--surface);
+                padding: 0 8px;
+                font-size: 13px;
+                color: var(--text);
+                cursor: pointer;
             }
             .user-menu {
                 display: flex;
@@ -117,36 +143,42 @@ pub fn topbar() -> View {
 
         <header class="topbar" data-testid="topbar">
             <div class="topbar-left">
-                <div class="search-box">
-                    <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
-                        <circle cx="11" cy="11" r="8"/>
-                        <line x1="21" y1="21" x2="16.65" y2="16.65"/>
-                    </svg>
-                    <input type="text" placeholder="Search requisitions, tenders, suppliers..." />
-                </div>
+                {search}
             </div>
             <div class="topbar-right">
-                <button class="topbar-btn" title="Notifications">
-                    <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
-                        <path d="M18 8A6 6 0 0 0 6 8c0 7-3 9-3 9h18s-3-2-3-9"/>
-                        <path d="M13.73 21a2 2 0 0 1-3.46 0"/>
-                    </svg>
-                    <span class="notification-dot"></span>
-                </button>
-                <button class="topbar-btn" title="Help">
+                {notifications}
+                <select
+                    class="language-picker"
+                    title={t("Language")}
+                    value={locale.code()}
+                    on:change={handle_language}
+                    data-testid="language-picker"
+                >
+                    for option in Locale::all() {
+                        <option value={option.code()} selected={option == locale}>{option.native_name()}</option>
+                    }
+                </select>
+                <button class="topbar-btn" title={t("Help")}>
                     <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
                         <circle cx="12" cy="12" r="10"/>
                         <path d="M9.09 9a3 3 0 0 1 5.83 1c0 2-3 3-3 3"/>
                         <line x1="12" y1="17" x2="12.01" y2="17"/>
                     </svg>
                 </button>
-                <div class="user-menu">
+                <div class="user-menu" data-testid="user-menu">
                     <div class="user-info">
-                        <div class="user-name">"Thabo Mokoena"</div>
-                        <div class="user-role">"Procurement Manager"</div>
+                        <div class="user-name">{user.name.clone()}</div>
+                        <div class="user-role">{user.role.label()}</div>
                     </div>
-                    <div class="user-avatar">"TM"</div>
+                    <div class="user-avatar">{user.avatar_initials.clone()}</div>
                 </div>
+                <button class="topbar-btn" title={t("Sign out")} data-testid="logout-button" on:click={handle_logout}>
+                    <svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                        <path d="M9 21H5a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2h4"/>
+                        <polyline points="16 17 21 12 16 7"/>
+                        <line x1="21" y1="12" x2="9" y2="12"/>
+                    </svg>
+                </button>
             </div>
         </header>
     }
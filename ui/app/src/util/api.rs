@@ -1,18 +1,21 @@
 //! API client utilities
 
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use wasm_bindgen_futures::JsFuture;
-use web_sys::{Request, RequestInit, Response};
-use serde::{de::DeserializeOwned, Serialize};
+use web_sys::{AbortController, AbortSignal, Request, RequestInit, Response};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use super::auth::{self, TokenResponse};
 
-/// Get API base URL from environment
+/// Get API base URL for the configured backend
 pub fn api_base() -> String {
-    // In production, this would come from environment config
-    "/eprocurement/api/v1".to_string()
+    super::backend::base_url()
 }
 
 /// HTTP methods
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HttpMethod {
     Get,
     Post,
@@ -22,7 +25,7 @@ pub enum HttpMethod {
 }
 
 impl HttpMethod {
-    fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &'static str {
         match self {
             HttpMethod::Get => "GET",
             HttpMethod::Post => "POST",
@@ -31,76 +34,521 @@ impl HttpMethod {
             HttpMethod::Delete => "DELETE",
         }
     }
+
+    pub fn parse(method: &str) -> Option<Self> {
+        match method {
+            "GET" => Some(HttpMethod::Get),
+            "POST" => Some(HttpMethod::Post),
+            "PUT" => Some(HttpMethod::Put),
+            "PATCH" => Some(HttpMethod::Patch),
+            "DELETE" => Some(HttpMethod::Delete),
+            _ => None,
+        }
+    }
+}
+
+/// Violation of a single input field, from a problem's `errors` /
+/// `invalid-params` extension member
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct FieldError {
+    #[serde(alias = "name", alias = "pointer", alias = "propertyPath")]
+    pub field: String,
+    #[serde(alias = "reason", alias = "detail")]
+    pub message: String,
+}
+
+/// RFC 7807 problem details
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Problem {
+    #[serde(rename = "type", default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub status: u16,
+    #[serde(default)]
+    pub detail: Option<String>,
+    #[serde(default)]
+    pub instance: Option<String>,
+    #[serde(default, alias = "invalid-params", alias = "invalid_params", alias = "violations")]
+    pub errors: Vec<FieldError>,
+}
+
+impl Problem {
+    /// Problem with only a status and title, for non-problem+json error bodies
+    pub fn new(status: u16, title: &str) -> Self {
+        Self {
+            status,
+            title: Some(title.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Most specific human-readable description
+    pub fn message(&self) -> String {
+        self.detail
+            .clone()
+            .or_else(|| self.title.clone())
+            .unwrap_or_else(|| format!("Request failed with status {}", self.status))
+    }
 }
 
 /// API error type
 #[derive(Debug, Clone)]
-pub struct ApiError {
-    pub status: u16,
-    pub message: String,
+pub enum ApiError {
+    /// The request could not be sent or its response could not be read
+    Network(String),
+    /// 401: missing or expired credentials
+    Unauthorized(Problem),
+    /// 403: signed in but not permitted
+    Forbidden(Problem),
+    /// 404
+    NotFound(Problem),
+    /// 409 / 412: the resource changed or is in the wrong state
+    Conflict(Problem),
+    /// 400 / 422: the request failed validation, possibly per field
+    Validation(Problem),
+    /// 5xx and any other unexpected status
+    Server(Problem),
+    /// No response within the request timeout
+    Timeout,
+    /// Aborted because the page that started it was left
+    Cancelled,
+}
+
+impl ApiError {
+    /// Classify a problem by its HTTP status
+    pub fn from_problem(mut problem: Problem, status: u16) -> Self {
+        problem.status = status;
+        match status {
+            401 => ApiError::Unauthorized(problem),
+            403 => ApiError::Forbidden(problem),
+            404 => ApiError::NotFound(problem),
+            409 | 412 => ApiError::Conflict(problem),
+            400 | 422 => ApiError::Validation(problem),
+            _ => ApiError::Server(problem),
+        }
+    }
+
+    pub fn network(message: impl Into<String>) -> Self {
+        ApiError::Network(message.into())
+    }
+
+    /// HTTP status, 0 for network errors
+    pub fn status(&self) -> u16 {
+        self.problem().map(|p| p.status).unwrap_or(0)
+    }
+
+    pub fn problem(&self) -> Option<&Problem> {
+        match self {
+            ApiError::Network(_) | ApiError::Timeout | ApiError::Cancelled => None,
+            ApiError::Unauthorized(p)
+            | ApiError::Forbidden(p)
+            | ApiError::NotFound(p)
+            | ApiError::Conflict(p)
+            | ApiError::Validation(p)
+            | ApiError::Server(p) => Some(p),
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            ApiError::Network(message) => message.clone(),
+            ApiError::Timeout => "The server took too long to respond".to_string(),
+            ApiError::Cancelled => "Request cancelled".to_string(),
+            _ => self.problem().map(Problem::message).unwrap_or_default(),
+        }
+    }
+
+    /// Whether retrying the same request may succeed
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ApiError::Network(_) | ApiError::Timeout => true,
+            ApiError::Server(p) => matches!(p.status, 429 | 502 | 503 | 504),
+            _ => false,
+        }
+    }
+
+    /// Per-field violations reported by the server
+    pub fn field_errors(&self) -> &[FieldError] {
+        match self {
+            ApiError::Validation(p) => &p.errors,
+            _ => &[],
+        }
+    }
 }
 
 impl std::fmt::Display for ApiError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "API Error {}: {}", self.status, self.message)
+        match self {
+            ApiError::Network(message) => write!(f, "Network error: {}", message),
+            _ => write!(f, "{}", self.message()),
+        }
     }
 }
 
-/// Make an API request
+/// First error reported for `field`, for passing to a form input's `error`.
+///
+/// Nested fields use dotted paths (`terms.payment_terms`); JSON pointers
+/// (`/terms/payment_terms`) are matched too.
+pub fn field_error(errors: &[FieldError], field: &str) -> Option<String> {
+    errors
+        .iter()
+        .find(|e| e.field.trim_start_matches('/').replace('/', ".") == field)
+        .map(|e| e.message.clone())
+}
+
+/// Timeout and retry policy for a request
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestOptions {
+    /// Abort the attempt after this many milliseconds; 0 disables the timeout
+    pub timeout_ms: u32,
+    /// Further attempts after a transient failure
+    pub max_retries: u32,
+    /// First backoff delay; doubled on each retry
+    pub backoff_base_ms: u32,
+    /// Upper bound for a single backoff delay
+    pub backoff_max_ms: u32,
+    /// Cancel the request when the user navigates to another page. Only
+    /// reads are page-scoped by default, so a save is never abandoned
+    /// half-way because the user moved on.
+    pub page_scoped: bool,
+    /// Sent as `If-Match` so the server can reject changes to a stale version
+    pub if_match: Option<String>,
+}
+
+impl Default for RequestOptions {
+    fn default() -> Self {
+        Self {
+            timeout_ms: 15_000,
+            max_retries: 3,
+            backoff_base_ms: 500,
+            backoff_max_ms: 8_000,
+            page_scoped: true,
+            if_match: None,
+        }
+    }
+}
+
+impl RequestOptions {
+    /// Defaults for `method`: only idempotent GETs are retried or cancelled
+    /// on navigation
+    pub fn for_method(method: &HttpMethod) -> Self {
+        let defaults = DEFAULT_OPTIONS.with(|o| o.borrow().clone());
+        match method {
+            HttpMethod::Get => defaults,
+            _ => Self {
+                timeout_ms: defaults.timeout_ms.saturating_mul(2),
+                max_retries: 0,
+                page_scoped: false,
+                ..defaults
+            },
+        }
+    }
+
+    /// Full-jitter exponential backoff before retry number `attempt` (0-based)
+    fn backoff_ms(&self, attempt: u32) -> u32 {
+        let ceiling = self
+            .backoff_base_ms
+            .saturating_mul(1u32 << attempt.min(16))
+            .min(self.backoff_max_ms);
+        (js_sys::Math::random() * ceiling as f64) as u32
+    }
+}
+
+thread_local! {
+    static DEFAULT_OPTIONS: RefCell<RequestOptions> = RefCell::new(RequestOptions::default());
+    /// Aborted and replaced on every page navigation
+    static PAGE_SCOPE: RefCell<Option<AbortController>> = RefCell::new(None);
+}
+
+/// Override the default GET timeout and retry policy
+pub fn set_default_options(options: RequestOptions) {
+    DEFAULT_OPTIONS.with(|o| *o.borrow_mut() = options);
+}
+
+/// Cancel every in-flight request started by the current page.
+///
+/// Called by the app shell when the route changes.
+pub fn cancel_page_requests() {
+    PAGE_SCOPE.with(|scope| {
+        if let Some(controller) = scope.borrow_mut().take() {
+            controller.abort();
+        }
+    });
+}
+
+/// Abort signal for the current page, created on first use
+fn page_signal() -> Option<AbortSignal> {
+    PAGE_SCOPE.with(|scope| {
+        let mut scope = scope.borrow_mut();
+        if scope.is_none() {
+            *scope = AbortController::new().ok();
+        }
+        scope.as_ref().map(|c| c.signal())
+    })
+}
+
+/// Make an authenticated API request with the default options for `method`
 pub async fn request<T: DeserializeOwned>(
     method: HttpMethod,
     endpoint: &str,
     body: Option<String>,
+) -> Result<T, ApiError> {
+    let options = RequestOptions::for_method(&method);
+    request_with(method, endpoint, body, &options).await
+}
+
+/// Make an authenticated API request.
+///
+/// The current access token is sent as a bearer token. On a 401 the session
+/// is refreshed once and the request retried; if the refresh fails the user
+/// is signed out. Transient failures are retried with jittered exponential
+/// backoff up to `options.max_retries` times.
+pub async fn request_with<T: DeserializeOwned>(
+    method: HttpMethod,
+    endpoint: &str,
+    body: Option<String>,
+    options: &RequestOptions,
 ) -> Result<T, ApiError> {
     let url = format!("{}{}", api_base(), endpoint);
+    let scope = if options.page_scoped { page_signal() } else { None };
+
+    let mut attempt = 0;
+    loop {
+        match authorized_send(&method, &url, body.as_deref(), options, scope.as_ref()).await {
+            Ok(response) => return parse_response(response).await,
+            Err(e) if e.is_transient() && attempt < options.max_retries => {
+                sleep(options.backoff_ms(attempt), scope.as_ref()).await?;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Send once with the bearer token, refreshing the session on a 401.
+/// Non-2xx responses are returned as errors.
+async fn authorized_send(
+    method: &HttpMethod,
+    url: &str,
+    body: Option<&str>,
+    options: &RequestOptions,
+    scope: Option<&AbortSignal>,
+) -> Result<Response, ApiError> {
+    let token = auth::access_token();
+    let mut response = send(method, url, body, token.as_deref(), options, scope).await?;
+
+    if response.status() == 401 {
+        // Another request may already have refreshed the session
+        let current = auth::access_token();
+        let retry_token = if current.is_some() && current != token {
+            current
+        } else {
+            auth::refresh_session().await.ok()
+        };
+
+        match retry_token {
+            Some(t) => response = send(method, url, body, Some(&t), options, scope).await?,
+            None => {
+                return Err(ApiError::Unauthorized(Problem::new(
+                    401,
+                    "Session expired, please sign in again",
+                )))
+            }
+        }
+    }
+
+    if !response.ok() {
+        return Err(read_problem(response).await);
+    }
+    Ok(response)
+}
+
+/// Exchange a refresh token for a new token pair.
+///
+/// Sent without a bearer token and never retried, so a rejected refresh
+/// token cannot loop back into another refresh. Not page-scoped, so
+/// navigating mid-refresh does not cancel it.
+pub async fn refresh_tokens(refresh_token: &str) -> Result<TokenResponse, ApiError> {
+    let url = format!("{}/auth/refresh", api_base());
+    let body = serde_json::json!({ "refresh_token": refresh_token }).to_string();
+    let options = RequestOptions {
+        max_retries: 0,
+        page_scoped: false,
+        ..RequestOptions::default()
+    };
+    let response = send(&HttpMethod::Post, &url, Some(&body), None, &options, None).await?;
+    parse_response(response).await
+}
+
+/// Send a single request and return the raw response.
+///
+/// The attempt is aborted on timeout or when `scope` (the page signal) aborts.
+async fn send(
+    method: &HttpMethod,
+    url: &str,
+    body: Option<&str>,
+    token: Option<&str>,
+    options: &RequestOptions,
+    scope: Option<&AbortSignal>,
+) -> Result<Response, ApiError> {
+    if scope.map(|s| s.aborted()).unwrap_or(false) {
+        return Err(ApiError::Cancelled);
+    }
+
+    let window = web_sys::window().ok_or_else(|| ApiError::network("No browser window"))?;
+    let controller = AbortController::new()
+        .map_err(|_| ApiError::network("Failed to create request"))?;
 
     let mut opts = RequestInit::new();
     opts.method(method.as_str());
+    opts.signal(Some(&controller.signal()));
 
     if let Some(b) = body {
-        opts.body(Some(&JsValue::from_str(&b)));
+        opts.body(Some(&JsValue::from_str(b)));
     }
 
-    let request = Request::new_with_str_and_init(&url, &opts)
-        .map_err(|_| ApiError {
-            status: 0,
-            message: "Failed to create request".to_string(),
-        })?;
+    let request = Request::new_with_str_and_init(url, &opts)
+        .map_err(|_| ApiError::network("Failed to create request"))?;
 
     request
         .headers()
         .set("Content-Type", "application/json")
         .ok();
 
-    let window = web_sys::window().unwrap();
-    let response_value = JsFuture::from(window.fetch_with_request(&request))
-        .await
-        .map_err(|_| ApiError {
-            status: 0,
-            message: "Network error".to_string(),
-        })?;
+    if let Some(t) = token {
+        request
+            .headers()
+            .set("Authorization", &format!("Bearer {}", t))
+            .ok();
+    }
 
-    let response: Response = response_value.dyn_into().unwrap();
-    let status = response.status();
+    if let Some(version) = &options.if_match {
+        request.headers().set("If-Match", version).ok();
+    }
+
+    // Propagate a page abort to this attempt
+    let on_page_abort = Closure::<dyn Fn()>::new({
+        let controller = controller.clone();
+        move || controller.abort()
+    });
+    if let Some(signal) = scope {
+        let _ = signal.add_event_listener_with_callback("abort", on_page_abort.as_ref().unchecked_ref());
+    }
+
+    let timed_out = Rc::new(Cell::new(false));
+    let on_timeout = Closure::<dyn Fn()>::new({
+        let controller = controller.clone();
+        let timed_out = timed_out.clone();
+        move || {
+            timed_out.set(true);
+            controller.abort();
+        }
+    });
+    let timer = if options.timeout_ms > 0 {
+        window
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                on_timeout.as_ref().unchecked_ref(),
+                options.timeout_ms.min(i32::MAX as u32) as i32,
+            )
+            .ok()
+    } else {
+        None
+    };
+
+    let result = JsFuture::from(window.fetch_with_request(&request)).await;
+
+    if let Some(handle) = timer {
+        window.clear_timeout_with_handle(handle);
+    }
+    if let Some(signal) = scope {
+        let _ = signal.remove_event_listener_with_callback("abort", on_page_abort.as_ref().unchecked_ref());
+    }
+
+    match result {
+        Ok(value) => value
+            .dyn_into::<Response>()
+            .map_err(|_| ApiError::network("Unexpected fetch result")),
+        Err(_) if timed_out.get() => Err(ApiError::Timeout),
+        Err(_) if controller.signal().aborted() => Err(ApiError::Cancelled),
+        Err(_) => Err(ApiError::network("Server unreachable")),
+    }
+}
 
+/// Resolve after `ms` milliseconds, or as soon as `scope` aborts, in which
+/// case the wait ends with [`ApiError::Cancelled`]
+async fn sleep(ms: u32, scope: Option<&AbortSignal>) -> Result<(), ApiError> {
+    if scope.map(|s| s.aborted()).unwrap_or(false) {
+        return Err(ApiError::Cancelled);
+    }
+    let window = web_sys::window().ok_or_else(|| ApiError::network("No browser window"))?;
+
+    let mut wake = None;
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| wake = Some(resolve));
+    let Some(wake) = wake else {
+        return Ok(());
+    };
+    let timer = window
+        .set_timeout_with_callback_and_timeout_and_arguments_0(&wake, ms.min(i32::MAX as u32) as i32)
+        .ok();
+    if let Some(signal) = scope {
+        let _ = signal.add_event_listener_with_callback("abort", &wake);
+    }
+
+    let _ = JsFuture::from(promise).await;
+
+    if let Some(handle) = timer {
+        window.clear_timeout_with_handle(handle);
+    }
+    if let Some(signal) = scope {
+        let _ = signal.remove_event_listener_with_callback("abort", &wake);
+        if signal.aborted() {
+            return Err(ApiError::Cancelled);
+        }
+    }
+    Ok(())
+}
+
+/// Check the response status and deserialize its JSON body.
+///
+/// A 204, or any success with an empty body, deserializes from `null`, so
+/// callers expecting `()` or an `Option` accept it.
+async fn parse_response<T: DeserializeOwned>(response: Response) -> Result<T, ApiError> {
     if !response.ok() {
-        return Err(ApiError {
-            status,
-            message: format!("Request failed with status {}", status),
-        });
+        return Err(read_problem(response).await);
     }
 
-    let json = JsFuture::from(response.json().unwrap())
-        .await
-        .map_err(|_| ApiError {
-            status,
-            message: "Failed to parse response".to_string(),
-        })?;
+    let text = if response.status() == 204 {
+        String::new()
+    } else {
+        let promise = response.text().map_err(|_| ApiError::network("Failed to read response"))?;
+        JsFuture::from(promise)
+            .await
+            .map_err(|_| ApiError::network("Failed to read response"))?
+            .as_string()
+            .unwrap_or_default()
+    };
+    let body = if text.trim().is_empty() { "null" } else { text.as_str() };
 
-    serde_wasm_bindgen::from_value(json).map_err(|e| ApiError {
-        status,
-        message: format!("JSON parse error: {}", e),
-    })
+    serde_json::from_str(body).map_err(|e| ApiError::network(format!("JSON parse error: {}", e)))
+}
+
+/// Build the error for a failed response, parsing a problem+json body if present
+pub(crate) async fn read_problem(response: Response) -> ApiError {
+    let status = response.status();
+    let fallback = || Problem::new(status, &format!("Request failed with status {}", status));
+
+    let text = match response.text() {
+        Ok(promise) => JsFuture::from(promise).await.ok().and_then(|t| t.as_string()),
+        Err(_) => None,
+    };
+    let problem = text
+        .and_then(|t| serde_json::from_str::<Problem>(&t).ok())
+        .filter(|p| p.title.is_some() || p.detail.is_some() || !p.errors.is_empty())
+        .unwrap_or_else(fallback);
+
+    ApiError::from_problem(problem, status)
 }
 
 /// GET request
@@ -113,10 +561,7 @@ pub async fn post<T: DeserializeOwned, B: Serialize>(
     endpoint: &str,
     body: &B,
 ) -> Result<T, ApiError> {
-    let json = serde_json::to_string(body).map_err(|e| ApiError {
-        status: 0,
-        message: format!("Serialization error: {}", e),
-    })?;
+    let json = serde_json::to_string(body).map_err(|e| ApiError::network(format!("Serialization error: {}", e)))?;
     request(HttpMethod::Post, endpoint, Some(json)).await
 }
 
@@ -125,10 +570,7 @@ pub async fn put<T: DeserializeOwned, B: Serialize>(
     endpoint: &str,
     body: &B,
 ) -> Result<T, ApiError> {
-    let json = serde_json::to_string(body).map_err(|e| ApiError {
-        status: 0,
-        message: format!("Serialization error: {}", e),
-    })?;
+    let json = serde_json::to_string(body).map_err(|e| ApiError::network(format!("Serialization error: {}", e)))?;
     request(HttpMethod::Put, endpoint, Some(json)).await
 }
 
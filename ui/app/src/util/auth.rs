@@ -1,6 +1,12 @@
 //! Authentication utilities
 
+use std::cell::RefCell;
 use components::prelude::*;
+use serde::{Deserialize, Serialize};
+use super::api::{self, ApiError, Problem};
+use super::offline;
+use super::oidc::{self, IdTokenSource};
+use super::query;
 
 /// User role enumeration
 #[derive(Clone, PartialEq, Debug)]
@@ -15,6 +21,41 @@ pub enum UserRole {
     SystemAdmin,
 }
 
+impl UserRole {
+    /// Map an ID token role claim (`procurement_manager`, `ProcurementManager`, ...)
+    pub fn from_claim(claim: &str) -> Option<Self> {
+        let normalized: String = claim
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        match normalized.as_str() {
+            "procurementofficer" => Some(UserRole::ProcurementOfficer),
+            "procurementmanager" => Some(UserRole::ProcurementManager),
+            "budgetholder" => Some(UserRole::BudgetHolder),
+            "approver" => Some(UserRole::Approver),
+            "contractmanager" => Some(UserRole::ContractManager),
+            "supplieradmin" => Some(UserRole::SupplierAdmin),
+            "auditviewer" => Some(UserRole::AuditViewer),
+            "systemadmin" => Some(UserRole::SystemAdmin),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            UserRole::ProcurementOfficer => "Procurement Officer",
+            UserRole::ProcurementManager => "Procurement Manager",
+            UserRole::BudgetHolder => "Budget Holder",
+            UserRole::Approver => "Approver",
+            UserRole::ContractManager => "Contract Manager",
+            UserRole::SupplierAdmin => "Supplier Admin",
+            UserRole::AuditViewer => "Audit Viewer",
+            UserRole::SystemAdmin => "System Admin",
+        }
+    }
+}
+
 /// User information
 #[derive(Clone, Debug)]
 pub struct User {
@@ -40,22 +81,248 @@ impl Default for User {
 }
 
 /// Authentication state
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct AuthState {
     pub authenticated: bool,
     pub user: Option<User>,
     pub token: Option<String>,
+    pub refresh_token: Option<String>,
+    pub id_token: Option<String>,
+    /// Reason the last login attempt failed, shown on the login screen
+    pub error: Option<String>,
 }
 
-impl Default for AuthState {
-    fn default() -> Self {
-        // Default to authenticated for demo purposes
+impl AuthState {
+    /// Demo session used when no identity provider is configured
+    pub fn demo() -> Self {
         Self {
             authenticated: true,
             user: Some(User::default()),
             token: Some("demo-token".to_string()),
+            refresh_token: Some("demo-refresh-token".to_string()),
+            ..Self::default()
         }
     }
+
+    /// Session to start the app with: the persisted OIDC session if there is
+    /// one, signed out if OIDC is configured, otherwise the demo session
+    pub fn initial() -> Self {
+        if oidc::is_enabled() {
+            restore_session().unwrap_or_default()
+        } else {
+            Self::demo()
+        }
+    }
+
+    /// Signed-in state from an IdP token response to the login that sent `nonce`
+    pub fn from_tokens(tokens: TokenResponse, nonce: &str) -> Result<Self, String> {
+        let id_token = tokens.id_token.ok_or("Identity provider returned no ID token")?;
+        let user = oidc::user_from_id_token(&id_token, IdTokenSource::Login(nonce))?;
+        Ok(Self {
+            authenticated: true,
+            user: Some(user),
+            token: Some(tokens.access_token),
+            refresh_token: tokens.refresh_token,
+            id_token: Some(id_token),
+            error: None,
+        })
+    }
+}
+
+/// Token set returned by the auth endpoints
+#[derive(Clone, Debug, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+    #[serde(default)]
+    pub id_token: Option<String>,
+}
+
+/// sessionStorage key for the persisted OIDC session
+const SESSION_KEY: &str = "eprocurement.session";
+
+/// Tokens persisted across page reloads
+#[derive(Serialize, Deserialize)]
+struct StoredSession {
+    access_token: String,
+    refresh_token: Option<String>,
+    id_token: String,
+}
+
+thread_local! {
+    /// Auth state shared with the API client, which runs outside the component tree
+    static AUTH: RefCell<Option<Signal<AuthState>>> = RefCell::new(None);
+}
+
+/// Register the application's auth state for use by the API client.
+///
+/// A session restored after its ID token lapsed is renewed straight away.
+pub fn install(auth_state: Signal<AuthState>) {
+    let lapsed = auth_state.get().id_token.as_deref().is_some_and(oidc::id_token_expired);
+    AUTH.with(|a| *a.borrow_mut() = Some(auth_state));
+    if lapsed {
+        spawn(async {
+            let _ = refresh_session().await;
+        });
+    }
+}
+
+fn auth_signal() -> Option<Signal<AuthState>> {
+    AUTH.with(|a| a.borrow().clone())
+}
+
+/// Snapshot of the current auth state, for code outside the component tree
+pub fn current() -> AuthState {
+    auth_signal().map(|s| s.get()).unwrap_or_default()
+}
+
+/// Access token of the signed-in user
+pub fn access_token() -> Option<String> {
+    auth_signal().and_then(|s| {
+        let state = s.get();
+        if state.authenticated { state.token } else { None }
+    })
+}
+
+/// Exchange the refresh token for a new access token.
+///
+/// A new ID token in the response replaces the signed-in user's details; it
+/// must be for the same subject. On failure the session is cleared so
+/// `auth_provider` shows the login screen.
+pub async fn refresh_session() -> Result<String, ApiError> {
+    let auth_state = auth_signal()
+        .ok_or_else(|| ApiError::Unauthorized(Problem::new(401, "Not signed in")))?;
+
+    let refresh_token = match auth_state.get().refresh_token {
+        Some(t) => t,
+        None => {
+            sign_out();
+            return Err(ApiError::Unauthorized(Problem::new(401, "No refresh token")));
+        }
+    };
+
+    let result = if oidc::is_enabled() {
+        oidc::refresh(&refresh_token).await
+    } else {
+        api::refresh_tokens(&refresh_token).await
+    };
+
+    match result {
+        Ok(tokens) => {
+            let mut state = auth_state.get();
+            state.token = Some(tokens.access_token.clone());
+            if tokens.refresh_token.is_some() {
+                state.refresh_token = tokens.refresh_token;
+            }
+            if let Some(id_token) = tokens.id_token {
+                if oidc::is_enabled() {
+                    let previous = state.user.as_ref().map(|u| u.id.clone());
+                    let user = oidc::user_from_id_token(&id_token, IdTokenSource::Refresh)
+                        .and_then(|user| match previous {
+                            Some(id) if id != user.id => Err("ID token is for another user".to_string()),
+                            _ => Ok(user),
+                        });
+                    match user {
+                        Ok(user) => state.user = Some(user),
+                        Err(e) => {
+                            sign_out();
+                            return Err(ApiError::Unauthorized(Problem::new(401, &e)));
+                        }
+                    }
+                }
+                state.id_token = Some(id_token);
+            }
+            persist_session(&state);
+            auth_state.set(state);
+            Ok(tokens.access_token)
+        }
+        // A flaky link should not end the session; only a rejected token does
+        Err(e) if e.is_transient() || matches!(e, ApiError::Cancelled) => Err(e),
+        Err(e) => {
+            sign_out();
+            Err(e)
+        }
+    }
+}
+
+/// Clear the session and cached data and mark the user as signed out.
+///
+/// Queued offline changes are kept, but only replay for the user who made
+/// them.
+pub fn sign_out() {
+    clear_session();
+    query::clear();
+    spawn(offline::clear_snapshots());
+    if let Some(auth_state) = auth_signal() {
+        auth_state.set(AuthState::default());
+    }
+}
+
+/// Sign out and end the IdP session
+pub async fn logout() {
+    let id_token = auth_signal().and_then(|s| s.get().id_token);
+    sign_out();
+    if oidc::is_enabled() {
+        oidc::end_session(id_token).await;
+    }
+}
+
+/// Complete the IdP login redirect, returning the resulting session
+pub async fn complete_login() -> AuthState {
+    let login = oidc::complete_login().await;
+    match login.and_then(|(tokens, nonce)| AuthState::from_tokens(tokens, &nonce)) {
+        Ok(state) => {
+            persist_session(&state);
+            state
+        }
+        Err(e) => {
+            clear_session();
+            AuthState {
+                error: Some(e),
+                ..AuthState::default()
+            }
+        }
+    }
+}
+
+/// Save the OIDC session so it survives a page reload
+pub fn persist_session(state: &AuthState) {
+    let (Some(access_token), Some(id_token)) = (state.token.clone(), state.id_token.clone()) else {
+        return;
+    };
+    let stored = StoredSession {
+        access_token,
+        refresh_token: state.refresh_token.clone(),
+        id_token,
+    };
+    if let (Some(storage), Ok(json)) = (session_storage(), serde_json::to_string(&stored)) {
+        let _ = storage.set_item(SESSION_KEY, &json);
+    }
+}
+
+fn restore_session() -> Option<AuthState> {
+    let json = session_storage()?.get_item(SESSION_KEY).ok()??;
+    let stored: StoredSession = serde_json::from_str(&json).ok()?;
+    let user = oidc::user_from_id_token(&stored.id_token, IdTokenSource::Stored).ok()?;
+    Some(AuthState {
+        authenticated: true,
+        user: Some(user),
+        token: Some(stored.access_token),
+        refresh_token: stored.refresh_token,
+        id_token: Some(stored.id_token),
+        error: None,
+    })
+}
+
+fn clear_session() {
+    if let Some(storage) = session_storage() {
+        let _ = storage.remove_item(SESSION_KEY);
+    }
+}
+
+fn session_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.session_storage().ok()?
 }
 
 /// Check if user has a specific role
@@ -74,11 +341,58 @@ pub fn has_any_role(auth: &AuthState, roles: &[UserRole]) -> bool {
 /// Authentication provider component
 #[component]
 pub fn auth_provider(auth_state: Signal<AuthState>, children: Vec<View>) -> View {
-    if !auth_state.get().authenticated {
+    let state = auth_state.get();
+
+    if !state.authenticated {
+        if oidc::is_callback() {
+            return view! {
+                <div class="auth-required" data-testid="auth-pending">
+                    <h2>"Signing in..."</h2>
+                </div>
+            };
+        }
+
+        let handle_login = Callback::<()>::new({
+            let auth_state = auth_state.clone();
+            move |_| {
+                let auth_state = auth_state.clone();
+                spawn(async move {
+                    if let Err(e) = oidc::begin_login().await {
+                        auth_state.set(AuthState {
+                            error: Some(e),
+                            ..AuthState::default()
+                        });
+                    }
+                });
+            }
+        });
+
+        // Without an identity provider, signing out returns to this screen
+        // and the demo session is the only way back in
+        let handle_demo_login = Callback::<()>::new({
+            let auth_state = auth_state.clone();
+            move |_| auth_state.set(AuthState::demo())
+        });
+
+        let error = state.error.clone().unwrap_or_default();
+        let login_enabled = oidc::is_enabled();
+
         return view! {
-            <div class="auth-required">
+            <div class="auth-required" data-testid="auth-required">
                 <h2>"Authentication Required"</h2>
                 <p>"Please log in to access the eProcurement system."</p>
+                if !error.is_empty() {
+                    <p class="auth-error" data-testid="auth-error">{error.clone()}</p>
+                }
+                if login_enabled {
+                    <button class="btn btn-primary" data-testid="login-button" on:click={handle_login}>
+                        "Sign in"
+                    </button>
+                } else {
+                    <button class="btn btn-primary" data-testid="demo-login-button" on:click={handle_demo_login}>
+                        "Sign in to the demo"
+                    </button>
+                }
             </div>
         };
     }
@@ -0,0 +1,309 @@
+//! Data backend selection
+//!
+//! Every feature service asks this module whether to serve its store from the
+//! bundled mock fixtures or from the REST API. The backend is chosen once at
+//! startup and can be overridden per browser for testing against a stub server.
+
+use std::cell::RefCell;
+use components::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use super::api::{self, ApiError, HttpMethod};
+use super::offline::{self, Provisional};
+use super::query;
+use super::reference::{self, DocumentType};
+
+/// Default REST API base path (see `[deploy] api_base` in rsc.toml)
+pub const DEFAULT_API_BASE: &str = "/eprocurement/api/v1";
+
+/// Query string parameter used to select the backend, e.g. `?backend=http`
+const QUERY_PARAM: &str = "backend";
+
+/// localStorage key that remembers a backend chosen via the query string
+const STORAGE_KEY: &str = "eprocurement.backend";
+
+/// `<meta name="...">` tag that sets the deployment default
+const META_NAME: &str = "eprocurement-backend";
+
+/// Data backend used by feature services
+#[derive(Clone, Debug, PartialEq)]
+pub enum Backend {
+    /// In-memory mock fixtures, no network traffic
+    Mock,
+    /// REST API rooted at `base_url`
+    Http { base_url: String },
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Backend::Mock
+    }
+}
+
+impl Backend {
+    /// Parse a backend spec: `mock`, `http` (default base) or an explicit base URL
+    pub fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        match spec {
+            "mock" => Some(Backend::Mock),
+            "http" | "api" => Some(Backend::Http { base_url: DEFAULT_API_BASE.to_string() }),
+            _ if spec.starts_with('/')
+                || spec.starts_with("http://")
+                || spec.starts_with("https://") =>
+            {
+                Some(Backend::Http { base_url: spec.trim_end_matches('/').to_string() })
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_spec(&self) -> String {
+        match self {
+            Backend::Mock => "mock".to_string(),
+            Backend::Http { base_url } => base_url.clone(),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Backend::Mock => "Mock data",
+            Backend::Http { .. } => "REST API",
+        }
+    }
+}
+
+thread_local! {
+    static BACKEND: RefCell<Backend> = RefCell::new(Backend::default());
+}
+
+/// Select the backend at startup.
+///
+/// Precedence: `?backend=` query parameter (remembered in localStorage),
+/// then a previously remembered choice, then the `eprocurement-backend`
+/// meta tag, then mock data.
+pub fn init() {
+    let backend = from_query()
+        .map(|b| {
+            remember(&b);
+            b
+        })
+        .or_else(from_storage)
+        .or_else(from_meta)
+        .unwrap_or_default();
+    set_backend(backend);
+}
+
+/// Override the active backend
+pub fn set_backend(backend: Backend) {
+    BACKEND.with(|b| *b.borrow_mut() = backend);
+}
+
+/// Currently active backend
+pub fn current() -> Backend {
+    BACKEND.with(|b| b.borrow().clone())
+}
+
+/// Whether services should serve mock fixtures
+pub fn is_mock() -> bool {
+    current() == Backend::Mock
+}
+
+/// Base URL for REST calls
+pub fn base_url() -> String {
+    match current() {
+        Backend::Http { base_url } => base_url,
+        Backend::Mock => DEFAULT_API_BASE.to_string(),
+    }
+}
+
+/// GET `endpoint` into `target` through the shared query cache
+pub async fn fetch_into<T>(endpoint: &str, target: &Signal<T>) -> Result<(), ApiError>
+where
+    T: DeserializeOwned + Clone + 'static,
+{
+    let owned = endpoint.to_string();
+    query::fetch_into(endpoint, target, query::DEFAULT_STALE_MS, move || {
+        let endpoint = owned.clone();
+        async move { api::get::<T>(&endpoint).await }
+    })
+    .await
+}
+
+/// Like [`fetch_into`], but keeps an offline snapshot of the response.
+///
+/// When the API cannot be reached, `target` is filled from the last
+/// snapshot instead and the call succeeds.
+pub async fn fetch_with_snapshot<T>(endpoint: &str, target: &Signal<T>) -> Result<(), ApiError>
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+{
+    match fetch_into(endpoint, target).await {
+        Ok(()) => {
+            offline::save_snapshot(endpoint, &target.get()).await;
+            Ok(())
+        }
+        Err(e @ (ApiError::Network(_) | ApiError::Timeout)) => {
+            match offline::load_snapshot::<T>(endpoint).await {
+                Some(snapshot) => {
+                    target.set(snapshot.data);
+                    Ok(())
+                }
+                None => Err(e),
+            }
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Send a mutation to the REST API and return the server's copy.
+///
+/// The mock backend echoes `entity` back so callers can apply the result
+/// to their store the same way under either backend.
+pub async fn save<T>(method: HttpMethod, endpoint: &str, entity: &T) -> Result<T, ApiError>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    match current() {
+        Backend::Mock => Ok(entity.clone()),
+        Backend::Http { .. } => {
+            let body = serde_json::to_string(entity)
+                .map_err(|e| ApiError::network(format!("Serialization error: {}", e)))?;
+            let saved = api::request(method, endpoint, Some(body)).await?;
+            query::invalidate_resource(endpoint);
+            Ok(saved)
+        }
+    }
+}
+
+/// Like [`save`], but queues the mutation in the offline outbox when the
+/// browser has no connection or the API cannot be reached, and returns
+/// `entity` as saved.
+///
+/// `label` describes the change if it later conflicts, and `base_version`
+/// is the `updated_at` of the record before it was edited.
+pub async fn save_or_queue<T>(
+    method: HttpMethod,
+    endpoint: &str,
+    entity: &T,
+    label: &str,
+    base_version: Option<String>,
+) -> Result<T, ApiError>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    if is_mock() {
+        return save(method, endpoint, entity).await;
+    }
+    if offline::is_online() {
+        match save(method, endpoint, entity).await {
+            Err(ApiError::Network(_) | ApiError::Timeout) => {}
+            result => return result,
+        }
+    }
+    queue(method, endpoint, entity, label, base_version, None).await
+}
+
+/// POST a new document numbered with a provisional `doc_type` number in
+/// its `field`, queueing it like [`save_or_queue`] when the API cannot be
+/// reached.
+///
+/// The server's number replaces the provisional one, in the register and,
+/// when the create was queued, in the changes queued after it.
+pub async fn create_or_queue<T>(
+    endpoint: &str,
+    entity: &T,
+    label: &str,
+    doc_type: DocumentType,
+    field: &str,
+) -> Result<T, ApiError>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    let number = number_in(entity, field).unwrap_or_default();
+    if is_mock() {
+        return save(HttpMethod::Post, endpoint, entity).await;
+    }
+    if offline::is_online() {
+        match save(HttpMethod::Post, endpoint, entity).await {
+            Err(ApiError::Network(_) | ApiError::Timeout) => {}
+            Ok(saved) => {
+                if let Some(confirmed) = number_in(&saved, field) {
+                    reference::reconcile(doc_type, &number, &confirmed);
+                }
+                return Ok(saved);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    let provisional = Provisional {
+        doc_type: doc_type.key().to_string(),
+        number,
+        field: field.to_string(),
+    };
+    queue(HttpMethod::Post, endpoint, entity, label, None, Some(provisional)).await
+}
+
+fn number_in<T: Serialize>(entity: &T, field: &str) -> Option<String> {
+    serde_json::to_value(entity).ok()?.get(field)?.as_str().map(str::to_string)
+}
+
+async fn queue<T>(
+    method: HttpMethod,
+    endpoint: &str,
+    entity: &T,
+    label: &str,
+    base_version: Option<String>,
+    provisional: Option<Provisional>,
+) -> Result<T, ApiError>
+where
+    T: Serialize + Clone,
+{
+    let body = serde_json::to_string(entity)
+        .map_err(|e| ApiError::network(format!("Serialization error: {}", e)))?;
+    offline::enqueue(method, endpoint, body, label, base_version, provisional).await?;
+    Ok(entity.clone())
+}
+
+/// DELETE `endpoint` on the REST API; a no-op for the mock backend
+pub async fn remove(endpoint: &str) -> Result<(), ApiError> {
+    match current() {
+        Backend::Mock => Ok(()),
+        Backend::Http { .. } => {
+            api::delete::<()>(endpoint).await?;
+            query::invalidate_resource(endpoint);
+            Ok(())
+        }
+    }
+}
+
+fn from_query() -> Option<Backend> {
+    let search = web_sys::window()?.location().search().ok()?;
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == QUERY_PARAM)
+        .and_then(|(_, value)| {
+            let decoded = js_sys::decode_uri_component(value).ok()?;
+            Backend::parse(&String::from(decoded))
+        })
+}
+
+fn from_storage() -> Option<Backend> {
+    let storage = web_sys::window()?.local_storage().ok()??;
+    let spec = storage.get_item(STORAGE_KEY).ok()??;
+    Backend::parse(&spec)
+}
+
+fn from_meta() -> Option<Backend> {
+    let document = web_sys::window()?.document()?;
+    let meta = document
+        .query_selector(&format!("meta[name=\"{}\"]", META_NAME))
+        .ok()??;
+    Backend::parse(&meta.get_attribute("content")?)
+}
+
+fn remember(backend: &Backend) {
+    if let Some(Ok(Some(storage))) = web_sys::window().map(|w| w.local_storage()) {
+        let _ = storage.set_item(STORAGE_KEY, &backend.as_spec());
+    }
+}
@@ -0,0 +1,660 @@
+//! Dates, times and the South African business-day calendar
+//!
+//! Timestamps travel as ISO 8601 strings. This module parses them into UTC
+//! instants, shows them in South African Standard Time (UTC+2, no daylight
+//! saving) and counts working days around the public holidays of the Public
+//! Holidays Act. Date-only strings and timestamps without an offset are read
+//! as SAST, since that is what users and the fixtures mean by them.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use super::backend;
+use super::format::format_date;
+use super::i18n::{t, tf, tn};
+
+/// SAST is UTC+2 all year
+pub const SAST_OFFSET_SECS: i64 = 2 * 3600;
+
+const SECS_PER_DAY: i64 = 86_400;
+
+/// Instant the mock fixtures were written against
+const MOCK_EPOCH: &str = "2025-02-27T10:00:00Z";
+
+/// Relative times further away than this show the date instead
+const RELATIVE_DAYS_LIMIT: i64 = 30;
+
+/// Day of the week
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// Calendar date with no time zone
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(Self { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Parse the `YYYY-MM-DD` prefix of an ISO date or timestamp
+    pub fn parse(iso: &str) -> Option<Self> {
+        let date = iso.get(..10)?;
+        let mut parts = date.split('-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        Self::new(year, month, day)
+    }
+
+    /// `YYYY-MM-DD`
+    pub fn to_iso(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_day_number(self.day_number() + days)
+    }
+
+    /// Calendar days from `self` to `other`; negative when `other` is earlier
+    pub fn days_until(&self, other: Date) -> i64 {
+        other.day_number() - self.day_number()
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        match (self.day_number() + 3).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    pub fn is_weekend(&self) -> bool {
+        matches!(self.weekday(), Weekday::Saturday | Weekday::Sunday)
+    }
+
+    /// Days since 1970-01-01 (Howard Hinnant's `days_from_civil`)
+    fn day_number(&self) -> i64 {
+        let year = (if self.month <= 2 { self.year - 1 } else { self.year }) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_day_number(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+}
+
+/// An instant, stored as milliseconds since the Unix epoch
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    millis: i64,
+}
+
+impl Timestamp {
+    pub fn from_millis(millis: i64) -> Self {
+        Self { millis }
+    }
+
+    pub fn millis(&self) -> i64 {
+        self.millis
+    }
+
+    /// Midnight SAST at the start of `date`
+    pub fn start_of_day(date: Date) -> Self {
+        Self::at_sast(date, 0, 0, 0)
+    }
+
+    /// Wall-clock time in South Africa on `date`
+    pub fn at_sast(date: Date, hour: u32, minute: u32, second: u32) -> Self {
+        let secs = date.day_number() * SECS_PER_DAY
+            + (hour as i64) * 3600
+            + (minute as i64) * 60
+            + second as i64
+            - SAST_OFFSET_SECS;
+        Self { millis: secs * 1000 }
+    }
+
+    /// Parse an ISO 8601 date or timestamp.
+    ///
+    /// Accepts `YYYY-MM-DD`, `YYYY-MM-DDTHH:MM[:SS[.fff]]` and a trailing
+    /// `Z` or `±HH:MM` offset. Anything without an offset is taken as SAST.
+    pub fn parse(iso: &str) -> Option<Self> {
+        let iso = iso.trim();
+        let date = Date::parse(iso)?;
+        let rest = &iso[10..];
+        if rest.is_empty() {
+            return Some(Self::start_of_day(date));
+        }
+
+        let rest = rest.strip_prefix('T').or_else(|| rest.strip_prefix(' '))?;
+        let offset_at = rest.find(|c| c == 'Z' || c == '+' || c == '-').unwrap_or(rest.len());
+        let (time, zone) = rest.split_at(offset_at);
+
+        let mut fields = time.split(':');
+        let hour: u32 = fields.next()?.parse().ok()?;
+        let minute: u32 = fields.next()?.parse().ok()?;
+        let (second, millis) = match fields.next() {
+            Some(sec) => {
+                let (whole, fraction) = sec.split_once('.').unwrap_or((sec, ""));
+                let fraction = format!("{:0<3}", fraction.get(..3).unwrap_or(fraction));
+                (whole.parse::<u32>().ok()?, fraction.parse::<i64>().ok()?)
+            }
+            None => (0, 0),
+        };
+        if hour > 23 || minute > 59 || second > 60 {
+            return None;
+        }
+
+        let offset_secs = match zone {
+            "" => SAST_OFFSET_SECS,
+            "Z" => 0,
+            _ => {
+                let sign = if zone.starts_with('-') { -1 } else { 1 };
+                let digits: String = zone[1..].chars().filter(|c| c.is_ascii_digit()).collect();
+                if digits.len() != 4 {
+                    return None;
+                }
+                let hours: i64 = digits[..2].parse().ok()?;
+                let minutes: i64 = digits[2..].parse().ok()?;
+                sign * (hours * 3600 + minutes * 60)
+            }
+        };
+
+        let local_secs = date.day_number() * SECS_PER_DAY
+            + (hour as i64) * 3600
+            + (minute as i64) * 60
+            + second as i64;
+        Some(Self { millis: (local_secs - offset_secs) * 1000 + millis })
+    }
+
+    /// UTC timestamp, `YYYY-MM-DDTHH:MM:SSZ`
+    pub fn to_iso(&self) -> String {
+        let secs = self.millis.div_euclid(1000);
+        let date = Date::from_day_number(secs.div_euclid(SECS_PER_DAY));
+        let of_day = secs.rem_euclid(SECS_PER_DAY);
+        format!(
+            "{}T{:02}:{:02}:{:02}Z",
+            date.to_iso(),
+            of_day / 3600,
+            (of_day % 3600) / 60,
+            of_day % 60
+        )
+    }
+
+    /// Calendar date in South Africa
+    pub fn sast_date(&self) -> Date {
+        Date::from_day_number(self.sast_secs().div_euclid(SECS_PER_DAY))
+    }
+
+    /// Wall-clock hour, minute and second in South Africa
+    pub fn sast_time(&self) -> (u32, u32, u32) {
+        let of_day = self.sast_secs().rem_euclid(SECS_PER_DAY);
+        ((of_day / 3600) as u32, ((of_day % 3600) / 60) as u32, (of_day % 60) as u32)
+    }
+
+    pub fn add_seconds(&self, seconds: i64) -> Self {
+        Self { millis: self.millis + seconds * 1000 }
+    }
+
+    /// Whole seconds from `self` to `other`; negative when `other` is earlier
+    pub fn seconds_until(&self, other: Timestamp) -> i64 {
+        (other.millis - self.millis).div_euclid(1000)
+    }
+
+    fn sast_secs(&self) -> i64 {
+        self.millis.div_euclid(1000) + SAST_OFFSET_SECS
+    }
+}
+
+/// One year's public holidays, in date order
+type Holidays = Rc<Vec<(Date, &'static str)>>;
+
+thread_local! {
+    /// Shift applied to the browser clock by the demo clock
+    static MOCK_SHIFT: Cell<Option<i64>> = Cell::new(None);
+    /// Public holidays per year, worked out once
+    static HOLIDAYS: RefCell<HashMap<i32, Holidays>> = RefCell::new(HashMap::new());
+}
+
+/// The current instant.
+///
+/// A dev build on the mock backend runs a demo clock: it starts at the
+/// fixtures' reference time when first read and runs forward from there, so
+/// deadlines and countdowns in the mock data stay meaningful. Release builds
+/// always use the real clock, whatever backend they are pointed at.
+pub fn now() -> Timestamp {
+    let real = js_sys::Date::now() as i64;
+    if !cfg!(debug_assertions) || !backend::is_mock() {
+        return Timestamp::from_millis(real);
+    }
+    let shift = MOCK_SHIFT.with(|shift| match shift.get() {
+        Some(s) => s,
+        None => {
+            let epoch = Timestamp::parse(MOCK_EPOCH).map(|t| t.millis()).unwrap_or(real);
+            shift.set(Some(epoch - real));
+            epoch - real
+        }
+    });
+    Timestamp::from_millis(real + shift)
+}
+
+/// The current instant as a UTC ISO timestamp, for `created_at` and friends
+pub fn now_iso() -> String {
+    now().to_iso()
+}
+
+/// Today's date in South Africa
+pub fn today() -> Date {
+    now().sast_date()
+}
+
+/// Calendar days from today until `iso`; `None` if it does not parse
+pub fn days_until(iso: &str) -> Option<i64> {
+    Date::parse(iso).map(|date| today().days_until(date))
+}
+
+/// Whether the date or timestamp `iso` is in the past
+pub fn is_past(iso: &str) -> bool {
+    Timestamp::parse(iso).map_or(false, |ts| ts < now())
+}
+
+/// Easter Sunday for `year` (anonymous Gregorian algorithm)
+pub fn easter_sunday(year: i32) -> Date {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    Date { year, month: month as u32, day: day as u32 }
+}
+
+/// South African public holidays in `year`, in date order.
+///
+/// Under the Public Holidays Act a holiday that falls on a Sunday is also
+/// observed on the next day that is not already a holiday.
+pub fn public_holidays(year: i32) -> Vec<(Date, &'static str)> {
+    holidays(year).as_ref().clone()
+}
+
+/// [`public_holidays`], computed once per year; business-day counts look
+/// them up for every day they step over
+fn holidays(year: i32) -> Holidays {
+    if let Some(cached) = HOLIDAYS.with(|h| h.borrow().get(&year).cloned()) {
+        return cached;
+    }
+    let computed = Rc::new(compute_holidays(year));
+    HOLIDAYS.with(|h| h.borrow_mut().insert(year, computed.clone()));
+    computed
+}
+
+fn compute_holidays(year: i32) -> Vec<(Date, &'static str)> {
+    const FIXED: [(u32, u32, &str); 10] = [
+        (1, 1, "New Year's Day"),
+        (3, 21, "Human Rights Day"),
+        (4, 27, "Freedom Day"),
+        (5, 1, "Workers' Day"),
+        (6, 16, "Youth Day"),
+        (8, 9, "National Women's Day"),
+        (9, 24, "Heritage Day"),
+        (12, 16, "Day of Reconciliation"),
+        (12, 25, "Christmas Day"),
+        (12, 26, "Day of Goodwill"),
+    ];
+
+    let easter = easter_sunday(year);
+    let mut holidays: Vec<(Date, &'static str)> = FIXED
+        .iter()
+        .map(|&(month, day, name)| (Date { year, month, day }, name))
+        .collect();
+    holidays.push((easter.add_days(-2), "Good Friday"));
+    holidays.push((easter.add_days(1), "Family Day"));
+
+    // Christmas on a Sunday pushes the observed day past the Day of Goodwill
+    let sundays: Vec<(Date, &'static str)> = holidays
+        .iter()
+        .filter(|(date, _)| date.weekday() == Weekday::Sunday)
+        .copied()
+        .collect();
+    for (date, name) in sundays {
+        let mut observed = date.add_days(1);
+        while holidays.iter().any(|(holiday, _)| *holiday == observed) {
+            observed = observed.add_days(1);
+        }
+        holidays.push((observed, name));
+    }
+    holidays.sort_by_key(|(date, _)| *date);
+    holidays
+}
+
+/// Name of the public holiday on `date`, if it is one
+pub fn holiday_name(date: Date) -> Option<&'static str> {
+    holidays(date.year)
+        .iter()
+        .find(|(holiday, _)| *holiday == date)
+        .map(|(_, name)| *name)
+}
+
+/// Weekdays that are not public holidays
+pub fn is_business_day(date: Date) -> bool {
+    !date.is_weekend() && holiday_name(date).is_none()
+}
+
+/// `date` itself if it is a business day, otherwise the next one
+pub fn next_business_day(date: Date) -> Date {
+    let mut date = date;
+    while !is_business_day(date) {
+        date = date.add_days(1);
+    }
+    date
+}
+
+/// The `days`th business day after `date`
+pub fn add_business_days(date: Date, days: u32) -> Date {
+    let mut date = date;
+    let mut remaining = days;
+    while remaining > 0 {
+        date = date.add_days(1);
+        if is_business_day(date) {
+            remaining -= 1;
+        }
+    }
+    date
+}
+
+/// Business days after `from` up to and including `to`; negative when
+/// `to` is earlier, counting the business days it is overdue by
+pub fn business_days_between(from: Date, to: Date) -> i64 {
+    let (start, end, sign) = if to >= from { (from, to, 1) } else { (to, from, -1) };
+    let mut count = 0;
+    let mut date = start;
+    while date < end {
+        date = date.add_days(1);
+        if is_business_day(date) {
+            count += 1;
+        }
+    }
+    sign * count
+}
+
+/// Business days from today until the date `iso`; `None` if it does not parse
+pub fn business_days_until(iso: &str) -> Option<i64> {
+    Date::parse(iso).map(|date| business_days_between(today(), date))
+}
+
+/// Due date of an SLA of `business_days` that starts at `started_at`
+pub fn sla_deadline(started_at: &str, business_days: u32) -> Option<Date> {
+    Timestamp::parse(started_at).map(|ts| add_business_days(ts.sast_date(), business_days))
+}
+
+/// Month the public-sector financial year starts in (PFMA: 1 April)
+pub const FISCAL_YEAR_START_MONTH: u32 = 4;
+
+/// Financial year running from 1 April to 31 March
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FiscalYear {
+    /// Calendar year the financial year starts in; 2024 for 2024/25
+    pub start_year: i32,
+}
+
+impl FiscalYear {
+    /// Financial year that `date` falls in
+    pub fn containing(date: Date) -> Self {
+        let start_year = if date.month >= FISCAL_YEAR_START_MONTH { date.year } else { date.year - 1 };
+        Self { start_year }
+    }
+
+    /// Financial year today falls in
+    pub fn current() -> Self {
+        Self::containing(today())
+    }
+
+    /// Calendar year the financial year ends in; 2025 for 2024/25
+    pub fn end_year(&self) -> i32 {
+        self.start_year + 1
+    }
+
+    pub fn start(&self) -> Date {
+        Date { year: self.start_year, month: FISCAL_YEAR_START_MONTH, day: 1 }
+    }
+
+    pub fn end(&self) -> Date {
+        Self { start_year: self.end_year() }.start().add_days(-1)
+    }
+
+    pub fn contains(&self, date: Date) -> bool {
+        Self::containing(date) == *self
+    }
+
+    pub fn previous(&self) -> Self {
+        Self { start_year: self.start_year - 1 }
+    }
+
+    pub fn next(&self) -> Self {
+        Self { start_year: self.end_year() }
+    }
+
+    /// "2024/25"
+    pub fn label(&self) -> String {
+        format!("{}/{:02}", self.start_year, self.end_year().rem_euclid(100))
+    }
+}
+
+/// "5 minutes ago", "in 3 days", "Yesterday"; dates further away than a
+/// month are shown as dates
+pub fn relative_time(then: Timestamp, now: Timestamp) -> String {
+    let seconds = now.seconds_until(then);
+    let abs = seconds.abs();
+    if abs < 60 {
+        return t("Just now").to_string();
+    }
+
+    let span = if abs < 3600 {
+        tn("{n} minute", "{n} minutes", abs / 60)
+    } else if abs < SECS_PER_DAY {
+        tn("{n} hour", "{n} hours", abs / 3600)
+    } else {
+        match now.sast_date().days_until(then.sast_date()) {
+            -1 => return t("Yesterday").to_string(),
+            1 => return t("Tomorrow").to_string(),
+            days if days.abs() > RELATIVE_DAYS_LIMIT => return format_date(&then.sast_date().to_iso()),
+            days => tn("{n} day", "{n} days", days.abs()),
+        }
+    };
+
+    if seconds < 0 {
+        tf("{time} ago", &[("time", span)])
+    } else {
+        tf("in {time}", &[("time", span)])
+    }
+}
+
+/// Like [`relative_time`] for a calendar date, relative to today
+pub fn relative_date(date: Date, today: Date) -> String {
+    match today.days_until(date) {
+        0 => t("Today").to_string(),
+        -1 => t("Yesterday").to_string(),
+        1 => t("Tomorrow").to_string(),
+        days if days.abs() > RELATIVE_DAYS_LIMIT => format_date(&date.to_iso()),
+        days if days < 0 => tf("{time} ago", &[("time", tn("{n} day", "{n} days", -days))]),
+        days => tf("in {time}", &[("time", tn("{n} day", "{n} days", days))]),
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(iso: &str) -> Date {
+        Date::parse(iso).unwrap()
+    }
+
+    #[test]
+    fn easter_sunday_matches_published_dates() {
+        let cases = [(2019, "2019-04-21"), (2022, "2022-04-17"), (2024, "2024-03-31"), (2025, "2025-04-20"), (2026, "2026-04-05"), (2038, "2038-04-25")];
+        for (year, expected) in cases {
+            assert_eq!(easter_sunday(year), date(expected), "{}", year);
+        }
+    }
+
+    #[test]
+    fn holidays_include_easter_and_sunday_observances() {
+        let cases = [
+            ("2025-01-01", Some("New Year's Day")),
+            ("2025-03-21", Some("Human Rights Day")),
+            ("2025-04-18", Some("Good Friday")),
+            ("2025-04-21", Some("Family Day")),
+            // Freedom Day 2025 is a Sunday
+            ("2025-04-27", Some("Freedom Day")),
+            ("2025-04-28", Some("Freedom Day")),
+            // Saturdays are not moved
+            ("2025-08-09", Some("National Women's Day")),
+            ("2025-08-11", None),
+            ("2025-12-25", Some("Christmas Day")),
+            ("2025-12-26", Some("Day of Goodwill")),
+            ("2024-03-29", Some("Good Friday")),
+            ("2024-04-01", Some("Family Day")),
+            ("2022-05-02", Some("Workers' Day")),
+            // Christmas 2022 is a Sunday, and the Monday is already a holiday
+            ("2022-12-26", Some("Day of Goodwill")),
+            ("2022-12-27", Some("Christmas Day")),
+            ("2026-08-10", Some("National Women's Day")),
+            ("2025-02-27", None),
+            ("2025-06-17", None),
+        ];
+        for (day, expected) in cases {
+            assert_eq!(holiday_name(date(day)), expected, "{}", day);
+        }
+    }
+
+    #[test]
+    fn holiday_counts_per_year() {
+        // Twelve statutory holidays plus one per Sunday that moved
+        for (year, count) in [(2022, 14), (2024, 13), (2025, 13), (2026, 13)] {
+            let holidays = public_holidays(year);
+            assert_eq!(holidays.len(), count, "{}", year);
+            assert!(holidays.windows(2).all(|w| w[0].0 < w[1].0), "{} is not in date order", year);
+        }
+    }
+
+    #[test]
+    fn cached_holidays_match_a_fresh_computation() {
+        for year in 2020..=2030 {
+            assert_eq!(public_holidays(year), compute_holidays(year));
+            assert_eq!(public_holidays(year), compute_holidays(year));
+        }
+    }
+
+    #[test]
+    fn business_day_checks() {
+        let cases = [
+            ("2025-02-27", true),
+            ("2025-03-01", false),
+            ("2025-03-02", false),
+            ("2025-03-21", false),
+            ("2025-04-28", false),
+            ("2025-04-29", true),
+        ];
+        for (day, expected) in cases {
+            assert_eq!(is_business_day(date(day)), expected, "{}", day);
+        }
+
+        let next = [("2025-04-18", "2025-04-22"), ("2025-04-26", "2025-04-29"), ("2025-02-27", "2025-02-27")];
+        for (day, expected) in next {
+            assert_eq!(next_business_day(date(day)), date(expected), "{}", day);
+        }
+    }
+
+    #[test]
+    fn adding_business_days_skips_weekends_and_holidays() {
+        let cases = [
+            ("2025-02-27", 0, "2025-02-27"),
+            ("2025-02-27", 1, "2025-02-28"),
+            ("2025-02-28", 1, "2025-03-03"),
+            ("2025-04-17", 1, "2025-04-22"),
+            ("2025-04-25", 1, "2025-04-29"),
+            ("2025-12-24", 2, "2025-12-30"),
+            ("2022-12-23", 1, "2022-12-28"),
+            ("2025-02-27", 10, "2025-03-13"),
+        ];
+        for (start, days, expected) in cases {
+            assert_eq!(add_business_days(date(start), days), date(expected), "{} + {}", start, days);
+        }
+    }
+
+    #[test]
+    fn business_days_between_counts_both_ways() {
+        let cases = [
+            ("2025-02-27", "2025-02-27", 0),
+            ("2025-02-27", "2025-03-06", 5),
+            ("2025-03-06", "2025-02-27", -5),
+            ("2025-04-17", "2025-04-22", 1),
+            ("2025-03-01", "2025-03-02", 0),
+            ("2025-12-24", "2026-01-02", 4),
+        ];
+        for (from, to, expected) in cases {
+            assert_eq!(business_days_between(date(from), date(to)), expected, "{} to {}", from, to);
+        }
+        for days in [0, 1, 5, 17, 40] {
+            let start = date("2025-03-18");
+            assert_eq!(business_days_between(start, add_business_days(start, days)), days as i64);
+        }
+    }
+}
@@ -1,52 +1,46 @@
 //! Formatting utilities
+//!
+//! Separators and month names follow the current interface locale.
+
+use super::datetime::{self, Date, Timestamp};
+use super::i18n;
+use super::money::Money;
 
 /// Format currency in South African Rand
-pub fn format_currency(amount: f64) -> String {
+pub fn format_currency(amount: impl Into<Money>) -> String {
+    let amount = amount.into();
     let abs = amount.abs();
-    let sign = if amount < 0.0 { "-" } else { "" };
+    let sign = if amount.is_negative() { "-" } else { "" };
 
-    if abs >= 1_000_000_000.0 {
-        format!("{}R {:.1}B", sign, abs / 1_000_000_000.0)
-    } else if abs >= 1_000_000.0 {
-        format!("{}R {:.1}M", sign, abs / 1_000_000.0)
-    } else if abs >= 1_000.0 {
-        format!("{}R {}", sign, format_number(abs as i64))
+    if abs >= Money::rands(1_000_000_000) {
+        format!("{}R {}B", sign, decimal(abs.to_rands() / 1_000_000_000.0, 1))
+    } else if abs >= Money::rands(1_000_000) {
+        format!("{}R {}M", sign, decimal(abs.to_rands() / 1_000_000.0, 1))
+    } else if abs >= Money::rands(1_000) {
+        format!("{}R {}", sign, format_number(abs.cents() / 100))
     } else {
-        format!("{}R {:.2}", sign, abs)
+        format!("{}R {}", sign, decimal(abs.to_rands(), 2))
     }
 }
 
 /// Format currency with full precision
-pub fn format_currency_full(amount: f64) -> String {
-    let formatted = format!("{:.2}", amount.abs());
-    let parts: Vec<&str> = formatted.split('.').collect();
-    let integer_part = parts[0];
-    let decimal_part = parts.get(1).unwrap_or(&"00");
-
-    // Add thousand separators
-    let with_separators: String = integer_part
-        .chars()
-        .rev()
-        .enumerate()
-        .map(|(i, c)| {
-            if i > 0 && i % 3 == 0 {
-                format!(",{}", c)
-            } else {
-                c.to_string()
-            }
-        })
-        .collect::<Vec<_>>()
-        .into_iter()
-        .rev()
-        .collect();
+pub fn format_currency_full(amount: impl Into<Money>) -> String {
+    let amount = amount.into();
+    let cents = amount.cents().unsigned_abs();
 
-    let sign = if amount < 0.0 { "-" } else { "" };
-    format!("{}R {}.{}", sign, with_separators, decimal_part)
+    let sign = if amount.is_negative() { "-" } else { "" };
+    format!(
+        "{}R {}{}{:02}",
+        sign,
+        format_number(cents / 100),
+        i18n::current().decimal_separator(),
+        cents % 100
+    )
 }
 
 /// Format percentage
 pub fn format_percentage(value: f64, decimals: usize) -> String {
-    format!("{:.precision$}%", value, precision = decimals)
+    format!("{}%", decimal(value, decimals))
 }
 
 /// Format date (ISO to display)
@@ -55,19 +49,9 @@ pub fn format_date(iso_date: &str) -> String {
     if iso_date.len() >= 10 {
         let parts: Vec<&str> = iso_date[..10].split('-').collect();
         if parts.len() == 3 {
-            let month = match parts[1] {
-                "01" => "Jan",
-                "02" => "Feb",
-                "03" => "Mar",
-                "04" => "Apr",
-                "05" => "May",
-                "06" => "Jun",
-                "07" => "Jul",
-                "08" => "Aug",
-                "09" => "Sep",
-                "10" => "Oct",
-                "11" => "Nov",
-                "12" => "Dec",
+            let months = i18n::current().month_abbreviations();
+            let month = match parts[1].parse::<usize>() {
+                Ok(m @ 1..=12) => months[m - 1],
                 _ => parts[1],
             };
             return format!("{} {} {}", parts[2], month, parts[0]);
@@ -76,8 +60,12 @@ pub fn format_date(iso_date: &str) -> String {
     iso_date.to_string()
 }
 
-/// Format date and time
+/// Format date and time, shown in SAST
 pub fn format_datetime(iso_datetime: &str) -> String {
+    if let Some(ts) = Timestamp::parse(iso_datetime).filter(|_| iso_datetime.len() > 10) {
+        let (hour, minute, _) = ts.sast_time();
+        return format!("{} {:02}:{:02}", format_date(&ts.sast_date().to_iso()), hour, minute);
+    }
     if iso_datetime.len() >= 16 {
         let date = format_date(&iso_datetime[..10]);
         let time = &iso_datetime[11..16];
@@ -87,19 +75,25 @@ pub fn format_datetime(iso_datetime: &str) -> String {
     }
 }
 
-/// Format relative time
+/// Format relative time ("5 minutes ago", "in 3 days"); date-only values
+/// are compared by calendar day
 pub fn format_relative_time(iso_datetime: &str) -> String {
-    // Simplified - in production would use actual date comparison
-    if iso_datetime.contains("T") {
-        "Today".to_string()
-    } else {
-        format_date(iso_datetime)
+    if iso_datetime.len() <= 10 {
+        return match Date::parse(iso_datetime) {
+            Some(date) => datetime::relative_date(date, datetime::today()),
+            None => iso_datetime.to_string(),
+        };
+    }
+    match Timestamp::parse(iso_datetime) {
+        Some(then) => datetime::relative_time(then, datetime::now()),
+        None => format_datetime(iso_datetime),
     }
 }
 
 /// Format number with thousands separators
 pub fn format_number(value: impl std::fmt::Display) -> String {
     let s = value.to_string();
+    let separator = i18n::current().group_separator();
     let mut result = String::new();
     let chars: Vec<char> = s.chars().collect();
     let len = chars.len();
@@ -108,7 +102,7 @@ pub fn format_number(value: impl std::fmt::Display) -> String {
         result.push(*c);
         let remaining = len - i - 1;
         if remaining > 0 && remaining % 3 == 0 && *c != '-' {
-            result.push(',');
+            result.push_str(separator);
         }
     }
     result
@@ -119,11 +113,20 @@ pub fn format_file_size(bytes: u64) -> String {
     if bytes < 1024 {
         format!("{} B", bytes)
     } else if bytes < 1024 * 1024 {
-        format!("{:.1} KB", bytes as f64 / 1024.0)
+        format!("{} KB", decimal(bytes as f64 / 1024.0, 1))
     } else if bytes < 1024 * 1024 * 1024 {
-        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+        format!("{} MB", decimal(bytes as f64 / (1024.0 * 1024.0), 1))
     } else {
-        format!("{:.1} GB", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+        format!("{} GB", decimal(bytes as f64 / (1024.0 * 1024.0 * 1024.0), 1))
+    }
+}
+
+/// Fixed-point number with the locale's decimal separator
+fn decimal(value: f64, places: usize) -> String {
+    let formatted = format!("{:.precision$}", value, precision = places);
+    match i18n::current().decimal_separator() {
+        "." => formatted,
+        separator => formatted.replace('.', separator),
     }
 }
 
@@ -0,0 +1,2005 @@
+//! Afrikaans message catalogue
+
+use super::Catalogue;
+
+pub const CATALOGUE: Catalogue = Catalogue {
+    messages: &[
+        ("Overview", "Oorsig"),
+        ("Dashboard", "Kontroleskerm"),
+        ("Procurement", "Verkryging"),
+        ("Requisitions", "Rekwisisies"),
+        ("Tenders", "Tenders"),
+        ("Evaluation", "Evaluering"),
+        ("Contracts", "Kontrakte"),
+        ("Purchase Orders", "Bestellings"),
+        ("Goods Receipt", "Goedereontvangs"),
+        ("Suppliers", "Verskaffers"),
+        ("Registry", "Register"),
+        ("Performance", "Prestasie"),
+        ("Risk", "Risiko"),
+        ("Supplier Portal", "Verskafferportaal"),
+        ("Catalogue", "Katalogus"),
+        ("Browse", "Blaai"),
+        ("Admin", "Administrasie"),
+        ("Compliance", "Nakoming"),
+        ("Analytics", "Analise"),
+        ("Audit Trail", "Ouditspoor"),
+        ("GRC Dashboard", "GRC-kontroleskerm"),
+        ("NBAC Reviews", "NBAC-hersienings"),
+        ("AGSA Reviews", "AGSA-hersienings"),
+        ("B-BBEE Goals", "B-BBEE-doelwitte"),
+        ("Special Sourcing", "Spesiale verkryging"),
+        ("Reverse Auction", "Omgekeerde veiling"),
+        ("Sourcing Plans", "Verkrygingsplanne"),
+        ("Tools", "Gereedskap"),
+        ("Documents", "Dokumente"),
+        ("AI Assistant", "KI-assistent"),
+        ("Search requisitions, tenders, suppliers...", "Soek rekwisisies, tenders, verskaffers..."),
+        ("Notifications", "Kennisgewings"),
+        ("Help", "Hulp"),
+        ("Sign out", "Teken uit"),
+        ("Language", "Taal"),
+        ("Mark all read", "Merk alles as gelees"),
+        ("Settings", "Instellings"),
+        ("Back", "Terug"),
+        ("Notification settings", "Kennisgewinginstellings"),
+        ("You're all caught up", "Jy is op hoogte"),
+        ("No matches", "Geen resultate"),
+        ("Searching...", "Soek tans..."),
+        ("Page not found", "Bladsy nie gevind nie"),
+        ("Back to Dashboard", "Terug na kontroleskerm"),
+        ("Try again", "Probeer weer"),
+        ("Something went wrong", "Iets het verkeerd geloop"),
+        ("Draft", "Konsep"),
+        ("Submitted", "Ingedien"),
+        ("Pending Approval", "Wag op goedkeuring"),
+        ("Approved", "Goedgekeur"),
+        ("Rejected", "Afgekeur"),
+        ("Cancelled", "Gekanselleer"),
+        ("In Progress", "Aan die gang"),
+        ("Complete", "Voltooi"),
+        ("Active", "Aktief"),
+        ("Published", "Gepubliseer"),
+        ("Pending", "Hangend"),
+        ("Expired", "Verval"),
+        ("Failed", "Misluk"),
+        ("New", "Nuut"),
+        ("Open", "Oop"),
+        ("Review", "Hersiening"),
+        ("On Hold", "Opgeskort"),
+        ("Scheduled", "Geskeduleer"),
+        ("Closed", "Gesluit"),
+        ("Adjudication", "Beoordeling"),
+        ("Awarded", "Toegeken"),
+        ("Low", "Laag"),
+        ("Medium", "Medium"),
+        ("High", "Hoog"),
+        ("Urgent", "Dringend"),
+        ("Request for Quotation", "Versoek om kwotasie"),
+        ("Request for Proposal", "Versoek om voorstel"),
+        ("Request for Tender", "Versoek om tender"),
+        ("Bids", "Bod"),
+        ("Payments", "Betalings"),
+        ("System", "Stelsel"),
+        ("Auctions", "Veilings"),
+        ("Approvals", "Goedkeurings"),
+        ("Certificates", "Sertifikate"),
+        ("Meetings", "Vergaderings"),
+        ("Today", "Vandag"),
+        ("Approval awaiting you", "Goedkeuring wag op jou"),
+        ("Tender closing", "Tender sluit binnekort"),
+        ("Contract expiring", "Kontrak verval binnekort"),
+        ("Certificate expiring", "Sertifikaat verval binnekort"),
+        ("NBAC meeting scheduled", "NBAC-vergadering geskeduleer"),
+        ("There is no page at {path}. Check the link or use the sidebar to find what you need.", "Daar is geen bladsy by {path} nie. Kontroleer die skakel of gebruik die kantbalk om te vind wat jy soek."),
+        ("Just now", "Sopas"),
+        ("Yesterday", "Gister"),
+        ("Tomorrow", "Môre"),
+        ("{time} ago", "{time} gelede"),
+        ("in {time}", "oor {time}"),
+        (" (R)", " (R)"),
+        (" (must equal 100%)", " (moet 100% wees)"),
+        (" Upload Document", " Laai dokument op"),
+        (" incl. VAT", " insl. BTW"),
+        (" supplier B-BBEE certificates expiring within 90 days", " verskaffer se B-BBEE-sertifikate verval binne 90 dae"),
+        ("% of Total", "% van totaal"),
+        ("+ Add Category", "+ Voeg kategorie by"),
+        ("+ Add Criterion", "+ Voeg kriterium by"),
+        ("+ Add Line Item", "+ Voeg lynitem by"),
+        ("+ Add Milestone", "+ Voeg mylpaal by"),
+        ("+ Add Objective", "+ Voeg doelwit by"),
+        ("+ New Requisition", "+ Nuwe rekwisisie"),
+        ("+ New Sourcing Plan", "+ Nuwe verkrygingsplan"),
+        (", the following approvals will be required before proceeding.", ", is die volgende goedkeurings nodig voordat voortgegaan kan word."),
+        ("1 day", "1 dag"),
+        ("1 hour", "1 uur"),
+        ("14 days", "14 dae"),
+        ("15 minutes", "15 minute"),
+        ("2 hours", "2 uur"),
+        ("3 days", "3 dae"),
+        ("30 minutes", "30 minute"),
+        ("7 days", "7 dae"),
+        ("AGSA - Audit Findings", "AGSA - Ouditbevindinge"),
+        ("AGSA Audit Findings", "AGSA-ouditbevindinge"),
+        ("AGSA Comments", "AGSA-kommentaar"),
+        ("AI Procurement Assistant", "KI-verkrygingsassistent"),
+        ("Accepted", "Aanvaar"),
+        ("Accepted goods", "Aanvaarde goedere"),
+        ("Access denied", "Toegang geweier"),
+        ("Accounting Officer Approval", "Goedkeuring deur rekenpligtige beampte"),
+        ("Acknowledged", "Erken"),
+        ("Action", "Aksie"),
+        ("Action Details", "Aksiebesonderhede"),
+        ("Action Items", "Aksie-items"),
+        ("Action Plan", "Aksieplan"),
+        ("Action Type", "Aksietipe"),
+        ("Actions", "Aksies"),
+        ("Actions Completed", "Aksies voltooi"),
+        ("Actions Overdue", "Aksies agterstallig"),
+        ("Activate", "Aktiveer"),
+        ("Active Bidders ({count})", "Aktiewe bieërs ({count})"),
+        ("Active Bids", "Aktiewe bod"),
+        ("Active Contracts", "Aktiewe kontrakte"),
+        ("Active Items", "Aktiewe items"),
+        ("Active Members", "Aktiewe lede"),
+        ("Active Plans", "Aktiewe planne"),
+        ("Active Requisitions", "Aktiewe rekwisisies"),
+        ("Active Risks", "Aktiewe risiko's"),
+        ("Active Suppliers", "Aktiewe verskaffers"),
+        ("Active Users", "Aktiewe gebruikers"),
+        ("Active:", "Aktief:"),
+        ("Actual", "Werklik"),
+        ("Actual Spend", "Werklike besteding"),
+        ("Add", "Voeg by"),
+        ("Add Catalogue Item", "Voeg katalogusitem by"),
+        ("Add Control", "Voeg kontrole by"),
+        ("Add Deliverable", "Voeg lewerbare by"),
+        ("Add Item", "Voeg item by"),
+        ("Add Line Item", "Voeg lynitem by"),
+        ("Add Milestone", "Voeg mylpaal by"),
+        ("Add Note", "Voeg nota by"),
+        ("Add Risk", "Voeg risiko by"),
+        ("Add Supplier", "Voeg verskaffer by"),
+        ("Add Target", "Voeg teiken by"),
+        ("Add, edit, and manage catalogue items", "Voeg katalogusitems by, wysig en bestuur hulle"),
+        ("Additional Information", "Bykomende inligting"),
+        ("Additional Notes", "Bykomende notas"),
+        ("Address", "Adres"),
+        ("Address Line 2", "Adreslyn 2"),
+        ("Address:", "Adres:"),
+        ("Administration", "Administrasie"),
+        ("Adverse", "Nadelig"),
+        ("After authorisation", "Ná magtiging"),
+        ("Agenda Items", "Agenda-items"),
+        ("Aging", "Ouderdom"),
+        ("Alerts", "Waarskuwings"),
+        ("All", "Alle"),
+        ("All Actions", "Alle aksies"),
+        ("All Categories", "Alle kategorieë"),
+        ("All Departments", "Alle departemente"),
+        ("All Documents", "Alle dokumente"),
+        ("All Entities", "Alle entiteite"),
+        ("All Levels", "Alle vlakke"),
+        ("All Provinces", "Alle provinsies"),
+        ("All Receipts ({count})", "Alle ontvangstes ({count})"),
+        ("All Requisitions", "Alle rekwisisies"),
+        ("All Risk Levels", "Alle risikovlakke"),
+        ("All Sourcing Plans", "Alle verkrygingsplanne"),
+        ("All Spend Targets", "Alle bestedingsteikens"),
+        ("All Statuses", "Alle statusse"),
+        ("All Suppliers", "Alle verskaffers"),
+        ("All Types", "Alle tipes"),
+        ("All Years", "Alle jare"),
+        ("All budgets are within their thresholds", "Alle begrotings is binne hul drempels"),
+        ("All documents", "Alle dokumente"),
+        ("All statuses", "Alle statusse"),
+        ("Allocated", "Toegeken"),
+        ("Allocated Budget", "Toegekende begroting"),
+        ("Allocated to Categories", "Aan kategorieë toegeken"),
+        ("Almost Certain", "Byna seker"),
+        ("Already on", "Reeds op"),
+        ("Also publish to internal supplier portal", "Publiseer ook op die interne verskafferportaal"),
+        ("Amount", "Bedrag"),
+        ("Amount limit", "Bedraglimiet"),
+        ("Annual procurement planning and budget management", "Jaarlikse verkrygingsbeplanning en begrotingsbestuur"),
+        ("Any additional information...", "Enige bykomende inligting..."),
+        ("Any additional notes to display on the portal", "Enige bykomende notas om op die portaal te wys"),
+        ("Any notes or comments for the supplier", "Enige notas of kommentaar vir die verskaffer"),
+        ("App Configuration", "Toepassingkonfigurasie"),
+        ("App Features", "Toepassingkenmerke"),
+        ("App Name", "Toepassingnaam"),
+        ("App Preview", "Toepassingvoorskou"),
+        ("App Version", "Toepassingweergawe"),
+        ("Applicable for existing strategic relationships that provide significant value. Must demonstrate strategic importance and value.", "Van toepassing op bestaande strategiese verhoudings wat beduidende waarde bied. Moet strategiese belang en waarde aantoon."),
+        ("Applicable for urgent requirements due to unforeseen circumstances. Must demonstrate immediate need and inability to follow normal process.", "Van toepassing op dringende behoeftes weens onvoorsiene omstandighede. Moet onmiddellike behoefte en die onvermoë om die normale proses te volg, aantoon."),
+        ("Applicable when only one supplier can provide the specific goods or services required. Must demonstrate that no reasonable alternative exists.", "Van toepassing wanneer slegs een verskaffer die spesifieke goedere of dienste kan lewer. Moet aantoon dat geen redelike alternatief bestaan nie."),
+        ("Applicable when supplier has exclusive rights through patents, licenses, or proprietary technology. Must provide evidence of exclusivity.", "Van toepassing wanneer die verskaffer eksklusiewe regte deur patente, lisensies of eie tegnologie het. Moet bewys van eksklusiwiteit lewer."),
+        ("Apply", "Pas toe"),
+        ("Apply Corrections", "Pas regstellings toe"),
+        ("Approval", "Goedkeuring"),
+        ("Approval Delegations", "Goedkeuringsdelegasies"),
+        ("Approval Rate", "Goedkeuringskoers"),
+        ("Approval Requirements", "Goedkeuringsvereistes"),
+        ("Approval Turnaround", "Goedkeuringsomkeertyd"),
+        ("Approval Workflow", "Goedkeuringswerkvloei"),
+        ("Approval steps past their SLA and steps escalated to the next authority", "Goedkeuringstappe oor hul SLA en stappe na die volgende gesag geëskaleer"),
+        ("Approvals Needing Attention", "Goedkeurings wat aandag nodig het"),
+        ("Approvals delegated to", "Goedkeurings gedelegeer aan"),
+        ("Approve", "Keur goed"),
+        ("Approve for Payment", "Keur goed vir betaling"),
+        ("Approved by", "Goedgekeur deur"),
+        ("Approved, Not on a Run", "Goedgekeur, nie op 'n lopie nie"),
+        ("Approver", "Goedkeurder"),
+        ("Archive", "Argiveer"),
+        ("Archived", "Geargiveer"),
+        ("Are you sure you want to delete this catalogue item? This action cannot be undone.", "Is jy seker jy wil hierdie katalogusitem skrap? Hierdie aksie kan nie ontdoen word nie."),
+        ("Ask me anything about procurement...", "Vra my enigiets oor verkryging..."),
+        ("Assigned To", "Toegewys aan"),
+        ("At Risk", "In gevaar"),
+        ("Attachments", "Aanhegsels"),
+        ("Attendance Mandatory", "Bywoning verpligtend"),
+        ("Auction", "Veiling"),
+        ("Auction Participations", "Veilingdeelnames"),
+        ("Auction is not currently live", "Veiling is nie tans regstreeks nie"),
+        ("Auctions ({count} total)", "Veilings ({count} totaal)"),
+        ("Audit Entries ({count})", "Ouditinskrywings ({count})"),
+        ("Audit Findings", "Ouditbevindinge"),
+        ("Audit Reports", "Ouditverslae"),
+        ("Auditor-General South Africa findings, action items, and compliance tracking", "Bevindinge van die Ouditeur-generaal van Suid-Afrika, aksie-items en nakomingsopsporing"),
+        ("Auth", "Magt."),
+        ("Authorisation", "Magtiging"),
+        ("Authorisations", "Magtigings"),
+        ("Authorise Run", "Magtig lopie"),
+        ("Authorization", "Magtiging"),
+        ("Availability (%)", "Beskikbaarheid (%)"),
+        ("Available", "Beskikbaar"),
+        ("Available Budget", "Beskikbare begroting"),
+        ("Average Confidence", "Gemiddelde vertroue"),
+        ("Avg Attendance", "Gem. bywoning"),
+        ("Avg Resolution Days", "Gem. oplossingsdae"),
+        ("Avg Turnaround", "Gem. omkeertyd"),
+        ("Avg. Business Days", "Gem. werksdae"),
+        ("Avg. Performance", "Gem. prestasie"),
+        ("Avg. Procurement Cycle", "Gem. verkrygingsiklus"),
+        ("Awaiting", "Wag"),
+        ("Awaiting Authorisation", "Wag op magtiging"),
+        ("Awaiting Inspection:", "Wag op inspeksie:"),
+        ("Awaiting Review", "Wag op hersiening"),
+        ("Awaiting approver", "Wag op goedkeurder"),
+        ("Award", "Toeken"),
+        ("Awarded Contracts", "Toegekende kontrakte"),
+        ("B-BBEE", "B-BBEE"),
+        ("B-BBEE %", "B-BBEE %"),
+        ("B-BBEE Compliance Metrics", "B-BBEE-nakomingsmaatstawwe"),
+        ("B-BBEE Compliant", "B-BBEE-nakomend"),
+        ("B-BBEE Level", "B-BBEE-vlak"),
+        ("B-BBEE Level Breakdown", "B-BBEE-vlakontleding"),
+        ("B-BBEE Performance", "B-BBEE-prestasie"),
+        ("B-BBEE Preferential Procurement Scorecard", "B-BBEE-telkaart vir voorkeurverkryging"),
+        ("B-BBEE Spend", "B-BBEE-besteding"),
+        ("B-BBEE Spend Distribution", "B-BBEE-bestedingsverspreiding"),
+        ("B-BBEE Spend Trend", "B-BBEE-bestedingsneiging"),
+        ("B-BBEE Weight", "B-BBEE-gewig"),
+        ("B-BBEE Weight (%)", "B-BBEE-gewig (%)"),
+        ("B-BBEE certificates are valid for twelve months from the date of issue", "B-BBEE-sertifikate is twaalf maande vanaf die uitreikingsdatum geldig"),
+        ("Back to AGSA Reviews", "Terug na AGSA-hersienings"),
+        ("Back to Auction List", "Terug na veilinglys"),
+        ("Back to Auctions", "Terug na veilings"),
+        ("Back to Contracts", "Terug na kontrakte"),
+        ("Back to Document Intake", "Terug na dokumentinname"),
+        ("Back to Evaluation List", "Terug na evalueringslys"),
+        ("Back to Evaluations", "Terug na evaluerings"),
+        ("Back to Goods Receipts", "Terug na goedereontvangstes"),
+        ("Back to Invoices", "Terug na fakture"),
+        ("Back to Library", "Terug na biblioteek"),
+        ("Back to Milestones", "Terug na mylpale"),
+        ("Back to Payment Runs", "Terug na betalingslopies"),
+        ("Back to Purchase Orders", "Terug na bestellings"),
+        ("Back to Registry", "Terug na register"),
+        ("Back to Requisitions", "Terug na rekwisisies"),
+        ("Back to Reviews", "Terug na hersienings"),
+        ("Back to Suppliers", "Terug na verskaffers"),
+        ("Back to Tender", "Terug na tender"),
+        ("Back to Tenders", "Terug na tenders"),
+        ("Bank Account", "Bankrekening"),
+        ("Bank Files", "Banklêers"),
+        ("Bank account or branch code invalid", "Bankrekening of takkode ongeldig"),
+        ("Based on estimated value of ", "Gebaseer op die beraamde waarde van "),
+        ("Basic Info", "Basiese inligting"),
+        ("Basic Information", "Basiese inligting"),
+        ("Batch approved invoices, authorise runs and release bank payment files", "Bondel goedgekeurde fakture, magtig lopies en stel bankbetalingslêers vry"),
+        ("Below", "Onder"),
+        ("Bid", "Bod"),
+        ("Bid Award", "Bodtoekenning"),
+        ("Bid Comparison", "Bodvergelyking"),
+        ("Bid Evaluation", "Bodevaluering"),
+        ("Bid History ({count})", "Bodgeskiedenis ({count})"),
+        ("Bid Price", "Bodprys"),
+        ("Bidder {id}", "Bieër {id}"),
+        ("Bidders", "Bieërs"),
+        ("Bids ({count})", "Bod ({count})"),
+        ("Bids Submitted", "Bod ingedien"),
+        ("Black Owned Spend", "Besteding by swart-besit"),
+        ("Black Women Owned", "Swart vroue-besit"),
+        ("Blacklisted", "Op swartlys"),
+        ("Box", "Boks"),
+        ("Branding", "Handelsmerk"),
+        ("Brief description of procurement", "Kort beskrywing van verkryging"),
+        ("Brief description of the procurement", "Kort beskrywing van die verkryging"),
+        ("Brief description of the requisition", "Kort beskrywing van die rekwisisie"),
+        ("Briefing Check-ins", "Inligtingsessie-aanmeldings"),
+        ("Briefing Date", "Inligtingsessiedatum"),
+        ("Broad-Based Black Economic Empowerment compliance tracking for FY 2025/26", "Nakomingsopsporing vir breë-basis swart ekonomiese bemagtiging vir BJ 2025/26"),
+        ("Browse Opportunities", "Blaai deur geleenthede"),
+        ("Browse and search available items", "Blaai en soek beskikbare items"),
+        ("Budget", "Begroting"),
+        ("Budget Alerts", "Begrotingswaarskuwings"),
+        ("Budget Allocation", "Begrotingstoekenning"),
+        ("Budget Control", "Begrotingsbeheer"),
+        ("Budget Lines", "Begrotingslyne"),
+        ("Budget Summary", "Begrotingsopsomming"),
+        ("Budget Transfers", "Begrotingsoordragte"),
+        ("Budget Utilization", "Begrotingsbenutting"),
+        ("Budget Utilized", "Begroting benut"),
+        ("Budget by Category", "Begroting volgens kategorie"),
+        ("Building, floor, etc. (optional)", "Gebou, vloer, ens. (opsioneel)"),
+        ("By submitting this request, you confirm that you have conducted appropriate market research and that deviation from competitive bidding is justified.", "Deur hierdie versoek in te dien, bevestig jy dat jy toepaslike marknavorsing gedoen het en dat afwyking van mededingende bieding geregverdig is."),
+        ("CFO Approval", "Goedkeuring deur finansiële hoof"),
+        ("CIPC registration", "CIPC-registrasie"),
+        ("CSD Unverified", "CSD ongeverifieer"),
+        ("CSD Verified", "CSD geverifieer"),
+        ("Cancel", "Kanselleer"),
+        ("Cancel Run", "Kanselleer lopie"),
+        ("Cancellation", "Kansellasie"),
+        ("Cannot be ordered directly: {reason}.", "Kan nie direk bestel word nie: {reason}."),
+        ("Cards", "Kaarte"),
+        ("Catalogue Items ({count})", "Katalogusitems ({count})"),
+        ("Catalogue Management", "Katalogusbestuur"),
+        ("Catastrophic", "Katastrofies"),
+        ("Categories", "Kategorieë"),
+        ("Categories ({count} total)", "Kategorieë ({count} totaal)"),
+        ("Category", "Kategorie"),
+        ("Category Breakdown", "Kategorie-ontleding"),
+        ("Category Name", "Kategorienaam"),
+        ("Category name", "Kategorienaam"),
+        ("Category:", "Kategorie:"),
+        ("Catering", "Spyseniering"),
+        ("Changes", "Veranderinge"),
+        ("Checks", "Kontroles"),
+        ("Choose a bid from the list on the left to begin scoring.", "Kies 'n bod uit die lys links om met puntetoekenning te begin."),
+        ("Choose a colleague", "Kies 'n kollega"),
+        ("Choose how to resolve the exception", "Kies hoe om die uitsondering op te los"),
+        ("City", "Stad"),
+        ("Classification", "Klassifikasie"),
+        ("Clean Audit", "Skoon oudit"),
+        ("Clear", "Maak skoon"),
+        ("Clear Filters", "Maak filters skoon"),
+        ("Close", "Sluit"),
+        ("Closing Date", "Sluitingsdatum"),
+        ("Code", "Kode"),
+        ("Comment", "Kommentaar"),
+        ("Comments (required to reject or return)", "Kommentaar (vereis om af te keur of terug te stuur)"),
+        ("Commitment", "Verbintenis"),
+        ("Commitments", "Verbintenisse"),
+        ("Committed", "Verbind"),
+        ("Committee", "Komitee"),
+        ("Committee Members", "Komiteelede"),
+        ("Committee Notes", "Komiteenotas"),
+        ("Committee Statistics", "Komiteestatistiek"),
+        ("Completed", "Voltooi"),
+        ("Completed ({count})", "Voltooi ({count})"),
+        ("Completed Today:", "Vandag voltooi:"),
+        ("Completed: {date}", "Voltooi: {date}"),
+        ("Completion Progress", "Voltooiingsvordering"),
+        ("Compliance Check", "Nakomingskontrole"),
+        ("Compliance Checks", "Nakomingskontroles"),
+        ("Compliance Checks ({count} total)", "Nakomingskontroles ({count} totaal)"),
+        ("Compliance Issues", "Nakomingskwessies"),
+        ("Compliance Metrics", "Nakomingsmaatstawwe"),
+        ("Compliance Score", "Nakomingstelling"),
+        ("Compliant", "Nakomend"),
+        ("Compliant Metrics", "Nakomende maatstawwe"),
+        ("Compliant Suppliers", "Nakomende verskaffers"),
+        ("Comprehensive procurement spend analysis for FY 2025/26", "Omvattende ontleding van verkrygingsbesteding vir BJ 2025/26"),
+        ("Confidence", "Vertroue"),
+        ("Configuration", "Konfigurasie"),
+        ("Confinement", "Beperking"),
+        ("Confirm Paid", "Bevestig betaal"),
+        ("Confirm it is a separate charge before accepting it.", "Bevestig dat dit 'n aparte heffing is voordat jy dit aanvaar."),
+        ("Conflict", "Konflik"),
+        ("Connected", "Gekoppel"),
+        ("Construction", "Konstruksie"),
+        ("Contact", "Kontak"),
+        ("Contact Email", "Kontak-e-pos"),
+        ("Contact Person", "Kontakpersoon"),
+        ("Contact Phone", "Kontaktelefoon"),
+        ("Contact:", "Kontak:"),
+        ("Container", "Houer"),
+        ("Continue to Attachments", "Gaan voort na aanhegsels"),
+        ("Continue to Budget", "Gaan voort na begroting"),
+        ("Continue to Categories", "Gaan voort na kategorieë"),
+        ("Continue to Line Items", "Gaan voort na lynitems"),
+        ("Continue to Review", "Gaan voort na hersiening"),
+        ("Continue to Timeline", "Gaan voort na tydlyn"),
+        ("Contract", "Kontrak"),
+        ("Contract Awards", "Kontraktoekennings"),
+        ("Contract Details", "Kontrakbesonderhede"),
+        ("Contract Duration", "Kontrakduur"),
+        ("Contract ID", "Kontrak-ID"),
+        ("Contract No.", "Kontrak nr."),
+        ("Contract Period", "Kontraktydperk"),
+        ("Contract Reference", "Kontrakverwysing"),
+        ("Contract Terms", "Kontrakvoorwaardes"),
+        ("Contract Title", "Kontraktitel"),
+        ("Contract Type", "Kontraktipe"),
+        ("Contract Utilization", "Kontrakbenutting"),
+        ("Contract Value", "Kontrakwaarde"),
+        ("Contract ref...", "Kontrakverw..."),
+        ("Contract {id} - {supplier_name}", "Kontrak {id} - {supplier_name}"),
+        ("Contract:", "Kontrak:"),
+        ("Contracts ({count})", "Kontrakte ({count})"),
+        ("Control", "Kontrole"),
+        ("Control Effectiveness", "Kontrole-doeltreffendheid"),
+        ("Controls", "Kontroles"),
+        ("Corporate Services", "Korporatiewe dienste"),
+        ("Corrected", "Reggestel"),
+        ("Corrections", "Regstellings"),
+        ("Corrective", "Regstellend"),
+        ("Cost Center", "Kostesentrum"),
+        ("Cost Centre", "Kostesentrum"),
+        ("Cost Savings", "Kostebesparings"),
+        ("Create", "Skep"),
+        ("Create Contract", "Skep kontrak"),
+        ("Create Purchase Order", "Skep bestelling"),
+        ("Create a new purchase requisition", "Skep 'n nuwe aankooprekwisisie"),
+        ("Create an annual procurement sourcing plan", "Skep 'n jaarlikse verkrygingsplan"),
+        ("Create {tender_type}", "Skep {tender_type}"),
+        ("Created", "Geskep"),
+        ("Criteria", "Kriteria"),
+        ("Criteria Count", "Aantal kriteria"),
+        ("Criterion Name", "Kriteriumnaam"),
+        ("Critical", "Krities"),
+        ("Critical Alerts", "Kritieke waarskuwings"),
+        ("Critical Risk", "Kritieke risiko"),
+        ("Critical Risk Suppliers - Immediate Attention Required", "Verskaffers met kritieke risiko - onmiddellike aandag nodig"),
+        ("Current Audit Opinion", "Huidige ouditmening"),
+        ("Current Bid", "Huidige bod"),
+        ("Current Lowest Bid", "Huidige laagste bod"),
+        ("Current: {amount}", "Huidig: {amount}"),
+        ("Custom Range", "Pasgemaakte reeks"),
+        ("Cyber", "Kuber"),
+        ("Dark", "Donker"),
+        ("Date", "Datum"),
+        ("Date (Newest)", "Datum (nuutste)"),
+        ("Date (Oldest)", "Datum (oudste)"),
+        ("Date Emergency Arose", "Datum waarop noodgeval ontstaan het"),
+        ("Date From", "Datum van"),
+        ("Date Received", "Datum ontvang"),
+        ("Date To", "Datum tot"),
+        ("Deactivate", "Deaktiveer"),
+        ("Decision", "Besluit"),
+        ("Decision History", "Besluitgeskiedenis"),
+        ("Decisions", "Besluite"),
+        ("Decisions This Month", "Besluite hierdie maand"),
+        ("Decisions YTD", "Besluite JTD"),
+        ("Decreasing", "Dalend"),
+        ("Defer", "Stel uit"),
+        ("Define key milestones and target dates for the procurement plan execution.", "Bepaal sleutelmylpale en teikendatums vir die uitvoering van die verkrygingsplan."),
+        ("Define the procurement categories and their budget allocations for this plan.", "Bepaal die verkrygingskategorieë en hul begrotingstoekennings vir hierdie plan."),
+        ("Delegate", "Delegeer"),
+        ("Delegate Approvals", "Delegeer goedkeurings"),
+        ("Delegate to", "Delegeer aan"),
+        ("Delegations", "Delegasies"),
+        ("Delegator", "Delegeerder"),
+        ("Delete", "Skrap"),
+        ("Delete Item", "Skrap item"),
+        ("Deliverable #{number}", "Lewerbare #{number}"),
+        ("Deliverables", "Lewerbares"),
+        ("Delivered", "Afgelewer"),
+        ("Deliveries Confirmed", "Aflewerings bevestig"),
+        ("Delivery", "Aflewering"),
+        ("Delivery Address", "Afleweringsadres"),
+        ("Delivery Address Line 1", "Afleweringsadres lyn 1"),
+        ("Delivery Details", "Afleweringsbesonderhede"),
+        ("Delivery Information", "Afleweringsinligting"),
+        ("Delivery Instructions", "Afleweringsinstruksies"),
+        ("Delivery Location", "Afleweringsplek"),
+        ("Delivery Note", "Afleweringsbrief"),
+        ("Delivery location", "Afleweringsplek"),
+        ("Department", "Departement"),
+        ("Department & Budget", "Departement en begroting"),
+        ("Department & Timeline", "Departement en tydlyn"),
+        ("Describe escalation levels and contacts", "Beskryf eskalasievlakke en kontakpersone"),
+        ("Describe market research conducted", "Beskryf die marknavorsing wat gedoen is"),
+        ("Describe penalties for SLA breaches", "Beskryf boetes vir SLA-oortredings"),
+        ("Describe renewal conditions", "Beskryf hernuwingsvoorwaardes"),
+        ("Describe termination conditions", "Beskryf beëindigingsvoorwaardes"),
+        ("Describe the contract scope and objectives", "Beskryf die kontrak se omvang en doelwitte"),
+        ("Describe the emergency and why normal procurement cannot be followed", "Beskryf die noodgeval en waarom normale verkryging nie gevolg kan word nie"),
+        ("Describe the scope and purpose of this sourcing plan...", "Beskryf die omvang en doel van hierdie verkrygingsplan..."),
+        ("Description", "Beskrywing"),
+        ("Designated Group Spend (%)", "Besteding by aangewese groepe (%)"),
+        ("Designated Groups", "Aangewese groepe"),
+        ("Detailed description of goods/services required", "Gedetailleerde beskrywing van die goedere/dienste benodig"),
+        ("Detailed description of work to be performed", "Gedetailleerde beskrywing van die werk wat gedoen moet word"),
+        ("Details", "Besonderhede"),
+        ("Detect automatically", "Bespeur outomaties"),
+        ("Detected", "Bespeur"),
+        ("Detective", "Opsporend"),
+        ("Deviation", "Afwyking"),
+        ("Deviation Justification", "Regverdiging vir afwyking"),
+        ("Deviation Request", "Afwykingsversoek"),
+        ("Deviation Type", "Afwykingstipe"),
+        ("Deviations from competitive bidding must be justified in terms of Treasury Regulation 16A6.4 and require appropriate approval based on value.", "Afwykings van mededingende bieding moet ingevolge Tesourieregulasie 16A6.4 geregverdig word en vereis toepaslike goedkeuring volgens waarde."),
+        ("Device", "Toestel"),
+        ("Device Breakdown", "Toestelontleding"),
+        ("Directive", "Voorskriftelik"),
+        ("Disable All", "Deaktiveer alles"),
+        ("Disclaimer", "Vrywaring"),
+        ("Disconnected", "Ontkoppel"),
+        ("Discontinued", "Gestaak"),
+        ("Dispute Resolution", "Geskilbeslegting"),
+        ("Docs", "Dokumente"),
+        ("Docs Scanned", "Dokumente geskandeer"),
+        ("Document", "Dokument"),
+        ("Document Intake", "Dokumentinname"),
+        ("Document Library", "Dokumentbiblioteek"),
+        ("Document Name", "Dokumentnaam"),
+        ("Document Preview", "Dokumentvoorskou"),
+        ("Document Type", "Dokumenttipe"),
+        ("Documentation", "Dokumentasie"),
+        ("Documents ({count} found)", "Dokumente ({count} gevind)"),
+        ("Documents Scanned", "Dokumente geskandeer"),
+        ("Download", "Laai af"),
+        ("Download CSV", "Laai CSV af"),
+        ("Download Docs", "Laai dokumente af"),
+        ("Download QR Code", "Laai QR-kode af"),
+        ("Draft:", "Konsep:"),
+        ("Drop files here or click to browse", "Los lêers hier of klik om te blaai"),
+        ("Due", "Verskuldig"),
+        ("Due Date", "Sperdatum"),
+        ("Due in 7 Days", "Verskuldig binne 7 dae"),
+        ("Due: {date}", "Verskuldig: {date}"),
+        ("EME Suppliers", "EME-verskaffers"),
+        ("EME/QSE Spend", "EME/QSE-besteding"),
+        ("Each", "Elk"),
+        ("Eastern Cape", "Oos-Kaap"),
+        ("Edit", "Wysig"),
+        ("Edit Catalogue Item", "Wysig katalogusitem"),
+        ("Editing {id}", "Wysig {id}"),
+        ("Effective", "Doeltreffend"),
+        ("Effectiveness", "Doeltreffendheid"),
+        ("Element", "Element"),
+        ("Email", "E-pos"),
+        ("Emergency", "Noodgeval"),
+        ("Emergency - Urgent procurement due to unforeseen circumstances", "Noodgeval - Dringende verkryging weens onvoorsiene omstandighede"),
+        ("Emergency Circumstances", "Noodomstandighede"),
+        ("Emergency Details", "Noodgevalbesonderhede"),
+        ("Emergency Procurement", "Noodverkryging"),
+        ("Enable All", "Aktiveer alles"),
+        ("Enable Offline Mode", "Aktiveer vanlynmodus"),
+        ("Enable or disable features available to suppliers in the mobile app.", "Aktiveer of deaktiveer kenmerke wat in die mobiele toepassing vir verskaffers beskikbaar is."),
+        ("Enable this to define service level requirements and penalties", "Aktiveer dit om diensvlakvereistes en boetes te bepaal"),
+        ("End Date", "Einddatum"),
+        ("End Time", "Eindtyd"),
+        ("Ended", "Afgeloop"),
+        ("Enter a descriptive title", "Voer 'n beskrywende titel in"),
+        ("Enter contract title", "Voer kontraktitel in"),
+        ("Enter document name", "Voer dokumentnaam in"),
+        ("Enter item description", "Voer itembeskrywing in"),
+        ("Enter item name", "Voer itemnaam in"),
+        ("Enter strategic objective...", "Voer strategiese doelwit in..."),
+        ("Enter the purchase order number and load the order to capture the lines", "Voer die bestellingsnommer in en laai die bestelling om die lyne vas te lê"),
+        ("Enter your bid", "Voer jou bod in"),
+        ("Enterprise & Supplier Development Element - Code 400", "Element vir onderneming- en verskafferontwikkeling - Kode 400"),
+        ("Entity", "Entiteit"),
+        ("Entity ID", "Entiteit-ID"),
+        ("Entity Name", "Entiteitnaam"),
+        ("Entity Type", "Entiteittipe"),
+        ("Escalated", "Geëskaleer"),
+        ("Escalated from", "Geëskaleer vanaf"),
+        ("Escalation Procedure", "Eskalasieprosedure"),
+        ("Est. Value", "Beraamde waarde"),
+        ("Estimated Total", "Beraamde totaal"),
+        ("Estimated Value", "Beraamde waarde"),
+        ("Estimated contract value in ZAR", "Beraamde kontrakwaarde in ZAR"),
+        ("Eval Deadline", "Evalueringsperdatum"),
+        ("Evaluated", "Geëvalueer"),
+        ("Evaluation Criteria", "Evalueringskriteria"),
+        ("Evaluation Summary", "Evalueringsopsomming"),
+        ("Exceeding", "Oorskry"),
+        ("Excel", "Excel"),
+        ("Exception", "Uitsondering"),
+        ("Exception Queue", "Uitsonderingsry"),
+        ("Exceptions", "Uitsonderings"),
+        ("Excluded Invoices", "Uitgeslote fakture"),
+        ("Expected", "Verwag"),
+        ("Expected Date:", "Verwagte datum:"),
+        ("Expected Delivery Date", "Verwagte afleweringsdatum"),
+        ("Expended", "Bestee"),
+        ("Expires in {days} days", "Verval oor {days} dae"),
+        ("Expiring (30 days):", "Verval (30 dae):"),
+        ("Expiring Certs", "Sertifikate wat verval"),
+        ("Expiring within 30 days", "Verval binne 30 dae"),
+        ("Explain why this requisition is needed...", "Verduidelik waarom hierdie rekwisisie nodig is..."),
+        ("Explain why this specific supplier is required", "Verduidelik waarom hierdie spesifieke verskaffer nodig is"),
+        ("Export", "Voer uit"),
+        ("Export Comparison", "Voer vergelyking uit"),
+        ("Export DTI Report", "Voer DTI-verslag uit"),
+        ("Export Excel", "Voer Excel uit"),
+        ("Export PDF", "Voer PDF uit"),
+        ("Export Report", "Voer verslag uit"),
+        ("Extension", "Verlenging"),
+        ("Extracted Fields", "Onttrekte velde"),
+        ("Extreme", "Uiterste"),
+        ("Extreme/High", "Uiterste/Hoog"),
+        ("FY 2025/26", "BJ 2025/26"),
+        ("Facilities", "Fasiliteite"),
+        ("Facilities Management", "Fasiliteitsbestuur"),
+        ("Feature Usage", "Kenmerkgebruik"),
+        ("Featured", "Uitgelig"),
+        ("Features", "Kenmerke"),
+        ("Fields to check", "Velde om na te gaan"),
+        ("Filter", "Filtreer"),
+        ("Finalize Evaluation", "Finaliseer evaluering"),
+        ("Finance", "Finansies"),
+        ("Financial", "Finansieel"),
+        ("Financial Impact", "Finansiële impak"),
+        ("Financial Score", "Finansiële telling"),
+        ("Financial Year", "Boekjaar"),
+        ("Finding", "Bevinding"),
+        ("Finding Statistics", "Bevindingstatistiek"),
+        ("Findings", "Bevindinge"),
+        ("Findings by Severity", "Bevindinge volgens erns"),
+        ("Fiscal Year", "Boekjaar"),
+        ("Fleet Management", "Vlootbestuur"),
+        ("Fleet Services", "Vlootdienste"),
+        ("Folders", "Vouers"),
+        ("Framework Agreement", "Raamwerkooreenkoms"),
+        ("Fraud", "Bedrog"),
+        ("Free State", "Vrystaat"),
+        ("From", "Van"),
+        ("From budget", "Van begroting"),
+        ("Full Justification", "Volledige regverdiging"),
+        ("Functionality Threshold", "Funksionaliteitsdrempel"),
+        ("Functionality Threshold (%)", "Funksionaliteitsdrempel (%)"),
+        ("Funds are committed against this cost centre when approved", "Fondse word teen hierdie kostesentrum verbind wanneer goedgekeur"),
+        ("Furniture", "Meubels"),
+        ("Furniture & Fittings", "Meubels en toebehore"),
+        ("GR Number", "GO-nommer"),
+        ("Gauteng", "Gauteng"),
+        ("General", "Algemeen"),
+        ("Generate", "Genereer"),
+        ("Generic", "Generies"),
+        ("Goods", "Goedere"),
+        ("Goods Receipt {id}", "Goedereontvangs {id}"),
+        ("Governance, Risk Management, and Compliance Overview", "Oorsig van bestuur, risikobestuur en nakoming"),
+        ("Governing Law", "Toepaslike reg"),
+        ("Grid", "Rooster"),
+        ("Hand your approvals to a colleague while you are out of office", "Gee jou goedkeurings aan 'n kollega terwyl jy van kantoor af weg is"),
+        ("Health Services", "Gesondheidsdienste"),
+        ("High Risk", "Hoë risiko"),
+        ("High/Critical Risk", "Hoë/kritieke risiko"),
+        ("Home", "Tuis"),
+        ("How can I help you today?", "Hoe kan ek jou vandag help?"),
+        ("Human Resources", "Menslike hulpbronne"),
+        ("I can assist with tender analysis, compliance checks, bid evaluation, and procurement guidance.", "Ek kan help met tenderontleding, nakomingskontroles, bodevaluering en verkrygingsleiding."),
+        ("IP Address", "IP-adres"),
+        ("IT Equipment", "IT-toerusting"),
+        ("IT Services", "IT-dienste"),
+        ("Image", "Beeld"),
+        ("Import", "Voer in"),
+        ("Import CSV", "Voer CSV in"),
+        ("In Stock", "In voorraad"),
+        ("In stock only", "Slegs in voorraad"),
+        ("Inactive", "Onaktief"),
+        ("Include Briefing Session", "Sluit inligtingsessie in"),
+        ("Include SLA Terms", "Sluit SLA-voorwaardes in"),
+        ("Include: reasons why competitive bidding is not possible, value for money considerations, risk assessment", "Sluit in: redes waarom mededingende bieding nie moontlik is nie, oorwegings van waarde vir geld, risiko-assessering"),
+        ("Including all milestones", "Insluitend alle mylpale"),
+        ("Increasing", "Stygend"),
+        ("Indicator Status", "Aanwyserstatus"),
+        ("Ineffective", "Ondoeltreffend"),
+        ("Info", "Inligting"),
+        ("Info Req", "Inligting versoek"),
+        ("Information Technology", "Inligtingstegnologie"),
+        ("Inherent", "Inherent"),
+        ("Insignificant", "Onbeduidend"),
+        ("Inspection", "Inspeksie"),
+        ("Inspection failed. Rejected items must be returned to the supplier.", "Inspeksie het misluk. Afgekeurde items moet aan die verskaffer teruggestuur word."),
+        ("Inspection:", "Inspeksie:"),
+        ("Internal Controls ({count} total)", "Interne kontroles ({count} totaal)"),
+        ("Internal Notes", "Interne notas"),
+        ("Internal Portal", "Interne portaal"),
+        ("Internal notes (not visible to supplier)", "Interne notas (nie sigbaar vir verskaffer nie)"),
+        ("Invoice", "Faktuur"),
+        ("Invoice Date", "Faktuurdatum"),
+        ("Invoice Details", "Faktuurbesonderhede"),
+        ("Invoice Lines", "Faktuurlyne"),
+        ("Invoice Register", "Faktuurregister"),
+        ("Invoice Total", "Faktuurtotaal"),
+        ("Invoice, supplier or order", "Faktuur, verskaffer of bestelling"),
+        ("Invoiced", "Gefaktureer"),
+        ("Invoiced Price", "Gefaktureerde prys"),
+        ("Invoiced against accepted", "Gefaktureer teenoor aanvaar"),
+        ("Invoiced against ordered", "Gefaktureer teenoor bestel"),
+        ("Invoices", "Fakture"),
+        ("Item", "Item"),
+        ("Item #{number}", "Item #{number}"),
+        ("Item Code", "Itemkode"),
+        ("Item Details", "Itembesonderhede"),
+        ("Item Name", "Itemnaam"),
+        ("Item description", "Itembeskrywing"),
+        ("Items", "Items"),
+        ("Items ({count} found)", "Items ({count} gevind)"),
+        ("Items Received", "Items ontvang"),
+        ("Its invoices return to the approved list for another run", "Sy fakture keer terug na die goedgekeurde lys vir 'n volgende lopie"),
+        ("Justification", "Regverdiging"),
+        ("Kit", "Stel"),
+        ("KwaZulu-Natal", "KwaZulu-Natal"),
+        ("LIVE", "REGSTREEKS"),
+        ("Last Active", "Laas aktief"),
+        ("Last Paid", "Laas betaal"),
+        ("Last Test", "Laaste toets"),
+        ("Last bid: {date}", "Laaste bod: {date}"),
+        ("Leave at zero to delegate any amount", "Laat op nul om enige bedrag te delegeer"),
+        ("Level 1", "Vlak 1"),
+        ("Level 1-2", "Vlak 1-2"),
+        ("Level 1-2 Spend", "Vlak 1-2-besteding"),
+        ("Level 1-3", "Vlak 1-3"),
+        ("Level 1-4", "Vlak 1-4"),
+        ("Level 2", "Vlak 2"),
+        ("Level 3", "Vlak 3"),
+        ("Level 3-4", "Vlak 3-4"),
+        ("Level 4+", "Vlak 4+"),
+        ("Level 5-8", "Vlak 5-8"),
+        ("Level Distribution", "Vlakverspreiding"),
+        ("Level {level}", "Vlak {level}"),
+        ("License", "Lisensie"),
+        ("Light", "Lig"),
+        ("Likely", "Waarskynlik"),
+        ("Limpopo", "Limpopo"),
+        ("Line Items", "Lynitems"),
+        ("Line Items ({count})", "Lynitems ({count})"),
+        ("Line Items Summary", "Opsomming van lynitems"),
+        ("Lines add up to {lines}, not the subtotal of {subtotal}", "Lyne tel op tot {lines}, nie die subtotaal van {subtotal} nie"),
+        ("Lines without a supplier go out for quotation.", "Lyne sonder 'n verskaffer gaan uit vir kwotasie."),
+        ("Link to existing contract (optional)", "Koppel aan bestaande kontrak (opsioneel)"),
+        ("Link to requisition (optional)", "Koppel aan rekwisisie (opsioneel)"),
+        ("Linked Tender ID", "Gekoppelde tender-ID"),
+        ("List", "Lys"),
+        ("Live", "Regstreeks"),
+        ("Load Order", "Laai bestelling"),
+        ("Loading auctions...", "Laai veilings..."),
+        ("Loading audit entries...", "Laai ouditinskrywings..."),
+        ("Loading catalogue...", "Laai katalogus..."),
+        ("Loading compliance data...", "Laai nakomingsdata..."),
+        ("Loading contract...", "Laai kontrak..."),
+        ("Loading contracts...", "Laai kontrakte..."),
+        ("Loading controls data...", "Laai kontroledata..."),
+        ("Loading document...", "Laai dokument..."),
+        ("Loading documents...", "Laai dokumente..."),
+        ("Loading finding...", "Laai bevinding..."),
+        ("Loading goods receipt...", "Laai goedereontvangs..."),
+        ("Loading goods receipts...", "Laai goedereontvangstes..."),
+        ("Loading invoice...", "Laai faktuur..."),
+        ("Loading items...", "Laai items..."),
+        ("Loading level data...", "Laai vlakdata..."),
+        ("Loading metrics...", "Laai maatstawwe..."),
+        ("Loading payment run...", "Laai betalingslopie..."),
+        ("Loading performance data...", "Laai prestasiedata..."),
+        ("Loading purchase order...", "Laai bestelling..."),
+        ("Loading purchase orders...", "Laai bestellings..."),
+        ("Loading requisition...", "Laai rekwisisie..."),
+        ("Loading review...", "Laai hersiening..."),
+        ("Loading risk data...", "Laai risikodata..."),
+        ("Loading supplier...", "Laai verskaffer..."),
+        ("Loading suppliers...", "Laai verskaffers..."),
+        ("Loading targets...", "Laai teikens..."),
+        ("Loading tenders...", "Laai tenders..."),
+        ("Loading violations data...", "Laai oortredingsdata..."),
+        ("Loading...", "Laai..."),
+        ("Local %", "Plaaslik %"),
+        ("Locked", "Gesluit"),
+        ("Locked by {user}", "Gesluit deur {user}"),
+        ("Login", "Aanmelding"),
+        ("Login Events", "Aanmeldgebeure"),
+        ("Logout", "Afmelding"),
+        ("Low Risk", "Lae risiko"),
+        ("Major", "Groot"),
+        ("Manage and monitor registered suppliers", "Bestuur en monitor geregistreerde verskaffers"),
+        ("Manage and organize procurement documents", "Bestuur en orden verkrygingsdokumente"),
+        ("Manage live reverse auctions for competitive bidding", "Bestuur regstreekse omgekeerde veilings vir mededingende bieding"),
+        ("Manage procurement tenders and bids", "Bestuur verkrygingstenders en bod"),
+        ("Manage purchase orders and track deliveries", "Bestuur bestellings en volg aflewerings"),
+        ("Manage purchase requisitions and approvals", "Bestuur aankooprekwisisies en goedkeurings"),
+        ("Manage supplier contracts and agreements", "Bestuur verskafferkontrakte en -ooreenkomste"),
+        ("Manage your tenders, bids, and contracts", "Bestuur jou tenders, bod en kontrakte"),
+        ("Management Response", "Bestuursreaksie"),
+        ("Mandatory Requirements", "Verpligte vereistes"),
+        ("Mark All Read", "Merk alles as gelees"),
+        ("Mark Complete", "Merk as voltooi"),
+        ("Market Analysis", "Markontleding"),
+        ("Market Research", "Marknavorsing"),
+        ("Match", "Pas"),
+        ("Match Tolerances", "Pastoleransies"),
+        ("Match tolerances saved", "Pastoleransies gestoor"),
+        ("Matched", "Gepas"),
+        ("Material", "Wesenlik"),
+        ("Max", "Maks."),
+        ("Max Offline Duration", "Maks. vanlynduur"),
+        ("Maximum file size: 50MB", "Maksimum lêergrootte: 50 MB"),
+        ("Maximum per transfer:", "Maksimum per oordrag:"),
+        ("Maximum time to acknowledge an issue", "Maksimum tyd om 'n kwessie te erken"),
+        ("Maximum time to resolve an issue", "Maksimum tyd om 'n kwessie op te los"),
+        ("Medical", "Medies"),
+        ("Medium Risk", "Medium risiko"),
+        ("Meeting No.", "Vergadering nr."),
+        ("Meeting Schedule", "Vergaderskedule"),
+        ("Metric", "Maatstaf"),
+        ("Milestone", "Mylpaal"),
+        ("Milestone Details", "Mylpaalbesonderhede"),
+        ("Milestone Name", "Mylpaalnaam"),
+        ("Milestone Timeline", "Mylpaaltydlyn"),
+        ("Milestone name", "Mylpaalnaam"),
+        ("Milestone {id} - Due: {date}", "Mylpaal {id} - Verskuldig: {date}"),
+        ("Milestones: {title}", "Mylpale: {title}"),
+        ("Minimum 3 years experience", "Minstens 3 jaar ondervinding"),
+        ("Minimum Bidding Period", "Minimum biedtydperk"),
+        ("Minimum score to qualify", "Minimum telling om te kwalifiseer"),
+        ("Minor", "Gering"),
+        ("Mitigated", "Versag"),
+        ("Mobile App Users", "Gebruikers van mobiele toepassing"),
+        ("Mobile Bids", "Mobiele bod"),
+        ("Mobile Supplier App", "Mobiele verskaffertoepassing"),
+        ("Moderate", "Matig"),
+        ("Monitor and analyze supplier performance metrics", "Monitor en ontleed verskafferprestasiemaatstawwe"),
+        ("Monitor and manage supplier risk exposure", "Monitor en bestuur verskafferrisiko-blootstelling"),
+        ("Month", "Maand"),
+        ("Monthly Spend Trend", "Maandelikse bestedingsneiging"),
+        ("Mpumalanga", "Mpumalanga"),
+        ("My Bid Submissions", "My bodindienings"),
+        ("My Profile", "My profiel"),
+        ("My Submissions", "My indienings"),
+        ("N/A", "n.v.t."),
+        ("NBAC - Bid Adjudication", "NBAC - Bodbeoordeling"),
+        ("Name", "Naam"),
+        ("Name (A-Z)", "Naam (A-Z)"),
+        ("Name (Z-A)", "Naam (Z-A)"),
+        ("Name of proposed supplier", "Naam van voorgestelde verskaffer"),
+        ("National Bid Adjudication Committee reviews and decisions", "Hersienings en besluite van die Nasionale Bodbeoordelingskomitee"),
+        ("National Treasury Approval", "Goedkeuring deur Nasionale Tesourie"),
+        ("New Assessment", "Nuwe assessering"),
+        ("New Auction", "Nuwe veiling"),
+        ("New Contract", "Nuwe kontrak"),
+        ("New Conversation", "Nuwe gesprek"),
+        ("New Delegation", "Nuwe delegasie"),
+        ("New Purchase Order", "Nuwe bestelling"),
+        ("New Receipt", "Nuwe ontvangs"),
+        ("New Requisition", "Nuwe rekwisisie"),
+        ("New Sourcing Plan", "Nuwe verkrygingsplan"),
+        ("New Tender", "Nuwe tender"),
+        ("New approver", "Nuwe goedkeurder"),
+        ("Next", "Volgende"),
+        ("Next Milestone", "Volgende mylpaal"),
+        ("Next Review", "Volgende hersiening"),
+        ("Next: Deliverables", "Volgende: Lewerbares"),
+        ("Next: Delivery", "Volgende: Aflewering"),
+        ("Next: Line Items", "Volgende: Lynitems"),
+        ("Next: Review", "Volgende: Hersiening"),
+        ("Next: SLA", "Volgende: SLA"),
+        ("Next: Terms", "Volgende: Voorwaardes"),
+        ("No Evaluation Selected", "Geen evaluering gekies nie"),
+        ("No Live Auction", "Geen regstreekse veiling nie"),
+        ("No attachments added", "Geen aanhegsels bygevoeg nie"),
+        ("No auctions found. Create a new auction to get started.", "Geen veilings gevind nie. Skep 'n nuwe veiling om te begin."),
+        ("No audit entries found", "Geen ouditinskrywings gevind nie"),
+        ("No bank details on record", "Geen bankbesonderhede op rekord nie"),
+        ("No bids yet", "Nog geen bod nie"),
+        ("No budget transfers", "Geen begrotingsoordragte nie"),
+        ("No committee notes yet.", "Nog geen komiteenotas nie."),
+        ("No contracts found", "Geen kontrakte gevind nie"),
+        ("No critical alerts at this time", "Tans geen kritieke waarskuwings nie"),
+        ("No delegations", "Geen delegasies nie"),
+        ("No documents found", "Geen dokumente gevind nie"),
+        ("No documents processed", "Geen dokumente verwerk nie"),
+        ("No invoices found", "Geen fakture gevind nie"),
+        ("No invoices need attention", "Geen fakture het aandag nodig nie"),
+        ("No items found", "Geen items gevind nie"),
+        ("No order loaded", "Geen bestelling gelaai nie"),
+        ("No overdue approvals", "Geen agterstallige goedkeurings nie"),
+        ("No payment runs yet", "Nog geen betalingslopies nie"),
+        ("No purchase orders found", "Geen bestellings gevind nie"),
+        ("No risk indicators found.", "Geen risiko-aanwysers gevind nie."),
+        ("No supplier", "Geen verskaffer nie"),
+        ("No supplier is registered under {registration}", "Geen verskaffer is onder {registration} geregistreer nie"),
+        ("No suppliers found. Adjust filters or add a new supplier.", "Geen verskaffers gevind nie. Verstel filters of voeg 'n nuwe verskaffer by."),
+        ("No suppliers match the selected criteria.", "Geen verskaffers voldoen aan die gekose kriteria nie."),
+        ("No tenders found. Create a new tender to get started.", "Geen tenders gevind nie. Skep 'n nuwe tender om te begin."),
+        ("Non-Compliant", "Nie-nakomend"),
+        ("Non-compliant - requires immediate attention", "Nie-nakomend - vereis onmiddellike aandag"),
+        ("North West", "Noordwes"),
+        ("Northern Cape", "Noord-Kaap"),
+        ("Not Tested", "Nie getoets nie"),
+        ("Not matched", "Nie gepas nie"),
+        ("Not ordered", "Nie bestel nie"),
+        ("Not yet submitted for approval", "Nog nie vir goedkeuring ingedien nie"),
+        ("Note: ", "Let wel: "),
+        ("Notes", "Notas"),
+        ("Notes (visible to supplier)", "Notas (sigbaar vir verskaffer)"),
+        ("Nothing paid this financial year", "Niks hierdie boekjaar betaal nie"),
+        ("Notice Period (days)", "Kennisgewingtydperk (dae)"),
+        ("Notify", "Stel in kennis"),
+        ("Notify registered suppliers in this category", "Stel geregistreerde verskaffers in hierdie kategorie in kennis"),
+        ("Observation", "Waarneming"),
+        ("Office Supplies", "Kantoorbenodigdhede"),
+        ("Offline Mode", "Vanlynmodus"),
+        ("On Track", "Op koers"),
+        ("On behalf of", "Namens"),
+        ("Once published, the tender will be visible to all suppliers. Ensure all documents are finalized before publishing.", "Sodra dit gepubliseer is, is die tender vir alle verskaffers sigbaar. Maak seker alle dokumente is gefinaliseer voordat jy publiseer."),
+        ("Open Issues", "Oop kwessies"),
+        ("Open Opportunities", "Oop geleenthede"),
+        ("Open Tenders", "Oop tenders"),
+        ("Open Violations", "Oop oortredings"),
+        ("Open in New Tab", "Maak oop in nuwe oortjie"),
+        ("Open record", "Maak rekord oop"),
+        ("Operating", "Werkend"),
+        ("Operations", "Bedrywighede"),
+        ("Opinion", "Mening"),
+        ("Opportunities", "Geleenthede"),
+        ("Order", "Bestelling"),
+        ("Order Date", "Besteldatum"),
+        ("Order Price", "Bestelprys"),
+        ("Ordered", "Bestel"),
+        ("Orders by Supplier", "Bestellings volgens verskaffer"),
+        ("Organization Name", "Organisasienaam"),
+        ("Other", "Ander"),
+        ("Out", "Uit"),
+        ("Out of Stock", "Uit voorraad"),
+        ("Outstanding", "Uitstaande"),
+        ("Overall", "Algeheel"),
+        ("Overall Progress", "Algehele vordering"),
+        ("Overall confidence", "Algehele vertroue"),
+        ("Overdue", "Agterstallig"),
+        ("Overdue Approvals", "Agterstallige goedkeurings"),
+        ("Overdue Reviews", "Agterstallige hersienings"),
+        ("Overdue by", "Agterstallig met"),
+        ("Overdue only", "Slegs agterstallig"),
+        ("Overview of procurement activities for FY 2025/26", "Oorsig van verkrygingsaktiwiteite vir BJ 2025/26"),
+        ("Owner", "Eienaar"),
+        ("Owner: {owner}", "Eienaar: {owner}"),
+        ("Ownership", "Eienaarskap"),
+        ("PDF, JPEG, PNG, TIFF or WebP, up to", "PDF, JPEG, PNG, TIFF of WebP, tot"),
+        ("PDF, Word, Excel, or images. Max 10MB per file.", "PDF, Word, Excel of beelde. Maks. 10 MB per lêer."),
+        ("PO Number", "Bestellingsnommer"),
+        ("PO Reference", "Bestellingsverwysing"),
+        ("PO Value", "Bestellingswaarde"),
+        ("Pack", "Pak"),
+        ("Paid", "Betaal"),
+        ("Paid This Year", "Hierdie jaar betaal"),
+        ("Paid from", "Betaal uit"),
+        ("Paid: {date}", "Betaal: {date}"),
+        ("Partial", "Gedeeltelik"),
+        ("Partial Compliance", "Gedeeltelike nakoming"),
+        ("Partial Pass", "Gedeeltelik geslaag"),
+        ("Partially", "Gedeeltelik"),
+        ("Partially Delivered", "Gedeeltelik afgelewer"),
+        ("Partially Effective", "Gedeeltelik doeltreffend"),
+        ("Partially Received", "Gedeeltelik ontvang"),
+        ("Participant", "Deelnemer"),
+        ("Passed", "Geslaag"),
+        ("Past Due", "Oor sperdatum"),
+        ("Paying on", "Betaal op"),
+        ("Payment Aging", "Betalingsouderdom"),
+        ("Payment Amount", "Betalingsbedrag"),
+        ("Payment Date", "Betaaldatum"),
+        ("Payment Report", "Betalingsverslag"),
+        ("Payment Run", "Betalingslopie"),
+        ("Payment Runs", "Betalingslopies"),
+        ("Payment Schedule", "Betalingskedule"),
+        ("Payment Terms", "Betalingsvoorwaardes"),
+        ("Payment confirmed by", "Betaling bevestig deur"),
+        ("Payment date", "Betaaldatum"),
+        ("Payment is due", "Betaling is verskuldig"),
+        ("Payment run", "Betalingslopie"),
+        ("Payments Made", "Betalings gemaak"),
+        ("Penalty Clause", "Boeteklousule"),
+        ("Pending Approvals", "Hangende goedkeurings"),
+        ("Pending Delivery:", "Hangende aflewering:"),
+        ("Pending Processing:", "Hangende verwerking:"),
+        ("Pending Receipts ({count})", "Hangende ontvangstes ({count})"),
+        ("Pending Review", "Hangende hersiening"),
+        ("Pending Reviews", "Hangende hersienings"),
+        ("Pending Start", "Wag om te begin"),
+        ("Pending delivery only", "Slegs hangende aflewering"),
+        ("Percentage", "Persentasie"),
+        ("Performance Distribution", "Prestasieverspreiding"),
+        ("Performance by Category", "Prestasie volgens kategorie"),
+        ("Period", "Tydperk"),
+        ("Phone", "Telefoon"),
+        ("Place your first bid to participate", "Plaas jou eerste bod om deel te neem"),
+        ("Plan Details", "Planbesonderhede"),
+        ("Plan ID", "Plan-ID"),
+        ("Plan Title", "Plantitel"),
+        ("Please review all information before saving. Once submitted for approval, changes will require re-approval.", "Gaan asseblief alle inligting na voordat jy stoor. Sodra dit vir goedkeuring ingedien is, vereis veranderinge hergoedkeuring."),
+        ("Please select an evaluation from the list to begin scoring.", "Kies asseblief 'n evaluering uit die lys om met puntetoekenning te begin."),
+        ("Points", "Punte"),
+        ("Policy", "Beleid"),
+        ("Policy Violations ({count} total)", "Beleidsoortredings ({count} totaal)"),
+        ("Portal Link", "Portaalskakel"),
+        ("Portal Notes", "Portaalnotas"),
+        ("Portal Reference", "Portaalverwysing"),
+        ("Possible", "Moontlik"),
+        ("Possible duplicate of", "Moontlike duplikaat van"),
+        ("Possible duplicate of {invoice_id} ({reason})", "Moontlike duplikaat van {invoice_id} ({reason})"),
+        ("Postal Code", "Poskode"),
+        ("Prepare Payment Run", "Berei betalingslopie voor"),
+        ("Prepare Run", "Berei lopie voor"),
+        ("Prepared by", "Voorberei deur"),
+        ("Preventive", "Voorkomend"),
+        ("Preview", "Voorskou"),
+        ("Preview Controls", "Voorskoukontroles"),
+        ("Preview and configure the mobile app for suppliers", "Voorskou en konfigureer die mobiele toepassing vir verskaffers"),
+        ("Preview not available", "Voorskou nie beskikbaar nie"),
+        ("Preview not available for this file type", "Voorskou nie beskikbaar vir hierdie lêertipe nie"),
+        ("Price", "Prys"),
+        ("Price (High to Low)", "Prys (hoog na laag)"),
+        ("Price (Low to High)", "Prys (laag na hoog)"),
+        ("Price Var.", "Prysafw."),
+        ("Price Weight", "Prysgewig"),
+        ("Price Weight (%)", "Prysgewig (%)"),
+        ("Primary Color", "Primêre kleur"),
+        ("Priority", "Prioriteit"),
+        ("Process and track goods received from suppliers", "Verwerk en volg goedere wat van verskaffers ontvang is"),
+        ("Processed", "Verwerk"),
+        ("Processed Documents", "Verwerkte dokumente"),
+        ("Procurement Categories", "Verkrygingskategorieë"),
+        ("Procurement Dashboard", "Verkrygingskontroleskerm"),
+        ("Procurement Details", "Verkrygingsbesonderhede"),
+        ("Procurement Division", "Verkrygingsafdeling"),
+        ("Professional", "Professioneel"),
+        ("Professional Services", "Professionele dienste"),
+        ("Profile", "Profiel"),
+        ("Progress", "Vordering"),
+        ("Projected Level", "Geprojekteerde vlak"),
+        ("Proposed Supplier", "Voorgestelde verskaffer"),
+        ("Provide a detailed description of the procurement plan", "Gee 'n gedetailleerde beskrywing van die verkrygingsplan"),
+        ("Provide detailed justification for deviating from competitive bidding", "Gee gedetailleerde regverdiging vir afwyking van mededingende bieding"),
+        ("Provide detailed justification for the procurement", "Gee gedetailleerde regverdiging vir die verkryging"),
+        ("Province", "Provinsie"),
+        ("Provincial B-BBEE Distribution", "Provinsiale B-BBEE-verspreiding"),
+        ("Provincial Distribution", "Provinsiale verspreiding"),
+        ("Publication Date", "Publikasiedatum"),
+        ("Publication Dates", "Publikasiedatums"),
+        ("Publication Settings", "Publikasie-instellings"),
+        ("Publish Tender", "Publiseer tender"),
+        ("Publish Update", "Publiseer bywerking"),
+        ("Publish internally", "Publiseer intern"),
+        ("Publish tender to e-Tender portal for bidding", "Publiseer tender op die e-Tender-portaal vir bieding"),
+        ("Publish to e-Tender", "Publiseer op e-Tender"),
+        ("Publish to the national e-Tender portal (etenders.gov.za)", "Publiseer op die nasionale e-Tender-portaal (etenders.gov.za)"),
+        ("Purchase Order", "Bestelling"),
+        ("Purchase Order {po_number}", "Bestelling {po_number}"),
+        ("Purchase Orders ({count})", "Bestellings ({count})"),
+        ("Purchase order {po_number} was not found", "Bestelling {po_number} is nie gevind nie"),
+        ("Push Enabled", "Stootkennisgewings aan"),
+        ("Qty", "Hoev."),
+        ("Qty Var.", "Hoev.-afw."),
+        ("Qualified", "Gekwalifiseer"),
+        ("Quality", "Gehalte"),
+        ("Quantity", "Hoeveelheid"),
+        ("Quick Actions", "Vinnige aksies"),
+        ("Quick Bid", "Vinnige bod"),
+        ("Quorum Required", "Kworum vereis"),
+        ("RFP - Request for Proposal", "RFP - Versoek om voorstel"),
+        ("RFQ - Request for Quotation", "RFQ - Versoek om kwotasie"),
+        ("RFT - Request for Tender", "RFT - Versoek om tender"),
+        ("Raised from approved requisition ", "Uitgereik uit goedgekeurde rekwisisie "),
+        ("Rank", "Rang"),
+        ("Rare", "Seldsaam"),
+        ("Rating", "Gradering"),
+        ("Re-run Match", "Pas weer"),
+        ("Read Documents", "Lees dokumente"),
+        ("Read as", "Lees as"),
+        ("Read by", "Gelees deur"),
+        ("Reading...", "Lees..."),
+        ("Reason", "Rede"),
+        ("Reason for reassignment", "Rede vir hertoewysing"),
+        ("Reassign", "Wys opnuut toe"),
+        ("Reassign pending step", "Wys hangende stap opnuut toe"),
+        ("Receipt Details", "Ontvangsbesonderhede"),
+        ("Received", "Ontvang"),
+        ("Received By", "Ontvang deur"),
+        ("Receiver's name", "Ontvanger se naam"),
+        ("Recent Activity", "Onlangse aktiwiteit"),
+        ("Recent Opportunities", "Onlangse geleenthede"),
+        ("Recent Requisitions", "Onlangse rekwisisies"),
+        ("Recently Added", "Onlangs bygevoeg"),
+        ("Recognition", "Erkenning"),
+        ("Recognized Spend", "Erkende besteding"),
+        ("Recommendation", "Aanbeveling"),
+        ("Recommended Supplier", "Aanbevole verskaffer"),
+        ("Recommended Value", "Aanbevole waarde"),
+        ("Recommended documents: Technical Specifications, Terms and Conditions, Evaluation Criteria, Pricing Schedule, Company Profile Template", "Aanbevole dokumente: Tegniese spesifikasies, Bepalings en voorwaardes, Evalueringskriteria, Prysskedule, Maatskappyprofielsjabloon"),
+        ("Record", "Rekord"),
+        ("Record Resolution", "Teken resolusie aan"),
+        ("Ref:", "Verw.:"),
+        ("Reference", "Verwysing"),
+        ("Reference Number", "Verwysingsnommer"),
+        ("Reg. No.", "Reg. nr."),
+        ("Register Invoice", "Registreer faktuur"),
+        ("Register and Match", "Registreer en pas"),
+        ("Register for Briefing", "Registreer vir inligtingsessie"),
+        ("Registered", "Geregistreer"),
+        ("Registered as {name} ({id})", "Geregistreer as {name} ({id})"),
+        ("Registered by", "Geregistreer deur"),
+        ("Registration", "Registrasie"),
+        ("Reject", "Keur af"),
+        ("Related To", "Verwant aan"),
+        ("Remaining", "Oorblywend"),
+        ("Remaining / Unallocated", "Oorblywend / Nie toegeken nie"),
+        ("Remittance", "Betalingsadvies"),
+        ("Remove", "Verwyder"),
+        ("Renewal Terms", "Hernuwingsvoorwaardes"),
+        ("Repeat Finding", "Herhaalde bevinding"),
+        ("Repeat Findings", "Herhaalde bevindinge"),
+        ("Report", "Verslag"),
+        ("Report No.", "Verslag nr."),
+        ("Report Violation", "Rapporteer oortreding"),
+        ("Reputation", "Reputasie"),
+        ("Req ID", "Rekw.-ID"),
+        ("Request Transfer", "Versoek oordrag"),
+        ("Request deviation from competitive bidding process", "Versoek afwyking van die mededingende biedproses"),
+        ("Requested", "Versoek"),
+        ("Requester", "Versoeker"),
+        ("Require Biometric Authentication", "Vereis biometriese verifikasie"),
+        ("Required By", "Benodig teen"),
+        ("Required documents: Supplier quotation, Market research evidence, Supplier B-BBEE certificate, Tax clearance", "Vereiste dokumente: Verskafferkwotasie, Bewys van marknavorsing, Verskaffer se B-BBEE-sertifikaat, Belastingklaring"),
+        ("Required uptime percentage", "Vereiste beskikbaarheidspersentasie"),
+        ("Requires Justification", "Vereis regverdiging"),
+        ("Requisition", "Rekwisisie"),
+        ("Requisition Details", "Rekwisisiebesonderhede"),
+        ("Requisition Reference", "Rekwisisieverwysing"),
+        ("Requisition {id} - {department}", "Rekwisisie {id} - {department}"),
+        ("Reserve threshold at {percent}%", "Reservedrempel by {percent}%"),
+        ("Reserve: {amount}", "Reserve: {amount}"),
+        ("Reset", "Stel terug"),
+        ("Residual", "Oorblywend"),
+        ("Resolution", "Resolusie"),
+        ("Resolution No.", "Resolusie nr."),
+        ("Resolution Rate", "Oplossingskoers"),
+        ("Resolution Time (hours)", "Oplossingstyd (ure)"),
+        ("Resolve Exception", "Los uitsondering op"),
+        ("Resolved", "Opgelos"),
+        ("Response", "Reaksie"),
+        ("Response Time (hours)", "Reaksietyd (ure)"),
+        ("Responsible", "Verantwoordelik"),
+        ("Responsible Party", "Verantwoordelike party"),
+        ("Responsible party", "Verantwoordelike party"),
+        ("Result", "Resultaat"),
+        ("Revenue Summary", "Inkomste-opsomming"),
+        ("Reverse Auctions", "Omgekeerde veilings"),
+        ("Review & Submit", "Hersien en dien in"),
+        ("Review Details", "Hersieningsbesonderhede"),
+        ("Review and score bids for open tenders", "Hersien en ken punte toe aan bod vir oop tenders"),
+        ("Reviews Pending Adjudication", "Hersienings wat op beoordeling wag"),
+        ("Revoke", "Herroep"),
+        ("Risk Distribution", "Risikoverspreiding"),
+        ("Risk Heat Map", "Risiko-hittekaart"),
+        ("Risk Implication", "Risiko-implikasie"),
+        ("Risk Indicator", "Risiko-aanwyser"),
+        ("Risk Indicators ({count} total)", "Risiko-aanwysers ({count} totaal)"),
+        ("Risk Indicators by Category", "Risiko-aanwysers volgens kategorie"),
+        ("Risk Level", "Risikovlak"),
+        ("Risk Rating", "Risikogradering"),
+        ("Risk Register ({count} total)", "Risikoregister ({count} totaal)"),
+        ("Risks", "Risiko's"),
+        ("Root Cause", "Grondoorsaak"),
+        ("Run", "Lopie"),
+        ("Run Assessment", "Voer assessering uit"),
+        ("Runs", "Lopies"),
+        ("Save", "Stoor"),
+        ("Save Changes", "Stoor veranderinge"),
+        ("Save Draft", "Stoor konsep"),
+        ("Save Tolerances", "Stoor toleransies"),
+        ("Save as", "Stoor as"),
+        ("Saved as", "Gestoor as"),
+        ("Saved as Records", "As rekords gestoor"),
+        ("Scan invoices, delivery notes and B-BBEE certificates into draft records", "Skandeer fakture, afleweringsbriewe en B-BBEE-sertifikate na konseprekords"),
+        ("Scanned document", "Geskandeerde dokument"),
+        ("Schedule Meeting", "Skeduleer vergadering"),
+        ("Scope", "Omvang"),
+        ("Scope Description", "Omvangbeskrywing"),
+        ("Scope of Work", "Werksomvang"),
+        ("Score", "Telling"),
+        ("Score: {supplier_name}", "Telling: {supplier_name}"),
+        ("Scorecard Points", "Telkaartpunte"),
+        ("Scoring", "Puntetoekenning"),
+        ("Scoring Method: {method} | Min Technical Score: {score}% | Price Weight: {weight}%", "Puntemetode: {method} | Min. tegniese telling: {score}% | Prysgewig: {weight}%"),
+        ("Scoring Progress", "Vordering met puntetoekenning"),
+        ("Scoring Weights", "Puntegewigte"),
+        ("Screen", "Skerm"),
+        ("Search", "Soek"),
+        ("Search GR, PO, supplier...", "Soek GO, bestelling, verskaffer..."),
+        ("Search POs...", "Soek bestellings..."),
+        ("Search auctions...", "Soek veilings..."),
+        ("Search audit logs...", "Soek ouditlogboeke..."),
+        ("Search by ID, description, requester...", "Soek volgens ID, beskrywing, versoeker..."),
+        ("Search by ID, title, owner...", "Soek volgens ID, titel, eienaar..."),
+        ("Search contracts...", "Soek kontrakte..."),
+        ("Search documents...", "Soek dokumente..."),
+        ("Search items...", "Soek items..."),
+        ("Search supplier...", "Soek verskaffer..."),
+        ("Search suppliers...", "Soek verskaffers..."),
+        ("Search tenders...", "Soek tenders..."),
+        ("Search the catalogue by name, item code or supplier", "Soek in die katalogus volgens naam, itemkode of verskaffer"),
+        ("Search user...", "Soek gebruiker..."),
+        ("Secondary Color", "Sekondêre kleur"),
+        ("Security", "Sekuriteit"),
+        ("Security Services", "Sekuriteitsdienste"),
+        ("Segregation", "Skeiding"),
+        ("Select Category", "Kies kategorie"),
+        ("Select Department", "Kies departement"),
+        ("Select Deviation Type", "Kies afwykingstipe"),
+        ("Select a Bid to Score", "Kies 'n bod om te beoordeel"),
+        ("Select action", "Kies aksie"),
+        ("Select category...", "Kies kategorie..."),
+        ("Select department", "Kies departement"),
+        ("Select province", "Kies provinsie"),
+        ("Select receiving budget", "Kies ontvangende begroting"),
+        ("Select source budget", "Kies bronbegroting"),
+        ("Select supplier", "Kies verskaffer"),
+        ("Select supplier...", "Kies verskaffer..."),
+        ("Select type", "Kies tipe"),
+        ("Select...", "Kies..."),
+        ("Send Notification", "Stuur kennisgewing"),
+        ("Sent to Supplier", "Aan verskaffer gestuur"),
+        ("Service", "Diens"),
+        ("Service Level Agreement", "Diensvlakooreenkoms"),
+        ("Services", "Dienste"),
+        ("Session Timeout", "Sessie-uittelling"),
+        ("Severity", "Erns"),
+        ("Showing {count} of {total} plans", "Wys {count} van {total} planne"),
+        ("Showing {count} of {total} requisitions", "Wys {count} van {total} rekwisisies"),
+        ("Significant", "Beduidend"),
+        ("Signing as", "Teken as"),
+        ("Single Source", "Enkelbron"),
+        ("Single Source - Only one supplier can provide the goods/services", "Enkelbron - Slegs een verskaffer kan die goedere/dienste lewer"),
+        ("Single Source Procurement", "Enkelbronverkryging"),
+        ("Size", "Grootte"),
+        ("Size (Largest)", "Grootte (grootste)"),
+        ("Size (Smallest)", "Grootte (kleinste)"),
+        ("SoD", "PS"),
+        ("Software", "Sagteware"),
+        ("Sole Supplier", "Enigste verskaffer"),
+        ("Sole Supplier - Supplier has exclusive rights (patents, licenses)", "Enigste verskaffer - Verskaffer het eksklusiewe regte (patente, lisensies)"),
+        ("Sort By", "Sorteer volgens"),
+        ("Source", "Bron"),
+        ("Source Requisition", "Bronrekwisisie"),
+        ("Sources:", "Bronne:"),
+        ("Sourcing", "Verkryging"),
+        ("Special delivery instructions, access requirements, etc.", "Spesiale afleweringsinstruksies, toegangsvereistes, ens."),
+        ("Specifications", "Spesifikasies"),
+        ("Spend", "Besteding"),
+        ("Spend Analytics", "Bestedingsanalise"),
+        ("Spend Targets", "Bestedingsteikens"),
+        ("Spend by B-BBEE Level", "Besteding volgens B-BBEE-vlak"),
+        ("Spend by Category", "Besteding volgens kategorie"),
+        ("Stable", "Stabiel"),
+        ("Stage", "Fase"),
+        ("Start Bid", "Beginbod"),
+        ("Start Date", "Begindatum"),
+        ("Start Scoring", "Begin puntetoekenning"),
+        ("Starting Price", "Beginprys"),
+        ("Starting: {amount}", "Begin: {amount}"),
+        ("Status", "Status"),
+        ("Status:", "Status:"),
+        ("Stock", "Voorraad"),
+        ("Strategic Objectives", "Strategiese doelwitte"),
+        ("Strategic Partnership", "Strategiese vennootskap"),
+        ("Strategic Partnership - Existing strategic relationship", "Strategiese vennootskap - Bestaande strategiese verhouding"),
+        ("Street address", "Straatadres"),
+        ("Submit", "Dien in"),
+        ("Submit Action Plan", "Dien aksieplan in"),
+        ("Submit Request", "Dien versoek in"),
+        ("Submit Scores", "Dien tellings in"),
+        ("Submit for Approval", "Dien in vir goedkeuring"),
+        ("Submit for Review", "Dien in vir hersiening"),
+        ("Subtotal", "Subtotaal"),
+        ("Subtotal:", "Subtotaal:"),
+        ("Success Rate", "Suksessyfer"),
+        ("Summary", "Opsomming"),
+        ("Supplier", "Verskaffer"),
+        ("Supplier Details", "Verskafferbesonderhede"),
+        ("Supplier Information", "Verskafferinligting"),
+        ("Supplier Invoice Number", "Verskaffer se faktuurnommer"),
+        ("Supplier Name", "Verskaffernaam"),
+        ("Supplier Performance", "Verskafferprestasie"),
+        ("Supplier Performance Scores ({count} suppliers)", "Verskafferprestasietellings ({count} verskaffers)"),
+        ("Supplier Registry", "Verskafferregister"),
+        ("Supplier Risk Assessment", "Verskafferrisiko-assessering"),
+        ("Supplier blacklisted", "Verskaffer op swartlys"),
+        ("Supplier contact name", "Verskaffer se kontaknaam"),
+        ("Supplier invoices, three-way matching and payment aging", "Verskafferfakture, drieledige passing en betalingsouderdom"),
+        ("Supplier not in the registry", "Verskaffer nie in die register nie"),
+        ("Supplier suspended", "Verskaffer opgeskort"),
+        ("Supplier:", "Verskaffer:"),
+        ("Suppliers ({count} total)", "Verskaffers ({count} totaal)"),
+        ("Suppliers at Risk ({count} total)", "Verskaffers in gevaar ({count} totaal)"),
+        ("Supply Chain", "Voorsieningsketting"),
+        ("Support", "Ondersteuning"),
+        ("Support Email", "Ondersteuning-e-pos"),
+        ("Support Phone", "Ondersteuningstelefoon"),
+        ("Supporting Documents", "Stawende dokumente"),
+        ("Suspended", "Opgeskort"),
+        ("Table", "Tabel"),
+        ("Tags (comma-separated)", "Etikette (kommageskei)"),
+        ("Target", "Teiken"),
+        ("Target Date", "Teikendatum"),
+        ("Tax (15%):", "Belasting (15%):"),
+        ("Tax clearance certificate", "Belastingklaringsertifikaat"),
+        ("Technical", "Tegnies"),
+        ("Technical Requirements", "Tegniese vereistes"),
+        ("Technical Score", "Tegniese telling"),
+        ("Technical Specifications", "Tegniese spesifikasies"),
+        ("Technical requirements and specifications", "Tegniese vereistes en spesifikasies"),
+        ("Template", "Sjabloon"),
+        ("Tender", "Tender"),
+        ("Tender Details", "Tenderbesonderhede"),
+        ("Tender Documents", "Tenderdokumente"),
+        ("Tender Published", "Tender gepubliseer"),
+        ("Tender Ref", "Tenderverw."),
+        ("Tender Summary", "Tenderopsomming"),
+        ("Tender Title", "Tendertitel"),
+        ("Tender Type", "Tendertipe"),
+        ("Tender Value", "Tenderwaarde"),
+        ("Tenders ({count} total)", "Tenders ({count} totaal)"),
+        ("Tenders Pending Evaluation", "Tenders wat op evaluering wag"),
+        ("Terminated", "Beëindig"),
+        ("Termination Clause", "Beëindigingsklousule"),
+        ("Terms", "Voorwaardes"),
+        ("The certificate expired on {expiry}", "Die sertifikaat het op {expiry} verval"),
+        ("The original is only shown in the session it was uploaded in", "Die oorspronklike word slegs gewys in die sessie waarin dit opgelaai is"),
+        ("Theme", "Tema"),
+        ("There is no live auction currently available.", "Daar is tans geen regstreekse veiling beskikbaar nie."),
+        ("Thinking...", "Dink..."),
+        ("This Month", "Hierdie maand"),
+        ("This Quarter", "Hierdie kwartaal"),
+        ("This invoice may duplicate", "Hierdie faktuur kan 'n duplikaat wees van"),
+        ("This tender must be approved before it can be published. Current status: ", "Hierdie tender moet goedgekeur word voordat dit gepubliseer kan word. Huidige status: "),
+        ("Three-Way Match", "Drieledige passing"),
+        ("Threshold", "Drempel"),
+        ("Tick none to delegate every document type", "Merk niks om elke dokumenttipe te delegeer"),
+        ("Time", "Tyd"),
+        ("Time Remaining", "Oorblywende tyd"),
+        ("Timeline", "Tydlyn"),
+        ("Timeline & Milestones", "Tydlyn en mylpale"),
+        ("Timeline ({count} milestones)", "Tydlyn ({count} mylpale)"),
+        ("Timestamp", "Tydstempel"),
+        ("Title", "Titel"),
+        ("To", "Na"),
+        ("To budget", "Na begroting"),
+        ("Today's Activity", "Vandag se aktiwiteit"),
+        ("Top Performers", "Toppresteerders"),
+        ("Top Performers (85%+)", "Toppresteerders (85%+)"),
+        ("Top Performing Suppliers", "Beste verskaffers"),
+        ("Top Suppliers by Spend", "Topverskaffers volgens besteding"),
+        ("Total", "Totaal"),
+        ("Total Active Budget", "Totale aktiewe begroting"),
+        ("Total Allocated", "Totaal toegeken"),
+        ("Total Amount:", "Totale bedrag:"),
+        ("Total App Users", "Totale toepassinggebruikers"),
+        ("Total Approved YTD", "Totaal goedgekeur JTD"),
+        ("Total Budget", "Totale begroting"),
+        ("Total Budget Amount", "Totale begrotingsbedrag"),
+        ("Total Checks", "Totale kontroles"),
+        ("Total Contract Value", "Totale kontrakwaarde"),
+        ("Total Contracts:", "Totale kontrakte:"),
+        ("Total Controls", "Totale kontroles"),
+        ("Total Documents", "Totale dokumente"),
+        ("Total Entries", "Totale inskrywings"),
+        ("Total Evaluations", "Totale evaluerings"),
+        ("Total Findings", "Totale bevindinge"),
+        ("Total Functionality Weight: ", "Totale funksionaliteitsgewig: "),
+        ("Total Items", "Totale items"),
+        ("Total Milestones", "Totale mylpale"),
+        ("Total POs:", "Totale bestellings:"),
+        ("Total Reviews", "Totale hersienings"),
+        ("Total Risks", "Totale risiko's"),
+        ("Total Score", "Totale telling"),
+        ("Total Spend", "Totale besteding"),
+        ("Total Storage", "Totale berging"),
+        ("Total Suppliers", "Totale verskaffers"),
+        ("Total Value", "Totale waarde"),
+        ("Total Value:", "Totale waarde:"),
+        ("Total Violations", "Totale oortredings"),
+        ("Total budget for this sourcing plan", "Totale begroting vir hierdie verkrygingsplan"),
+        ("Total of {total} should be {expected}", "Totaal van {total} moet {expected} wees"),
+        ("Total:", "Totaal:"),
+        ("Track all system activities and changes", "Volg alle stelselaktiwiteite en veranderinge"),
+        ("Training", "Opleiding"),
+        ("Transfer", "Oordrag"),
+        ("Transfers", "Oordragte"),
+        ("Transport", "Vervoer"),
+        ("Treasury", "Tesourie"),
+        ("Trend", "Neiging"),
+        ("Try adjusting your filters or add a new item", "Probeer jou filters verstel of voeg 'n nuwe item by"),
+        ("Try adjusting your filters or create a new contract", "Probeer jou filters verstel of skep 'n nuwe kontrak"),
+        ("Try adjusting your filters or create a new purchase order", "Probeer jou filters verstel of skep 'n nuwe bestelling"),
+        ("Try adjusting your filters to see more results", "Probeer jou filters verstel om meer resultate te sien"),
+        ("Try adjusting your search or filters", "Probeer jou soektog of filters verstel"),
+        ("Type", "Tipe"),
+        ("Tyre", "Band"),
+        ("Unanimous", "Eenparig"),
+        ("Under Review", "Word hersien"),
+        ("Underperforming", "Onderpresteer"),
+        ("Underperforming (<70%)", "Onderpresteer (<70%)"),
+        ("Unit", "Eenheid"),
+        ("Unit Price", "Eenheidsprys"),
+        ("Unit Price (ZAR)", "Eenheidsprys (ZAR)"),
+        ("Unknown", "Onbekend"),
+        ("Unknown document", "Onbekende dokument"),
+        ("Unlikely", "Onwaarskynlik"),
+        ("Unqualified w/Findings", "Ongekwalifiseer met bevindinge"),
+        ("Until", "Tot"),
+        ("Upcoming Deadlines", "Komende sperdatums"),
+        ("Upcoming Meetings", "Komende vergaderings"),
+        ("Upcoming Milestones", "Komende mylpale"),
+        ("Update", "Werk by"),
+        ("Updated", "Bygewerk"),
+        ("Upload", "Laai op"),
+        ("Upload Document", "Laai dokument op"),
+        ("Upload Documents", "Laai dokumente op"),
+        ("Upload Supporting Documents", "Laai stawende dokumente op"),
+        ("Upload a scanned document to get started", "Laai 'n geskandeerde dokument op om te begin"),
+        ("Upload at most", "Laai hoogstens op"),
+        ("Upload documents or adjust your filters", "Laai dokumente op of verstel jou filters"),
+        ("Upload quotations, market research, supplier documentation", "Laai kwotasies, marknavorsing en verskafferdokumentasie op"),
+        ("Upload specifications, terms, and other tender documents (PDF, Word, Excel)", "Laai spesifikasies, voorwaardes en ander tenderdokumente op (PDF, Word, Excel)"),
+        ("Upload supporting documents such as specifications, quotes, or budget approvals.", "Laai stawende dokumente soos spesifikasies, kwotasies of begrotingsgoedkeurings op."),
+        ("Uploaded By", "Opgelaai deur"),
+        ("Uploaded Documents", "Opgelaaide dokumente"),
+        ("User", "Gebruiker"),
+        ("User ID", "Gebruiker-ID"),
+        ("User Information", "Gebruikersinligting"),
+        ("Users", "Gebruikers"),
+        ("Utilisation", "Benutting"),
+        ("VAT", "BTW"),
+        ("VAT (15%):", "BTW (15%):"),
+        ("VAT:", "BTW:"),
+        ("Valid B-BBEE certificate", "Geldige B-BBEE-sertifikaat"),
+        ("Valid until {expiry}", "Geldig tot {expiry}"),
+        ("Value", "Waarde"),
+        ("Value:", "Waarde:"),
+        ("Variance", "Afwyking"),
+        ("Variation", "Variasie"),
+        ("Venue", "Lokaal"),
+        ("Verified", "Geverifieer"),
+        ("Version", "Weergawe"),
+        ("Version History", "Weergawegeskiedenis"),
+        ("View", "Bekyk"),
+        ("View All", "Bekyk alles"),
+        ("View All Auctions", "Bekyk alle veilings"),
+        ("View Contract", "Bekyk kontrak"),
+        ("View Details", "Bekyk besonderhede"),
+        ("View List", "Bekyk lys"),
+        ("View invoice", "Bekyk faktuur"),
+        ("View on e-Tender Portal", "Bekyk op e-Tender-portaal"),
+        ("Violations", "Oortredings"),
+        ("Violations by Type", "Oortredings volgens tipe"),
+        ("Virements between budget lines for FY", "Virements tussen begrotingslyne vir BJ"),
+        ("Votes", "Stemme"),
+        ("Waived", "Kwytgeskeld"),
+        ("Warehouse", "Pakhuis"),
+        ("Warning: Category allocations exceed total budget by ", "Waarskuwing: Kategorietoekennings oorskry die totale begroting met "),
+        ("Warranty Period (months)", "Waarborgtydperk (maande)"),
+        ("Weight %", "Gewig %"),
+        ("Weight: {weight}%", "Gewig: {weight}%"),
+        ("Western Cape", "Wes-Kaap"),
+        ("What alternatives were considered? Why were they not suitable?", "Watter alternatiewe is oorweeg? Waarom was hulle nie geskik nie?"),
+        ("Why This Supplier", "Waarom hierdie verskaffer"),
+        ("Why the funds are needed and why the source budget can release them", "Waarom die fondse nodig is en waarom die bronbegroting dit kan vrystel"),
+        ("Why the run is being cancelled", "Waarom die lopie gekanselleer word"),
+        ("Why the variance is acceptable, or what the supplier must correct", "Waarom die afwyking aanvaarbaar is, of wat die verskaffer moet regstel"),
+        ("Within SLA", "Binne SLA"),
+        ("Within tolerance", "Binne toleransie"),
+        ("Word", "Word"),
+        ("Works", "Werke"),
+        ("YTD Revenue", "Inkomste JTD"),
+        ("YTD Spend", "Besteding JTD"),
+        ("You are offline. Showing the data saved on this device.", "Jy is vanlyn. Die data wat op hierdie toestel gestoor is, word gewys."),
+        ("You are the leading bidder!", "Jy is die voorste bieër!"),
+        ("You have been outbid. Your last bid: R {bid}", "Jy is oorbie. Jou laaste bod: R {bid}"),
+        ("You have unsaved changes", "Jy het ongestoorde veranderinge"),
+        ("Your role does not have permission to view this page. Contact your system administrator if you need access.", "Jou rol het nie toestemming om hierdie bladsy te sien nie. Kontak jou stelseladministrateur as jy toegang nodig het."),
+        ("all must approve", "almal moet goedkeur"),
+        ("another user", "'n ander gebruiker"),
+        ("are past the", "is verby die"),
+        ("at a time", "op 'n slag"),
+        ("available", "beskikbaar"),
+        ("business day(s)", "werksdag(e)"),
+        ("day payment term", "dag-betalingstermyn"),
+        ("days after the invoice is received", "dae nadat die faktuur ontvang is"),
+        ("days overdue", "dae agterstallig"),
+        ("detected automatically", "outomaties bespeur"),
+        ("documents at a time", "dokumente op 'n slag"),
+        ("e-Tender Portal", "e-Tender-portaal"),
+        ("e.g. Annual leave", "bv. Jaarlikse verlof"),
+        ("e.g. important, q1, project-x", "bv. belangrik, k1, projek-x"),
+        ("e.g., 12", "bv. 12"),
+        ("e.g., 24", "bv. 24"),
+        ("e.g., 30", "bv. 30"),
+        ("e.g., 30 days from invoice", "bv. 30 dae vanaf faktuur"),
+        ("e.g., 36 months", "bv. 36 maande"),
+        ("e.g., 4", "bv. 4"),
+        ("e.g., 99.9", "bv. 99,9"),
+        ("e.g., Annual Procurement Plan FY 2025/26", "bv. Jaarlikse verkrygingsplan BJ 2025/26"),
+        ("e.g., Arbitration", "bv. Arbitrasie"),
+        ("e.g., CC-IT-001", "bv. CC-IT-001"),
+        ("e.g., CTR-2025-0001", "bv. CTR-2025-0001"),
+        ("e.g., Each, Months", "bv. Elk, Maande"),
+        ("e.g., Head Office, Johannesburg", "bv. Hoofkantoor, Johannesburg"),
+        ("e.g., IT-LAP-001", "bv. IT-LAP-001"),
+        ("e.g., PFMA/2025/IT/001", "bv. PFMA/2025/IT/001"),
+        ("e.g., REQ-2025-0001", "bv. REQ-2025-0001"),
+        ("e.g., South African Law", "bv. Suid-Afrikaanse reg"),
+        ("e.g., TND-2025-0001", "bv. TND-2025-0001"),
+        ("e.g., Technical Capability", "bv. Tegniese vermoë"),
+        ("each", "elk"),
+        ("excluded", "uitgesluit"),
+        ("field(s) to check", "veld(e) om na te gaan"),
+        ("held as a possible duplicate", "teruggehou as moontlike duplikaat"),
+        ("invoiced", "gefaktureer"),
+        ("invoices", "fakture"),
+        ("more authorisation(s) needed from someone other than", "meer magtiging(s) nodig van iemand anders as"),
+        ("of", "van"),
+        ("page(s)", "bladsy(e)"),
+        ("submitted for approval:", "vir goedkeuring ingedien:"),
+        ("suppliers", "verskaffers"),
+        ("type chosen on upload", "tipe gekies met oplaai"),
+        ("until", "tot"),
+        ("up to", "tot"),
+        ("{entity} not found", "{entity} nie gevind nie"),
+        ("{entity} {id} could not be loaded: {error}", "{entity} {id} kon nie gelaai word nie: {error}"),
+        ("{entity} {id} does not exist or you no longer have access to it.", "{entity} {id} bestaan nie of jy het nie meer toegang daartoe nie."),
+        ("\"{level}\" is not a level from 1 to 8", "\"{level}\" is nie 'n vlak van 1 tot 8 nie"),
+        ("(empty)", "(leeg)"),
+        ("31-60 days", "31-60 dae"),
+        ("61-90 days", "61-90 dae"),
+        ("90+ days", "90+ dae"),
+        ("Bulk EFT (fixed width)", "Grootmaat-EFT (vaste breedte)"),
+        ("AI Analysis", "KI-ontleding"),
+        ("AI {category} Suggestions", "KI-voorstelle: {category}"),
+        ("Abstain", "Buite stemming bly"),
+        ("Accept", "Aanvaar"),
+        ("Accept Variance", "Aanvaar afwyking"),
+        ("Actual: {value}", "Werklik: {value}"),
+        ("Ad Hoc", "Ad hoc"),
+        ("Adverse Opinion", "Ongunstige mening"),
+        ("Advisory Member", "Raadgewende lid"),
+        ("Against", "Teen"),
+        ("All Receipts", "Alle ontvangste"),
+        ("All incoming deliveries have been processed", "Alle inkomende aflewerings is verwerk"),
+        ("Analyze {entity} {id} for completeness and compliance.", "Ontleed {entity} {id} vir volledigheid en nakoming."),
+        ("Android", "Android"),
+        ("Android Phone", "Android-foon"),
+        ("Android Tablet", "Android-tablet"),
+        ("Annexure", "Bylae"),
+        ("Annually", "Jaarliks"),
+        ("Anti-Corruption", "Teenkorrupsie"),
+        ("Approval due", "Goedkeuring verskuldig"),
+        ("Approval escalated", "Goedkeuring geëskaleer"),
+        ("Asset Management", "Batebestuur"),
+        ("Auto-extend: Auction extends by {minutes} minutes if bid placed in last {seconds} seconds", "Outomatiese verlenging: die veiling word met {minutes} minute verleng as 'n bod in die laaste {seconds} sekondes gemaak word"),
+        ("Avoid", "Vermy"),
+        ("Awaiting Signature", "Wag op handtekening"),
+        ("Award Letter", "Toekenningsbrief"),
+        ("B-BBEE Certificate", "B-BBEE-sertifikaat"),
+        ("B-BBEE Supplier Classifications ({count} total)", "B-BBEE-verskafferklassifikasies ({count} in totaal)"),
+        ("B-BBEE Verification", "B-BBEE-verifikasie"),
+        ("B-BBEE level", "B-BBEE-vlak"),
+        ("Below Target", "Onder teiken"),
+        ("Bid Document", "Boddokument"),
+        ("Bid Management", "Bodbestuur"),
+        ("Bid Status Alerts", "Bodstatuswaarskuwings"),
+        ("Bid Submission", "Bodindiening"),
+        ("Bid Submitted", "Bod ingedien"),
+        ("Biometric Authentication", "Biometriese verifikasie"),
+        ("Black Owned (51%+)", "Swart besit (51%+)"),
+        ("Black Women Owned (30%+)", "Swart vroue-besit (30%+)"),
+        ("Briefing Check-In", "Inligtingsessie-aanmelding"),
+        ("Budget Transfer", "Begrotingsoordrag"),
+        ("Budgets, commitments and spend for FY {year}", "Begrotings, verbintenisse en besteding vir BJ {year}"),
+        ("CSV", "CSV"),
+        ("Capital Expenditure", "Kapitaalbesteding"),
+        ("Certificate expiry", "Sertifikaatvervaldatum"),
+        ("Certificate period", "Sertifikaattydperk"),
+        ("Chairperson", "Voorsitter"),
+        ("Check in to mandatory briefing sessions using GPS location", "Meld by verpligte inligtingsessies aan met GPS-ligging"),
+        ("Closing Soon", "Sluit binnekort"),
+        ("Closure", "Afsluiting"),
+        ("Company Registration", "Maatskappyregistrasie"),
+        ("Completed Receipts", "Voltooide ontvangste"),
+        ("Compliance Report", "Nakomingsverslag"),
+        ("Comprehensive", "Omvattend"),
+        ("Concern", "Kommer"),
+        ("Confirm deliveries with photo evidence and timestamps", "Bevestig aflewerings met fotobewyse en tydstempels"),
+        ("Conflict of Interest", "Botsing van belange"),
+        ("Continuous", "Deurlopend"),
+        ("Contract Detail", "Kontrakbesonderhede"),
+        ("Contract Extension", "Kontrakverlenging"),
+        ("Contract Reminders", "Kontrakherinneringe"),
+        ("Contract Review", "Kontrakhersiening"),
+        ("Contract Variation", "Kontrakwysiging"),
+        ("Create a new procurement tender", "Skep 'n nuwe verkrygingstender"),
+        ("Create a new purchase order", "Skep 'n nuwe bestelling"),
+        ("Create a new supplier contract", "Skep 'n nuwe verskafferkontrak"),
+        ("Current (0-30 days)", "Huidig (0-30 dae)"),
+        ("Current: {value}", "Huidig: {value}"),
+        ("Cybersecurity", "Kuberveiligheid"),
+        ("Daily", "Daagliks"),
+        ("Deadline: {date}", "Sperdatum: {date}"),
+        ("Deferred", "Uitgestel"),
+        ("Delayed", "Vertraag"),
+        ("Delivery Confirmation", "Afleweringsbevestiging"),
+        ("Delivery date", "Afleweringsdatum"),
+        ("Deputy Chairperson", "Ondervoorsitter"),
+        ("Designated Group", "Aangewese groep"),
+        ("Detailed", "Gedetailleerd"),
+        ("Deviation request {reference} created successfully. It will be routed for approval.", "Afwykingsversoek {reference} is suksesvol geskep. Dit sal vir goedkeuring gestuur word."),
+        ("Digital Signature", "Digitale handtekening"),
+        ("Disability Owned", "Besit deur persone met gestremdhede"),
+        ("Disclaimer of Opinion", "Vrywaring van mening"),
+        ("Document Scanner", "Dokumentskandeerder"),
+        ("Document Scanning", "Dokumentskandering"),
+        ("Document type", "Dokumenttipe"),
+        ("Due date", "Vervaldatum"),
+        ("Duplicate invoice", "Duplikaatfaktuur"),
+        ("EME (< R10m)", "EME (< R10m)"),
+        ("Edit Contract", "Wysig kontrak"),
+        ("Edit Purchase Order", "Wysig bestelling"),
+        ("Edit Requisition", "Wysig rekwisisie"),
+        ("Edit Tender", "Wysig tender"),
+        ("Enterprise & Supplier Development", "Onderneming- en verskafferontwikkeling"),
+        ("Enterprise Development", "Ondernemingsontwikkeling"),
+        ("Environmental", "Omgewing"),
+        ("Escalate", "Eskaleer"),
+        ("Evaluation Report", "Evalueringsverslag"),
+        ("Events", "Geleenthede"),
+        ("Exceeded", "Oorskry"),
+        ("Exempted Micro Enterprise", "Vrygestelde mikro-onderneming"),
+        ("Experience", "Ervaring"),
+        ("Fail", "Druip"),
+        ("Finance Representative", "Finansiesverteenwoordiger"),
+        ("Financial Analysis", "Finansiële ontleding"),
+        ("Financial Proposal", "Finansiële voorstel"),
+        ("Financial Statements", "Finansiële state"),
+        ("Follow-up", "Opvolg"),
+        ("Follow-up Audit", "Opvolgoudit"),
+        ("For", "Voor"),
+        ("Format", "Formaat"),
+        ("Fraud & Irregularities", "Bedrog en onreëlmatighede"),
+        ("Full Match", "Volle passing"),
+        ("Fully Automated", "Volledig outomaties"),
+        ("Generic (> R50m)", "Generies (> R50m)"),
+        ("Generic Enterprise", "Generiese onderneming"),
+        ("Get instant alerts when your bid status changes during evaluation", "Kry onmiddellike waarskuwings wanneer jou bodstatus tydens evaluering verander"),
+        ("Governance", "Bestuur"),
+        ("Hold for Investigation", "Hou vir ondersoek"),
+        ("IP: {address}", "IP: {address}"),
+        ("IT", "IT"),
+        ("IT & Technology", "IT en tegnologie"),
+        ("Identified exposure", "Geïdentifiseerde blootstelling"),
+        ("Implemented", "Geïmplementeer"),
+        ("In Review", "In hersiening"),
+        ("Info Required", "Inligting benodig"),
+        ("Initial Finding", "Aanvanklike bevinding"),
+        ("Interim Audit", "Tussentydse oudit"),
+        ("Internal Controls", "Interne kontroles"),
+        ("Internal Policy", "Interne beleid"),
+        ("Investigate", "Ondersoek"),
+        ("Invoice date", "Faktuurdatum"),
+        ("Item on order", "Item op bestelling"),
+        ("Labour Law", "Arbeidsreg"),
+        ("Last 12 Months", "Laaste 12 maande"),
+        ("Legal", "Regs"),
+        ("Legal Advisor", "Regsadviseur"),
+        ("Legal Opinion", "Regsmening"),
+        ("Level 4", "Vlak 4"),
+        ("Level 5", "Vlak 5"),
+        ("Level 6", "Vlak 6"),
+        ("Level 7", "Vlak 7"),
+        ("Level 8", "Vlak 8"),
+        ("Line items", "Lynitems"),
+        ("Live Auction", "Lewendige veiling"),
+        ("Local Content", "Plaaslike inhoud"),
+        ("MFMA", "MFMA"),
+        ("Maintenance & Repairs", "Onderhoud en herstelwerk"),
+        ("Management Control", "Bestuursbeheer"),
+        ("Manual", "Handmatig"),
+        ("Military Veteran Owned", "Besit deur militêre veterane"),
+        ("Min Decrement: {amount}", "Min. verlaging: {amount}"),
+        ("Mobile Invoicing", "Mobiele fakturering"),
+        ("Monitoring", "Monitering"),
+        ("Monthly", "Maandeliks"),
+        ("Most Popular", "Gewildste"),
+        ("Motivation Memo", "Motiveringsmemo"),
+        ("NT Regulations", "NT-regulasies"),
+        ("No CSD number", "Geen SSD-nommer nie"),
+        ("No Match", "Geen passing nie"),
+        ("No completed receipts", "Geen voltooide ontvangste nie"),
+        ("No goods receipts found", "Geen goedere-ontvangste gevind nie"),
+        ("No pending receipts", "Geen hangende ontvangste nie"),
+        ("No receipts have been completed yet", "Geen ontvangste is nog voltooi nie"),
+        ("Not Matched", "Nie gepas nie"),
+        ("Not Operating", "Werk nie"),
+        ("Not Started", "Nie begin nie"),
+        ("Not required (value below R10,000,000)", "Nie nodig nie (waarde onder R10 000 000)"),
+        ("Not required (value below R2,000,000)", "Nie nodig nie (waarde onder R2 000 000)"),
+        ("Not required (value below R500,000)", "Nie nodig nie (waarde onder R500 000)"),
+        ("Notified", "In kennis gestel"),
+        ("Offline Bid Preparation", "Vanlyn bodvoorbereiding"),
+        ("Operational", "Operasioneel"),
+        ("Operational Expenditure", "Bedryfsuitgawes"),
+        ("Ordinary", "Gewoon"),
+        ("PDF", "PDF"),
+        ("PFMA", "WOFB"),
+        ("PO exists", "Bestelling bestaan"),
+        ("POPIA", "WBPI"),
+        ("PPPFA", "WVVRB"),
+        ("Partial Match", "Gedeeltelike passing"),
+        ("Partially Compliant", "Gedeeltelik nakomend"),
+        ("Partially Operating", "Werk gedeeltelik"),
+        ("Participate in reverse auctions in real-time from your mobile device", "Neem intyds aan omgekeerde veilings deel vanaf jou mobiele toestel"),
+        ("Pass", "Slaag"),
+        ("Pending Action", "Wag op aksie"),
+        ("Pending Audit", "Wag op oudit"),
+        ("Pending Receipts", "Hangende ontvangste"),
+        ("Performance Audit", "Prestasie-oudit"),
+        ("Performance Information", "Prestasie-inligting"),
+        ("Personnel Costs", "Personeelkoste"),
+        ("Place Bid", "Plaas bod"),
+        ("Placing Bid...", "Plaas bod..."),
+        ("Planned", "Beplan"),
+        ("Please enter a title", "Voer asseblief 'n titel in"),
+        ("Please enter a valid amount", "Voer asseblief 'n geldige bedrag in"),
+        ("Please enter supplier name", "Voer asseblief die verskaffer se naam in"),
+        ("Please provide justification", "Verskaf asseblief 'n regverdiging"),
+        ("Please select a closing date", "Kies asseblief 'n sluitingsdatum"),
+        ("Please select a publication date", "Kies asseblief 'n publikasiedatum"),
+        ("Postponed", "Uitgestel"),
+        ("Preferential Procurement", "Voorkeurverkryging"),
+        ("Prepare bid documents offline and sync when connected", "Berei boddokumente vanlyn voor en sinkroniseer wanneer jy gekoppel is"),
+        ("Preview: {name}", "Voorskou: {name}"),
+        ("Priced from the catalogue", "Geprys uit die katalogus"),
+        ("Procedural", "Prosedureel"),
+        ("Process", "Verwerk"),
+        ("Process Recommendation", "Prosesaanbeveling"),
+        ("Publish to Portal", "Publiseer op portaal"),
+        ("Publishing...", "Publiseer..."),
+        ("QR Code Scanning", "QR-kodeskandering"),
+        ("QSE (R10m - R50m)", "QSE (R10m - R50m)"),
+        ("Qualified Opinion", "Gekwalifiseerde mening"),
+        ("Qualifying Small Enterprise", "Kwalifiserende klein onderneming"),
+        ("Quantity outstanding", "Uitstaande hoeveelheid"),
+        ("Quarterly", "Kwartaalliks"),
+        ("Receive push notifications when new tenders matching your profile are published", "Ontvang stootkennisgewings wanneer nuwe tenders wat by jou profiel pas, gepubliseer word"),
+        ("Recurring", "Herhalend"),
+        ("Recused", "Onttrek"),
+        ("Reduce", "Verminder"),
+        ("Registration number format", "Registrasienommerformaat"),
+        ("Regularity Audit", "Reëlmatigheidsoudit"),
+        ("Regulatory", "Regulatories"),
+        ("Regulatory Guidance", "Regulatoriese leiding"),
+        ("Reject Invoice", "Verwerp faktuur"),
+        ("Reminders for upcoming contract milestones, renewals, and deliverables", "Herinneringe vir komende kontrakmylpale, hernuwings en leweringe"),
+        ("Reputational", "Reputasie"),
+        ("Request Credit Note", "Versoek kredietnota"),
+        ("Request Information", "Versoek inligting"),
+        ("Required field", "Verpligte veld"),
+        ("Required for values above R10,000,000", "Nodig vir waardes bo R10 000 000"),
+        ("Required for values above R2,000,000", "Nodig vir waardes bo R2 000 000"),
+        ("Required for values above R500,000", "Nodig vir waardes bo R500 000"),
+        ("Required to ratify emergency procurement", "Nodig om noodverkryging te bekragtig"),
+        ("Retired", "Uitgedien"),
+        ("Return for Info", "Stuur terug vir inligting"),
+        ("Returned for Info", "Teruggestuur vir inligting"),
+        ("Review Required", "Hersiening nodig"),
+        ("Risk Assessment", "Risiko-assessering"),
+        ("Rural", "Landelik"),
+        ("Rural/Township Based", "Landelik/in 'n township gebaseer"),
+        ("SCM Regulations", "VKB-regulasies"),
+        ("Save Configuration", "Stoor konfigurasie"),
+        ("Save Features", "Stoor funksies"),
+        ("Saving...", "Stoor..."),
+        ("Scan QR codes on purchase orders for quick goods receipt", "Skandeer QR-kodes op bestellings vir vinnige goedere-ontvangs"),
+        ("Scan and upload documents directly from your device camera", "Skandeer en laai dokumente direk vanaf jou toestel se kamera op"),
+        ("Secretary", "Sekretaris"),
+        ("Segregation of Duties", "Skeiding van pligte"),
+        ("Semi-Automated", "Semi-outomaties"),
+        ("Shortlisted", "Op kortlys"),
+        ("Sign contracts electronically using your mobile device", "Teken kontrakte elektronies met jou mobiele toestel"),
+        ("Skills Development", "Vaardigheidsontwikkeling"),
+        ("Skipped", "Oorgeslaan"),
+        ("Socio-Economic Development", "Sosio-ekonomiese ontwikkeling"),
+        ("Sourcing Plan", "Verkrygingsplan"),
+        ("Special", "Spesiaal"),
+        ("Special Audit", "Spesiale oudit"),
+        ("Spend Analysis", "Bestedingsontleding"),
+        ("Standard", "Standaard"),
+        ("Strategic", "Strategies"),
+        ("Submit and track invoices directly from the mobile app", "Dien fakture direk vanaf die mobiele toep in en volg hulle"),
+        ("Submitting...", "Dien in..."),
+        ("Supplier Development", "Verskafferontwikkeling"),
+        ("Supplier exists", "Verskaffer bestaan"),
+        ("Supplier matches PO", "Verskaffer stem ooreen met bestelling"),
+        ("Supply Chain Management", "Voorsieningskanaalbestuur"),
+        ("Sworn Affidavit", "Beëdigde verklaring"),
+        ("Target: {value}", "Teiken: {value}"),
+        ("Tax Clearance", "Belastingklaring"),
+        ("Technical Advisor", "Tegniese adviseur"),
+        ("Technical Proposal", "Tegniese voorstel"),
+        ("Technical Report", "Tegniese verslag"),
+        ("Tender Analysis", "Tenderontleding"),
+        ("Tender Detail", "Tenderbesonderhede"),
+        ("Tender Document Analysis", "Tenderdokument-ontleding"),
+        ("Tender Notifications", "Tenderkennisgewings"),
+        ("Tender Specification", "Tenderspesifikasie"),
+        ("Tender successfully published to e-Tender portal. Reference: {reference}", "Tender suksesvol op die e-Tender-portaal gepubliseer. Verwysing: {reference}"),
+        ("Terms & Conditions", "Bepalings en voorwaardes"),
+        ("Text", "Teks"),
+        ("The delivery is dated in the future", "Die aflewering is in die toekoms gedateer"),
+        ("The document type could not be determined", "Die dokumenttipe kon nie bepaal word nie"),
+        ("The due date is not after the invoice date", "Die vervaldatum is nie ná die faktuurdatum nie"),
+        ("The invoice is dated in the future", "Die faktuur is in die toekoms gedateer"),
+        ("This month", "Hierdie maand"),
+        ("Threshold Breach", "Drempeloortreding"),
+        ("Total calculation", "Totaalberekening"),
+        ("Total is the subtotal plus VAT", "Die totaal is die subtotaal plus BTW"),
+        ("Transaction", "Transaksie"),
+        ("Travel & Subsistence", "Reis en verblyf"),
+        ("Try adjusting your filters or create a new goods receipt", "Probeer jou filters aanpas of skep 'n nuwe goedere-ontvangs"),
+        ("Type (A-Z)", "Tipe (A-Z)"),
+        ("Type (Z-A)", "Tipe (Z-A)"),
+        ("Under Evaluation", "Word geëvalueer"),
+        ("Under Investigation", "Word ondersoek"),
+        ("Unqualified with Findings", "Ongekwalifiseerd met bevindings"),
+        ("Unsuccessful", "Onsuksesvol"),
+        ("Unverified", "Ongeverifieer"),
+        ("Update Needed", "Bywerking nodig"),
+        ("Update contract details", "Werk kontrakbesonderhede by"),
+        ("Update purchase order details", "Werk bestellingbesonderhede by"),
+        ("Update tender details", "Werk tenderbesonderhede by"),
+        ("Use fingerprint or face recognition for secure authentication", "Gebruik vingerafdruk- of gesigherkenning vir veilige verifikasie"),
+        ("VAT calculation", "BTW-berekening"),
+        ("VAT is {rate}% of the subtotal", "BTW is {rate}% van die subtotaal"),
+        ("VAT number format", "BTW-nommerformaat"),
+        ("VAT of {vat} should be {expected}", "BTW van {vat} behoort {expected} te wees"),
+        ("Verification", "Verifikasie"),
+        ("Verify", "Verifieer"),
+        ("Voting Member", "Stemgeregtigde lid"),
+        ("Warning", "Waarskuwing"),
+        ("Weekly", "Weekliks"),
+        ("Withdrawn", "Onttrek"),
+        ("You", "Jy"),
+        ("Youth", "Jeug"),
+        ("Youth Owned", "Jeugbesit"),
+        ("achieved", "bereik"),
+        ("assessed", "geassesseer"),
+        ("bonus", "bonus"),
+        ("draft goods receipt", "konsep-goedere-ontvangs"),
+        ("draft invoice", "konsepfaktuur"),
+        ("faster than target", "vinniger as teiken"),
+        ("from last month", "sedert verlede maand"),
+        ("from last quarter", "sedert verlede kwartaal"),
+        ("from last year", "sedert verlede jaar"),
+        ("iOS", "iOS"),
+        ("iPad", "iPad"),
+        ("iPhone", "iPhone"),
+        ("in last 30 days", "in die laaste 30 dae"),
+        ("new this month", "nuut hierdie maand"),
+        ("non-compliant", "nie-nakomend"),
+        ("of budget", "van begroting"),
+        ("of total spend", "van totale besteding"),
+        ("of users", "van gebruikers"),
+        ("of {points} points", "uit {points} punte"),
+        ("remaining", "oor"),
+        ("require attention", "verg aandag"),
+        ("same amount within 7 days", "selfde bedrag binne 7 dae"),
+        ("same invoice number", "selfde faktuurnommer"),
+        ("similar invoice number", "soortgelyke faktuurnommer"),
+        ("supplier certificate", "verskaffersertifikaat"),
+        ("target", "teiken"),
+        ("total value", "totale waarde"),
+        ("under budget", "onder begroting"),
+        ("vs budget", "teenoor begroting"),
+        ("{change} was not applied: {reason}", "{change} is nie toegepas nie: {reason}"),
+        ("{completed}/{planned} tenders", "{completed}/{planned} tenders"),
+        ("{count} open risk indicators - Risk Score: {score}", "{count} oop risiko-aanwysers - risikotelling: {score}"),
+        ("{date} at {time}", "{date} om {time}"),
+        ("{date} by {name}", "{date} deur {name}"),
+        ("{days} days", "{days} dae"),
+        ("{days} days (per Treasury Regulations)", "{days} dae (volgens Tesourieregulasies)"),
+        ("{days} days (per Treasury Regulations); closes no earlier than {date}", "{days} dae (volgens Tesourieregulasies); sluit nie vroeër as {date} nie"),
+        ("{done} of {total} milestones", "{done} van {total} mylpale"),
+        ("{field} \"{value}\" is not a valid value", "{field} \"{value}\" is nie 'n geldige waarde nie"),
+        ("{field} was not found", "{field} is nie gevind nie"),
+        ("{item} is not on {po_number}", "{item} is nie op {po_number} nie"),
+        ("{name} ({code}) at {percent} utilisation", "{name} ({code}) op {percent} benutting"),
+        ("{name} ({code}) exceeds its budget by {amount}", "{name} ({code}) oorskry sy begroting met {amount}"),
+        ("{name} is in the supplier registry", "{name} is in die verskafferregister"),
+        ("{name} is not in the supplier registry", "{name} is nie in die verskafferregister nie"),
+        ("{number} has not been registered", "{number} is nog nie geregistreer nie"),
+        ("{number} is a valid CIPC number", "{number} is 'n geldige CIPC-nommer"),
+        ("{number} is a valid VAT number", "{number} is 'n geldige BTW-nommer"),
+        ("{number} is already registered as {invoice_id}", "{number} is reeds as {invoice_id} geregistreer"),
+        ("{number} is not a 10-digit VAT number starting with 4", "{number} is nie 'n 10-syfer-BTW-nommer wat met 4 begin nie"),
+        ("{number} is not in the form YYYY/NNNNNN/NN", "{number} is nie in die vorm JJJJ/NNNNNN/NN nie"),
+        ("{percent} approval rate", "{percent} goedkeuringskoers"),
+        ("{percent} of contract", "{percent} van kontrak"),
+        ("{percent} of total", "{percent} van totaal"),
+        ("{percent} saved", "{percent} bespaar"),
+        ("{po_number} issued to {supplier}", "{po_number} uitgereik aan {supplier}"),
+        ("{po_number} was issued to {supplier}, not {scanned}", "{po_number} is aan {supplier} uitgereik, nie aan {scanned} nie"),
+        ("{quantity} {item} delivered but only {outstanding} outstanding on {po_number}", "{quantity} {item} afgelewer, maar slegs {outstanding} uitstaande op {po_number}"),
+        ("{quantity}+ at {price}", "{quantity}+ teen {price}"),
+        ("{reference} - Tender {tender}", "{reference} - Tender {tender}"),
+        ("{start} to {end}", "{start} tot {end}"),
+        ("{supplier} - ordered {date}", "{supplier} - bestel op {date}"),
+        ("A conversion started by {name} on {date} did not finish. Resuming it reuses anything it already created.", "'n Omskakeling wat {name} op {date} begin het, is nie voltooi nie. As jy dit hervat, word enigiets wat reeds geskep is, hergebruik."),
+        ("Resume Conversion", "Hervat omskakeling"),
+        ("No extraction engine configured", "Geen uittrekenjin opgestel nie"),
+        ("Scanned documents cannot be read until an OCR service is connected. Capture them by hand for now.", "Geskandeerde dokumente kan nie gelees word voordat 'n OCR-diens gekoppel is nie. Vang hulle vir eers met die hand vas."),
+        ("The application stopped after an unexpected error. Reload to continue; changes not yet saved on this page may be lost.", "Die toepassing het ná 'n onverwagte fout gestop. Herlaai om voort te gaan; veranderinge wat nog nie op hierdie bladsy gestoor is nie, kan verlore gaan."),
+        ("Reload", "Herlaai"),
+        ("This page failed to load. The rest of the application is unaffected.", "Hierdie bladsy kon nie laai nie. Die res van die toepassing werk steeds."),
+        ("Enter an amount such as {example}", "Voer 'n bedrag soos {example} in"),
+        ("Apply SLAs now", "Pas SLA's nou toe"),
+    ],
+    plurals: &[
+        ("{n} unread notification", ["{n} ongeleeste kennisgewing", "{n} ongeleeste kennisgewings"]),
+        ("{n} day", ["{n} dag", "{n} dae"]),
+        ("{n} minute", ["{n} minuut", "{n} minute"]),
+        ("{n} hour", ["{n} uur", "{n} uur"]),
+        ("Back online. Sending {n} queued change...", ["Weer aanlyn. Stuur {n} verandering in die tou...", "Weer aanlyn. Stuur {n} veranderinge in die tou..."]),
+        ("Catalogue lines split into {n} purchase order, one per supplier.", ["Kataloguslyne in {n} bestelling verdeel, een per verskaffer.", "Kataloguslyne in {n} bestellings verdeel, een per verskaffer."]),
+        ("Repeat finding, outstanding for {n} year", ["Herhaalde bevinding, uitstaande vir {n} jaar", "Herhaalde bevinding, uitstaande vir {n} jaar"]),
+        ("You are offline. {n} change saved on this device will be sent when the connection returns.", ["Jy is vanlyn. {n} verandering op hierdie toestel gestoor, sal gestuur word wanneer die verbinding terugkeer.", "Jy is vanlyn. {n} veranderinge op hierdie toestel gestoor, sal gestuur word wanneer die verbinding terugkeer."]),
+        ("You have {n} unread notification", ["Jy het {n} ongeleese kennisgewing", "Jy het {n} ongeleese kennisgewings"]),
+        ("All items are catalogue items under contract: {n} purchase order can be raised directly.", ["Alle items is katalogusitems onder kontrak: {n} bestelling kan direk geplaas word.", "Alle items is katalogusitems onder kontrak: {n} bestellings kan direk geplaas word."]),
+        ("{n} active", ["{n} aktief", "{n} aktief"]),
+        ("{n} bidder", ["{n} bieër", "{n} bieërs"]),
+        ("{n} change", ["{n} verandering", "{n} veranderinge"]),
+        ("{n} check", ["{n} kontrole", "{n} kontroles"]),
+        ("{n} closing soon", ["{n} sluit binnekort", "{n} sluit binnekort"]),
+        ("{n} completed", ["{n} voltooi", "{n} voltooi"]),
+        ("{n} contract expiring within 30 days. Review and initiate renewal process.", ["{n} kontrak verval binne 30 dae. Hersien en begin die hernuwingsproses.", "{n} kontrakte verval binne 30 dae. Hersien en begin die hernuwingsproses."]),
+        ("{n} criterion defined", ["{n} maatstaf gedefinieer", "{n} maatstawwe gedefinieer"]),
+        ("{n} critical", ["{n} kritiek", "{n} kritiek"]),
+        ("{n} critical, attention needed", ["{n} kritiek, aandag nodig", "{n} kritiek, aandag nodig"]),
+        ("{n} day left", ["{n} dag oor", "{n} dae oor"]),
+        ("{n} document", ["{n} dokument", "{n} dokumente"]),
+        ("{n} expiring in 30 days", ["{n} verval binne 30 dae", "{n} verval binne 30 dae"]),
+        ("{n} field changed", ["{n} veld verander", "{n} velde verander"]),
+        ("{n} file", ["{n} lêer", "{n} lêers"]),
+        ("{n} high/extreme", ["{n} hoog/uiterste", "{n} hoog/uiterste"]),
+        ("{n} ineffective", ["{n} ondoeltreffend", "{n} ondoeltreffend"]),
+        ("{n} issue", ["{n} kwessie", "{n} kwessies"]),
+        ("{n} item", ["{n} item", "{n} items"]),
+        ("{n} item pending approval. Review and approve to make it available.", ["{n} item wag op goedkeuring. Hersien en keur goed om dit beskikbaar te stel.", "{n} items wag op goedkeuring. Hersien en keur goed om hulle beskikbaar te stel."]),
+        ("{n} line", ["{n} lyn", "{n} lyne"]),
+        ("{n} material", ["{n} wesenlik", "{n} wesenlik"]),
+        ("{n} milestone is overdue. Please review and take action.", ["{n} mylpaal is agterstallig. Hersien asseblief en tree op.", "{n} mylpale is agterstallig. Hersien asseblief en tree op."]),
+        ("{n} overdue", ["{n} agterstallig", "{n} agterstallig"]),
+        ("{n} plan in total", ["{n} plan in totaal", "{n} planne in totaal"]),
+        ("{n} purchase order pending delivery. Track and confirm deliveries.", ["{n} bestelling wag op aflewering. Volg en bevestig aflewerings.", "{n} bestellings wag op aflewering. Volg en bevestig aflewerings."]),
+        ("{n} receipt pending inspection. Complete quality checks before acceptance.", ["{n} ontvangs wag op inspeksie. Voltooi gehaltekontroles voor aanvaarding.", "{n} ontvangste wag op inspeksie. Voltooi gehaltekontroles voor aanvaarding."]),
+        ("{n} supplier", ["{n} verskaffer", "{n} verskaffers"]),
+        ("{n} this month", ["{n} hierdie maand", "{n} hierdie maand"]),
+        ("{n} under evaluation", ["{n} word geëvalueer", "{n} word geëvalueer"]),
+        ("{n} urgent", ["{n} dringend", "{n} dringend"]),
+        ("{n} urgent (>5 days)", ["{n} dringend (>5 dae)", "{n} dringend (>5 dae)"]),
+        ("{n} user", ["{n} gebruiker", "{n} gebruikers"]),
+        ("{n} requisition updated", ["{n} versoek bygewerk", "{n} versoeke bygewerk"]),
+    ],
+};
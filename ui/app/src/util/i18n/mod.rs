@@ -0,0 +1,413 @@
+//! Message catalogues and runtime locale switching
+//!
+//! Messages are keyed by their English text, gettext style, so a string with
+//! no translation falls back to English and call sites stay readable:
+//! `t("Pending Approval")`. Plural messages are keyed by their singular form
+//! and pick a form with the locale's plural rule. The locale lives in a
+//! signal, so every view that looked up a message re-renders when the user
+//! switches language.
+
+mod af;
+mod st;
+mod xh;
+mod zu;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use components::prelude::*;
+
+/// Supported interface languages
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    English,
+    Afrikaans,
+    IsiZulu,
+    IsiXhosa,
+    Sesotho,
+}
+
+impl Locale {
+    /// BCP 47 language code
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Afrikaans => "af",
+            Locale::IsiZulu => "zu",
+            Locale::IsiXhosa => "xh",
+            Locale::Sesotho => "st",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Locale::all().into_iter().find(|l| l.code() == code)
+    }
+
+    /// Language name in the language itself, for the picker
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::Afrikaans => "Afrikaans",
+            Locale::IsiZulu => "isiZulu",
+            Locale::IsiXhosa => "isiXhosa",
+            Locale::Sesotho => "Sesotho",
+        }
+    }
+
+    pub fn all() -> Vec<Locale> {
+        vec![
+            Locale::English,
+            Locale::Afrikaans,
+            Locale::IsiZulu,
+            Locale::IsiXhosa,
+            Locale::Sesotho,
+        ]
+    }
+
+    /// Thousands separator used by `format_number` and currency
+    pub fn group_separator(&self) -> &'static str {
+        match self {
+            Locale::English | Locale::IsiZulu => ",",
+            // Narrow no-break space, as CLDR uses for these locales
+            Locale::Afrikaans | Locale::IsiXhosa | Locale::Sesotho => "\u{202f}",
+        }
+    }
+
+    pub fn decimal_separator(&self) -> &'static str {
+        match self {
+            Locale::Afrikaans => ",",
+            _ => ".",
+        }
+    }
+
+    /// Abbreviated month names, January first
+    pub fn month_abbreviations(&self) -> [&'static str; 12] {
+        match self {
+            Locale::English => ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+            Locale::Afrikaans => ["Jan", "Feb", "Mrt", "Apr", "Mei", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"],
+            Locale::IsiZulu => ["Jan", "Feb", "Mas", "Epr", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"],
+            Locale::IsiXhosa => ["Jan", "Feb", "Mat", "Epr", "Mey", "Jun", "Jul", "Aga", "Sep", "Okt", "Nov", "Dis"],
+            Locale::Sesotho => ["Phe", "Kol", "Ube", "Mme", "Mot", "Jan", "Upu", "Pha", "Leo", "Mph", "Pun", "Tsh"],
+        }
+    }
+
+    /// Plural form index for `n`: 0 is "one", 1 is "other"
+    fn plural_form(&self, n: i64) -> usize {
+        let one = match self {
+            // CLDR: isiZulu treats zero like one
+            Locale::IsiZulu => n == 0 || n == 1,
+            _ => n == 1,
+        };
+        if one { 0 } else { 1 }
+    }
+
+    fn catalogue(&self) -> Option<&'static Catalogue> {
+        match self {
+            Locale::English => None,
+            Locale::Afrikaans => Some(&af::CATALOGUE),
+            Locale::IsiZulu => Some(&zu::CATALOGUE),
+            Locale::IsiXhosa => Some(&xh::CATALOGUE),
+            Locale::Sesotho => Some(&st::CATALOGUE),
+        }
+    }
+}
+
+/// Translations for one locale
+pub struct Catalogue {
+    /// English message to translation
+    pub messages: &'static [(&'static str, &'static str)],
+    /// English singular to the translated one and other forms
+    pub plurals: &'static [(&'static str, [&'static str; 2])],
+}
+
+/// A catalogue keyed for lookup. The first entry for a message wins.
+struct Index {
+    messages: HashMap<&'static str, &'static str>,
+    plurals: HashMap<&'static str, [&'static str; 2]>,
+}
+
+impl Index {
+    fn new(catalogue: &'static Catalogue) -> Self {
+        let mut messages = HashMap::with_capacity(catalogue.messages.len());
+        for (en, translated) in catalogue.messages {
+            messages.entry(*en).or_insert(*translated);
+        }
+        let mut plurals = HashMap::with_capacity(catalogue.plurals.len());
+        for (en, forms) in catalogue.plurals {
+            plurals.entry(*en).or_insert(*forms);
+        }
+        Self { messages, plurals }
+    }
+}
+
+thread_local! {
+    /// Installed by the app; read by `t` from views and label methods alike
+    static LOCALE: RefCell<Option<Signal<Locale>>> = RefCell::new(None);
+    /// Built the first time each locale is looked up; `t` runs for every label on every render
+    static INDEXES: RefCell<HashMap<Locale, Rc<Index>>> = RefCell::new(HashMap::new());
+}
+
+/// Lookup index for `locale`; None for English, which has no catalogue
+fn index(locale: Locale) -> Option<Rc<Index>> {
+    let catalogue = locale.catalogue()?;
+    Some(INDEXES.with(|indexes| {
+        indexes
+            .borrow_mut()
+            .entry(locale)
+            .or_insert_with(|| Rc::new(Index::new(catalogue)))
+            .clone()
+    }))
+}
+
+/// localStorage key prefix; the signed-in user's id is appended
+const STORAGE_PREFIX: &str = "eprocurement.locale.";
+
+/// Register the application's locale signal
+pub fn install(locale: Signal<Locale>) {
+    LOCALE.with(|l| *l.borrow_mut() = Some(locale));
+}
+
+/// Current locale; English until one has been installed
+pub fn current() -> Locale {
+    LOCALE.with(|l| l.borrow().as_ref().map(|s| s.get()).unwrap_or_default())
+}
+
+/// Switch language and remember the choice for `user_id`
+pub fn set_locale(locale: Locale, user_id: &str) {
+    // Saved first: effects that re-run on the change restore this choice
+    if let Some(Ok(Some(storage))) = web_sys::window().map(|w| w.local_storage()) {
+        let _ = storage.set_item(&format!("{}{}", STORAGE_PREFIX, user_id), locale.code());
+    }
+    set_document_lang(locale);
+    if let Some(signal) = LOCALE.with(|l| l.borrow().clone()) {
+        signal.set(locale);
+    }
+}
+
+/// Apply the language `user_id` last chose, if any
+pub fn restore_locale(user_id: &str) {
+    let saved = web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(&format!("{}{}", STORAGE_PREFIX, user_id)).ok().flatten())
+        .and_then(|code| Locale::from_code(&code));
+    if let (Some(locale), Some(signal)) = (saved, LOCALE.with(|l| l.borrow().clone())) {
+        if signal.get() != locale {
+            signal.set(locale);
+        }
+        set_document_lang(locale);
+    }
+}
+
+fn set_document_lang(locale: Locale) {
+    if let Some(root) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.document_element())
+    {
+        let _ = root.set_attribute("lang", locale.code());
+    }
+}
+
+/// Translate `message` into the current locale
+pub fn t(message: &'static str) -> &'static str {
+    index(current())
+        .and_then(|index| index.messages.get(message).copied())
+        .unwrap_or(message)
+}
+
+/// Translate `message` and fill its `{name}` placeholders
+pub fn tf(message: &'static str, args: &[(&str, String)]) -> String {
+    fill(t(message), args)
+}
+
+/// Translate a counted message; `{n}` is replaced with the formatted count
+pub fn tn(one: &'static str, other: &'static str, n: i64) -> String {
+    let locale = current();
+    let form = locale.plural_form(n);
+    let template = index(locale)
+        .and_then(|index| index.plurals.get(one).map(|forms| forms[form]))
+        .unwrap_or(if form == 0 { one } else { other });
+    fill(template, &[("n", super::format::format_number(n))])
+}
+
+fn fill(template: &str, args: &[(&str, String)]) -> String {
+    let mut out = template.to_string();
+    for (name, value) in args {
+        out = out.replace(&format!("{{{}}}", name), value);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+
+    /// Every `t`, `tf` and `tn` literal under `src`, as (file, key, is_plural)
+    fn used_keys() -> Vec<(String, String, bool)> {
+        let mut keys = Vec::new();
+        collect(Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src")), &mut keys);
+        keys
+    }
+
+    fn collect(dir: &Path, keys: &mut Vec<(String, String, bool)>) {
+        for entry in fs::read_dir(dir).unwrap().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                // The catalogues themselves are not call sites
+                if !path.ends_with("util/i18n") {
+                    collect(&path, keys);
+                }
+            } else if path.extension().is_some_and(|e| e == "rs") {
+                let source = fs::read_to_string(&path).unwrap();
+                for (key, plural) in scan(&source) {
+                    keys.push((path.display().to_string(), key, plural));
+                }
+            }
+        }
+    }
+
+    /// First string literal argument of each translation call in `source`
+    fn scan(source: &str) -> Vec<(String, bool)> {
+        let bytes = source.as_bytes();
+        let mut found = Vec::new();
+        for (call, plural) in [("t(", false), ("tf(", false), ("tn(", true)] {
+            let mut from = 0;
+            while let Some(at) = source[from..].find(call) {
+                let start = from + at;
+                from = start + call.len();
+                let preceded = start > 0 && {
+                    let c = bytes[start - 1];
+                    c.is_ascii_alphanumeric() || c == b'_' || c == b'.'
+                };
+                if preceded {
+                    continue;
+                }
+                let rest = source[from..].trim_start();
+                if let Some(literal) = rest.strip_prefix('"') {
+                    found.push((unescape(literal), plural));
+                } else if rest.starts_with("match ") {
+                    found.extend(match_arms(rest).into_iter().map(|key| (key, plural)));
+                }
+            }
+        }
+        found
+    }
+
+    /// String literals returned by the arms of a `t(match ... { ... })` label
+    fn match_arms(source: &str) -> Vec<String> {
+        let Some(open) = source.find('{') else { return Vec::new() };
+        let mut depth = 0;
+        let mut end = source.len();
+        for (i, c) in source[open..].char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = open + i;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        source[open..end]
+            .split("=>")
+            .skip(1)
+            .filter_map(|arm| arm.trim_start().strip_prefix('"').map(unescape))
+            .collect()
+    }
+
+    /// Body of a string literal up to its closing quote
+    fn unescape(literal: &str) -> String {
+        let mut out = String::new();
+        let mut chars = literal.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => break,
+                '\\' => match chars.next() {
+                    Some('n') => out.push('\n'),
+                    Some(other) => out.push(other),
+                    None => break,
+                },
+                _ => out.push(c),
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn scanner_skips_methods_and_longer_names() {
+        let found = scan(r#"t("A") x.t("B") fmt("C") tf( "D {x}", &[]) tn("E {n}", "Es", 2) t("Say \"hi\"")"#);
+        let keys: Vec<&str> = found.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, ["A", "Say \"hi\"", "D {x}", "E {n}"]);
+        assert!(found.iter().any(|(k, plural)| k == "E {n}" && *plural));
+        let arms = scan(r#"t(match self { S::A => "Alpha", S::B { .. } => "Beta" }) "Gamma""#);
+        assert_eq!(arms, [("Alpha".to_string(), false), ("Beta".to_string(), false)]);
+    }
+
+    #[test]
+    fn every_used_key_is_in_every_catalogue() {
+        let keys = used_keys();
+        assert!(keys.len() > 1000, "scanner found only {} keys", keys.len());
+        for locale in Locale::all() {
+            let Some(catalogue) = locale.catalogue() else { continue };
+            let missing: Vec<String> = keys
+                .iter()
+                .filter(|(_, key, plural)| {
+                    if *plural {
+                        !catalogue.plurals.iter().any(|(en, _)| en == key)
+                    } else {
+                        !catalogue.messages.iter().any(|(en, _)| en == key)
+                    }
+                })
+                .map(|(file, key, _)| format!("{}: {:?}", file, key))
+                .collect();
+            assert!(missing.is_empty(), "{} catalogue is missing:\n{}", locale.code(), missing.join("\n"));
+        }
+    }
+
+    #[test]
+    fn index_returns_the_first_translation_of_each_entry() {
+        for locale in Locale::all() {
+            let Some(catalogue) = locale.catalogue() else {
+                assert!(index(locale).is_none());
+                continue;
+            };
+            let index = index(locale).unwrap();
+            for (en, _) in catalogue.messages {
+                let first = catalogue.messages.iter().find(|(key, _)| key == en).map(|(_, tr)| *tr);
+                assert_eq!(index.messages.get(en).copied(), first, "{} {:?}", locale.code(), en);
+            }
+            for (en, _) in catalogue.plurals {
+                let first = catalogue.plurals.iter().find(|(key, _)| key == en).map(|(_, forms)| *forms);
+                assert_eq!(index.plurals.get(en).copied(), first, "{} {:?}", locale.code(), en);
+            }
+        }
+    }
+
+    #[test]
+    fn translations_keep_their_placeholders() {
+        fn placeholders(s: &str) -> Vec<&str> {
+            let mut names: Vec<&str> = s
+                .match_indices('{')
+                .filter_map(|(i, _)| s[i..].find('}').map(|j| &s[i..i + j + 1]))
+                .collect();
+            names.sort_unstable();
+            names
+        }
+        for locale in Locale::all() {
+            let Some(catalogue) = locale.catalogue() else { continue };
+            for (en, translated) in catalogue.messages {
+                assert_eq!(placeholders(en), placeholders(translated), "{} {:?}", locale.code(), en);
+            }
+            for (en, forms) in catalogue.plurals {
+                for form in forms {
+                    assert_eq!(placeholders(en), placeholders(form), "{} {:?}", locale.code(), en);
+                }
+            }
+        }
+    }
+}
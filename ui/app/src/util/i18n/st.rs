@@ -0,0 +1,2005 @@
+//! Sesotho message catalogue
+
+use super::Catalogue;
+
+pub const CATALOGUE: Catalogue = Catalogue {
+    messages: &[
+        ("Overview", "Kakaretso"),
+        ("Dashboard", "Deshebote"),
+        ("Procurement", "Theko"),
+        ("Requisitions", "Dikopo"),
+        ("Tenders", "Dithendara"),
+        ("Evaluation", "Tekolo"),
+        ("Contracts", "Dikonteraka"),
+        ("Purchase Orders", "Diodara tsa theko"),
+        ("Goods Receipt", "Kamohelo ya thepa"),
+        ("Suppliers", "Bafani"),
+        ("Registry", "Rejisetara"),
+        ("Performance", "Tshebetso"),
+        ("Risk", "Kotsi"),
+        ("Supplier Portal", "Potale ya bafani"),
+        ("Catalogue", "Khathaloko"),
+        ("Browse", "Batla"),
+        ("Admin", "Tsamaiso"),
+        ("Compliance", "Ho latela melao"),
+        ("Analytics", "Manollo"),
+        ("Audit Trail", "Mohlala wa tlhahlobo"),
+        ("GRC Dashboard", "Deshebote ya GRC"),
+        ("NBAC Reviews", "Ditlhahlobo tsa NBAC"),
+        ("AGSA Reviews", "Ditlhahlobo tsa AGSA"),
+        ("B-BBEE Goals", "Merero ya B-BBEE"),
+        ("Special Sourcing", "Theko e ikgethang"),
+        ("Reverse Auction", "Fantisi e kgutlelang morao"),
+        ("Sourcing Plans", "Merero ya theko"),
+        ("Tools", "Disebediswa"),
+        ("Documents", "Ditokomane"),
+        ("AI Assistant", "Mothusi wa AI"),
+        ("Search requisitions, tenders, suppliers...", "Batla dikopo, dithendara, bafani..."),
+        ("Notifications", "Ditsebiso"),
+        ("Help", "Thuso"),
+        ("Sign out", "Tswa"),
+        ("Language", "Puo"),
+        ("Mark all read", "Tshwaya tsohle di badilwe"),
+        ("Settings", "Ditlhophiso"),
+        ("Back", "Morao"),
+        ("Notification settings", "Ditlhophiso tsa ditsebiso"),
+        ("You're all caught up", "Ha ho ditsebiso tse ntjha"),
+        ("No matches", "Ha ho sephetho"),
+        ("Searching...", "E a batla..."),
+        ("Page not found", "Leqephe ha le a fumanwa"),
+        ("Back to Dashboard", "Kgutlela deshebote"),
+        ("Try again", "Leka hape"),
+        ("Something went wrong", "Ho na le ho sa tsamayang hantle"),
+        ("Draft", "Moralo"),
+        ("Submitted", "E rometswe"),
+        ("Pending Approval", "E emetse tumello"),
+        ("Approved", "E amohetswe"),
+        ("Rejected", "E hanwe"),
+        ("Cancelled", "E hlakotswe"),
+        ("In Progress", "E ntse e tswela pele"),
+        ("Complete", "E phethilwe"),
+        ("Active", "E a sebetsa"),
+        ("Published", "E phatlaladitswe"),
+        ("Pending", "E emetse"),
+        ("Expired", "E felletswe ke nako"),
+        ("Failed", "E hlolehile"),
+        ("New", "E ntjha"),
+        ("Open", "E butswe"),
+        ("Review", "Tlhahlobo"),
+        ("On Hold", "E emisitswe"),
+        ("Scheduled", "E hlophisitswe"),
+        ("Closed", "E kwetswe"),
+        ("Adjudication", "Qeto"),
+        ("Awarded", "E filwe"),
+        ("Low", "Tlase"),
+        ("Medium", "Mahareng"),
+        ("High", "Hodimo"),
+        ("Urgent", "E potlakileng"),
+        ("Request for Quotation", "Kopo ya ditheko"),
+        ("Request for Proposal", "Kopo ya tlhahiso"),
+        ("Request for Tender", "Kopo ya thendara"),
+        ("Bids", "Dibidi"),
+        ("Payments", "Ditefo"),
+        ("System", "Sistimi"),
+        ("Auctions", "Difantisi"),
+        ("Approvals", "Ditumello"),
+        ("Certificates", "Disetifikeiti"),
+        ("Meetings", "Dikopano"),
+        ("Today", "Kajeno"),
+        ("Approval awaiting you", "Tumello e o emetse"),
+        ("Tender closing", "Thendara e a kwalwa"),
+        ("Contract expiring", "Konteraka e a fela"),
+        ("Certificate expiring", "Setifikeiti se a fela"),
+        ("NBAC meeting scheduled", "Kopano ya NBAC e hlophisitswe"),
+        ("There is no page at {path}. Check the link or use the sidebar to find what you need.", "Ha ho leqephe ho {path}. Hlahloba kgokahanyo kapa o sebedise bara e ka thoko ho fumana seo o se batlang."),
+        ("Just now", "Hona joale"),
+        ("Yesterday", "Maobane"),
+        ("Tomorrow", "Hosasane"),
+        ("{time} ago", "{time} e fetileng"),
+        ("in {time}", "kamora {time}"),
+        (" (R)", " (R)"),
+        (" (must equal 100%)", " (e tlameha ho lekana le 100%)"),
+        (" Upload Document", " Kenya tokomane"),
+        (" incl. VAT", " ho kenyeletswa VAT"),
+        (" supplier B-BBEE certificates expiring within 90 days", " mangolo a B-BBEE a bafani a felang nakong ka hare ho matsatsi a 90"),
+        ("% of Total", "% ya kakaretso"),
+        ("+ Add Category", "+ Eketsa sehlopha"),
+        ("+ Add Criterion", "+ Eketsa tekanyo ya tlhahlobo"),
+        ("+ Add Line Item", "+ Eketsa ntho ya mola"),
+        ("+ Add Milestone", "+ Eketsa mohato wa bohlokwa"),
+        ("+ Add Objective", "+ Eketsa sepheo"),
+        ("+ New Requisition", "+ Kopo e ntjha"),
+        ("+ New Sourcing Plan", "+ Moralo o motjha wa phumano"),
+        (", the following approvals will be required before proceeding.", ", ditumello tse latelang di tla hlokahala pele ho tswela pele."),
+        ("1 day", "letsatsi le 1"),
+        ("1 hour", "hora e 1"),
+        ("14 days", "matsatsi a 14"),
+        ("15 minutes", "metsotso e 15"),
+        ("2 hours", "dihora tse 2"),
+        ("3 days", "matsatsi a 3"),
+        ("30 minutes", "metsotso e 30"),
+        ("7 days", "matsatsi a 7"),
+        ("AGSA - Audit Findings", "AGSA - Diphumano tsa tlhahlobo"),
+        ("AGSA Audit Findings", "Diphumano tsa tlhahlobo tsa AGSA"),
+        ("AGSA Comments", "Maikutlo a AGSA"),
+        ("AI Procurement Assistant", "Mothusi wa theko wa AI"),
+        ("Accepted", "E amohetswe"),
+        ("Accepted goods", "Thepa e amohetsweng"),
+        ("Access denied", "Phihlello e hannwe"),
+        ("Accounting Officer Approval", "Tumello ya Ofisiri ya Ditjhelete"),
+        ("Acknowledged", "E tsebisitswe"),
+        ("Action", "Ketso"),
+        ("Action Details", "Dintlha tsa ketso"),
+        ("Action Items", "Dintho tse lokelang ho etswa"),
+        ("Action Plan", "Moralo wa ketso"),
+        ("Action Type", "Mofuta wa ketso"),
+        ("Actions", "Diketso"),
+        ("Actions Completed", "Diketso tse phethilweng"),
+        ("Actions Overdue", "Diketso tse fetilweng ke nako"),
+        ("Activate", "Kenya tshebetsong"),
+        ("Active Bidders ({count})", "Bathenda ba sebetsang ({count})"),
+        ("Active Bids", "Dibidi tse sebetsang"),
+        ("Active Contracts", "Dikonteraka tse sebetsang"),
+        ("Active Items", "Dintho tse sebetsang"),
+        ("Active Members", "Ditho tse sebetsang"),
+        ("Active Plans", "Merero e sebetsang"),
+        ("Active Requisitions", "Dikopo tse sebetsang"),
+        ("Active Risks", "Dikotsi tse teng"),
+        ("Active Suppliers", "Bafani ba sebetsang"),
+        ("Active Users", "Basebedisi ba sebetsang"),
+        ("Active:", "E a sebetsa:"),
+        ("Actual", "Sa nnete"),
+        ("Actual Spend", "Tshebediso ya nnete"),
+        ("Add", "Eketsa"),
+        ("Add Catalogue Item", "Eketsa ntho ya khatalogo"),
+        ("Add Control", "Eketsa taolo"),
+        ("Add Deliverable", "Eketsa se tlang ho fanwa"),
+        ("Add Item", "Eketsa ntho"),
+        ("Add Line Item", "Eketsa ntho ya mola"),
+        ("Add Milestone", "Eketsa mohato wa bohlokwa"),
+        ("Add Note", "Eketsa tlhokomediso"),
+        ("Add Risk", "Eketsa kotsi"),
+        ("Add Supplier", "Eketsa mofani"),
+        ("Add Target", "Eketsa sepheo"),
+        ("Add, edit, and manage catalogue items", "Eketsa, fetola le ho laola dintho tsa khatalogo"),
+        ("Additional Information", "Tlhahisoleseding e eketsehileng"),
+        ("Additional Notes", "Dintlha tse eketsehileng"),
+        ("Address", "Aterese"),
+        ("Address Line 2", "Mola wa aterese 2"),
+        ("Address:", "Aterese:"),
+        ("Administration", "Tsamaiso"),
+        ("Adverse", "E mpe"),
+        ("After authorisation", "Kamora tumello"),
+        ("Agenda Items", "Dintlha tsa lenaneo"),
+        ("Aging", "Botsofadi"),
+        ("Alerts", "Ditemoso"),
+        ("All", "Tsohle"),
+        ("All Actions", "Diketso tsohle"),
+        ("All Categories", "Dihlopha tsohle"),
+        ("All Departments", "Mafapha ohle"),
+        ("All Documents", "Ditokomane tsohle"),
+        ("All Entities", "Mekgatlo yohle"),
+        ("All Levels", "Maemo ohle"),
+        ("All Provinces", "Diprofense tsohle"),
+        ("All Receipts ({count})", "Dirisiti tsohle ({count})"),
+        ("All Requisitions", "Dikopo tsohle"),
+        ("All Risk Levels", "Maemo ohle a kotsi"),
+        ("All Sourcing Plans", "Merero yohle ya phumano"),
+        ("All Spend Targets", "Dipheo tsohle tsa tshebediso"),
+        ("All Statuses", "Maemo ohle"),
+        ("All Suppliers", "Bafani bohle"),
+        ("All Types", "Mefuta yohle"),
+        ("All Years", "Dilemo tsohle"),
+        ("All budgets are within their thresholds", "Ditekanyetso tsohle di ka hare ho meedi ya tsona"),
+        ("All documents", "Ditokomane tsohle"),
+        ("All statuses", "Maemo ohle"),
+        ("Allocated", "E abetswe"),
+        ("Allocated Budget", "Tekanyetso e abetsweng"),
+        ("Allocated to Categories", "E abetswe dihlopha"),
+        ("Almost Certain", "E batla e netefetse"),
+        ("Already on", "E se e le ho"),
+        ("Also publish to internal supplier portal", "Phatlalatsa hape ho portale ya ka hare ya bafani"),
+        ("Amount", "Palo"),
+        ("Amount limit", "Moedi wa palo"),
+        ("Annual procurement planning and budget management", "Moralo wa theko wa selemo le tsamaiso ya tekanyetso"),
+        ("Any additional information...", "Tlhahisoleseding efe kapa efe e eketsehileng..."),
+        ("Any additional notes to display on the portal", "Dintlha dife kapa dife tse eketsehileng tse tla bontshwa portaleng"),
+        ("Any notes or comments for the supplier", "Dintlha kapa maikutlo afe kapa afe bakeng sa mofani"),
+        ("App Configuration", "Tlhophiso ya app"),
+        ("App Features", "Dikarolo tsa app"),
+        ("App Name", "Lebitso la app"),
+        ("App Preview", "Ponelopele ya app"),
+        ("App Version", "Mofuta wa app"),
+        ("Applicable for existing strategic relationships that provide significant value. Must demonstrate strategic importance and value.", "E sebetsa dikamanong tsa maano tse teng tse fanang ka boleng bo boholo. Ho tlameha ho bontshwa bohlokwa ba maano le boleng."),
+        ("Applicable for urgent requirements due to unforeseen circumstances. Must demonstrate immediate need and inability to follow normal process.", "E sebetsa ditlhokong tse potlakileng ka lebaka la maemo a sa lebellwang. Ho tlameha ho bontshwa tlhoko e potlakileng le ho se kgone ho latela tshebetso e tlwaelehileng."),
+        ("Applicable when only one supplier can provide the specific goods or services required. Must demonstrate that no reasonable alternative exists.", "E sebetsa ha ho na le mofani a le mong feela ya ka fanang ka thepa kapa ditshebeletso tse hlokahalang. Ho tlameha ho bontshwa hore ha ho na kgetho e nngwe e utlwahalang."),
+        ("Applicable when supplier has exclusive rights through patents, licenses, or proprietary technology. Must provide evidence of exclusivity.", "E sebetsa ha mofani a na le ditokelo tse ikgethang ka dipatente, dilaesense kapa theknoloji ya hae. Ho tlameha ho fanwa ka bopaki ba boikgethelo."),
+        ("Apply", "Sebedisa"),
+        ("Apply Corrections", "Sebedisa ditokiso"),
+        ("Approval", "Tumello"),
+        ("Approval Delegations", "Ho fetisetswa ha ditumello"),
+        ("Approval Rate", "Sekgahla sa tumello"),
+        ("Approval Requirements", "Ditlhoko tsa tumello"),
+        ("Approval Turnaround", "Nako ya tumello"),
+        ("Approval Workflow", "Tsamaiso ya tumello"),
+        ("Approval steps past their SLA and steps escalated to the next authority", "Mehato ya tumello e fetileng SLA ya yona le mehato e fetiseditsweng ho bolaodi bo latelang"),
+        ("Approvals Needing Attention", "Ditumello tse hlokang tlhokomelo"),
+        ("Approvals delegated to", "Ditumello di fetiseditswe ho"),
+        ("Approve", "Dumella"),
+        ("Approve for Payment", "Dumella tefo"),
+        ("Approved by", "E dumelletswe ke"),
+        ("Approved, Not on a Run", "E dumelletswe, ha e ho potoloho"),
+        ("Approver", "Modumedi"),
+        ("Archive", "Boloka polokelong"),
+        ("Archived", "E bolokilwe polokelong"),
+        ("Are you sure you want to delete this catalogue item? This action cannot be undone.", "Na o na le bonnete ba hore o batla ho hlakola ntho ena ya khatalogo? Ketso ena e ke ke ya kgutliswa."),
+        ("Ask me anything about procurement...", "Mpotse eng kapa eng ka theko..."),
+        ("Assigned To", "E abetswe"),
+        ("At Risk", "Kotsing"),
+        ("Attachments", "Dikgomaretso"),
+        ("Attendance Mandatory", "Ho ba teng ho qobellwa"),
+        ("Auction", "Fantisi"),
+        ("Auction Participations", "Ho nka karolo difantising"),
+        ("Auction is not currently live", "Fantisi ha e tswele pele hona jwale"),
+        ("Auctions ({count} total)", "Difantisi ({count} kaofela)"),
+        ("Audit Entries ({count})", "Dikeno tsa tlhahlobo ({count})"),
+        ("Audit Findings", "Diphumano tsa tlhahlobo"),
+        ("Audit Reports", "Ditlaleho tsa tlhahlobo"),
+        ("Auditor-General South Africa findings, action items, and compliance tracking", "Diphumano tsa Mohlahlobi-Kakaretso wa Afrika Borwa, dintho tse lokelang ho etswa le ho latedisa ho latela melao"),
+        ("Auth", "Netefatso"),
+        ("Authorisation", "Tumello"),
+        ("Authorisations", "Ditumello"),
+        ("Authorise Run", "Dumella potoloho"),
+        ("Authorization", "Tumello"),
+        ("Availability (%)", "Ho fumaneha (%)"),
+        ("Available", "E a fumaneha"),
+        ("Available Budget", "Tekanyetso e fumanehang"),
+        ("Average Confidence", "Kholiseho e mahareng"),
+        ("Avg Attendance", "Ho ba teng ho mahareng"),
+        ("Avg Resolution Days", "Matsatsi a mahareng a tharollo"),
+        ("Avg Turnaround", "Nako e mahareng ya karabo"),
+        ("Avg. Business Days", "Matsatsi a mahareng a mosebetsi"),
+        ("Avg. Performance", "Tshebetso e mahareng"),
+        ("Avg. Procurement Cycle", "Potoloho e mahareng ya theko"),
+        ("Awaiting", "E emetse"),
+        ("Awaiting Authorisation", "E emetse tumello"),
+        ("Awaiting Inspection:", "E emetse tlhahlobo:"),
+        ("Awaiting Review", "E emetse tekolo"),
+        ("Awaiting approver", "E emetse modumedi"),
+        ("Award", "Abela"),
+        ("Awarded Contracts", "Dikonteraka tse abetsweng"),
+        ("B-BBEE", "B-BBEE"),
+        ("B-BBEE %", "B-BBEE %"),
+        ("B-BBEE Compliance Metrics", "Ditekanyo tsa ho latela B-BBEE"),
+        ("B-BBEE Compliant", "E latela B-BBEE"),
+        ("B-BBEE Level", "Boemo ba B-BBEE"),
+        ("B-BBEE Level Breakdown", "Karohano ya maemo a B-BBEE"),
+        ("B-BBEE Performance", "Tshebetso ya B-BBEE"),
+        ("B-BBEE Preferential Procurement Scorecard", "Karete ya dintlha ya theko e kgethwang ya B-BBEE"),
+        ("B-BBEE Spend", "Tshebediso ya B-BBEE"),
+        ("B-BBEE Spend Distribution", "Kabo ya tshebediso ya B-BBEE"),
+        ("B-BBEE Spend Trend", "Mokgwa wa tshebediso ya B-BBEE"),
+        ("B-BBEE Weight", "Boima ba B-BBEE"),
+        ("B-BBEE Weight (%)", "Boima ba B-BBEE (%)"),
+        ("B-BBEE certificates are valid for twelve months from the date of issue", "Mangolo a B-BBEE a sebetsa dikgwedi tse leshome le metso e mmedi ho tloha letsatsing la ho fanwa"),
+        ("Back to AGSA Reviews", "Kgutlela ditekolong tsa AGSA"),
+        ("Back to Auction List", "Kgutlela lenaneng la difantisi"),
+        ("Back to Auctions", "Kgutlela difantising"),
+        ("Back to Contracts", "Kgutlela dikonterakeng"),
+        ("Back to Document Intake", "Kgutlela kamohelong ya ditokomane"),
+        ("Back to Evaluation List", "Kgutlela lenaneng la tlhahlobo"),
+        ("Back to Evaluations", "Kgutlela ditlhahlobong"),
+        ("Back to Goods Receipts", "Kgutlela kamohelong ya thepa"),
+        ("Back to Invoices", "Kgutlela ho di-invoice"),
+        ("Back to Library", "Kgutlela laeboraring"),
+        ("Back to Milestones", "Kgutlela mehatong ya bohlokwa"),
+        ("Back to Payment Runs", "Kgutlela dipotolohong tsa tefo"),
+        ("Back to Purchase Orders", "Kgutlela ditaelong tsa theko"),
+        ("Back to Registry", "Kgutlela rejistareng"),
+        ("Back to Requisitions", "Kgutlela dikopong"),
+        ("Back to Reviews", "Kgutlela ditekolong"),
+        ("Back to Suppliers", "Kgutlela ho bafani"),
+        ("Back to Tender", "Kgutlela thendareng"),
+        ("Back to Tenders", "Kgutlela ditendareng"),
+        ("Bank Account", "Akhaonto ya banka"),
+        ("Bank Files", "Difaele tsa banka"),
+        ("Bank account or branch code invalid", "Akhaonto ya banka kapa khoutu ya lekala ha e nepahale"),
+        ("Based on estimated value of ", "Ho ipapisitswe le boleng bo hakanngwang ba "),
+        ("Basic Info", "Tlhahisoleseding ya motheo"),
+        ("Basic Information", "Tlhahisoleseding ya motheo"),
+        ("Batch approved invoices, authorise runs and release bank payment files", "Bokella di-invoice tse dumelletsweng, dumella dipotoloho mme o ntshe difaele tsa tefo tsa banka"),
+        ("Below", "Ka tlase"),
+        ("Bid", "Bidi"),
+        ("Bid Award", "Kabo ya bidi"),
+        ("Bid Comparison", "Papiso ya dibidi"),
+        ("Bid Evaluation", "Tlhahlobo ya dibidi"),
+        ("Bid History ({count})", "Nalane ya dibidi ({count})"),
+        ("Bid Price", "Theko ya bidi"),
+        ("Bidder {id}", "Motenda {id}"),
+        ("Bidders", "Bathenda"),
+        ("Bids ({count})", "Dibidi ({count})"),
+        ("Bids Submitted", "Dibidi tse rometsweng"),
+        ("Black Owned Spend", "Tshebediso ho beng ba batsho"),
+        ("Black Women Owned", "E ruilwe ke basadi ba batsho"),
+        ("Blacklisted", "Lenaneng le letsho"),
+        ("Box", "Lebokose"),
+        ("Branding", "Letshwao"),
+        ("Brief description of procurement", "Tlhaloso e kgutshwane ya theko"),
+        ("Brief description of the procurement", "Tlhaloso e kgutshwane ya theko"),
+        ("Brief description of the requisition", "Tlhaloso e kgutshwane ya kopo"),
+        ("Briefing Check-ins", "Ho ingodisa kopanong ya tlhaloso"),
+        ("Briefing Date", "Letsatsi la kopano ya tlhaloso"),
+        ("Broad-Based Black Economic Empowerment compliance tracking for FY 2025/26", "Ho latedisa ho latela Matlafatso a Moruo a Batsho a Pharaletseng bakeng sa selemo sa ditjhelete sa 2025/26"),
+        ("Browse Opportunities", "Batla menyetla"),
+        ("Browse and search available items", "Sheba le ho batla dintho tse fumanehang"),
+        ("Budget", "Tekanyetso"),
+        ("Budget Alerts", "Ditemoso tsa tekanyetso"),
+        ("Budget Allocation", "Kabo ya tekanyetso"),
+        ("Budget Control", "Taolo ya tekanyetso"),
+        ("Budget Lines", "Mela ya tekanyetso"),
+        ("Budget Summary", "Kakaretso ya tekanyetso"),
+        ("Budget Transfers", "Diphetisetso tsa tekanyetso"),
+        ("Budget Utilization", "Tshebediso ya tekanyetso"),
+        ("Budget Utilized", "Tekanyetso e sebedisitsweng"),
+        ("Budget by Category", "Tekanyetso ka sehlopha"),
+        ("Building, floor, etc. (optional)", "Moaho, mokatong, jj. (ka boikgethelo)"),
+        ("By submitting this request, you confirm that you have conducted appropriate market research and that deviation from competitive bidding is justified.", "Ka ho romela kopo ena, o netefatsa hore o entse dipatlisiso tse nepahetseng tsa mmaraka le hore ho kgelohela thendareng ya tlhodisano ho na le lebaka."),
+        ("CFO Approval", "Tumello ya CFO"),
+        ("CIPC registration", "Ngodiso ya CIPC"),
+        ("CSD Unverified", "CSD ha e a netefatswa"),
+        ("CSD Verified", "CSD e netefaditswe"),
+        ("Cancel", "Hlakola"),
+        ("Cancel Run", "Hlakola potoloho"),
+        ("Cancellation", "Phediso"),
+        ("Cannot be ordered directly: {reason}.", "E ke ke ya odarwa ka kotloloho: {reason}."),
+        ("Cards", "Dikarete"),
+        ("Catalogue Items ({count})", "Dintho tsa khatalogo ({count})"),
+        ("Catalogue Management", "Tsamaiso ya khatalogo"),
+        ("Catastrophic", "Koduwa"),
+        ("Categories", "Dihlopha"),
+        ("Categories ({count} total)", "Dihlopha ({count} kaofela)"),
+        ("Category", "Sehlopha"),
+        ("Category Breakdown", "Karohano ka sehlopha"),
+        ("Category Name", "Lebitso la sehlopha"),
+        ("Category name", "Lebitso la sehlopha"),
+        ("Category:", "Sehlopha:"),
+        ("Catering", "Phepelo ya dijo"),
+        ("Changes", "Diphetoho"),
+        ("Checks", "Ditlhahlobo"),
+        ("Choose a bid from the list on the left to begin scoring.", "Kgetha bidi lenaneng le ka letsohong le letshehadi ho qala ho fana ka dintlha."),
+        ("Choose a colleague", "Kgetha mosebetsimmoho"),
+        ("Choose how to resolve the exception", "Kgetha hore na o tla rarolla mokgelo jwang"),
+        ("City", "Toropo"),
+        ("Classification", "Tlhophiso"),
+        ("Clean Audit", "Tlhahlobo e hlwekileng"),
+        ("Clear", "Hlakola"),
+        ("Clear Filters", "Hlakola difilthara"),
+        ("Close", "Kwala"),
+        ("Closing Date", "Letsatsi la ho kwala"),
+        ("Code", "Khoutu"),
+        ("Comment", "Maikutlo"),
+        ("Comments (required to reject or return)", "Maikutlo (a a hlokahala ho hana kapa ho kgutlisa)"),
+        ("Commitment", "Boitlamo"),
+        ("Commitments", "Boitlamo"),
+        ("Committed", "E itlamile"),
+        ("Committee", "Komiti"),
+        ("Committee Members", "Ditho tsa komiti"),
+        ("Committee Notes", "Dintlha tsa komiti"),
+        ("Committee Statistics", "Dipalopalo tsa komiti"),
+        ("Completed", "E phethilwe"),
+        ("Completed ({count})", "E phethilwe ({count})"),
+        ("Completed Today:", "E phethilwe kajeno:"),
+        ("Completed: {date}", "E phethilwe: {date}"),
+        ("Completion Progress", "Tswelopele ya ho phethela"),
+        ("Compliance Check", "Tlhahlobo ya ho latela melao"),
+        ("Compliance Checks", "Ditlhahlobo tsa ho latela melao"),
+        ("Compliance Checks ({count} total)", "Ditlhahlobo tsa ho latela melao ({count} kaofela)"),
+        ("Compliance Issues", "Ditaba tsa ho latela melao"),
+        ("Compliance Metrics", "Ditekanyo tsa ho latela melao"),
+        ("Compliance Score", "Dintlha tsa ho latela melao"),
+        ("Compliant", "E latela melao"),
+        ("Compliant Metrics", "Ditekanyo tse latelang melao"),
+        ("Compliant Suppliers", "Bafani ba latelang melao"),
+        ("Comprehensive procurement spend analysis for FY 2025/26", "Manollo e felletseng ya tshebediso ya theko bakeng sa selemo sa ditjhelete sa 2025/26"),
+        ("Confidence", "Kholiseho"),
+        ("Configuration", "Tlhophiso"),
+        ("Confinement", "Ho kwallwa"),
+        ("Confirm Paid", "Netefatsa tefo"),
+        ("Confirm it is a separate charge before accepting it.", "Netefatsa hore ke tefiso e arohaneng pele o e amohela."),
+        ("Conflict", "Kgohlano"),
+        ("Connected", "E hokahane"),
+        ("Construction", "Kaho"),
+        ("Contact", "Motho wa kgokahano"),
+        ("Contact Email", "Imeile ya kgokahano"),
+        ("Contact Person", "Motho wa kgokahano"),
+        ("Contact Phone", "Founu ya kgokahano"),
+        ("Contact:", "Motho wa kgokahano:"),
+        ("Container", "Setshelo"),
+        ("Continue to Attachments", "Tswela pele ho dikgomaretso"),
+        ("Continue to Budget", "Tswela pele ho tekanyetso"),
+        ("Continue to Categories", "Tswela pele ho dihlopha"),
+        ("Continue to Line Items", "Tswela pele ho dintho tsa mola"),
+        ("Continue to Review", "Tswela pele ho tekolo"),
+        ("Continue to Timeline", "Tswela pele ho nako ya tshebetso"),
+        ("Contract", "Konteraka"),
+        ("Contract Awards", "Dikabo tsa dikonteraka"),
+        ("Contract Details", "Dintlha tsa konteraka"),
+        ("Contract Duration", "Nako ya konteraka"),
+        ("Contract ID", "ID ya konteraka"),
+        ("Contract No.", "Nomoro ya konteraka"),
+        ("Contract Period", "Nako ya konteraka"),
+        ("Contract Reference", "Tshupiso ya konteraka"),
+        ("Contract Terms", "Dipehelo tsa konteraka"),
+        ("Contract Title", "Sehlooho sa konteraka"),
+        ("Contract Type", "Mofuta wa konteraka"),
+        ("Contract Utilization", "Tshebediso ya konteraka"),
+        ("Contract Value", "Boleng ba konteraka"),
+        ("Contract ref...", "Tshupiso ya konteraka..."),
+        ("Contract {id} - {supplier_name}", "Konteraka {id} - {supplier_name}"),
+        ("Contract:", "Konteraka:"),
+        ("Contracts ({count})", "Dikonteraka ({count})"),
+        ("Control", "Taolo"),
+        ("Control Effectiveness", "Katleho ya taolo"),
+        ("Controls", "Ditaolo"),
+        ("Corporate Services", "Ditshebeletso tsa khamphani"),
+        ("Corrected", "E lokisitswe"),
+        ("Corrections", "Ditokiso"),
+        ("Corrective", "E lokisang"),
+        ("Cost Center", "Setsi sa ditjeo"),
+        ("Cost Centre", "Setsi sa ditjeo"),
+        ("Cost Savings", "Poloko ya ditjeo"),
+        ("Create", "Theha"),
+        ("Create Contract", "Theha konteraka"),
+        ("Create Purchase Order", "Theha taelo ya theko"),
+        ("Create a new purchase requisition", "Theha kopo e ntjha ya theko"),
+        ("Create an annual procurement sourcing plan", "Theha moralo wa selemo wa phumano ya thepa"),
+        ("Create {tender_type}", "Theha {tender_type}"),
+        ("Created", "E thehilwe"),
+        ("Criteria", "Ditekanyo"),
+        ("Criteria Count", "Palo ya ditekanyo"),
+        ("Criterion Name", "Lebitso la tekanyo"),
+        ("Critical", "E bohlokwa haholo"),
+        ("Critical Alerts", "Ditemoso tse bohlokwa haholo"),
+        ("Critical Risk", "Kotsi e kgolo haholo"),
+        ("Critical Risk Suppliers - Immediate Attention Required", "Bafani ba kotsing e kgolo haholo - Ho hlokahala tlhokomelo hang-hang"),
+        ("Current Audit Opinion", "Maikutlo a hajwale a tlhahlobo"),
+        ("Current Bid", "Bidi ya hajwale"),
+        ("Current Lowest Bid", "Bidi e tlase ka ho fetisisa ya hajwale"),
+        ("Current: {amount}", "Ya hajwale: {amount}"),
+        ("Custom Range", "Mokoloko o ikgethetsweng"),
+        ("Cyber", "Saebara"),
+        ("Dark", "Lefifi"),
+        ("Date", "Letsatsi"),
+        ("Date (Newest)", "Letsatsi (le letjha ka ho fetisisa)"),
+        ("Date (Oldest)", "Letsatsi (la kgale ka ho fetisisa)"),
+        ("Date Emergency Arose", "Letsatsi leo tshohanyetso e qadileng ka lona"),
+        ("Date From", "Letsatsi ho tloha"),
+        ("Date Received", "Letsatsi la kamohelo"),
+        ("Date To", "Letsatsi ho fihlela"),
+        ("Deactivate", "Emisa tshebetso"),
+        ("Decision", "Qeto"),
+        ("Decision History", "Nalane ya diqeto"),
+        ("Decisions", "Diqeto"),
+        ("Decisions This Month", "Diqeto tsa kgwedi ena"),
+        ("Decisions YTD", "Diqeto tsa selemo ho fihlela hajwale"),
+        ("Decreasing", "E a fokotseha"),
+        ("Defer", "Diehisa"),
+        ("Define key milestones and target dates for the procurement plan execution.", "Hlalosa mehato ya bohlokwa le matsatsi a sepheo a phethahatso ya moralo wa theko."),
+        ("Define the procurement categories and their budget allocations for this plan.", "Hlalosa dihlopha tsa theko le dikabo tsa tsona tsa tekanyetso bakeng sa moralo ona."),
+        ("Delegate", "Fetisetsa"),
+        ("Delegate Approvals", "Fetisetsa ditumello"),
+        ("Delegate to", "Fetisetsa ho"),
+        ("Delegations", "Diphetisetso"),
+        ("Delegator", "Mofetisetsi"),
+        ("Delete", "Hlakola"),
+        ("Delete Item", "Hlakola ntho"),
+        ("Deliverable #{number}", "Se tla fanwa #{number}"),
+        ("Deliverables", "Tse tla fanwa"),
+        ("Delivered", "E tlisitswe"),
+        ("Deliveries Confirmed", "Ditliso di netefaditswe"),
+        ("Delivery", "Tliso"),
+        ("Delivery Address", "Aterese ya tliso"),
+        ("Delivery Address Line 1", "Mola wa aterese ya tliso 1"),
+        ("Delivery Details", "Dintlha tsa tliso"),
+        ("Delivery Information", "Tlhahisoleseding ya tliso"),
+        ("Delivery Instructions", "Ditaelo tsa tliso"),
+        ("Delivery Location", "Sebaka sa tliso"),
+        ("Delivery Note", "Lengolo la tliso"),
+        ("Delivery location", "Sebaka sa tliso"),
+        ("Department", "Lefapha"),
+        ("Department & Budget", "Lefapha le tekanyetso"),
+        ("Department & Timeline", "Lefapha le nako ya tshebetso"),
+        ("Describe escalation levels and contacts", "Hlalosa maemo a phetisetso le batho ba kgokahano"),
+        ("Describe market research conducted", "Hlalosa dipatlisiso tsa mmaraka tse entsweng"),
+        ("Describe penalties for SLA breaches", "Hlalosa dikotlo tsa ho tlola SLA"),
+        ("Describe renewal conditions", "Hlalosa dipehelo tsa ntjhafatso"),
+        ("Describe termination conditions", "Hlalosa dipehelo tsa phediso"),
+        ("Describe the contract scope and objectives", "Hlalosa bophara le dipheo tsa konteraka"),
+        ("Describe the emergency and why normal procurement cannot be followed", "Hlalosa tshohanyetso le hore na ke hobaneng theko e tlwaelehileng e sa kgone ho latelwa"),
+        ("Describe the scope and purpose of this sourcing plan...", "Hlalosa bophara le sepheo sa moralo ona wa phumano..."),
+        ("Description", "Tlhaloso"),
+        ("Designated Group Spend (%)", "Tshebediso ho dihlopha tse kgethilweng (%)"),
+        ("Designated Groups", "Dihlopha tse kgethilweng"),
+        ("Detailed description of goods/services required", "Tlhaloso e qaqileng ya thepa/ditshebeletso tse hlokahalang"),
+        ("Detailed description of work to be performed", "Tlhaloso e qaqileng ya mosebetsi o tla etswa"),
+        ("Details", "Dintlha"),
+        ("Detect automatically", "Lemoha ka bo yona"),
+        ("Detected", "E lemohuwe"),
+        ("Detective", "E lemohang"),
+        ("Deviation", "Kgeloho"),
+        ("Deviation Justification", "Lebaka la kgeloho"),
+        ("Deviation Request", "Kopo ya kgeloho"),
+        ("Deviation Type", "Mofuta wa kgeloho"),
+        ("Deviations from competitive bidding must be justified in terms of Treasury Regulation 16A6.4 and require appropriate approval based on value.", "Dikgeloho thendareng ya tlhodisano di tlameha ho ba le lebaka ho ya ka Molawana wa Letlotlo 16A6.4 mme di hloka tumello e nepahetseng ho ya ka boleng."),
+        ("Device", "Sesebediswa"),
+        ("Device Breakdown", "Karohano ka disebediswa"),
+        ("Directive", "E laelang"),
+        ("Disable All", "Tima tsohle"),
+        ("Disclaimer", "Boitlhotlhollo"),
+        ("Disconnected", "E kgaohile"),
+        ("Discontinued", "E emisitswe"),
+        ("Dispute Resolution", "Tharollo ya dikgohlano"),
+        ("Docs", "Ditokomane"),
+        ("Docs Scanned", "Ditokomane tse sekenilweng"),
+        ("Document", "Tokomane"),
+        ("Document Intake", "Kamohelo ya ditokomane"),
+        ("Document Library", "Laeborari ya ditokomane"),
+        ("Document Name", "Lebitso la tokomane"),
+        ("Document Preview", "Ponelopele ya tokomane"),
+        ("Document Type", "Mofuta wa tokomane"),
+        ("Documentation", "Ditokomane"),
+        ("Documents ({count} found)", "Ditokomane ({count} tse fumanweng)"),
+        ("Documents Scanned", "Ditokomane tse sekenilweng"),
+        ("Download", "Jarolla"),
+        ("Download CSV", "Jarolla CSV"),
+        ("Download Docs", "Jarolla ditokomane"),
+        ("Download QR Code", "Jarolla khoutu ya QR"),
+        ("Draft:", "Moralo:"),
+        ("Drop files here or click to browse", "Lahlela difaele mona kapa o tobetse ho batla"),
+        ("Due", "E lokela"),
+        ("Due Date", "Letsatsi la ho qetela"),
+        ("Due in 7 Days", "E lokela ka matsatsi a 7"),
+        ("Due: {date}", "E lokela: {date}"),
+        ("EME Suppliers", "Bafani ba EME"),
+        ("EME/QSE Spend", "Tshebediso ya EME/QSE"),
+        ("Each", "Ka nngwe"),
+        ("Eastern Cape", "Kapa Botjhabela"),
+        ("Edit", "Fetola"),
+        ("Edit Catalogue Item", "Fetola ntho ya khatalogo"),
+        ("Editing {id}", "Ho fetolwa {id}"),
+        ("Effective", "E a sebetsa"),
+        ("Effectiveness", "Katleho"),
+        ("Element", "Karolo"),
+        ("Email", "Imeile"),
+        ("Emergency", "Tshohanyetso"),
+        ("Emergency - Urgent procurement due to unforeseen circumstances", "Tshohanyetso - Theko e potlakileng ka lebaka la maemo a sa lebellwang"),
+        ("Emergency Circumstances", "Maemo a tshohanyetso"),
+        ("Emergency Details", "Dintlha tsa tshohanyetso"),
+        ("Emergency Procurement", "Theko ya tshohanyetso"),
+        ("Enable All", "Bulela tsohle"),
+        ("Enable Offline Mode", "Bulela mokgwa wa ntle ho marang-rang"),
+        ("Enable or disable features available to suppliers in the mobile app.", "Bulela kapa o time dikarolo tse fumanehang ho bafani ho app ya mohala."),
+        ("Enable this to define service level requirements and penalties", "Bulela sena ho hlalosa ditlhoko tsa boemo ba tshebeletso le dikotlo"),
+        ("End Date", "Letsatsi la ho fela"),
+        ("End Time", "Nako ya ho fela"),
+        ("Ended", "E fedile"),
+        ("Enter a descriptive title", "Kenya sehlooho se hlalosang"),
+        ("Enter contract title", "Kenya sehlooho sa konteraka"),
+        ("Enter document name", "Kenya lebitso la tokomane"),
+        ("Enter item description", "Kenya tlhaloso ya ntho"),
+        ("Enter item name", "Kenya lebitso la ntho"),
+        ("Enter strategic objective...", "Kenya sepheo sa maano..."),
+        ("Enter the purchase order number and load the order to capture the lines", "Kenya nomoro ya taelo ya theko mme o jarolle taelo ho hapa mela"),
+        ("Enter your bid", "Kenya bidi ya hao"),
+        ("Enterprise & Supplier Development Element - Code 400", "Karolo ya ntshetsopele ya dikgwebo le bafani - Khoutu 400"),
+        ("Entity", "Mokgatlo"),
+        ("Entity ID", "ID ya mokgatlo"),
+        ("Entity Name", "Lebitso la mokgatlo"),
+        ("Entity Type", "Mofuta wa mokgatlo"),
+        ("Escalated", "E fetiseditswe hodimo"),
+        ("Escalated from", "E fetiseditswe hodimo ho tloha ho"),
+        ("Escalation Procedure", "Tshebetso ya phetisetso hodimo"),
+        ("Est. Value", "Boleng bo hakanngwang"),
+        ("Estimated Total", "Kakaretso e hakanngwang"),
+        ("Estimated Value", "Boleng bo hakanngwang"),
+        ("Estimated contract value in ZAR", "Boleng bo hakanngwang ba konteraka ka ZAR"),
+        ("Eval Deadline", "Letsatsi la ho qetela la tlhahlobo"),
+        ("Evaluated", "E hlahlobilwe"),
+        ("Evaluation Criteria", "Ditekanyo tsa tlhahlobo"),
+        ("Evaluation Summary", "Kakaretso ya tlhahlobo"),
+        ("Exceeding", "E feta"),
+        ("Excel", "Excel"),
+        ("Exception", "Mokgelo"),
+        ("Exception Queue", "Mola wa mekgelo"),
+        ("Exceptions", "Mekgelo"),
+        ("Excluded Invoices", "Di-invoice tse sa kenyelletswang"),
+        ("Expected", "E lebelletswe"),
+        ("Expected Date:", "Letsatsi le lebelletsweng:"),
+        ("Expected Delivery Date", "Letsatsi le lebelletsweng la tliso"),
+        ("Expended", "E sebedisitswe"),
+        ("Expires in {days} days", "E fela ka matsatsi a {days}"),
+        ("Expiring (30 days):", "E haufi le ho fela (matsatsi a 30):"),
+        ("Expiring Certs", "Mangolo a haufi le ho fela"),
+        ("Expiring within 30 days", "E fela ka hare ho matsatsi a 30"),
+        ("Explain why this requisition is needed...", "Hlalosa hore na ke hobaneng kopo ena e hlokahala..."),
+        ("Explain why this specific supplier is required", "Hlalosa hore na ke hobaneng mofani enwa a hlokahala"),
+        ("Export", "Romela kantle"),
+        ("Export Comparison", "Romela papiso"),
+        ("Export DTI Report", "Romela tlaleho ya DTI"),
+        ("Export Excel", "Romela Excel"),
+        ("Export PDF", "Romela PDF"),
+        ("Export Report", "Romela tlaleho"),
+        ("Extension", "Katoloso"),
+        ("Extracted Fields", "Masimo a ntshitsweng"),
+        ("Extreme", "E feteletseng"),
+        ("Extreme/High", "E feteletseng/E phahameng"),
+        ("FY 2025/26", "Selemo sa ditjhelete 2025/26"),
+        ("Facilities", "Meaho"),
+        ("Facilities Management", "Tsamaiso ya meaho"),
+        ("Feature Usage", "Tshebediso ya dikarolo"),
+        ("Featured", "E hlahelletseng"),
+        ("Features", "Dikarolo"),
+        ("Fields to check", "Masimo a lokelang ho hlahlojwa"),
+        ("Filter", "Sefa"),
+        ("Finalize Evaluation", "Phethela tlhahlobo"),
+        ("Finance", "Ditjhelete"),
+        ("Financial", "Tsa ditjhelete"),
+        ("Financial Impact", "Phello ya ditjhelete"),
+        ("Financial Score", "Dintlha tsa ditjhelete"),
+        ("Financial Year", "Selemo sa ditjhelete"),
+        ("Finding", "Phumano"),
+        ("Finding Statistics", "Dipalopalo tsa diphumano"),
+        ("Findings", "Diphumano"),
+        ("Findings by Severity", "Diphumano ka boima"),
+        ("Fiscal Year", "Selemo sa ditjhelete"),
+        ("Fleet Management", "Tsamaiso ya dikoloi"),
+        ("Fleet Services", "Ditshebeletso tsa dikoloi"),
+        ("Folders", "Difoldara"),
+        ("Framework Agreement", "Tumellano ya moralo"),
+        ("Fraud", "Bomenemene"),
+        ("Free State", "Foreisetata"),
+        ("From", "Ho tloha"),
+        ("From budget", "Ho tloha tekanyetsong"),
+        ("Full Justification", "Lebaka le felletseng"),
+        ("Functionality Threshold", "Moedi wa tshebetso"),
+        ("Functionality Threshold (%)", "Moedi wa tshebetso (%)"),
+        ("Funds are committed against this cost centre when approved", "Ditjhelete di itlamisitswe setsing sena sa ditjeo ha di dumelletswe"),
+        ("Furniture", "Thepa ya ntlo"),
+        ("Furniture & Fittings", "Thepa ya ntlo le ditlhomo"),
+        ("GR Number", "Nomoro ya GR"),
+        ("Gauteng", "Gauteng"),
+        ("General", "Kakaretso"),
+        ("Generate", "Hlahisa"),
+        ("Generic", "E akaretsang"),
+        ("Goods", "Thepa"),
+        ("Goods Receipt {id}", "Kamohelo ya thepa {id}"),
+        ("Governance, Risk Management, and Compliance Overview", "Kakaretso ya puso, tsamaiso ya dikotsi le ho latela melao"),
+        ("Governing Law", "Molao o laolang"),
+        ("Grid", "Kerite"),
+        ("Hand your approvals to a colleague while you are out of office", "Fetisetsa ditumello tsa hao ho mosebetsimmoho ha o le siyo ofising"),
+        ("Health Services", "Ditshebeletso tsa bophelo bo botle"),
+        ("High Risk", "Kotsi e phahameng"),
+        ("High/Critical Risk", "Kotsi e phahameng/e kgolo haholo"),
+        ("Home", "Lehae"),
+        ("How can I help you today?", "Nka o thusa jwang kajeno?"),
+        ("Human Resources", "Basebetsi"),
+        ("I can assist with tender analysis, compliance checks, bid evaluation, and procurement guidance.", "Nka thusa ka manollo ya ditendara, ditlhahlobo tsa ho latela melao, tlhahlobo ya dibidi le tataiso ya theko."),
+        ("IP Address", "Aterese ya IP"),
+        ("IT Equipment", "Disebediswa tsa IT"),
+        ("IT Services", "Ditshebeletso tsa IT"),
+        ("Image", "Setshwantsho"),
+        ("Import", "Kenya"),
+        ("Import CSV", "Kenya CSV"),
+        ("In Stock", "E teng setokong"),
+        ("In stock only", "Tse setokong feela"),
+        ("Inactive", "Ha e sebetse"),
+        ("Include Briefing Session", "Kenyelletsa kopano ya tlhaloso"),
+        ("Include SLA Terms", "Kenyelletsa dipehelo tsa SLA"),
+        ("Include: reasons why competitive bidding is not possible, value for money considerations, risk assessment", "Kenyelletsa: mabaka a hore na ke hobaneng thendara ya tlhodisano e sa kgonehe, ditlhokomelo tsa boleng ba tjhelete, tekolo ya kotsi"),
+        ("Including all milestones", "Ho kenyelletswa mehato yohle ya bohlokwa"),
+        ("Increasing", "E a eketseha"),
+        ("Indicator Status", "Boemo ba sesupo"),
+        ("Ineffective", "Ha e sebetse"),
+        ("Info", "Tlhahisoleseding"),
+        ("Info Req", "Tlhahisoleseding e kopuwa"),
+        ("Information Technology", "Theknoloji ya tlhahisoleseding"),
+        ("Inherent", "Ya tlhaho"),
+        ("Insignificant", "E sa bohlokwa"),
+        ("Inspection", "Tlhahlobo"),
+        ("Inspection failed. Rejected items must be returned to the supplier.", "Tlhahlobo e hlolehile. Dintho tse hannweng di tlameha ho kgutliselwa ho mofani."),
+        ("Inspection:", "Tlhahlobo:"),
+        ("Internal Controls ({count} total)", "Ditaolo tsa ka hare ({count} kaofela)"),
+        ("Internal Notes", "Dintlha tsa ka hare"),
+        ("Internal Portal", "Portale ya ka hare"),
+        ("Internal notes (not visible to supplier)", "Dintlha tsa ka hare (ha di bonahale ho mofani)"),
+        ("Invoice", "Invoice"),
+        ("Invoice Date", "Letsatsi la invoice"),
+        ("Invoice Details", "Dintlha tsa invoice"),
+        ("Invoice Lines", "Mela ya invoice"),
+        ("Invoice Register", "Rejistara ya di-invoice"),
+        ("Invoice Total", "Kakaretso ya invoice"),
+        ("Invoice, supplier or order", "Invoice, mofani kapa taelo"),
+        ("Invoiced", "E kentswe ho invoice"),
+        ("Invoiced Price", "Theko e ho invoice"),
+        ("Invoiced against accepted", "Ho invoice ha ho bapiswa le tse amohetsweng"),
+        ("Invoiced against ordered", "Ho invoice ha ho bapiswa le tse odarilweng"),
+        ("Invoices", "Di-invoice"),
+        ("Item", "Ntho"),
+        ("Item #{number}", "Ntho #{number}"),
+        ("Item Code", "Khoutu ya ntho"),
+        ("Item Details", "Dintlha tsa ntho"),
+        ("Item Name", "Lebitso la ntho"),
+        ("Item description", "Tlhaloso ya ntho"),
+        ("Items", "Dintho"),
+        ("Items ({count} found)", "Dintho ({count} tse fumanweng)"),
+        ("Items Received", "Dintho tse amohetsweng"),
+        ("Its invoices return to the approved list for another run", "Di-invoice tsa yona di kgutlela lenaneng le dumelletsweng bakeng sa potoloho e nngwe"),
+        ("Justification", "Lebaka"),
+        ("Kit", "Sete"),
+        ("KwaZulu-Natal", "KwaZulu-Natala"),
+        ("LIVE", "E A TSWELA PELE"),
+        ("Last Active", "O qetetse ho sebetsa"),
+        ("Last Paid", "O qetetse ho lefuwa"),
+        ("Last Test", "Teko ya ho qetela"),
+        ("Last bid: {date}", "Bidi ya ho qetela: {date}"),
+        ("Leave at zero to delegate any amount", "Tlohela ho zero ho fetisetsa palo efe kapa efe"),
+        ("Level 1", "Boemo 1"),
+        ("Level 1-2", "Boemo 1-2"),
+        ("Level 1-2 Spend", "Tshebediso ya boemo 1-2"),
+        ("Level 1-3", "Boemo 1-3"),
+        ("Level 1-4", "Boemo 1-4"),
+        ("Level 2", "Boemo 2"),
+        ("Level 3", "Boemo 3"),
+        ("Level 3-4", "Boemo 3-4"),
+        ("Level 4+", "Boemo 4+"),
+        ("Level 5-8", "Boemo 5-8"),
+        ("Level Distribution", "Kabo ya maemo"),
+        ("Level {level}", "Boemo {level}"),
+        ("License", "Laesense"),
+        ("Light", "Lesedi"),
+        ("Likely", "E ka etsahala"),
+        ("Limpopo", "Limpopo"),
+        ("Line Items", "Dintho tsa mola"),
+        ("Line Items ({count})", "Dintho tsa mola ({count})"),
+        ("Line Items Summary", "Kakaretso ya dintho tsa mola"),
+        ("Lines add up to {lines}, not the subtotal of {subtotal}", "Mela e kopana ho ba {lines}, e seng kakaretso e nyane ya {subtotal}"),
+        ("Lines without a supplier go out for quotation.", "Mela e se nang mofani e romelwa bakeng sa qotsulo."),
+        ("Link to existing contract (optional)", "Hokahanya le konteraka e teng (ka boikgethelo)"),
+        ("Link to requisition (optional)", "Hokahanya le kopo (ka boikgethelo)"),
+        ("Linked Tender ID", "ID ya thendara e hokahantsweng"),
+        ("List", "Lenane"),
+        ("Live", "E a tswela pele"),
+        ("Load Order", "Jarolla taelo"),
+        ("Loading auctions...", "Ho jarollwa difantisi..."),
+        ("Loading audit entries...", "Ho jarollwa dikeno tsa tlhahlobo..."),
+        ("Loading catalogue...", "Ho jarollwa khatalogo..."),
+        ("Loading compliance data...", "Ho jarollwa data ya ho latela melao..."),
+        ("Loading contract...", "Ho jarollwa konteraka..."),
+        ("Loading contracts...", "Ho jarollwa dikonteraka..."),
+        ("Loading controls data...", "Ho jarollwa data ya ditaolo..."),
+        ("Loading document...", "Ho jarollwa tokomane..."),
+        ("Loading documents...", "Ho jarollwa ditokomane..."),
+        ("Loading finding...", "Ho jarollwa phumano..."),
+        ("Loading goods receipt...", "Ho jarollwa kamohelo ya thepa..."),
+        ("Loading goods receipts...", "Ho jarollwa dikamohelo tsa thepa..."),
+        ("Loading invoice...", "Ho jarollwa invoice..."),
+        ("Loading items...", "Ho jarollwa dintho..."),
+        ("Loading level data...", "Ho jarollwa data ya maemo..."),
+        ("Loading metrics...", "Ho jarollwa ditekanyo..."),
+        ("Loading payment run...", "Ho jarollwa potoloho ya tefo..."),
+        ("Loading performance data...", "Ho jarollwa data ya tshebetso..."),
+        ("Loading purchase order...", "Ho jarollwa taelo ya theko..."),
+        ("Loading purchase orders...", "Ho jarollwa ditaelo tsa theko..."),
+        ("Loading requisition...", "Ho jarollwa kopo..."),
+        ("Loading review...", "Ho jarollwa tekolo..."),
+        ("Loading risk data...", "Ho jarollwa data ya kotsi..."),
+        ("Loading supplier...", "Ho jarollwa mofani..."),
+        ("Loading suppliers...", "Ho jarollwa bafani..."),
+        ("Loading targets...", "Ho jarollwa dipheo..."),
+        ("Loading tenders...", "Ho jarollwa ditendara..."),
+        ("Loading violations data...", "Ho jarollwa data ya ditlolo..."),
+        ("Loading...", "E a jarolla..."),
+        ("Local %", "Ya lehae %"),
+        ("Locked", "E notlelletswe"),
+        ("Locked by {user}", "E notlelletswe ke {user}"),
+        ("Login", "Kena"),
+        ("Login Events", "Diketsahalo tsa ho kena"),
+        ("Logout", "Tswa"),
+        ("Low Risk", "Kotsi e tlase"),
+        ("Major", "E kgolo"),
+        ("Manage and monitor registered suppliers", "Laola le ho hlokomela bafani ba ngodisitsweng"),
+        ("Manage and organize procurement documents", "Laola le ho hlophisa ditokomane tsa theko"),
+        ("Manage live reverse auctions for competitive bidding", "Laola difantisi tse kgutlelang morao tse tswelang pele bakeng sa thendara ya tlhodisano"),
+        ("Manage procurement tenders and bids", "Laola ditendara tsa theko le dibidi"),
+        ("Manage purchase orders and track deliveries", "Laola ditaelo tsa theko mme o latedise ditliso"),
+        ("Manage purchase requisitions and approvals", "Laola dikopo tsa theko le ditumello"),
+        ("Manage supplier contracts and agreements", "Laola dikonteraka le ditumellano tsa bafani"),
+        ("Manage your tenders, bids, and contracts", "Laola ditendara tsa hao, dibidi le dikonteraka"),
+        ("Management Response", "Karabo ya botsamaisi"),
+        ("Mandatory Requirements", "Ditlhoko tse qobellwang"),
+        ("Mark All Read", "Tshwaya tsohle di badilwe"),
+        ("Mark Complete", "Tshwaya e phethilwe"),
+        ("Market Analysis", "Manollo ya mmaraka"),
+        ("Market Research", "Dipatlisiso tsa mmaraka"),
+        ("Match", "Bapisa"),
+        ("Match Tolerances", "Mamello ya papiso"),
+        ("Match tolerances saved", "Mamello ya papiso e bolokilwe"),
+        ("Matched", "E a tsamaisana"),
+        ("Material", "E bohlokwa"),
+        ("Max", "Boholo"),
+        ("Max Offline Duration", "Nako e telele ntle ho marang-rang"),
+        ("Maximum file size: 50MB", "Boholo bo phahameng ba faele: 50MB"),
+        ("Maximum per transfer:", "Boholo phetisetsong ka nngwe:"),
+        ("Maximum time to acknowledge an issue", "Nako e telele ya ho amohela bothata"),
+        ("Maximum time to resolve an issue", "Nako e telele ya ho rarolla bothata"),
+        ("Medical", "Bongaka"),
+        ("Medium Risk", "Kotsi e mahareng"),
+        ("Meeting No.", "Nomoro ya kopano"),
+        ("Meeting Schedule", "Lenaneo la dikopano"),
+        ("Metric", "Tekanyo"),
+        ("Milestone", "Mohato wa bohlokwa"),
+        ("Milestone Details", "Dintlha tsa mohato wa bohlokwa"),
+        ("Milestone Name", "Lebitso la mohato wa bohlokwa"),
+        ("Milestone Timeline", "Nako ya tshebetso ya mehato ya bohlokwa"),
+        ("Milestone name", "Lebitso la mohato wa bohlokwa"),
+        ("Milestone {id} - Due: {date}", "Mohato wa bohlokwa {id} - E lokela: {date}"),
+        ("Milestones: {title}", "Mehato ya bohlokwa: {title}"),
+        ("Minimum 3 years experience", "Bonyane boiphihlelo ba dilemo tse 3"),
+        ("Minimum Bidding Period", "Nako e nyane ya thendara"),
+        ("Minimum score to qualify", "Dintlha tse nyane tsa ho tshwaneleha"),
+        ("Minor", "E nyane"),
+        ("Mitigated", "E fokoditswe"),
+        ("Mobile App Users", "Basebedisi ba app ya mohala"),
+        ("Mobile Bids", "Dibidi tsa mohala"),
+        ("Mobile Supplier App", "App ya mohala ya bafani"),
+        ("Moderate", "E mahareng"),
+        ("Monitor and analyze supplier performance metrics", "Hlokomela le ho manolla ditekanyo tsa tshebetso ya bafani"),
+        ("Monitor and manage supplier risk exposure", "Hlokomela le ho laola kotsi ya bafani"),
+        ("Month", "Kgwedi"),
+        ("Monthly Spend Trend", "Mokgwa wa tshebediso wa kgwedi le kgwedi"),
+        ("Mpumalanga", "Mpumalanga"),
+        ("My Bid Submissions", "Dibidi tseo ke di rometseng"),
+        ("My Profile", "Profaele ya ka"),
+        ("My Submissions", "Tseo ke di rometseng"),
+        ("N/A", "Ha e sebetse"),
+        ("NBAC - Bid Adjudication", "NBAC - Kahlolo ya dibidi"),
+        ("Name", "Lebitso"),
+        ("Name (A-Z)", "Lebitso (A-Z)"),
+        ("Name (Z-A)", "Lebitso (Z-A)"),
+        ("Name of proposed supplier", "Lebitso la mofani ya sisintsweng"),
+        ("National Bid Adjudication Committee reviews and decisions", "Ditekolo le diqeto tsa Komiti ya Naha ya Kahlolo ya Dibidi"),
+        ("National Treasury Approval", "Tumello ya Letlotlo la Naha"),
+        ("New Assessment", "Tekolo e ntjha"),
+        ("New Auction", "Fantisi e ntjha"),
+        ("New Contract", "Konteraka e ntjha"),
+        ("New Conversation", "Puisano e ntjha"),
+        ("New Delegation", "Phetisetso e ntjha"),
+        ("New Purchase Order", "Taelo e ntjha ya theko"),
+        ("New Receipt", "Kamohelo e ntjha"),
+        ("New Requisition", "Kopo e ntjha"),
+        ("New Sourcing Plan", "Moralo o motjha wa phumano"),
+        ("New Tender", "Thendara e ntjha"),
+        ("New approver", "Modumedi e motjha"),
+        ("Next", "E latelang"),
+        ("Next Milestone", "Mohato wa bohlokwa o latelang"),
+        ("Next Review", "Tekolo e latelang"),
+        ("Next: Deliverables", "E latelang: Tse tla fanwa"),
+        ("Next: Delivery", "E latelang: Tliso"),
+        ("Next: Line Items", "E latelang: Dintho tsa mola"),
+        ("Next: Review", "E latelang: Tekolo"),
+        ("Next: SLA", "E latelang: SLA"),
+        ("Next: Terms", "E latelang: Dipehelo"),
+        ("No Evaluation Selected", "Ha ho tlhahlobo e kgethilweng"),
+        ("No Live Auction", "Ha ho fantisi e tswelang pele"),
+        ("No attachments added", "Ha ho dikgomaretso tse ekeditsweng"),
+        ("No auctions found. Create a new auction to get started.", "Ha ho difantisi tse fumanweng. Theha fantisi e ntjha ho qala."),
+        ("No audit entries found", "Ha ho dikeno tsa tlhahlobo tse fumanweng"),
+        ("No bank details on record", "Ha ho dintlha tsa banka tse rekotilweng"),
+        ("No bids yet", "Ha ho dibidi hajwale"),
+        ("No budget transfers", "Ha ho diphetisetso tsa tekanyetso"),
+        ("No committee notes yet.", "Ha ho dintlha tsa komiti hajwale."),
+        ("No contracts found", "Ha ho dikonteraka tse fumanweng"),
+        ("No critical alerts at this time", "Ha ho ditemoso tse bohlokwa haholo hajwale"),
+        ("No delegations", "Ha ho diphetisetso"),
+        ("No documents found", "Ha ho ditokomane tse fumanweng"),
+        ("No documents processed", "Ha ho ditokomane tse sebetswang"),
+        ("No invoices found", "Ha ho di-invoice tse fumanweng"),
+        ("No invoices need attention", "Ha ho di-invoice tse hlokang tlhokomelo"),
+        ("No items found", "Ha ho dintho tse fumanweng"),
+        ("No order loaded", "Ha ho taelo e jarolotsweng"),
+        ("No overdue approvals", "Ha ho ditumello tse fetilweng ke nako"),
+        ("No payment runs yet", "Ha ho dipotoloho tsa tefo hajwale"),
+        ("No purchase orders found", "Ha ho ditaelo tsa theko tse fumanweng"),
+        ("No risk indicators found.", "Ha ho disupo tsa kotsi tse fumanweng."),
+        ("No supplier", "Ha ho mofani"),
+        ("No supplier is registered under {registration}", "Ha ho mofani ya ngodisitsweng tlasa {registration}"),
+        ("No suppliers found. Adjust filters or add a new supplier.", "Ha ho bafani ba fumanweng. Fetola difilthara kapa o eketse mofani e motjha."),
+        ("No suppliers match the selected criteria.", "Ha ho bafani ba tsamaisanang le ditekanyo tse kgethilweng."),
+        ("No tenders found. Create a new tender to get started.", "Ha ho ditendara tse fumanweng. Theha thendara e ntjha ho qala."),
+        ("Non-Compliant", "Ha e latele melao"),
+        ("Non-compliant - requires immediate attention", "Ha e latele melao - e hloka tlhokomelo hang-hang"),
+        ("North West", "Leboya Bophirima"),
+        ("Northern Cape", "Kapa Leboya"),
+        ("Not Tested", "Ha e a lekwa"),
+        ("Not matched", "Ha e tsamaisane"),
+        ("Not ordered", "Ha e a odarwa"),
+        ("Not yet submitted for approval", "Ha e eso romelwe bakeng sa tumello"),
+        ("Note: ", "Hlokomela: "),
+        ("Notes", "Dintlha"),
+        ("Notes (visible to supplier)", "Dintlha (di a bonahala ho mofani)"),
+        ("Nothing paid this financial year", "Ha ho letho le lefuweng selemong sena sa ditjhelete"),
+        ("Notice Period (days)", "Nako ya tsebiso (matsatsi)"),
+        ("Notify", "Tsebisa"),
+        ("Notify registered suppliers in this category", "Tsebisa bafani ba ngodisitsweng sehlopheng sena"),
+        ("Observation", "Tlhokomeliso"),
+        ("Office Supplies", "Disebediswa tsa ofisi"),
+        ("Offline Mode", "Mokgwa wa ntle ho marang-rang"),
+        ("On Track", "E tseleng"),
+        ("On behalf of", "Lebitsong la"),
+        ("Once published, the tender will be visible to all suppliers. Ensure all documents are finalized before publishing.", "Hang ha e phatlaladitswe, thendara e tla bonahala ho bafani bohle. Netefatsa hore ditokomane tsohle di phethilwe pele o phatlalatsa."),
+        ("Open Issues", "Ditaba tse bulehileng"),
+        ("Open Opportunities", "Menyetla e bulehileng"),
+        ("Open Tenders", "Ditendara tse bulehileng"),
+        ("Open Violations", "Ditlolo tse bulehileng"),
+        ("Open in New Tab", "Bula thebong e ntjha"),
+        ("Open record", "Bula rekoto"),
+        ("Operating", "E a sebetsa"),
+        ("Operations", "Tshebetso"),
+        ("Opinion", "Maikutlo"),
+        ("Opportunities", "Menyetla"),
+        ("Order", "Taelo"),
+        ("Order Date", "Letsatsi la taelo"),
+        ("Order Price", "Theko ya taelo"),
+        ("Ordered", "E odarilwe"),
+        ("Orders by Supplier", "Ditaelo ka mofani"),
+        ("Organization Name", "Lebitso la mokgatlo"),
+        ("Other", "Tse ding"),
+        ("Out", "Kantle"),
+        ("Out of Stock", "Ha e teng setokong"),
+        ("Outstanding", "E ntse e emetse"),
+        ("Overall", "Ka kakaretso"),
+        ("Overall Progress", "Tswelopele ka kakaretso"),
+        ("Overall confidence", "Kholiseho ka kakaretso"),
+        ("Overdue", "E fetilwe ke nako"),
+        ("Overdue Approvals", "Ditumello tse fetilweng ke nako"),
+        ("Overdue Reviews", "Ditekolo tse fetilweng ke nako"),
+        ("Overdue by", "E fetilwe ke nako ka"),
+        ("Overdue only", "Tse fetilweng ke nako feela"),
+        ("Overview of procurement activities for FY 2025/26", "Kakaretso ya mesebetsi ya theko bakeng sa selemo sa ditjhelete sa 2025/26"),
+        ("Owner", "Mong"),
+        ("Owner: {owner}", "Mong: {owner}"),
+        ("Ownership", "Bong"),
+        ("PDF, JPEG, PNG, TIFF or WebP, up to", "PDF, JPEG, PNG, TIFF kapa WebP, ho fihlela ho"),
+        ("PDF, Word, Excel, or images. Max 10MB per file.", "PDF, Word, Excel kapa ditshwantsho. Boholo 10MB faeleng ka nngwe."),
+        ("PO Number", "Nomoro ya PO"),
+        ("PO Reference", "Tshupiso ya PO"),
+        ("PO Value", "Boleng ba PO"),
+        ("Pack", "Sephuthelwana"),
+        ("Paid", "E lefilwe"),
+        ("Paid This Year", "E lefilwe selemong sena"),
+        ("Paid from", "E lefilwe ho tswa ho"),
+        ("Paid: {date}", "E lefilwe: {date}"),
+        ("Partial", "Karolo"),
+        ("Partial Compliance", "Ho latela melao ka karolo"),
+        ("Partial Pass", "Ho feta ka karolo"),
+        ("Partially", "Ka karolo"),
+        ("Partially Delivered", "E tlisitswe ka karolo"),
+        ("Partially Effective", "E sebetsa ka karolo"),
+        ("Partially Received", "E amohetswe ka karolo"),
+        ("Participant", "Monkakarolo"),
+        ("Passed", "E fetile"),
+        ("Past Due", "E fetilwe ke nako"),
+        ("Paying on", "Ho lefuwa ka"),
+        ("Payment Aging", "Botsofadi ba tefo"),
+        ("Payment Amount", "Palo ya tefo"),
+        ("Payment Date", "Letsatsi la tefo"),
+        ("Payment Report", "Tlaleho ya tefo"),
+        ("Payment Run", "Potoloho ya tefo"),
+        ("Payment Runs", "Dipotoloho tsa tefo"),
+        ("Payment Schedule", "Lenaneo la tefo"),
+        ("Payment Terms", "Dipehelo tsa tefo"),
+        ("Payment confirmed by", "Tefo e netefaditswe ke"),
+        ("Payment date", "Letsatsi la tefo"),
+        ("Payment is due", "Tefo e lokela"),
+        ("Payment run", "Potoloho ya tefo"),
+        ("Payments Made", "Ditefo tse entsweng"),
+        ("Penalty Clause", "Karolo ya kotlo"),
+        ("Pending Approvals", "Ditumello tse emetseng"),
+        ("Pending Delivery:", "Tliso e emetseng:"),
+        ("Pending Processing:", "Tshebetso e emetseng:"),
+        ("Pending Receipts ({count})", "Dikamohelo tse emetseng ({count})"),
+        ("Pending Review", "E emetse tekolo"),
+        ("Pending Reviews", "Ditekolo tse emetseng"),
+        ("Pending Start", "E emetse ho qala"),
+        ("Pending delivery only", "Tliso e emetseng feela"),
+        ("Percentage", "Phesente"),
+        ("Performance Distribution", "Kabo ya tshebetso"),
+        ("Performance by Category", "Tshebetso ka sehlopha"),
+        ("Period", "Nako"),
+        ("Phone", "Founu"),
+        ("Place your first bid to participate", "Kenya bidi ya hao ya pele ho nka karolo"),
+        ("Plan Details", "Dintlha tsa moralo"),
+        ("Plan ID", "ID ya moralo"),
+        ("Plan Title", "Sehlooho sa moralo"),
+        ("Please review all information before saving. Once submitted for approval, changes will require re-approval.", "Ka kopo hlahloba tlhahisoleseding yohle pele o boloka. Hang ha e rometswe bakeng sa tumello, diphetoho di tla hloka tumello hape."),
+        ("Please select an evaluation from the list to begin scoring.", "Ka kopo kgetha tlhahlobo lenaneng ho qala ho fana ka dintlha."),
+        ("Points", "Dintlha"),
+        ("Policy", "Leano"),
+        ("Policy Violations ({count} total)", "Ditlolo tsa leano ({count} kaofela)"),
+        ("Portal Link", "Lehokela la portale"),
+        ("Portal Notes", "Dintlha tsa portale"),
+        ("Portal Reference", "Tshupiso ya portale"),
+        ("Possible", "E ka etsahala"),
+        ("Possible duplicate of", "E ka nna ya ba kopi ya"),
+        ("Possible duplicate of {invoice_id} ({reason})", "E ka nna ya ba kopi ya {invoice_id} ({reason})"),
+        ("Postal Code", "Khoutu ya poso"),
+        ("Prepare Payment Run", "Lokisetsa potoloho ya tefo"),
+        ("Prepare Run", "Lokisetsa potoloho"),
+        ("Prepared by", "E lokiseditswe ke"),
+        ("Preventive", "E thibelang"),
+        ("Preview", "Ponelopele"),
+        ("Preview Controls", "Ditaolo tsa ponelopele"),
+        ("Preview and configure the mobile app for suppliers", "Sheba pele le ho hlophisa app ya mohala ya bafani"),
+        ("Preview not available", "Ponelopele ha e fumanehe"),
+        ("Preview not available for this file type", "Ponelopele ha e fumanehe bakeng sa mofuta ona wa faele"),
+        ("Price", "Theko"),
+        ("Price (High to Low)", "Theko (e hodimo ho ya tlase)"),
+        ("Price (Low to High)", "Theko (e tlase ho ya hodimo)"),
+        ("Price Var.", "Phapang ya theko"),
+        ("Price Weight", "Boima ba theko"),
+        ("Price Weight (%)", "Boima ba theko (%)"),
+        ("Primary Color", "Mmala o ka sehloohong"),
+        ("Priority", "Ntho ya pele"),
+        ("Process and track goods received from suppliers", "Sebetsa le ho latedisa thepa e amohetsweng ho bafani"),
+        ("Processed", "E sebeditswe"),
+        ("Processed Documents", "Ditokomane tse sebeditsweng"),
+        ("Procurement Categories", "Dihlopha tsa theko"),
+        ("Procurement Dashboard", "Deshebote ya theko"),
+        ("Procurement Details", "Dintlha tsa theko"),
+        ("Procurement Division", "Karolo ya theko"),
+        ("Professional", "Ya botsebi"),
+        ("Professional Services", "Ditshebeletso tsa botsebi"),
+        ("Profile", "Profaele"),
+        ("Progress", "Tswelopele"),
+        ("Projected Level", "Boemo bo lebelletsweng"),
+        ("Proposed Supplier", "Mofani ya sisintsweng"),
+        ("Provide a detailed description of the procurement plan", "Fana ka tlhaloso e qaqileng ya moralo wa theko"),
+        ("Provide detailed justification for deviating from competitive bidding", "Fana ka lebaka le qaqileng la ho kgelohela thendareng ya tlhodisano"),
+        ("Provide detailed justification for the procurement", "Fana ka lebaka le qaqileng la theko"),
+        ("Province", "Profense"),
+        ("Provincial B-BBEE Distribution", "Kabo ya B-BBEE ka profense"),
+        ("Provincial Distribution", "Kabo ka profense"),
+        ("Publication Date", "Letsatsi la phatlalatso"),
+        ("Publication Dates", "Matsatsi a phatlalatso"),
+        ("Publication Settings", "Di-setting tsa phatlalatso"),
+        ("Publish Tender", "Phatlalatsa thendara"),
+        ("Publish Update", "Phatlalatsa ntjhafatso"),
+        ("Publish internally", "Phatlalatsa ka hare"),
+        ("Publish tender to e-Tender portal for bidding", "Phatlalatsa thendara portaleng ya e-Tender bakeng sa thendara"),
+        ("Publish to e-Tender", "Phatlalatsa ho e-Tender"),
+        ("Publish to the national e-Tender portal (etenders.gov.za)", "Phatlalatsa portaleng ya naha ya e-Tender (etenders.gov.za)"),
+        ("Purchase Order", "Taelo ya theko"),
+        ("Purchase Order {po_number}", "Taelo ya theko {po_number}"),
+        ("Purchase Orders ({count})", "Ditaelo tsa theko ({count})"),
+        ("Purchase order {po_number} was not found", "Taelo ya theko {po_number} ha e a fumanwa"),
+        ("Push Enabled", "Ditsebiso di butswe"),
+        ("Qty", "Palo"),
+        ("Qty Var.", "Phapang ya palo"),
+        ("Qualified", "E tshwanelehile"),
+        ("Quality", "Boleng"),
+        ("Quantity", "Palo"),
+        ("Quick Actions", "Diketso tse potlakileng"),
+        ("Quick Bid", "Bidi e potlakileng"),
+        ("Quorum Required", "Khoramo e a hlokahala"),
+        ("RFP - Request for Proposal", "RFP - Kopo ya tlhahiso"),
+        ("RFQ - Request for Quotation", "RFQ - Kopo ya qotsulo"),
+        ("RFT - Request for Tender", "RFT - Kopo ya thendara"),
+        ("Raised from approved requisition ", "E tswa kopong e dumelletsweng "),
+        ("Rank", "Boemo"),
+        ("Rare", "Ka seoelo"),
+        ("Rating", "Tekanyetso ya boleng"),
+        ("Re-run Match", "Bapisa hape"),
+        ("Read Documents", "Bala ditokomane"),
+        ("Read as", "Bala jwalo ka"),
+        ("Read by", "E badilwe ke"),
+        ("Reading...", "E a bala..."),
+        ("Reason", "Lebaka"),
+        ("Reason for reassignment", "Lebaka la ho abela hape"),
+        ("Reassign", "Abela hape"),
+        ("Reassign pending step", "Abela hape mohato o emetseng"),
+        ("Receipt Details", "Dintlha tsa kamohelo"),
+        ("Received", "E amohetswe"),
+        ("Received By", "E amohetswe ke"),
+        ("Receiver's name", "Lebitso la moamohedi"),
+        ("Recent Activity", "Mosebetsi wa morao tjena"),
+        ("Recent Opportunities", "Menyetla ya morao tjena"),
+        ("Recent Requisitions", "Dikopo tsa morao tjena"),
+        ("Recently Added", "Tse ekeditsweng morao tjena"),
+        ("Recognition", "Kananelo"),
+        ("Recognized Spend", "Tshebediso e amohetsweng"),
+        ("Recommendation", "Khothaletso"),
+        ("Recommended Supplier", "Mofani ya kgothaletswang"),
+        ("Recommended Value", "Boleng bo kgothaletswang"),
+        ("Recommended documents: Technical Specifications, Terms and Conditions, Evaluation Criteria, Pricing Schedule, Company Profile Template", "Ditokomane tse kgothaletswang: Ditlhaloso tsa setekheniki, Melao le dipehelo, Ditekanyo tsa tlhahlobo, Lenane la ditheko, Template ya profaele ya khamphani"),
+        ("Record", "Rekoto"),
+        ("Record Resolution", "Rekota qeto"),
+        ("Ref:", "Tshupiso:"),
+        ("Reference", "Tshupiso"),
+        ("Reference Number", "Nomoro ya tshupiso"),
+        ("Reg. No.", "Nomoro ya ngodiso"),
+        ("Register Invoice", "Ngodisa invoice"),
+        ("Register and Match", "Ngodisa mme o bapise"),
+        ("Register for Briefing", "Ingodisetse kopano ya tlhaloso"),
+        ("Registered", "E ngodisitswe"),
+        ("Registered as {name} ({id})", "E ngodisitswe jwalo ka {name} ({id})"),
+        ("Registered by", "E ngodisitswe ke"),
+        ("Registration", "Ngodiso"),
+        ("Reject", "Hana"),
+        ("Related To", "E amana le"),
+        ("Remaining", "E setseng"),
+        ("Remaining / Unallocated", "E setseng / E sa abelwang"),
+        ("Remittance", "Tsebiso ya tefo"),
+        ("Remove", "Tlosa"),
+        ("Renewal Terms", "Dipehelo tsa ntjhafatso"),
+        ("Repeat Finding", "Phumano e pheta-phetwang"),
+        ("Repeat Findings", "Diphumano tse pheta-phetwang"),
+        ("Report", "Tlaleho"),
+        ("Report No.", "Nomoro ya tlaleho"),
+        ("Report Violation", "Tlaleha tlolo"),
+        ("Reputation", "Botumo"),
+        ("Req ID", "ID ya kopo"),
+        ("Request Transfer", "Kopa phetisetso"),
+        ("Request deviation from competitive bidding process", "Kopa kgeloho tshebetsong ya thendara ya tlhodisano"),
+        ("Requested", "E kopilwe"),
+        ("Requester", "Mokopi"),
+        ("Require Biometric Authentication", "Hloka netefatso ya biometric"),
+        ("Required By", "E hlokahala ka"),
+        ("Required documents: Supplier quotation, Market research evidence, Supplier B-BBEE certificate, Tax clearance", "Ditokomane tse hlokahalang: Qotsulo ya mofani, Bopaki ba dipatlisiso tsa mmaraka, Lengolo la B-BBEE la mofani, Tumello ya lekgetho"),
+        ("Required uptime percentage", "Phesente ya nako ya tshebetso e hlokahalang"),
+        ("Requires Justification", "E hloka lebaka"),
+        ("Requisition", "Kopo"),
+        ("Requisition Details", "Dintlha tsa kopo"),
+        ("Requisition Reference", "Tshupiso ya kopo"),
+        ("Requisition {id} - {department}", "Kopo {id} - {department}"),
+        ("Reserve threshold at {percent}%", "Moedi wa polokelo ho {percent}%"),
+        ("Reserve: {amount}", "Polokelo: {amount}"),
+        ("Reset", "Seta hape"),
+        ("Residual", "E setseng"),
+        ("Resolution", "Qeto"),
+        ("Resolution No.", "Nomoro ya qeto"),
+        ("Resolution Rate", "Sekgahla sa tharollo"),
+        ("Resolution Time (hours)", "Nako ya tharollo (dihora)"),
+        ("Resolve Exception", "Rarolla mokgelo"),
+        ("Resolved", "E rarollotswe"),
+        ("Response", "Karabo"),
+        ("Response Time (hours)", "Nako ya karabo (dihora)"),
+        ("Responsible", "Ya ikarabellang"),
+        ("Responsible Party", "Mokga o ikarabellang"),
+        ("Responsible party", "Mokga o ikarabellang"),
+        ("Result", "Sephetho"),
+        ("Revenue Summary", "Kakaretso ya lekeno"),
+        ("Reverse Auctions", "Difantisi tse kgutlelang morao"),
+        ("Review & Submit", "Hlahloba mme o romele"),
+        ("Review Details", "Dintlha tsa tekolo"),
+        ("Review and score bids for open tenders", "Hlahloba le ho fana ka dintlha ho dibidi tsa ditendara tse bulehileng"),
+        ("Reviews Pending Adjudication", "Ditekolo tse emetseng kahlolo"),
+        ("Revoke", "Hlakola"),
+        ("Risk Distribution", "Kabo ya kotsi"),
+        ("Risk Heat Map", "Mmapa wa mocheso wa kotsi"),
+        ("Risk Implication", "Phello ya kotsi"),
+        ("Risk Indicator", "Sesupo sa kotsi"),
+        ("Risk Indicators ({count} total)", "Disupo tsa kotsi ({count} kaofela)"),
+        ("Risk Indicators by Category", "Disupo tsa kotsi ka sehlopha"),
+        ("Risk Level", "Boemo ba kotsi"),
+        ("Risk Rating", "Tekanyetso ya kotsi"),
+        ("Risk Register ({count} total)", "Rejistara ya kotsi ({count} kaofela)"),
+        ("Risks", "Dikotsi"),
+        ("Root Cause", "Sesosa sa motheo"),
+        ("Run", "Potoloho"),
+        ("Run Assessment", "Etsa tekolo"),
+        ("Runs", "Dipotoloho"),
+        ("Save", "Boloka"),
+        ("Save Changes", "Boloka diphetoho"),
+        ("Save Draft", "Boloka moralo"),
+        ("Save Tolerances", "Boloka mamello"),
+        ("Save as", "Boloka jwalo ka"),
+        ("Saved as", "E bolokilwe jwalo ka"),
+        ("Saved as Records", "E bolokilwe jwalo ka direkoto"),
+        ("Scan invoices, delivery notes and B-BBEE certificates into draft records", "Sekena di-invoice, mangolo a tliso le mangolo a B-BBEE hore e be direkoto tsa moralo"),
+        ("Scanned document", "Tokomane e sekenilweng"),
+        ("Schedule Meeting", "Hlophisa kopano"),
+        ("Scope", "Bophara"),
+        ("Scope Description", "Tlhaloso ya bophara"),
+        ("Scope of Work", "Bophara ba mosebetsi"),
+        ("Score", "Dintlha"),
+        ("Score: {supplier_name}", "Dintlha: {supplier_name}"),
+        ("Scorecard Points", "Dintlha tsa karete ya dintlha"),
+        ("Scoring", "Ho fana ka dintlha"),
+        ("Scoring Method: {method} | Min Technical Score: {score}% | Price Weight: {weight}%", "Mokgwa wa dintlha: {method} | Dintlha tse nyane tsa setekheniki: {score}% | Boima ba theko: {weight}%"),
+        ("Scoring Progress", "Tswelopele ya dintlha"),
+        ("Scoring Weights", "Boima ba dintlha"),
+        ("Screen", "Skrini"),
+        ("Search", "Batla"),
+        ("Search GR, PO, supplier...", "Batla GR, PO, mofani..."),
+        ("Search POs...", "Batla di-PO..."),
+        ("Search auctions...", "Batla difantisi..."),
+        ("Search audit logs...", "Batla direkoto tsa tlhahlobo..."),
+        ("Search by ID, description, requester...", "Batla ka ID, tlhaloso, mokopi..."),
+        ("Search by ID, title, owner...", "Batla ka ID, sehlooho, mong..."),
+        ("Search contracts...", "Batla dikonteraka..."),
+        ("Search documents...", "Batla ditokomane..."),
+        ("Search items...", "Batla dintho..."),
+        ("Search supplier...", "Batla mofani..."),
+        ("Search suppliers...", "Batla bafani..."),
+        ("Search tenders...", "Batla ditendara..."),
+        ("Search the catalogue by name, item code or supplier", "Batla khatalogong ka lebitso, khoutu ya ntho kapa mofani"),
+        ("Search user...", "Batla mosebedisi..."),
+        ("Secondary Color", "Mmala wa bobedi"),
+        ("Security", "Tshireletso"),
+        ("Security Services", "Ditshebeletso tsa tshireletso"),
+        ("Segregation", "Karohano"),
+        ("Select Category", "Kgetha sehlopha"),
+        ("Select Department", "Kgetha lefapha"),
+        ("Select Deviation Type", "Kgetha mofuta wa kgeloho"),
+        ("Select a Bid to Score", "Kgetha bidi eo o tla e fa dintlha"),
+        ("Select action", "Kgetha ketso"),
+        ("Select category...", "Kgetha sehlopha..."),
+        ("Select department", "Kgetha lefapha"),
+        ("Select province", "Kgetha profense"),
+        ("Select receiving budget", "Kgetha tekanyetso e amohelang"),
+        ("Select source budget", "Kgetha tekanyetso ya mohlodi"),
+        ("Select supplier", "Kgetha mofani"),
+        ("Select supplier...", "Kgetha mofani..."),
+        ("Select type", "Kgetha mofuta"),
+        ("Select...", "Kgetha..."),
+        ("Send Notification", "Romela tsebiso"),
+        ("Sent to Supplier", "E rometswe ho mofani"),
+        ("Service", "Tshebeletso"),
+        ("Service Level Agreement", "Tumellano ya boemo ba tshebeletso"),
+        ("Services", "Ditshebeletso"),
+        ("Session Timeout", "Ho fela ha nako ya seshene"),
+        ("Severity", "Boima"),
+        ("Showing {count} of {total} plans", "Ho bontshwa {count} ho {total} ya merero"),
+        ("Showing {count} of {total} requisitions", "Ho bontshwa {count} ho {total} ya dikopo"),
+        ("Significant", "E bohlokwa"),
+        ("Signing as", "O saena jwalo ka"),
+        ("Single Source", "Mohlodi o le mong"),
+        ("Single Source - Only one supplier can provide the goods/services", "Mohlodi o le mong - Mofani a le mong feela a ka fana ka thepa/ditshebeletso"),
+        ("Single Source Procurement", "Theko ho mohlodi o le mong"),
+        ("Size", "Boholo"),
+        ("Size (Largest)", "Boholo (bo boholo ka ho fetisisa)"),
+        ("Size (Smallest)", "Boholo (bo bonyane ka ho fetisisa)"),
+        ("SoD", "SoD"),
+        ("Software", "Software"),
+        ("Sole Supplier", "Mofani a le mong"),
+        ("Sole Supplier - Supplier has exclusive rights (patents, licenses)", "Mofani a le mong - Mofani o na le ditokelo tse ikgethang (dipatente, dilaesense)"),
+        ("Sort By", "Hlopha ka"),
+        ("Source", "Mohlodi"),
+        ("Source Requisition", "Kopo ya mohlodi"),
+        ("Sources:", "Mehlodi:"),
+        ("Sourcing", "Phumano"),
+        ("Special delivery instructions, access requirements, etc.", "Ditaelo tse ikgethang tsa tliso, ditlhoko tsa ho kena, jj."),
+        ("Specifications", "Ditlhaloso"),
+        ("Spend", "Tshebediso"),
+        ("Spend Analytics", "Manollo ya tshebediso"),
+        ("Spend Targets", "Dipheo tsa tshebediso"),
+        ("Spend by B-BBEE Level", "Tshebediso ka boemo ba B-BBEE"),
+        ("Spend by Category", "Tshebediso ka sehlopha"),
+        ("Stable", "E tsitsitse"),
+        ("Stage", "Mohato"),
+        ("Start Bid", "Bidi ya ho qala"),
+        ("Start Date", "Letsatsi la ho qala"),
+        ("Start Scoring", "Qala ho fana ka dintlha"),
+        ("Starting Price", "Theko ya ho qala"),
+        ("Starting: {amount}", "Ho qala: {amount}"),
+        ("Status", "Boemo"),
+        ("Status:", "Boemo:"),
+        ("Stock", "Setoko"),
+        ("Strategic Objectives", "Dipheo tsa maano"),
+        ("Strategic Partnership", "Tshebedisano ya maano"),
+        ("Strategic Partnership - Existing strategic relationship", "Tshebedisano ya maano - Kamano ya maano e teng"),
+        ("Street address", "Aterese ya seterata"),
+        ("Submit", "Romela"),
+        ("Submit Action Plan", "Romela moralo wa ketso"),
+        ("Submit Request", "Romela kopo"),
+        ("Submit Scores", "Romela dintlha"),
+        ("Submit for Approval", "Romela bakeng sa tumello"),
+        ("Submit for Review", "Romela bakeng sa tekolo"),
+        ("Subtotal", "Kakaretso e nyane"),
+        ("Subtotal:", "Kakaretso e nyane:"),
+        ("Success Rate", "Sekgahla sa katleho"),
+        ("Summary", "Kakaretso"),
+        ("Supplier", "Mofani"),
+        ("Supplier Details", "Dintlha tsa mofani"),
+        ("Supplier Information", "Tlhahisoleseding ya mofani"),
+        ("Supplier Invoice Number", "Nomoro ya invoice ya mofani"),
+        ("Supplier Name", "Lebitso la mofani"),
+        ("Supplier Performance", "Tshebetso ya mofani"),
+        ("Supplier Performance Scores ({count} suppliers)", "Dintlha tsa tshebetso ya bafani (bafani ba {count})"),
+        ("Supplier Registry", "Rejistara ya bafani"),
+        ("Supplier Risk Assessment", "Tekolo ya kotsi ya mofani"),
+        ("Supplier blacklisted", "Mofani o lenaneng le letsho"),
+        ("Supplier contact name", "Lebitso la motho wa kgokahano wa mofani"),
+        ("Supplier invoices, three-way matching and payment aging", "Di-invoice tsa bafani, papiso ya mahlakore a mararo le botsofadi ba tefo"),
+        ("Supplier not in the registry", "Mofani ha a rejistareng"),
+        ("Supplier suspended", "Mofani o emisitswe"),
+        ("Supplier:", "Mofani:"),
+        ("Suppliers ({count} total)", "Bafani ({count} kaofela)"),
+        ("Suppliers at Risk ({count} total)", "Bafani ba kotsing ({count} kaofela)"),
+        ("Supply Chain", "Ketane ya phepelo"),
+        ("Support", "Tshehetso"),
+        ("Support Email", "Imeile ya tshehetso"),
+        ("Support Phone", "Founu ya tshehetso"),
+        ("Supporting Documents", "Ditokomane tse tshehetsang"),
+        ("Suspended", "E emisitswe"),
+        ("Table", "Tafole"),
+        ("Tags (comma-separated)", "Ditaga (di arotswe ka feeloane)"),
+        ("Target", "Sepheo"),
+        ("Target Date", "Letsatsi la sepheo"),
+        ("Tax (15%):", "Lekgetho (15%):"),
+        ("Tax clearance certificate", "Lengolo la tumello ya lekgetho"),
+        ("Technical", "Setekheniki"),
+        ("Technical Requirements", "Ditlhoko tsa setekheniki"),
+        ("Technical Score", "Dintlha tsa setekheniki"),
+        ("Technical Specifications", "Ditlhaloso tsa setekheniki"),
+        ("Technical requirements and specifications", "Ditlhoko tsa setekheniki le ditlhaloso"),
+        ("Template", "Template"),
+        ("Tender", "Thendara"),
+        ("Tender Details", "Dintlha tsa thendara"),
+        ("Tender Documents", "Ditokomane tsa thendara"),
+        ("Tender Published", "Thendara e phatlaladitswe"),
+        ("Tender Ref", "Tshupiso ya thendara"),
+        ("Tender Summary", "Kakaretso ya thendara"),
+        ("Tender Title", "Sehlooho sa thendara"),
+        ("Tender Type", "Mofuta wa thendara"),
+        ("Tender Value", "Boleng ba thendara"),
+        ("Tenders ({count} total)", "Ditendara ({count} kaofela)"),
+        ("Tenders Pending Evaluation", "Ditendara tse emetseng tlhahlobo"),
+        ("Terminated", "E fedisitswe"),
+        ("Termination Clause", "Karolo ya phediso"),
+        ("Terms", "Dipehelo"),
+        ("The certificate expired on {expiry}", "Lengolo le felletswe ke nako ka {expiry}"),
+        ("The original is only shown in the session it was uploaded in", "Ya pele e bontshwa feela sesheneng eo e kentsweng ho yona"),
+        ("Theme", "Sehlooho sa ponahalo"),
+        ("There is no live auction currently available.", "Ha ho fantisi e tswelang pele e fumanehang hajwale."),
+        ("Thinking...", "E a nahana..."),
+        ("This Month", "Kgwedi ena"),
+        ("This Quarter", "Kotara ena"),
+        ("This invoice may duplicate", "Invoice ena e ka nna ya ba kopi ya"),
+        ("This tender must be approved before it can be published. Current status: ", "Thendara ena e tlameha ho dumellwa pele e ka phatlalatswa. Boemo ba hajwale: "),
+        ("Three-Way Match", "Papiso ya mahlakore a mararo"),
+        ("Threshold", "Moedi"),
+        ("Tick none to delegate every document type", "O se ke wa tshwaya letho ho fetisetsa mefuta yohle ya ditokomane"),
+        ("Time", "Nako"),
+        ("Time Remaining", "Nako e setseng"),
+        ("Timeline", "Nako ya tshebetso"),
+        ("Timeline & Milestones", "Nako ya tshebetso le mehato ya bohlokwa"),
+        ("Timeline ({count} milestones)", "Nako ya tshebetso (mehato ya bohlokwa e {count})"),
+        ("Timestamp", "Setempe sa nako"),
+        ("Title", "Sehlooho"),
+        ("To", "Ho ya ho"),
+        ("To budget", "Ho ya tekanyetsong"),
+        ("Today's Activity", "Mosebetsi wa kajeno"),
+        ("Top Performers", "Ba sebetsang hantle ka ho fetisisa"),
+        ("Top Performers (85%+)", "Ba sebetsang hantle ka ho fetisisa (85%+)"),
+        ("Top Performing Suppliers", "Bafani ba sebetsang hantle ka ho fetisisa"),
+        ("Top Suppliers by Spend", "Bafani ba hodimo ka tshebediso"),
+        ("Total", "Kakaretso"),
+        ("Total Active Budget", "Kakaretso ya tekanyetso e sebetsang"),
+        ("Total Allocated", "Kakaretso e abetsweng"),
+        ("Total Amount:", "Palo kaofela:"),
+        ("Total App Users", "Kakaretso ya basebedisi ba app"),
+        ("Total Approved YTD", "Kakaretso e dumelletsweng ya selemo ho fihlela hajwale"),
+        ("Total Budget", "Kakaretso ya tekanyetso"),
+        ("Total Budget Amount", "Palo kaofela ya tekanyetso"),
+        ("Total Checks", "Kakaretso ya ditlhahlobo"),
+        ("Total Contract Value", "Kakaretso ya boleng ba konteraka"),
+        ("Total Contracts:", "Kakaretso ya dikonteraka:"),
+        ("Total Controls", "Kakaretso ya ditaolo"),
+        ("Total Documents", "Kakaretso ya ditokomane"),
+        ("Total Entries", "Kakaretso ya dikeno"),
+        ("Total Evaluations", "Kakaretso ya ditlhahlobo"),
+        ("Total Findings", "Kakaretso ya diphumano"),
+        ("Total Functionality Weight: ", "Kakaretso ya boima ba tshebetso: "),
+        ("Total Items", "Kakaretso ya dintho"),
+        ("Total Milestones", "Kakaretso ya mehato ya bohlokwa"),
+        ("Total POs:", "Kakaretso ya di-PO:"),
+        ("Total Reviews", "Kakaretso ya ditekolo"),
+        ("Total Risks", "Kakaretso ya dikotsi"),
+        ("Total Score", "Kakaretso ya dintlha"),
+        ("Total Spend", "Kakaretso ya tshebediso"),
+        ("Total Storage", "Kakaretso ya polokelo"),
+        ("Total Suppliers", "Kakaretso ya bafani"),
+        ("Total Value", "Kakaretso ya boleng"),
+        ("Total Value:", "Kakaretso ya boleng:"),
+        ("Total Violations", "Kakaretso ya ditlolo"),
+        ("Total budget for this sourcing plan", "Kakaretso ya tekanyetso bakeng sa moralo ona wa phumano"),
+        ("Total of {total} should be {expected}", "Kakaretso ya {total} e lokela ho ba {expected}"),
+        ("Total:", "Kakaretso:"),
+        ("Track all system activities and changes", "Latedisa mesebetsi yohle ya sistimi le diphetoho"),
+        ("Training", "Thupelo"),
+        ("Transfer", "Phetisetso"),
+        ("Transfers", "Diphetisetso"),
+        ("Transport", "Dipalangwang"),
+        ("Treasury", "Letlotlo"),
+        ("Trend", "Mokgwa"),
+        ("Try adjusting your filters or add a new item", "Leka ho fetola difilthara tsa hao kapa o eketse ntho e ntjha"),
+        ("Try adjusting your filters or create a new contract", "Leka ho fetola difilthara tsa hao kapa o thehe konteraka e ntjha"),
+        ("Try adjusting your filters or create a new purchase order", "Leka ho fetola difilthara tsa hao kapa o thehe taelo e ntjha ya theko"),
+        ("Try adjusting your filters to see more results", "Leka ho fetola difilthara tsa hao ho bona diphetho tse ding"),
+        ("Try adjusting your search or filters", "Leka ho fetola patlo ya hao kapa difilthara"),
+        ("Type", "Mofuta"),
+        ("Tyre", "Thaere"),
+        ("Unanimous", "Ka bonngwe"),
+        ("Under Review", "E ntse e hlahlojwa"),
+        ("Underperforming", "Ba sa sebetseng hantle"),
+        ("Underperforming (<70%)", "Ba sa sebetseng hantle (<70%)"),
+        ("Unit", "Yuniti"),
+        ("Unit Price", "Theko ya yuniti"),
+        ("Unit Price (ZAR)", "Theko ya yuniti (ZAR)"),
+        ("Unknown", "Ha e tsejwe"),
+        ("Unknown document", "Tokomane e sa tsejweng"),
+        ("Unlikely", "Ha e na monyetla"),
+        ("Unqualified w/Findings", "Ntle le dipehelo empa ho na le diphumano"),
+        ("Until", "Ho fihlela"),
+        ("Upcoming Deadlines", "Matsatsi a ho qetela a tlang"),
+        ("Upcoming Meetings", "Dikopano tse tlang"),
+        ("Upcoming Milestones", "Mehato ya bohlokwa e tlang"),
+        ("Update", "Ntjhafatsa"),
+        ("Updated", "E ntjhafaditswe"),
+        ("Upload", "Kenya"),
+        ("Upload Document", "Kenya tokomane"),
+        ("Upload Documents", "Kenya ditokomane"),
+        ("Upload Supporting Documents", "Kenya ditokomane tse tshehetsang"),
+        ("Upload a scanned document to get started", "Kenya tokomane e sekenilweng ho qala"),
+        ("Upload at most", "Kenya bonyane ho fihlela ho"),
+        ("Upload documents or adjust your filters", "Kenya ditokomane kapa o fetole difilthara tsa hao"),
+        ("Upload quotations, market research, supplier documentation", "Kenya diqotsulo, dipatlisiso tsa mmaraka, ditokomane tsa mofani"),
+        ("Upload specifications, terms, and other tender documents (PDF, Word, Excel)", "Kenya ditlhaloso, dipehelo le ditokomane tse ding tsa thendara (PDF, Word, Excel)"),
+        ("Upload supporting documents such as specifications, quotes, or budget approvals.", "Kenya ditokomane tse tshehetsang tse kang ditlhaloso, diqotsulo kapa ditumello tsa tekanyetso."),
+        ("Uploaded By", "E kentswe ke"),
+        ("Uploaded Documents", "Ditokomane tse kentsweng"),
+        ("User", "Mosebedisi"),
+        ("User ID", "ID ya mosebedisi"),
+        ("User Information", "Tlhahisoleseding ya mosebedisi"),
+        ("Users", "Basebedisi"),
+        ("Utilisation", "Tshebediso"),
+        ("VAT", "VAT"),
+        ("VAT (15%):", "VAT (15%):"),
+        ("VAT:", "VAT:"),
+        ("Valid B-BBEE certificate", "Lengolo le sebetsang la B-BBEE"),
+        ("Valid until {expiry}", "E sebetsa ho fihlela {expiry}"),
+        ("Value", "Boleng"),
+        ("Value:", "Boleng:"),
+        ("Variance", "Phapang"),
+        ("Variation", "Phetoho"),
+        ("Venue", "Sebaka"),
+        ("Verified", "E netefaditswe"),
+        ("Version", "Mofuta"),
+        ("Version History", "Nalane ya mefuta"),
+        ("View", "Sheba"),
+        ("View All", "Sheba tsohle"),
+        ("View All Auctions", "Sheba difantisi tsohle"),
+        ("View Contract", "Sheba konteraka"),
+        ("View Details", "Sheba dintlha"),
+        ("View List", "Sheba lenane"),
+        ("View invoice", "Sheba invoice"),
+        ("View on e-Tender Portal", "Sheba portaleng ya e-Tender"),
+        ("Violations", "Ditlolo"),
+        ("Violations by Type", "Ditlolo ka mofuta"),
+        ("Virements between budget lines for FY", "Diphetisetso pakeng tsa mela ya tekanyetso bakeng sa selemo sa ditjhelete"),
+        ("Votes", "Divoutu"),
+        ("Waived", "E tlohelletswe"),
+        ("Warehouse", "Polokelo ya thepa"),
+        ("Warning: Category allocations exceed total budget by ", "Temoso: Dikabo tsa dihlopha di feta kakaretso ya tekanyetso ka "),
+        ("Warranty Period (months)", "Nako ya waranti (dikgwedi)"),
+        ("Weight %", "Boima %"),
+        ("Weight: {weight}%", "Boima: {weight}%"),
+        ("Western Cape", "Kapa Bophirima"),
+        ("What alternatives were considered? Why were they not suitable?", "Ke dikgetho dife tse ding tse ileng tsa nahanwa? Ke hobaneng di ne di sa tshwanela?"),
+        ("Why This Supplier", "Hobaneng mofani enwa"),
+        ("Why the funds are needed and why the source budget can release them", "Hobaneng ditjhelete di hlokahala le hore na ke hobaneng tekanyetso ya mohlodi e ka di lokolla"),
+        ("Why the run is being cancelled", "Hobaneng potoloho e hlakolwa"),
+        ("Why the variance is acceptable, or what the supplier must correct", "Hobaneng phapang e amoheleha, kapa ke eng seo mofani a tlamehang ho se lokisa"),
+        ("Within SLA", "Ka hare ho SLA"),
+        ("Within tolerance", "Ka hare ho mamello"),
+        ("Word", "Word"),
+        ("Works", "Mesebetsi"),
+        ("YTD Revenue", "Lekeno la selemo ho fihlela hajwale"),
+        ("YTD Spend", "Tshebediso ya selemo ho fihlela hajwale"),
+        ("You are offline. Showing the data saved on this device.", "Ha o a hokahana. Ho bontshwa data e bolokilweng sesebedisweng sena."),
+        ("You are the leading bidder!", "Ke wena ya etellang pele!"),
+        ("You have been outbid. Your last bid: R {bid}", "O fetilwe ka bidi. Bidi ya hao ya ho qetela: R {bid}"),
+        ("You have unsaved changes", "O na le diphetoho tse sa bolokwang"),
+        ("Your role does not have permission to view this page. Contact your system administrator if you need access.", "Karolo ya hao ha e na tumello ya ho sheba leqephe lena. Ikopanye le motsamaisi wa sistimi ha o hloka phihlello."),
+        ("all must approve", "bohle ba tlameha ho dumella"),
+        ("another user", "mosebedisi e mong"),
+        ("are past the", "di fetile"),
+        ("at a time", "ka nako e le nngwe"),
+        ("available", "e a fumaneha"),
+        ("business day(s)", "letsatsi/matsatsi a mosebetsi"),
+        ("day payment term", "nako ya tefo ya matsatsi"),
+        ("days after the invoice is received", "matsatsi kamora hore invoice e amohelwe"),
+        ("days overdue", "matsatsi a fetileng"),
+        ("detected automatically", "e lemohuwe ka bo yona"),
+        ("documents at a time", "ditokomane ka nako e le nngwe"),
+        ("e-Tender Portal", "Portale ya e-Tender"),
+        ("e.g. Annual leave", "mohl. Phomolo ya selemo"),
+        ("e.g. important, q1, project-x", "mohl. bohlokwa, q1, projeke-x"),
+        ("e.g., 12", "mohl., 12"),
+        ("e.g., 24", "mohl., 24"),
+        ("e.g., 30", "mohl., 30"),
+        ("e.g., 30 days from invoice", "mohl., matsatsi a 30 ho tloha ho invoice"),
+        ("e.g., 36 months", "mohl., dikgwedi tse 36"),
+        ("e.g., 4", "mohl., 4"),
+        ("e.g., 99.9", "mohl., 99.9"),
+        ("e.g., Annual Procurement Plan FY 2025/26", "mohl., Moralo wa Selemo wa Theko 2025/26"),
+        ("e.g., Arbitration", "mohl., Kahlolo ka mokena-dipakeng"),
+        ("e.g., CC-IT-001", "mohl., CC-IT-001"),
+        ("e.g., CTR-2025-0001", "mohl., CTR-2025-0001"),
+        ("e.g., Each, Months", "mohl., Ka nngwe, Dikgwedi"),
+        ("e.g., Head Office, Johannesburg", "mohl., Ofisi e kgolo, Johannesburg"),
+        ("e.g., IT-LAP-001", "mohl., IT-LAP-001"),
+        ("e.g., PFMA/2025/IT/001", "mohl., PFMA/2025/IT/001"),
+        ("e.g., REQ-2025-0001", "mohl., REQ-2025-0001"),
+        ("e.g., South African Law", "mohl., Molao wa Afrika Borwa"),
+        ("e.g., TND-2025-0001", "mohl., TND-2025-0001"),
+        ("e.g., Technical Capability", "mohl., Bokgoni ba setekheniki"),
+        ("each", "ka nngwe"),
+        ("excluded", "e sa kenyelletswa"),
+        ("field(s) to check", "tshimo/masimo a lokelang ho hlahlojwa"),
+        ("held as a possible duplicate", "e tshwerwe jwalo ka kopi e ka bang teng"),
+        ("invoiced", "e kentswe ho invoice"),
+        ("invoices", "di-invoice"),
+        ("more authorisation(s) needed from someone other than", "tumello e eketsehileng e hlokahala ho motho e mong ntle le"),
+        ("of", "ho"),
+        ("page(s)", "leqephe/maqephe"),
+        ("submitted for approval:", "e rometswe bakeng sa tumello:"),
+        ("suppliers", "bafani"),
+        ("type chosen on upload", "mofuta o kgethilweng ha ho kenngwa"),
+        ("until", "ho fihlela"),
+        ("up to", "ho fihlela ho"),
+        ("{entity} not found", "{entity} ha e a fumanwa"),
+        ("{entity} {id} could not be loaded: {error}", "{entity} {id} ha e a kgona ho jarollwa: {error}"),
+        ("{entity} {id} does not exist or you no longer have access to it.", "{entity} {id} ha e yo kapa ha o sa na phihlello ho yona."),
+        ("\"{level}\" is not a level from 1 to 8", "\"{level}\" hase boemo ho tloha ho 1 ho isa ho 8"),
+        ("(empty)", "(ha ho letho)"),
+        ("31-60 days", "matsatsi a 31-60"),
+        ("61-90 days", "matsatsi a 61-90"),
+        ("90+ days", "matsatsi a 90+"),
+        ("Bulk EFT (fixed width)", "EFT ya bongata (bophara bo tsitsitseng)"),
+        ("AI Analysis", "Manollo ya AI"),
+        ("AI {category} Suggestions", "Ditlhahiso tsa AI: {category}"),
+        ("Abstain", "Ho se vote"),
+        ("Accept", "Amohela"),
+        ("Accept Variance", "Amohela phapang"),
+        ("Actual: {value}", "Sa nnete: {value}"),
+        ("Ad Hoc", "Ka nakwana"),
+        ("Adverse Opinion", "Maikutlo a fosahetseng"),
+        ("Advisory Member", "Setho se eletsang"),
+        ("Against", "Kgahlano"),
+        ("All Receipts", "Dirisiti tsohle"),
+        ("All incoming deliveries have been processed", "Dithomello tsohle tse kenang di sebeditswe"),
+        ("Analyze {entity} {id} for completeness and compliance.", "Manolla {entity} {id} hore na e phethehile mme e latela melao."),
+        ("Android", "Android"),
+        ("Android Phone", "Mohala wa Android"),
+        ("Android Tablet", "Tablete ya Android"),
+        ("Annexure", "Sehlomathiso"),
+        ("Annually", "Selemo le selemo"),
+        ("Anti-Corruption", "Ho lwantsha bobodu"),
+        ("Approval due", "Tumello e lokela"),
+        ("Approval escalated", "Tumello e nyolleditswe hodimo"),
+        ("Asset Management", "Tsamaiso ya thepa"),
+        ("Auto-extend: Auction extends by {minutes} minutes if bid placed in last {seconds} seconds", "Katoloso ya boiketsetso: fantisi e eketswa ka metsotso e {minutes} haeba bid e entswe metsotswaneng e {seconds} ya ho qetela"),
+        ("Avoid", "Qoba"),
+        ("Awaiting Signature", "E emetse tekeno"),
+        ("Award Letter", "Lengolo la kabo"),
+        ("B-BBEE Certificate", "Setifikeiti sa B-BBEE"),
+        ("B-BBEE Supplier Classifications ({count} total)", "Dihlopha tsa bafani ba B-BBEE ({count} kaofela)"),
+        ("B-BBEE Verification", "Netefatso ya B-BBEE"),
+        ("B-BBEE level", "Boemo ba B-BBEE"),
+        ("Below Target", "Ka tlase ho sepheo"),
+        ("Bid Document", "Tokomane ya bid"),
+        ("Bid Management", "Tsamaiso ya di-bid"),
+        ("Bid Status Alerts", "Ditemoso tsa boemo ba bid"),
+        ("Bid Submission", "Ho romellwa ha bid"),
+        ("Bid Submitted", "Bid e rometswe"),
+        ("Biometric Authentication", "Netefatso ka biometric"),
+        ("Black Owned (51%+)", "Ya batho ba batsho (51%+)"),
+        ("Black Women Owned (30%+)", "Ya basadi ba batsho (30%+)"),
+        ("Briefing Check-In", "Ho ingodisa sebokeng sa tlhahiso"),
+        ("Budget Transfer", "Phetisetso ya tekanyetso"),
+        ("Budgets, commitments and spend for FY {year}", "Ditekanyetso, boitlamo le tshebediso ya selemo sa ditjhelete {year}"),
+        ("CSV", "CSV"),
+        ("Capital Expenditure", "Tshebediso ya motheo"),
+        ("Certificate expiry", "Ho fela ha setifikeiti"),
+        ("Certificate period", "Nako ya setifikeiti"),
+        ("Chairperson", "Modulasetulo"),
+        ("Check in to mandatory briefing sessions using GPS location", "Ingodise dibokeng tsa tlhahiso tse qobellwang o sebedisa sebaka sa GPS"),
+        ("Closing Soon", "E kwalwa haufinyane"),
+        ("Closure", "Ho kwalwa"),
+        ("Company Registration", "Ngodiso ya k'hamphani"),
+        ("Completed Receipts", "Dirisiti tse phethilweng"),
+        ("Compliance Report", "Tlaleho ya ho latela melao"),
+        ("Comprehensive", "E phethahetseng"),
+        ("Concern", "Matshwenyeho"),
+        ("Confirm deliveries with photo evidence and timestamps", "Netefatsa dithomello ka bopaki ba ditshwantsho le ditempe tsa nako"),
+        ("Conflict of Interest", "Kgohlano ya dithahasello"),
+        ("Continuous", "E tswelang pele"),
+        ("Contract Detail", "Dintlha tsa konteraka"),
+        ("Contract Extension", "Katoloso ya konteraka"),
+        ("Contract Reminders", "Dikgopotso tsa konteraka"),
+        ("Contract Review", "Tlhahlobo ya konteraka"),
+        ("Contract Variation", "Phetoho ya konteraka"),
+        ("Create a new procurement tender", "Theha thendara e ntjha ya theko"),
+        ("Create a new purchase order", "Theha odara e ntjha ya theko"),
+        ("Create a new supplier contract", "Theha konteraka e ntjha ya mofani"),
+        ("Current (0-30 days)", "Ya hajwale (matsatsi a 0-30)"),
+        ("Current: {value}", "Hajwale: {value}"),
+        ("Cybersecurity", "Tshireletso ya inthanete"),
+        ("Daily", "Letsatsi le letsatsi"),
+        ("Deadline: {date}", "Nako ya ho qetela: {date}"),
+        ("Deferred", "E chechisitswe"),
+        ("Delayed", "E diehile"),
+        ("Delivery Confirmation", "Netefatso ya thomello"),
+        ("Delivery date", "Letsatsi la thomello"),
+        ("Deputy Chairperson", "Motlatsi wa modulasetulo"),
+        ("Designated Group", "Sehlopha se kgethilweng"),
+        ("Detailed", "Ka botlalo"),
+        ("Deviation request {reference} created successfully. It will be routed for approval.", "Kopo ya kgeloho {reference} e entswe ka katleho. E tla romellwa bakeng sa tumello."),
+        ("Digital Signature", "Tekeno ya dijithale"),
+        ("Disability Owned", "Ya batho ba nang le bokowa"),
+        ("Disclaimer of Opinion", "Ho hana ho fana ka maikutlo"),
+        ("Document Scanner", "Sekena sa ditokomane"),
+        ("Document Scanning", "Ho skena ditokomane"),
+        ("Document type", "Mofuta wa tokomane"),
+        ("Due date", "Letsatsi la tefo"),
+        ("Duplicate invoice", "Invoice e pheta-phetwang"),
+        ("EME (< R10m)", "EME (< R10m)"),
+        ("Edit Contract", "Fetola konteraka"),
+        ("Edit Purchase Order", "Fetola odara ya theko"),
+        ("Edit Requisition", "Fetola kopo ya theko"),
+        ("Edit Tender", "Fetola thendara"),
+        ("Enterprise & Supplier Development", "Ntshetsopele ya dikgwebo le bafani"),
+        ("Enterprise Development", "Ntshetsopele ya dikgwebo"),
+        ("Environmental", "Tikoloho"),
+        ("Escalate", "Nyollela hodimo"),
+        ("Evaluation Report", "Tlaleho ya tekolo"),
+        ("Events", "Diketsahalo"),
+        ("Exceeded", "E fetisitswe"),
+        ("Exempted Micro Enterprise", "Kgwebo e nyenyane haholo e lokolotsweng"),
+        ("Experience", "Boiphihlelo"),
+        ("Fail", "E hlolehile"),
+        ("Finance Representative", "Moemedi wa ditjhelete"),
+        ("Financial Analysis", "Manollo ya ditjhelete"),
+        ("Financial Proposal", "Tlhahiso ya ditjhelete"),
+        ("Financial Statements", "Dipolelo tsa ditjhelete"),
+        ("Follow-up", "Tatelo"),
+        ("Follow-up Audit", "Tlhahlobo ya tatelo"),
+        ("For", "Bakeng"),
+        ("Format", "Sebopeho"),
+        ("Fraud & Irregularities", "Bomenemene le dikgeloho"),
+        ("Full Match", "E tshwana ka botlalo"),
+        ("Fully Automated", "Ka boiketsetso ka botlalo"),
+        ("Generic (> R50m)", "E tlwaelehileng (> R50m)"),
+        ("Generic Enterprise", "Kgwebo e tlwaelehileng"),
+        ("Get instant alerts when your bid status changes during evaluation", "Fumana ditemoso hang ha boemo ba bid ya hao bo fetoha nakong ya tekolo"),
+        ("Governance", "Puso"),
+        ("Hold for Investigation", "Tshwarella bakeng sa dipatlisiso"),
+        ("IP: {address}", "IP: {address}"),
+        ("IT", "IT"),
+        ("IT & Technology", "IT le theknoloji"),
+        ("Identified exposure", "Kotsi e fumanweng"),
+        ("Implemented", "E kentswe tshebetsong"),
+        ("In Review", "E ntse e hlahlojwa"),
+        ("Info Required", "Tlhahisoleseding e a hlokahala"),
+        ("Initial Finding", "Sephetho sa pele"),
+        ("Interim Audit", "Tlhahlobo ya nakwana"),
+        ("Internal Controls", "Ditaolo tsa ka hare"),
+        ("Internal Policy", "Leano la ka hare"),
+        ("Investigate", "Batlisisa"),
+        ("Invoice date", "Letsatsi la invoice"),
+        ("Item on order", "Ntho e leng odareng"),
+        ("Labour Law", "Molao wa basebetsi"),
+        ("Last 12 Months", "Dikgwedi tse 12 tse fetileng"),
+        ("Legal", "Molao"),
+        ("Legal Advisor", "Moeletsi wa molao"),
+        ("Legal Opinion", "Maikutlo a molao"),
+        ("Level 4", "Boemo 4"),
+        ("Level 5", "Boemo 5"),
+        ("Level 6", "Boemo 6"),
+        ("Level 7", "Boemo 7"),
+        ("Level 8", "Boemo 8"),
+        ("Line items", "Dintho tsa mola"),
+        ("Live Auction", "Fantisi e phelang"),
+        ("Local Content", "Dikahare tsa lehae"),
+        ("MFMA", "MFMA"),
+        ("Maintenance & Repairs", "Tlhokomelo le tokiso"),
+        ("Management Control", "Taolo ya botsamaisi"),
+        ("Manual", "Ka letsoho"),
+        ("Military Veteran Owned", "Ya bahlabani ba sesole"),
+        ("Min Decrement: {amount}", "Phokotso e nyenyane: {amount}"),
+        ("Mobile Invoicing", "Di-invoice tsa mohala"),
+        ("Monitoring", "Tlhokomelo"),
+        ("Monthly", "Kgwedi le kgwedi"),
+        ("Most Popular", "E ratwang haholo"),
+        ("Motivation Memo", "Memo ya tshusumetso"),
+        ("NT Regulations", "Melawana ya NT"),
+        ("No CSD number", "Ha ho nomoro ya CSD"),
+        ("No Match", "Ha e tshwane"),
+        ("No completed receipts", "Ha ho dirisiti tse phethilweng"),
+        ("No goods receipts found", "Ha ho dirisiti tsa thepa tse fumanweng"),
+        ("No pending receipts", "Ha ho dirisiti tse emetseng"),
+        ("No receipts have been completed yet", "Ha ho dirisiti tse seng di phethilwe"),
+        ("Not Matched", "Ha e a bapiswa"),
+        ("Not Operating", "Ha e sebetse"),
+        ("Not Started", "Ha e a qala"),
+        ("Not required (value below R10,000,000)", "Ha e hlokahale (boleng bo ka tlase ho R10,000,000)"),
+        ("Not required (value below R2,000,000)", "Ha e hlokahale (boleng bo ka tlase ho R2,000,000)"),
+        ("Not required (value below R500,000)", "Ha e hlokahale (boleng bo ka tlase ho R500,000)"),
+        ("Notified", "E tsebisitswe"),
+        ("Offline Bid Preparation", "Ho lokisa bid ntle le inthanete"),
+        ("Operational", "Ts'ebetso"),
+        ("Operational Expenditure", "Tshebediso ya ts'ebetso"),
+        ("Ordinary", "E tlwaelehileng"),
+        ("PDF", "PDF"),
+        ("PFMA", "PFMA"),
+        ("PO exists", "Odara ya theko e teng"),
+        ("POPIA", "POPIA"),
+        ("PPPFA", "PPPFA"),
+        ("Partial Match", "E tshwana ka karolo"),
+        ("Partially Compliant", "E latela ka karolo"),
+        ("Partially Operating", "E sebetsa ka karolo"),
+        ("Participate in reverse auctions in real-time from your mobile device", "Nka karolo difantising tse kgutlang morao ka nako ya nnete o sebedisa sesebediswa sa hao sa mohala"),
+        ("Pass", "E atlehile"),
+        ("Pending Action", "E emetse ketso"),
+        ("Pending Audit", "E emetse tlhahlobo"),
+        ("Pending Receipts", "Dirisiti tse emetseng"),
+        ("Performance Audit", "Tlhahlobo ya tshebetso"),
+        ("Performance Information", "Tlhahisoleseding ya tshebetso"),
+        ("Personnel Costs", "Ditjeo tsa basebetsi"),
+        ("Place Bid", "Etsa bid"),
+        ("Placing Bid...", "E etsa bid..."),
+        ("Planned", "E rerilwe"),
+        ("Please enter a title", "Ka kopo kenya sehlooho"),
+        ("Please enter a valid amount", "Ka kopo kenya tjhelete e nepahetseng"),
+        ("Please enter supplier name", "Ka kopo kenya lebitso la mofani"),
+        ("Please provide justification", "Ka kopo fana ka lebaka"),
+        ("Please select a closing date", "Ka kopo kgetha letsatsi la ho kwala"),
+        ("Please select a publication date", "Ka kopo kgetha letsatsi la phatlalatso"),
+        ("Postponed", "E chechisitswe"),
+        ("Preferential Procurement", "Theko e kgethang"),
+        ("Prepare bid documents offline and sync when connected", "Lokisa ditokomane tsa bid ntle le inthanete mme o lumellanye ha o hokahane"),
+        ("Preview: {name}", "Sheba pele: {name}"),
+        ("Priced from the catalogue", "Theko e tswa khathalogong"),
+        ("Procedural", "Tsamaiso"),
+        ("Process", "Sebetsa"),
+        ("Process Recommendation", "Kgothaletso ya tshebetso"),
+        ("Publish to Portal", "Phatlalatsa porotaleng"),
+        ("Publishing...", "E a phatlalatsa..."),
+        ("QR Code Scanning", "Ho skena dikhoutu tsa QR"),
+        ("QSE (R10m - R50m)", "QSE (R10m - R50m)"),
+        ("Qualified Opinion", "Maikutlo a nang le dipehelo"),
+        ("Qualifying Small Enterprise", "Kgwebo e nyenyane e tshwanelehang"),
+        ("Quantity outstanding", "Palo e setseng"),
+        ("Quarterly", "Kotara ka nngwe"),
+        ("Receive push notifications when new tenders matching your profile are published", "Fumana ditsebiso ha ho phatlalatswa dithendara tse ntjha tse tshwanang le profaele ya hao"),
+        ("Recurring", "E iphetang"),
+        ("Recused", "O itokolotse"),
+        ("Reduce", "Fokotsa"),
+        ("Registration number format", "Sebopeho sa nomoro ya ngodiso"),
+        ("Regularity Audit", "Tlhahlobo ya ho latela melao"),
+        ("Regulatory", "Melawana"),
+        ("Regulatory Guidance", "Tataiso ya melawana"),
+        ("Reject Invoice", "Hana invoice"),
+        ("Reminders for upcoming contract milestones, renewals, and deliverables", "Dikgopotso tsa mehato ya konteraka e tlang, ntjhafatso le dintho tse lokelang ho fanwa"),
+        ("Reputational", "Botumo"),
+        ("Request Credit Note", "Kopa lengolo la mokitlane"),
+        ("Request Information", "Kopa tlhahisoleseding"),
+        ("Required field", "Sebaka se hlokahalang"),
+        ("Required for values above R10,000,000", "E a hlokahala bakeng sa boleng bo fetang R10,000,000"),
+        ("Required for values above R2,000,000", "E a hlokahala bakeng sa boleng bo fetang R2,000,000"),
+        ("Required for values above R500,000", "E a hlokahala bakeng sa boleng bo fetang R500,000"),
+        ("Required to ratify emergency procurement", "E a hlokahala ho tiisa theko ya tshohanyetso"),
+        ("Retired", "E tlohetswe"),
+        ("Return for Info", "Kgutlisetsa bakeng sa tlhahisoleseding"),
+        ("Returned for Info", "E kgutlisitswe bakeng sa tlhahisoleseding"),
+        ("Review Required", "Tlhahlobo e a hlokahala"),
+        ("Risk Assessment", "Tekolo ya kotsi"),
+        ("Rural", "Mahaeng"),
+        ("Rural/Township Based", "Mahaeng/makeishening"),
+        ("SCM Regulations", "Melawana ya SCM"),
+        ("Save Configuration", "Boloka tlhophiso"),
+        ("Save Features", "Boloka dikarolo"),
+        ("Saving...", "E a boloka..."),
+        ("Scan QR codes on purchase orders for quick goods receipt", "Skena dikhoutu tsa QR ho diodara tsa theko bakeng sa kamohelo e potlakileng ya thepa"),
+        ("Scan and upload documents directly from your device camera", "Skena le ho kenya ditokomane ka kotloloho ka khamera ya sesebediswa sa hao"),
+        ("Secretary", "Mongodi"),
+        ("Segregation of Duties", "Karohano ya mesebetsi"),
+        ("Semi-Automated", "Ka boiketsetso ka karolo"),
+        ("Shortlisted", "Lenaneng le lekgutshwane"),
+        ("Sign contracts electronically using your mobile device", "Saena dikonteraka ka elektroniki o sebedisa sesebediswa sa hao sa mohala"),
+        ("Skills Development", "Ntshetsopele ya bokgoni"),
+        ("Skipped", "E tlolilwe"),
+        ("Socio-Economic Development", "Ntshetsopele ya setjhaba le moruo"),
+        ("Sourcing Plan", "Moralo wa ho fumana"),
+        ("Special", "E ikgethang"),
+        ("Special Audit", "Tlhahlobo e ikgethang"),
+        ("Spend Analysis", "Manollo ya tshebediso"),
+        ("Standard", "E tlwaelehileng"),
+        ("Strategic", "Maano"),
+        ("Submit and track invoices directly from the mobile app", "Romela le ho latela di-invoice ka kotloloho ho tswa ho app ya mohala"),
+        ("Submitting...", "E a romela..."),
+        ("Supplier Development", "Ntshetsopele ya bafani"),
+        ("Supplier exists", "Mofani o teng"),
+        ("Supplier matches PO", "Mofani o tshwana le odara ya theko"),
+        ("Supply Chain Management", "Tsamaiso ya ketane ya phepelo"),
+        ("Sworn Affidavit", "Kano e ngotsweng"),
+        ("Target: {value}", "Sepheo: {value}"),
+        ("Tax Clearance", "Tumello ya lekgetho"),
+        ("Technical Advisor", "Moeletsi wa tsa theknoloji"),
+        ("Technical Proposal", "Tlhahiso ya tsa theknoloji"),
+        ("Technical Report", "Tlaleho ya tsa theknoloji"),
+        ("Tender Analysis", "Manollo ya thendara"),
+        ("Tender Detail", "Dintlha tsa thendara"),
+        ("Tender Document Analysis", "Manollo ya tokomane ya thendara"),
+        ("Tender Notifications", "Ditsebiso tsa dithendara"),
+        ("Tender Specification", "Ditlhaloso tsa thendara"),
+        ("Tender successfully published to e-Tender portal. Reference: {reference}", "Thendara e phatlaladitswe ka katleho porotaleng ya e-Tender. Tshupiso: {reference}"),
+        ("Terms & Conditions", "Dipehelo le maemo"),
+        ("Text", "Mongolo"),
+        ("The delivery is dated in the future", "Thomello e na le letsatsi la nako e tlang"),
+        ("The document type could not be determined", "Mofuta wa tokomane ha o a ka wa fumanwa"),
+        ("The due date is not after the invoice date", "Letsatsi la tefo ha le kamora letsatsi la invoice"),
+        ("The invoice is dated in the future", "Invoice e na le letsatsi la nako e tlang"),
+        ("This month", "Kgweding ena"),
+        ("Threshold Breach", "Ho tlola moedi"),
+        ("Total calculation", "Palo ya kakaretso"),
+        ("Total is the subtotal plus VAT", "Kakaretso ke kakaretso e nyenyane hammoho le VAT"),
+        ("Transaction", "Thekiso"),
+        ("Travel & Subsistence", "Maeto le boipheliso"),
+        ("Try adjusting your filters or create a new goods receipt", "Leka ho fetola difilthara tsa hao kapa o thehe risiti e ntjha ya thepa"),
+        ("Type (A-Z)", "Mofuta (A-Z)"),
+        ("Type (Z-A)", "Mofuta (Z-A)"),
+        ("Under Evaluation", "E ntse e hlahlojwa"),
+        ("Under Investigation", "E ntse e batlisiswa"),
+        ("Unqualified with Findings", "Ntle le dipehelo, ho na le diphetho"),
+        ("Unsuccessful", "Ha e a atleha"),
+        ("Unverified", "Ha e a netefatswa"),
+        ("Update Needed", "Ntjhafatso e a hlokahala"),
+        ("Update contract details", "Ntjhafatsa dintlha tsa konteraka"),
+        ("Update purchase order details", "Ntjhafatsa dintlha tsa odara ya theko"),
+        ("Update tender details", "Ntjhafatsa dintlha tsa thendara"),
+        ("Use fingerprint or face recognition for secure authentication", "Sebedisa monwana kapa ho tsebahala ha sefahleho bakeng sa netefatso e sireletsehileng"),
+        ("VAT calculation", "Palo ya VAT"),
+        ("VAT is {rate}% of the subtotal", "VAT ke {rate}% ya kakaretso e nyenyane"),
+        ("VAT number format", "Sebopeho sa nomoro ya VAT"),
+        ("VAT of {vat} should be {expected}", "VAT ya {vat} e lokela ho ba {expected}"),
+        ("Verification", "Netefatso"),
+        ("Verify", "Netefatsa"),
+        ("Voting Member", "Setho se votang"),
+        ("Warning", "Temoso"),
+        ("Weekly", "Beke le beke"),
+        ("Withdrawn", "E hulitswe"),
+        ("You", "Wena"),
+        ("Youth", "Batjha"),
+        ("Youth Owned", "Ya batjha"),
+        ("achieved", "e fihlelletswe"),
+        ("assessed", "e hlahlobilwe"),
+        ("bonus", "bonase"),
+        ("draft goods receipt", "moralo wa risiti ya thepa"),
+        ("draft invoice", "moralo wa invoice"),
+        ("faster than target", "kapele ho feta sepheo"),
+        ("from last month", "ho tloha kgweding e fetileng"),
+        ("from last quarter", "ho tloha kotareng e fetileng"),
+        ("from last year", "ho tloha selemong se fetileng"),
+        ("iOS", "iOS"),
+        ("iPad", "iPad"),
+        ("iPhone", "iPhone"),
+        ("in last 30 days", "matsatsing a 30 a fetileng"),
+        ("new this month", "tse ntjha kgweding ena"),
+        ("non-compliant", "ha e latele melao"),
+        ("of budget", "ya tekanyetso"),
+        ("of total spend", "ya tshebediso yohle"),
+        ("of users", "ya basebedisi"),
+        ("of {points} points", "ho dintlha tse {points}"),
+        ("remaining", "e setseng"),
+        ("require attention", "di hloka tlhokomelo"),
+        ("same amount within 7 days", "tjhelete e tshwanang ka hara matsatsi a 7"),
+        ("same invoice number", "nomoro e tshwanang ya invoice"),
+        ("similar invoice number", "nomoro e batlang e tshwana ya invoice"),
+        ("supplier certificate", "setifikeiti sa mofani"),
+        ("target", "sepheo"),
+        ("total value", "boleng bohle"),
+        ("under budget", "ka tlase ho tekanyetso"),
+        ("vs budget", "ha ho bapiswa le tekanyetso"),
+        ("{change} was not applied: {reason}", "{change} ha e a kengwa tshebetsong: {reason}"),
+        ("{completed}/{planned} tenders", "dithendara tse {completed}/{planned}"),
+        ("{count} open risk indicators - Risk Score: {score}", "Matshwao a kotsi a bulehileng a {count} - Dintlha tsa kotsi: {score}"),
+        ("{date} at {time}", "{date} ka {time}"),
+        ("{date} by {name}", "{date} ke {name}"),
+        ("{days} days", "matsatsi a {days}"),
+        ("{days} days (per Treasury Regulations)", "matsatsi a {days} (ho ya ka Melawana ya Letlotlo)"),
+        ("{days} days (per Treasury Regulations); closes no earlier than {date}", "matsatsi a {days} (ho ya ka Melawana ya Letlotlo); e kwalwa pele ho {date} ho hang"),
+        ("{done} of {total} milestones", "mehato e {done} ho e {total}"),
+        ("{field} \"{value}\" is not a valid value", "{field} \"{value}\" hase boleng bo nepahetseng"),
+        ("{field} was not found", "{field} ha e a fumanwa"),
+        ("{item} is not on {po_number}", "{item} ha e yo ho {po_number}"),
+        ("{name} ({code}) at {percent} utilisation", "{name} ({code}) e sebedisitswe ka {percent}"),
+        ("{name} ({code}) exceeds its budget by {amount}", "{name} ({code}) e fetisa tekanyetso ya yona ka {amount}"),
+        ("{name} is in the supplier registry", "{name} o rejistareng ya bafani"),
+        ("{name} is not in the supplier registry", "{name} ha a yo rejistareng ya bafani"),
+        ("{number} has not been registered", "{number} ha e so ngodiswe"),
+        ("{number} is a valid CIPC number", "{number} ke nomoro ya CIPC e nepahetseng"),
+        ("{number} is a valid VAT number", "{number} ke nomoro ya VAT e nepahetseng"),
+        ("{number} is already registered as {invoice_id}", "{number} e se e ngodisitswe jwalo ka {invoice_id}"),
+        ("{number} is not a 10-digit VAT number starting with 4", "{number} hase nomoro ya VAT ya dinomoro tse 10 e qalang ka 4"),
+        ("{number} is not in the form YYYY/NNNNNN/NN", "{number} ha e sebopehong sa YYYY/NNNNNN/NN"),
+        ("{percent} approval rate", "sekgahla sa tumello sa {percent}"),
+        ("{percent} of contract", "{percent} ya konteraka"),
+        ("{percent} of total", "{percent} ya kakaretso"),
+        ("{percent} saved", "{percent} e bolokilwe"),
+        ("{po_number} issued to {supplier}", "{po_number} e fuwe {supplier}"),
+        ("{po_number} was issued to {supplier}, not {scanned}", "{po_number} e fuwe {supplier}, eseng {scanned}"),
+        ("{quantity} {item} delivered but only {outstanding} outstanding on {po_number}", "{quantity} {item} di rometswe empa ho setse {outstanding} feela ho {po_number}"),
+        ("{quantity}+ at {price}", "{quantity}+ ka {price}"),
+        ("{reference} - Tender {tender}", "{reference} - Thendara {tender}"),
+        ("{start} to {end}", "{start} ho isa ho {end}"),
+        ("{supplier} - ordered {date}", "{supplier} - e odarilwe ka {date}"),
+        ("A conversion started by {name} on {date} did not finish. Resuming it reuses anything it already created.", "Phetoho e qadilweng ke {name} ka {date} ha e a phethwa. Ho e tswela pele ho sebedisa hape eng kapa eng e seng e entswe."),
+        ("Resume Conversion", "Tswela pele ka phetoho"),
+        ("No extraction engine configured", "Ha ho enjene ya ho ntsha e hlophisitsweng"),
+        ("Scanned documents cannot be read until an OCR service is connected. Capture them by hand for now.", "Ditokomane tse skenilweng di ke ke tsa balwa ho fihlela tshebeletso ya OCR e hokahanngwa. Di kenye ka letsoho hajwale."),
+        ("The application stopped after an unexpected error. Reload to continue; changes not yet saved on this page may be lost.", "Tshebediso e emisitse ka mora phoso e sa lebellwang. Kenya hape ho tswela pele; diphetoho tse so kang di bolokwa leqepheng lena di ka lahleha."),
+        ("Reload", "Kenya hape"),
+        ("This page failed to load. The rest of the application is unaffected.", "Leqephe lena ha le a kgona ho jarolla. Karolo e nngwe ya tshebediso e ntse e sebetsa."),
+        ("Enter an amount such as {example}", "Kenya chelete e kang {example}"),
+        ("Apply SLAs now", "Sebedisa di-SLA hona jwale"),
+    ],
+    plurals: &[
+        ("{n} unread notification", ["{n} tsebiso e sa balwang", "{n} ditsebiso tse sa balwang"]),
+        ("{n} day", ["{n} letsatsi", "{n} matsatsi"]),
+        ("{n} minute", ["{n} motsotso", "{n} metsotso"]),
+        ("{n} hour", ["{n} hora", "{n} dihora"]),
+        ("Back online. Sending {n} queued change...", ["O hokahane hape. Ho romelwa phetoho e {n} e moleng...", "O hokahane hape. Ho romelwa diphetoho tse {n} tse moleng..."]),
+        ("Catalogue lines split into {n} purchase order, one per supplier.", ["Mela ya khatalogo e arotswe ho ba taelo ya theko e {n}, e le nngwe ka mofani.", "Mela ya khatalogo e arotswe ho ba ditaelo tsa theko tse {n}, e le nngwe ka mofani."]),
+        ("Repeat finding, outstanding for {n} year", ["Phumano e pheta-phetwang, e ntse e emetse selemo se {n}", "Phumano e pheta-phetwang, e ntse e emetse dilemo tse {n}"]),
+        ("You are offline. {n} change saved on this device will be sent when the connection returns.", ["Ha o a hokahana. Phetoho e {n} e bolokilweng sesebedisweng sena e tla romelwa ha kgokahano e kgutla.", "Ha o a hokahana. Diphetoho tse {n} tse bolokilweng sesebedisweng sena di tla romelwa ha kgokahano e kgutla."]),
+        ("You have {n} unread notification", ["O na le tsebiso e {n} e sa balwang", "O na le ditsebiso tse {n} tse sa balwang"]),
+        ("All items are catalogue items under contract: {n} purchase order can be raised directly.", ["Dintho tsohle ke tsa khathalogo tlasa konteraka: odara ya theko e {n} e ka etswa ka kotloloho.", "Dintho tsohle ke tsa khathalogo tlasa konteraka: diodara tsa theko tse {n} di ka etswa ka kotloloho."]),
+        ("{n} active", ["{n} e sebetsang", "{n} tse sebetsang"]),
+        ("{n} bidder", ["mobidi a {n}", "babidi ba {n}"]),
+        ("{n} change", ["phetoho e {n}", "diphetoho tse {n}"]),
+        ("{n} check", ["tlhahlobo e {n}", "ditlhahlobo tse {n}"]),
+        ("{n} closing soon", ["{n} e kwalwang haufinyane", "{n} tse kwalwang haufinyane"]),
+        ("{n} completed", ["{n} e phethilweng", "{n} tse phethilweng"]),
+        ("{n} contract expiring within 30 days. Review and initiate renewal process.", ["Konteraka e {n} e fela ka hara matsatsi a 30. Hlahloba mme o qale tshebetso ya ntjhafatso.", "Dikonteraka tse {n} di fela ka hara matsatsi a 30. Hlahloba mme o qale tshebetso ya ntjhafatso."]),
+        ("{n} criterion defined", ["Mokgwa wa tekolo o {n} o hlalositswe", "Mekgwa ya tekolo e {n} e hlalositswe"]),
+        ("{n} critical", ["{n} e matla", "{n} tse matla"]),
+        ("{n} critical, attention needed", ["{n} e matla, tlhokomelo e a hlokahala", "{n} tse matla, tlhokomelo e a hlokahala"]),
+        ("{n} day left", ["ho setse letsatsi le {n}", "ho setse matsatsi a {n}"]),
+        ("{n} document", ["tokomane e {n}", "ditokomane tse {n}"]),
+        ("{n} expiring in 30 days", ["{n} e fela ka matsatsi a 30", "{n} tse felang ka matsatsi a 30"]),
+        ("{n} field changed", ["sebaka se {n} se fetotswe", "dibaka tse {n} di fetotswe"]),
+        ("{n} file", ["faele e {n}", "difaele tse {n}"]),
+        ("{n} high/extreme", ["{n} e phahameng/e feteletseng", "{n} tse phahameng/tse feteletseng"]),
+        ("{n} ineffective", ["{n} e sa sebetseng", "{n} tse sa sebetseng"]),
+        ("{n} issue", ["bothata bo {n}", "mathata a {n}"]),
+        ("{n} item", ["ntho e {n}", "dintho tse {n}"]),
+        ("{n} item pending approval. Review and approve to make it available.", ["Ntho e {n} e emetse tumello. Hlahloba mme o dumele hore e fumanehe.", "Dintho tse {n} di emetse tumello. Hlahloba mme o dumele hore di fumanehe."]),
+        ("{n} line", ["mola o {n}", "mela e {n}"]),
+        ("{n} material", ["{n} e bohlokwa", "{n} tse bohlokwa"]),
+        ("{n} milestone is overdue. Please review and take action.", ["Mohato o {n} o fetile nako. Ka kopo hlahloba mme o nke kgato.", "Mehato e {n} e fetile nako. Ka kopo hlahloba mme o nke kgato."]),
+        ("{n} overdue", ["{n} e fetileng nako", "{n} tse fetileng nako"]),
+        ("{n} plan in total", ["moralo o {n} kaofela", "merero e {n} kaofela"]),
+        ("{n} purchase order pending delivery. Track and confirm deliveries.", ["Odara ya theko e {n} e emetse thomello. Latela mme o netefatse dithomello.", "Diodara tsa theko tse {n} di emetse thomello. Latela mme o netefatse dithomello."]),
+        ("{n} receipt pending inspection. Complete quality checks before acceptance.", ["Risiti e {n} e emetse tlhahlobo. Phetha ditlhahlobo tsa boleng pele ho kamohelo.", "Dirisiti tse {n} di emetse tlhahlobo. Phetha ditlhahlobo tsa boleng pele ho kamohelo."]),
+        ("{n} supplier", ["mofani a {n}", "bafani ba {n}"]),
+        ("{n} this month", ["{n} kgweding ena", "{n} kgweding ena"]),
+        ("{n} under evaluation", ["{n} e ntse e hlahlojwa", "{n} tse ntse di hlahlojwa"]),
+        ("{n} urgent", ["{n} e potlakileng", "{n} tse potlakileng"]),
+        ("{n} urgent (>5 days)", ["{n} e potlakileng (>matsatsi a 5)", "{n} tse potlakileng (>matsatsi a 5)"]),
+        ("{n} user", ["mosebedisi a {n}", "basebedisi ba {n}"]),
+        ("{n} requisition updated", ["{n} kopo e ntjhafaditswe", "{n} dikopo di ntjhafaditswe"]),
+    ],
+};
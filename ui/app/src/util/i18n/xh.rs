@@ -0,0 +1,2005 @@
+//! isiXhosa message catalogue
+
+use super::Catalogue;
+
+pub const CATALOGUE: Catalogue = Catalogue {
+    messages: &[
+        ("Overview", "Isishwankathelo"),
+        ("Dashboard", "Ideshibhodi"),
+        ("Procurement", "Ukuthengwa kweempahla"),
+        ("Requisitions", "Izicelo"),
+        ("Tenders", "Iithenda"),
+        ("Evaluation", "Uvavanyo"),
+        ("Contracts", "Iikhontrakthi"),
+        ("Purchase Orders", "Ii-odolo zokuthenga"),
+        ("Goods Receipt", "Ukufunyanwa kweempahla"),
+        ("Suppliers", "Ababoneleli"),
+        ("Registry", "Irejista"),
+        ("Performance", "Ukusebenza"),
+        ("Risk", "Umngcipheko"),
+        ("Supplier Portal", "Iphotali yababoneleli"),
+        ("Catalogue", "Ikhathalogu"),
+        ("Browse", "Khangela"),
+        ("Admin", "Ulawulo"),
+        ("Compliance", "Ukuthobela"),
+        ("Analytics", "Uhlalutyo"),
+        ("Audit Trail", "Umkhondo wophicotho"),
+        ("GRC Dashboard", "Ideshibhodi ye-GRC"),
+        ("NBAC Reviews", "Uphononongo lwe-NBAC"),
+        ("AGSA Reviews", "Uphononongo lwe-AGSA"),
+        ("B-BBEE Goals", "Iinjongo ze-B-BBEE"),
+        ("Special Sourcing", "Ukuthenga okukhethekileyo"),
+        ("Reverse Auction", "Ifandesi eguqulweyo"),
+        ("Sourcing Plans", "Izicwangciso zokuthenga"),
+        ("Tools", "Izixhobo"),
+        ("Documents", "Amaxwebhu"),
+        ("AI Assistant", "Umncedisi we-AI"),
+        ("Search requisitions, tenders, suppliers...", "Khangela izicelo, iithenda, ababoneleli..."),
+        ("Notifications", "Izaziso"),
+        ("Help", "Uncedo"),
+        ("Sign out", "Phuma"),
+        ("Language", "Ulwimi"),
+        ("Mark all read", "Phawula zonke njengezifundiweyo"),
+        ("Settings", "Iisetingi"),
+        ("Back", "Emva"),
+        ("Notification settings", "Iisetingi zezaziso"),
+        ("You're all caught up", "Akukho zaziso zintsha"),
+        ("No matches", "Akukho ziphumo"),
+        ("Searching...", "Iyakhangela..."),
+        ("Page not found", "Iphepha alifumanekanga"),
+        ("Back to Dashboard", "Buyela kwideshibhodi"),
+        ("Try again", "Zama kwakhona"),
+        ("Something went wrong", "Kukho into engahambanga kakuhle"),
+        ("Draft", "Uyilo"),
+        ("Submitted", "Ingenisiwe"),
+        ("Pending Approval", "Ilinde ukuvunywa"),
+        ("Approved", "Ivunyiwe"),
+        ("Rejected", "Yaliwe"),
+        ("Cancelled", "Irhoxisiwe"),
+        ("In Progress", "Iyaqhubeka"),
+        ("Complete", "Igqityiwe"),
+        ("Active", "Iyasebenza"),
+        ("Published", "Ipapashiwe"),
+        ("Pending", "Ilindile"),
+        ("Expired", "Iphelelwe lixesha"),
+        ("Failed", "Ayiphumelelanga"),
+        ("New", "Entsha"),
+        ("Open", "Ivuliwe"),
+        ("Review", "Uphononongo"),
+        ("On Hold", "Inqunyanyisiwe"),
+        ("Scheduled", "Icwangcisiwe"),
+        ("Closed", "Ivaliwe"),
+        ("Adjudication", "Isigqibo"),
+        ("Awarded", "Inikezelwe"),
+        ("Low", "Phantsi"),
+        ("Medium", "Phakathi"),
+        ("High", "Phezulu"),
+        ("Urgent", "Ingxamisekile"),
+        ("Request for Quotation", "Isicelo sexabiso"),
+        ("Request for Proposal", "Isicelo sesindululo"),
+        ("Request for Tender", "Isicelo sethenda"),
+        ("Bids", "Iibhidi"),
+        ("Payments", "Iintlawulo"),
+        ("System", "Inkqubo"),
+        ("Auctions", "Iifandesi"),
+        ("Approvals", "Ukuvunywa"),
+        ("Certificates", "Izatifikethi"),
+        ("Meetings", "Iintlanganiso"),
+        ("Today", "Namhlanje"),
+        ("Approval awaiting you", "Ukuvunywa kulindele wena"),
+        ("Tender closing", "Ithenda iyavalwa"),
+        ("Contract expiring", "Ikhontrakthi iyaphelelwa lixesha"),
+        ("Certificate expiring", "Isatifikethi siyaphelelwa lixesha"),
+        ("NBAC meeting scheduled", "Intlanganiso ye-NBAC icwangcisiwe"),
+        ("There is no page at {path}. Check the link or use the sidebar to find what you need.", "Akukho phepha ku-{path}. Jonga ikhonkco okanye usebenzise ibha esecaleni ukufumana into oyifunayo."),
+        ("Just now", "Kanye ngoku"),
+        ("Yesterday", "Izolo"),
+        ("Tomorrow", "Ngomso"),
+        ("{time} ago", "{time} edlulileyo"),
+        ("in {time}", "emva kwe-{time}"),
+        (" (R)", " (R)"),
+        (" (must equal 100%)", " (kufuneka kulingane ne-100%)"),
+        (" Upload Document", " Layisha uxwebhu"),
+        (" incl. VAT", " kuquka i-VAT"),
+        (" supplier B-BBEE certificates expiring within 90 days", " izatifikethi ze-B-BBEE zababoneleli eziphelelwa lixesha kwiintsuku ezingama-90"),
+        ("% of Total", "% yesixa"),
+        ("+ Add Category", "+ Yongeza udidi"),
+        ("+ Add Criterion", "+ Yongeza umgaqo wovavanyo"),
+        ("+ Add Line Item", "+ Yongeza into yomgca"),
+        ("+ Add Milestone", "+ Yongeza inqanaba elibalulekileyo"),
+        ("+ Add Objective", "+ Yongeza injongo"),
+        ("+ New Requisition", "+ Isicelo esitsha"),
+        ("+ New Sourcing Plan", "+ Isicwangciso esitsha sokufumana"),
+        (", the following approvals will be required before proceeding.", ", ezi mvume zilandelayo ziya kufuneka phambi kokuqhubeka."),
+        ("1 day", "usuku olu-1"),
+        ("1 hour", "iyure e-1"),
+        ("14 days", "iintsuku ezili-14"),
+        ("15 minutes", "imizuzu eli-15"),
+        ("2 hours", "iiyure ezi-2"),
+        ("3 days", "iintsuku ezi-3"),
+        ("30 minutes", "imizuzu engama-30"),
+        ("7 days", "iintsuku ezi-7"),
+        ("AGSA - Audit Findings", "AGSA - Iziphumo zophicotho"),
+        ("AGSA Audit Findings", "Iziphumo zophicotho ze-AGSA"),
+        ("AGSA Comments", "Izimvo ze-AGSA"),
+        ("AI Procurement Assistant", "Umncedisi wokuthenga we-AI"),
+        ("Accepted", "Kwamkelwe"),
+        ("Accepted goods", "Iimpahla ezamkelweyo"),
+        ("Access denied", "Ufikelelo lwaliwe"),
+        ("Accounting Officer Approval", "Imvume yeGosa leeAkhawunti"),
+        ("Acknowledged", "Kuvunyiwe ukufunyanwa"),
+        ("Action", "Inyathelo"),
+        ("Action Details", "Iinkcukacha zenyathelo"),
+        ("Action Items", "Izinto ekufuneka zenziwe"),
+        ("Action Plan", "Isicwangciso sentshukumo"),
+        ("Action Type", "Uhlobo lwenyathelo"),
+        ("Actions", "Amanyathelo"),
+        ("Actions Completed", "Amanyathelo agqityiweyo"),
+        ("Actions Overdue", "Amanyathelo adlulelwe lixesha"),
+        ("Activate", "Yenza isebenze"),
+        ("Active Bidders ({count})", "Abathenda abasebenzayo ({count})"),
+        ("Active Bids", "Iibhidi ezisebenzayo"),
+        ("Active Contracts", "Iikhontrakthi ezisebenzayo"),
+        ("Active Items", "Izinto ezisebenzayo"),
+        ("Active Members", "Amalungu asebenzayo"),
+        ("Active Plans", "Izicwangciso ezisebenzayo"),
+        ("Active Requisitions", "Izicelo ezisebenzayo"),
+        ("Active Risks", "Imingcipheko ekhoyo"),
+        ("Active Suppliers", "Ababoneleli abasebenzayo"),
+        ("Active Users", "Abasebenzisi abasebenzayo"),
+        ("Active:", "Iyasebenza:"),
+        ("Actual", "Okwenyani"),
+        ("Actual Spend", "Inkcitho yokwenyani"),
+        ("Add", "Yongeza"),
+        ("Add Catalogue Item", "Yongeza into yekhathalogu"),
+        ("Add Control", "Yongeza ulawulo"),
+        ("Add Deliverable", "Yongeza okuziswayo"),
+        ("Add Item", "Yongeza into"),
+        ("Add Line Item", "Yongeza into yomgca"),
+        ("Add Milestone", "Yongeza inqanaba elibalulekileyo"),
+        ("Add Note", "Yongeza inqaku"),
+        ("Add Risk", "Yongeza umngcipheko"),
+        ("Add Supplier", "Yongeza umboneleli"),
+        ("Add Target", "Yongeza ithagethi"),
+        ("Add, edit, and manage catalogue items", "Yongeza, hlela uze ulawule izinto zekhathalogu"),
+        ("Additional Information", "Ulwazi olongezelelweyo"),
+        ("Additional Notes", "Amanqaku ongezelelweyo"),
+        ("Address", "Idilesi"),
+        ("Address Line 2", "Umgca wedilesi 2"),
+        ("Address:", "Idilesi:"),
+        ("Administration", "Ulawulo"),
+        ("Adverse", "Okungalunganga"),
+        ("After authorisation", "Emva kogunyaziso"),
+        ("Agenda Items", "Izinto ezikwi-ajenda"),
+        ("Aging", "Ubudala"),
+        ("Alerts", "Izilumkiso"),
+        ("All", "Konke"),
+        ("All Actions", "Onke amanyathelo"),
+        ("All Categories", "Zonke iindidi"),
+        ("All Departments", "Onke amasebe"),
+        ("All Documents", "Onke amaxwebhu"),
+        ("All Entities", "Onke amaziko"),
+        ("All Levels", "Onke amanqanaba"),
+        ("All Provinces", "Onke amaphondo"),
+        ("All Receipts ({count})", "Zonke iirisithi ({count})"),
+        ("All Requisitions", "Zonke izicelo"),
+        ("All Risk Levels", "Onke amanqanaba omngcipheko"),
+        ("All Sourcing Plans", "Zonke izicwangciso zokufumana"),
+        ("All Spend Targets", "Zonke iithagethi zenkcitho"),
+        ("All Statuses", "Zonke iimeko"),
+        ("All Suppliers", "Bonke ababoneleli"),
+        ("All Types", "Zonke iintlobo"),
+        ("All Years", "Yonke iminyaka"),
+        ("All budgets are within their thresholds", "Zonke iibhajethi zingaphakathi kwemida yazo"),
+        ("All documents", "Onke amaxwebhu"),
+        ("All statuses", "Zonke iimeko"),
+        ("Allocated", "Kwabiwe"),
+        ("Allocated Budget", "Ibhajethi eyabiweyo"),
+        ("Allocated to Categories", "Kwabelwe iindidi"),
+        ("Almost Certain", "Phantse kuqinisekile"),
+        ("Already on", "Sele ikwi"),
+        ("Also publish to internal supplier portal", "Papasha nakwiphothali yangaphakathi yababoneleli"),
+        ("Amount", "Isixa"),
+        ("Amount limit", "Umda wesixa"),
+        ("Annual procurement planning and budget management", "Ucwangciso lokuthenga lonyaka nolawulo lwebhajethi"),
+        ("Any additional information...", "Naluphi na ulwazi olongezelelweyo..."),
+        ("Any additional notes to display on the portal", "Nawaphi na amanqaku ongezelelweyo aza kuboniswa kwiphothali"),
+        ("Any notes or comments for the supplier", "Nawaphi na amanqaku okanye izimvo zomboneleli"),
+        ("App Configuration", "Uqwalaselo lwe-app"),
+        ("App Features", "Iimpawu ze-app"),
+        ("App Name", "Igama le-app"),
+        ("App Preview", "Ukujonga kwangaphambili i-app"),
+        ("App Version", "Inguqulelo ye-app"),
+        ("Applicable for existing strategic relationships that provide significant value. Must demonstrate strategic importance and value.", "Kusebenza kubudlelwane obukhoyo bobuchule obunika ixabiso elibalulekileyo. Kufuneka kubonakaliswe ukubaluleka kobuchule nexabiso."),
+        ("Applicable for urgent requirements due to unforeseen circumstances. Must demonstrate immediate need and inability to follow normal process.", "Kusebenza kwiimfuno ezingxamisekileyo ngenxa yeemeko ebezingalindelekanga. Kufuneka kubonakaliswe imfuno ekhawulezileyo nokungakwazi ukulandela inkqubo eqhelekileyo."),
+        ("Applicable when only one supplier can provide the specific goods or services required. Must demonstrate that no reasonable alternative exists.", "Kusebenza xa kukho umboneleli omnye kuphela onokubonelela ngeempahla okanye iinkonzo ezifunekayo. Kufuneka kubonakaliswe ukuba akukho enye indlela esengqiqweni."),
+        ("Applicable when supplier has exclusive rights through patents, licenses, or proprietary technology. Must provide evidence of exclusivity.", "Kusebenza xa umboneleli enamalungelo akhethekileyo ngamalungelo awodwa omenzi, iilayisensi okanye itekhnoloji yakhe. Kufuneka anike ubungqina bokukhetheka."),
+        ("Apply", "Sebenzisa"),
+        ("Apply Corrections", "Sebenzisa izilungiso"),
+        ("Approval", "Imvume"),
+        ("Approval Delegations", "Ugqithiselo lweemvume"),
+        ("Approval Rate", "Izinga lokuvunywa"),
+        ("Approval Requirements", "Iimfuno zemvume"),
+        ("Approval Turnaround", "Ixesha lokuvunywa"),
+        ("Approval Workflow", "Inkqubo yokuvunywa"),
+        ("Approval steps past their SLA and steps escalated to the next authority", "Amanyathelo okuvunywa adlule i-SLA yawo namanyathelo adluliselwe kwigunya elilandelayo"),
+        ("Approvals Needing Attention", "Iimvume ezifuna ingqwalasela"),
+        ("Approvals delegated to", "Iimvume zigqithiselwe ku"),
+        ("Approve", "Vuma"),
+        ("Approve for Payment", "Vumela intlawulo"),
+        ("Approved by", "Kuvunywe ngu"),
+        ("Approved, Not on a Run", "Kuvunyiwe, akukho kumjikelo"),
+        ("Approver", "Ovumayo"),
+        ("Archive", "Gcina koovimba"),
+        ("Archived", "Kugcinwe koovimba"),
+        ("Are you sure you want to delete this catalogue item? This action cannot be undone.", "Uqinisekile ukuba ufuna ukucima le nto yekhathalogu? Eli nyathelo alinakubuyiselwa umva."),
+        ("Ask me anything about procurement...", "Ndibuze nantoni na malunga nokuthenga..."),
+        ("Assigned To", "Kwabelwe"),
+        ("At Risk", "Esemngciphekweni"),
+        ("Attachments", "Okuqhotyoshelweyo"),
+        ("Attendance Mandatory", "Ukubakho kunyanzelekile"),
+        ("Auction", "Ifandesi"),
+        ("Auction Participations", "Ukuthatha inxaxheba kwifandesi"),
+        ("Auction is not currently live", "Ifandesi ayiqhubeki ngoku"),
+        ("Auctions ({count} total)", "Iifandesi ({count} zizonke)"),
+        ("Audit Entries ({count})", "Amangeniso ophicotho ({count})"),
+        ("Audit Findings", "Iziphumo zophicotho"),
+        ("Audit Reports", "Iingxelo zophicotho"),
+        ("Auditor-General South Africa findings, action items, and compliance tracking", "Iziphumo zoMphicothi-Zincwadi Jikelele woMzantsi Afrika, izinto ekufuneka zenziwe nokulandelela ukuthobela"),
+        ("Auth", "Ugunyaziso"),
+        ("Authorisation", "Ugunyaziso"),
+        ("Authorisations", "Ugunyaziso"),
+        ("Authorise Run", "Gunyazisa umjikelo"),
+        ("Authorization", "Ugunyaziso"),
+        ("Availability (%)", "Ukufumaneka (%)"),
+        ("Available", "Iyafumaneka"),
+        ("Available Budget", "Ibhajethi efumanekayo"),
+        ("Average Confidence", "Ukuqiniseka okuphakathi"),
+        ("Avg Attendance", "Ukubakho okuphakathi"),
+        ("Avg Resolution Days", "Iintsuku eziphakathi zokusombulula"),
+        ("Avg Turnaround", "Ixesha eliphakathi lokuphendula"),
+        ("Avg. Business Days", "Iintsuku zomsebenzi eziphakathi"),
+        ("Avg. Performance", "Ukusebenza okuphakathi"),
+        ("Avg. Procurement Cycle", "Umjikelo wokuthenga ophakathi"),
+        ("Awaiting", "Kulindile"),
+        ("Awaiting Authorisation", "Kulinde ugunyaziso"),
+        ("Awaiting Inspection:", "Kulinde uhlolo:"),
+        ("Awaiting Review", "Kulinde uphononongo"),
+        ("Awaiting approver", "Kulinde ovumayo"),
+        ("Award", "Nika"),
+        ("Awarded Contracts", "Iikhontrakthi ezinikiweyo"),
+        ("B-BBEE", "B-BBEE"),
+        ("B-BBEE %", "B-BBEE %"),
+        ("B-BBEE Compliance Metrics", "Imilinganiselo yokuthobela i-B-BBEE"),
+        ("B-BBEE Compliant", "Iyathobela i-B-BBEE"),
+        ("B-BBEE Level", "Inqanaba le-B-BBEE"),
+        ("B-BBEE Level Breakdown", "Ukwahlulwa kwamanqanaba e-B-BBEE"),
+        ("B-BBEE Performance", "Ukusebenza kwe-B-BBEE"),
+        ("B-BBEE Preferential Procurement Scorecard", "Ikhadi lamanqaku okuthenga okukhethwayo kwe-B-BBEE"),
+        ("B-BBEE Spend", "Inkcitho ye-B-BBEE"),
+        ("B-BBEE Spend Distribution", "Unikezelo lwenkcitho ye-B-BBEE"),
+        ("B-BBEE Spend Trend", "Umkhwa wenkcitho ye-B-BBEE"),
+        ("B-BBEE Weight", "Ubunzima be-B-BBEE"),
+        ("B-BBEE Weight (%)", "Ubunzima be-B-BBEE (%)"),
+        ("B-BBEE certificates are valid for twelve months from the date of issue", "Izatifikethi ze-B-BBEE zisebenza iinyanga ezilishumi elinesibini ukusukela kumhla wokukhutshwa"),
+        ("Back to AGSA Reviews", "Buyela kuphononongo lwe-AGSA"),
+        ("Back to Auction List", "Buyela kuluhlu lweefandesi"),
+        ("Back to Auctions", "Buyela kwiifandesi"),
+        ("Back to Contracts", "Buyela kwiikhontrakthi"),
+        ("Back to Document Intake", "Buyela kulwamkelo lwamaxwebhu"),
+        ("Back to Evaluation List", "Buyela kuluhlu lovavanyo"),
+        ("Back to Evaluations", "Buyela kuvavanyo"),
+        ("Back to Goods Receipts", "Buyela kulwamkelo lweempahla"),
+        ("Back to Invoices", "Buyela kwii-invoyisi"),
+        ("Back to Library", "Buyela kwithala"),
+        ("Back to Milestones", "Buyela kumanqanaba abalulekileyo"),
+        ("Back to Payment Runs", "Buyela kwimijikelo yentlawulo"),
+        ("Back to Purchase Orders", "Buyela kwii-odolo zokuthenga"),
+        ("Back to Registry", "Buyela kwirejista"),
+        ("Back to Requisitions", "Buyela kwizicelo"),
+        ("Back to Reviews", "Buyela kuphononongo"),
+        ("Back to Suppliers", "Buyela kubaboneleli"),
+        ("Back to Tender", "Buyela kwithenda"),
+        ("Back to Tenders", "Buyela kwiithenda"),
+        ("Bank Account", "I-akhawunti yebhanki"),
+        ("Bank Files", "Iifayile zebhanki"),
+        ("Bank account or branch code invalid", "I-akhawunti yebhanki okanye ikhowudi yesebe ayisebenzi"),
+        ("Based on estimated value of ", "Ngokusekelwe kwixabiso eliqikelelweyo lika "),
+        ("Basic Info", "Ulwazi olusisiseko"),
+        ("Basic Information", "Ulwazi olusisiseko"),
+        ("Batch approved invoices, authorise runs and release bank payment files", "Qokelela ii-invoyisi ezivunyiweyo, gunyazisa imijikelo uze ukhuphe iifayile zentlawulo zebhanki"),
+        ("Below", "Ngezantsi"),
+        ("Bid", "Ibhidi"),
+        ("Bid Award", "Ukunikwa kwebhidi"),
+        ("Bid Comparison", "Uthelekiso lweebhidi"),
+        ("Bid Evaluation", "Uvavanyo lweebhidi"),
+        ("Bid History ({count})", "Imbali yeebhidi ({count})"),
+        ("Bid Price", "Ixabiso lebhidi"),
+        ("Bidder {id}", "Othenda {id}"),
+        ("Bidders", "Abathenda"),
+        ("Bids ({count})", "Iibhidi ({count})"),
+        ("Bids Submitted", "Iibhidi ezingenisiweyo"),
+        ("Black Owned Spend", "Inkcitho kumashishini angabantu abamnyama"),
+        ("Black Women Owned", "Okungabasetyhini abamnyama"),
+        ("Blacklisted", "Kuluhlu olumnyama"),
+        ("Box", "Ibhokisi"),
+        ("Branding", "Uphawu"),
+        ("Brief description of procurement", "Inkcazelo emfutshane yokuthenga"),
+        ("Brief description of the procurement", "Inkcazelo emfutshane yokuthenga"),
+        ("Brief description of the requisition", "Inkcazelo emfutshane yesicelo"),
+        ("Briefing Check-ins", "Ukubhalisa kwintlanganiso yenkcazelo"),
+        ("Briefing Date", "Umhla wentlanganiso yenkcazelo"),
+        ("Broad-Based Black Economic Empowerment compliance tracking for FY 2025/26", "Ukulandelela ukuthobela uXhotyiso lwezoQoqosho lwaBantu abaMnyama olubanzi lonyaka-mali ka-2025/26"),
+        ("Browse Opportunities", "Khangela amathuba"),
+        ("Browse and search available items", "Khangela uze ukhangele izinto ezifumanekayo"),
+        ("Budget", "Ibhajethi"),
+        ("Budget Alerts", "Izilumkiso zebhajethi"),
+        ("Budget Allocation", "Ulwabiwo lwebhajethi"),
+        ("Budget Control", "Ulawulo lwebhajethi"),
+        ("Budget Lines", "Imigca yebhajethi"),
+        ("Budget Summary", "Isishwankathelo sebhajethi"),
+        ("Budget Transfers", "Ukudluliselwa kwebhajethi"),
+        ("Budget Utilization", "Ukusetyenziswa kwebhajethi"),
+        ("Budget Utilized", "Ibhajethi esetyenzisiweyo"),
+        ("Budget by Category", "Ibhajethi ngodidi"),
+        ("Building, floor, etc. (optional)", "Isakhiwo, umgangatho, njl. (ukhetho)"),
+        ("By submitting this request, you confirm that you have conducted appropriate market research and that deviation from competitive bidding is justified.", "Ngokungenisa esi sicelo, uqinisekisa ukuba wenze uphando olufanelekileyo lwentengiso nokuba ukuphambuka kwithenda ekhuphisanayo kunesizathu."),
+        ("CFO Approval", "Imvume ye-CFO"),
+        ("CIPC registration", "Ubhaliso lwe-CIPC"),
+        ("CSD Unverified", "I-CSD ayiqinisekiswanga"),
+        ("CSD Verified", "I-CSD iqinisekisiwe"),
+        ("Cancel", "Rhoxisa"),
+        ("Cancel Run", "Rhoxisa umjikelo"),
+        ("Cancellation", "Urhoxiso"),
+        ("Cannot be ordered directly: {reason}.", "Ayinakuodolwa ngqo: {reason}."),
+        ("Cards", "Amakhadi"),
+        ("Catalogue Items ({count})", "Izinto zekhathalogu ({count})"),
+        ("Catalogue Management", "Ulawulo lwekhathalogu"),
+        ("Catastrophic", "Intlekele"),
+        ("Categories", "Iindidi"),
+        ("Categories ({count} total)", "Iindidi ({count} zizonke)"),
+        ("Category", "Udidi"),
+        ("Category Breakdown", "Ukwahlulwa ngodidi"),
+        ("Category Name", "Igama lodidi"),
+        ("Category name", "Igama lodidi"),
+        ("Category:", "Udidi:"),
+        ("Catering", "Ukuphakelwa kokutya"),
+        ("Changes", "Utshintsho"),
+        ("Checks", "Ukujonga"),
+        ("Choose a bid from the list on the left to begin scoring.", "Khetha ibhidi kuluhlu olusekhohlo ukuze uqalise ukunika amanqaku."),
+        ("Choose a colleague", "Khetha ogxa wakho"),
+        ("Choose how to resolve the exception", "Khetha indlela yokusombulula okungaqhelekanga"),
+        ("City", "Isixeko"),
+        ("Classification", "Udidiyelo"),
+        ("Clean Audit", "Uphicotho olucocekileyo"),
+        ("Clear", "Cima"),
+        ("Clear Filters", "Cima izihluzi"),
+        ("Close", "Vala"),
+        ("Closing Date", "Umhla wokuvala"),
+        ("Code", "Ikhowudi"),
+        ("Comment", "Izimvo"),
+        ("Comments (required to reject or return)", "Izimvo (ziyafuneka ukwala okanye ukubuyisa)"),
+        ("Commitment", "Uzibophelelo"),
+        ("Commitments", "Izibophelelo"),
+        ("Committed", "Kuzibophelelwe"),
+        ("Committee", "Ikomiti"),
+        ("Committee Members", "Amalungu ekomiti"),
+        ("Committee Notes", "Amanqaku ekomiti"),
+        ("Committee Statistics", "Amanani ekomiti"),
+        ("Completed", "Kugqityiwe"),
+        ("Completed ({count})", "Kugqityiwe ({count})"),
+        ("Completed Today:", "Kugqitywe namhlanje:"),
+        ("Completed: {date}", "Kugqityiwe: {date}"),
+        ("Completion Progress", "Inkqubela yokugqiba"),
+        ("Compliance Check", "Ukujonga ukuthobela"),
+        ("Compliance Checks", "Ukujonga ukuthobela"),
+        ("Compliance Checks ({count} total)", "Ukujonga ukuthobela ({count} kukonke)"),
+        ("Compliance Issues", "Imiba yokuthobela"),
+        ("Compliance Metrics", "Imilinganiselo yokuthobela"),
+        ("Compliance Score", "Amanqaku okuthobela"),
+        ("Compliant", "Iyathobela"),
+        ("Compliant Metrics", "Imilinganiselo ethobelayo"),
+        ("Compliant Suppliers", "Ababoneleli abathobelayo"),
+        ("Comprehensive procurement spend analysis for FY 2025/26", "Uhlalutyo olubanzi lwenkcitho yokuthenga yonyaka-mali ka-2025/26"),
+        ("Confidence", "Ukuqiniseka"),
+        ("Configuration", "Uqwalaselo"),
+        ("Confinement", "Ukuvalelwa"),
+        ("Confirm Paid", "Qinisekisa ukuhlawulwa"),
+        ("Confirm it is a separate charge before accepting it.", "Qinisekisa ukuba yintlawulo eyahlukileyo phambi kokuyamkela."),
+        ("Conflict", "Ungquzulwano"),
+        ("Connected", "Kuqhagamshelwe"),
+        ("Construction", "Ulwakhiwo"),
+        ("Contact", "Umntu wokuqhagamshelana"),
+        ("Contact Email", "I-imeyile yoqhagamshelwano"),
+        ("Contact Person", "Umntu wokuqhagamshelana"),
+        ("Contact Phone", "Ifowuni yoqhagamshelwano"),
+        ("Contact:", "Umntu wokuqhagamshelana:"),
+        ("Container", "Isikhongozeli"),
+        ("Continue to Attachments", "Qhubeka uye kokuqhotyoshelweyo"),
+        ("Continue to Budget", "Qhubeka uye kwibhajethi"),
+        ("Continue to Categories", "Qhubeka uye kwiindidi"),
+        ("Continue to Line Items", "Qhubeka uye kwizinto zomgca"),
+        ("Continue to Review", "Qhubeka uye kuphononongo"),
+        ("Continue to Timeline", "Qhubeka uye kuludwe lwexesha"),
+        ("Contract", "Ikhontrakthi"),
+        ("Contract Awards", "Ukunikwa kweekhontrakthi"),
+        ("Contract Details", "Iinkcukacha zekhontrakthi"),
+        ("Contract Duration", "Ubude bekhontrakthi"),
+        ("Contract ID", "I-ID yekhontrakthi"),
+        ("Contract No.", "Inombolo yekhontrakthi"),
+        ("Contract Period", "Ixesha lekhontrakthi"),
+        ("Contract Reference", "Isalathiso sekhontrakthi"),
+        ("Contract Terms", "Imiqathango yekhontrakthi"),
+        ("Contract Title", "Isihloko sekhontrakthi"),
+        ("Contract Type", "Uhlobo lwekhontrakthi"),
+        ("Contract Utilization", "Ukusetyenziswa kwekhontrakthi"),
+        ("Contract Value", "Ixabiso lekhontrakthi"),
+        ("Contract ref...", "Isalathiso sekhontrakthi..."),
+        ("Contract {id} - {supplier_name}", "Ikhontrakthi {id} - {supplier_name}"),
+        ("Contract:", "Ikhontrakthi:"),
+        ("Contracts ({count})", "Iikhontrakthi ({count})"),
+        ("Control", "Ulawulo"),
+        ("Control Effectiveness", "Ukusebenza kolawulo"),
+        ("Controls", "Ulawulo"),
+        ("Corporate Services", "Iinkonzo zequmrhu"),
+        ("Corrected", "Kulungisiwe"),
+        ("Corrections", "Izilungiso"),
+        ("Corrective", "Okulungisayo"),
+        ("Cost Center", "Iziko leendleko"),
+        ("Cost Centre", "Iziko leendleko"),
+        ("Cost Savings", "Ukonga iindleko"),
+        ("Create", "Yila"),
+        ("Create Contract", "Yila ikhontrakthi"),
+        ("Create Purchase Order", "Yila i-odolo yokuthenga"),
+        ("Create a new purchase requisition", "Yila isicelo esitsha sokuthenga"),
+        ("Create an annual procurement sourcing plan", "Yila isicwangciso sonyaka sokufumana iimpahla"),
+        ("Create {tender_type}", "Yila i-{tender_type}"),
+        ("Created", "Kuyilwe"),
+        ("Criteria", "Imigaqo"),
+        ("Criteria Count", "Inani lemigaqo"),
+        ("Criterion Name", "Igama lomgaqo"),
+        ("Critical", "Kubalulekile kakhulu"),
+        ("Critical Alerts", "Izilumkiso ezibalulekileyo"),
+        ("Critical Risk", "Umngcipheko omkhulu"),
+        ("Critical Risk Suppliers - Immediate Attention Required", "Ababoneleli abasemngciphekweni omkhulu - Kufuneka ingqwalasela ngokukhawuleza"),
+        ("Current Audit Opinion", "Uluvo lophicotho lwangoku"),
+        ("Current Bid", "Ibhidi yangoku"),
+        ("Current Lowest Bid", "Ibhidi ephantsi kakhulu yangoku"),
+        ("Current: {amount}", "Ngoku: {amount}"),
+        ("Custom Range", "Uluhlu olukhethwayo"),
+        ("Cyber", "Ezobuxhakaxhaka be-intanethi"),
+        ("Dark", "Mnyama"),
+        ("Date", "Umhla"),
+        ("Date (Newest)", "Umhla (omtsha kakhulu)"),
+        ("Date (Oldest)", "Umhla (omdala kakhulu)"),
+        ("Date Emergency Arose", "Umhla ongxamiseko oluvele ngawo"),
+        ("Date From", "Umhla ukusuka"),
+        ("Date Received", "Umhla wokufunyanwa"),
+        ("Date To", "Umhla ukuya"),
+        ("Deactivate", "Yekisa ukusebenza"),
+        ("Decision", "Isigqibo"),
+        ("Decision History", "Imbali yezigqibo"),
+        ("Decisions", "Izigqibo"),
+        ("Decisions This Month", "Izigqibo zale nyanga"),
+        ("Decisions YTD", "Izigqibo zonyaka ukuza kuthi ga ngoku"),
+        ("Decreasing", "Kuyehla"),
+        ("Defer", "Hlehlisa"),
+        ("Define key milestones and target dates for the procurement plan execution.", "Chaza amanqanaba abalulekileyo nemihla ekujoliswe kuyo yokuphunyezwa kwesicwangciso sokuthenga."),
+        ("Define the procurement categories and their budget allocations for this plan.", "Chaza iindidi zokuthenga nolwabiwo lwebhajethi yazo kwesi sicwangciso."),
+        ("Delegate", "Gqithisela"),
+        ("Delegate Approvals", "Gqithisela iimvume"),
+        ("Delegate to", "Gqithisela ku"),
+        ("Delegations", "Ugqithiselo"),
+        ("Delegator", "Ogqithiselayo"),
+        ("Delete", "Cima"),
+        ("Delete Item", "Cima into"),
+        ("Deliverable #{number}", "Okuziswayo #{number}"),
+        ("Deliverables", "Okuziswayo"),
+        ("Delivered", "Kuziswe"),
+        ("Deliveries Confirmed", "Ukuziswa kuqinisekisiwe"),
+        ("Delivery", "Ukuziswa"),
+        ("Delivery Address", "Idilesi yokuzisa"),
+        ("Delivery Address Line 1", "Umgca wedilesi yokuzisa 1"),
+        ("Delivery Details", "Iinkcukacha zokuzisa"),
+        ("Delivery Information", "Ulwazi lokuzisa"),
+        ("Delivery Instructions", "Imiyalelo yokuzisa"),
+        ("Delivery Location", "Indawo yokuzisa"),
+        ("Delivery Note", "Inqaku lokuzisa"),
+        ("Delivery location", "Indawo yokuzisa"),
+        ("Department", "Isebe"),
+        ("Department & Budget", "Isebe nebhajethi"),
+        ("Department & Timeline", "Isebe noludwe lwexesha"),
+        ("Describe escalation levels and contacts", "Chaza amanqanaba okunyusela phezulu nabantu bokuqhagamshelana"),
+        ("Describe market research conducted", "Chaza uphando lwentengiso olwenziweyo"),
+        ("Describe penalties for SLA breaches", "Chaza izohlwayo zokwaphulwa kwe-SLA"),
+        ("Describe renewal conditions", "Chaza imiqathango yohlaziyo"),
+        ("Describe termination conditions", "Chaza imiqathango yokupheliswa"),
+        ("Describe the contract scope and objectives", "Chaza ubungakanani neenjongo zekhontrakthi"),
+        ("Describe the emergency and why normal procurement cannot be followed", "Chaza ungxamiseko nokuba kutheni ukuthenga okuqhelekileyo kungenakulandelwa"),
+        ("Describe the scope and purpose of this sourcing plan...", "Chaza ubungakanani nenjongo yesi sicwangciso sokufumana..."),
+        ("Description", "Inkcazelo"),
+        ("Designated Group Spend (%)", "Inkcitho kumaqela achongiweyo (%)"),
+        ("Designated Groups", "Amaqela achongiweyo"),
+        ("Detailed description of goods/services required", "Inkcazelo eneenkcukacha yeempahla/iinkonzo ezifunekayo"),
+        ("Detailed description of work to be performed", "Inkcazelo eneenkcukacha yomsebenzi oza kwenziwa"),
+        ("Details", "Iinkcukacha"),
+        ("Detect automatically", "Fumanisa ngokuzenzekelayo"),
+        ("Detected", "Kufunyenwe"),
+        ("Detective", "Okufumanisayo"),
+        ("Deviation", "Ukuphambuka"),
+        ("Deviation Justification", "Isizathu sokuphambuka"),
+        ("Deviation Request", "Isicelo sokuphambuka"),
+        ("Deviation Type", "Uhlobo lokuphambuka"),
+        ("Deviations from competitive bidding must be justified in terms of Treasury Regulation 16A6.4 and require appropriate approval based on value.", "Ukuphambuka kwithenda ekhuphisanayo kufuneka kube nesizathu ngokoMmiselo woNondyebo 16A6.4 kwaye kufuna imvume efanelekileyo ngokwexabiso."),
+        ("Device", "Isixhobo"),
+        ("Device Breakdown", "Ukwahlulwa ngezixhobo"),
+        ("Directive", "Okuyalelayo"),
+        ("Disable All", "Cima konke"),
+        ("Disclaimer", "Isilumkiso sokungathathi xanduva"),
+        ("Disconnected", "Kuqhawukile"),
+        ("Discontinued", "Kuyekisiwe"),
+        ("Dispute Resolution", "Ukusonjululwa kweengxabano"),
+        ("Docs", "Amaxwebhu"),
+        ("Docs Scanned", "Amaxwebhu askeniweyo"),
+        ("Document", "Uxwebhu"),
+        ("Document Intake", "Ulwamkelo lwamaxwebhu"),
+        ("Document Library", "Ithala lamaxwebhu"),
+        ("Document Name", "Igama loxwebhu"),
+        ("Document Preview", "Ukujonga kwangaphambili uxwebhu"),
+        ("Document Type", "Uhlobo loxwebhu"),
+        ("Documentation", "Amaxwebhu"),
+        ("Documents ({count} found)", "Amaxwebhu ({count} afunyenweyo)"),
+        ("Documents Scanned", "Amaxwebhu askeniweyo"),
+        ("Download", "Khuphela"),
+        ("Download CSV", "Khuphela i-CSV"),
+        ("Download Docs", "Khuphela amaxwebhu"),
+        ("Download QR Code", "Khuphela ikhowudi ye-QR"),
+        ("Draft:", "Uyilo:"),
+        ("Drop files here or click to browse", "Beka iifayile apha okanye ucofe ukuze ukhangele"),
+        ("Due", "Kufuneka"),
+        ("Due Date", "Umhla wokugqibela"),
+        ("Due in 7 Days", "Kufuneka kwiintsuku ezi-7"),
+        ("Due: {date}", "Kufuneka: {date}"),
+        ("EME Suppliers", "Ababoneleli be-EME"),
+        ("EME/QSE Spend", "Inkcitho ye-EME/QSE"),
+        ("Each", "Nganye"),
+        ("Eastern Cape", "Mpuma Koloni"),
+        ("Edit", "Hlela"),
+        ("Edit Catalogue Item", "Hlela into yekhathalogu"),
+        ("Editing {id}", "Kuhlelwa {id}"),
+        ("Effective", "Kuyasebenza"),
+        ("Effectiveness", "Ukusebenza"),
+        ("Element", "Icandelo"),
+        ("Email", "I-imeyile"),
+        ("Emergency", "Ungxamiseko"),
+        ("Emergency - Urgent procurement due to unforeseen circumstances", "Ungxamiseko - Ukuthenga okungxamisekileyo ngenxa yeemeko ebezingalindelekanga"),
+        ("Emergency Circumstances", "Iimeko zongxamiseko"),
+        ("Emergency Details", "Iinkcukacha zongxamiseko"),
+        ("Emergency Procurement", "Ukuthenga kongxamiseko"),
+        ("Enable All", "Vula konke"),
+        ("Enable Offline Mode", "Vula imowudi engaxhunyiwe"),
+        ("Enable or disable features available to suppliers in the mobile app.", "Vula okanye ucime iimpawu ezifumanekayo kubaboneleli kwi-app yeselula."),
+        ("Enable this to define service level requirements and penalties", "Vula oku ukuze uchaze iimfuno zenqanaba lenkonzo nezohlwayo"),
+        ("End Date", "Umhla wokuphela"),
+        ("End Time", "Ixesha lokuphela"),
+        ("Ended", "Kuphelile"),
+        ("Enter a descriptive title", "Faka isihloko esichazayo"),
+        ("Enter contract title", "Faka isihloko sekhontrakthi"),
+        ("Enter document name", "Faka igama loxwebhu"),
+        ("Enter item description", "Faka inkcazelo yento"),
+        ("Enter item name", "Faka igama lento"),
+        ("Enter strategic objective...", "Faka injongo yobuchule..."),
+        ("Enter the purchase order number and load the order to capture the lines", "Faka inombolo ye-odolo yokuthenga uze ulayishe i-odolo ukuze ubhale imigca"),
+        ("Enter your bid", "Faka ibhidi yakho"),
+        ("Enterprise & Supplier Development Element - Code 400", "Icandelo lophuhliso lwamashishini nababoneleli - Ikhowudi 400"),
+        ("Entity", "Iziko"),
+        ("Entity ID", "I-ID yeziko"),
+        ("Entity Name", "Igama leziko"),
+        ("Entity Type", "Uhlobo lweziko"),
+        ("Escalated", "Kunyuselwe phezulu"),
+        ("Escalated from", "Kunyuselwe phezulu ukusuka ku"),
+        ("Escalation Procedure", "Inkqubo yokunyusela phezulu"),
+        ("Est. Value", "Ixabiso eliqikelelweyo"),
+        ("Estimated Total", "Isixa esiqikelelweyo"),
+        ("Estimated Value", "Ixabiso eliqikelelweyo"),
+        ("Estimated contract value in ZAR", "Ixabiso lekhontrakthi eliqikelelweyo nge-ZAR"),
+        ("Eval Deadline", "Umhla wokugqibela wovavanyo"),
+        ("Evaluated", "Kuvavanyiwe"),
+        ("Evaluation Criteria", "Imigaqo yovavanyo"),
+        ("Evaluation Summary", "Isishwankathelo sovavanyo"),
+        ("Exceeding", "Kudlula"),
+        ("Excel", "Excel"),
+        ("Exception", "Okungaqhelekanga"),
+        ("Exception Queue", "Umgca wokungaqhelekanga"),
+        ("Exceptions", "Okungaqhelekanga"),
+        ("Excluded Invoices", "Ii-invoyisi ezingaqukwanga"),
+        ("Expected", "Kulindelwe"),
+        ("Expected Date:", "Umhla olindelweyo:"),
+        ("Expected Delivery Date", "Umhla wokuzisa olindelweyo"),
+        ("Expended", "Kusetyenzisiwe"),
+        ("Expires in {days} days", "Kuphelelwa lixesha kwiintsuku ezingama-{days}"),
+        ("Expiring (30 days):", "Kuphelelwa lixesha (iintsuku ezingama-30):"),
+        ("Expiring Certs", "Izatifikethi eziphelelwa lixesha"),
+        ("Expiring within 30 days", "Kuphelelwa lixesha kwiintsuku ezingama-30"),
+        ("Explain why this requisition is needed...", "Chaza ukuba kutheni esi sicelo sifuneka..."),
+        ("Explain why this specific supplier is required", "Chaza ukuba kutheni kufuneka lo mboneleli"),
+        ("Export", "Thumela ngaphandle"),
+        ("Export Comparison", "Thumela uthelekiso"),
+        ("Export DTI Report", "Thumela ingxelo ye-DTI"),
+        ("Export Excel", "Thumela i-Excel"),
+        ("Export PDF", "Thumela i-PDF"),
+        ("Export Report", "Thumela ingxelo"),
+        ("Extension", "Ulwandiso"),
+        ("Extracted Fields", "Iindawo ezikhutshiweyo"),
+        ("Extreme", "Okugqithisileyo"),
+        ("Extreme/High", "Okugqithisileyo/Okuphezulu"),
+        ("FY 2025/26", "Unyaka-mali 2025/26"),
+        ("Facilities", "Izakhiwo"),
+        ("Facilities Management", "Ulawulo lwezakhiwo"),
+        ("Feature Usage", "Ukusetyenziswa kweempawu"),
+        ("Featured", "Okugqamileyo"),
+        ("Features", "Iimpawu"),
+        ("Fields to check", "Iindawo ekufuneka zijongwe"),
+        ("Filter", "Hluza"),
+        ("Finalize Evaluation", "Gqibezela uvavanyo"),
+        ("Finance", "Ezezimali"),
+        ("Financial", "Okwezimali"),
+        ("Financial Impact", "Impembelelo yezimali"),
+        ("Financial Score", "Amanqaku ezimali"),
+        ("Financial Year", "Unyaka-mali"),
+        ("Finding", "Isiphumo"),
+        ("Finding Statistics", "Amanani eziphumo"),
+        ("Findings", "Iziphumo"),
+        ("Findings by Severity", "Iziphumo ngobuzaza"),
+        ("Fiscal Year", "Unyaka-mali"),
+        ("Fleet Management", "Ulawulo lwezithuthi"),
+        ("Fleet Services", "Iinkonzo zezithuthi"),
+        ("Folders", "Iifolda"),
+        ("Framework Agreement", "Isivumelwano sesakhelo"),
+        ("Fraud", "Ubuqhetseba"),
+        ("Free State", "Free State"),
+        ("From", "Ukusuka"),
+        ("From budget", "Ukusuka kwibhajethi"),
+        ("Full Justification", "Isizathu esipheleleyo"),
+        ("Functionality Threshold", "Umda wokusebenza"),
+        ("Functionality Threshold (%)", "Umda wokusebenza (%)"),
+        ("Funds are committed against this cost centre when approved", "Imali izibophelela kweli ziko leendleko xa ivunyiwe"),
+        ("Furniture", "Ifenitshala"),
+        ("Furniture & Fittings", "Ifenitshala nezinto zokufakela"),
+        ("GR Number", "Inombolo ye-GR"),
+        ("Gauteng", "Gauteng"),
+        ("General", "Jikelele"),
+        ("Generate", "Velisa"),
+        ("Generic", "Okuqhelekileyo"),
+        ("Goods", "Iimpahla"),
+        ("Goods Receipt {id}", "Ulwamkelo lweempahla {id}"),
+        ("Governance, Risk Management, and Compliance Overview", "Ushwankathelo lolawulo, ulawulo lomngcipheko nokuthobela"),
+        ("Governing Law", "Umthetho olawulayo"),
+        ("Grid", "Igridi"),
+        ("Hand your approvals to a colleague while you are out of office", "Nika ogxa wakho iimvume zakho ngelixa ungekho eofisini"),
+        ("Health Services", "Iinkonzo zempilo"),
+        ("High Risk", "Umngcipheko ophezulu"),
+        ("High/Critical Risk", "Umngcipheko ophezulu/omkhulu"),
+        ("Home", "Ikhaya"),
+        ("How can I help you today?", "Ndingakunceda njani namhlanje?"),
+        ("Human Resources", "Abasebenzi"),
+        ("I can assist with tender analysis, compliance checks, bid evaluation, and procurement guidance.", "Ndingancedisa ngohlalutyo lweethenda, ukujonga ukuthobela, uvavanyo lweebhidi nesikhokelo sokuthenga."),
+        ("IP Address", "Idilesi ye-IP"),
+        ("IT Equipment", "Izixhobo ze-IT"),
+        ("IT Services", "Iinkonzo ze-IT"),
+        ("Image", "Umfanekiso"),
+        ("Import", "Ngenisa"),
+        ("Import CSV", "Ngenisa i-CSV"),
+        ("In Stock", "Ikhona esitokweni"),
+        ("In stock only", "Ezisesitokweni kuphela"),
+        ("Inactive", "Ayisebenzi"),
+        ("Include Briefing Session", "Quka intlanganiso yenkcazelo"),
+        ("Include SLA Terms", "Quka imiqathango ye-SLA"),
+        ("Include: reasons why competitive bidding is not possible, value for money considerations, risk assessment", "Quka: izizathu zokuba kutheni ithenda ekhuphisanayo ingenakwenzeka, ukuqwalaselwa kwexabiso lemali, uvavanyo lomngcipheko"),
+        ("Including all milestones", "Kuquka onke amanqanaba abalulekileyo"),
+        ("Increasing", "Kuyanyuka"),
+        ("Indicator Status", "Imeko yesalathisi"),
+        ("Ineffective", "Akusebenzi"),
+        ("Info", "Ulwazi"),
+        ("Info Req", "Kucelwa ulwazi"),
+        ("Information Technology", "Itekhnoloji yolwazi"),
+        ("Inherent", "Okungokwendalo"),
+        ("Insignificant", "Okungabalulekanga"),
+        ("Inspection", "Uhlolo"),
+        ("Inspection failed. Rejected items must be returned to the supplier.", "Uhlolo aluphumelelanga. Izinto ezaliweyo kufuneka zibuyiselwe kumboneleli."),
+        ("Inspection:", "Uhlolo:"),
+        ("Internal Controls ({count} total)", "Ulawulo lwangaphakathi ({count} kukonke)"),
+        ("Internal Notes", "Amanqaku angaphakathi"),
+        ("Internal Portal", "Iphothali yangaphakathi"),
+        ("Internal notes (not visible to supplier)", "Amanqaku angaphakathi (akabonakali kumboneleli)"),
+        ("Invoice", "I-invoyisi"),
+        ("Invoice Date", "Umhla we-invoyisi"),
+        ("Invoice Details", "Iinkcukacha ze-invoyisi"),
+        ("Invoice Lines", "Imigca ye-invoyisi"),
+        ("Invoice Register", "Irejista yee-invoyisi"),
+        ("Invoice Total", "Isixa se-invoyisi"),
+        ("Invoice, supplier or order", "I-invoyisi, umboneleli okanye i-odolo"),
+        ("Invoiced", "Kufakwe kwi-invoyisi"),
+        ("Invoiced Price", "Ixabiso elikwi-invoyisi"),
+        ("Invoiced against accepted", "Okukwi-invoyisi xa kuthelekiswa nokwamkelweyo"),
+        ("Invoiced against ordered", "Okukwi-invoyisi xa kuthelekiswa noko kuodoliweyo"),
+        ("Invoices", "Ii-invoyisi"),
+        ("Item", "Into"),
+        ("Item #{number}", "Into #{number}"),
+        ("Item Code", "Ikhowudi yento"),
+        ("Item Details", "Iinkcukacha zento"),
+        ("Item Name", "Igama lento"),
+        ("Item description", "Inkcazelo yento"),
+        ("Items", "Izinto"),
+        ("Items ({count} found)", "Izinto ({count} ezifunyenweyo)"),
+        ("Items Received", "Izinto ezifunyenweyo"),
+        ("Its invoices return to the approved list for another run", "Ii-invoyisi zawo zibuyela kuluhlu oluvunyiweyo ukuze kubekho omnye umjikelo"),
+        ("Justification", "Isizathu"),
+        ("Kit", "Ikiti"),
+        ("KwaZulu-Natal", "KwaZulu-Natal"),
+        ("LIVE", "IYAQHUBEKA"),
+        ("Last Active", "Ugqibele ukusebenza"),
+        ("Last Paid", "Ugqibele ukuhlawulwa"),
+        ("Last Test", "Uvavanyo lokugqibela"),
+        ("Last bid: {date}", "Ibhidi yokugqibela: {date}"),
+        ("Leave at zero to delegate any amount", "Shiya ku-zero ukuze ugqithisele nasiphi na isixa"),
+        ("Level 1", "Inqanaba 1"),
+        ("Level 1-2", "Inqanaba 1-2"),
+        ("Level 1-2 Spend", "Inkcitho yenqanaba 1-2"),
+        ("Level 1-3", "Inqanaba 1-3"),
+        ("Level 1-4", "Inqanaba 1-4"),
+        ("Level 2", "Inqanaba 2"),
+        ("Level 3", "Inqanaba 3"),
+        ("Level 3-4", "Inqanaba 3-4"),
+        ("Level 4+", "Inqanaba 4+"),
+        ("Level 5-8", "Inqanaba 5-8"),
+        ("Level Distribution", "Unikezelo lwamanqanaba"),
+        ("Level {level}", "Inqanaba {level}"),
+        ("License", "Ilayisensi"),
+        ("Light", "Ukukhanya"),
+        ("Likely", "Kunokwenzeka"),
+        ("Limpopo", "Limpopo"),
+        ("Line Items", "Izinto zomgca"),
+        ("Line Items ({count})", "Izinto zomgca ({count})"),
+        ("Line Items Summary", "Isishwankathelo sezinto zomgca"),
+        ("Lines add up to {lines}, not the subtotal of {subtotal}", "Imigca idibana ibe ngu-{lines}, hayi isixa esingaphantsi esingu-{subtotal}"),
+        ("Lines without a supplier go out for quotation.", "Imigca engenamboneleli iya kuthunyelwa ukuze kufunyanwe ikowuteshini."),
+        ("Link to existing contract (optional)", "Nxibelelanisa nekhontrakthi ekhoyo (ukhetho)"),
+        ("Link to requisition (optional)", "Nxibelelanisa nesicelo (ukhetho)"),
+        ("Linked Tender ID", "I-ID yethenda enxibelelanisiweyo"),
+        ("List", "Uluhlu"),
+        ("Live", "Iyaqhubeka"),
+        ("Load Order", "Layisha i-odolo"),
+        ("Loading auctions...", "Kulayishwa iifandesi..."),
+        ("Loading audit entries...", "Kulayishwa amangeniso ophicotho..."),
+        ("Loading catalogue...", "Kulayishwa ikhathalogu..."),
+        ("Loading compliance data...", "Kulayishwa idatha yokuthobela..."),
+        ("Loading contract...", "Kulayishwa ikhontrakthi..."),
+        ("Loading contracts...", "Kulayishwa iikhontrakthi..."),
+        ("Loading controls data...", "Kulayishwa idatha yolawulo..."),
+        ("Loading document...", "Kulayishwa uxwebhu..."),
+        ("Loading documents...", "Kulayishwa amaxwebhu..."),
+        ("Loading finding...", "Kulayishwa isiphumo..."),
+        ("Loading goods receipt...", "Kulayishwa ulwamkelo lweempahla..."),
+        ("Loading goods receipts...", "Kulayishwa ulwamkelo lweempahla..."),
+        ("Loading invoice...", "Kulayishwa i-invoyisi..."),
+        ("Loading items...", "Kulayishwa izinto..."),
+        ("Loading level data...", "Kulayishwa idatha yamanqanaba..."),
+        ("Loading metrics...", "Kulayishwa imilinganiselo..."),
+        ("Loading payment run...", "Kulayishwa umjikelo wentlawulo..."),
+        ("Loading performance data...", "Kulayishwa idatha yokusebenza..."),
+        ("Loading purchase order...", "Kulayishwa i-odolo yokuthenga..."),
+        ("Loading purchase orders...", "Kulayishwa ii-odolo zokuthenga..."),
+        ("Loading requisition...", "Kulayishwa isicelo..."),
+        ("Loading review...", "Kulayishwa uphononongo..."),
+        ("Loading risk data...", "Kulayishwa idatha yomngcipheko..."),
+        ("Loading supplier...", "Kulayishwa umboneleli..."),
+        ("Loading suppliers...", "Kulayishwa ababoneleli..."),
+        ("Loading targets...", "Kulayishwa iithagethi..."),
+        ("Loading tenders...", "Kulayishwa iithenda..."),
+        ("Loading violations data...", "Kulayishwa idatha yokwaphulwa..."),
+        ("Loading...", "Iyalayisha..."),
+        ("Local %", "Yasekhaya %"),
+        ("Locked", "Kutshixiwe"),
+        ("Locked by {user}", "Kutshixwe ngu-{user}"),
+        ("Login", "Ngena"),
+        ("Login Events", "Iziganeko zokungena"),
+        ("Logout", "Phuma"),
+        ("Low Risk", "Umngcipheko ophantsi"),
+        ("Major", "Okukhulu"),
+        ("Manage and monitor registered suppliers", "Lawula uze ubeke iliso kubaboneleli ababhalisiweyo"),
+        ("Manage and organize procurement documents", "Lawula uze ulungelelanise amaxwebhu okuthenga"),
+        ("Manage live reverse auctions for competitive bidding", "Lawula iifandesi ezibuyela umva eziqhubekayo zethenda ekhuphisanayo"),
+        ("Manage procurement tenders and bids", "Lawula iithenda zokuthenga neebhidi"),
+        ("Manage purchase orders and track deliveries", "Lawula ii-odolo zokuthenga uze ulandelele ukuziswa"),
+        ("Manage purchase requisitions and approvals", "Lawula izicelo zokuthenga neemvume"),
+        ("Manage supplier contracts and agreements", "Lawula iikhontrakthi nezivumelwano zababoneleli"),
+        ("Manage your tenders, bids, and contracts", "Lawula iithenda zakho, iibhidi neekhontrakthi"),
+        ("Management Response", "Impendulo yolawulo"),
+        ("Mandatory Requirements", "Iimfuno ezinyanzelekileyo"),
+        ("Mark All Read", "Phawula konke njengokufundiweyo"),
+        ("Mark Complete", "Phawula njengokugqityiweyo"),
+        ("Market Analysis", "Uhlalutyo lwentengiso"),
+        ("Market Research", "Uphando lwentengiso"),
+        ("Match", "Thelekisa"),
+        ("Match Tolerances", "Unyamezelo lothelekiso"),
+        ("Match tolerances saved", "Unyamezelo lothelekiso lugciniwe"),
+        ("Matched", "Kuyahambelana"),
+        ("Material", "Okubalulekileyo"),
+        ("Max", "Ubuninzi"),
+        ("Max Offline Duration", "Ixesha elide ungaxhunyiwe"),
+        ("Maximum file size: 50MB", "Ubungakanani befayile obuphezulu: 50MB"),
+        ("Maximum per transfer:", "Ubuninzi ngodluliselo ngalunye:"),
+        ("Maximum time to acknowledge an issue", "Ixesha elide lokuvuma umba"),
+        ("Maximum time to resolve an issue", "Ixesha elide lokusombulula umba"),
+        ("Medical", "Ezonyango"),
+        ("Medium Risk", "Umngcipheko ophakathi"),
+        ("Meeting No.", "Inombolo yentlanganiso"),
+        ("Meeting Schedule", "Ucwangciso lweentlanganiso"),
+        ("Metric", "Umlinganiselo"),
+        ("Milestone", "Inqanaba elibalulekileyo"),
+        ("Milestone Details", "Iinkcukacha zenqanaba elibalulekileyo"),
+        ("Milestone Name", "Igama lenqanaba elibalulekileyo"),
+        ("Milestone Timeline", "Uludwe lwexesha lwamanqanaba abalulekileyo"),
+        ("Milestone name", "Igama lenqanaba elibalulekileyo"),
+        ("Milestone {id} - Due: {date}", "Inqanaba elibalulekileyo {id} - Kufuneka: {date}"),
+        ("Milestones: {title}", "Amanqanaba abalulekileyo: {title}"),
+        ("Minimum 3 years experience", "Ubuncinane iminyaka emi-3 yamava"),
+        ("Minimum Bidding Period", "Ixesha elincinane lokuthenda"),
+        ("Minimum score to qualify", "Amanqaku amancinane okufaneleka"),
+        ("Minor", "Okuncinane"),
+        ("Mitigated", "Kunciphisiwe"),
+        ("Mobile App Users", "Abasebenzisi be-app yeselula"),
+        ("Mobile Bids", "Iibhidi zeselula"),
+        ("Mobile Supplier App", "I-app yeselula yababoneleli"),
+        ("Moderate", "Okuphakathi"),
+        ("Monitor and analyze supplier performance metrics", "Beka iliso uze uhlalutye imilinganiselo yokusebenza kwababoneleli"),
+        ("Monitor and manage supplier risk exposure", "Beka iliso uze ulawule umngcipheko wababoneleli"),
+        ("Month", "Inyanga"),
+        ("Monthly Spend Trend", "Umkhwa wenkcitho wenyanga nenyanga"),
+        ("Mpumalanga", "Mpumalanga"),
+        ("My Bid Submissions", "Iibhidi endizingenisileyo"),
+        ("My Profile", "Iprofayile yam"),
+        ("My Submissions", "Endikungenisileyo"),
+        ("N/A", "Akusebenzi"),
+        ("NBAC - Bid Adjudication", "NBAC - Ugwebo lweebhidi"),
+        ("Name", "Igama"),
+        ("Name (A-Z)", "Igama (A-Z)"),
+        ("Name (Z-A)", "Igama (Z-A)"),
+        ("Name of proposed supplier", "Igama lomboneleli ocetywayo"),
+        ("National Bid Adjudication Committee reviews and decisions", "Uphononongo nezigqibo zeKomiti yeSizwe yoGwebo lweeBhidi"),
+        ("National Treasury Approval", "Imvume kaNondyebo weSizwe"),
+        ("New Assessment", "Uvavanyo olutsha"),
+        ("New Auction", "Ifandesi entsha"),
+        ("New Contract", "Ikhontrakthi entsha"),
+        ("New Conversation", "Incoko entsha"),
+        ("New Delegation", "Ugqithiselo olutsha"),
+        ("New Purchase Order", "I-odolo entsha yokuthenga"),
+        ("New Receipt", "Ulwamkelo olutsha"),
+        ("New Requisition", "Isicelo esitsha"),
+        ("New Sourcing Plan", "Isicwangciso esitsha sokufumana"),
+        ("New Tender", "Ithenda entsha"),
+        ("New approver", "Ovumayo omtsha"),
+        ("Next", "Okulandelayo"),
+        ("Next Milestone", "Inqanaba elibalulekileyo elilandelayo"),
+        ("Next Review", "Uphononongo olulandelayo"),
+        ("Next: Deliverables", "Okulandelayo: Okuziswayo"),
+        ("Next: Delivery", "Okulandelayo: Ukuziswa"),
+        ("Next: Line Items", "Okulandelayo: Izinto zomgca"),
+        ("Next: Review", "Okulandelayo: Uphononongo"),
+        ("Next: SLA", "Okulandelayo: I-SLA"),
+        ("Next: Terms", "Okulandelayo: Imiqathango"),
+        ("No Evaluation Selected", "Akukhethwanga vavanyo"),
+        ("No Live Auction", "Akukho fandesi iqhubekayo"),
+        ("No attachments added", "Akukho okuqhotyoshelweyo okongeziweyo"),
+        ("No auctions found. Create a new auction to get started.", "Akukho fandesi zifunyenweyo. Yila ifandesi entsha ukuze uqalise."),
+        ("No audit entries found", "Akukho mangeniso ophicotho afunyenweyo"),
+        ("No bank details on record", "Akukho nkcukacha zebhanki zirekhodiweyo"),
+        ("No bids yet", "Akukabikho bhidi"),
+        ("No budget transfers", "Akukho kudluliselwa kwebhajethi"),
+        ("No committee notes yet.", "Akukabikho manqaku ekomiti."),
+        ("No contracts found", "Akukho khontrakthi zifunyenweyo"),
+        ("No critical alerts at this time", "Akukho zilumkiso zibalulekileyo ngoku"),
+        ("No delegations", "Akukho gqithiselo"),
+        ("No documents found", "Akukho maxwebhu afunyenweyo"),
+        ("No documents processed", "Akukho maxwebhu aqhutywe"),
+        ("No invoices found", "Akukho zi-invoyisi zifunyenweyo"),
+        ("No invoices need attention", "Akukho zi-invoyisi zifuna ingqwalasela"),
+        ("No items found", "Akukho zinto zifunyenweyo"),
+        ("No order loaded", "Akukho odolo ilayishiweyo"),
+        ("No overdue approvals", "Akukho mvume zidlulelwe lixesha"),
+        ("No payment runs yet", "Akukabikho mijikelo yentlawulo"),
+        ("No purchase orders found", "Akukho zi-odolo zokuthenga zifunyenweyo"),
+        ("No risk indicators found.", "Akukho zalathisi zomngcipheko zifunyenweyo."),
+        ("No supplier", "Akukho mboneleli"),
+        ("No supplier is registered under {registration}", "Akukho mboneleli ubhaliswe phantsi ko-{registration}"),
+        ("No suppliers found. Adjust filters or add a new supplier.", "Akukho baboneleli bafunyenweyo. Lungisa izihluzi okanye wongeze umboneleli omtsha."),
+        ("No suppliers match the selected criteria.", "Akukho baboneleli bahambelana nemigaqo ekhethiweyo."),
+        ("No tenders found. Create a new tender to get started.", "Akukho thenda zifunyenweyo. Yila ithenda entsha ukuze uqalise."),
+        ("Non-Compliant", "Ayithobeli"),
+        ("Non-compliant - requires immediate attention", "Ayithobeli - ifuna ingqwalasela ngokukhawuleza"),
+        ("North West", "Mntla Ntshona"),
+        ("Northern Cape", "Mntla Koloni"),
+        ("Not Tested", "Akuvavanywanga"),
+        ("Not matched", "Akuhambelani"),
+        ("Not ordered", "Akuodolwanga"),
+        ("Not yet submitted for approval", "Akukangeniswa ukuze kuvunywe"),
+        ("Note: ", "Qaphela: "),
+        ("Notes", "Amanqaku"),
+        ("Notes (visible to supplier)", "Amanqaku (ayabonakala kumboneleli)"),
+        ("Nothing paid this financial year", "Akukho nto ihlawulweyo kulo nyaka-mali"),
+        ("Notice Period (days)", "Ixesha lesaziso (iintsuku)"),
+        ("Notify", "Yazisa"),
+        ("Notify registered suppliers in this category", "Yazisa ababoneleli ababhalisiweyo kolu didi"),
+        ("Observation", "Okuqatshelweyo"),
+        ("Office Supplies", "Izinto zeofisi"),
+        ("Offline Mode", "Imowudi engaxhunyiwe"),
+        ("On Track", "Kusendleleni"),
+        ("On behalf of", "Egameni lika"),
+        ("Once published, the tender will be visible to all suppliers. Ensure all documents are finalized before publishing.", "Xa ipapashiwe, ithenda iya kubonakala kubo bonke ababoneleli. Qinisekisa ukuba onke amaxwebhu agqityiwe phambi kokupapasha."),
+        ("Open Issues", "Imiba evulekileyo"),
+        ("Open Opportunities", "Amathuba avulekileyo"),
+        ("Open Tenders", "Iithenda ezivulekileyo"),
+        ("Open Violations", "Ukwaphulwa okuvulekileyo"),
+        ("Open in New Tab", "Vula kwithebhu entsha"),
+        ("Open record", "Vula irekhodi"),
+        ("Operating", "Iyasebenza"),
+        ("Operations", "Imisebenzi"),
+        ("Opinion", "Uluvo"),
+        ("Opportunities", "Amathuba"),
+        ("Order", "I-odolo"),
+        ("Order Date", "Umhla we-odolo"),
+        ("Order Price", "Ixabiso le-odolo"),
+        ("Ordered", "Kuodoliwe"),
+        ("Orders by Supplier", "Ii-odolo ngomboneleli"),
+        ("Organization Name", "Igama lombutho"),
+        ("Other", "Okunye"),
+        ("Out", "Ngaphandle"),
+        ("Out of Stock", "Ayikho esitokweni"),
+        ("Outstanding", "Okusaseleyo"),
+        ("Overall", "Xa kudityanisiwe"),
+        ("Overall Progress", "Inkqubela iyonke"),
+        ("Overall confidence", "Ukuqiniseka kukonke"),
+        ("Overdue", "Kudlulelwe lixesha"),
+        ("Overdue Approvals", "Iimvume ezidlulelwe lixesha"),
+        ("Overdue Reviews", "Uphononongo oludlulelwe lixesha"),
+        ("Overdue by", "Kudlulelwe lixesha ngo"),
+        ("Overdue only", "Okudlulelwe lixesha kuphela"),
+        ("Overview of procurement activities for FY 2025/26", "Ushwankathelo lwemisebenzi yokuthenga yonyaka-mali ka-2025/26"),
+        ("Owner", "Umnini"),
+        ("Owner: {owner}", "Umnini: {owner}"),
+        ("Ownership", "Ubunini"),
+        ("PDF, JPEG, PNG, TIFF or WebP, up to", "PDF, JPEG, PNG, TIFF okanye WebP, ukuya kuthi ga ku"),
+        ("PDF, Word, Excel, or images. Max 10MB per file.", "PDF, Word, Excel okanye imifanekiso. Ubuninzi 10MB ngefayile nganye."),
+        ("PO Number", "Inombolo ye-PO"),
+        ("PO Reference", "Isalathiso se-PO"),
+        ("PO Value", "Ixabiso le-PO"),
+        ("Pack", "Ipakethe"),
+        ("Paid", "Kuhlawulwe"),
+        ("Paid This Year", "Kuhlawulwe kulo nyaka"),
+        ("Paid from", "Kuhlawulwe ukusuka ku"),
+        ("Paid: {date}", "Kuhlawulwe: {date}"),
+        ("Partial", "Icandelo"),
+        ("Partial Compliance", "Ukuthobela okuyinxalenye"),
+        ("Partial Pass", "Ukuphumelela okuyinxalenye"),
+        ("Partially", "Ngokuyinxalenye"),
+        ("Partially Delivered", "Kuziswe ngokuyinxalenye"),
+        ("Partially Effective", "Kusebenza ngokuyinxalenye"),
+        ("Partially Received", "Kufunyenwe ngokuyinxalenye"),
+        ("Participant", "Othatha inxaxheba"),
+        ("Passed", "Kuphumelele"),
+        ("Past Due", "Kudlulelwe lixesha"),
+        ("Paying on", "Kuhlawulwa ngo"),
+        ("Payment Aging", "Ubudala bentlawulo"),
+        ("Payment Amount", "Isixa sentlawulo"),
+        ("Payment Date", "Umhla wentlawulo"),
+        ("Payment Report", "Ingxelo yentlawulo"),
+        ("Payment Run", "Umjikelo wentlawulo"),
+        ("Payment Runs", "Imijikelo yentlawulo"),
+        ("Payment Schedule", "Ucwangciso lwentlawulo"),
+        ("Payment Terms", "Imiqathango yentlawulo"),
+        ("Payment confirmed by", "Intlawulo iqinisekiswe ngu"),
+        ("Payment date", "Umhla wentlawulo"),
+        ("Payment is due", "Intlawulo kufuneka yenziwe"),
+        ("Payment run", "Umjikelo wentlawulo"),
+        ("Payments Made", "Iintlawulo ezenziweyo"),
+        ("Penalty Clause", "Isolotya lesohlwayo"),
+        ("Pending Approvals", "Iimvume ezilindileyo"),
+        ("Pending Delivery:", "Ukuziswa okulindileyo:"),
+        ("Pending Processing:", "Ukuqhutywa okulindileyo:"),
+        ("Pending Receipts ({count})", "Ulwamkelo olulindileyo ({count})"),
+        ("Pending Review", "Kulinde uphononongo"),
+        ("Pending Reviews", "Uphononongo olulindileyo"),
+        ("Pending Start", "Kulinde ukuqalisa"),
+        ("Pending delivery only", "Ukuziswa okulindileyo kuphela"),
+        ("Percentage", "Ipesenti"),
+        ("Performance Distribution", "Unikezelo lokusebenza"),
+        ("Performance by Category", "Ukusebenza ngodidi"),
+        ("Period", "Ixesha"),
+        ("Phone", "Ifowuni"),
+        ("Place your first bid to participate", "Faka ibhidi yakho yokuqala ukuze uthathe inxaxheba"),
+        ("Plan Details", "Iinkcukacha zesicwangciso"),
+        ("Plan ID", "I-ID yesicwangciso"),
+        ("Plan Title", "Isihloko sesicwangciso"),
+        ("Please review all information before saving. Once submitted for approval, changes will require re-approval.", "Nceda uphonononge lonke ulwazi phambi kokugcina. Xa sele kungeniswe ukuze kuvunywe, utshintsho luya kufuna ukuvunywa kwakhona."),
+        ("Please select an evaluation from the list to begin scoring.", "Nceda ukhethe uvavanyo kuluhlu ukuze uqalise ukunika amanqaku."),
+        ("Points", "Amanqaku"),
+        ("Policy", "Umgaqo-nkqubo"),
+        ("Policy Violations ({count} total)", "Ukwaphulwa komgaqo-nkqubo ({count} kukonke)"),
+        ("Portal Link", "Ikhonkco lephothali"),
+        ("Portal Notes", "Amanqaku ephothali"),
+        ("Portal Reference", "Isalathiso sephothali"),
+        ("Possible", "Kunokwenzeka"),
+        ("Possible duplicate of", "Kunokuba yimpinda ka"),
+        ("Possible duplicate of {invoice_id} ({reason})", "Kunokuba yimpinda ka-{invoice_id} ({reason})"),
+        ("Postal Code", "Ikhowudi yeposi"),
+        ("Prepare Payment Run", "Lungiselela umjikelo wentlawulo"),
+        ("Prepare Run", "Lungiselela umjikelo"),
+        ("Prepared by", "Kulungiswe ngu"),
+        ("Preventive", "Okuthintelayo"),
+        ("Preview", "Jonga kwangaphambili"),
+        ("Preview Controls", "Izilawuli zokujonga kwangaphambili"),
+        ("Preview and configure the mobile app for suppliers", "Jonga kwangaphambili uze uqwalasele i-app yeselula yababoneleli"),
+        ("Preview not available", "Ukujonga kwangaphambili akufumaneki"),
+        ("Preview not available for this file type", "Ukujonga kwangaphambili akufumaneki kolu hlobo lwefayile"),
+        ("Price", "Ixabiso"),
+        ("Price (High to Low)", "Ixabiso (eliphezulu ukuya kwelisezantsi)"),
+        ("Price (Low to High)", "Ixabiso (elisezantsi ukuya kweliphezulu)"),
+        ("Price Var.", "Umahluko wexabiso"),
+        ("Price Weight", "Ubunzima bexabiso"),
+        ("Price Weight (%)", "Ubunzima bexabiso (%)"),
+        ("Primary Color", "Umbala ophambili"),
+        ("Priority", "Okubalulekileyo"),
+        ("Process and track goods received from suppliers", "Qhuba uze ulandelele iimpahla ezifunyenwe kubaboneleli"),
+        ("Processed", "Kuqhutywe"),
+        ("Processed Documents", "Amaxwebhu aqhutyiweyo"),
+        ("Procurement Categories", "Iindidi zokuthenga"),
+        ("Procurement Dashboard", "Ideshibhodi yokuthenga"),
+        ("Procurement Details", "Iinkcukacha zokuthenga"),
+        ("Procurement Division", "Icandelo lokuthenga"),
+        ("Professional", "Okobuchwephesha"),
+        ("Professional Services", "Iinkonzo zobuchwephesha"),
+        ("Profile", "Iprofayile"),
+        ("Progress", "Inkqubela"),
+        ("Projected Level", "Inqanaba eliqikelelweyo"),
+        ("Proposed Supplier", "Umboneleli ocetywayo"),
+        ("Provide a detailed description of the procurement plan", "Nika inkcazelo eneenkcukacha yesicwangciso sokuthenga"),
+        ("Provide detailed justification for deviating from competitive bidding", "Nika isizathu esineenkcukacha sokuphambuka kwithenda ekhuphisanayo"),
+        ("Provide detailed justification for the procurement", "Nika isizathu esineenkcukacha sokuthenga"),
+        ("Province", "Iphondo"),
+        ("Provincial B-BBEE Distribution", "Unikezelo lwe-B-BBEE ngephondo"),
+        ("Provincial Distribution", "Unikezelo ngephondo"),
+        ("Publication Date", "Umhla wopapasho"),
+        ("Publication Dates", "Imihla yopapasho"),
+        ("Publication Settings", "Iisetingi zopapasho"),
+        ("Publish Tender", "Papasha ithenda"),
+        ("Publish Update", "Papasha uhlaziyo"),
+        ("Publish internally", "Papasha ngaphakathi"),
+        ("Publish tender to e-Tender portal for bidding", "Papasha ithenda kwiphothali ye-e-Tender ukuze kuthendwe"),
+        ("Publish to e-Tender", "Papasha kwi-e-Tender"),
+        ("Publish to the national e-Tender portal (etenders.gov.za)", "Papasha kwiphothali yesizwe ye-e-Tender (etenders.gov.za)"),
+        ("Purchase Order", "I-odolo yokuthenga"),
+        ("Purchase Order {po_number}", "I-odolo yokuthenga {po_number}"),
+        ("Purchase Orders ({count})", "Ii-odolo zokuthenga ({count})"),
+        ("Purchase order {po_number} was not found", "I-odolo yokuthenga {po_number} ayifunyanwanga"),
+        ("Push Enabled", "Izaziso zivuliwe"),
+        ("Qty", "Inani"),
+        ("Qty Var.", "Umahluko wenani"),
+        ("Qualified", "Kufanelekile"),
+        ("Quality", "Umgangatho"),
+        ("Quantity", "Inani"),
+        ("Quick Actions", "Amanyathelo akhawulezayo"),
+        ("Quick Bid", "Ibhidi ekhawulezayo"),
+        ("Quorum Required", "Ikhoram iyafuneka"),
+        ("RFP - Request for Proposal", "RFP - Isicelo sesindululo"),
+        ("RFQ - Request for Quotation", "RFQ - Isicelo sekowuteshini"),
+        ("RFT - Request for Tender", "RFT - Isicelo sethenda"),
+        ("Raised from approved requisition ", "Kuvela kwisicelo esivunyiweyo "),
+        ("Rank", "Isikhundla"),
+        ("Rare", "Kunqabile"),
+        ("Rating", "Umlinganiselo"),
+        ("Re-run Match", "Phinda uthelekise"),
+        ("Read Documents", "Funda amaxwebhu"),
+        ("Read as", "Funda njenge"),
+        ("Read by", "Kufundwe ngu"),
+        ("Reading...", "Kuyafundwa..."),
+        ("Reason", "Isizathu"),
+        ("Reason for reassignment", "Isizathu sokwabela kwakhona"),
+        ("Reassign", "Yabela kwakhona"),
+        ("Reassign pending step", "Yabela kwakhona inyathelo elilindileyo"),
+        ("Receipt Details", "Iinkcukacha zolwamkelo"),
+        ("Received", "Kufunyenwe"),
+        ("Received By", "Kufunyenwe ngu"),
+        ("Receiver's name", "Igama lomamkeli"),
+        ("Recent Activity", "Umsebenzi wakutshanje"),
+        ("Recent Opportunities", "Amathuba akutshanje"),
+        ("Recent Requisitions", "Izicelo zakutshanje"),
+        ("Recently Added", "Okongezwe kutshanje"),
+        ("Recognition", "Ukwamkelwa"),
+        ("Recognized Spend", "Inkcitho eyamkelweyo"),
+        ("Recommendation", "Ingcebiso"),
+        ("Recommended Supplier", "Umboneleli ocetyiswayo"),
+        ("Recommended Value", "Ixabiso elicetyiswayo"),
+        ("Recommended documents: Technical Specifications, Terms and Conditions, Evaluation Criteria, Pricing Schedule, Company Profile Template", "Amaxwebhu acetyiswayo: Iinkcukacha zobugcisa, Imigaqo nemiqathango, Imigaqo yovavanyo, Uluhlu lwamaxabiso, Itemplate yeprofayile yenkampani"),
+        ("Record", "Irekhodi"),
+        ("Record Resolution", "Rekhoda isigqibo"),
+        ("Ref:", "Isalathiso:"),
+        ("Reference", "Isalathiso"),
+        ("Reference Number", "Inombolo yesalathiso"),
+        ("Reg. No.", "Inombolo yobhaliso"),
+        ("Register Invoice", "Bhalisa i-invoyisi"),
+        ("Register and Match", "Bhalisa uze uthelekise"),
+        ("Register for Briefing", "Bhalisela intlanganiso yenkcazelo"),
+        ("Registered", "Kubhalisiwe"),
+        ("Registered as {name} ({id})", "Ubhaliswe njengo-{name} ({id})"),
+        ("Registered by", "Kubhaliswe ngu"),
+        ("Registration", "Ubhaliso"),
+        ("Reject", "Yala"),
+        ("Related To", "Kunxulumene no"),
+        ("Remaining", "Okuseleyo"),
+        ("Remaining / Unallocated", "Okuseleyo / Okungabiwanga"),
+        ("Remittance", "Isaziso sentlawulo"),
+        ("Remove", "Susa"),
+        ("Renewal Terms", "Imiqathango yohlaziyo"),
+        ("Repeat Finding", "Isiphumo esiphindiweyo"),
+        ("Repeat Findings", "Iziphumo eziphindiweyo"),
+        ("Report", "Ingxelo"),
+        ("Report No.", "Inombolo yengxelo"),
+        ("Report Violation", "Xela ukwaphulwa"),
+        ("Reputation", "Udumo"),
+        ("Req ID", "I-ID yesicelo"),
+        ("Request Transfer", "Cela udluliselo"),
+        ("Request deviation from competitive bidding process", "Cela ukuphambuka kwinkqubo yethenda ekhuphisanayo"),
+        ("Requested", "Kuceliwe"),
+        ("Requester", "Umceli"),
+        ("Require Biometric Authentication", "Funa uqinisekiso lwe-biometric"),
+        ("Required By", "Kufuneka ngo"),
+        ("Required documents: Supplier quotation, Market research evidence, Supplier B-BBEE certificate, Tax clearance", "Amaxwebhu afunekayo: Ikowuteshini yomboneleli, Ubungqina bophando lwentengiso, Isatifikethi se-B-BBEE somboneleli, Imvume yerhafu"),
+        ("Required uptime percentage", "Ipesenti yexesha lokusebenza efunekayo"),
+        ("Requires Justification", "Kufuna isizathu"),
+        ("Requisition", "Isicelo"),
+        ("Requisition Details", "Iinkcukacha zesicelo"),
+        ("Requisition Reference", "Isalathiso sesicelo"),
+        ("Requisition {id} - {department}", "Isicelo {id} - {department}"),
+        ("Reserve threshold at {percent}%", "Umda wogcino ku-{percent}%"),
+        ("Reserve: {amount}", "Okugciniweyo: {amount}"),
+        ("Reset", "Setha kwakhona"),
+        ("Residual", "Okuseleyo"),
+        ("Resolution", "Isigqibo"),
+        ("Resolution No.", "Inombolo yesigqibo"),
+        ("Resolution Rate", "Izinga lokusombulula"),
+        ("Resolution Time (hours)", "Ixesha lokusombulula (iiyure)"),
+        ("Resolve Exception", "Sombulula okungaqhelekanga"),
+        ("Resolved", "Kusonjululwe"),
+        ("Response", "Impendulo"),
+        ("Response Time (hours)", "Ixesha lokuphendula (iiyure)"),
+        ("Responsible", "Onoxanduva"),
+        ("Responsible Party", "Iqela elinoxanduva"),
+        ("Responsible party", "Iqela elinoxanduva"),
+        ("Result", "Isiphumo"),
+        ("Revenue Summary", "Isishwankathelo sengeniso"),
+        ("Reverse Auctions", "Iifandesi ezibuyela umva"),
+        ("Review & Submit", "Phonononga uze ungenise"),
+        ("Review Details", "Iinkcukacha zophononongo"),
+        ("Review and score bids for open tenders", "Phonononga uze unike amanqaku iibhidi zeethenda ezivulekileyo"),
+        ("Reviews Pending Adjudication", "Uphononongo olulinde ugwebo"),
+        ("Revoke", "Rhoxisa"),
+        ("Risk Distribution", "Unikezelo lomngcipheko"),
+        ("Risk Heat Map", "Imaphu yobushushu bomngcipheko"),
+        ("Risk Implication", "Impembelelo yomngcipheko"),
+        ("Risk Indicator", "Isalathisi somngcipheko"),
+        ("Risk Indicators ({count} total)", "Izalathisi zomngcipheko ({count} zizonke)"),
+        ("Risk Indicators by Category", "Izalathisi zomngcipheko ngodidi"),
+        ("Risk Level", "Inqanaba lomngcipheko"),
+        ("Risk Rating", "Umlinganiselo womngcipheko"),
+        ("Risk Register ({count} total)", "Irejista yomngcipheko ({count} kukonke)"),
+        ("Risks", "Imingcipheko"),
+        ("Root Cause", "Unobangela oyingcambu"),
+        ("Run", "Umjikelo"),
+        ("Run Assessment", "Qhuba uvavanyo"),
+        ("Runs", "Imijikelo"),
+        ("Save", "Gcina"),
+        ("Save Changes", "Gcina utshintsho"),
+        ("Save Draft", "Gcina uyilo"),
+        ("Save Tolerances", "Gcina unyamezelo"),
+        ("Save as", "Gcina njenge"),
+        ("Saved as", "Kugcinwe njenge"),
+        ("Saved as Records", "Kugcinwe njengeerekhodi"),
+        ("Scan invoices, delivery notes and B-BBEE certificates into draft records", "Skena ii-invoyisi, amanqaku okuzisa nezatifikethi ze-B-BBEE zibe ziirekhodi zoyilo"),
+        ("Scanned document", "Uxwebhu oluskeniweyo"),
+        ("Schedule Meeting", "Cwangcisa intlanganiso"),
+        ("Scope", "Ubungakanani"),
+        ("Scope Description", "Inkcazelo yobungakanani"),
+        ("Scope of Work", "Ubungakanani bomsebenzi"),
+        ("Score", "Amanqaku"),
+        ("Score: {supplier_name}", "Amanqaku: {supplier_name}"),
+        ("Scorecard Points", "Amanqaku ekhadi lamanqaku"),
+        ("Scoring", "Ukunika amanqaku"),
+        ("Scoring Method: {method} | Min Technical Score: {score}% | Price Weight: {weight}%", "Indlela yamanqaku: {method} | Amanqaku amancinane obugcisa: {score}% | Ubunzima bexabiso: {weight}%"),
+        ("Scoring Progress", "Inkqubela yamanqaku"),
+        ("Scoring Weights", "Ubunzima bamanqaku"),
+        ("Screen", "Isikrini"),
+        ("Search", "Khangela"),
+        ("Search GR, PO, supplier...", "Khangela i-GR, i-PO, umboneleli..."),
+        ("Search POs...", "Khangela ii-PO..."),
+        ("Search auctions...", "Khangela iifandesi..."),
+        ("Search audit logs...", "Khangela iirekhodi zophicotho..."),
+        ("Search by ID, description, requester...", "Khangela nge-ID, inkcazelo, umceli..."),
+        ("Search by ID, title, owner...", "Khangela nge-ID, isihloko, umnini..."),
+        ("Search contracts...", "Khangela iikhontrakthi..."),
+        ("Search documents...", "Khangela amaxwebhu..."),
+        ("Search items...", "Khangela izinto..."),
+        ("Search supplier...", "Khangela umboneleli..."),
+        ("Search suppliers...", "Khangela ababoneleli..."),
+        ("Search tenders...", "Khangela iithenda..."),
+        ("Search the catalogue by name, item code or supplier", "Khangela ikhathalogu ngegama, ikhowudi yento okanye umboneleli"),
+        ("Search user...", "Khangela umsebenzisi..."),
+        ("Secondary Color", "Umbala wesibini"),
+        ("Security", "Ukhuseleko"),
+        ("Security Services", "Iinkonzo zokhuseleko"),
+        ("Segregation", "Ukwahlulwa"),
+        ("Select Category", "Khetha udidi"),
+        ("Select Department", "Khetha isebe"),
+        ("Select Deviation Type", "Khetha uhlobo lokuphambuka"),
+        ("Select a Bid to Score", "Khetha ibhidi oza kuyinika amanqaku"),
+        ("Select action", "Khetha inyathelo"),
+        ("Select category...", "Khetha udidi..."),
+        ("Select department", "Khetha isebe"),
+        ("Select province", "Khetha iphondo"),
+        ("Select receiving budget", "Khetha ibhajethi eyamkelayo"),
+        ("Select source budget", "Khetha ibhajethi yomthombo"),
+        ("Select supplier", "Khetha umboneleli"),
+        ("Select supplier...", "Khetha umboneleli..."),
+        ("Select type", "Khetha uhlobo"),
+        ("Select...", "Khetha..."),
+        ("Send Notification", "Thumela isaziso"),
+        ("Sent to Supplier", "Kuthunyelwe kumboneleli"),
+        ("Service", "Inkonzo"),
+        ("Service Level Agreement", "Isivumelwano senqanaba lenkonzo"),
+        ("Services", "Iinkonzo"),
+        ("Session Timeout", "Ukuphelelwa lixesha kweseshoni"),
+        ("Severity", "Ubuzaza"),
+        ("Showing {count} of {total} plans", "Kuboniswa {count} kwezingama-{total} izicwangciso"),
+        ("Showing {count} of {total} requisitions", "Kuboniswa {count} kwezingama-{total} izicelo"),
+        ("Significant", "Okubalulekileyo"),
+        ("Signing as", "Utyikitya njenge"),
+        ("Single Source", "Umthombo omnye"),
+        ("Single Source - Only one supplier can provide the goods/services", "Umthombo omnye - Umboneleli omnye kuphela onokubonelela ngeempahla/iinkonzo"),
+        ("Single Source Procurement", "Ukuthenga kumthombo omnye"),
+        ("Size", "Ubungakanani"),
+        ("Size (Largest)", "Ubungakanani (obukhulu kakhulu)"),
+        ("Size (Smallest)", "Ubungakanani (obuncinane kakhulu)"),
+        ("SoD", "SoD"),
+        ("Software", "Isoftwe"),
+        ("Sole Supplier", "Umboneleli omnye"),
+        ("Sole Supplier - Supplier has exclusive rights (patents, licenses)", "Umboneleli omnye - Umboneleli unamalungelo akhethekileyo (amalungelo awodwa omenzi, iilayisensi)"),
+        ("Sort By", "Lungelelanisa ngo"),
+        ("Source", "Umthombo"),
+        ("Source Requisition", "Isicelo somthombo"),
+        ("Sources:", "Imithombo:"),
+        ("Sourcing", "Ukufumana"),
+        ("Special delivery instructions, access requirements, etc.", "Imiyalelo ekhethekileyo yokuzisa, iimfuno zokungena, njl."),
+        ("Specifications", "Iinkcukacha"),
+        ("Spend", "Inkcitho"),
+        ("Spend Analytics", "Uhlalutyo lwenkcitho"),
+        ("Spend Targets", "Iithagethi zenkcitho"),
+        ("Spend by B-BBEE Level", "Inkcitho ngenqanaba le-B-BBEE"),
+        ("Spend by Category", "Inkcitho ngodidi"),
+        ("Stable", "Kuzinzile"),
+        ("Stage", "Inqanaba"),
+        ("Start Bid", "Ibhidi yokuqala"),
+        ("Start Date", "Umhla wokuqala"),
+        ("Start Scoring", "Qalisa ukunika amanqaku"),
+        ("Starting Price", "Ixabiso lokuqala"),
+        ("Starting: {amount}", "Ukuqala: {amount}"),
+        ("Status", "Imeko"),
+        ("Status:", "Imeko:"),
+        ("Stock", "Isitokhwe"),
+        ("Strategic Objectives", "Iinjongo zobuchule"),
+        ("Strategic Partnership", "Intsebenziswano yobuchule"),
+        ("Strategic Partnership - Existing strategic relationship", "Intsebenziswano yobuchule - Ubudlelwane bobuchule obukhoyo"),
+        ("Street address", "Idilesi yesitrato"),
+        ("Submit", "Ngenisa"),
+        ("Submit Action Plan", "Ngenisa isicwangciso sentshukumo"),
+        ("Submit Request", "Ngenisa isicelo"),
+        ("Submit Scores", "Ngenisa amanqaku"),
+        ("Submit for Approval", "Ngenisela imvume"),
+        ("Submit for Review", "Ngenisela uphononongo"),
+        ("Subtotal", "Isixa esingaphantsi"),
+        ("Subtotal:", "Isixa esingaphantsi:"),
+        ("Success Rate", "Izinga lempumelelo"),
+        ("Summary", "Isishwankathelo"),
+        ("Supplier", "Umboneleli"),
+        ("Supplier Details", "Iinkcukacha zomboneleli"),
+        ("Supplier Information", "Ulwazi lomboneleli"),
+        ("Supplier Invoice Number", "Inombolo ye-invoyisi yomboneleli"),
+        ("Supplier Name", "Igama lomboneleli"),
+        ("Supplier Performance", "Ukusebenza komboneleli"),
+        ("Supplier Performance Scores ({count} suppliers)", "Amanqaku okusebenza kwababoneleli (ababoneleli abangama-{count})"),
+        ("Supplier Registry", "Irejista yababoneleli"),
+        ("Supplier Risk Assessment", "Uvavanyo lomngcipheko womboneleli"),
+        ("Supplier blacklisted", "Umboneleli ukuluhlu olumnyama"),
+        ("Supplier contact name", "Igama lomntu wokuqhagamshelana nomboneleli"),
+        ("Supplier invoices, three-way matching and payment aging", "Ii-invoyisi zababoneleli, uthelekiso lwamacala amathathu nobudala bentlawulo"),
+        ("Supplier not in the registry", "Umboneleli akakho kwirejista"),
+        ("Supplier suspended", "Umboneleli unqunyanyisiwe"),
+        ("Supplier:", "Umboneleli:"),
+        ("Suppliers ({count} total)", "Ababoneleli ({count} bebonke)"),
+        ("Suppliers at Risk ({count} total)", "Ababoneleli abasemngciphekweni ({count} bebonke)"),
+        ("Supply Chain", "Ikhonkco lobonelelo"),
+        ("Support", "Inkxaso"),
+        ("Support Email", "I-imeyile yenkxaso"),
+        ("Support Phone", "Ifowuni yenkxaso"),
+        ("Supporting Documents", "Amaxwebhu axhasayo"),
+        ("Suspended", "Kunqunyanyisiwe"),
+        ("Table", "Itheyibhile"),
+        ("Tags (comma-separated)", "Iithegi (zahlulwe ngekoma)"),
+        ("Target", "Ithagethi"),
+        ("Target Date", "Umhla ekujoliswe kuwo"),
+        ("Tax (15%):", "Irhafu (15%):"),
+        ("Tax clearance certificate", "Isatifikethi semvume yerhafu"),
+        ("Technical", "Ezobugcisa"),
+        ("Technical Requirements", "Iimfuno zobugcisa"),
+        ("Technical Score", "Amanqaku obugcisa"),
+        ("Technical Specifications", "Iinkcukacha zobugcisa"),
+        ("Technical requirements and specifications", "Iimfuno zobugcisa neenkcukacha"),
+        ("Template", "Itemplate"),
+        ("Tender", "Ithenda"),
+        ("Tender Details", "Iinkcukacha zethenda"),
+        ("Tender Documents", "Amaxwebhu ethenda"),
+        ("Tender Published", "Ithenda ipapashiwe"),
+        ("Tender Ref", "Isalathiso sethenda"),
+        ("Tender Summary", "Isishwankathelo sethenda"),
+        ("Tender Title", "Isihloko sethenda"),
+        ("Tender Type", "Uhlobo lwethenda"),
+        ("Tender Value", "Ixabiso lethenda"),
+        ("Tenders ({count} total)", "Iithenda ({count} zizonke)"),
+        ("Tenders Pending Evaluation", "Iithenda ezilinde uvavanyo"),
+        ("Terminated", "Kupheliswe"),
+        ("Termination Clause", "Isolotya lokupheliswa"),
+        ("Terms", "Imiqathango"),
+        ("The certificate expired on {expiry}", "Isatifikethi saphelelwa lixesha ngomhla we-{expiry}"),
+        ("The original is only shown in the session it was uploaded in", "Eyokuqala iboniswa kuphela kwiseshoni eyalayishwa kuyo"),
+        ("Theme", "Umxholo"),
+        ("There is no live auction currently available.", "Akukho fandesi iqhubekayo efumanekayo ngoku."),
+        ("Thinking...", "Kuyacingwa..."),
+        ("This Month", "Le nyanga"),
+        ("This Quarter", "Le kota"),
+        ("This invoice may duplicate", "Le invoyisi isenokuba yimpinda ka"),
+        ("This tender must be approved before it can be published. Current status: ", "Le thenda kufuneka ivunywe phambi kokuba ipapashwe. Imeko yangoku: "),
+        ("Three-Way Match", "Uthelekiso lwamacala amathathu"),
+        ("Threshold", "Umda"),
+        ("Tick none to delegate every document type", "Ungaphawuli nanye ukuze ugqithisele zonke iintlobo zamaxwebhu"),
+        ("Time", "Ixesha"),
+        ("Time Remaining", "Ixesha eliseleyo"),
+        ("Timeline", "Uludwe lwexesha"),
+        ("Timeline & Milestones", "Uludwe lwexesha namanqanaba abalulekileyo"),
+        ("Timeline ({count} milestones)", "Uludwe lwexesha (amanqanaba abalulekileyo angama-{count})"),
+        ("Timestamp", "Isitampu sexesha"),
+        ("Title", "Isihloko"),
+        ("To", "Ukuya"),
+        ("To budget", "Ukuya kwibhajethi"),
+        ("Today's Activity", "Umsebenzi wanamhlanje"),
+        ("Top Performers", "Abasebenza kakuhle kakhulu"),
+        ("Top Performers (85%+)", "Abasebenza kakuhle kakhulu (85%+)"),
+        ("Top Performing Suppliers", "Ababoneleli abasebenza kakuhle kakhulu"),
+        ("Top Suppliers by Spend", "Ababoneleli abaphezulu ngenkcitho"),
+        ("Total", "Isixa"),
+        ("Total Active Budget", "Isixa sebhajethi esebenzayo"),
+        ("Total Allocated", "Isixa esabiweyo"),
+        ("Total Amount:", "Isixa siyonke:"),
+        ("Total App Users", "Isixa sabasebenzisi be-app"),
+        ("Total Approved YTD", "Isixa esivunyiweyo sonyaka ukuza kuthi ga ngoku"),
+        ("Total Budget", "Isixa sebhajethi"),
+        ("Total Budget Amount", "Isixa sebhajethi siyonke"),
+        ("Total Checks", "Isixa sokujonga"),
+        ("Total Contract Value", "Isixa sexabiso lekhontrakthi"),
+        ("Total Contracts:", "Isixa seekhontrakthi:"),
+        ("Total Controls", "Isixa solawulo"),
+        ("Total Documents", "Isixa samaxwebhu"),
+        ("Total Entries", "Isixa samangeniso"),
+        ("Total Evaluations", "Isixa sovavanyo"),
+        ("Total Findings", "Isixa seziphumo"),
+        ("Total Functionality Weight: ", "Isixa sobunzima bokusebenza: "),
+        ("Total Items", "Isixa sezinto"),
+        ("Total Milestones", "Isixa samanqanaba abalulekileyo"),
+        ("Total POs:", "Isixa see-PO:"),
+        ("Total Reviews", "Isixa sophononongo"),
+        ("Total Risks", "Isixa semingcipheko"),
+        ("Total Score", "Isixa samanqaku"),
+        ("Total Spend", "Isixa senkcitho"),
+        ("Total Storage", "Isixa sogcino"),
+        ("Total Suppliers", "Isixa sababoneleli"),
+        ("Total Value", "Isixa sexabiso"),
+        ("Total Value:", "Isixa sexabiso:"),
+        ("Total Violations", "Isixa sokwaphulwa"),
+        ("Total budget for this sourcing plan", "Isixa sebhajethi yesi sicwangciso sokufumana"),
+        ("Total of {total} should be {expected}", "Isixa esingu-{total} kufuneka sibe ngu-{expected}"),
+        ("Total:", "Isixa:"),
+        ("Track all system activities and changes", "Landelela yonke imisebenzi yenkqubo notshintsho"),
+        ("Training", "Uqeqesho"),
+        ("Transfer", "Udluliselo"),
+        ("Transfers", "Udluliselo"),
+        ("Transport", "Ezothutho"),
+        ("Treasury", "UNondyebo"),
+        ("Trend", "Umkhwa"),
+        ("Try adjusting your filters or add a new item", "Zama ukulungisa izihluzi zakho okanye wongeze into entsha"),
+        ("Try adjusting your filters or create a new contract", "Zama ukulungisa izihluzi zakho okanye uyile ikhontrakthi entsha"),
+        ("Try adjusting your filters or create a new purchase order", "Zama ukulungisa izihluzi zakho okanye uyile i-odolo entsha yokuthenga"),
+        ("Try adjusting your filters to see more results", "Zama ukulungisa izihluzi zakho ukuze ubone iziphumo ezingakumbi"),
+        ("Try adjusting your search or filters", "Zama ukulungisa ukhangelo lwakho okanye izihluzi"),
+        ("Type", "Uhlobo"),
+        ("Tyre", "Ivili"),
+        ("Unanimous", "Ngamxhelo mnye"),
+        ("Under Review", "Kuyaphononongwa"),
+        ("Underperforming", "Abangasebenzi kakuhle"),
+        ("Underperforming (<70%)", "Abangasebenzi kakuhle (<70%)"),
+        ("Unit", "Iyunithi"),
+        ("Unit Price", "Ixabiso leyunithi"),
+        ("Unit Price (ZAR)", "Ixabiso leyunithi (ZAR)"),
+        ("Unknown", "Akwaziwa"),
+        ("Unknown document", "Uxwebhu olungaziwayo"),
+        ("Unlikely", "Akunakwenzeka"),
+        ("Unqualified w/Findings", "Akunamiqathango kodwa kuneziphumo"),
+        ("Until", "Kude kube"),
+        ("Upcoming Deadlines", "Imihla yokugqibela ezayo"),
+        ("Upcoming Meetings", "Iintlanganiso ezizayo"),
+        ("Upcoming Milestones", "Amanqanaba abalulekileyo azayo"),
+        ("Update", "Hlaziya"),
+        ("Updated", "Kuhlaziyiwe"),
+        ("Upload", "Layisha"),
+        ("Upload Document", "Layisha uxwebhu"),
+        ("Upload Documents", "Layisha amaxwebhu"),
+        ("Upload Supporting Documents", "Layisha amaxwebhu axhasayo"),
+        ("Upload a scanned document to get started", "Layisha uxwebhu oluskeniweyo ukuze uqalise"),
+        ("Upload at most", "Layisha ubuninzi obungu"),
+        ("Upload documents or adjust your filters", "Layisha amaxwebhu okanye ulungise izihluzi zakho"),
+        ("Upload quotations, market research, supplier documentation", "Layisha iikowuteshini, uphando lwentengiso, amaxwebhu omboneleli"),
+        ("Upload specifications, terms, and other tender documents (PDF, Word, Excel)", "Layisha iinkcukacha, imiqathango namanye amaxwebhu ethenda (PDF, Word, Excel)"),
+        ("Upload supporting documents such as specifications, quotes, or budget approvals.", "Layisha amaxwebhu axhasayo afana neenkcukacha, iikowuteshini okanye iimvume zebhajethi."),
+        ("Uploaded By", "Kulayishwe ngu"),
+        ("Uploaded Documents", "Amaxwebhu alayishiweyo"),
+        ("User", "Umsebenzisi"),
+        ("User ID", "I-ID yomsebenzisi"),
+        ("User Information", "Ulwazi lomsebenzisi"),
+        ("Users", "Abasebenzisi"),
+        ("Utilisation", "Ukusetyenziswa"),
+        ("VAT", "I-VAT"),
+        ("VAT (15%):", "I-VAT (15%):"),
+        ("VAT:", "I-VAT:"),
+        ("Valid B-BBEE certificate", "Isatifikethi se-B-BBEE esisebenzayo"),
+        ("Valid until {expiry}", "Isebenza kude kube ngu-{expiry}"),
+        ("Value", "Ixabiso"),
+        ("Value:", "Ixabiso:"),
+        ("Variance", "Umahluko"),
+        ("Variation", "Utshintsho"),
+        ("Venue", "Indawo"),
+        ("Verified", "Kuqinisekisiwe"),
+        ("Version", "Inguqulelo"),
+        ("Version History", "Imbali yeenguqulelo"),
+        ("View", "Jonga"),
+        ("View All", "Jonga konke"),
+        ("View All Auctions", "Jonga zonke iifandesi"),
+        ("View Contract", "Jonga ikhontrakthi"),
+        ("View Details", "Jonga iinkcukacha"),
+        ("View List", "Jonga uluhlu"),
+        ("View invoice", "Jonga i-invoyisi"),
+        ("View on e-Tender Portal", "Jonga kwiphothali ye-e-Tender"),
+        ("Violations", "Ukwaphulwa"),
+        ("Violations by Type", "Ukwaphulwa ngohlobo"),
+        ("Virements between budget lines for FY", "Udluliselo phakathi kwemigca yebhajethi yonyaka-mali"),
+        ("Votes", "Iivoti"),
+        ("Waived", "Kuxolelwe"),
+        ("Warehouse", "Indlu yokugcina"),
+        ("Warning: Category allocations exceed total budget by ", "Isilumkiso: Ulwabiwo lweendidi ludlula isixa sebhajethi ngo "),
+        ("Warranty Period (months)", "Ixesha lewaranti (iinyanga)"),
+        ("Weight %", "Ubunzima %"),
+        ("Weight: {weight}%", "Ubunzima: {weight}%"),
+        ("Western Cape", "Ntshona Koloni"),
+        ("What alternatives were considered? Why were they not suitable?", "Zeziphi ezinye iindlela ezaqwalaselwayo? Kutheni zingafanelekanga?"),
+        ("Why This Supplier", "Kutheni lo mboneleli"),
+        ("Why the funds are needed and why the source budget can release them", "Kutheni imali ifuneka nokuba kutheni ibhajethi yomthombo inokuyikhupha"),
+        ("Why the run is being cancelled", "Kutheni umjikelo urhoxiswa"),
+        ("Why the variance is acceptable, or what the supplier must correct", "Kutheni umahluko wamkelekile, okanye yintoni ekufuneka umboneleli ayilungise"),
+        ("Within SLA", "Ngaphakathi kwe-SLA"),
+        ("Within tolerance", "Ngaphakathi kunyamezelo"),
+        ("Word", "Word"),
+        ("Works", "Imisebenzi"),
+        ("YTD Revenue", "Ingeniso yonyaka ukuza kuthi ga ngoku"),
+        ("YTD Spend", "Inkcitho yonyaka ukuza kuthi ga ngoku"),
+        ("You are offline. Showing the data saved on this device.", "Awuxhunyiwe. Kuboniswa idatha egcinwe kwesi sixhobo."),
+        ("You are the leading bidder!", "Nguwe ophambili!"),
+        ("You have been outbid. Your last bid: R {bid}", "Udlulwe ngebhidi. Ibhidi yakho yokugqibela: R {bid}"),
+        ("You have unsaved changes", "Unotshintsho olungagcinwanga"),
+        ("Your role does not have permission to view this page. Contact your system administrator if you need access.", "Indima yakho ayinayo imvume yokujonga eli phepha. Qhagamshelana nomlawuli wenkqubo ukuba ufuna ufikelelo."),
+        ("all must approve", "bonke kufuneka bavume"),
+        ("another user", "omnye umsebenzisi"),
+        ("are past the", "zidlule i"),
+        ("at a time", "ngexesha elinye"),
+        ("available", "iyafumaneka"),
+        ("business day(s)", "usuku/iintsuku zomsebenzi"),
+        ("day payment term", "ixesha lentlawulo leentsuku"),
+        ("days after the invoice is received", "iintsuku emva kokufunyanwa kwe-invoyisi"),
+        ("days overdue", "iintsuku ezidlulileyo"),
+        ("detected automatically", "kufunyenwe ngokuzenzekelayo"),
+        ("documents at a time", "amaxwebhu ngexesha elinye"),
+        ("e-Tender Portal", "Iphothali ye-e-Tender"),
+        ("e.g. Annual leave", "umz. Ikhefu lonyaka"),
+        ("e.g. important, q1, project-x", "umz. kubalulekile, q1, iprojekthi-x"),
+        ("e.g., 12", "umz., 12"),
+        ("e.g., 24", "umz., 24"),
+        ("e.g., 30", "umz., 30"),
+        ("e.g., 30 days from invoice", "umz., iintsuku ezingama-30 ukusukela kwi-invoyisi"),
+        ("e.g., 36 months", "umz., iinyanga ezingama-36"),
+        ("e.g., 4", "umz., 4"),
+        ("e.g., 99.9", "umz., 99.9"),
+        ("e.g., Annual Procurement Plan FY 2025/26", "umz., Isicwangciso Sonyaka Sokuthenga 2025/26"),
+        ("e.g., Arbitration", "umz., Ulamlo"),
+        ("e.g., CC-IT-001", "umz., CC-IT-001"),
+        ("e.g., CTR-2025-0001", "umz., CTR-2025-0001"),
+        ("e.g., Each, Months", "umz., Nganye, Iinyanga"),
+        ("e.g., Head Office, Johannesburg", "umz., Iofisi enkulu, eRhawutini"),
+        ("e.g., IT-LAP-001", "umz., IT-LAP-001"),
+        ("e.g., PFMA/2025/IT/001", "umz., PFMA/2025/IT/001"),
+        ("e.g., REQ-2025-0001", "umz., REQ-2025-0001"),
+        ("e.g., South African Law", "umz., Umthetho woMzantsi Afrika"),
+        ("e.g., TND-2025-0001", "umz., TND-2025-0001"),
+        ("e.g., Technical Capability", "umz., Ubuchule bobugcisa"),
+        ("each", "nganye"),
+        ("excluded", "akuqukwanga"),
+        ("field(s) to check", "indawo/iindawo ekufuneka zijongwe"),
+        ("held as a possible duplicate", "kubanjwe njengempinda enokwenzeka"),
+        ("invoiced", "kufakwe kwi-invoyisi"),
+        ("invoices", "ii-invoyisi"),
+        ("more authorisation(s) needed from someone other than", "ugunyaziso olongezelelweyo olufunekayo komnye umntu ongenguye"),
+        ("of", "kwi"),
+        ("page(s)", "iphepha/amaphepha"),
+        ("submitted for approval:", "kungeniselwe imvume:"),
+        ("suppliers", "ababoneleli"),
+        ("type chosen on upload", "uhlobo olukhethwe xa kulayishwa"),
+        ("until", "kude kube"),
+        ("up to", "ukuya kuthi ga ku"),
+        ("{entity} not found", "{entity} ayifunyanwanga"),
+        ("{entity} {id} could not be loaded: {error}", "{entity} {id} ayikwazanga ukulayishwa: {error}"),
+        ("{entity} {id} does not exist or you no longer have access to it.", "{entity} {id} ayikho okanye awusenako ukufikelela kuyo."),
+        ("\"{level}\" is not a level from 1 to 8", "\"{level}\" ayingomgangatho ukusuka ku-1 ukuya ku-8"),
+        ("(empty)", "(akukho nto)"),
+        ("31-60 days", "iintsuku ezingama-31-60"),
+        ("61-90 days", "iintsuku ezingama-61-90"),
+        ("90+ days", "iintsuku ezingama-90+"),
+        ("Bulk EFT (fixed width)", "I-EFT yobuninzi (ububanzi obumiselweyo)"),
+        ("AI Analysis", "Uhlalutyo lwe-AI"),
+        ("AI {category} Suggestions", "Iingcebiso ze-AI: {category}"),
+        ("Abstain", "Ukungavoti"),
+        ("Accept", "Yamkela"),
+        ("Accept Variance", "Yamkela umahluko"),
+        ("Actual: {value}", "Okwenyani: {value}"),
+        ("Ad Hoc", "Okwethutyana"),
+        ("Adverse Opinion", "Uluvo olungalunganga"),
+        ("Advisory Member", "Ilungu elicebisayo"),
+        ("Against", "Uyachasa"),
+        ("All Receipts", "Zonke iirisithi"),
+        ("All incoming deliveries have been processed", "Konke ukuhanjiswa okungenayo sele kuqhutyiwe"),
+        ("Analyze {entity} {id} for completeness and compliance.", "Hlalutya i-{entity} {id} ukuba igqibelele kwaye iyahambelana na nemigaqo."),
+        ("Android", "Android"),
+        ("Android Phone", "Ifowuni ye-Android"),
+        ("Android Tablet", "Ithebhulethi ye-Android"),
+        ("Annexure", "Isihlomelo"),
+        ("Annually", "Ngonyaka"),
+        ("Anti-Corruption", "Ukulwa urhwaphilizo"),
+        ("Approval due", "Imvume ifanele"),
+        ("Approval escalated", "Imvume inyuselwe phezulu"),
+        ("Asset Management", "Ulawulo lwee-asethi"),
+        ("Auto-extend: Auction extends by {minutes} minutes if bid placed in last {seconds} seconds", "Ukwandisa ngokuzenzekelayo: ifandesi yandiswa ngemizuzu eyi-{minutes} ukuba ibhidi ifakwe kwimizuzwana eyi-{seconds} yokugqibela"),
+        ("Avoid", "Phepha"),
+        ("Awaiting Signature", "Kulindwe umsayino"),
+        ("Award Letter", "Ileta yowiso"),
+        ("B-BBEE Certificate", "Isatifikethi se-B-BBEE"),
+        ("B-BBEE Supplier Classifications ({count} total)", "Udidi lwababoneleli be-B-BBEE ({count} zizonke)"),
+        ("B-BBEE Verification", "Uqinisekiso lwe-B-BBEE"),
+        ("B-BBEE level", "Inqanaba le-B-BBEE"),
+        ("Below Target", "Ngaphantsi kwethagethi"),
+        ("Bid Document", "Uxwebhu lwebhidi"),
+        ("Bid Management", "Ulawulo lweebhidi"),
+        ("Bid Status Alerts", "Izilumkiso zemeko yebhidi"),
+        ("Bid Submission", "Ukungeniswa kwebhidi"),
+        ("Bid Submitted", "Ibhidi ingenisiwe"),
+        ("Biometric Authentication", "Ungqinisiso nge-biometric"),
+        ("Black Owned (51%+)", "Ngabantu abantsundu (51%+)"),
+        ("Black Women Owned (30%+)", "Ngabafazi abantsundu (30%+)"),
+        ("Briefing Check-In", "Ukubhalisa kwintlanganiso yolwazi"),
+        ("Budget Transfer", "Ukudluliselwa kohlahlo-lwabiwo-mali"),
+        ("Budgets, commitments and spend for FY {year}", "Uhlahlo-lwabiwo-mali, izibophelelo nenkcitho yonyaka-mali {year}"),
+        ("CSV", "CSV"),
+        ("Capital Expenditure", "Inkcitho eyinkunzi"),
+        ("Certificate expiry", "Ukuphelelwa sisatifikethi"),
+        ("Certificate period", "Ixesha lesatifikethi"),
+        ("Chairperson", "Usihlalo"),
+        ("Check in to mandatory briefing sessions using GPS location", "Bhalisa kwiintlanganiso zolwazi ezinyanzelekileyo usebenzisa indawo ye-GPS"),
+        ("Closing Soon", "Kuvala kungekudala"),
+        ("Closure", "Ukuvalwa"),
+        ("Company Registration", "Ubhaliso lwenkampani"),
+        ("Completed Receipts", "Iirisithi ezigqityiweyo"),
+        ("Compliance Report", "Ingxelo yokuthobela"),
+        ("Comprehensive", "Epheleleyo"),
+        ("Concern", "Inkxalabo"),
+        ("Confirm deliveries with photo evidence and timestamps", "Qinisekisa ukuhanjiswa ngobungqina beefoto kunye nezitampu zexesha"),
+        ("Conflict of Interest", "Ungquzulwano lweemdla"),
+        ("Continuous", "Oluqhubekayo"),
+        ("Contract Detail", "Iinkcukacha zekhontrakthi"),
+        ("Contract Extension", "Ukwandiswa kwekhontrakthi"),
+        ("Contract Reminders", "Izikhumbuzo zekhontrakthi"),
+        ("Contract Review", "Uphononongo lwekhontrakthi"),
+        ("Contract Variation", "Utshintsho lwekhontrakthi"),
+        ("Create a new procurement tender", "Yenza ithenda entsha yokuthenga"),
+        ("Create a new purchase order", "Yenza i-odolo entsha yokuthenga"),
+        ("Create a new supplier contract", "Yenza ikhontrakthi entsha yomboneleli"),
+        ("Current (0-30 days)", "Ngoku (iintsuku ezi-0-30)"),
+        ("Current: {value}", "Ngoku: {value}"),
+        ("Cybersecurity", "Ukhuseleko lwe-intanethi"),
+        ("Daily", "Yonke imihla"),
+        ("Deadline: {date}", "Umhla wokugqibela: {date}"),
+        ("Deferred", "Kuhlehlisiwe"),
+        ("Delayed", "Kulibazisekile"),
+        ("Delivery Confirmation", "Uqinisekiso lokuhanjiswa"),
+        ("Delivery date", "Umhla wokuhanjiswa"),
+        ("Deputy Chairperson", "Usekela-sihlalo"),
+        ("Designated Group", "Iqela elichongiweyo"),
+        ("Detailed", "Eneenkcukacha"),
+        ("Deviation request {reference} created successfully. It will be routed for approval.", "Isicelo sokutenxa {reference} senziwe ngempumelelo. Siza kuthunyelwa ukuze samkelwe."),
+        ("Digital Signature", "Umsayino wedijithali"),
+        ("Disability Owned", "Ngabantu abakhubazekileyo"),
+        ("Disclaimer of Opinion", "Ukwala ukunika uluvo"),
+        ("Document Scanner", "Iskena samaxwebhu"),
+        ("Document Scanning", "Ukuskena amaxwebhu"),
+        ("Document type", "Uhlobo loxwebhu"),
+        ("Due date", "Umhla wokuhlawula"),
+        ("Duplicate invoice", "I-invoyisi ephindiweyo"),
+        ("EME (< R10m)", "EME (< R10m)"),
+        ("Edit Contract", "Hlela ikhontrakthi"),
+        ("Edit Purchase Order", "Hlela i-odolo yokuthenga"),
+        ("Edit Requisition", "Hlela isicelo sokuthenga"),
+        ("Edit Tender", "Hlela ithenda"),
+        ("Enterprise & Supplier Development", "Uphuhliso lwamashishini nababoneleli"),
+        ("Enterprise Development", "Uphuhliso lwamashishini"),
+        ("Environmental", "Okokusingqongileyo"),
+        ("Escalate", "Nyusela phezulu"),
+        ("Evaluation Report", "Ingxelo yovavanyo"),
+        ("Events", "Iziganeko"),
+        ("Exceeded", "Kugqithiwe"),
+        ("Exempted Micro Enterprise", "Ishishini elincinci kakhulu elikhululweyo"),
+        ("Experience", "Amava"),
+        ("Fail", "Uyasilela"),
+        ("Finance Representative", "Ummeli wezemali"),
+        ("Financial Analysis", "Uhlalutyo lwezemali"),
+        ("Financial Proposal", "Isindululo sezemali"),
+        ("Financial Statements", "Iingxelo zemali"),
+        ("Follow-up", "Ukulandelela"),
+        ("Follow-up Audit", "Uphicotho lokulandelela"),
+        ("For", "Uyavuma"),
+        ("Format", "Ifomathi"),
+        ("Fraud & Irregularities", "Ubuqhophololo nezinto ezingekho mthethweni"),
+        ("Full Match", "Kuyafana ngokupheleleyo"),
+        ("Fully Automated", "Ngokuzenzekelayo ngokupheleleyo"),
+        ("Generic (> R50m)", "Eqhelekileyo (> R50m)"),
+        ("Generic Enterprise", "Ishishini eliqhelekileyo"),
+        ("Get instant alerts when your bid status changes during evaluation", "Fumana izilumkiso ngoko nangoko xa imeko yebhidi yakho itshintsha ngexesha lovavanyo"),
+        ("Governance", "Ulawulo"),
+        ("Hold for Investigation", "Bamba ukuze kuphandwe"),
+        ("IP: {address}", "IP: {address}"),
+        ("IT", "IT"),
+        ("IT & Technology", "IT nobuchwepheshe"),
+        ("Identified exposure", "Umngcipheko ochongiweyo"),
+        ("Implemented", "Kuphunyeziwe"),
+        ("In Review", "Kuyaphononongwa"),
+        ("Info Required", "Kufuneka ulwazi"),
+        ("Initial Finding", "Okufunyenweyo kokuqala"),
+        ("Interim Audit", "Uphicotho lwethutyana"),
+        ("Internal Controls", "Ulawulo lwangaphakathi"),
+        ("Internal Policy", "Umgaqo-nkqubo wangaphakathi"),
+        ("Investigate", "Phanda"),
+        ("Invoice date", "Umhla we-invoyisi"),
+        ("Item on order", "Into ekwi-odolo"),
+        ("Labour Law", "Umthetho wezabasebenzi"),
+        ("Last 12 Months", "Iinyanga ezili-12 ezidlulileyo"),
+        ("Legal", "Ezomthetho"),
+        ("Legal Advisor", "Umcebisi wezomthetho"),
+        ("Legal Opinion", "Uluvo lwezomthetho"),
+        ("Level 4", "Inqanaba 4"),
+        ("Level 5", "Inqanaba 5"),
+        ("Level 6", "Inqanaba 6"),
+        ("Level 7", "Inqanaba 7"),
+        ("Level 8", "Inqanaba 8"),
+        ("Line items", "Izinto zomgca"),
+        ("Live Auction", "Ifandesi ephilayo"),
+        ("Local Content", "Umxholo wasekhaya"),
+        ("MFMA", "MFMA"),
+        ("Maintenance & Repairs", "Ulondolozo nokulungisa"),
+        ("Management Control", "Ulawulo lwabaphathi"),
+        ("Manual", "Ngesandla"),
+        ("Military Veteran Owned", "Ngamagqala omkhosi"),
+        ("Min Decrement: {amount}", "Ukuhla okuncinci: {amount}"),
+        ("Mobile Invoicing", "Ii-invoyisi zeselula"),
+        ("Monitoring", "Ukubeka iliso"),
+        ("Monthly", "Ngenyanga"),
+        ("Most Popular", "Ezithandwa kakhulu"),
+        ("Motivation Memo", "Imemo yenkuthazo"),
+        ("NT Regulations", "Imimiselo ye-NT"),
+        ("No CSD number", "Akukho nombolo ye-CSD"),
+        ("No Match", "Akufani"),
+        ("No completed receipts", "Akukho zirisithi zigqityiweyo"),
+        ("No goods receipts found", "Akufunyenwanga zirisithi zeempahla"),
+        ("No pending receipts", "Akukho zirisithi zilindileyo"),
+        ("No receipts have been completed yet", "Akukabikho zirisithi zigqityiweyo"),
+        ("Not Matched", "Akuthelekiswanga"),
+        ("Not Operating", "Akusebenzi"),
+        ("Not Started", "Akuqalanga"),
+        ("Not required (value below R10,000,000)", "Akufuneki (ixabiso lingaphantsi kwe-R10,000,000)"),
+        ("Not required (value below R2,000,000)", "Akufuneki (ixabiso lingaphantsi kwe-R2,000,000)"),
+        ("Not required (value below R500,000)", "Akufuneki (ixabiso lingaphantsi kwe-R500,000)"),
+        ("Notified", "Kwaziswe"),
+        ("Offline Bid Preparation", "Ukulungiselela ibhidi ngaphandle kwe-intanethi"),
+        ("Operational", "Okokusebenza"),
+        ("Operational Expenditure", "Inkcitho yokusebenza"),
+        ("Ordinary", "Eqhelekileyo"),
+        ("PDF", "PDF"),
+        ("PFMA", "PFMA"),
+        ("PO exists", "I-odolo yokuthenga ikhona"),
+        ("POPIA", "POPIA"),
+        ("PPPFA", "PPPFA"),
+        ("Partial Match", "Kuyafana ngokuyinxenye"),
+        ("Partially Compliant", "Kuthobela ngokuyinxenye"),
+        ("Partially Operating", "Kusebenza ngokuyinxenye"),
+        ("Participate in reverse auctions in real-time from your mobile device", "Thatha inxaxheba kwiifandesi ezibuyela umva ngexesha lokwenyani usebenzisa isixhobo sakho seselula"),
+        ("Pass", "Uyaphumelela"),
+        ("Pending Action", "Kulindwe inyathelo"),
+        ("Pending Audit", "Kulindwe uphicotho"),
+        ("Pending Receipts", "Iirisithi ezilindileyo"),
+        ("Performance Audit", "Uphicotho lokusebenza"),
+        ("Performance Information", "Ulwazi lokusebenza"),
+        ("Personnel Costs", "Iindleko zabasebenzi"),
+        ("Place Bid", "Faka ibhidi"),
+        ("Placing Bid...", "Ifaka ibhidi..."),
+        ("Planned", "Kucwangcisiwe"),
+        ("Please enter a title", "Nceda ufake isihloko"),
+        ("Please enter a valid amount", "Nceda ufake isixa esisemthethweni"),
+        ("Please enter supplier name", "Nceda ufake igama lomboneleli"),
+        ("Please provide justification", "Nceda unike isizathu"),
+        ("Please select a closing date", "Nceda ukhethe umhla wokuvala"),
+        ("Please select a publication date", "Nceda ukhethe umhla wopapasho"),
+        ("Postponed", "Kuhlehlisiwe"),
+        ("Preferential Procurement", "Ukuthenga okukhethekileyo"),
+        ("Prepare bid documents offline and sync when connected", "Lungiselela amaxwebhu ebhidi ngaphandle kwe-intanethi uze uvumelanise xa uqhagamshelwe"),
+        ("Preview: {name}", "Jonga kuqala: {name}"),
+        ("Priced from the catalogue", "Ixabiso lisuka kwikhathalogu"),
+        ("Procedural", "Okwenkqubo"),
+        ("Process", "Qhuba"),
+        ("Process Recommendation", "Ingcebiso yenkqubo"),
+        ("Publish to Portal", "Papasha kwiphothali"),
+        ("Publishing...", "Iyapapasha..."),
+        ("QR Code Scanning", "Ukuskena iikhowudi ze-QR"),
+        ("QSE (R10m - R50m)", "QSE (R10m - R50m)"),
+        ("Qualified Opinion", "Uluvo olunemiqathango"),
+        ("Qualifying Small Enterprise", "Ishishini elincinci elifanelekileyo"),
+        ("Quantity outstanding", "Isixa esiseleyo"),
+        ("Quarterly", "Ngekota"),
+        ("Receive push notifications when new tenders matching your profile are published", "Fumana izaziso xa kupapashwa iithenda ezintsha ezihambelana neprofayile yakho"),
+        ("Recurring", "Ephindaphindwayo"),
+        ("Recused", "Uzirhoxisile"),
+        ("Reduce", "Nciphisa"),
+        ("Registration number format", "Ifomathi yenombolo yobhaliso"),
+        ("Regularity Audit", "Uphicotho lokuthobela imigaqo"),
+        ("Regulatory", "Okwemimiselo"),
+        ("Regulatory Guidance", "Isikhokelo semimiselo"),
+        ("Reject Invoice", "Yala i-invoyisi"),
+        ("Reminders for upcoming contract milestones, renewals, and deliverables", "Izikhumbuzo zamanqanaba ekhontrakthi ezayo, ukuhlaziywa kunye nezinto ezinikezelwayo"),
+        ("Reputational", "Okwegama elihle"),
+        ("Request Credit Note", "Cela inowuthi yetyala"),
+        ("Request Information", "Cela ulwazi"),
+        ("Required field", "Intsimi efunekayo"),
+        ("Required for values above R10,000,000", "Kuyafuneka kumaxabiso angaphezu kwe-R10,000,000"),
+        ("Required for values above R2,000,000", "Kuyafuneka kumaxabiso angaphezu kwe-R2,000,000"),
+        ("Required for values above R500,000", "Kuyafuneka kumaxabiso angaphezu kwe-R500,000"),
+        ("Required to ratify emergency procurement", "Kuyafuneka ukuqinisekisa ukuthenga okungxamisekileyo"),
+        ("Retired", "Kurhoxisiwe"),
+        ("Return for Info", "Buyisela ngenxa yolwazi"),
+        ("Returned for Info", "Kubuyiselwe ngenxa yolwazi"),
+        ("Review Required", "Kufuneka uphononongo"),
+        ("Risk Assessment", "Uvavanyo lomngcipheko"),
+        ("Rural", "Ezilalini"),
+        ("Rural/Township Based", "Ezilalini/ezilokishini"),
+        ("SCM Regulations", "Imimiselo ye-SCM"),
+        ("Save Configuration", "Gcina uqwalaselo"),
+        ("Save Features", "Gcina iimpawu"),
+        ("Saving...", "Iyagcina..."),
+        ("Scan QR codes on purchase orders for quick goods receipt", "Skena iikhowudi ze-QR kwii-odolo zokuthenga ukuze wamkele iimpahla ngokukhawuleza"),
+        ("Scan and upload documents directly from your device camera", "Skena kwaye ulayishe amaxwebhu ngqo usebenzisa ikhamera yesixhobo sakho"),
+        ("Secretary", "Unobhala"),
+        ("Segregation of Duties", "Ukwahlulwa kwemisebenzi"),
+        ("Semi-Automated", "Ngokuzenzekelayo ngokuyinxenye"),
+        ("Shortlisted", "Kuluhlu olufutshane"),
+        ("Sign contracts electronically using your mobile device", "Sayina iikhontrakthi ngombane usebenzisa isixhobo sakho seselula"),
+        ("Skills Development", "Uphuhliso lwezakhono"),
+        ("Skipped", "Kutsitywe"),
+        ("Socio-Economic Development", "Uphuhliso lwentlalo noqoqosho"),
+        ("Sourcing Plan", "Isicwangciso sokufumana"),
+        ("Special", "Okukhethekileyo"),
+        ("Special Audit", "Uphicotho olukhethekileyo"),
+        ("Spend Analysis", "Uhlalutyo lwenkcitho"),
+        ("Standard", "Esemgangathweni"),
+        ("Strategic", "Okobuchule"),
+        ("Submit and track invoices directly from the mobile app", "Ngenisa kwaye ulandelele ii-invoyisi ngqo kwi-app yeselula"),
+        ("Submitting...", "Iyangenisa..."),
+        ("Supplier Development", "Uphuhliso lwababoneleli"),
+        ("Supplier exists", "Umboneleli ukhona"),
+        ("Supplier matches PO", "Umboneleli uyahambelana ne-odolo yokuthenga"),
+        ("Supply Chain Management", "Ulawulo lwekhonkco lonikezelo"),
+        ("Sworn Affidavit", "Ingxelo efungelweyo"),
+        ("Target: {value}", "Ithagethi: {value}"),
+        ("Tax Clearance", "Imvume yerhafu"),
+        ("Technical Advisor", "Umcebisi wezobuchwepheshe"),
+        ("Technical Proposal", "Isindululo sobuchwepheshe"),
+        ("Technical Report", "Ingxelo yobuchwepheshe"),
+        ("Tender Analysis", "Uhlalutyo lwethenda"),
+        ("Tender Detail", "Iinkcukacha zethenda"),
+        ("Tender Document Analysis", "Uhlalutyo loxwebhu lwethenda"),
+        ("Tender Notifications", "Izaziso zeethenda"),
+        ("Tender Specification", "Iinkcukacha ezifunekayo zethenda"),
+        ("Tender successfully published to e-Tender portal. Reference: {reference}", "Ithenda ipapashwe ngempumelelo kwiphothali ye-e-Tender. Isalathiso: {reference}"),
+        ("Terms & Conditions", "Imiqathango nemimiselo"),
+        ("Text", "Isicatshulwa"),
+        ("The delivery is dated in the future", "Ukuhanjiswa kunomhla wexesha elizayo"),
+        ("The document type could not be determined", "Uhlobo loxwebhu alukwazanga ukufunyanwa"),
+        ("The due date is not after the invoice date", "Umhla wokuhlawula awukho emva komhla we-invoyisi"),
+        ("The invoice is dated in the future", "I-invoyisi inomhla wexesha elizayo"),
+        ("This month", "Kule nyanga"),
+        ("Threshold Breach", "Ukugqithwa komda"),
+        ("Total calculation", "Ukubalwa kwetotali"),
+        ("Total is the subtotal plus VAT", "Itotali yitotali engaphantsi kunye ne-VAT"),
+        ("Transaction", "Intengiselwano"),
+        ("Travel & Subsistence", "Uhambo nokuziphilisa"),
+        ("Try adjusting your filters or create a new goods receipt", "Zama ukulungisa izihluzi zakho okanye wenze irisithi entsha yeempahla"),
+        ("Type (A-Z)", "Uhlobo (A-Z)"),
+        ("Type (Z-A)", "Uhlobo (Z-A)"),
+        ("Under Evaluation", "Kuyavavanywa"),
+        ("Under Investigation", "Kuyaphandwa"),
+        ("Unqualified with Findings", "Ngaphandle kwemiqathango, kukho okufunyenweyo"),
+        ("Unsuccessful", "Akuphumelelanga"),
+        ("Unverified", "Akuqinisekiswanga"),
+        ("Update Needed", "Kufuneka uhlaziyo"),
+        ("Update contract details", "Hlaziya iinkcukacha zekhontrakthi"),
+        ("Update purchase order details", "Hlaziya iinkcukacha ze-odolo yokuthenga"),
+        ("Update tender details", "Hlaziya iinkcukacha zethenda"),
+        ("Use fingerprint or face recognition for secure authentication", "Sebenzisa umnwe okanye ukuqondwa kobuso ukuze kungqinwe ngokukhuselekileyo"),
+        ("VAT calculation", "Ukubalwa kwe-VAT"),
+        ("VAT is {rate}% of the subtotal", "I-VAT yi-{rate}% yetotali engaphantsi"),
+        ("VAT number format", "Ifomathi yenombolo ye-VAT"),
+        ("VAT of {vat} should be {expected}", "I-VAT eyi-{vat} kufuneka ibe yi-{expected}"),
+        ("Verification", "Uqinisekiso"),
+        ("Verify", "Qinisekisa"),
+        ("Voting Member", "Ilungu elivotayo"),
+        ("Warning", "Isilumkiso"),
+        ("Weekly", "Ngeveki"),
+        ("Withdrawn", "Kurhoxisiwe"),
+        ("You", "Wena"),
+        ("Youth", "Ulutsha"),
+        ("Youth Owned", "Lulutsha"),
+        ("achieved", "kufezekisiwe"),
+        ("assessed", "kuvavanyiwe"),
+        ("bonus", "ibhonasi"),
+        ("draft goods receipt", "irisithi yeempahla eyidrafti"),
+        ("draft invoice", "i-invoyisi eyidrafti"),
+        ("faster than target", "ngokukhawuleza kunethagethi"),
+        ("from last month", "ukususela kwinyanga ephelileyo"),
+        ("from last quarter", "ukususela kwikota ephelileyo"),
+        ("from last year", "ukususela kunyaka ophelileyo"),
+        ("iOS", "iOS"),
+        ("iPad", "iPad"),
+        ("iPhone", "iPhone"),
+        ("in last 30 days", "kwiintsuku ezingama-30 ezidlulileyo"),
+        ("new this month", "ezintsha kule nyanga"),
+        ("non-compliant", "azithobeli"),
+        ("of budget", "yohlahlo-lwabiwo-mali"),
+        ("of total spend", "yenkcitho iyonke"),
+        ("of users", "yabasebenzisi"),
+        ("of {points} points", "kumanqaku angama-{points}"),
+        ("remaining", "eseleyo"),
+        ("require attention", "zifuna ingqalelo"),
+        ("same amount within 7 days", "isixa esifanayo kwiintsuku ezi-7"),
+        ("same invoice number", "inombolo ye-invoyisi efanayo"),
+        ("similar invoice number", "inombolo ye-invoyisi efana nayo"),
+        ("supplier certificate", "isatifikethi somboneleli"),
+        ("target", "ithagethi"),
+        ("total value", "ixabiso lilonke"),
+        ("under budget", "ngaphantsi kohlahlo-lwabiwo-mali"),
+        ("vs budget", "xa kuthelekiswa nohlahlo-lwabiwo-mali"),
+        ("{change} was not applied: {reason}", "{change} ayisetyenziswanga: {reason}"),
+        ("{completed}/{planned} tenders", "iithenda ezi-{completed}/{planned}"),
+        ("{count} open risk indicators - Risk Score: {score}", "Izalathisi zomngcipheko ezivulekileyo ezi-{count} - Amanqaku omngcipheko: {score}"),
+        ("{date} at {time}", "{date} ngo-{time}"),
+        ("{date} by {name}", "{date} ngu-{name}"),
+        ("{days} days", "iintsuku ezi-{days}"),
+        ("{days} days (per Treasury Regulations)", "iintsuku ezi-{days} (ngokweMimiselo kaNondyebo)"),
+        ("{days} days (per Treasury Regulations); closes no earlier than {date}", "iintsuku ezi-{days} (ngokweMimiselo kaNondyebo); ivala kungaphambi kuka-{date}"),
+        ("{done} of {total} milestones", "amanqanaba ali-{done} kwali-{total}"),
+        ("{field} \"{value}\" is not a valid value", "{field} \"{value}\" ayingoxabiso lisemthethweni"),
+        ("{field} was not found", "{field} ayifumanekanga"),
+        ("{item} is not on {po_number}", "{item} ayikho kwi-{po_number}"),
+        ("{name} ({code}) at {percent} utilisation", "{name} ({code}) isetyenziswe nge-{percent}"),
+        ("{name} ({code}) exceeds its budget by {amount}", "{name} ({code}) igqithe uhlahlo-lwabiwo-mali lwayo nge-{amount}"),
+        ("{name} is in the supplier registry", "{name} ukwirejista yababoneleli"),
+        ("{name} is not in the supplier registry", "{name} akakho kwirejista yababoneleli"),
+        ("{number} has not been registered", "{number} ayikabhaliswa"),
+        ("{number} is a valid CIPC number", "{number} yinombolo ye-CIPC esemthethweni"),
+        ("{number} is a valid VAT number", "{number} yinombolo ye-VAT esemthethweni"),
+        ("{number} is already registered as {invoice_id}", "{number} sele ibhaliswe njenge-{invoice_id}"),
+        ("{number} is not a 10-digit VAT number starting with 4", "{number} ayingonombolo ye-VAT enamanani ali-10 eqala ngo-4"),
+        ("{number} is not in the form YYYY/NNNNNN/NN", "{number} ayikho kwifom ethi YYYY/NNNNNN/NN"),
+        ("{percent} approval rate", "izinga lokwamkelwa eli-{percent}"),
+        ("{percent} of contract", "{percent} yekhontrakthi"),
+        ("{percent} of total", "{percent} yetotali"),
+        ("{percent} saved", "kugcinwe i-{percent}"),
+        ("{po_number} issued to {supplier}", "{po_number} ikhutshelwe u-{supplier}"),
+        ("{po_number} was issued to {supplier}, not {scanned}", "{po_number} ikhutshelwe u-{supplier}, hayi u-{scanned}"),
+        ("{quantity} {item} delivered but only {outstanding} outstanding on {po_number}", "Kuhanjiswe {quantity} {item} kodwa zi-{outstanding} kuphela eziseleyo kwi-{po_number}"),
+        ("{quantity}+ at {price}", "{quantity}+ nge-{price}"),
+        ("{reference} - Tender {tender}", "{reference} - Ithenda {tender}"),
+        ("{start} to {end}", "{start} ukuya ku-{end}"),
+        ("{supplier} - ordered {date}", "{supplier} - i-odolwe ngo-{date}"),
+        ("A conversion started by {name} on {date} did not finish. Resuming it reuses anything it already created.", "Uguqulo oluqalwe ngu-{name} ngo-{date} alugqitywanga. Ukuluqhubeka kusebenzisa kwakhona nantoni na esele yenziwe."),
+        ("Resume Conversion", "Qhubeka noguqulo"),
+        ("No extraction engine configured", "Akukho njini yokukhupha imiselweyo"),
+        ("Scanned documents cannot be read until an OCR service is connected. Capture them by hand for now.", "Amaxwebhu askeniweyo akanakufundwa de kudityaniswe inkonzo ye-OCR. Wafake ngesandla okwangoku."),
+        ("The application stopped after an unexpected error. Reload to continue; changes not yet saved on this page may be lost.", "Usetyenziso lumile emva kwempazamo engalindelekanga. Layisha kwakhona ukuze uqhubeke; iinguqu ezingekagcinwa kweli phepha zinokulahleka."),
+        ("Reload", "Layisha kwakhona"),
+        ("This page failed to load. The rest of the application is unaffected.", "Eli phepha alikwazanga ukulayisha. Yonke enye inkqubo isasebenza."),
+        ("Enter an amount such as {example}", "Faka imali efana no-{example}"),
+        ("Apply SLAs now", "Sebenzisa ii-SLA ngoku"),
+    ],
+    plurals: &[
+        ("{n} unread notification", ["{n} isaziso esingafundwanga", "{n} izaziso ezingafundwanga"]),
+        ("{n} day", ["{n} usuku", "{n} iintsuku"]),
+        ("{n} minute", ["{n} umzuzu", "{n} imizuzu"]),
+        ("{n} hour", ["{n} iyure", "{n} iiyure"]),
+        ("Back online. Sending {n} queued change...", ["Uxhunyiwe kwakhona. Kuthunyelwa {n} utshintsho olusemgceni...", "Uxhunyiwe kwakhona. Kuthunyelwa {n} iinguqu ezisemgceni..."]),
+        ("Catalogue lines split into {n} purchase order, one per supplier.", ["Imigca yekhathalogu yahlulwe yaba yi-odolo yokuthenga e-{n}, enye ngomboneleli.", "Imigca yekhathalogu yahlulwe yaba zii-odolo zokuthenga ezi-{n}, enye ngomboneleli."]),
+        ("Repeat finding, outstanding for {n} year", ["Isiphumo esiphindiweyo, sisalindile unyaka o-{n}", "Isiphumo esiphindiweyo, sisalindile iminyaka engama-{n}"]),
+        ("You are offline. {n} change saved on this device will be sent when the connection returns.", ["Awuxhunyiwe. {n} utshintsho olugcinwe kwesi sixhobo luya kuthunyelwa xa uqhagamshelo lubuya.", "Awuxhunyiwe. {n} iinguqu ezigcinwe kwesi sixhobo ziya kuthunyelwa xa uqhagamshelo lubuya."]),
+        ("You have {n} unread notification", ["Unesaziso esi-{n} esingafundwanga", "Uneezaziso ezi-{n} ezingafundwanga"]),
+        ("All items are catalogue items under contract: {n} purchase order can be raised directly.", ["Zonke izinto zikwikhathalogu phantsi kwekhontrakthi: i-odolo yokuthenga eyi-{n} ingenziwa ngqo.", "Zonke izinto zikwikhathalogu phantsi kwekhontrakthi: ii-odolo zokuthenga ezi-{n} zingenziwa ngqo."]),
+        ("{n} active", ["{n} esebenzayo", "{n} ezisebenzayo"]),
+        ("{n} bidder", ["umbhidi o-{n}", "ababhidi aba-{n}"]),
+        ("{n} change", ["utshintsho olu-{n}", "utshintsho olu-{n}"]),
+        ("{n} check", ["uhlolo olu-{n}", "uhlolo olu-{n}"]),
+        ("{n} closing soon", ["{n} evala kungekudala", "{n} ezivala kungekudala"]),
+        ("{n} completed", ["{n} egqityiweyo", "{n} ezigqityiweyo"]),
+        ("{n} contract expiring within 30 days. Review and initiate renewal process.", ["Ikhontrakthi eyi-{n} iphelelwa kwiintsuku ezingama-30. Phonononga uqalise inkqubo yokuhlaziya.", "Iikhontrakthi ezi-{n} ziphelelwa kwiintsuku ezingama-30. Phonononga uqalise inkqubo yokuhlaziya."]),
+        ("{n} criterion defined", ["Umlinganiselo o-{n} uchaziwe", "Imilinganiselo eyi-{n} ichaziwe"]),
+        ("{n} critical", ["{n} ebalulekileyo", "{n} ezibalulekileyo"]),
+        ("{n} critical, attention needed", ["{n} ebalulekileyo, kufuneka ingqalelo", "{n} ezibalulekileyo, kufuneka ingqalelo"]),
+        ("{n} day left", ["kusele usuku olu-{n}", "kusele iintsuku ezi-{n}"]),
+        ("{n} document", ["uxwebhu olu-{n}", "amaxwebhu a-{n}"]),
+        ("{n} expiring in 30 days", ["{n} iphelelwa kwiintsuku ezingama-30", "{n} ziphelelwa kwiintsuku ezingama-30"]),
+        ("{n} field changed", ["intsimi eyi-{n} itshintshiwe", "iintsimi ezi-{n} zitshintshiwe"]),
+        ("{n} file", ["ifayile eyi-{n}", "iifayile ezi-{n}"]),
+        ("{n} high/extreme", ["{n} ephezulu/egqithisileyo", "{n} eziphezulu/ezigqithisileyo"]),
+        ("{n} ineffective", ["{n} engasebenziyo", "{n} ezingasebenziyo"]),
+        ("{n} issue", ["ingxaki eyi-{n}", "iingxaki ezi-{n}"]),
+        ("{n} item", ["into eyi-{n}", "izinto ezi-{n}"]),
+        ("{n} item pending approval. Review and approve to make it available.", ["Into eyi-{n} ilinde imvume. Phonononga uvume ukuze ifumaneke.", "Izinto ezi-{n} zilinde imvume. Phonononga uvume ukuze zifumaneke."]),
+        ("{n} line", ["umgca o-{n}", "imigca eyi-{n}"]),
+        ("{n} material", ["{n} ebalulekileyo", "{n} ezibalulekileyo"]),
+        ("{n} milestone is overdue. Please review and take action.", ["Inqanaba eli-{n} ligqithile ixesha. Nceda uphonononge uthathe inyathelo.", "Amanqanaba a-{n} agqithile ixesha. Nceda uphonononge uthathe inyathelo."]),
+        ("{n} overdue", ["{n} egqithileyo ixesha", "{n} ezigqithileyo ixesha"]),
+        ("{n} plan in total", ["isicwangciso esi-{n} sisonke", "izicwangciso ezi-{n} zizonke"]),
+        ("{n} purchase order pending delivery. Track and confirm deliveries.", ["I-odolo yokuthenga eyi-{n} ilinde ukuhanjiswa. Landelela uqinisekise ukuhanjiswa.", "Ii-odolo zokuthenga ezi-{n} zilinde ukuhanjiswa. Landelela uqinisekise ukuhanjiswa."]),
+        ("{n} receipt pending inspection. Complete quality checks before acceptance.", ["Irisithi eyi-{n} ilinde uhlolo. Gqibezela uhlolo lomgangatho phambi kokwamkela.", "Iirisithi ezi-{n} zilinde uhlolo. Gqibezela uhlolo lomgangatho phambi kokwamkela."]),
+        ("{n} supplier", ["umboneleli o-{n}", "ababoneleli aba-{n}"]),
+        ("{n} this month", ["{n} kule nyanga", "{n} kule nyanga"]),
+        ("{n} under evaluation", ["{n} iyavavanywa", "{n} ziyavavanywa"]),
+        ("{n} urgent", ["{n} engxamisekileyo", "{n} ezingxamisekileyo"]),
+        ("{n} urgent (>5 days)", ["{n} engxamisekileyo (>iintsuku ezi-5)", "{n} ezingxamisekileyo (>iintsuku ezi-5)"]),
+        ("{n} user", ["umsebenzisi o-{n}", "abasebenzisi aba-{n}"]),
+        ("{n} requisition updated", ["{n} isicelo sihlaziyiwe", "{n} izicelo zihlaziyiwe"]),
+    ],
+};
@@ -0,0 +1,2005 @@
+//! isiZulu message catalogue
+
+use super::Catalogue;
+
+pub const CATALOGUE: Catalogue = Catalogue {
+    messages: &[
+        ("Overview", "Uhlolojikelele"),
+        ("Dashboard", "Ideshibhodi"),
+        ("Procurement", "Ukuthengwa kwempahla"),
+        ("Requisitions", "Izicelo"),
+        ("Tenders", "Amathenda"),
+        ("Evaluation", "Ukuhlolwa"),
+        ("Contracts", "Izinkontileka"),
+        ("Purchase Orders", "Ama-oda okuthenga"),
+        ("Goods Receipt", "Ukwamukelwa kwempahla"),
+        ("Suppliers", "Abahlinzeki"),
+        ("Registry", "Irejista"),
+        ("Performance", "Ukusebenza"),
+        ("Risk", "Ubungozi"),
+        ("Supplier Portal", "Iphothali yabahlinzeki"),
+        ("Catalogue", "Ikhathalogi"),
+        ("Browse", "Phequlula"),
+        ("Admin", "Ukuphatha"),
+        ("Compliance", "Ukuthobela"),
+        ("Analytics", "Uhlaziyo"),
+        ("Audit Trail", "Umkhondo wokucwaningwa"),
+        ("GRC Dashboard", "Ideshibhodi ye-GRC"),
+        ("NBAC Reviews", "Ukubuyekezwa kwe-NBAC"),
+        ("AGSA Reviews", "Ukubuyekezwa kwe-AGSA"),
+        ("B-BBEE Goals", "Imigomo ye-B-BBEE"),
+        ("Special Sourcing", "Ukuthenga okukhethekile"),
+        ("Reverse Auction", "Indali ehlehlayo"),
+        ("Sourcing Plans", "Izinhlelo zokuthenga"),
+        ("Tools", "Amathuluzi"),
+        ("Documents", "Amadokhumenti"),
+        ("AI Assistant", "Umsizi we-AI"),
+        ("Search requisitions, tenders, suppliers...", "Sesha izicelo, amathenda, abahlinzeki..."),
+        ("Notifications", "Izaziso"),
+        ("Help", "Usizo"),
+        ("Sign out", "Phuma"),
+        ("Language", "Ulimi"),
+        ("Mark all read", "Maka konke njengokufundiwe"),
+        ("Settings", "Izilungiselelo"),
+        ("Back", "Emuva"),
+        ("Notification settings", "Izilungiselelo zezaziso"),
+        ("You're all caught up", "Awunazo izaziso ezintsha"),
+        ("No matches", "Akukho okutholakele"),
+        ("Searching...", "Iyasesha..."),
+        ("Page not found", "Ikhasi alitholakalanga"),
+        ("Back to Dashboard", "Buyela kudeshibhodi"),
+        ("Try again", "Zama futhi"),
+        ("Something went wrong", "Kukhona okungahambanga kahle"),
+        ("Draft", "Okusalungiswa"),
+        ("Submitted", "Kuthunyelwe"),
+        ("Pending Approval", "Kulindele ukugunyazwa"),
+        ("Approved", "Kugunyaziwe"),
+        ("Rejected", "Kwenqatshiwe"),
+        ("Cancelled", "Kukhanseliwe"),
+        ("In Progress", "Kuyaqhubeka"),
+        ("Complete", "Kuqediwe"),
+        ("Active", "Kuyasebenza"),
+        ("Published", "Kushicilelwe"),
+        ("Pending", "Kulindile"),
+        ("Expired", "Kuphelelwe yisikhathi"),
+        ("Failed", "Kwehlulekile"),
+        ("New", "Okusha"),
+        ("Open", "Kuvuliwe"),
+        ("Review", "Ukubuyekeza"),
+        ("On Hold", "Kumisiwe"),
+        ("Scheduled", "Kuhleliwe"),
+        ("Closed", "Kuvaliwe"),
+        ("Adjudication", "Ukunqunywa"),
+        ("Awarded", "Kunikeziwe"),
+        ("Low", "Phansi"),
+        ("Medium", "Maphakathi"),
+        ("High", "Phezulu"),
+        ("Urgent", "Kuphuthumayo"),
+        ("Request for Quotation", "Isicelo sekhotheshini"),
+        ("Request for Proposal", "Isicelo sesiphakamiso"),
+        ("Request for Tender", "Isicelo sethenda"),
+        ("Bids", "Amabhidi"),
+        ("Payments", "Izinkokhelo"),
+        ("System", "Isistimu"),
+        ("Auctions", "Izindali"),
+        ("Approvals", "Ukugunyazwa"),
+        ("Certificates", "Izitifiketi"),
+        ("Meetings", "Imihlangano"),
+        ("Today", "Namuhla"),
+        ("Approval awaiting you", "Ukugunyazwa kulindele wena"),
+        ("Tender closing", "Ithenda liyavalwa"),
+        ("Contract expiring", "Inkontileka iyaphelelwa yisikhathi"),
+        ("Certificate expiring", "Isitifiketi siyaphelelwa yisikhathi"),
+        ("NBAC meeting scheduled", "Umhlangano we-NBAC uhleliwe"),
+        ("There is no page at {path}. Check the link or use the sidebar to find what you need.", "Alikho ikhasi ku-{path}. Hlola isixhumanisi noma usebenzise ibha eseceleni ukuthola okudingayo."),
+        ("Just now", "Manje nje"),
+        ("Yesterday", "Izolo"),
+        ("Tomorrow", "Kusasa"),
+        ("{time} ago", "{time} edlule"),
+        ("in {time}", "ngemuva kuka-{time}"),
+        (" (R)", " (R)"),
+        (" (must equal 100%)", " (kumele kulingane no-100%)"),
+        (" Upload Document", " Layisha idokhumenti"),
+        (" incl. VAT", " kufaka i-VAT"),
+        (" supplier B-BBEE certificates expiring within 90 days", " izitifiketi ze-B-BBEE zabahlinzeki eziphelelwa yisikhathi ezinsukwini ezingu-90"),
+        ("% of Total", "% yesamba"),
+        ("+ Add Category", "+ Engeza isigaba"),
+        ("+ Add Criterion", "+ Engeza inqubo yokuhlola"),
+        ("+ Add Line Item", "+ Engeza into yomugqa"),
+        ("+ Add Milestone", "+ Engeza isigaba esibalulekile"),
+        ("+ Add Objective", "+ Engeza inhloso"),
+        ("+ New Requisition", "+ Isicelo esisha"),
+        ("+ New Sourcing Plan", "+ Uhlelo olusha lokuthola"),
+        (", the following approvals will be required before proceeding.", ", kuzodingeka lezi zimvume ezilandelayo ngaphambi kokuqhubeka."),
+        ("1 day", "usuku olu-1"),
+        ("1 hour", "ihora eli-1"),
+        ("14 days", "izinsuku ezingu-14"),
+        ("15 minutes", "imizuzu eyi-15"),
+        ("2 hours", "amahora ama-2"),
+        ("3 days", "izinsuku ezi-3"),
+        ("30 minutes", "imizuzu engama-30"),
+        ("7 days", "izinsuku ezi-7"),
+        ("AGSA - Audit Findings", "AGSA - Okutholwe ekucwaningweni"),
+        ("AGSA Audit Findings", "Okutholwe ekucwaningweni kwe-AGSA"),
+        ("AGSA Comments", "Amazwana e-AGSA"),
+        ("AI Procurement Assistant", "Umsizi wokuthenga we-AI"),
+        ("Accepted", "Kwamukelwe"),
+        ("Accepted goods", "Impahla eyamukelwe"),
+        ("Access denied", "Ukufinyelela kwenqatshiwe"),
+        ("Accounting Officer Approval", "Imvume yesikhulu sokubalwa kwezimali"),
+        ("Acknowledged", "Kuqashelwe"),
+        ("Action", "Isenzo"),
+        ("Action Details", "Imininingwane yesenzo"),
+        ("Action Items", "Izinto okufanele zenziwe"),
+        ("Action Plan", "Uhlelo lokusebenza"),
+        ("Action Type", "Uhlobo lwesenzo"),
+        ("Actions", "Izenzo"),
+        ("Actions Completed", "Izenzo eziqediwe"),
+        ("Actions Overdue", "Izenzo ezidlulelwe yisikhathi"),
+        ("Activate", "Yenza kusebenze"),
+        ("Active Bidders ({count})", "Abathenda abasebenzayo ({count})"),
+        ("Active Bids", "Amabhidi asebenzayo"),
+        ("Active Contracts", "Izinkontileka ezisebenzayo"),
+        ("Active Items", "Izinto ezisebenzayo"),
+        ("Active Members", "Amalungu asebenzayo"),
+        ("Active Plans", "Izinhlelo ezisebenzayo"),
+        ("Active Requisitions", "Izicelo ezisebenzayo"),
+        ("Active Risks", "Ubungozi obukhona"),
+        ("Active Suppliers", "Abahlinzeki abasebenzayo"),
+        ("Active Users", "Abasebenzisi abasebenzayo"),
+        ("Active:", "Kuyasebenza:"),
+        ("Actual", "Okwangempela"),
+        ("Actual Spend", "Ukusetshenziswa kwangempela"),
+        ("Add", "Engeza"),
+        ("Add Catalogue Item", "Engeza into yekhathalogi"),
+        ("Add Control", "Engeza ulawulo"),
+        ("Add Deliverable", "Engeza okulethwayo"),
+        ("Add Item", "Engeza into"),
+        ("Add Line Item", "Engeza into yomugqa"),
+        ("Add Milestone", "Engeza isigaba esibalulekile"),
+        ("Add Note", "Engeza inothi"),
+        ("Add Risk", "Engeza ubungozi"),
+        ("Add Supplier", "Engeza umhlinzeki"),
+        ("Add Target", "Engeza umgomo"),
+        ("Add, edit, and manage catalogue items", "Engeza, hlela futhi uphathe izinto zekhathalogi"),
+        ("Additional Information", "Ulwazi olwengeziwe"),
+        ("Additional Notes", "Amanothi engeziwe"),
+        ("Address", "Ikheli"),
+        ("Address Line 2", "Umugqa wekheli 2"),
+        ("Address:", "Ikheli:"),
+        ("Administration", "Ukuphatha"),
+        ("Adverse", "Okungalungile"),
+        ("After authorisation", "Ngemva kokugunyazwa"),
+        ("Agenda Items", "Izinto ezisohlelweni"),
+        ("Aging", "Ubudala"),
+        ("Alerts", "Izexwayiso"),
+        ("All", "Konke"),
+        ("All Actions", "Zonke izenzo"),
+        ("All Categories", "Zonke izigaba"),
+        ("All Departments", "Yonke iminyango"),
+        ("All Documents", "Wonke amadokhumenti"),
+        ("All Entities", "Zonke izinhlangano"),
+        ("All Levels", "Wonke amazinga"),
+        ("All Provinces", "Zonke izifundazwe"),
+        ("All Receipts ({count})", "Konke ukwamukelwa ({count})"),
+        ("All Requisitions", "Zonke izicelo"),
+        ("All Risk Levels", "Wonke amazinga obungozi"),
+        ("All Sourcing Plans", "Zonke izinhlelo zokuthola"),
+        ("All Spend Targets", "Yonke imigomo yokusebenzisa"),
+        ("All Statuses", "Zonke izimo"),
+        ("All Suppliers", "Bonke abahlinzeki"),
+        ("All Types", "Zonke izinhlobo"),
+        ("All Years", "Yonke iminyaka"),
+        ("All budgets are within their thresholds", "Zonke izabelomali zingaphakathi kwemikhawulo yazo"),
+        ("All documents", "Wonke amadokhumenti"),
+        ("All statuses", "Zonke izimo"),
+        ("Allocated", "Kwabiwe"),
+        ("Allocated Budget", "Isabelomali esabiwe"),
+        ("Allocated to Categories", "Kwabelwe izigaba"),
+        ("Almost Certain", "Cishe kuqinisekile"),
+        ("Already on", "Sekuvele ku"),
+        ("Also publish to internal supplier portal", "Shicilela nakuphothali yangaphakathi yabahlinzeki"),
+        ("Amount", "Inani"),
+        ("Amount limit", "Umkhawulo wenani"),
+        ("Annual procurement planning and budget management", "Ukuhlela ukuthenga konyaka nokuphathwa kwesabelomali"),
+        ("Any additional information...", "Noma yiluphi ulwazi olwengeziwe..."),
+        ("Any additional notes to display on the portal", "Noma imaphi amanothi engeziwe azovela kuphothali"),
+        ("Any notes or comments for the supplier", "Noma imaphi amanothi noma amazwana omhlinzeki"),
+        ("App Configuration", "Ukumiswa kohlelo lokusebenza"),
+        ("App Features", "Izici zohlelo lokusebenza"),
+        ("App Name", "Igama lohlelo lokusebenza"),
+        ("App Preview", "Ukubuka kuqala uhlelo lokusebenza"),
+        ("App Version", "Inguqulo yohlelo lokusebenza"),
+        ("Applicable for existing strategic relationships that provide significant value. Must demonstrate strategic importance and value.", "Kusebenza ebudlelwaneni obukhona obunamasu obunikeza inani elibalulekile. Kumele kukhonjiswe ukubaluleka kwamasu nenani."),
+        ("Applicable for urgent requirements due to unforeseen circumstances. Must demonstrate immediate need and inability to follow normal process.", "Kusebenza ezidingweni eziphuthumayo ngenxa yezimo ezingalindelekile. Kumele kukhonjiswe isidingo esisheshayo nokungakwazi ukulandela inqubo evamile."),
+        ("Applicable when only one supplier can provide the specific goods or services required. Must demonstrate that no reasonable alternative exists.", "Kusebenza uma umhlinzeki oyedwa kuphela ongahlinzeka ngempahla noma izinsizakalo ezidingekayo. Kumele kukhonjiswe ukuthi ayikho enye indlela enengqondo."),
+        ("Applicable when supplier has exclusive rights through patents, licenses, or proprietary technology. Must provide evidence of exclusivity.", "Kusebenza uma umhlinzeki enamalungelo akhethekile ngamalungelo obunikazi, amalayisense noma ubuchwepheshe bakhe. Kumele kunikezwe ubufakazi bokukhetheka."),
+        ("Apply", "Sebenzisa"),
+        ("Apply Corrections", "Sebenzisa izilungiso"),
+        ("Approval", "Imvume"),
+        ("Approval Delegations", "Ukudluliselwa kwezimvume"),
+        ("Approval Rate", "Izinga lokuvunywa"),
+        ("Approval Requirements", "Izidingo zemvume"),
+        ("Approval Turnaround", "Isikhathi sokuvunywa"),
+        ("Approval Workflow", "Uhlelo lokuvunywa"),
+        ("Approval steps past their SLA and steps escalated to the next authority", "Izinyathelo zokuvunywa ezedlule i-SLA yazo nezinyathelo ezidluliselwe kwigunya elilandelayo"),
+        ("Approvals Needing Attention", "Izimvume ezidinga ukunakwa"),
+        ("Approvals delegated to", "Izimvume zidluliselwe ku"),
+        ("Approve", "Vuma"),
+        ("Approve for Payment", "Vumela ukukhokhelwa"),
+        ("Approved by", "Kuvunywe ngu"),
+        ("Approved, Not on a Run", "Kuvunyiwe, akukho kumjikelezo"),
+        ("Approver", "Ovumayo"),
+        ("Archive", "Gcina kunqolobane"),
+        ("Archived", "Kugcinwe kunqolobane"),
+        ("Are you sure you want to delete this catalogue item? This action cannot be undone.", "Uqinisekile ukuthi ufuna ukususa le nto yekhathalogi? Lesi senzo asikwazi ukuhlehliswa."),
+        ("Ask me anything about procurement...", "Ngibuze noma yini mayelana nokuthenga..."),
+        ("Assigned To", "Kunikezwe"),
+        ("At Risk", "Esengozini"),
+        ("Attachments", "Okunamathiselwe"),
+        ("Attendance Mandatory", "Ukuba khona kuyimpoqo"),
+        ("Auction", "Indali"),
+        ("Auction Participations", "Ukubamba iqhaza endalini"),
+        ("Auction is not currently live", "Indali ayiqhubeki njengamanje"),
+        ("Auctions ({count} total)", "Izindali ({count} sezizonke)"),
+        ("Audit Entries ({count})", "Okufakwe ekucwaningweni ({count})"),
+        ("Audit Findings", "Okutholwe ekucwaningweni"),
+        ("Audit Reports", "Imibiko yokucwaninga"),
+        ("Auditor-General South Africa findings, action items, and compliance tracking", "Okutholwe nguMcwaningimabhuku-Jikelele waseNingizimu Afrika, izinto okufanele zenziwe nokulandelela ukuthobela"),
+        ("Auth", "Ukugunyaza"),
+        ("Authorisation", "Ukugunyazwa"),
+        ("Authorisations", "Ukugunyazwa"),
+        ("Authorise Run", "Gunyaza umjikelezo"),
+        ("Authorization", "Ukugunyazwa"),
+        ("Availability (%)", "Ukutholakala (%)"),
+        ("Available", "Kuyatholakala"),
+        ("Available Budget", "Isabelomali esitholakalayo"),
+        ("Average Confidence", "Ukuqiniseka okumaphakathi"),
+        ("Avg Attendance", "Ukuba khona okumaphakathi"),
+        ("Avg Resolution Days", "Izinsuku ezimaphakathi zokuxazulula"),
+        ("Avg Turnaround", "Isikhathi esimaphakathi sokuphendula"),
+        ("Avg. Business Days", "Izinsuku zomsebenzi ezimaphakathi"),
+        ("Avg. Performance", "Ukusebenza okumaphakathi"),
+        ("Avg. Procurement Cycle", "Umjikelezo wokuthenga omaphakathi"),
+        ("Awaiting", "Kulindile"),
+        ("Awaiting Authorisation", "Kulinde ukugunyazwa"),
+        ("Awaiting Inspection:", "Kulinde ukuhlolwa:"),
+        ("Awaiting Review", "Kulinde ukubuyekezwa"),
+        ("Awaiting approver", "Kulinde ovumayo"),
+        ("Award", "Nikeza"),
+        ("Awarded Contracts", "Izinkontileka ezinikeziwe"),
+        ("B-BBEE", "B-BBEE"),
+        ("B-BBEE %", "B-BBEE %"),
+        ("B-BBEE Compliance Metrics", "Izilinganiso zokuthobela i-B-BBEE"),
+        ("B-BBEE Compliant", "Kuthobela i-B-BBEE"),
+        ("B-BBEE Level", "Izinga le-B-BBEE"),
+        ("B-BBEE Level Breakdown", "Ukuhlukaniswa kwamazinga e-B-BBEE"),
+        ("B-BBEE Performance", "Ukusebenza kwe-B-BBEE"),
+        ("B-BBEE Preferential Procurement Scorecard", "Ikhadi lamaphuzu okuthenga okukhethwayo kwe-B-BBEE"),
+        ("B-BBEE Spend", "Ukusetshenziswa kwe-B-BBEE"),
+        ("B-BBEE Spend Distribution", "Ukusatshalaliswa kokusetshenziswa kwe-B-BBEE"),
+        ("B-BBEE Spend Trend", "Ukuthambekela kokusetshenziswa kwe-B-BBEE"),
+        ("B-BBEE Weight", "Isisindo se-B-BBEE"),
+        ("B-BBEE Weight (%)", "Isisindo se-B-BBEE (%)"),
+        ("B-BBEE certificates are valid for twelve months from the date of issue", "Izitifiketi ze-B-BBEE zisebenza izinyanga eziyishumi nambili kusukela osukwini lokukhishwa"),
+        ("Back to AGSA Reviews", "Buyela ekubuyekezweni kwe-AGSA"),
+        ("Back to Auction List", "Buyela ohlwini lwezindali"),
+        ("Back to Auctions", "Buyela ezindalini"),
+        ("Back to Contracts", "Buyela ezinkontilekeni"),
+        ("Back to Document Intake", "Buyela ekwamukelweni kwamadokhumenti"),
+        ("Back to Evaluation List", "Buyela ohlwini lokuhlolwa"),
+        ("Back to Evaluations", "Buyela ekuhlolweni"),
+        ("Back to Goods Receipts", "Buyela ekwamukelweni kwempahla"),
+        ("Back to Invoices", "Buyela kuma-invoyisi"),
+        ("Back to Library", "Buyela emtapweni"),
+        ("Back to Milestones", "Buyela ezigabeni ezibalulekile"),
+        ("Back to Payment Runs", "Buyela emijikelezweni yokukhokha"),
+        ("Back to Purchase Orders", "Buyela kuma-oda okuthenga"),
+        ("Back to Registry", "Buyela kurejista"),
+        ("Back to Requisitions", "Buyela ezicelweni"),
+        ("Back to Reviews", "Buyela ekubuyekezweni"),
+        ("Back to Suppliers", "Buyela kubahlinzeki"),
+        ("Back to Tender", "Buyela kuthenda"),
+        ("Back to Tenders", "Buyela kumathenda"),
+        ("Bank Account", "I-akhawunti yasebhange"),
+        ("Bank Files", "Amafayela asebhange"),
+        ("Bank account or branch code invalid", "I-akhawunti yasebhange noma ikhodi yegatsha ayivumelekile"),
+        ("Based on estimated value of ", "Ngokusekelwe enanini elilinganisiwe lika "),
+        ("Basic Info", "Ulwazi oluyisisekelo"),
+        ("Basic Information", "Ulwazi oluyisisekelo"),
+        ("Batch approved invoices, authorise runs and release bank payment files", "Qoqa ama-invoyisi avunyiwe, gunyaza imijikelezo futhi ukhiphe amafayela okukhokha asebhange"),
+        ("Below", "Ngaphansi"),
+        ("Bid", "Ibhidi"),
+        ("Bid Award", "Ukunikezwa kwebhidi"),
+        ("Bid Comparison", "Ukuqhathaniswa kwamabhidi"),
+        ("Bid Evaluation", "Ukuhlolwa kwamabhidi"),
+        ("Bid History ({count})", "Umlando wamabhidi ({count})"),
+        ("Bid Price", "Intengo yebhidi"),
+        ("Bidder {id}", "Othenda {id}"),
+        ("Bidders", "Abathenda"),
+        ("Bids ({count})", "Amabhidi ({count})"),
+        ("Bids Submitted", "Amabhidi ahanjisiwe"),
+        ("Black Owned Spend", "Ukusetshenziswa kwabamnyama abangabanikazi"),
+        ("Black Women Owned", "Okuphethwe abesifazane abamnyama"),
+        ("Blacklisted", "Esohlwini olumnyama"),
+        ("Box", "Ibhokisi"),
+        ("Branding", "Uphawu"),
+        ("Brief description of procurement", "Incazelo emfushane yokuthenga"),
+        ("Brief description of the procurement", "Incazelo emfushane yokuthenga"),
+        ("Brief description of the requisition", "Incazelo emfushane yesicelo"),
+        ("Briefing Check-ins", "Ukubhalisa emhlanganweni wokuchaza"),
+        ("Briefing Date", "Usuku lomhlangano wokuchaza"),
+        ("Broad-Based Black Economic Empowerment compliance tracking for FY 2025/26", "Ukulandelela ukuthobela ukuNikwa amandla kwezoMnotho kwaBamnyama okuBanzi konyaka wezimali ka-2025/26"),
+        ("Browse Opportunities", "Phequlula amathuba"),
+        ("Browse and search available items", "Phequlula futhi useshe izinto ezitholakalayo"),
+        ("Budget", "Isabelomali"),
+        ("Budget Alerts", "Izexwayiso zesabelomali"),
+        ("Budget Allocation", "Ukwabiwa kwesabelomali"),
+        ("Budget Control", "Ukulawulwa kwesabelomali"),
+        ("Budget Lines", "Imigqa yesabelomali"),
+        ("Budget Summary", "Isifinyezo sesabelomali"),
+        ("Budget Transfers", "Ukudluliswa kwesabelomali"),
+        ("Budget Utilization", "Ukusetshenziswa kwesabelomali"),
+        ("Budget Utilized", "Isabelomali esisetshenzisiwe"),
+        ("Budget by Category", "Isabelomali ngesigaba"),
+        ("Building, floor, etc. (optional)", "Isakhiwo, isitezi, njll. (ongakukhetha)"),
+        ("By submitting this request, you confirm that you have conducted appropriate market research and that deviation from competitive bidding is justified.", "Ngokuhambisa lesi sicelo, uqinisekisa ukuthi wenze ucwaningo olufanele lwemakethe nokuthi ukuphambuka ekuthendeni okuncintisanayo kunesizathu."),
+        ("CFO Approval", "Imvume ye-CFO"),
+        ("CIPC registration", "Ukubhaliswa kwe-CIPC"),
+        ("CSD Unverified", "I-CSD ayiqinisekisiwe"),
+        ("CSD Verified", "I-CSD iqinisekisiwe"),
+        ("Cancel", "Khansela"),
+        ("Cancel Run", "Khansela umjikelezo"),
+        ("Cancellation", "Ukukhanselwa"),
+        ("Cannot be ordered directly: {reason}.", "Akukwazi uku-odwa ngqo: {reason}."),
+        ("Cards", "Amakhadi"),
+        ("Catalogue Items ({count})", "Izinto zekhathalogi ({count})"),
+        ("Catalogue Management", "Ukuphathwa kwekhathalogi"),
+        ("Catastrophic", "Okuyinhlekelele"),
+        ("Categories", "Izigaba"),
+        ("Categories ({count} total)", "Izigaba ({count} sezizonke)"),
+        ("Category", "Isigaba"),
+        ("Category Breakdown", "Ukuhlukaniswa ngesigaba"),
+        ("Category Name", "Igama lesigaba"),
+        ("Category name", "Igama lesigaba"),
+        ("Category:", "Isigaba:"),
+        ("Catering", "Ukuphakelwa kokudla"),
+        ("Changes", "Izinguquko"),
+        ("Checks", "Ukuhlola"),
+        ("Choose a bid from the list on the left to begin scoring.", "Khetha ibhidi ohlwini olungakwesobunxele ukuze uqale ukunikeza amaphuzu."),
+        ("Choose a colleague", "Khetha ozakwenu"),
+        ("Choose how to resolve the exception", "Khetha ukuthi ungayixazulula kanjani imbandakanyo"),
+        ("City", "Idolobha"),
+        ("Classification", "Ukuhlukaniswa"),
+        ("Clean Audit", "Ucwaningo oluhlanzekile"),
+        ("Clear", "Sula"),
+        ("Clear Filters", "Sula izihlungi"),
+        ("Close", "Vala"),
+        ("Closing Date", "Usuku lokuvala"),
+        ("Code", "Ikhodi"),
+        ("Comment", "Amazwana"),
+        ("Comments (required to reject or return)", "Amazwana (ayadingeka ukwenqaba noma ukubuyisa)"),
+        ("Commitment", "Isibophezelo"),
+        ("Commitments", "Izibophezelo"),
+        ("Committed", "Kubophezelwe"),
+        ("Committee", "Ikomidi"),
+        ("Committee Members", "Amalungu ekomidi"),
+        ("Committee Notes", "Amanothi ekomidi"),
+        ("Committee Statistics", "Izibalo zekomidi"),
+        ("Completed", "Kuqediwe"),
+        ("Completed ({count})", "Kuqediwe ({count})"),
+        ("Completed Today:", "Kuqedwe namuhla:"),
+        ("Completed: {date}", "Kuqediwe: {date}"),
+        ("Completion Progress", "Inqubekela phambili yokuqeda"),
+        ("Compliance Check", "Ukuhlolwa kokuthobela"),
+        ("Compliance Checks", "Ukuhlolwa kokuthobela"),
+        ("Compliance Checks ({count} total)", "Ukuhlolwa kokuthobela ({count} sekukonke)"),
+        ("Compliance Issues", "Izinkinga zokuthobela"),
+        ("Compliance Metrics", "Izilinganiso zokuthobela"),
+        ("Compliance Score", "Amaphuzu okuthobela"),
+        ("Compliant", "Kuyathobela"),
+        ("Compliant Metrics", "Izilinganiso ezithobelayo"),
+        ("Compliant Suppliers", "Abahlinzeki abathobelayo"),
+        ("Comprehensive procurement spend analysis for FY 2025/26", "Uhlaziyo oluphelele lokusetshenziswa kwemali yokuthenga konyaka wezimali ka-2025/26"),
+        ("Confidence", "Ukuqiniseka"),
+        ("Configuration", "Ukumiswa"),
+        ("Confinement", "Ukuvalelwa"),
+        ("Confirm Paid", "Qinisekisa ukukhokhwa"),
+        ("Confirm it is a separate charge before accepting it.", "Qinisekisa ukuthi kuyinkokhelo ehlukile ngaphambi kokuyamukela."),
+        ("Conflict", "Ukungqubuzana"),
+        ("Connected", "Kuxhunyiwe"),
+        ("Construction", "Ukwakha"),
+        ("Contact", "Oxhumana naye"),
+        ("Contact Email", "I-imeyili yokuxhumana"),
+        ("Contact Person", "Oxhumana naye"),
+        ("Contact Phone", "Ifoni yokuxhumana"),
+        ("Contact:", "Oxhumana naye:"),
+        ("Container", "Isitsha"),
+        ("Continue to Attachments", "Qhubekela kokunamathiselwe"),
+        ("Continue to Budget", "Qhubekela kwisabelomali"),
+        ("Continue to Categories", "Qhubekela ezigabeni"),
+        ("Continue to Line Items", "Qhubekela ezintweni zomugqa"),
+        ("Continue to Review", "Qhubekela ekubuyekezweni"),
+        ("Continue to Timeline", "Qhubekela kuhlelo lwesikhathi"),
+        ("Contract", "Inkontileka"),
+        ("Contract Awards", "Ukunikezwa kwezinkontileka"),
+        ("Contract Details", "Imininingwane yenkontileka"),
+        ("Contract Duration", "Ubude benkontileka"),
+        ("Contract ID", "I-ID yenkontileka"),
+        ("Contract No.", "Inombolo yenkontileka"),
+        ("Contract Period", "Isikhathi senkontileka"),
+        ("Contract Reference", "Inkomba yenkontileka"),
+        ("Contract Terms", "Imibandela yenkontileka"),
+        ("Contract Title", "Isihloko senkontileka"),
+        ("Contract Type", "Uhlobo lwenkontileka"),
+        ("Contract Utilization", "Ukusetshenziswa kwenkontileka"),
+        ("Contract Value", "Inani lenkontileka"),
+        ("Contract ref...", "Inkomba yenkontileka..."),
+        ("Contract {id} - {supplier_name}", "Inkontileka {id} - {supplier_name}"),
+        ("Contract:", "Inkontileka:"),
+        ("Contracts ({count})", "Izinkontileka ({count})"),
+        ("Control", "Ulawulo"),
+        ("Control Effectiveness", "Ukusebenza kahle kolawulo"),
+        ("Controls", "Ulawulo"),
+        ("Corporate Services", "Izinsizakalo zenhlangano"),
+        ("Corrected", "Kulungisiwe"),
+        ("Corrections", "Izilungiso"),
+        ("Corrective", "Okulungisayo"),
+        ("Cost Center", "Isikhungo sezindleko"),
+        ("Cost Centre", "Isikhungo sezindleko"),
+        ("Cost Savings", "Ukonga izindleko"),
+        ("Create", "Dala"),
+        ("Create Contract", "Dala inkontileka"),
+        ("Create Purchase Order", "Dala i-oda lokuthenga"),
+        ("Create a new purchase requisition", "Dala isicelo esisha sokuthenga"),
+        ("Create an annual procurement sourcing plan", "Dala uhlelo lonyaka lokuthola impahla"),
+        ("Create {tender_type}", "Dala i-{tender_type}"),
+        ("Created", "Kudaliwe"),
+        ("Criteria", "Imibandela"),
+        ("Criteria Count", "Inani lemibandela"),
+        ("Criterion Name", "Igama lombandela"),
+        ("Critical", "Kubucayi"),
+        ("Critical Alerts", "Izexwayiso ezibucayi"),
+        ("Critical Risk", "Ubungozi obubucayi"),
+        ("Critical Risk Suppliers - Immediate Attention Required", "Abahlinzeki abasengozini ebucayi - Kudingeka ukunakwa ngokushesha"),
+        ("Current Audit Opinion", "Umbono wamanje wokucwaninga"),
+        ("Current Bid", "Ibhidi yamanje"),
+        ("Current Lowest Bid", "Ibhidi ephansi kakhulu yamanje"),
+        ("Current: {amount}", "Okwamanje: {amount}"),
+        ("Custom Range", "Ibanga elikhethwayo"),
+        ("Cyber", "Ezobuchwepheshe be-inthanethi"),
+        ("Dark", "Mnyama"),
+        ("Date", "Usuku"),
+        ("Date (Newest)", "Usuku (olusha kakhulu)"),
+        ("Date (Oldest)", "Usuku (oludala kakhulu)"),
+        ("Date Emergency Arose", "Usuku lapho isimo esiphuthumayo saqala"),
+        ("Date From", "Usuku kusukela"),
+        ("Date Received", "Usuku lokwamukelwa"),
+        ("Date To", "Usuku kuze kube"),
+        ("Deactivate", "Misa ukusebenza"),
+        ("Decision", "Isinqumo"),
+        ("Decision History", "Umlando wezinqumo"),
+        ("Decisions", "Izinqumo"),
+        ("Decisions This Month", "Izinqumo zale nyanga"),
+        ("Decisions YTD", "Izinqumo zonyaka kuze kube manje"),
+        ("Decreasing", "Kuyehla"),
+        ("Defer", "Hlehlisa"),
+        ("Define key milestones and target dates for the procurement plan execution.", "Chaza izigaba ezibalulekile nezinsuku eziqondiwe zokwenziwa kohlelo lokuthenga."),
+        ("Define the procurement categories and their budget allocations for this plan.", "Chaza izigaba zokuthenga nokwabiwa kwesabelomali sazo kulolu hlelo."),
+        ("Delegate", "Dlulisela"),
+        ("Delegate Approvals", "Dlulisela izimvume"),
+        ("Delegate to", "Dlulisela ku"),
+        ("Delegations", "Ukudluliselwa"),
+        ("Delegator", "Odlulisayo"),
+        ("Delete", "Susa"),
+        ("Delete Item", "Susa into"),
+        ("Deliverable #{number}", "Okulethwayo #{number}"),
+        ("Deliverables", "Okulethwayo"),
+        ("Delivered", "Kulethiwe"),
+        ("Deliveries Confirmed", "Ukulethwa kuqinisekisiwe"),
+        ("Delivery", "Ukulethwa"),
+        ("Delivery Address", "Ikheli lokulethwa"),
+        ("Delivery Address Line 1", "Umugqa wekheli lokulethwa 1"),
+        ("Delivery Details", "Imininingwane yokulethwa"),
+        ("Delivery Information", "Ulwazi lokulethwa"),
+        ("Delivery Instructions", "Imiyalelo yokulethwa"),
+        ("Delivery Location", "Indawo yokulethwa"),
+        ("Delivery Note", "Inothi lokulethwa"),
+        ("Delivery location", "Indawo yokulethwa"),
+        ("Department", "Umnyango"),
+        ("Department & Budget", "Umnyango nesabelomali"),
+        ("Department & Timeline", "Umnyango nohlelo lwesikhathi"),
+        ("Describe escalation levels and contacts", "Chaza amazinga okudlulisela phezulu nabaxhumana nabo"),
+        ("Describe market research conducted", "Chaza ucwaningo lwemakethe olwenziwe"),
+        ("Describe penalties for SLA breaches", "Chaza izinhlawulo zokwephulwa kwe-SLA"),
+        ("Describe renewal conditions", "Chaza imibandela yokuvuselelwa"),
+        ("Describe termination conditions", "Chaza imibandela yokunqanyulwa"),
+        ("Describe the contract scope and objectives", "Chaza ububanzi nezinhloso zenkontileka"),
+        ("Describe the emergency and why normal procurement cannot be followed", "Chaza isimo esiphuthumayo nokuthi kungani ukuthenga okuvamile kungeke kulandelwe"),
+        ("Describe the scope and purpose of this sourcing plan...", "Chaza ububanzi nenhloso yalolu hlelo lokuthola..."),
+        ("Description", "Incazelo"),
+        ("Designated Group Spend (%)", "Ukusetshenziswa kumaqembu aqokiwe (%)"),
+        ("Designated Groups", "Amaqembu aqokiwe"),
+        ("Detailed description of goods/services required", "Incazelo enemininingwane yempahla/izinsizakalo ezidingekayo"),
+        ("Detailed description of work to be performed", "Incazelo enemininingwane yomsebenzi ozokwenziwa"),
+        ("Details", "Imininingwane"),
+        ("Detect automatically", "Thola ngokuzenzakalela"),
+        ("Detected", "Kutholiwe"),
+        ("Detective", "Okuthola"),
+        ("Deviation", "Ukuphambuka"),
+        ("Deviation Justification", "Isizathu sokuphambuka"),
+        ("Deviation Request", "Isicelo sokuphambuka"),
+        ("Deviation Type", "Uhlobo lokuphambuka"),
+        ("Deviations from competitive bidding must be justified in terms of Treasury Regulation 16A6.4 and require appropriate approval based on value.", "Ukuphambuka ekuthendeni okuncintisanayo kumele kube nesizathu ngokweSimiso SoMgcinimafa 16A6.4 futhi kudinga imvume efanele ngokwenani."),
+        ("Device", "Idivayisi"),
+        ("Device Breakdown", "Ukuhlukaniswa ngamadivayisi"),
+        ("Directive", "Okuqondisayo"),
+        ("Disable All", "Vala konke"),
+        ("Disclaimer", "Umshwana wokuzihlukanisa"),
+        ("Disconnected", "Kunqamukile"),
+        ("Discontinued", "Kuyekisiwe"),
+        ("Dispute Resolution", "Ukuxazululwa kwezingxabano"),
+        ("Docs", "Amadokhumenti"),
+        ("Docs Scanned", "Amadokhumenti askeniwe"),
+        ("Document", "Idokhumenti"),
+        ("Document Intake", "Ukwamukelwa kwamadokhumenti"),
+        ("Document Library", "Umtapo wamadokhumenti"),
+        ("Document Name", "Igama ledokhumenti"),
+        ("Document Preview", "Ukubuka kuqala idokhumenti"),
+        ("Document Type", "Uhlobo lwedokhumenti"),
+        ("Documentation", "Amadokhumenti"),
+        ("Documents ({count} found)", "Amadokhumenti ({count} atholakele)"),
+        ("Documents Scanned", "Amadokhumenti askeniwe"),
+        ("Download", "Landa"),
+        ("Download CSV", "Landa i-CSV"),
+        ("Download Docs", "Landa amadokhumenti"),
+        ("Download QR Code", "Landa ikhodi ye-QR"),
+        ("Draft:", "Okusalungiswa:"),
+        ("Drop files here or click to browse", "Faka amafayela lapha noma uchofoze ukuze uphequlule"),
+        ("Due", "Kufanele"),
+        ("Due Date", "Usuku lokugcina"),
+        ("Due in 7 Days", "Kufanele ezinsukwini ezi-7"),
+        ("Due: {date}", "Kufanele: {date}"),
+        ("EME Suppliers", "Abahlinzeki be-EME"),
+        ("EME/QSE Spend", "Ukusetshenziswa kwe-EME/QSE"),
+        ("Each", "Ngayinye"),
+        ("Eastern Cape", "EMpumalanga Kapa"),
+        ("Edit", "Hlela"),
+        ("Edit Catalogue Item", "Hlela into yekhathalogi"),
+        ("Editing {id}", "Kuhlelwa {id}"),
+        ("Effective", "Kuyasebenza"),
+        ("Effectiveness", "Ukusebenza kahle"),
+        ("Element", "Ingxenye"),
+        ("Email", "I-imeyili"),
+        ("Emergency", "Isimo esiphuthumayo"),
+        ("Emergency - Urgent procurement due to unforeseen circumstances", "Isimo esiphuthumayo - Ukuthenga okuphuthumayo ngenxa yezimo ezingalindelekile"),
+        ("Emergency Circumstances", "Izimo eziphuthumayo"),
+        ("Emergency Details", "Imininingwane yesimo esiphuthumayo"),
+        ("Emergency Procurement", "Ukuthenga okuphuthumayo"),
+        ("Enable All", "Vula konke"),
+        ("Enable Offline Mode", "Vula imodi engaxhunyiwe"),
+        ("Enable or disable features available to suppliers in the mobile app.", "Vula noma uvale izici ezitholakalayo kubahlinzeki kuhlelo lokusebenza lweselula."),
+        ("Enable this to define service level requirements and penalties", "Vula lokhu ukuze uchaze izidingo zezinga lesevisi nezinhlawulo"),
+        ("End Date", "Usuku lokuphela"),
+        ("End Time", "Isikhathi sokuphela"),
+        ("Ended", "Kuphelile"),
+        ("Enter a descriptive title", "Faka isihloko esichazayo"),
+        ("Enter contract title", "Faka isihloko senkontileka"),
+        ("Enter document name", "Faka igama ledokhumenti"),
+        ("Enter item description", "Faka incazelo yento"),
+        ("Enter item name", "Faka igama lento"),
+        ("Enter strategic objective...", "Faka inhloso yamasu..."),
+        ("Enter the purchase order number and load the order to capture the lines", "Faka inombolo ye-oda lokuthenga bese ulayisha i-oda ukuze uthwebule imigqa"),
+        ("Enter your bid", "Faka ibhidi yakho"),
+        ("Enterprise & Supplier Development Element - Code 400", "Ingxenye yokuthuthukiswa kwamabhizinisi nabahlinzeki - Ikhodi 400"),
+        ("Entity", "Inhlangano"),
+        ("Entity ID", "I-ID yenhlangano"),
+        ("Entity Name", "Igama lenhlangano"),
+        ("Entity Type", "Uhlobo lwenhlangano"),
+        ("Escalated", "Kudluliselwe phezulu"),
+        ("Escalated from", "Kudluliselwe phezulu kusuka ku"),
+        ("Escalation Procedure", "Inqubo yokudlulisela phezulu"),
+        ("Est. Value", "Inani elilinganisiwe"),
+        ("Estimated Total", "Isamba esilinganisiwe"),
+        ("Estimated Value", "Inani elilinganisiwe"),
+        ("Estimated contract value in ZAR", "Inani lenkontileka elilinganisiwe nge-ZAR"),
+        ("Eval Deadline", "Usuku lokugcina lokuhlola"),
+        ("Evaluated", "Kuhloliwe"),
+        ("Evaluation Criteria", "Imibandela yokuhlola"),
+        ("Evaluation Summary", "Isifinyezo sokuhlola"),
+        ("Exceeding", "Kudlula"),
+        ("Excel", "Excel"),
+        ("Exception", "Imbandakanyo"),
+        ("Exception Queue", "Umugqa wezimbandakanyo"),
+        ("Exceptions", "Izimbandakanyo"),
+        ("Excluded Invoices", "Ama-invoyisi angafakiwe"),
+        ("Expected", "Kulindelekile"),
+        ("Expected Date:", "Usuku olulindelekile:"),
+        ("Expected Delivery Date", "Usuku lokulethwa olulindelekile"),
+        ("Expended", "Kusetshenzisiwe"),
+        ("Expires in {days} days", "Kuphelelwa yisikhathi ezinsukwini ezingu-{days}"),
+        ("Expiring (30 days):", "Kuphelelwa yisikhathi (izinsuku ezingu-30):"),
+        ("Expiring Certs", "Izitifiketi eziphelelwa yisikhathi"),
+        ("Expiring within 30 days", "Kuphelelwa yisikhathi ezinsukwini ezingu-30"),
+        ("Explain why this requisition is needed...", "Chaza ukuthi kungani lesi sicelo sidingeka..."),
+        ("Explain why this specific supplier is required", "Chaza ukuthi kungani kudingeka lo mhlinzeki othile"),
+        ("Export", "Thumela ngaphandle"),
+        ("Export Comparison", "Thumela ukuqhathanisa"),
+        ("Export DTI Report", "Thumela umbiko we-DTI"),
+        ("Export Excel", "Thumela i-Excel"),
+        ("Export PDF", "Thumela i-PDF"),
+        ("Export Report", "Thumela umbiko"),
+        ("Extension", "Ukwelulwa"),
+        ("Extracted Fields", "Izinkambu ezikhishiwe"),
+        ("Extreme", "Okwedlulele"),
+        ("Extreme/High", "Okwedlulele/Okuphezulu"),
+        ("FY 2025/26", "Unyaka wezimali 2025/26"),
+        ("Facilities", "Izakhiwo"),
+        ("Facilities Management", "Ukuphathwa kwezakhiwo"),
+        ("Feature Usage", "Ukusetshenziswa kwezici"),
+        ("Featured", "Okugqamile"),
+        ("Features", "Izici"),
+        ("Fields to check", "Izinkambu okufanele zihlolwe"),
+        ("Filter", "Hlunga"),
+        ("Finalize Evaluation", "Qedela ukuhlola"),
+        ("Finance", "Ezezimali"),
+        ("Financial", "Okwezimali"),
+        ("Financial Impact", "Umthelela wezimali"),
+        ("Financial Score", "Amaphuzu ezimali"),
+        ("Financial Year", "Unyaka wezimali"),
+        ("Finding", "Okutholakele"),
+        ("Finding Statistics", "Izibalo zokutholakele"),
+        ("Findings", "Okutholakele"),
+        ("Findings by Severity", "Okutholakele ngobucayi"),
+        ("Fiscal Year", "Unyaka wezimali"),
+        ("Fleet Management", "Ukuphathwa kwezimoto"),
+        ("Fleet Services", "Izinsizakalo zezimoto"),
+        ("Folders", "Amafolda"),
+        ("Framework Agreement", "Isivumelwano sohlaka"),
+        ("Fraud", "Ukukhwabanisa"),
+        ("Free State", "Free State"),
+        ("From", "Kusuka"),
+        ("From budget", "Kusuka kwisabelomali"),
+        ("Full Justification", "Isizathu esigcwele"),
+        ("Functionality Threshold", "Umkhawulo wokusebenza"),
+        ("Functionality Threshold (%)", "Umkhawulo wokusebenza (%)"),
+        ("Funds are committed against this cost centre when approved", "Izimali zibophezelwa kulesi sikhungo sezindleko uma kuvunyiwe"),
+        ("Furniture", "Ifenisha"),
+        ("Furniture & Fittings", "Ifenisha nezinto zokufaka"),
+        ("GR Number", "Inombolo ye-GR"),
+        ("Gauteng", "Gauteng"),
+        ("General", "Okujwayelekile"),
+        ("Generate", "Khiqiza"),
+        ("Generic", "Okuvamile"),
+        ("Goods", "Impahla"),
+        ("Goods Receipt {id}", "Ukwamukelwa kwempahla {id}"),
+        ("Governance, Risk Management, and Compliance Overview", "Uhlolojikelele lokubusa, ukuphathwa kobungozi nokuthobela"),
+        ("Governing Law", "Umthetho olawulayo"),
+        ("Grid", "Igridi"),
+        ("Hand your approvals to a colleague while you are out of office", "Nikeza ozakwenu izimvume zakho ngenkathi ungekho ehhovisi"),
+        ("Health Services", "Izinsizakalo zezempilo"),
+        ("High Risk", "Ubungozi obuphezulu"),
+        ("High/Critical Risk", "Ubungozi obuphezulu/obubucayi"),
+        ("Home", "Ikhaya"),
+        ("How can I help you today?", "Ngingakusiza ngani namuhla?"),
+        ("Human Resources", "Abasebenzi"),
+        ("I can assist with tender analysis, compliance checks, bid evaluation, and procurement guidance.", "Ngingasiza ngokuhlaziywa kwamathenda, ukuhlolwa kokuthobela, ukuhlolwa kwamabhidi nokuqondiswa kokuthenga."),
+        ("IP Address", "Ikheli le-IP"),
+        ("IT Equipment", "Imishini ye-IT"),
+        ("IT Services", "Izinsizakalo ze-IT"),
+        ("Image", "Isithombe"),
+        ("Import", "Ngenisa"),
+        ("Import CSV", "Ngenisa i-CSV"),
+        ("In Stock", "Kusesitokweni"),
+        ("In stock only", "Okusesitokweni kuphela"),
+        ("Inactive", "Akusebenzi"),
+        ("Include Briefing Session", "Faka umhlangano wokuchaza"),
+        ("Include SLA Terms", "Faka imibandela ye-SLA"),
+        ("Include: reasons why competitive bidding is not possible, value for money considerations, risk assessment", "Faka: izizathu zokuthi kungani ukuthenda okuncintisanayo kungenakwenzeka, ukucatshangelwa kwenani lemali, ukuhlolwa kobungozi"),
+        ("Including all milestones", "Kufaka zonke izigaba ezibalulekile"),
+        ("Increasing", "Kuyakhuphuka"),
+        ("Indicator Status", "Isimo senkomba"),
+        ("Ineffective", "Akusebenzi kahle"),
+        ("Info", "Ulwazi"),
+        ("Info Req", "Kucelwa ulwazi"),
+        ("Information Technology", "Ubuchwepheshe bolwazi"),
+        ("Inherent", "Okungokwemvelo"),
+        ("Insignificant", "Okungabalulekile"),
+        ("Inspection", "Ukuhlolwa"),
+        ("Inspection failed. Rejected items must be returned to the supplier.", "Ukuhlolwa kwehlulekile. Izinto ezenqatshiwe kumele zibuyiselwe kumhlinzeki."),
+        ("Inspection:", "Ukuhlolwa:"),
+        ("Internal Controls ({count} total)", "Ulawulo lwangaphakathi ({count} sekukonke)"),
+        ("Internal Notes", "Amanothi angaphakathi"),
+        ("Internal Portal", "Iphothali yangaphakathi"),
+        ("Internal notes (not visible to supplier)", "Amanothi angaphakathi (awabonakali kumhlinzeki)"),
+        ("Invoice", "I-invoyisi"),
+        ("Invoice Date", "Usuku le-invoyisi"),
+        ("Invoice Details", "Imininingwane ye-invoyisi"),
+        ("Invoice Lines", "Imigqa ye-invoyisi"),
+        ("Invoice Register", "Irejista yama-invoyisi"),
+        ("Invoice Total", "Isamba se-invoyisi"),
+        ("Invoice, supplier or order", "I-invoyisi, umhlinzeki noma i-oda"),
+        ("Invoiced", "Kufakwe ku-invoyisi"),
+        ("Invoiced Price", "Intengo ekwi-invoyisi"),
+        ("Invoiced against accepted", "Okusenkwi-invoyisi uma kuqhathaniswa nokwamukelwe"),
+        ("Invoiced against ordered", "Okusenkwi-invoyisi uma kuqhathaniswa noku-odiwe"),
+        ("Invoices", "Ama-invoyisi"),
+        ("Item", "Into"),
+        ("Item #{number}", "Into #{number}"),
+        ("Item Code", "Ikhodi yento"),
+        ("Item Details", "Imininingwane yento"),
+        ("Item Name", "Igama lento"),
+        ("Item description", "Incazelo yento"),
+        ("Items", "Izinto"),
+        ("Items ({count} found)", "Izinto ({count} ezitholakele)"),
+        ("Items Received", "Izinto ezamukelwe"),
+        ("Its invoices return to the approved list for another run", "Ama-invoyisi awo abuyela ohlwini oluvunyiwe ukuze kube nomunye umjikelezo"),
+        ("Justification", "Isizathu"),
+        ("Kit", "Ikhithi"),
+        ("KwaZulu-Natal", "KwaZulu-Natali"),
+        ("LIVE", "KUYAQHUBEKA"),
+        ("Last Active", "Ugcine ukusebenza"),
+        ("Last Paid", "Ugcine ukukhokhelwa"),
+        ("Last Test", "Ukuhlolwa kokugcina"),
+        ("Last bid: {date}", "Ibhidi yokugcina: {date}"),
+        ("Leave at zero to delegate any amount", "Shiya kuqanda ukuze udlulisele noma yiliphi inani"),
+        ("Level 1", "Izinga 1"),
+        ("Level 1-2", "Izinga 1-2"),
+        ("Level 1-2 Spend", "Ukusetshenziswa kwezinga 1-2"),
+        ("Level 1-3", "Izinga 1-3"),
+        ("Level 1-4", "Izinga 1-4"),
+        ("Level 2", "Izinga 2"),
+        ("Level 3", "Izinga 3"),
+        ("Level 3-4", "Izinga 3-4"),
+        ("Level 4+", "Izinga 4+"),
+        ("Level 5-8", "Izinga 5-8"),
+        ("Level Distribution", "Ukusatshalaliswa kwamazinga"),
+        ("Level {level}", "Izinga {level}"),
+        ("License", "Ilayisense"),
+        ("Light", "Ukukhanya"),
+        ("Likely", "Kungenzeka"),
+        ("Limpopo", "Limpopo"),
+        ("Line Items", "Izinto zomugqa"),
+        ("Line Items ({count})", "Izinto zomugqa ({count})"),
+        ("Line Items Summary", "Isifinyezo sezinto zomugqa"),
+        ("Lines add up to {lines}, not the subtotal of {subtotal}", "Imigqa ihlangana ibe ngu-{lines}, hhayi isamba esingaphansi esingu-{subtotal}"),
+        ("Lines without a supplier go out for quotation.", "Imigqa engenamhlinzeki iyoyocelelwa isilinganiso senani."),
+        ("Link to existing contract (optional)", "Xhumanisa nenkontileka ekhona (ongakukhetha)"),
+        ("Link to requisition (optional)", "Xhumanisa nesicelo (ongakukhetha)"),
+        ("Linked Tender ID", "I-ID yethenda exhunyiwe"),
+        ("List", "Uhlu"),
+        ("Live", "Kuyaqhubeka"),
+        ("Load Order", "Layisha i-oda"),
+        ("Loading auctions...", "Kulayishwa izindali..."),
+        ("Loading audit entries...", "Kulayishwa okufakwe ekucwaningweni..."),
+        ("Loading catalogue...", "Kulayishwa ikhathalogi..."),
+        ("Loading compliance data...", "Kulayishwa idatha yokuthobela..."),
+        ("Loading contract...", "Kulayishwa inkontileka..."),
+        ("Loading contracts...", "Kulayishwa izinkontileka..."),
+        ("Loading controls data...", "Kulayishwa idatha yolawulo..."),
+        ("Loading document...", "Kulayishwa idokhumenti..."),
+        ("Loading documents...", "Kulayishwa amadokhumenti..."),
+        ("Loading finding...", "Kulayishwa okutholakele..."),
+        ("Loading goods receipt...", "Kulayishwa ukwamukelwa kwempahla..."),
+        ("Loading goods receipts...", "Kulayishwa ukwamukelwa kwempahla..."),
+        ("Loading invoice...", "Kulayishwa i-invoyisi..."),
+        ("Loading items...", "Kulayishwa izinto..."),
+        ("Loading level data...", "Kulayishwa idatha yamazinga..."),
+        ("Loading metrics...", "Kulayishwa izilinganiso..."),
+        ("Loading payment run...", "Kulayishwa umjikelezo wokukhokha..."),
+        ("Loading performance data...", "Kulayishwa idatha yokusebenza..."),
+        ("Loading purchase order...", "Kulayishwa i-oda lokuthenga..."),
+        ("Loading purchase orders...", "Kulayishwa ama-oda okuthenga..."),
+        ("Loading requisition...", "Kulayishwa isicelo..."),
+        ("Loading review...", "Kulayishwa ukubuyekezwa..."),
+        ("Loading risk data...", "Kulayishwa idatha yobungozi..."),
+        ("Loading supplier...", "Kulayishwa umhlinzeki..."),
+        ("Loading suppliers...", "Kulayishwa abahlinzeki..."),
+        ("Loading targets...", "Kulayishwa imigomo..."),
+        ("Loading tenders...", "Kulayishwa amathenda..."),
+        ("Loading violations data...", "Kulayishwa idatha yokwephulwa..."),
+        ("Loading...", "Kuyalayisha..."),
+        ("Local %", "Kwendawo %"),
+        ("Locked", "Kukhiyiwe"),
+        ("Locked by {user}", "Kukhiywe ngu-{user}"),
+        ("Login", "Ngena"),
+        ("Login Events", "Izehlakalo zokungena"),
+        ("Logout", "Phuma"),
+        ("Low Risk", "Ubungozi obuphansi"),
+        ("Major", "Okukhulu"),
+        ("Manage and monitor registered suppliers", "Phatha futhi uqaphe abahlinzeki ababhalisiwe"),
+        ("Manage and organize procurement documents", "Phatha futhi uhlele amadokhumenti okuthenga"),
+        ("Manage live reverse auctions for competitive bidding", "Phatha izindali eziphambene eziqhubekayo zokuthenda okuncintisanayo"),
+        ("Manage procurement tenders and bids", "Phatha amathenda okuthenga namabhidi"),
+        ("Manage purchase orders and track deliveries", "Phatha ama-oda okuthenga futhi ulandelele ukulethwa"),
+        ("Manage purchase requisitions and approvals", "Phatha izicelo zokuthenga nezimvume"),
+        ("Manage supplier contracts and agreements", "Phatha izinkontileka nezivumelwano zabahlinzeki"),
+        ("Manage your tenders, bids, and contracts", "Phatha amathenda akho, amabhidi nezinkontileka"),
+        ("Management Response", "Impendulo yabaphathi"),
+        ("Mandatory Requirements", "Izidingo eziyimpoqo"),
+        ("Mark All Read", "Maka konke njengokufundiwe"),
+        ("Mark Complete", "Maka njengokuqediwe"),
+        ("Market Analysis", "Uhlaziyo lwemakethe"),
+        ("Market Research", "Ucwaningo lwemakethe"),
+        ("Match", "Qhathanisa"),
+        ("Match Tolerances", "Ukubekezelela kokuqhathanisa"),
+        ("Match tolerances saved", "Ukubekezelela kokuqhathanisa kulondoloziwe"),
+        ("Matched", "Kuhambisana"),
+        ("Material", "Okubalulekile"),
+        ("Max", "Okuphezulu"),
+        ("Max Offline Duration", "Isikhathi esiphezulu ungaxhunyiwe"),
+        ("Maximum file size: 50MB", "Usayizi wefayela ophezulu: 50MB"),
+        ("Maximum per transfer:", "Okuphezulu ngokudluliswa ngakunye:"),
+        ("Maximum time to acknowledge an issue", "Isikhathi esiphezulu sokuqaphela inkinga"),
+        ("Maximum time to resolve an issue", "Isikhathi esiphezulu sokuxazulula inkinga"),
+        ("Medical", "Ezempilo"),
+        ("Medium Risk", "Ubungozi obumaphakathi"),
+        ("Meeting No.", "Inombolo yomhlangano"),
+        ("Meeting Schedule", "Uhlelo lwemihlangano"),
+        ("Metric", "Isilinganiso"),
+        ("Milestone", "Isigaba esibalulekile"),
+        ("Milestone Details", "Imininingwane yesigaba esibalulekile"),
+        ("Milestone Name", "Igama lesigaba esibalulekile"),
+        ("Milestone Timeline", "Uhlelo lwesikhathi lwezigaba ezibalulekile"),
+        ("Milestone name", "Igama lesigaba esibalulekile"),
+        ("Milestone {id} - Due: {date}", "Isigaba esibalulekile {id} - Kufanele: {date}"),
+        ("Milestones: {title}", "Izigaba ezibalulekile: {title}"),
+        ("Minimum 3 years experience", "Okungenani iminyaka emi-3 yolwazi"),
+        ("Minimum Bidding Period", "Isikhathi esincane sokuthenda"),
+        ("Minimum score to qualify", "Amaphuzu amancane okufaneleka"),
+        ("Minor", "Okuncane"),
+        ("Mitigated", "Kwehlisiwe"),
+        ("Mobile App Users", "Abasebenzisi bohlelo lweselula"),
+        ("Mobile Bids", "Amabhidi eselula"),
+        ("Mobile Supplier App", "Uhlelo lweselula lwabahlinzeki"),
+        ("Moderate", "Okumaphakathi"),
+        ("Monitor and analyze supplier performance metrics", "Qapha futhi uhlaziye izilinganiso zokusebenza kwabahlinzeki"),
+        ("Monitor and manage supplier risk exposure", "Qapha futhi uphathe ubungozi obubhekene nabahlinzeki"),
+        ("Month", "Inyanga"),
+        ("Monthly Spend Trend", "Ukuthambekela kokusetshenziswa kwanyanga zonke"),
+        ("Mpumalanga", "iMpumalanga"),
+        ("My Bid Submissions", "Amabhidi engiwahanjisile"),
+        ("My Profile", "Iphrofayili yami"),
+        ("My Submissions", "Engikuhanjisile"),
+        ("N/A", "Akusebenzi"),
+        ("NBAC - Bid Adjudication", "NBAC - Ukwahlulelwa kwamabhidi"),
+        ("Name", "Igama"),
+        ("Name (A-Z)", "Igama (A-Z)"),
+        ("Name (Z-A)", "Igama (Z-A)"),
+        ("Name of proposed supplier", "Igama lomhlinzeki ohlongozwayo"),
+        ("National Bid Adjudication Committee reviews and decisions", "Ukubuyekezwa nezinqumo zeKomidi Likazwelonke Lokwahlulela Amabhidi"),
+        ("National Treasury Approval", "Imvume kaMgcinimafa Kazwelonke"),
+        ("New Assessment", "Ukuhlolwa okusha"),
+        ("New Auction", "Indali entsha"),
+        ("New Contract", "Inkontileka entsha"),
+        ("New Conversation", "Ingxoxo entsha"),
+        ("New Delegation", "Ukudluliselwa okusha"),
+        ("New Purchase Order", "I-oda elisha lokuthenga"),
+        ("New Receipt", "Ukwamukelwa okusha"),
+        ("New Requisition", "Isicelo esisha"),
+        ("New Sourcing Plan", "Uhlelo olusha lokuthola"),
+        ("New Tender", "Ithenda entsha"),
+        ("New approver", "Ovumayo omusha"),
+        ("Next", "Okulandelayo"),
+        ("Next Milestone", "Isigaba esibalulekile esilandelayo"),
+        ("Next Review", "Ukubuyekezwa okulandelayo"),
+        ("Next: Deliverables", "Okulandelayo: Okulethwayo"),
+        ("Next: Delivery", "Okulandelayo: Ukulethwa"),
+        ("Next: Line Items", "Okulandelayo: Izinto zomugqa"),
+        ("Next: Review", "Okulandelayo: Ukubuyekeza"),
+        ("Next: SLA", "Okulandelayo: I-SLA"),
+        ("Next: Terms", "Okulandelayo: Imibandela"),
+        ("No Evaluation Selected", "Akukhethwanga ukuhlolwa"),
+        ("No Live Auction", "Ayikho indali eqhubekayo"),
+        ("No attachments added", "Akukho okunamathiselwe okungeziwe"),
+        ("No auctions found. Create a new auction to get started.", "Azikho izindali ezitholakele. Dala indali entsha ukuze uqale."),
+        ("No audit entries found", "Akukho okufakwe ekucwaningweni okutholakele"),
+        ("No bank details on record", "Ayikho imininingwane yasebhange erekhodiwe"),
+        ("No bids yet", "Awekho amabhidi okwamanje"),
+        ("No budget transfers", "Akukho ukudluliswa kwesabelomali"),
+        ("No committee notes yet.", "Awekho amanothi ekomidi okwamanje."),
+        ("No contracts found", "Azikho izinkontileka ezitholakele"),
+        ("No critical alerts at this time", "Azikho izexwayiso ezibucayi okwamanje"),
+        ("No delegations", "Akukho ukudluliselwa"),
+        ("No documents found", "Awekho amadokhumenti atholakele"),
+        ("No documents processed", "Awekho amadokhumenti acutshunguliwe"),
+        ("No invoices found", "Awekho ama-invoyisi atholakele"),
+        ("No invoices need attention", "Awekho ama-invoyisi adinga ukunakwa"),
+        ("No items found", "Azikho izinto ezitholakele"),
+        ("No order loaded", "Alikho i-oda elilayishiwe"),
+        ("No overdue approvals", "Azikho izimvume ezidlulelwe yisikhathi"),
+        ("No payment runs yet", "Ayikho imijikelezo yokukhokha okwamanje"),
+        ("No purchase orders found", "Awekho ama-oda okuthenga atholakele"),
+        ("No risk indicators found.", "Azikho izinkomba zobungozi ezitholakele."),
+        ("No supplier", "Akukho mhlinzeki"),
+        ("No supplier is registered under {registration}", "Akekho umhlinzeki obhaliswe ngaphansi kuka-{registration}"),
+        ("No suppliers found. Adjust filters or add a new supplier.", "Abekho abahlinzeki abatholakele. Lungisa izihlungi noma wengeze umhlinzeki omusha."),
+        ("No suppliers match the selected criteria.", "Abekho abahlinzeki abahambisana nemibandela ekhethiwe."),
+        ("No tenders found. Create a new tender to get started.", "Awekho amathenda atholakele. Dala ithenda entsha ukuze uqale."),
+        ("Non-Compliant", "Akuthobeli"),
+        ("Non-compliant - requires immediate attention", "Akuthobeli - kudinga ukunakwa ngokushesha"),
+        ("North West", "North West"),
+        ("Northern Cape", "Northern Cape"),
+        ("Not Tested", "Akuhloliwe"),
+        ("Not matched", "Akuhambisani"),
+        ("Not ordered", "Akuodiwe"),
+        ("Not yet submitted for approval", "Akukahanjiswa ukuze kuvunywe"),
+        ("Note: ", "Qaphela: "),
+        ("Notes", "Amanothi"),
+        ("Notes (visible to supplier)", "Amanothi (ayabonakala kumhlinzeki)"),
+        ("Nothing paid this financial year", "Akukho okukhokhwe kulo nyaka wezimali"),
+        ("Notice Period (days)", "Isikhathi sesaziso (izinsuku)"),
+        ("Notify", "Yazisa"),
+        ("Notify registered suppliers in this category", "Yazisa abahlinzeki ababhalisiwe kulesi sigaba"),
+        ("Observation", "Okubonwayo"),
+        ("Office Supplies", "Izinto zasehhovisi"),
+        ("Offline Mode", "Imodi engaxhunyiwe"),
+        ("On Track", "Kusendleleni"),
+        ("On behalf of", "Egameni lika"),
+        ("Once published, the tender will be visible to all suppliers. Ensure all documents are finalized before publishing.", "Uma isishicilelwe, ithenda izobonakala kubo bonke abahlinzeki. Qinisekisa ukuthi wonke amadokhumenti aqediwe ngaphambi kokushicilela."),
+        ("Open Issues", "Izinkinga ezivulekile"),
+        ("Open Opportunities", "Amathuba avulekile"),
+        ("Open Tenders", "Amathenda avulekile"),
+        ("Open Violations", "Ukwephulwa okuvulekile"),
+        ("Open in New Tab", "Vula kuthebhu entsha"),
+        ("Open record", "Vula irekhodi"),
+        ("Operating", "Kuyasebenza"),
+        ("Operations", "Ukusebenza"),
+        ("Opinion", "Umbono"),
+        ("Opportunities", "Amathuba"),
+        ("Order", "I-oda"),
+        ("Order Date", "Usuku lwe-oda"),
+        ("Order Price", "Intengo ye-oda"),
+        ("Ordered", "Ku-odiwe"),
+        ("Orders by Supplier", "Ama-oda ngomhlinzeki"),
+        ("Organization Name", "Igama lenhlangano"),
+        ("Other", "Okunye"),
+        ("Out", "Ngaphandle"),
+        ("Out of Stock", "Akukho esitokweni"),
+        ("Outstanding", "Okusalindile"),
+        ("Overall", "Sekukonke"),
+        ("Overall Progress", "Inqubekela phambili yonkana"),
+        ("Overall confidence", "Ukuqiniseka konkana"),
+        ("Overdue", "Kudlulelwe yisikhathi"),
+        ("Overdue Approvals", "Izimvume ezidlulelwe yisikhathi"),
+        ("Overdue Reviews", "Ukubuyekezwa okudlulelwe yisikhathi"),
+        ("Overdue by", "Kudlulelwe yisikhathi ngo"),
+        ("Overdue only", "Okudlulelwe yisikhathi kuphela"),
+        ("Overview of procurement activities for FY 2025/26", "Uhlolojikelele lwemisebenzi yokuthenga yonyaka wezimali ka-2025/26"),
+        ("Owner", "Umnikazi"),
+        ("Owner: {owner}", "Umnikazi: {owner}"),
+        ("Ownership", "Ubunikazi"),
+        ("PDF, JPEG, PNG, TIFF or WebP, up to", "PDF, JPEG, PNG, TIFF noma WebP, kuze kufike ku"),
+        ("PDF, Word, Excel, or images. Max 10MB per file.", "PDF, Word, Excel noma izithombe. Okuphezulu 10MB ngefayela ngalinye."),
+        ("PO Number", "Inombolo ye-PO"),
+        ("PO Reference", "Inkomba ye-PO"),
+        ("PO Value", "Inani le-PO"),
+        ("Pack", "Iphakethe"),
+        ("Paid", "Kukhokhiwe"),
+        ("Paid This Year", "Kukhokhwe kulo nyaka"),
+        ("Paid from", "Kukhokhwe kusuka ku"),
+        ("Paid: {date}", "Kukhokhiwe: {date}"),
+        ("Partial", "Ingxenye"),
+        ("Partial Compliance", "Ukuthobela okuyingxenye"),
+        ("Partial Pass", "Ukuphasa okuyingxenye"),
+        ("Partially", "Ngokwengxenye"),
+        ("Partially Delivered", "Kulethwe ngokwengxenye"),
+        ("Partially Effective", "Kusebenza ngokwengxenye"),
+        ("Partially Received", "Kwamukelwe ngokwengxenye"),
+        ("Participant", "Obambe iqhaza"),
+        ("Passed", "Kuphasile"),
+        ("Past Due", "Kudlulelwe yisikhathi"),
+        ("Paying on", "Kukhokhwa ngo"),
+        ("Payment Aging", "Ubudala bokukhokha"),
+        ("Payment Amount", "Inani lokukhokha"),
+        ("Payment Date", "Usuku lokukhokha"),
+        ("Payment Report", "Umbiko wokukhokha"),
+        ("Payment Run", "Umjikelezo wokukhokha"),
+        ("Payment Runs", "Imijikelezo yokukhokha"),
+        ("Payment Schedule", "Uhlelo lokukhokha"),
+        ("Payment Terms", "Imibandela yokukhokha"),
+        ("Payment confirmed by", "Ukukhokha kuqinisekiswe ngu"),
+        ("Payment date", "Usuku lokukhokha"),
+        ("Payment is due", "Ukukhokha kufanele"),
+        ("Payment run", "Umjikelezo wokukhokha"),
+        ("Payments Made", "Izinkokhelo ezenziwe"),
+        ("Penalty Clause", "Isigatshana sesijeziso"),
+        ("Pending Approvals", "Izimvume ezilindile"),
+        ("Pending Delivery:", "Ukulethwa okulindile:"),
+        ("Pending Processing:", "Ukucutshungulwa okulindile:"),
+        ("Pending Receipts ({count})", "Ukwamukelwa okulindile ({count})"),
+        ("Pending Review", "Kulinde ukubuyekezwa"),
+        ("Pending Reviews", "Ukubuyekezwa okulindile"),
+        ("Pending Start", "Kulinde ukuqala"),
+        ("Pending delivery only", "Ukulethwa okulindile kuphela"),
+        ("Percentage", "Iphesenti"),
+        ("Performance Distribution", "Ukusatshalaliswa kokusebenza"),
+        ("Performance by Category", "Ukusebenza ngesigaba"),
+        ("Period", "Isikhathi"),
+        ("Phone", "Ifoni"),
+        ("Place your first bid to participate", "Faka ibhidi yakho yokuqala ukuze ubambe iqhaza"),
+        ("Plan Details", "Imininingwane yohlelo"),
+        ("Plan ID", "I-ID yohlelo"),
+        ("Plan Title", "Isihloko sohlelo"),
+        ("Please review all information before saving. Once submitted for approval, changes will require re-approval.", "Sicela ubuyekeze lonke ulwazi ngaphambi kokulondoloza. Uma sekuhanjiselwe imvume, izinguquko zizodinga ukuvunywa kabusha."),
+        ("Please select an evaluation from the list to begin scoring.", "Sicela ukhethe ukuhlolwa ohlwini ukuze uqale ukunikeza amaphuzu."),
+        ("Points", "Amaphuzu"),
+        ("Policy", "Inqubomgomo"),
+        ("Policy Violations ({count} total)", "Ukwephulwa kwenqubomgomo ({count} sekukonke)"),
+        ("Portal Link", "Isixhumanisi sephothali"),
+        ("Portal Notes", "Amanothi ephothali"),
+        ("Portal Reference", "Inkomba yephothali"),
+        ("Possible", "Kungenzeka"),
+        ("Possible duplicate of", "Kungenzeka kuyimpinda ka"),
+        ("Possible duplicate of {invoice_id} ({reason})", "Kungenzeka kuyimpinda ka-{invoice_id} ({reason})"),
+        ("Postal Code", "Ikhodi yeposi"),
+        ("Prepare Payment Run", "Lungiselela umjikelezo wokukhokha"),
+        ("Prepare Run", "Lungiselela umjikelezo"),
+        ("Prepared by", "Kulungiswe ngu"),
+        ("Preventive", "Okuvimbelayo"),
+        ("Preview", "Buka kuqala"),
+        ("Preview Controls", "Izilawuli zokubuka kuqala"),
+        ("Preview and configure the mobile app for suppliers", "Buka kuqala futhi umise uhlelo lweselula lwabahlinzeki"),
+        ("Preview not available", "Ukubuka kuqala akutholakali"),
+        ("Preview not available for this file type", "Ukubuka kuqala akutholakali kulolu hlobo lwefayela"),
+        ("Price", "Intengo"),
+        ("Price (High to Low)", "Intengo (ephezulu kuya kwephansi)"),
+        ("Price (Low to High)", "Intengo (ephansi kuya kwephezulu)"),
+        ("Price Var.", "Umehluko wentengo"),
+        ("Price Weight", "Isisindo sentengo"),
+        ("Price Weight (%)", "Isisindo sentengo (%)"),
+        ("Primary Color", "Umbala oyinhloko"),
+        ("Priority", "Okubalulekile"),
+        ("Process and track goods received from suppliers", "Cubungula futhi ulandelele impahla eyamukelwe kubahlinzeki"),
+        ("Processed", "Kucutshunguliwe"),
+        ("Processed Documents", "Amadokhumenti acutshunguliwe"),
+        ("Procurement Categories", "Izigaba zokuthenga"),
+        ("Procurement Dashboard", "Ideshibhodi yokuthenga"),
+        ("Procurement Details", "Imininingwane yokuthenga"),
+        ("Procurement Division", "Uphiko lokuthenga"),
+        ("Professional", "Okobungcweti"),
+        ("Professional Services", "Izinsizakalo zobungcweti"),
+        ("Profile", "Iphrofayili"),
+        ("Progress", "Inqubekela phambili"),
+        ("Projected Level", "Izinga elibikezelwe"),
+        ("Proposed Supplier", "Umhlinzeki ohlongozwayo"),
+        ("Provide a detailed description of the procurement plan", "Nikeza incazelo enemininingwane yohlelo lokuthenga"),
+        ("Provide detailed justification for deviating from competitive bidding", "Nikeza isizathu esinemininingwane sokuphambuka ekuthendeni okuncintisanayo"),
+        ("Provide detailed justification for the procurement", "Nikeza isizathu esinemininingwane sokuthenga"),
+        ("Province", "Isifundazwe"),
+        ("Provincial B-BBEE Distribution", "Ukusatshalaliswa kwe-B-BBEE ngesifundazwe"),
+        ("Provincial Distribution", "Ukusatshalaliswa ngesifundazwe"),
+        ("Publication Date", "Usuku lokushicilelwa"),
+        ("Publication Dates", "Izinsuku zokushicilelwa"),
+        ("Publication Settings", "Izilungiselelo zokushicilela"),
+        ("Publish Tender", "Shicilela ithenda"),
+        ("Publish Update", "Shicilela isibuyekezo"),
+        ("Publish internally", "Shicilela ngaphakathi"),
+        ("Publish tender to e-Tender portal for bidding", "Shicilela ithenda kuphothali ye-e-Tender ukuze kuthendwe"),
+        ("Publish to e-Tender", "Shicilela ku-e-Tender"),
+        ("Publish to the national e-Tender portal (etenders.gov.za)", "Shicilela kuphothali kazwelonke ye-e-Tender (etenders.gov.za)"),
+        ("Purchase Order", "I-oda lokuthenga"),
+        ("Purchase Order {po_number}", "I-oda lokuthenga {po_number}"),
+        ("Purchase Orders ({count})", "Ama-oda okuthenga ({count})"),
+        ("Purchase order {po_number} was not found", "I-oda lokuthenga {po_number} alitholakalanga"),
+        ("Push Enabled", "Izaziso zivuliwe"),
+        ("Qty", "Inani"),
+        ("Qty Var.", "Umehluko wenani"),
+        ("Qualified", "Kufanelekile"),
+        ("Quality", "Ikhwalithi"),
+        ("Quantity", "Inani"),
+        ("Quick Actions", "Izenzo ezisheshayo"),
+        ("Quick Bid", "Ibhidi esheshayo"),
+        ("Quorum Required", "Ikhoramu iyadingeka"),
+        ("RFP - Request for Proposal", "RFP - Isicelo sesiphakamiso"),
+        ("RFQ - Request for Quotation", "RFQ - Isicelo sesilinganiso senani"),
+        ("RFT - Request for Tender", "RFT - Isicelo sethenda"),
+        ("Raised from approved requisition ", "Kuvela esicelweni esivunyiwe "),
+        ("Rank", "Isikhundla"),
+        ("Rare", "Kuyivelakancane"),
+        ("Rating", "Isilinganiso"),
+        ("Re-run Match", "Phinda uqhathanise"),
+        ("Read Documents", "Funda amadokhumenti"),
+        ("Read as", "Funda njenge"),
+        ("Read by", "Kufundwe ngu"),
+        ("Reading...", "Kuyafundwa..."),
+        ("Reason", "Isizathu"),
+        ("Reason for reassignment", "Isizathu sokunikeza kabusha"),
+        ("Reassign", "Nikeza kabusha"),
+        ("Reassign pending step", "Nikeza kabusha isinyathelo esilindile"),
+        ("Receipt Details", "Imininingwane yokwamukelwa"),
+        ("Received", "Kwamukelwe"),
+        ("Received By", "Kwamukelwe ngu"),
+        ("Receiver's name", "Igama lomamukeli"),
+        ("Recent Activity", "Umsebenzi wakamuva"),
+        ("Recent Opportunities", "Amathuba akamuva"),
+        ("Recent Requisitions", "Izicelo zakamuva"),
+        ("Recently Added", "Okungezwe muva nje"),
+        ("Recognition", "Ukuqashelwa"),
+        ("Recognized Spend", "Ukusetshenziswa okuqashelwe"),
+        ("Recommendation", "Isincomo"),
+        ("Recommended Supplier", "Umhlinzeki onconywayo"),
+        ("Recommended Value", "Inani elinconywayo"),
+        ("Recommended documents: Technical Specifications, Terms and Conditions, Evaluation Criteria, Pricing Schedule, Company Profile Template", "Amadokhumenti anconywayo: Imininingwane yezobuchwepheshe, Imigomo nemibandela, Imibandela yokuhlola, Uhlelo lwamanani, Isifanekiso sephrofayili yenkampani"),
+        ("Record", "Irekhodi"),
+        ("Record Resolution", "Rekhoda isinqumo"),
+        ("Ref:", "Inkomba:"),
+        ("Reference", "Inkomba"),
+        ("Reference Number", "Inombolo yenkomba"),
+        ("Reg. No.", "Inombolo yokubhalisa"),
+        ("Register Invoice", "Bhalisa i-invoyisi"),
+        ("Register and Match", "Bhalisa futhi uqhathanise"),
+        ("Register for Briefing", "Bhalisela umhlangano wokuchaza"),
+        ("Registered", "Kubhalisiwe"),
+        ("Registered as {name} ({id})", "Ubhaliswe njengo-{name} ({id})"),
+        ("Registered by", "Kubhaliswe ngu"),
+        ("Registration", "Ukubhalisa"),
+        ("Reject", "Yenqaba"),
+        ("Related To", "Kuhlobene no"),
+        ("Remaining", "Okusele"),
+        ("Remaining / Unallocated", "Okusele / Okungabiwanga"),
+        ("Remittance", "Isaziso sokukhokha"),
+        ("Remove", "Susa"),
+        ("Renewal Terms", "Imibandela yokuvuselela"),
+        ("Repeat Finding", "Okutholakele okuphindiwe"),
+        ("Repeat Findings", "Okutholakele okuphindiwe"),
+        ("Report", "Umbiko"),
+        ("Report No.", "Inombolo yombiko"),
+        ("Report Violation", "Bika ukwephulwa"),
+        ("Reputation", "Idumela"),
+        ("Req ID", "I-ID yesicelo"),
+        ("Request Transfer", "Cela ukudluliswa"),
+        ("Request deviation from competitive bidding process", "Cela ukuphambuka kunqubo yokuthenda okuncintisanayo"),
+        ("Requested", "Kuceliwe"),
+        ("Requester", "Ocelayo"),
+        ("Require Biometric Authentication", "Dinga ukuqinisekiswa nge-biometric"),
+        ("Required By", "Kudingeka ngo"),
+        ("Required documents: Supplier quotation, Market research evidence, Supplier B-BBEE certificate, Tax clearance", "Amadokhumenti adingekayo: Isilinganiso senani somhlinzeki, Ubufakazi bocwaningo lwemakethe, Isitifiketi se-B-BBEE somhlinzeki, Imvume yentela"),
+        ("Required uptime percentage", "Iphesenti lesikhathi sokusebenza elidingekayo"),
+        ("Requires Justification", "Kudinga isizathu"),
+        ("Requisition", "Isicelo"),
+        ("Requisition Details", "Imininingwane yesicelo"),
+        ("Requisition Reference", "Inkomba yesicelo"),
+        ("Requisition {id} - {department}", "Isicelo {id} - {department}"),
+        ("Reserve threshold at {percent}%", "Umkhawulo wokugodla ku-{percent}%"),
+        ("Reserve: {amount}", "Okugodliwe: {amount}"),
+        ("Reset", "Setha kabusha"),
+        ("Residual", "Okusele"),
+        ("Resolution", "Isinqumo"),
+        ("Resolution No.", "Inombolo yesinqumo"),
+        ("Resolution Rate", "Izinga lokuxazulula"),
+        ("Resolution Time (hours)", "Isikhathi sokuxazulula (amahora)"),
+        ("Resolve Exception", "Xazulula imbandakanyo"),
+        ("Resolved", "Kuxazululiwe"),
+        ("Response", "Impendulo"),
+        ("Response Time (hours)", "Isikhathi sokuphendula (amahora)"),
+        ("Responsible", "Obhekene nakho"),
+        ("Responsible Party", "Oyobhekana nakho"),
+        ("Responsible party", "Oyobhekana nakho"),
+        ("Result", "Umphumela"),
+        ("Revenue Summary", "Isifinyezo semali engenayo"),
+        ("Reverse Auctions", "Izindali eziphambene"),
+        ("Review & Submit", "Buyekeza futhi uhambise"),
+        ("Review Details", "Imininingwane yokubuyekezwa"),
+        ("Review and score bids for open tenders", "Buyekeza futhi unikeze amaphuzu amabhidi amathenda avulekile"),
+        ("Reviews Pending Adjudication", "Ukubuyekezwa okulinde ukwahlulelwa"),
+        ("Revoke", "Hoxisa"),
+        ("Risk Distribution", "Ukusatshalaliswa kobungozi"),
+        ("Risk Heat Map", "Imephu yokushisa kobungozi"),
+        ("Risk Implication", "Umthelela wobungozi"),
+        ("Risk Indicator", "Inkomba yobungozi"),
+        ("Risk Indicators ({count} total)", "Izinkomba zobungozi ({count} sezizonke)"),
+        ("Risk Indicators by Category", "Izinkomba zobungozi ngesigaba"),
+        ("Risk Level", "Izinga lobungozi"),
+        ("Risk Rating", "Isilinganiso sobungozi"),
+        ("Risk Register ({count} total)", "Irejista yobungozi ({count} sekukonke)"),
+        ("Risks", "Ubungozi"),
+        ("Root Cause", "Imbangela eyinhloko"),
+        ("Run", "Umjikelezo"),
+        ("Run Assessment", "Qhuba ukuhlola"),
+        ("Runs", "Imijikelezo"),
+        ("Save", "Londoloza"),
+        ("Save Changes", "Londoloza izinguquko"),
+        ("Save Draft", "Londoloza okusalungiswa"),
+        ("Save Tolerances", "Londoloza ukubekezelela"),
+        ("Save as", "Londoloza njenge"),
+        ("Saved as", "Kulondolozwe njenge"),
+        ("Saved as Records", "Kulondolozwe njengamarekhodi"),
+        ("Scan invoices, delivery notes and B-BBEE certificates into draft records", "Skena ama-invoyisi, amanothi okulethwa nezitifiketi ze-B-BBEE zibe amarekhodi asalungiswa"),
+        ("Scanned document", "Idokhumenti eskeniwe"),
+        ("Schedule Meeting", "Hlela umhlangano"),
+        ("Scope", "Ububanzi"),
+        ("Scope Description", "Incazelo yobubanzi"),
+        ("Scope of Work", "Ububanzi bomsebenzi"),
+        ("Score", "Amaphuzu"),
+        ("Score: {supplier_name}", "Amaphuzu: {supplier_name}"),
+        ("Scorecard Points", "Amaphuzu ekhadi lamaphuzu"),
+        ("Scoring", "Ukunikeza amaphuzu"),
+        ("Scoring Method: {method} | Min Technical Score: {score}% | Price Weight: {weight}%", "Indlela yamaphuzu: {method} | Amaphuzu amancane obuchwepheshe: {score}% | Isisindo sentengo: {weight}%"),
+        ("Scoring Progress", "Inqubekela phambili yamaphuzu"),
+        ("Scoring Weights", "Izisindo zamaphuzu"),
+        ("Screen", "Isikrini"),
+        ("Search", "Sesha"),
+        ("Search GR, PO, supplier...", "Sesha i-GR, i-PO, umhlinzeki..."),
+        ("Search POs...", "Sesha ama-PO..."),
+        ("Search auctions...", "Sesha izindali..."),
+        ("Search audit logs...", "Sesha amarekhodi okucwaninga..."),
+        ("Search by ID, description, requester...", "Sesha nge-ID, incazelo, ocelayo..."),
+        ("Search by ID, title, owner...", "Sesha nge-ID, isihloko, umnikazi..."),
+        ("Search contracts...", "Sesha izinkontileka..."),
+        ("Search documents...", "Sesha amadokhumenti..."),
+        ("Search items...", "Sesha izinto..."),
+        ("Search supplier...", "Sesha umhlinzeki..."),
+        ("Search suppliers...", "Sesha abahlinzeki..."),
+        ("Search tenders...", "Sesha amathenda..."),
+        ("Search the catalogue by name, item code or supplier", "Sesha ikhathalogi ngegama, ikhodi yento noma umhlinzeki"),
+        ("Search user...", "Sesha umsebenzisi..."),
+        ("Secondary Color", "Umbala wesibili"),
+        ("Security", "Ezokuphepha"),
+        ("Security Services", "Izinsizakalo zokuphepha"),
+        ("Segregation", "Ukuhlukaniswa"),
+        ("Select Category", "Khetha isigaba"),
+        ("Select Department", "Khetha umnyango"),
+        ("Select Deviation Type", "Khetha uhlobo lokuphambuka"),
+        ("Select a Bid to Score", "Khetha ibhidi ozoyinika amaphuzu"),
+        ("Select action", "Khetha isenzo"),
+        ("Select category...", "Khetha isigaba..."),
+        ("Select department", "Khetha umnyango"),
+        ("Select province", "Khetha isifundazwe"),
+        ("Select receiving budget", "Khetha isabelomali esamukelayo"),
+        ("Select source budget", "Khetha isabelomali somthombo"),
+        ("Select supplier", "Khetha umhlinzeki"),
+        ("Select supplier...", "Khetha umhlinzeki..."),
+        ("Select type", "Khetha uhlobo"),
+        ("Select...", "Khetha..."),
+        ("Send Notification", "Thumela isaziso"),
+        ("Sent to Supplier", "Kuthunyelwe kumhlinzeki"),
+        ("Service", "Isevisi"),
+        ("Service Level Agreement", "Isivumelwano sezinga lesevisi"),
+        ("Services", "Izinsizakalo"),
+        ("Session Timeout", "Ukuphelelwa yisikhathi kweseshini"),
+        ("Severity", "Ubucayi"),
+        ("Showing {count} of {total} plans", "Kuboniswa {count} kwezingu-{total} izinhlelo"),
+        ("Showing {count} of {total} requisitions", "Kuboniswa {count} kwezingu-{total} izicelo"),
+        ("Significant", "Okubalulekile"),
+        ("Signing as", "Usayina njenge"),
+        ("Single Source", "Umthombo owodwa"),
+        ("Single Source - Only one supplier can provide the goods/services", "Umthombo owodwa - Umhlinzeki oyedwa kuphela ongahlinzeka ngempahla/izinsizakalo"),
+        ("Single Source Procurement", "Ukuthenga kumthombo owodwa"),
+        ("Size", "Usayizi"),
+        ("Size (Largest)", "Usayizi (omkhulu kakhulu)"),
+        ("Size (Smallest)", "Usayizi (omncane kakhulu)"),
+        ("SoD", "SoD"),
+        ("Software", "Isofthiwe"),
+        ("Sole Supplier", "Umhlinzeki oyedwa"),
+        ("Sole Supplier - Supplier has exclusive rights (patents, licenses)", "Umhlinzeki oyedwa - Umhlinzeki unamalungelo akhethekile (amalungelo obunikazi, amalayisense)"),
+        ("Sort By", "Hlunga ngo"),
+        ("Source", "Umthombo"),
+        ("Source Requisition", "Isicelo somthombo"),
+        ("Sources:", "Imithombo:"),
+        ("Sourcing", "Ukuthola"),
+        ("Special delivery instructions, access requirements, etc.", "Imiyalelo ekhethekile yokulethwa, izidingo zokungena, njll."),
+        ("Specifications", "Imininingwane"),
+        ("Spend", "Ukusetshenziswa kwemali"),
+        ("Spend Analytics", "Uhlaziyo lokusetshenziswa kwemali"),
+        ("Spend Targets", "Imigomo yokusetshenziswa kwemali"),
+        ("Spend by B-BBEE Level", "Ukusetshenziswa kwemali ngezinga le-B-BBEE"),
+        ("Spend by Category", "Ukusetshenziswa kwemali ngesigaba"),
+        ("Stable", "Kuzinzile"),
+        ("Stage", "Isigaba"),
+        ("Start Bid", "Ibhidi yokuqala"),
+        ("Start Date", "Usuku lokuqala"),
+        ("Start Scoring", "Qala ukunikeza amaphuzu"),
+        ("Starting Price", "Intengo yokuqala"),
+        ("Starting: {amount}", "Ukuqala: {amount}"),
+        ("Status", "Isimo"),
+        ("Status:", "Isimo:"),
+        ("Stock", "Isitoko"),
+        ("Strategic Objectives", "Izinhloso zamasu"),
+        ("Strategic Partnership", "Ubambiswano lwamasu"),
+        ("Strategic Partnership - Existing strategic relationship", "Ubambiswano lwamasu - Ubudlelwano bamasu obukhona"),
+        ("Street address", "Ikheli lomgwaqo"),
+        ("Submit", "Hambisa"),
+        ("Submit Action Plan", "Hambisa uhlelo lokusebenza"),
+        ("Submit Request", "Hambisa isicelo"),
+        ("Submit Scores", "Hambisa amaphuzu"),
+        ("Submit for Approval", "Hambisela imvume"),
+        ("Submit for Review", "Hambisela ukubuyekezwa"),
+        ("Subtotal", "Isamba esingaphansi"),
+        ("Subtotal:", "Isamba esingaphansi:"),
+        ("Success Rate", "Izinga lempumelelo"),
+        ("Summary", "Isifinyezo"),
+        ("Supplier", "Umhlinzeki"),
+        ("Supplier Details", "Imininingwane yomhlinzeki"),
+        ("Supplier Information", "Ulwazi lomhlinzeki"),
+        ("Supplier Invoice Number", "Inombolo ye-invoyisi yomhlinzeki"),
+        ("Supplier Name", "Igama lomhlinzeki"),
+        ("Supplier Performance", "Ukusebenza komhlinzeki"),
+        ("Supplier Performance Scores ({count} suppliers)", "Amaphuzu okusebenza kwabahlinzeki (abahlinzeki abangu-{count})"),
+        ("Supplier Registry", "Irejista yabahlinzeki"),
+        ("Supplier Risk Assessment", "Ukuhlolwa kobungozi bomhlinzeki"),
+        ("Supplier blacklisted", "Umhlinzeki usohlwini olumnyama"),
+        ("Supplier contact name", "Igama loxhumana naye komhlinzeki"),
+        ("Supplier invoices, three-way matching and payment aging", "Ama-invoyisi abahlinzeki, ukuqhathanisa kwezinhlangothi ezintathu nobudala bokukhokha"),
+        ("Supplier not in the registry", "Umhlinzeki akekho kurejista"),
+        ("Supplier suspended", "Umhlinzeki umisiwe"),
+        ("Supplier:", "Umhlinzeki:"),
+        ("Suppliers ({count} total)", "Abahlinzeki ({count} sebebonke)"),
+        ("Suppliers at Risk ({count} total)", "Abahlinzeki abasengozini ({count} sebebonke)"),
+        ("Supply Chain", "Uchungechunge lokuhlinzeka"),
+        ("Support", "Ukwesekwa"),
+        ("Support Email", "I-imeyili yokwesekwa"),
+        ("Support Phone", "Ifoni yokwesekwa"),
+        ("Supporting Documents", "Amadokhumenti asekelayo"),
+        ("Suspended", "Kumisiwe"),
+        ("Table", "Ithebula"),
+        ("Tags (comma-separated)", "Omaka (ahlukaniswe ngokhefana)"),
+        ("Target", "Umgomo"),
+        ("Target Date", "Usuku oluqondiwe"),
+        ("Tax (15%):", "Intela (15%):"),
+        ("Tax clearance certificate", "Isitifiketi semvume yentela"),
+        ("Technical", "Ezobuchwepheshe"),
+        ("Technical Requirements", "Izidingo zobuchwepheshe"),
+        ("Technical Score", "Amaphuzu obuchwepheshe"),
+        ("Technical Specifications", "Imininingwane yobuchwepheshe"),
+        ("Technical requirements and specifications", "Izidingo zobuchwepheshe nemininingwane"),
+        ("Template", "Isifanekiso"),
+        ("Tender", "Ithenda"),
+        ("Tender Details", "Imininingwane yethenda"),
+        ("Tender Documents", "Amadokhumenti ethenda"),
+        ("Tender Published", "Ithenda ishicilelwe"),
+        ("Tender Ref", "Inkomba yethenda"),
+        ("Tender Summary", "Isifinyezo sethenda"),
+        ("Tender Title", "Isihloko sethenda"),
+        ("Tender Type", "Uhlobo lwethenda"),
+        ("Tender Value", "Inani lethenda"),
+        ("Tenders ({count} total)", "Amathenda ({count} esewonke)"),
+        ("Tenders Pending Evaluation", "Amathenda alinde ukuhlolwa"),
+        ("Terminated", "Kunqanyuliwe"),
+        ("Termination Clause", "Isigatshana sokunqanyulwa"),
+        ("Terms", "Imibandela"),
+        ("The certificate expired on {expiry}", "Isitifiketi saphelelwa yisikhathi ngomhla ka-{expiry}"),
+        ("The original is only shown in the session it was uploaded in", "Okwasekuqaleni kuboniswa kuphela eseshini okulayishwe kuyo"),
+        ("Theme", "Itimu"),
+        ("There is no live auction currently available.", "Ayikho indali eqhubekayo etholakalayo njengamanje."),
+        ("Thinking...", "Kuyacatshangwa..."),
+        ("This Month", "Le nyanga"),
+        ("This Quarter", "Le kota"),
+        ("This invoice may duplicate", "Le invoyisi ingaba yimpinda ka"),
+        ("This tender must be approved before it can be published. Current status: ", "Le thenda kumele ivunywe ngaphambi kokuthi ishicilelwe. Isimo samanje: "),
+        ("Three-Way Match", "Ukuqhathanisa kwezinhlangothi ezintathu"),
+        ("Threshold", "Umkhawulo"),
+        ("Tick none to delegate every document type", "Ungamaki lutho ukuze udlulisele zonke izinhlobo zamadokhumenti"),
+        ("Time", "Isikhathi"),
+        ("Time Remaining", "Isikhathi esisele"),
+        ("Timeline", "Uhlelo lwesikhathi"),
+        ("Timeline & Milestones", "Uhlelo lwesikhathi nezigaba ezibalulekile"),
+        ("Timeline ({count} milestones)", "Uhlelo lwesikhathi (izigaba ezibalulekile ezingu-{count})"),
+        ("Timestamp", "Isitembu sesikhathi"),
+        ("Title", "Isihloko"),
+        ("To", "Kuya ku"),
+        ("To budget", "Kuya kwisabelomali"),
+        ("Today's Activity", "Umsebenzi wanamuhla"),
+        ("Top Performers", "Abasebenza kahle kakhulu"),
+        ("Top Performers (85%+)", "Abasebenza kahle kakhulu (85%+)"),
+        ("Top Performing Suppliers", "Abahlinzeki abasebenza kahle kakhulu"),
+        ("Top Suppliers by Spend", "Abahlinzeki abaphezulu ngokusetshenziswa kwemali"),
+        ("Total", "Isamba"),
+        ("Total Active Budget", "Isamba sesabelomali esisebenzayo"),
+        ("Total Allocated", "Isamba esabiwe"),
+        ("Total Amount:", "Isamba senani:"),
+        ("Total App Users", "Isamba sabasebenzisi bohlelo"),
+        ("Total Approved YTD", "Isamba esivunyiwe sonyaka kuze kube manje"),
+        ("Total Budget", "Isamba sesabelomali"),
+        ("Total Budget Amount", "Isamba senani lesabelomali"),
+        ("Total Checks", "Isamba sokuhlola"),
+        ("Total Contract Value", "Isamba senani lenkontileka"),
+        ("Total Contracts:", "Isamba sezinkontileka:"),
+        ("Total Controls", "Isamba solawulo"),
+        ("Total Documents", "Isamba samadokhumenti"),
+        ("Total Entries", "Isamba sokufakiwe"),
+        ("Total Evaluations", "Isamba sokuhlolwa"),
+        ("Total Findings", "Isamba sokutholakele"),
+        ("Total Functionality Weight: ", "Isamba sesisindo sokusebenza: "),
+        ("Total Items", "Isamba sezinto"),
+        ("Total Milestones", "Isamba sezigaba ezibalulekile"),
+        ("Total POs:", "Isamba sama-PO:"),
+        ("Total Reviews", "Isamba sokubuyekezwa"),
+        ("Total Risks", "Isamba sobungozi"),
+        ("Total Score", "Isamba samaphuzu"),
+        ("Total Spend", "Isamba semali esetshenzisiwe"),
+        ("Total Storage", "Isamba sesitoreji"),
+        ("Total Suppliers", "Isamba sabahlinzeki"),
+        ("Total Value", "Isamba senani"),
+        ("Total Value:", "Isamba senani:"),
+        ("Total Violations", "Isamba sokwephulwa"),
+        ("Total budget for this sourcing plan", "Isamba sesabelomali salolu hlelo lokuthola"),
+        ("Total of {total} should be {expected}", "Isamba esingu-{total} kufanele sibe ngu-{expected}"),
+        ("Total:", "Isamba:"),
+        ("Track all system activities and changes", "Landelela yonke imisebenzi yesistimu nezinguquko"),
+        ("Training", "Ukuqeqeshwa"),
+        ("Transfer", "Ukudluliswa"),
+        ("Transfers", "Ukudluliswa"),
+        ("Transport", "Ezokuthutha"),
+        ("Treasury", "UMgcinimafa"),
+        ("Trend", "Ukuthambekela"),
+        ("Try adjusting your filters or add a new item", "Zama ukulungisa izihlungi zakho noma wengeze into entsha"),
+        ("Try adjusting your filters or create a new contract", "Zama ukulungisa izihlungi zakho noma udale inkontileka entsha"),
+        ("Try adjusting your filters or create a new purchase order", "Zama ukulungisa izihlungi zakho noma udale i-oda elisha lokuthenga"),
+        ("Try adjusting your filters to see more results", "Zama ukulungisa izihlungi zakho ukuze ubone imiphumela eminingi"),
+        ("Try adjusting your search or filters", "Zama ukulungisa usesho lwakho noma izihlungi"),
+        ("Type", "Uhlobo"),
+        ("Tyre", "Ithayi"),
+        ("Unanimous", "Ngazwi linye"),
+        ("Under Review", "Kuyabuyekezwa"),
+        ("Underperforming", "Abangasebenzi kahle"),
+        ("Underperforming (<70%)", "Abangasebenzi kahle (<70%)"),
+        ("Unit", "Iyunithi"),
+        ("Unit Price", "Intengo yeyunithi"),
+        ("Unit Price (ZAR)", "Intengo yeyunithi (ZAR)"),
+        ("Unknown", "Akwaziwa"),
+        ("Unknown document", "Idokhumenti engaziwa"),
+        ("Unlikely", "Akunakwenzeka"),
+        ("Unqualified w/Findings", "Akunamibandela kodwa kunokutholakele"),
+        ("Until", "Kuze kube"),
+        ("Upcoming Deadlines", "Izinsuku zokugcina ezizayo"),
+        ("Upcoming Meetings", "Imihlangano ezayo"),
+        ("Upcoming Milestones", "Izigaba ezibalulekile ezizayo"),
+        ("Update", "Buyekeza"),
+        ("Updated", "Kubuyekeziwe"),
+        ("Upload", "Layisha"),
+        ("Upload Document", "Layisha idokhumenti"),
+        ("Upload Documents", "Layisha amadokhumenti"),
+        ("Upload Supporting Documents", "Layisha amadokhumenti asekelayo"),
+        ("Upload a scanned document to get started", "Layisha idokhumenti eskeniwe ukuze uqale"),
+        ("Upload at most", "Layisha okungenani okuphezulu okungu"),
+        ("Upload documents or adjust your filters", "Layisha amadokhumenti noma ulungise izihlungi zakho"),
+        ("Upload quotations, market research, supplier documentation", "Layisha izilinganiso zamanani, ucwaningo lwemakethe, amadokhumenti omhlinzeki"),
+        ("Upload specifications, terms, and other tender documents (PDF, Word, Excel)", "Layisha imininingwane, imibandela namanye amadokhumenti ethenda (PDF, Word, Excel)"),
+        ("Upload supporting documents such as specifications, quotes, or budget approvals.", "Layisha amadokhumenti asekelayo afana nemininingwane, izilinganiso zamanani noma izimvume zesabelomali."),
+        ("Uploaded By", "Kulayishwe ngu"),
+        ("Uploaded Documents", "Amadokhumenti alayishiwe"),
+        ("User", "Umsebenzisi"),
+        ("User ID", "I-ID yomsebenzisi"),
+        ("User Information", "Ulwazi lomsebenzisi"),
+        ("Users", "Abasebenzisi"),
+        ("Utilisation", "Ukusetshenziswa"),
+        ("VAT", "I-VAT"),
+        ("VAT (15%):", "I-VAT (15%):"),
+        ("VAT:", "I-VAT:"),
+        ("Valid B-BBEE certificate", "Isitifiketi se-B-BBEE esisebenzayo"),
+        ("Valid until {expiry}", "Kusebenza kuze kube ngu-{expiry}"),
+        ("Value", "Inani"),
+        ("Value:", "Inani:"),
+        ("Variance", "Umehluko"),
+        ("Variation", "Ukuguquguquka"),
+        ("Venue", "Indawo"),
+        ("Verified", "Kuqinisekisiwe"),
+        ("Version", "Inguqulo"),
+        ("Version History", "Umlando wezinguqulo"),
+        ("View", "Buka"),
+        ("View All", "Buka konke"),
+        ("View All Auctions", "Buka zonke izindali"),
+        ("View Contract", "Buka inkontileka"),
+        ("View Details", "Buka imininingwane"),
+        ("View List", "Buka uhlu"),
+        ("View invoice", "Buka i-invoyisi"),
+        ("View on e-Tender Portal", "Buka kuphothali ye-e-Tender"),
+        ("Violations", "Ukwephulwa"),
+        ("Violations by Type", "Ukwephulwa ngohlobo"),
+        ("Virements between budget lines for FY", "Ukudluliswa phakathi kwemigqa yesabelomali yonyaka wezimali"),
+        ("Votes", "Amavoti"),
+        ("Waived", "Kuxolelwe"),
+        ("Warehouse", "Indlu yokugcina impahla"),
+        ("Warning: Category allocations exceed total budget by ", "Isexwayiso: Ukwabiwa kwezigaba kudlula isamba sesabelomali ngo "),
+        ("Warranty Period (months)", "Isikhathi sewaranti (izinyanga)"),
+        ("Weight %", "Isisindo %"),
+        ("Weight: {weight}%", "Isisindo: {weight}%"),
+        ("Western Cape", "Ntshonalanga Kapa"),
+        ("What alternatives were considered? Why were they not suitable?", "Yiziphi ezinye izindlela ezacatshangwa? Kungani zazingafanelekile?"),
+        ("Why This Supplier", "Kungani lo mhlinzeki"),
+        ("Why the funds are needed and why the source budget can release them", "Kungani izimali zidingeka nokuthi kungani isabelomali somthombo singazikhipha"),
+        ("Why the run is being cancelled", "Kungani umjikelezo ukhanselwa"),
+        ("Why the variance is acceptable, or what the supplier must correct", "Kungani umehluko wamukeleka, noma yini umhlinzeki okufanele ayilungise"),
+        ("Within SLA", "Ngaphakathi kwe-SLA"),
+        ("Within tolerance", "Ngaphakathi kokubekezelela"),
+        ("Word", "Word"),
+        ("Works", "Imisebenzi"),
+        ("YTD Revenue", "Imali engenayo yonyaka kuze kube manje"),
+        ("YTD Spend", "Imali esetshenzisiwe yonyaka kuze kube manje"),
+        ("You are offline. Showing the data saved on this device.", "Awuxhunyiwe. Kuboniswa idatha elondolozwe kule divayisi."),
+        ("You are the leading bidder!", "Nguwe ohola phambili!"),
+        ("You have been outbid. Your last bid: R {bid}", "Udluliwe ngebhidi. Ibhidi yakho yokugcina: R {bid}"),
+        ("You have unsaved changes", "Unezinguquko ezingalondoloziwe"),
+        ("Your role does not have permission to view this page. Contact your system administrator if you need access.", "Indima yakho ayinayo imvume yokubuka leli khasi. Xhumana nomphathi wesistimu uma udinga ukufinyelela."),
+        ("all must approve", "bonke kumele bavume"),
+        ("another user", "omunye umsebenzisi"),
+        ("are past the", "sekudlule i"),
+        ("at a time", "ngesikhathi esisodwa"),
+        ("available", "kuyatholakala"),
+        ("business day(s)", "usuku/izinsuku zomsebenzi"),
+        ("day payment term", "isikhathi sokukhokha sezinsuku"),
+        ("days after the invoice is received", "izinsuku ngemva kokwamukelwa kwe-invoyisi"),
+        ("days overdue", "izinsuku ezedlule"),
+        ("detected automatically", "kutholwe ngokuzenzakalela"),
+        ("documents at a time", "amadokhumenti ngesikhathi esisodwa"),
+        ("e-Tender Portal", "Iphothali ye-e-Tender"),
+        ("e.g. Annual leave", "isb. Ikhefu lonyaka"),
+        ("e.g. important, q1, project-x", "isb. kubalulekile, q1, iphrojekthi-x"),
+        ("e.g., 12", "isb., 12"),
+        ("e.g., 24", "isb., 24"),
+        ("e.g., 30", "isb., 30"),
+        ("e.g., 30 days from invoice", "isb., izinsuku ezingu-30 kusukela ku-invoyisi"),
+        ("e.g., 36 months", "isb., izinyanga ezingu-36"),
+        ("e.g., 4", "isb., 4"),
+        ("e.g., 99.9", "isb., 99.9"),
+        ("e.g., Annual Procurement Plan FY 2025/26", "isb., Uhlelo Lonyaka Lokuthenga 2025/26"),
+        ("e.g., Arbitration", "isb., Ukulamula"),
+        ("e.g., CC-IT-001", "isb., CC-IT-001"),
+        ("e.g., CTR-2025-0001", "isb., CTR-2025-0001"),
+        ("e.g., Each, Months", "isb., Ngayinye, Izinyanga"),
+        ("e.g., Head Office, Johannesburg", "isb., Ihhovisi elikhulu, eGoli"),
+        ("e.g., IT-LAP-001", "isb., IT-LAP-001"),
+        ("e.g., PFMA/2025/IT/001", "isb., PFMA/2025/IT/001"),
+        ("e.g., REQ-2025-0001", "isb., REQ-2025-0001"),
+        ("e.g., South African Law", "isb., Umthetho waseNingizimu Afrika"),
+        ("e.g., TND-2025-0001", "isb., TND-2025-0001"),
+        ("e.g., Technical Capability", "isb., Amandla obuchwepheshe"),
+        ("each", "ngayinye"),
+        ("excluded", "akufakiwe"),
+        ("field(s) to check", "inkambu/izinkambu okufanele zihlolwe"),
+        ("held as a possible duplicate", "kubanjwe njengempinda engenzeka"),
+        ("invoiced", "kufakwe ku-invoyisi"),
+        ("invoices", "ama-invoyisi"),
+        ("more authorisation(s) needed from someone other than", "kudingeka okunye ukugunyazwa komunye ongeyena"),
+        ("of", "ku"),
+        ("page(s)", "ikhasi/amakhasi"),
+        ("submitted for approval:", "kuhanjiselwe imvume:"),
+        ("suppliers", "abahlinzeki"),
+        ("type chosen on upload", "uhlobo olukhethwe lapho kulayishwa"),
+        ("until", "kuze kube"),
+        ("up to", "kuze kufike ku"),
+        ("{entity} not found", "{entity} akutholakalanga"),
+        ("{entity} {id} could not be loaded: {error}", "{entity} {id} akukwazanga ukulayishwa: {error}"),
+        ("{entity} {id} does not exist or you no longer have access to it.", "{entity} {id} akukho noma awusakwazi ukukufinyelela."),
+        ("\"{level}\" is not a level from 1 to 8", "\"{level}\" akulona izinga elisuka ku-1 kuya ku-8"),
+        ("(empty)", "(akukho lutho)"),
+        ("31-60 days", "izinsuku ezingu-31-60"),
+        ("61-90 days", "izinsuku ezingu-61-90"),
+        ("90+ days", "izinsuku ezingu-90+"),
+        ("Bulk EFT (fixed width)", "I-EFT yenqwaba (ububanzi obungaguquki)"),
+        ("AI Analysis", "Ukuhlaziya kwe-AI"),
+        ("AI {category} Suggestions", "Iziphakamiso ze-AI: {category}"),
+        ("Abstain", "Ukungavoti"),
+        ("Accept", "Yamukela"),
+        ("Accept Variance", "Yamukela umehluko"),
+        ("Actual: {value}", "Okwangempela: {value}"),
+        ("Ad Hoc", "Okwesikhashana"),
+        ("Adverse Opinion", "Umbono ongemuhle"),
+        ("Advisory Member", "Ilungu elieluleka"),
+        ("Against", "Uyaphikisa"),
+        ("All Receipts", "Zonke izamukelo"),
+        ("All incoming deliveries have been processed", "Konke ukulethwa okungenayo sekucutshunguliwe"),
+        ("Analyze {entity} {id} for completeness and compliance.", "Hlaziya u-{entity} {id} ukuthi uphelele futhi uyahambisana yini nemithetho."),
+        ("Android", "Android"),
+        ("Android Phone", "Ifoni ye-Android"),
+        ("Android Tablet", "Ithebhulethi ye-Android"),
+        ("Annexure", "Isijobelelo"),
+        ("Annually", "Minyaka yonke"),
+        ("Anti-Corruption", "Ukulwa nenkohlakalo"),
+        ("Approval due", "Ukugunyazwa kufanele"),
+        ("Approval escalated", "Ukugunyazwa kudluliselwe phezulu"),
+        ("Asset Management", "Ukuphathwa kwempahla"),
+        ("Auto-extend: Auction extends by {minutes} minutes if bid placed in last {seconds} seconds", "Ukwelula ngokuzenzakalela: indali yeluleka ngemizuzu engu-{minutes} uma kwenziwa ibhidi emizuzwaneni engu-{seconds} yokugcina"),
+        ("Avoid", "Gwema"),
+        ("Awaiting Signature", "Kulindwe isiginesha"),
+        ("Award Letter", "Incwadi yokuklonyeliswa"),
+        ("B-BBEE Certificate", "Isitifiketi se-B-BBEE"),
+        ("B-BBEE Supplier Classifications ({count} total)", "Izigaba zabahlinzeki be-B-BBEE (ezingu-{count} sezizonke)"),
+        ("B-BBEE Verification", "Ukuqinisekiswa kwe-B-BBEE"),
+        ("B-BBEE level", "Izinga le-B-BBEE"),
+        ("Below Target", "Ngaphansi kwethagethi"),
+        ("Bid Document", "Idokhumenti yebhidi"),
+        ("Bid Management", "Ukuphathwa kwamabhidi"),
+        ("Bid Status Alerts", "Izexwayiso zesimo sebhidi"),
+        ("Bid Submission", "Ukuhanjiswa kwebhidi"),
+        ("Bid Submitted", "Ibhidi ihanjisiwe"),
+        ("Biometric Authentication", "Ukuqinisekisa nge-biometric"),
+        ("Black Owned (51%+)", "Okungokwabamnyama (51%+)"),
+        ("Black Women Owned (30%+)", "Okungokwabesifazane abamnyama (30%+)"),
+        ("Briefing Check-In", "Ukubhalisa emhlanganweni wolwazi"),
+        ("Budget Transfer", "Ukudluliswa kwesabelomali"),
+        ("Budgets, commitments and spend for FY {year}", "Izabelomali, izibopho nokusetshenziswa konyaka wezimali {year}"),
+        ("CSV", "CSV"),
+        ("Capital Expenditure", "Izindleko zenhlokomali"),
+        ("Certificate expiry", "Ukuphelelwa yisikhathi kwesitifiketi"),
+        ("Certificate period", "Isikhathi sesitifiketi"),
+        ("Chairperson", "Usihlalo"),
+        ("Check in to mandatory briefing sessions using GPS location", "Bhalisa emihlanganweni yolwazi eyimpoqo usebenzisa indawo ye-GPS"),
+        ("Closing Soon", "Kuvala maduze"),
+        ("Closure", "Ukuvalwa"),
+        ("Company Registration", "Ukubhaliswa kwenkampani"),
+        ("Completed Receipts", "Izamukelo eziqediwe"),
+        ("Compliance Report", "Umbiko wokuthobela"),
+        ("Comprehensive", "Okuphelele"),
+        ("Concern", "Ukukhathazeka"),
+        ("Confirm deliveries with photo evidence and timestamps", "Qinisekisa ukulethwa ngobufakazi bezithombe nezitembu zesikhathi"),
+        ("Conflict of Interest", "Ukungqubuzana kwezithakazelo"),
+        ("Continuous", "Okuqhubekayo"),
+        ("Contract Detail", "Imininingwane yenkontileka"),
+        ("Contract Extension", "Ukwelulwa kwenkontileka"),
+        ("Contract Reminders", "Izikhumbuzo zenkontileka"),
+        ("Contract Review", "Ukubuyekezwa kwenkontileka"),
+        ("Contract Variation", "Ukushintshwa kwenkontileka"),
+        ("Create a new procurement tender", "Dala ithenda entsha yokuthenga"),
+        ("Create a new purchase order", "Dala i-oda lokuthenga elisha"),
+        ("Create a new supplier contract", "Dala inkontileka entsha yomhlinzeki"),
+        ("Current (0-30 days)", "Okwamanje (izinsuku ezingu-0-30)"),
+        ("Current: {value}", "Okwamanje: {value}"),
+        ("Cybersecurity", "Ukuphepha kwe-inthanethi"),
+        ("Daily", "Nsuku zonke"),
+        ("Deadline: {date}", "Umnqamulajuqu: {date}"),
+        ("Deferred", "Kuhlehlisiwe"),
+        ("Delayed", "Kubambezelekile"),
+        ("Delivery Confirmation", "Ukuqinisekiswa kokulethwa"),
+        ("Delivery date", "Usuku lokulethwa"),
+        ("Deputy Chairperson", "Iphini likasihlalo"),
+        ("Designated Group", "Iqembu eliqokiwe"),
+        ("Detailed", "Okunemininingwane"),
+        ("Deviation request {reference} created successfully. It will be routed for approval.", "Isicelo sokuphambuka {reference} sidalwe ngempumelelo. Sizothunyelwa ukuze sigunyazwe."),
+        ("Digital Signature", "Isiginesha yedijithali"),
+        ("Disability Owned", "Okungokwabantu abakhubazekile"),
+        ("Disclaimer of Opinion", "Ukwenqaba ukunikeza umbono"),
+        ("Document Scanner", "Iskena samadokhumenti"),
+        ("Document Scanning", "Ukuskena amadokhumenti"),
+        ("Document type", "Uhlobo lwedokhumenti"),
+        ("Due date", "Usuku lokukhokha"),
+        ("Duplicate invoice", "I-invoyisi ephindiwe"),
+        ("EME (< R10m)", "EME (< R10m)"),
+        ("Edit Contract", "Hlela inkontileka"),
+        ("Edit Purchase Order", "Hlela i-oda lokuthenga"),
+        ("Edit Requisition", "Hlela isicelo sokuthenga"),
+        ("Edit Tender", "Hlela ithenda"),
+        ("Enterprise & Supplier Development", "Ukuthuthukiswa kwamabhizinisi nabahlinzeki"),
+        ("Enterprise Development", "Ukuthuthukiswa kwamabhizinisi"),
+        ("Environmental", "Okwemvelo"),
+        ("Escalate", "Dlulisela phezulu"),
+        ("Evaluation Report", "Umbiko wokuhlola"),
+        ("Events", "Imicimbi"),
+        ("Exceeded", "Kweqiwe"),
+        ("Exempted Micro Enterprise", "Ibhizinisi elincane kakhulu elikhululiwe"),
+        ("Experience", "Isipiliyoni"),
+        ("Fail", "Wehlulekile"),
+        ("Finance Representative", "Ummeleli wezezimali"),
+        ("Financial Analysis", "Ukuhlaziywa kwezimali"),
+        ("Financial Proposal", "Isiphakamiso sezezimali"),
+        ("Financial Statements", "Izitatimende zezimali"),
+        ("Follow-up", "Ukulandelela"),
+        ("Follow-up Audit", "Ukucwaningwa kokulandelela"),
+        ("For", "Uyavuma"),
+        ("Format", "Ifomethi"),
+        ("Fraud & Irregularities", "Ukukhwabanisa nokungahambi ngomthetho"),
+        ("Full Match", "Kufana ngokuphelele"),
+        ("Fully Automated", "Okuzenzakalelayo ngokuphelele"),
+        ("Generic (> R50m)", "Elijwayelekile (> R50m)"),
+        ("Generic Enterprise", "Ibhizinisi elijwayelekile"),
+        ("Get instant alerts when your bid status changes during evaluation", "Thola izexwayiso ngokushesha uma isimo sebhidi yakho sishintsha ngesikhathi sokuhlolwa"),
+        ("Governance", "Ukuphatha"),
+        ("Hold for Investigation", "Misa ukuze kuphenywe"),
+        ("IP: {address}", "IP: {address}"),
+        ("IT", "IT"),
+        ("IT & Technology", "IT nobuchwepheshe"),
+        ("Identified exposure", "Ubungozi obutholakele"),
+        ("Implemented", "Kusetshenzisiwe"),
+        ("In Review", "Kuyabuyekezwa"),
+        ("Info Required", "Kudingeka ulwazi"),
+        ("Initial Finding", "Okutholakele kokuqala"),
+        ("Interim Audit", "Ukucwaningwa kwesikhashana"),
+        ("Internal Controls", "Izilawuli zangaphakathi"),
+        ("Internal Policy", "Inqubomgomo yangaphakathi"),
+        ("Investigate", "Phenya"),
+        ("Invoice date", "Usuku lwe-invoyisi"),
+        ("Item on order", "Into ekwi-oda"),
+        ("Labour Law", "Umthetho wezabasebenzi"),
+        ("Last 12 Months", "Izinyanga ezingu-12 ezedlule"),
+        ("Legal", "Okwezomthetho"),
+        ("Legal Advisor", "Umeluleki wezomthetho"),
+        ("Legal Opinion", "Umbono wezomthetho"),
+        ("Level 4", "Izinga 4"),
+        ("Level 5", "Izinga 5"),
+        ("Level 6", "Izinga 6"),
+        ("Level 7", "Izinga 7"),
+        ("Level 8", "Izinga 8"),
+        ("Line items", "Izinto zomugqa"),
+        ("Live Auction", "Indali ebukhoma"),
+        ("Local Content", "Okuqukethwe kwasekhaya"),
+        ("MFMA", "MFMA"),
+        ("Maintenance & Repairs", "Ukulungisa nokunakekela"),
+        ("Management Control", "Ukulawulwa kwabaphathi"),
+        ("Manual", "Ngesandla"),
+        ("Military Veteran Owned", "Okungokomakadebona bamasosha"),
+        ("Min Decrement: {amount}", "Ukwehla okuncane: {amount}"),
+        ("Mobile Invoicing", "Ama-invoyisi eselula"),
+        ("Monitoring", "Ukuqapha"),
+        ("Monthly", "Nyanga zonke"),
+        ("Most Popular", "Okuthandwa kakhulu"),
+        ("Motivation Memo", "Imemo yokugqugquzela"),
+        ("NT Regulations", "Imithethonqubo ye-NT"),
+        ("No CSD number", "Ayikho inombolo ye-CSD"),
+        ("No Match", "Akufani"),
+        ("No completed receipts", "Azikho izamukelo eziqediwe"),
+        ("No goods receipts found", "Azitholakalanga izamukelo zempahla"),
+        ("No pending receipts", "Azikho izamukelo ezilindile"),
+        ("No receipts have been completed yet", "Azikho izamukelo eseziqediwe"),
+        ("Not Matched", "Akufaniswanga"),
+        ("Not Operating", "Akusebenzi"),
+        ("Not Started", "Akuqalile"),
+        ("Not required (value below R10,000,000)", "Akudingeki (inani lingaphansi kuka-R10,000,000)"),
+        ("Not required (value below R2,000,000)", "Akudingeki (inani lingaphansi kuka-R2,000,000)"),
+        ("Not required (value below R500,000)", "Akudingeki (inani lingaphansi kuka-R500,000)"),
+        ("Notified", "Kwaziswe"),
+        ("Offline Bid Preparation", "Ukulungiselela ibhidi ngaphandle kwe-inthanethi"),
+        ("Operational", "Okokusebenza"),
+        ("Operational Expenditure", "Izindleko zokusebenza"),
+        ("Ordinary", "Okujwayelekile"),
+        ("PDF", "PDF"),
+        ("PFMA", "PFMA"),
+        ("PO exists", "I-oda lokuthenga likhona"),
+        ("POPIA", "POPIA"),
+        ("PPPFA", "PPPFA"),
+        ("Partial Match", "Kufana kancane"),
+        ("Partially Compliant", "Kuhambisana kancane"),
+        ("Partially Operating", "Kusebenza kancane"),
+        ("Participate in reverse auctions in real-time from your mobile device", "Hlanganyela ezindalini ezihlehlayo ngesikhathi sangempela usebenzisa idivayisi yakho yeselula"),
+        ("Pass", "Uphumelele"),
+        ("Pending Action", "Kulindwe isenzo"),
+        ("Pending Audit", "Kulindwe ukucwaningwa"),
+        ("Pending Receipts", "Izamukelo ezilindile"),
+        ("Performance Audit", "Ukucwaningwa kokusebenza"),
+        ("Performance Information", "Ulwazi lokusebenza"),
+        ("Personnel Costs", "Izindleko zabasebenzi"),
+        ("Place Bid", "Faka ibhidi"),
+        ("Placing Bid...", "Ifaka ibhidi..."),
+        ("Planned", "Kuhleliwe"),
+        ("Please enter a title", "Sicela ufake isihloko"),
+        ("Please enter a valid amount", "Sicela ufake inani elivumelekile"),
+        ("Please enter supplier name", "Sicela ufake igama lomhlinzeki"),
+        ("Please provide justification", "Sicela unikeze isizathu"),
+        ("Please select a closing date", "Sicela ukhethe usuku lokuvala"),
+        ("Please select a publication date", "Sicela ukhethe usuku lokushicilela"),
+        ("Postponed", "Kuhlehlisiwe"),
+        ("Preferential Procurement", "Ukuthenga okukhethekile"),
+        ("Prepare bid documents offline and sync when connected", "Lungiselela amadokhumenti ebhidi ngaphandle kwe-inthanethi bese uvumelanisa uma uxhunyiwe"),
+        ("Preview: {name}", "Buka kuqala: {name}"),
+        ("Priced from the catalogue", "Intengo ivela kukhathalogi"),
+        ("Procedural", "Okwenqubo"),
+        ("Process", "Cubungula"),
+        ("Process Recommendation", "Isincomo senqubo"),
+        ("Publish to Portal", "Shicilela kuphothali"),
+        ("Publishing...", "Iyashicilela..."),
+        ("QR Code Scanning", "Ukuskena amakhodi e-QR"),
+        ("QSE (R10m - R50m)", "QSE (R10m - R50m)"),
+        ("Qualified Opinion", "Umbono onemibandela"),
+        ("Qualifying Small Enterprise", "Ibhizinisi elincane elifanelekayo"),
+        ("Quantity outstanding", "Inani elisalindile"),
+        ("Quarterly", "Ngekota"),
+        ("Receive push notifications when new tenders matching your profile are published", "Thola izaziso uma kushicilelwa amathenda amasha afana nephrofayela yakho"),
+        ("Recurring", "Okuphindaphindayo"),
+        ("Recused", "Uzihoxisile"),
+        ("Reduce", "Nciphisa"),
+        ("Registration number format", "Ifomethi yenombolo yokubhalisa"),
+        ("Regularity Audit", "Ukucwaningwa kokuhambisana nemithetho"),
+        ("Regulatory", "Okomthethonqubo"),
+        ("Regulatory Guidance", "Isiqondiso somthethonqubo"),
+        ("Reject Invoice", "Yenqaba i-invoyisi"),
+        ("Reminders for upcoming contract milestones, renewals, and deliverables", "Izikhumbuzo zamagxathu enkontileka azayo, ukuvuselelwa nokulethwayo"),
+        ("Reputational", "Okwedumela"),
+        ("Request Credit Note", "Cela inothi lesikweletu"),
+        ("Request Information", "Cela ulwazi"),
+        ("Required field", "Inkambu edingekayo"),
+        ("Required for values above R10,000,000", "Kuyadingeka kumanani angaphezu kuka-R10,000,000"),
+        ("Required for values above R2,000,000", "Kuyadingeka kumanani angaphezu kuka-R2,000,000"),
+        ("Required for values above R500,000", "Kuyadingeka kumanani angaphezu kuka-R500,000"),
+        ("Required to ratify emergency procurement", "Kuyadingeka ukuze kuqinisekiswe ukuthenga okuphuthumayo"),
+        ("Retired", "Kuyekiwe"),
+        ("Return for Info", "Buyisela ukuze kunikezwe ulwazi"),
+        ("Returned for Info", "Kubuyiselwe ukuze kunikezwe ulwazi"),
+        ("Review Required", "Kudingeka ukubuyekezwa"),
+        ("Risk Assessment", "Ukuhlolwa kobungozi"),
+        ("Rural", "Emaphandleni"),
+        ("Rural/Township Based", "Emaphandleni/ezilokishini"),
+        ("SCM Regulations", "Imithethonqubo ye-SCM"),
+        ("Save Configuration", "Londoloza ukumiswa"),
+        ("Save Features", "Londoloza izici"),
+        ("Saving...", "Iyalondoloza..."),
+        ("Scan QR codes on purchase orders for quick goods receipt", "Skena amakhodi e-QR kuma-oda okuthenga ukuze wamukele impahla ngokushesha"),
+        ("Scan and upload documents directly from your device camera", "Skena futhi ulayishe amadokhumenti ngqo usebenzisa ikhamera yedivayisi yakho"),
+        ("Secretary", "Unobhala"),
+        ("Segregation of Duties", "Ukuhlukaniswa kwemisebenzi"),
+        ("Semi-Automated", "Okuzenzakalelayo kancane"),
+        ("Shortlisted", "Kusohlwini olufushane"),
+        ("Sign contracts electronically using your mobile device", "Sayina izinkontileka ngogesi usebenzisa idivayisi yakho yeselula"),
+        ("Skills Development", "Ukuthuthukiswa kwamakhono"),
+        ("Skipped", "Kweqiwe"),
+        ("Socio-Economic Development", "Ukuthuthukiswa kwezenhlalo nomnotho"),
+        ("Sourcing Plan", "Uhlelo lokuthola"),
+        ("Special", "Okukhethekile"),
+        ("Special Audit", "Ukucwaningwa okukhethekile"),
+        ("Spend Analysis", "Ukuhlaziywa kokusetshenziswa kwemali"),
+        ("Standard", "Okujwayelekile"),
+        ("Strategic", "Okwamasu"),
+        ("Submit and track invoices directly from the mobile app", "Hambisa futhi ulandelele ama-invoyisi ngqo ku-app yeselula"),
+        ("Submitting...", "Iyahambisa..."),
+        ("Supplier Development", "Ukuthuthukiswa kwabahlinzeki"),
+        ("Supplier exists", "Umhlinzeki ukhona"),
+        ("Supplier matches PO", "Umhlinzeki ufana ne-oda lokuthenga"),
+        ("Supply Chain Management", "Ukuphathwa kochungechunge lokuhlinzeka"),
+        ("Sworn Affidavit", "Isifungo"),
+        ("Target: {value}", "Ithagethi: {value}"),
+        ("Tax Clearance", "Ukugunyazwa kwentela"),
+        ("Technical Advisor", "Umeluleki wezobuchwepheshe"),
+        ("Technical Proposal", "Isiphakamiso sobuchwepheshe"),
+        ("Technical Report", "Umbiko wobuchwepheshe"),
+        ("Tender Analysis", "Ukuhlaziywa kwethenda"),
+        ("Tender Detail", "Imininingwane yethenda"),
+        ("Tender Document Analysis", "Ukuhlaziywa kwedokhumenti yethenda"),
+        ("Tender Notifications", "Izaziso zamathenda"),
+        ("Tender Specification", "Imininingwane edingekayo yethenda"),
+        ("Tender successfully published to e-Tender portal. Reference: {reference}", "Ithenda ishicilelwe ngempumelelo kuphothali ye-e-Tender. Inkomba: {reference}"),
+        ("Terms & Conditions", "Imigomo nemibandela"),
+        ("Text", "Umbhalo"),
+        ("The delivery is dated in the future", "Ukulethwa kunosuku olusesikhathini esizayo"),
+        ("The document type could not be determined", "Uhlobo lwedokhumenti aluzange lutholakale"),
+        ("The due date is not after the invoice date", "Usuku lokukhokha aluwona ngemva kosuku lwe-invoyisi"),
+        ("The invoice is dated in the future", "I-invoyisi inosuku olusesikhathini esizayo"),
+        ("This month", "Kule nyanga"),
+        ("Threshold Breach", "Ukweqa umkhawulo"),
+        ("Total calculation", "Ukubalwa kwesamba"),
+        ("Total is the subtotal plus VAT", "Isamba siyisamba esingaphansi kanye ne-VAT"),
+        ("Transaction", "Okwenziwayo"),
+        ("Travel & Subsistence", "Ukuhamba nokuziphilisa"),
+        ("Try adjusting your filters or create a new goods receipt", "Zama ukulungisa izihlungi zakho noma udale isamukelo sempahla esisha"),
+        ("Type (A-Z)", "Uhlobo (A-Z)"),
+        ("Type (Z-A)", "Uhlobo (Z-A)"),
+        ("Under Evaluation", "Kuyahlolwa"),
+        ("Under Investigation", "Kuyaphenywa"),
+        ("Unqualified with Findings", "Ngaphandle kwemibandela, kunokutholakele"),
+        ("Unsuccessful", "Akuphumelelanga"),
+        ("Unverified", "Akuqinisekisiwe"),
+        ("Update Needed", "Kudingeka ukubuyekezwa"),
+        ("Update contract details", "Buyekeza imininingwane yenkontileka"),
+        ("Update purchase order details", "Buyekeza imininingwane ye-oda lokuthenga"),
+        ("Update tender details", "Buyekeza imininingwane yethenda"),
+        ("Use fingerprint or face recognition for secure authentication", "Sebenzisa izigxivizo zeminwe noma ukubonwa kobuso ukuze uqinisekise ngokuphephile"),
+        ("VAT calculation", "Ukubalwa kwe-VAT"),
+        ("VAT is {rate}% of the subtotal", "I-VAT ingu-{rate}% wesamba esingaphansi"),
+        ("VAT number format", "Ifomethi yenombolo ye-VAT"),
+        ("VAT of {vat} should be {expected}", "I-VAT engu-{vat} kufanele ibe ngu-{expected}"),
+        ("Verification", "Ukuqinisekisa"),
+        ("Verify", "Qinisekisa"),
+        ("Voting Member", "Ilungu elivotayo"),
+        ("Warning", "Isexwayiso"),
+        ("Weekly", "Masonto onke"),
+        ("Withdrawn", "Kuhoxisiwe"),
+        ("You", "Wena"),
+        ("Youth", "Intsha"),
+        ("Youth Owned", "Okungokwentsha"),
+        ("achieved", "kufinyelelwe"),
+        ("assessed", "kuhloliwe"),
+        ("bonus", "ibhonasi"),
+        ("draft goods receipt", "isamukelo sempahla esiwuhlaka"),
+        ("draft invoice", "i-invoyisi ewuhlaka"),
+        ("faster than target", "ngokushesha kunethagethi"),
+        ("from last month", "kusukela enyangeni edlule"),
+        ("from last quarter", "kusukela kwikota edlule"),
+        ("from last year", "kusukela onyakeni odlule"),
+        ("iOS", "iOS"),
+        ("iPad", "iPad"),
+        ("iPhone", "iPhone"),
+        ("in last 30 days", "ezinsukwini ezingu-30 ezedlule"),
+        ("new this month", "okusha kule nyanga"),
+        ("non-compliant", "akuhambisani"),
+        ("of budget", "wesabelomali"),
+        ("of total spend", "wokusetshenziswa kwemali konke"),
+        ("of users", "wabasebenzisi"),
+        ("of {points} points", "kwamaphuzu angu-{points}"),
+        ("remaining", "okusele"),
+        ("require attention", "kudinga ukunakwa"),
+        ("same amount within 7 days", "inani elifanayo phakathi kwezinsuku ezingu-7"),
+        ("same invoice number", "inombolo ye-invoyisi efanayo"),
+        ("similar invoice number", "inombolo ye-invoyisi efanayo cishe"),
+        ("supplier certificate", "isitifiketi somhlinzeki"),
+        ("target", "ithagethi"),
+        ("total value", "inani lilonke"),
+        ("under budget", "ngaphansi kwesabelomali"),
+        ("vs budget", "uma kuqhathaniswa nesabelomali"),
+        ("{change} was not applied: {reason}", "{change} akusetshenziswanga: {reason}"),
+        ("{completed}/{planned} tenders", "amathenda angu-{completed}/{planned}"),
+        ("{count} open risk indicators - Risk Score: {score}", "Izinkomba zobungozi ezivulekile ezingu-{count} - Amaphuzu obungozi: {score}"),
+        ("{date} at {time}", "{date} ngo-{time}"),
+        ("{date} by {name}", "{date} ngu-{name}"),
+        ("{days} days", "izinsuku ezingu-{days}"),
+        ("{days} days (per Treasury Regulations)", "izinsuku ezingu-{days} (ngokwemithethonqubo kaMgcinimafa)"),
+        ("{days} days (per Treasury Regulations); closes no earlier than {date}", "izinsuku ezingu-{days} (ngokwemithethonqubo kaMgcinimafa); ivala kungakapheli u-{date}"),
+        ("{done} of {total} milestones", "amagxathu angu-{done} kwangu-{total}"),
+        ("{field} \"{value}\" is not a valid value", "{field} \"{value}\" akulona inani elivumelekile"),
+        ("{field} was not found", "{field} akutholakalanga"),
+        ("{item} is not on {po_number}", "{item} akukho ku-{po_number}"),
+        ("{name} ({code}) at {percent} utilisation", "{name} ({code}) kusetshenziswe u-{percent}"),
+        ("{name} ({code}) exceeds its budget by {amount}", "{name} ({code}) seqe isabelomali saso ngo-{amount}"),
+        ("{name} is in the supplier registry", "{name} usohlwini lwabahlinzeki"),
+        ("{name} is not in the supplier registry", "{name} akekho ohlwini lwabahlinzeki"),
+        ("{number} has not been registered", "{number} ayikabhaliswa"),
+        ("{number} is a valid CIPC number", "{number} iyinombolo ye-CIPC evumelekile"),
+        ("{number} is a valid VAT number", "{number} iyinombolo ye-VAT evumelekile"),
+        ("{number} is already registered as {invoice_id}", "{number} isivele ibhaliswe njengo-{invoice_id}"),
+        ("{number} is not a 10-digit VAT number starting with 4", "{number} akuyona inombolo ye-VAT enamadijithi angu-10 eqala ngo-4"),
+        ("{number} is not in the form YYYY/NNNNNN/NN", "{number} ayikho ngefomu elithi YYYY/NNNNNN/NN"),
+        ("{percent} approval rate", "izinga lokugunyazwa elingu-{percent}"),
+        ("{percent} of contract", "{percent} wenkontileka"),
+        ("{percent} of total", "{percent} wesamba"),
+        ("{percent} saved", "kongiwe u-{percent}"),
+        ("{po_number} issued to {supplier}", "{po_number} likhishelwe u-{supplier}"),
+        ("{po_number} was issued to {supplier}, not {scanned}", "{po_number} likhishelwe u-{supplier}, hhayi u-{scanned}"),
+        ("{quantity} {item} delivered but only {outstanding} outstanding on {po_number}", "Kulethwe {quantity} {item} kodwa kusalele {outstanding} kuphela ku-{po_number}"),
+        ("{quantity}+ at {price}", "{quantity}+ ngo-{price}"),
+        ("{reference} - Tender {tender}", "{reference} - Ithenda {tender}"),
+        ("{start} to {end}", "{start} kuya ku-{end}"),
+        ("{supplier} - ordered {date}", "{supplier} - kwa-odwa ngo-{date}"),
+        ("A conversion started by {name} on {date} did not finish. Resuming it reuses anything it already created.", "Ukuguqulwa okuqalwe ngu-{name} ngo-{date} akuzange kuphothulwe. Ukukuqhubeka kusebenzisa futhi noma yini esivele idaliwe."),
+        ("Resume Conversion", "Qhubeka nokuguqula"),
+        ("No extraction engine configured", "Ayikho injini yokukhipha emisiwe"),
+        ("Scanned documents cannot be read until an OCR service is connected. Capture them by hand for now.", "Amadokhumenti askeniwe awakwazi ukufundwa kuze kuxhunywe isevisi ye-OCR. Wafake ngesandla okwamanje."),
+        ("The application stopped after an unexpected error. Reload to continue; changes not yet saved on this page may be lost.", "Uhlelo lokusebenza lumile ngemuva kwephutha elingalindelekile. Layisha kabusha ukuze uqhubeke; izinguquko ezingakagcinwa kuleli khasi zingalahleka."),
+        ("Reload", "Layisha kabusha"),
+        ("This page failed to load. The rest of the application is unaffected.", "Leli khasi lehlulekile ukulayisha. Konke okunye kohlelo kusasebenza."),
+        ("Enter an amount such as {example}", "Faka inani elifana no-{example}"),
+        ("Apply SLAs now", "Sebenzisa ama-SLA manje"),
+    ],
+    plurals: &[
+        ("{n} unread notification", ["{n} isaziso esingafundiwe", "{n} izaziso ezingafundiwe"]),
+        ("{n} day", ["{n} usuku", "{n} izinsuku"]),
+        ("{n} minute", ["{n} umzuzu", "{n} imizuzu"]),
+        ("{n} hour", ["{n} ihora", "{n} amahora"]),
+        ("Back online. Sending {n} queued change...", ["Usuxhunyiwe futhi. Kuthunyelwa {n} ushintsho olusemugqeni...", "Usuxhunyiwe futhi. Kuthunyelwa {n} izinguquko ezisemugqeni..."]),
+        ("Catalogue lines split into {n} purchase order, one per supplier.", ["Imigqa yekhathalogi ihlukaniswe yaba {n} i-oda lokuthenga, elilodwa ngomhlinzeki.", "Imigqa yekhathalogi ihlukaniswe yaba {n} ama-oda okuthenga, elilodwa ngomhlinzeki."]),
+        ("Repeat finding, outstanding for {n} year", ["Okutholakele okuphindiwe, kusalindile unyaka o-{n}", "Okutholakele okuphindiwe, kusalindile iminyaka engu-{n}"]),
+        ("You are offline. {n} change saved on this device will be sent when the connection returns.", ["Awuxhunyiwe. {n} ushintsho olulondolozwe kule divayisi luzothunyelwa uma uxhumano lubuya.", "Awuxhunyiwe. {n} izinguquko ezilondolozwe kule divayisi zizothunyelwa uma uxhumano lubuya."]),
+        ("You have {n} unread notification", ["Unesaziso esingu-{n} esingafundiwe", "Unezaziso ezingu-{n} ezingafundiwe"]),
+        ("All items are catalogue items under contract: {n} purchase order can be raised directly.", ["Zonke izinto zisekhathalogini ngaphansi kwenkontileka: i-oda lokuthenga elingu-{n} lingadalwa ngqo.", "Zonke izinto zisekhathalogini ngaphansi kwenkontileka: ama-oda okuthenga angu-{n} angadalwa ngqo."]),
+        ("{n} active", ["{n} esebenzayo", "{n} ezisebenzayo"]),
+        ("{n} bidder", ["umbhidi ongu-{n}", "ababhidi abangu-{n}"]),
+        ("{n} change", ["ushintsho olungu-{n}", "izinguquko ezingu-{n}"]),
+        ("{n} check", ["ukuhlola okungu-{n}", "ukuhlola okungu-{n}"]),
+        ("{n} closing soon", ["{n} evala maduze", "{n} ezivala maduze"]),
+        ("{n} completed", ["{n} eqediwe", "{n} eziqediwe"]),
+        ("{n} contract expiring within 30 days. Review and initiate renewal process.", ["Inkontileka engu-{n} iphelelwa yisikhathi phakathi kwezinsuku ezingu-30. Buyekeza uqale inqubo yokuvuselela.", "Izinkontileka ezingu-{n} ziphelelwa yisikhathi phakathi kwezinsuku ezingu-30. Buyekeza uqale inqubo yokuvuselela."]),
+        ("{n} criterion defined", ["Indlela yokuhlola engu-{n} ichaziwe", "Izindlela zokuhlola ezingu-{n} zichaziwe"]),
+        ("{n} critical", ["{n} ebucayi", "{n} ezibucayi"]),
+        ("{n} critical, attention needed", ["{n} ebucayi, kudingeka ukunakwa", "{n} ezibucayi, kudingeka ukunakwa"]),
+        ("{n} day left", ["kusele usuku olu-{n}", "kusele izinsuku ezingu-{n}"]),
+        ("{n} document", ["idokhumenti engu-{n}", "amadokhumenti angu-{n}"]),
+        ("{n} expiring in 30 days", ["{n} iphelelwa ezinsukwini ezingu-30", "{n} ziphelelwa ezinsukwini ezingu-30"]),
+        ("{n} field changed", ["inkambu engu-{n} ishintshiwe", "izinkambu ezingu-{n} zishintshiwe"]),
+        ("{n} file", ["ifayela elingu-{n}", "amafayela angu-{n}"]),
+        ("{n} high/extreme", ["{n} ephezulu/edlulele", "{n} eziphezulu/ezidlulele"]),
+        ("{n} ineffective", ["{n} engasebenzi", "{n} ezingasebenzi"]),
+        ("{n} issue", ["inkinga engu-{n}", "izinkinga ezingu-{n}"]),
+        ("{n} item", ["into engu-{n}", "izinto ezingu-{n}"]),
+        ("{n} item pending approval. Review and approve to make it available.", ["Into engu-{n} ilinde ukugunyazwa. Buyekeza ugunyaze ukuze itholakale.", "Izinto ezingu-{n} zilinde ukugunyazwa. Buyekeza ugunyaze ukuze zitholakale."]),
+        ("{n} line", ["umugqa ongu-{n}", "imigqa engu-{n}"]),
+        ("{n} material", ["{n} ebalulekile", "{n} ezibalulekile"]),
+        ("{n} milestone is overdue. Please review and take action.", ["Igxathu elingu-{n} lidlulelwe yisikhathi. Sicela ubuyekeze uthathe isinyathelo.", "Amagxathu angu-{n} adlulelwe yisikhathi. Sicela ubuyekeze uthathe isinyathelo."]),
+        ("{n} overdue", ["{n} edlulelwe yisikhathi", "{n} ezidlulelwe yisikhathi"]),
+        ("{n} plan in total", ["uhlelo olungu-{n} lulonke", "izinhlelo ezingu-{n} zizonke"]),
+        ("{n} purchase order pending delivery. Track and confirm deliveries.", ["I-oda lokuthenga elingu-{n} lilinde ukulethwa. Landelela futhi uqinisekise ukulethwa.", "Ama-oda okuthenga angu-{n} alinde ukulethwa. Landelela futhi uqinisekise ukulethwa."]),
+        ("{n} receipt pending inspection. Complete quality checks before acceptance.", ["Isamukelo esingu-{n} silinde ukuhlolwa. Qedela ukuhlolwa kwekhwalithi ngaphambi kokwamukela.", "Izamukelo ezingu-{n} zilinde ukuhlolwa. Qedela ukuhlolwa kwekhwalithi ngaphambi kokwamukela."]),
+        ("{n} supplier", ["umhlinzeki ongu-{n}", "abahlinzeki abangu-{n}"]),
+        ("{n} this month", ["{n} kule nyanga", "{n} kule nyanga"]),
+        ("{n} under evaluation", ["{n} iyahlolwa", "{n} ziyahlolwa"]),
+        ("{n} urgent", ["{n} ephuthumayo", "{n} eziphuthumayo"]),
+        ("{n} urgent (>5 days)", ["{n} ephuthumayo (>izinsuku ezi-5)", "{n} eziphuthumayo (>izinsuku ezi-5)"]),
+        ("{n} user", ["umsebenzisi ongu-{n}", "abasebenzisi abangu-{n}"]),
+        ("{n} requisition updated", ["{n} isicelo sibuyekeziwe", "{n} izicelo zibuyekeziwe"]),
+    ],
+};
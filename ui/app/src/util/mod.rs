@@ -2,4 +2,14 @@
 
 pub mod api;
 pub mod auth;
+pub mod backend;
+pub mod datetime;
 pub mod format;
+pub mod i18n;
+pub mod money;
+pub mod offline;
+pub mod oidc;
+pub mod permissions;
+pub mod query;
+pub mod reference;
+pub mod url;
@@ -0,0 +1,454 @@
+//! Exact Rand amounts
+//!
+//! [`Money`] holds a whole number of cents, so sums of line items and budget
+//! balances never pick up floating-point error. Anything that scales an
+//! amount (VAT, percentages, unit price by a fractional quantity) rounds back
+//! to the cent half away from zero, which is how SARS expects VAT to be
+//! rounded on tax invoices.
+//!
+//! On the wire an amount is a JSON number of rands (`1234.56`), the same shape
+//! the API used when amounts were `f64`. Strings such as `"1234.56"` are
+//! accepted too, since some endpoints serialise decimals that way.
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Standard South African VAT rate, in percent
+pub const VAT_RATE: f64 = 15.0;
+
+/// An amount in South African Rand, stored as cents
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Money {
+    cents: i64,
+}
+
+impl Money {
+    pub const ZERO: Money = Money { cents: 0 };
+
+    pub const fn from_cents(cents: i64) -> Self {
+        Self { cents }
+    }
+
+    /// Whole rands
+    pub const fn rands(rands: i64) -> Self {
+        Self { cents: rands * 100 }
+    }
+
+    /// Nearest cent to a floating-point rand value. NaN, infinities and
+    /// values beyond the range of cents are an error rather than zero.
+    pub fn from_rands(rands: f64) -> Result<Self, String> {
+        let cents = (rands * 100.0).round();
+        if !cents.is_finite() || cents.abs() >= i64::MAX as f64 {
+            return Err(format!("{} is not a valid amount", rands));
+        }
+        Ok(Self { cents: cents as i64 })
+    }
+
+    pub const fn cents(self) -> i64 {
+        self.cents
+    }
+
+    /// Value in rands, for charts and ratios only; never feed it back into
+    /// arithmetic on amounts
+    pub fn to_rands(self) -> f64 {
+        self.cents as f64 / 100.0
+    }
+
+    pub const fn is_zero(self) -> bool {
+        self.cents == 0
+    }
+
+    pub const fn is_negative(self) -> bool {
+        self.cents < 0
+    }
+
+    pub const fn is_positive(self) -> bool {
+        self.cents > 0
+    }
+
+    pub const fn abs(self) -> Self {
+        Self { cents: self.cents.abs() }
+    }
+
+    /// `percent`% of this amount, rounded to the cent
+    pub fn percent(self, percent: f64) -> Self {
+        self.scale(basis_points(percent), 10_000)
+    }
+
+    /// Multiply by a fractional factor, rounded to the cent. The factor is
+    /// taken to six decimal places and applied to the cents in integer
+    /// arithmetic.
+    pub fn times(self, factor: f64) -> Self {
+        self.scale((factor * 1_000_000.0).round() as i64, 1_000_000)
+    }
+
+    /// This amount as a percentage of `total`; zero when `total` is zero
+    pub fn percent_of(self, total: Money) -> f64 {
+        if total.is_zero() {
+            0.0
+        } else {
+            self.cents as f64 / total.cents as f64 * 100.0
+        }
+    }
+
+    /// VAT payable on this VAT-exclusive amount
+    pub fn vat_on(self, rate: f64) -> Self {
+        self.percent(rate)
+    }
+
+    /// This VAT-exclusive amount with VAT added
+    pub fn with_vat(self, rate: f64) -> Self {
+        self + self.vat_on(rate)
+    }
+
+    /// The VAT-exclusive part of this VAT-inclusive amount
+    pub fn excluding_vat(self, rate: f64) -> Self {
+        let bp = basis_points(rate);
+        self.scale(10_000, 10_000 + bp)
+    }
+
+    /// The VAT contained in this VAT-inclusive amount. Computed as the
+    /// difference so that exclusive + VAT always equals the inclusive amount.
+    pub fn vat_included(self, rate: f64) -> Self {
+        self - self.excluding_vat(rate)
+    }
+
+    /// Parse an amount typed with `decimal` as the decimal mark. With ","
+    /// (Afrikaans) a "." is rejected rather than guessed at, and groups are
+    /// separated by spaces only; otherwise this is `str::parse`.
+    pub fn parse_with_decimal(text: &str, decimal: &str) -> Result<Self, String> {
+        if decimal != "," {
+            return text.parse();
+        }
+        if text.contains('.') {
+            return Err(format!("'{}' is not an amount", text.trim()));
+        }
+        text.replace(',', ".").parse()
+    }
+
+    /// This amount as an input field shows it, with `decimal` as the decimal mark
+    pub fn to_input_string(self, decimal: &str) -> String {
+        self.to_string().replace('.', decimal)
+    }
+
+    /// `self * numerator / denominator`, rounded half away from zero
+    fn scale(self, numerator: i64, denominator: i64) -> Self {
+        let product = self.cents as i128 * numerator as i128;
+        let denominator = denominator as i128;
+        let half = denominator / 2;
+        let rounded = if product >= 0 {
+            (product + half) / denominator
+        } else {
+            (product - half) / denominator
+        };
+        Self { cents: rounded as i64 }
+    }
+}
+
+/// A percentage as whole basis points (15% -> 1500)
+fn basis_points(percent: f64) -> i64 {
+    (percent * 100.0).round() as i64
+}
+
+/// For formatting figures that are already rands, such as chart totals. A
+/// non-finite figure shows as zero; arithmetic on amounts goes through
+/// [`Money::from_rands`], which rejects it.
+impl From<f64> for Money {
+    fn from(rands: f64) -> Self {
+        Self::from_rands(rands).unwrap_or_default()
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, rhs: Money) -> Money {
+        Money { cents: self.cents + rhs.cents }
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, rhs: Money) {
+        self.cents += rhs.cents;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, rhs: Money) -> Money {
+        Money { cents: self.cents - rhs.cents }
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, rhs: Money) {
+        self.cents -= rhs.cents;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money { cents: -self.cents }
+    }
+}
+
+impl Mul<u32> for Money {
+    type Output = Money;
+
+    fn mul(self, quantity: u32) -> Money {
+        Money { cents: self.cents * quantity as i64 }
+    }
+}
+
+impl Mul<i64> for Money {
+    type Output = Money;
+
+    fn mul(self, quantity: i64) -> Money {
+        Money { cents: self.cents * quantity }
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.copied().sum()
+    }
+}
+
+/// Plain decimal rands (`-1234.50`), as used in form inputs and query strings.
+/// For display use `util::format::format_currency`.
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.cents < 0 { "-" } else { "" };
+        let abs = self.cents.unsigned_abs();
+        write!(f, "{}{}.{:02}", sign, abs / 100, abs % 100)
+    }
+}
+
+/// Parses what a user types into an amount field: an optional `R`, `.` as
+/// the decimal mark, and commas or spaces between groups of three digits.
+/// A comma anywhere else is rejected, so "1,23" is neither R1.23 nor R123.
+/// More than two decimals is rejected rather than silently rounded.
+impl FromStr for Money {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("'{}' is not an amount", s.trim());
+        let mut text: String = s
+            .trim()
+            .trim_start_matches(['R', 'r'])
+            .trim_start()
+            .chars()
+            .map(|c| if c.is_whitespace() { ',' } else { c })
+            .collect();
+
+        let negative = text.starts_with('-');
+        if negative {
+            text.remove(0);
+        }
+
+        let (whole, fraction) = text.split_once('.').unwrap_or((&text, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(invalid());
+        }
+        let mut groups = whole.split(',');
+        let leading = groups.next().unwrap_or_default();
+        let grouped = whole.contains(',');
+        let groups_ok = groups.all(|g| g.len() == 3 && g.chars().all(|c| c.is_ascii_digit()))
+            && (!grouped || (1..=3).contains(&leading.len()));
+        if !groups_ok
+            || !leading.chars().all(|c| c.is_ascii_digit())
+            || !fraction.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        if fraction.len() > 2 {
+            return Err("Amounts can have at most two decimal places".to_string());
+        }
+
+        let whole = whole.replace(',', "");
+        let rands: i64 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().map_err(|_| "Amount is too large".to_string())?
+        };
+        let cents: i64 = format!("{:0<2}", fraction).parse().unwrap_or(0);
+        let total = rands
+            .checked_mul(100)
+            .and_then(|c| c.checked_add(cents))
+            .ok_or_else(|| "Amount is too large".to_string())?;
+
+        Ok(Money { cents: if negative { -total } else { total } })
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.to_rands())
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(MoneyVisitor)
+    }
+}
+
+struct MoneyVisitor;
+
+impl<'de> Visitor<'de> for MoneyVisitor {
+    type Value = Money;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an amount in rands as a number or decimal string")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Money, E> {
+        v.checked_mul(100)
+            .map(Money::from_cents)
+            .ok_or_else(|| E::custom("amount out of range"))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Money, E> {
+        i64::try_from(v)
+            .map_err(|_| E::custom("amount out of range"))
+            .and_then(|v| self.visit_i64(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Money, E> {
+        Money::from_rands(v).map_err(E::custom)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Money, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Result<Money, String> {
+        text.parse()
+    }
+
+    #[test]
+    fn parses_what_users_type() {
+        let cases = [
+            ("0", 0),
+            ("12", 1_200),
+            ("12.5", 1_250),
+            ("12.05", 1_205),
+            (".5", 50),
+            ("R 1234.56", 123_456),
+            ("r99", 9_900),
+            ("1,234", 123_400),
+            ("1,234,567.89", 123_456_789),
+            ("1 234 567.89", 123_456_789),
+            ("1\u{202f}234.50", 123_450),
+            ("-1,234.50", -123_450),
+            ("  R-7.10  ", -710),
+        ];
+        for (text, cents) in cases {
+            assert_eq!(parse(text), Ok(Money::from_cents(cents)), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn rejects_ambiguous_and_malformed_amounts() {
+        for text in ["", "R", "-", ".", "1,23", "1,2", "12,50", "1 23", "1234,567", ",123", "1,,234", "1.2.3", "1.234,50", "12a", "1e3", "--5"] {
+            assert!(parse(text).is_err(), "{:?} should not parse", text);
+        }
+        assert_eq!(parse("1.234"), Err("Amounts can have at most two decimal places".to_string()));
+        assert_eq!(parse("99999999999999999999"), Err("Amount is too large".to_string()));
+    }
+
+    #[test]
+    fn display_round_trips_through_parse() {
+        for cents in [0, 5, 99, 100, 123_456, -1, -123_456] {
+            let money = Money::from_cents(cents);
+            assert_eq!(parse(&money.to_string()), Ok(money));
+        }
+    }
+
+    #[test]
+    fn parses_with_a_decimal_comma() {
+        let cases = [("12,5", 1_250), ("R 1234,56", 123_456), ("1 234 567,89", 123_456_789), ("-7,10", -710), ("99", 9_900)];
+        for (text, cents) in cases {
+            assert_eq!(Money::parse_with_decimal(text, ","), Ok(Money::from_cents(cents)), "{:?}", text);
+        }
+        for text in ["12.50", "1,234,50", "1.234,50", "1,234"] {
+            assert!(Money::parse_with_decimal(text, ",").is_err(), "{:?} should not parse", text);
+        }
+        assert_eq!(Money::parse_with_decimal("1,234.50", "."), Ok(Money::from_cents(123_450)));
+        for cents in [0, 5, 123_456, -123_456] {
+            let money = Money::from_cents(cents);
+            assert_eq!(Money::parse_with_decimal(&money.to_input_string(","), ","), Ok(money));
+        }
+    }
+
+    #[test]
+    fn from_rands_rounds_to_the_cent_and_rejects_non_finite() {
+        assert_eq!(Money::from_rands(1.005 + 1e-9), Ok(Money::from_cents(101)));
+        assert_eq!(Money::from_rands(-2.345), Ok(Money::from_cents(-235)));
+        assert_eq!(Money::from_rands(0.1 + 0.2), Ok(Money::from_cents(30)));
+        for rands in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e300] {
+            assert!(Money::from_rands(rands).is_err(), "{}", rands);
+        }
+        assert_eq!(Money::from(f64::NAN), Money::ZERO);
+    }
+
+    #[test]
+    fn scaling_rounds_half_away_from_zero() {
+        let cases = [
+            (Money::from_cents(1), 50.0, 1),
+            (Money::from_cents(-1), 50.0, -1),
+            (Money::from_cents(3), 50.0, 2),
+            (Money::from_cents(10_000), 12.5, 1_250),
+            (Money::from_cents(333), 33.33, 111),
+        ];
+        for (amount, percent, cents) in cases {
+            assert_eq!(amount.percent(percent), Money::from_cents(cents), "{} at {}%", amount, percent);
+        }
+        assert_eq!(Money::from_cents(1_000).times(1.0 / 3.0), Money::from_cents(333));
+        assert_eq!(Money::from_cents(2_000).times(1.0 / 3.0), Money::from_cents(667));
+        assert_eq!(Money::from_cents(-2_000).times(1.0 / 3.0), Money::from_cents(-667));
+        assert_eq!(Money::rands(1_000_000_000).times(0.5), Money::rands(500_000_000));
+        assert_eq!(Money::from_cents(199).times(2.5), Money::from_cents(498));
+    }
+
+    #[test]
+    fn vat_splits_add_back_to_the_inclusive_amount() {
+        assert_eq!(Money::rands(100).vat_on(VAT_RATE), Money::rands(15));
+        assert_eq!(Money::rands(100).with_vat(VAT_RATE), Money::rands(115));
+        assert_eq!(Money::rands(115).excluding_vat(VAT_RATE), Money::rands(100));
+        assert_eq!(Money::rands(115).vat_included(VAT_RATE), Money::rands(15));
+        // R0.03 excl. VAT carries 0.45c of VAT, which rounds down
+        assert_eq!(Money::from_cents(3).vat_on(VAT_RATE), Money::ZERO);
+        assert_eq!(Money::from_cents(10).vat_on(VAT_RATE), Money::from_cents(2));
+
+        for cents in [1, 7, 99, 100, 1_001, 123_457, 999_999, -4_321] {
+            let inclusive = Money::from_cents(cents);
+            assert_eq!(
+                inclusive.excluding_vat(VAT_RATE) + inclusive.vat_included(VAT_RATE),
+                inclusive,
+                "{}",
+                inclusive
+            );
+        }
+        assert_eq!(Money::rands(100).vat_on(0.0), Money::ZERO);
+    }
+}
@@ -0,0 +1,457 @@
+//! Offline persistence and mutation outbox
+//!
+//! Store snapshots and mutations made without connectivity are kept in
+//! IndexedDB. Lists fall back to their last snapshot when the API cannot be
+//! reached, and queued mutations are replayed in order once the browser is
+//! back online. A queued mutation the server rejects, e.g. because the record
+//! changed in the meantime, is dropped from the outbox and reported as a
+//! conflict.
+//!
+//! Both are scoped to the signed-in user: snapshots are cleared on sign-out
+//! and keyed by user id, and only the signed-in user's mutations are
+//! replayed, with their own token. When a queued create is replayed, the
+//! server's number for the document replaces its provisional number in the
+//! mutations queued after it.
+
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use components::prelude::*;
+use js_sys::{Function, Promise};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{IdbDatabase, IdbObjectStoreParameters, IdbRequest, IdbTransactionMode};
+use super::api::{self, ApiError, HttpMethod, RequestOptions};
+use super::auth;
+use super::query;
+use super::reference::{self, DocumentType};
+
+const DB_NAME: &str = "eprocurement";
+const DB_VERSION: u32 = 1;
+const SNAPSHOTS: &str = "snapshots";
+const OUTBOX: &str = "outbox";
+
+/// A store snapshot and when it was taken
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Snapshot<T> {
+    /// Milliseconds since the epoch
+    pub saved_at: f64,
+    pub data: T,
+}
+
+/// A mutation recorded while offline
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Queue position, assigned by IndexedDB
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq: Option<u32>,
+    pub method: String,
+    pub endpoint: String,
+    pub body: String,
+    /// What the user did, e.g. "Record receipt of GR-2025-0042 item 1"
+    pub label: String,
+    /// Version of the record the change was made against, sent as `If-Match`
+    #[serde(default)]
+    pub base_version: Option<String>,
+    pub queued_at: f64,
+    /// User who made the change; nobody else's session replays it
+    #[serde(default)]
+    pub user_id: String,
+    /// Set when the mutation creates a document under a provisional number
+    #[serde(default)]
+    pub provisional: Option<Provisional>,
+}
+
+/// A document created offline under a provisional reference number
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Provisional {
+    /// [`DocumentType::key`] of the register the number was drawn from
+    pub doc_type: String,
+    pub number: String,
+    /// Field of the server's response that holds its number for the document
+    pub field: String,
+}
+
+/// A queued mutation the server refused on replay
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutboxConflict {
+    pub label: String,
+    pub endpoint: String,
+    pub message: String,
+}
+
+/// Connectivity and outbox state shown by the app shell
+#[derive(Clone, Debug, Default)]
+pub struct SyncState {
+    pub online: bool,
+    pub pending: usize,
+    pub syncing: bool,
+    pub conflicts: Vec<OutboxConflict>,
+}
+
+thread_local! {
+    static DB: RefCell<Option<IdbDatabase>> = RefCell::new(None);
+    static SYNC: RefCell<Option<Signal<SyncState>>> = RefCell::new(None);
+    /// Entries already sent that IndexedDB would not delete. They are never
+    /// sent again; deleting them is retried on the next replay.
+    static SENT: RefCell<BTreeSet<u32>> = RefCell::new(BTreeSet::new());
+}
+
+/// Whether the browser reports a network connection
+pub fn is_online() -> bool {
+    web_sys::window()
+        .map(|w| w.navigator().on_line())
+        .unwrap_or(true)
+}
+
+/// Register the sync state, listen for connectivity changes and flush any
+/// mutations left over from a previous session
+pub fn install(sync_state: Signal<SyncState>) {
+    let mut state = sync_state.get();
+    state.online = is_online();
+    sync_state.set(state);
+    SYNC.with(|s| *s.borrow_mut() = Some(sync_state));
+
+    if let Some(window) = web_sys::window() {
+        let on_online = Closure::<dyn Fn()>::new(|| {
+            update(|s| s.online = true);
+            spawn_local(async {
+                replay().await;
+            });
+        });
+        let on_offline = Closure::<dyn Fn()>::new(|| update(|s| s.online = false));
+        let _ = window.add_event_listener_with_callback("online", on_online.as_ref().unchecked_ref());
+        let _ = window.add_event_listener_with_callback("offline", on_offline.as_ref().unchecked_ref());
+        // Listeners live for the whole session
+        on_online.forget();
+        on_offline.forget();
+    }
+
+    spawn_local(async {
+        refresh_pending().await;
+        if is_online() {
+            replay().await;
+        }
+    });
+}
+
+fn update(f: impl FnOnce(&mut SyncState)) {
+    if let Some(signal) = SYNC.with(|s| s.borrow().clone()) {
+        let mut state = signal.get();
+        f(&mut state);
+        signal.set(state);
+    }
+}
+
+/// Forget a reported conflict
+pub fn dismiss_conflict(index: usize) {
+    update(|s| {
+        if index < s.conflicts.len() {
+            s.conflicts.remove(index);
+        }
+    });
+}
+
+/// Id of the signed-in user, empty when signed out
+fn user_id() -> String {
+    auth::current().user.map(|u| u.id).unwrap_or_default()
+}
+
+/// IndexedDB key of the signed-in user's snapshot for `key`
+fn snapshot_key(key: &str) -> JsValue {
+    JsValue::from_str(&format!("{}:{}", user_id(), key))
+}
+
+/// Persist `data` as the snapshot for `key`
+pub async fn save_snapshot<T: Serialize>(key: &str, data: &T) {
+    let snapshot = Snapshot { saved_at: js_sys::Date::now(), data };
+    let Ok(json) = serde_json::to_string(&snapshot) else { return };
+    let Some(db) = open().await else { return };
+    let Ok(tx) = db.transaction_with_str_and_mode(SNAPSHOTS, IdbTransactionMode::Readwrite) else { return };
+    if let Ok(store) = tx.object_store(SNAPSHOTS) {
+        if let Ok(request) = store.put_with_key(&JsValue::from_str(&json), &snapshot_key(key)) {
+            let _ = await_request(&request).await;
+        }
+    }
+}
+
+/// Last snapshot the signed-in user saved for `key`
+pub async fn load_snapshot<T: DeserializeOwned>(key: &str) -> Option<Snapshot<T>> {
+    let db = open().await?;
+    let tx = db.transaction_with_str(SNAPSHOTS).ok()?;
+    let request = tx.object_store(SNAPSHOTS).ok()?.get(&snapshot_key(key)).ok()?;
+    let value = await_request(&request).await.ok()?;
+    serde_json::from_str(&value.as_string()?).ok()
+}
+
+/// Delete every snapshot, so the next user of the browser cannot read the
+/// data cached for the last one
+pub async fn clear_snapshots() {
+    let Some(db) = open().await else { return };
+    let Ok(tx) = db.transaction_with_str_and_mode(SNAPSHOTS, IdbTransactionMode::Readwrite) else { return };
+    if let Ok(request) = tx.object_store(SNAPSHOTS).and_then(|s| s.clear()) {
+        let _ = await_request(&request).await;
+    }
+}
+
+/// Record a mutation for replay once the browser is back online.
+/// `provisional` is set when it creates a document under a provisional number.
+pub async fn enqueue(
+    method: HttpMethod,
+    endpoint: &str,
+    body: String,
+    label: &str,
+    base_version: Option<String>,
+    provisional: Option<Provisional>,
+) -> Result<(), ApiError> {
+    let entry = OutboxEntry {
+        seq: None,
+        method: method.as_str().to_string(),
+        endpoint: endpoint.to_string(),
+        body,
+        label: label.to_string(),
+        base_version,
+        queued_at: js_sys::Date::now(),
+        user_id: user_id(),
+        provisional,
+    };
+    let failed = || ApiError::network("Could not save the change for later");
+
+    let value = to_js(&entry).ok_or_else(failed)?;
+    let db = open().await.ok_or_else(failed)?;
+    let tx = db
+        .transaction_with_str_and_mode(OUTBOX, IdbTransactionMode::Readwrite)
+        .map_err(|_| failed())?;
+    let request = tx
+        .object_store(OUTBOX)
+        .and_then(|store| store.add(&value))
+        .map_err(|_| failed())?;
+    await_request(&request).await.map_err(|_| failed())?;
+
+    update(|s| s.pending += 1);
+    Ok(())
+}
+
+fn to_js(entry: &OutboxEntry) -> Option<JsValue> {
+    serde_json::to_string(entry)
+        .ok()
+        .and_then(|json| js_sys::JSON::parse(&json).ok())
+}
+
+/// The signed-in user's queued mutations not yet sent, oldest first
+pub async fn pending() -> Vec<OutboxEntry> {
+    let user_id = user_id();
+    let sent = SENT.with(|s| s.borrow().clone());
+    let mut entries = all_entries().await;
+    entries.retain(|e| {
+        !user_id.is_empty() && e.user_id == user_id && !e.seq.is_some_and(|seq| sent.contains(&seq))
+    });
+    entries
+}
+
+/// Every queued mutation, whoever made it, oldest first
+async fn all_entries() -> Vec<OutboxEntry> {
+    let Some(db) = open().await else { return Vec::new() };
+    let Ok(tx) = db.transaction_with_str(OUTBOX) else { return Vec::new() };
+    let Some(request) = tx.object_store(OUTBOX).ok().and_then(|s| s.get_all().ok()) else {
+        return Vec::new();
+    };
+    let Ok(values) = await_request(&request).await else { return Vec::new() };
+
+    let mut entries: Vec<OutboxEntry> = js_sys::Array::from(&values)
+        .iter()
+        .filter_map(|v| js_sys::JSON::stringify(&v).ok())
+        .filter_map(|json| serde_json::from_str(&String::from(json)).ok())
+        .collect();
+    entries.sort_by_key(|e| e.seq);
+    entries
+}
+
+/// Point the signed-in user's queued mutations at the server's number for a
+/// document they were queued against under its provisional number
+async fn replace_provisional(provisional: &str, confirmed: &str) {
+    let Some(db) = open().await else { return };
+    for mut entry in pending().await {
+        if !entry.endpoint.contains(provisional) && !entry.body.contains(provisional) {
+            continue;
+        }
+        entry.endpoint = entry.endpoint.replace(provisional, confirmed);
+        entry.body = entry.body.replace(provisional, confirmed);
+        let Some(value) = to_js(&entry) else { continue };
+        let Ok(tx) = db.transaction_with_str_and_mode(OUTBOX, IdbTransactionMode::Readwrite) else { return };
+        if let Ok(request) = tx.object_store(OUTBOX).and_then(|s| s.put(&value)) {
+            let _ = await_request(&request).await;
+        }
+    }
+}
+
+/// Record the server's number for a document created by a replayed mutation
+async fn reconcile(provisional: &Provisional, response: &serde_json::Value) {
+    let Some(confirmed) = response.get(&provisional.field).and_then(|v| v.as_str()) else {
+        return;
+    };
+    if let Some(doc_type) = DocumentType::from_key(&provisional.doc_type) {
+        reference::reconcile(doc_type, &provisional.number, confirmed);
+    }
+    if confirmed != provisional.number {
+        replace_provisional(&provisional.number, confirmed).await;
+    }
+}
+
+async fn remove_entry(seq: u32) -> Result<(), String> {
+    let failed = || format!("Could not remove queued change {} from the outbox", seq);
+    let db = open().await.ok_or_else(failed)?;
+    let tx = db
+        .transaction_with_str_and_mode(OUTBOX, IdbTransactionMode::Readwrite)
+        .map_err(|_| failed())?;
+    let request = tx
+        .object_store(OUTBOX)
+        .and_then(|s| s.delete(&JsValue::from(seq)))
+        .map_err(|_| failed())?;
+    await_request(&request).await.map(|_| ()).map_err(|_| failed())
+}
+
+/// Take a sent or refused entry off the queue. One IndexedDB will not delete
+/// is remembered in `SENT` so no pass sends it again.
+async fn discard(seq: u32) -> Result<(), String> {
+    let removed = remove_entry(seq).await;
+    if removed.is_err() {
+        SENT.with(|s| s.borrow_mut().insert(seq));
+    }
+    removed
+}
+
+/// Retry deleting entries a previous pass sent but could not remove
+async fn discard_sent() {
+    let sent: Vec<u32> = SENT.with(|s| s.borrow().iter().copied().collect());
+    for seq in sent {
+        if remove_entry(seq).await.is_ok() {
+            SENT.with(|s| s.borrow_mut().remove(&seq));
+        }
+    }
+}
+
+async fn refresh_pending() {
+    let count = pending().await.len();
+    update(|s| s.pending = count);
+}
+
+/// Send the signed-in user's queued mutations in the order they were made.
+///
+/// Stops at the first transient failure so later changes are never applied
+/// ahead of earlier ones, and when the outbox cannot be updated. Mutations
+/// the server refuses are dropped and reported in `SyncState::conflicts`.
+/// Other users' mutations stay queued for their own session.
+pub async fn replay() {
+    let already_syncing = SYNC
+        .with(|s| s.borrow().clone())
+        .map(|s| s.get().syncing)
+        .unwrap_or(false);
+    if already_syncing || auth::access_token().is_none() {
+        return;
+    }
+    update(|s| s.syncing = true);
+    discard_sent().await;
+
+    // Re-read the queue each time, since a replayed create rewrites the
+    // mutations queued after it
+    while let Some(entry) = pending().await.into_iter().next() {
+        let Some(seq) = entry.seq else { break };
+        let Some(method) = HttpMethod::parse(&entry.method) else {
+            if discard(seq).await.is_err() {
+                break;
+            }
+            continue;
+        };
+        let options = RequestOptions {
+            max_retries: 0,
+            page_scoped: false,
+            if_match: entry.base_version.clone(),
+            ..RequestOptions::for_method(&method)
+        };
+
+        match api::request_with::<serde_json::Value>(method, &entry.endpoint, Some(entry.body.clone()), &options).await {
+            Ok(response) => {
+                let removed = discard(seq).await;
+                if let Some(provisional) = &entry.provisional {
+                    reconcile(provisional, &response).await;
+                }
+                query::invalidate_resource(&entry.endpoint);
+                if removed.is_err() {
+                    break;
+                }
+            }
+            Err(e) if e.is_transient() || matches!(e, ApiError::Unauthorized(_) | ApiError::Cancelled) => break,
+            Err(e) => {
+                let removed = discard(seq).await;
+                query::invalidate_resource(&entry.endpoint);
+                let conflict = OutboxConflict {
+                    label: entry.label.clone(),
+                    endpoint: entry.endpoint.clone(),
+                    message: e.message(),
+                };
+                update(|s| s.conflicts.push(conflict));
+                if removed.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    refresh_pending().await;
+    update(|s| s.syncing = false);
+}
+
+/// Open the database once per session, creating the object stores on first use
+async fn open() -> Option<IdbDatabase> {
+    if let Some(db) = DB.with(|d| d.borrow().clone()) {
+        return Some(db);
+    }
+
+    let factory = web_sys::window()?.indexed_db().ok()??;
+    let request = factory.open_with_u32(DB_NAME, DB_VERSION).ok()?;
+
+    let on_upgrade = Closure::once_into_js({
+        let request = request.clone();
+        move || {
+            let Some(db) = request.result().ok().and_then(|r| r.dyn_into::<IdbDatabase>().ok()) else {
+                return;
+            };
+            let names = db.object_store_names();
+            if !names.contains(SNAPSHOTS) {
+                let _ = db.create_object_store(SNAPSHOTS);
+            }
+            if !names.contains(OUTBOX) {
+                let mut params = IdbObjectStoreParameters::new();
+                params.key_path(Some(&JsValue::from_str("seq")));
+                params.auto_increment(true);
+                let _ = db.create_object_store_with_optional_parameters(OUTBOX, &params);
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade.unchecked_ref()));
+
+    let db: IdbDatabase = await_request(&request).await.ok()?.dyn_into().ok()?;
+    DB.with(|d| *d.borrow_mut() = Some(db.clone()));
+    Some(db)
+}
+
+/// Resolve with the request's result once it succeeds
+async fn await_request(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = Promise::new(&mut |resolve: Function, reject: Function| {
+        let on_success = Closure::once_into_js({
+            let request = request.clone();
+            move || {
+                let result = request.result().unwrap_or(JsValue::UNDEFINED);
+                let _ = resolve.call1(&JsValue::NULL, &result);
+            }
+        });
+        let on_error = Closure::once_into_js(move || {
+            let _ = reject.call0(&JsValue::NULL);
+        });
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+    JsFuture::from(promise).await
+}
@@ -0,0 +1,582 @@
+//! OpenID Connect login (authorization code flow with PKCE)
+//!
+//! The identity provider is configured with `<meta>` tags in index.html. A
+//! dev build also reads per-browser overrides from localStorage, so it can be
+//! pointed at a local mock IdP; release builds ignore them, so nothing stored
+//! in the browser can redirect a sign-in. When no issuer is configured OIDC
+//! is disabled and the app runs with the demo session.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Request, RequestInit, Response};
+use serde::{Deserialize, Serialize};
+use super::api::{self, ApiError, Problem};
+use super::auth::{TokenResponse, User, UserRole};
+
+/// `<meta>` tag / localStorage key holding the issuer URL
+const ISSUER_KEY: &str = "eprocurement-oidc-issuer";
+
+/// `<meta>` tag / localStorage key holding the client ID
+const CLIENT_ID_KEY: &str = "eprocurement-oidc-client-id";
+
+/// `<meta>` tag / localStorage key holding the requested scopes
+const SCOPE_KEY: &str = "eprocurement-oidc-scope";
+
+const DEFAULT_SCOPE: &str = "openid profile email offline_access";
+
+/// `<meta>` tag holding the path the app is deployed under
+const BASE_PATH_KEY: &str = "eprocurement-base-path";
+
+const DEFAULT_BASE_PATH: &str = "/app";
+
+/// Path under the base path the IdP redirects back to after login
+const CALLBACK_ROUTE: &str = "/auth/callback";
+
+/// Seconds of clock skew tolerated when checking an ID token's expiry
+const CLOCK_SKEW_SECS: f64 = 60.0;
+
+/// sessionStorage key for the in-flight PKCE verifier and state
+const PKCE_KEY: &str = "eprocurement.oidc.pkce";
+
+/// Identity provider settings
+#[derive(Clone, Debug, PartialEq)]
+pub struct OidcConfig {
+    pub issuer: String,
+    pub client_id: String,
+    pub scope: String,
+}
+
+impl OidcConfig {
+    /// Read the configuration from the meta tags, or a dev build's
+    /// localStorage overrides
+    pub fn load() -> Option<Self> {
+        let issuer = setting(ISSUER_KEY)?;
+        let client_id = setting(CLIENT_ID_KEY)?;
+        let scope = setting(SCOPE_KEY).unwrap_or_else(|| DEFAULT_SCOPE.to_string());
+        Some(Self {
+            issuer: issuer.trim_end_matches('/').to_string(),
+            client_id,
+            scope,
+        })
+    }
+}
+
+/// Endpoints advertised by the IdP discovery document
+#[derive(Clone, Debug, Deserialize)]
+struct Discovery {
+    authorization_endpoint: String,
+    token_endpoint: String,
+    #[serde(default)]
+    end_session_endpoint: Option<String>,
+}
+
+/// PKCE verifier, anti-CSRF state and ID token nonce kept across the login
+/// redirect
+#[derive(Serialize, Deserialize)]
+struct PendingLogin {
+    state: String,
+    verifier: String,
+    nonce: String,
+}
+
+/// `aud` claim: a single client ID or a list of them
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Audience {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Audience {
+    fn contains(&self, client_id: &str) -> bool {
+        match self {
+            Audience::One(aud) => aud == client_id,
+            Audience::Many(auds) => auds.iter().any(|aud| aud == client_id),
+        }
+    }
+
+    fn is_shared(&self) -> bool {
+        matches!(self, Audience::Many(auds) if auds.len() > 1)
+    }
+}
+
+/// ID token claims mapped onto `User`
+#[derive(Deserialize)]
+struct IdClaims {
+    iss: String,
+    aud: Audience,
+    #[serde(default)]
+    azp: Option<String>,
+    exp: f64,
+    #[serde(default)]
+    nonce: Option<String>,
+    sub: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    preferred_username: Option<String>,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    roles: Vec<String>,
+    #[serde(default)]
+    role: Option<String>,
+    #[serde(default)]
+    department: Option<String>,
+}
+
+thread_local! {
+    static DISCOVERY: RefCell<Option<Discovery>> = RefCell::new(None);
+}
+
+/// Whether an identity provider is configured
+pub fn is_enabled() -> bool {
+    OidcConfig::load().is_some()
+}
+
+/// Whether the current page load is the IdP redirecting back after login
+pub fn is_callback() -> bool {
+    let Some(location) = web_sys::window().map(|w| w.location()) else {
+        return false;
+    };
+    let path = location.pathname().unwrap_or_default();
+    let search = location.search().unwrap_or_default();
+    path.trim_end_matches('/') == callback_path()
+        && (query_param(&search, "code").is_some() || query_param(&search, "error").is_some())
+}
+
+/// Path the app is deployed under, from the `eprocurement-base-path` meta
+/// tag, without a trailing slash
+pub fn base_path() -> String {
+    let path = meta(BASE_PATH_KEY).unwrap_or_else(|| DEFAULT_BASE_PATH.to_string());
+    format!("/{}", path.trim_matches('/')).trim_end_matches('/').to_string()
+}
+
+/// Path the IdP redirects back to after login
+pub fn callback_path() -> String {
+    format!("{}{}", base_path(), CALLBACK_ROUTE)
+}
+
+/// Redirect to the IdP login page
+pub async fn begin_login() -> Result<(), String> {
+    let config = OidcConfig::load().ok_or("OpenID Connect is not configured")?;
+    let discovery = discover(&config).await?;
+
+    let pending = PendingLogin {
+        state: random_token(16)?,
+        verifier: random_token(48)?,
+        nonce: random_token(16)?,
+    };
+    let challenge = pkce_challenge(&pending.verifier).await?;
+
+    let storage = session_storage().ok_or("Session storage unavailable")?;
+    let json = serde_json::to_string(&pending).map_err(|e| e.to_string())?;
+    storage.set_item(PKCE_KEY, &json).map_err(|_| "Failed to store login state")?;
+
+    let url = format!(
+        "{}{}{}",
+        discovery.authorization_endpoint,
+        if discovery.authorization_endpoint.contains('?') { "&" } else { "?" },
+        form_encode(&[
+            ("response_type", "code"),
+            ("client_id", &config.client_id),
+            ("redirect_uri", &redirect_uri()),
+            ("scope", &config.scope),
+            ("state", &pending.state),
+            ("nonce", &pending.nonce),
+            ("code_challenge", &challenge),
+            ("code_challenge_method", "S256"),
+        ])
+    );
+
+    web_sys::window()
+        .ok_or("no window")?
+        .location()
+        .assign(&url)
+        .map_err(|_| "Failed to redirect to login".to_string())
+}
+
+/// Exchange the authorization code from the callback URL for tokens,
+/// returned with the nonce the ID token must carry
+pub async fn complete_login() -> Result<(TokenResponse, String), String> {
+    let config = OidcConfig::load().ok_or("OpenID Connect is not configured")?;
+    let search = web_sys::window()
+        .ok_or("no window")?
+        .location()
+        .search()
+        .unwrap_or_default();
+
+    if let Some(error) = query_param(&search, "error") {
+        let description = query_param(&search, "error_description").unwrap_or_default();
+        return Err(format!("Login failed: {} {}", error, description).trim().to_string());
+    }
+
+    let code = query_param(&search, "code").ok_or("Missing authorization code")?;
+    let state = query_param(&search, "state").unwrap_or_default();
+
+    let storage = session_storage().ok_or("Session storage unavailable")?;
+    let pending: PendingLogin = storage
+        .get_item(PKCE_KEY)
+        .ok()
+        .flatten()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .ok_or("No login in progress")?;
+    let _ = storage.remove_item(PKCE_KEY);
+
+    if pending.state != state {
+        return Err("Login state mismatch".to_string());
+    }
+
+    let discovery = discover(&config).await?;
+    let tokens = post_form(
+        &discovery.token_endpoint,
+        &[
+            ("grant_type", "authorization_code"),
+            ("code", &code),
+            ("redirect_uri", &redirect_uri()),
+            ("client_id", &config.client_id),
+            ("code_verifier", &pending.verifier),
+        ],
+    )
+    .await
+    .map_err(|e| e.message())?;
+    Ok((tokens, pending.nonce))
+}
+
+/// Exchange a refresh token at the IdP token endpoint
+pub async fn refresh(refresh_token: &str) -> Result<TokenResponse, ApiError> {
+    let config = OidcConfig::load()
+        .ok_or_else(|| ApiError::Unauthorized(Problem::new(401, "OpenID Connect is not configured")))?;
+    let discovery = discover(&config).await.map_err(ApiError::Network)?;
+    post_form(
+        &discovery.token_endpoint,
+        &[
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+            ("client_id", &config.client_id),
+        ],
+    )
+    .await
+}
+
+/// Redirect to the IdP end-session endpoint, or back to the app if it has none
+pub async fn end_session(id_token: Option<String>) {
+    let Some(window) = web_sys::window() else { return };
+    let home = format!("{}{}/", window.location().origin().unwrap_or_default(), base_path());
+
+    let mut target = home.clone();
+    if let Some(config) = OidcConfig::load() {
+        if let Ok(Discovery { end_session_endpoint: Some(endpoint), .. }) = discover(&config).await {
+            let mut params = vec![
+                ("client_id", config.client_id.as_str()),
+                ("post_logout_redirect_uri", home.as_str()),
+            ];
+            if let Some(hint) = id_token.as_deref() {
+                params.push(("id_token_hint", hint));
+            }
+            let sep = if endpoint.contains('?') { "&" } else { "?" };
+            target = format!("{}{}{}", endpoint, sep, form_encode(&params));
+        }
+    }
+
+    let _ = window.location().assign(&target);
+}
+
+/// Where an ID token came from, which decides how it is checked
+#[derive(Clone, Copy, Debug)]
+pub enum IdTokenSource<'a> {
+    /// Returned by the login that sent this nonce
+    Login(&'a str),
+    /// Returned by a refresh-token grant, which carries no nonce
+    Refresh,
+    /// Read back after a page reload. It may have lapsed since; the refresh
+    /// token renews it, so its expiry is not checked.
+    Stored,
+}
+
+fn id_claims(id_token: &str) -> Result<IdClaims, String> {
+    let payload = id_token.split('.').nth(1).ok_or("Malformed ID token")?;
+    let bytes = base64url_decode(payload).ok_or("Malformed ID token")?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("Invalid ID token claims: {}", e))
+}
+
+/// Whether `id_token` has expired, or cannot be read
+pub fn id_token_expired(id_token: &str) -> bool {
+    id_claims(id_token).map_or(true, |claims| claims.exp <= js_sys::Date::now() / 1000.0)
+}
+
+/// Build the signed-in user from ID token claims.
+///
+/// The token must have been issued by the configured issuer, to this
+/// client, and, unless it is a stored one, not have expired. A token from a
+/// login must carry the nonce sent with the authorization request. The
+/// signature is not verified here: the token came straight from the token
+/// endpoint over TLS and the API verifies the access token on every call.
+pub fn user_from_id_token(id_token: &str, source: IdTokenSource) -> Result<User, String> {
+    let config = OidcConfig::load().ok_or("OpenID Connect is not configured")?;
+    let claims = id_claims(id_token)?;
+
+    if claims.iss.trim_end_matches('/') != config.issuer {
+        return Err("ID token was issued by another identity provider".to_string());
+    }
+    if !claims.aud.contains(&config.client_id)
+        || (claims.aud.is_shared() && claims.azp.as_deref() != Some(config.client_id.as_str()))
+    {
+        return Err("ID token was issued to another application".to_string());
+    }
+    if !matches!(source, IdTokenSource::Stored) && claims.exp + CLOCK_SKEW_SECS <= js_sys::Date::now() / 1000.0 {
+        return Err("ID token has expired, please sign in again".to_string());
+    }
+    if let IdTokenSource::Login(expected) = source {
+        if claims.nonce.as_deref() != Some(expected) {
+            return Err("ID token does not belong to this sign-in".to_string());
+        }
+    }
+
+    let role = claims
+        .roles
+        .iter()
+        .chain(claims.role.iter())
+        .find_map(|r| UserRole::from_claim(r))
+        .ok_or("Your account has no eProcurement role assigned")?;
+
+    let name = claims
+        .name
+        .or(claims.preferred_username)
+        .unwrap_or_else(|| claims.sub.clone());
+    let avatar_initials = name
+        .split_whitespace()
+        .filter_map(|w| w.chars().next())
+        .take(2)
+        .collect::<String>()
+        .to_uppercase();
+
+    Ok(User {
+        id: claims.sub,
+        name,
+        email: claims.email.unwrap_or_default(),
+        role,
+        department: claims.department.unwrap_or_default(),
+        avatar_initials,
+    })
+}
+
+/// Absolute redirect URI registered with the IdP
+fn redirect_uri() -> String {
+    let origin = web_sys::window()
+        .and_then(|w| w.location().origin().ok())
+        .unwrap_or_default();
+    format!("{}{}", origin, callback_path())
+}
+
+async fn discover(config: &OidcConfig) -> Result<Discovery, String> {
+    if let Some(discovery) = DISCOVERY.with(|d| d.borrow().clone()) {
+        return Ok(discovery);
+    }
+
+    let url = format!("{}/.well-known/openid-configuration", config.issuer);
+    let window = web_sys::window().ok_or("no window")?;
+    let response: Response = JsFuture::from(window.fetch_with_str(&url))
+        .await
+        .map_err(|_| "Identity provider unreachable".to_string())?
+        .dyn_into()
+        .map_err(|_| "Identity provider unreachable".to_string())?;
+    if !response.ok() {
+        return Err(format!("Discovery failed with status {}", response.status()));
+    }
+    let json = JsFuture::from(response.json().map_err(|_| "Invalid discovery document")?)
+        .await
+        .map_err(|_| "Invalid discovery document".to_string())?;
+    let discovery: Discovery = serde_wasm_bindgen::from_value(json).map_err(|e| e.to_string())?;
+
+    DISCOVERY.with(|d| *d.borrow_mut() = Some(discovery.clone()));
+    Ok(discovery)
+}
+
+/// POST an `application/x-www-form-urlencoded` body to the token endpoint
+async fn post_form(url: &str, params: &[(&str, &str)]) -> Result<TokenResponse, ApiError> {
+    let mut opts = RequestInit::new();
+    opts.method("POST");
+    opts.body(Some(&JsValue::from_str(&form_encode(params))));
+
+    let request = Request::new_with_str_and_init(url, &opts)
+        .map_err(|_| ApiError::network("Failed to create request"))?;
+    request
+        .headers()
+        .set("Content-Type", "application/x-www-form-urlencoded")
+        .ok();
+
+    let window = web_sys::window().ok_or_else(|| ApiError::network("no window"))?;
+    let response: Response = JsFuture::from(window.fetch_with_request(&request))
+        .await
+        .map_err(|_| ApiError::network("Identity provider unreachable"))?
+        .dyn_into()
+        .map_err(|_| ApiError::network("Identity provider unreachable"))?;
+
+    if !response.ok() {
+        return Err(api::read_problem(response).await);
+    }
+
+    let json = JsFuture::from(response.json().map_err(|_| ApiError::network("Failed to parse response"))?)
+        .await
+        .map_err(|_| ApiError::network("Failed to parse response"))?;
+    serde_wasm_bindgen::from_value(json)
+        .map_err(|e| ApiError::network(format!("JSON parse error: {}", e)))
+}
+
+/// S256 code challenge for a PKCE verifier
+async fn pkce_challenge(verifier: &str) -> Result<String, String> {
+    let subtle = web_sys::window()
+        .ok_or("no window")?
+        .crypto()
+        .map_err(|_| "Web Crypto unavailable")?
+        .subtle();
+    let data = js_sys::Uint8Array::from(verifier.as_bytes());
+    let promise = subtle
+        .digest_with_str_and_buffer_source("SHA-256", &data)
+        .map_err(|_| "SHA-256 unavailable")?;
+    let digest = JsFuture::from(promise).await.map_err(|_| "SHA-256 failed")?;
+    Ok(base64url_encode(&js_sys::Uint8Array::new(&digest).to_vec()))
+}
+
+/// Random URL-safe token of `len` bytes of entropy
+fn random_token(len: usize) -> Result<String, String> {
+    let mut bytes = vec![0u8; len];
+    web_sys::window()
+        .ok_or("no window")?
+        .crypto()
+        .map_err(|_| "Web Crypto unavailable")?
+        .get_random_values_with_u8_array(&mut bytes)
+        .map_err(|_| "Random number generation failed")?;
+    Ok(base64url_encode(&bytes))
+}
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Unpadded base64url encoding
+fn base64url_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 4 / 3 + 3);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |acc, (i, b)| acc | ((*b as u32) << (16 - 8 * i)));
+        for i in 0..chunk.len() + 1 {
+            out.push(BASE64URL[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+/// Decode base64url, padded or not
+fn base64url_decode(input: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in input.trim_end_matches('=').bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'-' | b'+' => 62,
+            b'_' | b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(out)
+}
+
+fn form_encode(params: &[(&str, &str)]) -> String {
+    params
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, String::from(js_sys::encode_uri_component(v))))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn query_param(search: &str, name: &str) -> Option<String> {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .and_then(|(_, value)| js_sys::decode_uri_component(&value.replace('+', " ")).ok())
+        .map(String::from)
+}
+
+fn session_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.session_storage().ok()?
+}
+
+/// `<meta>` tag value, or in a dev build a localStorage override of the
+/// same name
+fn setting(key: &str) -> Option<String> {
+    if cfg!(debug_assertions) {
+        let stored = web_sys::window()?
+            .local_storage()
+            .ok()
+            .flatten()
+            .and_then(|s| s.get_item(key).ok().flatten())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        if stored.is_some() {
+            return stored;
+        }
+    }
+    meta(key)
+}
+
+fn meta(key: &str) -> Option<String> {
+    let value = web_sys::window()?
+        .document()?
+        .query_selector(&format!("meta[name=\"{}\"]", key))
+        .ok()??
+        .get_attribute("content")?;
+    let value = value.trim().to_string();
+    if value.is_empty() { None } else { Some(value) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64url_matches_rfc_4648_vectors() {
+        let cases = [("", ""), ("f", "Zg"), ("fo", "Zm8"), ("foo", "Zm9v"), ("foob", "Zm9vYg"), ("fooba", "Zm9vYmE"), ("foobar", "Zm9vYmFy")];
+        for (plain, encoded) in cases {
+            assert_eq!(base64url_encode(plain.as_bytes()), encoded, "{:?}", plain);
+            assert_eq!(base64url_decode(encoded).as_deref(), Some(plain.as_bytes()), "{:?}", encoded);
+        }
+    }
+
+    #[test]
+    fn base64url_uses_the_url_safe_alphabet() {
+        assert_eq!(base64url_encode(&[0xfb, 0xff, 0xfe]), "-__-");
+        assert_eq!(base64url_decode("-__-"), Some(vec![0xfb, 0xff, 0xfe]));
+        assert_eq!(base64url_decode("+//+"), Some(vec![0xfb, 0xff, 0xfe]));
+        assert_eq!(base64url_decode("Zm9vYg=="), Some(b"foob".to_vec()));
+        assert_eq!(base64url_decode("Zm9v!"), None);
+    }
+
+    #[test]
+    fn pkce_verifier_and_challenge_match_rfc_7636_appendix_b() {
+        let entropy = [
+            116, 24, 223, 180, 151, 153, 224, 37, 79, 250, 96, 125, 216, 173, 187, 186,
+            22, 212, 37, 77, 105, 214, 191, 240, 91, 88, 5, 88, 83, 132, 141, 121,
+        ];
+        assert_eq!(base64url_encode(&entropy), "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+
+        // SHA-256 of the verifier, as Web Crypto returns it to `pkce_challenge`
+        let digest = [
+            19, 211, 30, 150, 26, 26, 216, 236, 47, 22, 177, 12, 76, 152, 46, 8,
+            118, 168, 120, 173, 109, 241, 68, 86, 110, 225, 137, 74, 203, 112, 249, 195,
+        ];
+        assert_eq!(base64url_encode(&digest), "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM");
+    }
+}
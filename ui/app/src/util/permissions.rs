@@ -0,0 +1,179 @@
+//! Role-based permission matrix
+//!
+//! Single source of truth for which roles may open each route and perform
+//! each sensitive action. `SystemAdmin` is allowed everything.
+
+use crate::Route;
+use super::auth::{self, AuthState, UserRole};
+use UserRole::*;
+
+/// Sensitive actions guarded independently of the route they appear on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    ApprovePurchaseOrder,
+    BlacklistSupplier,
+    RecordDecision,
+    FinalizeEvaluation,
+    ActOnApproval,
+    ReassignApproval,
+    EnforceApprovalSla,
+    TransferBudget,
+    RegisterInvoice,
+    ApproveInvoice,
+    ConfigureMatching,
+    ProcessDocuments,
+    PreparePaymentRun,
+    AuthorisePaymentRun,
+    ConvertRequisition,
+}
+
+impl Action {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::ApprovePurchaseOrder => "approve purchase orders",
+            Action::BlacklistSupplier => "blacklist suppliers",
+            Action::RecordDecision => "record NBAC decisions",
+            Action::FinalizeEvaluation => "finalize evaluations",
+            Action::ActOnApproval => "act on approval steps",
+            Action::ReassignApproval => "reassign approval steps",
+            Action::EnforceApprovalSla => "apply approval SLAs",
+            Action::TransferBudget => "request budget transfers",
+            Action::RegisterInvoice => "register invoices",
+            Action::ApproveInvoice => "approve invoices for payment",
+            Action::ConfigureMatching => "change match tolerances",
+            Action::ProcessDocuments => "capture scanned documents",
+            Action::PreparePaymentRun => "prepare payment runs",
+            Action::AuthorisePaymentRun => "authorise payment runs",
+            Action::ConvertRequisition => "convert requisitions into tenders and orders",
+        }
+    }
+}
+
+const ALL: &[UserRole] = &[
+    ProcurementOfficer, ProcurementManager, BudgetHolder, Approver,
+    ContractManager, SupplierAdmin, AuditViewer, SystemAdmin,
+];
+const INTERNAL: &[UserRole] = &[
+    ProcurementOfficer, ProcurementManager, BudgetHolder, Approver,
+    ContractManager, AuditViewer,
+];
+const REQUISITIONING: &[UserRole] = &[ProcurementOfficer, ProcurementManager, BudgetHolder, Approver];
+const SOURCING: &[UserRole] = &[ProcurementOfficer, ProcurementManager];
+const EVALUATION: &[UserRole] = &[ProcurementOfficer, ProcurementManager, Approver];
+const CONTRACTS: &[UserRole] = &[ProcurementOfficer, ProcurementManager, ContractManager];
+const ORDERING: &[UserRole] = &[ProcurementOfficer, ProcurementManager, BudgetHolder, Approver];
+const RECEIVING: &[UserRole] = &[ProcurementOfficer, ProcurementManager, ContractManager];
+const SUPPLIER_MANAGEMENT: &[UserRole] = &[ProcurementOfficer, ProcurementManager, ContractManager, AuditViewer];
+const SUPPLIER_FACING: &[UserRole] = &[ProcurementManager, SupplierAdmin];
+const PLANNING: &[UserRole] = &[ProcurementOfficer, ProcurementManager, BudgetHolder];
+const OVERSIGHT: &[UserRole] = &[ProcurementManager, AuditViewer];
+const REPORTING: &[UserRole] = &[ProcurementManager, BudgetHolder, Approver, AuditViewer];
+const COMMITTEE: &[UserRole] = &[ProcurementManager, Approver];
+const CATALOGUE_ADMIN: &[UserRole] = &[ProcurementManager];
+const APPROVING: &[UserRole] = &[ProcurementManager, BudgetHolder, Approver];
+const BUDGETING: &[UserRole] = &[ProcurementManager, BudgetHolder];
+const PAYABLES: &[UserRole] = &[ProcurementOfficer, ProcurementManager, BudgetHolder, AuditViewer];
+const INTAKE: &[UserRole] = &[ProcurementOfficer, ProcurementManager];
+
+/// Roles allowed to open a route
+pub fn route_roles(route: &Route) -> &'static [UserRole] {
+    match route {
+        Route::Dashboard
+        | Route::NotFound(_)
+        | Route::DocumentsLibrary
+        | Route::DocumentsDetail(_)
+        | Route::AiAssistantChat => ALL,
+        Route::CatalogueList => INTERNAL,
+        Route::CatalogueAdmin => CATALOGUE_ADMIN,
+
+        Route::RequisitionsList
+        | Route::RequisitionsCreate
+        | Route::RequisitionsDetail(_)
+        | Route::RequisitionsEdit(_) => REQUISITIONING,
+
+        Route::TendersList
+        | Route::TendersCreate
+        | Route::TendersEdit(_)
+        | Route::TendersPublication(_)
+        | Route::TendersDeviation(_)
+        | Route::ReverseAuctionList
+        | Route::ReverseAuctionLive(_) => SOURCING,
+
+        Route::EvaluationList | Route::EvaluationScoring(_) => EVALUATION,
+
+        Route::ContractsList
+        | Route::ContractsCreate
+        | Route::ContractsEdit(_)
+        | Route::ContractsMilestones(_) => CONTRACTS,
+
+        Route::PurchaseOrdersList
+        | Route::PurchaseOrdersCreate
+        | Route::PurchaseOrdersDetail(_)
+        | Route::PurchaseOrdersEdit(_) => ORDERING,
+        Route::GoodsReceiptList | Route::GoodsReceiptDetail(_) => RECEIVING,
+        Route::InvoicesList | Route::InvoicesCreate | Route::InvoicesDetail(_) => PAYABLES,
+        Route::PaymentRunsList | Route::PaymentRunDetail(_) => PAYABLES,
+        Route::OcrIntake | Route::OcrReview(_) => INTAKE,
+
+        Route::SuppliersRegistry
+        | Route::SuppliersDetail(_)
+        | Route::SuppliersPerformance
+        | Route::SuppliersRisk => SUPPLIER_MANAGEMENT,
+        Route::SupplierPortalDashboard | Route::MobileSupplierApp => SUPPLIER_FACING,
+
+        Route::SourcingPlanList | Route::SourcingPlanCreate | Route::SourcingPlanEdit(_) => PLANNING,
+
+        Route::AnalyticsDashboard | Route::BudgetDashboard | Route::BudgetTransfers => REPORTING,
+        Route::GrcDashboard
+        | Route::AuditTrail
+        | Route::AgsaReviews
+        | Route::AgsaFindingDetail(_) => OVERSIGHT,
+        Route::BbbeeGoals => &[ProcurementOfficer, ProcurementManager, AuditViewer],
+        Route::NbacReviews | Route::NbacReviewDetail(_) => COMMITTEE,
+        Route::ApprovalDelegations | Route::OverdueApprovals => APPROVING,
+    }
+}
+
+/// Roles allowed to perform an action
+pub fn action_roles(action: Action) -> &'static [UserRole] {
+    match action {
+        Action::ApprovePurchaseOrder => &[ProcurementManager, Approver, BudgetHolder],
+        Action::BlacklistSupplier => &[ProcurementManager],
+        Action::RecordDecision => COMMITTEE,
+        Action::FinalizeEvaluation => &[ProcurementManager, Approver],
+        Action::ActOnApproval => APPROVING,
+        Action::ReassignApproval => &[ProcurementManager],
+        Action::EnforceApprovalSla => &[ProcurementManager],
+        Action::TransferBudget => BUDGETING,
+        Action::RegisterInvoice => &[ProcurementOfficer, ProcurementManager],
+        Action::ApproveInvoice => BUDGETING,
+        Action::ConfigureMatching => &[ProcurementManager],
+        Action::ProcessDocuments => INTAKE,
+        Action::PreparePaymentRun => &[ProcurementOfficer, ProcurementManager],
+        Action::AuthorisePaymentRun => BUDGETING,
+        Action::ConvertRequisition => SOURCING,
+    }
+}
+
+fn allowed(auth: &AuthState, roles: &[UserRole]) -> bool {
+    auth.authenticated && (auth::has_role(auth, SystemAdmin) || auth::has_any_role(auth, roles))
+}
+
+/// Whether the user may open `route`
+pub fn can_access(auth: &AuthState, route: &Route) -> bool {
+    allowed(auth, route_roles(route))
+}
+
+/// Whether the user may perform `action`
+pub fn can_perform(auth: &AuthState, action: Action) -> bool {
+    allowed(auth, action_roles(action))
+}
+
+/// Service-side guard: error unless the signed-in user may perform `action`
+pub fn require(action: Action) -> Result<(), String> {
+    if can_perform(&auth::current(), action) {
+        Ok(())
+    } else {
+        Err(format!("You are not permitted to {}", action.label()))
+    }
+}
@@ -0,0 +1,252 @@
+//! Shared client-side query cache
+//!
+//! Responses are cached per key (the API endpoint) and shared by every store
+//! that reads the same data. Concurrent reads of a key share one request, and
+//! stale entries are served immediately while a background request refreshes
+//! them. Mutations invalidate the keys whose data they change; a request
+//! already in flight for an invalidated key is discarded when it completes,
+//! so it cannot overwrite the data with what was read before the change.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+use components::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::{future_to_promise, spawn_local, JsFuture};
+use super::api::ApiError;
+
+/// How long a cached response is served without revalidating
+pub const DEFAULT_STALE_MS: f64 = 30_000.0;
+
+struct Entry {
+    value: Box<dyn Any>,
+    fetched_at: f64,
+    invalidated: bool,
+}
+
+thread_local! {
+    static CACHE: RefCell<HashMap<String, Entry>> = RefCell::new(HashMap::new());
+    static IN_FLIGHT: RefCell<HashMap<String, js_sys::Promise>> = RefCell::new(HashMap::new());
+    static FAILURES: RefCell<HashMap<String, ApiError>> = RefCell::new(HashMap::new());
+    /// Bumped for a key each time it is invalidated or the cache is cleared
+    static GENERATIONS: RefCell<HashMap<String, u64>> = RefCell::new(HashMap::new());
+}
+
+fn now() -> f64 {
+    js_sys::Date::now()
+}
+
+fn generation(key: &str) -> u64 {
+    GENERATIONS.with(|g| *g.borrow_mut().entry(key.to_string()).or_default())
+}
+
+fn bump_generation(key: &str) {
+    GENERATIONS.with(|g| *g.borrow_mut().entry(key.to_string()).or_default() += 1);
+}
+
+/// Cached value for `key` and whether it is still fresh
+fn cached<T: Clone + 'static>(key: &str, stale_ms: f64) -> Option<(T, bool)> {
+    CACHE.with(|cache| {
+        let cache = cache.borrow();
+        let entry = cache.get(key)?;
+        let value = entry.value.downcast_ref::<T>()?.clone();
+        let fresh = !entry.invalidated && now() - entry.fetched_at < stale_ms;
+        Some((value, fresh))
+    })
+}
+
+fn store<T: 'static>(key: &str, value: T) {
+    CACHE.with(|cache| {
+        cache.borrow_mut().insert(
+            key.to_string(),
+            Entry {
+                value: Box::new(value),
+                fetched_at: now(),
+                invalidated: false,
+            },
+        );
+    });
+}
+
+/// Fetch `key`, sharing the request with any concurrent caller for the same key.
+///
+/// If `key` is invalidated while the request is in flight, its result is
+/// dropped and the key fetched again.
+pub async fn fetch<T, F, Fut>(key: &str, fetcher: F) -> Result<T, ApiError>
+where
+    T: Clone + 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, ApiError>> + 'static,
+{
+    let fetcher = Rc::new(fetcher);
+    loop {
+        let started = generation(key);
+        let pending = IN_FLIGHT.with(|f| f.borrow().get(key).cloned());
+        let promise = match pending {
+            Some(promise) => promise,
+            None => {
+                let owned_key = key.to_string();
+                let fetcher = fetcher.clone();
+                let promise = future_to_promise(async move {
+                    let result = fetcher().await;
+                    // Invalidated meanwhile: the key no longer belongs to this request
+                    if generation(&owned_key) != started {
+                        return Err(JsValue::UNDEFINED);
+                    }
+                    IN_FLIGHT.with(|f| f.borrow_mut().remove(&owned_key));
+                    match result {
+                        Ok(value) => {
+                            FAILURES.with(|f| f.borrow_mut().remove(&owned_key));
+                            store(&owned_key, value);
+                            Ok(JsValue::UNDEFINED)
+                        }
+                        Err(e) => {
+                            FAILURES.with(|f| f.borrow_mut().insert(owned_key, e));
+                            Err(JsValue::UNDEFINED)
+                        }
+                    }
+                });
+                IN_FLIGHT.with(|f| f.borrow_mut().insert(key.to_string(), promise.clone()));
+                promise
+            }
+        };
+
+        let outcome = JsFuture::from(promise).await;
+        if generation(key) != started {
+            continue;
+        }
+        return match outcome {
+            Ok(_) => cached::<T>(key, f64::INFINITY)
+                .map(|(value, _)| value)
+                .ok_or_else(|| ApiError::network("Cached response has an unexpected type")),
+            Err(_) => Err(FAILURES
+                .with(|f| f.borrow().get(key).cloned())
+                .unwrap_or_else(|| ApiError::network("Request failed"))),
+        };
+    }
+}
+
+/// Stale-while-revalidate read into a store signal.
+///
+/// A cached value is written to `target` immediately; if it is older than
+/// `stale_ms` or invalidated, a background request refreshes it and updates
+/// `target` again. Without a cached value the request is awaited.
+pub async fn fetch_into<T, F, Fut>(
+    key: &str,
+    target: &Signal<T>,
+    stale_ms: f64,
+    fetcher: F,
+) -> Result<(), ApiError>
+where
+    T: Clone + 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, ApiError>> + 'static,
+{
+    match cached::<T>(key, stale_ms) {
+        Some((value, fresh)) => {
+            target.set(value);
+            if !fresh {
+                let key = key.to_string();
+                let target = target.clone();
+                spawn_local(async move {
+                    // Keep showing the stale value if revalidation fails
+                    if let Ok(value) = fetch(&key, fetcher).await {
+                        target.set(value);
+                    }
+                });
+            }
+            Ok(())
+        }
+        None => {
+            let value = fetch(key, fetcher).await?;
+            target.set(value);
+            Ok(())
+        }
+    }
+}
+
+/// Whether `key` is `prefix` itself or a sub-resource / query of it
+fn matches_prefix(key: &str, prefix: &str) -> bool {
+    key == prefix
+        || key
+            .strip_prefix(prefix)
+            .map(|rest| rest.starts_with('/') || rest.starts_with('?'))
+            .unwrap_or(false)
+}
+
+/// Mark every key under each prefix as stale, e.g. `/suppliers` also
+/// invalidates `/suppliers/kpis` and `/suppliers/SUP-001`. Requests in
+/// flight for those keys are detached, so the next read starts a new one.
+pub fn invalidate(prefixes: &[&str]) {
+    let matches = |key: &str| prefixes.iter().any(|p| matches_prefix(key, p));
+    CACHE.with(|cache| {
+        for (key, entry) in cache.borrow_mut().iter_mut() {
+            if matches(key) {
+                entry.invalidated = true;
+                bump_generation(key);
+            }
+        }
+    });
+    IN_FLIGHT.with(|f| {
+        f.borrow_mut().retain(|key, _| {
+            if !matches(key) {
+                return true;
+            }
+            if !CACHE.with(|cache| cache.borrow().contains_key(key)) {
+                bump_generation(key);
+            }
+            false
+        })
+    });
+}
+
+/// Other collections whose cached data a change to `resource` makes stale.
+///
+/// Every mutation goes through [`invalidate_resource`], so a derived figure
+/// only needs listing here to stay in step with the records it is built from.
+fn dependents(resource: &str) -> &'static [&'static str] {
+    match resource {
+        // Requisitions feed the dashboard queue and spend pipeline
+        "/requisitions" => &["/dashboard", "/analytics"],
+        // Order status drives committed spend and the receiving queue
+        "/purchase-orders" => &["/dashboard", "/analytics", "/goods-receipts"],
+        // A contract's status feeds the supplier's `active_contracts` count
+        "/contracts" => &["/suppliers", "/dashboard", "/analytics"],
+        // Receipts move received quantities, matching and actual spend
+        "/goods-receipts" => &["/purchase-orders", "/invoices", "/budget", "/dashboard", "/analytics"],
+        "/invoices" => &["/payment-runs", "/budget", "/dashboard", "/analytics"],
+        "/payment-runs" => &["/invoices", "/budget", "/dashboard", "/analytics"],
+        "/budget" => &["/dashboard", "/analytics"],
+        "/tenders" => &["/portal", "/evaluations", "/sourcing-plans", "/dashboard"],
+        "/evaluations" => &["/tenders", "/nbac"],
+        "/nbac" => &["/tenders", "/evaluations"],
+        "/suppliers" => &["/bbbee", "/dashboard", "/analytics"],
+        _ => &[],
+    }
+}
+
+/// Invalidate the top-level collection an endpoint belongs to, so
+/// `/contracts/CON-001/approve` invalidates `/contracts`, together with the
+/// collections derived from it
+pub fn invalidate_resource(endpoint: &str) {
+    let path = endpoint.split('?').next().unwrap_or_default();
+    if let Some(resource) = path.trim_start_matches('/').split('/').next() {
+        if !resource.is_empty() {
+            let resource = format!("/{}", resource);
+            let mut prefixes = vec![resource.as_str()];
+            prefixes.extend_from_slice(dependents(&resource));
+            invalidate(&prefixes);
+        }
+    }
+}
+
+/// Drop every cached response and detach every request in flight, e.g. on
+/// sign-out
+pub fn clear() {
+    GENERATIONS.with(|g| g.borrow_mut().values_mut().for_each(|generation| *generation += 1));
+    IN_FLIGHT.with(|f| f.borrow_mut().clear());
+    CACHE.with(|cache| cache.borrow_mut().clear());
+    FAILURES.with(|f| f.borrow_mut().clear());
+}
@@ -0,0 +1,436 @@
+//! Reference numbers for procurement documents
+//!
+//! Requisitions, tenders, purchase orders, goods receipts, contracts and NBAC
+//! resolutions are numbered per financial year from a template such as
+//! `REQ-{FY}-{SEQ:4}`, so the sequence starts again at 1 on 1 April. The
+//! templates come from the organisation's settings when the REST backend is
+//! in use and fall back to the defaults below.
+//!
+//! The server owns the registers. A number issued here is provisional: it
+//! lets a new document be shown, linked and queued offline before it is
+//! saved, and [`reconcile`] replaces it with the server's number once the
+//! create succeeds. Sequences are reserved synchronously and remembered in
+//! localStorage per register and financial year, and numbers already in the
+//! store are skipped, so documents created in this browser, while an earlier
+//! save is still in flight or after a reload, do not share a provisional
+//! number. Other browsers and users number independently, so a provisional
+//! number can clash with theirs; only the number the server allocates is
+//! unique.
+//!
+//! Records that have no register of their own, such as line items and
+//! uploaded documents, get an opaque [`unique_id`] instead.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use super::api;
+use super::backend;
+use super::datetime::FiscalYear;
+
+/// localStorage key prefix for the last sequence issued per register
+const COUNTER_KEY: &str = "eprocurement.reference";
+
+/// Settings endpoint holding the organisation's numbering templates, keyed
+/// by [`DocumentType::key`]
+const TEMPLATES_ENDPOINT: &str = "/settings/reference-numbers";
+
+/// Sequence width when a template says just `{SEQ}`
+const DEFAULT_SEQ_WIDTH: usize = 4;
+
+/// Documents that carry a reference number
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DocumentType {
+    Requisition,
+    Rfq,
+    Rfp,
+    Rft,
+    PurchaseOrder,
+    GoodsReceipt,
+    Invoice,
+    PaymentRun,
+    Contract,
+    NbacResolution,
+}
+
+impl DocumentType {
+    pub const ALL: [DocumentType; 10] = [
+        DocumentType::Requisition,
+        DocumentType::Rfq,
+        DocumentType::Rfp,
+        DocumentType::Rft,
+        DocumentType::PurchaseOrder,
+        DocumentType::GoodsReceipt,
+        DocumentType::Invoice,
+        DocumentType::PaymentRun,
+        DocumentType::Contract,
+        DocumentType::NbacResolution,
+    ];
+
+    /// Key used by the settings API
+    pub fn key(&self) -> &'static str {
+        match self {
+            DocumentType::Requisition => "requisition",
+            DocumentType::Rfq => "rfq",
+            DocumentType::Rfp => "rfp",
+            DocumentType::Rft => "rft",
+            DocumentType::PurchaseOrder => "purchase_order",
+            DocumentType::GoodsReceipt => "goods_receipt",
+            DocumentType::Invoice => "invoice",
+            DocumentType::PaymentRun => "payment_run",
+            DocumentType::Contract => "contract",
+            DocumentType::NbacResolution => "nbac_resolution",
+        }
+    }
+
+    /// Document type for a [`key`](Self::key)
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|doc_type| doc_type.key() == key)
+    }
+
+    pub fn default_template(&self) -> &'static str {
+        match self {
+            DocumentType::Requisition => "REQ-{FY}-{SEQ:4}",
+            DocumentType::Rfq => "RFQ-{FY}-{SEQ:4}",
+            DocumentType::Rfp => "RFP-{FY}-{SEQ:4}",
+            DocumentType::Rft => "RFT-{FY}-{SEQ:4}",
+            DocumentType::PurchaseOrder => "PO-{FY}-{SEQ:4}",
+            DocumentType::GoodsReceipt => "GR-{FY}-{SEQ:4}",
+            DocumentType::Invoice => "INV-{FY}-{SEQ:4}",
+            DocumentType::PaymentRun => "PAY-{FY}-{SEQ:4}",
+            DocumentType::Contract => "CTR-{FY}-{SEQ:4}",
+            DocumentType::NbacResolution => "NBAC/RES/{FY}/{SEQ:3}",
+        }
+    }
+
+    /// Register the sequence is drawn from. RFQs, RFPs and RFTs share the
+    /// tender register, so a tender number is unique whatever its type.
+    fn register(&self) -> &'static str {
+        match self {
+            DocumentType::Rfq | DocumentType::Rfp | DocumentType::Rft => "tender",
+            other => other.key(),
+        }
+    }
+}
+
+/// A reference number issued from a register
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reference {
+    pub number: String,
+    pub fiscal_year: FiscalYear,
+    pub sequence: u32,
+}
+
+thread_local! {
+    /// Templates configured for this organisation; defaults apply otherwise
+    static TEMPLATES: RefCell<HashMap<DocumentType, String>> = RefCell::new(HashMap::new());
+    /// Last sequence issued per counter key, for when localStorage is unavailable
+    static ISSUED: RefCell<HashMap<String, u32>> = RefCell::new(HashMap::new());
+    /// Provisional numbers the server replaced, mapped to the server's number
+    static RECONCILED: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+    static NEXT_ID: Cell<u32> = Cell::new(0);
+}
+
+/// Template in effect for `doc_type`
+pub fn template(doc_type: DocumentType) -> String {
+    TEMPLATES
+        .with(|t| t.borrow().get(&doc_type).cloned())
+        .unwrap_or_else(|| doc_type.default_template().to_string())
+}
+
+/// Use `template` for new `doc_type` numbers.
+///
+/// Templates may use `{FY}` (year the financial year ends, `2025`),
+/// `{FY_START}` (`2024`) and `{FY_LABEL}` (`2024/25`), and must contain the
+/// sequence exactly once as `{SEQ}` or `{SEQ:n}` for a width of n digits.
+pub fn set_template(doc_type: DocumentType, template: &str) -> Result<(), String> {
+    validate(template)?;
+    TEMPLATES.with(|t| t.borrow_mut().insert(doc_type, template.trim().to_string()));
+    Ok(())
+}
+
+/// Load the organisation's numbering templates. Under the mock backend, or
+/// if the settings cannot be fetched, the default templates stay in effect.
+pub async fn load_templates() {
+    if backend::is_mock() {
+        return;
+    }
+    let Ok(templates) = api::get::<HashMap<String, String>>(TEMPLATES_ENDPOINT).await else {
+        return;
+    };
+    for doc_type in DocumentType::ALL {
+        if let Some(template) = templates.get(doc_type.key()) {
+            // A template that fails validation is ignored rather than issuing
+            // numbers that could repeat
+            let _ = set_template(doc_type, template);
+        }
+    }
+}
+
+/// Issue the next provisional number for `doc_type` in the current financial
+/// year. `existing` holds the numbers already in the store; the sequence
+/// continues after the highest of them that belongs to the same register.
+pub fn issue<'a>(doc_type: DocumentType, existing: impl IntoIterator<Item = &'a str>) -> Reference {
+    let fiscal_year = FiscalYear::current();
+    let key = counter_key(doc_type, fiscal_year);
+
+    let highest_existing = existing
+        .into_iter()
+        .filter_map(|number| sequence_of(doc_type, fiscal_year, number))
+        .max()
+        .unwrap_or(0);
+    let sequence = last_issued(&key).max(highest_existing) + 1;
+    record_issued(&key, sequence);
+
+    Reference {
+        number: render(&template(doc_type), fiscal_year, sequence),
+        fiscal_year,
+        sequence,
+    }
+}
+
+/// Record the number the server gave a document created as `provisional`.
+///
+/// Later provisional numbers continue after the server's, and [`resolve`]
+/// maps the provisional number to the final one for links made before the
+/// save completed.
+pub fn reconcile(doc_type: DocumentType, provisional: &str, confirmed: &str) {
+    if provisional != confirmed {
+        RECONCILED.with(|r| r.borrow_mut().insert(provisional.to_string(), confirmed.to_string()));
+    }
+
+    let fiscal_year = FiscalYear::current();
+    if let Some(sequence) = sequence_of(doc_type, fiscal_year, confirmed) {
+        let key = counter_key(doc_type, fiscal_year);
+        if sequence > last_issued(&key) {
+            record_issued(&key, sequence);
+        }
+    }
+}
+
+/// The server's number for `number` if it was a provisional number the
+/// server has since replaced, otherwise `number` itself
+pub fn resolve(number: &str) -> String {
+    RECONCILED
+        .with(|r| r.borrow().get(number).cloned())
+        .unwrap_or_else(|| number.to_string())
+}
+
+/// Opaque identifier such as `LI-M7N2Q4K01X3F9`, unique across tabs and
+/// sessions: the clock, a per-session counter and a random part
+pub fn unique_id(prefix: &str) -> String {
+    let counter = NEXT_ID.with(|n| {
+        let value = n.get();
+        n.set(value.wrapping_add(1));
+        value
+    });
+    let millis = js_sys::Date::now() as u64;
+    let random = (js_sys::Math::random() * 36f64.powi(4)) as u64;
+    format!(
+        "{}-{}{}{}",
+        prefix,
+        base36(millis, 0),
+        base36(u64::from(counter % 1296), 2),
+        base36(random, 4)
+    )
+}
+
+/// One piece of a parsed template
+#[derive(Clone, Debug, PartialEq)]
+enum Part<'a> {
+    Text(&'a str),
+    FiscalYear,
+    FiscalYearStart,
+    FiscalYearLabel,
+    Sequence(usize),
+}
+
+fn parse(template: &str) -> Result<Vec<Part<'_>>, String> {
+    let mut parts = Vec::new();
+    let mut rest = template.trim();
+    while let Some(open) = rest.find('{') {
+        if open > 0 {
+            parts.push(Part::Text(&rest[..open]));
+        }
+        let close = rest[open..]
+            .find('}')
+            .map(|i| open + i)
+            .ok_or_else(|| format!("Unclosed '{{' in template '{}'", template))?;
+        let token = &rest[open + 1..close];
+        parts.push(match token {
+            "FY" => Part::FiscalYear,
+            "FY_START" => Part::FiscalYearStart,
+            "FY_LABEL" => Part::FiscalYearLabel,
+            "SEQ" => Part::Sequence(DEFAULT_SEQ_WIDTH),
+            _ => match token.strip_prefix("SEQ:").and_then(|w| w.parse().ok()) {
+                Some(width @ 1..=9) => Part::Sequence(width),
+                _ => return Err(format!("Unknown placeholder '{{{}}}' in template '{}'", token, template)),
+            },
+        });
+        rest = &rest[close + 1..];
+    }
+    if !rest.is_empty() {
+        parts.push(Part::Text(rest));
+    }
+    Ok(parts)
+}
+
+fn validate(template: &str) -> Result<(), String> {
+    let parts = parse(template)?;
+    let sequences = parts.iter().filter(|p| matches!(p, Part::Sequence(_))).count();
+    if sequences != 1 {
+        return Err(format!("Template '{}' must contain {{SEQ}} exactly once", template));
+    }
+    let has_year = parts
+        .iter()
+        .any(|p| matches!(p, Part::FiscalYear | Part::FiscalYearStart | Part::FiscalYearLabel));
+    if !has_year {
+        // Sequences restart every April, so without the year numbers would repeat
+        return Err(format!("Template '{}' must contain the financial year", template));
+    }
+    Ok(())
+}
+
+fn render_part(part: &Part, fiscal_year: FiscalYear) -> String {
+    match part {
+        Part::Text(text) => text.to_string(),
+        Part::FiscalYear => fiscal_year.end_year().to_string(),
+        Part::FiscalYearStart => fiscal_year.start_year.to_string(),
+        Part::FiscalYearLabel => fiscal_year.label(),
+        Part::Sequence(_) => String::new(),
+    }
+}
+
+fn render(template: &str, fiscal_year: FiscalYear, sequence: u32) -> String {
+    let Ok(parts) = parse(template) else {
+        return format!("{}-{:04}", template, sequence);
+    };
+    parts
+        .iter()
+        .map(|part| match part {
+            Part::Sequence(width) => format!("{:0width$}", sequence, width = *width),
+            other => render_part(other, fiscal_year),
+        })
+        .collect()
+}
+
+/// Sequence of `number` if `template` would have produced it in `fiscal_year`
+fn parse_sequence(template: &str, fiscal_year: FiscalYear, number: &str) -> Option<u32> {
+    let parts = parse(template).ok()?;
+    let split = parts.iter().position(|p| matches!(p, Part::Sequence(_)))?;
+    let prefix: String = parts[..split].iter().map(|p| render_part(p, fiscal_year)).collect();
+    let suffix: String = parts[split + 1..].iter().map(|p| render_part(p, fiscal_year)).collect();
+
+    let digits = number.strip_prefix(prefix.as_str())?.strip_suffix(suffix.as_str())?;
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Sequence of `number` in the register `doc_type` draws from
+fn sequence_of(doc_type: DocumentType, fiscal_year: FiscalYear, number: &str) -> Option<u32> {
+    DocumentType::ALL
+        .iter()
+        .filter(|d| d.register() == doc_type.register())
+        .find_map(|d| parse_sequence(&template(*d), fiscal_year, number))
+}
+
+fn counter_key(doc_type: DocumentType, fiscal_year: FiscalYear) -> String {
+    format!("{}.{}.{}", COUNTER_KEY, doc_type.register(), fiscal_year.start_year)
+}
+
+fn last_issued(key: &str) -> u32 {
+    let stored = web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(key).ok().flatten())
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0);
+    let in_session = ISSUED.with(|i| i.borrow().get(key).copied()).unwrap_or(0);
+    stored.max(in_session)
+}
+
+fn record_issued(key: &str, sequence: u32) {
+    ISSUED.with(|i| i.borrow_mut().insert(key.to_string(), sequence));
+    if let Some(Ok(Some(storage))) = web_sys::window().map(|w| w.local_storage()) {
+        let _ = storage.set_item(key, &sequence.to_string());
+    }
+}
+
+/// Upper-case base 36, left-padded with zeros to `width`
+fn base36(mut value: u64, width: usize) -> String {
+    const DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut out = Vec::new();
+    while value > 0 {
+        out.push(DIGITS[(value % 36) as usize]);
+        value /= 36;
+    }
+    while out.len() < width.max(1) {
+        out.push(b'0');
+    }
+    out.reverse();
+    String::from_utf8(out).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FY_2024: FiscalYear = FiscalYear { start_year: 2024 };
+
+    #[test]
+    fn templates_render_the_year_and_padded_sequence() {
+        let cases = [
+            ("REQ-{FY}-{SEQ:4}", 7, "REQ-2025-0007"),
+            ("NBAC/RES/{FY}/{SEQ:3}", 12, "NBAC/RES/2025/012"),
+            ("PO/{FY_LABEL}/{SEQ}", 42, "PO/2024/25/0042"),
+            ("{FY_START}-GR-{SEQ:2}", 123, "2024-GR-123"),
+            ("  CTR-{FY}-{SEQ:1}  ", 5, "CTR-2025-5"),
+        ];
+        for (template, sequence, expected) in cases {
+            assert_eq!(render(template, FY_2024, sequence), expected, "{}", template);
+            assert_eq!(parse_sequence(template, FY_2024, expected), Some(sequence), "{}", template);
+        }
+    }
+
+    #[test]
+    fn numbers_from_another_year_or_template_have_no_sequence() {
+        let cases = ["REQ-2024-0007", "PO-2025-0007", "REQ-2025-", "REQ-2025-00A7", "REQ-2025-0007-X"];
+        for number in cases {
+            assert_eq!(parse_sequence("REQ-{FY}-{SEQ:4}", FY_2024, number), None, "{}", number);
+        }
+    }
+
+    #[test]
+    fn templates_need_one_sequence_and_the_year() {
+        let cases = [
+            ("REQ-{FY}-{SEQ:4}", true),
+            ("REQ-{FY_LABEL}-{SEQ}", true),
+            ("REQ-{SEQ:4}", false),
+            ("REQ-{FY}", false),
+            ("REQ-{FY}-{SEQ}-{SEQ}", false),
+            ("REQ-{FY}-{SEQ:0}", false),
+            ("REQ-{FY}-{SEQ:10}", false),
+            ("REQ-{YEAR}-{SEQ}", false),
+            ("REQ-{FY-{SEQ}", false),
+            ("REQ-{FY}-{SEQ", false),
+        ];
+        for (template, valid) in cases {
+            assert_eq!(validate(template).is_ok(), valid, "{}", template);
+        }
+    }
+
+    #[test]
+    fn tender_types_share_one_register() {
+        for number in ["RFQ-2025-0003", "RFP-2025-0003", "RFT-2025-0003"] {
+            assert_eq!(sequence_of(DocumentType::Rfq, FY_2024, number), Some(3), "{}", number);
+        }
+        assert_eq!(sequence_of(DocumentType::Requisition, FY_2024, "RFQ-2025-0003"), None);
+        assert_eq!(counter_key(DocumentType::Rfp, FY_2024), counter_key(DocumentType::Rft, FY_2024));
+    }
+
+    #[test]
+    fn base36_pads_to_width() {
+        assert_eq!(base36(0, 0), "0");
+        assert_eq!(base36(35, 2), "0Z");
+        assert_eq!(base36(1296, 2), "100");
+    }
+}
@@ -0,0 +1,135 @@
+//! Query string helpers
+//!
+//! List filters round-trip through the URL so a filtered view can be shared
+//! as a link. Each filter type implements [`QueryFilter`]; list pages call
+//! [`sync_filter`] once with their store's filter signal.
+
+use components::prelude::*;
+use wasm_bindgen::JsValue;
+
+/// Ordered query string parameters
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QueryParams {
+    pairs: Vec<(String, String)>,
+}
+
+impl QueryParams {
+    /// Parse `a=1&b=two`, with or without a leading `?`
+    pub fn parse(search: &str) -> Self {
+        let pairs = search
+            .trim_start_matches('?')
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (decode(key), decode(value))
+            })
+            .collect();
+        Self { pairs }
+    }
+
+    /// Parameters of the current browser URL
+    pub fn current() -> Self {
+        let search = web_sys::window()
+            .and_then(|w| w.location().search().ok())
+            .unwrap_or_default();
+        Self::parse(&search)
+    }
+
+    /// Non-empty value of `key`
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.pairs
+            .iter()
+            .find(|(k, v)| k == key && !v.is_empty())
+            .map(|(_, v)| v.clone())
+    }
+
+    /// Value of `key` parsed as a number or flag
+    pub fn get_parsed<T: std::str::FromStr>(&self, key: &str) -> Option<T> {
+        self.get(key).and_then(|v| v.parse().ok())
+    }
+
+    /// Set `key`, or remove it when `value` is `None` or empty
+    pub fn set(&mut self, key: &str, value: Option<impl ToString>) {
+        let value = value.map(|v| v.to_string()).filter(|v| !v.is_empty());
+        match (self.pairs.iter().position(|(k, _)| k == key), value) {
+            (Some(pos), Some(value)) => self.pairs[pos].1 = value,
+            (None, Some(value)) => self.pairs.push((key.to_string(), value)),
+            (Some(_), None) => self.pairs.retain(|(k, _)| k != key),
+            (None, None) => {}
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Encoded query string including the leading `?`, or empty
+    pub fn to_query_string(&self) -> String {
+        if self.pairs.is_empty() {
+            return String::new();
+        }
+        let encoded: Vec<String> = self
+            .pairs
+            .iter()
+            .map(|(k, v)| format!("{}={}", encode(k), encode(v)))
+            .collect();
+        format!("?{}", encoded.join("&"))
+    }
+}
+
+/// A list filter that can be stored in the query string
+pub trait QueryFilter: Clone + Default + 'static {
+    /// Build the filter from URL parameters; absent keys mean "no filter"
+    fn from_query(params: &QueryParams) -> Self;
+
+    /// Write the filter's keys into `params`, removing unset ones
+    fn write_query(&self, params: &mut QueryParams);
+}
+
+/// Keep `filter` and the URL query string in step.
+///
+/// On first call the URL wins, so opening a shared link applies its filter.
+/// After that every change to `filter` replaces the query string without
+/// adding a history entry.
+pub fn sync_filter<F: QueryFilter>(filter: &Signal<F>) {
+    let current = QueryParams::current();
+    let mut expected = current.clone();
+    filter.get().write_query(&mut expected);
+    if expected != current {
+        filter.set(F::from_query(&current));
+    }
+
+    effect({
+        let filter = filter.clone();
+        move || {
+            let current = QueryParams::current();
+            let mut next = current.clone();
+            filter.get().write_query(&mut next);
+            if next != current {
+                replace_query(&next);
+            }
+        }
+    });
+}
+
+/// Replace the current URL's query string
+pub fn replace_query(params: &QueryParams) {
+    let Some(window) = web_sys::window() else { return };
+    let path = window.location().pathname().unwrap_or_default();
+    if let Ok(history) = window.history() {
+        let url = format!("{}{}", path, params.to_query_string());
+        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&url));
+    }
+}
+
+fn encode(value: &str) -> String {
+    String::from(js_sys::encode_uri_component(value))
+}
+
+fn decode(value: &str) -> String {
+    let value = value.replace('+', " ");
+    js_sys::decode_uri_component(&value)
+        .map(String::from)
+        .unwrap_or(value)
+}
@@ -57,3 +57,60 @@ pub async fn logout_clears_session(ctx: BrowserTestContext) -> Result<(), String
         .await.map_err(|e| e.to_string())?;
     Ok(())
 }
+
+/// Without an identity provider, signing out offers the demo sign-in, which
+/// brings the app back.
+pub async fn demo_sign_in_after_logout(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.navigate("/app/", Some("[data-testid='app-shell']"))
+        .await.map_err(|e| e.to_string())?;
+    ctx.click("[data-testid='logout-button']")
+        .await.map_err(|e| e.to_string())?;
+    ctx.wait_for("[data-testid='demo-login-button']")
+        .await.map_err(|e| e.to_string())?;
+    ctx.click("[data-testid='demo-login-button']")
+        .await.map_err(|e| e.to_string())?;
+    ctx.wait_for("[data-testid='app-shell']")
+        .await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// With an OIDC issuer configured and no session, the login screen is shown.
+/// The issuer is set through the localStorage override a dev build reads.
+pub async fn oidc_configured_shows_login(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.navigate("/app/", Some("[data-testid='app-shell']"))
+        .await.map_err(|e| e.to_string())?;
+    ctx.set_local_storage("eprocurement-oidc-issuer", "http://localhost:8090/default")
+        .await.map_err(|e| e.to_string())?;
+    ctx.set_local_storage("eprocurement-oidc-client-id", "eprocurement-web")
+        .await.map_err(|e| e.to_string())?;
+    let result = async {
+        ctx.navigate("/app/", Some("[data-testid='auth-required']"))
+            .await.map_err(|e| e.to_string())?;
+        ctx.assert_element_exists("[data-testid='login-button']")
+            .await.map_err(|e| e.to_string())
+    }
+    .await;
+    ctx.evaluate("localStorage.removeItem('eprocurement-oidc-issuer'); localStorage.removeItem('eprocurement-oidc-client-id');")
+        .await.map_err(|e| e.to_string())?;
+    result
+}
+
+/// An IdP error on the callback is reported on the login screen.
+pub async fn oidc_callback_error_shown(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.navigate("/app/", Some("[data-testid='app-shell']"))
+        .await.map_err(|e| e.to_string())?;
+    ctx.set_local_storage("eprocurement-oidc-issuer", "http://localhost:8090/default")
+        .await.map_err(|e| e.to_string())?;
+    ctx.set_local_storage("eprocurement-oidc-client-id", "eprocurement-web")
+        .await.map_err(|e| e.to_string())?;
+    let result = async {
+        ctx.navigate("/app/auth/callback?error=access_denied", Some("[data-testid='auth-error']"))
+            .await.map_err(|e| e.to_string())?;
+        ctx.assert_element_exists("[data-testid='login-button']")
+            .await.map_err(|e| e.to_string())
+    }
+    .await;
+    ctx.evaluate("localStorage.removeItem('eprocurement-oidc-issuer'); localStorage.removeItem('eprocurement-oidc-client-id');")
+        .await.map_err(|e| e.to_string())?;
+    result
+}
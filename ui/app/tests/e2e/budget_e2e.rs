@@ -0,0 +1,9 @@
+use e2e_test::{BrowserTestContext, assertions::PageAssertions};
+
+/// The budget dashboard shows category utilisation and the budget lines.
+pub async fn budget_dashboard_renders(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.wait_for("[data-testid='budget-dashboard']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='budget-by-category']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='data-table']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
@@ -112,6 +112,12 @@ pub async fn go_to(ctx: &BrowserTestContext, route: &str) -> Result<(), String>
         "/agsa" => Some("nav-agsa-reviews"),
         "/documents" => Some("nav-documents"),
         "/ai-assistant" => Some("nav-ai-assistant"),
+        "/invoices" => Some("nav-invoices"),
+        "/payments" => Some("nav-payment-runs"),
+        "/intake" => Some("nav-document-intake"),
+        "/delegations" => Some("nav-delegations"),
+        "/approvals/overdue" => Some("nav-overdue-approvals"),
+        "/budget" => Some("nav-budget"),
         "/mobile" => None, // No direct nav for mobile
         _ => None,
     };
@@ -121,6 +127,11 @@ pub async fn go_to(ctx: &BrowserTestContext, route: &str) -> Result<(), String>
         let selector = format!("[data-testid='{}']", testid);
         ctx.wait_for(&selector).await.map_err(|e| e.to_string())?;
         ctx.click(&selector).await.map_err(|e| e.to_string())?;
+    } else if !matches!(route, "/" | "/dashboard" | "/mobile") {
+        // Detail and form routes have no nav link; open them by URL
+        ctx.navigate(&format!("/app{}", route), Some("[data-testid='app-shell']"))
+            .await
+            .map_err(|e| e.to_string())?;
     }
 
     Ok(())
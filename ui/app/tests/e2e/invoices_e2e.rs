@@ -0,0 +1,9 @@
+use e2e_test::{BrowserTestContext, assertions::PageAssertions};
+
+/// The invoice register shows payment aging and the exception queue.
+pub async fn invoice_register_renders(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.wait_for("[data-testid='invoice-register']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='invoice-aging']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='invoice-exceptions']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
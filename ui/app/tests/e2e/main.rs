@@ -21,6 +21,11 @@ mod ai_assistant_e2e;
 mod sourcing_plan_e2e;
 mod bbbee_e2e;
 mod agsa_e2e;
+mod workflow_e2e;
+mod budget_e2e;
+mod invoices_e2e;
+mod ocr_e2e;
+mod payments_e2e;
 mod mobile_e2e;
 mod visual_e2e;
 
@@ -49,6 +54,9 @@ async fn run_eprocurement_e2e() {
         suite.add_test(make_test("localStorage_auth_bypasses_login", auth_e2e::localStorage_auth_bypasses_login));
         suite.add_test(make_test("app_shell_structure",             auth_e2e::app_shell_structure));
         suite.add_test(make_test("logout_clears_session",           auth_e2e::logout_clears_session));
+        suite.add_test(make_test("demo_sign_in_after_logout",       auth_e2e::demo_sign_in_after_logout));
+        suite.add_test(make_test("oidc_configured_shows_login",     auth_e2e::oidc_configured_shows_login));
+        suite.add_test(make_test("oidc_callback_error_shown",       auth_e2e::oidc_callback_error_shown));
         runner.add_suite(suite);
     }
 
@@ -71,6 +79,13 @@ async fn run_eprocurement_e2e() {
         suite.add_test(make_test("route_analytics",             navigation_e2e::route_analytics));
         suite.add_test(make_test("route_grc",                   navigation_e2e::route_grc));
         suite.add_test(make_test("route_audit",                 navigation_e2e::route_audit));
+        suite.add_test(make_test("deep_link_requisition_detail", navigation_e2e::deep_link_requisition_detail));
+        suite.add_test(make_test("deep_link_supplier_detail",   navigation_e2e::deep_link_supplier_detail));
+        suite.add_test(make_test("unknown_path_not_found",      navigation_e2e::unknown_path_not_found));
+        suite.add_test(make_test("missing_entity_not_found",    navigation_e2e::missing_entity_not_found));
+        suite.add_test(make_test("global_search_opens_hit",     navigation_e2e::global_search_opens_hit));
+        suite.add_test(make_test("notification_inbox_opens",    navigation_e2e::notification_inbox_opens));
+        suite.add_test(make_test("language_picker_switches_locale", navigation_e2e::language_picker_switches_locale));
         runner.add_suite(suite);
     }
 
@@ -95,6 +110,9 @@ async fn run_eprocurement_e2e() {
         suite.add_test(make_auth_test("list_renders",           "/requisitions", requisitions_e2e::list_renders));
         suite.add_test(make_auth_test("create_btn_visible",     "/requisitions", requisitions_e2e::create_btn_visible));
         suite.add_test(make_auth_test("filter_bar_renders",     "/requisitions", requisitions_e2e::filter_bar_renders));
+        suite.add_test(make_auth_test("approval_timeline_renders", "/requisitions/REQ-2025-0847", requisitions_e2e::approval_timeline_renders));
+        suite.add_test(make_auth_test("requisition_offers_sourcing", "/requisitions/REQ-2025-0846", requisitions_e2e::requisition_offers_sourcing));
+        suite.add_test(make_auth_test("requisition_form_catalogue_picker", "/requisitions/new", requisitions_e2e::requisition_form_catalogue_picker));
         runner.add_suite(suite);
     }
 
@@ -267,6 +285,42 @@ async fn run_eprocurement_e2e() {
         runner.add_suite(suite);
     }
 
+    // ── workflow ────────────────────────────────────────────────────────
+    if should_run_suite("workflow") {
+        let mut suite = BrowserTestSuite::new("workflow");
+        suite.add_test(make_auth_test("delegations_render", "/delegations", workflow_e2e::delegations_render));
+        suite.add_test(make_auth_test("overdue_approvals_escalate", "/approvals/overdue", workflow_e2e::overdue_approvals_escalate));
+        runner.add_suite(suite);
+    }
+
+    // ── budget ──────────────────────────────────────────────────────────
+    if should_run_suite("budget") {
+        let mut suite = BrowserTestSuite::new("budget");
+        suite.add_test(make_auth_test("budget_dashboard_renders", "/budget", budget_e2e::budget_dashboard_renders));
+        runner.add_suite(suite);
+    }
+
+    // ── invoices ────────────────────────────────────────────────────────
+    if should_run_suite("invoices") {
+        let mut suite = BrowserTestSuite::new("invoices");
+        suite.add_test(make_auth_test("invoice_register_renders", "/invoices", invoices_e2e::invoice_register_renders));
+        runner.add_suite(suite);
+    }
+
+    // ── ocr ─────────────────────────────────────────────────────────────
+    if should_run_suite("ocr") {
+        let mut suite = BrowserTestSuite::new("ocr");
+        suite.add_test(make_auth_test("ocr_intake_renders", "/intake", ocr_e2e::ocr_intake_renders));
+        runner.add_suite(suite);
+    }
+
+    // ── payments ────────────────────────────────────────────────────────
+    if should_run_suite("payments") {
+        let mut suite = BrowserTestSuite::new("payments");
+        suite.add_test(make_auth_test("payment_runs_render", "/payments", payments_e2e::payment_runs_render));
+        runner.add_suite(suite);
+    }
+
     // ── mobile ──────────────────────────────────────────────────────────
     if should_run_suite("mobile") {
         let mut suite = BrowserTestSuite::new("mobile");
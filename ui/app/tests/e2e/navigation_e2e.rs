@@ -110,3 +110,79 @@ pub async fn route_audit(ctx: BrowserTestContext) -> Result<(), String> {
     ctx.assert_element_exists("[data-testid='audit-landing']").await.map_err(|e| e.to_string())?;
     Ok(())
 }
+
+pub async fn deep_link_requisition_detail(ctx: BrowserTestContext) -> Result<(), String> {
+    go_to(&ctx, "/").await?;
+    ctx.navigate("/app/requisitions/REQ-2025-0847", Some("[data-testid='requisition-detail']"))
+        .await
+        .map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='requisition-detail']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub async fn deep_link_supplier_detail(ctx: BrowserTestContext) -> Result<(), String> {
+    go_to(&ctx, "/").await?;
+    ctx.navigate("/app/suppliers/SUP-001", Some("[data-testid='supplier-detail']"))
+        .await
+        .map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='supplier-detail']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub async fn unknown_path_not_found(ctx: BrowserTestContext) -> Result<(), String> {
+    go_to(&ctx, "/").await?;
+    ctx.navigate("/app/no-such-page", Some("[data-testid='not-found']"))
+        .await
+        .map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='app-shell']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub async fn missing_entity_not_found(ctx: BrowserTestContext) -> Result<(), String> {
+    go_to(&ctx, "/").await?;
+    ctx.navigate("/app/suppliers/SUP-999", Some("[data-testid='entity-not-found']"))
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub async fn global_search_opens_hit(ctx: BrowserTestContext) -> Result<(), String> {
+    go_to(&ctx, "/").await?;
+    ctx.wait_for("[data-testid='global-search-input']").await.map_err(|e| e.to_string())?;
+    ctx.fill("[data-testid='global-search-input']", "SUP-001")
+        .await
+        .map_err(|e| e.to_string())?;
+    ctx.wait_for("[data-testid='search-hit']").await.map_err(|e| e.to_string())?;
+    ctx.click("[data-testid='search-hit']").await.map_err(|e| e.to_string())?;
+    ctx.wait_for("[data-testid='supplier-detail']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+pub async fn notification_inbox_opens(ctx: BrowserTestContext) -> Result<(), String> {
+    go_to(&ctx, "/").await?;
+    ctx.wait_for("[data-testid='notification-unread-count']").await.map_err(|e| e.to_string())?;
+    ctx.click("[data-testid='notification-bell']").await.map_err(|e| e.to_string())?;
+    ctx.wait_for("[data-testid='notification-inbox']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='notification-item']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Picking a language re-renders the shell in it; the choice is reset after.
+pub async fn language_picker_switches_locale(ctx: BrowserTestContext) -> Result<(), String> {
+    go_to(&ctx, "/").await?;
+    ctx.wait_for("[data-testid='language-picker']").await.map_err(|e| e.to_string())?;
+    let pick = |code: &str| format!(
+        "(() => {{ const s = document.querySelector(\"[data-testid='language-picker']\"); \
+         s.value = '{}'; s.dispatchEvent(new Event('change', {{ bubbles: true }})); }})()",
+        code
+    );
+    ctx.evaluate(&pick("af")).await.map_err(|e| e.to_string())?;
+    let state = ctx.evaluate(
+        "document.documentElement.lang + '|' + (document.querySelector(\"[data-testid='nav-dashboard']\")?.textContent || '')"
+    ).await.map_err(|e| e.to_string())?;
+    ctx.evaluate(&pick("en")).await.map_err(|e| e.to_string())?;
+    if !state.as_str().map_or(false, |s| s.starts_with("af|") && s.contains("Kontroleskerm")) {
+        return Err(format!("Expected Afrikaans shell, got: {:?}", state));
+    }
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use e2e_test::{BrowserTestContext, assertions::PageAssertions};
+
+/// Document intake offers the upload zone and lists processed scans.
+pub async fn ocr_intake_renders(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.wait_for("[data-testid='ocr-intake']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='ocr-upload']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='ocr-history']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
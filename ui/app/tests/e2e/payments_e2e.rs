@@ -0,0 +1,9 @@
+use e2e_test::{BrowserTestContext, assertions::PageAssertions};
+
+/// Payment runs list the prepared and paid runs and the payment report.
+pub async fn payment_runs_render(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.wait_for("[data-testid='payment-runs']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='payment-runs-table']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='payment-report']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
@@ -21,3 +21,33 @@ pub async fn filter_bar_renders(ctx: BrowserTestContext) -> Result<(), String> {
     ctx.assert_element_exists("[data-testid='filter-bar']").await.map_err(|e| e.to_string())?;
     Ok(())
 }
+
+/// A requisition part-way through approval shows each step of its chain.
+pub async fn approval_timeline_renders(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.wait_for("[data-testid='approval-timeline']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='approval-step']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// An approved requisition of contracted catalogue items offers both a
+/// tender and a direct purchase order.
+pub async fn requisition_offers_sourcing(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.wait_for("[data-testid='requisition-sourcing']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='requisition-convert-tender']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='requisition-convert-orders']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Searching the catalogue from the line items step lists priced items that
+/// can be added to the requisition.
+pub async fn requisition_form_catalogue_picker(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.wait_for("[data-testid='requisition-form-next']").await.map_err(|e| e.to_string())?;
+    ctx.click("[data-testid='requisition-form-next']").await.map_err(|e| e.to_string())?;
+    ctx.wait_for("[data-testid='catalogue-picker-search']").await.map_err(|e| e.to_string())?;
+    ctx.fill("[data-testid='catalogue-picker-search']", "paper")
+        .await
+        .map_err(|e| e.to_string())?;
+    ctx.wait_for("[data-testid='catalogue-result']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='catalogue-picker-add']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
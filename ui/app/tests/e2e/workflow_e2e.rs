@@ -0,0 +1,19 @@
+use e2e_test::{BrowserTestContext, assertions::PageAssertions};
+
+/// The delegations page shows the delegation form and the seeded delegations.
+pub async fn delegations_render(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.wait_for("[data-testid='delegations-page']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='delegation-form']").await.map_err(|e| e.to_string())?;
+    ctx.assert_element_exists("[data-testid='data-table']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Requisition approvals that outran their SLA are listed on the overdue
+/// approvals page and escalated when the SLAs are applied.
+pub async fn overdue_approvals_escalate(ctx: BrowserTestContext) -> Result<(), String> {
+    ctx.wait_for("[data-testid='overdue-approvals']").await.map_err(|e| e.to_string())?;
+    ctx.wait_for("[data-testid='overdue-approvals-table']").await.map_err(|e| e.to_string())?;
+    ctx.click("[data-testid='overdue-apply-slas']").await.map_err(|e| e.to_string())?;
+    ctx.wait_for("[data-testid='overdue-escalated']").await.map_err(|e| e.to_string())?;
+    Ok(())
+}